@@ -0,0 +1,538 @@
+//! Public plugin API surface snapshots and diffs
+//!
+//! An [`ApiSnapshot`] records what games build against: event types and
+//! their fields, plugin structs with the service/system/resource types they
+//! register, and hook trait method signatures. Snapshots are plain JSON
+//! with sorted maps, so the same source always serializes to the same text
+//! regardless of file or declaration order.
+//!
+//! [`ApiDiff::between`] compares two snapshots and sorts every difference
+//! into [`ChangeKind::Breaking`], [`ChangeKind::Additive`] or
+//! [`ChangeKind::Internal`].
+//!
+//! ```ignore
+//! let old = ApiSnapshot::from_json(&std::fs::read_to_string("api_snapshot.json")?)?;
+//! let new = ApiSnapshot::from_plugin_dir("crates/issun/src/plugin")?;
+//! let diff = ApiDiff::between(&old, &new);
+//! if diff.has_breaking() {
+//!     std::process::exit(2);
+//! }
+//! ```
+
+use crate::error::{AnalyzerError, Result};
+use crate::types::PluginInfo;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+
+/// Version of the snapshot format written by this crate
+pub const API_SNAPSHOT_VERSION: u32 = 1;
+
+/// Public API surface of a plugin directory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiSnapshot {
+    /// Snapshot format version
+    pub version: u32,
+    /// Event types by name
+    pub events: BTreeMap<String, EventApi>,
+    /// Plugin structs by name
+    pub plugins: BTreeMap<String, PluginApi>,
+    /// Hook traits by name
+    pub hooks: BTreeMap<String, HookApi>,
+}
+
+/// Event type in a snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventApi {
+    /// Plugin directory the event is defined in
+    pub module: String,
+    /// Field name -> type
+    pub fields: BTreeMap<String, String>,
+}
+
+/// Plugin struct in a snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginApi {
+    /// Plugin directory the struct is defined in
+    pub module: String,
+    /// Name the plugin reports (used for dependencies)
+    pub name: Option<String>,
+    pub services: BTreeSet<String>,
+    pub systems: BTreeSet<String>,
+    pub resources: BTreeSet<String>,
+    pub runtime_states: BTreeSet<String>,
+}
+
+/// Hook trait in a snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookApi {
+    /// Plugin directory the trait is defined in
+    pub module: String,
+    /// Method name -> signature
+    pub methods: BTreeMap<String, HookMethodApi>,
+}
+
+/// Hook method signature in a snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookMethodApi {
+    /// Parameter types, without the receiver
+    pub params: Vec<String>,
+    pub return_type: String,
+    pub has_default_impl: bool,
+}
+
+impl HookMethodApi {
+    fn signature(&self) -> String {
+        format!("({}) -> {}", self.params.join(", "), self.return_type)
+    }
+}
+
+impl ApiSnapshot {
+    /// Snapshot of plugins inferred by
+    /// [`infer_plugins_from_directory`](crate::plugin_extractor::infer_plugins_from_directory)
+    ///
+    /// When two plugins define an item with the same name, the later one (in
+    /// plugin name order) is keyed as `plugin::Name`.
+    pub fn from_plugins(plugins: &[PluginInfo]) -> Self {
+        let mut sorted: Vec<&PluginInfo> = plugins.iter().collect();
+        sorted.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut snapshot = Self {
+            version: API_SNAPSHOT_VERSION,
+            events: BTreeMap::new(),
+            plugins: BTreeMap::new(),
+            hooks: BTreeMap::new(),
+        };
+
+        for plugin in sorted {
+            let module = plugin.name.clone();
+
+            for event in &plugin.event_details {
+                let api = EventApi {
+                    module: module.clone(),
+                    fields: event
+                        .fields
+                        .iter()
+                        .map(|field| (field.name.clone(), field.ty.clone()))
+                        .collect(),
+                };
+                insert_unique(&mut snapshot.events, &module, &event.name, api);
+            }
+
+            for registration in &plugin.registrations {
+                let api = PluginApi {
+                    module: module.clone(),
+                    name: registration.plugin_name.clone(),
+                    services: registration.services.iter().cloned().collect(),
+                    systems: registration.systems.iter().cloned().collect(),
+                    resources: registration.resources.iter().cloned().collect(),
+                    runtime_states: registration.runtime_states.iter().cloned().collect(),
+                };
+                insert_unique(
+                    &mut snapshot.plugins,
+                    &module,
+                    &registration.struct_name,
+                    api,
+                );
+            }
+
+            for hook in &plugin.hook_details {
+                let api = HookApi {
+                    module: module.clone(),
+                    methods: hook
+                        .methods
+                        .iter()
+                        .map(|method| {
+                            (
+                                method.name.clone(),
+                                HookMethodApi {
+                                    params: method.params.clone(),
+                                    return_type: method.return_type.clone(),
+                                    has_default_impl: method.has_default_impl,
+                                },
+                            )
+                        })
+                        .collect(),
+                };
+                insert_unique(&mut snapshot.hooks, &module, &hook.trait_name, api);
+            }
+        }
+
+        snapshot
+    }
+
+    /// Snapshot of a plugin directory such as `crates/issun/src/plugin`
+    pub fn from_plugin_dir<P: AsRef<Path>>(plugin_dir: P) -> Result<Self> {
+        let plugins = crate::plugin_extractor::infer_plugins_from_directory(plugin_dir)?;
+        Ok(Self::from_plugins(&plugins))
+    }
+
+    /// Pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("snapshot serializes")
+    }
+
+    /// Parse a snapshot, rejecting versions newer than this crate writes
+    pub fn from_json(json: &str) -> Result<Self> {
+        let snapshot: Self = serde_json::from_str(json)?;
+        if snapshot.version > API_SNAPSHOT_VERSION {
+            return Err(AnalyzerError::InvalidInput(format!(
+                "API snapshot version {} is newer than supported version {}",
+                snapshot.version, API_SNAPSHOT_VERSION
+            )));
+        }
+        Ok(snapshot)
+    }
+}
+
+/// Insert under `name`, or `module::name` if `name` is taken
+fn insert_unique<T>(map: &mut BTreeMap<String, T>, module: &str, name: &str, value: T) {
+    let key = if map.contains_key(name) {
+        format!("{}::{}", module, name)
+    } else {
+        name.to_string()
+    };
+    map.insert(key, value);
+}
+
+/// How a difference affects games built against the old snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ChangeKind {
+    /// Game code may stop compiling or lose behavior
+    Breaking,
+    /// New API that existing game code does not see
+    Additive,
+    /// Recorded but does not affect game code
+    Internal,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChangeKind::Breaking => write!(f, "breaking"),
+            ChangeKind::Additive => write!(f, "additive"),
+            ChangeKind::Internal => write!(f, "internal"),
+        }
+    }
+}
+
+/// A single difference between two snapshots
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiChange {
+    pub kind: ChangeKind,
+    /// Changed item, e.g. `SaveRequested.slot` or `CombatHook::on_attack`
+    pub item: String,
+    /// What changed
+    pub description: String,
+}
+
+impl fmt::Display for ApiChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}: {}", self.kind, self.item, self.description)
+    }
+}
+
+/// Differences between two snapshots, sorted by kind and item
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiDiff {
+    pub changes: Vec<ApiChange>,
+}
+
+impl ApiDiff {
+    /// Changes going from `old` to `new`
+    ///
+    /// - Breaking: removed event, plugin, hook trait or hook method; removed,
+    ///   added or retyped event field (games construct events with struct
+    ///   literals); removed registered type; changed plugin name; changed
+    ///   hook signature; new hook method or removed default without a default
+    /// - Additive: new event, plugin, hook trait, registered type, or hook
+    ///   method with a default
+    /// - Internal: item moved to another plugin directory, hook method
+    ///   gained a default
+    pub fn between(old: &ApiSnapshot, new: &ApiSnapshot) -> Self {
+        let mut diff = Self::default();
+        diff.diff_events(old, new);
+        diff.diff_plugins(old, new);
+        diff.diff_hooks(old, new);
+        diff.changes
+            .sort_by(|a, b| (a.kind, &a.item).cmp(&(b.kind, &b.item)));
+        diff
+    }
+
+    /// Whether any change is breaking
+    pub fn has_breaking(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.kind == ChangeKind::Breaking)
+    }
+
+    /// Changes of one kind
+    pub fn of_kind(&self, kind: ChangeKind) -> impl Iterator<Item = &ApiChange> {
+        self.changes
+            .iter()
+            .filter(move |change| change.kind == kind)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    fn push(&mut self, kind: ChangeKind, item: impl Into<String>, description: impl Into<String>) {
+        self.changes.push(ApiChange {
+            kind,
+            item: item.into(),
+            description: description.into(),
+        });
+    }
+
+    fn diff_module(&mut self, item: &str, old: &str, new: &str) {
+        if old != new {
+            self.push(
+                ChangeKind::Internal,
+                item,
+                format!("moved from plugin `{}` to `{}`", old, new),
+            );
+        }
+    }
+
+    fn diff_events(&mut self, old: &ApiSnapshot, new: &ApiSnapshot) {
+        for (name, old_event) in &old.events {
+            let Some(new_event) = new.events.get(name) else {
+                self.push(ChangeKind::Breaking, name, "event removed");
+                continue;
+            };
+            self.diff_module(name, &old_event.module, &new_event.module);
+
+            for (field, old_ty) in &old_event.fields {
+                let item = format!("{}.{}", name, field);
+                match new_event.fields.get(field) {
+                    None => self.push(
+                        ChangeKind::Breaking,
+                        item,
+                        format!("field `{}: {}` removed", field, old_ty),
+                    ),
+                    Some(new_ty) if new_ty != old_ty => self.push(
+                        ChangeKind::Breaking,
+                        item,
+                        format!("field type changed from `{}` to `{}`", old_ty, new_ty),
+                    ),
+                    Some(_) => {}
+                }
+            }
+            for (field, new_ty) in &new_event.fields {
+                if !old_event.fields.contains_key(field) {
+                    self.push(
+                        ChangeKind::Breaking,
+                        format!("{}.{}", name, field),
+                        format!("field `{}: {}` added", field, new_ty),
+                    );
+                }
+            }
+        }
+
+        for name in new.events.keys() {
+            if !old.events.contains_key(name) {
+                self.push(ChangeKind::Additive, name, "event added");
+            }
+        }
+    }
+
+    fn diff_plugins(&mut self, old: &ApiSnapshot, new: &ApiSnapshot) {
+        for (name, old_plugin) in &old.plugins {
+            let Some(new_plugin) = new.plugins.get(name) else {
+                self.push(ChangeKind::Breaking, name, "plugin removed");
+                continue;
+            };
+            self.diff_module(name, &old_plugin.module, &new_plugin.module);
+
+            if old_plugin.name != new_plugin.name {
+                self.push(
+                    ChangeKind::Breaking,
+                    name,
+                    format!(
+                        "plugin name changed from {:?} to {:?}",
+                        old_plugin.name, new_plugin.name
+                    ),
+                );
+            }
+
+            for (role, old_types, new_types) in [
+                ("service", &old_plugin.services, &new_plugin.services),
+                ("system", &old_plugin.systems, &new_plugin.systems),
+                ("resource", &old_plugin.resources, &new_plugin.resources),
+                (
+                    "runtime state",
+                    &old_plugin.runtime_states,
+                    &new_plugin.runtime_states,
+                ),
+            ] {
+                for ty in old_types.difference(new_types) {
+                    self.push(
+                        ChangeKind::Breaking,
+                        name,
+                        format!("{} `{}` no longer registered", role, ty),
+                    );
+                }
+                for ty in new_types.difference(old_types) {
+                    self.push(
+                        ChangeKind::Additive,
+                        name,
+                        format!("{} `{}` registered", role, ty),
+                    );
+                }
+            }
+        }
+
+        for name in new.plugins.keys() {
+            if !old.plugins.contains_key(name) {
+                self.push(ChangeKind::Additive, name, "plugin added");
+            }
+        }
+    }
+
+    fn diff_hooks(&mut self, old: &ApiSnapshot, new: &ApiSnapshot) {
+        for (name, old_hook) in &old.hooks {
+            let Some(new_hook) = new.hooks.get(name) else {
+                self.push(ChangeKind::Breaking, name, "hook trait removed");
+                continue;
+            };
+            self.diff_module(name, &old_hook.module, &new_hook.module);
+
+            for (method, old_method) in &old_hook.methods {
+                let item = format!("{}::{}", name, method);
+                let Some(new_method) = new_hook.methods.get(method) else {
+                    self.push(ChangeKind::Breaking, item, "hook method removed");
+                    continue;
+                };
+                if old_method.signature() != new_method.signature() {
+                    self.push(
+                        ChangeKind::Breaking,
+                        item.clone(),
+                        format!(
+                            "signature changed from `{}` to `{}`",
+                            old_method.signature(),
+                            new_method.signature()
+                        ),
+                    );
+                }
+                match (old_method.has_default_impl, new_method.has_default_impl) {
+                    (true, false) => {
+                        self.push(ChangeKind::Breaking, item, "default implementation removed")
+                    }
+                    (false, true) => {
+                        self.push(ChangeKind::Internal, item, "default implementation added")
+                    }
+                    _ => {}
+                }
+            }
+
+            for (method, new_method) in &new_hook.methods {
+                if old_hook.methods.contains_key(method) {
+                    continue;
+                }
+                let item = format!("{}::{}", name, method);
+                if new_method.has_default_impl {
+                    self.push(ChangeKind::Additive, item, "hook method added");
+                } else {
+                    self.push(
+                        ChangeKind::Breaking,
+                        item,
+                        "hook method added without a default implementation",
+                    );
+                }
+            }
+        }
+
+        for name in new.hooks.keys() {
+            if !old.hooks.contains_key(name) {
+                self.push(ChangeKind::Additive, name, "hook trait added");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(json: serde_json::Value) -> ApiSnapshot {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn base() -> serde_json::Value {
+        serde_json::json!({
+            "version": 1,
+            "events": {
+                "DoorOpened": { "module": "dungeon", "fields": { "id": "u32" } }
+            },
+            "plugins": {
+                "DungeonPlugin": {
+                    "module": "dungeon",
+                    "name": "issun:dungeon",
+                    "services": [],
+                    "systems": ["DungeonSystem"],
+                    "resources": ["DungeonConfig"],
+                    "runtime_states": []
+                }
+            },
+            "hooks": {
+                "DungeonHook": {
+                    "module": "dungeon",
+                    "methods": {
+                        "on_enter": { "params": ["u32"], "return_type": "()", "has_default_impl": true }
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_identical_snapshots_have_no_changes() {
+        let diff = ApiDiff::between(&snapshot(base()), &snapshot(base()));
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_hook_changes() {
+        let mut new = base();
+        new["hooks"]["DungeonHook"]["methods"]["on_enter"]["params"] = serde_json::json!(["u64"]);
+        new["hooks"]["DungeonHook"]["methods"]["can_leave"] =
+            serde_json::json!({ "params": [], "return_type": "bool", "has_default_impl": false });
+
+        let diff = ApiDiff::between(&snapshot(base()), &snapshot(new));
+
+        let items: Vec<_> = diff
+            .of_kind(ChangeKind::Breaking)
+            .map(|c| c.item.as_str())
+            .collect();
+        assert_eq!(items, ["DungeonHook::can_leave", "DungeonHook::on_enter"]);
+    }
+
+    #[test]
+    fn test_registration_and_module_changes() {
+        let mut new = base();
+        new["plugins"]["DungeonPlugin"]["resources"] = serde_json::json!(["DungeonLayout"]);
+        new["events"]["DoorOpened"]["module"] = serde_json::json!("room");
+
+        let diff = ApiDiff::between(&snapshot(base()), &snapshot(new));
+
+        let kinds: Vec<_> = diff.changes.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                ChangeKind::Breaking,
+                ChangeKind::Additive,
+                ChangeKind::Internal
+            ]
+        );
+        assert!(diff.changes[0].description.contains("DungeonConfig"));
+    }
+
+    #[test]
+    fn test_newer_snapshot_version_is_rejected() {
+        let mut json = base();
+        json["version"] = serde_json::json!(API_SNAPSHOT_VERSION + 1);
+        assert!(ApiSnapshot::from_json(&json.to_string()).is_err());
+    }
+}
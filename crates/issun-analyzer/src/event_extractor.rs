@@ -1,9 +1,11 @@
 //! Event extraction logic for EventReader and EventBus::publish
 
-use crate::types::{EventPublication, EventSubscription};
+use crate::types::{EventDefinition, EventField, EventPublication, EventSubscription};
+use proc_macro2::Span;
+use std::collections::HashSet;
 use syn::{
-    visit::Visit, AngleBracketedGenericArguments, Expr, ExprCall, ExprMethodCall, File,
-    GenericArgument, Item, PathArguments, Type, TypePath,
+    spanned::Spanned, visit::Visit, AngleBracketedGenericArguments, Expr, ExprCall, ExprMethodCall,
+    Fields, File, GenericArgument, Item, PathArguments, Type, TypePath,
 };
 
 /// Extract EventReader<E> usage from struct fields
@@ -17,16 +19,14 @@ pub fn extract_event_readers(file_path: &str, syntax_tree: &File) -> Vec<EventSu
             // Check each field
             for field in &item_struct.fields {
                 if let Some(event_type) = extract_event_reader_type(&field.ty) {
-                    // Get approximate line number from span
-                    // Note: proc_macro2::Span doesn't expose line numbers in stable Rust
-                    // We use 0 as a placeholder
-                    let line = 0;
+                    let (line, column) = span_start(field.span());
 
                     subscriptions.push(EventSubscription {
                         subscriber: struct_name.clone(),
                         event_type,
                         file_path: file_path.to_string(),
                         line,
+                        column,
                     });
                 }
             }
@@ -62,6 +62,96 @@ pub fn extract_reader_calls(file_path: &str, syntax_tree: &File) -> Vec<EventSub
     visitor.subscriptions
 }
 
+/// Extract event type definitions with their fields
+///
+/// A struct or enum is an event when the same file has `impl Event for ..`
+/// for it. Enum variant fields are recorded as `Variant.field` (or
+/// `Variant.0`), unit variants as `Variant` with an empty type.
+pub fn extract_event_definitions(file_path: &str, syntax_tree: &File) -> Vec<EventDefinition> {
+    let event_impls: HashSet<String> = syntax_tree
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Impl(item_impl) => {
+                let (_, trait_path, _) = item_impl.trait_.as_ref()?;
+                if trait_path.segments.last()?.ident != "Event" {
+                    return None;
+                }
+                match &*item_impl.self_ty {
+                    Type::Path(type_path) => type_path
+                        .path
+                        .segments
+                        .last()
+                        .map(|seg| seg.ident.to_string()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect();
+
+    let mut definitions = Vec::new();
+
+    for item in &syntax_tree.items {
+        let (ident, fields) = match item {
+            Item::Struct(item_struct) => {
+                (&item_struct.ident, event_fields("", &item_struct.fields))
+            }
+            Item::Enum(item_enum) => {
+                let fields = item_enum
+                    .variants
+                    .iter()
+                    .flat_map(|variant| {
+                        let prefix = variant.ident.to_string();
+                        match &variant.fields {
+                            Fields::Unit => vec![EventField {
+                                name: prefix,
+                                ty: String::new(),
+                            }],
+                            fields => event_fields(&format!("{}.", prefix), fields),
+                        }
+                    })
+                    .collect();
+                (&item_enum.ident, fields)
+            }
+            _ => continue,
+        };
+
+        let name = ident.to_string();
+        if event_impls.contains(&name) {
+            let (line, _) = span_start(ident.span());
+            definitions.push(EventDefinition {
+                name,
+                file_path: file_path.to_string(),
+                line,
+                fields,
+            });
+        }
+    }
+
+    definitions
+}
+
+/// Named or numbered fields, each name prefixed with `prefix`
+fn event_fields(prefix: &str, fields: &Fields) -> Vec<EventField> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let name = field
+                .ident
+                .as_ref()
+                .map(|ident| ident.to_string())
+                .unwrap_or_else(|| index.to_string());
+            let ty = &field.ty;
+            EventField {
+                name: format!("{}{}", prefix, name),
+                ty: quote::quote! { #ty }.to_string(),
+            }
+        })
+        .collect()
+}
+
 /// Check if a type is EventReader<E> and extract E
 fn extract_event_reader_type(ty: &Type) -> Option<String> {
     if let Type::Path(TypePath { path, .. }) = ty {
@@ -135,10 +225,12 @@ impl<'ast> Visit<'ast> for EventBusVisitor {
             if receiver_name.contains("bus") || receiver_name.contains("events") {
                 // Check for publish call
                 if node.method == "publish" {
-                    // Extract turbofish generic argument: publish::<EventType>()
-                    if let Some(event_type) = extract_turbofish_type(&node.turbofish) {
-                        // Use placeholder line number
-                        let line = 0;
+                    // Extract turbofish generic argument: publish::<EventType>(),
+                    // or the struct literal being published: publish(EventType { .. })
+                    let event_type = extract_turbofish_type(&node.turbofish)
+                        .or_else(|| node.args.first().and_then(extract_struct_literal_type));
+                    if let Some(event_type) = event_type {
+                        let (line, column) = span_start(node.method.span());
 
                         self.publications.push(EventPublication {
                             publisher: self
@@ -148,13 +240,14 @@ impl<'ast> Visit<'ast> for EventBusVisitor {
                             event_type,
                             file_path: self.file_path.clone(),
                             line,
+                            column,
                         });
                     }
                 }
                 // Check for reader call: bus.reader::<EventType>()
                 else if node.method == "reader" {
                     if let Some(event_type) = extract_turbofish_type(&node.turbofish) {
-                        let line = 0;
+                        let (line, column) = span_start(node.method.span());
 
                         self.subscriptions.push(EventSubscription {
                             subscriber: self
@@ -164,6 +257,7 @@ impl<'ast> Visit<'ast> for EventBusVisitor {
                             event_type,
                             file_path: self.file_path.clone(),
                             line,
+                            column,
                         });
                     }
                 }
@@ -189,8 +283,7 @@ impl<'ast> Visit<'ast> for EventBusVisitor {
                 // Extract generic from last segment
                 if let Some(last_seg) = expr_path.path.segments.last() {
                     if let Some(event_type) = extract_segment_turbofish(last_seg) {
-                        // Use placeholder line number
-                        let line = 0;
+                        let (line, column) = span_start(node.func.span());
 
                         self.publications.push(EventPublication {
                             publisher: self
@@ -200,6 +293,7 @@ impl<'ast> Visit<'ast> for EventBusVisitor {
                             event_type,
                             file_path: self.file_path.clone(),
                             line,
+                            column,
                         });
                     }
                 }
@@ -211,6 +305,12 @@ impl<'ast> Visit<'ast> for EventBusVisitor {
     }
 }
 
+/// 1-based line and column where a span starts
+fn span_start(span: Span) -> (usize, usize) {
+    let start = span.start();
+    (start.line, start.column + 1)
+}
+
 /// Extract type from turbofish syntax: ::<Type>
 fn extract_turbofish_type(turbofish: &Option<AngleBracketedGenericArguments>) -> Option<String> {
     turbofish.as_ref().and_then(|tf| {
@@ -222,6 +322,19 @@ fn extract_turbofish_type(turbofish: &Option<AngleBracketedGenericArguments>) ->
     })
 }
 
+/// Extract the type of a struct literal expression: `EventType { .. }`
+fn extract_struct_literal_type(expr: &Expr) -> Option<String> {
+    if let Expr::Struct(expr_struct) = expr {
+        expr_struct
+            .path
+            .segments
+            .last()
+            .map(|seg| seg.ident.to_string())
+    } else {
+        None
+    }
+}
+
 /// Extract type from path segment's generic arguments
 fn extract_segment_turbofish(segment: &syn::PathSegment) -> Option<String> {
     if let PathArguments::AngleBracketed(args) = &segment.arguments {
@@ -250,6 +363,7 @@ mod tests {
         assert_eq!(subscriptions.len(), 1);
         assert_eq!(subscriptions[0].subscriber, "MySystem");
         assert_eq!(subscriptions[0].event_type, "MyEvent");
+        assert_eq!((subscriptions[0].line, subscriptions[0].column), (3, 17));
     }
 
     #[test]
@@ -268,5 +382,76 @@ mod tests {
         assert_eq!(publications.len(), 1);
         assert_eq!(publications[0].publisher, "handle");
         assert_eq!(publications[0].event_type, "MyEvent");
+        assert_eq!((publications[0].line, publications[0].column), (4, 25));
+    }
+
+    #[test]
+    fn test_extract_publish_of_struct_literal() {
+        let code = r#"
+            fn finish(bus: &mut EventBus) {
+                bus.publish(events::RoundEnded { round: 3 });
+                bus.publish(make_event());
+            }
+        "#;
+
+        let syntax_tree = syn::parse_file(code).unwrap();
+        let publications = extract_event_publications("test.rs", &syntax_tree);
+
+        assert_eq!(publications.len(), 1);
+        assert_eq!(publications[0].event_type, "RoundEnded");
+    }
+
+    #[test]
+    fn test_extract_event_definitions_with_fields() {
+        let code = r#"
+            pub struct SaveRequested {
+                pub slot: String,
+                pub label: Option<String>,
+            }
+            impl Event for SaveRequested {}
+
+            pub struct Tick(pub u64);
+            impl issun::event::Event for Tick {}
+
+            pub enum DoorEvent {
+                Opened { id: u32 },
+                Closed,
+            }
+            impl Event for DoorEvent {}
+
+            pub struct NotAnEvent {
+                pub value: i32,
+            }
+        "#;
+
+        let syntax_tree = syn::parse_file(code).unwrap();
+        let events = extract_event_definitions("events.rs", &syntax_tree);
+
+        let names: Vec<_> = events.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["SaveRequested", "Tick", "DoorEvent"]);
+        assert_eq!(events[0].line, 2);
+
+        let fields = |index: usize| -> Vec<(String, String)> {
+            events[index]
+                .fields
+                .iter()
+                .map(|f| (f.name.clone(), f.ty.clone()))
+                .collect()
+        };
+        assert_eq!(
+            fields(0),
+            [
+                ("slot".to_string(), "String".to_string()),
+                ("label".to_string(), "Option < String >".to_string()),
+            ]
+        );
+        assert_eq!(fields(1), [("0".to_string(), "u64".to_string())]);
+        assert_eq!(
+            fields(2),
+            [
+                ("Opened.id".to_string(), "u32".to_string()),
+                ("Closed".to_string(), String::new()),
+            ]
+        );
     }
 }
@@ -1,7 +1,7 @@
 //! Mermaid graph generation for Event and Hook flows
 
-use crate::types::{AnalysisResult, PluginInfo};
-use std::collections::{HashMap, HashSet};
+use crate::types::{AnalysisResult, PluginInfo, SystemInfo};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 
 /// Options for graph generation
 #[derive(Debug, Clone)]
@@ -353,6 +353,373 @@ impl<'a> CombinedFlowGraphGenerator<'a> {
     }
 }
 
+/// Options for sequence diagram generation
+#[derive(Debug, Clone)]
+pub struct SequenceOptions {
+    /// Maximum number of publish hops to follow from the starting event
+    pub max_depth: usize,
+    /// Show hook calls made by each participant
+    pub show_hooks: bool,
+    /// Name of the participant that publishes the starting event
+    pub origin: String,
+}
+
+impl Default for SequenceOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 5,
+            show_hooks: true,
+            origin: "Origin".to_string(),
+        }
+    }
+}
+
+/// A single step in an event sequence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SequenceStep {
+    /// `from` publishes `event`, received by exactly one subscriber
+    Message {
+        from: String,
+        to: String,
+        event: String,
+    },
+    /// `from` publishes `event`, received by several subscribers in no defined order
+    Parallel {
+        from: String,
+        to: Vec<String>,
+        event: String,
+    },
+    /// `from` calls into a hook trait
+    HookCall { from: String, hook: String },
+    /// `from` publishes `event`, which is already being handled further up the chain
+    Cycle { from: String, event: String },
+    /// `from` publishes `event`, which was already expanded through another branch
+    AlreadyShown { from: String, event: String },
+    /// `from` publishes `event`, but the depth limit stops the walk
+    DepthLimit { from: String, event: String },
+    /// `from` publishes `event`, which has no known subscribers
+    NoSubscribers { from: String, event: String },
+}
+
+/// Generate a linear sequence diagram for the chain triggered by one event
+///
+/// Walks the static publish/subscribe/hook graph breadth-first from the
+/// chosen event. Cycles and the depth limit are marked and truncated.
+pub struct SequenceDiagramGenerator<'a> {
+    result: &'a AnalysisResult,
+    event: String,
+    options: SequenceOptions,
+}
+
+impl<'a> SequenceDiagramGenerator<'a> {
+    pub fn for_event(result: &'a AnalysisResult, event: &str, options: SequenceOptions) -> Self {
+        Self {
+            result,
+            event: event.to_string(),
+            options,
+        }
+    }
+
+    /// Walk the event chain and return the ordered steps
+    pub fn steps(&self) -> Vec<SequenceStep> {
+        let flows = self.build_flows();
+        let mut steps = Vec::new();
+        let mut expanded: HashSet<String> = HashSet::new();
+        let mut hooks_shown: HashSet<String> = HashSet::new();
+
+        // (event, publisher, depth, events already on this chain)
+        let mut queue: VecDeque<(String, String, usize, Vec<String>)> = VecDeque::new();
+        queue.push_back((
+            self.event.clone(),
+            self.options.origin.clone(),
+            0,
+            Vec::new(),
+        ));
+
+        while let Some((event, from, depth, chain)) = queue.pop_front() {
+            if chain.contains(&event) {
+                steps.push(SequenceStep::Cycle { from, event });
+                continue;
+            }
+            if expanded.contains(&event) {
+                steps.push(SequenceStep::AlreadyShown { from, event });
+                continue;
+            }
+            if depth > self.options.max_depth {
+                steps.push(SequenceStep::DepthLimit { from, event });
+                continue;
+            }
+            expanded.insert(event.clone());
+
+            let subscribers: Vec<String> = flows
+                .subscribers
+                .get(&event)
+                .map(|s| s.iter().cloned().collect())
+                .unwrap_or_default();
+
+            match subscribers.len() {
+                0 => {
+                    steps.push(SequenceStep::NoSubscribers { from, event });
+                    continue;
+                }
+                1 => steps.push(SequenceStep::Message {
+                    from,
+                    to: subscribers[0].clone(),
+                    event: event.clone(),
+                }),
+                _ => steps.push(SequenceStep::Parallel {
+                    from,
+                    to: subscribers.clone(),
+                    event: event.clone(),
+                }),
+            }
+
+            let mut next_chain = chain;
+            next_chain.push(event);
+
+            for subscriber in &subscribers {
+                if self.options.show_hooks && hooks_shown.insert(subscriber.clone()) {
+                    if let Some(hooks) = flows.hooks.get(subscriber) {
+                        for hook in hooks {
+                            steps.push(SequenceStep::HookCall {
+                                from: subscriber.clone(),
+                                hook: hook.clone(),
+                            });
+                        }
+                    }
+                }
+
+                if let Some(published) = flows.publishes.get(subscriber) {
+                    for next in published {
+                        queue.push_back((
+                            next.clone(),
+                            subscriber.clone(),
+                            depth + 1,
+                            next_chain.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        steps
+    }
+
+    /// Generate Mermaid sequenceDiagram
+    pub fn generate(&self) -> String {
+        let steps = self.steps();
+        let mut graph = String::from("sequenceDiagram\n");
+        graph.push_str(&format!("    %% Event Sequence: {}\n", self.event));
+
+        for participant in self.participants(&steps) {
+            let id = self.sanitize_id(&participant);
+            if id == participant {
+                graph.push_str(&format!("    participant {}\n", id));
+            } else {
+                graph.push_str(&format!("    participant {} as {}\n", id, participant));
+            }
+        }
+
+        for step in &steps {
+            match step {
+                SequenceStep::Message { from, to, event } => {
+                    graph.push_str(&format!(
+                        "    {}->>{}: {}\n",
+                        self.sanitize_id(from),
+                        self.sanitize_id(to),
+                        event
+                    ));
+                }
+                SequenceStep::Parallel { from, to, event } => {
+                    for (i, subscriber) in to.iter().enumerate() {
+                        let keyword = if i == 0 { "par" } else { "and" };
+                        graph.push_str(&format!("    {} {}\n", keyword, subscriber));
+                        graph.push_str(&format!(
+                            "        {}->>{}: {}\n",
+                            self.sanitize_id(from),
+                            self.sanitize_id(subscriber),
+                            event
+                        ));
+                    }
+                    graph.push_str("    end\n");
+                }
+                SequenceStep::HookCall { from, hook } => {
+                    let id = self.sanitize_id(from);
+                    graph.push_str(&format!(
+                        "    {}-->>{}: 🪝 {}\n",
+                        id,
+                        self.sanitize_id(hook),
+                        hook
+                    ));
+                }
+                SequenceStep::Cycle { from, event } => {
+                    graph.push_str(&format!(
+                        "    Note over {}: 🔁 cycle: {} (truncated)\n",
+                        self.sanitize_id(from),
+                        event
+                    ));
+                }
+                SequenceStep::AlreadyShown { from, event } => {
+                    graph.push_str(&format!(
+                        "    Note over {}: ↩ {} (shown above)\n",
+                        self.sanitize_id(from),
+                        event
+                    ));
+                }
+                SequenceStep::DepthLimit { from, event } => {
+                    graph.push_str(&format!(
+                        "    Note over {}: ⋯ {} (depth limit)\n",
+                        self.sanitize_id(from),
+                        event
+                    ));
+                }
+                SequenceStep::NoSubscribers { from, event } => {
+                    graph.push_str(&format!(
+                        "    Note over {}: 📭 {} (no subscribers)\n",
+                        self.sanitize_id(from),
+                        event
+                    ));
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// Generate plain-text sequence (fallback when Mermaid is unavailable)
+    pub fn generate_text(&self) -> String {
+        let mut text = format!("Event Sequence: {}\n", self.event);
+
+        for (i, step) in self.steps().iter().enumerate() {
+            let line = match step {
+                SequenceStep::Message { from, to, event } => {
+                    format!("{} -> {} : {}", from, to, event)
+                }
+                SequenceStep::Parallel { from, to, event } => {
+                    format!("{} -> [{}] : {} (parallel)", from, to.join(" | "), event)
+                }
+                SequenceStep::HookCall { from, hook } => format!("{} ~> {} : hook", from, hook),
+                SequenceStep::Cycle { from, event } => {
+                    format!("{} -> {} : [cycle, truncated]", from, event)
+                }
+                SequenceStep::AlreadyShown { from, event } => {
+                    format!("{} -> {} : [shown above]", from, event)
+                }
+                SequenceStep::DepthLimit { from, event } => {
+                    format!("{} -> {} : [depth limit]", from, event)
+                }
+                SequenceStep::NoSubscribers { from, event } => {
+                    format!("{} -> {} : [no subscribers]", from, event)
+                }
+            };
+            text.push_str(&format!("{:>3}. {}\n", i + 1, line));
+        }
+
+        text
+    }
+
+    /// Participants in order of first appearance
+    fn participants(&self, steps: &[SequenceStep]) -> Vec<String> {
+        let mut participants: Vec<String> = Vec::new();
+        let mut add = |name: &str| {
+            if !participants.iter().any(|p| p == name) {
+                participants.push(name.to_string());
+            }
+        };
+
+        for step in steps {
+            match step {
+                SequenceStep::Message { from, to, .. } => {
+                    add(from);
+                    add(to);
+                }
+                SequenceStep::Parallel { from, to, .. } => {
+                    add(from);
+                    for subscriber in to {
+                        add(subscriber);
+                    }
+                }
+                SequenceStep::HookCall { from, hook } => {
+                    add(from);
+                    add(hook);
+                }
+                SequenceStep::Cycle { from, .. }
+                | SequenceStep::AlreadyShown { from, .. }
+                | SequenceStep::DepthLimit { from, .. }
+                | SequenceStep::NoSubscribers { from, .. } => add(from),
+            }
+        }
+
+        participants
+    }
+
+    /// Build subscriber/publisher/hook lookup tables from systems and file analyses
+    fn build_flows(&self) -> SequenceFlows {
+        let mut flows = SequenceFlows::default();
+
+        let systems: Vec<&SystemInfo> = self
+            .result
+            .systems
+            .iter()
+            .chain(self.result.plugins.iter().filter_map(|p| p.system.as_ref()))
+            .collect();
+
+        for system in systems {
+            for event_type in &system.subscribes {
+                flows
+                    .subscribers
+                    .entry(event_type.clone())
+                    .or_default()
+                    .insert(system.name.clone());
+            }
+            for event_type in &system.publishes {
+                flows
+                    .publishes
+                    .entry(system.name.clone())
+                    .or_default()
+                    .insert(event_type.clone());
+            }
+            for hook in &system.hooks {
+                flows
+                    .hooks
+                    .entry(system.name.clone())
+                    .or_default()
+                    .insert(hook.clone());
+            }
+        }
+
+        for subscription in self.result.all_subscriptions() {
+            flows
+                .subscribers
+                .entry(subscription.event_type.clone())
+                .or_default()
+                .insert(subscription.subscriber.clone());
+        }
+
+        for publication in self.result.all_publications() {
+            flows
+                .publishes
+                .entry(publication.publisher.clone())
+                .or_default()
+                .insert(publication.event_type.clone());
+        }
+
+        flows
+    }
+
+    fn sanitize_id(&self, s: &str) -> String {
+        s.replace("::", "_").replace(['<', '>', ' ', '-'], "_")
+    }
+}
+
+/// Lookup tables used by the sequence walk (sorted for deterministic output)
+#[derive(Debug, Default)]
+struct SequenceFlows {
+    subscribers: HashMap<String, BTreeSet<String>>,
+    publishes: HashMap<String, BTreeSet<String>>,
+    hooks: HashMap<String, BTreeSet<String>>,
+}
+
 /// Event flow data structure
 #[derive(Debug, Clone)]
 struct EventFlow {
@@ -383,7 +750,7 @@ impl EventFlow {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{AnalysisResult, EventSubscription, FileAnalysis};
+    use crate::types::{AnalysisResult, EventSubscription, FileAnalysis, SystemInfo};
 
     #[test]
     fn test_event_flow_generator() {
@@ -397,6 +764,7 @@ mod tests {
                 event_type: "TestEvent".to_string(),
                 file_path: "test.rs".to_string(),
                 line: 10,
+                column: 1,
             }],
             publications: vec![],
         };
@@ -411,6 +779,101 @@ mod tests {
         assert!(graph.contains("TestSystem"));
     }
 
+    fn system(name: &str, subscribes: &[&str], publishes: &[&str]) -> SystemInfo {
+        SystemInfo {
+            name: name.to_string(),
+            module_path: String::new(),
+            file_path: "test.rs".to_string(),
+            subscribes: subscribes.iter().map(|s| s.to_string()).collect(),
+            publishes: publishes.iter().map(|s| s.to_string()).collect(),
+            hooks: vec![],
+            states: vec![],
+        }
+    }
+
+    #[test]
+    fn test_sequence_three_hop_chain() {
+        let mut result = AnalysisResult::new();
+        result.add_system(system(
+            "SaveSystem",
+            &["SaveGameRequested"],
+            &["SaveGameSerialized"],
+        ));
+        result.add_system(system(
+            "StorageSystem",
+            &["SaveGameSerialized"],
+            &["SaveGameWritten"],
+        ));
+        result.add_system(system("UiSystem", &["SaveGameWritten"], &[]));
+
+        let generator = SequenceDiagramGenerator::for_event(
+            &result,
+            "SaveGameRequested",
+            SequenceOptions::default(),
+        );
+
+        let expected = "\
+sequenceDiagram
+    %% Event Sequence: SaveGameRequested
+    participant Origin
+    participant SaveSystem
+    participant StorageSystem
+    participant UiSystem
+    Origin->>SaveSystem: SaveGameRequested
+    SaveSystem->>StorageSystem: SaveGameSerialized
+    StorageSystem->>UiSystem: SaveGameWritten
+";
+        assert_eq!(generator.generate(), expected);
+
+        let text = generator.generate_text();
+        assert!(text.contains("  1. Origin -> SaveSystem : SaveGameRequested"));
+        assert!(text.contains("  3. StorageSystem -> UiSystem : SaveGameWritten"));
+    }
+
+    #[test]
+    fn test_sequence_cycle_truncated() {
+        let mut result = AnalysisResult::new();
+        result.add_system(system("PingSystem", &["Ping"], &["Pong"]));
+        result.add_system(system("PongSystem", &["Pong"], &["Ping"]));
+
+        let generator =
+            SequenceDiagramGenerator::for_event(&result, "Ping", SequenceOptions::default());
+        let steps = generator.steps();
+
+        assert_eq!(steps.len(), 3);
+        assert_eq!(
+            steps[2],
+            SequenceStep::Cycle {
+                from: "PongSystem".to_string(),
+                event: "Ping".to_string(),
+            }
+        );
+        assert!(generator
+            .generate()
+            .contains("Note over PongSystem: 🔁 cycle: Ping (truncated)"));
+    }
+
+    #[test]
+    fn test_sequence_fan_out_parallel_and_depth_limit() {
+        let mut result = AnalysisResult::new();
+        result.add_system(system("AudioSystem", &["Hit"], &[]));
+        result.add_system(system("CombatSystem", &["Hit"], &["Damaged"]));
+        result.add_system(system("HealthSystem", &["Damaged"], &[]));
+
+        let options = SequenceOptions {
+            max_depth: 0,
+            ..Default::default()
+        };
+        let graph = SequenceDiagramGenerator::for_event(&result, "Hit", options).generate();
+
+        assert!(graph.contains(
+            "    par AudioSystem\n        Origin->>AudioSystem: Hit\n    and CombatSystem\n"
+        ));
+        assert!(graph.contains("    end\n"));
+        assert!(graph.contains("Note over CombatSystem: ⋯ Damaged (depth limit)"));
+        assert!(!graph.contains("HealthSystem"));
+    }
+
     #[test]
     fn test_sanitize_id() {
         let result = AnalysisResult::new();
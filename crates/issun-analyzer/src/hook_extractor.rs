@@ -161,7 +161,7 @@ impl<'ast> Visit<'ast> for HookCallVisitor {
                         .clone()
                         .unwrap_or_else(|| "unknown".to_string()),
                     file_path: self.file_path.clone(),
-                    line: 0,
+                    line: node.method.span().start().line,
                 });
             }
         }
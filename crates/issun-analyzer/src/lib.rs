@@ -5,13 +5,19 @@
 //! - Event publications (EventBus::publish<E>())
 //! - Hook trait definitions and calls
 //! - System and Plugin structures
+//!
+//! Validation warnings can be exported as SARIF 2.1.0 (see [`sarif`]), and
+//! the public plugin API can be snapshotted and diffed between engine
+//! versions (see [`api_surface`]).
 
 pub mod analyzer;
+pub mod api_surface;
 pub mod error;
 pub mod event_extractor;
 pub mod graph_generator;
 pub mod hook_extractor;
 pub mod plugin_extractor;
+pub mod sarif;
 pub mod system_extractor;
 pub mod types;
 pub mod validator;
@@ -19,19 +25,26 @@ pub mod validator;
 pub use analyzer::Analyzer;
 pub use error::{AnalyzerError, Result};
 pub use types::{
-    AnalysisResult, EventPublication, EventSubscription, FileAnalysis, HookCall, HookCategory,
-    HookInfo, HookMethod, PluginInfo, SystemInfo,
+    AnalysisResult, EventDefinition, EventField, EventPublication, EventSubscription, FileAnalysis,
+    HookCall, HookCategory, HookInfo, HookMethod, PluginInfo, PluginRegistration, SourceLocation,
+    SystemInfo,
 };
 
 /// Re-export commonly used types
 pub mod prelude {
     pub use crate::analyzer::Analyzer;
+    pub use crate::api_surface::{ApiChange, ApiDiff, ApiSnapshot, ChangeKind};
     pub use crate::error::{AnalyzerError, Result};
     pub use crate::graph_generator::{
         CombinedFlowGraphGenerator, EventFlowGraphGenerator, GraphOptions, HookFlowGraphGenerator,
+        SequenceDiagramGenerator, SequenceOptions, SequenceStep,
     };
+    pub use crate::sarif::SarifLog;
     pub use crate::types::{
-        AnalysisResult, EventPublication, EventSubscription, FileAnalysis, PluginInfo, SystemInfo,
+        AnalysisResult, EventPublication, EventSubscription, FileAnalysis, PluginInfo,
+        SourceLocation, SystemInfo,
+    };
+    pub use crate::validator::{
+        ValidationResult, ValidationRule, ValidationWarning, Validator, WarningSeverity,
     };
-    pub use crate::validator::{ValidationResult, ValidationWarning, Validator, WarningSeverity};
 }
@@ -1,7 +1,11 @@
 //! Plugin structure inference from directory layout
 
-use crate::types::PluginInfo;
-use std::path::Path;
+use crate::types::{PluginInfo, PluginRegistration};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use syn::{
+    Attribute, Expr, Fields, File, ImplItem, Item, ItemImpl, ItemStruct, Lit, Pat, Stmt, Type,
+};
 
 /// Infer plugin structure from directory layout
 ///
@@ -71,6 +75,8 @@ fn analyze_plugin_directory(dir: &Path) -> crate::Result<Option<PluginInfo>> {
         hooks: Vec::new(),
         events: Vec::new(),
         hook_details: Vec::new(),
+        event_details: Vec::new(),
+        registrations: Vec::new(),
     };
 
     // Try to find system.rs
@@ -105,9 +111,356 @@ fn analyze_plugin_directory(dir: &Path) -> crate::Result<Option<PluginInfo>> {
         }
     }
 
+    // Event definitions and plugin registrations may live in any file
+    for file in collect_rust_files(dir) {
+        let Ok(content) = std::fs::read_to_string(&file) else {
+            continue;
+        };
+        let Ok(syntax_tree) = syn::parse_file(&content) else {
+            continue;
+        };
+        let file_path = file.display().to_string();
+        plugin
+            .event_details
+            .extend(crate::event_extractor::extract_event_definitions(
+                &file_path,
+                &syntax_tree,
+            ));
+        plugin
+            .registrations
+            .extend(extract_plugin_registrations(&file_path, &syntax_tree));
+    }
+
     Ok(Some(plugin))
 }
 
+/// `.rs` files under `dir`, sorted by path
+fn collect_rust_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&current) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// Extract the types each plugin struct registers
+///
+/// Covers `impl Plugin for X` (the `register_*` calls in `build`) and
+/// `#[derive(Plugin)]` structs (the `#[plugin(..)]` field attributes).
+/// Registered values are resolved to a type name from struct literals,
+/// `Type::new(..)`-style calls, `self` fields and `let` bindings; values
+/// that cannot be resolved are left out.
+pub fn extract_plugin_registrations(
+    file_path: &str,
+    syntax_tree: &File,
+) -> Vec<PluginRegistration> {
+    let structs: HashMap<String, &ItemStruct> = syntax_tree
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(item_struct) => Some((item_struct.ident.to_string(), item_struct)),
+            _ => None,
+        })
+        .collect();
+
+    let mut registrations = Vec::new();
+
+    for item in &syntax_tree.items {
+        match item {
+            Item::Struct(item_struct) if derives_plugin(&item_struct.attrs) => {
+                registrations.push(derived_registration(file_path, item_struct));
+            }
+            Item::Impl(item_impl) => {
+                let is_plugin_impl = item_impl
+                    .trait_
+                    .as_ref()
+                    .and_then(|(_, path, _)| path.segments.last())
+                    .is_some_and(|seg| seg.ident == "Plugin");
+                if !is_plugin_impl {
+                    continue;
+                }
+                let Some(struct_name) = type_name(&item_impl.self_ty) else {
+                    continue;
+                };
+                let fields = structs
+                    .get(&struct_name)
+                    .map(|item_struct| field_types(&item_struct.fields))
+                    .unwrap_or_default();
+                registrations.push(impl_registration(
+                    file_path,
+                    struct_name,
+                    item_impl,
+                    &fields,
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    registrations
+}
+
+/// Whether `#[derive(..)]` lists `Plugin`
+fn derives_plugin(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        let mut found = false;
+        if attr.path().is_ident("derive") {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|seg| seg.ident == "Plugin")
+                {
+                    found = true;
+                }
+                Ok(())
+            });
+        }
+        found
+    })
+}
+
+/// Registration of a `#[derive(Plugin)]` struct
+///
+/// Reads `#[plugin(name = .., service = Type, ..)]` on the struct and
+/// `#[plugin(resource)]`/`#[resource]`-style attributes on its fields.
+fn derived_registration(file_path: &str, item_struct: &ItemStruct) -> PluginRegistration {
+    let mut registration = PluginRegistration {
+        struct_name: item_struct.ident.to_string(),
+        file_path: file_path.to_string(),
+        ..Default::default()
+    };
+
+    for attr in item_struct
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("plugin"))
+    {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                registration.plugin_name = Some(value.value());
+            } else {
+                let ty: Type = meta.value()?.parse()?;
+                if let (Some(list), Some(ty)) = (
+                    registration_list(&mut registration, &meta.path),
+                    type_name(&ty),
+                ) {
+                    list.push(ty);
+                }
+            }
+            Ok(())
+        });
+    }
+
+    for field in &item_struct.fields {
+        let Some(ty) = type_name(&field.ty) else {
+            continue;
+        };
+        for attr in &field.attrs {
+            let mut roles = Vec::new();
+            if attr.path().is_ident("plugin") {
+                let _ = attr.parse_nested_meta(|meta| {
+                    roles.push(meta.path.clone());
+                    Ok(())
+                });
+            } else {
+                roles.push(attr.path().clone());
+            }
+            for role in roles {
+                if let Some(list) = registration_list(&mut registration, &role) {
+                    list.push(ty.clone());
+                }
+            }
+        }
+    }
+
+    registration
+}
+
+/// Registration list for a derive attribute key (`service`, `state`, ..)
+fn registration_list<'a>(
+    registration: &'a mut PluginRegistration,
+    key: &syn::Path,
+) -> Option<&'a mut Vec<String>> {
+    if key.is_ident("service") {
+        Some(&mut registration.services)
+    } else if key.is_ident("system") {
+        Some(&mut registration.systems)
+    } else if key.is_ident("resource") {
+        Some(&mut registration.resources)
+    } else if key.is_ident("runtime_state") || key.is_ident("state") {
+        Some(&mut registration.runtime_states)
+    } else {
+        None
+    }
+}
+
+/// Registration of a hand-written `impl Plugin for ..`
+fn impl_registration(
+    file_path: &str,
+    struct_name: String,
+    item_impl: &ItemImpl,
+    fields: &HashMap<String, String>,
+) -> PluginRegistration {
+    let mut registration = PluginRegistration {
+        struct_name,
+        file_path: file_path.to_string(),
+        ..Default::default()
+    };
+
+    for impl_item in &item_impl.items {
+        let ImplItem::Fn(method) = impl_item else {
+            continue;
+        };
+
+        if method.sig.ident == "name" {
+            registration.plugin_name = method.block.stmts.last().and_then(|stmt| match stmt {
+                Stmt::Expr(Expr::Lit(expr_lit), None) => match &expr_lit.lit {
+                    Lit::Str(lit) => Some(lit.value()),
+                    _ => None,
+                },
+                _ => None,
+            });
+        } else if method.sig.ident == "build" {
+            let mut locals = HashMap::new();
+            for stmt in &method.block.stmts {
+                match stmt {
+                    Stmt::Local(local) => {
+                        let (ident, declared) = match &local.pat {
+                            Pat::Ident(pat) => (&pat.ident, None),
+                            Pat::Type(pat_type) => match &*pat_type.pat {
+                                Pat::Ident(pat) => (&pat.ident, type_name(&pat_type.ty)),
+                                _ => continue,
+                            },
+                            _ => continue,
+                        };
+                        let resolved = declared.or_else(|| {
+                            let init = local.init.as_ref()?;
+                            resolve_type(&init.expr, fields, &locals)
+                        });
+                        if let Some(ty) = resolved {
+                            locals.insert(ident.to_string(), ty);
+                        }
+                    }
+                    Stmt::Expr(Expr::MethodCall(call), _) => {
+                        let list = match call.method.to_string().as_str() {
+                            "register_service" => &mut registration.services,
+                            "register_system" => &mut registration.systems,
+                            "register_resource" => &mut registration.resources,
+                            "register_runtime_state" => &mut registration.runtime_states,
+                            _ => continue,
+                        };
+                        if let Some(ty) = call
+                            .args
+                            .first()
+                            .and_then(|arg| resolve_type(arg, fields, &locals))
+                        {
+                            list.push(ty);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    registration
+}
+
+/// Field name -> type name of a struct
+fn field_types(fields: &Fields) -> HashMap<String, String> {
+    fields
+        .iter()
+        .filter_map(|field| Some((field.ident.as_ref()?.to_string(), type_name(&field.ty)?)))
+        .collect()
+}
+
+/// Last path segment of a type, looking through `Box`/`Arc`/`Rc`
+fn type_name(ty: &Type) -> Option<String> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if matches!(segment.ident.to_string().as_str(), "Box" | "Arc" | "Rc") {
+        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+            if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                return type_name(inner);
+            }
+        }
+        return None;
+    }
+    Some(segment.ident.to_string())
+}
+
+/// Type of a registered value, if it can be told from the expression
+fn resolve_type(
+    expr: &Expr,
+    fields: &HashMap<String, String>,
+    locals: &HashMap<String, String>,
+) -> Option<String> {
+    match expr {
+        Expr::Struct(expr_struct) => {
+            let ident = expr_struct.path.segments.last()?.ident.to_string();
+            (ident != "Self").then_some(ident)
+        }
+        Expr::Call(call) => {
+            let Expr::Path(func) = &*call.func else {
+                return None;
+            };
+            let segments: Vec<String> = func
+                .path
+                .segments
+                .iter()
+                .map(|seg| seg.ident.to_string())
+                .collect();
+            match segments.as_slice() {
+                // Wrappers and `Arc::clone(&x)` take the type of their argument
+                [.., wrapper, _] if matches!(wrapper.as_str(), "Box" | "Arc" | "Rc") => {
+                    resolve_type(call.args.first()?, fields, locals)
+                }
+                [.., ty, _] if ty != "Self" => Some(ty.clone()),
+                // Tuple struct constructor: `Counter(0)`
+                [ty] if ty.starts_with(char::is_uppercase) => Some(ty.clone()),
+                _ => None,
+            }
+        }
+        // `.clone()`, builder chains: the type of the receiver
+        Expr::MethodCall(call) => resolve_type(&call.receiver, fields, locals),
+        Expr::Field(field) => match (&*field.base, &field.member) {
+            (Expr::Path(base), syn::Member::Named(member)) if base.path.is_ident("self") => {
+                fields.get(&member.to_string()).cloned()
+            }
+            _ => None,
+        },
+        Expr::Path(path) => {
+            let ident = path.path.get_ident()?.to_string();
+            locals.get(&ident).cloned().or_else(|| {
+                // Unit struct
+                ident.starts_with(char::is_uppercase).then_some(ident)
+            })
+        }
+        Expr::Reference(reference) => resolve_type(&reference.expr, fields, locals),
+        Expr::Paren(paren) => resolve_type(&paren.expr, fields, locals),
+        _ => None,
+    }
+}
+
 /// Extract hook trait names from hook.rs
 fn extract_hook_traits(hook_file: &Path) -> crate::Result<Vec<String>> {
     use syn::{File, Item};
@@ -269,4 +622,88 @@ mod tests {
         assert!(events.contains(&"CombatStartedEvent".to_string()));
         assert!(events.contains(&"CombatCommand".to_string()));
     }
+
+    #[test]
+    fn test_extract_registrations_from_build() {
+        let code = r#"
+            pub struct ActionPlugin {
+                config: ActionConfig,
+                hook: Arc<dyn ActionHook>,
+            }
+
+            impl Plugin for ActionPlugin {
+                fn name(&self) -> &'static str {
+                    "issun:action"
+                }
+
+                fn build(&self, builder: &mut dyn PluginBuilder) {
+                    let points = ActionPoints::new(self.config.max_per_period);
+                    builder.register_runtime_state(points);
+                    builder.register_system(Box::new(ActionSystem::new(Arc::clone(&self.hook))));
+                    builder.register_service(Box::new(ActionService));
+                    builder.register_resource(self.config.clone());
+                    builder.register_resource(make_config());
+                }
+            }
+        "#;
+
+        let syntax_tree = syn::parse_file(code).unwrap();
+        let registrations = extract_plugin_registrations("plugin.rs", &syntax_tree);
+
+        assert_eq!(registrations.len(), 1);
+        let registration = &registrations[0];
+        assert_eq!(registration.struct_name, "ActionPlugin");
+        assert_eq!(registration.plugin_name.as_deref(), Some("issun:action"));
+        assert_eq!(registration.runtime_states, ["ActionPoints"]);
+        assert_eq!(registration.systems, ["ActionSystem"]);
+        assert_eq!(registration.services, ["ActionService"]);
+        assert_eq!(registration.resources, ["ActionConfig"]);
+    }
+
+    #[test]
+    fn test_extract_registrations_from_derive() {
+        let code = r#"
+            #[derive(Plugin)]
+            #[plugin(name = "issun:loot")]
+            pub struct LootPlugin {
+                #[plugin(skip)]
+                hook: Arc<dyn LootHook>,
+                #[plugin(resource)]
+                config: LootConfig,
+                #[plugin(runtime_state)]
+                state: LootState,
+                #[plugin(service)]
+                service: LootService,
+                #[plugin(system)]
+                system: LootSystem,
+            }
+
+            #[derive(Plugin)]
+            #[plugin(name = "issun:combat", service = CombatLog)]
+            pub struct CombatPlugin {
+                #[resource]
+                config: CombatConfig,
+                #[state]
+                #[plugin(reset)]
+                state: CombatState,
+            }
+        "#;
+
+        let syntax_tree = syn::parse_file(code).unwrap();
+        let registrations = extract_plugin_registrations("plugin.rs", &syntax_tree);
+
+        assert_eq!(registrations.len(), 2);
+        let registration = &registrations[0];
+        assert_eq!(registration.plugin_name.as_deref(), Some("issun:loot"));
+        assert_eq!(registration.resources, ["LootConfig"]);
+        assert_eq!(registration.runtime_states, ["LootState"]);
+        assert_eq!(registration.services, ["LootService"]);
+        assert_eq!(registration.systems, ["LootSystem"]);
+
+        let registration = &registrations[1];
+        assert_eq!(registration.plugin_name.as_deref(), Some("issun:combat"));
+        assert_eq!(registration.services, ["CombatLog"]);
+        assert_eq!(registration.resources, ["CombatConfig"]);
+        assert_eq!(registration.runtime_states, ["CombatState"]);
+    }
 }
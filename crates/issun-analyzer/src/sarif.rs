@@ -0,0 +1,237 @@
+//! SARIF 2.1.0 export of validation results
+//!
+//! SARIF is the static-analysis exchange format understood by editors and
+//! code-scanning tools, so validation warnings can be shown inline at the
+//! publish/subscribe sites that caused them.
+
+use crate::types::SourceLocation;
+use crate::validator::{ValidationResult, WarningSeverity, VALIDATION_RULES};
+use serde::{Deserialize, Serialize};
+
+/// SARIF specification version written by this module
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// Schema URI of SARIF 2.1.0
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Top-level SARIF document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub version: String,
+    pub runs: Vec<SarifRun>,
+}
+
+/// One analysis run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifRun {
+    pub tool: SarifTool,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifTool {
+    pub driver: SarifDriver,
+}
+
+/// Analysis tool description, including the rule metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    pub name: String,
+    pub version: String,
+    pub information_uri: String,
+    pub rules: Vec<SarifRule>,
+}
+
+/// Metadata describing one warning code
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    pub id: String,
+    pub name: String,
+    pub short_description: SarifMessage,
+    pub default_configuration: SarifRuleConfiguration,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifRuleConfiguration {
+    pub level: String,
+}
+
+/// One finding
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    pub rule_index: usize,
+    pub level: String,
+    pub message: SarifMessage,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<SarifLocation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifMessage {
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    pub physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    pub artifact_location: SarifArtifactLocation,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifArtifactLocation {
+    pub uri: String,
+}
+
+/// Region inside a file (1-based line and column)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    pub start_line: usize,
+    pub start_column: usize,
+}
+
+impl SarifLog {
+    /// Convert validation warnings into a single-run SARIF document
+    pub fn from_validation(validation: &ValidationResult) -> Self {
+        let rules = VALIDATION_RULES
+            .iter()
+            .map(|rule| SarifRule {
+                id: rule.code.to_string(),
+                name: rule.name.to_string(),
+                short_description: SarifMessage {
+                    text: rule.description.to_string(),
+                },
+                default_configuration: SarifRuleConfiguration {
+                    level: level(rule.severity).to_string(),
+                },
+            })
+            .collect();
+
+        let results = validation
+            .warnings
+            .iter()
+            .map(|warning| SarifResult {
+                rule_id: warning.code().to_string(),
+                rule_index: VALIDATION_RULES
+                    .iter()
+                    .position(|rule| rule.code == warning.code())
+                    .unwrap_or_default(),
+                level: level(warning.severity()).to_string(),
+                message: SarifMessage {
+                    text: warning.message(),
+                },
+                locations: warning.locations().iter().map(location).collect(),
+            })
+            .collect();
+
+        Self {
+            schema: SARIF_SCHEMA.to_string(),
+            version: SARIF_VERSION.to_string(),
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "issun-analyzer".to_string(),
+                        version: env!("CARGO_PKG_VERSION").to_string(),
+                        information_uri: "https://github.com/ynishi/issun".to_string(),
+                        rules,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+
+    /// Serialize as pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("SARIF log is always serializable")
+    }
+}
+
+/// SARIF `level` for a warning severity
+pub fn level(severity: WarningSeverity) -> &'static str {
+    match severity {
+        WarningSeverity::High => "error",
+        WarningSeverity::Medium => "warning",
+        WarningSeverity::Low => "note",
+    }
+}
+
+fn location(source: &SourceLocation) -> SarifLocation {
+    // Extractors that could not resolve a position leave line 0
+    let region = (source.line > 0).then(|| SarifRegion {
+        start_line: source.line,
+        start_column: source.column.max(1),
+    });
+    SarifLocation {
+        physical_location: SarifPhysicalLocation {
+            artifact_location: SarifArtifactLocation {
+                uri: path_to_uri(&source.file_path),
+            },
+            region,
+        },
+    }
+}
+
+/// Relative paths stay relative (resolved against the project root by
+/// SARIF viewers), absolute paths become `file://` URIs.
+fn path_to_uri(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let path = path.trim_start_matches("./");
+    if path.starts_with('/') {
+        format!("file://{}", path)
+    } else if path.chars().nth(1) == Some(':') {
+        format!("file:///{}", path)
+    } else {
+        path.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::ValidationWarning;
+
+    #[test]
+    fn test_levels_follow_severity() {
+        assert_eq!(level(WarningSeverity::High), "error");
+        assert_eq!(level(WarningSeverity::Medium), "warning");
+        assert_eq!(level(WarningSeverity::Low), "note");
+    }
+
+    #[test]
+    fn test_path_to_uri() {
+        assert_eq!(path_to_uri("./src/plugin/a.rs"), "src/plugin/a.rs");
+        assert_eq!(path_to_uri("/repo/src/a.rs"), "file:///repo/src/a.rs");
+        assert_eq!(path_to_uri("C:\\repo\\a.rs"), "file:///C:/repo/a.rs");
+    }
+
+    #[test]
+    fn test_warning_without_position_has_no_region() {
+        let mut validation = ValidationResult::new();
+        validation.add_warning(ValidationWarning::PotentialEventLoop {
+            cycle: vec!["a".to_string(), "b".to_string(), "a".to_string()],
+            locations: vec![SourceLocation::new("a.rs", 0, 0)],
+        });
+
+        let log = SarifLog::from_validation(&validation);
+        let result = &log.runs[0].results[0];
+        assert_eq!(result.rule_id, "ISSUN003");
+        assert_eq!(result.rule_index, 2);
+        assert_eq!(result.level, "error");
+        assert!(result.locations[0].physical_location.region.is_none());
+    }
+}
@@ -11,8 +11,18 @@ pub struct EventSubscription {
     pub event_type: String,
     /// Source file path
     pub file_path: String,
-    /// Line number where the field is defined
+    /// Line number where the field or reader call is (1-based)
     pub line: usize,
+    /// Column where the field or reader call starts (1-based)
+    #[serde(default)]
+    pub column: usize,
+}
+
+impl EventSubscription {
+    /// Source location of this subscription
+    pub fn location(&self) -> SourceLocation {
+        SourceLocation::new(&self.file_path, self.line, self.column)
+    }
 }
 
 /// Event publication information (EventBus::publish<E>() calls)
@@ -24,8 +34,39 @@ pub struct EventPublication {
     pub event_type: String,
     /// Source file path
     pub file_path: String,
-    /// Line number where publish is called
+    /// Line number where publish is called (1-based)
+    pub line: usize,
+    /// Column where the publish call starts (1-based)
+    #[serde(default)]
+    pub column: usize,
+}
+
+impl EventPublication {
+    /// Source location of this publication
+    pub fn location(&self) -> SourceLocation {
+        SourceLocation::new(&self.file_path, self.line, self.column)
+    }
+}
+
+/// Position in a source file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SourceLocation {
+    /// Source file path
+    pub file_path: String,
+    /// Line number (1-based)
     pub line: usize,
+    /// Column number (1-based)
+    pub column: usize,
+}
+
+impl SourceLocation {
+    pub fn new(file_path: &str, line: usize, column: usize) -> Self {
+        Self {
+            file_path: file_path.to_string(),
+            line,
+            column,
+        }
+    }
 }
 
 /// Complete analysis result for a single file
@@ -114,10 +155,52 @@ pub struct HookCall {
     pub caller: String,
     /// Source file path
     pub file_path: String,
-    /// Line number (placeholder: 0)
+    /// Line number of the call (1-based)
     pub line: usize,
 }
 
+/// Field of an event type
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EventField {
+    /// Field name; tuple fields are numbered ("0", "1") and enum variant
+    /// fields are prefixed with the variant ("Started.id")
+    pub name: String,
+    /// Field type (simplified representation), empty for unit variants
+    pub ty: String,
+}
+
+/// Event type definition (a type with `impl Event for ..`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EventDefinition {
+    /// Event type name
+    pub name: String,
+    /// Source file path
+    pub file_path: String,
+    /// Line number of the type definition (1-based)
+    pub line: usize,
+    /// Fields in declaration order
+    pub fields: Vec<EventField>,
+}
+
+/// Types a plugin registers in `Plugin::build`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct PluginRegistration {
+    /// Plugin struct name
+    pub struct_name: String,
+    /// Name returned by `Plugin::name` or given in `#[plugin(name = ..)]`
+    pub plugin_name: Option<String>,
+    /// Source file path
+    pub file_path: String,
+    /// Service types
+    pub services: Vec<String>,
+    /// System types
+    pub systems: Vec<String>,
+    /// Read-only resource types
+    pub resources: Vec<String>,
+    /// Runtime state types
+    pub runtime_states: Vec<String>,
+}
+
 /// Plugin information inferred from directory structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginInfo {
@@ -133,6 +216,12 @@ pub struct PluginInfo {
     pub events: Vec<String>,
     /// Detailed hook information (if analyzed)
     pub hook_details: Vec<HookInfo>,
+    /// Event types implemented anywhere in the plugin, with their fields
+    #[serde(default)]
+    pub event_details: Vec<EventDefinition>,
+    /// Plugin structs and the types they register
+    #[serde(default)]
+    pub registrations: Vec<PluginRegistration>,
 }
 
 /// Complete analysis result for a project
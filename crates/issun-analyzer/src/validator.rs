@@ -1,38 +1,145 @@
 //! Validation of event flows and system dependencies
 
-use crate::types::AnalysisResult;
+use crate::types::{AnalysisResult, SourceLocation};
 use std::collections::{HashMap, HashSet};
 
 /// Validation warning categories
+///
+/// `locations` point at the code that caused the warning (publish calls,
+/// readers); they are empty when the extractors could not attribute it.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ValidationWarning {
     /// Event is published but never subscribed
     UnusedEvent {
         event_type: String,
         publishers: Vec<String>,
+        locations: Vec<SourceLocation>,
     },
 
     /// Event is subscribed but never published
     MissingPublisher {
         event_type: String,
         subscribers: Vec<String>,
+        locations: Vec<SourceLocation>,
     },
 
     /// Potential circular dependency in event flow
-    PotentialEventLoop { cycle: Vec<String> },
+    PotentialEventLoop {
+        cycle: Vec<String>,
+        locations: Vec<SourceLocation>,
+    },
 
     /// System subscribes to the same event multiple times
-    DuplicateSubscription { system: String, event_type: String },
+    DuplicateSubscription {
+        system: String,
+        event_type: String,
+        locations: Vec<SourceLocation>,
+    },
 }
 
+/// Static description of one kind of validation warning
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationRule {
+    /// Stable code (used as the SARIF rule id)
+    pub code: &'static str,
+    /// Short kebab-case name
+    pub name: &'static str,
+    /// One-line description
+    pub description: &'static str,
+    /// Default severity
+    pub severity: WarningSeverity,
+}
+
+/// All validation rules, in code order
+pub const VALIDATION_RULES: [ValidationRule; 4] = [
+    ValidationRule {
+        code: "ISSUN001",
+        name: "unused-event",
+        description: "Event is published but never subscribed",
+        severity: WarningSeverity::Low,
+    },
+    ValidationRule {
+        code: "ISSUN002",
+        name: "missing-publisher",
+        description: "Event is subscribed but never published",
+        severity: WarningSeverity::Medium,
+    },
+    ValidationRule {
+        code: "ISSUN003",
+        name: "potential-event-loop",
+        description: "Systems trigger each other in a cycle through events",
+        severity: WarningSeverity::High,
+    },
+    ValidationRule {
+        code: "ISSUN004",
+        name: "duplicate-subscription",
+        description: "System subscribes to the same event multiple times",
+        severity: WarningSeverity::Low,
+    },
+];
+
 impl ValidationWarning {
+    /// Rule this warning belongs to
+    pub fn rule(&self) -> &'static ValidationRule {
+        let index = match self {
+            ValidationWarning::UnusedEvent { .. } => 0,
+            ValidationWarning::MissingPublisher { .. } => 1,
+            ValidationWarning::PotentialEventLoop { .. } => 2,
+            ValidationWarning::DuplicateSubscription { .. } => 3,
+        };
+        &VALIDATION_RULES[index]
+    }
+
+    /// Stable code of this warning (e.g. `ISSUN001`)
+    pub fn code(&self) -> &'static str {
+        self.rule().code
+    }
+
     /// Get the severity level of this warning
     pub fn severity(&self) -> WarningSeverity {
+        self.rule().severity
+    }
+
+    /// Source locations this warning refers to
+    pub fn locations(&self) -> &[SourceLocation] {
         match self {
-            ValidationWarning::UnusedEvent { .. } => WarningSeverity::Low,
-            ValidationWarning::MissingPublisher { .. } => WarningSeverity::Medium,
-            ValidationWarning::PotentialEventLoop { .. } => WarningSeverity::High,
-            ValidationWarning::DuplicateSubscription { .. } => WarningSeverity::Low,
+            ValidationWarning::UnusedEvent { locations, .. }
+            | ValidationWarning::MissingPublisher { locations, .. }
+            | ValidationWarning::PotentialEventLoop { locations, .. }
+            | ValidationWarning::DuplicateSubscription { locations, .. } => locations,
+        }
+    }
+
+    /// Single-line message without decoration (for machine-readable output)
+    pub fn message(&self) -> String {
+        match self {
+            ValidationWarning::UnusedEvent {
+                event_type,
+                publishers,
+                ..
+            } => format!(
+                "Event '{}' is published but never subscribed (publishers: {})",
+                event_type,
+                publishers.join(", ")
+            ),
+            ValidationWarning::MissingPublisher {
+                event_type,
+                subscribers,
+                ..
+            } => format!(
+                "Event '{}' is subscribed but never published (subscribers: {})",
+                event_type,
+                subscribers.join(", ")
+            ),
+            ValidationWarning::PotentialEventLoop { cycle, .. } => {
+                format!("Potential event loop: {}", cycle.join(" -> "))
+            }
+            ValidationWarning::DuplicateSubscription {
+                system, event_type, ..
+            } => format!(
+                "System '{}' subscribes to event '{}' multiple times",
+                system, event_type
+            ),
         }
     }
 
@@ -42,6 +149,7 @@ impl ValidationWarning {
             ValidationWarning::UnusedEvent {
                 event_type,
                 publishers,
+                ..
             } => {
                 format!(
                     "⚠️  Event '{}' is published but never subscribed\n   Publishers: {}",
@@ -52,6 +160,7 @@ impl ValidationWarning {
             ValidationWarning::MissingPublisher {
                 event_type,
                 subscribers,
+                ..
             } => {
                 format!(
                     "⚠️  Event '{}' is subscribed but never published\n   Subscribers: {}",
@@ -59,13 +168,15 @@ impl ValidationWarning {
                     subscribers.join(", ")
                 )
             }
-            ValidationWarning::PotentialEventLoop { cycle } => {
+            ValidationWarning::PotentialEventLoop { cycle, .. } => {
                 format!(
                     "⚠️  Potential event loop detected:\n   {}",
                     cycle.join(" → ")
                 )
             }
-            ValidationWarning::DuplicateSubscription { system, event_type } => {
+            ValidationWarning::DuplicateSubscription {
+                system, event_type, ..
+            } => {
                 format!(
                     "⚠️  System '{}' subscribes to event '{}' multiple times",
                     system, event_type
@@ -190,6 +301,7 @@ impl<'a> Validator<'a> {
     /// Check for events that are published but never subscribed
     fn check_unused_events(&self, validation: &mut ValidationResult) {
         let mut published_events: HashMap<String, Vec<String>> = HashMap::new();
+        let mut publish_sites: HashMap<String, Vec<SourceLocation>> = HashMap::new();
         let mut subscribed_events: HashSet<String> = HashSet::new();
 
         // Collect published events
@@ -198,6 +310,10 @@ impl<'a> Validator<'a> {
                 .entry(publication.event_type.clone())
                 .or_default()
                 .push(publication.publisher.clone());
+            publish_sites
+                .entry(publication.event_type.clone())
+                .or_default()
+                .push(publication.location());
         }
 
         // Collect subscribed events
@@ -208,9 +324,11 @@ impl<'a> Validator<'a> {
         // Find unused events
         for (event_type, publishers) in published_events {
             if !subscribed_events.contains(&event_type) {
+                let locations = publish_sites.remove(&event_type).unwrap_or_default();
                 validation.add_warning(ValidationWarning::UnusedEvent {
                     event_type,
                     publishers,
+                    locations,
                 });
             }
         }
@@ -219,6 +337,7 @@ impl<'a> Validator<'a> {
     /// Check for events that are subscribed but never published
     fn check_missing_publishers(&self, validation: &mut ValidationResult) {
         let mut subscribed_events: HashMap<String, Vec<String>> = HashMap::new();
+        let mut subscribe_sites: HashMap<String, Vec<SourceLocation>> = HashMap::new();
         let mut published_events: HashSet<String> = HashSet::new();
 
         // Collect subscribed events
@@ -227,6 +346,10 @@ impl<'a> Validator<'a> {
                 .entry(subscription.event_type.clone())
                 .or_default()
                 .push(subscription.subscriber.clone());
+            subscribe_sites
+                .entry(subscription.event_type.clone())
+                .or_default()
+                .push(subscription.location());
         }
 
         // Collect published events
@@ -237,9 +360,11 @@ impl<'a> Validator<'a> {
         // Find missing publishers
         for (event_type, subscribers) in subscribed_events {
             if !published_events.contains(&event_type) {
+                let locations = subscribe_sites.remove(&event_type).unwrap_or_default();
                 validation.add_warning(ValidationWarning::MissingPublisher {
                     event_type,
                     subscribers,
+                    locations,
                 });
             }
         }
@@ -259,6 +384,7 @@ impl<'a> Validator<'a> {
                 validation.add_warning(ValidationWarning::DuplicateSubscription {
                     system: subscriber.clone(),
                     event_type: event_type.clone(),
+                    locations: vec![subscription.location()],
                 });
             } else {
                 events.insert(event_type.clone());
@@ -312,6 +438,8 @@ impl<'a> Validator<'a> {
 
         for system in system_dependencies.keys() {
             if !visited.contains(system) {
+                // A found cycle returns early and leaves its path on the stack
+                rec_stack.clear();
                 if let Some(cycle) = Self::detect_cycle_dfs(
                     system,
                     &system_dependencies,
@@ -319,12 +447,41 @@ impl<'a> Validator<'a> {
                     &mut rec_stack,
                     &mut vec![system.clone()],
                 ) {
-                    validation.add_warning(ValidationWarning::PotentialEventLoop { cycle });
+                    let locations = self.cycle_publish_sites(&cycle, &event_to_subscribers);
+                    validation
+                        .add_warning(ValidationWarning::PotentialEventLoop { cycle, locations });
                 }
             }
         }
     }
 
+    /// Publish calls that link each system of a cycle to the next one
+    fn cycle_publish_sites(
+        &self,
+        cycle: &[String],
+        event_to_subscribers: &HashMap<String, Vec<String>>,
+    ) -> Vec<SourceLocation> {
+        let mut locations = Vec::new();
+        for edge in cycle.windows(2) {
+            let (publisher, subscriber) = (&edge[0], &edge[1]);
+            locations.extend(
+                self.result
+                    .all_publications()
+                    .into_iter()
+                    .filter(|publication| {
+                        &publication.publisher == publisher
+                            && event_to_subscribers
+                                .get(&publication.event_type)
+                                .is_some_and(|subscribers| subscribers.contains(subscriber))
+                    })
+                    .map(|publication| publication.location()),
+            );
+        }
+        locations.sort();
+        locations.dedup();
+        locations
+    }
+
     /// DFS-based cycle detection
     fn detect_cycle_dfs(
         node: &str,
@@ -364,7 +521,9 @@ impl<'a> Validator<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{AnalysisResult, EventPublication, EventSubscription, FileAnalysis};
+    use crate::types::{
+        AnalysisResult, EventPublication, EventSubscription, FileAnalysis, SourceLocation,
+    };
 
     #[test]
     fn test_unused_event_detection() {
@@ -379,6 +538,7 @@ mod tests {
                 event_type: "UnusedEvent".to_string(),
                 file_path: "test.rs".to_string(),
                 line: 10,
+                column: 5,
             }],
         };
         result.add_file(file);
@@ -391,6 +551,11 @@ mod tests {
             validation.warnings[0],
             ValidationWarning::UnusedEvent { .. }
         ));
+        assert_eq!(validation.warnings[0].code(), "ISSUN001");
+        assert_eq!(
+            validation.warnings[0].locations(),
+            &[SourceLocation::new("test.rs", 10, 5)]
+        );
     }
 
     #[test]
@@ -405,6 +570,7 @@ mod tests {
                 event_type: "MissingEvent".to_string(),
                 file_path: "test.rs".to_string(),
                 line: 10,
+                column: 5,
             }],
             publications: vec![],
         };
@@ -432,12 +598,14 @@ mod tests {
                 event_type: "TestEvent".to_string(),
                 file_path: "test.rs".to_string(),
                 line: 10,
+                column: 5,
             }],
             publications: vec![EventPublication {
                 publisher: "PublisherSystem".to_string(),
                 event_type: "TestEvent".to_string(),
                 file_path: "test.rs".to_string(),
                 line: 20,
+                column: 5,
             }],
         };
         result.add_file(file);
@@ -447,4 +615,57 @@ mod tests {
 
         assert_eq!(validation.warnings.len(), 0);
     }
+
+    fn publication(publisher: &str, event_type: &str, line: usize) -> EventPublication {
+        EventPublication {
+            publisher: publisher.to_string(),
+            event_type: event_type.to_string(),
+            file_path: "loop.rs".to_string(),
+            line,
+            column: 9,
+        }
+    }
+
+    fn subscription(subscriber: &str, event_type: &str) -> EventSubscription {
+        EventSubscription {
+            subscriber: subscriber.to_string(),
+            event_type: event_type.to_string(),
+            file_path: "loop.rs".to_string(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    #[test]
+    fn test_event_loop_points_at_publish_sites() {
+        let mut result = AnalysisResult::new();
+        result.add_file(FileAnalysis {
+            path: "loop.rs".to_string(),
+            subscriptions: vec![
+                subscription("b", "Ping"),
+                subscription("a", "Pong"),
+                subscription("d", "Ping"),
+                subscription("c", "Other"),
+            ],
+            publications: vec![
+                publication("a", "Ping", 10),
+                publication("b", "Pong", 20),
+                publication("c", "Ping", 30),
+                publication("d", "Other", 40),
+            ],
+        });
+
+        let validation = Validator::new(&result).validate();
+        let loops: Vec<_> = validation
+            .warnings
+            .iter()
+            .filter(|w| matches!(w, ValidationWarning::PotentialEventLoop { .. }))
+            .collect();
+
+        assert!(!loops.is_empty());
+        for warning in loops {
+            assert_eq!(warning.code(), "ISSUN003");
+            assert!(!warning.locations().is_empty());
+        }
+    }
 }
@@ -0,0 +1,91 @@
+//! API diff: snapshots of fixture plugin directories before and after a change
+
+use issun_analyzer::prelude::*;
+use std::path::PathBuf;
+
+fn snapshot(name: &str) -> ApiSnapshot {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/api_surface")
+        .join(name);
+    let snapshot = ApiSnapshot::from_plugin_dir(dir).unwrap();
+
+    // Compare through JSON, as the CLI does with a stored snapshot
+    ApiSnapshot::from_json(&snapshot.to_json()).unwrap()
+}
+
+#[test]
+fn test_snapshot_records_public_surface() {
+    let snapshot = snapshot("before");
+
+    let event = &snapshot.events["SaveRequested"];
+    assert_eq!(event.module, "save");
+    assert_eq!(event.fields["slot"], "String");
+    assert_eq!(event.fields["label"], "Option < String >");
+
+    let plugin = &snapshot.plugins["SavePlugin"];
+    assert_eq!(plugin.name.as_deref(), Some("issun:save"));
+    assert!(plugin.systems.contains("SaveSystem"));
+    assert!(plugin.resources.contains("SaveConfig"));
+
+    let method = &snapshot.hooks["SaveHook"].methods["before_save"];
+    assert_eq!(method.params, ["& str", "& mut SaveData"]);
+    assert!(method.has_default_impl);
+}
+
+#[test]
+fn test_renamed_event_field_is_breaking() {
+    let diff = ApiDiff::between(&snapshot("before"), &snapshot("renamed_field"));
+
+    assert!(diff.has_breaking());
+    let breaking: Vec<_> = diff.of_kind(ChangeKind::Breaking).collect();
+    assert_eq!(breaking.len(), 2, "{:?}", diff.changes);
+    assert_eq!(breaking[0].item, "SaveRequested.slot");
+    assert!(breaking[0].description.contains("removed"));
+    assert_eq!(breaking[1].item, "SaveRequested.slot_id");
+    assert!(breaking[0]
+        .to_string()
+        .starts_with("[breaking] SaveRequested.slot:"));
+}
+
+#[test]
+fn test_additive_change_is_not_breaking() {
+    let diff = ApiDiff::between(&snapshot("before"), &snapshot("additive"));
+
+    assert!(!diff.has_breaking(), "{:?}", diff.changes);
+    let additive: Vec<_> = diff
+        .of_kind(ChangeKind::Additive)
+        .map(|change| change.item.as_str())
+        .collect();
+    assert_eq!(
+        additive,
+        ["SaveDeleted", "SaveHook::on_deleted", "SavePlugin"]
+    );
+}
+
+#[test]
+fn test_snapshot_json_is_independent_of_declaration_order() {
+    // `additive` declares the shared items in a different order
+    let mut before = snapshot("before");
+    let mut additive = snapshot("additive");
+    additive.events.remove("SaveDeleted");
+    additive
+        .hooks
+        .get_mut("SaveHook")
+        .unwrap()
+        .methods
+        .remove("on_deleted");
+    additive
+        .plugins
+        .get_mut("SavePlugin")
+        .unwrap()
+        .services
+        .clear();
+    before
+        .plugins
+        .get_mut("SavePlugin")
+        .unwrap()
+        .services
+        .clear();
+
+    assert_eq!(before.to_json(), additive.to_json());
+}
@@ -0,0 +1,7 @@
+pub trait SaveHook: Send + Sync {
+    fn on_saved(&self, slot: &str);
+
+    fn before_save(&self, slot: &str, data: &mut SaveData) {}
+
+    fn on_deleted(&self, slot: &str) {}
+}
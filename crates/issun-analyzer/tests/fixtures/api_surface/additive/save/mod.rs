@@ -0,0 +1,5 @@
+// Fixture for the API diff test: not compiled, only parsed.
+
+pub mod events;
+pub mod hook;
+pub mod plugin;
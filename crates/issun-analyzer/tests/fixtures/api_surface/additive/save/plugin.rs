@@ -0,0 +1,16 @@
+pub struct SavePlugin {
+    hook: Arc<dyn SaveHook>,
+    config: SaveConfig,
+}
+
+impl Plugin for SavePlugin {
+    fn name(&self) -> &'static str {
+        "issun:save"
+    }
+
+    fn build(&self, builder: &mut dyn PluginBuilder) {
+        builder.register_system(Box::new(SaveSystem::new(self.hook.clone())));
+        builder.register_resource(self.config.clone());
+        builder.register_service(Box::new(SaveSlotService::default()));
+    }
+}
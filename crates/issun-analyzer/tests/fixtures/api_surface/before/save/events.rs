@@ -0,0 +1,14 @@
+use issun::event::Event;
+
+pub struct SaveRequested {
+    pub slot: String,
+    pub label: Option<String>,
+}
+
+impl Event for SaveRequested {}
+
+pub struct GameSaved {
+    pub slot: String,
+}
+
+impl Event for GameSaved {}
@@ -0,0 +1,5 @@
+pub trait SaveHook: Send + Sync {
+    fn before_save(&self, slot: &str, data: &mut SaveData) {}
+
+    fn on_saved(&self, slot: &str);
+}
@@ -0,0 +1,15 @@
+pub struct SavePlugin {
+    config: SaveConfig,
+    hook: Arc<dyn SaveHook>,
+}
+
+impl Plugin for SavePlugin {
+    fn name(&self) -> &'static str {
+        "issun:save"
+    }
+
+    fn build(&self, builder: &mut dyn PluginBuilder) {
+        builder.register_resource(self.config.clone());
+        builder.register_system(Box::new(SaveSystem::new(self.hook.clone())));
+    }
+}
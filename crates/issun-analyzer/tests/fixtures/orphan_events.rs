@@ -0,0 +1,11 @@
+// Fixture for the SARIF export test: not compiled, only parsed.
+
+struct ScoreboardSystem {
+    ghosts: EventReader<GhostEvent>,
+}
+
+impl ScoreboardSystem {
+    fn on_round_end(&mut self, bus: &mut EventBus) {
+        bus.publish::<RoundEndedEvent>(RoundEndedEvent::default());
+    }
+}
@@ -0,0 +1,173 @@
+//! SARIF export: a fixture with an unused event and a missing publisher
+
+use issun_analyzer::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+fn analyze_fixture() -> (String, ValidationResult) {
+    let path = fixture("orphan_events.rs");
+    let analyzer = Analyzer::new(env!("CARGO_MANIFEST_DIR"));
+    let file = analyzer.analyze_file(&path).unwrap();
+
+    let mut result = AnalysisResult::new();
+    result.add_file(file);
+    let validation = Validator::new(&result).validate();
+    (path.to_string_lossy().to_string(), validation)
+}
+
+/// Check `value` against the vendored SARIF schema subset
+///
+/// Supports the keywords that schema uses: `$ref`, `type`, `required`,
+/// `properties`, `items`, `enum`, `minimum`, `anyOf` and `uniqueItems`.
+fn check_schema(value: &Value, schema: &Value, root: &Value, path: &str) -> Vec<String> {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let name = reference.trim_start_matches("#/definitions/");
+        return check_schema(value, &root["definitions"][name], root, path);
+    }
+
+    let mut errors = Vec::new();
+
+    if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+        let ok = match expected {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "integer" => value.is_i64() || value.is_u64(),
+            other => panic!("unsupported type {}", other),
+        };
+        if !ok {
+            errors.push(format!("{}: expected {}", path, expected));
+            return errors;
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            errors.push(format!("{}: {} not in {:?}", path, value, allowed));
+        }
+    }
+
+    if let Some(minimum) = schema.get("minimum").and_then(Value::as_i64) {
+        if value.as_i64().is_some_and(|v| v < minimum) {
+            errors.push(format!("{}: {} < {}", path, value, minimum));
+        }
+    }
+
+    for required in schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let key = required.as_str().unwrap();
+        if value.get(key).is_none() {
+            errors.push(format!("{}: missing '{}'", path, key));
+        }
+    }
+
+    if let Some(options) = schema.get("anyOf").and_then(Value::as_array) {
+        if options
+            .iter()
+            .all(|option| !check_schema(value, option, root, path).is_empty())
+        {
+            errors.push(format!("{}: matches none of anyOf", path));
+        }
+    }
+
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        for (key, property_schema) in properties {
+            if let Some(property) = value.get(key) {
+                let child = format!("{}.{}", path, key);
+                errors.extend(check_schema(property, property_schema, root, &child));
+            }
+        }
+    }
+
+    if let Some(items) = value.as_array() {
+        if let Some(item_schema) = schema.get("items") {
+            for (i, item) in items.iter().enumerate() {
+                let child = format!("{}[{}]", path, i);
+                errors.extend(check_schema(item, item_schema, root, &child));
+            }
+        }
+        if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+            for (i, item) in items.iter().enumerate() {
+                if items[..i].contains(item) {
+                    errors.push(format!("{}[{}]: duplicate item", path, i));
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+#[test]
+fn test_fixture_produces_located_sarif_results() {
+    let (path, validation) = analyze_fixture();
+    assert_eq!(validation.warnings.len(), 2);
+
+    let sarif: Value = serde_json::from_str(&SarifLog::from_validation(&validation).to_json())
+        .expect("SARIF output is valid JSON");
+    let run = &sarif["runs"][0];
+    assert_eq!(sarif["version"], "2.1.0");
+    assert_eq!(run["tool"]["driver"]["name"], "issun-analyzer");
+    assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 4);
+
+    let results = run["results"].as_array().unwrap();
+    let result_for = |rule: &str| {
+        results
+            .iter()
+            .find(|result| result["ruleId"] == rule)
+            .unwrap_or_else(|| panic!("no {} result", rule))
+    };
+    let uri = format!("file://{}", path.replace('\\', "/"));
+
+    // bus.publish::<RoundEndedEvent>(...) — nobody subscribes
+    let unused = result_for("ISSUN001");
+    assert_eq!(unused["level"], "note");
+    assert!(unused["message"]["text"]
+        .as_str()
+        .unwrap()
+        .contains("RoundEndedEvent"));
+    let location = &unused["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], uri.as_str());
+    assert_eq!(location["region"]["startLine"], 9);
+    assert_eq!(location["region"]["startColumn"], 13);
+
+    // ghosts: EventReader<GhostEvent> — nobody publishes
+    let missing = result_for("ISSUN002");
+    assert_eq!(missing["level"], "warning");
+    assert_eq!(missing["ruleIndex"], 1);
+    let location = &missing["locations"][0]["physicalLocation"];
+    assert_eq!(location["region"]["startLine"], 4);
+    assert_eq!(location["region"]["startColumn"], 5);
+}
+
+#[test]
+fn test_sarif_output_passes_schema_check() {
+    let (_, validation) = analyze_fixture();
+    let sarif: Value =
+        serde_json::from_str(&SarifLog::from_validation(&validation).to_json()).unwrap();
+
+    let schema: Value = serde_json::from_str(
+        &std::fs::read_to_string(fixture("sarif-2.1.0-minimal.schema.json")).unwrap(),
+    )
+    .unwrap();
+
+    let errors = check_schema(&sarif, &schema, &schema, "$");
+    assert!(errors.is_empty(), "schema violations: {:#?}", errors);
+
+    // The checker itself rejects broken documents
+    let mut broken = sarif.clone();
+    broken["runs"][0]["results"][0]["level"] = Value::from("fatal");
+    broken["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["region"]["startLine"] =
+        Value::from(0);
+    assert_eq!(check_schema(&broken, &schema, &schema, "$").len(), 2);
+}
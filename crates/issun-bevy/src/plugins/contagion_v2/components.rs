@@ -34,7 +34,7 @@ impl<M: Mechanic<State = SimpleSeverity>> Default for ContagionState<M> {
 impl<M: Mechanic<State = SimpleSeverity>> ContagionState<M> {
     pub fn new(severity: u32) -> Self {
         Self {
-            state: SimpleSeverity { severity },
+            state: SimpleSeverity::new(severity),
             _marker: PhantomData,
         }
     }
@@ -44,7 +44,17 @@ impl<M: Mechanic<State = SimpleSeverity>> ContagionState<M> {
     }
 
     pub fn is_infected(&self) -> bool {
-        self.state.severity > 0
+        self.state.is_infected()
+    }
+
+    /// Whether the infection killed this entity (set by the core mechanic)
+    pub fn is_dead(&self) -> bool {
+        self.state.is_dead()
+    }
+
+    /// Turns the current infection has lasted
+    pub fn infected_turns(&self) -> u32 {
+        self.state.infected_turns
     }
 }
 
@@ -59,9 +69,24 @@ pub struct ContagionConfigResource {
 impl ContagionConfigResource {
     pub fn new(base_rate: f32) -> Self {
         Self {
-            config: ContagionConfig { base_rate },
+            config: ContagionConfig {
+                base_rate,
+                ..Default::default()
+            },
         }
     }
+
+    /// Per-turn recovery chance used by `ProbabilisticRecovery`
+    pub fn with_recovery_rate(mut self, recovery_rate: f32) -> Self {
+        self.config.recovery_rate = recovery_rate;
+        self
+    }
+
+    /// Lethality used by `LethalityOutcome`
+    pub fn with_lethality(mut self, lethality: f32) -> Self {
+        self.config.lethality = lethality;
+        self
+    }
 }
 
 // ==================== Input Components ====================
@@ -111,5 +136,5 @@ pub type SimpleVirusState = ContagionState<SimpleVirus>;
 /// Explosive pandemic-style virus
 pub type ExplosiveVirusState = ContagionState<ExplosiveVirus>;
 
-/// Zombie apocalypse virus (fast spread + low resistance threshold)
+/// Zombie apocalypse virus (fast spread + low resistance threshold, resolves after 5 turns)
 pub type ZombieVirusState = ContagionState<ZombieVirus>;
@@ -0,0 +1,267 @@
+//! Input params providers
+//!
+//! Games compute `ContagionInputParams` from their own components (panic,
+//! routes, population) through a provider instead of mutating the params from
+//! input handlers. Providers run every frame in [`ContagionParamsSet::Provide`]
+//! inside `IssunSet::Input`, so the step systems in `IssunSet::Logic` always
+//! see the params of the current frame.
+//!
+//! ```ignore
+//! // Closure-based: computed per entity from its own components
+//! app.add_contagion_input_source(|entity: EntityRef, current: &ContagionInputParams| {
+//!     let district = entity.get::<District>()?;
+//!     Some(ContagionInputParams::new(district.panic_level, current.resistance))
+//! });
+//!
+//! // System-based: anything a Bevy system can read
+//! fn route_params(routes: Res<Routes>, districts: Query<(Entity, &District)>, mut writer: InputParamsWriter) {
+//!     for (entity, district) in &districts {
+//!         writer.set(entity, ContagionInputParams::new(routes.pressure(district), 10));
+//!     }
+//! }
+//! app.add_contagion_input_system(route_params);
+//! ```
+//!
+//! When a provider is registered but skips a frame, a [`ParamsStale`] message
+//! is written and the step runs with the previous params.
+
+use bevy::ecs::schedule::ScheduleConfigs;
+use bevy::ecs::system::{ScheduleSystem, SystemParam};
+use bevy::ecs::world::EntityRef;
+use bevy::prelude::*;
+
+use super::components::ContagionInputParams;
+
+/// Ordering of the input params pipeline, inside `IssunSet::Input`
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ContagionParamsSet {
+    /// Start a new params frame
+    Begin,
+    /// Providers compute this frame's params
+    Provide,
+    /// Report providers that skipped the frame
+    Check,
+}
+
+/// Computes an entity's `ContagionInputParams` from its own components
+pub trait InputParamsSource: Send + Sync + 'static {
+    /// Params of `entity` for this frame; `None` keeps `current`
+    fn compute(
+        &self,
+        entity: EntityRef<'_>,
+        current: &ContagionInputParams,
+    ) -> Option<ContagionInputParams>;
+}
+
+impl<F> InputParamsSource for F
+where
+    F: Fn(EntityRef<'_>, &ContagionInputParams) -> Option<ContagionInputParams>
+        + Send
+        + Sync
+        + 'static,
+{
+    fn compute(
+        &self,
+        entity: EntityRef<'_>,
+        current: &ContagionInputParams,
+    ) -> Option<ContagionInputParams> {
+        self(entity, current)
+    }
+}
+
+/// Closure-based providers, run in registration order
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
+pub struct InputParamsSources {
+    #[reflect(ignore)]
+    sources: Vec<Box<dyn InputParamsSource>>,
+}
+
+impl InputParamsSources {
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+/// Freshness of the input params
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct InputParamsStatus {
+    providers: usize,
+    frame: u64,
+    updated_frame: Option<u64>,
+    checked_frame: Option<u64>,
+}
+
+impl InputParamsStatus {
+    /// Registered providers (sources and systems)
+    pub fn providers(&self) -> usize {
+        self.providers
+    }
+
+    /// Current params frame, 1 for the first update
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Last frame a provider updated the params
+    pub fn last_updated(&self) -> Option<u64> {
+        self.updated_frame
+    }
+
+    /// Whether a provider updated the params this frame
+    pub fn is_fresh(&self) -> bool {
+        self.updated_frame == Some(self.frame)
+    }
+
+    /// Record that a provider ran this frame
+    pub fn mark_updated(&mut self) {
+        self.updated_frame = Some(self.frame);
+    }
+
+    /// Whether this frame's params went through the freshness check
+    pub(crate) fn is_checked(&self) -> bool {
+        self.checked_frame == Some(self.frame)
+    }
+}
+
+/// Written when a registered provider left this frame's params untouched
+#[derive(Message, Clone, Debug, PartialEq, Reflect)]
+pub struct ParamsStale {
+    pub frame: u64,
+    /// Last frame the params were provided, `None` if never
+    pub last_updated: Option<u64>,
+}
+
+/// Access for system-based providers
+///
+/// `set` marks the params as provided for this frame; a provider with
+/// nothing to write calls [`InputParamsWriter::mark_updated`].
+#[derive(SystemParam)]
+pub struct InputParamsWriter<'w, 's> {
+    params: Query<'w, 's, &'static mut ContagionInputParams>,
+    status: ResMut<'w, InputParamsStatus>,
+}
+
+impl InputParamsWriter<'_, '_> {
+    /// Current params of `entity`
+    pub fn get(&self, entity: Entity) -> Option<&ContagionInputParams> {
+        self.params.get(entity).ok()
+    }
+
+    /// Set the params of `entity` for this frame; `false` if it has none
+    pub fn set(&mut self, entity: Entity, params: ContagionInputParams) -> bool {
+        self.status.mark_updated();
+        match self.params.get_mut(entity) {
+            Ok(mut current) => {
+                *current = params;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Mark this frame's params as provided without changing any
+    pub fn mark_updated(&mut self) {
+        self.status.mark_updated();
+    }
+}
+
+/// Registration of input params providers
+pub trait ContagionInputAppExt {
+    /// Run `source` for every entity with `ContagionInputParams` each frame
+    fn add_contagion_input_source(&mut self, source: impl InputParamsSource) -> &mut Self;
+
+    /// Add a provider system; it writes through [`InputParamsWriter`]
+    fn add_contagion_input_system<M>(
+        &mut self,
+        system: impl IntoScheduleConfigs<ScheduleSystem, M>,
+    ) -> &mut Self;
+}
+
+impl ContagionInputAppExt for App {
+    fn add_contagion_input_source(&mut self, source: impl InputParamsSource) -> &mut Self {
+        let world = self.world_mut();
+        world
+            .get_resource_or_insert_with(InputParamsSources::default)
+            .sources
+            .push(Box::new(source));
+        world
+            .get_resource_or_insert_with(InputParamsStatus::default)
+            .providers += 1;
+        self
+    }
+
+    fn add_contagion_input_system<M>(
+        &mut self,
+        system: impl IntoScheduleConfigs<ScheduleSystem, M>,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(InputParamsStatus::default)
+            .providers += 1;
+        let system: ScheduleConfigs<ScheduleSystem> =
+            system.into_configs().in_set(ContagionParamsSet::Provide);
+        self.add_systems(Update, system)
+    }
+}
+
+// ==================== Systems ====================
+
+/// Start a new params frame
+pub(crate) fn begin_input_params_frame(mut status: ResMut<InputParamsStatus>) {
+    status.frame += 1;
+}
+
+/// Run the closure-based providers
+pub(crate) fn run_input_params_sources(world: &mut World) {
+    world.resource_scope(|world, sources: Mut<InputParamsSources>| {
+        if sources.is_empty() {
+            return;
+        }
+
+        let mut query = world.query::<(EntityRef, &ContagionInputParams)>();
+        let updates: Vec<(Entity, ContagionInputParams)> = query
+            .iter(world)
+            .filter_map(|(entity, current)| {
+                let mut params = *current;
+                let mut provided = false;
+                for source in &sources.sources {
+                    if let Some(next) = source.compute(entity, &params) {
+                        params = next;
+                        provided = true;
+                    }
+                }
+                provided.then_some((entity.id(), params))
+            })
+            .collect();
+
+        for (entity, params) in updates {
+            if let Some(mut current) = world.get_mut::<ContagionInputParams>(entity) {
+                *current = params;
+            }
+        }
+        world.resource_mut::<InputParamsStatus>().mark_updated();
+    });
+}
+
+/// Report a provider that skipped this frame
+pub(crate) fn check_input_params(
+    mut status: ResMut<InputParamsStatus>,
+    mut stale: MessageWriter<ParamsStale>,
+) {
+    if status.providers > 0 && !status.is_fresh() {
+        warn!(
+            "Contagion input params are stale in frame {} (last provided: {:?})",
+            status.frame, status.updated_frame
+        );
+        stale.write(ParamsStale {
+            frame: status.frame,
+            last_updated: status.updated_frame,
+        });
+    }
+    status.checked_frame = Some(status.frame);
+}
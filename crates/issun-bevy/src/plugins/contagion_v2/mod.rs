@@ -10,6 +10,11 @@
 //! - **Static Dispatch**: All policies resolved at compile time
 //! - **Bevy Integration**: Components wrap issun-core types
 //! - **Event-Driven**: Uses Mechanic::step() with EventEmitter
+//! - **Recovery/Death from Core**: Recovery and outcome policies of the
+//!   mechanic drive `Recovered`/`Died`; the plugin adds no logic of its own
+//! - **Input Providers**: Games compute `ContagionInputParams` in
+//!   `IssunSet::Input` via `add_contagion_input_source`/`add_contagion_input_system`;
+//!   the step always sees the params of the same frame
 //!
 //! # Example
 //!
@@ -28,10 +33,18 @@
 //! ```
 
 mod components;
+mod input;
 mod plugin;
 mod reflect_wrappers;
 mod systems;
 
+#[cfg(test)]
+mod tests;
+
 pub use components::*;
+pub use input::{
+    ContagionInputAppExt, ContagionParamsSet, InputParamsSource, InputParamsSources,
+    InputParamsStatus, InputParamsWriter, ParamsStale,
+};
 pub use plugin::ContagionV2Plugin;
 pub use reflect_wrappers::*;
@@ -3,6 +3,7 @@
 use bevy::prelude::*;
 use issun_core::mechanics::contagion::prelude::*;
 
+use super::input::*;
 use super::reflect_wrappers::*;
 use super::systems::*;
 use crate::IssunSet;
@@ -11,9 +12,17 @@ use crate::IssunSet;
 ///
 /// This plugin demonstrates how to use issun-core's contagion mechanic
 /// with Bevy ECS using static dispatch and zero-cost abstraction.
+///
+/// Recovery and death are decided by the mechanic's recovery/outcome
+/// policies; the plugin only forwards the resulting `ContagionEvent`s.
+///
+/// Input params providers run in `IssunSet::Input` and the step systems only
+/// after [`ContagionParamsSet::Check`], see the `input` module.
 #[derive(Default)]
 pub struct ContagionV2Plugin {
     pub base_rate: f32,
+    pub recovery_rate: f32,
+    pub lethality: f32,
 }
 
 impl ContagionV2Plugin {
@@ -25,16 +34,33 @@ impl ContagionV2Plugin {
         self.base_rate = base_rate;
         self
     }
+
+    pub fn with_recovery_rate(mut self, recovery_rate: f32) -> Self {
+        self.recovery_rate = recovery_rate;
+        self
+    }
+
+    pub fn with_lethality(mut self, lethality: f32) -> Self {
+        self.lethality = lethality;
+        self
+    }
 }
 
 impl Plugin for ContagionV2Plugin {
     fn build(&self, app: &mut App) {
         // Resources
-        app.insert_resource(ContagionConfigResource::new(self.base_rate));
+        app.insert_resource(
+            ContagionConfigResource::new(self.base_rate)
+                .with_recovery_rate(self.recovery_rate)
+                .with_lethality(self.lethality),
+        );
         app.insert_resource(ContagionRng::default());
+        app.init_resource::<InputParamsSources>();
+        app.init_resource::<InputParamsStatus>();
 
         // Messages - using issun-core's ContagionEvent
         app.add_message::<ContagionEventWrapper>();
+        app.add_message::<ParamsStale>();
 
         // Component registration
         // Note: ContagionState<M> cannot be registered directly due to generic constraints.
@@ -42,19 +68,44 @@ impl Plugin for ContagionV2Plugin {
         app.register_type::<ContagionConfigResource>()
             .register_type::<ContagionInputParams>()
             .register_type::<ContagionRng>()
+            .register_type::<InputParamsSources>()
+            .register_type::<InputParamsStatus>()
+            .register_type::<ParamsStale>()
             .register_type::<SimpleVirusStateReflect>()
             .register_type::<ExplosiveVirusStateReflect>()
             .register_type::<ZombieVirusStateReflect>();
 
-        // Systems - one system per mechanic type
+        // Input params: providers, then the freshness check
+        app.configure_sets(
+            Update,
+            (
+                ContagionParamsSet::Begin,
+                ContagionParamsSet::Provide,
+                ContagionParamsSet::Check,
+            )
+                .chain()
+                .in_set(IssunSet::Input),
+        );
         app.add_systems(
             Update,
             (
-                contagion_step_system::<SimpleVirus>.in_set(IssunSet::Logic),
-                contagion_step_system::<ExplosiveVirus>.in_set(IssunSet::Logic),
-                contagion_step_system::<ZombieVirus>.in_set(IssunSet::Logic),
+                begin_input_params_frame.in_set(ContagionParamsSet::Begin),
+                run_input_params_sources.in_set(ContagionParamsSet::Provide),
+                check_input_params.in_set(ContagionParamsSet::Check),
             ),
         );
+
+        // Systems - one system per mechanic type, always after this frame's params
+        app.add_systems(
+            Update,
+            (
+                contagion_step_system::<SimpleVirus>,
+                contagion_step_system::<ExplosiveVirus>,
+                contagion_step_system::<ZombieVirus>,
+            )
+                .in_set(IssunSet::Logic)
+                .after(ContagionParamsSet::Check),
+        );
     }
 }
 
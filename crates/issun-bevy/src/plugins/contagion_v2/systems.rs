@@ -8,6 +8,7 @@ use issun_core::mechanics::contagion::prelude::*;
 use issun_core::mechanics::{EventEmitter, Mechanic};
 
 use super::components::*;
+use super::input::InputParamsStatus;
 use super::plugin::{ContagionConfigResource, ContagionEventWrapper, ContagionRng};
 
 /// Generic contagion step system for any mechanic type
@@ -18,6 +19,7 @@ use super::plugin::{ContagionConfigResource, ContagionEventWrapper, ContagionRng
 /// 3. Emits events through Bevy's message system
 pub fn contagion_step_system<M>(
     config: Res<ContagionConfigResource>,
+    status: Res<InputParamsStatus>,
     mut rng: ResMut<ContagionRng>,
     mut query: Query<(Entity, &mut ContagionState<M>, &ContagionInputParams)>,
     mut message_writer: MessageWriter<ContagionEventWrapper>,
@@ -31,6 +33,12 @@ pub fn contagion_step_system<M>(
         + Sync
         + 'static,
 {
+    debug_assert!(
+        status.providers() == 0 || status.is_checked(),
+        "contagion step ran before the input params of frame {} were provided",
+        status.frame()
+    );
+
     for (entity, mut state, params) in query.iter_mut() {
         // Generate random value for this frame
         let rng_value = rng.gen_f32();
@@ -80,6 +88,12 @@ pub fn log_contagion_events(mut messages: MessageReader<ContagionEventWrapper>)
                     wrapper.entity, new_severity
                 );
             }
+            ContagionEvent::Recovered => {
+                info!("Entity {:?} recovered", wrapper.entity);
+            }
+            ContagionEvent::Died => {
+                info!("Entity {:?} died of its infection", wrapper.entity);
+            }
         }
     }
 }
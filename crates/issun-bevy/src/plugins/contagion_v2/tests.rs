@@ -0,0 +1,212 @@
+//! Adapter tests: state transitions come from issun-core's contagion events
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests {
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::world::EntityRef;
+    use bevy::prelude::*;
+    use issun_core::mechanics::contagion::ContagionEvent;
+
+    use super::super::components::*;
+    use super::super::input::*;
+    use super::super::plugin::{ContagionEventWrapper, ContagionV2Plugin};
+    use crate::IssunCorePlugin;
+
+    fn create_test_app(lethality: f32) -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            IssunCorePlugin,
+            ContagionV2Plugin::new()
+                .with_base_rate(0.1)
+                .with_lethality(lethality),
+        ));
+        app
+    }
+
+    /// Spawn an infected zombie-virus carrier in an empty area (no further spread)
+    fn spawn_carrier(app: &mut App, severity: u32) -> Entity {
+        app.world_mut()
+            .spawn((
+                ZombieVirusState::new(severity),
+                ContagionInputParams::new(0.0, 0),
+            ))
+            .id()
+    }
+
+    fn events_for(app: &App, entity: Entity) -> Vec<ContagionEvent> {
+        let messages = app.world().resource::<Messages<ContagionEventWrapper>>();
+        let mut cursor = messages.get_cursor();
+        cursor
+            .read(messages)
+            .filter(|wrapper| wrapper.entity == entity)
+            .map(|wrapper| wrapper.event)
+            .collect()
+    }
+
+    #[test]
+    fn test_zombie_carrier_dies_from_core_event() {
+        let mut app = create_test_app(1.0);
+        let carrier = spawn_carrier(&mut app, 3);
+
+        // ZombieVirus resolves after 5 turns
+        for _ in 0..4 {
+            app.update();
+            assert!(events_for(&app, carrier).is_empty());
+        }
+
+        app.update();
+        assert_eq!(events_for(&app, carrier), vec![ContagionEvent::Died]);
+
+        let state = app.world().get::<ZombieVirusState>(carrier).unwrap();
+        assert!(state.is_dead());
+        assert!(!state.is_infected());
+
+        // Dead carriers no longer produce events
+        app.world_mut()
+            .resource_mut::<Messages<ContagionEventWrapper>>()
+            .clear();
+        app.update();
+        app.update();
+        assert!(events_for(&app, carrier).is_empty());
+    }
+
+    #[test]
+    fn test_zombie_carrier_recovers_without_lethality() {
+        let mut app = create_test_app(0.0);
+        let carrier = spawn_carrier(&mut app, 3);
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(events_for(&app, carrier), vec![ContagionEvent::Recovered]);
+
+        let state = app.world().get::<ZombieVirusState>(carrier).unwrap();
+        assert!(!state.is_infected());
+        assert!(!state.is_dead());
+        assert_eq!(state.severity(), 0);
+        assert_eq!(state.infected_turns(), 0);
+    }
+
+    /// Exposure of an entity, turned into density by the test provider
+    #[derive(Component, Clone, Copy, Reflect)]
+    #[reflect(Component)]
+    struct Exposure(f32);
+
+    /// Lets a test skip the provider for a frame
+    #[derive(Resource, Reflect)]
+    #[reflect(Resource)]
+    struct ProviderEnabled(bool);
+
+    fn exposure_provider(exposed: Query<(Entity, &Exposure)>, mut writer: InputParamsWriter) {
+        for (entity, exposure) in &exposed {
+            writer.set(entity, ContagionInputParams::new(exposure.0, 0));
+        }
+    }
+
+    /// Always-spreading simple virus, params provided from `Exposure`
+    fn create_provider_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            IssunCorePlugin,
+            ContagionV2Plugin::new().with_base_rate(1.0),
+        ));
+        app.insert_resource(ProviderEnabled(true));
+        app.add_contagion_input_system(
+            exposure_provider.run_if(|enabled: Res<ProviderEnabled>| enabled.0),
+        );
+        app
+    }
+
+    fn stale_messages(app: &App) -> Vec<ParamsStale> {
+        let messages = app.world().resource::<Messages<ParamsStale>>();
+        messages.get_cursor().read(messages).cloned().collect()
+    }
+
+    #[test]
+    fn test_provider_change_applies_in_the_same_frame() {
+        let mut app = create_provider_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                SimpleVirusState::default(),
+                // Stale params a step would infect with
+                ContagionInputParams::new(1.0, 0),
+                Exposure(0.0),
+            ))
+            .id();
+
+        app.update();
+        assert!(events_for(&app, entity).is_empty());
+
+        // The provider picks the exposure up before this frame's step
+        app.world_mut().get_mut::<Exposure>(entity).unwrap().0 = 1.0;
+        app.update();
+        assert_eq!(events_for(&app, entity), vec![ContagionEvent::Infected]);
+        assert!(stale_messages(&app).is_empty());
+
+        let status = app.world().resource::<InputParamsStatus>();
+        assert_eq!(status.providers(), 1);
+        assert!(status.is_fresh());
+    }
+
+    #[test]
+    fn test_source_provider_reads_entity_components() {
+        let mut app = create_test_app(0.0);
+        app.world_mut()
+            .resource_mut::<ContagionConfigResource>()
+            .config
+            .base_rate = 1.0;
+        app.add_contagion_input_source(|entity: EntityRef, current: &ContagionInputParams| {
+            let exposure = entity.get::<Exposure>()?;
+            Some(ContagionInputParams::new(exposure.0, current.resistance))
+        });
+        let exposed = app
+            .world_mut()
+            .spawn((
+                SimpleVirusState::default(),
+                ContagionInputParams::new(0.0, 0),
+                Exposure(1.0),
+            ))
+            .id();
+
+        app.update();
+        assert_eq!(events_for(&app, exposed), vec![ContagionEvent::Infected]);
+        let params = app.world().get::<ContagionInputParams>(exposed).unwrap();
+        assert_eq!(params.density, 1.0);
+    }
+
+    #[test]
+    fn test_skipped_provider_reports_stale_params() {
+        let mut app = create_provider_app();
+        app.world_mut().spawn((
+            SimpleVirusState::default(),
+            ContagionInputParams::new(0.0, 0),
+            Exposure(0.0),
+        ));
+
+        app.update();
+        app.update();
+        assert!(stale_messages(&app).is_empty());
+
+        app.world_mut().resource_mut::<ProviderEnabled>().0 = false;
+        app.update();
+        assert_eq!(
+            stale_messages(&app),
+            vec![ParamsStale {
+                frame: 3,
+                last_updated: Some(2),
+            }]
+        );
+
+        app.world_mut()
+            .resource_mut::<Messages<ParamsStale>>()
+            .clear();
+        app.world_mut().resource_mut::<ProviderEnabled>().0 = true;
+        app.update();
+        assert!(app.world().resource::<InputParamsStatus>().is_fresh());
+        assert!(stale_messages(&app).is_empty());
+    }
+}
@@ -0,0 +1,79 @@
+//! `#[plugin(systems(...))]` of the IssunBevyPlugin derive
+//!
+//! The plugin declares pandemic-crisis's systems: a chained startup
+//! sequence and event handlers placed in IssunSet stages.
+
+use bevy::prelude::*;
+use issun_bevy::IssunCorePlugin;
+use issun_macros::IssunBevyPlugin;
+
+#[derive(Resource, Clone, Default)]
+pub struct CallLog(Vec<&'static str>);
+
+#[derive(Default, IssunBevyPlugin)]
+#[plugin(
+    name = "outbreak",
+    systems(
+        startup(chain) = "setup_world, setup_player, spawn_initial_disease",
+        update(IssunSet::PostLogic) = "handle_contagion_spread",
+        update(issun_bevy::IssunSet::Logic, chain) = "spread_contagion, tally_infections",
+        update = "handle_state_changes",
+    )
+)]
+pub struct OutbreakPlugin {
+    #[resource]
+    pub log: CallLog,
+}
+
+fn setup_world(mut log: ResMut<CallLog>) {
+    log.0.push("setup_world");
+}
+
+fn setup_player(mut log: ResMut<CallLog>) {
+    log.0.push("setup_player");
+}
+
+fn spawn_initial_disease(mut log: ResMut<CallLog>) {
+    log.0.push("spawn_initial_disease");
+}
+
+fn spread_contagion(mut log: ResMut<CallLog>) {
+    log.0.push("spread_contagion");
+}
+
+fn tally_infections(mut log: ResMut<CallLog>) {
+    log.0.push("tally_infections");
+}
+
+fn handle_contagion_spread(mut log: ResMut<CallLog>) {
+    log.0.push("handle_contagion_spread");
+}
+
+fn handle_state_changes(mut log: ResMut<CallLog>) {
+    log.0.push("handle_state_changes");
+}
+
+#[test]
+fn test_systems_attribute_registers_schedules_and_sets() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(IssunCorePlugin)
+        .add_plugins(OutbreakPlugin::default());
+
+    app.update();
+    let log = &app.world().resource::<CallLog>().0;
+    assert_eq!(
+        log[..3],
+        ["setup_world", "setup_player", "spawn_initial_disease"]
+    );
+
+    // The IssunSet stages order the sets: Logic (chained) before PostLogic
+    let position = |name| log.iter().position(|entry| *entry == name).unwrap();
+    assert!(position("spread_contagion") < position("tally_infections"));
+    assert!(position("tally_infections") < position("handle_contagion_spread"));
+    assert!(log.contains(&"handle_state_changes"));
+    assert_eq!(log.len(), 7);
+
+    app.update();
+    assert_eq!(app.world().resource::<CallLog>().0.len(), 11);
+}
@@ -0,0 +1,104 @@
+//! `#[plugin(test_harness = true)]` of the IssunBevyPlugin derive
+
+use bevy::prelude::*;
+use issun_macros::IssunBevyPlugin;
+
+#[derive(Message, Clone, Debug)]
+pub struct TurnAdvanced {
+    pub turns: u32,
+}
+
+#[derive(Resource, Clone, Debug, Default)]
+pub struct TurnClock {
+    pub turn: u32,
+}
+
+#[derive(Resource, Clone, Debug, Default)]
+pub struct TurnRules {
+    pub max_turns: u32,
+}
+
+#[derive(Default, IssunBevyPlugin)]
+#[plugin(
+    test_harness = true,
+    messages = [TurnAdvanced],
+    update_systems = [advance_clock]
+)]
+pub struct TurnPlugin {
+    #[config]
+    pub rules: TurnRules,
+
+    #[resource]
+    pub clock: TurnClock,
+}
+
+fn advance_clock(
+    mut messages: MessageReader<TurnAdvanced>,
+    rules: Res<TurnRules>,
+    mut clock: ResMut<TurnClock>,
+) {
+    for message in messages.read() {
+        clock.turn = (clock.turn + message.turns).min(rules.max_turns);
+    }
+}
+
+#[derive(Resource, Clone, Debug, Default)]
+pub struct Forgotten;
+
+/// Declares a resource but skips its registration
+#[derive(Default, IssunBevyPlugin)]
+#[plugin(test_harness = true)]
+pub struct ForgetfulPlugin {
+    #[resource]
+    pub clock: TurnClock,
+
+    #[resource]
+    #[skip]
+    pub forgotten: Forgotten,
+}
+
+#[test]
+fn test_harness_app_has_the_plugin_resources() {
+    let app = TurnPlugin::default()
+        .with_rules(TurnRules { max_turns: 5 })
+        .test_app();
+
+    TurnPlugin::assert_resources(&app);
+    assert!(app
+        .world()
+        .contains_resource::<issun_bevy::IssunCorePluginMarker>());
+    assert_eq!(app.world().resource::<TurnRules>().max_turns, 5);
+    assert_eq!(app.world().resource::<TurnClock>().turn, 0);
+}
+
+#[test]
+fn test_send_and_update_round_trips_a_message() {
+    let mut app = TurnPlugin::default()
+        .with_rules(TurnRules { max_turns: 5 })
+        .test_app();
+
+    TurnPlugin::send_and_update(&mut app, TurnAdvanced { turns: 2 });
+    assert_eq!(app.world().resource::<TurnClock>().turn, 2);
+
+    TurnPlugin::send_and_update(&mut app, TurnAdvanced { turns: 4 });
+    assert_eq!(app.world().resource::<TurnClock>().turn, 5);
+}
+
+#[test]
+#[should_panic(expected = "ForgetfulPlugin did not register resource Forgotten")]
+fn test_assert_resources_catches_an_unregistered_resource() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(issun_bevy::IssunCorePlugin)
+        .add_plugins(ForgetfulPlugin::default());
+    app.update();
+
+    ForgetfulPlugin::assert_resources(&app);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "ForgetfulPlugin did not register resource Forgotten")]
+fn test_harness_app_catches_an_unregistered_resource() {
+    ForgetfulPlugin::default().test_app();
+}
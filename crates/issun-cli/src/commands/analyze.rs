@@ -1,15 +1,42 @@
 //! Analyze command - Static analysis of ISSUN plugins
 
 use crate::config::Config;
-use crate::error::Result;
-use clap::Args;
+use crate::error::{CliError, Result};
+use clap::{Args, Subcommand, ValueEnum};
 use issun_analyzer::plugin_extractor::infer_plugins_from_directory;
 use issun_analyzer::prelude::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Output format of validation results
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable report on stdout
+    #[default]
+    Text,
+    /// SARIF 2.1.0 JSON for editors and code-scanning tools
+    Sarif,
+}
+
+/// API surface modes of `issun analyze`
+#[derive(Subcommand, Debug)]
+pub enum AnalyzeMode {
+    /// Write the public plugin API (events, plugins, hooks) as a JSON snapshot
+    /// (to --output or api_snapshot.json)
+    ApiSnapshot,
+    /// Compare the current plugins against a snapshot; exits with code 2 when
+    /// a change is breaking
+    ApiDiff {
+        /// Snapshot written by `issun analyze api-snapshot`
+        old: PathBuf,
+    },
+}
 
 /// Analyze plugin architecture and event flows
 #[derive(Args, Debug)]
 pub struct AnalyzeCommand {
+    #[command(subcommand)]
+    pub mode: Option<AnalyzeMode>,
+
     /// Generate event flow graph
     #[arg(long)]
     pub event_flow: bool,
@@ -22,10 +49,26 @@ pub struct AnalyzeCommand {
     #[arg(long)]
     pub combined_flow: bool,
 
+    /// Generate sequence diagram for the chain triggered by an event type
+    #[arg(long, value_name = "EVENT_TYPE")]
+    pub sequence: Option<String>,
+
+    /// Maximum publish hops to follow for --sequence
+    #[arg(long, default_value = "5")]
+    pub sequence_depth: usize,
+
+    /// Emit --sequence as plain text instead of Mermaid
+    #[arg(long)]
+    pub plain: bool,
+
     /// Validate event consistency
     #[arg(long)]
     pub validate: bool,
 
+    /// Output format for --validate (sarif is written to --output or validation.sarif)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
     /// List all plugins
     #[arg(long)]
     pub list_plugins: bool,
@@ -58,11 +101,20 @@ impl AnalyzeCommand {
         let plugin_dir = config.plugin_dir_absolute();
         println!("📂 Analyzing plugins in: {}\n", plugin_dir.display());
 
+        match &self.mode {
+            Some(AnalyzeMode::ApiSnapshot) => return self.write_api_snapshot(&plugin_dir, config),
+            Some(AnalyzeMode::ApiDiff { old }) => return self.diff_api(&plugin_dir, old),
+            None => {}
+        }
+
         // Infer plugins from directory
         let plugins = infer_plugins_from_directory(&plugin_dir)?;
 
         let mut result = AnalysisResult::new();
         for plugin in plugins {
+            for file in analyze_plugin_files(Path::new(&plugin.path)) {
+                result.add_file(file);
+            }
             result.add_plugin(plugin);
         }
 
@@ -110,8 +162,13 @@ impl AnalyzeCommand {
             executed = true;
         }
 
+        if let Some(event_type) = &self.sequence {
+            self.generate_sequence_diagram(&result, config, event_type)?;
+            executed = true;
+        }
+
         if self.validate {
-            self.validate_event_flow(&result)?;
+            self.validate_event_flow(&result, config)?;
             executed = true;
         }
 
@@ -304,12 +361,68 @@ impl AnalyzeCommand {
         Ok(())
     }
 
-    fn validate_event_flow(&self, result: &AnalysisResult) -> Result<()> {
+    fn generate_sequence_diagram(
+        &self,
+        result: &AnalysisResult,
+        config: &Config,
+        event_type: &str,
+    ) -> Result<()> {
+        println!("📈 Generating Sequence Diagram for {}...", event_type);
+
+        let options = SequenceOptions {
+            max_depth: self.sequence_depth,
+            ..Default::default()
+        };
+
+        let graph_gen = SequenceDiagramGenerator::for_event(result, event_type, options);
+        let (content, extension) = if self.plain {
+            (graph_gen.generate_text(), "txt")
+        } else {
+            (graph_gen.generate(), "mmd")
+        };
+
+        let output_path = self.output.clone().unwrap_or_else(|| {
+            config
+                .output_dir_absolute()
+                .join(format!("sequence_{}.{}", event_type, extension))
+        });
+
+        std::fs::write(&output_path, content)?;
+
+        println!("   ✅ Saved to: {}", output_path.display());
+        if !self.plain {
+            println!("   View at: https://mermaid.live");
+        }
+        println!();
+
+        Ok(())
+    }
+
+    fn validate_event_flow(&self, result: &AnalysisResult, config: &Config) -> Result<()> {
         println!("🔎 Validating Event Flow...\n");
 
         let validator = Validator::new(result);
         let validation = validator.validate();
 
+        if self.format == OutputFormat::Sarif {
+            let output_path = self
+                .output
+                .clone()
+                .unwrap_or_else(|| config.output_dir_absolute().join("validation.sarif"));
+
+            std::fs::write(
+                &output_path,
+                SarifLog::from_validation(&validation).to_json(),
+            )?;
+
+            println!(
+                "   ✅ {} warning(s) saved to: {}\n",
+                validation.warnings.len(),
+                output_path.display()
+            );
+            return Ok(());
+        }
+
         validation.print_report();
 
         println!("\n📋 Validation Summary:");
@@ -335,4 +448,85 @@ impl AnalyzeCommand {
         println!();
         Ok(())
     }
+
+    fn write_api_snapshot(&self, plugin_dir: &Path, config: &Config) -> Result<()> {
+        println!("📸 Writing API Snapshot...");
+
+        let snapshot = ApiSnapshot::from_plugin_dir(plugin_dir)?;
+
+        let output_path = self
+            .output
+            .clone()
+            .unwrap_or_else(|| config.output_dir_absolute().join("api_snapshot.json"));
+
+        std::fs::write(&output_path, snapshot.to_json())?;
+
+        println!(
+            "   ✅ {} events, {} plugins, {} hook traits saved to: {}\n",
+            snapshot.events.len(),
+            snapshot.plugins.len(),
+            snapshot.hooks.len(),
+            output_path.display()
+        );
+
+        Ok(())
+    }
+
+    fn diff_api(&self, plugin_dir: &Path, old_path: &Path) -> Result<()> {
+        println!("🔀 Comparing API against: {}\n", old_path.display());
+
+        let old = ApiSnapshot::from_json(&std::fs::read_to_string(old_path)?)?;
+        let new = ApiSnapshot::from_plugin_dir(plugin_dir)?;
+        let diff = ApiDiff::between(&old, &new);
+
+        if diff.is_empty() {
+            println!("   ✅ No API changes\n");
+            return Ok(());
+        }
+
+        for (kind, title) in [
+            (ChangeKind::Breaking, "🔴 Breaking"),
+            (ChangeKind::Additive, "🟢 Additive"),
+            (ChangeKind::Internal, "⚪ Internal"),
+        ] {
+            let changes: Vec<_> = diff.of_kind(kind).collect();
+            if changes.is_empty() {
+                continue;
+            }
+            println!("{} ({}):", title, changes.len());
+            for change in changes {
+                println!("   • {}: {}", change.item, change.description);
+            }
+            println!();
+        }
+
+        let breaking = diff.of_kind(ChangeKind::Breaking).count();
+        if breaking > 0 {
+            return Err(CliError::BreakingChanges(breaking));
+        }
+
+        println!("   ✅ No breaking changes\n");
+        Ok(())
+    }
+}
+
+/// Event publications/subscriptions of every Rust file in a plugin directory
+///
+/// Files that fail to parse are skipped, like in plugin inference.
+fn analyze_plugin_files(dir: &Path) -> Vec<FileAnalysis> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    paths.sort();
+
+    let analyzer = Analyzer::new(dir);
+    paths
+        .iter()
+        .filter_map(|path| analyzer.analyze_file(path).ok())
+        .collect()
 }
@@ -25,4 +25,22 @@ pub enum CliError {
     #[error("Command failed: {0}")]
     #[allow(dead_code)]
     CommandError(String),
+
+    /// `analyze api-diff` found breaking changes
+    #[error("{0} breaking API change(s)")]
+    BreakingChanges(usize),
+
+    /// `scenario` found failing or invalid scenario files
+    #[error("{0} scenario(s) failed")]
+    ScenariosFailed(usize),
+}
+
+impl CliError {
+    /// Process exit code: 2 for breaking API changes, 1 for other errors
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            CliError::BreakingChanges(_) => 2,
+            _ => 1,
+        }
+    }
 }
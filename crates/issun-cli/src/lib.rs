@@ -0,0 +1,6 @@
+//! Commands of the ISSUN CLI that game binaries can embed
+//!
+//! `scenario run` needs the game's factories, so a game registers them and
+//! hands over to [`scenario::main`] from its own binary.
+
+pub mod scenario;
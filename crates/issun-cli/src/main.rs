@@ -7,7 +7,10 @@ mod error;
 use clap::{Parser, Subcommand};
 use commands::AnalyzeCommand;
 use config::Config;
-use error::Result;
+use error::{CliError, Result};
+use issun::testing::scenario::ScenarioFactories;
+use issun_cli::scenario::ScenarioCommand;
+use std::process::ExitCode;
 
 /// ISSUN - A mini game engine for logic-focused games
 #[derive(Parser, Debug)]
@@ -34,11 +37,21 @@ struct Cli {
 enum Commands {
     /// Analyze plugin architecture and event flows
     Analyze(AnalyzeCommand),
+    /// Run or check scenario files (game binaries register games via issun_cli::scenario::main)
+    Scenario(ScenarioCommand),
 }
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::from(e.exit_code())
+        }
+    }
+}
 
+fn run(cli: Cli) -> Result<()> {
     // Build configuration
     let config = Config::new()
         .with_project_root(&cli.project_root)
@@ -48,6 +61,12 @@ fn main() -> Result<()> {
     // Execute subcommand
     match &cli.command {
         Commands::Analyze(cmd) => cmd.execute(&config)?,
+        Commands::Scenario(cmd) => {
+            let failed = cmd.execute(&ScenarioFactories::new())?;
+            if failed > 0 {
+                return Err(CliError::ScenariosFailed(failed));
+            }
+        }
     }
 
     Ok(())
@@ -0,0 +1,129 @@
+//! Scenario command - Run scenario files against registered games
+//!
+//! A game binary registers its factories and hands over to [`main`]:
+//!
+//! ```ignore
+//! // src/bin/scenarios.rs
+//! fn main() -> std::process::ExitCode {
+//!     issun_cli::scenario::main(my_game::scenario_factories())
+//! }
+//! ```
+//!
+//! ```bash
+//! cargo run --bin scenarios -- run tests/scenarios/*.ron
+//! ```
+
+use clap::{Args, Parser, Subcommand};
+use issun::testing::scenario::{self, Scenario, ScenarioFactories};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Scenario modes
+#[derive(Subcommand, Debug)]
+pub enum ScenarioMode {
+    /// Run scenario files against the games registered in this binary
+    Run {
+        /// Scenario files (.ron)
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+    /// Parse scenario files and list their steps
+    Check {
+        /// Scenario files (.ron)
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+}
+
+/// Run or check scenario files
+#[derive(Args, Debug)]
+pub struct ScenarioCommand {
+    #[command(subcommand)]
+    pub mode: ScenarioMode,
+}
+
+impl ScenarioCommand {
+    /// Run or check every file; returns the number of files that failed
+    pub fn execute(&self, factories: &ScenarioFactories) -> std::io::Result<usize> {
+        match &self.mode {
+            ScenarioMode::Run { paths } => run_all(paths, factories),
+            ScenarioMode::Check { paths } => Ok(check_all(paths)),
+        }
+    }
+}
+
+/// Command line of a game binary that only runs scenarios
+#[derive(Parser, Debug)]
+#[command(about = "Run ISSUN scenario files")]
+struct ScenarioCli {
+    #[command(flatten)]
+    command: ScenarioCommand,
+}
+
+/// Entry point for game binaries: parse the command line as a scenario
+/// command and run it with `factories`
+pub fn main(factories: ScenarioFactories) -> ExitCode {
+    let cli = ScenarioCli::parse();
+    match cli.command.execute(&factories) {
+        Ok(0) => ExitCode::SUCCESS,
+        Ok(failed) => {
+            eprintln!("Error: {} scenario(s) failed", failed);
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_all(paths: &[PathBuf], factories: &ScenarioFactories) -> std::io::Result<usize> {
+    if factories.game_names().is_empty() {
+        eprintln!(
+            "⚠️  No games are registered in this binary; \
+             call issun_cli::scenario::main from a game binary to run scenarios"
+        );
+    }
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+
+    let mut failed = 0;
+    for path in paths {
+        println!("▶ {}", path.display());
+        match runtime.block_on(scenario::run(path, factories)) {
+            Ok(report) => println!("  ✅ {}", report),
+            Err(e) => {
+                failed += 1;
+                println!("  ❌ {}", e.to_string().replace('\n', "\n  "));
+            }
+        }
+    }
+    println!("\n{} passed, {} failed", paths.len() - failed, failed);
+    Ok(failed)
+}
+
+fn check_all(paths: &[PathBuf]) -> usize {
+    let mut failed = 0;
+    for path in paths {
+        match Scenario::load(path) {
+            Ok(scenario) => {
+                println!(
+                    "✅ {}: '{}' on game '{}'",
+                    path.display(),
+                    scenario.name,
+                    scenario.game
+                );
+                for (index, step) in scenario.steps.iter().enumerate() {
+                    println!("   {}. {}", index + 1, step);
+                }
+            }
+            Err(e) => {
+                failed += 1;
+                println!("❌ {}: {}", path.display(), e);
+            }
+        }
+    }
+    failed
+}
@@ -36,7 +36,7 @@
 //! type CustomVirus = ContagionMechanic<ExponentialSpread, ThresholdProgression<50>>;
 //!
 //! // 2. Create configuration (shared across all entities)
-//! let config = ContagionConfig { base_rate: 0.15 };
+//! let config = ContagionConfig { base_rate: 0.15, ..Default::default() };
 //!
 //! // 3. Create per-entity state
 //! let mut state = SimpleSeverity::default();
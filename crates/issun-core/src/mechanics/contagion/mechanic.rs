@@ -7,26 +7,42 @@ use std::marker::PhantomData;
 
 use crate::mechanics::{EventEmitter, Mechanic, ParallelSafe};
 
-use super::policies::{ProgressionPolicy, SpreadPolicy};
-use super::strategies::{LinearSpread, ThresholdProgression};
-use super::types::{ContagionConfig, ContagionEvent, ContagionInput, SimpleSeverity};
+use super::policies::{OutcomePolicy, ProgressionPolicy, RecoveryPolicy, SpreadPolicy};
+use super::strategies::{LinearSpread, NoRecovery, SurvivalOutcome, ThresholdProgression};
+use super::types::{
+    ContagionConfig, ContagionEvent, ContagionInput, InfectionOutcome, SimpleSeverity,
+};
 
 /// A policy-based contagion mechanic.
 ///
-/// `ContagionMechanic` is a generic "shell" that accepts two policy type parameters:
+/// `ContagionMechanic` is a generic "shell" that accepts four policy type parameters:
 /// - `S`: The spread policy (determines how infection spreads based on density)
 /// - `P`: The progression policy (determines how infection severity increases)
+/// - `R`: The recovery policy (determines when an infection resolves)
+/// - `O`: The outcome policy (determines whether a resolved infection recovers or kills)
 ///
 /// # Type Parameters
 ///
 /// - `S: SpreadPolicy` - Controls how infection spread rate is calculated (default: `LinearSpread`)
 /// - `P: ProgressionPolicy` - Controls how infection severity progresses (default: `ThresholdProgression`)
+/// - `R: RecoveryPolicy` - Controls when infections resolve (default: `NoRecovery`)
+/// - `O: OutcomePolicy` - Controls how resolved infections end (default: `SurvivalOutcome`)
 ///
 /// # Default Generics
 ///
-/// Both type parameters have sensible defaults, allowing you to customize only what you need:
+/// All type parameters have sensible defaults, allowing you to customize only what you need:
 /// - Default spread: `LinearSpread` (proportional to density)
 /// - Default progression: `ThresholdProgression` (resistance-based threshold)
+/// - Default recovery: `NoRecovery` (severity only climbs)
+/// - Default outcome: `SurvivalOutcome` (nobody dies)
+///
+/// # Step Order
+///
+/// 1. Dead entities are skipped
+/// 2. Spread and progression (may emit `Infected` / `Progressed`)
+/// 3. For entities infected before this step, `infected_turns` increases and the
+///    recovery policy is consulted; if the infection resolves, the outcome policy
+///    decides between `Recovered` (state reset to healthy) and `Died` (state flagged dead)
 ///
 /// # Design Notes
 ///
@@ -53,7 +69,7 @@ use super::types::{ContagionConfig, ContagionEvent, ContagionInput, SimpleSeveri
 /// type MyVirus = ContagionMechanic<LinearSpread, ThresholdProgression>;
 ///
 /// // Create config and state
-/// let config = ContagionConfig { base_rate: 0.1 };
+/// let config = ContagionConfig { base_rate: 0.1, ..Default::default() };
 /// let mut state = SimpleSeverity::default();
 ///
 /// // Create input for this frame
@@ -83,11 +99,19 @@ use super::types::{ContagionConfig, ContagionEvent, ContagionInput, SimpleSeveri
 pub struct ContagionMechanic<
     S: SpreadPolicy = LinearSpread,
     P: ProgressionPolicy = ThresholdProgression,
+    R: RecoveryPolicy = NoRecovery,
+    O: OutcomePolicy = SurvivalOutcome,
 > {
-    _marker: PhantomData<(S, P)>,
+    _marker: PhantomData<(S, P, R, O)>,
 }
 
-impl<S: SpreadPolicy, P: ProgressionPolicy> Mechanic for ContagionMechanic<S, P> {
+impl<S, P, R, O> Mechanic for ContagionMechanic<S, P, R, O>
+where
+    S: SpreadPolicy,
+    P: ProgressionPolicy,
+    R: RecoveryPolicy,
+    O: OutcomePolicy,
+{
     type Config = ContagionConfig;
     type State = SimpleSeverity;
     type Input = ContagionInput;
@@ -102,6 +126,12 @@ impl<S: SpreadPolicy, P: ProgressionPolicy> Mechanic for ContagionMechanic<S, P>
         input: Self::Input,
         emitter: &mut impl EventEmitter<Self::Event>,
     ) {
+        // Terminal state: the dead don't progress or recover
+        if state.dead {
+            return;
+        }
+        let was_infected = state.severity > 0;
+
         // 1. Calculate effective spread rate using the SpreadPolicy
         let effective_rate = S::calculate_rate(config.base_rate, input.density);
 
@@ -124,6 +154,30 @@ impl<S: SpreadPolicy, P: ProgressionPolicy> Mechanic for ContagionMechanic<S, P>
             }
             // If severity didn't change (e.g., resisted), no event is emitted
         }
+
+        // 5. Resolve infections that were already running before this step
+        if was_infected {
+            state.infected_turns += 1;
+
+            if R::should_recover(
+                state.infected_turns,
+                state.severity,
+                config.recovery_rate,
+                input.rng,
+            ) {
+                match O::resolve(state.severity, config.lethality) {
+                    InfectionOutcome::Recovered => {
+                        state.severity = 0;
+                        state.infected_turns = 0;
+                        emitter.emit(ContagionEvent::Recovered);
+                    }
+                    InfectionOutcome::Died => {
+                        state.dead = true;
+                        emitter.emit(ContagionEvent::Died);
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -131,7 +185,8 @@ impl<S: SpreadPolicy, P: ProgressionPolicy> Mechanic for ContagionMechanic<S, P>
 mod tests {
     use super::*;
     use crate::mechanics::contagion::strategies::{
-        ExponentialSpread, LinearProgression, LinearSpread, ThresholdProgression,
+        ExponentialSpread, LethalityOutcome, LinearProgression, LinearSpread,
+        ProbabilisticRecovery, ThresholdProgression, ThresholdRecovery,
     };
 
     // Test helper: simple event collector
@@ -149,7 +204,10 @@ mod tests {
     fn test_no_infection_when_rng_too_high() {
         type TestMechanic = ContagionMechanic<LinearSpread, LinearProgression>;
 
-        let config = ContagionConfig { base_rate: 0.1 };
+        let config = ContagionConfig {
+            base_rate: 0.1,
+            ..Default::default()
+        };
         let mut state = SimpleSeverity::default();
         let input = ContagionInput {
             density: 0.5,
@@ -168,7 +226,10 @@ mod tests {
     fn test_initial_infection() {
         type TestMechanic = ContagionMechanic<LinearSpread, LinearProgression>;
 
-        let config = ContagionConfig { base_rate: 0.1 };
+        let config = ContagionConfig {
+            base_rate: 0.1,
+            ..Default::default()
+        };
         let mut state = SimpleSeverity::default();
         let input = ContagionInput {
             density: 0.5,
@@ -188,8 +249,11 @@ mod tests {
     fn test_infection_progression() {
         type TestMechanic = ContagionMechanic<LinearSpread, LinearProgression>;
 
-        let config = ContagionConfig { base_rate: 0.1 };
-        let mut state = SimpleSeverity { severity: 3 }; // Already infected
+        let config = ContagionConfig {
+            base_rate: 0.1,
+            ..Default::default()
+        };
+        let mut state = SimpleSeverity::new(3); // Already infected
         let input = ContagionInput {
             density: 1.0,
             resistance: 5,
@@ -211,8 +275,11 @@ mod tests {
     fn test_high_resistance_blocks_progression() {
         type TestMechanic = ContagionMechanic<LinearSpread, ThresholdProgression>;
 
-        let config = ContagionConfig { base_rate: 0.5 };
-        let mut state = SimpleSeverity { severity: 2 };
+        let config = ContagionConfig {
+            base_rate: 0.5,
+            ..Default::default()
+        };
+        let mut state = SimpleSeverity::new(2);
         let input = ContagionInput {
             density: 1.0,
             resistance: 20, // High resistance
@@ -231,7 +298,10 @@ mod tests {
     fn test_exponential_spread_scales_correctly() {
         type TestMechanic = ContagionMechanic<ExponentialSpread, LinearProgression>;
 
-        let config = ContagionConfig { base_rate: 0.1 };
+        let config = ContagionConfig {
+            base_rate: 0.1,
+            ..Default::default()
+        };
 
         // Low density: rate = 0.1 * 0.2^2 = 0.004
         let mut state1 = SimpleSeverity::default();
@@ -260,7 +330,10 @@ mod tests {
     fn test_multiple_steps_accumulate_severity() {
         type TestMechanic = ContagionMechanic<LinearSpread, LinearProgression>;
 
-        let config = ContagionConfig { base_rate: 1.0 }; // Always spread
+        let config = ContagionConfig {
+            base_rate: 1.0, // Always spread
+            ..Default::default()
+        };
         let mut state = SimpleSeverity::default();
         let mut emitter = TestEmitter { events: vec![] };
 
@@ -277,4 +350,157 @@ mod tests {
         assert_eq!(state.severity, 5);
         assert_eq!(emitter.events.len(), 5); // 1 Infected + 4 Progressed
     }
+
+    // Input that never spreads at base_rate 0.1 and density 0.0
+    fn idle_input(rng: f32) -> ContagionInput {
+        ContagionInput {
+            density: 0.0,
+            resistance: 0,
+            rng,
+        }
+    }
+
+    #[test]
+    fn test_default_policies_never_resolve() {
+        type TestMechanic = ContagionMechanic<LinearSpread, LinearProgression>;
+
+        let config = ContagionConfig {
+            recovery_rate: 1.0,
+            lethality: 1.0,
+            ..Default::default()
+        };
+        let mut state = SimpleSeverity::new(3);
+        let mut emitter = TestEmitter { events: vec![] };
+
+        for _ in 0..50 {
+            TestMechanic::step(&config, &mut state, idle_input(0.99), &mut emitter);
+        }
+
+        assert_eq!(state.severity, 3);
+        assert_eq!(state.infected_turns, 50);
+        assert!(emitter.events.is_empty());
+    }
+
+    #[test]
+    fn test_threshold_recovery_resets_state() {
+        type TestMechanic =
+            ContagionMechanic<LinearSpread, LinearProgression, ThresholdRecovery<3>>;
+
+        let config = ContagionConfig::default();
+        let mut state = SimpleSeverity::new(4);
+        let mut emitter = TestEmitter { events: vec![] };
+
+        for _ in 0..2 {
+            TestMechanic::step(&config, &mut state, idle_input(0.5), &mut emitter);
+        }
+        assert_eq!(state.infected_turns, 2);
+        assert!(emitter.events.is_empty());
+
+        TestMechanic::step(&config, &mut state, idle_input(0.5), &mut emitter);
+        assert_eq!(emitter.events, vec![ContagionEvent::Recovered]);
+        assert_eq!(state, SimpleSeverity::default());
+    }
+
+    #[test]
+    fn test_newly_infected_does_not_resolve_same_step() {
+        type TestMechanic =
+            ContagionMechanic<LinearSpread, LinearProgression, ThresholdRecovery<0>>;
+
+        let config = ContagionConfig {
+            base_rate: 1.0,
+            ..Default::default()
+        };
+        let mut state = SimpleSeverity::default();
+        let mut emitter = TestEmitter { events: vec![] };
+        let input = ContagionInput {
+            density: 1.0,
+            resistance: 0,
+            rng: 0.0,
+        };
+
+        TestMechanic::step(&config, &mut state, input, &mut emitter);
+        assert_eq!(emitter.events, vec![ContagionEvent::Infected]);
+
+        TestMechanic::step(&config, &mut state, input, &mut emitter);
+        assert_eq!(emitter.events.len(), 3);
+        assert_eq!(emitter.events[2], ContagionEvent::Recovered);
+    }
+
+    #[test]
+    fn test_lethal_outcome_flags_dead() {
+        type TestMechanic = ContagionMechanic<
+            LinearSpread,
+            LinearProgression,
+            ThresholdRecovery<1>,
+            LethalityOutcome<10>,
+        >;
+
+        let config = ContagionConfig {
+            lethality: 0.5,
+            ..Default::default()
+        };
+        let mut emitter = TestEmitter { events: vec![] };
+
+        // Mild case survives
+        let mut mild = SimpleSeverity::new(2);
+        TestMechanic::step(&config, &mut mild, idle_input(0.5), &mut emitter);
+        assert_eq!(emitter.events, vec![ContagionEvent::Recovered]);
+        assert!(!mild.is_infected());
+
+        // Severe case dies and keeps its severity
+        let mut severe = SimpleSeverity::new(8);
+        TestMechanic::step(&config, &mut severe, idle_input(0.5), &mut emitter);
+        assert_eq!(emitter.events[1], ContagionEvent::Died);
+        assert!(severe.is_dead());
+        assert_eq!(severe.severity, 8);
+    }
+
+    #[test]
+    fn test_dead_entities_are_skipped() {
+        type TestMechanic =
+            ContagionMechanic<LinearSpread, LinearProgression, ThresholdRecovery<1>>;
+
+        let config = ContagionConfig {
+            base_rate: 1.0,
+            ..Default::default()
+        };
+        let mut state = SimpleSeverity {
+            severity: 5,
+            infected_turns: 4,
+            dead: true,
+        };
+        let before = state;
+        let mut emitter = TestEmitter { events: vec![] };
+        let input = ContagionInput {
+            density: 1.0,
+            resistance: 0,
+            rng: 0.0,
+        };
+
+        TestMechanic::step(&config, &mut state, input, &mut emitter);
+
+        assert_eq!(state, before);
+        assert!(emitter.events.is_empty());
+    }
+
+    #[test]
+    fn test_probabilistic_recovery_uses_recovery_rate() {
+        type TestMechanic =
+            ContagionMechanic<LinearSpread, LinearProgression, ProbabilisticRecovery>;
+
+        let config = ContagionConfig {
+            recovery_rate: 0.2,
+            ..Default::default()
+        };
+        let mut emitter = TestEmitter { events: vec![] };
+
+        let mut unlucky = SimpleSeverity::new(2);
+        TestMechanic::step(&config, &mut unlucky, idle_input(0.5), &mut emitter);
+        assert!(unlucky.is_infected());
+
+        let mut lucky = SimpleSeverity::new(2);
+        TestMechanic::step(&config, &mut lucky, idle_input(0.9), &mut emitter);
+        assert!(!lucky.is_infected());
+        assert_eq!(emitter.events, vec![ContagionEvent::Recovered]);
+    }
 }
@@ -6,9 +6,11 @@
 //! # Architecture
 //!
 //! The contagion mechanic follows a **Policy-Based Design**:
-//! - The core `ContagionMechanic<S, P>` is generic over two policies
+//! - The core `ContagionMechanic<S, P, R, O>` is generic over four policies
 //! - `S: SpreadPolicy` determines how infection spreads
 //! - `P: ProgressionPolicy` determines how infection progresses
+//! - `R: RecoveryPolicy` determines when an infection resolves
+//! - `O: OutcomePolicy` determines whether a resolved infection recovers or kills
 //! - All logic is resolved at compile time via static dispatch
 //!
 //! # Quick Start
@@ -21,7 +23,7 @@
 //! type ZombieVirus = ContagionMechanic<ExponentialSpread, ThresholdProgression>;
 //!
 //! // Create configuration
-//! let config = ContagionConfig { base_rate: 0.1 };
+//! let config = ContagionConfig { base_rate: 0.1, ..Default::default() };
 //! let mut state = SimpleSeverity::default();
 //!
 //! // Prepare input for this frame
@@ -46,9 +48,9 @@
 //!
 //! ## Core Modules
 //! - `types`: Basic data structures (Config, Input, Event, SimpleSeverity)
-//! - `policies`: Basic policy traits (SpreadPolicy, ProgressionPolicy)
+//! - `policies`: Basic policy traits (SpreadPolicy, ProgressionPolicy, RecoveryPolicy, OutcomePolicy)
 //! - `strategies`: Concrete implementations of basic policies
-//! - `mechanic`: The basic `ContagionMechanic<S, P>` implementation
+//! - `mechanic`: The basic `ContagionMechanic<S, P, R, O>` implementation
 //! - `presets`: Ready-to-use type aliases for common configurations
 //!
 //! ## Advanced Modules
@@ -78,8 +80,10 @@ pub mod prelude;
 
 // Re-export core types for convenience
 pub use mechanic::ContagionMechanic;
-pub use policies::{ProgressionPolicy, SpreadPolicy};
-pub use types::{ContagionConfig, ContagionEvent, ContagionInput, SimpleSeverity};
+pub use policies::{OutcomePolicy, ProgressionPolicy, RecoveryPolicy, SpreadPolicy};
+pub use types::{
+    ContagionConfig, ContagionEvent, ContagionInput, InfectionOutcome, SimpleSeverity,
+};
 
 // Re-export advanced types
 pub use content::{ContagionContent, DiseaseLevel, TrendDirection};
@@ -12,6 +12,8 @@
 //! - Implementations are Zero-Sized Types (ZST) for optimal performance
 //! - Different policies can be combined to create custom mechanics
 
+use super::types::InfectionOutcome;
+
 /// Policy for calculating infection spread rate.
 ///
 /// This policy determines how quickly an infection spreads based on
@@ -124,3 +126,77 @@ pub trait ProgressionPolicy {
     /// - Ensure the return value is valid for your game logic
     fn update_severity(current: u32, resistance: u32) -> u32;
 }
+
+/// Policy for deciding when an infection resolves.
+///
+/// This policy is consulted once per step for entities that were already
+/// infected before the step. When it returns `true`, the infection ends and
+/// the `OutcomePolicy` decides whether the entity recovers or dies.
+///
+/// # Examples
+///
+/// ```
+/// use issun_core::mechanics::contagion::policies::RecoveryPolicy;
+///
+/// // Every infection lasts exactly a week
+/// pub struct WeeklyRecovery;
+///
+/// impl RecoveryPolicy for WeeklyRecovery {
+///     fn should_recover(infected_turns: u32, _severity: u32, _recovery_rate: f32, _rng: f32) -> bool {
+///         infected_turns >= 7
+///     }
+/// }
+///
+/// assert!(!WeeklyRecovery::should_recover(6, 3, 0.0, 0.5));
+/// assert!(WeeklyRecovery::should_recover(7, 3, 0.0, 0.5));
+/// ```
+pub trait RecoveryPolicy {
+    /// Decide whether the infection resolves this step.
+    ///
+    /// # Parameters
+    ///
+    /// - `infected_turns`: Number of turns the infection has lasted so far
+    /// - `severity`: Current severity level
+    /// - `recovery_rate`: Per-turn recovery chance from config (0.0 to 1.0)
+    /// - `rng`: The frame's random value (0.0 to 1.0)
+    ///
+    /// # Returns
+    ///
+    /// `true` if the infection ends this step.
+    fn should_recover(infected_turns: u32, severity: u32, recovery_rate: f32, rng: f32) -> bool;
+}
+
+/// Policy for deciding how a resolved infection ends.
+///
+/// Called once when the `RecoveryPolicy` resolves an infection.
+///
+/// # Examples
+///
+/// ```
+/// use issun_core::mechanics::contagion::policies::OutcomePolicy;
+/// use issun_core::mechanics::contagion::InfectionOutcome;
+///
+/// // Any infection that was allowed to reach severity 10 is fatal
+/// pub struct HardCap;
+///
+/// impl OutcomePolicy for HardCap {
+///     fn resolve(severity: u32, _lethality: f32) -> InfectionOutcome {
+///         if severity >= 10 {
+///             InfectionOutcome::Died
+///         } else {
+///             InfectionOutcome::Recovered
+///         }
+///     }
+/// }
+///
+/// assert_eq!(HardCap::resolve(12, 0.0), InfectionOutcome::Died);
+/// ```
+pub trait OutcomePolicy {
+    /// Decide the terminal state of an infection.
+    ///
+    /// # Parameters
+    ///
+    /// - `severity`: Severity at the time the infection resolves
+    /// - `lethality`: Lethality from config (0.0 to 1.0)
+    fn resolve(severity: u32, lethality: f32) -> InfectionOutcome;
+}
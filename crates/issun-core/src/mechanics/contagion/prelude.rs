@@ -10,11 +10,13 @@
 //!
 //! // Now you have access to:
 //! // Basic types:
-//! // - ContagionMechanic<S, P>
-//! // - ContagionConfig, SimpleSeverity, ContagionInput, ContagionEvent
-//! // - SpreadPolicy, ProgressionPolicy
+//! // - ContagionMechanic<S, P, R, O>
+//! // - ContagionConfig, SimpleSeverity, ContagionInput, ContagionEvent, InfectionOutcome
+//! // - SpreadPolicy, ProgressionPolicy, RecoveryPolicy, OutcomePolicy
 //! // - LinearSpread, ExponentialSpread
 //! // - LinearProgression, ThresholdProgression
+//! // - NoRecovery, ThresholdRecovery, ProbabilisticRecovery
+//! // - SurvivalOutcome, LethalityOutcome
 //! // - Presets: SimpleVirus, ExplosiveVirus, ZombieVirus, etc.
 //! //
 //! // Advanced types:
@@ -30,12 +32,15 @@
 
 // Basic types
 pub use super::mechanic::ContagionMechanic;
-pub use super::policies::{ProgressionPolicy, SpreadPolicy};
+pub use super::policies::{OutcomePolicy, ProgressionPolicy, RecoveryPolicy, SpreadPolicy};
 pub use super::presets::*;
 pub use super::strategies::{
-    ExponentialSpread, LinearProgression, LinearSpread, ThresholdProgression,
+    ExponentialSpread, LethalityOutcome, LinearProgression, LinearSpread, NoRecovery,
+    ProbabilisticRecovery, SurvivalOutcome, ThresholdProgression, ThresholdRecovery,
+};
+pub use super::types::{
+    ContagionConfig, ContagionEvent, ContagionInput, InfectionOutcome, SimpleSeverity,
 };
-pub use super::types::{ContagionConfig, ContagionEvent, ContagionInput, SimpleSeverity};
 
 // Advanced types
 pub use super::content::{ContagionContent, DiseaseLevel, TrendDirection};
@@ -3,9 +3,16 @@
 //! This module provides convenient type aliases that combine commonly-used
 //! strategies into ready-to-use mechanics. These presets demonstrate best
 //! practices and serve as starting points for custom implementations.
+//!
+//! Presets that don't mention recovery use `NoRecovery`: severity only climbs.
+//! Presets with a lethal outcome read `ContagionConfig::lethality`; with the
+//! default lethality of 0.0 every resolved infection still ends in recovery.
 
 use super::mechanic::ContagionMechanic;
-use super::strategies::{ExponentialSpread, LinearProgression, LinearSpread, ThresholdProgression};
+use super::strategies::{
+    ExponentialSpread, LethalityOutcome, LinearProgression, LinearSpread, ProbabilisticRecovery,
+    ThresholdProgression, ThresholdRecovery,
+};
 
 // ============================================================================
 // Basic Presets (using default generics)
@@ -33,7 +40,7 @@ use super::strategies::{ExponentialSpread, LinearProgression, LinearSpread, Thre
 /// # impl EventEmitter<issun_core::mechanics::contagion::ContagionEvent> for TestEmitter {
 /// #     fn emit(&mut self, _event: issun_core::mechanics::contagion::ContagionEvent) {}
 /// # }
-/// let config = ContagionConfig { base_rate: 0.1 };
+/// let config = ContagionConfig { base_rate: 0.1, ..Default::default() };
 /// let mut state = SimpleSeverity::default();
 /// let input = ContagionInput { density: 0.5, resistance: 5, rng: 0.03 };
 /// let mut emitter = TestEmitter;
@@ -74,7 +81,7 @@ pub type SimpleVirus = ContagionMechanic;
 /// # impl EventEmitter<issun_core::mechanics::contagion::ContagionEvent> for TestEmitter {
 /// #     fn emit(&mut self, _event: issun_core::mechanics::contagion::ContagionEvent) {}
 /// # }
-/// let config = ContagionConfig { base_rate: 0.15 };
+/// let config = ContagionConfig { base_rate: 0.15, ..Default::default() };
 /// let mut state = SimpleSeverity::default();
 /// // High density = exponentially higher spread rate
 /// let input = ContagionInput { density: 0.8, resistance: 5, rng: 0.05 };
@@ -88,19 +95,21 @@ pub type ExplosiveVirus = ContagionMechanic<ExponentialSpread>;
 ///
 /// - Spread: `LinearSpread` (proportional to density)
 /// - Progression: `LinearProgression<10>` (resistance threshold: 10)
+/// - Recovery: `ThresholdRecovery<7>` (infections last 7 turns)
 ///
 /// # Characteristics
 ///
 /// - Predictable spread rate
 /// - Linear scaling with population density
 /// - Uses `LinearProgression` (>= threshold blocks progression)
+/// - Everyone recovers after a week
 ///
 /// # Use Cases
 ///
 /// - Contact-based diseases (flu, cold)
 /// - When you want predictable, proportional behavior
 /// - Early-game or tutorial mechanics
-pub type SteadyVirus = ContagionMechanic<LinearSpread, LinearProgression>;
+pub type SteadyVirus = ContagionMechanic<LinearSpread, LinearProgression, ThresholdRecovery<7>>;
 
 // ============================================================================
 // Resistance-focused Presets
@@ -128,11 +137,13 @@ pub type VirulentVirus = ContagionMechanic<LinearSpread, ThresholdProgression<5>
 ///
 /// - Spread: `LinearSpread` (proportional to density)
 /// - Progression: `ThresholdProgression<20>` (high resistance threshold)
+/// - Recovery: `ThresholdRecovery<3>` (infections last 3 turns)
 ///
 /// # Characteristics
 ///
 /// - Even moderate resistance (>20) can block progression
 /// - Most entities can resist with basic stats
+/// - Infections clear up quickly
 /// - Good for early-game or tutorial content
 ///
 /// # Use Cases
@@ -140,7 +151,8 @@ pub type VirulentVirus = ContagionMechanic<LinearSpread, ThresholdProgression<5>
 /// - Easy difficulty modes
 /// - Tutorial mechanics
 /// - Background flavor without serious threat
-pub type WeakVirus = ContagionMechanic<LinearSpread, ThresholdProgression<20>>;
+pub type WeakVirus =
+    ContagionMechanic<LinearSpread, ThresholdProgression<20>, ThresholdRecovery<3>>;
 
 // ============================================================================
 // Specialized Presets
@@ -150,12 +162,15 @@ pub type WeakVirus = ContagionMechanic<LinearSpread, ThresholdProgression<20>>;
 ///
 /// - Spread: `ExponentialSpread` (pandemic-style)
 /// - Progression: `ThresholdProgression<5>` (low threshold)
+/// - Recovery: `ThresholdRecovery<5>` (the bite resolves after 5 turns)
+/// - Outcome: `LethalityOutcome<5>` (severe cases die; set `lethality` in config)
 ///
 /// # Characteristics
 ///
 /// - Spreads exponentially in crowded areas
 /// - Very hard to resist (only resistance >5 works)
 /// - Creates classic zombie outbreak scenarios
+/// - After 5 turns the victim either shakes it off or dies (and turns)
 ///
 /// # Use Cases
 ///
@@ -174,7 +189,7 @@ pub type WeakVirus = ContagionMechanic<LinearSpread, ThresholdProgression<20>>;
 /// # impl EventEmitter<issun_core::mechanics::contagion::ContagionEvent> for TestEmitter {
 /// #     fn emit(&mut self, _event: issun_core::mechanics::contagion::ContagionEvent) {}
 /// # }
-/// let config = ContagionConfig { base_rate: 0.2 };
+/// let config = ContagionConfig { base_rate: 0.2, lethality: 0.8, ..Default::default() };
 /// let mut state = SimpleSeverity::default();
 /// // Dense crowd + low resistance = high infection chance
 /// let input = ContagionInput { density: 0.9, resistance: 3, rng: 0.05 };
@@ -183,22 +198,35 @@ pub type WeakVirus = ContagionMechanic<LinearSpread, ThresholdProgression<20>>;
 /// ZombieVirus::step(&config, &mut state, input, &mut emitter);
 /// // Likely to infect due to exponential spread and low resistance threshold
 /// ```
-pub type ZombieVirus = ContagionMechanic<ExponentialSpread, ThresholdProgression<5>>;
+pub type ZombieVirus = ContagionMechanic<
+    ExponentialSpread,
+    ThresholdProgression<5>,
+    ThresholdRecovery<5>,
+    LethalityOutcome<5>,
+>;
 
 /// Plague: moderate spread but very persistent.
 ///
 /// - Spread: `LinearSpread` (proportional to density)
 /// - Progression: `LinearProgression<5>` (low threshold for progression)
+/// - Recovery: `ProbabilisticRecovery` (rolls `recovery_rate` each turn)
+/// - Outcome: `LethalityOutcome<10>` (long, severe cases die)
 ///
 /// # Characteristics
 ///
 /// - Linear spread rate
 /// - Very hard to stop progression once infected
 /// - Encourages prevention over cure
+/// - Unpredictable course: the longer it lingers, the deadlier it gets
 ///
 /// # Use Cases
 ///
 /// - Medieval plague simulations
 /// - When you want infection to be serious and persistent
 /// - Scenarios where prevention is key
-pub type PlagueVirus = ContagionMechanic<LinearSpread, LinearProgression<5>>;
+pub type PlagueVirus = ContagionMechanic<
+    LinearSpread,
+    LinearProgression<5>,
+    ProbabilisticRecovery,
+    LethalityOutcome<10>,
+>;
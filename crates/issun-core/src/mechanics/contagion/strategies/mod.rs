@@ -3,9 +3,13 @@
 //! This module contains concrete implementations of the various policy traits
 //! used by the contagion mechanic. Strategies are organized by the policy they implement.
 
+pub mod outcome;
 pub mod progression;
+pub mod recovery;
 pub mod spread;
 
 // Re-export common strategies for convenience
+pub use outcome::{LethalityOutcome, SurvivalOutcome};
 pub use progression::{LinearProgression, ThresholdProgression};
+pub use recovery::{NoRecovery, ProbabilisticRecovery, ThresholdRecovery};
 pub use spread::{ExponentialSpread, LinearSpread};
@@ -0,0 +1,98 @@
+//! Lethality outcome strategy.
+//!
+//! Severe infections kill; how severe is "too severe" depends on the
+//! configured lethality.
+
+use crate::mechanics::contagion::policies::OutcomePolicy;
+use crate::mechanics::contagion::types::InfectionOutcome;
+
+/// Outcome policy that kills when severity is high relative to lethality.
+///
+/// Severity is normalized against `MAX_SEVERITY` (capped at 1.0). An
+/// infection is fatal when the normalized severity reaches `1.0 - lethality`:
+/// - `lethality = 0.0`: nobody dies
+/// - `lethality = 0.3`: infections resolving at 70% of `MAX_SEVERITY` or above are fatal
+/// - `lethality = 1.0`: every infection is fatal
+///
+/// # Type Parameters
+///
+/// - `MAX_SEVERITY`: Severity considered maximal (const generic, default: 10)
+///
+/// # Examples
+///
+/// ```
+/// use issun_core::mechanics::contagion::policies::OutcomePolicy;
+/// use issun_core::mechanics::contagion::strategies::LethalityOutcome;
+/// use issun_core::mechanics::contagion::InfectionOutcome;
+///
+/// assert_eq!(LethalityOutcome::<10>::resolve(8, 0.3), InfectionOutcome::Died);
+/// assert_eq!(LethalityOutcome::<10>::resolve(6, 0.3), InfectionOutcome::Recovered);
+/// assert_eq!(LethalityOutcome::<10>::resolve(10, 0.0), InfectionOutcome::Recovered);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LethalityOutcome<const MAX_SEVERITY: u32 = 10>;
+
+impl<const MAX_SEVERITY: u32> OutcomePolicy for LethalityOutcome<MAX_SEVERITY> {
+    fn resolve(severity: u32, lethality: f32) -> InfectionOutcome {
+        if lethality <= 0.0 {
+            return InfectionOutcome::Recovered;
+        }
+
+        let normalized = (severity as f32 / MAX_SEVERITY.max(1) as f32).min(1.0);
+        if normalized >= 1.0 - lethality.min(1.0) {
+            InfectionOutcome::Died
+        } else {
+            InfectionOutcome::Recovered
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_lethality_never_kills() {
+        assert_eq!(
+            LethalityOutcome::<10>::resolve(1000, 0.0),
+            InfectionOutcome::Recovered
+        );
+    }
+
+    #[test]
+    fn test_full_lethality_always_kills() {
+        assert_eq!(
+            LethalityOutcome::<10>::resolve(0, 1.0),
+            InfectionOutcome::Died
+        );
+        assert_eq!(
+            LethalityOutcome::<10>::resolve(1, 1.0),
+            InfectionOutcome::Died
+        );
+    }
+
+    #[test]
+    fn test_threshold_boundary() {
+        // lethality 0.5 with MAX_SEVERITY 10: severity 5 and above is fatal
+        assert_eq!(
+            LethalityOutcome::<10>::resolve(4, 0.5),
+            InfectionOutcome::Recovered
+        );
+        assert_eq!(
+            LethalityOutcome::<10>::resolve(5, 0.5),
+            InfectionOutcome::Died
+        );
+    }
+
+    #[test]
+    fn test_severity_above_max_is_capped() {
+        assert_eq!(
+            LethalityOutcome::<4>::resolve(100, 0.1),
+            InfectionOutcome::Died
+        );
+        assert_eq!(
+            LethalityOutcome::<4>::resolve(3, 0.1),
+            InfectionOutcome::Recovered
+        );
+    }
+}
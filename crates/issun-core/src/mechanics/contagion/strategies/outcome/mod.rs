@@ -0,0 +1,9 @@
+//! Outcome strategy implementations.
+//!
+//! This module provides concrete implementations of the `OutcomePolicy` trait.
+
+mod lethality;
+mod survival;
+
+pub use lethality::LethalityOutcome;
+pub use survival::SurvivalOutcome;
@@ -0,0 +1,44 @@
+//! Survival outcome strategy.
+
+use crate::mechanics::contagion::policies::OutcomePolicy;
+use crate::mechanics::contagion::types::InfectionOutcome;
+
+/// Outcome policy under which every infection ends in recovery.
+///
+/// This is the default outcome policy of `ContagionMechanic`; `lethality`
+/// is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use issun_core::mechanics::contagion::policies::OutcomePolicy;
+/// use issun_core::mechanics::contagion::strategies::SurvivalOutcome;
+/// use issun_core::mechanics::contagion::InfectionOutcome;
+///
+/// assert_eq!(SurvivalOutcome::resolve(100, 1.0), InfectionOutcome::Recovered);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SurvivalOutcome;
+
+impl OutcomePolicy for SurvivalOutcome {
+    fn resolve(_severity: u32, _lethality: f32) -> InfectionOutcome {
+        InfectionOutcome::Recovered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_always_recovers() {
+        assert_eq!(
+            SurvivalOutcome::resolve(0, 0.0),
+            InfectionOutcome::Recovered
+        );
+        assert_eq!(
+            SurvivalOutcome::resolve(u32::MAX, 1.0),
+            InfectionOutcome::Recovered
+        );
+    }
+}
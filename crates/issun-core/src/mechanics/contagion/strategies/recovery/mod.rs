@@ -0,0 +1,11 @@
+//! Recovery strategy implementations.
+//!
+//! This module provides concrete implementations of the `RecoveryPolicy` trait.
+
+mod none;
+mod probabilistic;
+mod threshold;
+
+pub use none::NoRecovery;
+pub use probabilistic::ProbabilisticRecovery;
+pub use threshold::ThresholdRecovery;
@@ -0,0 +1,42 @@
+//! No-recovery strategy.
+
+use crate::mechanics::contagion::policies::RecoveryPolicy;
+
+/// Recovery policy under which infections never resolve.
+///
+/// This is the default recovery policy of `ContagionMechanic`, matching the
+/// original behavior where severity only climbs.
+///
+/// # Examples
+///
+/// ```
+/// use issun_core::mechanics::contagion::policies::RecoveryPolicy;
+/// use issun_core::mechanics::contagion::strategies::NoRecovery;
+///
+/// assert!(!NoRecovery::should_recover(1000, 1, 1.0, 0.99));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoRecovery;
+
+impl RecoveryPolicy for NoRecovery {
+    fn should_recover(
+        _infected_turns: u32,
+        _severity: u32,
+        _recovery_rate: f32,
+        _rng: f32,
+    ) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_never_recovers() {
+        for turns in [0, 1, 10, u32::MAX] {
+            assert!(!NoRecovery::should_recover(turns, 5, 1.0, 0.999));
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Probabilistic recovery strategy.
+//!
+//! Every turn an infected entity has a chance to recover.
+
+use crate::mechanics::contagion::policies::RecoveryPolicy;
+
+/// Recovery policy that rolls against `ContagionConfig::recovery_rate` each turn.
+///
+/// The roll uses the upper tail of the frame's random value
+/// (`rng >= 1.0 - recovery_rate`), while spread uses the lower tail
+/// (`rng < rate`). As long as the two rates add up to at most 1.0, a single
+/// frame never both progresses and resolves the infection.
+///
+/// # Use Cases
+///
+/// - Diseases with an unpredictable course
+/// - Plagues where some victims linger much longer than others
+///
+/// # Examples
+///
+/// ```
+/// use issun_core::mechanics::contagion::policies::RecoveryPolicy;
+/// use issun_core::mechanics::contagion::strategies::ProbabilisticRecovery;
+///
+/// // 20% recovery chance: rolls of 0.8 and above recover
+/// assert!(ProbabilisticRecovery::should_recover(1, 3, 0.2, 0.85));
+/// assert!(!ProbabilisticRecovery::should_recover(1, 3, 0.2, 0.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProbabilisticRecovery;
+
+impl RecoveryPolicy for ProbabilisticRecovery {
+    fn should_recover(_infected_turns: u32, _severity: u32, recovery_rate: f32, rng: f32) -> bool {
+        recovery_rate > 0.0 && rng >= 1.0 - recovery_rate.min(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upper_tail_recovers() {
+        assert!(ProbabilisticRecovery::should_recover(0, 1, 0.25, 0.75));
+        assert!(ProbabilisticRecovery::should_recover(0, 1, 0.25, 0.99));
+        assert!(!ProbabilisticRecovery::should_recover(0, 1, 0.25, 0.74));
+        assert!(!ProbabilisticRecovery::should_recover(0, 1, 0.25, 0.0));
+    }
+
+    #[test]
+    fn test_zero_rate_never_recovers() {
+        assert!(!ProbabilisticRecovery::should_recover(100, 1, 0.0, 0.999));
+    }
+
+    #[test]
+    fn test_full_rate_always_recovers() {
+        assert!(ProbabilisticRecovery::should_recover(0, 1, 1.0, 0.0));
+        assert!(ProbabilisticRecovery::should_recover(0, 1, 2.0, 0.0));
+    }
+}
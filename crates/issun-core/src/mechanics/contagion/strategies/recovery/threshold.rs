@@ -0,0 +1,56 @@
+//! Threshold recovery strategy.
+//!
+//! Infections run a fixed course and resolve after a number of turns.
+
+use crate::mechanics::contagion::policies::RecoveryPolicy;
+
+/// Recovery policy that resolves infections after a fixed number of turns.
+///
+/// - If `infected_turns >= TURNS`: the infection resolves
+/// - Otherwise: the infection continues
+///
+/// # Type Parameters
+///
+/// - `TURNS`: Length of the infection in turns (const generic, default: 5)
+///
+/// # Use Cases
+///
+/// - Diseases with a predictable course (flu, cold)
+/// - Zombie bites that turn the victim after a fixed time
+///
+/// # Examples
+///
+/// ```
+/// use issun_core::mechanics::contagion::policies::RecoveryPolicy;
+/// use issun_core::mechanics::contagion::strategies::ThresholdRecovery;
+///
+/// assert!(!ThresholdRecovery::<3>::should_recover(2, 4, 0.0, 0.5));
+/// assert!(ThresholdRecovery::<3>::should_recover(3, 4, 0.0, 0.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThresholdRecovery<const TURNS: u32 = 5>;
+
+impl<const TURNS: u32> RecoveryPolicy for ThresholdRecovery<TURNS> {
+    fn should_recover(infected_turns: u32, _severity: u32, _recovery_rate: f32, _rng: f32) -> bool {
+        infected_turns >= TURNS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolves_at_threshold() {
+        assert!(!ThresholdRecovery::<5>::should_recover(0, 1, 0.0, 0.0));
+        assert!(!ThresholdRecovery::<5>::should_recover(4, 1, 0.0, 0.0));
+        assert!(ThresholdRecovery::<5>::should_recover(5, 1, 0.0, 0.0));
+        assert!(ThresholdRecovery::<5>::should_recover(6, 1, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_ignores_rng_and_rate() {
+        assert!(!ThresholdRecovery::<2>::should_recover(1, 1, 1.0, 0.999));
+        assert!(ThresholdRecovery::<2>::should_recover(2, 1, 0.0, 0.0));
+    }
+}
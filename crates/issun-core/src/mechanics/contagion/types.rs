@@ -5,6 +5,7 @@
 //! - `InfectionState`: Per-entity mutable state
 //! - `ContagionInput`: Per-frame input data
 //! - `ContagionEvent`: Events emitted by the mechanic
+//! - `InfectionOutcome`: How an infection ends
 
 /// Static configuration for a contagion mechanic.
 ///
@@ -18,6 +19,7 @@
 ///
 /// let config = ContagionConfig {
 ///     base_rate: 0.05, // 5% base infection chance per frame
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -27,11 +29,26 @@ pub struct ContagionConfig {
     /// This represents the baseline probability of infection spread
     /// before any modifiers (density, resistance, etc.) are applied.
     pub base_rate: f32,
+
+    /// Per-turn recovery chance (0.0 to 1.0).
+    ///
+    /// Only used by recovery policies that roll for recovery
+    /// (e.g. `ProbabilisticRecovery`).
+    pub recovery_rate: f32,
+
+    /// How deadly the infection is when it resolves (0.0 to 1.0).
+    ///
+    /// Interpreted by the `OutcomePolicy`. 0.0 means nobody dies.
+    pub lethality: f32,
 }
 
 impl Default for ContagionConfig {
     fn default() -> Self {
-        Self { base_rate: 0.1 }
+        Self {
+            base_rate: 0.1,
+            recovery_rate: 0.1,
+            lethality: 0.0,
+        }
     }
 }
 
@@ -51,7 +68,8 @@ impl Default for ContagionConfig {
 /// assert_eq!(healthy.severity, 0);
 ///
 /// // Infected entity
-/// let infected = SimpleSeverity { severity: 5 };
+/// let infected = SimpleSeverity::new(5);
+/// assert!(infected.is_infected());
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct SimpleSeverity {
@@ -60,6 +78,36 @@ pub struct SimpleSeverity {
     /// - 0: Healthy (not infected)
     /// - 1+: Infected (higher values indicate more severe infection)
     pub severity: u32,
+
+    /// Number of turns the current infection has lasted.
+    ///
+    /// Reset to 0 when the entity recovers.
+    pub infected_turns: u32,
+
+    /// Whether the infection killed this entity.
+    ///
+    /// Dead entities are ignored by the mechanic.
+    pub dead: bool,
+}
+
+impl SimpleSeverity {
+    /// Create a living entity with the given severity.
+    pub fn new(severity: u32) -> Self {
+        Self {
+            severity,
+            ..Default::default()
+        }
+    }
+
+    /// Whether the entity is currently infected (and alive).
+    pub fn is_infected(&self) -> bool {
+        self.severity > 0 && !self.dead
+    }
+
+    /// Whether the infection killed this entity.
+    pub fn is_dead(&self) -> bool {
+        self.dead
+    }
 }
 
 /// Per-frame input for contagion calculation.
@@ -117,6 +165,8 @@ pub struct ContagionInput {
 ///     ContagionEvent::Progressed { new_severity } => {
 ///         println!("Infection progressed to severity {}", new_severity);
 ///     }
+///     ContagionEvent::Recovered => println!("Entity recovered"),
+///     ContagionEvent::Died => println!("Entity died"),
 /// }
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -134,4 +184,23 @@ pub enum ContagionEvent {
         /// The new severity level after progression.
         new_severity: u32,
     },
+
+    /// Infection resolved and the entity is healthy again.
+    ///
+    /// Severity and infection duration are reset to 0.
+    Recovered,
+
+    /// Infection resolved fatally.
+    ///
+    /// The state is flagged as dead and no longer updated.
+    Died,
+}
+
+/// Terminal outcome of an infection, decided by an `OutcomePolicy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InfectionOutcome {
+    /// The entity survives and becomes healthy.
+    Recovered,
+    /// The entity dies.
+    Died,
 }
@@ -31,7 +31,8 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, Attribute, Data, DeriveInput, Field, Fields, Type};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Field, Fields, Token, Type};
 
 /// Derive macro for auto-generating entity component getters
 pub fn derive_issun_entity_impl(input: TokenStream) -> TokenStream {
@@ -40,21 +41,20 @@ pub fn derive_issun_entity_impl(input: TokenStream) -> TokenStream {
     let struct_name = &input.ident;
 
     // Find the field marked with #[primary] attribute
-    let primary_field = find_primary_field(&input);
-
-    if primary_field.is_none() {
+    let Some(primary_field) = find_primary_field(&input) else {
         return syn::Error::new_spanned(
-            &input,
+            &input.ident,
             "IssunEntity requires a field marked with #[primary]",
         )
         .to_compile_error()
         .into();
-    }
-
-    let primary_field = primary_field.unwrap();
+    };
 
     // Parse #[components(...)] attribute to get component types
-    let component_types = parse_components_attr(&input.attrs);
+    let component_types = match parse_components_attr(&input.attrs) {
+        Ok(types) => types,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     let getter_methods: Vec<_> = component_types
         .iter()
@@ -117,43 +117,14 @@ fn has_primary_attr(field: &Field) -> bool {
 }
 
 /// Parse #[components(...)] attribute to extract component types
-fn parse_components_attr(attrs: &[Attribute]) -> Vec<Type> {
-    for attr in attrs {
-        if !attr.path().is_ident("components") {
-            continue;
-        }
-
-        // Parse the content inside #[components(...)]
-        let result = attr.parse_args_with(|input: syn::parse::ParseStream| {
-            let mut types = Vec::new();
-
-            // Parse comma-separated list of types
-            loop {
-                if input.is_empty() {
-                    break;
-                }
-
-                let ty: Type = input.parse()?;
-                types.push(ty);
-
-                // Check for comma
-                if input.peek(syn::Token![,]) {
-                    let _: syn::Token![,] = input.parse()?;
-                } else {
-                    break;
-                }
-            }
-
-            Ok(types)
-        });
-
-        if let Ok(types) = result {
-            return types;
-        }
+fn parse_components_attr(attrs: &[Attribute]) -> syn::Result<Vec<Type>> {
+    if let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("components")) {
+        let types = attr.parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated)?;
+        return Ok(types.into_iter().collect());
     }
 
     // Default: ActionPoints if no #[components(...)] attribute
-    vec![syn::parse_str("ActionPoints").unwrap()]
+    Ok(vec![syn::parse_quote!(ActionPoints)])
 }
 
 /// Extract the simple type name from a Type (for method naming)
@@ -12,28 +12,43 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Expr, Token};
 
-/// Proc macro for simplified EventLog::add() calls
-pub fn log_impl(input: TokenStream) -> TokenStream {
-    let input_str = input.to_string();
-
-    // Parse the macro input: first argument is the world/app, rest is format string + args
-    let parts: Vec<&str> = input_str.splitn(2, ',').collect();
+/// `app, "format", args...`
+struct LogInput {
+    world: Expr,
+    message_and_args: proc_macro2::TokenStream,
+}
 
-    if parts.len() < 2 {
-        return syn::Error::new(
-            proc_macro2::Span::call_site(),
-            "log! macro requires at least 2 arguments: log!(app, \"message\", ...args)",
-        )
-        .to_compile_error()
-        .into();
+impl Parse for LogInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let world = input.parse()?;
+        if input.is_empty() {
+            return Err(input.error(
+                "log! macro requires at least 2 arguments: log!(app, \"message\", ...args)",
+            ));
+        }
+        input.parse::<Token![,]>()?;
+        if input.is_empty() {
+            return Err(input.error("log! expects a format string after the app"));
+        }
+        Ok(Self {
+            world,
+            message_and_args: input.parse()?,
+        })
     }
+}
 
-    let world_expr: proc_macro2::TokenStream = parts[0].trim().parse().unwrap();
-    let message_and_args: proc_macro2::TokenStream = parts[1].trim().parse().unwrap();
+/// Proc macro for simplified EventLog::add() calls
+pub fn log_impl(input: TokenStream) -> TokenStream {
+    let LogInput {
+        world,
+        message_and_args,
+    } = parse_macro_input!(input as LogInput);
 
     let expanded = quote! {
-        #world_expr.world_mut().resource_mut::<EventLog>()
+        #world.world_mut().resource_mut::<EventLog>()
             .add(format!(#message_and_args))
     };
 
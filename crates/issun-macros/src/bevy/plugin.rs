@@ -64,20 +64,102 @@
 //! - `#[plugin(components = [Type1, Type2, ...])]` - Auto-register component types for Reflection (optional, Phase 2.2)
 //! - `#[plugin(startup_systems = [fn1, fn2, ...])]` - Auto-register systems to Startup schedule (optional, Phase 2.2)
 //! - `#[plugin(update_systems = [fn1, fn2, ...])]` - Auto-register systems to Update schedule (optional, Phase 2.2)
+//! - `#[plugin(systems(update(IssunSet::Logic) = "fn1, fn2", startup(chain) = "fn3"))]` - Auto-register systems with schedule, set and ordering (optional, see below)
 //! - `#[plugin(requires = [Plugin1, Plugin2, ...])]` - Declare issun-bevy plugin dependencies (optional, Phase 2.3)
 //! - `#[plugin(requires_bevy = [BevyPlugin1, ...])]` - Declare Bevy standard plugin dependencies (optional, Phase 2.3)
 //! - `#[plugin(auto_require_core = true)]` - Auto-require IssunCorePlugin (default: true, Phase 2.3)
+//! - `#[plugin(test_harness = true)]` - Generate headless test helpers (optional, see below)
 //!
 //! ## Field-level attributes
 //!
 //! - `#[config]` - Mark as config resource (insert_resource + builder method)
 //! - `#[resource]` - Mark as resource (insert_resource + builder method)
 //! - `#[skip]` - Skip this field (no auto-generation)
+//!
+//! # Systems
+//!
+//! Each entry of `systems(...)` is a schedule (`startup`, `pre_startup`,
+//! `post_startup`, `first`, `pre_update`, `update`, `post_update`, `last`,
+//! `fixed_update`) assigned a comma-separated list of system paths. Optional
+//! parentheses place the group in a system set and/or `chain` it:
+//!
+//! ```ignore
+//! #[derive(Default, IssunBevyPlugin)]
+//! #[plugin(systems(
+//!     startup(chain) = "setup_world, setup_player",
+//!     update(IssunSet::Logic) = "tick_contagion, apply_damage",
+//!     update(IssunSet::PostLogic, chain) = "check_deaths, cleanup",
+//! ))]
+//! pub struct PandemicPlugin;
+//! ```
+//!
+//! generates
+//!
+//! ```ignore
+//! app.add_systems(Startup, (setup_world, setup_player).chain());
+//! app.add_systems(Update, (tick_contagion, apply_damage).in_set(::issun_bevy::IssunSet::Logic));
+//! app.add_systems(Update, (check_deaths, cleanup).chain().in_set(::issun_bevy::IssunSet::PostLogic));
+//! ```
+//!
+//! `IssunSet::...` variants are checked when the macro runs; other set
+//! paths are used as written.
+//!
+//! # Test Harness
+//!
+//! `test_harness = true` adds helpers for headless tests, compiled only under
+//! `cfg(any(test, feature = "test-harness"))` so they never ship in release
+//! builds. Outside the plugin crate's own `#[cfg(test)]` code (integration
+//! tests, examples), declare and enable a `test-harness` feature:
+//!
+//! - `MyPlugin::test_app(self) -> App` - `MinimalPlugins`, `IssunCorePlugin`,
+//!   the `requires` plugins (via `Default`) and this plugin, updated once;
+//!   debug builds then run `assert_resources`
+//! - `MyPlugin::assert_resources(&app)` - panics naming the first
+//!   `#[resource]`/`#[config]` type missing from the world, `#[skip]`ped
+//!   ones included, so a resource the plugin forgot to insert is caught
+//! - `MyPlugin::send_and_update(&mut app, message)` - writes a message and
+//!   runs one update
+//!
+//! ```ignore
+//! #[derive(Default, IssunBevyPlugin)]
+//! #[plugin(test_harness = true, messages = [TurnAdvanced])]
+//! pub struct TurnPlugin {
+//!     #[resource]
+//!     pub clock: TurnClock,
+//! }
+//!
+//! #[test]
+//! fn test_turn_advances() {
+//!     let mut app = TurnPlugin::default().test_app();
+//!     TurnPlugin::send_and_update(&mut app, TurnAdvanced);
+//!     assert_eq!(app.world().resource::<TurnClock>().turn, 1);
+//! }
+//! ```
 
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::parse::Parse;
-use syn::{parse_macro_input, Attribute, Data, DeriveInput, Field, Fields, Lit, Path, Token, Type};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Field, Fields, Ident, Lit, LitStr, Path,
+    Token, Type,
+};
+
+/// Variants of `issun_bevy::IssunSet`
+const ISSUN_SETS: [&str; 4] = ["Input", "Logic", "PostLogic", "Visual"];
+
+/// Schedules accepted in `systems(...)`, with their Bevy names
+const SCHEDULES: [(&str, &str); 9] = [
+    ("pre_startup", "PreStartup"),
+    ("startup", "Startup"),
+    ("post_startup", "PostStartup"),
+    ("first", "First"),
+    ("pre_update", "PreUpdate"),
+    ("update", "Update"),
+    ("post_update", "PostUpdate"),
+    ("last", "Last"),
+    ("fixed_update", "FixedUpdate"),
+];
 
 /// Plugin configuration options
 #[derive(Default)]
@@ -91,6 +173,113 @@ struct PluginConfig {
     requires: Vec<Type>,        // Phase 2.3 - issun-bevy plugin dependencies
     requires_bevy: Vec<Type>,   // Phase 2.3 - Bevy standard plugin dependencies
     auto_require_core: bool,    // Phase 2.3 - Auto-require IssunCorePlugin (default: true)
+    system_groups: Vec<SystemGroup>,
+    test_harness: bool,
+    errors: Vec<syn::Error>,
+}
+
+/// One `schedule(set, chain) = "fn1, fn2"` entry of `systems(...)`
+struct SystemGroup {
+    schedule: Ident,
+    set: Option<proc_macro2::TokenStream>,
+    chain: bool,
+    systems: Vec<Path>,
+}
+
+impl SystemGroup {
+    /// Parse one entry; `meta.path` is the schedule
+    fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Self> {
+        let name = meta.path.require_ident()?;
+        let Some((_, schedule)) = SCHEDULES.iter().find(|(key, _)| name == key) else {
+            let expected: Vec<&str> = SCHEDULES.iter().map(|(key, _)| *key).collect();
+            return Err(syn::Error::new_spanned(
+                name,
+                format!(
+                    "unknown schedule `{}`; expected one of: {}",
+                    name,
+                    expected.join(", ")
+                ),
+            ));
+        };
+        let schedule = Ident::new(schedule, name.span());
+
+        let mut set = None;
+        let mut chain = false;
+        if meta.input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            for option in Punctuated::<Path, Token![,]>::parse_terminated(&content)? {
+                if option.is_ident("chain") {
+                    chain = true;
+                } else if set.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        option,
+                        "a system group can only be in one set",
+                    ));
+                } else {
+                    set = Some(resolve_set(&option)?);
+                }
+            }
+        }
+
+        let list: LitStr = meta.value()?.parse()?;
+        let systems: Vec<Path> = list
+            .parse_with(Punctuated::<Path, Token![,]>::parse_terminated)?
+            .into_iter()
+            .collect();
+        if systems.is_empty() {
+            return Err(syn::Error::new_spanned(
+                list,
+                "expected at least one system",
+            ));
+        }
+
+        Ok(Self {
+            schedule,
+            set,
+            chain,
+            systems,
+        })
+    }
+
+    fn registration(&self) -> proc_macro2::TokenStream {
+        let schedule = &self.schedule;
+        let systems = &self.systems;
+        // Fully qualified, so the plugin's module needs no prelude import
+        let mut group = quote! { (#(#systems,)*) };
+        if self.chain {
+            group = quote! { ::bevy::prelude::IntoScheduleConfigs::chain(#group) };
+        }
+        if let Some(set) = &self.set {
+            group = quote! { ::bevy::prelude::IntoScheduleConfigs::in_set(#group, #set) };
+        }
+        quote! {
+            app.add_systems(::bevy::prelude::#schedule, #group);
+        }
+    }
+}
+
+/// `IssunSet::Variant` (checked against [`ISSUN_SETS`]) or a user set as written
+fn resolve_set(path: &Path) -> syn::Result<proc_macro2::TokenStream> {
+    let segments: Vec<&syn::PathSegment> = path.segments.iter().collect();
+    let [.., enum_segment, variant] = segments.as_slice() else {
+        return Ok(quote! { #path });
+    };
+    if enum_segment.ident != "IssunSet" {
+        return Ok(quote! { #path });
+    }
+    if !ISSUN_SETS.iter().any(|set| variant.ident == set) {
+        return Err(syn::Error::new_spanned(
+            &variant.ident,
+            format!(
+                "unknown IssunSet variant `{}`; expected one of: {}",
+                variant.ident,
+                ISSUN_SETS.join(", ")
+            ),
+        ));
+    }
+    let variant = &variant.ident;
+    Ok(quote! { ::issun_bevy::IssunSet::#variant })
 }
 
 /// Helper struct for parsing messages = [Type1, Type2, ...]
@@ -132,7 +321,13 @@ pub fn derive_issun_bevy_plugin_impl(input: TokenStream) -> TokenStream {
     let struct_name = &input.ident;
 
     // Parse #[plugin(...)] attributes
-    let plugin_config = parse_plugin_attrs(&input.attrs, struct_name);
+    let mut plugin_config = parse_plugin_attrs(&input.attrs, struct_name);
+    if let Some(mut error) = plugin_config.errors.pop() {
+        for other in plugin_config.errors.drain(..) {
+            error.combine(other);
+        }
+        return error.to_compile_error().into();
+    }
 
     // Parse fields
     let fields = match &input.data {
@@ -157,8 +352,14 @@ pub fn derive_issun_bevy_plugin_impl(input: TokenStream) -> TokenStream {
     // Collect fields marked with #[config] or #[resource]
     let mut config_fields = Vec::new();
     let mut resource_fields = Vec::new();
+    // Checked by the test harness even when #[skip]ped
+    let mut expected_resources = Vec::new();
 
     for field in fields.iter() {
+        if has_config_attr(field) || has_resource_attr(field) {
+            expected_resources.push(&field.ty);
+        }
+
         if has_skip_attr(field) {
             continue;
         }
@@ -247,6 +448,12 @@ pub fn derive_issun_bevy_plugin_impl(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
+    // Generate grouped systems
+    let system_groups = plugin_config
+        .system_groups
+        .iter()
+        .map(SystemGroup::registration);
+
     // Generate dependency checks (Phase 2.3)
     let dependency_checks = generate_dependency_checks(&plugin_config, struct_name);
 
@@ -289,6 +496,12 @@ pub fn derive_issun_bevy_plugin_impl(input: TokenStream) -> TokenStream {
         })
         .collect();
 
+    let test_harness = if plugin_config.test_harness {
+        generate_test_harness(&plugin_config, struct_name, &expected_resources)
+    } else {
+        quote! {}
+    };
+
     let expanded = quote! {
         // Marker resource definition (Phase 2.3)
         #marker_registration
@@ -315,6 +528,8 @@ pub fn derive_issun_bevy_plugin_impl(input: TokenStream) -> TokenStream {
 
                 #update_systems
 
+                #(#system_groups)*
+
                 // Extension point for user customization
                 // Add your systems and additional setup below:
                 // app.add_systems(Update, your_system);
@@ -324,6 +539,8 @@ pub fn derive_issun_bevy_plugin_impl(input: TokenStream) -> TokenStream {
         impl #struct_name {
             #(#builder_methods)*
         }
+
+        #test_harness
     };
 
     TokenStream::from(expanded)
@@ -341,6 +558,9 @@ fn parse_plugin_attrs(attrs: &[Attribute], struct_name: &syn::Ident) -> PluginCo
         requires: Vec::new(),
         requires_bevy: Vec::new(),
         auto_require_core: true, // Default: true
+        system_groups: Vec::new(),
+        test_harness: false,
+        errors: Vec::new(),
     };
 
     for attr in attrs {
@@ -389,6 +609,15 @@ fn parse_plugin_attrs(attrs: &[Attribute], struct_name: &syn::Ident) -> PluginCo
                         config.update_systems = systems.paths;
                     }
                 }
+            } else if meta.path.is_ident("systems") {
+                // Parse systems(update(IssunSet::Logic) = "fn1, fn2", ...)
+                let result = meta.parse_nested_meta(|group| {
+                    config.system_groups.push(SystemGroup::parse(&group)?);
+                    Ok(())
+                });
+                if let Err(error) = result {
+                    config.errors.push(error);
+                }
             } else if meta.path.is_ident("requires") {
                 // Parse requires = [Plugin1, Plugin2, ...]
                 if let Ok(value) = meta.value() {
@@ -410,6 +639,13 @@ fn parse_plugin_attrs(attrs: &[Attribute], struct_name: &syn::Ident) -> PluginCo
                         config.auto_require_core = b.value();
                     }
                 }
+            } else if meta.path.is_ident("test_harness") {
+                // Parse test_harness = true/false
+                if let Ok(value) = meta.value() {
+                    if let Ok(Lit::Bool(b)) = value.parse::<Lit>() {
+                        config.test_harness = b.value();
+                    }
+                }
             }
             Ok(())
         });
@@ -517,6 +753,66 @@ fn generate_dependency_checks(
     }
 }
 
+/// Generate the `test_harness = true` helpers
+fn generate_test_harness(
+    config: &PluginConfig,
+    struct_name: &syn::Ident,
+    expected_resources: &[&Type],
+) -> proc_macro2::TokenStream {
+    let struct_name_str = struct_name.to_string();
+    let requires = &config.requires;
+    let resource_checks = expected_resources.iter().map(|ty| {
+        let type_str = quote!(#ty).to_string();
+        quote! {
+            assert!(
+                app.world().contains_resource::<#ty>(),
+                "{} did not register resource {}",
+                #struct_name_str,
+                #type_str
+            );
+        }
+    });
+
+    quote! {
+        // Crates that only use the harness from unit tests need not declare the feature
+        #[allow(unexpected_cfgs)]
+        const _: () = {
+            #[cfg(any(test, feature = "test-harness"))]
+            impl #struct_name {
+                /// Headless app with `MinimalPlugins`, `IssunCorePlugin` and this plugin, updated once
+                ///
+                /// Debug builds check the plugin's resources with [`Self::assert_resources`].
+                pub fn test_app(self) -> ::bevy::prelude::App {
+                    let mut app = ::bevy::prelude::App::new();
+                    app.add_plugins(::bevy::prelude::MinimalPlugins)
+                        .add_plugins(::issun_bevy::IssunCorePlugin);
+                    #(app.add_plugins(<#requires as ::core::default::Default>::default());)*
+                    app.add_plugins(self);
+                    app.update();
+                    if cfg!(debug_assertions) {
+                        Self::assert_resources(&app);
+                    }
+                    app
+                }
+
+                /// Panic unless every `#[resource]`/`#[config]` type is in `app`'s world
+                pub fn assert_resources(app: &::bevy::prelude::App) {
+                    #(#resource_checks)*
+                }
+
+                /// Write `message`, then run one update
+                pub fn send_and_update<M: ::bevy::prelude::Message>(
+                    app: &mut ::bevy::prelude::App,
+                    message: M,
+                ) {
+                    app.world_mut().write_message(message);
+                    app.update();
+                }
+            }
+        };
+    }
+}
+
 /// Convert PascalCase to snake_case
 fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
@@ -528,3 +824,57 @@ fn to_snake_case(s: &str) -> String {
     }
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_errors(attr: &str) -> Vec<String> {
+        let input: DeriveInput = syn::parse_str(&format!("{} struct GamePlugin;", attr)).unwrap();
+        let config = parse_plugin_attrs(&input.attrs, &input.ident);
+        config
+            .errors
+            .iter()
+            .map(|error| error.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_systems_groups_parse() {
+        let input: DeriveInput = syn::parse_str(
+            r#"#[plugin(systems(startup(chain) = "a, b", update(MySet::Ai) = "c"))] struct P;"#,
+        )
+        .unwrap();
+        let config = parse_plugin_attrs(&input.attrs, &input.ident);
+        assert!(config.errors.is_empty());
+        let groups = &config.system_groups;
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].schedule, "Startup");
+        assert!(groups[0].chain && groups[0].set.is_none());
+        assert_eq!(groups[0].systems.len(), 2);
+        assert_eq!(groups[1].schedule, "Update");
+        // Sets outside IssunSet are used as written
+        assert_eq!(
+            groups[1].set.as_ref().unwrap().to_string(),
+            quote!(MySet::Ai).to_string()
+        );
+    }
+
+    #[test]
+    fn test_systems_errors_name_the_problem() {
+        assert_eq!(
+            config_errors(r#"#[plugin(systems(update(IssunSet::Logik) = "a"))]"#),
+            ["unknown IssunSet variant `Logik`; expected one of: Input, Logic, PostLogic, Visual"]
+        );
+        assert!(config_errors(r#"#[plugin(systems(tick = "a"))]"#)[0]
+            .starts_with("unknown schedule `tick`"));
+        assert_eq!(
+            config_errors(r#"#[plugin(systems(update = "a,, b"))]"#),
+            ["expected identifier"]
+        );
+        assert_eq!(
+            config_errors(r#"#[plugin(systems(update = ""))]"#),
+            ["expected at least one system"]
+        );
+    }
+}
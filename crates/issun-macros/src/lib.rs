@@ -6,6 +6,7 @@
 //! - `#[derive(Service)]` - Auto-implement Service trait
 //! - `#[derive(System)]` - Auto-implement System trait
 //! - `#[derive(Asset)]` - Auto-generate asset loading
+//! - `#[hook_impl]` - Implement a plugin Hook trait, keeping unlisted defaults
 //!
 //! Bevy-specific macros:
 //! - `#[derive(IssunEntity)]` - Auto-generate component getters for any entity-holding Resource
@@ -18,24 +19,31 @@ mod bevy;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use proc_macro_crate::{crate_name, FoundCrate};
-use quote::{format_ident, quote, ToTokens};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use std::collections::HashMap;
 use std::mem;
 use syn::{
     braced,
     parse::{Parse, ParseStream},
-    parse_macro_input,
+    parse_macro_input, parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
-    Attribute, Block, Data, DeriveInput, Fields, FnArg, Ident, ImplItem, ImplItemFn, ItemFn,
-    ItemImpl, Lit, LitStr, Meta, Pat, PatIdent, PatType, Path, Result, Signature, Stmt, Token,
-    Type, Visibility,
+    visit::{self, Visit},
+    visit_mut::{self, VisitMut},
+    Attribute, Block, Data, DeriveInput, Expr, Fields, FnArg, GenericArgument, Ident, ImplItem,
+    ImplItemFn, ItemFn, ItemImpl, LitStr, Meta, Pat, PatIdent, PatType, Path, PathArguments,
+    Result, Signature, Stmt, Token, Type, Visibility,
 };
 
 /// Helper function to get the issun crate identifier
 /// Returns `crate` if called from within issun crate, otherwise `::issun`
+///
+/// Doctests of issun are separate crates, so they get `::issun` too.
 fn get_crate_name() -> proc_macro2::TokenStream {
     match crate_name("issun") {
+        Ok(FoundCrate::Itself) if std::env::var_os("UNSTABLE_RUSTDOC_TEST_PATH").is_some() => {
+            quote!(::issun)
+        }
         Ok(FoundCrate::Itself) => quote!(crate),
         Ok(FoundCrate::Name(name)) => {
             let ident = format_ident!("{}", name);
@@ -61,136 +69,142 @@ fn get_crate_name() -> proc_macro2::TokenStream {
 /// - Scene trait implementation
 /// - GameState struct (or custom name via `name` attribute)
 /// - GameState::new() with initial scene and context
+///
+/// The generated state can be customised:
+/// - `ctx_params = "cfg: &GameConfig"` - parameters of `GameState::new`
+/// - `ctx_init = "GameContext::from_config(cfg)?"` - context expression
+///   (default: `GameContext::new()`)
+/// - `ctx_error = "ConfigError"` - `new` returns `Result<Self, ConfigError>`
+/// - `extra_fields = "frame: u64, #[serde(skip)] paused: bool"` - additional
+///   fields, initialised with `Default::default()`
+/// - `no_state` - skip the GameState struct and write your own
+///
+/// `Default` is only implemented when `new` takes no parameters and cannot
+/// fail.
+///
+/// The lifecycle hooks do nothing unless listed in `delegate`:
+/// - `delegate = "on_enter, on_update, on_exit"` - each listed hook calls the
+///   inherent `async fn` of the same name on the variant data
+///   (`data.on_update(services, systems, resources).await`)
+/// - unit variants skip delegated hooks, or call associated functions of
+///   the type named by `#[scene(delegate_to = "TitleScreen")]`
 #[proc_macro_derive(Scene, attributes(scene))]
 pub fn derive_scene(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    match expand_scene(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
 
+fn expand_scene(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
     let scene_name = &input.ident;
 
     // Parse #[scene(...)] attributes
-    let scene_attrs = parse_scene_attributes(&input.attrs);
+    let scene_attrs = parse_scene_attributes(&input.attrs)?;
 
     let crate_name = get_crate_name();
 
-    // Generate Scene trait implementation
-    let scene_impl = quote! {
-        #[::async_trait::async_trait]
-        impl #crate_name::scene::Scene for #scene_name {
-            async fn on_enter(
-                &mut self,
-                _services: &#crate_name::context::ServiceContext,
-                _systems: &mut #crate_name::context::SystemContext,
-                _resources: &mut #crate_name::context::ResourceContext,
-            ) {
-                // Default implementation: do nothing
-            }
-
-            async fn on_update(
-                &mut self,
-                _services: &#crate_name::context::ServiceContext,
-                _systems: &mut #crate_name::context::SystemContext,
-                _resources: &mut #crate_name::context::ResourceContext,
-            ) -> #crate_name::scene::SceneTransition<Self> {
-                // Default implementation: stay in current scene
-                #crate_name::scene::SceneTransition::Stay
-            }
-
-            async fn on_exit(
-                &mut self,
-                _services: &#crate_name::context::ServiceContext,
-                _systems: &mut #crate_name::context::SystemContext,
-                _resources: &mut #crate_name::context::ResourceContext,
-            ) {
-                // Default implementation: do nothing
-            }
-
-            async fn on_suspend(
-                &mut self,
-                _services: &#crate_name::context::ServiceContext,
-                _systems: &mut #crate_name::context::SystemContext,
-                _resources: &mut #crate_name::context::ResourceContext,
-            ) {
-            }
-
-            async fn on_resume(
-                &mut self,
-                _services: &#crate_name::context::ServiceContext,
-                _systems: &mut #crate_name::context::SystemContext,
-                _resources: &mut #crate_name::context::ResourceContext,
-            ) {
+    // Enums report the active variant in diagnostics
+    let scene_name_impl = if let Data::Enum(data_enum) = &input.data {
+        let arms = data_enum.variants.iter().map(|variant| {
+            let variant_name = &variant.ident;
+            let label = format!("{}::{}", scene_name, variant_name);
+            quote! { Self::#variant_name { .. } => #label.to_string(), }
+        });
+        quote! {
+            fn scene_name(&self) -> String {
+                match *self {
+                    #(#arms)*
+                }
             }
         }
+    } else {
+        quote! {}
     };
 
-    // Generate GameState struct if context and initial are specified
-    let game_state_gen =
-        if let (Some(context), Some(initial)) = (&scene_attrs.context, &scene_attrs.initial) {
-            let state_name = scene_attrs
-                .name
-                .as_ref()
-                .map(|n| format_ident!("{}", n))
-                .unwrap_or_else(|| format_ident!("GameState"));
-
-            let context_ident = format_ident!("{}", context);
-            let initial_expr: proc_macro2::TokenStream = initial.parse().unwrap();
+    // Lifecycle hooks, dispatched to variant data when listed in `delegate`
+    let (delegated, delegate_arms) = parse_delegate_hooks(input, &scene_attrs)?;
+    let hooks = SCENE_HOOKS.iter().map(|hook| {
+        let hook_ident = format_ident!("{}", hook);
+        let returns_transition = *hook == "on_update";
+        let return_ty = if returns_transition {
+            quote! { -> #crate_name::scene::SceneTransition<Self> }
+        } else {
+            quote! {}
+        };
 
+        if delegated.iter().any(|name| name == hook) {
+            let skip = if returns_transition {
+                quote! { #crate_name::scene::SceneTransition::Stay }
+            } else {
+                quote! { () }
+            };
+            let arms = delegate_arms.iter().map(|arm| arm.expand(hook, &skip));
             quote! {
-                /// Auto-generated game state combining scene and context
-                #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-                pub struct #state_name {
-                    pub scene: #scene_name,
-                    pub ctx: #context_ident,
-                    pub should_quit: bool,
-                }
-
-                impl #state_name {
-                    pub fn new() -> Self {
-                        Self {
-                            scene: #scene_name::#initial_expr,
-                            ctx: #context_ident::new(),
-                            should_quit: false,
-                        }
-                    }
-                }
-
-                impl Default for #state_name {
-                    fn default() -> Self {
-                        Self::new()
+                async fn #hook_ident(
+                    &mut self,
+                    services: &#crate_name::context::ServiceContext,
+                    systems: &mut #crate_name::context::SystemContext,
+                    resources: &mut #crate_name::context::ResourceContext,
+                ) #return_ty {
+                    match self {
+                        #(#arms)*
                     }
                 }
             }
         } else {
-            quote! {}
-        };
+            let body = if returns_transition {
+                quote! { #crate_name::scene::SceneTransition::Stay }
+            } else {
+                quote! {}
+            };
+            quote! {
+                async fn #hook_ident(
+                    &mut self,
+                    _services: &#crate_name::context::ServiceContext,
+                    _systems: &mut #crate_name::context::SystemContext,
+                    _resources: &mut #crate_name::context::ResourceContext,
+                ) #return_ty {
+                    #body
+                }
+            }
+        }
+    });
+
+    // Generate Scene trait implementation
+    let scene_impl = quote! {
+        #[::async_trait::async_trait]
+        impl #crate_name::scene::Scene for #scene_name {
+            #(#hooks)*
+
+            #scene_name_impl
+        }
+    };
+
+    let game_state_gen = generate_game_state(input, &scene_attrs)?;
 
     // Generate handler dispatcher if handler_params is specified
     let handler_gen = if let Some(params) = &scene_attrs.handler_params {
         let handler_name = scene_attrs
             .handler
             .as_ref()
-            .map(|h| format_ident!("{}", h))
+            .map(|h| format_ident!("{}", h.value()))
             .unwrap_or_else(|| format_ident!("handle_input"));
 
-        let params_tokens: proc_macro2::TokenStream = params.parse().unwrap();
-
-        // Extract parameter names from "ctx: &mut GameContext, input: InputEvent" -> ["ctx", "input"]
-        let param_names = extract_param_names(params);
+        // "ctx: &mut GameContext, input: InputEvent" -> [ctx: .., input: ..] and [ctx, input]
+        let params_args = params.parse_with(Punctuated::<FnArg, Token![,]>::parse_terminated)?;
+        let param_names = handler_param_names(&params_args)?;
+        let params_args = params_args.iter();
 
         // Default return type based on scene name (kept for potential future use)
-        let _return_type = scene_attrs
-            .handler_return
-            .as_ref()
-            .map(|r| r.parse::<proc_macro2::TokenStream>().unwrap())
-            .unwrap_or_else(|| {
-                quote! { (#scene_name, ::issun::scene::SceneTransition<#scene_name>) }
-            });
+        let _return_type = match &scene_attrs.handler_return {
+            Some(r) => r.parse::<Type>()?,
+            None => parse_quote! { (#scene_name, ::issun::scene::SceneTransition<#scene_name>) },
+        };
 
         // Extract variants from enum
-        let variants = if let Data::Enum(data_enum) = &input.data {
-            &data_enum.variants
-        } else {
-            panic!("Scene derive macro only works on enums");
-        };
+        let variants = require_enum(input, "handler_params")?;
 
         // Generate match arms for each variant
         let match_arms = variants.iter().map(|variant| {
@@ -214,7 +228,7 @@ pub fn derive_scene(input: TokenStream) -> TokenStream {
                 services: &#crate_name::context::ServiceContext,
                 systems: &mut #crate_name::context::SystemContext,
                 resources: &mut #crate_name::context::ResourceContext,
-                #params_tokens,
+                #(#params_args),*
             ) -> ::issun::scene::SceneTransition<#scene_name> {
                 match scene {
                     #(#match_arms),*
@@ -225,100 +239,417 @@ pub fn derive_scene(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
-    let expanded = quote! {
+    Ok(quote! {
         #scene_impl
         #game_state_gen
         #handler_gen
+    })
+}
+
+/// Variants of an enum scene, or an error pointing at the struct/union
+/// keyword naming the attribute that needs an enum
+fn require_enum<'a>(
+    input: &'a DeriveInput,
+    attribute: &str,
+) -> Result<&'a Punctuated<syn::Variant, Token![,]>> {
+    let span = match &input.data {
+        Data::Enum(data_enum) => return Ok(&data_enum.variants),
+        Data::Struct(data_struct) => data_struct.struct_token.span(),
+        Data::Union(data_union) => data_union.union_token.span(),
     };
+    Err(syn::Error::new(
+        span,
+        format!(
+            "#[scene({} = ...)] only works on enums: #[derive(Scene)] on a struct \
+             implements Scene but cannot dispatch to variants",
+            attribute
+        ),
+    ))
+}
 
-    TokenStream::from(expanded)
+/// `initial = "Title"` / `"Title(TitleData::new())"` / `"Title { data }"`,
+/// naming a variant of the scene enum
+fn parse_initial_variant(
+    initial: &LitStr,
+    scene_name: &Ident,
+    variants: &Punctuated<syn::Variant, Token![,]>,
+) -> Result<syn::Expr> {
+    let expr: syn::Expr = initial.parse().map_err(|err| {
+        syn::Error::new(
+            initial.span(),
+            format!("#[scene(initial = ...)] is not an expression: {}", err),
+        )
+    })?;
+    let path = match &expr {
+        syn::Expr::Path(expr_path) => Some(&expr_path.path),
+        syn::Expr::Call(call) => match call.func.as_ref() {
+            syn::Expr::Path(expr_path) => Some(&expr_path.path),
+            _ => None,
+        },
+        syn::Expr::Struct(expr_struct) => Some(&expr_struct.path),
+        _ => None,
+    };
+    let variant = path.and_then(Path::get_ident).ok_or_else(|| {
+        syn::Error::new(
+            initial.span(),
+            "#[scene(initial = ...)] expects a variant, e.g. \"Title\" or \"Title(TitleData::new())\"",
+        )
+    })?;
+    if !variants.iter().any(|v| v.ident == *variant) {
+        return Err(syn::Error::new(
+            initial.span(),
+            format!("`{}` has no variant `{}`", scene_name, variant),
+        ));
+    }
+    Ok(expr)
 }
 
-/// Parse #[scene(...)] attributes
-struct SceneAttributes {
-    context: Option<String>,
-    initial: Option<String>,
-    name: Option<String>,
-    handler: Option<String>,
-    handler_params: Option<String>,
-    handler_return: Option<String>,
-}
-
-fn parse_scene_attributes(attrs: &[syn::Attribute]) -> SceneAttributes {
-    let mut context = None;
-    let mut initial = None;
-    let mut name = None;
-    let mut handler = None;
-    let mut handler_params = None;
-    let mut handler_return = None;
+/// Scene lifecycle hooks that `delegate` can forward to variant data
+const SCENE_HOOKS: [&str; 5] = [
+    "on_enter",
+    "on_update",
+    "on_exit",
+    "on_suspend",
+    "on_resume",
+];
+
+/// How one variant handles a delegated hook
+enum DelegateArm {
+    /// `Variant(data)` calls `data.hook(..)`
+    Data { variant: Ident, ty: Type },
+    /// Unit variant with `#[scene(delegate_to = "Type")]` calls `Type::hook(..)`
+    Associated { variant: Ident, target: Type },
+    /// Unit variant without a target: the hook does nothing
+    Skip { variant: Ident },
+}
 
+impl DelegateArm {
+    fn expand(&self, hook: &str, skip: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        // Calls carry the span of the payload type, so a missing or
+        // mismatched method is reported on the variant that needs it
+        match self {
+            Self::Data { variant, ty } => {
+                let hook = Ident::new(hook, ty.span());
+                let call = quote_spanned! {ty.span()=>
+                    data.#hook(services, systems, resources).await
+                };
+                quote! { Self::#variant(data) => #call, }
+            }
+            Self::Associated { variant, target } => {
+                let hook = Ident::new(hook, target.span());
+                let call = quote_spanned! {target.span()=>
+                    <#target>::#hook(services, systems, resources).await
+                };
+                quote! { Self::#variant => #call, }
+            }
+            Self::Skip { variant } => quote! { Self::#variant => #skip, },
+        }
+    }
+}
+
+/// Hooks listed in `#[scene(delegate = "...")]` and how each variant
+/// handles them
+fn parse_delegate_hooks(
+    input: &DeriveInput,
+    attrs: &SceneAttributes,
+) -> Result<(Vec<String>, Vec<DelegateArm>)> {
+    let Some(delegate) = &attrs.delegate else {
+        return Ok((Vec::new(), Vec::new()));
+    };
+    let variants = require_enum(input, "delegate")?;
+
+    let mut hooks: Vec<String> = Vec::new();
+    for hook in delegate.value().split(',').map(str::trim) {
+        if hook.is_empty() {
+            continue;
+        }
+        if !SCENE_HOOKS.contains(&hook) {
+            return Err(syn::Error::new(
+                delegate.span(),
+                format!(
+                    "#[scene(delegate = ...)] unknown hook `{}`, expected one of: {}",
+                    hook,
+                    SCENE_HOOKS.join(", ")
+                ),
+            ));
+        }
+        if !hooks.iter().any(|existing| existing == hook) {
+            hooks.push(hook.to_string());
+        }
+    }
+
+    let mut arms = Vec::new();
+    for variant in variants {
+        let target = parse_delegate_target(&variant.attrs)?;
+        let variant_name = variant.ident.clone();
+        let arm = match (&variant.fields, target) {
+            (Fields::Unnamed(fields), None) if fields.unnamed.len() == 1 => DelegateArm::Data {
+                variant: variant_name,
+                ty: fields.unnamed[0].ty.clone(),
+            },
+            (Fields::Unit, Some(target)) => DelegateArm::Associated {
+                variant: variant_name,
+                target,
+            },
+            (Fields::Unit, None) => DelegateArm::Skip {
+                variant: variant_name,
+            },
+            (_, Some(target)) => {
+                return Err(syn::Error::new(
+                    target.span(),
+                    "#[scene(delegate_to = ...)] only works on unit variants",
+                ))
+            }
+            (fields, None) => {
+                return Err(syn::Error::new(
+                    fields.span(),
+                    "#[scene(delegate = ...)] needs unit variants or variants with exactly \
+                     one unnamed field holding the scene data",
+                ))
+            }
+        };
+        arms.push(arm);
+    }
+
+    Ok((hooks, arms))
+}
+
+/// `#[scene(delegate_to = "Type")]` on a variant
+fn parse_delegate_target(attrs: &[syn::Attribute]) -> Result<Option<Type>> {
+    let mut target = None;
     for attr in attrs {
-        if attr.path().is_ident("scene") {
-            let _ = attr.parse_nested_meta(|meta| {
-                if meta.path.is_ident("context") {
-                    if let Ok(value) = meta.value() {
-                        if let Ok(Lit::Str(s)) = value.parse::<Lit>() {
-                            context = Some(s.value());
-                        }
-                    }
-                } else if meta.path.is_ident("initial") {
-                    if let Ok(value) = meta.value() {
-                        if let Ok(Lit::Str(s)) = value.parse::<Lit>() {
-                            initial = Some(s.value());
-                        }
-                    }
-                } else if meta.path.is_ident("name") {
-                    if let Ok(value) = meta.value() {
-                        if let Ok(Lit::Str(s)) = value.parse::<Lit>() {
-                            name = Some(s.value());
-                        }
-                    }
-                } else if meta.path.is_ident("handler") {
-                    if let Ok(value) = meta.value() {
-                        if let Ok(Lit::Str(s)) = value.parse::<Lit>() {
-                            handler = Some(s.value());
-                        }
-                    }
-                } else if meta.path.is_ident("handler_params") {
-                    if let Ok(value) = meta.value() {
-                        if let Ok(Lit::Str(s)) = value.parse::<Lit>() {
-                            handler_params = Some(s.value());
-                        }
-                    }
-                } else if meta.path.is_ident("handler_return") {
-                    if let Ok(value) = meta.value() {
-                        if let Ok(Lit::Str(s)) = value.parse::<Lit>() {
-                            handler_return = Some(s.value());
-                        }
-                    }
-                }
+        if !attr.path().is_ident("scene") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("delegate_to") {
+                let lit: LitStr = meta.value()?.parse()?;
+                target = Some(lit.parse::<Type>()?);
                 Ok(())
-            });
+            } else {
+                Err(meta.error("unknown scene variant attribute, expected `delegate_to`"))
+            }
+        })?;
+    }
+    Ok(target)
+}
+
+/// Generate the GameState struct if context and initial are specified
+fn generate_game_state(
+    input: &DeriveInput,
+    attrs: &SceneAttributes,
+) -> Result<proc_macro2::TokenStream> {
+    let customisations = [
+        ("ctx_init", attrs.ctx_init.as_ref()),
+        ("ctx_params", attrs.ctx_params.as_ref()),
+        ("ctx_error", attrs.ctx_error.as_ref()),
+        ("extra_fields", attrs.extra_fields.as_ref()),
+    ];
+
+    let (context, initial) = match (&attrs.context, &attrs.initial) {
+        (Some(context), Some(initial)) if attrs.no_state.is_none() => (context, initial),
+        _ => {
+            if let Some((key, lit)) = customisations
+                .iter()
+                .find_map(|(key, lit)| lit.map(|lit| (key, lit)))
+            {
+                let reason = if attrs.no_state.is_some() {
+                    "conflicts with `no_state`"
+                } else {
+                    "requires both `context` and `initial`"
+                };
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!("#[scene({} = ...)] {}", key, reason),
+                ));
+            }
+            return Ok(quote! {});
+        }
+    };
+    let variants = require_enum(input, "initial")?;
+
+    let scene_name = &input.ident;
+    let state_name = match &attrs.name {
+        Some(name) => format_ident!("{}", name.value(), span = name.span()),
+        None => format_ident!("GameState"),
+    };
+
+    let context_ty: Type = context.parse().map_err(|err| {
+        syn::Error::new(
+            context.span(),
+            format!("#[scene(context = ...)] is not a type: {}", err),
+        )
+    })?;
+    let initial_expr = parse_initial_variant(initial, scene_name, variants)?;
+    let ctx_init = match &attrs.ctx_init {
+        Some(init) => {
+            let expr: syn::Expr = init.parse().map_err(|err| {
+                syn::Error::new(
+                    init.span(),
+                    format!("#[scene(ctx_init = ...)] is not an expression: {}", err),
+                )
+            })?;
+            quote! { #expr }
+        }
+        None => quote! { <#context_ty>::new() },
+    };
+    let ctx_params = match &attrs.ctx_params {
+        Some(params) => params.parse_with(Punctuated::<FnArg, Token![,]>::parse_terminated)?,
+        None => Punctuated::new(),
+    };
+    let ctx_error = match &attrs.ctx_error {
+        Some(error) => Some(error.parse::<Type>()?),
+        None => None,
+    };
+    let mut extra_fields = match &attrs.extra_fields {
+        Some(fields) => fields.parse_with(|input: ParseStream| {
+            Punctuated::<syn::Field, Token![,]>::parse_terminated_with(
+                input,
+                syn::Field::parse_named,
+            )
+        })?,
+        None => Punctuated::new(),
+    };
+    for field in extra_fields.iter_mut() {
+        if matches!(field.vis, Visibility::Inherited) {
+            field.vis = syn::parse_quote!(pub);
         }
     }
+    let extra_names = extra_fields.iter().map(|field| &field.ident);
 
-    SceneAttributes {
-        context,
-        initial,
-        name,
-        handler,
-        handler_params,
-        handler_return,
+    let body = quote! {
+        Self {
+            scene: #scene_name::#initial_expr,
+            ctx: #ctx_init,
+            should_quit: false,
+            #(#extra_names: ::core::default::Default::default(),)*
+        }
+    };
+    let new_fn = match &ctx_error {
+        Some(error) => quote! {
+            pub fn new(#ctx_params) -> ::core::result::Result<Self, #error> {
+                Ok(#body)
+            }
+        },
+        None => quote! {
+            pub fn new(#ctx_params) -> Self {
+                #body
+            }
+        },
+    };
+    let default_impl = if ctx_params.is_empty() && ctx_error.is_none() {
+        quote! {
+            impl Default for #state_name {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        /// Auto-generated game state combining scene and context
+        #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+        pub struct #state_name {
+            pub scene: #scene_name,
+            pub ctx: #context_ty,
+            pub should_quit: bool,
+            #extra_fields
+        }
+
+        impl #state_name {
+            #new_fn
+        }
+
+        #default_impl
+    })
+}
+
+/// Parse #[scene(...)] attributes
+#[derive(Default)]
+struct SceneAttributes {
+    context: Option<LitStr>,
+    initial: Option<LitStr>,
+    name: Option<LitStr>,
+    handler: Option<LitStr>,
+    handler_params: Option<LitStr>,
+    handler_return: Option<LitStr>,
+    delegate: Option<LitStr>,
+    ctx_init: Option<LitStr>,
+    ctx_params: Option<LitStr>,
+    ctx_error: Option<LitStr>,
+    extra_fields: Option<LitStr>,
+    no_state: Option<Span>,
+}
+
+fn parse_scene_attributes(attrs: &[syn::Attribute]) -> Result<SceneAttributes> {
+    let mut parsed = SceneAttributes::default();
+
+    for attr in attrs {
+        if !attr.path().is_ident("scene") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("no_state") {
+                parsed.no_state = Some(meta.path.span());
+                return Ok(());
+            }
+
+            let slot = if meta.path.is_ident("context") {
+                &mut parsed.context
+            } else if meta.path.is_ident("initial") {
+                &mut parsed.initial
+            } else if meta.path.is_ident("name") {
+                &mut parsed.name
+            } else if meta.path.is_ident("handler") {
+                &mut parsed.handler
+            } else if meta.path.is_ident("handler_params") {
+                &mut parsed.handler_params
+            } else if meta.path.is_ident("handler_return") {
+                &mut parsed.handler_return
+            } else if meta.path.is_ident("delegate") {
+                &mut parsed.delegate
+            } else if meta.path.is_ident("ctx_init") {
+                &mut parsed.ctx_init
+            } else if meta.path.is_ident("ctx_params") {
+                &mut parsed.ctx_params
+            } else if meta.path.is_ident("ctx_error") {
+                &mut parsed.ctx_error
+            } else if meta.path.is_ident("extra_fields") {
+                &mut parsed.extra_fields
+            } else {
+                return Err(meta.error("unknown scene attribute"));
+            };
+            *slot = Some(meta.value()?.parse::<LitStr>()?);
+            Ok(())
+        })?;
     }
+
+    Ok(parsed)
 }
 
-/// Extract parameter names from function parameters string
-/// "ctx: &mut GameContext, input: InputEvent" -> vec![ident("ctx"), ident("input")]
-fn extract_param_names(params_str: &str) -> Vec<proc_macro2::Ident> {
-    params_str
-        .split(',')
-        .filter_map(|param| {
-            let param = param.trim();
-            // Extract the identifier before the colon
-            param.split(':').next().map(|name| {
-                let name = name.trim();
-                format_ident!("{}", name)
-            })
+/// Names the dispatcher forwards `handler_params` under
+///
+/// Every parameter has to bind a plain name; `self`, `_` and destructuring
+/// patterns cannot be passed on to the variant handlers.
+fn handler_param_names(params: &Punctuated<FnArg, Token![,]>) -> Result<Vec<Ident>> {
+    params
+        .iter()
+        .map(|param| match param {
+            FnArg::Typed(typed) => match typed.pat.as_ref() {
+                Pat::Ident(pat) if pat.subpat.is_none() => Ok(pat.ident.clone()),
+                pat => Err(syn::Error::new_spanned(
+                    pat,
+                    "handler_params must bind each parameter to a name, \
+                     e.g. `input: InputEvent`",
+                )),
+            },
+            FnArg::Receiver(receiver) => Err(syn::Error::new_spanned(
+                receiver,
+                "handler_params cannot take `self`: the dispatcher passes the scene itself",
+            )),
         })
         .collect()
 }
@@ -334,68 +665,163 @@ fn extract_param_names(params_str: &str) -> Vec<proc_macro2::Ident> {
 ///     pub hp: i32,
 /// }
 /// ```
+///
+/// The id field may be any type: `String` and other string types are
+/// borrowed, everything else is formatted with `ToString` (`u32`, a newtype
+/// implementing `Display`). `#[entity(id, as_ref)]` borrows through
+/// `AsRef<str>` instead, for newtypes without `Display`.
+///
+/// Also generates `Player::ENTITY_KIND` (`"player"`).
 #[proc_macro_derive(Entity, attributes(entity))]
 pub fn derive_entity(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    match expand_entity(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
 
+/// How the `#[entity(id)]` field becomes a `&str`
+enum EntityIdAccess {
+    /// String types and `#[entity(id, as_ref)]`: `AsRef<str>`
+    Borrow,
+    /// Anything else: `ToString`
+    Format,
+}
+
+fn expand_entity(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
     let name = &input.ident;
+    let crate_name = get_crate_name();
 
-    // Extract fields to find #[entity(id)]
-    let id_field = match &input.data {
-        Data::Struct(data) => {
-            match &data.fields {
-                Fields::Named(fields) => {
-                    // Find field with #[entity(id)] attribute
-                    fields
-                        .named
-                        .iter()
-                        .find(|field| {
-                            field
-                                .attrs
-                                .iter()
-                                .any(|attr| attr.path().is_ident("entity"))
-                        })
-                        .map(|field| field.ident.as_ref().unwrap())
-                }
-                _ => None,
-            }
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "#[derive(Entity)] only supports structs",
+            ))
         }
-        _ => None,
     };
 
-    let id_impl = if let Some(field_name) = id_field {
-        quote! {
-            fn id(&self) -> &str {
-                &self.#field_name
+    let mut id = None;
+    for (index, field) in fields.iter().enumerate() {
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("entity")) {
+            let mut is_id = false;
+            let mut as_ref = false;
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("id") {
+                    is_id = true;
+                    Ok(())
+                } else if meta.path.is_ident("as_ref") {
+                    as_ref = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `id` or `as_ref`"))
+                }
+            })?;
+            if !is_id {
+                return Err(syn::Error::new_spanned(attr, "expected `#[entity(id)]`"));
             }
-        }
-    } else {
-        // No #[entity(id)] found - use default implementation
-        quote! {
-            fn id(&self) -> &str {
-                ""
+            if id.is_some() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "only one field can be marked #[entity(id)]",
+                ));
             }
+            let member = match &field.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(index.into()),
+            };
+            let access = if as_ref || is_string_type(&field.ty) {
+                EntityIdAccess::Borrow
+            } else {
+                EntityIdAccess::Format
+            };
+            id = Some((member, &field.ty, access));
         }
+    }
+
+    let Some((member, ty, access)) = id else {
+        return Err(syn::Error::new_spanned(
+            name,
+            "#[derive(Entity)] needs an id field marked #[entity(id)]",
+        ));
     };
 
-    let expanded = quote! {
-        #[async_trait::async_trait]
-        impl Entity for #name {
-            #id_impl
+    let (id_impl, matches_impl) = match access {
+        EntityIdAccess::Borrow => (
+            quote_spanned! {ty.span()=>
+                ::std::borrow::Cow::Borrowed(::std::convert::AsRef::<str>::as_ref(&self.#member))
+            },
+            quote_spanned! {ty.span()=>
+                ::std::convert::AsRef::<str>::as_ref(&self.#member) == id
+            },
+        ),
+        EntityIdAccess::Format => (
+            quote_spanned! {ty.span()=>
+                ::std::borrow::Cow::Owned(::std::string::ToString::to_string(&self.#member))
+            },
+            quote! {
+                #crate_name::entity::Entity::id(self) == id
+            },
+        ),
+    };
+
+    let kind = snake_case(&name.to_string());
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-            async fn update(&mut self, _ctx: &mut Context) {
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Kind of entity, the snake_case type name
+            pub const ENTITY_KIND: &'static str = #kind;
+        }
+
+        #[::async_trait::async_trait]
+        impl #impl_generics #crate_name::entity::Entity for #name #ty_generics #where_clause {
+            fn id(&self) -> ::std::borrow::Cow<'_, str> {
+                #id_impl
+            }
+
+            fn matches_id(&self, id: &str) -> bool {
+                #matches_impl
+            }
+
+            async fn update(&mut self, _ctx: &mut #crate_name::context::Context) {
                 // Default implementation: do nothing
             }
         }
-    };
+    })
+}
 
-    TokenStream::from(expanded)
+/// Whether `ty` is one of the std string types, which borrow as `&str`
+fn is_string_type(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => is_string_type(&reference.elem),
+        Type::Path(path) => {
+            let Some(segment) = path.path.segments.last() else {
+                return false;
+            };
+            match segment.ident.to_string().as_str() {
+                "String" | "str" => true,
+                // `Arc<str>` is `AsRef<str>`, `Arc<String>` is not
+                "Box" | "Rc" | "Arc" | "Cow" => match &segment.arguments {
+                    syn::PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| {
+                        matches!(arg, syn::GenericArgument::Type(Type::Path(inner)) if inner.path.is_ident("str"))
+                    }),
+                    _ => false,
+                },
+                _ => false,
+            }
+        }
+        _ => false,
+    }
 }
 
 /// Derive macro for Service trait
 ///
 /// Auto-generates the boilerplate Service trait implementation.
-/// Requires #[service(name = "service_name")] attribute.
+/// The name comes from `#[service(name = "service_name")]`, or defaults to
+/// the snake_case type name (`CombatService` -> `"combat_service"`).
 ///
 /// # Example
 /// ```ignore
@@ -421,14 +847,19 @@ pub fn derive_service(input: TokenStream) -> TokenStream {
     let struct_name = &input.ident;
 
     // Parse #[service(name = "...")] attribute
-    let service_name = parse_service_name(&input.attrs);
-    let service_name_lit = syn::LitStr::new(&service_name, proc_macro2::Span::call_site());
+    let service_name = match parse_registered_name(&input.attrs, "service", struct_name) {
+        Ok(name) => name,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let name_doc = &service_name.doc;
+    let service_name = &service_name.value;
 
     let crate_name = get_crate_name();
 
     let expanded = quote! {
         impl #struct_name {
-            pub const NAME: &'static str = #service_name_lit;
+            #[doc = #name_doc]
+            pub const NAME: &'static str = #service_name;
         }
 
         #[::async_trait::async_trait]
@@ -454,35 +885,11 @@ pub fn derive_service(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
-/// Parse #[service(name = "service_name")] attribute
-fn parse_service_name(attrs: &[syn::Attribute]) -> String {
-    for attr in attrs {
-        if attr.path().is_ident("service") {
-            let mut name = None;
-            let _ = attr.parse_nested_meta(|meta| {
-                if meta.path.is_ident("name") {
-                    if let Ok(value) = meta.value() {
-                        if let Ok(Lit::Str(s)) = value.parse::<Lit>() {
-                            name = Some(s.value());
-                        }
-                    }
-                }
-                Ok(())
-            });
-            if let Some(n) = name {
-                return n;
-            }
-        }
-    }
-
-    // Default: use lowercase struct name
-    "unknown_service".to_string()
-}
-
 /// Derive macro for System trait
 ///
 /// Auto-generates the boilerplate System trait implementation.
-/// Requires #[system(name = "system_name")] attribute.
+/// The name comes from `#[system(name = "system_name")]`, or defaults to
+/// the snake_case type name (`CombatSystem` -> `"combat_system"`).
 ///
 /// # Example
 /// ```ignore
@@ -509,14 +916,19 @@ pub fn derive_system(input: TokenStream) -> TokenStream {
     let struct_name = &input.ident;
 
     // Parse #[system(name = "...")] attribute
-    let system_name = parse_system_name(&input.attrs);
-    let system_name_lit = syn::LitStr::new(&system_name, proc_macro2::Span::call_site());
+    let system_name = match parse_registered_name(&input.attrs, "system", struct_name) {
+        Ok(name) => name,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let name_doc = &system_name.doc;
+    let system_name = &system_name.value;
 
     let crate_name = get_crate_name();
 
     let expanded = quote! {
         impl #struct_name {
-            pub const NAME: &'static str = #system_name_lit;
+            #[doc = #name_doc]
+            pub const NAME: &'static str = #system_name;
         }
 
         #[::async_trait::async_trait]
@@ -538,29 +950,87 @@ pub fn derive_system(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
-/// Parse #[system(name = "system_name")] attribute
-fn parse_system_name(attrs: &[syn::Attribute]) -> String {
+/// Name of a derived Service or System: `#[<attribute>(name = "...")]`, or
+/// the snake_case type name
+struct RegisteredName {
+    value: String,
+    doc: String,
+}
+
+fn parse_registered_name(
+    attrs: &[syn::Attribute],
+    attribute: &str,
+    ident: &Ident,
+) -> Result<RegisteredName> {
+    let mut name: Option<LitStr> = None;
     for attr in attrs {
-        if attr.path().is_ident("system") {
-            let mut name = None;
-            let _ = attr.parse_nested_meta(|meta| {
-                if meta.path.is_ident("name") {
-                    if let Ok(value) = meta.value() {
-                        if let Ok(Lit::Str(s)) = value.parse::<Lit>() {
-                            name = Some(s.value());
-                        }
-                    }
+        if !attr.path().is_ident(attribute) {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("name") {
+                return Err(meta.error("expected `name`"));
+            }
+            let lit: LitStr = meta.value()?.parse()?;
+            if lit.value().trim().is_empty() {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!("#[{}(name = ...)] must not be empty", attribute),
+                ));
+            }
+            if let Some(previous) = &name {
+                if previous.value() != lit.value() {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        format!(
+                            "conflicting #[{}(name = ...)]: \"{}\" and \"{}\"",
+                            attribute,
+                            previous.value(),
+                            lit.value()
+                        ),
+                    ));
                 }
-                Ok(())
-            });
-            if let Some(n) = name {
-                return n;
             }
-        }
+            name = Some(lit);
+            Ok(())
+        })?;
     }
 
-    // Default: use lowercase struct name
-    "unknown_system".to_string()
+    Ok(match name {
+        Some(lit) => RegisteredName {
+            doc: format!(
+                " Name set by `#[{}(name = \"{}\")]`",
+                attribute,
+                lit.value()
+            ),
+            value: lit.value(),
+        },
+        None => {
+            let value = snake_case(&ident.to_string());
+            RegisteredName {
+                doc: format!(
+                    " Name derived from the type name `{}` (set `#[{}(name = \"...\")]` to override)",
+                    ident, attribute
+                ),
+                value,
+            }
+        }
+    })
+}
+
+/// `CombatService` -> `combat_service`
+fn snake_case(ident: &str) -> String {
+    ident
+        .chars()
+        .enumerate()
+        .flat_map(|(i, c)| {
+            if i > 0 && c.is_uppercase() {
+                vec!['_', c.to_ascii_lowercase()]
+            } else {
+                vec![c.to_ascii_lowercase()]
+            }
+        })
+        .collect()
 }
 
 /// Derive macro for Asset trait
@@ -608,22 +1078,77 @@ pub fn derive_asset(input: TokenStream) -> TokenStream {
 ///     pub enemies: Vec<EnemyAsset>,
 /// }
 /// ```
-#[proc_macro_derive(Resource)]
+///
+/// `#[resource(init = "...")]` implements `ResourceInit` with the given
+/// expression, so `#[plugin(resource = T)]` and
+/// `GameBuilder::with_default_resource` build the type without `Default`:
+///
+/// ```ignore
+/// #[derive(Resource)]
+/// #[resource(init = "EventLog::with_capacity(64)")]
+/// pub struct EventLog {
+///     entries: VecDeque<String>,
+/// }
+/// ```
+#[proc_macro_derive(Resource, attributes(resource))]
 pub fn derive_resource(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     let name = &input.ident;
     let crate_name = get_crate_name();
 
+    let init = match parse_resource_init(&input.attrs) {
+        Ok(init) => init,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let init_impl = init.map(|init| {
+        quote! {
+            impl #name {
+                #[doc(hidden)]
+                pub fn __issun_default_init() -> Self {
+                    #init
+                }
+            }
+
+            impl #crate_name::resources::ResourceInit for #name {
+                fn init_resource() -> Self {
+                    Self::__issun_default_init()
+                }
+            }
+        }
+    });
+
     let expanded = quote! {
         impl #crate_name::resources::Resource for #name {
             // Uses default implementation from trait
         }
+
+        #init_impl
     };
 
     TokenStream::from(expanded)
 }
 
+/// Parse `#[resource(init = "...")]`
+fn parse_resource_init(attrs: &[syn::Attribute]) -> Result<Option<Expr>> {
+    let mut init = None;
+    for attr in attrs {
+        if !attr.path().is_ident("resource") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("init") {
+                let lit: LitStr = meta.value()?.parse()?;
+                init = Some(lit.parse::<Expr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `init`"))
+            }
+        })?;
+    }
+    Ok(init)
+}
+
 /// Derive macro for Plugin trait
 ///
 /// # Example
@@ -635,6 +1160,28 @@ pub fn derive_resource(input: TokenStream) -> TokenStream {
 /// #[plugin(state = MyState)]
 /// pub struct MyPlugin;
 /// ```
+///
+/// A bare type is built with `Default` (`state` and `resource` types with
+/// `ResourceInit`, so `#[resource(init = "...")]` applies). A string holds
+/// an expression instead, for components that need configuration:
+///
+/// ```ignore
+/// #[derive(Plugin)]
+/// #[plugin(service = "CombatService::new(cfg())")]
+/// #[plugin(system = "ContagionSystem::new(Arc::new(AlertHook))")]
+/// pub struct CombatPlugin;
+/// ```
+///
+/// `#[plugin(hook = MyHook)]` registers `Arc::new(MyHook::default())` with
+/// `PluginBuilderExt::register_hook`; the expression form must produce the
+/// `Arc`, e.g. `hook = "Arc::new(AlertHook) as Arc<dyn ContagionHook>"`.
+///
+/// Fields marked `#[plugin(runtime_state, reset)]` (or `#[state]` plus
+/// `#[plugin(reset)]`) are reset to their post-build value when the run
+/// restarts.
+///
+/// `#[plugin(resource, config = "combat")]` lets the config file override
+/// the field's resource under `[combat]`; the type implements `ConfigParams`.
 #[proc_macro_derive(Plugin, attributes(plugin, resource, state, system, service))]
 pub fn derive_plugin(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -647,6 +1194,7 @@ pub fn derive_plugin(input: TokenStream) -> TokenStream {
     let mut systems = Vec::new();
     let mut states = Vec::new();
     let mut resources = Vec::new();
+    let mut hooks = Vec::new();
 
     for attr in &input.attrs {
         if !attr.path().is_ident("plugin") {
@@ -660,27 +1208,23 @@ pub fn derive_plugin(input: TokenStream) -> TokenStream {
                 plugin_name = Some(lit.value());
                 Ok(())
             } else if meta.path.is_ident("service") {
-                let value = meta.value()?;
-                let ty: Type = value.parse()?;
-                services.push(ty);
+                services.push(PluginComponent::parse(&meta)?);
                 Ok(())
             } else if meta.path.is_ident("system") {
-                let value = meta.value()?;
-                let ty: Type = value.parse()?;
-                systems.push(ty);
+                systems.push(PluginComponent::parse(&meta)?);
                 Ok(())
             } else if meta.path.is_ident("state") {
-                let value = meta.value()?;
-                let ty: Type = value.parse()?;
-                states.push(ty);
+                states.push(PluginComponent::parse(&meta)?);
                 Ok(())
             } else if meta.path.is_ident("resource") {
-                let value = meta.value()?;
-                let ty: Type = value.parse()?;
-                resources.push(ty);
+                resources.push(PluginComponent::parse(&meta)?);
+                Ok(())
+            } else if meta.path.is_ident("hook") {
+                hooks.push(PluginComponent::parse(&meta)?);
                 Ok(())
             } else {
-                Err(meta.error("expected `name`, `service`, `system`, `state`, or `resource`"))
+                Err(meta
+                    .error("expected `name`, `service`, `system`, `state`, `resource`, or `hook`"))
             }
         });
 
@@ -691,43 +1235,48 @@ pub fn derive_plugin(input: TokenStream) -> TokenStream {
 
     let plugin_name = plugin_name.unwrap_or_else(|| {
         // Default: convert MyPlugin -> "my_plugin"
-        let name_str = name.to_string();
-        name_str
-            .trim_end_matches("Plugin")
-            .chars()
-            .enumerate()
-            .flat_map(|(i, c)| {
-                if i > 0 && c.is_uppercase() {
-                    vec!['_', c.to_ascii_lowercase()]
-                } else {
-                    vec![c.to_ascii_lowercase()]
-                }
-            })
-            .collect::<String>()
+        snake_case(name.to_string().trim_end_matches("Plugin"))
     });
 
-    // Generate registrations from attributes (Type::default())
-    let attr_service_registrations = services.iter().map(|ty| {
-        quote! {
-            builder.register_service(Box::new(#ty::default()));
+    // Generate registrations from attributes (Type::default() or expression)
+    let attr_service_registrations = services.iter().map(|component| {
+        let value = component.construct();
+        quote_spanned! {component.span()=>
+            builder.register_service(Box::new(#value));
         }
     });
 
-    let attr_system_registrations = systems.iter().map(|ty| {
-        quote! {
-            builder.register_system(Box::new(#ty::default()));
+    let attr_system_registrations = systems.iter().map(|component| {
+        let value = component.construct();
+        quote_spanned! {component.span()=>
+            builder.register_system(Box::new(#value));
         }
     });
 
-    let attr_state_registrations = states.iter().map(|ty| {
-        quote! {
-            builder.register_runtime_state(#ty::default());
+    let attr_state_registrations = states.iter().map(|component| {
+        let value = component.construct_resource(&crate_name);
+        quote_spanned! {component.span()=>
+            builder.register_runtime_state(#value);
         }
     });
 
-    let attr_resource_registrations = resources.iter().map(|ty| {
-        quote! {
-            builder.register_resource(#ty::default());
+    let attr_resource_registrations = resources.iter().map(|component| {
+        let value = component.construct_resource(&crate_name);
+        quote_spanned! {component.span()=>
+            builder.register_resource(#value);
+        }
+    });
+
+    let attr_hook_registrations = hooks.iter().map(|component| {
+        let value = match component {
+            PluginComponent::Default(_) => {
+                let value = component.construct();
+                quote_spanned! {component.span()=> ::std::sync::Arc::new(#value) }
+            }
+            PluginComponent::Expr(_) => component.construct(),
+        };
+        quote_spanned! {component.span()=>
+            builder.register_hook(#value);
         }
     });
 
@@ -738,9 +1287,28 @@ pub fn derive_plugin(input: TokenStream) -> TokenStream {
         for field in &data.fields {
             let field_name = &field.ident;
 
-            // Skip fields without identifiers (tuple structs) for now, or handle them if needed.
-            // The requirement implies named fields for clarity, but let's support named fields primarily.
+            // Registrations read fields by name
             if field_name.is_none() {
+                let registering = field.attrs.iter().find(|attr| {
+                    [
+                        "plugin",
+                        "resource",
+                        "state",
+                        "runtime_state",
+                        "system",
+                        "service",
+                    ]
+                    .iter()
+                    .any(|name| attr.path().is_ident(name))
+                });
+                if let Some(attr) = registering {
+                    return syn::Error::new_spanned(
+                        attr,
+                        "#[derive(Plugin)] field attributes need a struct with named fields",
+                    )
+                    .to_compile_error()
+                    .into();
+                }
                 continue;
             }
             let field_access = quote! { self.#field_name };
@@ -766,10 +1334,23 @@ pub fn derive_plugin(input: TokenStream) -> TokenStream {
                             field_registrations.push(quote! {
                                 builder.register_service(Box::new(#field_access.clone()));
                             });
+                        } else if meta.path.is_ident("reset") {
+                            // Restore the field's post-build value on SceneTransition::Restart
+                            let ty = &field.ty;
+                            field_registrations.push(quote! {
+                                builder.register_reset_to_initial::<#ty>();
+                            });
+                        } else if meta.path.is_ident("config") {
+                            // Let the config file override the field's resource under [section]
+                            let section: LitStr = meta.value()?.parse()?;
+                            let ty = &field.ty;
+                            field_registrations.push(quote! {
+                                builder.register_config::<#ty>(#section);
+                            });
                         } else if meta.path.is_ident("skip") {
                             // Explicitly skip this field - no registration
                         } else {
-                            return Err(meta.error("expected `resource`, `state`, `runtime_state`, `system`, `service`, or `skip`"));
+                            return Err(meta.error("expected `resource`, `state`, `runtime_state`, `reset`, `config`, `system`, `service`, or `skip`"));
                         }
                         Ok(())
                     });
@@ -809,14 +1390,17 @@ pub fn derive_plugin(input: TokenStream) -> TokenStream {
                 #plugin_name
             }
 
+            // Bare-type components are `Type::default()` spanned at the type
+            #[allow(clippy::default_constructed_unit_structs)]
             fn build(&self, builder: &mut dyn #crate_name::plugin::PluginBuilder) {
                 use #crate_name::plugin::PluginBuilderExt;
 
-                // Attribute-based registrations (Types)
+                // Attribute-based registrations (Types or expressions)
                 #(#attr_service_registrations)*
                 #(#attr_system_registrations)*
                 #(#attr_state_registrations)*
                 #(#attr_resource_registrations)*
+                #(#attr_hook_registrations)*
 
                 // Field-based registrations (Instances)
                 #(#field_registrations)*
@@ -827,7 +1411,170 @@ pub fn derive_plugin(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Value of a struct-level `#[plugin(service = ...)]`-style attribute
+enum PluginComponent {
+    /// `service = MyService`: built with `Default`
+    Default(Type),
+    /// `service = "MyService::new(cfg())"`: built by the expression
+    Expr(Expr),
+}
+
+impl PluginComponent {
+    fn parse(meta: &syn::meta::ParseNestedMeta) -> Result<Self> {
+        let value = meta.value()?;
+        if value.peek(LitStr) {
+            let lit: LitStr = value.parse()?;
+            lit.parse()
+                .map(Self::Expr)
+                .map_err(|err| syn::Error::new(lit.span(), format!("invalid expression: {}", err)))
+        } else {
+            Ok(Self::Default(value.parse()?))
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Self::Default(ty) => ty.span(),
+            Self::Expr(expr) => expr.span(),
+        }
+    }
+
+    fn construct(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::Default(ty) => quote_spanned! {ty.span()=> <#ty>::default() },
+            Self::Expr(expr) => quote! { #expr },
+        }
+    }
+
+    /// Like [`construct`](Self::construct), but bare types are built with
+    /// `ResourceInit`, which honours `#[resource(init = "...")]`
+    fn construct_resource(
+        &self,
+        crate_name: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match self {
+            Self::Default(ty) => quote_spanned! {ty.span()=>
+                <#ty as #crate_name::resources::ResourceInit>::init_resource()
+            },
+            Self::Expr(expr) => quote! { #expr },
+        }
+    }
+}
+
 /// Attribute macro that generates `process_events` for systems reacting to events.
+///
+/// Each `#[subscribe(EventType)]` method is called once per event of that
+/// type read from the `EventBus`. Every event type is collected once, however
+/// many handlers subscribe to it.
+///
+/// Events are read through a named reader keyed by the impl type
+/// (`EventBus::reader_for`): a system run twice in a frame handles each event
+/// once, and one that skips frames catches up on the events it missed.
+///
+/// Handlers borrow the events straight from the bus, which stays read-locked
+/// while they run. If a handler takes the bus itself (`#[state] bus: &mut
+/// EventBus`), the events are copied into the bus's reused snapshot buffers
+/// and the lock is released first.
+///
+/// When the `EventBus` has a tracer, each event type's batch of events is
+/// timed and attributed to (system type, event type) in the tracer.
+///
+/// # Resources and services
+///
+/// Handlers take further parameters from the contexts:
+/// - `#[state] config: &GameConfig` reads a resource, `#[state] log: &mut
+///   CombatLog` writes it; only writers lock out other handlers
+/// - `#[state(optional)] weather: Option<&Weather>` (or `Option<&mut T>`)
+///   is `None` when the resource is missing
+/// - `#[service(name = "rng")] rng: &RngService` borrows a service
+/// - `#[events] out: &mut EventWriter` collects follow-up events
+///
+/// A handler whose non-optional state or service is missing is skipped.
+///
+/// # Follow-up events
+///
+/// Handlers cannot publish to the bus `process_events` is reading from.
+/// Events sent to an `#[events]` writer are published after every handler
+/// has run (also after `StopAll`), in send order, so they are handled in
+/// the next frame like any other publish.
+///
+/// ```ignore
+/// #[issun::event_handler]
+/// impl Shop {
+///     #[subscribe(PurchaseRequested)]
+///     async fn buy(&mut self, event: &PurchaseRequested, #[events] out: &mut EventWriter) {
+///         out.send(ItemAddedEvent { item: event.item.clone() });
+///     }
+/// }
+/// ```
+///
+/// # Order and early exit
+///
+/// Every event is offered to its handlers in turn. `#[subscribe(E, priority
+/// = 10)]` runs a handler before lower priorities (default 0); equal
+/// priorities run in declaration order. Event types are processed one after
+/// another, the type with the highest-priority handler first.
+///
+/// A handler may return `HandlerFlow` instead of `()` (which means
+/// `Continue`):
+/// - `StopEvent`: later handlers do not see this event
+/// - `StopAll`: nothing else is handled in this `process_events` call
+///
+/// ```ignore
+/// #[issun::event_handler]
+/// impl MenuInput {
+///     #[subscribe(KeyPressed, priority = 10)]
+///     async fn menu(&mut self, event: &KeyPressed) -> HandlerFlow {
+///         if self.open { HandlerFlow::StopEvent } else { HandlerFlow::Continue }
+///     }
+///
+///     #[subscribe(KeyPressed)]
+///     async fn gameplay(&mut self, event: &KeyPressed) { /* ... */ }
+/// }
+/// ```
+///
+/// # Several event types
+///
+/// A method subscribes to several types with stacked `#[subscribe(A)]
+/// #[subscribe(B)]` attributes or with `#[subscribe(any(A, B))]`. Its event
+/// parameter is then either
+/// - a trait object (`&dyn Trait`) implemented by every type, or
+/// - `&Name` for a plain identifier `Name`: the macro generates
+///   `enum Name { A(A), B(B) }` next to the impl block, with `Clone` and a
+///   `From` impl per type. Variants are named after the last path segment of
+///   each type, and the enum gets the handler's visibility.
+///
+/// A type cannot be listed twice. A `filter` receives the same parameter as
+/// the handler and may be given once per method.
+///
+/// ```ignore
+/// use issun::event::Event;
+///
+/// #[derive(Clone)]
+/// struct ItemAddedEvent { item: String }
+/// impl Event for ItemAddedEvent {}
+///
+/// #[derive(Clone)]
+/// struct ItemRemovedEvent { item: String }
+/// impl Event for ItemRemovedEvent {}
+///
+/// #[derive(Default)]
+/// struct InventoryLog { lines: Vec<String> }
+///
+/// #[issun::event_handler]
+/// impl InventoryLog {
+///     #[subscribe(any(ItemAddedEvent, ItemRemovedEvent))]
+///     async fn on_change(&mut self, event: &ItemAddedOrRemoved) {
+///         self.lines.push(match event {
+///             ItemAddedOrRemoved::ItemAddedEvent(e) => format!("+{}", e.item),
+///             ItemAddedOrRemoved::ItemRemovedEvent(e) => format!("-{}", e.item),
+///         });
+///     }
+/// }
+///
+/// let added: ItemAddedOrRemoved = ItemAddedEvent { item: "sword".into() }.into();
+/// assert!(matches!(added, ItemAddedOrRemoved::ItemAddedEvent(_)));
+/// ```
 #[proc_macro_attribute]
 pub fn event_handler(attr: TokenStream, item: TokenStream) -> TokenStream {
     let args = parse_macro_input!(attr as EventHandlerArgs);
@@ -850,18 +1597,18 @@ pub fn event_handler(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     for impl_item in &mut item_impl.items {
         if let ImplItem::Fn(method) = impl_item {
-            let mut subscribe_attr = None;
+            let mut subscribe_attrs = Vec::new();
             method.attrs.retain(|attr| {
                 if attr.path().is_ident("subscribe") {
-                    subscribe_attr = Some(attr.clone());
+                    subscribe_attrs.push(attr.clone());
                     false
                 } else {
                     true
                 }
             });
 
-            if let Some(attr) = subscribe_attr {
-                if let Err(err) = context.register_handler(method, attr) {
+            if !subscribe_attrs.is_empty() {
+                if let Err(err) = context.register_handler(method, subscribe_attrs) {
                     return err.to_compile_error().into();
                 }
             }
@@ -880,7 +1627,11 @@ pub fn event_handler(attr: TokenStream, item: TokenStream) -> TokenStream {
     match context.generate_process_fn(&crate_name) {
         Ok(process_fn) => {
             item_impl.items.push(ImplItem::Fn(process_fn));
-            TokenStream::from(quote! { #item_impl })
+            let event_enums = context.event_enums.iter().map(EventEnum::expand);
+            TokenStream::from(quote! {
+                #item_impl
+                #(#event_enums)*
+            })
         }
         Err(err) => err.to_compile_error().into(),
     }
@@ -936,7 +1687,9 @@ struct EventHandlerContext {
     events: Vec<EventCollection>,
     event_lookup: HashMap<String, usize>,
     handlers: Vec<Handler>,
+    event_enums: Vec<EventEnum>,
     uses_services: bool,
+    uses_writer: bool,
 }
 
 impl EventHandlerContext {
@@ -946,14 +1699,16 @@ impl EventHandlerContext {
             events: Vec::new(),
             event_lookup: HashMap::new(),
             handlers: Vec::new(),
+            event_enums: Vec::new(),
             uses_services: false,
+            uses_writer: false,
         })
     }
 
     fn register_handler(
         &mut self,
         method: &mut ImplItemFn,
-        subscribe_attr: Attribute,
+        subscribe_attrs: Vec<Attribute>,
     ) -> Result<()> {
         if method.sig.asyncness.is_none() {
             return Err(syn::Error::new(
@@ -988,8 +1743,34 @@ impl EventHandlerContext {
             }
         }
 
-        let subscribe = parse_subscribe_attr(subscribe_attr)?;
-        let event_index = self.register_event(&subscribe.event_type);
+        let mut event_types: Vec<Type> = Vec::new();
+        let mut priorities = Vec::new();
+        let mut filter = None;
+        for attr in subscribe_attrs {
+            let subscribe = parse_subscribe_attr(attr)?;
+            for ty in subscribe.event_types {
+                if event_types
+                    .iter()
+                    .any(|seen| type_to_key(seen) == type_to_key(&ty))
+                {
+                    return Err(syn::Error::new(
+                        ty.span(),
+                        "event type is already subscribed by this handler",
+                    ));
+                }
+                event_types.push(ty);
+                priorities.push(subscribe.priority);
+            }
+            if let Some(ident) = subscribe.filter {
+                if filter.is_some() {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "a handler may only have one filter",
+                    ));
+                }
+                filter = Some(ident);
+            }
+        }
 
         let event_input = inputs_iter.next().ok_or_else(|| {
             syn::Error::new(
@@ -1024,14 +1805,26 @@ impl EventHandlerContext {
             }
         };
 
-        let requested_event = subscribe.event_type.to_token_stream().to_string();
-        let actual_event = event_ty.to_token_stream().to_string();
-        if requested_event != actual_event {
-            return Err(syn::Error::new(
-                method.sig.ident.span(),
-                "event parameter type must match #[subscribe(...)]",
-            ));
-        }
+        let conversion = if let [event_type] = event_types.as_slice() {
+            if type_to_key(event_type) != type_to_key(&event_ty) {
+                return Err(syn::Error::new(
+                    method.sig.ident.span(),
+                    "event parameter type must match #[subscribe(...)]",
+                ));
+            }
+            EventConversion::Direct
+        } else if matches!(event_ty, Type::TraitObject(_)) {
+            EventConversion::Direct
+        } else {
+            let ident = event_enum_ident(&event_ty)?;
+            self.register_event_enum(&ident, &method.vis, &event_types)?;
+            EventConversion::Enum(ident)
+        };
+        let subscriptions = event_types
+            .iter()
+            .zip(priorities)
+            .map(|(ty, priority)| (self.register_event(ty), priority))
+            .collect();
 
         let mut args = Vec::new();
         for input in inputs_iter {
@@ -1046,22 +1839,79 @@ impl EventHandlerContext {
             };
 
             let arg = parse_handler_arg(pat_type, self.default_state.as_ref())?;
-            if matches!(arg.kind, HandlerArgKind::Service { .. }) {
-                self.uses_services = true;
+            match arg.kind {
+                HandlerArgKind::Service { .. } => self.uses_services = true,
+                HandlerArgKind::Events => self.uses_writer = true,
+                HandlerArgKind::State { .. } => {}
             }
             args.push(arg);
         }
 
         self.handlers.push(Handler {
             method_ident: method.sig.ident.clone(),
-            event_index,
-            filter: subscribe.filter,
+            subscriptions,
+            conversion,
+            filter,
             args,
         });
 
         Ok(())
     }
 
+    /// Record the enum generated for a multi-type handler; handlers sharing
+    /// a name must subscribe to the same types
+    fn register_event_enum(
+        &mut self,
+        ident: &Ident,
+        vis: &Visibility,
+        event_types: &[Type],
+    ) -> Result<()> {
+        let keys: Vec<String> = event_types.iter().map(type_to_key).collect();
+        if let Some(existing) = self.event_enums.iter().find(|e| e.ident == *ident) {
+            let existing_keys: Vec<String> = existing
+                .variants
+                .iter()
+                .map(|(_, ty)| type_to_key(ty))
+                .collect();
+            if existing_keys != keys {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("`{}` is already generated for different event types", ident),
+                ));
+            }
+            return Ok(());
+        }
+
+        let mut variants: Vec<(Ident, Type)> = Vec::new();
+        for ty in event_types {
+            let variant = match ty {
+                Type::Path(type_path) => type_path
+                    .path
+                    .segments
+                    .last()
+                    .map(|segment| segment.ident.clone()),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                syn::Error::new(ty.span(), "event types of a combined handler must be paths")
+            })?;
+            if variants.iter().any(|(seen, _)| *seen == variant) {
+                return Err(syn::Error::new(
+                    ty.span(),
+                    format!("two event types would both become variant `{}`", variant),
+                ));
+            }
+            variants.push((variant, ty.clone()));
+        }
+
+        self.event_enums.push(EventEnum {
+            ident: ident.clone(),
+            vis: vis.clone(),
+            variants,
+        });
+        Ok(())
+    }
+
     fn register_event(&mut self, ty: &Type) -> usize {
         let key = type_to_key(ty);
         if let Some(index) = self.event_lookup.get(&key) {
@@ -1083,33 +1933,30 @@ impl EventHandlerContext {
         let resource_ctx_ty = quote! { #crate_name::context::ResourceContext };
         let service_ctx_ty = quote! { #crate_name::context::ServiceContext };
 
-        let collects = self.events.iter().map(|event| {
-            let ident = &event.ident;
-            let ty = &event.ty;
-            quote! {
-                let #ident: ::std::vec::Vec<#ty> =
-                    event_bus.reader::<#ty>().iter().cloned().collect();
+        let detach = self.handlers.iter().any(Handler::takes_event_bus);
+        let empty_check = |on_empty: proc_macro2::TokenStream| {
+            if self.events.is_empty() {
+                return quote! {};
             }
-        });
-
-        let empty_check = if self.events.is_empty() {
-            quote! {}
-        } else {
-            let empties = self.events.iter().map(|event| {
-                let ident = &event.ident;
-                quote! { #ident.is_empty() }
-            });
+            let idents = self.events.iter().map(|event| &event.ident);
             quote! {
-                if true #(&& #empties)* {
+                if true #(&& #idents.is_empty())* {
+                    #on_empty
                     return;
                 }
             }
         };
-
-        let handler_blocks = self
-            .handlers
-            .iter()
-            .map(|handler| handler.expand(self.events.as_slice()));
+        // Each impl type reads through its own cursor on the bus
+        let reader_name = quote! { ::std::any::type_name::<Self>() };
+
+        let flow_ty = quote! { #crate_name::event::HandlerFlow };
+        let event_blocks = self.dispatch_order().into_iter().map(|(index, handlers)| {
+            let event = &self.events[index];
+            let calls = handlers
+                .iter()
+                .map(|handler| handler.expand_call(event, &flow_ty));
+            event.expand(calls, crate_name)
+        });
 
         let service_usage = if self.uses_services {
             quote! {}
@@ -1117,20 +1964,96 @@ impl EventHandlerContext {
             quote! { let _ = services; }
         };
 
-        let body = quote! {
-            let mut event_bus = match resources.get_mut::<#event_bus_ty>().await {
-                Some(bus) => bus,
-                None => return,
-            };
+        let event_writer_ty = quote! { #crate_name::event::EventWriter };
+        let (writer_init, writer_flush) = if self.uses_writer {
+            (
+                quote! { let mut __event_writer = #event_writer_ty::new(); },
+                quote! { __event_writer.flush(&mut event_bus); },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+
+        let body = if detach {
+            // A handler locks the bus: copy the events out, hand the
+            // buffers back afterwards
+            let snapshots = self.events.iter().map(|event| {
+                let ident = &event.ident;
+                let ty = &event.ty;
+                quote! {
+                    let #ident: ::std::vec::Vec<#ty> = event_bus.snapshot_for::<#ty>(#reader_name);
+                }
+            });
+            let recycles: Vec<_> = self
+                .events
+                .iter()
+                .map(|event| {
+                    let ident = &event.ident;
+                    quote! { event_bus.recycle(#ident); }
+                })
+                .collect();
+            let empty_check = empty_check(quote! { #(#recycles)* });
+            quote! {
+                let mut event_bus = match resources.get_mut::<#event_bus_ty>().await {
+                    Some(bus) => bus,
+                    None => return,
+                };
+
+                #(#snapshots)*
+                #empty_check
+
+                let __handler_tracer = event_bus.tracer().cloned();
+                drop(event_bus);
 
-            #(#collects)*
+                #service_usage
+                #writer_init
+                let mut __stop_all = false;
+                #(#event_blocks)*
 
-            #empty_check
+                if let Some(mut event_bus) = resources.get_mut::<#event_bus_ty>().await {
+                    #(#recycles)*
+                    #writer_flush
+                }
+            }
+        } else {
+            let readers = self.events.iter().map(|event| {
+                let ident = &event.ident;
+                let ty = &event.ty;
+                quote! { let #ident = event_bus.reader_for::<#ty>(#reader_name); }
+            });
+            let empty_check = empty_check(quote! {});
+            let handle = quote! {
+                let event_bus = match resources.get::<#event_bus_ty>().await {
+                    Some(bus) => bus,
+                    None => return,
+                };
 
-            drop(event_bus);
+                #(#readers)*
+                #empty_check
 
-            #service_usage
-            #(#handler_blocks)*
+                let __handler_tracer = event_bus.tracer().cloned();
+
+                #service_usage
+                let mut __stop_all = false;
+                #(#event_blocks)*
+            };
+            if self.uses_writer {
+                // The read guard is released before the bus is locked for
+                // publishing
+                quote! {
+                    #writer_init
+                    {
+                        #handle
+                    }
+                    if !__event_writer.is_empty() {
+                        if let Some(mut event_bus) = resources.get_mut::<#event_bus_ty>().await {
+                            #writer_flush
+                        }
+                    }
+                }
+            } else {
+                handle
+            }
         };
 
         let process_fn: ImplItemFn = syn::parse_quote! {
@@ -1145,6 +2068,34 @@ impl EventHandlerContext {
 
         Ok(process_fn)
     }
+
+    /// Event types with their handlers, highest priority first
+    ///
+    /// Types are ordered by their highest-priority handler, then by first
+    /// subscription; handlers of a type by priority, then declaration.
+    fn dispatch_order(&self) -> Vec<(usize, Vec<&Handler>)> {
+        use std::cmp::Reverse;
+
+        let mut order: Vec<(usize, Vec<&Handler>)> = (0..self.events.len())
+            .map(|index| {
+                let mut handlers: Vec<&Handler> = self
+                    .handlers
+                    .iter()
+                    .filter(|handler| handler.priority(index).is_some())
+                    .collect();
+                // Stable: equal priorities keep declaration order
+                handlers.sort_by_key(|handler| Reverse(handler.priority(index)));
+                (index, handlers)
+            })
+            .collect();
+        order.sort_by_key(|(index, handlers)| {
+            let top = handlers
+                .first()
+                .and_then(|handler| handler.priority(*index));
+            (Reverse(top), *index)
+        });
+        order
+    }
 }
 
 struct EventCollection {
@@ -1152,25 +2103,161 @@ struct EventCollection {
     ident: Ident,
 }
 
+impl EventCollection {
+    /// Run `calls` for every collected event, stopping on
+    /// `HandlerFlow::StopEvent` / `StopAll`
+    ///
+    /// One span per event type batch, only when the bus has a tracer.
+    fn expand(
+        &self,
+        calls: impl Iterator<Item = proc_macro2::TokenStream>,
+        crate_name: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let ty = &self.ty;
+        quote! {
+            if !__stop_all && !#ident.is_empty() {
+                let __handler_span = #crate_name::trace::HandlerSpan::start::<Self, #ty>(
+                    __handler_tracer.as_ref(),
+                );
+                let mut __handled = 0usize;
+                for event in #ident.iter() {
+                    __handled += 1;
+                    '__handlers: {
+                        #(#calls)*
+                    }
+                    if __stop_all {
+                        break;
+                    }
+                }
+                if let Some(__handler_span) = __handler_span {
+                    __handler_span.finish(__handled);
+                }
+            }
+        }
+    }
+}
+
+/// How a collected event is passed to a handler
+enum EventConversion {
+    /// As `&EventType`, or coerced to the handler's `&dyn Trait`
+    Direct,
+    /// Wrapped in the generated enum
+    Enum(Ident),
+}
+
+/// Enum generated for a handler subscribed to several event types
+struct EventEnum {
+    ident: Ident,
+    vis: Visibility,
+    variants: Vec<(Ident, Type)>,
+}
+
+impl EventEnum {
+    fn expand(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let vis = &self.vis;
+        let variants = self.variants.iter().map(|(variant, ty)| {
+            quote! { #variant(#ty) }
+        });
+        let froms = self.variants.iter().map(|(variant, ty)| {
+            quote! {
+                impl ::std::convert::From<#ty> for #ident {
+                    fn from(event: #ty) -> Self {
+                        Self::#variant(event)
+                    }
+                }
+            }
+        });
+        let doc = format!(
+            "Events handled together by one `#[subscribe]` handler: {}",
+            self.variants
+                .iter()
+                .map(|(variant, _)| format!("`{}`", variant))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Clone)]
+            #vis enum #ident {
+                #(#variants),*
+            }
+
+            #(#froms)*
+        }
+    }
+}
+
+/// Name of the enum a combined handler takes (`&ItemAddedOrRemoved`)
+fn event_enum_ident(ty: &Type) -> Result<Ident> {
+    if let Type::Path(type_path) = ty {
+        if type_path.qself.is_none() {
+            if let Some(ident) = type_path.path.get_ident() {
+                return Ok(ident.clone());
+            }
+        }
+    }
+    Err(syn::Error::new(
+        ty.span(),
+        "a handler for several event types takes `&dyn Trait` or `&Name` of the enum to generate",
+    ))
+}
+
 struct Handler {
     method_ident: Ident,
-    event_index: usize,
+    /// (event index, priority) per subscribed type
+    subscriptions: Vec<(usize, i32)>,
+    conversion: EventConversion,
     filter: Option<Ident>,
     args: Vec<HandlerArg>,
 }
 
 impl Handler {
-    fn expand(&self, events: &[EventCollection]) -> proc_macro2::TokenStream {
-        let event_ident = &events[self.event_index].ident;
+    /// Whether the handler locks the `EventBus` through a `#[state]` argument
+    fn takes_event_bus(&self) -> bool {
+        self.args.iter().any(|arg| match &arg.kind {
+            HandlerArgKind::State {
+                ty: Type::Path(type_path),
+                ..
+            } => type_path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "EventBus"),
+            _ => false,
+        })
+    }
+
+    /// Priority of the subscription to event `index`, if subscribed
+    fn priority(&self, index: usize) -> Option<i32> {
+        self.subscriptions
+            .iter()
+            .find(|(event, _)| *event == index)
+            .map(|(_, priority)| *priority)
+    }
+
+    /// Call the handler for `event` inside the `'__handlers` block and
+    /// honor the `HandlerFlow` it returns
+    fn expand_call(
+        &self,
+        event: &EventCollection,
+        flow_ty: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let event_ty = &event.ty;
         let method_ident = &self.method_ident;
-        let filter_check = if let Some(filter) = &self.filter {
-            quote! {
-                if !self.#filter(event) {
-                    continue;
-                }
-            }
-        } else {
-            quote! {}
+        let convert = match &self.conversion {
+            EventConversion::Direct => quote! {},
+            EventConversion::Enum(enum_ident) => quote! {
+                let event = &<#enum_ident as ::std::convert::From<#event_ty>>::from(
+                    ::std::clone::Clone::clone(event),
+                );
+            },
+        };
+        let passes_filter = match &self.filter {
+            Some(filter) => quote! { self.#filter(event) },
+            None => quote! { true },
         };
 
         let arg_exprs: Vec<_> = self
@@ -1178,21 +2265,40 @@ impl Handler {
             .iter()
             .map(|arg| arg.argument_expression())
             .collect();
+        let call = quote! {
+            <#flow_ty as ::std::convert::From<_>>::from(
+                self.#method_ident(event #(, #arg_exprs)*).await,
+            )
+        };
 
-        let mut block = quote! {
-            for event in #event_ident.iter() {
-                #filter_check
-                self.#method_ident(event #(, #arg_exprs)*).await;
+        // Handlers whose state or service is missing are skipped
+        let flow = if self.args.is_empty() {
+            quote! { let __flow: #flow_ty = #call; }
+        } else {
+            let mut block = quote! { __flow = #call; };
+            for arg in self.args.iter().rev() {
+                block = arg.wrap_block(block);
+            }
+            quote! {
+                let mut __flow = #flow_ty::Continue;
+                #block
             }
         };
 
-        for arg in self.args.iter().rev() {
-            block = arg.wrap_block(block);
-        }
-
         quote! {
-            if !#event_ident.is_empty() {
-                #block
+            {
+                #convert
+                if #passes_filter {
+                    #flow
+                    match __flow {
+                        #flow_ty::Continue => {}
+                        #flow_ty::StopEvent => break '__handlers,
+                        #flow_ty::StopAll => {
+                            __stop_all = true;
+                            break '__handlers;
+                        }
+                    }
+                }
             }
         }
     }
@@ -1207,21 +2313,45 @@ impl HandlerArg {
     fn argument_expression(&self) -> proc_macro2::TokenStream {
         let ident = &self.ident;
         match &self.kind {
-            HandlerArgKind::State { mutable, .. } => {
-                if *mutable {
-                    quote! { &mut *#ident }
-                } else {
-                    quote! { &*#ident }
-                }
-            }
+            HandlerArgKind::State {
+                mutable, optional, ..
+            } => match (*mutable, *optional) {
+                (true, false) => quote! { &mut *#ident },
+                (false, false) => quote! { &*#ident },
+                (true, true) => quote! { #ident.as_deref_mut() },
+                (false, true) => quote! { #ident.as_deref() },
+            },
             HandlerArgKind::Service { .. } => quote! { #ident },
+            HandlerArgKind::Events => quote! { &mut __event_writer },
         }
     }
 
     fn wrap_block(&self, block: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
         let ident = &self.ident;
         match &self.kind {
-            HandlerArgKind::State { ty, mutable } => {
+            // Optional state runs the handler with `None` when missing
+            HandlerArgKind::State {
+                ty,
+                mutable,
+                optional: true,
+            } => {
+                if *mutable {
+                    quote! {
+                        let mut #ident = resources.get_mut::<#ty>().await;
+                        #block
+                    }
+                } else {
+                    quote! {
+                        let #ident = resources.get::<#ty>().await;
+                        #block
+                    }
+                }
+            }
+            HandlerArgKind::State {
+                ty,
+                mutable,
+                optional: false,
+            } => {
                 if *mutable {
                     quote! {
                         if let Some(mut #ident) = resources.get_mut::<#ty>().await {
@@ -1244,24 +2374,52 @@ impl HandlerArg {
                     }
                 }
             }
+            HandlerArgKind::Events => block,
         }
     }
 }
 
 enum HandlerArgKind {
-    State { ty: Type, mutable: bool },
-    Service { ty: Type, service_name: String },
+    State {
+        ty: Type,
+        mutable: bool,
+        optional: bool,
+    },
+    Service {
+        ty: Type,
+        service_name: String,
+    },
+    /// `#[events] out: &mut EventWriter`
+    Events,
 }
 
 struct SubscribeAttr {
-    event_type: Type,
+    event_types: Vec<Type>,
     filter: Option<Ident>,
+    priority: i32,
 }
 
+/// `#[subscribe(EventType)]` or `#[subscribe(any(A, B, ...))]`, optionally
+/// followed by `, filter = "method"` and `, priority = N`
 fn parse_subscribe_attr(attr: Attribute) -> Result<SubscribeAttr> {
     attr.parse_args_with(|input: ParseStream| {
-        let event_type: Type = input.parse()?;
+        let fork = input.fork();
+        let is_any =
+            fork.parse::<Ident>().is_ok_and(|ident| ident == "any") && fork.peek(syn::token::Paren);
+        let event_types = if is_any {
+            input.parse::<Ident>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let types = Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+            if types.is_empty() {
+                return Err(content.error("any(...) needs at least one event type"));
+            }
+            types.into_iter().collect()
+        } else {
+            vec![input.parse::<Type>()?]
+        };
         let mut filter = None;
+        let mut priority = 0;
 
         while input.peek(Token![,]) {
             input.parse::<Token![,]>()?;
@@ -1271,12 +2429,22 @@ fn parse_subscribe_attr(attr: Attribute) -> Result<SubscribeAttr> {
                 let lit: LitStr = input.parse()?;
                 let ident = Ident::new(&lit.value(), lit.span());
                 filter = Some(ident);
+            } else if key == "priority" {
+                input.parse::<Token![=]>()?;
+                let negative = input.parse::<Option<Token![-]>>()?.is_some();
+                let lit: syn::LitInt = input.parse()?;
+                let value: i32 = lit.base10_parse()?;
+                priority = if negative { -value } else { value };
             } else {
                 return Err(syn::Error::new(key.span(), "unknown #[subscribe] option"));
             }
         }
 
-        Ok(SubscribeAttr { event_type, filter })
+        Ok(SubscribeAttr {
+            event_types,
+            filter,
+            priority,
+        })
     })
 }
 
@@ -1290,16 +2458,13 @@ fn parse_handler_arg(
 
     for attr in attrs {
         if attr.path().is_ident("state") {
-            if !matches!(attr.meta, Meta::Path(_)) {
-                return Err(syn::Error::new(
-                    attr.span(),
-                    "#[state] does not take arguments",
-                ));
-            }
-            kind = Some(create_state_arg(&pat_type.ty, attr.span())?);
+            let optional = parse_state_attr(&attr)?;
+            kind = Some(create_state_arg(&pat_type.ty, optional, attr.span())?);
         } else if attr.path().is_ident("service") {
             let service_name = parse_service_attr(&attr)?;
             kind = Some(create_service_arg(&pat_type.ty, service_name, attr.span())?);
+        } else if attr.path().is_ident("events") {
+            kind = Some(create_events_arg(&pat_type.ty, attr.span())?);
         } else {
             pat_type.attrs.push(attr);
         }
@@ -1316,6 +2481,7 @@ fn parse_handler_arg(
                 kind: HandlerArgKind::State {
                     ty: default_state.ty.clone(),
                     mutable: true,
+                    optional: false,
                 },
             });
         }
@@ -1323,24 +2489,94 @@ fn parse_handler_arg(
 
     Err(syn::Error::new(
         pat_type.ty.span(),
-        "additional parameters must be marked with #[state] or #[service]",
+        "additional parameters must be marked with #[state], #[service] or #[events]",
     ))
 }
 
-fn create_state_arg(ty: &Type, span: Span) -> Result<HandlerArgKind> {
-    if let Type::Reference(reference) = ty {
-        if reference.mutability.is_none() {
-            return Err(syn::Error::new(span, "state parameters must be `&mut T`"));
+/// `#[state]` or `#[state(optional)]`; returns whether it is optional
+fn parse_state_attr(attr: &Attribute) -> Result<bool> {
+    match &attr.meta {
+        Meta::Path(_) => Ok(false),
+        Meta::List(_) => {
+            let mut optional = false;
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("optional") {
+                    optional = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown #[state] option, expected `optional`"))
+                }
+            })?;
+            Ok(optional)
         }
+        Meta::NameValue(_) => Err(syn::Error::new(
+            attr.span(),
+            "expected `#[state]` or `#[state(optional)]`",
+        )),
+    }
+}
+
+/// `&T` / `&mut T`, or `Option<&T>` / `Option<&mut T>` when optional
+fn create_state_arg(ty: &Type, optional: bool, span: Span) -> Result<HandlerArgKind> {
+    let ty = if optional {
+        option_inner(ty).ok_or_else(|| {
+            syn::Error::new(
+                span,
+                "optional state parameters must be `Option<&T>` or `Option<&mut T>`",
+            )
+        })?
+    } else {
+        ty
+    };
+    if let Type::Reference(reference) = ty {
         Ok(HandlerArgKind::State {
             ty: reference.elem.as_ref().clone(),
-            mutable: true,
+            mutable: reference.mutability.is_some(),
+            optional,
         })
     } else {
         Err(syn::Error::new(span, "state parameters must be references"))
     }
 }
 
+/// `&mut EventWriter`
+fn create_events_arg(ty: &Type, span: Span) -> Result<HandlerArgKind> {
+    if let Type::Reference(reference) = ty {
+        if let (Some(_), Type::Path(type_path)) = (&reference.mutability, reference.elem.as_ref()) {
+            if type_path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "EventWriter")
+            {
+                return Ok(HandlerArgKind::Events);
+            }
+        }
+    }
+    Err(syn::Error::new(
+        span,
+        "#[events] parameters must be `&mut EventWriter`",
+    ))
+}
+
+/// `T` of `Option<T>`
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn create_service_arg(ty: &Type, service_name: String, span: Span) -> Result<HandlerArgKind> {
     if let Type::Reference(reference) = ty {
         if reference.mutability.is_some() {
@@ -1441,8 +2677,15 @@ fn sanitize_ident(raw: &str) -> String {
 
 /// Function-like macro that declares ISSUN events with common derives.
 ///
-/// Generates a struct definition for each event along with the required derives
-/// and an implementation of [`issun::event::Event`].
+/// Generates a struct or enum definition for each event along with the
+/// required derives and an implementation of [`issun::event::Event`].
+/// Events are written as `Name { fields }`, `Name;`, `Name(TypeA, TypeB);`
+/// or `enum Name { Variants }`; extra `#[derive(...)]`s are added to the
+/// common set. Generic events are not supported.
+///
+/// With issun's `event-factories` feature, each event also registers a
+/// factory under its type name, collected by
+/// `issun::event::EventFactoryRegistry::registered()`.
 #[proc_macro]
 pub fn event(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as EventMacroInput);
@@ -1501,8 +2744,19 @@ impl EventDefinition {
             #[derive(#(#derives),*)]
         };
 
-        let body = match self.fields {
-            EventFields::Unit => quote!(;),
+        let attrs = self.attrs;
+        let vis = self.visibility;
+        let name = self.name;
+        let name_str = name.to_string();
+
+        let definition = match self.fields {
+            EventFields::Unit => quote!(#vis struct #name;),
+            EventFields::Tuple(fields) => quote!(#vis struct #name(#fields);),
+            EventFields::Enum(variants) => quote! {
+                #vis enum #name {
+                    #variants
+                }
+            },
             EventFields::Struct(fields) => {
                 let rendered_fields = fields.into_iter().map(|field| {
                     let attrs = field.attrs;
@@ -1516,23 +2770,21 @@ impl EventDefinition {
                 });
 
                 quote! {
-                    {
+                    #vis struct #name {
                         #(#rendered_fields,)*
                     }
                 }
             }
         };
 
-        let attrs = self.attrs;
-        let vis = self.visibility;
-        let name = self.name;
-
         quote! {
             #(#attrs)*
             #derive_attr
-            #vis struct #name #body
+            #definition
 
             impl #crate_name::event::Event for #name {}
+
+            #crate_name::__register_event_factory!(#name, #name_str);
         }
     }
 }
@@ -1547,7 +2799,7 @@ impl Parse for EventDefinition {
             if attr.path().is_ident("derive") {
                 let parsed: Punctuated<Path, Token![,]> =
                     attr.parse_args_with(Punctuated::parse_terminated)?;
-                derives.extend(parsed.into_iter());
+                derives.extend(parsed);
             } else {
                 attrs.push(attr);
             }
@@ -1559,13 +2811,36 @@ impl Parse for EventDefinition {
             Visibility::Inherited
         };
 
-        if input.peek(Token![struct]) {
+        let is_enum = input.peek(Token![enum]);
+        if is_enum {
+            input.parse::<Token![enum]>()?;
+        } else if input.peek(Token![struct]) {
             input.parse::<Token![struct]>()?;
         }
 
         let name: Ident = input.parse()?;
 
-        let fields = if input.peek(Token![;]) {
+        if input.peek(Token![<]) {
+            let generics: syn::Generics = input.parse()?;
+            return Err(syn::Error::new(
+                generics.span(),
+                format!(
+                    "event! does not support generic events; declare `{}` with \
+                     #[derive(Debug, Clone, Serialize, Deserialize)] and implement `Event` for it by hand",
+                    name
+                ),
+            ));
+        }
+
+        let fields = if is_enum {
+            let content;
+            braced!(content in input);
+            EventFields::Enum(content.parse_terminated(syn::Variant::parse, Token![,])?)
+        } else if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            EventFields::Tuple(content.parse_terminated(syn::Field::parse_unnamed, Token![,])?)
+        } else if input.peek(Token![;]) {
             input.parse::<Token![;]>()?;
             EventFields::Unit
         } else {
@@ -1623,7 +2898,9 @@ impl Parse for EventDefinition {
 
 enum EventFields {
     Unit,
+    Tuple(Punctuated<syn::Field, Token![,]>),
     Struct(Vec<EventField>),
+    Enum(Punctuated<syn::Variant, Token![,]>),
 }
 
 struct EventField {
@@ -1754,6 +3031,7 @@ pub fn log(input: TokenStream) -> TokenStream {
 /// - `#[plugin(requires = [Plugin1, Plugin2, ...])]` - Declare issun-bevy plugin dependencies (Phase 2.3)
 /// - `#[plugin(requires_bevy = [BevyPlugin1, ...])]` - Declare Bevy standard plugin dependencies (Phase 2.3)
 /// - `#[plugin(auto_require_core = true)]` - Auto-require IssunCorePlugin (default: true, Phase 2.3)
+/// - `#[plugin(test_harness = true)]` - Generate `test_app()`, `assert_resources()` and `send_and_update()`
 ///
 /// ## Field-level
 /// - `#[config]` - Config resource (insert_resource + builder method)
@@ -1765,12 +3043,44 @@ pub fn log(input: TokenStream) -> TokenStream {
 /// - `Plugin::build()` implementation with resource registration
 /// - Builder methods: `with_config()`, `with_stats()`
 /// - Type registration (if `auto_register_types = true`)
+/// - Test helpers under `cfg(any(test, feature = "test-harness"))` (if `test_harness = true`)
 #[proc_macro_derive(IssunBevyPlugin, attributes(plugin, config, resource, skip))]
 pub fn derive_issun_bevy_plugin(input: TokenStream) -> TokenStream {
     bevy::derive_issun_bevy_plugin_impl(input)
 }
 
 /// Attribute macro that injects `pump_event_systems` calls before/after input handlers.
+///
+/// Options: `before`, `after` (both by default), `pump_fn = path` to call
+/// another pump, and `around = "method"` to also pump after every top-level
+/// statement of the body that calls `.method(..)`, nested blocks and match
+/// arms included. The pump runs once the statement is done, so a bus guard
+/// scoped to it (`if let Some(mut bus) = resources.get_mut::<EventBus>().await
+/// { bus.publish(e); }`) is released first; a guard bound by a top-level
+/// `let` must be dropped before the call. An `auto_pump!();` statement
+/// anywhere in the body becomes a pump too, so systems react to an event
+/// before the next branch:
+///
+/// ```ignore
+/// #[auto_pump(around = "publish")]
+/// async fn handle_input(
+///     &mut self,
+///     services: &ServiceContext,
+///     systems: &mut SystemContext,
+///     resources: &mut ResourceContext,
+///     input: InputEvent,
+/// ) -> SceneTransition<GameScene> {
+///     bus(resources).await.publish(AttackRequested); // pumped here
+///     if self.enemy_defeated(resources).await {
+///         return SceneTransition::Switch(GameScene::Victory);
+///     }
+///     auto_pump!(); // and here
+///     SceneTransition::Stay
+/// }
+/// ```
+///
+/// Pumps run in place, so markers inside closures and async blocks are
+/// rejected and `around` skips them.
 #[proc_macro_attribute]
 pub fn auto_pump(attr: TokenStream, item: TokenStream) -> TokenStream {
     let args = parse_macro_input!(attr as AutoPumpArgs);
@@ -1795,6 +3105,7 @@ struct AutoPumpArgs {
     before: bool,
     after: bool,
     pump_fn: Option<Path>,
+    around: Option<String>,
     sides_specified: bool,
 }
 
@@ -1804,6 +3115,7 @@ impl Default for AutoPumpArgs {
             before: true,
             after: true,
             pump_fn: None,
+            around: None,
             sides_specified: false,
         }
     }
@@ -1819,6 +3131,7 @@ impl Parse for AutoPumpArgs {
             before: false,
             after: false,
             pump_fn: None,
+            around: None,
             sides_specified: false,
         };
 
@@ -1836,12 +3149,29 @@ impl Parse for AutoPumpArgs {
                 "pump_fn" => {
                     input.parse::<Token![=]>()?;
                     let path = if input.peek(LitStr) {
-                        input.parse::<LitStr>()?.parse::<Path>()?
+                        let lit = input.parse::<LitStr>()?;
+                        lit.parse::<Path>().map_err(|_| {
+                            syn::Error::new(
+                                lit.span(),
+                                "pump_fn expects a function path, e.g. pump_fn = crate::pump",
+                            )
+                        })?
                     } else {
                         input.parse::<Path>()?
                     };
                     args.pump_fn = Some(path);
                 }
+                "around" => {
+                    input.parse::<Token![=]>()?;
+                    let method = input.parse::<LitStr>()?;
+                    if method.parse::<Ident>().is_err() {
+                        return Err(syn::Error::new(
+                            method.span(),
+                            "around expects a method name, e.g. around = \"publish\"",
+                        ));
+                    }
+                    args.around = Some(method.value());
+                }
                 other => {
                     return Err(syn::Error::new(
                         ident.span(),
@@ -1871,15 +3201,33 @@ struct PumpParams {
 }
 
 fn apply_auto_pump(signature: &Signature, block: &mut Block, args: &AutoPumpArgs) -> Result<()> {
+    let params = extract_pump_params(signature)?;
+    let pump_path: Path = args
+        .pump_fn
+        .clone()
+        .unwrap_or_else(|| syn::parse_quote!(crate::plugins::pump_event_systems));
+
+    let pump = build_pump_expr(&pump_path, &params);
+    let mut inline = InlinePumps {
+        pump: &pump,
+        deferred: false,
+        errors: Vec::new(),
+    };
+    inline.visit_block_mut(block);
+    if let Some(error) = inline.errors.into_iter().reduce(|mut all, error| {
+        all.combine(error);
+        all
+    }) {
+        return Err(error);
+    }
+    if let Some(method) = args.around.as_deref() {
+        pump_after_calls(block, method, &pump);
+    }
+
     if !args.before && !args.after {
         return Ok(());
     }
 
-    let params = extract_pump_params(signature)?;
-    let pump_path = args.pump_fn.clone().unwrap_or_else(|| {
-        syn::parse_str::<Path>("crate::plugins::pump_event_systems").expect("valid path")
-    });
-
     let mut original = mem::take(&mut block.stmts);
     let mut stmts = Vec::new();
 
@@ -1890,23 +3238,28 @@ fn apply_auto_pump(signature: &Signature, block: &mut Block, args: &AutoPumpArgs
 
     // Check if the last statement is a trailing expression (no semicolon)
     // If so, we need to insert 'after' pump BEFORE it to preserve return value
-    let has_trailing_expr = original
-        .last()
-        .is_some_and(|stmt| matches!(stmt, Stmt::Expr(_, None)));
+    // (`for` and `while` are always `()`, so they are pumped after)
+    let has_trailing_expr = original.last().is_some_and(|stmt| {
+        matches!(stmt, Stmt::Expr(expr, None) if !matches!(expr, Expr::ForLoop(_) | Expr::While(_)))
+    });
 
     if args.after && has_trailing_expr {
         // Insert all but last statement
+        let pumped = ends_with_pump(&original[..original.len() - 1], &pump);
         if original.len() > 1 {
             stmts.extend(original.drain(..original.len() - 1));
         }
-        // Insert 'after' pump
-        stmts.push(build_pump_stmt(&pump_path, &params));
+        // Insert 'after' pump, unless an inline pump just ran
+        if !pumped {
+            stmts.push(build_pump_stmt(&pump_path, &params));
+        }
         // Insert trailing expression last
         stmts.extend(original);
     } else {
         // No trailing expression, just append everything
+        let pumped = ends_with_pump(&original, &pump);
         stmts.extend(original);
-        if args.after {
+        if args.after && !pumped {
             stmts.push(build_pump_stmt(&pump_path, &params));
         }
     }
@@ -1915,16 +3268,157 @@ fn apply_auto_pump(signature: &Signature, block: &mut Block, args: &AutoPumpArgs
     Ok(())
 }
 
+/// Pumps after every top-level statement of `block` that calls `method`
+///
+/// Only at the top level: a guard the call goes through may live until the
+/// end of any block the call is nested in.
+fn pump_after_calls(block: &mut Block, method: &str, pump: &Expr) {
+    let calls = |visit: &dyn Fn(&mut MethodCallFinder<'_>)| {
+        let mut finder = MethodCallFinder {
+            method,
+            found: false,
+        };
+        visit(&mut finder);
+        finder.found
+    };
+
+    let count = block.stmts.len();
+    let mut stmts = Vec::with_capacity(count);
+    for (index, stmt) in mem::take(&mut block.stmts).into_iter().enumerate() {
+        match stmt {
+            Stmt::Expr(expr, None)
+                if index + 1 == count && calls(&|finder| finder.visit_expr(&expr)) =>
+            {
+                // Keep the block's value after the pump
+                stmts.push(parse_quote! { let __auto_pump_value = #expr; });
+                stmts.push(parse_quote! { #pump; });
+                stmts.push(Stmt::Expr(parse_quote! { __auto_pump_value }, None));
+            }
+            stmt if calls(&|finder| finder.visit_stmt(&stmt)) => {
+                stmts.push(stmt);
+                stmts.push(parse_quote! { #pump; });
+            }
+            stmt => stmts.push(stmt),
+        }
+    }
+    block.stmts = stmts;
+}
+
+fn ends_with_pump(stmts: &[Stmt], pump: &Expr) -> bool {
+    stmts.last().is_some_and(|stmt| match stmt {
+        Stmt::Expr(expr, Some(_)) => {
+            expr.to_token_stream().to_string() == pump.to_token_stream().to_string()
+        }
+        _ => false,
+    })
+}
+
 fn build_pump_stmt(path: &Path, params: &PumpParams) -> Stmt {
+    let pump = build_pump_expr(path, params);
+    parse_quote! { #pump; }
+}
+
+fn build_pump_expr(path: &Path, params: &PumpParams) -> Expr {
     let services = &params.services;
     let systems = &params.systems;
     let resources = &params.resources;
 
-    // Use quote! to generate tokens, then parse into Stmt
-    let tokens = quote! {
-        #path(#services, #systems, #resources).await;
-    };
-    syn::parse2(tokens).expect("failed to parse pump statement")
+    parse_quote! { #path(#services, #systems, #resources).await }
+}
+
+/// Turns `auto_pump!()` markers into pumps
+struct InlinePumps<'a> {
+    pump: &'a Expr,
+    /// Inside a closure or async block, which doesn't run in place
+    deferred: bool,
+    errors: Vec<syn::Error>,
+}
+
+impl InlinePumps<'_> {
+    fn is_marker(&mut self, mac: &syn::Macro) -> bool {
+        if mac
+            .path
+            .segments
+            .last()
+            .is_none_or(|segment| segment.ident != "auto_pump")
+        {
+            return false;
+        }
+        if self.deferred {
+            self.errors.push(syn::Error::new_spanned(
+                mac,
+                "auto_pump!() cannot be used inside a closure or async block",
+            ));
+        } else if !mac.tokens.is_empty() {
+            self.errors.push(syn::Error::new_spanned(
+                &mac.tokens,
+                "auto_pump!() takes no arguments",
+            ));
+        }
+        true
+    }
+
+    fn with_deferred(&mut self, visit: impl FnOnce(&mut Self)) {
+        let outer = mem::replace(&mut self.deferred, true);
+        visit(self);
+        self.deferred = outer;
+    }
+}
+
+impl VisitMut for InlinePumps<'_> {
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        visit_mut::visit_block_mut(self, block);
+
+        let pump = self.pump;
+        for stmt in &mut block.stmts {
+            if let Stmt::Macro(stmt_macro) = stmt {
+                if self.is_marker(&stmt_macro.mac) {
+                    *stmt = parse_quote! { #pump; };
+                }
+            }
+        }
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Macro(expr_macro) = expr {
+            if self.is_marker(&expr_macro.mac) {
+                *expr = self.pump.clone();
+                return;
+            }
+        }
+        visit_mut::visit_expr_mut(self, expr);
+    }
+
+    fn visit_expr_closure_mut(&mut self, closure: &mut syn::ExprClosure) {
+        self.with_deferred(|inline| visit_mut::visit_expr_closure_mut(inline, closure));
+    }
+
+    fn visit_expr_async_mut(&mut self, async_block: &mut syn::ExprAsync) {
+        self.with_deferred(|inline| visit_mut::visit_expr_async_mut(inline, async_block));
+    }
+
+    // Nested items are separate functions
+    fn visit_item_mut(&mut self, _item: &mut syn::Item) {}
+}
+
+/// Looks for calls to `method` made by a statement, nested blocks and match
+/// arms included; closures and async blocks don't run in place
+struct MethodCallFinder<'a> {
+    method: &'a str,
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for MethodCallFinder<'_> {
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        self.found |= call.method == self.method;
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_closure(&mut self, _closure: &'ast syn::ExprClosure) {}
+
+    fn visit_expr_async(&mut self, _async_block: &'ast syn::ExprAsync) {}
+
+    fn visit_item(&mut self, _item: &'ast syn::Item) {}
 }
 
 fn extract_pump_params(signature: &Signature) -> Result<PumpParams> {
@@ -1979,3 +3473,250 @@ fn matches_context(ty: &Type, expected: &str) -> bool {
         _ => false,
     }
 }
+
+/// Attribute macro for implementing a plugin Hook trait
+///
+/// Every plugin Hook (`CombatHook`, `LootHook`, `TerritoryHook`, ...) gives
+/// each method a documented default, so an impl only lists the methods it
+/// overrides; the rest keep the trait's defaults. The macro wraps the impl
+/// in issun's re-exported `async_trait` and makes plain `fn` methods async,
+/// so games need neither the `async-trait` dependency nor the `async`
+/// keyword on every method:
+///
+/// ```ignore
+/// use issun::context::ResourceContext;
+/// use issun::hook_impl;
+/// use issun::plugin::combat::{BattleId, CombatHook};
+///
+/// struct TurnLimit;
+///
+/// #[hook_impl]
+/// impl CombatHook for TurnLimit {
+///     fn before_turn(
+///         &self,
+///         _battle_id: &BattleId,
+///         turn: u32,
+///         _resources: &ResourceContext,
+///     ) -> Result<(), String> {
+///         if turn > 10 { Err("the enemy retreats".into()) } else { Ok(()) }
+///     }
+/// }
+/// ```
+///
+/// An impl with no methods at all is a hook that keeps every default.
+#[proc_macro_attribute]
+pub fn hook_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        return syn::Error::new_spanned(attr, "#[hook_impl] takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+
+    let mut hook = parse_macro_input!(item as ItemImpl);
+    if let Err(err) = apply_hook_impl(&mut hook) {
+        return err.to_compile_error().into();
+    }
+
+    let crate_name = get_crate_name();
+    TokenStream::from(quote! {
+        #[#crate_name::async_trait::async_trait]
+        #hook
+    })
+}
+
+fn apply_hook_impl(hook: &mut ItemImpl) -> Result<()> {
+    if hook.trait_.is_none() {
+        return Err(syn::Error::new_spanned(
+            &hook.self_ty,
+            "#[hook_impl] expects a Hook trait impl, e.g. impl CombatHook for MyHook",
+        ));
+    }
+
+    for item in &mut hook.items {
+        if let ImplItem::Fn(method) = item {
+            if method.sig.asyncness.is_none() {
+                method.sig.asyncness = Some(Token![async](method.sig.fn_token.span));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generic_event_is_rejected_with_a_clear_error() {
+        let error = match syn::parse_str::<EventMacroInput>("pub Wrapped<T: Clone> { value: T }") {
+            Ok(_) => panic!("generic event was accepted"),
+            Err(error) => error.to_string(),
+        };
+        assert!(
+            error.starts_with("event! does not support generic events"),
+            "{}",
+            error
+        );
+        assert!(error.contains("`Wrapped`"), "{}", error);
+    }
+
+    #[test]
+    fn test_event_shapes_parse() {
+        let input = syn::parse_str::<EventMacroInput>(
+            "pub A { x: u32 } B; C(i64); D(pub u8, String); pub enum E { F { y: u32 }, G(u8), H }",
+        )
+        .unwrap();
+        let shapes: Vec<&str> = input
+            .events
+            .iter()
+            .map(|event| match &event.fields {
+                EventFields::Unit => "unit",
+                EventFields::Tuple(_) => "tuple",
+                EventFields::Struct(_) => "struct",
+                EventFields::Enum(_) => "enum",
+            })
+            .collect();
+        assert_eq!(shapes, ["struct", "unit", "tuple", "tuple", "enum"]);
+    }
+
+    fn expand_auto_pump(args: &str, function: &str) -> Result<String> {
+        let args = syn::parse_str::<AutoPumpArgs>(args)?;
+        let mut function = syn::parse_str::<ItemFn>(function)?;
+        apply_auto_pump(&function.sig, &mut function.block, &args)?;
+        Ok(function.block.to_token_stream().to_string())
+    }
+
+    const PUMPED_SIGNATURE: &str = "async fn handle(s: &ServiceContext, sy: &mut SystemContext, r: &mut ResourceContext) -> u32";
+
+    #[test]
+    fn test_auto_pump_marker_pumps_between_publishes() {
+        let expanded = expand_auto_pump(
+            "after, pump_fn = pump",
+            &format!(
+                "{} {{ bus.publish(A); auto_pump!(); bus.publish(B); 7 }}",
+                PUMPED_SIGNATURE
+            ),
+        )
+        .unwrap();
+        let expected = quote! {{
+            bus.publish(A);
+            pump(s, sy, r).await;
+            bus.publish(B);
+            pump(s, sy, r).await;
+            7
+        }};
+        assert_eq!(expanded, expected.to_string());
+    }
+
+    #[test]
+    fn test_auto_pump_around_pumps_after_top_level_statements() {
+        let expanded = expand_auto_pump(
+            "after, pump_fn = pump, around = \"publish\"",
+            &format!(
+                "{} {{
+                    bus.publish(A);
+                    if ready {{ bus.publish(B); }}
+                    let handler = |event| bus.publish(event);
+                    match choice {{ 1 => bus.publish(D), _ => {{}} }}
+                    bus.publish(E)
+                }}",
+                PUMPED_SIGNATURE
+            ),
+        )
+        .unwrap();
+        // Pumped after the top-level statement, once its guards are dropped
+        let expected = quote! {{
+            bus.publish(A);
+            pump(s, sy, r).await;
+            if ready {
+                bus.publish(B);
+            }
+            pump(s, sy, r).await;
+            let handler = |event| bus.publish(event);
+            match choice {
+                1 => bus.publish(D),
+                _ => {}
+            }
+            pump(s, sy, r).await;
+            let __auto_pump_value = bus.publish(E);
+            pump(s, sy, r).await;
+            __auto_pump_value
+        }};
+        assert_eq!(expanded, expected.to_string());
+    }
+
+    #[test]
+    fn test_auto_pump_rejects_markers_in_closures() {
+        let error = expand_auto_pump(
+            "pump_fn = pump",
+            &format!(
+                "{} {{ let later = || {{ auto_pump!(); }}; 1 }}",
+                PUMPED_SIGNATURE
+            ),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "auto_pump!() cannot be used inside a closure or async block"
+        );
+    }
+
+    fn expand_hook_impl(hook: &str) -> Result<Vec<String>> {
+        let mut hook = syn::parse_str::<ItemImpl>(hook)?;
+        apply_hook_impl(&mut hook)?;
+        Ok(hook
+            .items
+            .iter()
+            .map(|item| match item {
+                ImplItem::Fn(method) => method.sig.to_token_stream().to_string(),
+                other => other.to_token_stream().to_string(),
+            })
+            .collect())
+    }
+
+    #[test]
+    fn test_hook_impl_without_overrides_stays_empty() {
+        assert!(expand_hook_impl("impl ActionHook for Quiet {}")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_hook_impl_makes_an_override_async() {
+        let expanded = expand_hook_impl(
+            "impl ActionHook for Log { fn on_actions_reset(&self, n: u32, r: &mut ResourceContext) {} }",
+        )
+        .unwrap();
+        assert_eq!(
+            expanded,
+            [
+                quote! { async fn on_actions_reset(&self, n: u32, r: &mut ResourceContext) }
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hook_impl_keeps_every_override() {
+        let expanded = expand_hook_impl(
+            "impl ActionHook for Full {
+                async fn on_action_consumed(&self, c: &ActionConsumed, r: &mut ResourceContext) {}
+                fn on_actions_depleted(&self, r: &mut ResourceContext) -> bool { false }
+                fn on_actions_reset(&self, n: u32, r: &mut ResourceContext) {}
+            }",
+        )
+        .unwrap();
+        assert_eq!(expanded.len(), 3);
+        assert!(expanded.iter().all(|sig| sig.starts_with("async fn")));
+    }
+
+    #[test]
+    fn test_hook_impl_rejects_inherent_impls() {
+        let error = expand_hook_impl("impl MyHook { fn helper(&self) {} }").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "#[hook_impl] expects a Hook trait impl, e.g. impl CombatHook for MyHook"
+        );
+    }
+}
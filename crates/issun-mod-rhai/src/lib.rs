@@ -14,14 +14,30 @@
 //!     .build()
 //!     .await?;
 //! ```
+//!
+//! MODs are either a single `.rhai` file or a directory with a `mod.toml`
+//! manifest (see `issun::modding::ModManifest`) and an entry script.
 
+use issun::engine::rng::MasterSeed;
 use issun::modding::{
-    ModBackend, ModError, ModHandle, ModLoader, ModMetadata, ModResult, PluginAction, PluginControl,
+    EventSchema, ModActionDefinition, ModBackend, ModError, ModHandle, ModLoader, ModLogEntry,
+    ModLogLevel, ModManifest, ModMetadata, ModPermissionDenied, ModPermissionPolicy,
+    ModPermissions, ModResult, ModRng, ModRuntimeErrorEvent, ModStrings, PluginAction,
+    PluginControl, PluginParams,
 };
-use rhai::{Dynamic, Engine, FnPtr, Scope, AST};
+use rhai::{Dynamic, Engine, EvalAltResult, FnAccess, FnPtr, NativeCallContext, Scope, AST};
 use std::collections::HashMap;
-use std::path::Path;
-use std::sync::{Arc, Mutex};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::SystemTime;
+
+/// Log lines kept when nobody drains them; older ones are dropped first
+const MAX_QUEUED_LOGS: usize = 1000;
+
+/// Nesting limit of `call_mod()`, so MODs calling each other can't recurse forever
+const MAX_MOD_CALL_DEPTH: usize = 16;
 
 /// Event subscription from a MOD script
 #[derive(Clone)]
@@ -30,31 +46,155 @@ pub struct EventSubscription {
     pub callback: FnPtr,
 }
 
+/// Host customization of the script engine, re-applied to clones
+type EngineSetup = Arc<dyn Fn(&mut Engine) + Send + Sync>;
+
+/// Callback registered by `schedule(turns, callback)`
+#[derive(Clone)]
+struct ScheduledCallback {
+    mod_id: String,
+    remaining_turns: u64,
+    callback: FnPtr,
+}
+
+/// Persistent key-value store of a single MOD (`store_set` / `store_get`)
+type ModStore = serde_json::Map<String, serde_json::Value>;
+
+/// Payload schema registered by `declare_event`
+#[derive(Clone)]
+struct DeclaredEvent {
+    mod_id: String,
+    schema: EventSchema,
+}
+
+/// Permissions of the loaded MODs and the calls they refused
+#[derive(Clone, Default)]
+struct Permissions {
+    by_mod: HashMap<String, ModPermissions>,
+    denied: Vec<ModPermissionDenied>,
+}
+
+impl Permissions {
+    /// Whether the executing MOD passes `check`; refusals are queued
+    ///
+    /// Host code running outside a MOD is always permitted.
+    fn permits(
+        permissions: &Shared<Permissions>,
+        mod_id: Option<&str>,
+        check: impl FnOnce(&ModPermissions, &str) -> Result<(), ModPermissionDenied>,
+    ) -> bool {
+        let Some(mod_id) = mod_id else {
+            return true;
+        };
+        let mut permissions = permissions.lock();
+        let Some(granted) = permissions.by_mod.get(mod_id) else {
+            return true;
+        };
+        match check(granted, mod_id) {
+            Ok(()) => true,
+            Err(denied) => {
+                eprintln!(
+                    "[RhaiLoader] MOD '{}' may not {}",
+                    denied.mod_id, denied.action
+                );
+                permissions.denied.push(denied);
+                false
+            }
+        }
+    }
+}
+
+/// Execution limits for MOD scripts
+///
+/// Every script call (`on_init`, `on_update`, event callbacks, ...) runs with
+/// its own operation budget, so a runaway loop fails that call with
+/// `ModError::ExecutionFailed` instead of hanging the game. A value of `0`
+/// disables the corresponding limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RhaiLoaderConfig {
+    /// Maximum number of operations per script call
+    pub max_operations: u64,
+    /// Maximum depth of nested function calls
+    pub max_call_depth: usize,
+    /// Maximum length of a string, in bytes
+    pub max_string_size: usize,
+}
+
+impl Default for RhaiLoaderConfig {
+    fn default() -> Self {
+        Self {
+            max_operations: 1_000_000,
+            max_call_depth: 64,
+            max_string_size: 1024 * 1024,
+        }
+    }
+}
+
 /// Rhai-based MOD loader
 ///
 /// Loads and executes Rhai scripts that can control ISSUN plugins.
 pub struct RhaiLoader {
     engine: Engine,
+    limits: RhaiLoaderConfig,
     scripts: HashMap<String, LoadedScript>,
-    command_queue: Arc<Mutex<Vec<PluginControl>>>,
-    event_subscriptions: Arc<Mutex<HashMap<String, Vec<EventSubscription>>>>, // mod_id -> subscriptions
-    event_publish_queue: Arc<Mutex<Vec<(String, serde_json::Value)>>>,        // (event_type, data)
+    command_queue: Shared<Vec<PluginControl>>,
+    event_subscriptions: Shared<HashMap<String, Vec<EventSubscription>>>, // mod_id -> subscriptions
+    event_publish_queue: Shared<Vec<(String, serde_json::Value)>>,        // (event_type, data)
+    current_mod: Shared<Option<String>>, // MOD whose script is currently executing
+    stores: Shared<HashMap<String, ModStore>>, // mod_id -> persistent store
+    plugin_params: Shared<PluginParams>, // refreshed by ModBridgeSystem each frame
+    rng: Shared<ScriptRng>,              // backs random()/random_range()/random_int()
+    string_queue: Shared<Vec<ModStrings>>, // queued by register_strings()
+    action_queue: Shared<Vec<ModActionDefinition>>, // queued by register_action()
+    event_schemas: Shared<HashMap<String, DeclaredEvent>>, // event_type -> declaration
+    schedules: Shared<Vec<ScheduledCallback>>, // in scheduling order
+    log_queue: Shared<Vec<ModLogEntry>>, // queued by log()/log_warn()/log_error()
+    stdout_logging: Arc<AtomicBool>,     // also print log lines (headless use)
+    libraries: Shared<HashMap<String, AST>>, // mod_id -> functions, for call_mod()
+    permissions: Shared<Permissions>,    // checked by plugin control and publish_event
+    recovery: Arc<LockRecovery>,         // poisoned locks recovered since the last drain_errors
+    permission_policy: ModPermissionPolicy,
+    errors: Vec<ModRuntimeErrorEvent>, // failed callbacks and reloads, drained by ModEventSystem
+    engine_setups: Vec<EngineSetup>,
+    dispatch_order: Vec<String>, // mod ids, set by ModLoadSystem
+    seed: Option<u64>,
+    watch: bool,
 }
 
+#[derive(Clone)]
 struct LoadedScript {
     ast: AST,
     scope: Scope<'static>,
     #[allow(dead_code)] // Reserved for future event callback implementation
     mod_id: String,
+    path: PathBuf,
+    dir: Option<PathBuf>, // MOD directory holding mod.toml, for directory MODs
+    modified: Option<SystemTime>,
+    has_on_update: bool, // checked once so ticks don't pay for a failed lookup
 }
 
 impl RhaiLoader {
     /// Create a new RhaiLoader with default ISSUN API bindings
     pub fn new() -> Self {
-        let command_queue = Arc::new(Mutex::new(Vec::new()));
-        let event_subscriptions = Arc::new(Mutex::new(HashMap::new()));
-        let event_publish_queue = Arc::new(Mutex::new(Vec::new()));
+        let recovery = Arc::new(LockRecovery::default());
+        let command_queue = Shared::new("command_queue", Vec::new(), &recovery);
+        let event_subscriptions = Shared::new("event_subscriptions", HashMap::new(), &recovery);
+        let event_publish_queue = Shared::new("event_publish_queue", Vec::new(), &recovery);
+        let current_mod = Shared::new("current_mod", None, &recovery);
+        let stores = Shared::new("stores", HashMap::new(), &recovery);
+        let plugin_params = Shared::new("plugin_params", HashMap::new(), &recovery);
+        let rng = Shared::new("rng", ScriptRng::default(), &recovery);
+        let string_queue = Shared::new("string_queue", Vec::new(), &recovery);
+        let action_queue = Shared::new("action_queue", Vec::new(), &recovery);
+        let event_schemas = Shared::new("event_schemas", HashMap::new(), &recovery);
+        let schedules = Shared::new("schedules", Vec::new(), &recovery);
+        let log_queue = Shared::new("log_queue", Vec::new(), &recovery);
+        let stdout_logging = Arc::new(AtomicBool::new(false));
+        let libraries = Shared::new("libraries", HashMap::new(), &recovery);
+        let permissions = Shared::new("permissions", Permissions::default(), &recovery);
         let mut engine = Engine::new();
+        let limits = RhaiLoaderConfig::default();
+        Self::apply_limits(&mut engine, &limits);
 
         // Register ISSUN API functions
         Self::register_api(
@@ -62,113 +202,791 @@ impl RhaiLoader {
             command_queue.clone(),
             event_subscriptions.clone(),
             event_publish_queue.clone(),
+            current_mod.clone(),
+            stores.clone(),
+            plugin_params.clone(),
+            rng.clone(),
+            string_queue.clone(),
+            action_queue.clone(),
+            event_schemas.clone(),
+            schedules.clone(),
+            log_queue.clone(),
+            stdout_logging.clone(),
+            libraries.clone(),
+            permissions.clone(),
         );
 
         Self {
             engine,
+            limits,
             scripts: HashMap::new(),
             command_queue,
             event_subscriptions,
             event_publish_queue,
+            current_mod,
+            stores,
+            plugin_params,
+            rng,
+            string_queue,
+            action_queue,
+            event_schemas,
+            schedules,
+            log_queue,
+            stdout_logging,
+            libraries,
+            permissions,
+            recovery,
+            permission_policy: ModPermissionPolicy::default(),
+            errors: Vec::new(),
+            engine_setups: Vec::new(),
+            dispatch_order: Vec::new(),
+            seed: None,
+            watch: false,
+        }
+    }
+
+    /// Make `random()`, `random_range()` and `random_int()` deterministic
+    ///
+    /// Loaders with the same seed that run the same scripts in the same order
+    /// see the same random numbers, which keeps recorded replays valid.
+    /// Once a master seed is set (`ModLoader::set_master_seed`), every MOD
+    /// draws from its own stream instead and this seed only serves code
+    /// running outside a MOD.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        *self.rng.lock() = ScriptRng::seeded(seed);
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Seed of the script random generator, if it is deterministic
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Replace the script execution limits
+    pub fn with_limits(mut self, limits: RhaiLoaderConfig) -> Self {
+        Self::apply_limits(&mut self.engine, &limits);
+        self.limits = limits;
+        self
+    }
+
+    /// Current script execution limits
+    pub fn limits(&self) -> RhaiLoaderConfig {
+        self.limits
+    }
+
+    fn apply_limits(engine: &mut Engine, limits: &RhaiLoaderConfig) {
+        engine.set_max_operations(limits.max_operations);
+        engine.set_max_call_levels(limits.max_call_depth);
+        engine.set_max_string_size(limits.max_string_size);
+    }
+
+    /// Customize the script engine, e.g. to register game-specific functions
+    ///
+    /// Call this before loading MODs: scripts compiled earlier don't see
+    /// the changes. The setup is re-applied to every clone of the loader.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let gold = Arc::new(AtomicI64::new(0));
+    /// let loader = RhaiLoader::new().with_engine_setup({
+    ///     let gold = gold.clone();
+    ///     move |engine| {
+    ///         let gold = gold.clone();
+    ///         engine.register_fn("add_gold", move |amount: i64| {
+    ///             gold.fetch_add(amount, Ordering::Relaxed);
+    ///         });
+    ///     }
+    /// });
+    /// ```
+    pub fn with_engine_setup(
+        mut self,
+        setup: impl Fn(&mut Engine) + Send + Sync + 'static,
+    ) -> Self {
+        setup(&mut self.engine);
+        self.engine_setups.push(Arc::new(setup));
+        self
+    }
+
+    /// Watch loaded script files and reload them when they change
+    ///
+    /// When enabled, file modification times are checked on every
+    /// `drain_commands()` call and changed MODs are reloaded in place.
+    pub fn with_watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
+        self
+    }
+
+    /// Also print MOD log lines to stdout
+    ///
+    /// Off by default: log lines are only queued for `drain_logs()`, which
+    /// keeps terminal UIs intact. Enable for headless runs and tools.
+    pub fn with_stdout_logging(self, enabled: bool) -> Self {
+        self.stdout_logging.store(enabled, Ordering::Relaxed);
+        self
+    }
+
+    /// Reload every MOD whose script file changed since it was (re)loaded
+    ///
+    /// Returns the handles of the reloaded MODs. Failed reloads are queued
+    /// as runtime errors and the previous version stays active.
+    pub fn reload_changed(&mut self) -> Vec<ModHandle> {
+        let changed: Vec<String> = self
+            .scripts
+            .iter()
+            .filter(|(_, script)| modified_time(&script.path) > script.modified)
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        let mut reloaded = Vec::new();
+        for mod_id in changed {
+            match self.reload_script(&mod_id) {
+                Ok(handle) => {
+                    if self.stdout_logging.load(Ordering::Relaxed) {
+                        println!("[RhaiLoader] Reloaded MOD '{}'", mod_id);
+                    }
+                    reloaded.push(handle);
+                }
+                Err(e) => {
+                    self.errors
+                        .push(ModRuntimeErrorEvent::new(&mod_id, "reload", e));
+                    // Don't retry until the file changes again
+                    if let Some(script) = self.scripts.get_mut(&mod_id) {
+                        script.modified = modified_time(&script.path);
+                    }
+                }
+            }
+        }
+        reloaded
+    }
+
+    /// Recompile a loaded script and re-run its `on_init()`
+    ///
+    /// The script scope is carried over so top-level state survives the reload.
+    /// Event subscriptions of the old version are dropped before `on_init()` runs.
+    fn reload_script(&mut self, mod_id: &str) -> ModResult<ModHandle> {
+        let (path, dir) = self
+            .scripts
+            .get(mod_id)
+            .map(|script| (script.path.clone(), script.dir.clone()))
+            .ok_or_else(|| ModError::NotFound(format!("Script '{}' not loaded", mod_id)))?;
+
+        let manifest = dir.as_deref().map(ModManifest::from_dir).transpose()?;
+        let granted = match &manifest {
+            Some(manifest) => self.permission_policy.resolve(Some(manifest))?,
+            None => self.permission_policy.permissions_for(&path)?,
+        };
+        let modified = modified_time(&path);
+        let ast = self.compile_file(&path)?;
+
+        // Compilation succeeded, swap out the old version
+        let mut scope = match self.scripts.remove(mod_id) {
+            Some(old) => old.scope,
+            None => Scope::new(),
+        };
+
+        self.event_subscriptions.lock().remove(mod_id);
+        self.drop_event_schemas(mod_id);
+        self.drop_schedules(mod_id);
+        self.grant(mod_id, granted);
+
+        let _guard = self.enter_mod(mod_id);
+        let mut metadata = self.extract_metadata(mod_id, &ast, &mut scope)?;
+        if let Some(manifest) = &manifest {
+            apply_manifest(&mut metadata, manifest)?;
+        }
+        self.run_on_init(mod_id, &ast, &mut scope)?;
+
+        self.publish_library(mod_id, &ast);
+        self.scripts.insert(
+            mod_id.to_string(),
+            LoadedScript {
+                has_on_update: defines_on_update(&ast),
+                ast,
+                scope,
+                mod_id: mod_id.to_string(),
+                path,
+                dir,
+                modified,
+            },
+        );
+
+        Ok(ModHandle {
+            id: mod_id.to_string(),
+            metadata,
+            backend: ModBackend::Rhai,
+        })
+    }
+
+    /// Attach the permissions host functions check for `mod_id`
+    fn grant(&self, mod_id: &str, granted: ModPermissions) {
+        self.permissions
+            .lock()
+            .by_mod
+            .insert(mod_id.to_string(), granted);
+    }
+
+    /// Read and compile a script file
+    fn compile_file(&self, path: &Path) -> ModResult<AST> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| ModError::LoadFailed(format!("Failed to read file: {}", e)))?;
+
+        self.engine
+            .compile(&content)
+            .map_err(|e| ModError::InvalidFormat(format!("Compilation error: {}", e)))
+    }
+
+    /// Call `on_init()` if the script defines it
+    ///
+    /// Only limit violations are fatal; on failure the subscriptions made so
+    /// far are dropped again.
+    fn run_on_init(&self, mod_id: &str, ast: &AST, scope: &mut Scope<'static>) -> ModResult<()> {
+        if let Err(e) = self.engine.call_fn::<()>(scope, ast, "on_init", ()) {
+            if let Some(err) = self.limit_error(mod_id, &e) {
+                self.event_subscriptions.lock().remove(mod_id);
+                return Err(err);
+            }
         }
+        Ok(())
+    }
+
+    /// Map a script error caused by an execution limit to `ModError::ExecutionFailed`
+    ///
+    /// Returns `None` for every other error so callers keep their own handling.
+    fn limit_error(&self, mod_id: &str, error: &EvalAltResult) -> Option<ModError> {
+        let limit = match error {
+            EvalAltResult::ErrorTooManyOperations(_) => {
+                format!("max_operations ({})", self.limits.max_operations)
+            }
+            EvalAltResult::ErrorStackOverflow(_) => {
+                format!("max_call_depth ({})", self.limits.max_call_depth)
+            }
+            EvalAltResult::ErrorDataTooLarge(..) => {
+                format!("max_string_size ({})", self.limits.max_string_size)
+            }
+            EvalAltResult::ErrorInFunctionCall(_, _, inner, _)
+            | EvalAltResult::ErrorInModule(_, inner, _) => return self.limit_error(mod_id, inner),
+            _ => return None,
+        };
+        Some(ModError::ExecutionFailed(format!(
+            "MOD '{}' exceeded {}",
+            mod_id, limit
+        )))
+    }
+
+    /// Make the functions of `mod_id` callable by other MODs via `call_mod()`
+    fn publish_library(&self, mod_id: &str, ast: &AST) {
+        self.libraries
+            .lock()
+            .insert(mod_id.to_string(), ast.clone_functions_only());
+    }
+
+    /// Forget the event schemas declared by `mod_id`
+    fn drop_event_schemas(&self, mod_id: &str) {
+        self.event_schemas
+            .lock()
+            .retain(|_, declared| declared.mod_id != mod_id);
+    }
+
+    /// Cancel the pending callbacks scheduled by `mod_id`
+    fn drop_schedules(&self, mod_id: &str) {
+        self.schedules
+            .lock()
+            .retain(|scheduled| scheduled.mod_id != mod_id);
+    }
+
+    /// Mark `mod_id` as the executing MOD until the returned guard is dropped
+    ///
+    /// Host functions such as `subscribe_event` read this to attribute
+    /// their side effects to the right MOD.
+    fn enter_mod(&self, mod_id: &str) -> CurrentModGuard {
+        CurrentModGuard::enter(self.current_mod.clone(), mod_id)
     }
 
     /// Register ISSUN API functions that scripts can call
+    #[allow(clippy::too_many_arguments)]
     fn register_api(
         engine: &mut Engine,
-        queue: Arc<Mutex<Vec<PluginControl>>>,
-        subscriptions: Arc<Mutex<HashMap<String, Vec<EventSubscription>>>>,
-        publish_queue: Arc<Mutex<Vec<(String, serde_json::Value)>>>,
+        queue: Shared<Vec<PluginControl>>,
+        subscriptions: Shared<HashMap<String, Vec<EventSubscription>>>,
+        publish_queue: Shared<Vec<(String, serde_json::Value)>>,
+        current_mod: Shared<Option<String>>,
+        stores: Shared<HashMap<String, ModStore>>,
+        plugin_params: Shared<PluginParams>,
+        rng: Shared<ScriptRng>,
+        string_queue: Shared<Vec<ModStrings>>,
+        action_queue: Shared<Vec<ModActionDefinition>>,
+        event_schemas: Shared<HashMap<String, DeclaredEvent>>,
+        schedules: Shared<Vec<ScheduledCallback>>,
+        log_queue: Shared<Vec<ModLogEntry>>,
+        stdout_logging: Arc<AtomicBool>,
+        libraries: Shared<HashMap<String, AST>>,
+        permissions: Shared<Permissions>,
     ) {
         // Logging API
-        engine.register_fn("log", |msg: &str| {
-            println!("[MOD] {}", msg);
-        });
+        for (name, level) in [
+            ("log", ModLogLevel::Info),
+            ("log_warn", ModLogLevel::Warn),
+            ("log_error", ModLogLevel::Error),
+        ] {
+            let logs = log_queue.clone();
+            let current = current_mod.clone();
+            let stdout = stdout_logging.clone();
+            engine.register_fn(name, move |msg: &str| {
+                let mod_id = current.lock().clone();
+                if stdout.load(Ordering::Relaxed) {
+                    match (&mod_id, level) {
+                        (Some(id), ModLogLevel::Info) => println!("[MOD {}] {}", id, msg),
+                        (Some(id), _) => println!("[MOD {}] {}: {}", id, level, msg),
+                        (None, ModLogLevel::Info) => println!("[MOD] {}", msg),
+                        (None, _) => println!("[MOD] {}: {}", level, msg),
+                    }
+                }
+                let mut logs = logs.lock();
+                if logs.len() >= MAX_QUEUED_LOGS {
+                    logs.remove(0);
+                }
+                logs.push(ModLogEntry::new(mod_id, level, msg));
+            });
+        }
+
+        // Plugin control API, commands are attributed to the calling MOD and
+        // dropped unless its permissions cover the plugin
+        let push_control = {
+            let q = queue.clone();
+            let current = current_mod.clone();
+            let permissions = permissions.clone();
+            move |control: PluginControl| {
+                let mod_id = current.lock().clone();
+                let permitted =
+                    Permissions::permits(&permissions, mod_id.as_deref(), |granted, id| {
+                        granted.check_control(id, &control)
+                    });
+                if !permitted {
+                    return;
+                }
+                let control = match mod_id {
+                    Some(mod_id) => control.with_issuer(mod_id),
+                    None => control,
+                };
+                q.lock().push(control);
+            }
+        };
 
         // Plugin control API - Enable
         {
-            let q = queue.clone();
+            let push = push_control.clone();
             engine.register_fn("enable_plugin", move |name: &str| {
-                let control = PluginControl::enable(name);
-                if let Ok(mut queue) = q.lock() {
-                    queue.push(control);
-                }
+                push(PluginControl::enable(name));
             });
         }
 
         // Plugin control API - Disable
         {
-            let q = queue.clone();
+            let push = push_control.clone();
             engine.register_fn("disable_plugin", move |name: &str| {
-                let control = PluginControl::disable(name);
-                if let Ok(mut queue) = q.lock() {
-                    queue.push(control);
-                }
+                push(PluginControl::disable(name));
             });
         }
 
         // Plugin control API - Set Parameter
         {
-            let q = queue.clone();
+            let push = push_control;
             engine.register_fn(
                 "set_plugin_param",
                 move |plugin: &str, key: &str, value: Dynamic| {
                     let json_value = dynamic_to_json(value);
-                    let control = PluginControl::set_param(plugin, key, json_value);
-                    if let Ok(mut queue) = q.lock() {
-                        queue.push(control);
-                    }
+                    push(PluginControl::set_param(plugin, key, json_value));
+                },
+            );
+        }
+
+        // Plugin state API - Read Parameter (`()` if unknown)
+        {
+            let params = plugin_params;
+            engine.register_fn(
+                "get_plugin_param",
+                move |plugin: &str, key: &str| -> Dynamic {
+                    let plugin = plugin.strip_prefix("issun:").unwrap_or(plugin);
+                    params
+                        .lock()
+                        .get(&(plugin.to_string(), key.to_string()))
+                        .map(json_to_dynamic)
+                        .unwrap_or(Dynamic::UNIT)
                 },
             );
         }
 
-        // Random number generation
-        engine.register_fn("random", || -> f64 { rand::random() });
+        // Random number generation: random() in [0, 1), random_range(min, max)
+        // in [min, max), random_int(min, max) in [min, max]
+        {
+            let rng = rng.clone();
+            let current = current_mod.clone();
+            engine.register_fn("random", move || -> f64 {
+                ScriptRng::next_f64(&rng, &current)
+            });
+        }
+        {
+            let rng = rng.clone();
+            let current = current_mod.clone();
+            engine.register_fn("random_range", move |min: f64, max: f64| -> f64 {
+                min + (max - min) * ScriptRng::next_f64(&rng, &current)
+            });
+        }
+        {
+            let rng = rng.clone();
+            let current = current_mod.clone();
+            engine.register_fn("random_range", move |min: i64, max: i64| -> f64 {
+                let (min, max) = (min as f64, max as f64);
+                min + (max - min) * ScriptRng::next_f64(&rng, &current)
+            });
+        }
+        {
+            let rng = rng.clone();
+            let current = current_mod.clone();
+            engine.register_fn("random_int", move |min: i64, max: i64| -> i64 {
+                let (low, high) = if min <= max { (min, max) } else { (max, min) };
+                let span = (high as i128 - low as i128 + 1) as u128;
+                let offset = ScriptRng::next_u64(&rng, &current) as u128 % span;
+                (low as i128 + offset as i128) as i64
+            });
+        }
 
         // Event subscription API
         {
             let subs = subscriptions.clone();
+            let current = current_mod.clone();
             engine.register_fn(
                 "subscribe_event",
                 move |event_type: &str, callback: FnPtr| {
-                    // Get MOD_ID from current scope
-                    // Note: This is a limitation - we can't access scope here
-                    // We'll need to handle this in ModEventSystem by using a thread-local or similar
-                    // For now, we store with a placeholder and update it when we know the mod_id
-                    if let Ok(mut subscriptions) = subs.lock() {
-                        subscriptions
-                            .entry("__current__".to_string())
-                            .or_default()
-                            .push(EventSubscription {
-                                event_type: event_type.to_string(),
-                                callback,
-                            });
+                    // Attribute the subscription to the MOD whose script is running
+                    let mod_id = current.lock().clone();
+                    let Some(mod_id) = mod_id else {
+                        eprintln!(
+                            "[RhaiLoader] subscribe_event('{}') called outside of a MOD context",
+                            event_type
+                        );
+                        return;
+                    };
+
+                    subs.lock()
+                        .entry(mod_id)
+                        .or_default()
+                        .push(EventSubscription {
+                            event_type: event_type.to_string(),
+                            callback,
+                        });
+                },
+            );
+        }
+
+        // Event unsubscription API (returns the number of removed subscriptions)
+        {
+            let subs = subscriptions.clone();
+            let current = current_mod.clone();
+            engine.register_fn("unsubscribe_event", move |event_type: &str| -> i64 {
+                let mod_id = current.lock().clone();
+                let Some(mod_id) = mod_id else {
+                    eprintln!(
+                        "[RhaiLoader] unsubscribe_event('{}') called outside of a MOD context",
+                        event_type
+                    );
+                    return 0;
+                };
+
+                let mut subscriptions = subs.lock();
+                let Some(mod_subscriptions) = subscriptions.get_mut(&mod_id) else {
+                    return 0;
+                };
+                let before = mod_subscriptions.len();
+                mod_subscriptions.retain(|s| s.event_type != event_type);
+                (before - mod_subscriptions.len()) as i64
+            });
+        }
+
+        // Event schema API: declare_event("Name", #{ field: "string", other: "int" })
+        {
+            let schemas = event_schemas.clone();
+            let current = current_mod.clone();
+            engine.register_fn(
+                "declare_event",
+                move |event_type: &str, fields: rhai::Map| -> Result<(), Box<EvalAltResult>> {
+                    let Some(mod_id) = current.lock().clone() else {
+                        eprintln!(
+                            "[RhaiLoader] declare_event('{}') called outside of a MOD context",
+                            event_type
+                        );
+                        return Ok(());
+                    };
+
+                    let schema = EventSchema::parse(
+                        fields
+                            .into_iter()
+                            .map(|(name, ty)| (name.to_string(), ty.to_string())),
+                    )
+                    .map_err(|e| format!("declare_event('{}'): {}", event_type, e))?;
+
+                    let mut schemas = schemas.lock();
+                    if let Some(existing) = schemas.get(event_type) {
+                        if existing.mod_id != mod_id && existing.schema != schema {
+                            eprintln!(
+                                "[RhaiLoader] MOD '{}' redeclares event '{}' declared by '{}'",
+                                mod_id, event_type, existing.mod_id
+                            );
+                        }
                     }
+                    schemas.insert(event_type.to_string(), DeclaredEvent { mod_id, schema });
+                    Ok(())
                 },
             );
         }
 
-        // Event publish API
+        // Event publish API; payloads of declared events are validated, event
+        // types outside the MOD's permissions are dropped
         {
             let pq = publish_queue.clone();
-            engine.register_fn("publish_event", move |event_type: &str, data: Dynamic| {
-                // Convert Dynamic to JSON
-                let json_data = dynamic_to_json(data);
-                if let Ok(mut queue) = pq.lock() {
-                    queue.push((event_type.to_string(), json_data));
+            let schemas = event_schemas;
+            let current = current_mod.clone();
+            engine.register_fn(
+                "publish_event",
+                move |event_type: &str, data: Dynamic| -> Result<(), Box<EvalAltResult>> {
+                    let mod_id = current.lock().clone();
+                    let permitted =
+                        Permissions::permits(&permissions, mod_id.as_deref(), |granted, id| {
+                            granted.check_publish(id, event_type)
+                        });
+                    if !permitted {
+                        return Ok(());
+                    }
+
+                    // Convert Dynamic to JSON
+                    let json_data = dynamic_to_json(data);
+
+                    let violations = schemas
+                        .lock()
+                        .get(event_type)
+                        .map(|declared| declared.schema.violations(&json_data))
+                        .unwrap_or_default();
+                    if !violations.is_empty() {
+                        let message =
+                            format!("publish_event('{}'): {}", event_type, violations.join(", "));
+                        eprintln!("[RhaiLoader] {}", message);
+                        return Err(message.into());
+                    }
+
+                    pq.lock().push((event_type.to_string(), json_data));
+                    Ok(())
+                },
+            );
+        }
+
+        // Delayed callbacks: schedule(turns, |turn| ...) runs after `turns` turns
+        {
+            let current = current_mod.clone();
+            engine.register_fn("schedule", move |turns: i64, callback: FnPtr| {
+                let Some(mod_id) = current.lock().clone() else {
+                    eprintln!("[RhaiLoader] schedule() called outside of a MOD context");
+                    return;
+                };
+
+                schedules.lock().push(ScheduledCallback {
+                    mod_id,
+                    // A delay of 0 runs on the next turn
+                    remaining_turns: turns.max(1) as u64,
+                    callback,
+                });
+            });
+        }
+
+        // Persistent store API (survives unload, reload and save/load)
+        {
+            let st = stores.clone();
+            let current = current_mod.clone();
+            engine.register_fn("store_set", move |key: &str, value: Dynamic| {
+                let Some(mod_id) = current.lock().clone() else {
+                    eprintln!(
+                        "[RhaiLoader] store_set('{}') called outside of a MOD context",
+                        key
+                    );
+                    return;
+                };
+
+                let mut stores = st.lock();
+                let store = stores.entry(mod_id).or_default();
+                // Storing () removes the key
+                if value.is_unit() {
+                    store.remove(key);
+                } else {
+                    store.insert(key.to_string(), dynamic_to_json(value));
                 }
             });
         }
+        {
+            let st = stores.clone();
+            let current = current_mod.clone();
+            engine.register_fn("store_get", move |key: &str| -> Dynamic {
+                let Some(mod_id) = current.lock().clone() else {
+                    return Dynamic::UNIT;
+                };
+
+                st.lock()
+                    .get(&mod_id)
+                    .and_then(|s| s.get(key))
+                    .map(json_to_dynamic)
+                    .unwrap_or(Dynamic::UNIT)
+            });
+        }
+
+        // Localization API: register_strings("ja", #{ "item.flame_sword.name": "炎の剣" })
+        {
+            let sq = string_queue;
+            let current = current_mod.clone();
+            engine.register_fn("register_strings", move |lang: &str, strings: rhai::Map| {
+                let Some(mod_id) = current.lock().clone() else {
+                    eprintln!(
+                        "[RhaiLoader] register_strings('{}') called outside of a MOD context",
+                        lang
+                    );
+                    return;
+                };
+
+                let strings = strings
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect();
+                sq.lock().push(ModStrings {
+                    mod_id,
+                    language: lang.to_string(),
+                    strings,
+                });
+            });
+        }
+
+        // Player actions: register_action("campaign", 2, "run_campaign")
+        //
+        // The callback is a function name or `Fn("name")`; the bridge calls it
+        // with a context map when the player performs the action.
+        {
+            let aq = action_queue;
+            let current = current_mod.clone();
+            let register = move |name: &str,
+                                 ap_cost: i64,
+                                 callback: Dynamic,
+                                 cooldown_days: i64|
+                  -> Result<(), Box<EvalAltResult>> {
+                let Some(mod_id) = current.lock().clone() else {
+                    eprintln!(
+                        "[RhaiLoader] register_action('{}') called outside of a MOD context",
+                        name
+                    );
+                    return Ok(());
+                };
+                let callback = if let Some(fn_ptr) = callback.clone().try_cast::<FnPtr>() {
+                    if fn_ptr.is_curried() || fn_ptr.is_anonymous() {
+                        return Err(format!(
+                            "register_action('{}'): the callback must be a named function",
+                            name
+                        )
+                        .into());
+                    }
+                    fn_ptr.fn_name().to_string()
+                } else if let Some(fn_name) = callback.try_cast::<String>() {
+                    fn_name
+                } else {
+                    return Err(format!(
+                        "register_action('{}'): the callback must be a function name",
+                        name
+                    )
+                    .into());
+                };
+
+                aq.lock().push(ModActionDefinition {
+                    mod_id,
+                    name: name.to_string(),
+                    ap_cost: ap_cost.max(0) as u32,
+                    cooldown_days: cooldown_days.max(0) as u32,
+                    callback,
+                });
+                Ok(())
+            };
+            let without_cooldown = register.clone();
+            engine.register_fn(
+                "register_action",
+                move |name: &str, ap_cost: i64, callback: Dynamic| {
+                    without_cooldown(name, ap_cost, callback, 0)
+                },
+            );
+            engine.register_fn("register_action", register);
+        }
+
+        // MOD-to-MOD calls: call_mod("balance_lib", "scaled_damage", [level, base])
+        //
+        // Script functions can't see a MOD's scope, so the callee's function
+        // library is all that's needed; loaded scripts stay owned by the loader.
+        {
+            let current = current_mod.clone();
+            let depth = Arc::new(AtomicUsize::new(0));
+            engine.register_fn(
+                "call_mod",
+                move |ctx: NativeCallContext,
+                      mod_id: &str,
+                      fn_name: &str,
+                      args: rhai::Array|
+                      -> Result<Dynamic, Box<EvalAltResult>> {
+                    let library = libraries
+                        .lock()
+                        .get(mod_id)
+                        .cloned()
+                        .ok_or_else(|| format!("call_mod: MOD '{}' is not loaded", mod_id))?;
+                    let exported = library.iter_functions().any(|f| {
+                        f.name == fn_name
+                            && f.params.len() == args.len()
+                            && f.access != FnAccess::Private
+                    });
+                    if !exported {
+                        return Err(format!(
+                            "call_mod: MOD '{}' has no function '{}' taking {} argument(s)",
+                            mod_id,
+                            fn_name,
+                            args.len()
+                        )
+                        .into());
+                    }
+
+                    if depth.fetch_add(1, Ordering::SeqCst) >= MAX_MOD_CALL_DEPTH {
+                        depth.fetch_sub(1, Ordering::SeqCst);
+                        return Err(format!(
+                            "call_mod('{}', '{}'): MOD calls nested deeper than {}",
+                            mod_id, fn_name, MAX_MOD_CALL_DEPTH
+                        )
+                        .into());
+                    }
+                    // Side effects of the callee are attributed to the callee
+                    let result = {
+                        let _guard = CurrentModGuard::enter(current.clone(), mod_id);
+                        ctx.engine()
+                            .call_fn::<Dynamic>(&mut Scope::new(), &library, fn_name, args)
+                    };
+                    depth.fetch_sub(1, Ordering::SeqCst);
+                    result
+                },
+            );
+        }
 
         // TODO: Add more ISSUN API functions as needed
         // - hook_into()
-        // - get_plugin_state()
         // - query_entities()
         // etc.
     }
 
     /// Extract metadata from a Rhai script by calling `get_metadata()` function
-    fn extract_metadata(&self, ast: &AST, scope: &mut Scope) -> ModResult<ModMetadata> {
+    fn extract_metadata(
+        &self,
+        mod_id: &str,
+        ast: &AST,
+        scope: &mut Scope,
+    ) -> ModResult<ModMetadata> {
         // Try to call get_metadata() function from script
         let result = self
             .engine
@@ -194,76 +1012,119 @@ impl RhaiLoader {
                     .get("description")
                     .and_then(|v| v.clone().try_cast::<String>());
 
+                let priority = map
+                    .get("priority")
+                    .and_then(|v| v.as_int().ok())
+                    .map_or(0, |p| p.clamp(i32::MIN as i64, i32::MAX as i64) as i32);
+
+                let after = map
+                    .get("after")
+                    .and_then(|v| v.clone().try_cast::<rhai::Array>())
+                    .map(|ids| {
+                        ids.into_iter()
+                            .filter_map(|id| id.try_cast::<String>())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
                 Ok(ModMetadata {
                     name,
                     version,
                     author,
                     description,
+                    dependencies: Vec::new(),
+                    priority,
+                    after,
                 })
             }
-            Err(_) => {
+            Err(e) => {
+                if let Some(err) = self.limit_error(mod_id, &e) {
+                    return Err(err);
+                }
+
                 // No metadata function, use defaults
                 Ok(ModMetadata {
                     name: "Unknown".to_string(),
                     version: "0.1.0".to_string(),
                     author: None,
                     description: None,
+                    dependencies: Vec::new(),
+                    priority: 0,
+                    after: Vec::new(),
                 })
             }
         }
     }
 }
 
-impl Default for RhaiLoader {
-    fn default() -> Self {
-        Self::new()
-    }
-}
+impl RhaiLoader {
+    /// Load a MOD directory: reads `mod.toml`, then loads its entry script
+    ///
+    /// The MOD id is the directory name. Name, version, author, description
+    /// and dependencies come from the manifest instead of `get_metadata()`.
+    pub fn load_dir(&mut self, dir: &Path) -> ModResult<ModHandle> {
+        let manifest = ModManifest::from_dir(dir)?;
+        let id = dir
+            .file_name()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| ModError::InvalidFormat("Invalid directory name".to_string()))?
+            .to_string();
 
-impl ModLoader for RhaiLoader {
-    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
-        // Read script file
-        let content = std::fs::read_to_string(path)
-            .map_err(|e| ModError::LoadFailed(format!("Failed to read file: {}", e)))?;
+        let entry = manifest.entry_path(dir);
+        if !entry.is_file() {
+            return Err(ModError::LoadFailed(format!(
+                "Entry script {} of MOD '{}' not found",
+                entry.display(),
+                id
+            )));
+        }
 
-        // Compile script
-        let ast = self
-            .engine
-            .compile(&content)
-            .map_err(|e| ModError::InvalidFormat(format!("Compilation error: {}", e)))?;
+        self.load_script(id, &entry, Some((dir.to_path_buf(), manifest)))
+    }
 
-        let mut scope = Scope::new();
+    /// Compile a script, run its `on_init()` and register it under `id`
+    fn load_script(
+        &mut self,
+        id: String,
+        path: &Path,
+        manifest: Option<(PathBuf, ModManifest)>,
+    ) -> ModResult<ModHandle> {
+        // Read and compile script
+        let modified = modified_time(path);
+        let ast = self.compile_file(path)?;
+        let granted = match &manifest {
+            Some((_, manifest)) => self.permission_policy.resolve(Some(manifest))?,
+            None => self.permission_policy.permissions_for(path)?,
+        };
+        self.grant(&id, granted);
 
-        // Extract metadata from script
-        let metadata = self.extract_metadata(&ast, &mut scope)?;
+        let _guard = self.enter_mod(&id);
+        let mut scope = Scope::new();
 
-        // Generate ID from filename
-        let id = path
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .ok_or_else(|| ModError::InvalidFormat("Invalid filename".to_string()))?
-            .to_string();
+        // Extract metadata from script, the manifest takes precedence
+        let mut metadata = self.extract_metadata(&id, &ast, &mut scope)?;
+        if let Some((_, manifest)) = &manifest {
+            apply_manifest(&mut metadata, manifest)?;
+        }
 
         // Inject MOD_ID into scope for API functions to access
         scope.push("MOD_ID", id.clone());
 
         // Call on_init() if it exists
-        let _ = self.engine.call_fn::<()>(&mut scope, &ast, "on_init", ());
-
-        // Move subscriptions from "__current__" to actual mod_id
-        if let Ok(mut subscriptions) = self.event_subscriptions.lock() {
-            if let Some(current_subs) = subscriptions.remove("__current__") {
-                subscriptions.insert(id.clone(), current_subs);
-            }
-        }
+        self.run_on_init(&id, &ast, &mut scope)?;
 
         // Store loaded script
+        self.publish_library(&id, &ast);
         self.scripts.insert(
             id.clone(),
             LoadedScript {
+                has_on_update: defines_on_update(&ast),
                 ast,
                 scope,
                 mod_id: id.clone(),
+                path: path.to_path_buf(),
+                dir: manifest.map(|(dir, _)| dir),
+                modified,
             },
         );
 
@@ -273,30 +1134,154 @@ impl ModLoader for RhaiLoader {
             backend: ModBackend::Rhai,
         })
     }
+}
 
-    fn unload(&mut self, handle: &ModHandle) -> ModResult<()> {
-        // Call on_shutdown() if it exists
-        if let Some(script) = self.scripts.get_mut(&handle.id) {
-            let _ = self
-                .engine
-                .call_fn::<()>(&mut script.scope, &script.ast, "on_shutdown", ());
+/// Deep copy: loaded scripts (AST and scope), subscriptions, event schemas,
+/// scheduled callbacks, queued commands/events/strings/actions/logs, runtime
+/// errors not yet drained, stores, permissions and the random state all carry
+/// over; engine setups are re-applied to the new engine.
+/// The clone shares nothing with the original afterwards.
+impl Clone for RhaiLoader {
+    fn clone(&self) -> Self {
+        let mut loader = Self::new()
+            .with_limits(self.limits)
+            .with_watch(self.watch)
+            .with_stdout_logging(self.stdout_logging.load(Ordering::Relaxed));
+        for setup in &self.engine_setups {
+            setup(&mut loader.engine);
         }
+        loader.engine_setups = self.engine_setups.clone();
+        loader.dispatch_order = self.dispatch_order.clone();
+        loader.seed = self.seed;
+        loader.scripts = self.scripts.clone();
+        loader.errors = self.errors.clone();
+        copy_shared(&self.command_queue, &loader.command_queue);
+        copy_shared(&self.event_subscriptions, &loader.event_subscriptions);
+        copy_shared(&self.event_publish_queue, &loader.event_publish_queue);
+        copy_shared(&self.stores, &loader.stores);
+        copy_shared(&self.plugin_params, &loader.plugin_params);
+        copy_shared(&self.rng, &loader.rng);
+        copy_shared(&self.string_queue, &loader.string_queue);
+        copy_shared(&self.action_queue, &loader.action_queue);
+        copy_shared(&self.event_schemas, &loader.event_schemas);
+        copy_shared(&self.schedules, &loader.schedules);
+        copy_shared(&self.log_queue, &loader.log_queue);
+        copy_shared(&self.libraries, &loader.libraries);
+        copy_shared(&self.permissions, &loader.permissions);
+        loader.permission_policy = self.permission_policy.clone();
+        loader
+    }
+}
 
-        self.scripts.remove(&handle.id);
-        Ok(())
+impl Default for RhaiLoader {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    fn control_plugin(&mut self, handle: &ModHandle, control: &PluginControl) -> ModResult<()> {
-        let script = self
-            .scripts
-            .get_mut(&handle.id)
-            .ok_or_else(|| ModError::NotFound(format!("Script '{}' not loaded", handle.id)))?;
+impl ModLoader for RhaiLoader {
+    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
+        if path.is_dir() {
+            return self.load_dir(path);
+        }
 
-        // Serialize action for passing to script
-        let action_str = match &control.action {
-            PluginAction::Enable => "enable".to_string(),
-            PluginAction::Disable => "disable".to_string(),
-            PluginAction::SetParameter { key, value } => {
+        // Generate ID from filename
+        let id = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| ModError::InvalidFormat("Invalid filename".to_string()))?
+            .to_string();
+
+        self.load_script(id, path, None)
+    }
+
+    /// Directory MODs: their `mod.toml`. Scripts: `get_metadata()`, called
+    /// without running the top-level statements or `on_init()`
+    fn peek_metadata(&mut self, path: &Path) -> ModResult<ModMetadata> {
+        if path.is_dir() {
+            return ModManifest::from_dir(path)?.metadata();
+        }
+
+        let id = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| ModError::InvalidFormat("Invalid filename".to_string()))?
+            .to_string();
+        let mut ast = self.compile_file(path)?;
+        ast.clear_statements();
+
+        let _guard = self.enter_mod(&id);
+        self.extract_metadata(&id, &ast, &mut Scope::new())
+    }
+
+    fn file_extensions(&self) -> Vec<&'static str> {
+        vec!["rhai"]
+    }
+
+    fn reload(&mut self, handle: &ModHandle) -> ModResult<ModHandle> {
+        self.reload_script(&handle.id)
+    }
+
+    fn unload(&mut self, handle: &ModHandle) -> ModResult<()> {
+        // Call on_shutdown() if it exists
+        let _guard = self.enter_mod(&handle.id);
+        if let Some(script) = self.scripts.get_mut(&handle.id) {
+            let _ = self
+                .engine
+                .call_fn::<()>(&mut script.scope, &script.ast, "on_shutdown", ());
+        }
+
+        self.scripts.remove(&handle.id);
+        self.libraries.lock().remove(&handle.id);
+        self.event_subscriptions.lock().remove(&handle.id);
+        self.string_queue
+            .lock()
+            .retain(|strings| strings.mod_id != handle.id);
+        self.action_queue
+            .lock()
+            .retain(|action| action.mod_id != handle.id);
+        self.drop_event_schemas(&handle.id);
+        self.drop_schedules(&handle.id);
+        self.permissions.lock().by_mod.remove(&handle.id);
+        Ok(())
+    }
+
+    fn update(&mut self, handle: &ModHandle, tick: u64) -> ModResult<()> {
+        let _guard = self.enter_mod(&handle.id);
+        let script = self
+            .scripts
+            .get_mut(&handle.id)
+            .ok_or_else(|| ModError::NotFound(format!("Script '{}' not loaded", handle.id)))?;
+
+        if !script.has_on_update {
+            return Ok(());
+        }
+
+        // The return value, if any, is ignored
+        let engine = &self.engine;
+        isolate(&self.recovery, &handle.id, || {
+            engine.call_fn::<Dynamic>(&mut script.scope, &script.ast, "on_update", (tick as i64,))
+        })
+        .map_err(ModError::ExecutionFailed)?
+        .map(|_| ())
+        .map_err(|e| {
+            self.limit_error(&handle.id, &e)
+                .unwrap_or_else(|| ModError::ExecutionFailed(format!("Script error: {}", e)))
+        })
+    }
+
+    fn control_plugin(&mut self, handle: &ModHandle, control: &PluginControl) -> ModResult<()> {
+        let _guard = self.enter_mod(&handle.id);
+        let script = self
+            .scripts
+            .get_mut(&handle.id)
+            .ok_or_else(|| ModError::NotFound(format!("Script '{}' not loaded", handle.id)))?;
+
+        // Serialize action for passing to script
+        let action_str = match &control.action {
+            PluginAction::Enable => "enable".to_string(),
+            PluginAction::Disable => "disable".to_string(),
+            PluginAction::SetParameter { key, value } => {
                 format!("set_param:{}={}", key, value)
             }
             PluginAction::TriggerHook { hook_name, .. } => {
@@ -312,7 +1297,10 @@ impl ModLoader for RhaiLoader {
                 "on_control_plugin",
                 (control.plugin_name.clone(), action_str),
             )
-            .map_err(|e| ModError::ExecutionFailed(format!("Script error: {}", e)))?;
+            .map_err(|e| {
+                self.limit_error(&handle.id, &e)
+                    .unwrap_or_else(|| ModError::ExecutionFailed(format!("Script error: {}", e)))
+            })?;
 
         Ok(())
     }
@@ -323,6 +1311,7 @@ impl ModLoader for RhaiLoader {
         fn_name: &str,
         args: Vec<serde_json::Value>,
     ) -> ModResult<serde_json::Value> {
+        let _guard = self.enter_mod(&handle.id);
         let script = self
             .scripts
             .get_mut(&handle.id)
@@ -343,6 +1332,7 @@ impl ModLoader for RhaiLoader {
                 }
                 serde_json::Value::String(s) => Dynamic::from(s),
                 serde_json::Value::Bool(b) => Dynamic::from(b),
+                serde_json::Value::Array(_) | serde_json::Value::Object(_) => json_to_dynamic(&v),
                 _ => Dynamic::from(v.to_string()),
             })
             .collect();
@@ -380,7 +1370,11 @@ impl ModLoader for RhaiLoader {
                 ))
             }
         }
-        .map_err(|e| ModError::FunctionNotFound(format!("Function '{}': {}", fn_name, e)))?;
+        .map_err(|e| {
+            self.limit_error(&handle.id, &e).unwrap_or_else(|| {
+                ModError::FunctionNotFound(format!("Function '{}': {}", fn_name, e))
+            })
+        })?;
 
         // Convert result back to JSON (simplified)
         let json_result = if result.is::<i64>() {
@@ -399,40 +1393,79 @@ impl ModLoader for RhaiLoader {
     }
 
     fn drain_commands(&mut self) -> Vec<PluginControl> {
-        if let Ok(mut queue) = self.command_queue.lock() {
-            queue.drain(..).collect()
-        } else {
-            Vec::new()
+        if self.watch {
+            self.reload_changed();
         }
+
+        self.command_queue.lock().drain(..).collect()
     }
 
     fn drain_events(&mut self) -> Vec<(String, serde_json::Value)> {
-        if let Ok(mut queue) = self.event_publish_queue.lock() {
-            queue.drain(..).collect()
-        } else {
-            Vec::new()
-        }
+        self.event_publish_queue.lock().drain(..).collect()
+    }
+
+    fn drain_strings(&mut self) -> Vec<ModStrings> {
+        self.string_queue.lock().drain(..).collect()
+    }
+
+    fn drain_actions(&mut self) -> Vec<ModActionDefinition> {
+        self.action_queue.lock().drain(..).collect()
+    }
+
+    fn drain_logs(&mut self) -> Vec<ModLogEntry> {
+        self.log_queue.lock().drain(..).collect()
+    }
+
+    /// Applies to MODs loaded or reloaded afterwards
+    fn set_permission_policy(&mut self, policy: ModPermissionPolicy) {
+        self.permission_policy = policy;
+    }
+
+    fn drain_denials(&mut self) -> Vec<ModPermissionDenied> {
+        self.permissions.lock().denied.drain(..).collect()
+    }
+
+    fn drain_errors(&mut self) -> Vec<ModRuntimeErrorEvent> {
+        let mut errors = std::mem::take(&mut self.errors);
+        errors.extend(self.recovery.take_report());
+        errors
+    }
+
+    fn set_dispatch_order(&mut self, order: &[String]) {
+        self.dispatch_order = order.to_vec();
     }
 
     fn dispatch_event(&mut self, event_type: &str, event_data: &serde_json::Value) -> usize {
-        let subscriptions = self.get_all_subscriptions();
+        let mut subscriptions: Vec<(String, Vec<EventSubscription>)> =
+            self.get_all_subscriptions().into_iter().collect();
+        subscriptions.sort_by_cached_key(|(mod_id, _)| self.dispatch_rank(mod_id));
         let mut count = 0;
 
-        // Iterate through all MODs and their subscriptions
+        // Iterate through all MODs in dispatch order, each budgeted per callback
         for (mod_id, mod_subscriptions) in subscriptions {
             for subscription in mod_subscriptions {
                 // Check if this subscription matches the event type
                 if subscription.event_type == event_type {
                     // Call the callback
-                    match self.call_event_callback(&mod_id, &subscription.callback, event_data) {
+                    let recovery = self.recovery.clone();
+                    let result = isolate(&recovery, &mod_id, || {
+                        self.call_event_callback(&mod_id, &subscription.callback, event_data)
+                    })
+                    .and_then(|result| result);
+                    match result {
                         Ok(_) => {
                             count += 1;
                         }
                         Err(e) => {
-                            eprintln!(
-                                "[RhaiLoader] Failed to call event callback for MOD '{}': {}",
-                                mod_id, e
-                            );
+                            self.errors.push(ModRuntimeErrorEvent::new(
+                                &mod_id,
+                                format!(
+                                    "event '{}' callback '{}'",
+                                    event_type,
+                                    subscription.callback.fn_name()
+                                ),
+                                e,
+                            ));
                         }
                     }
                 }
@@ -442,28 +1475,121 @@ impl ModLoader for RhaiLoader {
         count
     }
 
+    fn tick_schedules(&mut self, turn: u64) -> usize {
+        // Take the due callbacks out first; callbacks may schedule again
+        let due: Vec<ScheduledCallback> = {
+            let mut schedules = self.schedules.lock();
+            for scheduled in schedules.iter_mut() {
+                scheduled.remaining_turns = scheduled.remaining_turns.saturating_sub(1);
+            }
+            let (due, pending) = schedules
+                .drain(..)
+                .partition(|scheduled| scheduled.remaining_turns == 0);
+            *schedules = pending;
+            due
+        };
+
+        let mut count = 0;
+        for scheduled in due {
+            let turn = serde_json::json!(turn);
+            let recovery = self.recovery.clone();
+            let result = isolate(&recovery, &scheduled.mod_id, || {
+                self.call_event_callback(&scheduled.mod_id, &scheduled.callback, &turn)
+            })
+            .and_then(|result| result);
+            match result {
+                Ok(()) => count += 1,
+                Err(e) => self.errors.push(ModRuntimeErrorEvent::new(
+                    &scheduled.mod_id,
+                    format!("scheduled callback '{}'", scheduled.callback.fn_name()),
+                    e,
+                )),
+            }
+        }
+        count
+    }
+
+    fn export_state(&self) -> HashMap<String, serde_json::Value> {
+        self.stores
+            .lock()
+            .iter()
+            .map(|(mod_id, store)| (mod_id.clone(), serde_json::Value::Object(store.clone())))
+            .collect()
+    }
+
+    fn import_state(&mut self, state: HashMap<String, serde_json::Value>) {
+        let mut stores = self.stores.lock();
+        stores.clear();
+        for (mod_id, value) in state {
+            match value {
+                serde_json::Value::Object(store) => {
+                    stores.insert(mod_id, store);
+                }
+                _ => eprintln!(
+                    "[RhaiLoader] Ignoring non-object store data for MOD '{}'",
+                    mod_id
+                ),
+            }
+        }
+    }
+
+    fn set_master_seed(&mut self, seed: Option<MasterSeed>) {
+        let mut rng = self.rng.lock();
+        if rng.master != seed {
+            rng.master = seed;
+            rng.streams.clear();
+        }
+    }
+
+    fn export_rng(&self) -> HashMap<String, u64> {
+        self.rng
+            .lock()
+            .streams
+            .iter()
+            .map(|(mod_id, stream)| (mod_id.clone(), stream.position()))
+            .collect()
+    }
+
+    fn import_rng(&mut self, positions: HashMap<String, u64>) {
+        self.rng.lock().streams = positions
+            .into_iter()
+            .map(|(mod_id, position)| (mod_id, ModRng::from_position(position)))
+            .collect();
+    }
+
+    fn sync_plugin_params(&mut self, params: &PluginParams) {
+        self.plugin_params.lock().clone_from(params);
+    }
+
     fn clone_box(&self) -> Box<dyn ModLoader> {
-        Box::new(Self::new())
+        Box::new(self.clone())
     }
 }
 
 impl RhaiLoader {
     /// Get all event subscriptions for all loaded MODs
     pub fn get_all_subscriptions(&self) -> HashMap<String, Vec<EventSubscription>> {
-        if let Ok(subscriptions) = self.event_subscriptions.lock() {
-            subscriptions.clone()
-        } else {
-            HashMap::new()
-        }
+        self.event_subscriptions.lock().clone()
+    }
+
+    /// Sort key of a MOD's callbacks: position in the dispatch order, MODs
+    /// outside of it last, then the MOD id
+    fn dispatch_rank(&self, mod_id: &str) -> (usize, String) {
+        let position = self
+            .dispatch_order
+            .iter()
+            .position(|id| id == mod_id)
+            .unwrap_or(usize::MAX);
+        (position, mod_id.to_string())
     }
 
     /// Get event subscriptions for a specific MOD
     pub fn get_subscriptions(&self, mod_id: &str) -> Vec<EventSubscription> {
-        if let Ok(subscriptions) = self.event_subscriptions.lock() {
-            subscriptions.get(mod_id).cloned().unwrap_or_default()
-        } else {
-            Vec::new()
-        }
+        self.event_subscriptions
+            .lock()
+            .get(mod_id)
+            .cloned()
+            .unwrap_or_default()
     }
 
     /// Call a Rhai callback with JSON event data
@@ -473,6 +1599,7 @@ impl RhaiLoader {
         callback: &FnPtr,
         event_data: &serde_json::Value,
     ) -> Result<(), String> {
+        let _guard = self.enter_mod(mod_id);
         if let Some(script) = self.scripts.get_mut(mod_id) {
             // Convert JSON to Rhai Dynamic
             let rhai_data = json_to_dynamic(event_data);
@@ -480,7 +1607,10 @@ impl RhaiLoader {
             // Call the callback
             let _ = callback
                 .call::<Dynamic>(&self.engine, &script.ast, (rhai_data,))
-                .map_err(|e| format!("Callback error: {}", e))?;
+                .map_err(|e| match self.limit_error(mod_id, &e) {
+                    Some(err) => err.to_string(),
+                    None => format!("Callback error: {}", e),
+                })?;
             Ok(())
         } else {
             Err(format!("MOD '{}' not found", mod_id))
@@ -488,6 +1618,208 @@ impl RhaiLoader {
     }
 }
 
+/// Overwrite script-provided metadata with the manifest's
+fn apply_manifest(metadata: &mut ModMetadata, manifest: &ModManifest) -> ModResult<()> {
+    metadata.name = manifest.name.clone();
+    metadata.version = manifest.version.clone();
+    metadata.author = manifest.author.clone().or(metadata.author.take());
+    metadata.description = manifest.description.clone().or(metadata.description.take());
+    metadata.dependencies = manifest.dependencies()?;
+    metadata.priority = manifest.priority;
+    metadata.after = manifest.after.clone();
+    Ok(())
+}
+
+/// Whether a script defines `fn on_update(tick)`
+fn defines_on_update(ast: &AST) -> bool {
+    ast.iter_functions()
+        .any(|f| f.name == "on_update" && f.params.len() == 1)
+}
+
+/// Overwrite `to` with a copy of `from`'s contents
+///
+/// Host functions hold clones of the destination `Arc`, so the value is
+/// copied rather than the `Arc` replaced.
+fn copy_shared<T: Clone>(from: &Shared<T>, to: &Shared<T>) {
+    let from = from.lock();
+    to.lock().clone_from(&from);
+}
+
+/// Modification time of a script file, if available
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Random source behind the script random functions
+#[derive(Clone, Default)]
+struct ScriptRng {
+    /// Stream shared by all MODs without a master seed; `None` uses the
+    /// thread-local `rand` generator (non-deterministic)
+    fallback: Option<ModRng>,
+    /// With a master seed, every MOD draws from its own stream
+    master: Option<MasterSeed>,
+    streams: HashMap<String, ModRng>,
+}
+
+impl ScriptRng {
+    fn seeded(seed: u64) -> Self {
+        Self {
+            fallback: Some(ModRng::from_seed(seed)),
+            ..Self::default()
+        }
+    }
+
+    /// Next value of the executing MOD's stream
+    fn next_u64(rng: &Shared<ScriptRng>, current: &Shared<Option<String>>) -> u64 {
+        let mut rng = rng.lock();
+        let rng = &mut *rng;
+        let mod_id = current.lock().clone();
+        match (rng.master, mod_id) {
+            (Some(master), Some(mod_id)) => rng
+                .streams
+                .entry(mod_id)
+                .or_insert_with_key(|mod_id| ModRng::for_mod(master, mod_id))
+                .next_u64(),
+            _ => match &mut rng.fallback {
+                Some(stream) => stream.next_u64(),
+                None => rand::random(),
+            },
+        }
+    }
+
+    /// Uniform float in [0, 1) from the top 53 bits
+    fn next_f64(rng: &Shared<ScriptRng>, current: &Shared<Option<String>>) -> f64 {
+        (Self::next_u64(rng, current) >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Restores the previously executing MOD when dropped
+///
+/// Nesting is supported so a callback that re-enters the loader
+/// hands attribution back to the outer MOD afterwards.
+struct CurrentModGuard {
+    slot: Shared<Option<String>>,
+    previous: Option<String>,
+}
+
+impl CurrentModGuard {
+    fn enter(slot: Shared<Option<String>>, mod_id: &str) -> Self {
+        let previous = slot.lock().replace(mod_id.to_string());
+        Self { slot, previous }
+    }
+}
+
+impl Drop for CurrentModGuard {
+    fn drop(&mut self) {
+        *self.slot.lock() = self.previous.take();
+    }
+}
+
+/// State shared between the loader and its host functions
+///
+/// A panic while a lock is held poisons it. Rather than failing every later
+/// access (which silently dropped commands and events for the rest of the
+/// session), the lock is recovered and the recovery reported once through
+/// `drain_errors`.
+struct Shared<T> {
+    name: &'static str,
+    value: Arc<Mutex<T>>,
+    recovery: Arc<LockRecovery>,
+}
+
+impl<T> Shared<T> {
+    fn new(name: &'static str, value: T, recovery: &Arc<LockRecovery>) -> Self {
+        Self {
+            name,
+            value: Arc::new(Mutex::new(value)),
+            recovery: recovery.clone(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, T> {
+        self.value.lock().unwrap_or_else(|poisoned| {
+            self.value.clear_poison();
+            self.recovery.note(self.name);
+            poisoned.into_inner()
+        })
+    }
+}
+
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name,
+            value: self.value.clone(),
+            recovery: self.recovery.clone(),
+        }
+    }
+}
+
+/// Poisoned locks recovered since the last report
+#[derive(Default)]
+struct LockRecovery {
+    state: Mutex<RecoveryState>,
+}
+
+#[derive(Default)]
+struct RecoveryState {
+    locks: Vec<&'static str>,
+    /// MOD whose callback panicked last, blamed for the poisoning
+    panicked_mod: Option<String>,
+}
+
+impl LockRecovery {
+    fn state(&self) -> MutexGuard<'_, RecoveryState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn note(&self, lock: &'static str) {
+        let mut state = self.state();
+        if !state.locks.contains(&lock) {
+            state.locks.push(lock);
+        }
+    }
+
+    fn note_panic(&self, mod_id: &str) {
+        self.state().panicked_mod = Some(mod_id.to_string());
+    }
+
+    /// One error describing every lock recovered since the last call
+    fn take_report(&self) -> Option<ModRuntimeErrorEvent> {
+        let mut state = self.state();
+        if state.locks.is_empty() {
+            return None;
+        }
+        let locks = std::mem::take(&mut state.locks);
+        Some(ModRuntimeErrorEvent::new(
+            state.panicked_mod.as_deref().unwrap_or("unknown"),
+            "lock recovery",
+            format!(
+                "recovered {} poisoned by a panic; changes made during the panic may be incomplete",
+                locks.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Run a script call of `mod_id`, turning a panic into an error message so
+/// one MOD can't take down the game or the dispatch to the other MODs
+fn isolate<T>(
+    recovery: &LockRecovery,
+    mod_id: &str,
+    call: impl FnOnce() -> T,
+) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(call)).map_err(|payload| {
+        recovery.note_panic(mod_id);
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        format!("panicked: {}", message)
+    })
+}
+
 /// Helper function to convert Rhai Dynamic to JSON
 fn dynamic_to_json(value: Dynamic) -> serde_json::Value {
     if value.is::<i64>() {
@@ -609,6 +1941,172 @@ fn on_init() {{
         assert_eq!(handle.metadata.version, "0.1.0");
     }
 
+    #[test]
+    fn test_logs_are_queued_with_level_and_mod() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_init() {{
+    log("ready");
+    log_warn("low gold");
+    subscribe_event("Boom", |event| {{
+        log_error("boom: " + event.size);
+    }});
+}}
+"#
+        )
+        .unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+        loader.dispatch_event("Boom", &serde_json::json!({ "size": 3 }));
+
+        let logs: Vec<_> = loader
+            .drain_logs()
+            .into_iter()
+            .map(|entry| (entry.mod_id, entry.level, entry.message))
+            .collect();
+        let mod_id = Some(handle.id.clone());
+        assert_eq!(
+            logs,
+            vec![
+                (mod_id.clone(), ModLogLevel::Info, "ready".to_string()),
+                (mod_id.clone(), ModLogLevel::Warn, "low gold".to_string()),
+                (mod_id, ModLogLevel::Error, "boom: 3".to_string()),
+            ]
+        );
+        assert!(loader.drain_logs().is_empty());
+    }
+
+    /// Load `script` as a MOD with id `id` from `dir`
+    fn load_named(loader: &mut RhaiLoader, dir: &Path, id: &str, script: &str) -> ModHandle {
+        let path = dir.join(format!("{}.rhai", id));
+        std::fs::write(&path, script).unwrap();
+        loader.load(&path).unwrap()
+    }
+
+    #[test]
+    fn test_call_mod_runs_library_functions() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut loader = RhaiLoader::new();
+        load_named(
+            &mut loader,
+            dir.path(),
+            "balance_lib",
+            r#"
+fn scaled_damage(level, base) { log("scaling"); base * level + bonus() }
+private fn bonus() { 1 }
+"#,
+        );
+        let warrior = load_named(
+            &mut loader,
+            dir.path(),
+            "warrior",
+            r#"
+fn on_init() {
+    store_set("damage", call_mod("balance_lib", "scaled_damage", [3, 10]));
+}
+fn missing_mod() { call_mod("nope", "scaled_damage", [1, 2]) }
+fn missing_fn() { call_mod("balance_lib", "scaled_damage", [1]) }
+fn private_fn() { call_mod("balance_lib", "bonus", []) }
+"#,
+        );
+
+        assert_eq!(
+            loader.export_state()["warrior"],
+            serde_json::json!({ "damage": 31 })
+        );
+        let logs = loader.drain_logs();
+        assert_eq!(logs[0].mod_id.as_deref(), Some("balance_lib"));
+
+        for (function, message) in [
+            ("missing_mod", "MOD 'nope' is not loaded"),
+            (
+                "missing_fn",
+                "MOD 'balance_lib' has no function 'scaled_damage' taking 1 argument(s)",
+            ),
+            (
+                "private_fn",
+                "MOD 'balance_lib' has no function 'bonus' taking 0 argument(s)",
+            ),
+        ] {
+            let err = loader
+                .call_function(&warrior, function, Vec::new())
+                .unwrap_err();
+            assert!(err.to_string().contains(message), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_call_mod_recursion_is_bounded() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut loader = RhaiLoader::new();
+        let ping = load_named(
+            &mut loader,
+            dir.path(),
+            "ping",
+            r#"fn bounce(n) { call_mod("pong", "bounce", [n + 1]) }"#,
+        );
+        load_named(
+            &mut loader,
+            dir.path(),
+            "pong",
+            r#"fn bounce(n) { call_mod("ping", "bounce", [n + 1]) }"#,
+        );
+
+        let err = loader
+            .call_function(&ping, "bounce", vec![serde_json::json!(0)])
+            .unwrap_err();
+        assert!(err.to_string().contains("nested deeper than 16"), "{}", err);
+
+        // The depth counter is released again after the failure
+        let lib = load_named(&mut loader, dir.path(), "lib", "fn one() { 1 }");
+        let caller = load_named(
+            &mut loader,
+            dir.path(),
+            "caller",
+            r#"fn run() { call_mod("lib", "one", []) }"#,
+        );
+        assert_eq!(
+            loader.call_function(&caller, "run", Vec::new()).unwrap(),
+            serde_json::json!(1)
+        );
+
+        // Unloaded MODs can't be called anymore
+        loader.unload(&lib).unwrap();
+        assert!(loader.call_function(&caller, "run", Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_plugin_commands_carry_their_issuer() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut loader = RhaiLoader::new();
+        load_named(
+            &mut loader,
+            dir.path(),
+            "easy_mode",
+            r#"fn on_init() { set_plugin_param("combat", "difficulty_multiplier", 0.5); }"#,
+        );
+        load_named(
+            &mut loader,
+            dir.path(),
+            "hard_mode",
+            r#"fn on_init() { disable_plugin("loot"); }"#,
+        );
+
+        let commands = loader.drain_commands();
+        let issuers: Vec<_> = commands
+            .iter()
+            .map(|c| (c.plugin_name.as_str(), c.issuer.as_deref()))
+            .collect();
+        assert_eq!(
+            issuers,
+            vec![("combat", Some("easy_mode")), ("loot", Some("hard_mode"))]
+        );
+    }
+
     #[test]
     fn test_unload_script() {
         let mut loader = RhaiLoader::new();
@@ -652,30 +2150,93 @@ fn add(a, b) {{
     }
 
     #[test]
-    fn test_control_plugin() {
-        let mut loader = RhaiLoader::new();
+    fn test_clone_keeps_loaded_scripts_and_state() {
+        let mut loader = RhaiLoader::new().with_seed(7);
 
         let mut file = NamedTempFile::new().unwrap();
         writeln!(
             file,
             r#"
-fn on_control_plugin(plugin_name, action) {{
-    log("Controlling: " + plugin_name + " - " + action);
+fn on_init() {{
+    subscribe_event("PlayerDamaged", |event| {{
+        store_set("last_damage", event.amount);
+    }});
+    enable_plugin("combat");
+}}
+
+fn add(a, b) {{
+    a + b
 }}
 "#
         )
         .unwrap();
 
         let handle = loader.load(file.path()).unwrap();
-        let control = PluginControl::enable("combat");
-
-        // Should not error
-        loader.control_plugin(&handle, &control).unwrap();
-    }
-
-    #[test]
-    fn test_command_queue() {
-        let mut loader = RhaiLoader::new();
+        let mut clone = loader.clone();
+        let mut boxed = loader.clone_box();
+
+        let result = clone
+            .call_function(
+                &handle,
+                "add",
+                vec![serde_json::json!(2), serde_json::json!(3)],
+            )
+            .unwrap();
+        assert_eq!(result, serde_json::json!(5));
+        assert_eq!(clone.get_subscriptions(&handle.id).len(), 1);
+        assert_eq!(
+            boxed
+                .call_function(
+                    &handle,
+                    "add",
+                    vec![serde_json::json!(1), serde_json::json!(1)]
+                )
+                .unwrap(),
+            serde_json::json!(2)
+        );
+
+        // Queued commands were copied, not shared
+        assert_eq!(clone.drain_commands().len(), 1);
+        assert_eq!(boxed.drain_commands().len(), 1);
+        assert_eq!(loader.drain_commands().len(), 1);
+
+        // The clone's callbacks write to the clone's own store
+        let damage = serde_json::json!({ "amount": 12 });
+        assert_eq!(clone.dispatch_event("PlayerDamaged", &damage), 1);
+        assert_eq!(clone.export_state()[&handle.id]["last_damage"], 12);
+        assert!(loader.export_state().is_empty());
+
+        // Seed and unload are independent too
+        assert_eq!(clone.seed(), Some(7));
+        loader.unload(&handle).unwrap();
+        assert_eq!(clone.get_subscriptions(&handle.id).len(), 1);
+    }
+
+    #[test]
+    fn test_control_plugin() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_control_plugin(plugin_name, action) {{
+    log("Controlling: " + plugin_name + " - " + action);
+}}
+"#
+        )
+        .unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+        let control = PluginControl::enable("combat");
+
+        // Should not error
+        loader.control_plugin(&handle, &control).unwrap();
+    }
+
+    #[test]
+    fn test_command_queue() {
+        let mut loader = RhaiLoader::new();
 
         let mut file = NamedTempFile::new().unwrap();
         writeln!(
@@ -754,46 +2315,156 @@ fn on_init() {{
     }
 
     #[test]
-    fn test_call_event_callback() {
+    fn test_subscribe_event_from_callback_is_attributed_per_mod() {
         let mut loader = RhaiLoader::new();
 
-        let mut file = NamedTempFile::new().unwrap();
+        let mut file_a = NamedTempFile::new().unwrap();
         writeln!(
-            file,
+            file_a,
             r#"
-let message = "";
+fn on_init() {{
+    subscribe_event("Trigger", |event| {{
+        subscribe_event("LateA", |event| {{ }});
+    }});
+}}
+
+fn setup() {{
+    subscribe_event("SetupA", |event| {{ }});
+}}
+"#
+        )
+        .unwrap();
 
+        let mut file_b = NamedTempFile::new().unwrap();
+        writeln!(
+            file_b,
+            r#"
 fn on_init() {{
-    subscribe_event("TestEvent", |event| {{
-        log("Event received: " + event.message);
+    subscribe_event("Trigger", |event| {{
+        subscribe_event("LateB", |event| {{ }});
     }});
 }}
+
+fn setup() {{
+    subscribe_event("SetupB", |event| {{ }});
+}}
+"#
+        )
+        .unwrap();
+
+        // Subscribe from call_function before the second MOD is loaded
+        let handle_a = loader.load(file_a.path()).unwrap();
+        loader.call_function(&handle_a, "setup", vec![]).unwrap();
+        let handle_b = loader.load(file_b.path()).unwrap();
+        loader.call_function(&handle_b, "setup", vec![]).unwrap();
+
+        // Subscribe from event callbacks
+        assert_eq!(loader.dispatch_event("Trigger", &serde_json::json!({})), 2);
+
+        let types = |mod_id: &str| -> Vec<String> {
+            let mut types: Vec<String> = loader
+                .get_subscriptions(mod_id)
+                .into_iter()
+                .map(|s| s.event_type)
+                .collect();
+            types.sort();
+            types
+        };
+
+        assert_eq!(types(&handle_a.id), vec!["LateA", "SetupA", "Trigger"]);
+        assert_eq!(types(&handle_b.id), vec!["LateB", "SetupB", "Trigger"]);
+        assert_eq!(loader.get_all_subscriptions().len(), 2);
+    }
+
+    #[test]
+    fn test_reload_replaces_script_without_double_subscribing() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(
+            file,
+            r#"
+fn on_init() {{ subscribe_event("Tick", |e| {{ }}); }}
+fn version() {{ 1 }}
 "#
         )
         .unwrap();
 
         let handle = loader.load(file.path()).unwrap();
+        assert_eq!(
+            loader.call_function(&handle, "version", vec![]).unwrap(),
+            serde_json::json!(1)
+        );
 
-        // Get subscriptions
-        let subscriptions = loader.get_subscriptions(&handle.id);
-        assert_eq!(subscriptions.len(), 1);
+        std::fs::write(
+            file.path(),
+            r#"
+fn get_metadata() { #{ name: "Reloaded", version: "2.0.0" } }
+fn on_init() { subscribe_event("Tick", |e| { }); }
+fn version() { 2 }
+"#,
+        )
+        .unwrap();
 
-        // Prepare event data
-        let event_data = serde_json::json!({
-            "message": "Hello from test",
-            "value": 42
-        });
+        let reloaded = loader.reload(&handle).unwrap();
+        assert_eq!(reloaded.id, handle.id);
+        assert_eq!(reloaded.metadata.version, "2.0.0");
+        assert_eq!(
+            loader.call_function(&handle, "version", vec![]).unwrap(),
+            serde_json::json!(2)
+        );
+        assert_eq!(loader.get_subscriptions(&handle.id).len(), 1);
+    }
 
-        // Call the callback
-        let result =
-            loader.call_event_callback(&handle.id, &subscriptions[0].callback, &event_data);
+    #[test]
+    fn test_reload_keeps_old_version_on_compile_error() {
+        let mut loader = RhaiLoader::new();
 
-        // Should succeed
-        assert!(result.is_ok(), "Callback failed: {:?}", result.err());
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "fn version() {{ 1 }}").unwrap();
+        let handle = loader.load(file.path()).unwrap();
+
+        std::fs::write(file.path(), "fn version() { ").unwrap();
+        assert!(matches!(
+            loader.reload(&handle),
+            Err(ModError::InvalidFormat(_))
+        ));
+        assert_eq!(
+            loader.call_function(&handle, "version", vec![]).unwrap(),
+            serde_json::json!(1)
+        );
     }
 
     #[test]
-    fn test_publish_event() {
+    fn test_watch_reloads_changed_scripts_on_drain() {
+        let mut loader = RhaiLoader::new().with_watch(true);
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "fn version() {{ 1 }}").unwrap();
+        let handle = loader.load(file.path()).unwrap();
+
+        // Unchanged file: nothing to reload
+        assert!(loader.reload_changed().is_empty());
+
+        std::fs::write(
+            file.path(),
+            r#"fn on_init() { enable_plugin("combat"); } fn version() { 2 }"#,
+        )
+        .unwrap();
+        let later = SystemTime::now() + std::time::Duration::from_secs(5);
+        file.as_file().set_modified(later).unwrap();
+
+        let commands = loader.drain_commands();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].plugin_name, "combat");
+        assert_eq!(
+            loader.call_function(&handle, "version", vec![]).unwrap(),
+            serde_json::json!(2)
+        );
+    }
+
+    #[test]
+    fn test_unsubscribe_event_stops_dispatch() {
         let mut loader = RhaiLoader::new();
 
         let mut file = NamedTempFile::new().unwrap();
@@ -801,39 +2472,1055 @@ fn on_init() {{
             file,
             r#"
 fn on_init() {{
-    log("Publishing events");
-
-    publish_event("CustomEvent1", #{{
-        message: "Hello",
-        value: 42
+    subscribe_event("Tick", |event| {{ }});
+    subscribe_event("Tick", |event| {{ }});
+    subscribe_event("Stop", |event| {{
+        let removed = unsubscribe_event("Tick");
+        if removed != 2 {{
+            throw "expected 2 removed subscriptions, got " + removed;
+        }}
     }});
+}}
+"#
+        )
+        .unwrap();
 
-    publish_event("CustomEvent2", #{{
-        data: "World"
-    }});
+        let handle = loader.load(file.path()).unwrap();
+        let event = serde_json::json!({});
+
+        assert_eq!(loader.dispatch_event("Tick", &event), 2);
+        assert_eq!(loader.dispatch_event("Stop", &event), 1);
+        assert_eq!(loader.dispatch_event("Tick", &event), 0);
+        assert_eq!(loader.get_subscriptions(&handle.id).len(), 1);
+    }
+
+    #[test]
+    fn test_on_update_commands_are_drained() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_update(tick) {{
+    if tick % 2 == 1 {{
+        set_plugin_param("combat", "tick", tick);
+    }}
 }}
 "#
         )
         .unwrap();
 
-        let _handle = loader.load(file.path()).unwrap();
+        let handle = loader.load(file.path()).unwrap();
 
-        // Drain published events
-        let events = loader.drain_events();
+        loader.update(&handle, 0).unwrap();
+        assert!(loader.drain_commands().is_empty());
 
-        // Should have 2 published events
-        assert_eq!(events.len(), 2);
+        loader.update(&handle, 1).unwrap();
+        let commands = loader.drain_commands();
+        assert_eq!(commands.len(), 1);
+        match &commands[0].action {
+            PluginAction::SetParameter { key, value } => {
+                assert_eq!(key, "tick");
+                assert_eq!(value, &serde_json::json!(1));
+            }
+            other => panic!("unexpected action {:?}", other),
+        }
+    }
 
-        // Verify event types and data
-        assert_eq!(events[0].0, "CustomEvent1");
-        assert_eq!(events[0].1["message"], "Hello");
-        assert_eq!(events[0].1["value"], 42);
+    #[test]
+    fn test_get_plugin_param_reads_synced_values() {
+        let mut loader = RhaiLoader::new();
 
-        assert_eq!(events[1].0, "CustomEvent2");
-        assert_eq!(events[1].1["data"], "World");
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_update(tick) {{
+    let difficulty = get_plugin_param("issun:combat", "difficulty_multiplier");
+    if difficulty != () {{
+        set_plugin_param("combat", "difficulty_multiplier", difficulty * 2.0);
+    }}
+}}
+"#
+        )
+        .unwrap();
 
-        // Draining again should return empty
-        let events2 = loader.drain_events();
-        assert_eq!(events2.len(), 0);
+        let handle = loader.load(file.path()).unwrap();
+
+        // Nothing synced yet: the param reads as ()
+        loader.update(&handle, 0).unwrap();
+        assert!(loader.drain_commands().is_empty());
+
+        let mut params = PluginParams::new();
+        params.insert(
+            ("combat".to_string(), "difficulty_multiplier".to_string()),
+            serde_json::json!(1.5),
+        );
+        loader.sync_plugin_params(&params);
+
+        loader.update(&handle, 1).unwrap();
+        let commands = loader.drain_commands();
+        assert_eq!(commands.len(), 1);
+        match &commands[0].action {
+            PluginAction::SetParameter { key, value } => {
+                assert_eq!(key, "difficulty_multiplier");
+                assert_eq!(value, &serde_json::json!(3.0));
+            }
+            other => panic!("unexpected action {:?}", other),
+        }
+    }
+
+    fn run_seeded(seed: u64, script: &str) -> Vec<serde_json::Value> {
+        let mut loader = RhaiLoader::new().with_seed(seed);
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", script).unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+        for tick in 0..3 {
+            loader.update(&handle, tick).unwrap();
+        }
+        loader
+            .drain_commands()
+            .into_iter()
+            // Each run loads from its own temp file, so ids differ
+            .map(|command| {
+                serde_json::to_value(PluginControl {
+                    issuer: None,
+                    ..command
+                })
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_seeded_random_is_reproducible() {
+        let script = r#"
+fn on_init() {
+    set_plugin_param("loot", "luck", random());
+}
+
+fn on_update(tick) {
+    let roll = random_int(1, 6);
+    if roll < 1 || roll > 6 { throw "roll out of range"; }
+    let spread = random_range(0.5, 1.5);
+    if spread < 0.5 || spread >= 1.5 { throw "spread out of range"; }
+    set_plugin_param("combat", "roll", roll);
+    set_plugin_param("combat", "spread", spread);
+    set_plugin_param("combat", "swapped", random_int(10, -10));
+}
+"#;
+
+        let first = run_seeded(42, script);
+        assert_eq!(first.len(), 1 + 3 * 3);
+        assert_eq!(first, run_seeded(42, script));
+        assert_ne!(first, run_seeded(43, script));
+
+        let loader = RhaiLoader::new().with_seed(7);
+        assert_eq!(loader.seed(), Some(7));
+        assert_eq!(RhaiLoader::new().seed(), None);
+    }
+
+    /// Commands, published event types and refusals of a MOD trying to
+    /// reach plugins and events under `defaults`
+    fn run_with_permissions(
+        defaults: ModPermissions,
+    ) -> (Vec<String>, Vec<String>, Vec<ModPermissionDenied>) {
+        let mut loader = RhaiLoader::new();
+        loader.set_permission_policy(ModPermissionPolicy {
+            defaults,
+            trusted_keys: Vec::new(),
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cheats.rhai");
+        std::fs::write(
+            &path,
+            r#"
+fn on_init() {
+    enable_plugin("combat");
+    set_plugin_param("save_load", "slots", 0);
+    publish_event("loot:dropped", #{ item: "sword" });
+    publish_event("GoldAdded", #{ amount: 9999 });
+}
+"#,
+        )
+        .unwrap();
+        loader.load(&path).unwrap();
+
+        let commands = loader
+            .drain_commands()
+            .into_iter()
+            .map(|command| command.plugin_name)
+            .collect();
+        let events = loader
+            .drain_events()
+            .into_iter()
+            .map(|(event_type, _)| event_type)
+            .collect();
+        (commands, events, loader.drain_denials())
+    }
+
+    #[test]
+    fn test_permissions_allow_everything_by_default() {
+        let (commands, events, denials) = run_with_permissions(ModPermissions::all());
+        assert_eq!(commands, vec!["combat", "save_load"]);
+        assert_eq!(events, vec!["loot:dropped", "GoldAdded"]);
+        assert!(denials.is_empty());
+    }
+
+    #[test]
+    fn test_denied_calls_queue_nothing() {
+        let (commands, events, denials) = run_with_permissions(ModPermissions::none());
+        assert!(commands.is_empty());
+        assert!(events.is_empty());
+        let actions: Vec<_> = denials
+            .iter()
+            .map(|denied| denied.action.as_str())
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                "enable plugin 'combat'",
+                "set parameter 'slots' of plugin 'save_load'",
+                "publish event 'loot:dropped'",
+                "publish event 'GoldAdded'",
+            ]
+        );
+        assert!(denials.iter().all(|denied| denied.mod_id == "cheats"));
+    }
+
+    #[test]
+    fn test_wildcard_permissions() {
+        let (commands, events, denials) = run_with_permissions(
+            ModPermissions::none()
+                .with_plugin("comb*")
+                .with_event("loot:*"),
+        );
+        assert_eq!(commands, vec!["combat"]);
+        assert_eq!(events, vec!["loot:dropped"]);
+        assert_eq!(denials.len(), 2);
+        assert_eq!(
+            denials[0].action,
+            "set parameter 'slots' of plugin 'save_load'"
+        );
+        assert_eq!(denials[1].action, "publish event 'GoldAdded'");
+    }
+
+    /// Loader with `dice.rhai` loaded under `master`
+    fn dice_loader(dir: &Path, master: u64) -> (RhaiLoader, ModHandle) {
+        let path = dir.join("dice.rhai");
+        std::fs::write(&path, "fn roll() { random_int(1, 1000000) }").unwrap();
+        let mut loader = RhaiLoader::new();
+        loader.set_master_seed(Some(MasterSeed(master)));
+        let handle = loader.load(&path).unwrap();
+        (loader, handle)
+    }
+
+    fn roll(loader: &mut RhaiLoader, handle: &ModHandle, times: usize) -> Vec<serde_json::Value> {
+        (0..times)
+            .map(|_| loader.call_function(handle, "roll", vec![]).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_master_seed_gives_each_mod_a_reproducible_stream() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut first, handle) = dice_loader(dir.path(), 42);
+        let (mut second, _) = dice_loader(dir.path(), 42);
+        let rolls = roll(&mut first, &handle, 5);
+        assert_eq!(rolls, roll(&mut second, &handle, 5));
+
+        let (mut other_seed, _) = dice_loader(dir.path(), 43);
+        assert_ne!(rolls, roll(&mut other_seed, &handle, 5));
+
+        // The stream is (master seed, "mod", MOD id)
+        let mut stream = ModRng::for_mod(MasterSeed(42), "dice");
+        let expected: Vec<_> = (0..5)
+            .map(|_| serde_json::json!(stream.int(1, 1000000)))
+            .collect();
+        assert_eq!(rolls, expected);
+    }
+
+    #[test]
+    fn test_rng_positions_continue_after_import_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut loader, handle) = dice_loader(dir.path(), 42);
+        let (mut reference, _) = dice_loader(dir.path(), 42);
+        let expected = roll(&mut reference, &handle, 6);
+
+        assert_eq!(roll(&mut loader, &handle, 2), expected[..2]);
+        let positions = loader.export_rng();
+        assert_eq!(positions.len(), 1);
+
+        // A loader resumed from a save continues mid-sequence
+        let (mut resumed, _) = dice_loader(dir.path(), 42);
+        resumed.import_rng(positions);
+        assert_eq!(roll(&mut resumed, &handle, 2), expected[2..4]);
+
+        // Hot reload keeps the position
+        let handle = resumed.reload(&handle).unwrap();
+        assert_eq!(roll(&mut resumed, &handle, 2), expected[4..]);
+
+        // Same seed again is a no-op; a new seed restarts the stream
+        resumed.set_master_seed(Some(MasterSeed(42)));
+        assert_eq!(resumed.export_rng().len(), 1);
+        resumed.set_master_seed(Some(MasterSeed(7)));
+        assert!(resumed.export_rng().is_empty());
+    }
+
+    #[test]
+    fn test_update_without_on_update_is_noop() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        // A differently-shaped on_update is not the per-tick callback
+        writeln!(file, "fn on_update() {{ enable_plugin(\"combat\"); }}").unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+        loader.update(&handle, 0).unwrap();
+        assert!(loader.drain_commands().is_empty());
+
+        let missing = ModHandle {
+            id: "missing".to_string(),
+            ..handle
+        };
+        assert!(matches!(
+            loader.update(&missing, 0),
+            Err(ModError::NotFound(_))
+        ));
+    }
+
+    fn limited_loader() -> RhaiLoader {
+        RhaiLoader::new().with_limits(RhaiLoaderConfig {
+            max_operations: 10_000,
+            max_call_depth: 16,
+            max_string_size: 1024,
+        })
+    }
+
+    fn assert_limit_error<T: std::fmt::Debug>(result: ModResult<T>, mod_id: &str, limit: &str) {
+        match result {
+            Err(ModError::ExecutionFailed(msg)) => {
+                assert!(msg.contains(mod_id), "missing MOD id in: {}", msg);
+                assert!(msg.contains(limit), "missing limit in: {}", msg);
+            }
+            other => panic!("expected ExecutionFailed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_infinite_loop_in_on_init_fails_load() {
+        let mut loader = limited_loader();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_init() {{
+    subscribe_event("Tick", |event| {{ }});
+    while true {{ }}
+}}
+"#
+        )
+        .unwrap();
+        let mod_id = file
+            .path()
+            .file_stem()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let started = std::time::Instant::now();
+        let result = loader.load(file.path());
+
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+        assert_limit_error(result, &mod_id, "max_operations (10000)");
+        assert!(loader.get_subscriptions(&mod_id).is_empty());
+    }
+
+    #[test]
+    fn test_call_limits_fail_calls() {
+        let mut loader = limited_loader();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn recurse(n) {{ recurse(n + 1) }}
+fn grow() {{
+    let s = "x";
+    loop {{ s += s; }}
+}}
+fn on_update(tick) {{ loop {{ }} }}
+"#
+        )
+        .unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+
+        assert_limit_error(
+            loader.call_function(&handle, "recurse", vec![serde_json::json!(0)]),
+            &handle.id,
+            "max_call_depth",
+        );
+        assert_limit_error(
+            loader.call_function(&handle, "grow", vec![]),
+            &handle.id,
+            "max_string_size",
+        );
+        assert_limit_error(loader.update(&handle, 0), &handle.id, "max_operations");
+    }
+
+    #[test]
+    fn test_unload_clears_subscriptions() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_init() {{
+    subscribe_event("Tick", |event| {{ }});
+}}
+"#
+        )
+        .unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+        let event = serde_json::json!({});
+        assert_eq!(loader.dispatch_event("Tick", &event), 1);
+
+        loader.unload(&handle).unwrap();
+
+        assert_eq!(loader.dispatch_event("Tick", &event), 0);
+        assert!(loader.get_all_subscriptions().is_empty());
+    }
+
+    #[test]
+    fn test_call_event_callback() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+let message = "";
+
+fn on_init() {{
+    subscribe_event("TestEvent", |event| {{
+        log("Event received: " + event.message);
+    }});
+}}
+"#
+        )
+        .unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+
+        // Get subscriptions
+        let subscriptions = loader.get_subscriptions(&handle.id);
+        assert_eq!(subscriptions.len(), 1);
+
+        // Prepare event data
+        let event_data = serde_json::json!({
+            "message": "Hello from test",
+            "value": 42
+        });
+
+        // Call the callback
+        let result =
+            loader.call_event_callback(&handle.id, &subscriptions[0].callback, &event_data);
+
+        // Should succeed
+        assert!(result.is_ok(), "Callback failed: {:?}", result.err());
+    }
+
+    #[test]
+    fn test_store_round_trips_nested_values() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_init() {{
+    store_set("profile", #{{ name: "hero", tags: ["a", "b"], stats: #{{ hp: 10, speed: 1.5 }} }});
+    store_set("gone", 1);
+    store_set("gone", ());
+}}
+
+fn second_tag() {{
+    store_get("profile").tags[1]
+}}
+
+fn speed() {{
+    store_get("profile").stats.speed
+}}
+"#
+        )
+        .unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+
+        let expected = serde_json::json!({
+            "name": "hero",
+            "tags": ["a", "b"],
+            "stats": { "hp": 10, "speed": 1.5 }
+        });
+        assert_eq!(
+            loader.call_function(&handle, "second_tag", vec![]).unwrap(),
+            serde_json::json!("b")
+        );
+        assert_eq!(
+            loader.call_function(&handle, "speed", vec![]).unwrap(),
+            serde_json::json!(1.5)
+        );
+        assert_eq!(loader.export_state()[&handle.id]["profile"], expected);
+        assert!(loader.export_state()[&handle.id].get("gone").is_none());
+    }
+
+    #[test]
+    fn test_store_survives_reload_unload_and_import() {
+        let script = r#"
+fn on_init() {
+    let boots = store_get("boots");
+    if boots == () { boots = 0; }
+    store_set("boots", boots + 1);
+}
+
+fn boots() { store_get("boots") }
+"#;
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", script).unwrap();
+
+        let mut loader = RhaiLoader::new();
+        let handle = loader.load(file.path()).unwrap();
+        assert_eq!(
+            loader.call_function(&handle, "boots", vec![]).unwrap(),
+            serde_json::json!(1)
+        );
+
+        // Reload: the store is visible to on_init()
+        let handle = loader.reload(&handle).unwrap();
+        assert_eq!(
+            loader.call_function(&handle, "boots", vec![]).unwrap(),
+            serde_json::json!(2)
+        );
+
+        // Unload and load again
+        loader.unload(&handle).unwrap();
+        let handle = loader.load(file.path()).unwrap();
+        assert_eq!(
+            loader.call_function(&handle, "boots", vec![]).unwrap(),
+            serde_json::json!(3)
+        );
+
+        // Game load into a fresh loader: import before the MOD is loaded
+        let saved = loader.export_state();
+        let mut restored = RhaiLoader::new();
+        restored.import_state(saved);
+        let handle = restored.load(file.path()).unwrap();
+        assert_eq!(
+            restored.call_function(&handle, "boots", vec![]).unwrap(),
+            serde_json::json!(4)
+        );
+    }
+
+    #[test]
+    fn test_publish_event() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_init() {{
+    log("Publishing events");
+
+    publish_event("CustomEvent1", #{{
+        message: "Hello",
+        value: 42
+    }});
+
+    publish_event("CustomEvent2", #{{
+        data: "World"
+    }});
+}}
+"#
+        )
+        .unwrap();
+
+        let _handle = loader.load(file.path()).unwrap();
+
+        // Drain published events
+        let events = loader.drain_events();
+
+        // Should have 2 published events
+        assert_eq!(events.len(), 2);
+
+        // Verify event types and data
+        assert_eq!(events[0].0, "CustomEvent1");
+        assert_eq!(events[0].1["message"], "Hello");
+        assert_eq!(events[0].1["value"], 42);
+
+        assert_eq!(events[1].0, "CustomEvent2");
+        assert_eq!(events[1].1["data"], "World");
+
+        // Draining again should return empty
+        let events2 = loader.drain_events();
+        assert_eq!(events2.len(), 0);
+    }
+
+    #[test]
+    fn test_declared_events_are_validated_on_publish() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_init() {{
+    declare_event("CustomEvent1", #{{ message: "string", value: "int" }});
+    publish_event("CustomEvent1", #{{ message: "Hello", value: 42, extra: true }});
+}}
+
+fn publish_bad() {{
+    let errors = 0;
+    try {{
+        publish_event("CustomEvent1", #{{ message: "Hello" }});
+    }} catch (e) {{
+        errors += 1;
+    }}
+    try {{
+        publish_event("CustomEvent1", #{{ message: "Hello", value: "42" }});
+    }} catch (e) {{
+        errors += 1;
+    }}
+    // Undeclared events stay unchecked
+    publish_event("CustomEvent2", #{{ anything: 1 }});
+    errors
+}}
+
+fn publish_unchecked() {{
+    publish_event("CustomEvent1", #{{ message: 1 }});
+}}
+"#
+        )
+        .unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+        let events = loader.drain_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].1["value"], 42);
+
+        assert_eq!(
+            loader
+                .call_function(&handle, "publish_bad", vec![])
+                .unwrap(),
+            serde_json::json!(2)
+        );
+        let events = loader.drain_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0, "CustomEvent2");
+
+        // An uncaught mismatch fails the call with the field in the message
+        let err = loader
+            .call_function(&handle, "publish_unchecked", vec![])
+            .unwrap_err();
+        assert!(err.to_string().contains("field 'message' must be string"));
+        assert!(loader.drain_events().is_empty());
+
+        // The declaration goes away with its MOD
+        loader.unload(&handle).unwrap();
+        assert!(loader.event_schemas.lock().is_empty());
+    }
+
+    #[test]
+    fn test_declare_event_rejects_unknown_types() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn declare() {{
+    declare_event("CustomEvent1", #{{ value: "integer" }});
+}}
+"#
+        )
+        .unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+        let err = loader
+            .call_function(&handle, "declare", vec![])
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown type 'integer'"));
+        assert!(loader.event_schemas.lock().is_empty());
+    }
+
+    #[test]
+    fn test_host_fns_survive_clone() {
+        use std::sync::atomic::{AtomicI64, Ordering};
+
+        let gold = Arc::new(AtomicI64::new(0));
+        let loader = RhaiLoader::new()
+            .with_engine_setup({
+                let gold = gold.clone();
+                move |engine| {
+                    let gold = gold.clone();
+                    engine.register_fn("add_gold", move |amount: i64| {
+                        gold.fetch_add(amount, Ordering::Relaxed);
+                    });
+                }
+            })
+            .with_engine_setup(|engine| {
+                engine.register_fn("double", |x: i64| x * 2);
+            });
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn reward(amount) {{
+    add_gold(double(amount));
+}}
+"#
+        )
+        .unwrap();
+
+        // Loaded into a clone: the clone's engine must know the host functions
+        let mut cloned = loader.clone_box();
+        let handle = cloned.load(file.path()).unwrap();
+        cloned
+            .call_function(&handle, "reward", vec![serde_json::json!(5)])
+            .unwrap();
+        assert_eq!(gold.load(Ordering::Relaxed), 10);
+    }
+
+    /// Loader whose `host_crash()` panics while holding the command queue
+    /// lock, plus a MOD that crashes through it and one that keeps working
+    fn loader_with_crashing_mod() -> (RhaiLoader, ModHandle, ModHandle, Vec<NamedTempFile>) {
+        let loader = RhaiLoader::new();
+        let queue = loader.command_queue.clone();
+        let mut loader = loader.with_engine_setup(move |engine| {
+            let queue = queue.clone();
+            engine.register_fn("host_crash", move || {
+                let _queue = queue.lock();
+                panic!("host function crashed");
+            });
+        });
+
+        let mut crashing = NamedTempFile::new().unwrap();
+        writeln!(
+            crashing,
+            r#"
+fn on_init() {{
+    subscribe_event("Tick", |event| {{ host_crash(); }});
+}}
+
+fn crash() {{
+    host_crash();
+}}
+"#
+        )
+        .unwrap();
+        let mut healthy = NamedTempFile::new().unwrap();
+        writeln!(
+            healthy,
+            r#"
+fn on_init() {{
+    subscribe_event("Tick", |event| {{
+        enable_plugin("combat");
+        publish_event("Healed", #{{ amount: 1 }});
+    }});
+}}
+
+fn work() {{
+    disable_plugin("combat");
+}}
+"#
+        )
+        .unwrap();
+
+        let crashing_handle = loader.load(crashing.path()).unwrap();
+        let healthy_handle = loader.load(healthy.path()).unwrap();
+        loader.set_dispatch_order(&[crashing_handle.id.clone(), healthy_handle.id.clone()]);
+        (
+            loader,
+            crashing_handle,
+            healthy_handle,
+            vec![crashing, healthy],
+        )
+    }
+
+    #[test]
+    fn test_poisoned_queue_recovers_after_caught_panic() {
+        let (mut loader, crashing, healthy, _files) = loader_with_crashing_mod();
+
+        let crashed = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            loader.call_function(&crashing, "crash", vec![])
+        }));
+        assert!(crashed.is_err());
+
+        // The next MOD's commands still reach the queue and drain
+        loader.call_function(&healthy, "work", vec![]).unwrap();
+        let commands = loader.drain_commands();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].issuer.as_deref(), Some(healthy.id.as_str()));
+        assert_eq!(loader.get_subscriptions(&healthy.id).len(), 1);
+
+        // Reported exactly once
+        let errors = loader.drain_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].context, "lock recovery");
+        assert!(errors[0].error.contains("command_queue"));
+        loader.call_function(&healthy, "work", vec![]).unwrap();
+        assert_eq!(loader.drain_commands().len(), 1);
+        assert!(loader.drain_errors().is_empty());
+    }
+
+    #[test]
+    fn test_clone_keeps_undrained_errors() {
+        let (mut loader, crashing, _healthy, _files) = loader_with_crashing_mod();
+        loader.dispatch_event("Tick", &serde_json::json!({}));
+
+        let mut clone = loader.clone();
+        let errors = clone.drain_errors();
+        assert!(!errors.is_empty());
+        assert!(errors.iter().all(|error| error.mod_id == crashing.id));
+        // Copied, not moved; the clone's queues are fresh locks, so only the
+        // original reports recovering its poisoned one
+        assert!(loader.drain_errors().starts_with(&errors));
+    }
+
+    #[test]
+    fn test_panicking_callback_is_isolated_from_other_mods() {
+        let (mut loader, crashing, healthy, _files) = loader_with_crashing_mod();
+
+        assert_eq!(loader.dispatch_event("Tick", &serde_json::json!({})), 1);
+
+        let commands = loader.drain_commands();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].issuer.as_deref(), Some(healthy.id.as_str()));
+        assert_eq!(loader.drain_events().len(), 1);
+
+        let errors = loader.drain_errors();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].mod_id, crashing.id);
+        assert_eq!(errors[0].error, "panicked: host function crashed");
+        assert_eq!(errors[1].mod_id, crashing.id);
+        assert_eq!(errors[1].context, "lock recovery");
+
+        // Nothing left to report once the panics stop
+        loader.call_function(&healthy, "work", vec![]).unwrap();
+        assert_eq!(loader.drain_commands().len(), 1);
+        assert!(loader.drain_errors().is_empty());
+    }
+
+    #[test]
+    fn test_schedule_runs_callback_after_delay() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_init() {{
+    schedule(3, |turn| {{
+        publish_event("InfectionRateRaised", #{{ turn: turn }});
+    }});
+}}
+"#
+        )
+        .unwrap();
+
+        loader.load(file.path()).unwrap();
+        assert_eq!(loader.tick_schedules(1), 0);
+        assert_eq!(loader.tick_schedules(2), 0);
+        assert!(loader.drain_events().is_empty());
+
+        assert_eq!(loader.tick_schedules(3), 1);
+        let events = loader.drain_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0, "InfectionRateRaised");
+        assert_eq!(events[0].1["turn"], 3);
+
+        // One-shot
+        assert_eq!(loader.tick_schedules(4), 0);
+    }
+
+    #[test]
+    fn test_unload_cancels_schedules() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_init() {{
+    schedule(1, |turn| publish_event("Late", #{{}}));
+}}
+"#
+        )
+        .unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+        loader.unload(&handle).unwrap();
+        assert_eq!(loader.tick_schedules(1), 0);
+        assert!(loader.drain_events().is_empty());
+    }
+
+    #[test]
+    fn test_register_strings_is_attributed_and_dropped_on_unload() {
+        let mut loader = RhaiLoader::new();
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+fn on_init() {{
+    register_strings("en", #{{ "item.flame_sword.name": "Flame Sword" }});
+    register_strings("ja", #{{ "item.flame_sword.name": "炎の剣" }});
+}}
+
+fn on_update(tick) {{
+    register_strings("en", #{{ "tick": "Tick " + tick }});
+}}
+"#
+        )
+        .unwrap();
+
+        let handle = loader.load(file.path()).unwrap();
+        let mut strings = loader.drain_strings();
+        strings.sort_by(|a, b| a.language.cmp(&b.language));
+        assert_eq!(strings.len(), 2);
+        assert!(strings.iter().all(|s| s.mod_id == handle.id));
+        assert_eq!(strings[0].strings["item.flame_sword.name"], "Flame Sword");
+        assert_eq!(strings[1].language, "ja");
+        assert_eq!(strings[1].strings["item.flame_sword.name"], "炎の剣");
+        assert!(loader.drain_strings().is_empty());
+
+        // Strings still queued when the MOD unloads are discarded
+        loader.update(&handle, 3).unwrap();
+        loader.unload(&handle).unwrap();
+        assert!(loader.drain_strings().is_empty());
+    }
+
+    #[test]
+    fn test_register_action_queues_named_callbacks() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut loader = RhaiLoader::new();
+        let handle = load_named(
+            &mut loader,
+            dir.path(),
+            "propaganda",
+            r#"
+fn on_init() {
+    register_action("campaign", 2, "run_campaign");
+    register_action("rally", 1, Fn("run_rally"), 3);
+}
+fn run_campaign(ctx) { ctx.target.district }
+fn run_rally(ctx) { }
+fn closure_callback() { register_action("bad", 1, |ctx| ctx); }
+"#,
+        );
+
+        let actions = loader.drain_actions();
+        let summary: Vec<_> = actions
+            .iter()
+            .map(|a| {
+                (
+                    a.name.as_str(),
+                    a.ap_cost,
+                    a.cooldown_days,
+                    a.callback.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("campaign", 2, 0, "run_campaign"),
+                ("rally", 1, 3, "run_rally")
+            ]
+        );
+        assert!(actions.iter().all(|a| a.mod_id == "propaganda"));
+
+        // Context maps reach the callback as Rhai maps
+        let context = serde_json::json!({ "target": { "district": "north" } });
+        assert_eq!(
+            loader
+                .call_function(&handle, "run_campaign", vec![context])
+                .unwrap(),
+            serde_json::json!("north")
+        );
+
+        // Closures can't be called back by name
+        assert!(loader
+            .call_function(&handle, "closure_callback", vec![])
+            .is_err());
+        assert!(loader.drain_actions().is_empty());
+    }
+
+    fn write_mod_dir(root: &std::path::Path, manifest: &str, entry: &str) -> PathBuf {
+        let dir = root.join("better_loot");
+        std::fs::create_dir_all(dir.join("scripts")).unwrap();
+        std::fs::write(dir.join("mod.toml"), manifest).unwrap();
+        std::fs::write(dir.join("scripts/main.rhai"), entry).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_dir_reads_manifest_and_entry() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = write_mod_dir(
+            root.path(),
+            r#"
+name = "Better Loot"
+version = "1.3.0"
+entry = "scripts/main.rhai"
+depends = ["core_tweaks >= 1.2"]
+"#,
+            r#"
+fn get_metadata() { #{ name: "ignored", version: "0.0.1", author: "modder" } }
+fn on_init() { enable_plugin("loot"); }
+"#,
+        );
+
+        let mut loader = RhaiLoader::new();
+        let handle = loader.load(&dir).unwrap();
+
+        assert_eq!(handle.id, "better_loot");
+        assert_eq!(handle.metadata.name, "Better Loot");
+        assert_eq!(handle.metadata.version, "1.3.0");
+        assert_eq!(handle.metadata.author.as_deref(), Some("modder"));
+        assert_eq!(handle.metadata.dependencies.len(), 1);
+        assert_eq!(
+            handle.metadata.dependencies[0].to_string(),
+            "core_tweaks >= 1.2"
+        );
+        assert_eq!(loader.drain_commands()[0].plugin_name, "loot");
+
+        // Reloading keeps the manifest metadata
+        let reloaded = loader.reload(&handle).unwrap();
+        assert_eq!(reloaded.metadata.name, "Better Loot");
+        assert_eq!(reloaded.metadata.dependencies, handle.metadata.dependencies);
+    }
+
+    #[test]
+    fn test_load_dir_without_entry_script_fails() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = write_mod_dir(
+            root.path(),
+            "name = \"Better Loot\"\nversion = \"1.0.0\"\n",
+            "fn on_init() { }",
+        );
+
+        // Default entry is main.rhai at the top of the directory
+        let err = RhaiLoader::new().load_dir(&dir).unwrap_err();
+        assert!(matches!(err, ModError::LoadFailed(_)));
+        assert!(err.to_string().contains("main.rhai"));
     }
 }
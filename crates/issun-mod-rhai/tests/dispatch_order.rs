@@ -0,0 +1,93 @@
+//! Callbacks of several MODs for the same event run in dispatch order
+
+use issun::context::ResourceContext;
+use issun::event::EventBus;
+use issun::modding::{DynamicEvent, ModEventSystem, ModLogEvent, ModRegistry, ModSystemPlugin};
+use issun::prelude::GameBuilder;
+use issun_mod_rhai::RhaiLoader;
+use std::path::Path;
+
+/// Write a MOD that logs when it sees `CombatEnded`
+fn write_mod(dir: &Path, id: &str, metadata: &str) {
+    let script = format!(
+        r#"
+fn get_metadata() {{
+    #{{ name: "{id}", version: "1.0.0", {metadata} }}
+}}
+
+fn on_init() {{
+    subscribe_event("CombatEnded", |event| {{
+        log("drop adjusted");
+    }});
+}}
+"#
+    );
+    std::fs::write(dir.join(format!("{}.rhai", id)), script).unwrap();
+}
+
+/// Build a game from `dir` and return (dispatch order, callback order)
+async fn run_combat_ended(dir: &Path) -> (Vec<String>, Vec<String>) {
+    let game = GameBuilder::new()
+        .with_plugin(
+            ModSystemPlugin::new()
+                .with_loader(RhaiLoader::new())
+                .with_mod_dir(dir),
+        )
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+    let mut resources: ResourceContext = game.resources;
+
+    let order = resources
+        .get::<ModRegistry>()
+        .await
+        .unwrap()
+        .dispatch_order()
+        .to_vec();
+
+    {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.publish(DynamicEvent {
+            event_type: "CombatEnded".to_string(),
+            data: serde_json::json!({ "gold": 10 }),
+        });
+        bus.dispatch();
+    }
+    ModEventSystem::new().update_resources(&mut resources).await;
+
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    let called = bus
+        .reader::<ModLogEvent>()
+        .iter()
+        .filter_map(|event| event.entry.mod_id.clone())
+        .collect();
+    (order, called)
+}
+
+#[tokio::test]
+async fn test_after_constraint_flips_load_order() {
+    let dir = tempfile::tempdir().unwrap();
+    write_mod(dir.path(), "a_balance_plus", r#"after: ["b_core_balance"]"#);
+    write_mod(dir.path(), "b_core_balance", "");
+
+    let (order, called) = run_combat_ended(dir.path()).await;
+    assert_eq!(order, vec!["b_core_balance", "a_balance_plus"]);
+    assert_eq!(called, order);
+}
+
+#[tokio::test]
+async fn test_priority_ties_fall_back_to_load_order() {
+    let dir = tempfile::tempdir().unwrap();
+    write_mod(dir.path(), "late", "priority: 0");
+    write_mod(dir.path(), "tie_y", "priority: 5");
+    write_mod(dir.path(), "tie_x", "priority: 5");
+
+    let expected = vec!["tie_x", "tie_y", "late"];
+    for _ in 0..3 {
+        let (order, called) = run_combat_ended(dir.path()).await;
+        assert_eq!(order, expected);
+        assert_eq!(called, expected);
+    }
+}
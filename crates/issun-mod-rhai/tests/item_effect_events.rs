@@ -0,0 +1,82 @@
+//! An item's PublishEvent effect reaches the subscription of a MOD
+
+use issun::context::{ResourceContext, ServiceContext};
+use issun::event::EventBus;
+use issun::modding::{ModEventSystem, ModLogEvent, ModSystemPlugin};
+use issun::plugin::inventory::{
+    DefaultInventoryHook, InventoryPlugin, InventoryState, InventorySystem, ItemCatalog,
+    ItemDefinition, ItemEffect, ItemUseRequested,
+};
+use issun::prelude::GameBuilder;
+use issun_mod_rhai::RhaiLoader;
+use std::sync::Arc;
+
+const FIREWORKS_MOD: &str = r#"
+fn get_metadata() {
+    #{ name: "fireworks", version: "1.0.0" }
+}
+
+fn on_init() {
+    subscribe_event("Fireworks", |event| {
+        log("launched " + event.color);
+    });
+}
+"#;
+
+#[tokio::test]
+async fn test_publish_event_effect_reaches_mod_subscription() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("fireworks.rhai"), FIREWORKS_MOD).unwrap();
+
+    // The MOD's event is not a Rust type, so it has no factory
+    let items = ItemCatalog::new().with_item(
+        "fireworks",
+        ItemDefinition::consumable(vec![ItemEffect::PublishEvent {
+            name: "Fireworks".to_string(),
+            payload: serde_json::json!({ "color": "red" }),
+        }]),
+    );
+    let game = GameBuilder::new()
+        .with_plugin(
+            ModSystemPlugin::new()
+                .with_loader(RhaiLoader::new())
+                .with_mod_dir(dir.path()),
+        )
+        .unwrap()
+        .with_plugin(InventoryPlugin::new().with_items(items))
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+    let mut resources: ResourceContext = game.resources;
+
+    resources
+        .get_mut::<InventoryState>()
+        .await
+        .unwrap()
+        .add_item(&"player".to_string(), &"fireworks".to_string(), 1)
+        .unwrap();
+    {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.publish(ItemUseRequested {
+            entity_id: "player".to_string(),
+            item_id: "fireworks".to_string(),
+            target: None,
+        });
+        bus.dispatch();
+    }
+    InventorySystem::new(Arc::new(DefaultInventoryHook))
+        .process_events(&ServiceContext::new(), &mut resources)
+        .await;
+    resources.get_mut::<EventBus>().await.unwrap().dispatch();
+    ModEventSystem::new().update_resources(&mut resources).await;
+
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    let logs: Vec<String> = bus
+        .reader::<ModLogEvent>()
+        .iter()
+        .map(|event| event.entry.message.clone())
+        .collect();
+    assert_eq!(logs, vec!["launched red"]);
+}
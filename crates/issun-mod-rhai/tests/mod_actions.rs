@@ -0,0 +1,187 @@
+//! Player actions defined by a MOD with `register_action()`, performed
+//! through ModBridgeSystem
+
+use issun::context::ResourceContext;
+use issun::engine::ModBridgeSystem;
+use issun::event::{Event, EventBus};
+use issun::modding::{ModActions, ModLoader, ModLoaderState, ModUnloadedEvent};
+use issun::plugin::action::{
+    ActionFailed, ActionPerformed, ActionPoints, ActionRegistry, ActionRequested,
+};
+use issun::plugin::GameTimer;
+use issun_mod_rhai::RhaiLoader;
+use std::path::Path;
+
+const FIXTURE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/propaganda.rhai"
+);
+
+struct Harness {
+    resources: ResourceContext,
+    bridge: ModBridgeSystem,
+}
+
+impl Harness {
+    /// The fixture MOD loaded, with 3 action points on day 1
+    async fn new() -> Self {
+        let mut loader = RhaiLoader::new();
+        let handle = loader.load(Path::new(FIXTURE)).unwrap();
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(GameTimer::new());
+        resources.insert(ActionPoints::new(3));
+        resources.insert(ActionRegistry::new());
+        resources.insert(ModActions::new());
+        resources.insert(ModLoaderState {
+            loader: Box::new(loader),
+            loaded_mods: vec![handle],
+        });
+
+        let mut harness = Self {
+            resources,
+            bridge: ModBridgeSystem::new(),
+        };
+        harness.update().await;
+        harness
+    }
+
+    async fn publish(&mut self, event: impl Event + serde::Serialize) {
+        let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+        bus.publish(event);
+        bus.dispatch();
+    }
+
+    async fn update(&mut self) {
+        self.bridge.update_resources(&mut self.resources).await;
+    }
+
+    async fn request(&mut self, action_id: &str) {
+        self.publish(ActionRequested {
+            action_id: action_id.to_string(),
+            actor: Some("player".to_string()),
+            target: Some(serde_json::json!({ "district": "north" })),
+        })
+        .await;
+        self.update().await;
+        self.resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .dispatch();
+    }
+
+    async fn collect<E: Event + Clone>(&self) -> Vec<E> {
+        let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+        bus.reader::<E>().iter().cloned().collect()
+    }
+
+    async fn available(&self) -> u32 {
+        self.resources
+            .get::<ActionPoints>()
+            .await
+            .unwrap()
+            .available
+    }
+}
+
+#[tokio::test]
+async fn test_mod_action_appears_in_registry_and_menu() {
+    let harness = Harness::new().await;
+
+    let registry = harness.resources.get::<ActionRegistry>().await.unwrap();
+    let campaign = registry.get("propaganda:campaign").unwrap();
+    assert_eq!(campaign.ap_cost, 2);
+    assert_eq!(campaign.cooldown_days, 1);
+    assert_eq!(campaign.source.as_deref(), Some("propaganda"));
+
+    let actions = harness.resources.get::<ModActions>().await.unwrap();
+    let menu: Vec<_> = actions
+        .iter()
+        .map(|entry| (entry.action_id.as_str(), entry.label_key.as_str()))
+        .collect();
+    assert_eq!(
+        menu,
+        [
+            ("propaganda:campaign", "propaganda.action.campaign"),
+            ("propaganda:rally", "propaganda.action.rally"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_mod_action_runs_callback_and_deducts_ap() {
+    let mut harness = Harness::new().await;
+
+    harness.request("propaganda:campaign").await;
+
+    assert_eq!(harness.available().await, 1);
+    assert_eq!(
+        harness.collect::<ActionPerformed>().await,
+        [ActionPerformed {
+            action_id: "propaganda:campaign".to_string(),
+            actor: Some("player".to_string()),
+            ap_cost: 2,
+            remaining: 1,
+        }]
+    );
+    let logs = {
+        let mut loader_state = harness.resources.get_mut::<ModLoaderState>().await.unwrap();
+        loader_state.loader.drain_logs()
+    };
+    assert!(logs
+        .iter()
+        .any(|entry| entry.message == "campaign by player in north on day 1"));
+
+    // On cooldown for the rest of the day
+    harness.request("propaganda:campaign").await;
+    let failed = harness.collect::<ActionFailed>().await;
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].refunded, 0);
+    assert!(failed[0].reason.contains("cooldown"), "{:?}", failed);
+    assert_eq!(harness.available().await, 1);
+}
+
+#[tokio::test]
+async fn test_callback_error_refunds() {
+    let mut harness = Harness::new().await;
+
+    harness.request("propaganda:rally").await;
+
+    assert_eq!(harness.available().await, 3);
+    assert!(harness.collect::<ActionPerformed>().await.is_empty());
+    let failed = harness.collect::<ActionFailed>().await;
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].refunded, 1);
+    assert!(
+        failed[0].reason.contains("the square is empty"),
+        "{:?}",
+        failed
+    );
+}
+
+#[tokio::test]
+async fn test_unload_removes_actions_from_menus() {
+    let mut harness = Harness::new().await;
+
+    harness
+        .publish(ModUnloadedEvent {
+            mod_id: "propaganda".to_string(),
+        })
+        .await;
+    harness.update().await;
+
+    assert!(harness
+        .resources
+        .get::<ModActions>()
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(harness
+        .resources
+        .get::<ActionRegistry>()
+        .await
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,72 @@
+//! Loading every script of a mods/ directory at startup
+
+use issun::modding::{ModLoader, ModRegistry, ModSystemConfig, ModSystemPlugin};
+use issun::prelude::GameBuilder;
+use issun_mod_rhai::RhaiLoader;
+
+#[tokio::test]
+async fn test_mod_dir_skips_broken_scripts() {
+    let dir = tempfile::tempdir().unwrap();
+    let script = r#"
+fn get_metadata() {
+    #{ name: "Arena Tweak", version: "1.0.0" }
+}
+"#;
+    std::fs::write(dir.path().join("b_tweak.rhai"), script).unwrap();
+    std::fs::write(dir.path().join("a_tweak.rhai"), script).unwrap();
+    std::fs::write(dir.path().join("broken.rhai"), "fn on_init( {").unwrap();
+    std::fs::write(dir.path().join("README.md"), "# MODs").unwrap();
+
+    let game = GameBuilder::new()
+        .with_plugin(
+            ModSystemPlugin::new()
+                .with_loader(RhaiLoader::new())
+                .with_mod_dir(dir.path()),
+        )
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    let registry = game.resources.get::<ModRegistry>().await.unwrap();
+    assert_eq!(registry.ids(), vec!["a_tweak", "b_tweak"]);
+    assert_eq!(
+        registry.get("a_tweak").unwrap().metadata.name,
+        "Arena Tweak"
+    );
+
+    let config = game.resources.get::<ModSystemConfig>().await.unwrap();
+    assert_eq!(config.mod_dir, dir.path().display().to_string());
+}
+
+#[test]
+fn test_peek_metadata_runs_no_script_code() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("loud.rhai");
+    std::fs::write(
+        &path,
+        r#"
+log("top level");
+fn get_metadata() {
+    #{ name: "Loud", version: "2.0.0", priority: 4 }
+}
+fn on_init() {
+    enable_plugin("combat");
+}
+"#,
+    )
+    .unwrap();
+
+    let mut loader = RhaiLoader::new();
+    let metadata = loader.peek_metadata(&path).unwrap();
+    assert_eq!(metadata.name, "Loud");
+    assert_eq!(metadata.version, "2.0.0");
+    assert_eq!(metadata.priority, 4);
+    assert!(loader.drain_logs().is_empty());
+    assert!(loader.drain_commands().is_empty());
+
+    std::fs::write(dir.path().join("broken.rhai"), "fn on_init( {").unwrap();
+    assert!(loader
+        .peek_metadata(&dir.path().join("broken.rhai"))
+        .is_err());
+}
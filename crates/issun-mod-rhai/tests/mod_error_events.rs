@@ -0,0 +1,121 @@
+//! Errors of Rhai MOD callbacks published as `ModRuntimeErrorEvent`
+
+use issun::context::ResourceContext;
+use issun::engine::ModBridgeSystem;
+use issun::event::EventBus;
+use issun::modding::{
+    DynamicEvent, ModEventSystem, ModLoader, ModLoaderState, ModRuntimeErrorEvent,
+};
+use issun_mod_rhai::RhaiLoader;
+
+const THROWING: &str = r#"
+fn on_init() {
+    subscribe_event("Boom", |data| {
+        throw "kaboom: " + data.power;
+    });
+}
+
+fn on_update(tick) {
+    if tick == 0 {
+        throw "bad tick";
+    }
+}
+"#;
+
+/// The throwing script loaded as MOD "throwing"
+fn resources_with_throwing_mod(dir: &std::path::Path) -> ResourceContext {
+    let path = dir.join("throwing.rhai");
+    std::fs::write(&path, THROWING).unwrap();
+    let mut loader = RhaiLoader::new();
+    let handle = loader.load(&path).unwrap();
+
+    let mut resources = ResourceContext::new();
+    resources.insert(EventBus::new());
+    resources.insert(ModLoaderState {
+        loader: Box::new(loader),
+        loaded_mods: vec![handle],
+    });
+    resources
+}
+
+async fn runtime_errors(resources: &ResourceContext) -> Vec<ModRuntimeErrorEvent> {
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    bus.reader::<ModRuntimeErrorEvent>()
+        .iter()
+        .cloned()
+        .collect()
+}
+
+#[tokio::test]
+async fn test_throwing_event_callback_publishes_runtime_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut resources = resources_with_throwing_mod(dir.path());
+    {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.publish(DynamicEvent {
+            event_type: "Boom".to_string(),
+            data: serde_json::json!({ "power": 3 }),
+        });
+        bus.dispatch();
+    }
+
+    ModEventSystem::new().update_resources(&mut resources).await;
+
+    let errors = runtime_errors(&resources).await;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].mod_id, "throwing");
+    assert!(errors[0].context.starts_with("event 'Boom'"));
+    assert!(errors[0].error.contains("kaboom: 3"));
+
+    // Reported once
+    ModEventSystem::new().update_resources(&mut resources).await;
+    assert!(runtime_errors(&resources).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_throwing_on_update_publishes_runtime_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut resources = resources_with_throwing_mod(dir.path());
+
+    let mut bridge = ModBridgeSystem::new();
+    bridge.update_resources(&mut resources).await;
+
+    let errors = runtime_errors(&resources).await;
+    assert_eq!(
+        errors
+            .iter()
+            .map(|error| (error.mod_id.as_str(), error.context.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("throwing", "on_update")]
+    );
+    assert!(errors[0].error.contains("bad tick"));
+
+    bridge.update_resources(&mut resources).await;
+    assert!(runtime_errors(&resources).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_hot_reload_queues_runtime_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("throwing.rhai");
+    std::fs::write(&path, THROWING).unwrap();
+    let mut loader = RhaiLoader::new().with_watch(true);
+    loader.load(&path).unwrap();
+
+    // Break the script; the old version stays loaded
+    std::fs::write(&path, "fn on_init( {").unwrap();
+    let later = std::time::SystemTime::now() + std::time::Duration::from_secs(10);
+    std::fs::File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(later)
+        .unwrap();
+    assert!(loader.reload_changed().is_empty());
+
+    let errors = loader.drain_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].mod_id, "throwing");
+    assert_eq!(errors[0].context, "reload");
+}
@@ -20,15 +20,127 @@
 //!     .build()
 //!     .await?;
 //! ```
+//!
+//! Components load from binary `.wasm` files and from the text format
+//! (`.wat`). Add a `RhaiLoader` as a second loader to mix both backends in
+//! one MOD directory.
+//!
+//! # WASI
+//!
+//! Guests get inherited stdio, clocks and random numbers. Anything more, such
+//! as filesystem access, is granted explicitly with [`WasmLoader::with_wasi`]:
+//!
+//! ```ignore
+//! use issun_mod_wasm::{DirPerms, FilePerms, WasmLoader};
+//!
+//! let loader = WasmLoader::new()?.with_wasi(|builder| {
+//!     builder.preopened_dir("mods/data", "/data", DirPerms::READ, FilePerms::READ)?;
+//!     Ok(())
+//! });
+//! ```
+//!
+//! # Random numbers
+//!
+//! Once the loader has a master seed (`ModLoader::set_master_seed`, done by
+//! `ModSystemPlugin` from the `MasterSeed` resource), `random`,
+//! `random-range` and `random-int` draw from a stream per MOD, seeded from
+//! (master seed, `"mod"`, MOD id), so sessions with the same seed replay the
+//! same numbers. Unloading a MOD keeps its stream position for the next
+//! load. Without a master seed they draw from `rand`.
+//!
+//! # Permissions
+//!
+//! Plugin control and `publish-event` check the MOD's
+//! `issun::modding::ModPermissions`, taken from the policy set with
+//! `ModLoader::set_permission_policy` when the MOD loads; a signed
+//! `[permissions]` section in the sidecar manifest (`pathfinder.toml`) can
+//! replace the defaults. Refused calls queue nothing and are drained with
+//! `drain_denials`. Directories in its `filesystem` list are preopened for
+//! the MOD, relative ones against the directory holding the component.
+//!
+//! # Limits
+//!
+//! Every call into a guest (`on_init`, `call_custom`, `on_control_plugin`,
+//! `on_event`, ...) gets a fresh fuel budget and, optionally, a wall-clock
+//! deadline, so a guest stuck in a loop fails that call with
+//! `ModError::ExecutionFailed` instead of hanging the game. See
+//! [`WasmLoaderConfig`].
+//!
+//! # Async mode
+//!
+//! [`WasmLoader::new_async`] builds an async engine: guest calls made through
+//! [`ModLoader::load_async`] and [`ModLoader::call_function_async`] yield to
+//! the executor at every epoch tick, so a MOD busy with a long `call_custom`
+//! doesn't stall the other tasks on its thread. The synchronous `ModLoader`
+//! methods keep working on an async loader.
+//!
+//! ```ignore
+//! let mut loader = WasmLoader::new_async()?;
+//! let handle = loader.load_async(Path::new("mods/pathfinder.wasm")).await?;
+//! let route = loader.call_function_async(&handle, "route", args).await?;
+//! ```
+//!
+//! # Compilation cache
+//!
+//! Compiling a large component takes seconds. [`WasmLoader::with_cache_dir`]
+//! keeps compiled artifacts on disk, keyed by the component's content and
+//! the engine (Wasmtime version and settings), so loading an unchanged MOD
+//! again skips compilation:
+//!
+//! ```ignore
+//! let loader = WasmLoader::new()?.with_cache_dir("target/mod-cache");
+//! ```
+//!
+//! # Metadata
+//!
+//! [`ModLoader::peek_metadata`] reads a MOD's metadata without running its
+//! `on_init`, for listing installed MODs. It looks, in order, for a sidecar
+//! manifest next to the component (`pathfinder.toml` for
+//! `pathfinder.wasm`), for a `mod.toml` embedded in a custom section named
+//! [`METADATA_SECTION`], and finally instantiates the component in a
+//! throwaway store to call its `get-metadata` export.
+//!
+//! # API versions
+//!
+//! MODs import the host API as `issun:modapi/api@<version>`. The loader
+//! reads that version before instantiating a component and accepts
+//! [`MIN_API_VERSION`] up to [`API_VERSION`]; MODs built before the API was
+//! versioned import the unversioned `issun:modapi/api`, which counts as 0.1
+//! and is served by a shim. Anything else fails with
+//! `ModError::InvalidFormat("MOD 'x' built for api 0.4, host supports 0.1–0.3")`
+//! instead of an instantiation error.
+//!
+//! # Events
+//!
+//! A guest calls the `subscribe-event` import for each event type it wants;
+//! [`ModLoader::dispatch_event`] then calls its `on-event` export with the
+//! payload as a JSON string, in dispatch order.
 
+use ::issun::engine::rng::MasterSeed;
+use ::issun::modding::permissions::mod_dir;
 use ::issun::modding::{
-    ModBackend, ModError, ModHandle, ModLoader, ModMetadata, ModResult, PluginAction, PluginControl,
+    ModBackend, ModError, ModHandle, ModLoader, ModLogEntry, ModLogLevel, ModManifest, ModMetadata,
+    ModPermissionDenied, ModPermissionPolicy, ModPermissions, ModResult, ModRng,
+    ModRuntimeErrorEvent, ModStrings, PluginAction, PluginControl,
 };
+use async_trait::async_trait;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
-use std::path::Path;
-use wasmtime::component::{bindgen, Component, Linker};
-use wasmtime::{Config, Engine, Store};
-use wasmtime_wasi::{WasiCtx, WasiCtxBuilder, WasiView};
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::pin::pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+use wasmtime::component::{bindgen, Component, Linker, ResourceTable};
+use wasmtime::{
+    Config, Engine, Store, StoreContextMut, StoreLimits, StoreLimitsBuilder, Trap, UpdateDeadline,
+};
+use wasmtime_wasi::{WasiCtx, WasiView};
+
+pub use wasmtime_wasi::{DirPerms, FilePerms, WasiCtxBuilder};
 
 // Generate Rust bindings from WIT file
 bindgen!({
@@ -37,23 +149,335 @@ bindgen!({
     async: false,
 });
 
+/// The same world with async exports, for [`WasmLoader::new_async`]
+///
+/// Imports stay synchronous and reuse the `api` interface generated above,
+/// so `HostState` implements the host API once.
+mod async_bindings {
+    wasmtime::component::bindgen!({
+        world: "mod-guest",
+        path: "wit/issun.wit",
+        async: {
+            only_imports: [],
+        },
+        with: {
+            "issun:modapi/api@0.3.0": crate::issun::modapi::api,
+        },
+    });
+}
+
+/// Custom section holding a MOD's `mod.toml`, read by `peek_metadata`
+pub const METADATA_SECTION: &str = "issun-mod";
+
+/// Version of the MOD API in `wit/issun.wit`
+pub const API_VERSION: ApiVersion = ApiVersion::new(0, 3);
+
+/// Oldest MOD API version that still loads
+pub const MIN_API_VERSION: ApiVersion = ApiVersion::new(0, 1);
+
+/// Interfaces of the MOD API are imported as `issun:modapi/<name>[@version]`
+const API_PACKAGE: &str = "issun:modapi/";
+
+/// Import names of the host API for MODs built against earlier versions:
+/// unversioned (0.1) and 0.2, which lacks `random-range` and `random-int`
+const LEGACY_APIS: [&str; 2] = ["issun:modapi/api", "issun:modapi/api@0.2.0"];
+
+/// MOD API version (major.minor; patch releases are compatible)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ApiVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl ApiVersion {
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    /// Version of an interface import name such as `issun:modapi/api@0.2.0`
+    ///
+    /// `None` for imports outside the MOD API. Unversioned names are 0.1.
+    pub fn from_import(name: &str) -> Option<Self> {
+        let interface = name.strip_prefix(API_PACKAGE)?;
+        let Some((_, version)) = interface.split_once('@') else {
+            return Some(Self::new(0, 1));
+        };
+        let mut parts = version.split(['.', '-', '+']);
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        Some(Self::new(major, minor))
+    }
+
+    /// Whether this host can load MODs built for `self`
+    pub fn is_supported(self) -> bool {
+        (MIN_API_VERSION..=API_VERSION).contains(&self)
+    }
+}
+
+impl std::fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Interval at which the engine epoch advances when a timeout is configured
+const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+/// Execution limits for Wasm MODs
+///
+/// Budgets apply per guest call. `None` disables the corresponding limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmLoaderConfig {
+    /// Fuel (roughly, Wasm instructions) available to each call
+    pub fuel_per_call: Option<u64>,
+    /// Wall-clock time each call may take, in milliseconds
+    pub epoch_timeout_ms: Option<u64>,
+    /// Maximum linear memory of a MOD instance, in bytes
+    pub max_memory_bytes: usize,
+}
+
+impl Default for WasmLoaderConfig {
+    fn default() -> Self {
+        Self {
+            fuel_per_call: Some(100_000_000),
+            epoch_timeout_ms: None,
+            max_memory_bytes: 64 * 1024 * 1024,
+        }
+    }
+}
+
+impl WasmLoaderConfig {
+    /// Epoch ticks each call may take, if a timeout is configured
+    fn epoch_ticks(&self) -> Option<u64> {
+        self.epoch_timeout_ms
+            .map(|timeout_ms| timeout_ms.div_ceil(EPOCH_TICK.as_millis() as u64).max(1))
+    }
+
+    /// Refill the budgets of `store` before calling into the guest
+    fn arm(&self, store: &mut Store<HostState>) -> ModResult<()> {
+        if let Some(fuel) = self.fuel_per_call {
+            store
+                .set_fuel(fuel)
+                .map_err(|e| ModError::ExecutionFailed(format!("Failed to set fuel: {}", e)))?;
+        }
+        if store.data().epoch.yielding {
+            // Yield at every tick; the deadline callback counts the budget down
+            store.data_mut().epoch.ticks_left = self.epoch_ticks();
+            store.set_epoch_deadline(1);
+        } else if let Some(ticks) = self.epoch_ticks() {
+            store.set_epoch_deadline(ticks);
+        }
+        Ok(())
+    }
+
+    /// Error for a guest call that ran out of budget, if that is what failed
+    fn limit_error(&self, mod_id: &str, error: &anyhow::Error) -> Option<ModError> {
+        let limit = match error.downcast_ref::<Trap>()? {
+            Trap::OutOfFuel => "fuel budget".to_string(),
+            Trap::Interrupt => format!("time budget ({} ms)", self.epoch_timeout_ms?),
+            _ => return None,
+        };
+        Some(ModError::ExecutionFailed(format!(
+            "MOD '{}' exceeded {}",
+            mod_id, limit
+        )))
+    }
+
+    /// Map a failed guest call, reporting budget exhaustion as such
+    fn call_error(&self, mod_id: &str, what: &str, error: anyhow::Error) -> ModError {
+        self.limit_error(mod_id, &error)
+            .unwrap_or_else(|| ModError::ExecutionFailed(format!("{} failed: {}", what, error)))
+    }
+}
+
+/// Counters of the compilation cache, see [`WasmLoader::with_cache_dir`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Components loaded from the cache without compiling
+    pub hits: u64,
+    /// Components compiled and written to the cache
+    pub misses: u64,
+    /// Unreadable cache entries that were deleted and compiled again
+    pub corrupt: u64,
+}
+
+/// A cached artifact as read from disk
+enum CacheEntry {
+    Hit(Component),
+    Missing,
+    Corrupt,
+}
+
+/// Hash of `bytes`, used for cache keys and entry checksums
+fn checksum(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Advances the engine epoch until dropped, driving epoch timeouts
+struct EpochTicker {
+    stop: Arc<AtomicBool>,
+}
+
+impl EpochTicker {
+    fn start(engine: Engine) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = stop.clone();
+        std::thread::spawn(move || {
+            while !stopped.load(Ordering::Relaxed) {
+                std::thread::sleep(EPOCH_TICK);
+                engine.increment_epoch();
+            }
+        });
+        Self { stop }
+    }
+}
+
+impl Drop for EpochTicker {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Epoch handling of one store
+#[derive(Debug, Default)]
+struct EpochYield {
+    // Whether the store yields to the executor at every epoch tick
+    yielding: bool,
+    // Ticks the current call may still take, if it has a timeout
+    ticks_left: Option<u64>,
+}
+
+/// Epoch deadline callback of async stores: yield every tick, and interrupt
+/// the call once its time budget is spent
+fn yield_or_interrupt(mut store: StoreContextMut<'_, HostState>) -> anyhow::Result<UpdateDeadline> {
+    match &mut store.data_mut().epoch.ticks_left {
+        Some(0) => Err(Trap::Interrupt.into()),
+        Some(left) => {
+            *left -= 1;
+            Ok(UpdateDeadline::Yield(1))
+        }
+        None => Ok(UpdateDeadline::Yield(1)),
+    }
+}
+
+/// Runtime for synchronous calls into an async engine made outside tokio
+fn blocking_runtime() -> &'static tokio::runtime::Runtime {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to build the WasmLoader runtime")
+    })
+}
+
+/// Drive a guest call to completion from synchronous code
+///
+/// Calls into a sync engine never suspend. Calls into an async engine yield
+/// at epoch ticks and need an executor: on a multi-threaded tokio runtime
+/// the current worker blocks in place, handing its other tasks to the rest
+/// of the pool; anywhere else a helper thread runs the call.
+fn complete<F>(async_mode: bool, future: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    if !async_mode {
+        let mut future = pin!(future);
+        match future
+            .as_mut()
+            .poll(&mut Context::from_waker(Waker::noop()))
+        {
+            Poll::Ready(output) => return output,
+            Poll::Pending => unreachable!("calls into a sync engine never suspend"),
+        }
+    }
+
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread {
+            return tokio::task::block_in_place(|| handle.block_on(future));
+        }
+    }
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| blocking_runtime().block_on(future))
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    })
+}
+
 /// Host state for Wasm execution
 pub struct HostState {
-    wasi: WasiCtx,
-    // Store for host-side state that guest can access
-    log_buffer: Vec<String>,
+    mod_id: String,
+    // WASI state is `Send` but not `Sync`; the mutex makes the store `Sync`,
+    // as `ModLoader` requires, and is only reached through `get_mut`
+    wasi: Mutex<WasiCtx>,
+    // Memory limit of the instance
+    limits: StoreLimits,
+    // WASI resources (streams, pollables, descriptors) handed to the guest
+    table: Mutex<ResourceTable>,
+    // Lines queued by log, log-warn and log-error; the MOD id is set when drained
+    logs: Vec<ModLogEntry>,
+    // Also print log lines (headless use)
+    stdout_logging: bool,
+    // Strings queued by register-strings, as (language, key -> text)
+    strings: Vec<(String, HashMap<String, String>)>,
+    // Plugin commands queued by enable/disable-plugin and set-plugin-param
+    commands: Vec<PluginControl>,
+    // Events queued by publish-event, as (event_type, data)
+    events: Vec<(String, serde_json::Value)>,
+    // Event types passed to subscribe-event, in subscription order
+    subscriptions: Vec<String>,
+    // Epoch yielding and time budget of async stores
+    epoch: EpochYield,
+    // The MOD's random stream under the master seed; `None` draws from `rand`
+    rng: Option<ModRng>,
+    // Checked by plugin control and publish-event
+    permissions: ModPermissions,
+    // Calls refused by `permissions`
+    denials: Vec<ModPermissionDenied>,
+}
+
+impl HostState {
+    fn push_log(&mut self, level: ModLogLevel, message: String) {
+        if self.stdout_logging {
+            println!("[WASM MOD] [{}] {}", level, message);
+        }
+        self.logs.push(ModLogEntry::new(None, level, message));
+    }
+
+    /// Queue `control` if the MOD's permissions cover its plugin
+    fn push_control(&mut self, control: PluginControl) {
+        match self.permissions.check_control(&self.mod_id, &control) {
+            Ok(()) => self.commands.push(control),
+            Err(denied) => self.denials.push(denied),
+        }
+    }
+
+    /// Draw from the MOD's stream, or from a throwaway one seeded by `rand`
+    fn draw<T>(&mut self, f: impl FnOnce(&mut ModRng) -> T) -> T {
+        match &mut self.rng {
+            Some(rng) => f(rng),
+            None => f(&mut ModRng::from_seed(rand::random())),
+        }
+    }
 }
 
 impl WasiView for HostState {
     fn ctx(&mut self) -> &mut WasiCtx {
-        &mut self.wasi
+        self.wasi.get_mut().unwrap_or_else(PoisonError::into_inner)
     }
 
-    fn table(&mut self) -> &mut wasmtime::component::ResourceTable {
-        todo!("Resource table not yet implemented")
+    fn table(&mut self) -> &mut ResourceTable {
+        self.table.get_mut().unwrap_or_else(PoisonError::into_inner)
     }
 }
 
+/// Adjusts the WASI context of each instantiated MOD
+type WasiConfigurator = Arc<dyn Fn(&mut WasiCtxBuilder) -> anyhow::Result<()> + Send + Sync>;
+
 /// WebAssembly-based MOD loader
 ///
 /// Uses Wasmtime and the Component Model to run sandboxed Wasm modules.
@@ -61,30 +485,204 @@ pub struct WasmLoader {
     engine: Engine,
     linker: Linker<HostState>,
     instances: HashMap<String, LoadedWasmMod>,
+    wasi_configurators: Vec<WasiConfigurator>,
+    dispatch_order: Vec<String>, // mod ids, set by ModLoadSystem
+    config: WasmLoaderConfig,
+    _ticker: Option<Arc<EpochTicker>>, // shared by clones, which share the engine
+    cache_dir: Option<PathBuf>,
+    cache_stats: CacheStats,
+    async_mode: bool,
+    stdout_logging: bool,
+    master_seed: Option<MasterSeed>,
+    // Stream positions of MODs not loaded (imported, or kept across unload)
+    rng_positions: HashMap<String, u64>,
+    permission_policy: ModPermissionPolicy,
+    errors: Vec<ModRuntimeErrorEvent>, // failed on_event calls, drained by ModEventSystem
 }
 
 struct LoadedWasmMod {
     store: Store<HostState>,
-    instance: ModGuest,
+    instance: Guest,
+    // Compiled component, kept so clones can instantiate it again
+    component: Component,
+    grant: Grant,
+}
+
+/// Permissions of a MOD and the directory its filesystem grants start from
+#[derive(Clone, Default)]
+struct Grant {
+    permissions: ModPermissions,
+    dir: PathBuf,
+}
+
+/// Exports of one MOD instance, sync or async like the engine
+enum Guest {
+    Sync(ModGuest),
+    Async(async_bindings::ModGuest),
+}
+
+impl Guest {
+    async fn instantiate(
+        store: &mut Store<HostState>,
+        component: &Component,
+        linker: &Linker<HostState>,
+    ) -> anyhow::Result<Self> {
+        if store.data().epoch.yielding {
+            async_bindings::ModGuest::instantiate_async(store, component, linker)
+                .await
+                .map(Self::Async)
+        } else {
+            ModGuest::instantiate(store, component, linker).map(Self::Sync)
+        }
+    }
+
+    async fn get_metadata(&self, store: &mut Store<HostState>) -> anyhow::Result<ModMetadata> {
+        let (name, version, author, description) = match self {
+            Self::Sync(guest) => {
+                let metadata = guest.call_get_metadata(store)?;
+                (
+                    metadata.name,
+                    metadata.version,
+                    metadata.author,
+                    metadata.description,
+                )
+            }
+            Self::Async(guest) => {
+                let metadata = guest.call_get_metadata(store).await?;
+                (
+                    metadata.name,
+                    metadata.version,
+                    metadata.author,
+                    metadata.description,
+                )
+            }
+        };
+        Ok(ModMetadata {
+            name,
+            version,
+            author,
+            description,
+            dependencies: Vec::new(),
+            priority: 0,
+            after: Vec::new(),
+        })
+    }
+
+    async fn on_init(&self, store: &mut Store<HostState>) -> anyhow::Result<()> {
+        match self {
+            Self::Sync(guest) => guest.call_on_init(store),
+            Self::Async(guest) => guest.call_on_init(store).await,
+        }
+    }
+
+    async fn on_shutdown(&self, store: &mut Store<HostState>) -> anyhow::Result<()> {
+        match self {
+            Self::Sync(guest) => guest.call_on_shutdown(store),
+            Self::Async(guest) => guest.call_on_shutdown(store).await,
+        }
+    }
+
+    async fn on_control_plugin(
+        &self,
+        store: &mut Store<HostState>,
+        plugin_name: &str,
+        action: &str,
+    ) -> anyhow::Result<()> {
+        match self {
+            Self::Sync(guest) => guest.call_on_control_plugin(store, plugin_name, action),
+            Self::Async(guest) => {
+                guest
+                    .call_on_control_plugin(store, plugin_name, action)
+                    .await
+            }
+        }
+    }
+
+    async fn on_event(
+        &self,
+        store: &mut Store<HostState>,
+        event_type: &str,
+        payload_json: &str,
+    ) -> anyhow::Result<()> {
+        match self {
+            Self::Sync(guest) => guest.call_on_event(store, event_type, payload_json),
+            Self::Async(guest) => guest.call_on_event(store, event_type, payload_json).await,
+        }
+    }
+
+    async fn call_custom(
+        &self,
+        store: &mut Store<HostState>,
+        fn_name: &str,
+        args_json: &str,
+    ) -> anyhow::Result<String> {
+        match self {
+            Self::Sync(guest) => guest.call_call_custom(store, fn_name, args_json),
+            Self::Async(guest) => guest.call_call_custom(store, fn_name, args_json).await,
+        }
+    }
 }
 
 impl WasmLoader {
-    /// Create a new WasmLoader with WASI support
+    /// Create a new WasmLoader with WASI support and default limits
     pub fn new() -> ModResult<Self> {
+        Self::with_config(WasmLoaderConfig::default())
+    }
+
+    /// Create a new WasmLoader with WASI support and the given limits
+    ///
+    /// Limits are fixed for the lifetime of the loader, as fuel metering and
+    /// epoch interruption are properties of the engine.
+    pub fn with_config(limits: WasmLoaderConfig) -> ModResult<Self> {
+        Self::build(limits, false)
+    }
+
+    /// Create an async WasmLoader with WASI support and default limits
+    ///
+    /// Guest calls made through `load_async` and `call_function_async` yield
+    /// to the executor every epoch tick (10 ms) while the guest runs. The
+    /// synchronous `ModLoader` methods still work: on a multi-threaded tokio
+    /// runtime they block in place, elsewhere they run the call on a helper
+    /// thread.
+    pub fn new_async() -> ModResult<Self> {
+        Self::with_config_async(WasmLoaderConfig::default())
+    }
+
+    /// Create an async WasmLoader with WASI support and the given limits
+    pub fn with_config_async(limits: WasmLoaderConfig) -> ModResult<Self> {
+        Self::build(limits, true)
+    }
+
+    /// Whether this loader was created with [`WasmLoader::new_async`]
+    pub fn is_async(&self) -> bool {
+        self.async_mode
+    }
+
+    fn build(limits: WasmLoaderConfig, async_mode: bool) -> ModResult<Self> {
+        // Async engines use epochs to yield, with or without a timeout
+        let epochs = async_mode || limits.epoch_timeout_ms.is_some();
+
         // Configure Wasmtime engine
         let mut config = Config::new();
         config.wasm_component_model(true);
-        config.async_support(false);
+        config.async_support(async_mode);
+        config.consume_fuel(limits.fuel_per_call.is_some());
+        config.epoch_interruption(epochs);
 
         let engine = Engine::new(&config)
             .map_err(|e| ModError::LoadFailed(format!("Engine creation failed: {}", e)))?;
+        let ticker = epochs.then(|| Arc::new(EpochTicker::start(engine.clone())));
 
         // Create linker for host functions
         let mut linker = Linker::new(&engine);
 
         // Add WASI support
-        wasmtime_wasi::add_to_linker_sync(&mut linker)
-            .map_err(|e| ModError::LoadFailed(format!("WASI linker failed: {}", e)))?;
+        let wasi = if async_mode {
+            wasmtime_wasi::add_to_linker_async(&mut linker)
+        } else {
+            wasmtime_wasi::add_to_linker_sync(&mut linker)
+        };
+        wasi.map_err(|e| ModError::LoadFailed(format!("WASI linker failed: {}", e)))?;
 
         // Link the WIT-defined host functions
         Self::link_host_functions(&mut linker)?;
@@ -93,85 +691,300 @@ impl WasmLoader {
             engine,
             linker,
             instances: HashMap::new(),
+            wasi_configurators: Vec::new(),
+            dispatch_order: Vec::new(),
+            config: limits,
+            _ticker: ticker,
+            cache_dir: None,
+            cache_stats: CacheStats::default(),
+            async_mode,
+            stdout_logging: false,
+            master_seed: None,
+            rng_positions: HashMap::new(),
+            permission_policy: ModPermissionPolicy::default(),
+            errors: Vec::new(),
         })
     }
 
+    /// Execution limits of this loader
+    pub fn config(&self) -> WasmLoaderConfig {
+        self.config
+    }
+
+    /// Configure the WASI context of every MOD loaded afterwards
+    ///
+    /// `configure` runs on top of the defaults (inherited stdio) each time a
+    /// component is instantiated, so it can grant preopened directories,
+    /// environment variables or arguments. Calls accumulate in order; an
+    /// error fails the load.
+    pub fn with_wasi(
+        mut self,
+        configure: impl Fn(&mut WasiCtxBuilder) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) -> Self {
+        self.wasi_configurators.push(Arc::new(configure));
+        self
+    }
+
+    /// Cache compiled components in `dir`
+    ///
+    /// Entries are named after a hash of the component bytes and of the
+    /// engine's compatibility hash (Wasmtime version and settings), so a
+    /// changed MOD or engine compiles again. Entries carry a checksum; a
+    /// truncated or corrupt entry is deleted and the MOD recompiled. The
+    /// directory is created on first write and must only be writable by
+    /// trusted users, as cached artifacts are native code.
+    pub fn with_cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Also print MOD log lines to stdout
+    ///
+    /// Off by default: log lines are only queued for `drain_logs()`, which
+    /// keeps terminal UIs intact. Enable for headless runs and tools.
+    /// Applies to MODs loaded afterwards.
+    pub fn with_stdout_logging(mut self, enabled: bool) -> Self {
+        self.stdout_logging = enabled;
+        self
+    }
+
+    /// Hits, misses and corrupt entries of the compilation cache so far
+    pub fn cache_stats(&self) -> CacheStats {
+        self.cache_stats
+    }
+
+    /// Compile the component at `path`, through the cache when configured
+    fn compile(&mut self, path: &Path) -> ModResult<Component> {
+        let Some(cache_dir) = self.cache_dir.clone() else {
+            return Component::from_file(&self.engine, path)
+                .map_err(|e| ModError::LoadFailed(format!("Failed to load component: {}", e)));
+        };
+
+        let bytes = std::fs::read(path).map_err(|e| {
+            ModError::LoadFailed(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        let entry = cache_dir.join(self.cache_key(&bytes));
+
+        match self.read_cache_entry(&entry) {
+            CacheEntry::Hit(component) => {
+                self.cache_stats.hits += 1;
+                return Ok(component);
+            }
+            CacheEntry::Corrupt => {
+                self.cache_stats.corrupt += 1;
+                let _ = std::fs::remove_file(&entry);
+            }
+            CacheEntry::Missing => {}
+        }
+
+        let component = Component::new(&self.engine, &bytes)
+            .map_err(|e| ModError::LoadFailed(format!("Failed to load component: {}", e)))?;
+        self.cache_stats.misses += 1;
+        if let Err(e) = Self::write_cache_entry(&cache_dir, &entry, &component) {
+            eprintln!("[WasmLoader] Failed to cache {}: {}", path.display(), e);
+        }
+        Ok(component)
+    }
+
+    /// File name of the cache entry for a component's bytes
+    fn cache_key(&self, bytes: &[u8]) -> String {
+        let mut engine = DefaultHasher::new();
+        self.engine
+            .precompile_compatibility_hash()
+            .hash(&mut engine);
+        format!("{:016x}-{:016x}.cwasm", checksum(bytes), engine.finish())
+    }
+
+    /// Read a cache entry: the serialized component followed by its checksum
+    fn read_cache_entry(&self, entry: &Path) -> CacheEntry {
+        let data = match std::fs::read(entry) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return CacheEntry::Missing,
+            Err(_) => return CacheEntry::Corrupt,
+        };
+        let Some(split) = data.len().checked_sub(8) else {
+            return CacheEntry::Corrupt;
+        };
+        let (artifact, trailer) = data.split_at(split);
+        if trailer != checksum(artifact).to_le_bytes() {
+            return CacheEntry::Corrupt;
+        }
+
+        // SAFETY: the checksum matches, so `artifact` is what
+        // `Component::serialize` wrote for an engine with the same
+        // compatibility hash; Wasmtime still validates the header.
+        match unsafe { Component::deserialize(&self.engine, artifact) } {
+            Ok(component) => CacheEntry::Hit(component),
+            Err(_) => CacheEntry::Corrupt,
+        }
+    }
+
+    /// Write a cache entry atomically, so readers never see a partial file
+    fn write_cache_entry(
+        cache_dir: &Path,
+        entry: &Path,
+        component: &Component,
+    ) -> anyhow::Result<()> {
+        let mut data = component.serialize()?;
+        let sum = checksum(&data);
+        data.extend_from_slice(&sum.to_le_bytes());
+
+        std::fs::create_dir_all(cache_dir)?;
+        let partial = entry.with_extension(format!("tmp{}", std::process::id()));
+        std::fs::write(&partial, &data)?;
+        std::fs::rename(&partial, entry)?;
+        Ok(())
+    }
+
     /// Link host API functions defined in WIT
     fn link_host_functions(linker: &mut Linker<HostState>) -> ModResult<()> {
         // Link the api interface
         crate::issun::modapi::api::add_to_linker(linker, |state: &mut HostState| state)
             .map_err(|e| ModError::LoadFailed(format!("Failed to link API: {}", e)))?;
 
-        Ok(())
-    }
-}
+        // The same functions under the import names of earlier versions
+        for name in LEGACY_APIS {
+            link_legacy_api(linker, name)
+                .map_err(|e| ModError::LoadFailed(format!("Failed to link {}: {}", name, e)))?;
+        }
 
-impl Default for WasmLoader {
-    fn default() -> Self {
-        Self::new().expect("Failed to create WasmLoader")
+        Ok(())
     }
-}
 
-// Implement host API functions
-impl crate::issun::modapi::api::Host for HostState {
-    fn log(&mut self, message: String) {
-        println!("[WASM MOD] {}", message);
-        self.log_buffer.push(message);
+    /// MOD API version a component was built against
+    ///
+    /// Read from the names of its `issun:modapi` imports; `None` if it
+    /// imports none of them.
+    pub fn api_version(&self, component: &Component) -> Option<ApiVersion> {
+        component
+            .component_type()
+            .imports(&self.engine)
+            .filter_map(|(name, _)| ApiVersion::from_import(name))
+            .max()
     }
 
-    fn enable_plugin(&mut self, name: String) {
-        println!("[WASM MOD API] Enable plugin: {}", name);
+    /// Fail with `ModError::InvalidFormat` unless the component's API
+    /// version is supported
+    fn check_api_version(&self, mod_id: &str, component: &Component) -> ModResult<()> {
+        match self.api_version(component) {
+            Some(version) if !version.is_supported() => Err(ModError::InvalidFormat(format!(
+                "MOD '{}' built for api {}, host supports {}–{}",
+                mod_id, version, MIN_API_VERSION, API_VERSION
+            ))),
+            _ => Ok(()),
+        }
     }
 
-    fn disable_plugin(&mut self, name: String) {
-        println!("[WASM MOD API] Disable plugin: {}", name);
-    }
+    /// Instantiate a compiled component in a fresh store and run its `on_init`
+    async fn instantiate(
+        &self,
+        mod_id: &str,
+        component: Component,
+        grant: Grant,
+    ) -> ModResult<(LoadedWasmMod, ModMetadata)> {
+        let (mut store, instance, metadata) =
+            self.instantiate_guest(mod_id, &component, &grant).await?;
 
-    fn set_plugin_param(&mut self, plugin: String, key: String, value: String) {
-        println!("[WASM MOD API] Set {}.{} = {}", plugin, key, value);
-    }
+        // Call on_init
+        self.config.arm(&mut store)?;
+        instance
+            .on_init(&mut store)
+            .await
+            .map_err(|e| self.config.call_error(mod_id, "on_init", e))?;
 
-    fn random(&mut self) -> f32 {
-        rand::random()
+        Ok((
+            LoadedWasmMod {
+                store,
+                instance,
+                component,
+                grant,
+            },
+            metadata,
+        ))
     }
-}
 
-impl ModLoader for WasmLoader {
-    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
-        // Load Wasm component from file
-        let component = Component::from_file(&self.engine, path)
-            .map_err(|e| ModError::LoadFailed(format!("Failed to load component: {}", e)))?;
+    /// Instantiate a compiled component in a fresh store and read its metadata
+    async fn instantiate_guest(
+        &self,
+        mod_id: &str,
+        component: &Component,
+        grant: &Grant,
+    ) -> ModResult<(Store<HostState>, Guest, ModMetadata)> {
+        self.check_api_version(mod_id, component)?;
 
         // Create WASI context
-        let wasi = WasiCtxBuilder::new().inherit_stdio().build();
+        let mut builder = WasiCtxBuilder::new();
+        builder.inherit_stdio();
+        for configure in &self.wasi_configurators {
+            configure(&mut builder)
+                .map_err(|e| ModError::LoadFailed(format!("WASI configuration failed: {}", e)))?;
+        }
+        for (host_dir, guest_dir) in grant.permissions.filesystem_dirs(&grant.dir) {
+            builder
+                .preopened_dir(&host_dir, &guest_dir, DirPerms::all(), FilePerms::all())
+                .map_err(|e| {
+                    ModError::LoadFailed(format!(
+                        "Failed to grant {} to MOD '{}': {}",
+                        host_dir.display(),
+                        mod_id,
+                        e
+                    ))
+                })?;
+        }
 
         let host_state = HostState {
-            wasi,
-            log_buffer: Vec::new(),
+            mod_id: mod_id.to_string(),
+            wasi: Mutex::new(builder.build()),
+            limits: StoreLimitsBuilder::new()
+                .memory_size(self.config.max_memory_bytes)
+                .build(),
+            table: Mutex::new(ResourceTable::new()),
+            logs: Vec::new(),
+            stdout_logging: self.stdout_logging,
+            strings: Vec::new(),
+            commands: Vec::new(),
+            events: Vec::new(),
+            subscriptions: Vec::new(),
+            epoch: EpochYield {
+                yielding: self.async_mode,
+                ticks_left: None,
+            },
+            rng: self.stream(mod_id),
+            permissions: grant.permissions.clone(),
+            denials: Vec::new(),
         };
 
         let mut store = Store::new(&self.engine, host_state);
+        store.limiter(|state| &mut state.limits);
+        if self.async_mode {
+            store.epoch_deadline_callback(yield_or_interrupt);
+        }
 
         // Instantiate the component
-        let instance = ModGuest::instantiate(&mut store, &component, &self.linker)
-            .map_err(|e| ModError::LoadFailed(format!("Instantiation failed: {}", e)))?;
+        self.config.arm(&mut store)?;
+        let instance = Guest::instantiate(&mut store, component, &self.linker)
+            .await
+            .map_err(|e| {
+                self.config
+                    .limit_error(mod_id, &e)
+                    .unwrap_or_else(|| ModError::LoadFailed(format!("Instantiation failed: {}", e)))
+            })?;
 
         // Get metadata
-        let metadata_wasm = instance
-            .call_get_metadata(&mut store)
-            .map_err(|e| ModError::LoadFailed(format!("get_metadata failed: {}", e)))?;
-
-        let metadata = ModMetadata {
-            name: metadata_wasm.name,
-            version: metadata_wasm.version,
-            author: metadata_wasm.author,
-            description: metadata_wasm.description,
-        };
+        self.config.arm(&mut store)?;
+        let metadata = instance.get_metadata(&mut store).await.map_err(|e| {
+            self.config
+                .limit_error(mod_id, &e)
+                .unwrap_or_else(|| ModError::LoadFailed(format!("get_metadata failed: {}", e)))
+        })?;
 
-        // Call on_init
-        instance
-            .call_on_init(&mut store)
-            .map_err(|e| ModError::ExecutionFailed(format!("on_init failed: {}", e)))?;
+        Ok((store, instance, metadata))
+    }
+
+    /// Load the component at `path`; `ModLoader::load` and `load_async`
+    async fn load_mod(&mut self, path: &Path) -> ModResult<ModHandle> {
+        // Load Wasm component from file (or the compilation cache)
+        let component = self.compile(path)?;
 
         // Generate ID
         let id = path
@@ -180,9 +993,14 @@ impl ModLoader for WasmLoader {
             .ok_or_else(|| ModError::InvalidFormat("Invalid filename".to_string()))?
             .to_string();
 
+        let grant = Grant {
+            permissions: self.permission_policy.permissions_for(path)?,
+            dir: mod_dir(path),
+        };
+        let (loaded, metadata) = self.instantiate(&id, component, grant).await?;
+
         // Store instance
-        self.instances
-            .insert(id.clone(), LoadedWasmMod { store, instance });
+        self.instances.insert(id.clone(), loaded);
 
         Ok(ModHandle {
             id,
@@ -191,15 +1009,69 @@ impl ModLoader for WasmLoader {
         })
     }
 
-    fn unload(&mut self, handle: &ModHandle) -> ModResult<()> {
+    /// Metadata of the MOD at `path` without running its `on_init`
+    ///
+    /// See the crate docs for where it is looked up.
+    async fn peek_mod(&mut self, path: &Path) -> ModResult<ModMetadata> {
+        if path.is_dir() {
+            return ModManifest::from_dir(path)?.metadata();
+        }
+
+        let sidecar = path.with_extension("toml");
+        if sidecar.is_file() {
+            let content = std::fs::read_to_string(&sidecar).map_err(|e| {
+                ModError::LoadFailed(format!("Failed to read {}: {}", sidecar.display(), e))
+            })?;
+            return ModManifest::parse(&content)?.metadata();
+        }
+
+        let bytes = std::fs::read(path).map_err(|e| {
+            ModError::LoadFailed(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        if let Some(section) = custom_section(&bytes, METADATA_SECTION) {
+            let content = std::str::from_utf8(section).map_err(|_| {
+                ModError::InvalidFormat(format!("{} section is not UTF-8", METADATA_SECTION))
+            })?;
+            return ModManifest::parse(content)?.metadata();
+        }
+
+        // Instantiation still runs the component's start functions
+        let id = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| ModError::InvalidFormat("Invalid filename".to_string()))?
+            .to_string();
+        let component = self.compile(path)?;
+        let (_store, _instance, metadata) = self
+            .instantiate_guest(&id, &component, &Grant::default())
+            .await?;
+        Ok(metadata)
+    }
+
+    /// Random stream a MOD starts from: its kept position, else the start
+    /// of (master seed, "mod", MOD id)
+    fn stream(&self, mod_id: &str) -> Option<ModRng> {
+        let master = self.master_seed?;
+        Some(match self.rng_positions.get(mod_id) {
+            Some(&position) => ModRng::from_position(position),
+            None => ModRng::for_mod(master, mod_id),
+        })
+    }
+
+    async fn unload_mod(&mut self, handle: &ModHandle) {
         if let Some(mut loaded) = self.instances.remove(&handle.id) {
+            // Loading the MOD again continues its stream
+            if let Some(rng) = loaded.store.data().rng {
+                self.rng_positions.insert(handle.id.clone(), rng.position());
+            }
             // Call on_shutdown
-            let _ = loaded.instance.call_on_shutdown(&mut loaded.store);
+            if self.config.arm(&mut loaded.store).is_ok() {
+                let _ = loaded.instance.on_shutdown(&mut loaded.store).await;
+            }
         }
-        Ok(())
     }
 
-    fn control_plugin(&mut self, handle: &ModHandle, control: &PluginControl) -> ModResult<()> {
+    async fn control_mod(&mut self, handle: &ModHandle, control: &PluginControl) -> ModResult<()> {
         let loaded = self
             .instances
             .get_mut(&handle.id)
@@ -216,15 +1088,18 @@ impl ModLoader for WasmLoader {
             }
         };
 
+        self.config.arm(&mut loaded.store)?;
         loaded
             .instance
-            .call_on_control_plugin(&mut loaded.store, &control.plugin_name, &action_str)
-            .map_err(|e| ModError::ExecutionFailed(format!("on_control_plugin failed: {}", e)))?;
+            .on_control_plugin(&mut loaded.store, &control.plugin_name, &action_str)
+            .await
+            .map_err(|e| self.config.call_error(&handle.id, "on_control_plugin", e))?;
 
         Ok(())
     }
 
-    fn call_function(
+    /// Call `fn_name` through `call-custom`; `call_function` and its async variant
+    async fn call_mod_function(
         &mut self,
         handle: &ModHandle,
         fn_name: &str,
@@ -235,28 +1110,519 @@ impl ModLoader for WasmLoader {
             .get_mut(&handle.id)
             .ok_or_else(|| ModError::NotFound(format!("Wasm module '{}' not loaded", handle.id)))?;
 
-        // Convert args to strings (simplified)
-        let args_str: Vec<String> = args.into_iter().map(|v| v.to_string()).collect();
-
         // Call custom function
-        let result_str = loaded
+        self.config.arm(&mut loaded.store)?;
+        let result_json = loaded
             .instance
-            .call_call_custom(&mut loaded.store, fn_name, &args_str)
-            .map_err(|e| ModError::FunctionNotFound(format!("call_custom failed: {}", e)))?;
+            .call_custom(&mut loaded.store, fn_name, &encode_args(args))
+            .await
+            .map_err(|e| {
+                self.config.limit_error(&handle.id, &e).unwrap_or_else(|| {
+                    ModError::FunctionNotFound(format!("call_custom failed: {}", e))
+                })
+            })?;
 
-        // Parse result as JSON
-        serde_json::from_str(&result_str)
-            .map_err(|e| ModError::ExecutionFailed(format!("Invalid JSON result: {}", e)))
+        decode_result(&result_json)
     }
 
-    fn clone_box(&self) -> Box<dyn ModLoader> {
-        Box::new(Self::new().expect("Failed to clone WasmLoader"))
-    }
-}
+    async fn dispatch_to_mods(
+        &mut self,
+        event_type: &str,
+        event_data: &serde_json::Value,
+    ) -> usize {
+        let payload = event_data.to_string();
+        let config = self.config;
+        let mut count = 0;
+        let mut errors = Vec::new();
+
+        for (mod_id, loaded) in self.instances_in_dispatch_order() {
+            let subscribed = loaded
+                .store
+                .data()
+                .subscriptions
+                .iter()
+                .any(|subscription| subscription == event_type);
+            if !subscribed {
+                continue;
+            }
+
+            let delivered = match config.arm(&mut loaded.store) {
+                Ok(()) => loaded
+                    .instance
+                    .on_event(&mut loaded.store, event_type, &payload)
+                    .await
+                    .map_err(|e| config.call_error(mod_id, "on_event", e)),
+                Err(e) => Err(e),
+            };
+            match delivered {
+                Ok(()) => count += 1,
+                Err(e) => errors.push(ModRuntimeErrorEvent::new(
+                    mod_id,
+                    format!("event '{}'", event_type),
+                    e,
+                )),
+            }
+        }
+
+        self.errors.append(&mut errors);
+        count
+    }
+
+    /// Loader sharing the engine, with every loaded component instantiated again
+    async fn clone_loader(&self) -> Self {
+        let mut instances = HashMap::new();
+        for (id, loaded) in &self.instances {
+            match self
+                .instantiate(id, loaded.component.clone(), loaded.grant.clone())
+                .await
+            {
+                Ok((mut clone, _)) => {
+                    clone.store.data_mut().rng = loaded.store.data().rng;
+                    instances.insert(id.clone(), clone);
+                }
+                Err(e) => eprintln!("[WasmLoader] Failed to clone MOD '{}': {}", id, e),
+            }
+        }
+
+        Self {
+            engine: self.engine.clone(),
+            linker: self.linker.clone(),
+            instances,
+            wasi_configurators: self.wasi_configurators.clone(),
+            dispatch_order: self.dispatch_order.clone(),
+            config: self.config,
+            _ticker: self._ticker.clone(),
+            cache_dir: self.cache_dir.clone(),
+            cache_stats: CacheStats::default(),
+            async_mode: self.async_mode,
+            stdout_logging: self.stdout_logging,
+            master_seed: self.master_seed,
+            rng_positions: self.rng_positions.clone(),
+            permission_policy: self.permission_policy.clone(),
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl Default for WasmLoader {
+    fn default() -> Self {
+        Self::new().expect("Failed to create WasmLoader")
+    }
+}
+
+// Implement host API functions
+impl crate::issun::modapi::api::Host for HostState {
+    fn log(&mut self, message: String) {
+        self.push_log(ModLogLevel::Info, message);
+    }
+
+    fn log_warn(&mut self, message: String) {
+        self.push_log(ModLogLevel::Warn, message);
+    }
+
+    fn log_error(&mut self, message: String) {
+        self.push_log(ModLogLevel::Error, message);
+    }
+
+    fn enable_plugin(&mut self, name: String) {
+        self.push_control(PluginControl::enable(name));
+    }
+
+    fn disable_plugin(&mut self, name: String) {
+        self.push_control(PluginControl::disable(name));
+    }
+
+    fn set_plugin_param(&mut self, plugin: String, key: String, value: String) {
+        self.push_control(PluginControl::set_param(plugin, key, parse_json(value)));
+    }
+
+    fn publish_event(&mut self, event_type: String, data: String) {
+        match self.permissions.check_publish(&self.mod_id, &event_type) {
+            Ok(()) => self.events.push((event_type, parse_json(data))),
+            Err(denied) => self.denials.push(denied),
+        }
+    }
+
+    fn subscribe_event(&mut self, event_type: String) {
+        if !self.subscriptions.contains(&event_type) {
+            self.subscriptions.push(event_type);
+        }
+    }
+
+    fn random(&mut self) -> f32 {
+        // The top 24 bits, so the result stays below 1.0
+        self.draw(|rng| (rng.next_u64() >> 40) as f32 / (1u32 << 24) as f32)
+    }
+
+    fn random_range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.random()
+    }
+
+    fn random_int(&mut self, min: i64, max: i64) -> i64 {
+        self.draw(|rng| rng.int(min, max))
+    }
+
+    fn register_strings(&mut self, lang: String, strings: Vec<(String, String)>) {
+        self.strings.push((lang, strings.into_iter().collect()));
+    }
+}
+
+/// Define the host API under an import name of [`LEGACY_APIS`]
+///
+/// Later versions only added functions, so the shim forwards to the same
+/// `Host` functions; a MOD simply doesn't import the ones it predates.
+fn link_legacy_api(linker: &mut Linker<HostState>, name: &str) -> anyhow::Result<()> {
+    use crate::issun::modapi::api::Host;
+
+    let mut api = linker.instance(name)?;
+    api.func_wrap(
+        "log",
+        |mut store: StoreContextMut<'_, HostState>, (message,): (String,)| {
+            Host::log(store.data_mut(), message);
+            Ok(())
+        },
+    )?;
+    api.func_wrap(
+        "log-warn",
+        |mut store: StoreContextMut<'_, HostState>, (message,): (String,)| {
+            Host::log_warn(store.data_mut(), message);
+            Ok(())
+        },
+    )?;
+    api.func_wrap(
+        "log-error",
+        |mut store: StoreContextMut<'_, HostState>, (message,): (String,)| {
+            Host::log_error(store.data_mut(), message);
+            Ok(())
+        },
+    )?;
+    api.func_wrap(
+        "enable-plugin",
+        |mut store: StoreContextMut<'_, HostState>, (name,): (String,)| {
+            Host::enable_plugin(store.data_mut(), name);
+            Ok(())
+        },
+    )?;
+    api.func_wrap(
+        "disable-plugin",
+        |mut store: StoreContextMut<'_, HostState>, (name,): (String,)| {
+            Host::disable_plugin(store.data_mut(), name);
+            Ok(())
+        },
+    )?;
+    api.func_wrap(
+        "set-plugin-param",
+        |mut store: StoreContextMut<'_, HostState>,
+         (plugin, key, value): (String, String, String)| {
+            Host::set_plugin_param(store.data_mut(), plugin, key, value);
+            Ok(())
+        },
+    )?;
+    api.func_wrap(
+        "publish-event",
+        |mut store: StoreContextMut<'_, HostState>, (event_type, data): (String, String)| {
+            Host::publish_event(store.data_mut(), event_type, data);
+            Ok(())
+        },
+    )?;
+    api.func_wrap(
+        "subscribe-event",
+        |mut store: StoreContextMut<'_, HostState>, (event_type,): (String,)| {
+            Host::subscribe_event(store.data_mut(), event_type);
+            Ok(())
+        },
+    )?;
+    api.func_wrap(
+        "random",
+        |mut store: StoreContextMut<'_, HostState>, (): ()| Ok((Host::random(store.data_mut()),)),
+    )?;
+    api.func_wrap(
+        "random-range",
+        |mut store: StoreContextMut<'_, HostState>, (min, max): (f32, f32)| {
+            Ok((Host::random_range(store.data_mut(), min, max),))
+        },
+    )?;
+    api.func_wrap(
+        "random-int",
+        |mut store: StoreContextMut<'_, HostState>, (min, max): (i64, i64)| {
+            Ok((Host::random_int(store.data_mut(), min, max),))
+        },
+    )?;
+    api.func_wrap(
+        "register-strings",
+        |mut store: StoreContextMut<'_, HostState>,
+         (lang, strings): (String, Vec<(String, String)>)| {
+            Host::register_strings(store.data_mut(), lang, strings);
+            Ok(())
+        },
+    )?;
+    Ok(())
+}
+
+/// JSON passed as a string by the guest; anything else stays a plain string
+/// Contents of the custom section `name` of a core module or component
+///
+/// Nested modules and components of a component are searched too.
+fn custom_section<'a>(bytes: &'a [u8], name: &str) -> Option<&'a [u8]> {
+    if bytes.get(..4)? != b"\0asm" {
+        return None;
+    }
+    // The layer field after the version is 1 for components
+    let component = bytes.get(6..8)? == [1, 0];
+
+    let mut rest = bytes.get(8..)?;
+    while let Some((&id, after)) = rest.split_first() {
+        let (size, after) = read_leb_u32(after)?;
+        let contents = after.get(..size as usize)?;
+        rest = &after[size as usize..];
+        match id {
+            0 => {
+                let (len, data) = read_leb_u32(contents)?;
+                if data.get(..len as usize)? == name.as_bytes() {
+                    return Some(&data[len as usize..]);
+                }
+            }
+            // Core module and component sections
+            1 | 4 if component => {
+                if let Some(found) = custom_section(contents, name) {
+                    return Some(found);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Unsigned LEB128 value at the start of `bytes` and what follows it
+fn read_leb_u32(bytes: &[u8]) -> Option<(u32, &[u8])> {
+    let mut value = 0u32;
+    for (i, &byte) in bytes.iter().enumerate().take(5) {
+        value |= u32::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, &bytes[i + 1..]));
+        }
+    }
+    None
+}
+
+fn parse_json(value: String) -> serde_json::Value {
+    serde_json::from_str(&value).unwrap_or(serde_json::Value::String(value))
+}
+
+/// `call-custom` arguments: one JSON array, so values keep their types
+fn encode_args(args: Vec<serde_json::Value>) -> String {
+    serde_json::Value::Array(args).to_string()
+}
+
+/// `call-custom` result, which must be a JSON document
+fn decode_result(result_json: &str) -> ModResult<serde_json::Value> {
+    serde_json::from_str(result_json)
+        .map_err(|e| ModError::ExecutionFailed(format!("Invalid JSON result: {}", e)))
+}
+
+impl WasmLoader {
+    /// Loaded MODs in id order, so drained queues are deterministic
+    fn instances_by_id(&mut self) -> Vec<(&String, &mut LoadedWasmMod)> {
+        let mut instances: Vec<_> = self.instances.iter_mut().collect();
+        instances.sort_by(|a, b| a.0.cmp(b.0));
+        instances
+    }
+
+    /// Loaded MODs in dispatch order; MODs missing from it follow by id
+    fn instances_in_dispatch_order(&mut self) -> Vec<(&String, &mut LoadedWasmMod)> {
+        let order = &self.dispatch_order;
+        let mut instances: Vec<_> = self.instances.iter_mut().collect();
+        instances.sort_by_cached_key(|(mod_id, _)| {
+            let position = order
+                .iter()
+                .position(|id| id == *mod_id)
+                .unwrap_or(usize::MAX);
+            (position, (*mod_id).clone())
+        });
+        instances
+    }
+}
+
+#[async_trait]
+impl ModLoader for WasmLoader {
+    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
+        complete(self.async_mode, self.load_mod(path))
+    }
+
+    async fn load_async(&mut self, path: &Path) -> ModResult<ModHandle> {
+        self.load_mod(path).await
+    }
+
+    fn peek_metadata(&mut self, path: &Path) -> ModResult<ModMetadata> {
+        complete(self.async_mode, self.peek_mod(path))
+    }
+
+    fn file_extensions(&self) -> Vec<&'static str> {
+        vec!["wasm", "wat"]
+    }
+
+    fn unload(&mut self, handle: &ModHandle) -> ModResult<()> {
+        complete(self.async_mode, self.unload_mod(handle));
+        Ok(())
+    }
+
+    fn control_plugin(&mut self, handle: &ModHandle, control: &PluginControl) -> ModResult<()> {
+        complete(self.async_mode, self.control_mod(handle, control))
+    }
+
+    fn call_function(
+        &mut self,
+        handle: &ModHandle,
+        fn_name: &str,
+        args: Vec<serde_json::Value>,
+    ) -> ModResult<serde_json::Value> {
+        complete(
+            self.async_mode,
+            self.call_mod_function(handle, fn_name, args),
+        )
+    }
+
+    async fn call_function_async(
+        &mut self,
+        handle: &ModHandle,
+        fn_name: &str,
+        args: Vec<serde_json::Value>,
+    ) -> ModResult<serde_json::Value> {
+        self.call_mod_function(handle, fn_name, args).await
+    }
+
+    fn drain_commands(&mut self) -> Vec<PluginControl> {
+        let mut drained = Vec::new();
+        for (mod_id, loaded) in self.instances_by_id() {
+            drained.extend(
+                loaded
+                    .store
+                    .data_mut()
+                    .commands
+                    .drain(..)
+                    .map(|command| command.with_issuer(mod_id.clone())),
+            );
+        }
+        drained
+    }
+
+    fn drain_events(&mut self) -> Vec<(String, serde_json::Value)> {
+        let mut drained = Vec::new();
+        for (_, loaded) in self.instances_by_id() {
+            drained.append(&mut loaded.store.data_mut().events);
+        }
+        drained
+    }
+
+    /// Lines logged by every MOD, by MOD id and then in logging order
+    fn drain_logs(&mut self) -> Vec<ModLogEntry> {
+        let mut drained = Vec::new();
+        for (mod_id, loaded) in self.instances_by_id() {
+            drained.extend(
+                loaded
+                    .store
+                    .data_mut()
+                    .logs
+                    .drain(..)
+                    .map(|entry| ModLogEntry {
+                        mod_id: Some(mod_id.clone()),
+                        ..entry
+                    }),
+            );
+        }
+        drained
+    }
+
+    fn drain_strings(&mut self) -> Vec<ModStrings> {
+        let mut drained = Vec::new();
+        for (mod_id, loaded) in &mut self.instances {
+            for (language, strings) in loaded.store.data_mut().strings.drain(..) {
+                drained.push(ModStrings {
+                    mod_id: mod_id.clone(),
+                    language,
+                    strings,
+                });
+            }
+        }
+        drained
+    }
+
+    fn set_dispatch_order(&mut self, order: &[String]) {
+        self.dispatch_order = order.to_vec();
+    }
+
+    fn dispatch_event(&mut self, event_type: &str, event_data: &serde_json::Value) -> usize {
+        complete(
+            self.async_mode,
+            self.dispatch_to_mods(event_type, event_data),
+        )
+    }
+
+    /// Every loaded component is instantiated again in the clone (sharing
+    /// the engine), so the clone can call into the same MODs. Wasm stores
+    /// can't be copied: each clone instance starts from a fresh `on_init`.
+    fn clone_box(&self) -> Box<dyn ModLoader> {
+        Box::new(complete(self.async_mode, self.clone_loader()))
+    }
+
+    /// Applies to MODs loaded afterwards
+    fn set_permission_policy(&mut self, policy: ModPermissionPolicy) {
+        self.permission_policy = policy;
+    }
+
+    fn drain_denials(&mut self) -> Vec<ModPermissionDenied> {
+        let mut drained = Vec::new();
+        for (_, loaded) in self.instances_by_id() {
+            drained.append(&mut loaded.store.data_mut().denials);
+        }
+        drained
+    }
+
+    fn drain_errors(&mut self) -> Vec<ModRuntimeErrorEvent> {
+        std::mem::take(&mut self.errors)
+    }
+
+    fn set_master_seed(&mut self, seed: Option<MasterSeed>) {
+        if self.master_seed == seed {
+            return;
+        }
+        self.master_seed = seed;
+        self.rng_positions.clear();
+        for (mod_id, loaded) in &mut self.instances {
+            loaded.store.data_mut().rng = seed.map(|master| ModRng::for_mod(master, mod_id));
+        }
+    }
+
+    fn export_rng(&self) -> HashMap<String, u64> {
+        let mut positions = self.rng_positions.clone();
+        for (mod_id, loaded) in &self.instances {
+            if let Some(rng) = loaded.store.data().rng {
+                positions.insert(mod_id.clone(), rng.position());
+            }
+        }
+        positions
+    }
+
+    fn import_rng(&mut self, positions: HashMap<String, u64>) {
+        self.rng_positions = positions;
+        let streams: Vec<_> = self
+            .instances
+            .keys()
+            .map(|mod_id| (mod_id.clone(), self.stream(mod_id)))
+            .collect();
+        for (mod_id, rng) in streams {
+            if let Some(loaded) = self.instances.get_mut(&mod_id) {
+                loaded.store.data_mut().rng = rng;
+            }
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use wasmtime::AsContextMut;
 
     #[test]
     fn test_wasm_loader_creation() {
@@ -264,6 +1630,503 @@ mod tests {
         assert!(loader.is_ok());
     }
 
+    #[test]
+    fn test_clone_box_shares_engine() {
+        let loader = WasmLoader::new().unwrap();
+        let mut clone = loader.clone_box();
+        assert!(clone.drain_strings().is_empty());
+        assert!(clone.drain_commands().is_empty());
+        assert!(clone.drain_events().is_empty());
+        assert!(clone.drain_logs().is_empty());
+    }
+
+    /// Host state of a MOD with the default permissions, outside any store
+    fn host_state() -> HostState {
+        HostState {
+            mod_id: "test_mod".to_string(),
+            wasi: Mutex::new(WasiCtxBuilder::new().build()),
+            limits: StoreLimitsBuilder::new().build(),
+            table: Mutex::new(ResourceTable::new()),
+            logs: Vec::new(),
+            stdout_logging: false,
+            strings: Vec::new(),
+            commands: Vec::new(),
+            events: Vec::new(),
+            subscriptions: Vec::new(),
+            epoch: EpochYield::default(),
+            rng: None,
+            permissions: ModPermissions::all(),
+            denials: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_host_queues_commands_and_events() {
+        use crate::issun::modapi::api::Host;
+
+        let mut state = host_state();
+        state.enable_plugin("contagion".to_string());
+        state.set_plugin_param(
+            "contagion".to_string(),
+            "infection_rate".to_string(),
+            "0.05".to_string(),
+        );
+        state.set_plugin_param("ui".to_string(), "theme".to_string(), "dark".to_string());
+        state.publish_event(
+            "Outbreak".to_string(),
+            r#"{"district":"harbor"}"#.to_string(),
+        );
+
+        let commands: Vec<_> = state
+            .commands
+            .iter()
+            .map(|command| serde_json::to_value(command).unwrap())
+            .collect();
+        let expected: Vec<_> = [
+            PluginControl::enable("contagion"),
+            PluginControl::set_param("contagion", "infection_rate", serde_json::json!(0.05)),
+            PluginControl::set_param("ui", "theme", serde_json::json!("dark")),
+        ]
+        .iter()
+        .map(|command| serde_json::to_value(command).unwrap())
+        .collect();
+        assert_eq!(commands, expected);
+        assert_eq!(
+            state.events,
+            vec![(
+                "Outbreak".to_string(),
+                serde_json::json!({ "district": "harbor" })
+            )]
+        );
+    }
+
+    #[test]
+    fn test_host_checks_permissions() {
+        use crate::issun::modapi::api::Host;
+
+        let mut state = HostState {
+            permissions: ModPermissions::none()
+                .with_plugin("contagion")
+                .with_event("outbreak:*"),
+            ..host_state()
+        };
+        state.enable_plugin("contagion".to_string());
+        state.disable_plugin("save_load".to_string());
+        state.set_plugin_param("ui".to_string(), "theme".to_string(), "dark".to_string());
+        state.publish_event("outbreak:harbor".to_string(), "{}".to_string());
+        state.publish_event("GoldAdded".to_string(), "{}".to_string());
+
+        let plugins: Vec<_> = state
+            .commands
+            .iter()
+            .map(|command| command.plugin_name.as_str())
+            .collect();
+        assert_eq!(plugins, ["contagion"]);
+        assert_eq!(state.events.len(), 1);
+        let denied: Vec<_> = state
+            .denials
+            .iter()
+            .map(|denied| (denied.mod_id.as_str(), denied.action.as_str()))
+            .collect();
+        assert_eq!(
+            denied,
+            [
+                ("test_mod", "disable plugin 'save_load'"),
+                ("test_mod", "set parameter 'theme' of plugin 'ui'"),
+                ("test_mod", "publish event 'GoldAdded'"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_host_queues_log_levels() {
+        use crate::issun::modapi::api::Host;
+
+        let mut state = host_state();
+        state.log("spawned".to_string());
+        state.log_warn("low gold".to_string());
+        state.log_error("boss config missing".to_string());
+
+        let logged: Vec<_> = state
+            .logs
+            .iter()
+            .map(|entry| (entry.level, entry.message.as_str(), entry.mod_id.is_none()))
+            .collect();
+        assert_eq!(
+            logged,
+            vec![
+                (ModLogLevel::Info, "spawned", true),
+                (ModLogLevel::Warn, "low gold", true),
+                (ModLogLevel::Error, "boss config missing", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_host_tracks_subscriptions_once() {
+        use crate::issun::modapi::api::Host;
+
+        let mut state = host_state();
+        state.subscribe_event("TurnAdvanced".to_string());
+        state.subscribe_event("Outbreak".to_string());
+        state.subscribe_event("TurnAdvanced".to_string());
+
+        assert_eq!(state.subscriptions, ["TurnAdvanced", "Outbreak"]);
+    }
+
+    #[test]
+    fn test_dispatch_without_mods_reaches_nobody() {
+        let mut loader = WasmLoader::new().unwrap();
+        loader.set_dispatch_order(&["pandemic".to_string()]);
+        assert_eq!(
+            loader.dispatch_event("TurnAdvanced", &serde_json::json!({ "turn": 1 })),
+            0
+        );
+    }
+
+    #[test]
+    fn test_config_controls_engine_features() {
+        let loader = WasmLoader::with_config(WasmLoaderConfig {
+            fuel_per_call: None,
+            epoch_timeout_ms: Some(50),
+            max_memory_bytes: 1024 * 1024,
+        })
+        .unwrap();
+        assert_eq!(loader.config().epoch_timeout_ms, Some(50));
+        assert!(loader._ticker.is_some());
+
+        // Clones share the ticker along with the engine
+        let clone = loader.clone_box();
+        drop(loader);
+        drop(clone);
+
+        assert_eq!(
+            WasmLoader::new().unwrap().config(),
+            WasmLoaderConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_async_loader_yields_on_epochs() {
+        let loader = WasmLoader::new_async().unwrap();
+        assert!(loader.is_async());
+        assert_eq!(loader.config(), WasmLoaderConfig::default());
+        // Epochs drive yielding even without a timeout
+        assert!(loader._ticker.is_some());
+        assert!(!WasmLoader::new().unwrap().is_async());
+        assert!(WasmLoader::new().unwrap()._ticker.is_none());
+    }
+
+    #[test]
+    fn test_epoch_budget_counts_down_in_async_stores() {
+        let loader = WasmLoader::with_config_async(WasmLoaderConfig {
+            epoch_timeout_ms: Some(25),
+            ..WasmLoaderConfig::default()
+        })
+        .unwrap();
+        let state = HostState {
+            epoch: EpochYield {
+                yielding: true,
+                ticks_left: None,
+            },
+            ..host_state()
+        };
+        let mut store = Store::new(&loader.engine, state);
+        loader.config.arm(&mut store).unwrap();
+        assert_eq!(store.data().epoch.ticks_left, Some(3));
+
+        let mut deadlines = Vec::new();
+        for _ in 0..4 {
+            deadlines.push(match yield_or_interrupt(store.as_context_mut()) {
+                Ok(UpdateDeadline::Yield(ticks)) => Ok(ticks),
+                Ok(_) => panic!("async stores only yield"),
+                Err(e) => Err(e.downcast::<Trap>().unwrap()),
+            });
+        }
+        assert_eq!(deadlines, [Ok(1), Ok(1), Ok(1), Err(Trap::Interrupt)]);
+    }
+
+    #[test]
+    fn test_complete_outside_tokio() {
+        assert_eq!(complete(false, async { 1 }), 1);
+        let slept = complete(true, async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            2
+        });
+        assert_eq!(slept, 2);
+    }
+
+    #[tokio::test]
+    async fn test_complete_on_current_thread_runtime() {
+        let slept = complete(true, async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            3
+        });
+        assert_eq!(slept, 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_complete_blocks_in_place_on_multi_thread_runtime() {
+        let slept = complete(true, async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            4
+        });
+        assert_eq!(slept, 4);
+    }
+
+    #[test]
+    fn test_traps_map_to_limit_errors() {
+        let config = WasmLoaderConfig {
+            epoch_timeout_ms: Some(200),
+            ..WasmLoaderConfig::default()
+        };
+
+        let fuel = config.call_error("spin", "call_custom", anyhow::Error::new(Trap::OutOfFuel));
+        assert_eq!(
+            fuel.to_string(),
+            ModError::ExecutionFailed("MOD 'spin' exceeded fuel budget".to_string()).to_string()
+        );
+        let time = config.call_error("spin", "on_init", anyhow::Error::new(Trap::Interrupt));
+        assert!(time.to_string().contains("exceeded time budget (200 ms)"));
+        let other = config.call_error("spin", "on_init", anyhow::anyhow!("boom"));
+        assert!(other.to_string().contains("on_init failed: boom"));
+    }
+
+    /// Custom section `name` with `data`, as encoded in a binary
+    fn custom_section_bytes(name: &str, data: &[u8]) -> Vec<u8> {
+        let mut contents = vec![name.len() as u8];
+        contents.extend_from_slice(name.as_bytes());
+        contents.extend_from_slice(data);
+        let mut section = vec![0, contents.len() as u8];
+        section.extend(contents);
+        section
+    }
+
+    #[test]
+    fn test_custom_section_in_nested_module() {
+        let mut module = b"\0asm\x01\0\0\0".to_vec();
+        module.extend(custom_section_bytes("name", b"x"));
+        module.extend(custom_section_bytes(METADATA_SECTION, b"name = \"a\""));
+        assert_eq!(
+            custom_section(&module, METADATA_SECTION),
+            Some(&b"name = \"a\""[..])
+        );
+
+        let mut component = b"\0asm\x0d\0\x01\0".to_vec();
+        component.extend([1, module.len() as u8]);
+        component.extend(&module);
+        assert_eq!(
+            custom_section(&component, METADATA_SECTION),
+            Some(&b"name = \"a\""[..])
+        );
+        assert_eq!(custom_section(&component, "missing"), None);
+        assert_eq!(custom_section(&component[..20], METADATA_SECTION), None);
+        assert_eq!(custom_section(b"(component)", METADATA_SECTION), None);
+    }
+
+    #[test]
+    fn test_peek_metadata_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut loader = WasmLoader::new().unwrap();
+
+        // Embedded manifest, read without compiling
+        let embedded = dir.path().join("embedded.wasm");
+        let mut bytes = b"\0asm\x0d\0\x01\0".to_vec();
+        bytes.extend(custom_section_bytes(
+            METADATA_SECTION,
+            b"name = \"Pathfinder\"\nversion = \"0.3.0\"\npriority = 2",
+        ));
+        std::fs::write(&embedded, bytes).unwrap();
+        let metadata = loader.peek_metadata(&embedded).unwrap();
+        assert_eq!(metadata.name, "Pathfinder");
+        assert_eq!(metadata.version, "0.3.0");
+        assert_eq!(metadata.priority, 2);
+
+        // A sidecar manifest takes precedence
+        std::fs::write(
+            dir.path().join("embedded.toml"),
+            "name = \"Sidecar\"\nversion = \"1.0.0\"",
+        )
+        .unwrap();
+        assert_eq!(loader.peek_metadata(&embedded).unwrap().name, "Sidecar");
+
+        // Otherwise the component is instantiated, which fails without exports
+        let empty = dir.path().join("empty.wasm");
+        std::fs::write(&empty, "(component)").unwrap();
+        assert!(loader.peek_metadata(&empty).is_err());
+        assert!(loader.instances.is_empty());
+    }
+
+    #[test]
+    fn test_api_version_from_import() {
+        assert_eq!(
+            ApiVersion::from_import("issun:modapi/api"),
+            Some(ApiVersion::new(0, 1))
+        );
+        assert_eq!(
+            ApiVersion::from_import("issun:modapi/api@0.3.0"),
+            Some(API_VERSION)
+        );
+        assert_eq!(
+            ApiVersion::from_import("issun:modapi/api@1.0.0-rc.1"),
+            Some(ApiVersion::new(1, 0))
+        );
+        assert_eq!(ApiVersion::from_import("wasi:cli/stdout@0.2.0"), None);
+        assert_eq!(ApiVersion::from_import("issun:modapi/api@x"), None);
+        assert_eq!(API_VERSION.to_string(), "0.3");
+        assert!(MIN_API_VERSION.is_supported());
+        assert!(ApiVersion::new(0, 2).is_supported());
+        assert!(!ApiVersion::new(0, 4).is_supported());
+    }
+
+    #[test]
+    fn test_api_version_of_component() {
+        let loader = WasmLoader::new().unwrap();
+        let component = |import: &str| {
+            let wat = format!(
+                r#"(component (import "{}" (instance (export "log" (func (param "message" string))))))"#,
+                import
+            );
+            Component::new(&loader.engine, wat).unwrap()
+        };
+
+        let empty = Component::new(&loader.engine, "(component)").unwrap();
+        assert_eq!(loader.api_version(&empty), None);
+        assert!(loader.check_api_version("empty", &empty).is_ok());
+
+        // Every supported version links
+        for (import, version) in [
+            ("issun:modapi/api", MIN_API_VERSION),
+            ("issun:modapi/api@0.2.0", ApiVersion::new(0, 2)),
+            ("issun:modapi/api@0.3.0", API_VERSION),
+        ] {
+            let component = component(import);
+            assert_eq!(loader.api_version(&component), Some(version));
+            assert!(loader.check_api_version("mod", &component).is_ok());
+            loader.linker.instantiate_pre(&component).unwrap();
+        }
+
+        let error = loader
+            .check_api_version("future", &component("issun:modapi/api@0.4.0"))
+            .unwrap_err();
+        assert!(matches!(error, ModError::InvalidFormat(_)));
+        assert!(error
+            .to_string()
+            .contains("MOD 'future' built for api 0.4, host supports 0.1–0.3"));
+    }
+
+    /// Loader caching in a fresh directory, and an empty component to compile
+    fn cached_loader() -> (WasmLoader, tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let component = dir.path().join("empty.wasm");
+        std::fs::write(&component, "(component)").unwrap();
+        let loader = WasmLoader::new()
+            .unwrap()
+            .with_cache_dir(dir.path().join("cache"));
+        (loader, dir, component)
+    }
+
+    fn cache_entries(dir: &Path) -> Vec<PathBuf> {
+        std::fs::read_dir(dir.join("cache"))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect()
+    }
+
+    #[test]
+    fn test_cache_hit_skips_compilation() {
+        let (mut loader, dir, component) = cached_loader();
+        loader.compile(&component).unwrap();
+        assert_eq!(
+            loader.cache_stats(),
+            CacheStats {
+                hits: 0,
+                misses: 1,
+                corrupt: 0
+            }
+        );
+        assert_eq!(cache_entries(dir.path()).len(), 1);
+
+        let mut again = WasmLoader::new()
+            .unwrap()
+            .with_cache_dir(dir.path().join("cache"));
+        again.compile(&component).unwrap();
+        assert_eq!(again.cache_stats().hits, 1);
+        assert_eq!(again.cache_stats().misses, 0);
+
+        // A changed component gets its own entry
+        std::fs::write(&component, "(component (core module))").unwrap();
+        again.compile(&component).unwrap();
+        assert_eq!(again.cache_stats().misses, 1);
+        assert_eq!(cache_entries(dir.path()).len(), 2);
+    }
+
+    #[test]
+    fn test_truncated_cache_entry_is_recompiled() {
+        let (mut loader, dir, component) = cached_loader();
+        loader.compile(&component).unwrap();
+
+        let entry = cache_entries(dir.path()).remove(0);
+        let data = std::fs::read(&entry).unwrap();
+        std::fs::write(&entry, &data[..data.len() / 2]).unwrap();
+
+        loader.compile(&component).unwrap();
+        assert_eq!(loader.cache_stats().corrupt, 1);
+        assert_eq!(loader.cache_stats().misses, 2);
+        assert!(std::fs::read(&entry).unwrap().len() > data.len() / 2);
+
+        loader.compile(&component).unwrap();
+        assert_eq!(loader.cache_stats().hits, 1);
+    }
+
+    #[test]
+    fn test_engine_settings_are_part_of_the_key() {
+        let (loader, _dir, _) = cached_loader();
+        let unmetered = WasmLoader::with_config(WasmLoaderConfig {
+            fuel_per_call: None,
+            ..WasmLoaderConfig::default()
+        })
+        .unwrap();
+        assert_ne!(loader.cache_key(b"mod"), unmetered.cache_key(b"mod"));
+        assert_eq!(
+            loader.cache_key(b"mod"),
+            WasmLoader::new().unwrap().cache_key(b"mod")
+        );
+    }
+
     // Note: Full integration tests require building Wasm modules
-    // See examples/basic-wasm-mod for a complete example
+    // See tests/basic_wasm_mod.rs and examples/basic-wasm-mod
+
+    #[test]
+    fn test_call_args_keep_their_types() {
+        let args = vec![
+            serde_json::json!(1),
+            serde_json::json!(2.5),
+            serde_json::json!(true),
+            serde_json::json!({ "a": [1, 2] }),
+            serde_json::Value::Null,
+            serde_json::json!([[1, [2, null]], []]),
+        ];
+        let encoded = encode_args(args.clone());
+        assert_eq!(
+            encoded,
+            r#"[1,2.5,true,{"a":[1,2]},null,[[1,[2,null]],[]]]"#
+        );
+
+        // What an echoing guest returns decodes to the same values
+        let decoded = decode_result(&encoded).unwrap();
+        assert_eq!(decoded, serde_json::Value::Array(args));
+        assert!(decoded[0].is_u64());
+        assert!(decoded[1].is_f64());
+    }
+
+    #[test]
+    fn test_call_result_must_be_json() {
+        assert_eq!(decode_result("null").unwrap(), serde_json::Value::Null);
+        assert_eq!(encode_args(Vec::new()), "[]");
+        assert!(matches!(
+            decode_result("LOW"),
+            Err(ModError::ExecutionFailed(_))
+        ));
+    }
 }
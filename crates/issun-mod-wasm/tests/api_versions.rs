@@ -0,0 +1,97 @@
+//! MODs built against earlier and the current host API all load; newer ones
+//! fail with a clear error
+
+use issun::modding::{ModError, ModLoader, ModLogLevel};
+use issun_mod_wasm::{ApiVersion, WasmLoader, API_VERSION, MIN_API_VERSION};
+use std::path::Path;
+
+const API_0_1: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/api_0_1_mod.wat"
+);
+const API_0_2: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/api_0_2_mod.wat"
+);
+const API_0_3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/api_0_3_mod.wat"
+);
+
+#[test]
+fn test_previous_api_version_loads() {
+    let mut loader = WasmLoader::new().unwrap();
+    let handle = loader.load(Path::new(API_0_1)).unwrap();
+    assert_eq!(handle.metadata.name, "API 0.1 Fixture");
+    assert_eq!(handle.metadata.version, "0.1.0");
+
+    // Host calls go through the 0.1 shim
+    let logs = loader.drain_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].mod_id.as_deref(), Some("api_0_1_mod"));
+    assert_eq!(logs[0].level, ModLogLevel::Info);
+    assert_eq!(logs[0].message, "loaded by the 0.1 shim");
+
+    let result = loader
+        .call_function(&handle, "anything", vec![serde_json::json!("arg")])
+        .unwrap();
+    assert_eq!(result, serde_json::Value::Null);
+
+    // 0.2 goes through its own shim
+    let handle = loader.load(Path::new(API_0_2)).unwrap();
+    assert_eq!(handle.metadata.name, "API 0.2 Fixture");
+    let logs = loader.drain_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].message, "warn from api 0.2");
+}
+
+#[test]
+fn test_current_api_version_loads() {
+    let mut loader = WasmLoader::new().unwrap();
+    let handle = loader.load(Path::new(API_0_3)).unwrap();
+    assert_eq!(handle.metadata.name, "API 0.3 Fixture");
+
+    let logs = loader.drain_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].level, ModLogLevel::Warn);
+    assert_eq!(logs[0].message, "warn from api 0.3");
+
+    let roll = loader.call_function(&handle, "roll", vec![]).unwrap();
+    assert!((1..=6).contains(&roll.as_i64().unwrap()));
+
+    // All versions side by side, in the async mode too
+    let mut loader = WasmLoader::new_async().unwrap();
+    loader.load(Path::new(API_0_1)).unwrap();
+    loader.load(Path::new(API_0_2)).unwrap();
+    loader.load(Path::new(API_0_3)).unwrap();
+    assert_eq!(loader.drain_logs().len(), 3);
+}
+
+#[test]
+fn test_newer_api_version_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("from_the_future.wat");
+    std::fs::write(
+        &path,
+        r#"(component
+            (import "issun:modapi/api@0.4.0" (instance
+                (export "log" (func (param "message" string)))
+            ))
+        )"#,
+    )
+    .unwrap();
+
+    let mut loader = WasmLoader::new().unwrap();
+    let error = loader.load(&path).unwrap_err();
+    let ModError::InvalidFormat(message) = error else {
+        panic!("expected InvalidFormat, got {:?}", error);
+    };
+    assert_eq!(
+        message,
+        format!(
+            "MOD 'from_the_future' built for api 0.4, host supports {}–{}",
+            MIN_API_VERSION, API_VERSION
+        )
+    );
+    assert!(!ApiVersion::new(0, 4).is_supported());
+}
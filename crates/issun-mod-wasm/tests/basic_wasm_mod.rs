@@ -0,0 +1,184 @@
+//! The basic-wasm-mod component queues the same commands and events as the
+//! equivalent Rhai MOD (examples/basic-rhai-mod) and reacts to the game
+//! events it subscribes to
+
+use issun::modding::{ModLoader, ModLogLevel, PluginControl};
+use issun_mod_rhai::RhaiLoader;
+use issun_mod_wasm::WasmLoader;
+use std::path::{Path, PathBuf};
+
+mod common;
+
+fn fixture() -> PathBuf {
+    common::fixture("basic_wasm_mod.wat")
+}
+
+const RHAI_MOD: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../examples/basic-rhai-mod/pandemic_mod.rhai"
+);
+
+/// Commands without their issuer, which is the (differing) MOD id
+fn observable(commands: &[PluginControl]) -> Vec<serde_json::Value> {
+    commands
+        .iter()
+        .map(|command| {
+            serde_json::to_value(PluginControl {
+                issuer: None,
+                ..command.clone()
+            })
+            .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_wasm_mod_matches_rhai_mod() {
+    let mut wasm = WasmLoader::new().unwrap();
+    let wasm_handle = wasm.load(&fixture()).unwrap();
+    let mut rhai = RhaiLoader::new();
+    let rhai_handle = rhai.load(Path::new(RHAI_MOD)).unwrap();
+
+    // on_init: enable contagion, set the initial rate, announce the outbreak
+    let wasm_commands = wasm.drain_commands();
+    let rhai_commands = rhai.drain_commands();
+    assert_eq!(wasm_commands.len(), 2);
+    assert_eq!(observable(&wasm_commands), observable(&rhai_commands));
+    assert!(wasm_commands
+        .iter()
+        .all(|command| command.issuer.as_deref() == Some(wasm_handle.id.as_str())));
+    assert!(rhai_commands
+        .iter()
+        .all(|command| command.issuer.as_deref() == Some(rhai_handle.id.as_str())));
+
+    let wasm_events = wasm.drain_events();
+    assert_eq!(
+        wasm_events,
+        vec![(
+            "PandemicStarted".to_string(),
+            serde_json::json!({ "infection_rate": 0.05 })
+        )]
+    );
+    assert_eq!(wasm_events, rhai.drain_events());
+
+    // Queues are empty once drained
+    assert!(wasm.drain_commands().is_empty());
+    assert!(wasm.drain_events().is_empty());
+}
+
+#[test]
+fn test_subscribed_events_reach_on_event() {
+    let mut loader = WasmLoader::new().unwrap();
+    let handle = loader.load(&fixture()).unwrap();
+    loader.set_dispatch_order(std::slice::from_ref(&handle.id));
+    loader.drain_commands();
+
+    // on_init subscribed to TurnAdvanced only
+    assert_eq!(
+        loader.dispatch_event("PandemicStarted", &serde_json::json!({})),
+        0
+    );
+    assert_eq!(
+        loader.dispatch_event("TurnAdvanced", &serde_json::json!({ "turn": 49 })),
+        1
+    );
+    assert!(loader.drain_commands().is_empty());
+
+    assert_eq!(
+        loader.dispatch_event("TurnAdvanced", &serde_json::json!({ "turn": 50 })),
+        1
+    );
+    let commands = loader.drain_commands();
+    assert_eq!(
+        observable(&commands),
+        observable(&[PluginControl::set_param(
+            "contagion",
+            "infection_rate",
+            serde_json::json!(0.10)
+        )])
+    );
+    assert_eq!(commands[0].issuer.as_deref(), Some(handle.id.as_str()));
+
+    // Unloaded MODs receive nothing
+    loader.unload(&handle).unwrap();
+    assert_eq!(
+        loader.dispatch_event("TurnAdvanced", &serde_json::json!({ "turn": 100 })),
+        0
+    );
+}
+
+#[test]
+fn test_call_function_round_trips_typed_args() {
+    let mut loader = WasmLoader::new().unwrap();
+    let handle = loader.load(&fixture()).unwrap();
+    let mut echo = |args: serde_json::Value| {
+        let serde_json::Value::Array(args) = args else {
+            unreachable!()
+        };
+        loader.call_function(&handle, "echo", args).unwrap()
+    };
+
+    let args = serde_json::json!([1, 2.5, true, { "a": [1, 2] }]);
+    let echoed = echo(args.clone());
+    assert_eq!(echoed, args);
+    assert!(echoed[0].is_u64());
+    assert!(echoed[1].is_f64());
+
+    let nested = serde_json::json!([null, [[null], [1, [2.5, "x"]]], {}, []]);
+    assert_eq!(echo(nested.clone()), nested);
+    assert_eq!(echo(serde_json::json!([])), serde_json::json!([]));
+
+    // Numbers arrive as numbers, not strings
+    assert_eq!(
+        loader
+            .call_function(
+                &handle,
+                "calculate_risk",
+                vec![serde_json::json!(30), serde_json::json!(100.0)]
+            )
+            .unwrap(),
+        serde_json::json!({ "risk_level": "HIGH", "risk_value": 0.3 })
+    );
+}
+
+#[test]
+fn test_peek_metadata_skips_on_init() {
+    let mut loader = WasmLoader::new().unwrap();
+    let peeked = loader.peek_metadata(&fixture()).unwrap();
+
+    // Nothing was queued: on_init didn't run
+    assert!(loader.drain_commands().is_empty());
+    assert!(loader.drain_events().is_empty());
+
+    let handle = loader.load(&fixture()).unwrap();
+    assert_eq!(peeked.name, handle.metadata.name);
+    assert_eq!(peeked.version, handle.metadata.version);
+    assert_eq!(loader.drain_commands().len(), 2);
+}
+
+#[test]
+fn test_logs_are_drained_with_mod_id() {
+    let mut loader = WasmLoader::new().unwrap();
+    let handle = loader.load(&fixture()).unwrap();
+
+    let logs = loader.drain_logs();
+    let messages: Vec<_> = logs.iter().map(|entry| entry.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "🦠 Wasm Pandemic MOD initialized!",
+            "Initial infection rate: 5%"
+        ]
+    );
+    assert!(logs
+        .iter()
+        .all(|entry| entry.mod_id.as_deref() == Some(handle.id.as_str())
+            && entry.level == ModLogLevel::Info));
+    assert!(loader.drain_logs().is_empty());
+
+    // The critical phase is a warning
+    loader.dispatch_event("TurnAdvanced", &serde_json::json!({ "turn": 50 }));
+    let logs = loader.drain_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].level, ModLogLevel::Warn);
+}
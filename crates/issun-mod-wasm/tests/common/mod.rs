@@ -0,0 +1,19 @@
+//! Fixtures shared by the integration tests
+
+use std::path::PathBuf;
+
+/// Path of a component in tests/fixtures
+///
+/// Panics if the fixture is missing, so a test never passes without
+/// running its guest.
+pub fn fixture(name: &str) -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name);
+    assert!(
+        path.exists(),
+        "missing fixture {}, see tests/fixtures/README.md",
+        path.display()
+    );
+    path
+}
@@ -0,0 +1,72 @@
+//! Loading a MOD a second time through `with_cache_dir` reuses the compiled
+//! artifact instead of compiling the component again
+
+use issun::modding::ModLoader;
+use issun_mod_wasm::{CacheStats, WasmLoader};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+mod common;
+
+/// Load the fixture with a fresh loader caching in `cache_dir`
+fn timed_load(cache_dir: &Path) -> (CacheStats, Duration) {
+    let mut loader = WasmLoader::new().unwrap().with_cache_dir(cache_dir);
+    let started = Instant::now();
+    let handle = loader.load(&common::fixture("basic_wasm_mod.wat")).unwrap();
+    let elapsed = started.elapsed();
+    assert_eq!(handle.id, "basic_wasm_mod");
+    (loader.cache_stats(), elapsed)
+}
+
+#[test]
+fn test_second_load_skips_compilation() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let (first, compiled) = timed_load(dir.path());
+    assert_eq!(
+        first,
+        CacheStats {
+            hits: 0,
+            misses: 1,
+            corrupt: 0
+        }
+    );
+
+    let (second, cached) = timed_load(dir.path());
+    assert_eq!(
+        second,
+        CacheStats {
+            hits: 1,
+            misses: 0,
+            corrupt: 0
+        }
+    );
+    assert!(
+        cached * 2 < compiled,
+        "cached load took {:?}, compiling took {:?}",
+        cached,
+        compiled
+    );
+}
+
+#[test]
+fn test_corrupt_entry_is_replaced() {
+    let dir = tempfile::tempdir().unwrap();
+    timed_load(dir.path());
+
+    for entry in std::fs::read_dir(dir.path()).unwrap() {
+        std::fs::write(entry.unwrap().path(), b"not a component").unwrap();
+    }
+
+    let (stats, _) = timed_load(dir.path());
+    assert_eq!(
+        stats,
+        CacheStats {
+            hits: 0,
+            misses: 1,
+            corrupt: 1
+        }
+    );
+    let (stats, _) = timed_load(dir.path());
+    assert_eq!(stats.hits, 1);
+}
@@ -0,0 +1,95 @@
+;; Minimal MOD built against host API 0.3
+;;
+;; Imports `issun:modapi/api@0.3.0`, logs a warning from on-init and answers
+;; every call-custom with a die roll from `random-int(1, 6)`. Copy it to
+;; api_0_<n>_mod.wat when the API version is bumped, so the previous version
+;; keeps being tested.
+(component
+  (import "issun:modapi/api@0.3.0" (instance $api
+    (export "log-warn" (func (param "message" string)))
+    (export "random-int" (func (param "min" s64) (param "max" s64) (result s64)))
+  ))
+  (alias export $api "log-warn" (func $api-log))
+  (alias export $api "random-int" (func $api-random-int))
+
+  ;; Memory lives in its own instance so `log` can be lowered before the
+  ;; main module is instantiated
+  (core module $memory-module
+    (memory (export "memory") 1)
+  )
+  (core instance $memory-instance (instantiate $memory-module))
+  (alias core export $memory-instance "memory" (core memory $memory))
+
+  (core func $log (canon lower (func $api-log) (memory $memory)))
+  (core func $random-int (canon lower (func $api-random-int)))
+
+  (core module $main
+    (import "env" "memory" (memory 1))
+    (import "api" "log" (func $log (param i32 i32)))
+    (import "api" "random-int" (func $random-int (param i64 i64) (result i64)))
+    (global $heap (mut i32) (i32.const 1024))
+
+    (data (i32.const 0) "API 0.3 Fixture")
+    (data (i32.const 16) "0.3.0")
+    (data (i32.const 32) "warn from api 0.3")
+    ;; metadata: name (0, 15), version (16, 5), author none, description none
+    (data (i32.const 64) "\00\00\00\00\0f\00\00\00\10\00\00\00\05\00\00\00")
+    ;; call-custom result: one digit at 112
+    (data (i32.const 120) "\70\00\00\00\01\00\00\00")
+
+    (func (export "get-metadata") (result i32)
+      i32.const 64)
+    (func (export "on-init")
+      (call $log (i32.const 32) (i32.const 17)))
+    (func (export "on-shutdown"))
+    (func (export "on-control-plugin") (param i32 i32 i32 i32))
+    (func (export "on-event") (param i32 i32 i32 i32))
+    (func (export "call-custom") (param i32 i32 i32 i32) (result i32)
+      (i32.store8 (i32.const 112)
+        (i32.add (i32.const 48)
+          (i32.wrap_i64 (call $random-int (i64.const 1) (i64.const 6)))))
+      i32.const 120)
+
+    ;; Bump allocator for the strings the host passes in
+    (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+      (local $ptr i32)
+      (local.set $ptr
+        (i32.and
+          (i32.add (global.get $heap) (i32.sub (local.get 2) (i32.const 1)))
+          (i32.sub (i32.const 0) (local.get 2))))
+      (global.set $heap (i32.add (local.get $ptr) (local.get 3)))
+      (local.get $ptr))
+  )
+  (core instance $main-instance (instantiate $main
+    (with "env" (instance $memory-instance))
+    (with "api" (instance
+      (export "log" (func $log))
+      (export "random-int" (func $random-int))
+    ))
+  ))
+  (alias core export $main-instance "realloc" (core func $realloc))
+
+  (type $metadata-def (record
+    (field "name" string)
+    (field "version" string)
+    (field "author" (option string))
+    (field "description" (option string))
+  ))
+  (export $metadata "metadata" (type $metadata-def))
+
+  (func (export "get-metadata") (result $metadata)
+    (canon lift (core func $main-instance "get-metadata") (memory $memory)))
+  (func (export "on-init")
+    (canon lift (core func $main-instance "on-init")))
+  (func (export "on-shutdown")
+    (canon lift (core func $main-instance "on-shutdown")))
+  (func (export "on-control-plugin") (param "plugin-name" string) (param "action" string)
+    (canon lift (core func $main-instance "on-control-plugin")
+      (memory $memory) (realloc $realloc)))
+  (func (export "on-event") (param "event-type" string) (param "payload-json" string)
+    (canon lift (core func $main-instance "on-event")
+      (memory $memory) (realloc $realloc)))
+  (func (export "call-custom") (param "fn-name" string) (param "args-json" string) (result string)
+    (canon lift (core func $main-instance "call-custom")
+      (memory $memory) (realloc $realloc)))
+)
@@ -0,0 +1,329 @@
+;; The pandemic MOD of examples/basic-wasm-mod, by hand
+;;
+;; on-init enables `contagion`, sets its infection rate, publishes
+;; `PandemicStarted` and subscribes to `TurnAdvanced`; on-event and the `tick`
+;; function change the rate at turns 50, 100 and 200. call-custom also
+;; answers `echo` (the arguments, unchanged) and `calculate_risk`, whose
+;; non-negative arguments are read as plain decimals and whose risk value is
+;; rounded to three decimals.
+(component
+  (import "issun:modapi/api@0.3.0" (instance $api
+    (export "log" (func (param "message" string)))
+    (export "log-warn" (func (param "message" string)))
+    (export "enable-plugin" (func (param "name" string)))
+    (export "set-plugin-param" (func (param "plugin" string) (param "key" string) (param "value" string)))
+    (export "publish-event" (func (param "event-type" string) (param "data" string)))
+    (export "subscribe-event" (func (param "event-type" string)))
+  ))
+  (alias export $api "log" (func $api-log))
+  (alias export $api "log-warn" (func $api-log-warn))
+  (alias export $api "enable-plugin" (func $api-enable))
+  (alias export $api "set-plugin-param" (func $api-set-param))
+  (alias export $api "publish-event" (func $api-publish))
+  (alias export $api "subscribe-event" (func $api-subscribe))
+
+  ;; Memory lives in its own instance so the imports can be lowered before
+  ;; the main module is instantiated
+  (core module $memory-module
+    (memory (export "memory") 1)
+  )
+  (core instance $memory-instance (instantiate $memory-module))
+  (alias core export $memory-instance "memory" (core memory $memory))
+
+  (core func $log (canon lower (func $api-log) (memory $memory)))
+  (core func $log-warn (canon lower (func $api-log-warn) (memory $memory)))
+  (core func $enable (canon lower (func $api-enable) (memory $memory)))
+  (core func $set-param (canon lower (func $api-set-param) (memory $memory)))
+  (core func $publish (canon lower (func $api-publish) (memory $memory)))
+  (core func $subscribe (canon lower (func $api-subscribe) (memory $memory)))
+
+  (core module $main
+    (import "env" "memory" (memory 1))
+    (import "api" "log" (func $log (param i32 i32)))
+    (import "api" "log-warn" (func $log-warn (param i32 i32)))
+    (import "api" "enable-plugin" (func $enable (param i32 i32)))
+    (import "api" "set-plugin-param" (func $set-param (param i32 i32 i32 i32 i32 i32)))
+    (import "api" "publish-event" (func $publish (param i32 i32 i32 i32)))
+    (import "api" "subscribe-event" (func $subscribe (param i32 i32)))
+    (global $heap (mut i32) (i32.const 4096))
+    ;; End of the text composed in the output buffer at 1024
+    (global $out (mut i32) (i32.const 1024))
+    ;; End of the number last read by $number
+    (global $cursor (mut i32) (i32.const 0))
+
+    (data (i32.const 0) "Wasm Pandemic Controller")
+    (data (i32.const 24) "1.0.0")
+    (data (i32.const 32) "ISSUN Team")
+    (data (i32.const 48) "WebAssembly-based pandemic simulation controller")
+    (data (i32.const 96) "🦠 Wasm Pandemic MOD initialized!")
+    (data (i32.const 136) "Initial infection rate: 5%")
+    (data (i32.const 168) "contagion")
+    (data (i32.const 184) "infection_rate")
+    (data (i32.const 200) "0.05")
+    (data (i32.const 208) "0.10")
+    (data (i32.const 216) "0.15")
+    (data (i32.const 224) "0.03")
+    (data (i32.const 232) "PandemicStarted")
+    (data (i32.const 248) "{\"infection_rate\":0.05}")
+    (data (i32.const 272) "TurnAdvanced")
+    (data (i32.const 288) "⚠️  Pandemic entering critical phase!")
+    (data (i32.const 336) "🔴 PANDEMIC OUTBREAK!")
+    (data (i32.const 360) "✅ Vaccine developed!")
+    (data (i32.const 384) "Wasm Pandemic MOD shutting down...")
+    (data (i32.const 424) "Controlling plugin: ")
+    (data (i32.const 448) " - ")
+    (data (i32.const 456) "\"turn\"")
+    (data (i32.const 464) "calculate_risk")
+    (data (i32.const 480) "tick")
+    (data (i32.const 488) "echo")
+    (data (i32.const 496) "{\"error\":\"Unknown function\"}")
+    (data (i32.const 528) "{\"risk_level\":\"")
+    (data (i32.const 544) "\",\"risk_value\":")
+    (data (i32.const 560) "LOW")
+    (data (i32.const 568) "MODERATE")
+    (data (i32.const 576) "HIGH")
+    (data (i32.const 584) "CRITICAL")
+    (data (i32.const 592) "{\"turn\":")
+    ;; metadata: name (0, 24), version (24, 5), author some (32, 10),
+    ;; description some (48, 48)
+    (data (i32.const 608)
+      "\00\00\00\00\18\00\00\00\18\00\00\00\05\00\00\00"
+      "\01\00\00\00\20\00\00\00\0a\00\00\00"
+      "\01\00\00\00\30\00\00\00\30\00\00\00")
+    ;; call-custom results are (pointer, length) at 656
+
+    (func $bytes-eq (param $a i32) (param $a-len i32) (param $b i32) (param $b-len i32) (result i32)
+      (if (i32.ne (local.get $a-len) (local.get $b-len))
+        (then (return (i32.const 0))))
+      (block $done
+        (loop $next
+          (br_if $done (i32.eqz (local.get $a-len)))
+          (if (i32.ne (i32.load8_u (local.get $a)) (i32.load8_u (local.get $b)))
+            (then (return (i32.const 0))))
+          (local.set $a (i32.add (local.get $a) (i32.const 1)))
+          (local.set $b (i32.add (local.get $b) (i32.const 1)))
+          (local.set $a-len (i32.sub (local.get $a-len) (i32.const 1)))
+          (br $next)))
+      (i32.const 1))
+
+    ;; Address right after the first `needle` in the haystack, 0 if none
+    (func $find (param $hay i32) (param $hay-len i32) (param $needle i32) (param $needle-len i32) (result i32)
+      (block $missing
+        (loop $next
+          (br_if $missing (i32.lt_u (local.get $hay-len) (local.get $needle-len)))
+          (if (call $bytes-eq (local.get $hay) (local.get $needle-len) (local.get $needle) (local.get $needle-len))
+            (then (return (i32.add (local.get $hay) (local.get $needle-len)))))
+          (local.set $hay (i32.add (local.get $hay) (i32.const 1)))
+          (local.set $hay-len (i32.sub (local.get $hay-len) (i32.const 1)))
+          (br $next)))
+      (i32.const 0))
+
+    ;; First decimal number in [ptr, end), 0 if none; sets $cursor past it
+    (func $number (param $ptr i32) (param $end i32) (result f64)
+      (local $value f64) (local $fraction f64) (local $divisor f64) (local $digit i32)
+      (block $found
+        (loop $skip
+          (br_if $found (i32.ge_u (local.get $ptr) (local.get $end)))
+          (br_if $found (i32.lt_u (i32.sub (i32.load8_u (local.get $ptr)) (i32.const 48)) (i32.const 10)))
+          (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+          (br $skip)))
+      (block $integer-done
+        (loop $integer
+          (br_if $integer-done (i32.ge_u (local.get $ptr) (local.get $end)))
+          (local.set $digit (i32.sub (i32.load8_u (local.get $ptr)) (i32.const 48)))
+          (br_if $integer-done (i32.ge_u (local.get $digit) (i32.const 10)))
+          (local.set $value
+            (f64.add (f64.mul (local.get $value) (f64.const 10)) (f64.convert_i32_u (local.get $digit))))
+          (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+          (br $integer)))
+      (local.set $divisor (f64.const 1))
+      (if (i32.lt_u (local.get $ptr) (local.get $end))
+        (then
+          (if (i32.eq (i32.load8_u (local.get $ptr)) (i32.const 46))
+            (then
+              (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+              (block $fraction-done
+                (loop $fraction-digit
+                  (br_if $fraction-done (i32.ge_u (local.get $ptr) (local.get $end)))
+                  (local.set $digit (i32.sub (i32.load8_u (local.get $ptr)) (i32.const 48)))
+                  (br_if $fraction-done (i32.ge_u (local.get $digit) (i32.const 10)))
+                  (local.set $fraction
+                    (f64.add (f64.mul (local.get $fraction) (f64.const 10)) (f64.convert_i32_u (local.get $digit))))
+                  (local.set $divisor (f64.mul (local.get $divisor) (f64.const 10)))
+                  (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+                  (br $fraction-digit)))))))
+      (global.set $cursor (local.get $ptr))
+      (f64.add (local.get $value) (f64.div (local.get $fraction) (local.get $divisor))))
+
+    (func $emit (param $ptr i32) (param $len i32)
+      (memory.copy (global.get $out) (local.get $ptr) (local.get $len))
+      (global.set $out (i32.add (global.get $out) (local.get $len))))
+
+    (func $emit-byte (param $byte i32)
+      (i32.store8 (global.get $out) (local.get $byte))
+      (global.set $out (i32.add (global.get $out) (i32.const 1))))
+
+    (func $emit-uint (param $value i64)
+      (if (i64.ge_u (local.get $value) (i64.const 10))
+        (then (call $emit-uint (i64.div_u (local.get $value) (i64.const 10)))))
+      (call $emit-byte
+        (i32.add (i32.const 48) (i32.wrap_i64 (i64.rem_u (local.get $value) (i64.const 10))))))
+
+    ;; Up to three decimals, without trailing zeros
+    (func $emit-decimal (param $value f64)
+      (local $thousandths i64) (local $fraction i64) (local $unit i64)
+      (local.set $thousandths
+        (i64.trunc_sat_f64_u (f64.nearest (f64.mul (local.get $value) (f64.const 1000)))))
+      (call $emit-uint (i64.div_u (local.get $thousandths) (i64.const 1000)))
+      (local.set $fraction (i64.rem_u (local.get $thousandths) (i64.const 1000)))
+      (if (i64.eqz (local.get $fraction))
+        (then (return)))
+      (call $emit-byte (i32.const 46))
+      (local.set $unit (i64.const 100))
+      (loop $digit
+        (call $emit-byte
+          (i32.add (i32.const 48) (i32.wrap_i64 (i64.div_u (local.get $fraction) (local.get $unit)))))
+        (local.set $fraction (i64.rem_u (local.get $fraction) (local.get $unit)))
+        (local.set $unit (i64.div_u (local.get $unit) (i64.const 10)))
+        (br_if $digit (i64.ne (local.get $fraction) (i64.const 0)))))
+
+    ;; The composed text as a call-custom result
+    (func $output (result i32)
+      (i32.store (i32.const 656) (i32.const 1024))
+      (i32.store (i32.const 660) (i32.sub (global.get $out) (i32.const 1024)))
+      (i32.const 656))
+
+    (func $advance-turn (param $turn i64)
+      (if (i64.eq (local.get $turn) (i64.const 50))
+        (then
+          (call $log-warn (i32.const 288) (i32.const 41))
+          (call $set-param (i32.const 168) (i32.const 9) (i32.const 184) (i32.const 14) (i32.const 208) (i32.const 4))))
+      (if (i64.eq (local.get $turn) (i64.const 100))
+        (then
+          (call $log (i32.const 336) (i32.const 23))
+          (call $set-param (i32.const 168) (i32.const 9) (i32.const 184) (i32.const 14) (i32.const 216) (i32.const 4))))
+      (if (i64.eq (local.get $turn) (i64.const 200))
+        (then
+          (call $log (i32.const 360) (i32.const 22))
+          (call $set-param (i32.const 168) (i32.const 9) (i32.const 184) (i32.const 14) (i32.const 224) (i32.const 4)))))
+
+    (func (export "get-metadata") (result i32)
+      i32.const 608)
+    (func (export "on-init")
+      (call $log (i32.const 96) (i32.const 35))
+      (call $enable (i32.const 168) (i32.const 9))
+      (call $set-param (i32.const 168) (i32.const 9) (i32.const 184) (i32.const 14) (i32.const 200) (i32.const 4))
+      (call $publish (i32.const 232) (i32.const 15) (i32.const 248) (i32.const 23))
+      (call $log (i32.const 136) (i32.const 26))
+      (call $subscribe (i32.const 272) (i32.const 12)))
+    (func (export "on-shutdown")
+      (call $log (i32.const 384) (i32.const 34)))
+    (func (export "on-control-plugin") (param $plugin i32) (param $plugin-len i32) (param $action i32) (param $action-len i32)
+      (global.set $out (i32.const 1024))
+      (call $emit (i32.const 424) (i32.const 20))
+      (call $emit (local.get $plugin) (local.get $plugin-len))
+      (call $emit (i32.const 448) (i32.const 3))
+      (call $emit (local.get $action) (local.get $action-len))
+      (call $log (i32.const 1024) (i32.sub (global.get $out) (i32.const 1024))))
+    (func (export "on-event") (param $type i32) (param $type-len i32) (param $payload i32) (param $payload-len i32)
+      (local $turn i32)
+      (if (i32.eqz (call $bytes-eq (local.get $type) (local.get $type-len) (i32.const 272) (i32.const 12)))
+        (then (return)))
+      ;; Payload: {"turn":50}
+      (local.set $turn (call $find (local.get $payload) (local.get $payload-len) (i32.const 456) (i32.const 6)))
+      (if (i32.eqz (local.get $turn))
+        (then (return)))
+      (call $advance-turn
+        (i64.trunc_sat_f64_u
+          (call $number (local.get $turn) (i32.add (local.get $payload) (local.get $payload-len))))))
+    (func (export "call-custom") (param $name i32) (param $name-len i32) (param $args i32) (param $args-len i32) (result i32)
+      (local $end i32) (local $turn i64) (local $risk f64)
+      (local.set $end (i32.add (local.get $args) (local.get $args-len)))
+      (global.set $out (i32.const 1024))
+
+      ;; The arguments already are one JSON array
+      (if (call $bytes-eq (local.get $name) (local.get $name-len) (i32.const 488) (i32.const 4))
+        (then
+          (i32.store (i32.const 656) (local.get $args))
+          (i32.store (i32.const 660) (local.get $args-len))
+          (return (i32.const 656))))
+
+      (if (call $bytes-eq (local.get $name) (local.get $name-len) (i32.const 480) (i32.const 4))
+        (then
+          (local.set $turn (i64.trunc_sat_f64_u (call $number (local.get $args) (local.get $end))))
+          (call $advance-turn (local.get $turn))
+          (call $emit (i32.const 592) (i32.const 8))
+          (call $emit-uint (local.get $turn))
+          (call $emit-byte (i32.const 125))
+          (return (call $output))))
+
+      (if (call $bytes-eq (local.get $name) (local.get $name-len) (i32.const 464) (i32.const 14))
+        (then
+          (local.set $risk (call $number (local.get $args) (local.get $end)))
+          (local.set $risk
+            (f64.div (local.get $risk) (call $number (global.get $cursor) (local.get $end))))
+          (call $emit (i32.const 528) (i32.const 15))
+          (if (f64.lt (local.get $risk) (f64.const 0.1))
+            (then (call $emit (i32.const 560) (i32.const 3)))
+            (else
+              (if (f64.lt (local.get $risk) (f64.const 0.3))
+                (then (call $emit (i32.const 568) (i32.const 8)))
+                (else
+                  (if (f64.lt (local.get $risk) (f64.const 0.6))
+                    (then (call $emit (i32.const 576) (i32.const 4)))
+                    (else (call $emit (i32.const 584) (i32.const 8))))))))
+          (call $emit (i32.const 544) (i32.const 15))
+          (call $emit-decimal (local.get $risk))
+          (call $emit-byte (i32.const 125))
+          (return (call $output))))
+
+      (call $emit (i32.const 496) (i32.const 28))
+      (call $output))
+
+    ;; Bump allocator for the strings the host passes in
+    (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+      (local $ptr i32)
+      (local.set $ptr
+        (i32.and
+          (i32.add (global.get $heap) (i32.sub (local.get 2) (i32.const 1)))
+          (i32.sub (i32.const 0) (local.get 2))))
+      (global.set $heap (i32.add (local.get $ptr) (local.get 3)))
+      (local.get $ptr))
+  )
+  (core instance $main-instance (instantiate $main
+    (with "env" (instance $memory-instance))
+    (with "api" (instance
+      (export "log" (func $log))
+      (export "log-warn" (func $log-warn))
+      (export "enable-plugin" (func $enable))
+      (export "set-plugin-param" (func $set-param))
+      (export "publish-event" (func $publish))
+      (export "subscribe-event" (func $subscribe))
+    ))
+  ))
+  (alias core export $main-instance "realloc" (core func $realloc))
+
+  (type $metadata-def (record
+    (field "name" string)
+    (field "version" string)
+    (field "author" (option string))
+    (field "description" (option string))
+  ))
+  (export $metadata "metadata" (type $metadata-def))
+
+  (func (export "get-metadata") (result $metadata)
+    (canon lift (core func $main-instance "get-metadata") (memory $memory)))
+  (func (export "on-init")
+    (canon lift (core func $main-instance "on-init")))
+  (func (export "on-shutdown")
+    (canon lift (core func $main-instance "on-shutdown")))
+  (func (export "on-control-plugin") (param "plugin-name" string) (param "action" string)
+    (canon lift (core func $main-instance "on-control-plugin")
+      (memory $memory) (realloc $realloc)))
+  (func (export "on-event") (param "event-type" string) (param "payload-json" string)
+    (canon lift (core func $main-instance "on-event")
+      (memory $memory) (realloc $realloc)))
+  (func (export "call-custom") (param "fn-name" string) (param "args-json" string) (result string)
+    (canon lift (core func $main-instance "call-custom")
+      (memory $memory) (realloc $realloc)))
+)
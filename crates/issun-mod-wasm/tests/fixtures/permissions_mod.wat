@@ -0,0 +1,101 @@
+;; MOD reaching for plugins and events, for permission checks
+;;
+;; on-init enables `combat`, disables `save_load` and publishes
+;; `loot:dropped`; call-custom answers `null`.
+(component
+  (import "issun:modapi/api@0.3.0" (instance $api
+    (export "enable-plugin" (func (param "name" string)))
+    (export "disable-plugin" (func (param "name" string)))
+    (export "publish-event" (func (param "event-type" string) (param "data" string)))
+  ))
+  (alias export $api "enable-plugin" (func $api-enable))
+  (alias export $api "disable-plugin" (func $api-disable))
+  (alias export $api "publish-event" (func $api-publish))
+
+  ;; Memory lives in its own instance so the imports can be lowered before
+  ;; the main module is instantiated
+  (core module $memory-module
+    (memory (export "memory") 1)
+  )
+  (core instance $memory-instance (instantiate $memory-module))
+  (alias core export $memory-instance "memory" (core memory $memory))
+
+  (core func $enable (canon lower (func $api-enable) (memory $memory)))
+  (core func $disable (canon lower (func $api-disable) (memory $memory)))
+  (core func $publish (canon lower (func $api-publish) (memory $memory)))
+
+  (core module $main
+    (import "env" "memory" (memory 1))
+    (import "api" "enable-plugin" (func $enable (param i32 i32)))
+    (import "api" "disable-plugin" (func $disable (param i32 i32)))
+    (import "api" "publish-event" (func $publish (param i32 i32 i32 i32)))
+    (global $heap (mut i32) (i32.const 1024))
+
+    (data (i32.const 0) "Permissions Fixture")
+    (data (i32.const 24) "1.0.0")
+    (data (i32.const 32) "combat")
+    (data (i32.const 40) "save_load")
+    (data (i32.const 56) "loot:dropped")
+    (data (i32.const 72) "{}")
+    ;; metadata: name (0, 19), version (24, 5), author none, description none
+    (data (i32.const 96) "\00\00\00\00\13\00\00\00\18\00\00\00\05\00\00\00")
+    ;; call-custom result: "null" at 144
+    (data (i32.const 144) "null")
+    (data (i32.const 152) "\90\00\00\00\04\00\00\00")
+
+    (func (export "get-metadata") (result i32)
+      i32.const 96)
+    (func (export "on-init")
+      (call $enable (i32.const 32) (i32.const 6))
+      (call $disable (i32.const 40) (i32.const 9))
+      (call $publish (i32.const 56) (i32.const 12) (i32.const 72) (i32.const 2)))
+    (func (export "on-shutdown"))
+    (func (export "on-control-plugin") (param i32 i32 i32 i32))
+    (func (export "on-event") (param i32 i32 i32 i32))
+    (func (export "call-custom") (param i32 i32 i32 i32) (result i32)
+      i32.const 152)
+
+    ;; Bump allocator for the strings the host passes in
+    (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+      (local $ptr i32)
+      (local.set $ptr
+        (i32.and
+          (i32.add (global.get $heap) (i32.sub (local.get 2) (i32.const 1)))
+          (i32.sub (i32.const 0) (local.get 2))))
+      (global.set $heap (i32.add (local.get $ptr) (local.get 3)))
+      (local.get $ptr))
+  )
+  (core instance $main-instance (instantiate $main
+    (with "env" (instance $memory-instance))
+    (with "api" (instance
+      (export "enable-plugin" (func $enable))
+      (export "disable-plugin" (func $disable))
+      (export "publish-event" (func $publish))
+    ))
+  ))
+  (alias core export $main-instance "realloc" (core func $realloc))
+
+  (type $metadata-def (record
+    (field "name" string)
+    (field "version" string)
+    (field "author" (option string))
+    (field "description" (option string))
+  ))
+  (export $metadata "metadata" (type $metadata-def))
+
+  (func (export "get-metadata") (result $metadata)
+    (canon lift (core func $main-instance "get-metadata") (memory $memory)))
+  (func (export "on-init")
+    (canon lift (core func $main-instance "on-init")))
+  (func (export "on-shutdown")
+    (canon lift (core func $main-instance "on-shutdown")))
+  (func (export "on-control-plugin") (param "plugin-name" string) (param "action" string)
+    (canon lift (core func $main-instance "on-control-plugin")
+      (memory $memory) (realloc $realloc)))
+  (func (export "on-event") (param "event-type" string) (param "payload-json" string)
+    (canon lift (core func $main-instance "on-event")
+      (memory $memory) (realloc $realloc)))
+  (func (export "call-custom") (param "fn-name" string) (param "args-json" string) (result string)
+    (canon lift (core func $main-instance "call-custom")
+      (memory $memory) (realloc $realloc)))
+)
@@ -0,0 +1,164 @@
+;; The runaway MOD of examples/spin-wasm-mod, by hand
+;;
+;; call-custom `spin` loops forever, `allocate(megabytes)` grows memory by
+;; that much, fills it and answers `{"allocated": bytes}` (it traps when the
+;; memory cannot grow, as an allocation failure aborts a Rust guest) and
+;; `ping` answers `"pong"`. Imports nothing.
+(component
+  (core module $memory-module
+    (memory (export "memory") 1)
+  )
+  (core instance $memory-instance (instantiate $memory-module))
+  (alias core export $memory-instance "memory" (core memory $memory))
+
+  (core module $main
+    (import "env" "memory" (memory 1))
+    (global $heap (mut i32) (i32.const 4096))
+    ;; End of the text composed in the output buffer at 1024
+    (global $out (mut i32) (i32.const 1024))
+
+    (data (i32.const 0) "Spin")
+    (data (i32.const 8) "1.0.0")
+    (data (i32.const 16) "ISSUN Team")
+    (data (i32.const 32) "Runs away on purpose")
+    (data (i32.const 56) "spin")
+    (data (i32.const 64) "allocate")
+    (data (i32.const 72) "ping")
+    (data (i32.const 80) "\"pong\"")
+    (data (i32.const 88) "{\"error\":\"Unknown function\"}")
+    (data (i32.const 120) "{\"allocated\":")
+    ;; metadata: name (0, 4), version (8, 5), author some (16, 10),
+    ;; description some (32, 20)
+    (data (i32.const 136)
+      "\00\00\00\00\04\00\00\00\08\00\00\00\05\00\00\00"
+      "\01\00\00\00\10\00\00\00\0a\00\00\00"
+      "\01\00\00\00\20\00\00\00\14\00\00\00")
+    ;; call-custom results are (pointer, length) at 176
+
+    (func $bytes-eq (param $a i32) (param $a-len i32) (param $b i32) (param $b-len i32) (result i32)
+      (if (i32.ne (local.get $a-len) (local.get $b-len))
+        (then (return (i32.const 0))))
+      (block $done
+        (loop $next
+          (br_if $done (i32.eqz (local.get $a-len)))
+          (if (i32.ne (i32.load8_u (local.get $a)) (i32.load8_u (local.get $b)))
+            (then (return (i32.const 0))))
+          (local.set $a (i32.add (local.get $a) (i32.const 1)))
+          (local.set $b (i32.add (local.get $b) (i32.const 1)))
+          (local.set $a-len (i32.sub (local.get $a-len) (i32.const 1)))
+          (br $next)))
+      (i32.const 1))
+
+    ;; First unsigned integer in [ptr, end), 0 if none
+    (func $uint (param $ptr i32) (param $end i32) (result i64)
+      (local $value i64) (local $digit i32)
+      (block $found
+        (loop $skip
+          (br_if $found (i32.ge_u (local.get $ptr) (local.get $end)))
+          (br_if $found (i32.lt_u (i32.sub (i32.load8_u (local.get $ptr)) (i32.const 48)) (i32.const 10)))
+          (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+          (br $skip)))
+      (block $done
+        (loop $integer
+          (br_if $done (i32.ge_u (local.get $ptr) (local.get $end)))
+          (local.set $digit (i32.sub (i32.load8_u (local.get $ptr)) (i32.const 48)))
+          (br_if $done (i32.ge_u (local.get $digit) (i32.const 10)))
+          (local.set $value
+            (i64.add (i64.mul (local.get $value) (i64.const 10)) (i64.extend_i32_u (local.get $digit))))
+          (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+          (br $integer)))
+      (local.get $value))
+
+    (func $emit (param $ptr i32) (param $len i32)
+      (memory.copy (global.get $out) (local.get $ptr) (local.get $len))
+      (global.set $out (i32.add (global.get $out) (local.get $len))))
+
+    (func $emit-byte (param $byte i32)
+      (i32.store8 (global.get $out) (local.get $byte))
+      (global.set $out (i32.add (global.get $out) (i32.const 1))))
+
+    (func $emit-uint (param $value i64)
+      (if (i64.ge_u (local.get $value) (i64.const 10))
+        (then (call $emit-uint (i64.div_u (local.get $value) (i64.const 10)))))
+      (call $emit-byte
+        (i32.add (i32.const 48) (i32.wrap_i64 (i64.rem_u (local.get $value) (i64.const 10))))))
+
+    (func $result (param $ptr i32) (param $len i32) (result i32)
+      (i32.store (i32.const 176) (local.get $ptr))
+      (i32.store (i32.const 180) (local.get $len))
+      (i32.const 176))
+
+    (func (export "get-metadata") (result i32)
+      i32.const 136)
+    (func (export "on-init"))
+    (func (export "on-shutdown"))
+    (func (export "on-control-plugin") (param i32 i32 i32 i32))
+    (func (export "on-event") (param i32 i32 i32 i32))
+    (func (export "call-custom") (param $name i32) (param $name-len i32) (param $args i32) (param $args-len i32) (result i32)
+      (local $turns i64) (local $bytes i32) (local $base i32)
+      (if (call $bytes-eq (local.get $name) (local.get $name-len) (i32.const 56) (i32.const 4))
+        (then
+          (loop $forever
+            (local.set $turns (i64.add (local.get $turns) (i64.const 1)))
+            (br $forever))))
+
+      (if (call $bytes-eq (local.get $name) (local.get $name-len) (i32.const 64) (i32.const 8))
+        (then
+          (local.set $bytes
+            (i32.shl
+              (i32.wrap_i64 (call $uint (local.get $args) (i32.add (local.get $args) (local.get $args-len))))
+              (i32.const 20)))
+          (local.set $base (memory.grow (i32.shr_u (local.get $bytes) (i32.const 16))))
+          (if (i32.eq (local.get $base) (i32.const -1))
+            (then (unreachable)))
+          (memory.fill (i32.shl (local.get $base) (i32.const 16)) (i32.const 1) (local.get $bytes))
+          (global.set $out (i32.const 1024))
+          (call $emit (i32.const 120) (i32.const 13))
+          (call $emit-uint (i64.extend_i32_u (local.get $bytes)))
+          (call $emit-byte (i32.const 125))
+          (return (call $result (i32.const 1024) (i32.sub (global.get $out) (i32.const 1024))))))
+
+      (if (call $bytes-eq (local.get $name) (local.get $name-len) (i32.const 72) (i32.const 4))
+        (then (return (call $result (i32.const 80) (i32.const 6)))))
+
+      (call $result (i32.const 88) (i32.const 28)))
+
+    ;; Bump allocator for the strings the host passes in
+    (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+      (local $ptr i32)
+      (local.set $ptr
+        (i32.and
+          (i32.add (global.get $heap) (i32.sub (local.get 2) (i32.const 1)))
+          (i32.sub (i32.const 0) (local.get 2))))
+      (global.set $heap (i32.add (local.get $ptr) (local.get 3)))
+      (local.get $ptr))
+  )
+  (core instance $main-instance (instantiate $main
+    (with "env" (instance $memory-instance))
+  ))
+  (alias core export $main-instance "realloc" (core func $realloc))
+
+  (type $metadata-def (record
+    (field "name" string)
+    (field "version" string)
+    (field "author" (option string))
+    (field "description" (option string))
+  ))
+  (export $metadata "metadata" (type $metadata-def))
+
+  (func (export "get-metadata") (result $metadata)
+    (canon lift (core func $main-instance "get-metadata") (memory $memory)))
+  (func (export "on-init")
+    (canon lift (core func $main-instance "on-init")))
+  (func (export "on-shutdown")
+    (canon lift (core func $main-instance "on-shutdown")))
+  (func (export "on-control-plugin") (param "plugin-name" string) (param "action" string)
+    (canon lift (core func $main-instance "on-control-plugin")
+      (memory $memory) (realloc $realloc)))
+  (func (export "on-event") (param "event-type" string) (param "payload-json" string)
+    (canon lift (core func $main-instance "on-event")
+      (memory $memory) (realloc $realloc)))
+  (func (export "call-custom") (param "fn-name" string) (param "args-json" string) (result string)
+    (canon lift (core func $main-instance "call-custom")
+      (memory $memory) (realloc $realloc)))
+)
@@ -0,0 +1,387 @@
+;; The WASI probe MOD of examples/wasi-probe-mod, by hand
+;;
+;; Imports WASI preview2 directly: on-init prints to wasi:cli/stdout and logs
+;; the wall clock, call-custom `now` answers the Unix seconds and
+;; `read_file(path)` reads up to 512 bytes of a file under a preopened
+;; directory, answering `{"contents": ...}` or `{"error": ...}`. The path is
+;; taken verbatim from the first JSON string argument.
+(component $probe
+  (import "issun:modapi/api@0.3.0" (instance $api
+    (export "log" (func (param "message" string)))
+  ))
+  (alias export $api "log" (func $api-log))
+
+  (import "wasi:io/error@0.2.0" (instance $io-error
+    (export "error" (type (sub resource)))
+  ))
+  (alias export $io-error "error" (type $error))
+
+  (import "wasi:io/streams@0.2.0" (instance $streams
+    (export "output-stream" (type $output-stream (sub resource)))
+    (alias outer $probe $error (type $outer-error))
+    (export "error" (type $error (eq $outer-error)))
+    (type $stream-error-def (variant
+      (case "last-operation-failed" (own $error))
+      (case "closed")
+    ))
+    (export "stream-error" (type $stream-error (eq $stream-error-def)))
+    (export "[method]output-stream.blocking-write-and-flush"
+      (func (param "self" (borrow $output-stream)) (param "contents" (list u8))
+        (result (result (error $stream-error)))))
+  ))
+  (alias export $streams "output-stream" (type $output-stream))
+  (alias export $streams "[method]output-stream.blocking-write-and-flush" (func $wasi-write))
+
+  (import "wasi:cli/stdout@0.2.0" (instance $stdout
+    (alias outer $probe $output-stream (type $outer-output-stream))
+    (export "output-stream" (type $output-stream (eq $outer-output-stream)))
+    (export "get-stdout" (func (result (own $output-stream))))
+  ))
+  (alias export $stdout "get-stdout" (func $wasi-get-stdout))
+
+  (import "wasi:clocks/wall-clock@0.2.0" (instance $wall-clock
+    (type $datetime-def (record
+      (field "seconds" u64)
+      (field "nanoseconds" u32)
+    ))
+    (export "datetime" (type $datetime (eq $datetime-def)))
+    (export "now" (func (result $datetime)))
+  ))
+  (alias export $wall-clock "now" (func $wasi-now))
+
+  (import "wasi:filesystem/types@0.2.0" (instance $filesystem
+    (export "descriptor" (type $descriptor (sub resource)))
+    (type $path-flags-def (flags "symlink-follow"))
+    (export "path-flags" (type $path-flags (eq $path-flags-def)))
+    (type $open-flags-def (flags "create" "directory" "exclusive" "truncate"))
+    (export "open-flags" (type $open-flags (eq $open-flags-def)))
+    (type $descriptor-flags-def (flags
+      "read" "write" "file-integrity-sync" "data-integrity-sync"
+      "requested-write-sync" "mutate-directory"))
+    (export "descriptor-flags" (type $descriptor-flags (eq $descriptor-flags-def)))
+    (type $error-code-def (enum
+      "access" "would-block" "already" "bad-descriptor" "busy" "deadlock"
+      "quota" "exist" "file-too-large" "illegal-byte-sequence" "in-progress"
+      "interrupted" "invalid" "io" "is-directory" "loop" "too-many-links"
+      "message-size" "name-too-long" "no-device" "no-entry" "no-lock"
+      "insufficient-memory" "insufficient-space" "not-directory" "not-empty"
+      "not-recoverable" "unsupported" "no-tty" "no-such-device" "overflow"
+      "not-permitted" "pipe" "read-only" "invalid-seek" "text-file-busy"
+      "cross-device"))
+    (export "error-code" (type $error-code (eq $error-code-def)))
+    (export "[method]descriptor.open-at"
+      (func (param "self" (borrow $descriptor)) (param "path-flags" $path-flags)
+        (param "path" string) (param "open-flags" $open-flags)
+        (param "flags" $descriptor-flags)
+        (result (result (own $descriptor) (error $error-code)))))
+    (export "[method]descriptor.read"
+      (func (param "self" (borrow $descriptor)) (param "length" u64) (param "offset" u64)
+        (result (result (tuple (list u8) bool) (error $error-code)))))
+  ))
+  (alias export $filesystem "descriptor" (type $descriptor))
+  (alias export $filesystem "[method]descriptor.open-at" (func $wasi-open-at))
+  (alias export $filesystem "[method]descriptor.read" (func $wasi-read))
+
+  (import "wasi:filesystem/preopens@0.2.0" (instance $preopens
+    (alias outer $probe $descriptor (type $outer-descriptor))
+    (export "descriptor" (type $descriptor (eq $outer-descriptor)))
+    (export "get-directories" (func (result (list (tuple (own $descriptor) string)))))
+  ))
+  (alias export $preopens "get-directories" (func $wasi-get-directories))
+
+  ;; Memory and the allocator live in their own instance so the imports,
+  ;; some of which return lists, can be lowered before the main module is
+  ;; instantiated
+  (core module $memory-module
+    (memory (export "memory") 1)
+    (global $heap (mut i32) (i32.const 4096))
+
+    ;; Bump allocator for the strings and lists the host passes in
+    (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+      (local $ptr i32)
+      (local.set $ptr
+        (i32.and
+          (i32.add (global.get $heap) (i32.sub (local.get 2) (i32.const 1)))
+          (i32.sub (i32.const 0) (local.get 2))))
+      (global.set $heap (i32.add (local.get $ptr) (local.get 3)))
+      (local.get $ptr))
+  )
+  (core instance $memory-instance (instantiate $memory-module))
+  (alias core export $memory-instance "memory" (core memory $memory))
+  (alias core export $memory-instance "realloc" (core func $realloc))
+
+  (core func $log (canon lower (func $api-log) (memory $memory)))
+  (core func $get-stdout (canon lower (func $wasi-get-stdout)))
+  (core func $write (canon lower (func $wasi-write) (memory $memory)))
+  (core func $drop-stream (canon resource.drop $output-stream))
+  (core func $now (canon lower (func $wasi-now) (memory $memory)))
+  (core func $get-directories
+    (canon lower (func $wasi-get-directories) (memory $memory) (realloc $realloc)))
+  (core func $open-at (canon lower (func $wasi-open-at) (memory $memory)))
+  (core func $read (canon lower (func $wasi-read) (memory $memory) (realloc $realloc)))
+  (core func $drop-descriptor (canon resource.drop $descriptor))
+
+  (core module $main
+    (import "env" "memory" (memory 1))
+    (import "api" "log" (func $log (param i32 i32)))
+    (import "wasi" "get-stdout" (func $get-stdout (result i32)))
+    (import "wasi" "write" (func $write (param i32 i32 i32 i32)))
+    (import "wasi" "drop-stream" (func $drop-stream (param i32)))
+    (import "wasi" "now" (func $now (param i32)))
+    (import "wasi" "get-directories" (func $get-directories (param i32)))
+    (import "wasi" "open-at" (func $open-at (param i32 i32 i32 i32 i32 i32 i32)))
+    (import "wasi" "read" (func $read (param i32 i64 i64 i32)))
+    (import "wasi" "drop-descriptor" (func $drop-descriptor (param i32)))
+    ;; End of the text composed in the output buffer at 1024
+    (global $out (mut i32) (i32.const 1024))
+
+    (data (i32.const 0) "WASI Probe")
+    (data (i32.const 16) "1.0.0")
+    (data (i32.const 24) "ISSUN Team")
+    (data (i32.const 40) "Exercises WASI stdout, clocks and filesystem")
+    (data (i32.const 88) "wasi-probe: initialized\n")
+    (data (i32.const 112) "wasi-probe: shutting down\n")
+    (data (i32.const 144) "wall clock: ")
+    (data (i32.const 160) "now")
+    (data (i32.const 168) "read_file")
+    (data (i32.const 184) "{\"error\":\"Unknown function\"}")
+    (data (i32.const 216) "{\"contents\":\"")
+    (data (i32.const 232) "{\"error\":\"")
+    (data (i32.const 248) "no preopened directory")
+    (data (i32.const 272) "error code ")
+    ;; metadata: name (0, 10), version (16, 5), author some (24, 10),
+    ;; description some (40, 44)
+    (data (i32.const 288)
+      "\00\00\00\00\0a\00\00\00\10\00\00\00\05\00\00\00"
+      "\01\00\00\00\18\00\00\00\0a\00\00\00"
+      "\01\00\00\00\28\00\00\00\2c\00\00\00")
+    ;; call-custom results are (pointer, length) at 328; WASI calls return
+    ;; through 336
+
+    (func $bytes-eq (param $a i32) (param $a-len i32) (param $b i32) (param $b-len i32) (result i32)
+      (if (i32.ne (local.get $a-len) (local.get $b-len))
+        (then (return (i32.const 0))))
+      (block $done
+        (loop $next
+          (br_if $done (i32.eqz (local.get $a-len)))
+          (if (i32.ne (i32.load8_u (local.get $a)) (i32.load8_u (local.get $b)))
+            (then (return (i32.const 0))))
+          (local.set $a (i32.add (local.get $a) (i32.const 1)))
+          (local.set $b (i32.add (local.get $b) (i32.const 1)))
+          (local.set $a-len (i32.sub (local.get $a-len) (i32.const 1)))
+          (br $next)))
+      (i32.const 1))
+
+    ;; Address of the first `byte` in [ptr, end), `end` if none
+    (func $find-byte (param $ptr i32) (param $end i32) (param $byte i32) (result i32)
+      (block $found
+        (loop $next
+          (br_if $found (i32.ge_u (local.get $ptr) (local.get $end)))
+          (br_if $found (i32.eq (i32.load8_u (local.get $ptr)) (local.get $byte)))
+          (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+          (br $next)))
+      (local.get $ptr))
+
+    (func $emit (param $ptr i32) (param $len i32)
+      (memory.copy (global.get $out) (local.get $ptr) (local.get $len))
+      (global.set $out (i32.add (global.get $out) (local.get $len))))
+
+    (func $emit-byte (param $byte i32)
+      (i32.store8 (global.get $out) (local.get $byte))
+      (global.set $out (i32.add (global.get $out) (i32.const 1))))
+
+    (func $emit-uint (param $value i64)
+      (if (i64.ge_u (local.get $value) (i64.const 10))
+        (then (call $emit-uint (i64.div_u (local.get $value) (i64.const 10)))))
+      (call $emit-byte
+        (i32.add (i32.const 48) (i32.wrap_i64 (i64.rem_u (local.get $value) (i64.const 10))))))
+
+    (func $emit-hex-digit (param $digit i32)
+      (call $emit-byte
+        (i32.add (local.get $digit)
+          (select (i32.const 48) (i32.const 87) (i32.lt_u (local.get $digit) (i32.const 10))))))
+
+    ;; Bytes as the inside of a JSON string
+    (func $emit-escaped (param $ptr i32) (param $len i32)
+      (local $byte i32)
+      (block $done
+        (loop $next
+          (br_if $done (i32.eqz (local.get $len)))
+          (local.set $byte (i32.load8_u (local.get $ptr)))
+          (if (i32.lt_u (local.get $byte) (i32.const 32))
+            (then
+              (call $emit-byte (i32.const 92))
+              (call $emit-byte (i32.const 117))
+              (call $emit-byte (i32.const 48))
+              (call $emit-byte (i32.const 48))
+              (call $emit-hex-digit (i32.shr_u (local.get $byte) (i32.const 4)))
+              (call $emit-hex-digit (i32.and (local.get $byte) (i32.const 15))))
+            (else
+              (if (i32.or (i32.eq (local.get $byte) (i32.const 34)) (i32.eq (local.get $byte) (i32.const 92)))
+                (then (call $emit-byte (i32.const 92))))
+              (call $emit-byte (local.get $byte))))
+          (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+          (local.set $len (i32.sub (local.get $len) (i32.const 1)))
+          (br $next))))
+
+    (func $output (result i32)
+      (i32.store (i32.const 328) (i32.const 1024))
+      (i32.store (i32.const 332) (i32.sub (global.get $out) (i32.const 1024)))
+      (i32.const 328))
+
+    ;; {"error":"error code <n>"} for a wasi:filesystem error-code
+    (func $error-code (param $code i32) (result i32)
+      (global.set $out (i32.const 1024))
+      (call $emit (i32.const 232) (i32.const 10))
+      (call $emit (i32.const 272) (i32.const 11))
+      (call $emit-uint (i64.extend_i32_u (local.get $code)))
+      (call $emit-byte (i32.const 34))
+      (call $emit-byte (i32.const 125))
+      (call $output))
+
+    (func $print (param $ptr i32) (param $len i32)
+      (local $stream i32)
+      (local.set $stream (call $get-stdout))
+      (call $write (local.get $stream) (local.get $ptr) (local.get $len) (i32.const 336))
+      (call $drop-stream (local.get $stream)))
+
+    (func $unix-secs (result i64)
+      (call $now (i32.const 336))
+      (i64.load (i32.const 336)))
+
+    (func $read-file (param $path i32) (param $path-len i32) (result i32)
+      (local $entry i32) (local $entries-end i32) (local $name-len i32)
+      (local $dir i32) (local $prefix-len i32) (local $file i32)
+      (local.set $dir (i32.const -1))
+
+      ;; Keep the first preopen whose name prefixes the path, drop the others
+      (call $get-directories (i32.const 336))
+      (local.set $entry (i32.load (i32.const 336)))
+      (local.set $entries-end
+        (i32.add (local.get $entry) (i32.mul (i32.load (i32.const 340)) (i32.const 12))))
+      (block $done
+        (loop $next
+          (br_if $done (i32.ge_u (local.get $entry) (local.get $entries-end)))
+          (local.set $name-len (i32.load offset=8 (local.get $entry)))
+          (if (i32.and
+                (i32.eq (local.get $dir) (i32.const -1))
+                (i32.lt_u (local.get $name-len) (local.get $path-len)))
+            (then
+              (if (i32.and
+                    (call $bytes-eq (local.get $path) (local.get $name-len)
+                      (i32.load offset=4 (local.get $entry)) (local.get $name-len))
+                    (i32.eq (i32.load8_u (i32.add (local.get $path) (local.get $name-len))) (i32.const 47)))
+                (then
+                  (local.set $dir (i32.load (local.get $entry)))
+                  (local.set $prefix-len (local.get $name-len))))))
+          (if (i32.ne (local.get $dir) (i32.load (local.get $entry)))
+            (then (call $drop-descriptor (i32.load (local.get $entry)))))
+          (local.set $entry (i32.add (local.get $entry) (i32.const 12)))
+          (br $next)))
+      (if (i32.eq (local.get $dir) (i32.const -1))
+        (then
+          (global.set $out (i32.const 1024))
+          (call $emit (i32.const 232) (i32.const 10))
+          (call $emit (i32.const 248) (i32.const 22))
+          (call $emit-byte (i32.const 34))
+          (call $emit-byte (i32.const 125))
+          (return (call $output))))
+
+      ;; symlink-follow, no open flags, read
+      (call $open-at (local.get $dir) (i32.const 1)
+        (i32.add (local.get $path) (i32.add (local.get $prefix-len) (i32.const 1)))
+        (i32.sub (local.get $path-len) (i32.add (local.get $prefix-len) (i32.const 1)))
+        (i32.const 0) (i32.const 1) (i32.const 336))
+      (call $drop-descriptor (local.get $dir))
+      (if (i32.load8_u (i32.const 336))
+        (then (return (call $error-code (i32.load8_u (i32.const 340))))))
+      (local.set $file (i32.load (i32.const 340)))
+
+      (call $read (local.get $file) (i64.const 512) (i64.const 0) (i32.const 336))
+      (call $drop-descriptor (local.get $file))
+      (if (i32.load8_u (i32.const 336))
+        (then (return (call $error-code (i32.load8_u (i32.const 340))))))
+      (global.set $out (i32.const 1024))
+      (call $emit (i32.const 216) (i32.const 13))
+      (call $emit-escaped (i32.load (i32.const 340)) (i32.load (i32.const 344)))
+      (call $emit-byte (i32.const 34))
+      (call $emit-byte (i32.const 125))
+      (call $output))
+
+    (func (export "get-metadata") (result i32)
+      i32.const 288)
+    (func (export "on-init")
+      (call $print (i32.const 88) (i32.const 24))
+      (global.set $out (i32.const 1024))
+      (call $emit (i32.const 144) (i32.const 12))
+      (call $emit-uint (call $unix-secs))
+      (call $log (i32.const 1024) (i32.sub (global.get $out) (i32.const 1024))))
+    (func (export "on-shutdown")
+      (call $print (i32.const 112) (i32.const 26)))
+    (func (export "on-control-plugin") (param i32 i32 i32 i32))
+    (func (export "on-event") (param i32 i32 i32 i32))
+    (func (export "call-custom") (param $name i32) (param $name-len i32) (param $args i32) (param $args-len i32) (result i32)
+      (local $end i32) (local $path i32)
+      (local.set $end (i32.add (local.get $args) (local.get $args-len)))
+
+      (if (call $bytes-eq (local.get $name) (local.get $name-len) (i32.const 160) (i32.const 3))
+        (then
+          (global.set $out (i32.const 1024))
+          (call $emit-uint (call $unix-secs))
+          (return (call $output))))
+
+      (if (call $bytes-eq (local.get $name) (local.get $name-len) (i32.const 168) (i32.const 9))
+        (then
+          (local.set $path
+            (i32.add (call $find-byte (local.get $args) (local.get $end) (i32.const 34)) (i32.const 1)))
+          (if (i32.gt_u (local.get $path) (local.get $end))
+            (then (local.set $path (local.get $end))))
+          (return
+            (call $read-file (local.get $path)
+              (i32.sub (call $find-byte (local.get $path) (local.get $end) (i32.const 34)) (local.get $path))))))
+
+      (i32.store (i32.const 328) (i32.const 184))
+      (i32.store (i32.const 332) (i32.const 28))
+      (i32.const 328))
+  )
+  (core instance $main-instance (instantiate $main
+    (with "env" (instance $memory-instance))
+    (with "api" (instance
+      (export "log" (func $log))
+    ))
+    (with "wasi" (instance
+      (export "get-stdout" (func $get-stdout))
+      (export "write" (func $write))
+      (export "drop-stream" (func $drop-stream))
+      (export "now" (func $now))
+      (export "get-directories" (func $get-directories))
+      (export "open-at" (func $open-at))
+      (export "read" (func $read))
+      (export "drop-descriptor" (func $drop-descriptor))
+    ))
+  ))
+
+  (type $metadata-def (record
+    (field "name" string)
+    (field "version" string)
+    (field "author" (option string))
+    (field "description" (option string))
+  ))
+  (export $metadata "metadata" (type $metadata-def))
+
+  (func (export "get-metadata") (result $metadata)
+    (canon lift (core func $main-instance "get-metadata") (memory $memory)))
+  (func (export "on-init")
+    (canon lift (core func $main-instance "on-init")))
+  (func (export "on-shutdown")
+    (canon lift (core func $main-instance "on-shutdown")))
+  (func (export "on-control-plugin") (param "plugin-name" string) (param "action" string)
+    (canon lift (core func $main-instance "on-control-plugin")
+      (memory $memory) (realloc $realloc)))
+  (func (export "on-event") (param "event-type" string) (param "payload-json" string)
+    (canon lift (core func $main-instance "on-event")
+      (memory $memory) (realloc $realloc)))
+  (func (export "call-custom") (param "fn-name" string) (param "args-json" string) (result string)
+    (canon lift (core func $main-instance "call-custom")
+      (memory $memory) (realloc $realloc)))
+)
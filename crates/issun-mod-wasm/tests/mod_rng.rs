@@ -0,0 +1,62 @@
+//! With a master seed, `random-int` draws from the MOD's own stream
+
+use issun::engine::rng::MasterSeed;
+use issun::modding::{ModHandle, ModLoader, ModRng};
+use issun_mod_wasm::WasmLoader;
+use std::path::Path;
+
+const API_0_3: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/api_0_3_mod.wat"
+);
+
+fn seeded_loader(master: u64) -> (WasmLoader, ModHandle) {
+    let mut loader = WasmLoader::new().unwrap();
+    loader.set_master_seed(Some(MasterSeed(master)));
+    let handle = loader.load(Path::new(API_0_3)).unwrap();
+    (loader, handle)
+}
+
+fn rolls(loader: &mut WasmLoader, handle: &ModHandle, times: usize) -> Vec<i64> {
+    (0..times)
+        .map(|_| {
+            loader
+                .call_function(handle, "roll", vec![])
+                .unwrap()
+                .as_i64()
+                .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_same_seed_rolls_the_same() {
+    let (mut first, handle) = seeded_loader(42);
+    let (mut second, _) = seeded_loader(42);
+    let expected = rolls(&mut first, &handle, 8);
+    assert_eq!(expected, rolls(&mut second, &handle, 8));
+
+    let mut stream = ModRng::for_mod(MasterSeed(42), "api_0_3_mod");
+    let direct: Vec<i64> = (0..8).map(|_| stream.int(1, 6)).collect();
+    assert_eq!(expected, direct);
+}
+
+#[test]
+fn test_positions_continue_after_import_and_unload() {
+    let (mut reference, handle) = seeded_loader(42);
+    let expected = rolls(&mut reference, &handle, 6);
+
+    let (mut loader, _) = seeded_loader(42);
+    assert_eq!(rolls(&mut loader, &handle, 2), expected[..2]);
+    let positions = loader.export_rng();
+
+    // Resumed from a save
+    let (mut resumed, _) = seeded_loader(42);
+    resumed.import_rng(positions);
+    assert_eq!(rolls(&mut resumed, &handle, 2), expected[2..4]);
+
+    // Loaded again after an unload
+    resumed.unload(&handle).unwrap();
+    let handle = resumed.load(Path::new(API_0_3)).unwrap();
+    assert_eq!(rolls(&mut resumed, &handle, 2), expected[4..]);
+}
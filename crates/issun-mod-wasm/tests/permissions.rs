@@ -0,0 +1,73 @@
+//! Plugin control and publish-event go through the MOD's permissions
+
+use issun::modding::{ModLoader, ModPermissionPolicy, ModPermissions};
+use issun_mod_wasm::WasmLoader;
+use std::path::Path;
+
+const PERMISSIONS_MOD: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/permissions_mod.wat"
+);
+
+/// Plugins controlled, event types published and refused actions of the
+/// fixture's `on_init` under `defaults`
+fn run_with(defaults: ModPermissions) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let mut loader = WasmLoader::new().unwrap();
+    loader.set_permission_policy(ModPermissionPolicy {
+        defaults,
+        trusted_keys: Vec::new(),
+    });
+    loader.load(Path::new(PERMISSIONS_MOD)).unwrap();
+
+    let plugins = loader
+        .drain_commands()
+        .into_iter()
+        .map(|command| command.plugin_name)
+        .collect();
+    let events = loader
+        .drain_events()
+        .into_iter()
+        .map(|(event_type, _)| event_type)
+        .collect();
+    let denials = loader.drain_denials();
+    assert!(denials
+        .iter()
+        .all(|denied| denied.mod_id == "permissions_mod"));
+    let actions = denials.into_iter().map(|denied| denied.action).collect();
+    (plugins, events, actions)
+}
+
+#[test]
+fn test_default_permissions_allow_everything() {
+    let (plugins, events, denied) = run_with(ModPermissions::all());
+    assert_eq!(plugins, ["combat", "save_load"]);
+    assert_eq!(events, ["loot:dropped"]);
+    assert!(denied.is_empty());
+}
+
+#[test]
+fn test_denied_calls_queue_nothing() {
+    let (plugins, events, denied) = run_with(ModPermissions::none());
+    assert!(plugins.is_empty());
+    assert!(events.is_empty());
+    assert_eq!(
+        denied,
+        [
+            "enable plugin 'combat'",
+            "disable plugin 'save_load'",
+            "publish event 'loot:dropped'",
+        ]
+    );
+}
+
+#[test]
+fn test_wildcard_permissions() {
+    let (plugins, events, denied) = run_with(
+        ModPermissions::none()
+            .with_plugin("issun:comb*")
+            .with_event("loot:*"),
+    );
+    assert_eq!(plugins, ["combat"]);
+    assert_eq!(events, ["loot:dropped"]);
+    assert_eq!(denied, ["disable plugin 'save_load'"]);
+}
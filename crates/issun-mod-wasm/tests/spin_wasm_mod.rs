@@ -0,0 +1,152 @@
+//! The spin-wasm-mod component runs away on purpose; WasmLoaderConfig
+//! budgets turn its calls into errors instead of hanging the host, and async
+//! loaders let other tasks run while it spins
+
+use issun::modding::{ModError, ModLoader};
+use issun_mod_wasm::{WasmLoader, WasmLoaderConfig};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+mod common;
+
+fn fixture() -> PathBuf {
+    common::fixture("spin_wasm_mod.wat")
+}
+
+/// Run `fn_name` on a freshly loaded spin MOD, timing the call
+fn call(
+    config: WasmLoaderConfig,
+    fn_name: &str,
+    args: Vec<serde_json::Value>,
+) -> (Result<serde_json::Value, ModError>, Duration) {
+    let mut loader = WasmLoader::with_config(config).unwrap();
+    let handle = loader.load(&fixture()).unwrap();
+
+    // Well-behaved calls fit the budget, every time
+    for _ in 0..3 {
+        assert_eq!(
+            loader.call_function(&handle, "ping", Vec::new()).unwrap(),
+            serde_json::json!("pong")
+        );
+    }
+
+    let started = Instant::now();
+    let result = loader.call_function(&handle, fn_name, args);
+    (result, started.elapsed())
+}
+
+#[test]
+fn test_fuel_budget_stops_spinning_guest() {
+    let config = WasmLoaderConfig {
+        fuel_per_call: Some(10_000_000),
+        epoch_timeout_ms: None,
+        ..WasmLoaderConfig::default()
+    };
+    let (result, elapsed) = call(config, "spin", Vec::new());
+
+    match result {
+        Err(ModError::ExecutionFailed(message)) => {
+            assert_eq!(message, "MOD 'spin_wasm_mod' exceeded fuel budget")
+        }
+        other => panic!("expected fuel exhaustion, got {:?}", other),
+    }
+    assert!(elapsed < Duration::from_secs(5), "took {:?}", elapsed);
+}
+
+#[test]
+fn test_epoch_timeout_stops_spinning_guest() {
+    let config = WasmLoaderConfig {
+        fuel_per_call: None,
+        epoch_timeout_ms: Some(200),
+        ..WasmLoaderConfig::default()
+    };
+    let (result, elapsed) = call(config, "spin", Vec::new());
+
+    match result {
+        Err(ModError::ExecutionFailed(message)) => {
+            assert!(
+                message.contains("exceeded time budget (200 ms)"),
+                "{}",
+                message
+            )
+        }
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+    assert!(elapsed >= Duration::from_millis(150), "took {:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(2), "took {:?}", elapsed);
+}
+
+#[test]
+fn test_memory_limit_fails_large_allocations() {
+    let config = WasmLoaderConfig {
+        max_memory_bytes: 16 * 1024 * 1024,
+        ..WasmLoaderConfig::default()
+    };
+    let (result, _) = call(config, "allocate", vec![serde_json::json!(1)]);
+    assert_eq!(
+        result.unwrap(),
+        serde_json::json!({ "allocated": 1024 * 1024 })
+    );
+
+    let (result, _) = call(config, "allocate", vec![serde_json::json!(32)]);
+    assert!(result.is_err(), "{:?}", result);
+}
+
+#[tokio::test]
+async fn test_async_call_yields_to_other_tasks() {
+    let config = WasmLoaderConfig {
+        fuel_per_call: None,
+        epoch_timeout_ms: Some(300),
+        ..WasmLoaderConfig::default()
+    };
+    let mut loader = WasmLoader::with_config_async(config).unwrap();
+    let handle = loader.load_async(&fixture()).await.unwrap();
+    assert_eq!(
+        loader
+            .call_function_async(&handle, "ping", Vec::new())
+            .await
+            .unwrap(),
+        serde_json::json!("pong")
+    );
+
+    // Same thread as the spinning guest (current-thread runtime)
+    let ticks = tokio::spawn(async {
+        let mut ticks = 0;
+        loop {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            ticks += 1;
+            if ticks == 5 {
+                return ticks;
+            }
+        }
+    });
+
+    let started = Instant::now();
+    let result = loader
+        .call_function_async(&handle, "spin", Vec::new())
+        .await;
+    match result {
+        Err(ModError::ExecutionFailed(message)) => {
+            assert!(message.contains("exceeded time budget"), "{}", message)
+        }
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+    assert!(
+        started.elapsed() >= Duration::from_millis(250),
+        "took {:?}",
+        started.elapsed()
+    );
+    assert!(ticks.is_finished(), "the ticker task never ran");
+    assert_eq!(ticks.await.unwrap(), 5);
+}
+
+#[test]
+fn test_sync_calls_work_on_async_loader() {
+    let mut loader = WasmLoader::new_async().unwrap();
+    let handle = loader.load(&fixture()).unwrap();
+    assert_eq!(
+        loader.call_function(&handle, "ping", Vec::new()).unwrap(),
+        serde_json::json!("pong")
+    );
+    loader.unload(&handle).unwrap();
+}
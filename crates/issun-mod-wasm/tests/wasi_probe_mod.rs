@@ -0,0 +1,83 @@
+//! The wasi-probe-mod component uses WASI stdout, clocks and preopened
+//! directories without panicking the host
+
+use issun::modding::ModLoader;
+use issun_mod_wasm::{DirPerms, FilePerms, WasmLoader};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+mod common;
+
+fn fixture() -> PathBuf {
+    common::fixture("wasi_probe_mod.wat")
+}
+
+#[test]
+fn test_stdout_and_wall_clock() {
+    // on_init prints to stdout and reads the clock
+    let mut loader = WasmLoader::new().unwrap();
+    let handle = loader.load(&fixture()).unwrap();
+
+    let now = loader
+        .call_function(&handle, "now", Vec::new())
+        .unwrap()
+        .as_u64()
+        .unwrap();
+    let host_now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    assert!(
+        host_now.abs_diff(now) < 60,
+        "guest {} host {}",
+        now,
+        host_now
+    );
+
+    loader.unload(&handle).unwrap();
+}
+
+#[test]
+fn test_filesystem_needs_a_preopened_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("greeting.txt"), "hello mod").unwrap();
+    let read = |loader: &mut WasmLoader| {
+        let handle = loader.load(&fixture()).unwrap();
+        loader
+            .call_function(
+                &handle,
+                "read_file",
+                vec![serde_json::json!("/data/greeting.txt")],
+            )
+            .unwrap()
+    };
+
+    // Not granted: the guest sees an error instead of the host filesystem
+    let mut sandboxed = WasmLoader::new().unwrap();
+    assert!(read(&mut sandboxed).get("error").is_some());
+
+    let host_dir = dir.path().to_path_buf();
+    let mut granted = WasmLoader::new().unwrap().with_wasi(move |builder| {
+        builder.preopened_dir(&host_dir, "/data", DirPerms::READ, FilePerms::READ)?;
+        Ok(())
+    });
+    assert_eq!(
+        read(&mut granted),
+        serde_json::json!({ "contents": "hello mod" })
+    );
+}
+
+#[test]
+fn test_wasi_configuration_error_fails_the_load() {
+    let mut loader = WasmLoader::new().unwrap().with_wasi(|builder| {
+        builder.preopened_dir(
+            "/nonexistent/issun-mod-wasm",
+            "/data",
+            DirPerms::READ,
+            FilePerms::READ,
+        )?;
+        Ok(())
+    });
+
+    assert!(loader.load(&fixture()).is_err());
+}
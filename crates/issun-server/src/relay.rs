@@ -5,7 +5,7 @@ use crate::connection::ClientConnection;
 use crate::metrics::SharedMetrics;
 use crate::room::RoomManager;
 use anyhow::Result;
-use issun::network::{backend::RawNetworkEvent, NodeId};
+use issun::network::{backend::RawNetworkEvent, NodeId, NAMESPACE_JOIN};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
@@ -202,26 +202,35 @@ impl RelayServer {
     /// Relay an event to all other clients (room-aware)
     async fn relay_event(
         from: NodeId,
-        event: RawNetworkEvent,
+        mut event: RawNetworkEvent,
         clients: &Arc<RwLock<HashMap<NodeId, ClientConnection>>>,
         room_manager: &Arc<RoomManager>,
         metrics: &SharedMetrics,
     ) {
         let relay_start = Instant::now();
+
+        // A namespace maps to the connections that sent frames for it
+        if let Some(namespace) = &event.metadata.namespace {
+            room_manager.join_namespace(namespace, from).await;
+            if event.type_name == NAMESPACE_JOIN {
+                return;
+            }
+        }
+
         let clients_guard = clients.read().await;
 
         let target_clients: Vec<_> = match event.scope {
             issun::network::NetworkScope::Broadcast => {
-                // Check if sender is in a room
-                if let Some(_room_id) = room_manager.get_client_room(from).await {
-                    // Room-scoped broadcast: send to all clients in the same room except sender
+                if let Some(namespace) = &event.metadata.namespace {
+                    // Namespace-scoped broadcast: the other connections of the match
+                    debug!("Namespace {} broadcast from {:?}", namespace, from);
+                    room_manager.namespace_broadcast(namespace, from).await
+                } else if let Some(broadcast) = room_manager.room_broadcast(from).await {
+                    // Room-scoped broadcast: send to the other clients in the room;
+                    // ordered rooms stamp the event and echo it to the sender
                     debug!("Room-scoped broadcast from {:?}", from);
-                    room_manager
-                        .get_room_clients(from)
-                        .await
-                        .into_iter()
-                        .filter(|id| *id != from)
-                        .collect()
+                    event.metadata.relay = broadcast.stamp;
+                    broadcast.targets
                 } else {
                     // Global broadcast: send to all clients except sender (not in any room)
                     debug!("Global broadcast from {:?}", from);
@@ -1,7 +1,7 @@
 //! Room/Lobby system for organizing multiplayer games
 
 use anyhow::Result;
-use issun::network::NodeId;
+use issun::network::{NodeId, RelaySequencer, RelayStamp};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -74,6 +74,42 @@ pub struct Room {
     /// Creation timestamp
     #[allow(dead_code)]
     pub created_at: std::time::SystemTime,
+
+    /// Stamps broadcasts in ordered rooms (`None`: relayed in arrival order)
+    pub sequencer: Option<RelaySequencer>,
+}
+
+/// Settings of a room at creation
+#[derive(Debug, Clone)]
+pub struct RoomOptions {
+    /// Maximum number of clients allowed
+    pub max_clients: usize,
+
+    /// Room name (optional)
+    pub name: Option<String>,
+
+    /// Sequence every broadcast so all clients observe the same order
+    pub ordered: bool,
+}
+
+impl Default for RoomOptions {
+    fn default() -> Self {
+        Self {
+            max_clients: 4,
+            name: None,
+            ordered: false,
+        }
+    }
+}
+
+/// Recipients of a broadcast within a room
+#[derive(Debug, Clone)]
+pub struct RoomBroadcast {
+    /// Clients to forward the event to
+    pub targets: Vec<NodeId>,
+
+    /// Stamp to attach to the event (ordered rooms only)
+    pub stamp: Option<RelayStamp>,
 }
 
 impl Room {
@@ -92,9 +128,16 @@ impl Room {
             host,
             metadata: HashMap::new(),
             created_at: std::time::SystemTime::now(),
+            sequencer: None,
         }
     }
 
+    /// Whether broadcasts in this room are sequenced by the relay
+    #[allow(dead_code)]
+    pub fn is_ordered(&self) -> bool {
+        self.sequencer.is_some()
+    }
+
     /// Check if room is full
     pub fn is_full(&self) -> bool {
         self.clients.len() >= self.max_clients
@@ -155,6 +198,10 @@ pub struct RoomManager {
 
     /// Client to room mapping
     client_rooms: Arc<RwLock<HashMap<NodeId, RoomId>>>,
+
+    /// Members of each bus namespace; unlike rooms, a client can be in
+    /// many, so one connection can carry several matches
+    namespaces: Arc<RwLock<HashMap<String, HashSet<NodeId>>>>,
 }
 
 impl RoomManager {
@@ -163,6 +210,7 @@ impl RoomManager {
         Self {
             rooms: Arc::new(RwLock::new(HashMap::new())),
             client_rooms: Arc::new(RwLock::new(HashMap::new())),
+            namespaces: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -174,6 +222,20 @@ impl RoomManager {
         max_clients: usize,
         name: Option<String>,
     ) -> Result<RoomId> {
+        self.create_room_with(
+            host,
+            RoomOptions {
+                max_clients,
+                name,
+                ordered: false,
+            },
+        )
+        .await
+    }
+
+    /// Create a new room with the given options
+    #[allow(dead_code)]
+    pub async fn create_room_with(&self, host: NodeId, options: RoomOptions) -> Result<RoomId> {
         // Check if host is already in a room
         {
             let client_rooms = self.client_rooms.read().await;
@@ -182,8 +244,11 @@ impl RoomManager {
             }
         }
 
-        let mut room = Room::new(host, max_clients);
-        room.name = name;
+        let mut room = Room::new(host, options.max_clients);
+        room.name = options.name;
+        if options.ordered {
+            room.sequencer = Some(RelaySequencer::new());
+        }
         let room_id = room.id;
 
         // Add room and update mappings
@@ -197,7 +262,10 @@ impl RoomManager {
             client_rooms.insert(host, room_id);
         }
 
-        info!("Room created: {} by host {:?}", room_id, host);
+        info!(
+            "Room created: {} by host {:?} (ordered: {})",
+            room_id, host, options.ordered
+        );
         Ok(room_id)
     }
 
@@ -275,6 +343,7 @@ impl RoomManager {
     }
 
     /// Get clients in the same room as the given client
+    #[allow(dead_code)]
     pub async fn get_room_clients(&self, client: NodeId) -> Vec<NodeId> {
         let room_id = {
             let client_rooms = self.client_rooms.read().await;
@@ -291,6 +360,51 @@ impl RoomManager {
             .unwrap_or_default()
     }
 
+    /// Recipients of a broadcast from `client`, if it is in a room
+    ///
+    /// Ordered rooms stamp the broadcast with the room's next sequence
+    /// number and include the sender, so it sees its own events in room
+    /// order too. Sequences are independent per room.
+    pub async fn room_broadcast(&self, client: NodeId) -> Option<RoomBroadcast> {
+        let room_id = self.get_client_room(client).await?;
+
+        let mut rooms = self.rooms.write().await;
+        let room = rooms.get_mut(&room_id)?;
+        let stamp = room.sequencer.as_mut().map(RelaySequencer::stamp);
+        let targets = room
+            .clients
+            .iter()
+            .copied()
+            .filter(|id| *id != client || stamp.is_some())
+            .collect();
+
+        Some(RoomBroadcast { targets, stamp })
+    }
+
+    /// Add `client` to the members of `namespace`
+    ///
+    /// Namespaces are joined implicitly by the first frame a client sends
+    /// for them (a bus announces its namespace when it connects).
+    pub async fn join_namespace(&self, namespace: &str, client: NodeId) {
+        let mut namespaces = self.namespaces.write().await;
+        if namespaces
+            .entry(namespace.to_string())
+            .or_default()
+            .insert(client)
+        {
+            debug!("Client {:?} joined namespace {}", client, namespace);
+        }
+    }
+
+    /// Recipients of a broadcast from `client` in `namespace`: the other members
+    pub async fn namespace_broadcast(&self, namespace: &str, client: NodeId) -> Vec<NodeId> {
+        let namespaces = self.namespaces.read().await;
+        namespaces
+            .get(namespace)
+            .map(|members| members.iter().copied().filter(|id| *id != client).collect())
+            .unwrap_or_default()
+    }
+
     /// List all available rooms
     #[allow(dead_code)]
     pub async fn list_rooms(&self) -> Vec<Room> {
@@ -336,6 +450,13 @@ impl RoomManager {
 
     /// Clean up rooms for a disconnected client
     pub async fn handle_disconnect(&self, client: NodeId) {
+        {
+            let mut namespaces = self.namespaces.write().await;
+            for members in namespaces.values_mut() {
+                members.remove(&client);
+            }
+            namespaces.retain(|_, members| !members.is_empty());
+        }
         if let Err(e) = self.leave_room(client).await {
             warn!("Failed to remove disconnected client from room: {}", e);
         }
@@ -401,6 +522,120 @@ mod tests {
         assert!(manager.join_room(room_id, client3).await.is_err());
     }
 
+    #[tokio::test]
+    async fn test_ordered_rooms_sequence_independently() {
+        let manager = RoomManager::new();
+        let (a1, a2, b1) = (
+            NodeId::from_u64(1),
+            NodeId::from_u64(2),
+            NodeId::from_u64(3),
+        );
+
+        let ordered = RoomOptions {
+            ordered: true,
+            ..RoomOptions::default()
+        };
+        let room_a = manager.create_room_with(a1, ordered.clone()).await.unwrap();
+        manager.join_room(room_a, a2).await.unwrap();
+        manager.create_room_with(b1, ordered).await.unwrap();
+        assert!(manager.get_room(room_a).await.unwrap().is_ordered());
+
+        let first = manager.room_broadcast(a1).await.unwrap();
+        let second = manager.room_broadcast(a2).await.unwrap();
+        let other_room = manager.room_broadcast(b1).await.unwrap();
+
+        assert_eq!(first.stamp.unwrap().sequence, 0);
+        assert_eq!(second.stamp.unwrap().sequence, 1);
+        assert_eq!(other_room.stamp.unwrap().sequence, 0);
+
+        // The sender gets its own events back, in room order
+        let mut targets = first.targets;
+        targets.sort_by_key(|id| id.as_u64());
+        assert_eq!(targets, [a1, a2]);
+    }
+
+    #[tokio::test]
+    async fn test_ordered_room_sequence_wraps_around() {
+        let manager = RoomManager::new();
+        let host = NodeId::from_u64(1);
+        let room_id = manager
+            .create_room_with(
+                host,
+                RoomOptions {
+                    ordered: true,
+                    ..RoomOptions::default()
+                },
+            )
+            .await
+            .unwrap();
+        manager
+            .rooms
+            .write()
+            .await
+            .get_mut(&room_id)
+            .unwrap()
+            .sequencer = Some(RelaySequencer::starting_at(u32::MAX));
+
+        let last = manager.room_broadcast(host).await.unwrap().stamp.unwrap();
+        let wrapped = manager.room_broadcast(host).await.unwrap().stamp.unwrap();
+        assert_eq!((last.sequence, wrapped.sequence), (u32::MAX, 0));
+    }
+
+    #[tokio::test]
+    async fn test_unordered_room_broadcast_excludes_sender() {
+        let manager = RoomManager::new();
+        let (host, client) = (NodeId::from_u64(1), NodeId::from_u64(2));
+        let room_id = manager.create_room(host, 4, None).await.unwrap();
+        manager.join_room(room_id, client).await.unwrap();
+
+        let broadcast = manager.room_broadcast(host).await.unwrap();
+        assert!(broadcast.stamp.is_none());
+        assert_eq!(broadcast.targets, [client]);
+        assert!(manager.room_broadcast(NodeId::from_u64(9)).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_namespace_broadcast_reaches_other_members_only() {
+        let manager = RoomManager::new();
+        let (server, player, other) = (
+            NodeId::from_u64(1),
+            NodeId::from_u64(2),
+            NodeId::from_u64(3),
+        );
+        // One connection takes part in two matches
+        manager.join_namespace("match-1", server).await;
+        manager.join_namespace("match-2", server).await;
+        manager.join_namespace("match-1", player).await;
+        manager.join_namespace("match-2", other).await;
+
+        assert_eq!(
+            manager.namespace_broadcast("match-1", server).await,
+            [player]
+        );
+        assert_eq!(
+            manager.namespace_broadcast("match-2", server).await,
+            [other]
+        );
+        assert_eq!(
+            manager.namespace_broadcast("match-1", player).await,
+            [server]
+        );
+        assert!(manager
+            .namespace_broadcast("match-3", server)
+            .await
+            .is_empty());
+
+        manager.handle_disconnect(server).await;
+        assert!(manager
+            .namespace_broadcast("match-1", player)
+            .await
+            .is_empty());
+        assert!(manager
+            .namespace_broadcast("match-2", other)
+            .await
+            .is_empty());
+    }
+
     #[tokio::test]
     async fn test_list_rooms() {
         let manager = RoomManager::new();
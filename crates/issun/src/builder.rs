@@ -1,12 +1,20 @@
 //! Game builder for ISSUN
 
 use crate::context::ResourceContext;
+use crate::engine::config_file::{ConfigFileOptions, ConfigReloadSystem, ConfigSection};
+use crate::engine::crash::{CrashReportConfig, CrashReporter};
+use crate::engine::flags::{read_flags_file, FeatureFlag, FeatureFlagSystem, FeatureFlags};
+use crate::engine::lifecycle::PluginLifecycle;
 use crate::error::{IssunError, Result};
 use crate::plugin::{Plugin, PluginBuilder};
+use crate::scene::{ResetHandler, ResetRegistry};
 use crate::service::Service;
 use crate::system::System;
+use serde::Serialize;
 use std::any::TypeId;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
 
 /// Game builder for composing plugins and configuring the game
 pub struct GameBuilder {
@@ -15,6 +23,11 @@ pub struct GameBuilder {
     runtime_resources: HashMap<TypeId, Box<dyn RuntimeResourceEntry>>,
     extra_services: Vec<Box<dyn Service>>,
     extra_systems: Vec<Box<dyn System>>,
+    reset_handlers: ResetRegistry,
+    crash_reporter: Option<CrashReportConfig>,
+    flags: Vec<(String, bool)>,
+    flags_file: Option<PathBuf>,
+    config_file: Option<(PathBuf, ConfigFileOptions)>,
 }
 
 impl GameBuilder {
@@ -26,6 +39,11 @@ impl GameBuilder {
             runtime_resources: HashMap::new(),
             extra_services: Vec::new(),
             extra_systems: Vec::new(),
+            reset_handlers: ResetRegistry::new(),
+            crash_reporter: None,
+            flags: Vec::new(),
+            flags_file: None,
+            config_file: None,
         }
     }
 
@@ -55,6 +73,16 @@ impl GameBuilder {
         self
     }
 
+    /// Register a mutable runtime resource built by its [`ResourceInit`]
+    ///
+    /// [`ResourceInit`]: crate::resources::ResourceInit
+    pub fn with_default_resource<T>(self) -> Self
+    where
+        T: crate::resources::ResourceInit + Send + Sync + 'static,
+    {
+        self.with_resource(T::init_resource())
+    }
+
     /// Register an additional stateless service
     pub fn with_service(mut self, service: impl Service + 'static) -> Self {
         self.extra_services.push(Box::new(service));
@@ -67,6 +95,58 @@ impl GameBuilder {
         self
     }
 
+    /// Register a handler that resets game state on `SceneTransition::Restart`
+    ///
+    /// `owner` names the game or module the handler belongs to in
+    /// `RunRestarted`. Resources without a handler survive restarts.
+    pub fn with_reset_handler(
+        mut self,
+        owner: impl Into<String>,
+        handler: impl ResetHandler + 'static,
+    ) -> Self {
+        self.reset_handlers.register(owner, handler);
+        self
+    }
+
+    /// Write a crash report bundle when the game panics
+    ///
+    /// Registers a [`CrashReporter`] resource and installs its panic hook.
+    /// The runners feed it every tick; `BugReportRequested` events write a
+    /// bundle without crashing.
+    pub fn with_crash_reporter(mut self, config: CrashReportConfig) -> Self {
+        self.crash_reporter = Some(config);
+        self
+    }
+
+    /// Declare feature flag `name`, see [`FeatureFlags`]
+    ///
+    /// The flags file, `ISSUN_FLAG_<NAME>` environment variables and
+    /// `SetFeatureFlagRequested` events override `default`.
+    pub fn declare_flag(mut self, name: impl Into<String>, default: bool) -> Self {
+        self.flags.push((name.into(), default));
+        self
+    }
+
+    /// Read flag overrides (`name = true` lines, TOML) from `path` at build
+    pub fn with_flags_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.flags_file = Some(path.into());
+        self
+    }
+
+    /// Override plugin config sections (`[combat]`, `[contagion]`, ...)
+    /// from the TOML file at `path`
+    ///
+    /// The file is applied at build; with [`ConfigFileOptions::watch`] it is
+    /// also reloaded while the game runs, see [`ConfigReloadSystem`].
+    pub fn with_config_file(
+        mut self,
+        path: impl Into<PathBuf>,
+        options: ConfigFileOptions,
+    ) -> Self {
+        self.config_file = Some((path.into(), options));
+        self
+    }
+
     /// Build and run the game
     #[allow(deprecated)]
     pub async fn build(mut self) -> Result<Game> {
@@ -80,10 +160,24 @@ impl GameBuilder {
 
         // Build plugins in dependency order
         let mut plugin_builder = DefaultPluginBuilder::new();
-        for idx in sorted_indices {
+        let mut report = BuildReport::default();
+        for &idx in &sorted_indices {
+            plugin_builder.current_plugin = self.plugins[idx].name();
             self.plugins[idx].build(&mut plugin_builder);
+            report.plugins.push(self.plugins[idx].name().to_string());
         }
 
+        // Keep the plugins around (in build order) for on_start/on_exit
+        let mut registered: Vec<Option<Box<dyn Plugin>>> =
+            self.plugins.drain(..).map(Some).collect();
+        let lifecycle = PluginLifecycle::new(
+            sorted_indices
+                .iter()
+                .filter_map(|&idx| registered[idx].take())
+                .map(Arc::from)
+                .collect(),
+        );
+
         let DefaultPluginBuilder {
             entities,
             services: plugin_services,
@@ -91,14 +185,19 @@ impl GameBuilder {
             assets,
             resources: plugin_resources,
             runtime_resources: plugin_runtime_resources,
+            resets: mut reset_registry,
+            flags: plugin_flags,
+            configs,
+            ..
         } = plugin_builder;
+        reset_registry.extend(self.reset_handlers);
 
         // Combine services/systems from plugins and manual registrations
         let mut all_services = plugin_services;
-        all_services.extend(self.extra_services.into_iter());
+        all_services.extend(self.extra_services);
 
         let mut all_systems = plugin_systems;
-        all_systems.extend(self.extra_systems.into_iter());
+        all_systems.extend(self.extra_systems);
 
         // Legacy context (for backward compatibility)
         let mut context = crate::context::Context::new();
@@ -106,6 +205,7 @@ impl GameBuilder {
         // New contexts
         let mut resource_context = crate::context::ResourceContext::new();
         resource_context.insert(crate::event::EventBus::new());
+        resource_context.insert(lifecycle);
         let mut service_context = crate::context::ServiceContext::new();
         let mut system_context = crate::context::SystemContext::new();
 
@@ -116,6 +216,21 @@ impl GameBuilder {
             service_context.register(cloned);
         }
 
+        // Feature flags: defaults, then the flags file, then the environment
+        let flags = FeatureFlags::new();
+        for (name, default) in plugin_flags.into_iter().chain(self.flags) {
+            flags.declare(name, default);
+        }
+        if let Some(path) = &self.flags_file {
+            flags.apply_file(&read_flags_file(path)?)?;
+        }
+        flags.apply_env(std::env::vars());
+        report.flags = flags.entries();
+        resource_context.insert(flags.clone());
+        resource_context.insert(report);
+        system_context.set_feature_flags(flags);
+        system_context.register(FeatureFlagSystem::new());
+
         // Register systems into SystemContext
         for system in all_systems {
             system_context.register_boxed(system);
@@ -133,13 +248,30 @@ impl GameBuilder {
 
         // Register resources from plugins into resource_context (new architecture)
         // Move resources from plugin_resources to resource_context
-        for (type_id, boxed) in plugin_resources.into_inner().into_iter() {
-            resource_context.insert_boxed(type_id, boxed);
+        for (type_id, type_name, boxed) in plugin_resources.into_inner() {
+            resource_context.insert_boxed(type_id, type_name, boxed);
         }
 
         // Note: Legacy context.resources() is no longer used in the new architecture
         // Game now uses resource_context which has all the resources
 
+        // Config file overrides, before reset handlers capture the initial state
+        if let Some((path, options)) = self.config_file {
+            let mut config_reload = ConfigReloadSystem::new(path, options, configs);
+            config_reload.apply_initial(&mut resource_context).await?;
+            system_context.register(config_reload);
+        }
+
+        // Reset handlers remember the post-build state for restarts
+        reset_registry.capture(&resource_context).await;
+        resource_context.insert(reset_registry);
+
+        if let Some(config) = self.crash_reporter {
+            let reporter = CrashReporter::new(config);
+            reporter.install();
+            resource_context.insert(reporter);
+        }
+
         Ok(Game {
             resources: resource_context,
             services: service_context,
@@ -231,6 +363,11 @@ struct DefaultPluginBuilder {
     assets: HashMap<String, Box<dyn std::any::Any + Send + Sync>>,
     resources: crate::resources::Resources,
     runtime_resources: HashMap<TypeId, Box<dyn RuntimeResourceEntry>>,
+    resets: ResetRegistry,
+    flags: Vec<(String, bool)>,
+    configs: Vec<Box<dyn ConfigSection>>,
+    /// Plugin whose `build` is running, owner of its reset handlers
+    current_plugin: &'static str,
 }
 
 impl DefaultPluginBuilder {
@@ -242,6 +379,10 @@ impl DefaultPluginBuilder {
             assets: HashMap::new(),
             resources: crate::resources::Resources::default(),
             runtime_resources: HashMap::new(),
+            resets: ResetRegistry::new(),
+            flags: Vec::new(),
+            configs: Vec::new(),
+            current_plugin: "",
         }
     }
 }
@@ -276,6 +417,27 @@ impl PluginBuilder for DefaultPluginBuilder {
     fn resources_mut(&mut self) -> &mut crate::resources::Resources {
         &mut self.resources
     }
+
+    fn register_reset_handler(&mut self, handler: Box<dyn ResetHandler>) {
+        self.resets.register_boxed(self.current_plugin, handler);
+    }
+
+    fn declare_flag(&mut self, name: &str, default: bool) {
+        self.flags.push((name.to_string(), default));
+    }
+
+    fn register_config_section(&mut self, section: Box<dyn ConfigSection>) {
+        self.configs.push(section);
+    }
+}
+
+/// What [`GameBuilder::build`] put together (resource)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BuildReport {
+    /// Plugin names in build order
+    pub plugins: Vec<String>,
+    /// Feature flags after the flags file and environment overrides
+    pub flags: BTreeMap<String, FeatureFlag>,
 }
 
 /// Game instance with partitioned contexts (Proposal C)
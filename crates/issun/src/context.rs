@@ -9,6 +9,8 @@
 //! - `ServiceContext`: Stateless domain logic (Services)
 //! - `SystemContext`: Stateful orchestration (Systems)
 
+use crate::engine::flags::FeatureFlags;
+use crate::error::{IssunError, Result};
 use crate::resources::Resources;
 use crate::service::Service;
 use crate::state::States;
@@ -17,7 +19,9 @@ use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::{OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock};
 
 /// Marker trait for game context
@@ -422,6 +426,32 @@ impl<T: 'static> DerefMut for ResourceWriteGuard<T> {
 /// - Multiple readers OR single writer
 /// - Immutable during scene rendering, mutable in systems
 ///
+/// # Locking model
+///
+/// - Every resource has its own `tokio::sync::RwLock`. The type-to-lock map
+///   is only changed through `&mut self` (`insert`, `remove`), so lookups
+///   take no lock and guards on different resources never wait on each other.
+/// - Guards own a handle to their resource's lock and do not borrow the
+///   context; a resource replaced or removed while guarded stays alive for
+///   the guard, detached from the context.
+/// - Locks are fair: a waiting writer blocks new readers of the same
+///   resource, so one long-held read guard can stall later readers too.
+/// - Acquisition flavours:
+///   - `get` / `get_mut` wait as long as it takes
+///   - `try_get` / `try_get_mut` never wait; `None` means absent *or* locked
+///   - `try_get_timeout` / `try_get_mut_timeout` wait up to a deadline and
+///     report `IssunError::ResourceNotFound` or `IssunError::ResourceTimedOut`
+/// - Taking a second guard on a resource the same task already holds
+///   mutably never succeeds; use a timeout variant when that may happen.
+///
+/// # Scene-scoped resources
+///
+/// [`insert_scoped`](Self::insert_scoped) ties a resource to the scene whose
+/// hook is running; `SceneDirector` removes it when that scene exits and
+/// publishes `ScopedResourcesDropped`. Suspended scenes keep theirs.
+/// [`promote`](Self::promote) makes a scoped resource global again. Access is
+/// the same as for any other resource.
+///
 /// # Example
 ///
 /// ```ignore
@@ -439,6 +469,35 @@ impl<T: 'static> DerefMut for ResourceWriteGuard<T> {
 /// ```
 pub struct ResourceContext {
     resources: HashMap<TypeId, Resource>,
+    meta: HashMap<TypeId, ResourceMeta>,
+    /// Scene instance whose lifecycle hook is running, set by `SceneDirector`
+    scope: Option<u64>,
+    /// Scene instance owning each scoped resource
+    scoped: HashMap<TypeId, u64>,
+}
+
+/// Bookkeeping for [`ResourceContext::resource_infos`]
+struct ResourceMeta {
+    type_name: &'static str,
+    writes: Arc<AtomicU64>,
+}
+
+impl ResourceMeta {
+    fn new(type_name: &'static str) -> Self {
+        Self {
+            type_name,
+            writes: Arc::new(AtomicU64::new(0)),
+        }
+    }
+}
+
+/// Description of one resource in a [`ResourceContext`]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ResourceInfo {
+    /// Full type name (`std::any::type_name`)
+    pub type_name: String,
+    /// Number of mutable borrows since insertion; grows when the resource may have changed
+    pub writes: u64,
 }
 
 impl ResourceContext {
@@ -446,11 +505,16 @@ impl ResourceContext {
     pub fn new() -> Self {
         Self {
             resources: HashMap::new(),
+            meta: HashMap::new(),
+            scope: None,
+            scoped: HashMap::new(),
         }
     }
 
     /// Insert a resource into the context
     ///
+    /// Replacing a scoped resource makes it global.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -460,6 +524,77 @@ impl ResourceContext {
     pub fn insert<T: 'static + Send + Sync>(&mut self, resource: T) {
         self.resources
             .insert(TypeId::of::<T>(), Arc::new(RwLock::new(Box::new(resource))));
+        self.meta.insert(
+            TypeId::of::<T>(),
+            ResourceMeta::new(std::any::type_name::<T>()),
+        );
+        self.scoped.remove(&TypeId::of::<T>());
+    }
+
+    /// Insert a resource that lives until the current scene exits
+    ///
+    /// Only meaningful inside a `SceneDirector` lifecycle hook. Outside one
+    /// there is no current scene: debug builds panic, release builds insert
+    /// the resource as a global one that is never dropped with a scene.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if no scene is current.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // In CombatScene::on_enter
+    /// resources.insert_scoped(CombatPreviewCache::default());
+    /// ```
+    #[track_caller]
+    pub fn insert_scoped<T: 'static + Send + Sync>(&mut self, resource: T) {
+        debug_assert!(
+            self.scope.is_some(),
+            "insert_scoped::<{}> outside a scene lifecycle hook",
+            std::any::type_name::<T>()
+        );
+        self.insert(resource);
+        if let Some(scope) = self.scope {
+            self.scoped.insert(TypeId::of::<T>(), scope);
+        }
+    }
+
+    /// Make a scoped resource global so it outlives its scene
+    ///
+    /// Returns `false` if `T` was not scoped.
+    pub fn promote<T: 'static>(&mut self) -> bool {
+        self.scoped.remove(&TypeId::of::<T>()).is_some()
+    }
+
+    /// Check if a resource is scoped to a scene
+    pub fn is_scoped<T: 'static>(&self) -> bool {
+        self.scoped.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Set the scene instance `insert_scoped` ties resources to
+    pub(crate) fn set_scope(&mut self, scope: Option<u64>) {
+        self.scope = scope;
+    }
+
+    /// Remove the resources scoped to `scope`, returning their sorted type names
+    pub(crate) fn drop_scope(&mut self, scope: u64) -> Vec<String> {
+        let type_ids: Vec<TypeId> = self
+            .scoped
+            .iter()
+            .filter(|(_, owner)| **owner == scope)
+            .map(|(type_id, _)| *type_id)
+            .collect();
+        let mut dropped = Vec::new();
+        for type_id in type_ids {
+            self.scoped.remove(&type_id);
+            self.resources.remove(&type_id);
+            if let Some(meta) = self.meta.remove(&type_id) {
+                dropped.push(meta.type_name.to_string());
+            }
+        }
+        dropped.sort();
+        dropped
     }
 
     /// Insert a pre-boxed resource into the context (internal use)
@@ -468,9 +603,12 @@ impl ResourceContext {
     pub(crate) fn insert_boxed(
         &mut self,
         type_id: TypeId,
+        type_name: &'static str,
         boxed: Box<dyn std::any::Any + Send + Sync>,
     ) {
         self.resources.insert(type_id, Arc::new(RwLock::new(boxed)));
+        self.meta.insert(type_id, ResourceMeta::new(type_name));
+        self.scoped.remove(&type_id);
     }
 
     /// Get immutable reference to a resource (async read lock)
@@ -509,6 +647,7 @@ impl ResourceContext {
     pub async fn get_mut<T: 'static + Send + Sync>(&self) -> Option<ResourceWriteGuard<T>> {
         let resource = self.resources.get(&TypeId::of::<T>())?.clone();
         let guard = resource.write_owned().await;
+        self.record_write(TypeId::of::<T>());
         Some(ResourceWriteGuard {
             guard,
             _marker: PhantomData,
@@ -529,12 +668,57 @@ impl ResourceContext {
     pub fn try_get_mut<T: 'static + Send + Sync>(&self) -> Option<ResourceWriteGuard<T>> {
         let resource = self.resources.get(&TypeId::of::<T>())?.clone();
         let guard = resource.try_write_owned().ok()?;
+        self.record_write(TypeId::of::<T>());
         Some(ResourceWriteGuard {
             guard,
             _marker: PhantomData,
         })
     }
 
+    /// Get immutable reference to a resource, waiting at most `timeout`
+    ///
+    /// Unlike `try_get`, contention is reported instead of hidden:
+    /// `IssunError::ResourceTimedOut` if a writer holds the resource past the
+    /// deadline, `IssunError::ResourceNotFound` if it does not exist.
+    pub async fn try_get_timeout<T: 'static + Send + Sync>(
+        &self,
+        timeout: Duration,
+    ) -> Result<ResourceReadGuard<T>> {
+        let resource = self.entry::<T>()?;
+        let guard = tokio::time::timeout(timeout, resource.read_owned())
+            .await
+            .map_err(|_| timed_out::<T>(timeout))?;
+        Ok(ResourceReadGuard {
+            guard,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get mutable reference to a resource, waiting at most `timeout`
+    ///
+    /// Errors like [`try_get_timeout`](Self::try_get_timeout).
+    pub async fn try_get_mut_timeout<T: 'static + Send + Sync>(
+        &self,
+        timeout: Duration,
+    ) -> Result<ResourceWriteGuard<T>> {
+        let resource = self.entry::<T>()?;
+        let guard = tokio::time::timeout(timeout, resource.write_owned())
+            .await
+            .map_err(|_| timed_out::<T>(timeout))?;
+        self.record_write(TypeId::of::<T>());
+        Ok(ResourceWriteGuard {
+            guard,
+            _marker: PhantomData,
+        })
+    }
+
+    fn entry<T: 'static>(&self) -> Result<Resource> {
+        self.resources
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .ok_or_else(|| IssunError::ResourceNotFound(std::any::type_name::<T>().to_string()))
+    }
+
     /// Check if a resource exists
     pub fn contains<T: 'static>(&self) -> bool {
         self.resources.contains_key(&TypeId::of::<T>())
@@ -542,9 +726,31 @@ impl ResourceContext {
 
     /// Remove a resource from the context
     pub fn remove<T: 'static>(&mut self) -> bool {
+        self.meta.remove(&TypeId::of::<T>());
+        self.scoped.remove(&TypeId::of::<T>());
         self.resources.remove(&TypeId::of::<T>()).is_some()
     }
 
+    /// Type names and write counts of all resources, sorted by type name
+    pub fn resource_infos(&self) -> Vec<ResourceInfo> {
+        let mut infos: Vec<ResourceInfo> = self
+            .meta
+            .values()
+            .map(|meta| ResourceInfo {
+                type_name: meta.type_name.to_string(),
+                writes: meta.writes.load(Ordering::Relaxed),
+            })
+            .collect();
+        infos.sort_by(|a, b| a.type_name.cmp(&b.type_name));
+        infos
+    }
+
+    fn record_write(&self, type_id: TypeId) {
+        if let Some(meta) = self.meta.get(&type_id) {
+            meta.writes.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
     /// Get the number of registered resources
     pub fn len(&self) -> usize {
         self.resources.len()
@@ -562,6 +768,13 @@ impl Default for ResourceContext {
     }
 }
 
+fn timed_out<T>(waited: Duration) -> IssunError {
+    IssunError::ResourceTimedOut {
+        resource: std::any::type_name::<T>().to_string(),
+        waited,
+    }
+}
+
 /// Container for stateless Services (pure domain logic)
 ///
 /// Services provide pure, reusable functionality without state.
@@ -678,6 +891,9 @@ impl Default for ServiceContext {
 /// ```
 pub struct SystemContext {
     systems: HashMap<TypeId, Box<dyn System>>,
+    /// Feature flag of each gated system
+    gates: HashMap<TypeId, String>,
+    flags: FeatureFlags,
 }
 
 impl SystemContext {
@@ -685,6 +901,8 @@ impl SystemContext {
     pub fn new() -> Self {
         Self {
             systems: HashMap::new(),
+            gates: HashMap::new(),
+            flags: FeatureFlags::new(),
         }
     }
 
@@ -699,15 +917,48 @@ impl SystemContext {
     /// systems.register(TurnManager::new());
     /// ```
     pub fn register<T: System + 'static>(&mut self, system: T) {
-        self.systems.insert(TypeId::of::<T>(), Box::new(system));
+        self.register_boxed(Box::new(system));
     }
 
     /// Register an already boxed system (used by GameBuilder)
     pub fn register_boxed(&mut self, system: Box<dyn System>) {
         let type_id = system.as_any().type_id();
+        match system.gate() {
+            Some(flag) => self.gates.insert(type_id, flag.to_string()),
+            None => self.gates.remove(&type_id),
+        };
         self.systems.insert(type_id, system);
     }
 
+    /// Use `flags` to decide which gated systems are active
+    ///
+    /// `GameBuilder` passes the game's `FeatureFlags` resource; without it
+    /// every gated system is inactive.
+    pub fn set_feature_flags(&mut self, flags: FeatureFlags) {
+        self.flags = flags;
+    }
+
+    /// Whether `T` is registered and not gated on a flag that is off
+    pub fn is_active<T: System + 'static>(&self) -> bool {
+        self.contains::<T>()
+            && match self.gates.get(&TypeId::of::<T>()) {
+                Some(flag) => self.flags.is_enabled(flag),
+                None => true,
+            }
+    }
+
+    /// Mutable reference to `T` if it is active
+    ///
+    /// The runners update systems through this; use
+    /// [`get_mut`](Self::get_mut) to reach a system regardless of its gate.
+    pub fn active_mut<T: System + 'static>(&mut self) -> Option<&mut T> {
+        if self.is_active::<T>() {
+            self.get_mut::<T>()
+        } else {
+            None
+        }
+    }
+
     /// Get immutable reference to a system
     ///
     /// # Example
@@ -745,6 +996,7 @@ impl SystemContext {
 
     /// Remove a system from the context
     pub fn remove<T: System + 'static>(&mut self) -> bool {
+        self.gates.remove(&TypeId::of::<T>());
         self.systems.remove(&TypeId::of::<T>()).is_some()
     }
 
@@ -925,6 +1177,70 @@ mod new_context_tests {
         assert_eq!(reader2.name, "Hero");
     }
 
+    #[tokio::test]
+    async fn test_timeout_reports_same_key_contention() {
+        let mut resources = ResourceContext::new();
+        resources.insert(Player::new("Hero"));
+        let timeout = Duration::from_millis(30);
+
+        let writer = resources.get_mut::<Player>().await.unwrap();
+        let started = std::time::Instant::now();
+        let read = resources.try_get_timeout::<Player>(timeout).await;
+        assert!(matches!(
+            read,
+            Err(IssunError::ResourceTimedOut { waited, .. }) if waited == timeout
+        ));
+        assert!(started.elapsed() >= timeout);
+        assert!(matches!(
+            resources.try_get_mut_timeout::<Player>(timeout).await,
+            Err(IssunError::ResourceTimedOut { .. })
+        ));
+        drop(writer);
+
+        assert_eq!(
+            resources
+                .try_get_timeout::<Player>(timeout)
+                .await
+                .unwrap()
+                .name,
+            "Hero"
+        );
+        let err = resources
+            .try_get_mut_timeout::<Score>(timeout)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(err, IssunError::ResourceNotFound(_)));
+        assert!(err.to_string().contains("Score"));
+    }
+
+    #[tokio::test]
+    async fn test_resource_infos_count_writes() {
+        let mut resources = ResourceContext::new();
+        resources.insert(Player::new("Hero"));
+        resources.insert(Score(0));
+
+        resources.get_mut::<Score>().await.unwrap().0 += 1;
+        resources.try_get_mut::<Score>().unwrap().0 += 1;
+        let _ = resources.get::<Player>().await.unwrap();
+
+        let infos = resources.resource_infos();
+        assert_eq!(infos.len(), 2);
+        let score = infos
+            .iter()
+            .find(|info| info.type_name.ends_with("::Score"))
+            .unwrap();
+        assert_eq!(score.writes, 2);
+        let player = infos
+            .iter()
+            .find(|info| info.type_name.ends_with("::Player"))
+            .unwrap();
+        assert_eq!(player.writes, 0);
+
+        resources.remove::<Score>();
+        assert_eq!(resources.resource_infos().len(), 1);
+    }
+
     // ===== ServiceContext Tests =====
 
     #[test]
@@ -0,0 +1,469 @@
+//! Plugin config overrides from a TOML file, with live reload
+//!
+//! Plugins register their config resources as named sections
+//! (`#[plugin(config = "combat")]` on a field, or
+//! `PluginBuilderExt::register_config`). A file given to
+//! [`GameBuilder::with_config_file`](crate::builder::GameBuilder::with_config_file)
+//! overrides fields of those sections at build:
+//!
+//! ```toml
+//! [combat]
+//! difficulty_multiplier = 1.5
+//!
+//! [contagion]
+//! propagation_rate = 0.3
+//! ```
+//!
+//! With [`ConfigFileOptions::watch`] the runners poll the file between ticks
+//! and apply what changed; a [`ReloadConfigRequested`] event (the debug
+//! console's `config reload`) forces a reload. Each section is validated
+//! against its config type before anything from it is applied: a section
+//! with a type error or an unknown field is left untouched and reported as
+//! [`ConfigReloadFailed`]. Fields a plugin marked immutable after start in
+//! its [`ParamSchema`] are skipped at runtime and reported as
+//! [`ConfigFieldsSkipped`].
+//!
+//! ```ignore
+//! let game = GameBuilder::new()
+//!     .with_plugin(CombatPlugin::new())?
+//!     .with_config_file("balance.toml", ConfigFileOptions::watch())
+//!     .build()
+//!     .await?;
+//!
+//! // In a system that derives values from the config
+//! for reloaded in bus.reader::<ConfigReloaded>().iter() {
+//!     if reloaded.sections.iter().any(|s| s == "combat") { /* recompute */ }
+//! }
+//! ```
+
+use crate::context::ResourceContext;
+use crate::error::{IssunError, Result};
+use crate::event::{Event, EventBus};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Metadata about the fields of a config type
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParamSchema {
+    immutable: BTreeSet<String>,
+}
+
+impl ParamSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark `field` as read once at build; reloads leave it alone
+    pub fn immutable_after_start(mut self, field: impl Into<String>) -> Self {
+        self.immutable.insert(field.into());
+        self
+    }
+
+    pub fn is_immutable(&self, field: &str) -> bool {
+        self.immutable.contains(field)
+    }
+}
+
+/// A config resource that can be overridden from the config file
+///
+/// ```ignore
+/// impl ConfigParams for CombatConfig {
+///     fn param_schema() -> ParamSchema {
+///         ParamSchema::new().immutable_after_start("default_max_hp")
+///     }
+/// }
+/// ```
+pub trait ConfigParams: Serialize + DeserializeOwned + Send + Sync + 'static {
+    /// Field metadata; by default every field may change at runtime
+    fn param_schema() -> ParamSchema {
+        ParamSchema::new()
+    }
+}
+
+/// A registered section, type-erased over its config resource
+#[async_trait]
+pub trait ConfigSection: Send + Sync {
+    /// Table name in the config file
+    fn name(&self) -> &str;
+
+    fn schema(&self) -> &ParamSchema;
+
+    /// Current value of the resource, `None` if it is not registered
+    async fn current(&self, resources: &ResourceContext) -> Option<Value>;
+
+    /// Replace the resource with `value`; nothing changes if `value` does not
+    /// deserialize into the config type
+    async fn apply(
+        &self,
+        resources: &mut ResourceContext,
+        value: Value,
+    ) -> std::result::Result<(), String>;
+}
+
+/// [`ConfigSection`] for the resource `T`
+pub struct TypedConfigSection<T> {
+    name: String,
+    schema: ParamSchema,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: ConfigParams> TypedConfigSection<T> {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            schema: T::param_schema(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<T: ConfigParams> ConfigSection for TypedConfigSection<T> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn schema(&self) -> &ParamSchema {
+        &self.schema
+    }
+
+    async fn current(&self, resources: &ResourceContext) -> Option<Value> {
+        let value = resources.get::<T>().await?;
+        serde_json::to_value(&*value).ok()
+    }
+
+    async fn apply(
+        &self,
+        resources: &mut ResourceContext,
+        value: Value,
+    ) -> std::result::Result<(), String> {
+        let value: T = serde_json::from_value(value).map_err(|e| e.to_string())?;
+        let mut current = resources
+            .get_mut::<T>()
+            .await
+            .ok_or_else(|| format!("config resource for [{}] is not registered", self.name))?;
+        *current = value;
+        Ok(())
+    }
+}
+
+/// How the config file is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigFileOptions {
+    /// Re-read the file between ticks and apply changes
+    pub watch: bool,
+    /// Minimum time between two reads while watching
+    pub poll_interval: Duration,
+}
+
+impl ConfigFileOptions {
+    /// Read the file once at build
+    pub fn once() -> Self {
+        Self::default()
+    }
+
+    /// Read the file at build and reload it when it changes
+    pub fn watch() -> Self {
+        Self {
+            watch: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
+impl Default for ConfigFileOptions {
+    fn default() -> Self {
+        Self {
+            watch: false,
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Request to re-read the config file now, watched or not
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReloadConfigRequested;
+
+impl Event for ReloadConfigRequested {}
+
+/// Sections of the config file were applied to the live resources
+///
+/// Only published when at least one field changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigReloaded {
+    /// Sections with at least one changed field
+    pub sections: Vec<String>,
+    /// Changed fields as `"<section>.<field>"`
+    pub changed_fields: Vec<String>,
+}
+
+impl Event for ConfigReloaded {}
+
+/// A section of the config file was rejected; nothing from it was applied
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigReloadFailed {
+    /// `None` when the file itself could not be read or parsed
+    pub section: Option<String>,
+    pub error: String,
+}
+
+impl Event for ConfigReloadFailed {}
+
+/// Fields changed in the config file that only apply at build
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigFieldsSkipped {
+    pub section: String,
+    pub fields: Vec<String>,
+}
+
+impl Event for ConfigFieldsSkipped {}
+
+/// What applying the config file did
+#[derive(Debug, Default)]
+struct ApplyOutcome {
+    reloaded: Option<ConfigReloaded>,
+    failures: Vec<ConfigReloadFailed>,
+    skipped: Vec<ConfigFieldsSkipped>,
+}
+
+/// Applies the config file to the registered sections
+///
+/// Registered by `GameBuilder` when a config file is set and updated by the
+/// runners after the feature flags, so reloaded values are in place before
+/// the tick's systems run.
+#[derive(crate::System)]
+#[system(name = "config_reload_system")]
+pub struct ConfigReloadSystem {
+    path: PathBuf,
+    options: ConfigFileOptions,
+    sections: Vec<Box<dyn ConfigSection>>,
+    last_content: Option<String>,
+    last_poll: Option<Instant>,
+}
+
+impl ConfigReloadSystem {
+    pub fn new(
+        path: impl Into<PathBuf>,
+        options: ConfigFileOptions,
+        sections: Vec<Box<dyn ConfigSection>>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            options,
+            sections,
+            last_content: None,
+            last_poll: None,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Names of the registered sections
+    pub fn sections(&self) -> Vec<&str> {
+        self.sections.iter().map(|section| section.name()).collect()
+    }
+
+    /// Apply the file at build; immutable fields may be set here
+    pub(crate) async fn apply_initial(&mut self, resources: &mut ResourceContext) -> Result<()> {
+        let content = read_config_file(&self.path)?;
+        let outcome = self.apply(&content, resources, false).await;
+        self.last_content = Some(content);
+        match outcome.failures.into_iter().next() {
+            Some(failure) => Err(IssunError::Serialization(match failure.section {
+                Some(section) => format!("Invalid config section [{}]: {}", section, failure.error),
+                None => failure.error,
+            })),
+            None => Ok(()),
+        }
+    }
+
+    /// Reload if a [`ReloadConfigRequested`] was published this tick or the
+    /// watched file changed since the last read
+    pub async fn update(&mut self, resources: &mut ResourceContext) {
+        let requested = match resources.get_mut::<EventBus>().await {
+            Some(mut bus) => !bus.reader::<ReloadConfigRequested>().is_empty(),
+            None => return,
+        };
+        let now = Instant::now();
+        let due = self.options.watch
+            && self
+                .last_poll
+                .is_none_or(|last| now.duration_since(last) >= self.options.poll_interval);
+        if !requested && !due {
+            return;
+        }
+        self.last_poll = Some(now);
+
+        let outcome = match read_config_file(&self.path) {
+            Ok(content) => {
+                if !requested && self.last_content.as_ref() == Some(&content) {
+                    return;
+                }
+                let outcome = self.apply(&content, resources, true).await;
+                self.last_content = Some(content);
+                outcome
+            }
+            Err(error) => ApplyOutcome {
+                failures: vec![ConfigReloadFailed {
+                    section: None,
+                    error: error.to_string(),
+                }],
+                ..ApplyOutcome::default()
+            },
+        };
+
+        let Some(mut bus) = resources.get_mut::<EventBus>().await else {
+            return;
+        };
+        for failure in outcome.failures {
+            bus.publish(failure);
+        }
+        for skipped in outcome.skipped {
+            bus.publish(skipped);
+        }
+        if let Some(reloaded) = outcome.reloaded {
+            bus.publish(reloaded);
+        }
+    }
+
+    async fn apply(
+        &self,
+        content: &str,
+        resources: &mut ResourceContext,
+        running: bool,
+    ) -> ApplyOutcome {
+        let mut outcome = ApplyOutcome::default();
+        let file: toml::Table = match toml::from_str(content) {
+            Ok(file) => file,
+            Err(e) => {
+                outcome.failures.push(ConfigReloadFailed {
+                    section: None,
+                    error: format!("Invalid config file: {}", e),
+                });
+                return outcome;
+            }
+        };
+
+        let mut sections = Vec::new();
+        let mut changed_fields = Vec::new();
+        for (name, overrides) in file {
+            let Some(section) = self.sections.iter().find(|s| s.name() == name) else {
+                eprintln!("[Config] Ignoring unknown section [{}]", name);
+                continue;
+            };
+            let result = match overrides {
+                toml::Value::Table(overrides) => {
+                    apply_section(section.as_ref(), overrides, resources, running).await
+                }
+                _ => Err(format!("[{}] must be a table", name)),
+            };
+            match result {
+                Ok((changed, skipped)) => {
+                    if !skipped.is_empty() {
+                        eprintln!(
+                            "[Config] [{}] fields only apply at start, skipped: {}",
+                            name,
+                            skipped.join(", ")
+                        );
+                        outcome.skipped.push(ConfigFieldsSkipped {
+                            section: name.clone(),
+                            fields: skipped,
+                        });
+                    }
+                    if !changed.is_empty() {
+                        changed_fields.extend(changed.iter().map(|f| format!("{}.{}", name, f)));
+                        sections.push(name);
+                    }
+                }
+                Err(error) => outcome.failures.push(ConfigReloadFailed {
+                    section: Some(name),
+                    error,
+                }),
+            }
+        }
+
+        if !sections.is_empty() {
+            outcome.reloaded = Some(ConfigReloaded {
+                sections,
+                changed_fields,
+            });
+        }
+        outcome
+    }
+}
+
+/// Overlay `overrides` on the section's current value and apply the result;
+/// returns the changed and the skipped (immutable) fields
+async fn apply_section(
+    section: &dyn ConfigSection,
+    overrides: toml::Table,
+    resources: &mut ResourceContext,
+    running: bool,
+) -> std::result::Result<(Vec<String>, Vec<String>), String> {
+    let Some(Value::Object(mut merged)) = section.current(resources).await else {
+        return Err(format!(
+            "config resource for [{}] is not registered",
+            section.name()
+        ));
+    };
+
+    let mut changed = Vec::new();
+    let mut skipped = Vec::new();
+    let mut unknown = Vec::new();
+    for (field, value) in overrides {
+        let value = serde_json::to_value(value).map_err(|e| e.to_string())?;
+        let Some(current) = merged.get(&field) else {
+            unknown.push(field);
+            continue;
+        };
+        if same_value(current, &value) {
+            continue;
+        }
+        if running && section.schema().is_immutable(&field) {
+            skipped.push(field);
+            continue;
+        }
+        merged.insert(field.clone(), value);
+        changed.push(field);
+    }
+    if !unknown.is_empty() {
+        return Err(format!("unknown field(s): {}", unknown.join(", ")));
+    }
+
+    // Unchanged values are the current ones, so only a change can be invalid
+    if !changed.is_empty() {
+        section.apply(resources, Value::Object(merged)).await?;
+    }
+    Ok((changed, skipped))
+}
+
+/// Integers in the file compare equal to the floats they override
+fn same_value(current: &Value, new: &Value) -> bool {
+    match (current.as_f64(), new.as_f64()) {
+        (Some(a), Some(b)) => a == b,
+        _ => current == new,
+    }
+}
+
+/// Read a config file for [`ConfigReloadSystem`]
+pub(crate) fn read_config_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| {
+        IssunError::Io(std::io::Error::new(
+            e.kind(),
+            format!("Failed to read config file {}: {}", path.display(), e),
+        ))
+    })
+}
@@ -0,0 +1,238 @@
+//! Crash report archives
+//!
+//! Bundles are zip files with stored (uncompressed) entries, so players can
+//! open them with any archive tool and the engine needs no compression
+//! library. Only what [`BundleWriter`] produces is read back by
+//! [`CrashBundle`]; it is not a general zip reader.
+
+use crate::error::{IssunError, Result};
+use std::path::Path;
+
+const LOCAL_HEADER: u32 = 0x0403_4b50;
+const CENTRAL_HEADER: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIR: u32 = 0x0605_4b50;
+/// Version 2.0: plain stored entries
+const ZIP_VERSION: u16 = 20;
+/// Entry names are UTF-8
+const FLAG_UTF8: u16 = 1 << 11;
+/// 1980-01-01, the earliest DOS date
+const DOS_DATE: u16 = (1 << 5) | 1;
+
+/// Builds a bundle in memory, entry by entry
+#[derive(Debug, Default)]
+pub struct BundleWriter {
+    data: Vec<u8>,
+    central: Vec<u8>,
+    entries: u16,
+}
+
+impl BundleWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a file
+    pub fn add(&mut self, name: &str, contents: &[u8]) {
+        let offset = self.data.len() as u32;
+        let crc = crc32(contents);
+        let size = contents.len() as u32;
+
+        put_u32(&mut self.data, LOCAL_HEADER);
+        put_u16(&mut self.data, ZIP_VERSION);
+        put_entry_fields(&mut self.data, crc, size, name);
+        put_u16(&mut self.data, 0); // extra field length
+        self.data.extend_from_slice(name.as_bytes());
+        self.data.extend_from_slice(contents);
+
+        put_u32(&mut self.central, CENTRAL_HEADER);
+        put_u16(&mut self.central, ZIP_VERSION); // made by
+        put_u16(&mut self.central, ZIP_VERSION); // needed
+        put_entry_fields(&mut self.central, crc, size, name);
+        put_u16(&mut self.central, 0); // extra field length
+        put_u16(&mut self.central, 0); // comment length
+        put_u16(&mut self.central, 0); // disk number
+        put_u16(&mut self.central, 0); // internal attributes
+        put_u32(&mut self.central, 0); // external attributes
+        put_u32(&mut self.central, offset);
+        self.central.extend_from_slice(name.as_bytes());
+
+        self.entries += 1;
+    }
+
+    /// Bytes of the finished archive
+    pub fn finish(mut self) -> Vec<u8> {
+        let central_offset = self.data.len() as u32;
+        let central_size = self.central.len() as u32;
+        self.data.append(&mut self.central);
+
+        put_u32(&mut self.data, END_OF_CENTRAL_DIR);
+        put_u16(&mut self.data, 0); // this disk
+        put_u16(&mut self.data, 0); // disk with the central directory
+        put_u16(&mut self.data, self.entries);
+        put_u16(&mut self.data, self.entries);
+        put_u32(&mut self.data, central_size);
+        put_u32(&mut self.data, central_offset);
+        put_u16(&mut self.data, 0); // comment length
+        self.data
+    }
+}
+
+/// Fields shared by local and central headers, from the flags to the name length
+fn put_entry_fields(out: &mut Vec<u8>, crc: u32, size: u32, name: &str) {
+    put_u16(out, FLAG_UTF8);
+    put_u16(out, 0); // method: stored
+    put_u16(out, 0); // time
+    put_u16(out, DOS_DATE);
+    put_u32(out, crc);
+    put_u32(out, size); // compressed size
+    put_u32(out, size);
+    put_u16(out, name.len() as u16);
+}
+
+fn put_u16(out: &mut Vec<u8>, value: u16) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+/// CRC-32 (IEEE) as used by zip
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// A crash report read back from disk
+#[derive(Debug, Clone, Default)]
+pub struct CrashBundle {
+    entries: Vec<(String, Vec<u8>)>,
+}
+
+impl CrashBundle {
+    /// Read a bundle written by the crash reporter
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    /// Parse the bytes of a bundle
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let invalid =
+            |what: &str| IssunError::Serialization(format!("Invalid crash bundle: {}", what));
+        let u16_at = |at: usize| -> Result<u16> {
+            bytes
+                .get(at..at + 2)
+                .map(|b| u16::from_le_bytes([b[0], b[1]]))
+                .ok_or_else(|| invalid("truncated header"))
+        };
+        let u32_at = |at: usize| -> Result<u32> {
+            bytes
+                .get(at..at + 4)
+                .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .ok_or_else(|| invalid("truncated header"))
+        };
+
+        let mut entries = Vec::new();
+        let mut at = 0;
+        while u32_at(at)? == LOCAL_HEADER {
+            if u16_at(at + 8)? != 0 {
+                return Err(invalid("compressed entry"));
+            }
+            let crc = u32_at(at + 14)?;
+            let size = u32_at(at + 22)? as usize;
+            let name_len = u16_at(at + 26)? as usize;
+            let extra_len = u16_at(at + 28)? as usize;
+
+            let name_start = at + 30;
+            let data_start = name_start + name_len + extra_len;
+            let name = bytes
+                .get(name_start..name_start + name_len)
+                .and_then(|name| std::str::from_utf8(name).ok())
+                .ok_or_else(|| invalid("entry name"))?;
+            let data = bytes
+                .get(data_start..data_start + size)
+                .ok_or_else(|| invalid("truncated entry"))?;
+            if crc32(data) != crc {
+                return Err(invalid(&format!("checksum mismatch in '{}'", name)));
+            }
+
+            entries.push((name.to_string(), data.to_vec()));
+            at = data_start + size;
+        }
+        Ok(Self { entries })
+    }
+
+    /// Entry names in archive order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Contents of an entry
+    pub fn get(&self, name: &str) -> Option<&[u8]> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == name)
+            .map(|(_, data)| data.as_slice())
+    }
+
+    /// Contents of a text entry
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.get(name)
+            .and_then(|data| std::str::from_utf8(data).ok())
+    }
+
+    /// Contents of a JSON entry
+    pub fn get_json(&self, name: &str) -> Option<serde_json::Value> {
+        self.get(name)
+            .and_then(|data| serde_json::from_slice(data).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_bundle_round_trip() {
+        let mut writer = BundleWriter::new();
+        writer.add("manifest.json", br#"{"kind":"panic"}"#);
+        writer.add("panic.txt", "floor 7 で落ちた".as_bytes());
+        let bundle = CrashBundle::from_bytes(&writer.finish()).unwrap();
+
+        assert_eq!(
+            bundle.names().collect::<Vec<_>>(),
+            vec!["manifest.json", "panic.txt"]
+        );
+        assert_eq!(bundle.get_str("panic.txt"), Some("floor 7 で落ちた"));
+        assert_eq!(
+            bundle.get_json("manifest.json"),
+            Some(serde_json::json!({ "kind": "panic" }))
+        );
+        assert!(bundle.get("missing").is_none());
+    }
+
+    #[test]
+    fn test_corrupt_bundle_is_rejected() {
+        let mut writer = BundleWriter::new();
+        writer.add("panic.txt", b"boom");
+        let mut bytes = writer.finish();
+        // Flip a byte of the entry data
+        bytes[30 + "panic.txt".len()] ^= 0xff;
+        assert!(CrashBundle::from_bytes(&bytes).is_err());
+    }
+}
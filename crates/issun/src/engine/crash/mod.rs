@@ -0,0 +1,561 @@
+//! Crash reports and session statistics
+//!
+//! A [`CrashReporter`] keeps a rolling picture of the session — current
+//! scene, [`EventBusStats`], tick timings, feature flags, loaded MODs and
+//! recent MOD log lines — refreshed by the runners every tick. When the game panics, or a
+//! [`BugReportRequested`] event is published, it writes everything into one
+//! timestamped bundle the player can attach to a bug report.
+//!
+//! Enable it with [`GameBuilder::with_crash_reporter`](crate::builder::GameBuilder::with_crash_reporter):
+//!
+//! ```ignore
+//! let game = GameBuilder::new()
+//!     .with_crash_reporter(
+//!         CrashReportConfig::new("reports")
+//!             .with_game("border-economy", env!("CARGO_PKG_VERSION"))
+//!             .with_privacy(CrashPrivacy::IncludeGameState)
+//!             .observe::<GameContext>(),
+//!     )
+//!     .build()
+//!     .await?;
+//! ```
+//!
+//! Bundles are written best-effort: a section that cannot be produced is
+//! listed under `notes` in `manifest.json` instead of failing the report.
+
+mod bundle;
+
+pub use bundle::{BundleWriter, CrashBundle};
+
+use crate::context::ResourceContext;
+use crate::engine::flags::FeatureFlags;
+use crate::engine::query::QueryFuture;
+use crate::error::Result;
+use crate::event::{Event, EventBus, EventBusStats};
+use crate::modding::{ModLogEntry, ModLogEvent, ModRegistry};
+use crate::scene::{Scene, SceneDirector};
+use serde::{Deserialize, Serialize};
+use std::backtrace::Backtrace;
+use std::collections::{BTreeMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, TryLockError};
+use std::time::Instant;
+
+/// How much game state a report may contain
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrashPrivacy {
+    /// Engine diagnostics only
+    #[default]
+    DiagnosticsOnly,
+    /// Also snapshot the observed resources (may contain player data)
+    IncludeGameState,
+}
+
+type ResourceSnapshot = Arc<
+    dyn for<'a> Fn(&'a ResourceContext) -> QueryFuture<'a, Option<serde_json::Value>> + Send + Sync,
+>;
+
+/// Settings of a [`CrashReporter`]
+#[derive(Clone)]
+pub struct CrashReportConfig {
+    /// Directory the bundles are written to (created on demand)
+    pub directory: PathBuf,
+    pub privacy: CrashPrivacy,
+    pub game_name: String,
+    pub game_version: String,
+    /// MOD log lines and tick timings kept for the report
+    pub history: usize,
+    /// Ticks between resource snapshots (with [`CrashPrivacy::IncludeGameState`])
+    pub snapshot_interval: u64,
+    observed: Vec<(String, ResourceSnapshot)>,
+}
+
+impl CrashReportConfig {
+    /// Write bundles to `directory`, without game state
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            privacy: CrashPrivacy::default(),
+            game_name: String::new(),
+            game_version: String::new(),
+            history: 100,
+            snapshot_interval: 30,
+            observed: Vec::new(),
+        }
+    }
+
+    /// Builder: Set the privacy level
+    pub fn with_privacy(mut self, privacy: CrashPrivacy) -> Self {
+        self.privacy = privacy;
+        self
+    }
+
+    /// Builder: Name and version of the game, for the manifest
+    pub fn with_game(mut self, name: impl Into<String>, version: impl Into<String>) -> Self {
+        self.game_name = name.into();
+        self.game_version = version.into();
+        self
+    }
+
+    /// Builder: Number of log lines and tick timings to keep
+    pub fn with_history(mut self, history: usize) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Builder: Ticks between resource snapshots
+    pub fn with_snapshot_interval(mut self, ticks: u64) -> Self {
+        self.snapshot_interval = ticks.max(1);
+        self
+    }
+
+    /// Builder: Include resource `T` in game state snapshots
+    ///
+    /// Ignored unless the privacy level is [`CrashPrivacy::IncludeGameState`].
+    pub fn observe<T: Serialize + Send + Sync + 'static>(mut self) -> Self {
+        let snapshot: ResourceSnapshot = Arc::new(|resources| {
+            Box::pin(async move {
+                let resource = resources.get::<T>().await?;
+                serde_json::to_value(&*resource).ok()
+            })
+        });
+        self.observed
+            .push((std::any::type_name::<T>().to_string(), snapshot));
+        self
+    }
+}
+
+impl std::fmt::Debug for CrashReportConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CrashReportConfig")
+            .field("directory", &self.directory)
+            .field("privacy", &self.privacy)
+            .field("game_name", &self.game_name)
+            .field("game_version", &self.game_version)
+            .field("history", &self.history)
+            .field("snapshot_interval", &self.snapshot_interval)
+            .field(
+                "observed",
+                &self
+                    .observed
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Ask the crash reporter for a bundle without crashing
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BugReportRequested {
+    /// What the player was doing, stored in the manifest
+    pub description: Option<String>,
+}
+
+impl Event for BugReportRequested {}
+
+/// A bundle requested with [`BugReportRequested`] was written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BugReportSaved {
+    pub path: PathBuf,
+}
+
+impl Event for BugReportSaved {}
+
+/// Duration of one runner tick
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FrameSample {
+    pub frame: u64,
+    pub duration_ms: f64,
+}
+
+/// Tick timings of the session
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FrameStats {
+    /// Ticks recorded since the game started
+    pub frames: u64,
+    /// Most recent ticks, oldest first
+    pub recent: VecDeque<FrameSample>,
+}
+
+impl FrameStats {
+    /// Mean tick duration over the recent ticks
+    pub fn average_ms(&self) -> Option<f64> {
+        if self.recent.is_empty() {
+            return None;
+        }
+        Some(self.recent.iter().map(|s| s.duration_ms).sum::<f64>() / self.recent.len() as f64)
+    }
+}
+
+/// What triggered a bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReportKind {
+    Panic,
+    BugReport,
+}
+
+impl ReportKind {
+    fn name(self) -> &'static str {
+        match self {
+            ReportKind::Panic => "crash",
+            ReportKind::BugReport => "bug-report",
+        }
+    }
+}
+
+/// Session state captured by the last [`CrashReporter::record`]
+#[derive(Default)]
+struct SessionState {
+    scene: Option<String>,
+    event_bus: Option<EventBusStats>,
+    frames: FrameStats,
+    last_tick: Option<Instant>,
+    mods: Option<serde_json::Value>,
+    flags: Option<serde_json::Value>,
+    mod_log: VecDeque<ModLogEntry>,
+    resources: Option<BTreeMap<String, serde_json::Value>>,
+}
+
+/// Writes crash and bug report bundles, see the [module docs](self)
+///
+/// Registered as a resource by `GameBuilder`; clones share the same session.
+#[derive(Clone)]
+pub struct CrashReporter {
+    config: Arc<CrashReportConfig>,
+    state: Arc<Mutex<SessionState>>,
+}
+
+impl CrashReporter {
+    pub fn new(config: CrashReportConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            state: Arc::new(Mutex::new(SessionState::default())),
+        }
+    }
+
+    pub fn config(&self) -> &CrashReportConfig {
+        &self.config
+    }
+
+    /// Tick timings recorded so far
+    pub fn frame_stats(&self) -> FrameStats {
+        self.lock().frames.clone()
+    }
+
+    /// Write a bundle when any thread panics
+    ///
+    /// The previous hook (e.g. the one installed by `Tui`, which restores the
+    /// terminal) still runs; the bundle path is printed after it.
+    pub fn install(&self) {
+        let reporter = self.clone();
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Box<dyn Any>".to_string());
+            let location = info
+                .location()
+                .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+                .unwrap_or_else(|| "unknown location".to_string());
+            let thread = std::thread::current()
+                .name()
+                .unwrap_or("<unnamed>")
+                .to_string();
+            let report = format!(
+                "thread '{}' panicked at {}:\n{}\n\n{}",
+                thread,
+                location,
+                message,
+                Backtrace::force_capture()
+            );
+
+            let saved = reporter.write(ReportKind::Panic, Some(report), None);
+            previous(info);
+            match saved {
+                Ok(path) => eprintln!("Crash report saved to {}", path.display()),
+                Err(e) => eprintln!("Failed to write crash report: {}", e),
+            }
+        }));
+    }
+
+    /// Refresh the session state and answer [`BugReportRequested`] events
+    ///
+    /// Called by the runners once per tick, before events are dispatched.
+    pub async fn record(&self, scene: Option<String>, resources: &ResourceContext) {
+        let now = Instant::now();
+        let mut requests = Vec::new();
+        let mut event_bus = None;
+        let mut mod_log = Vec::new();
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            event_bus = Some(bus.stats());
+            mod_log.extend(bus.reader::<ModLogEvent>().iter().map(|e| e.entry.clone()));
+            requests.extend(bus.reader::<BugReportRequested>().iter().cloned());
+        }
+        let mods = match resources.get::<ModRegistry>().await {
+            Some(registry) => serde_json::to_value(registry.handles()).ok(),
+            None => None,
+        };
+        let flags = match resources.get::<FeatureFlags>().await {
+            Some(flags) => serde_json::to_value(&*flags).ok(),
+            None => None,
+        };
+
+        let frame = self.lock().frames.frames;
+        let snapshot = if self.config.privacy == CrashPrivacy::IncludeGameState
+            && frame.is_multiple_of(self.config.snapshot_interval.max(1))
+        {
+            let mut values = BTreeMap::new();
+            for (name, snapshot) in &self.config.observed {
+                if let Some(value) = snapshot(resources).await {
+                    values.insert(name.clone(), value);
+                }
+            }
+            Some(values)
+        } else {
+            None
+        };
+
+        {
+            let history = self.config.history;
+            let mut state = self.lock();
+            state.scene = scene;
+            state.event_bus = event_bus;
+            state.mods = mods;
+            state.flags = flags;
+            if snapshot.is_some() {
+                state.resources = snapshot;
+            }
+            state.mod_log.extend(mod_log);
+            while state.mod_log.len() > history {
+                state.mod_log.pop_front();
+            }
+
+            if let Some(last) = state.last_tick.replace(now) {
+                let sample = FrameSample {
+                    frame,
+                    duration_ms: now.duration_since(last).as_secs_f64() * 1000.0,
+                };
+                state.frames.recent.push_back(sample);
+                while state.frames.recent.len() > history {
+                    state.frames.recent.pop_front();
+                }
+            }
+            state.frames.frames += 1;
+        }
+
+        for request in requests {
+            match self.write(ReportKind::BugReport, None, request.description) {
+                Ok(path) => {
+                    if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                        bus.publish(BugReportSaved { path });
+                    }
+                }
+                Err(e) => eprintln!("[CrashReporter] Failed to write bug report: {}", e),
+            }
+        }
+    }
+
+    /// Write a bug report bundle now
+    pub fn write_bug_report(&self, description: Option<String>) -> Result<PathBuf> {
+        self.write(ReportKind::BugReport, None, description)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SessionState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Assemble and save a bundle, skipping sections that fail
+    fn write(
+        &self,
+        kind: ReportKind,
+        panic: Option<String>,
+        description: Option<String>,
+    ) -> Result<PathBuf> {
+        let mut bundle = SectionWriter::default();
+        if let Some(panic) = panic {
+            bundle.add("panic.txt", Ok(panic.into_bytes()));
+        }
+
+        // The panicking thread may hold the lock; never wait for it
+        let state = match self.state.try_lock() {
+            Ok(state) => Some(state),
+            Err(TryLockError::Poisoned(e)) => Some(e.into_inner()),
+            Err(TryLockError::WouldBlock) => None,
+        };
+        let scene = state.as_ref().and_then(|s| s.scene.clone());
+        match &state {
+            Some(state) => {
+                bundle.add_json("event_bus.json", state.event_bus.as_ref(), "no EventBus");
+                bundle.add_json("frames.json", Some(&state.frames), "");
+                bundle.add_json("mods.json", state.mods.as_ref(), "no ModRegistry");
+                bundle.add_json(
+                    "feature_flags.json",
+                    state.flags.as_ref(),
+                    "no FeatureFlags",
+                );
+                bundle.add_json("mod_log.json", Some(&state.mod_log), "");
+                if self.config.privacy == CrashPrivacy::IncludeGameState {
+                    bundle.add_json(
+                        "resources.json",
+                        state.resources.as_ref(),
+                        "no snapshot taken yet",
+                    );
+                }
+            }
+            None => bundle
+                .notes
+                .push("session state: locked by the crashing thread".to_string()),
+        }
+        drop(state);
+
+        let created_at = chrono::Utc::now();
+        let manifest = serde_json::json!({
+            "kind": kind.name(),
+            "created_at": created_at.to_rfc3339(),
+            "engine_version": env!("CARGO_PKG_VERSION"),
+            "game": {
+                "name": self.config.game_name,
+                "version": self.config.game_version,
+            },
+            "scene": scene,
+            "description": description,
+            "privacy": self.config.privacy,
+            "sections": bundle.sections,
+            "notes": bundle.notes,
+        });
+        let mut writer = bundle.writer;
+        writer.add(
+            "manifest.json",
+            &serde_json::to_vec_pretty(&manifest).unwrap_or_default(),
+        );
+
+        std::fs::create_dir_all(&self.config.directory)?;
+        let path = self.config.directory.join(format!(
+            "{}-{}.zip",
+            kind.name(),
+            created_at.format("%Y%m%d-%H%M%S%.3f")
+        ));
+        std::fs::write(&path, writer.finish())?;
+        Ok(path)
+    }
+}
+
+impl std::fmt::Debug for CrashReporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CrashReporter")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Bundle under construction with its section list and notes
+#[derive(Default)]
+struct SectionWriter {
+    writer: BundleWriter,
+    sections: Vec<String>,
+    notes: Vec<String>,
+}
+
+impl SectionWriter {
+    fn add(&mut self, name: &str, contents: std::result::Result<Vec<u8>, String>) {
+        match contents {
+            Ok(contents) => {
+                self.writer.add(name, &contents);
+                self.sections.push(name.to_string());
+            }
+            Err(reason) => self.notes.push(format!("{}: {}", name, reason)),
+        }
+    }
+
+    /// Add `value` as JSON, or note `missing` when it is `None`
+    fn add_json<T: Serialize>(&mut self, name: &str, value: Option<&T>, missing: &str) {
+        let contents = match value {
+            Some(value) => serde_json::to_vec_pretty(value).map_err(|e| e.to_string()),
+            None => Err(missing.to_string()),
+        };
+        self.add(name, contents);
+    }
+}
+
+/// Let the game's [`CrashReporter`], if any, record this tick
+pub(crate) async fn record_tick<S: Scene>(director: &mut SceneDirector<S>) {
+    let reporter = match director.resources().get::<CrashReporter>().await {
+        Some(reporter) => reporter.clone(),
+        None => return,
+    };
+    let scene = director.current().map(|scene| scene.scene_name());
+    reporter.record(scene, director.resources()).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bug_report_notes_missing_sections() {
+        let dir = tempfile::tempdir().unwrap();
+        let reporter = CrashReporter::new(
+            CrashReportConfig::new(dir.path())
+                .with_game("test-game", "1.2.3")
+                .with_privacy(CrashPrivacy::IncludeGameState),
+        );
+
+        let path = reporter
+            .write_bug_report(Some("stuck on floor 7".into()))
+            .unwrap();
+        assert!(path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("bug-report-"));
+
+        let bundle = CrashBundle::open(&path).unwrap();
+        let manifest = bundle.get_json("manifest.json").unwrap();
+        assert_eq!(manifest["kind"], "bug-report");
+        assert_eq!(manifest["game"]["version"], "1.2.3");
+        assert_eq!(manifest["description"], "stuck on floor 7");
+        assert!(bundle.get("frames.json").is_some());
+        assert!(bundle.get("event_bus.json").is_none());
+
+        let notes: Vec<String> = serde_json::from_value(manifest["notes"].clone()).unwrap();
+        assert!(notes.contains(&"event_bus.json: no EventBus".to_string()));
+        assert!(notes.contains(&"resources.json: no snapshot taken yet".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_record_tracks_frames_and_mod_log() {
+        let reporter = CrashReporter::new(CrashReportConfig::new("unused").with_history(2));
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+
+        for i in 0..4 {
+            {
+                let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+                bus.publish(ModLogEvent {
+                    entry: ModLogEntry::new(
+                        None,
+                        crate::modding::ModLogLevel::Info,
+                        format!("line {}", i),
+                    ),
+                });
+                bus.dispatch();
+            }
+            reporter.record(Some("Dungeon".into()), &resources).await;
+        }
+
+        let stats = reporter.frame_stats();
+        assert_eq!(stats.frames, 4);
+        assert_eq!(stats.recent.len(), 2);
+        assert_eq!(stats.recent.back().unwrap().frame, 3);
+
+        let state = reporter.lock();
+        assert_eq!(state.scene.as_deref(), Some("Dungeon"));
+        let lines: Vec<&str> = state.mod_log.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(lines, vec!["line 2", "line 3"]);
+    }
+}
@@ -0,0 +1,433 @@
+//! Feature flags with runtime toggles
+//!
+//! Flags are declared at build time with a default, then overridden in this
+//! order (later wins):
+//!
+//! 1. the default from [`GameBuilder::declare_flag`](crate::builder::GameBuilder::declare_flag)
+//!    or `PluginBuilder::declare_flag`
+//! 2. the flags file from [`GameBuilder::with_flags_file`](crate::builder::GameBuilder::with_flags_file)
+//! 3. `ISSUN_FLAG_<NAME>` environment variables (`ISSUN_FLAG_NEW_DAMAGE_PIPELINE=1`)
+//! 4. [`SetFeatureFlagRequested`] events at runtime, e.g. from the debug console
+//!
+//! Systems registered with [`gated_on`](crate::system::SystemExt::gated_on)
+//! are skipped by the runners while their flag is off.
+//!
+//! ```ignore
+//! let game = GameBuilder::new()
+//!     .declare_flag("new_damage_pipeline", false)
+//!     .with_flags_file("flags.toml")
+//!     .with_system(DamagePipelineSystem::new().gated_on("new_damage_pipeline"))
+//!     .build()
+//!     .await?;
+//!
+//! // In a system
+//! let flags = resources.get::<FeatureFlags>().await.unwrap();
+//! if flags.is_enabled("new_damage_pipeline") { /* ... */ }
+//! ```
+//!
+//! MODs read flags as plugin params (`get_plugin_param("flags", name)`) and
+//! may only set their own flags, named `"<mod_id>:<name>"`; the first
+//! `set_plugin_param("flags", "<mod_id>:<name>", true)` declares one.
+
+use crate::context::ResourceContext;
+use crate::error::{IssunError, Result};
+use crate::event::{Event, EventBus};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// Prefix of the environment variables that override flags
+pub const FLAG_ENV_PREFIX: &str = "ISSUN_FLAG_";
+
+/// Where a flag's current value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlagSource {
+    Default,
+    ConfigFile,
+    Env,
+    Runtime,
+}
+
+/// A declared flag
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureFlag {
+    pub enabled: bool,
+    /// Value it was declared with
+    pub default: bool,
+    pub source: FlagSource,
+    /// MOD that declared it; `None` for flags of the game
+    pub owner: Option<String>,
+}
+
+/// Why a flag could not be set
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FlagError {
+    #[error("feature flag '{0}' is not declared")]
+    Undeclared(String),
+    #[error("MOD '{issuer}' may only set its own flags ('{issuer}:*'), not '{name}'")]
+    NotOwner { name: String, issuer: String },
+}
+
+/// Declared flags and their current values (resource)
+///
+/// Clones share the same flags, so a system can keep one and query it
+/// without going through `ResourceContext`.
+#[derive(Clone, Default)]
+pub struct FeatureFlags {
+    flags: Arc<RwLock<BTreeMap<String, FeatureFlag>>>,
+}
+
+impl FeatureFlags {
+    /// Create an empty flag set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare `name` with `default`; an existing flag keeps its value
+    pub fn declare(&self, name: impl Into<String>, default: bool) {
+        self.write()
+            .entry(name.into())
+            .or_insert_with(|| FeatureFlag {
+                enabled: default,
+                default,
+                source: FlagSource::Default,
+                owner: None,
+            });
+    }
+
+    /// Whether `name` is on; undeclared flags are off
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.read().get(name).is_some_and(|flag| flag.enabled)
+    }
+
+    /// Whether `name` is declared
+    pub fn is_declared(&self, name: &str) -> bool {
+        self.read().contains_key(name)
+    }
+
+    /// Current state of `name`
+    pub fn get(&self, name: &str) -> Option<FeatureFlag> {
+        self.read().get(name).cloned()
+    }
+
+    /// Every flag with its current value, sorted by name
+    pub fn values(&self) -> BTreeMap<String, bool> {
+        self.read()
+            .iter()
+            .map(|(name, flag)| (name.clone(), flag.enabled))
+            .collect()
+    }
+
+    /// Every flag, sorted by name
+    pub fn entries(&self) -> BTreeMap<String, FeatureFlag> {
+        self.read().clone()
+    }
+
+    /// Set `name` on behalf of `issuer` (a MOD id, `None` for the game)
+    ///
+    /// The game may set any declared flag. A MOD may only set flags named
+    /// `"<issuer>:..."`, declaring them on first use. Returns whether the
+    /// value changed.
+    pub fn set(
+        &self,
+        name: &str,
+        enabled: bool,
+        issuer: Option<&str>,
+    ) -> std::result::Result<bool, FlagError> {
+        let mut flags = self.write();
+        if let Some(issuer) = issuer {
+            let owned = name
+                .strip_prefix(issuer)
+                .is_some_and(|rest| rest.starts_with(':'));
+            if !owned {
+                return Err(FlagError::NotOwner {
+                    name: name.to_string(),
+                    issuer: issuer.to_string(),
+                });
+            }
+            flags
+                .entry(name.to_string())
+                .or_insert_with(|| FeatureFlag {
+                    enabled: false,
+                    default: false,
+                    source: FlagSource::Default,
+                    owner: Some(issuer.to_string()),
+                });
+        }
+
+        let flag = flags
+            .get_mut(name)
+            .ok_or_else(|| FlagError::Undeclared(name.to_string()))?;
+        let changed = flag.enabled != enabled;
+        flag.enabled = enabled;
+        flag.source = FlagSource::Runtime;
+        Ok(changed)
+    }
+
+    /// Apply `name = true/false` entries of a flags file
+    ///
+    /// Unknown names are ignored with a warning; a file can serve several
+    /// builds.
+    pub fn apply_file(&self, content: &str) -> Result<()> {
+        let values: BTreeMap<String, bool> = toml::from_str(content)
+            .map_err(|e| IssunError::Serialization(format!("Invalid flags file: {}", e)))?;
+        self.apply(values, FlagSource::ConfigFile);
+        Ok(())
+    }
+
+    /// Apply `ISSUN_FLAG_<NAME>` variables from `vars`
+    ///
+    /// `<NAME>` is the flag name in upper case with every character that is
+    /// not a letter or digit replaced by `_`. Accepted values are
+    /// `1/0`, `true/false`, `on/off` and `yes/no`.
+    pub fn apply_env(&self, vars: impl IntoIterator<Item = (String, String)>) {
+        let names: BTreeMap<String, String> = self
+            .read()
+            .keys()
+            .map(|name| (env_var_name(name), name.clone()))
+            .collect();
+
+        let mut values = BTreeMap::new();
+        for (var, value) in vars {
+            if !var.starts_with(FLAG_ENV_PREFIX) {
+                continue;
+            }
+            let Some(name) = names.get(&var) else {
+                eprintln!("[FeatureFlags] {} does not match a declared flag", var);
+                continue;
+            };
+            match parse_bool(&value) {
+                Some(enabled) => {
+                    values.insert(name.clone(), enabled);
+                }
+                None => eprintln!("[FeatureFlags] {}={} is not a boolean", var, value),
+            }
+        }
+        self.apply(values, FlagSource::Env);
+    }
+
+    fn apply(&self, values: BTreeMap<String, bool>, source: FlagSource) {
+        let mut flags = self.write();
+        for (name, enabled) in values {
+            match flags.get_mut(&name) {
+                Some(flag) => {
+                    flag.enabled = enabled;
+                    flag.source = source;
+                }
+                None => eprintln!("[FeatureFlags] Ignoring undeclared flag '{}'", name),
+            }
+        }
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, BTreeMap<String, FeatureFlag>> {
+        self.flags.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, BTreeMap<String, FeatureFlag>> {
+        self.flags.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl std::fmt::Debug for FeatureFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.values()).finish()
+    }
+}
+
+impl Serialize for FeatureFlags {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        self.entries().serialize(serializer)
+    }
+}
+
+/// Environment variable that overrides flag `name`
+pub fn env_var_name(name: &str) -> String {
+    let suffix: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}{}", FLAG_ENV_PREFIX, suffix)
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "on" | "yes" => Some(true),
+        "0" | "false" | "off" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// Read a flags file for [`FeatureFlags::apply_file`]
+pub(crate) fn read_flags_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| {
+        IssunError::Io(std::io::Error::new(
+            e.kind(),
+            format!("Failed to read flags file {}: {}", path.display(), e),
+        ))
+    })
+}
+
+/// Request to turn a flag on or off
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetFeatureFlagRequested {
+    pub name: String,
+    pub enabled: bool,
+    /// MOD that asks; `None` for the game or the debug console
+    #[serde(default)]
+    pub issuer: Option<String>,
+}
+
+impl Event for SetFeatureFlagRequested {}
+
+/// A flag changed its value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureFlagChanged {
+    pub name: String,
+    pub enabled: bool,
+    pub issuer: Option<String>,
+}
+
+impl Event for FeatureFlagChanged {}
+
+/// A [`SetFeatureFlagRequested`] was refused
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureFlagRejected {
+    pub name: String,
+    pub enabled: bool,
+    pub issuer: Option<String>,
+    pub reason: String,
+}
+
+impl Event for FeatureFlagRejected {}
+
+/// Applies [`SetFeatureFlagRequested`] events
+///
+/// Registered by `GameBuilder` and updated by the runners before any other
+/// system, so a toggle takes effect in the tick after it was published.
+#[derive(Debug, Default, Clone, crate::System)]
+#[system(name = "feature_flag_system")]
+pub struct FeatureFlagSystem;
+
+impl FeatureFlagSystem {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Apply this tick's requests; publishes `FeatureFlagChanged` for every
+    /// value that changed and `FeatureFlagRejected` for refused requests
+    pub async fn update(&mut self, resources: &mut ResourceContext) {
+        let requests: Vec<SetFeatureFlagRequested> = match resources.get_mut::<EventBus>().await {
+            Some(mut bus) => bus
+                .reader::<SetFeatureFlagRequested>()
+                .iter()
+                .cloned()
+                .collect(),
+            None => return,
+        };
+        if requests.is_empty() {
+            return;
+        }
+        let Some(flags) = resources.get::<FeatureFlags>().await.map(|f| f.clone()) else {
+            return;
+        };
+
+        let Some(mut bus) = resources.get_mut::<EventBus>().await else {
+            return;
+        };
+        for request in requests {
+            match flags.set(&request.name, request.enabled, request.issuer.as_deref()) {
+                Ok(true) => bus.publish(FeatureFlagChanged {
+                    name: request.name,
+                    enabled: request.enabled,
+                    issuer: request.issuer,
+                }),
+                Ok(false) => {}
+                Err(error) => bus.publish(FeatureFlagRejected {
+                    reason: error.to_string(),
+                    name: request.name,
+                    enabled: request.enabled,
+                    issuer: request.issuer,
+                }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overrides_apply_in_order() {
+        let flags = FeatureFlags::new();
+        flags.declare("new_damage_pipeline", false);
+        flags.declare("diffusion-model", true);
+        flags.declare("untouched", true);
+
+        flags
+            .apply_file("new_damage_pipeline = true\ndiffusion-model = true")
+            .unwrap();
+        flags.apply_env([
+            ("ISSUN_FLAG_DIFFUSION_MODEL".to_string(), "off".to_string()),
+            ("PATH".to_string(), "/bin".to_string()),
+        ]);
+
+        let new_damage = flags.get("new_damage_pipeline").unwrap();
+        assert!(new_damage.enabled);
+        assert_eq!(new_damage.source, FlagSource::ConfigFile);
+        let diffusion = flags.get("diffusion-model").unwrap();
+        assert!(!diffusion.enabled);
+        assert_eq!(diffusion.source, FlagSource::Env);
+        assert_eq!(flags.get("untouched").unwrap().source, FlagSource::Default);
+        assert!(flags.apply_file("new_damage_pipeline = 1").is_err());
+    }
+
+    #[test]
+    fn test_mods_only_set_their_own_flags() {
+        let flags = FeatureFlags::new();
+        flags.declare("new_damage_pipeline", false);
+
+        assert_eq!(
+            flags.set("new_damage_pipeline", true, Some("tweaks")),
+            Err(FlagError::NotOwner {
+                name: "new_damage_pipeline".to_string(),
+                issuer: "tweaks".to_string(),
+            })
+        );
+        assert!(flags.set("tweaksmore:x", true, Some("tweaks")).is_err());
+
+        assert_eq!(
+            flags.set("tweaks:fast_mode", true, Some("tweaks")),
+            Ok(true)
+        );
+        let flag = flags.get("tweaks:fast_mode").unwrap();
+        assert_eq!(flag.owner.as_deref(), Some("tweaks"));
+        assert!(flag.enabled);
+
+        assert_eq!(flags.set("new_damage_pipeline", true, None), Ok(true));
+        assert_eq!(flags.set("new_damage_pipeline", true, None), Ok(false));
+        assert_eq!(
+            flags.set("missing", true, None),
+            Err(FlagError::Undeclared("missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_env_var_name() {
+        assert_eq!(
+            env_var_name("new_damage_pipeline"),
+            "ISSUN_FLAG_NEW_DAMAGE_PIPELINE"
+        );
+        assert_eq!(env_var_name("mod:fast-mode"), "ISSUN_FLAG_MOD_FAST_MODE");
+    }
+}
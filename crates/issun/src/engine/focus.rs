@@ -0,0 +1,450 @@
+//! Terminal focus handling: keep simulating, slow down or pause while away
+//!
+//! [`GameRunner::with_focus_tracking`](crate::engine::GameRunner::with_focus_tracking)
+//! feeds a [`FocusTracker`] with terminal focus events (crossterm
+//! `FocusGained`/`FocusLost`, where the terminal reports them) and with
+//! input. Terminals that never report focus fall back to an idle heuristic:
+//! no input for [`FocusConfig::idle_timeout`] counts as focus lost, and the
+//! next input as focus regained.
+//!
+//! Every transition publishes one [`WindowFocusChanged`]. While unfocused,
+//! the [`FocusPolicy`] applies:
+//!
+//! - `ContinueNormal`: nothing changes
+//! - `ReduceTickRate(factor)`: the tick interval is stretched by `factor` and
+//!   rendering is skipped, so the simulation keeps going on little CPU
+//! - `PauseSimulation`: [`SimulationControl`] is paused until focus returns
+//!
+//! On return an [`AwaySummary`] lists the in-game days that passed and the
+//! notable events collected meanwhile.
+//!
+//! ```ignore
+//! let tracker = FocusTracker::new()
+//!     .with_notable::<DayChanged>(|event| format!("Day {} began", event.day))
+//!     .with_notable::<RaidRepelled>(|event| format!("{} repelled", event.raider));
+//!
+//! let game = GameBuilder::new()
+//!     .with_resource(FocusConfig::new(FocusPolicy::PauseSimulation).with_autosave(true))
+//!     .build()
+//!     .await?;
+//! GameRunner::new(director).with_focus_tracking(tracker).run(&mut tui, render, on_input).await?;
+//! ```
+//!
+//! The player's choice from a settings screen goes to
+//! [`FocusConfig::player_policy`], which overrides the game's policy.
+
+use crate::context::ResourceContext;
+use crate::event::{Event, EventBus};
+use crate::plugin::save_load::AutoSaveRequested;
+use crate::plugin::time::GameTimer;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// What happens to the simulation while the terminal is unfocused
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FocusPolicy {
+    /// Keep ticking and rendering as usual
+    #[default]
+    ContinueNormal,
+    /// Multiply the tick interval by the factor and skip rendering
+    ReduceTickRate(u32),
+    /// Pause [`SimulationControl`] until focus returns
+    PauseSimulation,
+}
+
+/// Focus handling of the game, read every frame
+#[derive(crate::Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FocusConfig {
+    /// The game's policy
+    pub policy: FocusPolicy,
+    /// The player's policy from the settings, if they chose one
+    #[serde(default)]
+    pub player_policy: Option<FocusPolicy>,
+    /// No input for this long counts as focus lost (`None`: terminal focus
+    /// events only)
+    #[serde(default)]
+    pub idle_timeout: Option<Duration>,
+    /// Publish `AutoSaveRequested` when focus is lost
+    #[serde(default)]
+    pub autosave_on_focus_loss: bool,
+}
+
+impl Default for FocusConfig {
+    fn default() -> Self {
+        Self::new(FocusPolicy::ContinueNormal)
+    }
+}
+
+impl FocusConfig {
+    pub fn new(policy: FocusPolicy) -> Self {
+        Self {
+            policy,
+            player_policy: None,
+            idle_timeout: Some(Duration::from_secs(120)),
+            autosave_on_focus_loss: false,
+        }
+    }
+
+    pub fn with_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    pub fn with_autosave(mut self, enabled: bool) -> Self {
+        self.autosave_on_focus_loss = enabled;
+        self
+    }
+
+    /// The player's policy if set, the game's otherwise
+    pub fn effective_policy(&self) -> FocusPolicy {
+        self.player_policy.unwrap_or(self.policy)
+    }
+}
+
+/// Whether scene updates and systems run
+///
+/// While paused, `GameRunner` keeps rendering, handling input and
+/// dispatching events, but skips `Scene::on_update` and the system updates.
+#[derive(crate::Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimulationControl {
+    paused: bool,
+}
+
+impl SimulationControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+/// How a focus change was detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FocusSource {
+    /// The terminal reported `FocusGained`/`FocusLost`
+    Terminal,
+    /// No input for the idle timeout
+    Idle,
+    /// Input arrived after an idle focus loss
+    Input,
+}
+
+/// The terminal gained or lost focus; published once per transition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindowFocusChanged {
+    pub focused: bool,
+    pub source: FocusSource,
+}
+
+impl Event for WindowFocusChanged {}
+
+/// "While you were away": published when focus returns
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AwaySummary {
+    /// Real time since focus was lost (for idle detection: since the last
+    /// input)
+    pub away_for: Duration,
+    /// In-game days that passed meanwhile (0 without a `GameTimer`)
+    pub days_elapsed: u32,
+    /// Descriptions of the notable events, oldest first
+    pub notable: Vec<String>,
+}
+
+impl Event for AwaySummary {}
+
+/// Signal from the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusSignal {
+    Gained,
+    Lost,
+    /// Any key press
+    Input,
+}
+
+/// Per-frame decision of the runner
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FramePlan {
+    /// Interval between simulation ticks
+    pub tick_interval: Duration,
+    /// Whether to draw this frame
+    pub render: bool,
+}
+
+type NotableTap = Box<dyn FnMut(&mut EventBus, &mut Vec<String>) + Send>;
+
+/// Where the current absence started
+struct Away {
+    since: Instant,
+    day: Option<u32>,
+    paused_simulation: bool,
+}
+
+/// Tracks terminal focus and applies the [`FocusConfig`] of the game
+pub struct FocusTracker {
+    focused: bool,
+    last_input: Option<(Instant, Option<u32>)>,
+    away: Option<Away>,
+    taps: Vec<NotableTap>,
+    /// Notable events since the last input (focused) or the absence began
+    notable: Vec<String>,
+}
+
+impl Default for FocusTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FocusTracker {
+    pub fn new() -> Self {
+        Self {
+            focused: true,
+            last_input: None,
+            away: None,
+            taps: Vec::new(),
+            notable: Vec::new(),
+        }
+    }
+
+    /// List events of type `E` in the [`AwaySummary`], described by `describe`
+    pub fn with_notable<E: Event>(
+        mut self,
+        describe: impl Fn(&E) -> String + Send + 'static,
+    ) -> Self {
+        self.taps.push(Box::new(move |bus, notable| {
+            notable.extend(bus.reader::<E>().iter().map(&describe));
+        }));
+        self
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Handle a terminal signal
+    pub async fn signal(
+        &mut self,
+        signal: FocusSignal,
+        now: Instant,
+        resources: &mut ResourceContext,
+    ) {
+        match signal {
+            FocusSignal::Gained => self.regain(FocusSource::Terminal, now, resources).await,
+            FocusSignal::Lost => {
+                let day = current_day(resources).await;
+                self.lose(FocusSource::Terminal, now, day, resources).await;
+            }
+            FocusSignal::Input => {
+                if !self.focused {
+                    self.regain(FocusSource::Input, now, resources).await;
+                }
+                self.last_input = Some((now, current_day(resources).await));
+                self.notable.clear();
+            }
+        }
+    }
+
+    /// Apply the idle heuristic; call once per frame
+    pub async fn poll_idle(&mut self, now: Instant, resources: &mut ResourceContext) {
+        let Some(timeout) = config(resources).await.idle_timeout else {
+            return;
+        };
+        if !self.focused {
+            return;
+        }
+        let (last, day) = match self.last_input {
+            Some(last_input) => last_input,
+            None => {
+                // Nothing typed yet: count from the first frame
+                let start = (now, current_day(resources).await);
+                self.last_input = Some(start);
+                start
+            }
+        };
+        if now.duration_since(last) >= timeout {
+            self.lose(FocusSource::Idle, last, day, resources).await;
+        }
+    }
+
+    /// Collect notable events readable on the bus; call once per frame,
+    /// before `EventBus::dispatch`
+    pub async fn collect(&mut self, resources: &mut ResourceContext) {
+        if self.taps.is_empty() {
+            return;
+        }
+        let Some(mut bus) = resources.get_mut::<EventBus>().await else {
+            return;
+        };
+        for tap in &mut self.taps {
+            tap(&mut bus, &mut self.notable);
+        }
+    }
+
+    /// Tick interval and rendering for this frame under `config`
+    pub fn frame_plan(&self, base: Duration, config: &FocusConfig) -> FramePlan {
+        match config.effective_policy() {
+            FocusPolicy::ReduceTickRate(factor) if !self.focused => FramePlan {
+                tick_interval: base.saturating_mul(factor.max(1)),
+                render: false,
+            },
+            _ => FramePlan {
+                tick_interval: base,
+                render: true,
+            },
+        }
+    }
+
+    async fn lose(
+        &mut self,
+        source: FocusSource,
+        since: Instant,
+        day: Option<u32>,
+        resources: &mut ResourceContext,
+    ) {
+        if !self.focused {
+            return;
+        }
+        self.focused = false;
+        if source == FocusSource::Terminal {
+            // Events since the last input happened while the player was here
+            self.notable.clear();
+        }
+
+        let config = config(resources).await;
+        let paused_simulation = config.effective_policy() == FocusPolicy::PauseSimulation;
+        if paused_simulation {
+            pause_simulation(resources, true).await;
+        }
+        self.away = Some(Away {
+            since,
+            day,
+            paused_simulation,
+        });
+
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.publish(WindowFocusChanged {
+                focused: false,
+                source,
+            });
+            if config.autosave_on_focus_loss {
+                bus.publish(AutoSaveRequested {
+                    reason: Some("focus_lost".to_string()),
+                });
+            }
+        }
+    }
+
+    async fn regain(&mut self, source: FocusSource, now: Instant, resources: &mut ResourceContext) {
+        if self.focused {
+            return;
+        }
+        self.focused = true;
+        // Idle detection restarts from here
+        let day = current_day(resources).await;
+        self.last_input = Some((now, day));
+
+        let away = self.away.take();
+        if away.as_ref().is_some_and(|away| away.paused_simulation) {
+            pause_simulation(resources, false).await;
+        }
+
+        let Some(mut bus) = resources.get_mut::<EventBus>().await else {
+            return;
+        };
+        bus.publish(WindowFocusChanged {
+            focused: true,
+            source,
+        });
+        if let Some(away) = away {
+            bus.publish(AwaySummary {
+                away_for: now.saturating_duration_since(away.since),
+                days_elapsed: day
+                    .zip(away.day)
+                    .map_or(0, |(now, then)| now.saturating_sub(then)),
+                notable: std::mem::take(&mut self.notable),
+            });
+        }
+    }
+}
+
+async fn config(resources: &ResourceContext) -> FocusConfig {
+    resources
+        .get::<FocusConfig>()
+        .await
+        .map(|config| config.clone())
+        .unwrap_or_default()
+}
+
+async fn current_day(resources: &ResourceContext) -> Option<u32> {
+    resources.get::<GameTimer>().await.map(|timer| timer.day)
+}
+
+async fn pause_simulation(resources: &mut ResourceContext, paused: bool) {
+    if !resources.contains::<SimulationControl>() {
+        resources.insert(SimulationControl::new());
+    }
+    if let Some(mut control) = resources.get_mut::<SimulationControl>().await {
+        if paused {
+            control.pause();
+        } else {
+            control.resume();
+        }
+    }
+}
+
+/// Whether `SimulationControl` pauses the simulation
+pub(crate) async fn simulation_paused(resources: &ResourceContext) -> bool {
+    resources
+        .get::<SimulationControl>()
+        .await
+        .is_some_and(|control| control.is_paused())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_player_policy_overrides_game_policy() {
+        let mut config = FocusConfig::new(FocusPolicy::PauseSimulation);
+        assert_eq!(config.effective_policy(), FocusPolicy::PauseSimulation);
+        config.player_policy = Some(FocusPolicy::ContinueNormal);
+        assert_eq!(config.effective_policy(), FocusPolicy::ContinueNormal);
+    }
+
+    #[test]
+    fn test_frame_plan_stretches_only_while_unfocused() {
+        let config = FocusConfig::new(FocusPolicy::ReduceTickRate(4));
+        let mut tracker = FocusTracker::new();
+        let base = Duration::from_millis(33);
+        assert_eq!(
+            tracker.frame_plan(base, &config),
+            FramePlan {
+                tick_interval: base,
+                render: true
+            }
+        );
+
+        tracker.focused = false;
+        assert_eq!(
+            tracker.frame_plan(base, &config),
+            FramePlan {
+                tick_interval: Duration::from_millis(132),
+                render: false
+            }
+        );
+        // Factor 0 is treated as 1
+        let config = FocusConfig::new(FocusPolicy::ReduceTickRate(0));
+        assert_eq!(tracker.frame_plan(base, &config).tick_interval, base);
+    }
+}
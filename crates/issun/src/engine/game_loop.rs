@@ -71,7 +71,12 @@ impl GameLoop {
                 SceneTransition::Stay => {
                     // Continue current scene
                 }
-                SceneTransition::Switch(_) | SceneTransition::Push(_) | SceneTransition::Pop => {
+                SceneTransition::Switch(_)
+                | SceneTransition::Push(_)
+                | SceneTransition::Pop
+                | SceneTransition::Replace(_)
+                | SceneTransition::Restart(_)
+                | SceneTransition::Styled(..) => {
                     // Scene requests transition
                     // TODO: Handle scene transitions with SceneDirector
                     break;
@@ -4,6 +4,13 @@
 //! Useful for server-side simulation, testing, and AI training.
 
 use crate::{
+    engine::{
+        crash::record_tick,
+        lifecycle::{exit_plugins, start_plugins},
+        lockstep::{pass_tick_gate, TickGate},
+        playtime::record_playtime,
+        query::QueryReceiver,
+    },
     error::Result,
     event::EventBus,
     scene::{Scene, SceneDirector},
@@ -48,6 +55,8 @@ pub struct HeadlessRunner<S> {
     director: SceneDirector<S>,
     tick_rate: Duration,
     max_ticks: Option<u64>,
+    tick_gate: Option<Box<dyn TickGate>>,
+    queries: Option<QueryReceiver>,
 }
 
 impl<S: Scene> HeadlessRunner<S> {
@@ -57,6 +66,8 @@ impl<S: Scene> HeadlessRunner<S> {
             director,
             tick_rate: Duration::from_millis(100),
             max_ticks: None,
+            tick_gate: None,
+            queries: None,
         }
     }
 
@@ -75,6 +86,25 @@ impl<S: Scene> HeadlessRunner<S> {
         self
     }
 
+    /// Gate every tick on a [`TickGate`] (e.g. a
+    /// [`LockstepDriver`](crate::engine::LockstepDriver)).
+    ///
+    /// Stalled frames skip the scene update, systems and event dispatch, and
+    /// do not count towards `max_ticks`.
+    pub fn with_tick_gate(mut self, gate: impl TickGate + 'static) -> Self {
+        self.tick_gate = Some(Box::new(gate));
+        self
+    }
+
+    /// Serve a [query channel](crate::engine::query) between ticks.
+    ///
+    /// Pending queries run at the start of every frame, before the tick gate,
+    /// so they are answered even while lockstep stalls.
+    pub fn with_query_channel(mut self, queries: QueryReceiver) -> Self {
+        self.queries = Some(queries);
+        self
+    }
+
     /// Borrow the underlying director.
     pub fn director(&self) -> &SceneDirector<S> {
         &self.director
@@ -85,57 +115,27 @@ impl<S: Scene> HeadlessRunner<S> {
         &mut self.director
     }
 
-    /// Update all registered systems that require periodic updates.
-    ///
-    /// This method processes event-driven systems like TimerSystem and ActionResetSystem
-    /// that respond to published events.
-    async fn update_systems(&mut self) {
-        use crate::plugin::action::ActionResetSystem;
-        use crate::plugin::time::TimerSystem;
-
-        // Update TimerSystem (processes AdvanceTimeRequested → DayChanged)
-        self.director
-            .with_current_async(|_, services, systems, resources| {
-                Box::pin(async move {
-                    if let Some(timer_system) = systems.get_mut::<TimerSystem>() {
-                        timer_system.update(services, resources).await;
-                    }
-                })
-            })
-            .await;
-
-        // Update ActionResetSystem (processes DayChanged → reset action points)
-        self.director
-            .with_current_async(|_, services, systems, resources| {
-                Box::pin(async move {
-                    if let Some(action_reset) = systems.get_mut::<ActionResetSystem>() {
-                        action_reset.update(services, resources).await;
-                    }
-                })
-            })
-            .await;
-    }
-
     /// Run the headless game loop until the director requests quit or max_ticks is reached.
+    ///
+    /// Plugin `on_start` hooks run before the first tick and `on_exit` hooks
+    /// run once the loop ends.
     pub async fn run(mut self) -> Result<()> {
         let mut interval = time::interval(self.tick_rate);
         let mut tick_count = 0u64;
 
+        start_plugins(&mut self.director).await;
+
         loop {
             interval.tick().await;
 
-            // Scene update (Scene::on_update)
-            let transition = self.director.update().await;
-            self.director.handle(transition).await?;
-
-            // Update registered systems (handles event-driven logic)
-            self.update_systems().await;
+            serve_queries(&mut self.queries, &mut self.director).await;
 
-            // Dispatch events
-            if let Some(mut event_bus) = self.director.resources_mut().get_mut::<EventBus>().await {
-                event_bus.dispatch();
+            if !pass_tick_gate(&mut self.tick_gate, &mut self.director).await {
+                continue;
             }
 
+            run_tick(&mut self.director).await?;
+
             tick_count += 1;
 
             // Check exit conditions
@@ -150,6 +150,7 @@ impl<S: Scene> HeadlessRunner<S> {
             }
         }
 
+        exit_plugins(&mut self.director).await;
         Ok(())
     }
 }
@@ -204,6 +205,8 @@ pub struct ChannelHeadlessRunner<S, Cmd> {
     director: SceneDirector<S>,
     tick_rate: Duration,
     max_ticks: Option<u64>,
+    tick_gate: Option<Box<dyn TickGate>>,
+    queries: Option<QueryReceiver>,
     command_rx: tokio::sync::mpsc::Receiver<Cmd>,
 }
 
@@ -222,6 +225,8 @@ impl<S: Scene> HeadlessRunner<S> {
             director: self.director,
             tick_rate: self.tick_rate,
             max_ticks: self.max_ticks,
+            tick_gate: self.tick_gate,
+            queries: self.queries,
             command_rx,
         }
     }
@@ -238,37 +243,6 @@ impl<S: Scene, Cmd: crate::event::Event + Clone + serde::Serialize> ChannelHeadl
         &mut self.director
     }
 
-    /// Update all registered systems that require periodic updates.
-    ///
-    /// This method processes event-driven systems like TimerSystem and ActionResetSystem
-    /// that respond to published events.
-    async fn update_systems(&mut self) {
-        use crate::plugin::action::ActionResetSystem;
-        use crate::plugin::time::TimerSystem;
-
-        // Update TimerSystem (processes AdvanceTimeRequested → DayChanged)
-        self.director
-            .with_current_async(|_, services, systems, resources| {
-                Box::pin(async move {
-                    if let Some(timer_system) = systems.get_mut::<TimerSystem>() {
-                        timer_system.update(services, resources).await;
-                    }
-                })
-            })
-            .await;
-
-        // Update ActionResetSystem (processes DayChanged → reset action points)
-        self.director
-            .with_current_async(|_, services, systems, resources| {
-                Box::pin(async move {
-                    if let Some(action_reset) = systems.get_mut::<ActionResetSystem>() {
-                        action_reset.update(services, resources).await;
-                    }
-                })
-            })
-            .await;
-    }
-
     /// Run the headless game loop with command channel support.
     ///
     /// This runner uses `tokio::select!` to wait for either:
@@ -277,26 +251,26 @@ impl<S: Scene, Cmd: crate::event::Event + Clone + serde::Serialize> ChannelHeadl
     ///
     /// Commands are published to the EventBus immediately upon receipt and dispatched
     /// right away, providing <1ms latency compared to ~25ms with polling-based approach.
+    ///
+    /// Plugin lifecycle hooks run as in [`HeadlessRunner::run`].
     pub async fn run(mut self) -> Result<()> {
         let mut interval = time::interval(self.tick_rate);
         let mut tick_count = 0u64;
 
+        start_plugins(&mut self.director).await;
+
         loop {
             tokio::select! {
                 // Regular tick update
                 _ = interval.tick() => {
-                    // Scene update (Scene::on_update)
-                    let transition = self.director.update().await;
-                    self.director.handle(transition).await?;
+                    serve_queries(&mut self.queries, &mut self.director).await;
 
-                    // Update registered systems (handles event-driven logic)
-                    self.update_systems().await;
-
-                    // Dispatch events
-                    if let Some(mut event_bus) = self.director.resources_mut().get_mut::<EventBus>().await {
-                        event_bus.dispatch();
+                    if !pass_tick_gate(&mut self.tick_gate, &mut self.director).await {
+                        continue;
                     }
 
+                    run_tick(&mut self.director).await?;
+
                     tick_count += 1;
 
                     // Check exit conditions
@@ -326,19 +300,111 @@ impl<S: Scene, Cmd: crate::event::Event + Clone + serde::Serialize> ChannelHeadl
             }
         }
 
+        exit_plugins(&mut self.director).await;
         Ok(())
     }
 }
 
+/// One headless frame: scene update, event-driven systems, crash-report
+/// sample and event dispatch
+///
+/// Shared by both runners and by [scenario tests](crate::testing::scenario).
+pub(crate) async fn run_tick<S: Scene>(director: &mut SceneDirector<S>) -> Result<()> {
+    // Scene update (Scene::on_update)
+    let transition = director.update().await;
+    director.handle(transition).await?;
+
+    // Update registered systems (handles event-driven logic)
+    update_systems(director).await;
+
+    record_tick(director).await;
+    record_playtime(director).await;
+
+    // Dispatch events
+    if let Some(mut event_bus) = director.resources_mut().get_mut::<EventBus>().await {
+        event_bus.dispatch();
+    }
+    Ok(())
+}
+
+/// Update all registered systems that require periodic updates.
+///
+/// This processes event-driven systems like TimerSystem and ActionResetSystem
+/// that respond to published events. Systems gated on a feature flag that is
+/// off are skipped.
+async fn update_systems<S: Scene>(director: &mut SceneDirector<S>) {
+    use crate::engine::config_file::ConfigReloadSystem;
+    use crate::engine::flags::FeatureFlagSystem;
+    use crate::plugin::action::ActionResetSystem;
+    use crate::plugin::time::TimerSystem;
+
+    // Apply feature flag toggles first so gates see this tick's values
+    director
+        .with_current_async(|_, _, systems, resources| {
+            Box::pin(async move {
+                if let Some(flag_system) = systems.get_mut::<FeatureFlagSystem>() {
+                    flag_system.update(resources).await;
+                }
+            })
+        })
+        .await;
+
+    // Apply config file changes before the systems that read the configs
+    director
+        .with_current_async(|_, _, systems, resources| {
+            Box::pin(async move {
+                if let Some(config_reload) = systems.get_mut::<ConfigReloadSystem>() {
+                    config_reload.update(resources).await;
+                }
+            })
+        })
+        .await;
+
+    // Update TimerSystem (processes AdvanceTimeRequested → DayChanged)
+    director
+        .with_current_async(|_, services, systems, resources| {
+            Box::pin(async move {
+                if let Some(timer_system) = systems.active_mut::<TimerSystem>() {
+                    timer_system.update(services, resources).await;
+                }
+            })
+        })
+        .await;
+
+    // Update ActionResetSystem (processes DayChanged → reset action points)
+    director
+        .with_current_async(|_, services, systems, resources| {
+            Box::pin(async move {
+                if let Some(action_reset) = systems.active_mut::<ActionResetSystem>() {
+                    action_reset.update(services, resources).await;
+                }
+            })
+        })
+        .await;
+}
+
+/// Run pending queries, if the runner has a query channel
+async fn serve_queries<S: Scene>(
+    queries: &mut Option<QueryReceiver>,
+    director: &mut SceneDirector<S>,
+) {
+    if let Some(queries) = queries {
+        queries.serve(director.resources_mut()).await;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         builder::GameBuilder,
         context::{ResourceContext, ServiceContext, SystemContext},
+        engine::lockstep::TickDecision,
         event::Event,
         scene::{Scene, SceneTransition},
     };
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
     use tokio::sync::mpsc;
 
     // Test scene that counts updates
@@ -408,6 +474,143 @@ mod tests {
         // Test passes if run completes without error
     }
 
+    // Gate that stalls every other frame
+    struct AlternatingGate {
+        polls: Arc<AtomicU32>,
+    }
+
+    #[async_trait::async_trait]
+    impl TickGate for AlternatingGate {
+        async fn poll(&mut self, _resources: &mut ResourceContext) -> TickDecision {
+            if self.polls.fetch_add(1, Ordering::SeqCst).is_multiple_of(2) {
+                TickDecision::Stall
+            } else {
+                TickDecision::Advance
+            }
+        }
+    }
+
+    struct CountingScene {
+        updates: Arc<AtomicU32>,
+    }
+
+    #[async_trait::async_trait]
+    impl Scene for CountingScene {
+        async fn on_update(
+            &mut self,
+            _services: &ServiceContext,
+            _systems: &mut SystemContext,
+            _resources: &mut ResourceContext,
+        ) -> SceneTransition<Self> {
+            self.updates.fetch_add(1, Ordering::SeqCst);
+            SceneTransition::Stay
+        }
+    }
+
+    #[tokio::test]
+    async fn test_headless_runner_skips_stalled_ticks() {
+        let game = GameBuilder::new().build().await.unwrap();
+        let updates = Arc::new(AtomicU32::new(0));
+        let polls = Arc::new(AtomicU32::new(0));
+
+        let director = SceneDirector::new(
+            CountingScene {
+                updates: updates.clone(),
+            },
+            game.services,
+            game.systems,
+            game.resources,
+        )
+        .await;
+
+        HeadlessRunner::new(director)
+            .with_tick_rate(Duration::from_millis(1))
+            .with_max_ticks(5)
+            .with_tick_gate(AlternatingGate {
+                polls: polls.clone(),
+            })
+            .run()
+            .await
+            .unwrap();
+
+        // Stalled frames neither update the scene nor count towards max_ticks
+        assert_eq!(updates.load(Ordering::SeqCst), 5);
+        assert_eq!(polls.load(Ordering::SeqCst), 10);
+    }
+
+    // Plugin that snapshots the scene's update count at start and exit
+    struct ProbePlugin {
+        updates: Arc<AtomicU32>,
+        at_start: Arc<AtomicU32>,
+        at_exit: Arc<AtomicU32>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::plugin::Plugin for ProbePlugin {
+        fn name(&self) -> &'static str {
+            "probe"
+        }
+
+        fn build(&self, _builder: &mut dyn crate::plugin::PluginBuilder) {}
+
+        async fn on_start(
+            &self,
+            _services: &ServiceContext,
+            _systems: &mut SystemContext,
+            _resources: &mut ResourceContext,
+        ) {
+            self.at_start
+                .store(self.updates.load(Ordering::SeqCst), Ordering::SeqCst);
+        }
+
+        async fn on_exit(
+            &self,
+            _services: &ServiceContext,
+            _systems: &mut SystemContext,
+            _resources: &mut ResourceContext,
+        ) {
+            self.at_exit
+                .store(self.updates.load(Ordering::SeqCst), Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_headless_runner_max_ticks_triggers_on_exit() {
+        let updates = Arc::new(AtomicU32::new(0));
+        let at_start = Arc::new(AtomicU32::new(u32::MAX));
+        let at_exit = Arc::new(AtomicU32::new(u32::MAX));
+        let game = GameBuilder::new()
+            .with_plugin(ProbePlugin {
+                updates: updates.clone(),
+                at_start: at_start.clone(),
+                at_exit: at_exit.clone(),
+            })
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        let director = SceneDirector::new(
+            CountingScene {
+                updates: updates.clone(),
+            },
+            game.services,
+            game.systems,
+            game.resources,
+        )
+        .await;
+
+        HeadlessRunner::new(director)
+            .with_tick_rate(Duration::from_millis(1))
+            .with_max_ticks(3)
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(at_start.load(Ordering::SeqCst), 0);
+        assert_eq!(at_exit.load(Ordering::SeqCst), 3);
+    }
+
     // Test command for ChannelHeadlessRunner
     #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
     struct TestCommand {
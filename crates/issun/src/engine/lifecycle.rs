@@ -0,0 +1,271 @@
+//! Plugin startup/shutdown lifecycle
+//!
+//! [`GameBuilder::build`](crate::builder::GameBuilder::build) stores the
+//! registered plugins in a [`PluginLifecycle`] resource. The runners call
+//! [`Plugin::on_start`] for each of them once before the first tick (in build
+//! order) and [`Plugin::on_exit`] on clean shutdown (in reverse order).
+//!
+//! Every call is bounded by [`Plugin::lifecycle_timeout`], so a hung plugin
+//! cannot block startup or exit; the timeout is reported and the runner moves
+//! on to the next plugin.
+
+use crate::{
+    error::IssunError,
+    plugin::Plugin,
+    scene::{Scene, SceneDirector},
+};
+use std::{future::Future, sync::Arc, time::Duration};
+
+/// Plugins registered with the builder, in build order (dependencies first)
+pub struct PluginLifecycle {
+    plugins: Vec<Arc<dyn Plugin>>,
+    started: bool,
+}
+
+impl PluginLifecycle {
+    /// Create a lifecycle for the given plugins, already in build order
+    pub fn new(plugins: Vec<Arc<dyn Plugin>>) -> Self {
+        Self {
+            plugins,
+            started: false,
+        }
+    }
+
+    /// Names of the plugins in start order
+    pub fn plugin_names(&self) -> Vec<&'static str> {
+        self.plugins.iter().map(|plugin| plugin.name()).collect()
+    }
+
+    /// Whether `on_start` has run and `on_exit` has not yet
+    pub fn is_started(&self) -> bool {
+        self.started
+    }
+}
+
+/// Run `on_start` for every plugin, unless the lifecycle already started.
+pub(crate) async fn start_plugins<S: Scene>(director: &mut SceneDirector<S>) {
+    let Some(plugins) = toggle_started(director, true).await else {
+        return;
+    };
+
+    let (services, systems, resources) = director.contexts_mut();
+    for plugin in plugins.iter() {
+        run_bounded(
+            plugin.name(),
+            "on_start",
+            plugin.lifecycle_timeout(),
+            plugin.on_start(services, systems, resources),
+        )
+        .await;
+    }
+}
+
+/// Run `on_exit` in reverse order for every plugin, if the lifecycle started.
+pub(crate) async fn exit_plugins<S: Scene>(director: &mut SceneDirector<S>) {
+    let Some(plugins) = toggle_started(director, false).await else {
+        return;
+    };
+
+    let (services, systems, resources) = director.contexts_mut();
+    for plugin in plugins.iter().rev() {
+        run_bounded(
+            plugin.name(),
+            "on_exit",
+            plugin.lifecycle_timeout(),
+            plugin.on_exit(services, systems, resources),
+        )
+        .await;
+    }
+}
+
+/// Flip the started flag and hand out the plugins if the flag actually changed
+async fn toggle_started<S: Scene>(
+    director: &mut SceneDirector<S>,
+    start: bool,
+) -> Option<Vec<Arc<dyn Plugin>>> {
+    let mut lifecycle = director.resources().get_mut::<PluginLifecycle>().await?;
+    if lifecycle.started == start {
+        return None;
+    }
+    lifecycle.started = start;
+    Some(lifecycle.plugins.clone())
+}
+
+async fn run_bounded(plugin: &str, phase: &str, timeout: Duration, call: impl Future<Output = ()>) {
+    if tokio::time::timeout(timeout, call).await.is_err() {
+        let error = IssunError::Plugin(format!(
+            "{} of plugin '{}' timed out after {:?}",
+            phase, plugin, timeout
+        ));
+        eprintln!("[Lifecycle] {}", error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builder::GameBuilder,
+        context::{ResourceContext, ServiceContext, SystemContext},
+        plugin::PluginBuilder,
+        scene::SceneTransition,
+    };
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    struct RecordingPlugin {
+        name: &'static str,
+        deps: Vec<&'static str>,
+        exit_delay: Option<Duration>,
+        log: Log,
+    }
+
+    impl RecordingPlugin {
+        fn new(name: &'static str, log: &Log) -> Self {
+            Self {
+                name,
+                deps: Vec::new(),
+                exit_delay: None,
+                log: log.clone(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Plugin for RecordingPlugin {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn build(&self, _builder: &mut dyn PluginBuilder) {}
+
+        fn dependencies(&self) -> Vec<&'static str> {
+            self.deps.clone()
+        }
+
+        async fn on_start(
+            &self,
+            _services: &ServiceContext,
+            _systems: &mut SystemContext,
+            _resources: &mut ResourceContext,
+        ) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("start:{}", self.name));
+        }
+
+        async fn on_exit(
+            &self,
+            _services: &ServiceContext,
+            _systems: &mut SystemContext,
+            _resources: &mut ResourceContext,
+        ) {
+            if let Some(delay) = self.exit_delay {
+                tokio::time::sleep(delay).await;
+            }
+            self.log.lock().unwrap().push(format!("exit:{}", self.name));
+        }
+
+        fn lifecycle_timeout(&self) -> Duration {
+            Duration::from_millis(50)
+        }
+    }
+
+    struct IdleScene;
+
+    #[async_trait]
+    impl Scene for IdleScene {
+        async fn on_update(
+            &mut self,
+            _services: &ServiceContext,
+            _systems: &mut SystemContext,
+            _resources: &mut ResourceContext,
+        ) -> SceneTransition<Self> {
+            SceneTransition::Stay
+        }
+    }
+
+    async fn director_with(plugins: Vec<RecordingPlugin>) -> SceneDirector<IdleScene> {
+        let mut builder = GameBuilder::new();
+        for plugin in plugins {
+            builder = builder.with_plugin(plugin).unwrap();
+        }
+        let game = builder.build().await.unwrap();
+        SceneDirector::new(IdleScene, game.services, game.systems, game.resources).await
+    }
+
+    fn entries(log: &Log) -> Vec<String> {
+        log.lock().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn start_runs_in_build_order_and_exit_in_reverse() {
+        let log = Log::default();
+        let mut director = director_with(vec![
+            RecordingPlugin::new("a", &log),
+            RecordingPlugin::new("b", &log),
+            RecordingPlugin::new("c", &log),
+        ])
+        .await;
+
+        start_plugins(&mut director).await;
+        // A second start is a no-op
+        start_plugins(&mut director).await;
+        exit_plugins(&mut director).await;
+        exit_plugins(&mut director).await;
+
+        assert_eq!(
+            entries(&log),
+            vec!["start:a", "start:b", "start:c", "exit:c", "exit:b", "exit:a"]
+        );
+    }
+
+    #[tokio::test]
+    async fn dependencies_start_first() {
+        let log = Log::default();
+        let mut dependent = RecordingPlugin::new("dependent", &log);
+        dependent.deps = vec!["base"];
+        let mut director = director_with(vec![dependent, RecordingPlugin::new("base", &log)]).await;
+
+        let lifecycle = director.resources().get::<PluginLifecycle>().await.unwrap();
+        assert_eq!(lifecycle.plugin_names(), vec!["base", "dependent"]);
+        drop(lifecycle);
+
+        start_plugins(&mut director).await;
+        assert_eq!(entries(&log), vec!["start:base", "start:dependent"]);
+    }
+
+    #[tokio::test]
+    async fn hung_exit_times_out_and_later_plugins_still_exit() {
+        let log = Log::default();
+        let mut hung = RecordingPlugin::new("hung", &log);
+        hung.exit_delay = Some(Duration::from_secs(30));
+        let mut director = director_with(vec![
+            RecordingPlugin::new("first", &log),
+            hung,
+            RecordingPlugin::new("last", &log),
+        ])
+        .await;
+
+        start_plugins(&mut director).await;
+        let started = std::time::Instant::now();
+        exit_plugins(&mut director).await;
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(
+            entries(&log),
+            vec![
+                "start:first",
+                "start:hung",
+                "start:last",
+                "exit:last",
+                "exit:first"
+            ]
+        );
+        let lifecycle = director.resources().get::<PluginLifecycle>().await.unwrap();
+        assert!(!lifecycle.is_started());
+    }
+}
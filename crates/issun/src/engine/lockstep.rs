@@ -0,0 +1,597 @@
+//! Input-delay lockstep for deterministic multiplayer
+//!
+//! Every peer runs the same simulation and only exchanges inputs. Local inputs
+//! are collected into an [`InputFrame`] scheduled `input_delay` ticks in the
+//! future and broadcast over the [`EventBus`] network layer. A tick is only
+//! released once frames from *all* peers have arrived for it; until then the
+//! runner stalls instead of diverging.
+//!
+//! A [`DesyncDetector`] verifies the peers really agree: each peer hashes its
+//! simulated resources after a tick ([`resource_hash`]) and shares a
+//! [`StateHash`]; differing hashes for a tick raise [`DesyncDetected`].
+//!
+//! Runners consult a [`TickGate`] before each simulation step, so lockstep is
+//! opt-in via [`HeadlessRunner::with_tick_gate`](crate::engine::HeadlessRunner::with_tick_gate)
+//! or [`GameRunner::with_tick_gate`](crate::engine::GameRunner::with_tick_gate).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use issun::engine::lockstep::{LocalInput, LockstepConfig, LockstepDriver, LockstepTick};
+//!
+//! let driver = LockstepDriver::<PlayerCommand>::new(my_peer_id, [0, 1], LockstepConfig::default());
+//! let runner = HeadlessRunner::new(director).with_tick_gate(driver);
+//!
+//! // In a scene: submit local input...
+//! bus.publish(LocalInput { input: PlayerCommand::Jump });
+//! // ...and simulate using the released inputs of every peer.
+//! if let Some(tick) = resources.get::<LockstepTick<PlayerCommand>>().await {
+//!     for frame in &tick.frames { /* apply frame.inputs */ }
+//! }
+//! ```
+
+use crate::context::ResourceContext;
+use crate::event::{Event, EventBus};
+use crate::scene::{Scene, SceneDirector};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{Duration, Instant};
+
+/// Identifier of a lockstep participant.
+pub type PeerId = u64;
+
+/// Decision returned by a [`TickGate`] before each simulation step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickDecision {
+    /// Run the next simulation tick.
+    Advance,
+    /// Skip this frame: no scene update, no system update, no event dispatch.
+    Stall,
+    /// Gating failed permanently (e.g. a peer timed out).
+    ///
+    /// The runner drops the gate and keeps running ungated so scenes can react
+    /// to the disconnect (e.g. by returning to a lobby).
+    Disconnect,
+}
+
+/// Pluggable gate consulted by runners before each simulation tick.
+#[async_trait]
+pub trait TickGate: Send {
+    /// Decide whether the runner may advance the simulation this frame.
+    async fn poll(&mut self, resources: &mut ResourceContext) -> TickDecision;
+}
+
+/// Consult the tick gate (if any); returns `false` when this frame must be skipped.
+pub(crate) async fn pass_tick_gate<S: Scene>(
+    gate: &mut Option<Box<dyn TickGate>>,
+    director: &mut SceneDirector<S>,
+) -> bool {
+    let Some(tick_gate) = gate.as_mut() else {
+        return true;
+    };
+
+    match tick_gate.poll(director.resources_mut()).await {
+        TickDecision::Advance => true,
+        TickDecision::Stall => false,
+        TickDecision::Disconnect => {
+            // Stop gating and let scenes observe the disconnect event.
+            *gate = None;
+            if let Some(mut event_bus) = director.resources_mut().get_mut::<EventBus>().await {
+                event_bus.dispatch();
+            }
+            false
+        }
+    }
+}
+
+/// Marker for input types that can be exchanged in lockstep.
+pub trait LockstepInput: Clone + Send + Sync + Serialize + DeserializeOwned + 'static {}
+
+impl<T> LockstepInput for T where T: Clone + Send + Sync + Serialize + DeserializeOwned + 'static {}
+
+/// Inputs of one peer for one tick (networked).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "I: LockstepInput")]
+pub struct InputFrame<I> {
+    pub peer: PeerId,
+    pub tick: u64,
+    pub inputs: Vec<I>,
+}
+
+impl<I: LockstepInput> Event for InputFrame<I> {
+    #[cfg(feature = "network")]
+    fn is_networked() -> bool {
+        true
+    }
+}
+
+/// Local input submitted by game code; scheduled into the next outgoing frame.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "I: LockstepInput")]
+pub struct LocalInput<I> {
+    pub input: I,
+}
+
+impl<I: LockstepInput> Event for LocalInput<I> {}
+
+/// Published when the driver starts stalling on missing frames.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockstepWaiting {
+    pub tick: u64,
+    pub missing: Vec<PeerId>,
+}
+
+impl Event for LockstepWaiting {}
+
+/// Published when a stall exceeds [`LockstepConfig::stall_timeout`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockstepTimedOut {
+    pub tick: u64,
+    pub missing: Vec<PeerId>,
+}
+
+impl Event for LockstepTimedOut {}
+
+/// Hash of one peer's simulated state after a tick (networked).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateHash {
+    pub peer: PeerId,
+    pub tick: u64,
+    pub hash: u64,
+}
+
+impl Event for StateHash {
+    #[cfg(feature = "network")]
+    fn is_networked() -> bool {
+        true
+    }
+}
+
+/// Published when peers report different state hashes for the same tick.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DesyncDetected {
+    pub tick: u64,
+    /// Every peer's hash for the tick.
+    pub hashes: BTreeMap<PeerId, u64>,
+}
+
+impl Event for DesyncDetected {}
+
+/// Resource holding the inputs of every peer for the tick being simulated.
+///
+/// Replaced each time the driver releases a tick; frames are ordered by peer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockstepTick<I> {
+    pub tick: u64,
+    pub frames: Vec<InputFrame<I>>,
+}
+
+/// Lockstep tuning.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockstepConfig {
+    /// Number of ticks between submitting an input and simulating it.
+    pub input_delay: u64,
+    /// How long to stall on missing frames before giving up.
+    pub stall_timeout: Duration,
+}
+
+impl Default for LockstepConfig {
+    fn default() -> Self {
+        Self {
+            input_delay: 2,
+            stall_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// [`TickGate`] implementing input-delay lockstep over the [`EventBus`].
+pub struct LockstepDriver<I> {
+    local: PeerId,
+    peers: BTreeSet<PeerId>,
+    config: LockstepConfig,
+    next_tick: u64,
+    pending: BTreeMap<u64, BTreeMap<PeerId, InputFrame<I>>>,
+    local_inputs: Vec<I>,
+    stalled_since: Option<Instant>,
+    started: bool,
+}
+
+impl<I: LockstepInput> LockstepDriver<I> {
+    /// Create a driver for `local` among `peers` (the local peer is added if missing).
+    pub fn new(
+        local: PeerId,
+        peers: impl IntoIterator<Item = PeerId>,
+        config: LockstepConfig,
+    ) -> Self {
+        let mut peers: BTreeSet<PeerId> = peers.into_iter().collect();
+        peers.insert(local);
+        Self {
+            local,
+            peers,
+            config,
+            next_tick: 0,
+            pending: BTreeMap::new(),
+            local_inputs: Vec::new(),
+            stalled_since: None,
+            started: false,
+        }
+    }
+
+    /// Local peer id.
+    pub fn local_peer(&self) -> PeerId {
+        self.local
+    }
+
+    /// Next tick to be released.
+    pub fn current_tick(&self) -> u64 {
+        self.next_tick
+    }
+
+    /// Peers whose frame for the next tick has not arrived yet.
+    pub fn missing_peers(&self) -> Vec<PeerId> {
+        let received = self.pending.get(&self.next_tick);
+        self.peers
+            .iter()
+            .copied()
+            .filter(|peer| !received.is_some_and(|frames| frames.contains_key(peer)))
+            .collect()
+    }
+
+    /// Collect inputs and frames from the bus and try to release the next tick.
+    ///
+    /// Returns the released tick, or `None` while stalled.
+    pub fn step(
+        &mut self,
+        bus: &mut EventBus,
+    ) -> std::result::Result<Option<LockstepTick<I>>, LockstepTimedOut> {
+        if !self.started {
+            self.start(bus);
+        }
+
+        #[cfg(feature = "network")]
+        bus.poll_network();
+
+        for frame in bus.drain::<InputFrame<I>>() {
+            // Our own frames are stored when they are sent; ignore the local echo.
+            if frame.peer == self.local || !self.peers.contains(&frame.peer) {
+                continue;
+            }
+            if frame.tick < self.next_tick {
+                continue;
+            }
+            self.pending
+                .entry(frame.tick)
+                .or_default()
+                .entry(frame.peer)
+                .or_insert(frame);
+        }
+        self.local_inputs
+            .extend(bus.drain::<LocalInput<I>>().into_iter().map(|l| l.input));
+
+        let missing = self.missing_peers();
+        if !missing.is_empty() {
+            let tick = self.next_tick;
+            match self.stalled_since {
+                None => {
+                    self.stalled_since = Some(Instant::now());
+                    bus.publish(LockstepWaiting { tick, missing });
+                }
+                Some(since) if since.elapsed() >= self.config.stall_timeout => {
+                    let timed_out = LockstepTimedOut { tick, missing };
+                    bus.publish(timed_out.clone());
+                    return Err(timed_out);
+                }
+                Some(_) => {}
+            }
+            return Ok(None);
+        }
+
+        self.stalled_since = None;
+        let tick = self.next_tick;
+        let frames = self
+            .pending
+            .remove(&tick)
+            .map(|frames| frames.into_values().collect())
+            .unwrap_or_default();
+        self.next_tick += 1;
+
+        // Inputs gathered up to now are simulated `input_delay` ticks later.
+        let inputs = std::mem::take(&mut self.local_inputs);
+        self.send_local(bus, tick + self.config.input_delay, inputs);
+
+        Ok(Some(LockstepTick { tick, frames }))
+    }
+
+    /// Ticks inside the initial delay window carry no input for anyone.
+    fn start(&mut self, bus: &mut EventBus) {
+        #[cfg(feature = "network")]
+        bus.register_networked_event::<InputFrame<I>>();
+
+        for tick in 0..self.config.input_delay {
+            for &peer in &self.peers {
+                self.pending.entry(tick).or_default().insert(
+                    peer,
+                    InputFrame {
+                        peer,
+                        tick,
+                        inputs: Vec::new(),
+                    },
+                );
+            }
+        }
+        if self.config.input_delay == 0 {
+            let inputs = std::mem::take(&mut self.local_inputs);
+            self.send_local(bus, 0, inputs);
+        }
+        self.started = true;
+    }
+
+    fn send_local(&mut self, bus: &mut EventBus, tick: u64, inputs: Vec<I>) {
+        let frame = InputFrame {
+            peer: self.local,
+            tick,
+            inputs,
+        };
+        bus.publish(frame.clone());
+        self.pending
+            .entry(tick)
+            .or_default()
+            .insert(self.local, frame);
+    }
+}
+
+/// Compares the [`StateHash`]es of every peer, tick by tick.
+pub struct DesyncDetector {
+    peers: BTreeSet<PeerId>,
+    reported: BTreeMap<u64, BTreeMap<PeerId, u64>>,
+}
+
+impl DesyncDetector {
+    /// Create a detector expecting a hash from each of `peers` per tick.
+    pub fn new(peers: impl IntoIterator<Item = PeerId>) -> Self {
+        Self {
+            peers: peers.into_iter().collect(),
+            reported: BTreeMap::new(),
+        }
+    }
+
+    /// Record a peer's hash; once every peer reported the tick, returns the
+    /// desync if their hashes differ.
+    pub fn record(&mut self, hash: StateHash) -> Option<DesyncDetected> {
+        let hashes = self.reported.entry(hash.tick).or_default();
+        hashes.insert(hash.peer, hash.hash);
+        if !self.peers.iter().all(|peer| hashes.contains_key(peer)) {
+            return None;
+        }
+
+        let hashes = self.reported.remove(&hash.tick).unwrap_or_default();
+        let mut values = hashes.values();
+        let first = values.next().copied();
+        if values.all(|value| Some(*value) == first) {
+            None
+        } else {
+            Some(DesyncDetected {
+                tick: hash.tick,
+                hashes,
+            })
+        }
+    }
+
+    /// Record every [`StateHash`] readable on the bus and publish a
+    /// [`DesyncDetected`] for each diverging tick.
+    pub fn check(&mut self, bus: &mut EventBus) -> Vec<DesyncDetected> {
+        let hashes: Vec<StateHash> = bus.reader::<StateHash>().iter().copied().collect();
+        let desyncs: Vec<DesyncDetected> = hashes
+            .into_iter()
+            .filter_map(|hash| self.record(hash))
+            .collect();
+        for desync in &desyncs {
+            bus.publish(desync.clone());
+        }
+        desyncs
+    }
+
+    /// Ticks some peers have not reported a hash for yet.
+    pub fn pending_ticks(&self) -> Vec<u64> {
+        self.reported.keys().copied().collect()
+    }
+}
+
+/// Hash of resource `T` for a [`StateHash`]: FNV-1a over its bincode encoding.
+///
+/// `None` if the resource is missing or does not serialize.
+pub async fn resource_hash<T: Serialize + Send + Sync + 'static>(
+    resources: &ResourceContext,
+) -> Option<u64> {
+    let resource = resources.get::<T>().await?;
+    let bytes = bincode::serialize(&*resource).ok()?;
+    Some(bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    }))
+}
+
+#[async_trait]
+impl<I: LockstepInput> TickGate for LockstepDriver<I> {
+    async fn poll(&mut self, resources: &mut ResourceContext) -> TickDecision {
+        let result = match resources.get_mut::<EventBus>().await {
+            Some(mut bus) => self.step(&mut bus),
+            None => return TickDecision::Stall,
+        };
+
+        match result {
+            Ok(Some(tick)) => {
+                resources.insert(tick);
+                TickDecision::Advance
+            }
+            Ok(None) => TickDecision::Stall,
+            Err(_) => TickDecision::Disconnect,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The simulated game state: every input applied so far
+    #[derive(Default, Serialize)]
+    struct Sim {
+        applied: Vec<(PeerId, u32)>,
+    }
+
+    struct Peer {
+        driver: LockstepDriver<u32>,
+        bus: EventBus,
+        resources: ResourceContext,
+    }
+
+    impl Peer {
+        fn new(id: PeerId, config: LockstepConfig) -> Self {
+            let mut resources = ResourceContext::new();
+            resources.insert(Sim::default());
+            Self {
+                driver: LockstepDriver::new(id, [0, 1], config),
+                bus: EventBus::new(),
+                resources,
+            }
+        }
+
+        /// Step once and return the outgoing frames (the simulated network).
+        async fn step(&mut self, input: u32) -> (Option<u64>, Vec<InputFrame<u32>>) {
+            self.bus.publish(LocalInput { input });
+            let released = self.driver.step(&mut self.bus).expect("no timeout");
+            if let Some(tick) = &released {
+                let mut sim = self.resources.get_mut::<Sim>().await.unwrap();
+                for frame in &tick.frames {
+                    sim.applied
+                        .extend(frame.inputs.iter().map(|value| (frame.peer, *value)));
+                }
+            }
+            self.bus.dispatch();
+            let sent = self.bus.drain::<InputFrame<u32>>();
+            (released.map(|t| t.tick), sent)
+        }
+
+        async fn state_hash(&self, tick: u64) -> StateHash {
+            StateHash {
+                peer: self.driver.local_peer(),
+                tick,
+                hash: resource_hash::<Sim>(&self.resources).await.unwrap(),
+            }
+        }
+
+        fn deliver(&mut self, frames: Vec<InputFrame<u32>>) {
+            for frame in frames {
+                self.bus.publish(frame);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_two_peers_stay_in_lockstep() {
+        let mut a = Peer::new(0, LockstepConfig::default());
+        let mut b = Peer::new(1, LockstepConfig::default());
+        let mut detector = DesyncDetector::new([0, 1]);
+
+        for i in 0..500u32 {
+            let (tick_a, sent_a) = a.step(i).await;
+            let (tick_b, sent_b) = b.step(i * 7 + 1).await;
+            assert_eq!(tick_a, Some(u64::from(i)));
+            assert_eq!(tick_b, Some(u64::from(i)));
+
+            let tick = u64::from(i);
+            assert_eq!(detector.record(a.state_hash(tick).await), None);
+            let desync = detector.record(b.state_hash(tick).await);
+            assert_eq!(desync, None, "desync at tick {i}");
+
+            b.deliver(sent_a);
+            a.deliver(sent_b);
+        }
+        assert_eq!(a.driver.current_tick(), 500);
+        assert!(detector.pending_ticks().is_empty());
+        assert!(!a.resources.get::<Sim>().await.unwrap().applied.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_detector_reports_diverging_hashes() {
+        let a = Peer::new(0, LockstepConfig::default());
+        let b = Peer::new(1, LockstepConfig::default());
+        a.resources
+            .get_mut::<Sim>()
+            .await
+            .unwrap()
+            .applied
+            .push((0, 1));
+
+        let mut detector = DesyncDetector::new([0, 1]);
+        let mut bus = EventBus::new();
+        bus.publish(a.state_hash(3).await);
+        bus.publish(b.state_hash(3).await);
+        bus.publish(b.state_hash(4).await);
+        bus.dispatch();
+
+        let desyncs = detector.check(&mut bus);
+        assert_eq!(desyncs.len(), 1);
+        assert_eq!(desyncs[0].tick, 3);
+        assert_ne!(desyncs[0].hashes[&0], desyncs[0].hashes[&1]);
+        assert_eq!(detector.pending_ticks(), vec![4]);
+        bus.dispatch();
+        assert_eq!(bus.reader::<DesyncDetected>().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_delayed_peer_stalls_instead_of_diverging() {
+        let mut a = Peer::new(0, LockstepConfig::default());
+        let mut b = Peer::new(1, LockstepConfig::default());
+        let mut b_outbox = Vec::new();
+
+        // A runs ahead while B's frames are held back.
+        let mut advanced = 0;
+        let mut waiting = 0;
+        for i in 0..10u32 {
+            let (tick, sent) = a.step(i).await;
+            if tick.is_some() {
+                advanced += 1;
+            }
+            waiting += a.bus.reader::<LockstepWaiting>().len();
+            b.deliver(sent);
+            let (_, sent_b) = b.step(100 + i).await;
+            b_outbox.extend(sent_b);
+        }
+        assert_eq!(advanced, 2, "only the input delay window may run ahead");
+        assert_eq!(a.driver.current_tick(), 2);
+        assert_eq!(a.driver.missing_peers(), vec![1]);
+        assert_eq!(waiting, 1, "waiting is reported once per stall");
+
+        // Once B's frames arrive, A catches up and both agree.
+        a.deliver(b_outbox);
+        let mut guard = 0;
+        while a.driver.current_tick() < b.driver.current_tick() {
+            let (_, sent) = a.step(0).await;
+            b.deliver(sent);
+            guard += 1;
+            assert!(guard < 100);
+        }
+        let mut detector = DesyncDetector::new([0, 1]);
+        let tick = a.driver.current_tick() - 1;
+        detector.record(a.state_hash(tick).await);
+        assert_eq!(detector.record(b.state_hash(tick).await), None);
+    }
+
+    #[test]
+    fn test_stall_timeout_disconnects() {
+        let config = LockstepConfig {
+            input_delay: 0,
+            stall_timeout: Duration::ZERO,
+        };
+        let mut driver = LockstepDriver::<u32>::new(0, [0, 1], config);
+        let mut bus = EventBus::new();
+
+        assert_eq!(driver.step(&mut bus), Ok(None));
+        let timed_out = driver.step(&mut bus).unwrap_err();
+        assert_eq!(timed_out.tick, 0);
+        assert_eq!(timed_out.missing, vec![1]);
+    }
+}
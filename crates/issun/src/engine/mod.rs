@@ -1,14 +1,47 @@
 //! Engine modules for ISSUN
 
+pub mod config_file;
+pub mod crash;
+pub mod flags;
+pub mod focus;
 pub mod game_loop;
 pub mod headless_runner;
 pub mod input;
+pub mod lifecycle;
+pub mod lockstep;
 pub mod mod_bridge_system;
+pub mod playtime;
+pub mod query;
 pub mod rng;
 pub mod runner;
 
+pub use config_file::{
+    ConfigFieldsSkipped, ConfigFileOptions, ConfigParams, ConfigReloadFailed, ConfigReloadSystem,
+    ConfigReloaded, ConfigSection, ParamSchema, ReloadConfigRequested, TypedConfigSection,
+};
+pub use crash::{
+    BugReportRequested, BugReportSaved, CrashBundle, CrashPrivacy, CrashReportConfig,
+    CrashReporter, FrameStats,
+};
+pub use flags::{
+    FeatureFlag, FeatureFlagChanged, FeatureFlagRejected, FeatureFlagSystem, FeatureFlags,
+    FlagError, FlagSource, SetFeatureFlagRequested,
+};
+pub use focus::{
+    AwaySummary, FocusConfig, FocusPolicy, FocusSignal, FocusSource, FocusTracker, FramePlan,
+    SimulationControl, WindowFocusChanged,
+};
 pub use headless_runner::{ChannelHeadlessRunner, HeadlessRunner};
 pub use input::InputMapper;
+pub use lifecycle::PluginLifecycle;
+pub use lockstep::{
+    DesyncDetected, DesyncDetector, LockstepConfig, LockstepDriver, StateHash, TickDecision,
+    TickGate,
+};
 pub use mod_bridge_system::ModBridgeSystem;
-pub use rng::GameRng;
+pub use playtime::{
+    IdleDetected, PlaytimeProfile, PlaytimeStats, PlaytimeTracker, PLAYTIME_METRIC,
+};
+pub use query::{query_channel, QueryHandle, QueryReceiver};
+pub use rng::{derive_seed, mix_seed, DailyChallenge, GameRng, MasterSeed};
 pub use runner::GameRunner;
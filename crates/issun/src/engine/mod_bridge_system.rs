@@ -1,25 +1,45 @@
 //! MOD Bridge System
 //!
 //! This system bridges MOD events to Plugin configurations, enabling runtime control
-//! of plugins through MOD scripts.
+//! of plugins through MOD scripts. It also drives the per-tick `on_update` callback
+//! of loaded MODs, advances their scheduled callbacks on `DayChanged`, performs
+//! the player actions MODs define and keeps the loader's view of plugin
+//! parameters up to date.
 
 use crate::context::ResourceContext;
 use crate::event::EventBus;
 use crate::modding::events::*;
+use crate::modding::{
+    ModActionEntry, ModActions, ModLoaderState, ModLogEntry, ModLogLevel, ModRegistry,
+    ModSystemConfig, ParamConflictPolicy, PluginParams,
+};
+use crate::plugin::action::{
+    ActionConsumedEvent, ActionDefinition, ActionFailed, ActionPerformed, ActionPoints,
+    ActionRegistry, ActionRequested,
+};
+use crate::plugin::{DayChanged, GameTimer};
 use crate::system::System;
 use async_trait::async_trait;
 use std::any::Any;
+use std::collections::HashMap;
 
 /// System that bridges MOD events to Plugin configurations
 ///
 /// This system listens to MOD-issued events (PluginEnabledEvent, PluginDisabledEvent,
 /// PluginParameterChangedEvent) and updates plugin configurations accordingly.
 ///
+/// When two MODs set the same parameter, `ModSystemConfig::param_conflicts`
+/// decides which value stays and the conflict is published as a `ModLogEvent`.
+///
+/// Failed `on_update` calls and action callbacks are published as
+/// `ModRuntimeErrorEvent`.
+///
 /// # Supported Plugins
 ///
 /// Currently supports:
 /// - `combat` / `issun:combat` - Combat system
 /// - `inventory` / `issun:inventory` - Inventory system
+/// - `run_summary` / `issun:run_summary` - Score weights (parameters only)
 ///
 /// # Example
 ///
@@ -36,19 +56,64 @@ use std::any::Any;
 /// // In a MOD script
 /// enable_plugin("combat");
 /// set_plugin_param("combat", "max_hp", 150);
+///
+/// // Read the current value (refreshed every update), then write it back
+/// let difficulty = get_plugin_param("combat", "difficulty_multiplier");
+/// set_plugin_param("combat", "difficulty_multiplier", difficulty * 2.0);
+///
+/// // Called once per update with the tick number (starting at 0)
+/// fn on_update(tick) {
+///     if tick % 60 == 0 { publish_event("Heartbeat", #{ tick: tick }); }
+/// }
+///
+/// // Called with the new day once three `DayChanged` events have passed
+/// schedule(3, |turn| set_plugin_param("combat", "difficulty_multiplier", 2.0));
+///
+/// // A 2 AP action in the game's menu; throwing cancels it with a refund
+/// register_action("campaign", 2, "run_campaign");
+/// fn run_campaign(ctx) { log("campaign on day " + ctx.day); }
 /// ```
-pub struct ModBridgeSystem;
+///
+/// # MOD Actions
+///
+/// Actions registered with `register_action` are added to the
+/// `ActionRegistry` as `"<mod_id>:<name>"` and listed in `ModActions`. When
+/// the game publishes `ActionRequested` for one, the cooldown and action
+/// points are checked, the cost is consumed and the MOD callback is called
+/// with `#{ action, day, actor, target }`. `ActionConsumedEvent` and
+/// `ActionPerformed` follow a successful callback; a rejected request or a
+/// failed callback publishes `ActionFailed`, refunding the cost in the
+/// latter case.
+pub struct ModBridgeSystem {
+    tick: u64,
+    /// MOD that last set (or, first-wins, owns) each (plugin, key)
+    param_owners: HashMap<(String, String), String>,
+}
 
 impl ModBridgeSystem {
     /// Create a new ModBridgeSystem
     pub fn new() -> Self {
-        Self
+        Self {
+            tick: 0,
+            param_owners: HashMap::new(),
+        }
+    }
+
+    /// Number of ticks driven so far
+    pub fn tick(&self) -> u64 {
+        self.tick
     }
 
     /// Update method using ResourceContext (Modern pattern)
     ///
     /// This method is the recommended way to update the system.
     pub async fn update_resources(&mut self, resources: &mut ResourceContext) {
+        // Step 0: Refresh readable plugin params, then let loaded MODs run their per-tick callback
+        Self::sync_plugin_params(resources).await;
+        self.update_mods(resources).await;
+        Self::tick_schedules(resources).await;
+        Self::register_actions(resources).await;
+
         // Step 1: Collect all MOD events
         let enabled_events: Vec<PluginEnabledEvent> = {
             if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
@@ -86,6 +151,23 @@ impl ModBridgeSystem {
             }
         };
 
+        let unloaded: Vec<ModUnloadedEvent> = {
+            if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+                event_bus
+                    .reader::<ModUnloadedEvent>()
+                    .iter()
+                    .cloned()
+                    .collect()
+            } else {
+                Vec::new()
+            }
+        };
+        for event in unloaded {
+            self.param_owners.retain(|_, owner| *owner != event.mod_id);
+            Self::remove_actions(resources, &event.mod_id).await;
+        }
+        Self::perform_actions(resources).await;
+
         // Step 2: Process enable events
         for event in enabled_events {
             Self::handle_enable_resources(resources, &event).await;
@@ -96,9 +178,396 @@ impl ModBridgeSystem {
             Self::handle_disable_resources(resources, &event).await;
         }
 
-        // Step 4: Process parameter changes
+        // Step 4: Process parameter changes, resolving conflicts between MODs
+        let policy = match resources.get::<ModSystemConfig>().await {
+            Some(config) => config.param_conflicts,
+            None => ParamConflictPolicy::default(),
+        };
+        let params_changed = !param_events.is_empty();
+        let mut conflicts = Vec::new();
         for event in param_events {
-            Self::handle_parameter_change_resources(resources, &event).await;
+            let (apply, conflict) = self.resolve_param_owner(&event, policy);
+            conflicts.extend(conflict);
+            if apply {
+                Self::handle_parameter_change_resources(resources, &event).await;
+            }
+        }
+        if !conflicts.is_empty() {
+            if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+                for entry in conflicts {
+                    eprintln!("[MOD Bridge] {}", entry.message);
+                    event_bus.publish(ModLogEvent { entry });
+                }
+            }
+        }
+
+        // Step 5: Make this frame's changes readable right away
+        if params_changed {
+            Self::sync_plugin_params(resources).await;
+        }
+    }
+
+    /// Track which MOD set a parameter; returns whether to apply the change
+    /// and the conflict to report, if any
+    fn resolve_param_owner(
+        &mut self,
+        event: &PluginParameterChangedEvent,
+        policy: ParamConflictPolicy,
+    ) -> (bool, Option<ModLogEntry>) {
+        let Some(issuer) = &event.issuer else {
+            return (true, None);
+        };
+        let plugin = Self::normalize_plugin_name(&event.plugin_name);
+        let param = (plugin.to_string(), event.key.clone());
+
+        let previous = match self.param_owners.get(&param) {
+            Some(owner) if owner != issuer => owner.clone(),
+            _ => {
+                self.param_owners.insert(param, issuer.clone());
+                return (true, None);
+            }
+        };
+
+        let (apply, message) = match policy {
+            ParamConflictPolicy::LastWins => {
+                self.param_owners.insert(param, issuer.clone());
+                (
+                    true,
+                    format!(
+                        "{}.{} = {} overrides the value set by MOD '{}'",
+                        plugin, event.key, event.value, previous
+                    ),
+                )
+            }
+            ParamConflictPolicy::FirstWins => (
+                false,
+                format!(
+                    "{}.{} = {} ignored, MOD '{}' set it first",
+                    plugin, event.key, event.value, previous
+                ),
+            ),
+        };
+        let entry = ModLogEntry::new(Some(issuer.clone()), ModLogLevel::Warn, message);
+        (apply, Some(entry))
+    }
+
+    /// Hand the current plugin parameters to the MOD loader
+    async fn sync_plugin_params(resources: &ResourceContext) {
+        if !resources.contains::<ModLoaderState>() {
+            return;
+        }
+        let params = Self::collect_plugin_params(resources).await;
+        if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
+            loader_state.loader.sync_plugin_params(&params);
+        }
+    }
+
+    /// Current values of the MOD-controllable plugin configs
+    ///
+    /// Every config field is exposed under its own name, along with the
+    /// `set_plugin_param` aliases (`max_hp`, `difficulty`, `max_slots`).
+    /// Run summary weights are exposed under their contribution names and
+    /// feature flags under `("flags", name)`.
+    pub async fn collect_plugin_params(resources: &ResourceContext) -> PluginParams {
+        let mut params = PluginParams::new();
+
+        if let Some(config) = resources.get::<crate::plugin::CombatConfig>().await {
+            Self::insert_config_fields(&mut params, "combat", &*config);
+            params.insert(
+                ("combat".to_string(), "max_hp".to_string()),
+                serde_json::json!(config.default_max_hp),
+            );
+            params.insert(
+                ("combat".to_string(), "difficulty".to_string()),
+                serde_json::json!(config.difficulty_multiplier),
+            );
+        }
+
+        if let Some(config) = resources.get::<crate::plugin::InventoryConfig>().await {
+            Self::insert_config_fields(&mut params, "inventory", &*config);
+            params.insert(
+                ("inventory".to_string(), "max_slots".to_string()),
+                serde_json::json!(config.default_capacity),
+            );
+        }
+
+        if let Some(config) = resources
+            .get::<crate::plugin::run_summary::RunSummaryConfig>()
+            .await
+        {
+            for (key, weight) in &config.weights {
+                params.insert(
+                    ("run_summary".to_string(), key.clone()),
+                    serde_json::json!(weight),
+                );
+            }
+        }
+
+        if let Some(flags) = resources.get::<crate::engine::flags::FeatureFlags>().await {
+            for (name, enabled) in flags.values() {
+                params.insert(("flags".to_string(), name), serde_json::json!(enabled));
+            }
+        }
+
+        params
+    }
+
+    /// Insert every top-level field of a serializable config
+    fn insert_config_fields<T: serde::Serialize>(
+        params: &mut PluginParams,
+        plugin: &str,
+        config: &T,
+    ) {
+        if let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(config) {
+            for (key, value) in fields {
+                params.insert((plugin.to_string(), key), value);
+            }
+        }
+    }
+
+    /// Call `ModLoader::update` for every loaded MOD in dispatch order, then
+    /// advance the tick
+    async fn update_mods(&mut self, resources: &mut ResourceContext) {
+        let order: Vec<String> = match resources.get::<ModRegistry>().await {
+            Some(registry) => registry.dispatch_order().to_vec(),
+            None => Vec::new(),
+        };
+
+        let mut errors = Vec::new();
+        if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
+            let ModLoaderState {
+                loader,
+                loaded_mods,
+            } = &mut *loader_state;
+            let mut handles: Vec<_> = loaded_mods.iter().collect();
+            handles.sort_by_key(|handle| {
+                order
+                    .iter()
+                    .position(|id| *id == handle.id)
+                    .unwrap_or(usize::MAX)
+            });
+            for handle in handles {
+                if let Err(e) = loader.update(handle, self.tick) {
+                    errors.push(ModRuntimeErrorEvent::new(&handle.id, "on_update", e));
+                }
+            }
+        }
+        self.tick += 1;
+
+        if errors.is_empty() {
+            return;
+        }
+        if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+            for error in errors {
+                event_bus.publish(error);
+            }
+        }
+    }
+
+    /// Advance MOD-scheduled callbacks once per `DayChanged` event
+    async fn tick_schedules(resources: &mut ResourceContext) {
+        let days: Vec<u32> = match resources.get_mut::<EventBus>().await {
+            Some(mut event_bus) => event_bus
+                .reader::<DayChanged>()
+                .iter()
+                .map(|event| event.day)
+                .collect(),
+            None => return,
+        };
+        if days.is_empty() {
+            return;
+        }
+
+        if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
+            for day in days {
+                loader_state.loader.tick_schedules(day as u64);
+            }
+        }
+    }
+
+    /// Add the actions MODs registered to the action registry and menu
+    async fn register_actions(resources: &mut ResourceContext) {
+        let definitions = match resources.get_mut::<ModLoaderState>().await {
+            Some(mut loader_state) => loader_state.loader.drain_actions(),
+            None => return,
+        };
+
+        for definition in definitions {
+            let entry = ModActionEntry::from_definition(definition);
+            match resources.get_mut::<ActionRegistry>().await {
+                Some(mut registry) => {
+                    registry.register(
+                        ActionDefinition::new(&entry.action_id, entry.ap_cost)
+                            .with_label(&entry.label_key)
+                            .with_cooldown(entry.cooldown_days)
+                            .with_source(&entry.mod_id),
+                    );
+                }
+                None => eprintln!(
+                    "[MOD Bridge] No ActionRegistry (ActionPlugin); '{}' can't be performed",
+                    entry.action_id
+                ),
+            }
+            if let Some(mut actions) = resources.get_mut::<ModActions>().await {
+                actions.insert(entry);
+            }
+        }
+    }
+
+    /// Drop the actions of an unloaded MOD
+    async fn remove_actions(resources: &mut ResourceContext, mod_id: &str) {
+        if let Some(mut registry) = resources.get_mut::<ActionRegistry>().await {
+            registry.remove_source(mod_id);
+        }
+        if let Some(mut actions) = resources.get_mut::<ModActions>().await {
+            actions.remove_mod(mod_id);
+        }
+    }
+
+    /// Perform the requested MOD actions; requests for other actions are
+    /// left to the game
+    async fn perform_actions(resources: &mut ResourceContext) {
+        let requests: Vec<ActionRequested> = match resources.get_mut::<EventBus>().await {
+            Some(mut event_bus) => event_bus
+                .reader::<ActionRequested>()
+                .iter()
+                .cloned()
+                .collect(),
+            None => return,
+        };
+        if requests.is_empty() {
+            return;
+        }
+        let day = match resources.get::<GameTimer>().await {
+            Some(timer) => timer.day,
+            None => 0,
+        };
+
+        for request in requests {
+            let entry = match resources.get::<ModActions>().await {
+                Some(actions) => actions.get(&request.action_id).cloned(),
+                None => None,
+            };
+            if let Some(entry) = entry {
+                Self::perform_action(resources, &entry, request, day).await;
+            }
+        }
+    }
+
+    /// Validate and pay for one MOD action, then run its callback
+    async fn perform_action(
+        resources: &mut ResourceContext,
+        entry: &ModActionEntry,
+        request: ActionRequested,
+        day: u32,
+    ) {
+        let ActionRequested {
+            action_id,
+            actor,
+            target,
+        } = request;
+
+        // Check cooldown and cost, then consume
+        let paid = {
+            let registry = resources.get::<ActionRegistry>().await;
+            let points = resources.get_mut::<ActionPoints>().await;
+            match (registry, points) {
+                (Some(registry), Some(mut points)) => {
+                    match registry.check(&action_id, &points, day) {
+                        Ok(definition) => {
+                            let cost = definition.ap_cost;
+                            points.consume_n(cost);
+                            Ok((cost, points.available))
+                        }
+                        Err(e) => Err(e.to_string()),
+                    }
+                }
+                _ => Err("ActionPlugin is not registered".to_string()),
+            }
+        };
+        let (ap_cost, remaining) = match paid {
+            Ok(paid) => paid,
+            Err(reason) => {
+                if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+                    event_bus.publish(ActionFailed {
+                        action_id,
+                        actor,
+                        reason,
+                        refunded: 0,
+                    });
+                }
+                return;
+            }
+        };
+
+        let context = serde_json::json!({
+            "action": action_id,
+            "day": day,
+            "actor": actor,
+            "target": target,
+        });
+        let result = match resources.get_mut::<ModLoaderState>().await {
+            Some(mut loader_state) => {
+                let ModLoaderState {
+                    loader,
+                    loaded_mods,
+                } = &mut *loader_state;
+                match loaded_mods.iter().find(|handle| handle.id == entry.mod_id) {
+                    Some(handle) => loader
+                        .call_function_async(handle, &entry.callback, vec![context])
+                        .await
+                        .map_err(|e| e.to_string()),
+                    None => Err(format!("MOD '{}' is not loaded", entry.mod_id)),
+                }
+            }
+            None => Err("no MOD loader".to_string()),
+        };
+
+        match result {
+            Ok(_) => {
+                if let Some(mut registry) = resources.get_mut::<ActionRegistry>().await {
+                    registry.mark_used(&action_id, day);
+                }
+                if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+                    event_bus.publish(ActionConsumedEvent {
+                        context: action_id.clone(),
+                        remaining,
+                        depleted: remaining == 0,
+                    });
+                    event_bus.publish(ActionPerformed {
+                        action_id,
+                        actor,
+                        ap_cost,
+                        remaining,
+                    });
+                }
+            }
+            Err(e) => {
+                if let Some(mut points) = resources.get_mut::<ActionPoints>().await {
+                    points.refund(ap_cost);
+                }
+                let reason = format!("{} callback failed: {}", entry.callback, e);
+                if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+                    event_bus.publish(ModRuntimeErrorEvent::new(
+                        &entry.mod_id,
+                        format!("action '{}' callback '{}'", action_id, entry.callback),
+                        &e,
+                    ));
+                    event_bus.publish(ModLogEvent {
+                        entry: ModLogEntry::new(
+                            Some(entry.mod_id.clone()),
+                            ModLogLevel::Warn,
+                            format!("action '{}': {}", action_id, reason),
+                        ),
+                    });
+                    event_bus.publish(ActionFailed {
+                        action_id,
+                        actor,
+                        reason,
+                        refunded: ap_cost,
+                    });
+                }
+            }
         }
     }
 
@@ -171,6 +640,10 @@ impl ModBridgeSystem {
             "inventory" => {
                 Self::apply_inventory_param_resources(resources, &event.key, &event.value).await
             }
+            "run_summary" => {
+                Self::apply_run_summary_param_resources(resources, &event.key, &event.value).await
+            }
+            "flags" => Self::request_flag_change(resources, event).await,
             name => {
                 eprintln!("[MOD Bridge] Plugin '{}' is not MOD-controllable yet", name);
             }
@@ -191,13 +664,13 @@ impl ModBridgeSystem {
                         println!("[MOD Bridge] Combat.enabled = {}", enabled);
                     }
                 }
-                "max_hp" => {
+                "max_hp" | "default_max_hp" => {
                     if let Some(hp) = value.as_i64() {
                         config.default_max_hp = hp as u32;
                         println!("[MOD Bridge] Combat.max_hp = {}", hp);
                     }
                 }
-                "difficulty" => {
+                "difficulty" | "difficulty_multiplier" => {
                     if let Some(diff) = value.as_f64() {
                         config.difficulty_multiplier = diff as f32;
                         println!("[MOD Bridge] Combat.difficulty = {}", diff);
@@ -212,6 +685,51 @@ impl ModBridgeSystem {
         }
     }
 
+    /// Apply a score weight to the run summary config (ResourceContext version)
+    ///
+    /// The key is the contribution name, e.g. `"combat.battles_won"`.
+    async fn apply_run_summary_param_resources(
+        resources: &mut ResourceContext,
+        key: &str,
+        value: &serde_json::Value,
+    ) {
+        if let Some(mut config) = resources
+            .get_mut::<crate::plugin::run_summary::RunSummaryConfig>()
+            .await
+        {
+            if let Some(weight) = value.as_f64() {
+                config.weights.insert(key.to_string(), weight);
+                println!("[MOD Bridge] RunSummary.{} weight = {}", key, weight);
+            } else {
+                eprintln!(
+                    "[MOD Bridge] RunSummary weight for {} must be a number",
+                    key
+                );
+            }
+        } else {
+            eprintln!("[MOD Bridge] RunSummary config not found");
+        }
+    }
+
+    /// Forward a flag change to [`FeatureFlagSystem`](crate::engine::flags::FeatureFlagSystem),
+    /// which enforces that MODs only set their own flags
+    async fn request_flag_change(
+        resources: &mut ResourceContext,
+        event: &PluginParameterChangedEvent,
+    ) {
+        let Some(enabled) = event.value.as_bool() else {
+            eprintln!("[MOD Bridge] Flag {} must be a boolean", event.key);
+            return;
+        };
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.publish(crate::engine::flags::SetFeatureFlagRequested {
+                name: event.key.clone(),
+                enabled,
+                issuer: event.issuer.clone(),
+            });
+        }
+    }
+
     /// Apply parameter to inventory config (ResourceContext version)
     async fn apply_inventory_param_resources(
         resources: &mut ResourceContext,
@@ -226,7 +744,7 @@ impl ModBridgeSystem {
                         println!("[MOD Bridge] Inventory.enabled = {}", enabled);
                     }
                 }
-                "max_slots" => {
+                "max_slots" | "default_capacity" => {
                     if let Some(slots) = value.as_i64() {
                         config.default_capacity = slots as usize;
                         println!("[MOD Bridge] Inventory.max_slots = {}", slots);
@@ -278,6 +796,7 @@ impl System for ModBridgeSystem {
 mod tests {
     use super::*;
     use crate::context::ResourceContext;
+    use crate::modding::ModHandle;
 
     #[test]
     fn test_normalize_plugin_name() {
@@ -313,6 +832,7 @@ mod tests {
             let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
             event_bus.publish(PluginEnabledEvent {
                 plugin_name: "combat".to_string(),
+                issuer: None,
             });
             event_bus.dispatch();
         }
@@ -340,6 +860,7 @@ mod tests {
             let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
             event_bus.publish(PluginDisabledEvent {
                 plugin_name: "combat".to_string(),
+                issuer: None,
             });
             event_bus.dispatch();
         }
@@ -369,6 +890,7 @@ mod tests {
                 plugin_name: "combat".to_string(),
                 key: "max_hp".to_string(),
                 value: serde_json::json!(150),
+                issuer: None,
             });
             event_bus.dispatch();
         }
@@ -398,6 +920,7 @@ mod tests {
                 plugin_name: "combat".to_string(),
                 key: "difficulty".to_string(),
                 value: serde_json::json!(2.5),
+                issuer: None,
             });
             event_bus.dispatch();
         }
@@ -425,6 +948,7 @@ mod tests {
             let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
             event_bus.publish(PluginEnabledEvent {
                 plugin_name: "inventory".to_string(),
+                issuer: None,
             });
             event_bus.dispatch();
         }
@@ -454,6 +978,7 @@ mod tests {
                 plugin_name: "inventory".to_string(),
                 key: "max_slots".to_string(),
                 value: serde_json::json!(50),
+                issuer: None,
             });
             event_bus.dispatch();
         }
@@ -481,6 +1006,7 @@ mod tests {
             let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
             event_bus.publish(PluginEnabledEvent {
                 plugin_name: "issun:combat".to_string(),
+                issuer: None,
             });
             event_bus.dispatch();
         }
@@ -509,14 +1035,17 @@ mod tests {
             let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
             event_bus.publish(PluginEnabledEvent {
                 plugin_name: "combat".to_string(),
+                issuer: None,
             });
             event_bus.publish(PluginParameterChangedEvent {
                 plugin_name: "combat".to_string(),
                 key: "max_hp".to_string(),
                 value: serde_json::json!(200),
+                issuer: None,
             });
             event_bus.publish(PluginDisabledEvent {
                 plugin_name: "inventory".to_string(),
+                issuer: None,
             });
             event_bus.dispatch();
         }
@@ -539,4 +1068,366 @@ mod tests {
             .unwrap();
         assert!(!inventory_config.enabled);
     }
+
+    #[derive(Clone, Default)]
+    struct TickLoader {
+        seen: std::sync::Arc<std::sync::Mutex<Vec<(String, u64)>>>,
+        params: std::sync::Arc<std::sync::Mutex<crate::modding::PluginParams>>,
+        turns: std::sync::Arc<std::sync::Mutex<Vec<u64>>>,
+    }
+
+    impl crate::modding::ModLoader for TickLoader {
+        fn load(&mut self, path: &std::path::Path) -> crate::modding::ModResult<ModHandle> {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .map(handle)
+                .ok_or_else(|| crate::modding::ModError::InvalidFormat("Invalid path".to_string()))
+        }
+
+        fn unload(&mut self, _handle: &ModHandle) -> crate::modding::ModResult<()> {
+            Ok(())
+        }
+
+        fn update(&mut self, handle: &ModHandle, tick: u64) -> crate::modding::ModResult<()> {
+            self.seen.lock().unwrap().push((handle.id.clone(), tick));
+            Ok(())
+        }
+
+        fn control_plugin(
+            &mut self,
+            _handle: &ModHandle,
+            _control: &crate::modding::PluginControl,
+        ) -> crate::modding::ModResult<()> {
+            Ok(())
+        }
+
+        fn sync_plugin_params(&mut self, params: &crate::modding::PluginParams) {
+            *self.params.lock().unwrap() = params.clone();
+        }
+
+        fn tick_schedules(&mut self, turn: u64) -> usize {
+            self.turns.lock().unwrap().push(turn);
+            0
+        }
+
+        fn clone_box(&self) -> Box<dyn crate::modding::ModLoader> {
+            Box::new(self.clone())
+        }
+    }
+
+    fn handle(id: &str) -> ModHandle {
+        ModHandle {
+            id: id.to_string(),
+            metadata: crate::modding::ModMetadata {
+                name: id.to_string(),
+                version: "1.0.0".to_string(),
+                author: None,
+                description: None,
+                dependencies: Vec::new(),
+                priority: 0,
+                after: Vec::new(),
+            },
+            backend: crate::modding::ModBackend::Rhai,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_drives_loaded_mods_each_tick() {
+        let loader = TickLoader::default();
+        let seen = loader.seen.clone();
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(ModLoaderState {
+            loader: Box::new(loader),
+            loaded_mods: vec![handle("a"), handle("b")],
+        });
+
+        let mut system = ModBridgeSystem::new();
+        system.update_resources(&mut resources).await;
+        system.update_resources(&mut resources).await;
+
+        assert_eq!(system.tick(), 2);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                ("a".to_string(), 0),
+                ("b".to_string(), 0),
+                ("a".to_string(), 1),
+                ("b".to_string(), 1),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_follows_dispatch_order() {
+        let loader = TickLoader::default();
+        let seen = loader.seen.clone();
+
+        let mut late = handle("a");
+        late.metadata.after = vec!["b".to_string()];
+        let mods = vec![late, handle("b")];
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(ModRegistry::new(mods.clone()));
+        resources.insert(ModLoaderState {
+            loader: Box::new(loader),
+            loaded_mods: mods,
+        });
+
+        ModBridgeSystem::new()
+            .update_resources(&mut resources)
+            .await;
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![("b".to_string(), 0), ("a".to_string(), 0)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_day_changed_ticks_schedules() {
+        let loader = TickLoader::default();
+        let turns = loader.turns.clone();
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(ModLoaderState {
+            loader: Box::new(loader),
+            loaded_mods: Vec::new(),
+        });
+
+        let mut system = ModBridgeSystem::new();
+        system.update_resources(&mut resources).await;
+        assert!(turns.lock().unwrap().is_empty());
+
+        {
+            let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
+            event_bus.publish(DayChanged { day: 2 });
+            event_bus.publish(DayChanged { day: 3 });
+            event_bus.dispatch();
+        }
+        system.update_resources(&mut resources).await;
+        assert_eq!(*turns.lock().unwrap(), vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_run_summary_weight_change() {
+        use crate::plugin::run_summary::RunSummaryConfig;
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(RunSummaryConfig::default());
+
+        {
+            let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
+            event_bus.publish(PluginParameterChangedEvent {
+                plugin_name: "run_summary".to_string(),
+                key: "dungeon.deepest_floor".to_string(),
+                value: serde_json::json!(1000),
+                issuer: None,
+            });
+            event_bus.dispatch();
+        }
+
+        let mut system = ModBridgeSystem::new();
+        system.update_resources(&mut resources).await;
+
+        let config = resources.get::<RunSummaryConfig>().await.unwrap();
+        assert_eq!(config.weight("dungeon.deepest_floor"), 1000.0);
+    }
+
+    #[tokio::test]
+    async fn test_plugin_params_are_synced_to_loader() {
+        let loader = TickLoader::default();
+        let params = loader.params.clone();
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(crate::plugin::CombatConfig::default());
+        resources.insert(ModLoaderState {
+            loader: Box::new(loader),
+            loaded_mods: Vec::new(),
+        });
+
+        {
+            let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
+            event_bus.publish(PluginParameterChangedEvent {
+                plugin_name: "combat".to_string(),
+                key: "default_max_hp".to_string(),
+                value: serde_json::json!(250),
+                issuer: None,
+            });
+            event_bus.dispatch();
+        }
+
+        let mut system = ModBridgeSystem::new();
+        system.update_resources(&mut resources).await;
+
+        // The change is visible to scripts under both the field name and its alias
+        let params = params.lock().unwrap();
+        let get = |key: &str| {
+            params
+                .get(&("combat".to_string(), key.to_string()))
+                .cloned()
+        };
+        assert_eq!(get("default_max_hp"), Some(serde_json::json!(250)));
+        assert_eq!(get("max_hp"), Some(serde_json::json!(250)));
+        assert_eq!(get("enabled"), Some(serde_json::json!(true)));
+        assert!(get("missing").is_none());
+    }
+
+    /// Publish one `set_plugin_param` per (issuer, value) and run an update
+    async fn set_difficulty_from(
+        system: &mut ModBridgeSystem,
+        resources: &mut ResourceContext,
+        changes: &[(&str, f64)],
+    ) -> (f32, Vec<ModLogEntry>) {
+        {
+            let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
+            for (issuer, value) in changes {
+                event_bus.publish(PluginParameterChangedEvent {
+                    plugin_name: "combat".to_string(),
+                    key: "difficulty_multiplier".to_string(),
+                    value: serde_json::json!(value),
+                    issuer: Some(issuer.to_string()),
+                });
+            }
+            event_bus.dispatch();
+        }
+        system.update_resources(resources).await;
+
+        let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
+        event_bus.dispatch();
+        let logs = event_bus
+            .reader::<ModLogEvent>()
+            .iter()
+            .map(|event| event.entry.clone())
+            .collect();
+        drop(event_bus);
+        let config = resources
+            .get::<crate::plugin::CombatConfig>()
+            .await
+            .unwrap();
+        (config.difficulty_multiplier, logs)
+    }
+
+    #[tokio::test]
+    async fn test_param_conflict_last_wins() {
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(crate::plugin::CombatConfig::default());
+        let mut system = ModBridgeSystem::new();
+
+        let (difficulty, logs) = set_difficulty_from(
+            &mut system,
+            &mut resources,
+            &[("easy_mode", 0.5), ("hard_mode", 2.0)],
+        )
+        .await;
+        assert_eq!(difficulty, 2.0);
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].mod_id.as_deref(), Some("hard_mode"));
+        assert_eq!(logs[0].level, ModLogLevel::Warn);
+        assert_eq!(
+            logs[0].message,
+            "combat.difficulty_multiplier = 2.0 overrides the value set by MOD 'easy_mode'"
+        );
+
+        // The same MOD changing its own value again is no conflict
+        let (difficulty, logs) =
+            set_difficulty_from(&mut system, &mut resources, &[("hard_mode", 3.0)]).await;
+        assert_eq!(difficulty, 3.0);
+        assert!(logs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_param_conflict_first_wins() {
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(crate::plugin::CombatConfig::default());
+        resources.insert(ModSystemConfig {
+            param_conflicts: ParamConflictPolicy::FirstWins,
+            ..ModSystemConfig::default()
+        });
+        let mut system = ModBridgeSystem::new();
+
+        let (difficulty, logs) = set_difficulty_from(
+            &mut system,
+            &mut resources,
+            &[("easy_mode", 0.5), ("hard_mode", 2.0)],
+        )
+        .await;
+        assert_eq!(difficulty, 0.5);
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].mod_id.as_deref(), Some("hard_mode"));
+        assert_eq!(
+            logs[0].message,
+            "combat.difficulty_multiplier = 2.0 ignored, MOD 'easy_mode' set it first"
+        );
+
+        // Unloading the owner frees the key
+        {
+            let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
+            event_bus.publish(ModUnloadedEvent {
+                mod_id: "easy_mode".to_string(),
+            });
+        }
+        let (difficulty, logs) =
+            set_difficulty_from(&mut system, &mut resources, &[("hard_mode", 2.0)]).await;
+        assert_eq!(difficulty, 2.0);
+        assert!(logs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mod_action_rejected_without_enough_ap() {
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(ActionPoints::new(1));
+        let mut registry = ActionRegistry::new();
+        registry
+            .register(ActionDefinition::new("propaganda:campaign", 2).with_source("propaganda"));
+        resources.insert(registry);
+        let mut actions = ModActions::new();
+        actions.insert(ModActionEntry::from_definition(
+            crate::modding::ModActionDefinition {
+                mod_id: "propaganda".to_string(),
+                name: "campaign".to_string(),
+                ap_cost: 2,
+                cooldown_days: 0,
+                callback: "run_campaign".to_string(),
+            },
+        ));
+        resources.insert(actions);
+
+        {
+            let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
+            for action_id in ["propaganda:campaign", "base:scout"] {
+                event_bus.publish(ActionRequested {
+                    action_id: action_id.to_string(),
+                    actor: None,
+                    target: None,
+                });
+            }
+            event_bus.dispatch();
+        }
+        let mut system = ModBridgeSystem::new();
+        system.update_resources(&mut resources).await;
+
+        // Only the MOD action is answered; nothing was consumed
+        let mut event_bus = resources.get_mut::<EventBus>().await.unwrap();
+        event_bus.dispatch();
+        let failed: Vec<_> = event_bus.reader::<ActionFailed>().iter().cloned().collect();
+        assert_eq!(
+            failed,
+            [ActionFailed {
+                action_id: "propaganda:campaign".to_string(),
+                actor: None,
+                reason: "Needs 2 action points, 1 remaining".to_string(),
+                refunded: 0,
+            }]
+        );
+        drop(event_bus);
+        assert_eq!(resources.get::<ActionPoints>().await.unwrap().available, 1);
+    }
 }
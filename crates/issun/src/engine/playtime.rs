@@ -0,0 +1,289 @@
+//! Playtime tracking, segmented by scene
+//!
+//! Insert a [`PlaytimeTracker`] resource and the runners add the wall-clock
+//! time of every frame to the [`PlaytimeStats`] resource (inserted if the
+//! game has none) under the current scene's
+//! [`scene_name`](crate::scene::Scene::scene_name). Time while
+//! [`SimulationControl`](crate::engine::SimulationControl) pauses the game,
+//! or while the player is idle, is counted separately and not as played.
+//!
+//! `PlaytimeStats` belongs to the save: `SaveLoadPlugin` writes it into every
+//! save (with `play_time_secs` in the metadata header, for the save menu)
+//! and restores it on load. Each save also rolls the time played since the
+//! previous save into the [`PlaytimeProfile`] resource, the lifetime totals
+//! the game keeps with its player profile.
+//!
+//! ```ignore
+//! let game = GameBuilder::new()
+//!     .with_resource(
+//!         PlaytimeTracker::new().with_idle_timeout(Some(Duration::from_secs(300))),
+//!     )
+//!     .with_resource(load_profile()?) // PlaytimeProfile
+//!     .build()
+//!     .await?;
+//!
+//! let stats = resources.get::<PlaytimeStats>().await.unwrap();
+//! println!("{:?} played, {:?} in combat", stats.total(), stats.scene("GameScene::Combat"));
+//! ```
+
+use crate::context::ResourceContext;
+use crate::engine::focus::simulation_paused;
+use crate::event::{Event, EventBus};
+use crate::plugin::metrics::{
+    DefineMetricRequested, MetricDefinition, MetricId, MetricType, MetricValue, MetricsRegistry,
+    RecordMetricRequested,
+};
+use crate::scene::{Scene, SceneDirector};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Metric recorded with the total playtime in seconds
+pub const PLAYTIME_METRIC: &str = "playtime.total_secs";
+
+/// Playtime of one save (resource, saved with the game)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlaytimeStats {
+    /// Time played per scene name
+    pub per_scene: BTreeMap<String, Duration>,
+    /// Time the simulation was paused
+    pub paused: Duration,
+    /// Time the player was idle
+    pub idle: Duration,
+}
+
+impl PlaytimeStats {
+    /// Time played in every scene, without pauses and idle time
+    pub fn total(&self) -> Duration {
+        self.per_scene.values().sum()
+    }
+
+    /// Time played in scene `name`
+    pub fn scene(&self, name: &str) -> Duration {
+        self.per_scene.get(name).copied().unwrap_or_default()
+    }
+}
+
+/// Lifetime playtime across saves (resource, kept with the player profile)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlaytimeProfile {
+    pub per_scene: BTreeMap<String, Duration>,
+}
+
+impl PlaytimeProfile {
+    pub fn total(&self) -> Duration {
+        self.per_scene.values().sum()
+    }
+
+    pub fn scene(&self, name: &str) -> Duration {
+        self.per_scene.get(name).copied().unwrap_or_default()
+    }
+}
+
+/// No input for the tracker's idle timeout; playtime stops accumulating
+/// until the next input
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdleDetected {
+    /// Time since the last input
+    pub idle_for: Duration,
+}
+
+impl Event for IdleDetected {}
+
+/// Accumulates playtime into [`PlaytimeStats`] (resource)
+#[derive(Debug, Clone)]
+pub struct PlaytimeTracker {
+    idle_timeout: Option<Duration>,
+    metrics_interval: Duration,
+    last_frame: Option<Instant>,
+    last_activity: Option<Instant>,
+    idle: bool,
+    session: Duration,
+    /// Played since the last roll-up into the profile, per scene
+    unrolled: BTreeMap<String, Duration>,
+    last_metric: Option<Instant>,
+    /// `DefineMetricRequested` was published
+    metric_defined: bool,
+}
+
+impl Default for PlaytimeTracker {
+    fn default() -> Self {
+        Self {
+            idle_timeout: None,
+            metrics_interval: Duration::from_secs(60),
+            last_frame: None,
+            last_activity: None,
+            idle: false,
+            session: Duration::ZERO,
+            unrolled: BTreeMap::new(),
+            last_metric: None,
+            metric_defined: false,
+        }
+    }
+}
+
+impl PlaytimeTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop accumulating after `timeout` without input; `None` never does
+    pub fn with_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// How often [`PLAYTIME_METRIC`] is recorded while a `MetricsRegistry`
+    /// exists (default: every minute)
+    pub fn with_metrics_interval(mut self, interval: Duration) -> Self {
+        self.metrics_interval = interval;
+        self
+    }
+
+    /// Time played since the game started, across loads
+    pub fn current_session(&self) -> Duration {
+        self.session
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.idle
+    }
+
+    /// The player did something; ends idle time
+    pub fn note_input(&mut self, now: Instant) {
+        self.last_activity = Some(now);
+        self.idle = false;
+    }
+
+    /// Account the time since the previous frame to `scene`
+    ///
+    /// Called by the runners once per frame; `now` is a parameter so tests
+    /// can drive a fake clock.
+    pub async fn record(&mut self, scene: Option<&str>, now: Instant, resources: &ResourceContext) {
+        let elapsed = self
+            .last_frame
+            .map_or(Duration::ZERO, |last| now.saturating_duration_since(last));
+        self.last_frame = Some(now);
+        let last_activity = *self.last_activity.get_or_insert(now);
+
+        let paused = simulation_paused(resources).await;
+        if let Some(mut stats) = resources.get_mut::<PlaytimeStats>().await {
+            if paused {
+                stats.paused += elapsed;
+            } else if self.idle {
+                stats.idle += elapsed;
+            } else if let Some(scene) = scene {
+                *stats.per_scene.entry(scene.to_string()).or_default() += elapsed;
+                *self.unrolled.entry(scene.to_string()).or_default() += elapsed;
+                self.session += elapsed;
+            }
+        }
+
+        // Idle from this frame on; a paused game is not waiting for the player
+        let mut idle_detected = None;
+        if let (Some(timeout), false) = (self.idle_timeout, paused) {
+            let idle_for = now.saturating_duration_since(last_activity);
+            if !self.idle && idle_for >= timeout {
+                self.idle = true;
+                idle_detected = Some(IdleDetected { idle_for });
+            }
+        }
+
+        let metric = self.metric_due(now, resources).await;
+        if idle_detected.is_none() && metric.is_none() {
+            return;
+        }
+        let Some(mut bus) = resources.get_mut::<EventBus>().await else {
+            return;
+        };
+        if let Some(event) = idle_detected {
+            bus.publish(event);
+        }
+        if let Some((define, value)) = metric {
+            if let Some(definition) = define {
+                bus.publish(DefineMetricRequested { definition });
+            }
+            bus.publish(RecordMetricRequested { value });
+        }
+    }
+
+    /// Add the time played since the last roll-up to the [`PlaytimeProfile`],
+    /// if the game has one
+    pub async fn roll_up(&mut self, resources: &ResourceContext) {
+        let Some(mut profile) = resources.get_mut::<PlaytimeProfile>().await else {
+            return;
+        };
+        for (scene, played) in std::mem::take(&mut self.unrolled) {
+            *profile.per_scene.entry(scene).or_default() += played;
+        }
+    }
+
+    /// The metric to record this frame, with its definition the first time
+    async fn metric_due(
+        &mut self,
+        now: Instant,
+        resources: &ResourceContext,
+    ) -> Option<(Option<MetricDefinition>, MetricValue)> {
+        if self
+            .last_metric
+            .is_some_and(|last| now.saturating_duration_since(last) < self.metrics_interval)
+        {
+            return None;
+        }
+        let id = MetricId::new(PLAYTIME_METRIC);
+        let defined = resources
+            .get::<MetricsRegistry>()
+            .await?
+            .get_definition(&id)
+            .is_some();
+        let total = resources.get::<PlaytimeStats>().await?.total();
+        self.last_metric = Some(now);
+
+        // Defined once; the registry only learns of it when MetricsSystem runs
+        let define = (!defined && !std::mem::replace(&mut self.metric_defined, true)).then(|| {
+            MetricDefinition::new(
+                PLAYTIME_METRIC,
+                "Playtime",
+                "Time played in this save",
+                MetricType::Gauge,
+                "seconds",
+            )
+        });
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        Some((define, MetricValue::new(id, total.as_secs_f64(), timestamp)))
+    }
+}
+
+/// Let the game's [`PlaytimeTracker`], if any, account this frame
+pub(crate) async fn record_playtime<S: Scene>(director: &mut SceneDirector<S>) {
+    if !director.resources().contains::<PlaytimeTracker>() {
+        return;
+    }
+    if !director.resources().contains::<PlaytimeStats>() {
+        director.resources_mut().insert(PlaytimeStats::default());
+    }
+    let resources = director.resources();
+    let Some(mut tracker) = resources.get_mut::<PlaytimeTracker>().await else {
+        return;
+    };
+    let scene = director.current().map(|scene| scene.scene_name());
+    tracker
+        .record(scene.as_deref(), Instant::now(), resources)
+        .await;
+}
+
+/// Tell the game's [`PlaytimeTracker`], if any, that the player did something
+pub(crate) async fn note_playtime_input(resources: &ResourceContext) {
+    if let Some(mut tracker) = resources.get_mut::<PlaytimeTracker>().await {
+        tracker.note_input(Instant::now());
+    }
+}
+
+/// Roll the game's playtime into its profile after a save
+pub(crate) async fn roll_up_playtime(resources: &ResourceContext) {
+    if let Some(mut tracker) = resources.get_mut::<PlaytimeTracker>().await {
+        tracker.roll_up(resources).await;
+    }
+}
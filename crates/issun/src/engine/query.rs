@@ -0,0 +1,125 @@
+//! Query channel: run closures against live resources between ticks
+//!
+//! Code outside the game loop (HTTP handlers, consoles, test harnesses) sends
+//! a closure through a [`QueryHandle`]; the runner executes every pending
+//! closure with `&mut ResourceContext` between two ticks and sends the result
+//! back. No resource lock is held while the caller waits.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let (queries, receiver) = query_channel();
+//! let runner = HeadlessRunner::new(director).with_query_channel(receiver);
+//! tokio::spawn(runner.run());
+//!
+//! let gold = queries
+//!     .query(|resources| Box::pin(async move {
+//!         resources.get::<Wallet>().await.map(|w| w.gold)
+//!     }))
+//!     .await;
+//! ```
+
+use crate::context::ResourceContext;
+use std::future::Future;
+use std::pin::Pin;
+use tokio::sync::{mpsc, oneshot};
+
+/// Future returned by a query closure
+pub type QueryFuture<'a, R> = Pin<Box<dyn Future<Output = R> + Send + 'a>>;
+
+type QueryFn = Box<dyn for<'a> FnOnce(&'a mut ResourceContext) -> QueryFuture<'a, ()> + Send>;
+
+/// Create a connected handle/receiver pair
+pub fn query_channel() -> (QueryHandle, QueryReceiver) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    (QueryHandle { tx }, QueryReceiver { rx })
+}
+
+/// Sending side of the query channel; cheap to clone
+#[derive(Clone)]
+pub struct QueryHandle {
+    tx: mpsc::UnboundedSender<QueryFn>,
+}
+
+impl QueryHandle {
+    /// Run `query` on the runner's resources at the next tick boundary
+    ///
+    /// Returns `None` if the runner has stopped.
+    pub async fn query<R, F>(&self, query: F) -> Option<R>
+    where
+        F: for<'a> FnOnce(&'a mut ResourceContext) -> QueryFuture<'a, R> + Send + 'static,
+        R: Send + 'static,
+    {
+        let (reply, response) = oneshot::channel();
+        let job: QueryFn = Box::new(move |resources| {
+            Box::pin(async move {
+                let _ = reply.send(query(resources).await);
+            })
+        });
+        self.tx.send(job).ok()?;
+        response.await.ok()
+    }
+
+    /// Whether the receiving runner is gone
+    pub fn is_closed(&self) -> bool {
+        self.tx.is_closed()
+    }
+}
+
+/// Receiving side of the query channel, owned by a runner
+pub struct QueryReceiver {
+    rx: mpsc::UnboundedReceiver<QueryFn>,
+}
+
+impl QueryReceiver {
+    /// Run every query received so far; returns how many ran
+    pub async fn serve(&mut self, resources: &mut ResourceContext) -> usize {
+        let mut served = 0;
+        while let Ok(job) = self.rx.try_recv() {
+            job(resources).await;
+            served += 1;
+        }
+        served
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter(u32);
+
+    #[tokio::test]
+    async fn test_queries_run_when_served() {
+        let (queries, mut receiver) = query_channel();
+        let mut resources = ResourceContext::new();
+        resources.insert(Counter(1));
+
+        let read = tokio::spawn({
+            let queries = queries.clone();
+            async move {
+                queries
+                    .query(|resources| {
+                        Box::pin(async move {
+                            let mut counter = resources.get_mut::<Counter>().await.unwrap();
+                            counter.0 += 1;
+                            counter.0
+                        })
+                    })
+                    .await
+            }
+        });
+
+        // Nothing runs until the owner serves the channel
+        while receiver.serve(&mut resources).await == 0 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(read.await.unwrap(), Some(2));
+        assert_eq!(resources.get::<Counter>().await.unwrap().0, 2);
+
+        drop(receiver);
+        assert!(queries.is_closed());
+        let closed = queries.query(|_| Box::pin(async { 0 })).await;
+        assert_eq!(closed, None);
+    }
+}
@@ -1,7 +1,23 @@
 //! Random number generation for ISSUN
-
+//!
+//! Systems that must be reproducible (daily challenges, replays) derive their
+//! own stream from the game's [`MasterSeed`] instead of sharing one cursor:
+//!
+//! ```ignore
+//! let game = GameBuilder::new()
+//!     .with_resource(DailyChallenge::today().master_seed())
+//!     .build()
+//!     .await?;
+//!
+//! // In a system: independent of how many rolls other systems made
+//! let mut rng = master.rng("loot", run);
+//! ```
+
+use crate::resources::Resource;
+use chrono::{NaiveDate, Utc};
 use rand::rngs::StdRng;
-use rand::{Rng, SeedableRng};
+use rand::{Rng, RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
 
 /// Seeded random number generator for reproducible gameplay
 pub struct GameRng {
@@ -72,6 +88,95 @@ impl Default for GameRng {
     }
 }
 
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+/// Derive a child seed from `seed` and a stream label
+///
+/// Uses FNV-1a and splitmix64 rather than `std::hash`, so the result is the
+/// same on every platform and compiler version.
+pub fn derive_seed(seed: u64, label: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in label.as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    mix_seed(seed, hash)
+}
+
+/// Derive a child seed from `seed` and a number (run, sequence, ...)
+pub fn mix_seed(seed: u64, value: u64) -> u64 {
+    let mut z = (seed ^ value.rotate_left(32)).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Seed every reproducible stream of a game is derived from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MasterSeed(pub u64);
+
+impl Resource for MasterSeed {}
+
+impl MasterSeed {
+    /// Seed of the `name` stream for run `run` (0 for the first run)
+    pub fn stream(self, name: &str, run: u32) -> u64 {
+        mix_seed(derive_seed(self.0, name), u64::from(run))
+    }
+
+    /// RNG over the `name` stream for run `run`
+    pub fn rng(self, name: &str, run: u32) -> GameRng {
+        GameRng::new(self.stream(name, run))
+    }
+
+    /// Seed of the random stream of MOD `mod_id`
+    pub fn mod_stream(self, mod_id: &str) -> u64 {
+        derive_seed(derive_seed(self.0, "mod"), mod_id)
+    }
+}
+
+/// Daily challenge: everyone playing on the same date shares one seed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyChallenge {
+    pub date: NaiveDate,
+}
+
+impl DailyChallenge {
+    pub fn new(date: NaiveDate) -> Self {
+        Self { date }
+    }
+
+    /// Challenge for the current UTC date
+    pub fn today() -> Self {
+        Self::new(Utc::now().date_naive())
+    }
+
+    /// Seed for this date
+    pub fn seed(&self) -> u64 {
+        derive_seed(0, &format!("issun:daily:{}", self.date.format("%Y-%m-%d")))
+    }
+
+    /// Master seed to insert as a resource
+    pub fn master_seed(&self) -> MasterSeed {
+        MasterSeed(self.seed())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,6 +213,35 @@ mod tests {
         assert_eq!(result1, result2);
     }
 
+    #[test]
+    fn test_derived_streams() {
+        let master = MasterSeed(42);
+        assert_eq!(master.stream("loot", 0), MasterSeed(42).stream("loot", 0));
+        assert_ne!(master.stream("loot", 0), master.stream("loot", 1));
+        assert_ne!(master.stream("loot", 0), master.stream("combat", 0));
+        assert_ne!(master.stream("loot", 0), MasterSeed(43).stream("loot", 0));
+
+        // Pinned so seeds stay valid across releases
+        assert_eq!(derive_seed(42, "loot"), 0xad8f_5d6b_49ea_8a78);
+
+        assert_ne!(
+            master.mod_stream("easy_mode"),
+            master.mod_stream("hard_mode")
+        );
+        assert_eq!(
+            master.mod_stream("easy_mode"),
+            derive_seed(derive_seed(42, "mod"), "easy_mode")
+        );
+    }
+
+    #[test]
+    fn test_daily_challenge_seed() {
+        let day = |d| DailyChallenge::new(NaiveDate::from_ymd_opt(2026, 10, d).unwrap());
+        assert_eq!(day(15).seed(), day(15).seed());
+        assert_ne!(day(15).seed(), day(16).seed());
+        assert_eq!(day(15).master_seed(), MasterSeed(day(15).seed()));
+    }
+
     #[test]
     fn test_choose() {
         let mut rng = GameRng::new(42);
@@ -5,10 +5,23 @@
 
 use crate::{
     context::{ResourceContext, ServiceContext, SystemContext},
+    engine::{
+        crash::record_tick,
+        focus::{simulation_paused, FocusConfig, FocusSignal, FocusTracker, FramePlan},
+        lifecycle::{exit_plugins, start_plugins},
+        lockstep::{pass_tick_gate, TickGate},
+        playtime::{note_playtime_input, record_playtime},
+        query::QueryReceiver,
+    },
     error::Result,
     event::EventBus,
-    scene::{Scene, SceneDirector, SceneTransition},
-    ui::{input::poll_input, InputEvent, Tui},
+    scene::{Scene, SceneDirector, SceneTransition, TransitionStyle},
+    ui::{
+        core::text_input::TextEntryActive,
+        input::{poll_input_or_focus, PolledInput},
+        ratatui::{apply_theme_requests, RatatuiTheme, TransitionLayer},
+        InputEvent, Tui,
+    },
 };
 use ratatui::Frame;
 use std::{
@@ -21,6 +34,10 @@ use std::{
 pub struct GameRunner<S> {
     director: SceneDirector<S>,
     tick_rate: Duration,
+    tick_gate: Option<Box<dyn TickGate>>,
+    transition_style: TransitionStyle,
+    queries: Option<QueryReceiver>,
+    focus: Option<FocusTracker>,
 }
 
 impl<S: Scene> GameRunner<S> {
@@ -29,6 +46,10 @@ impl<S: Scene> GameRunner<S> {
         Self {
             director,
             tick_rate: Duration::from_millis(33),
+            tick_gate: None,
+            transition_style: TransitionStyle::instant(),
+            queries: None,
+            focus: None,
         }
     }
 
@@ -38,6 +59,38 @@ impl<S: Scene> GameRunner<S> {
         self
     }
 
+    /// Gate every periodic update on a [`TickGate`] (e.g. a
+    /// [`LockstepDriver`](crate::engine::LockstepDriver)).
+    ///
+    /// Rendering and input keep running while the gate stalls, but the scene
+    /// update, systems and event dispatch wait until it releases the tick.
+    pub fn with_tick_gate(mut self, gate: impl TickGate + 'static) -> Self {
+        self.tick_gate = Some(Box::new(gate));
+        self
+    }
+
+    /// Effect for scene changes that do not request their own style via
+    /// [`SceneTransition::with_style`] (default: instant).
+    pub fn with_transition_style(mut self, style: TransitionStyle) -> Self {
+        self.transition_style = style;
+        self
+    }
+
+    /// Serve a [query channel](crate::engine::query) (e.g. for
+    /// [`DebugHttpPlugin`](crate::plugin::debug_http)) once per frame,
+    /// before input and the tick gate.
+    pub fn with_query_channel(mut self, queries: QueryReceiver) -> Self {
+        self.queries = Some(queries);
+        self
+    }
+
+    /// Track terminal focus and apply the game's [`FocusConfig`] while the
+    /// terminal is unfocused (see [`crate::engine::focus`]).
+    pub fn with_focus_tracking(mut self, tracker: FocusTracker) -> Self {
+        self.focus = Some(tracker);
+        self
+    }
+
     /// Borrow the underlying director.
     pub fn director(&self) -> &SceneDirector<S> {
         &self.director
@@ -48,21 +101,68 @@ impl<S: Scene> GameRunner<S> {
         &mut self.director
     }
 
+    /// Pass a terminal signal to the focus tracker, if any.
+    async fn signal_focus(&mut self, signal: FocusSignal) {
+        if let Some(focus) = &mut self.focus {
+            focus
+                .signal(signal, Instant::now(), self.director.resources_mut())
+                .await;
+        }
+    }
+
+    /// Start the transition's effect (if it changes the scene) and perform it.
+    async fn handle_transition(
+        &mut self,
+        transition: SceneTransition<S>,
+        transitions: &mut TransitionLayer,
+    ) -> Result<()> {
+        if transition.changes_scene() {
+            let style = transition.style().unwrap_or(self.transition_style);
+            transitions.start(style, Instant::now());
+        }
+        self.director.handle(transition).await
+    }
+
     /// Update all registered systems that require periodic updates.
     ///
     /// This method processes event-driven systems like TimerSystem and ActionResetSystem
     /// that respond to published events (AdvanceTimeRequested, DayChanged, etc.).
     ///
     /// Systems with custom update signatures will be called with appropriate contexts.
+    /// Systems gated on a feature flag that is off are skipped.
     async fn update_systems(&mut self) {
+        use crate::engine::config_file::ConfigReloadSystem;
+        use crate::engine::flags::FeatureFlagSystem;
         use crate::plugin::action::ActionResetSystem;
         use crate::plugin::time::TimerSystem;
 
+        // Apply feature flag toggles first so gates see this tick's values
+        self.director
+            .with_current_async(|_, _, systems, resources| {
+                Box::pin(async move {
+                    if let Some(flag_system) = systems.get_mut::<FeatureFlagSystem>() {
+                        flag_system.update(resources).await;
+                    }
+                })
+            })
+            .await;
+
+        // Apply config file changes before the systems that read the configs
+        self.director
+            .with_current_async(|_, _, systems, resources| {
+                Box::pin(async move {
+                    if let Some(config_reload) = systems.get_mut::<ConfigReloadSystem>() {
+                        config_reload.update(resources).await;
+                    }
+                })
+            })
+            .await;
+
         // Update TimerSystem (processes AdvanceTimeRequested → DayChanged)
         self.director
             .with_current_async(|_, services, systems, resources| {
                 Box::pin(async move {
-                    if let Some(timer_system) = systems.get_mut::<TimerSystem>() {
+                    if let Some(timer_system) = systems.active_mut::<TimerSystem>() {
                         timer_system.update(services, resources).await;
                     }
                 })
@@ -73,7 +173,7 @@ impl<S: Scene> GameRunner<S> {
         self.director
             .with_current_async(|_, services, systems, resources| {
                 Box::pin(async move {
-                    if let Some(action_reset) = systems.get_mut::<ActionResetSystem>() {
+                    if let Some(action_reset) = systems.active_mut::<ActionResetSystem>() {
                         action_reset.update(services, resources).await;
                     }
                 })
@@ -83,6 +183,26 @@ impl<S: Scene> GameRunner<S> {
 
     /// Run the game loop until the director requests quit.
     ///
+    /// If no [`RatatuiTheme`] resource exists, the dark preset is inserted with
+    /// the color depth reported by the terminal. `SetThemeRequested` events are
+    /// applied before each draw.
+    ///
+    /// Plugin `on_start` hooks run before the first frame; `on_exit` hooks run
+    /// when the director quits or its scene stack empties.
+    ///
+    /// Scene changes play their [`TransitionStyle`] (or the runner's default):
+    /// the outgoing frame is mixed into the incoming scene's frames, and input
+    /// is queued until the effect completes, then delivered in order.
+    ///
+    /// While a [`SimulationControl`](crate::engine::SimulationControl) resource
+    /// is paused, the scene update and systems are skipped; rendering, input
+    /// and event dispatch continue. With
+    /// [`with_focus_tracking`](Self::with_focus_tracking), focus changes are
+    /// published and the [`FocusConfig`] policy applies while unfocused.
+    ///
+    /// While a [`TextEntryActive`] resource is present, character keys reach
+    /// `on_input` as `InputEvent::Char` even for `h`/`j`/`k`/`l`/`q`.
+    ///
     /// # Parameters
     /// - `tui`: initialized [`Tui`] instance.
     /// - `render`: callback invoked every frame with the current scene and resources.
@@ -100,24 +220,90 @@ impl<S: Scene> GameRunner<S> {
     {
         let mut last_tick = Instant::now();
 
+        if !self.director.resources().contains::<RatatuiTheme>() {
+            self.director
+                .resources_mut()
+                .insert(RatatuiTheme::default().with_detected_color_depth());
+        }
+
+        start_plugins(&mut self.director).await;
+        let mut transitions = TransitionLayer::new();
+
         loop {
-            // Draw
-            tui.terminal().draw(|frame| {
-                if let Some(scene) = self.director.current() {
-                    render(frame, scene, self.director.resources());
+            apply_theme_requests(self.director.resources_mut()).await;
+            if let Some(queries) = &mut self.queries {
+                queries.serve(self.director.resources_mut()).await;
+            }
+
+            let plan = match &self.focus {
+                Some(focus) => {
+                    let config = self
+                        .director
+                        .resources()
+                        .get::<FocusConfig>()
+                        .await
+                        .map(|config| config.clone())
+                        .unwrap_or_default();
+                    focus.frame_plan(self.tick_rate, &config)
                 }
-            })?;
+                None => FramePlan {
+                    tick_interval: self.tick_rate,
+                    render: true,
+                },
+            };
+
+            // Draw, mixing in the outgoing frame while an effect plays
+            if plan.render {
+                let completed = tui.terminal().draw(|frame| {
+                    if let Some(scene) = self.director.current() {
+                        render(frame, scene, self.director.resources());
+                    }
+                    transitions.compose(frame.buffer_mut(), Instant::now());
+                })?;
+                transitions.capture(completed.buffer);
+            }
 
             // Calculate timeout for next tick
-            let timeout = self
-                .tick_rate
+            let timeout = plan
+                .tick_interval
                 .checked_sub(last_tick.elapsed())
                 .unwrap_or(Duration::ZERO);
 
             // Poll input with timeout
-            let input = poll_input(timeout)?;
+            let text_entry = self.director.resources().contains::<TextEntryActive>();
+            let input = match poll_input_or_focus(timeout, text_entry)? {
+                PolledInput::Input(input) => {
+                    if input != InputEvent::Other {
+                        self.signal_focus(FocusSignal::Input).await;
+                        note_playtime_input(self.director.resources()).await;
+                    }
+                    input
+                }
+                PolledInput::Focus(focused) => {
+                    let signal = if focused {
+                        FocusSignal::Gained
+                    } else {
+                        FocusSignal::Lost
+                    };
+                    self.signal_focus(signal).await;
+                    InputEvent::Other
+                }
+            };
+            if let Some(focus) = &mut self.focus {
+                focus
+                    .poll_idle(Instant::now(), self.director.resources_mut())
+                    .await;
+            }
+
+            // Input waits while an effect plays, then arrives in order
+            let mut next_input = if input != InputEvent::Other {
+                transitions.hold(input, Instant::now())
+            } else {
+                None
+            }
+            .or_else(|| transitions.release(Instant::now()));
 
-            if input != InputEvent::Other {
+            while let Some(input) = next_input {
                 if let Some(transition) = self
                     .director
                     .with_current_async(|scene, services, systems, resources| {
@@ -125,17 +311,25 @@ impl<S: Scene> GameRunner<S> {
                     })
                     .await
                 {
-                    self.director.handle(transition).await?;
+                    self.handle_transition(transition, &mut transitions).await?;
                 }
+                next_input = transitions.release(Instant::now());
             }
 
-            // Periodic update (Scene::on_update)
-            if last_tick.elapsed() >= self.tick_rate {
-                let transition = self.director.update().await;
-                self.director.handle(transition).await?;
+            // Periodic update (Scene::on_update), unless SimulationControl pauses it
+            let due = last_tick.elapsed() >= plan.tick_interval;
+            let stalled = due && !pass_tick_gate(&mut self.tick_gate, &mut self.director).await;
+            if stalled {
+                // Retry the gate on the next tick instead of spinning
+                last_tick = Instant::now();
+            } else if due {
+                if !simulation_paused(self.director.resources()).await {
+                    let transition = self.director.update().await;
+                    self.handle_transition(transition, &mut transitions).await?;
 
-                // Update registered systems (handles event-driven logic)
-                self.update_systems().await;
+                    // Update registered systems (handles event-driven logic)
+                    self.update_systems().await;
+                }
 
                 last_tick = Instant::now();
             }
@@ -144,11 +338,22 @@ impl<S: Scene> GameRunner<S> {
                 break;
             }
 
+            if stalled {
+                continue;
+            }
+
+            record_tick(&mut self.director).await;
+            record_playtime(&mut self.director).await;
+
+            if let Some(focus) = &mut self.focus {
+                focus.collect(self.director.resources_mut()).await;
+            }
             if let Some(mut event_bus) = self.director.resources_mut().get_mut::<EventBus>().await {
                 event_bus.dispatch();
             }
         }
 
+        exit_plugins(&mut self.director).await;
         Ok(())
     }
 }
@@ -4,6 +4,7 @@
 
 use crate::context::Context;
 use async_trait::async_trait;
+use std::borrow::Cow;
 
 /// Entity trait for game objects
 ///
@@ -12,7 +13,14 @@ use async_trait::async_trait;
 #[async_trait]
 pub trait Entity: Send + Sync {
     /// Unique identifier for this entity
-    fn id(&self) -> &str;
+    ///
+    /// Borrowed for string ids; numeric and other ids are formatted.
+    fn id(&self) -> Cow<'_, str>;
+
+    /// Whether this entity's id is `id`
+    fn matches_id(&self, id: &str) -> bool {
+        self.id() == id
+    }
 
     /// Update the entity state
     ///
@@ -44,8 +52,8 @@ mod tests {
 
     #[async_trait]
     impl Entity for TestEntity {
-        fn id(&self) -> &str {
-            &self.id
+        fn id(&self) -> Cow<'_, str> {
+            Cow::Borrowed(&self.id)
         }
 
         async fn update(&mut self, _ctx: &mut Context) {
@@ -29,6 +29,17 @@ pub enum IssunError {
     #[error("Game loop error: {0}")]
     GameLoop(String),
 
+    /// Resource missing from the `ResourceContext`
+    #[error("Resource not found: {0}")]
+    ResourceNotFound(String),
+
+    /// Resource lock not acquired before the deadline
+    #[error("Timed out after {waited:?} waiting for resource {resource}")]
+    ResourceTimedOut {
+        resource: String,
+        waited: std::time::Duration,
+    },
+
     /// Asset loading error
     #[error("Asset loading error: {0}")]
     AssetLoad(String),
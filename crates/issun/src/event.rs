@@ -2,12 +2,22 @@
 //!
 //! Events are double buffered per type: events published during frame `N` are
 //! consumed in frame `N + 1` after the runner calls [`EventBus::dispatch`].
+//! Named readers ([`EventBus::reader_for`]) keep their own cursor instead and
+//! see every event once, whichever frames they run in.
 
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
+use std::sync::PoisonError;
 
 #[cfg(feature = "network")]
-use crate::network::{NetworkMetadata, NetworkScope};
+use crate::network::{Delivery, NetworkMetadata, NetworkScope, NetworkSendStats};
+
+mod factory;
+
+#[cfg(feature = "event-factories")]
+#[doc(hidden)]
+pub use factory::EventFactoryRegistration;
+pub use factory::{AnyEventPublish, EventFactoryRegistry, FactoryError};
 
 /// Marker trait for types that can flow through the [`EventBus`].
 ///
@@ -25,18 +35,89 @@ pub trait Event: Clone + Send + Sync + 'static {
     fn network_scope() -> NetworkScope {
         NetworkScope::default()
     }
+
+    /// What happens to this event when the send budget is spent
+    #[cfg(feature = "network")]
+    fn delivery() -> Delivery {
+        Delivery::default()
+    }
+
+    /// Lane used by [`EventBus::publish`] for this event type
+    fn priority() -> Priority {
+        Priority::Normal
+    }
+
+    /// Keep only the latest published instance of this type
+    ///
+    /// For state broadcasts (positions, scores) where only the newest value
+    /// matters: each publish replaces the one still waiting for dispatch, so
+    /// readers see at most one per frame and a networked event is sent once
+    /// per dispatch. Events received from the network coalesce the same way.
+    fn is_latest_only() -> bool {
+        false
+    }
+}
+
+/// Lane an event is published in, see [`EventBus::publish_with_priority`]
+///
+/// Readers see all `High` events of a type before its `Normal` ones, and
+/// those before its `Low` ones; within a lane, in publish order.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum Priority {
+    /// Input and commands, handled before what gameplay derives from them
+    High,
+    #[default]
+    Normal,
+    Low,
+}
+
+impl Priority {
+    /// Every lane, in reading order
+    pub const ALL: [Priority; 3] = [Priority::High, Priority::Normal, Priority::Low];
+
+    fn lane(self) -> usize {
+        self as usize
+    }
 }
 
 /// Event bus stored inside [`ResourceContext`](crate::context::ResourceContext).
 ///
 /// Each event type has its own [`EventChannel`] that double-buffers events so
-/// publishers and subscribers do not contend within the same frame.
+/// publishers and subscribers do not contend within the same frame. Events
+/// are published in a [`Priority`] lane; readers see them lane by lane.
 pub struct EventBus {
     channels: HashMap<TypeId, Box<dyn EventChannelStorage>>,
 
     #[cfg(feature = "network")]
     network: Option<NetworkState>,
 
+    // Set by `with_namespace`: tags outgoing frames, filters incoming ones
+    #[cfg(feature = "network")]
+    namespace: Option<String>,
+
+    #[cfg(feature = "network")]
+    legacy_frames: crate::network::LegacyFramePolicy,
+
+    // Incoming frames seen by `poll_network`, for `stats()`
+    #[cfg(feature = "network")]
+    frames: NetworkFrameStats,
+
+    // Send rates and byte budget applied by `dispatch`
+    #[cfg(feature = "network")]
+    shaper: crate::network::shaping::SendShaper,
+
     // Optional tracer for debugging event chains
     tracer: Option<std::sync::Arc<std::sync::Mutex<crate::trace::EventChainTracer>>>,
 
@@ -44,6 +125,77 @@ pub struct EventBus {
     recorder: Option<std::sync::Arc<std::sync::Mutex<crate::replay::EventRecorder>>>,
 
     current_frame: u64,
+
+    // Events published since creation, for `stats()`
+    published: u64,
+
+    // Cursors of `reader_for` readers: index of the next unread event, per
+    // type and reader name
+    readers: std::sync::Mutex<HashMap<TypeId, HashMap<String, u64>>>,
+
+    // Heap allocations made by the bus, for `stats()`
+    #[cfg(debug_assertions)]
+    allocations: EventBusAllocations,
+}
+
+/// Snapshot of an [`EventBus`]'s channels, see [`EventBus::stats`]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EventBusStats {
+    pub frame: u64,
+    /// Events published since the bus was created
+    pub published_total: u64,
+    /// One entry per event type, sorted by type name
+    pub channels: Vec<EventChannelStats>,
+    /// Heap allocations made by the bus (debug builds only)
+    #[cfg(debug_assertions)]
+    #[serde(default)]
+    pub allocations: EventBusAllocations,
+    /// Incoming network frames (network feature only)
+    #[cfg(feature = "network")]
+    #[serde(default)]
+    pub network: NetworkFrameStats,
+    /// Outgoing networked events after send shaping (network feature only)
+    #[cfg(feature = "network")]
+    #[serde(default)]
+    pub network_send: NetworkSendStats,
+}
+
+/// Incoming frames seen by [`EventBus::poll_network`]
+#[cfg(feature = "network")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NetworkFrameStats {
+    /// Frames handed over by the backend
+    pub received: u64,
+    /// Ignored because they belong to another namespace
+    pub foreign_namespace: u64,
+    /// Frames without a namespace ignored per
+    /// [`LegacyFramePolicy::Ignore`](crate::network::LegacyFramePolicy::Ignore)
+    pub legacy_ignored: u64,
+}
+
+/// Heap allocations counted by an [`EventBus`] since it was created
+///
+/// A steady-state frame should not move these: buffers keep their capacity
+/// across [`EventBus::dispatch`] and snapshots reuse a spare buffer.
+#[cfg(debug_assertions)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EventBusAllocations {
+    /// Channels created for a new event type
+    pub channels: u64,
+    /// Publishes that grew a channel's write buffer
+    pub buffer_growths: u64,
+    /// [`EventBus::snapshot`] calls that had to grow a buffer
+    pub snapshots: u64,
+}
+
+/// Buffered events of one type
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EventChannelStats {
+    pub event_type: String,
+    /// Published this frame, visible after the next dispatch
+    pub pending: usize,
+    /// Visible to readers this frame
+    pub readable: usize,
 }
 
 #[cfg(feature = "network")]
@@ -56,6 +208,10 @@ struct NetworkState {
     sequence: std::sync::atomic::AtomicU64,
     current_metadata: Option<NetworkMetadata>,
     deserializers: HashMap<String, Box<dyn EventDeserializer>>,
+    // Set by `with_ordered_delivery`: relay-stamped events wait for their turn
+    ordering: Option<crate::network::ordering::SequenceBuffer>,
+    // Networked events published since the last dispatch, per priority lane
+    outgoing: [Vec<crate::network::shaping::Outgoing>; 3],
 }
 
 #[cfg(feature = "network")]
@@ -101,7 +257,7 @@ impl<E: Event + serde::de::DeserializeOwned> EventDeserializer for TypedEventDes
                 .or_insert_with(|| Box::new(EventChannel::<E>::new()));
 
             if let Some(channel) = entry.as_any_mut().downcast_mut::<EventChannel<E>>() {
-                channel.push(event);
+                channel.push(E::priority(), event);
             }
         }
     }
@@ -120,9 +276,21 @@ impl EventBus {
             channels: HashMap::new(),
             #[cfg(feature = "network")]
             network: None,
+            #[cfg(feature = "network")]
+            namespace: None,
+            #[cfg(feature = "network")]
+            legacy_frames: Default::default(),
+            #[cfg(feature = "network")]
+            frames: NetworkFrameStats::default(),
+            #[cfg(feature = "network")]
+            shaper: Default::default(),
             tracer: None,
             recorder: None,
             current_frame: 0,
+            published: 0,
+            readers: Default::default(),
+            #[cfg(debug_assertions)]
+            allocations: EventBusAllocations::default(),
         }
     }
 
@@ -139,6 +307,54 @@ impl EventBus {
         self.tracer = None;
     }
 
+    /// The tracer, if one is set
+    ///
+    /// Systems clone it before releasing the bus to time their handlers
+    /// (see [`crate::trace::HandlerSpan`]).
+    pub fn tracer(
+        &self,
+    ) -> Option<&std::sync::Arc<std::sync::Mutex<crate::trace::EventChainTracer>>> {
+        self.tracer.as_ref()
+    }
+
+    /// Publish the current frame's handler costs as `MetricsPlugin` gauges
+    ///
+    /// Publishes a `RecordMetricRequested` per (system, event type) that ran
+    /// a handler this frame, named `trace.cost.<event>.<system>` in
+    /// milliseconds, preceded by a `DefineMetricRequested` the first time a
+    /// gauge appears. Call it once per frame, before advancing the frame.
+    /// Does nothing unless an enabled tracer is set; returns the number of
+    /// gauges published.
+    pub fn publish_cost_metrics(&mut self, timestamp: u64) -> usize {
+        let (costs, new_definitions) = {
+            let Some(tracer) = self.tracer.as_ref() else {
+                return 0;
+            };
+            let mut tracer = tracer.lock().unwrap_or_else(PoisonError::into_inner);
+            if !tracer.is_enabled() {
+                return 0;
+            }
+
+            let costs = tracer.event_costs_for_frame(tracer.current_frame());
+            let new_definitions: Vec<_> = costs
+                .iter()
+                .filter(|cost| tracer.mark_cost_metric_defined(&cost.metric_id()))
+                .map(|cost| cost.metric_definition())
+                .collect();
+            (costs, new_definitions)
+        };
+
+        for definition in new_definitions {
+            self.publish(crate::plugin::metrics::DefineMetricRequested { definition });
+        }
+        for cost in &costs {
+            self.publish(crate::plugin::metrics::RecordMetricRequested {
+                value: cost.metric_value(timestamp),
+            });
+        }
+        costs.len()
+    }
+
     /// Set a recorder for event replay
     pub fn set_recorder(
         &mut self,
@@ -158,16 +374,14 @@ impl EventBus {
 
         // Update tracer
         if let Some(ref tracer) = self.tracer {
-            if let Ok(mut t) = tracer.lock() {
-                t.set_frame(frame);
-            }
+            let mut t = tracer.lock().unwrap_or_else(PoisonError::into_inner);
+            t.set_frame(frame);
         }
 
         // Update recorder
         if let Some(ref recorder) = self.recorder {
-            if let Ok(mut r) = recorder.lock() {
-                r.set_frame(frame);
-            }
+            let mut r = recorder.lock().unwrap_or_else(PoisonError::into_inner);
+            r.set_frame(frame);
         }
     }
 
@@ -176,64 +390,108 @@ impl EventBus {
         self.current_frame
     }
 
-    /// Publishes a new event.
+    /// Publishes a new event in its type's [`Event::priority`] lane.
     ///
     /// Events are queued for the next frame and become visible after
     /// [`EventBus::dispatch`] runs.
     ///
     /// If the event is marked as networked and network is enabled, the event
-    /// will also be transmitted to remote nodes.
+    /// will also be transmitted to remote nodes when the bus is dispatched.
+    /// With [`EventBus::with_ordered_delivery`], networked broadcasts are only
+    /// dispatched locally once the relay echoes them back.
     pub fn publish<E>(&mut self, event: E)
+    where
+        E: Event + serde::Serialize,
+    {
+        self.publish_with_priority(event, E::priority());
+    }
+
+    /// Publishes a new event in the given lane.
+    ///
+    /// Readers see `High` events before `Normal` and `Low` ones of the same
+    /// type, whatever the publish order; networked events are sent lane by
+    /// lane as well. Remote nodes put them in their type's lane.
+    pub fn publish_with_priority<E>(&mut self, event: E, priority: Priority)
     where
         E: Event + serde::Serialize,
     {
         // Trace event publication
         if let Some(ref tracer) = self.tracer {
-            if let Ok(mut t) = tracer.lock() {
-                t.record_simple(
-                    crate::trace::TraceEntryType::EventPublished {
-                        event_type: std::any::type_name::<E>().to_string(),
-                        event_id: format!("{}@{}", std::any::type_name::<E>(), self.current_frame),
-                    },
-                    "EventBus",
-                );
-            }
+            let mut t = tracer.lock().unwrap_or_else(PoisonError::into_inner);
+            t.record_simple(
+                crate::trace::TraceEntryType::EventPublished {
+                    event_type: std::any::type_name::<E>().to_string(),
+                    event_id: format!("{}@{}", std::any::type_name::<E>(), self.current_frame),
+                },
+                "EventBus",
+            );
         }
 
+        // With ordered delivery, the relay echoes broadcasts back in room
+        // order; they are recorded and dispatched when they arrive
+        #[cfg(feature = "network")]
+        let deferred = E::is_networked()
+            && E::network_scope() == NetworkScope::Broadcast
+            && self
+                .network
+                .as_ref()
+                .is_some_and(|net| net.ordering.is_some());
+        #[cfg(not(feature = "network"))]
+        let deferred = false;
+
         // Record event for replay
         if let Some(ref recorder) = self.recorder {
-            if let Ok(mut r) = recorder.lock() {
+            let mut r = recorder.lock().unwrap_or_else(PoisonError::into_inner);
+            if !deferred {
                 r.record(&event);
             }
         }
 
-        // Always perform local dispatch
-        let channel = self.channel_mut::<E>();
-        channel.push(event.clone());
+        // Perform local dispatch, unless the relay orders it
+        self.published += 1;
+        if !deferred {
+            let channel = self.channel_mut::<E>();
+            #[cfg(debug_assertions)]
+            let grows = {
+                let lane = &channel.pending[priority.lane()];
+                let len = if E::is_latest_only() { 0 } else { lane.len() };
+                len == lane.capacity()
+            };
+            channel.push(priority, event.clone());
+            #[cfg(debug_assertions)]
+            if grows {
+                self.allocations.buffer_growths += 1;
+            }
+        }
 
-        // If networked, send to network backend
+        // If networked, queue for the network backend; `dispatch` sends it
         #[cfg(feature = "network")]
         if E::is_networked() {
             if let Some(ref mut net) = self.network {
-                let sequence = net
-                    .sequence
-                    .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
-
-                let metadata = NetworkMetadata::new(net.backend.node_id(), sequence);
+                let mut metadata = NetworkMetadata::new(net.backend.node_id(), 0);
+                metadata.namespace = self.namespace.clone();
                 let scope = E::network_scope();
+                let type_name = std::any::type_name::<E>();
+
+                // Only the latest value is worth sending
+                if E::is_latest_only() {
+                    for lane in &mut net.outgoing {
+                        lane.retain(|queued| queued.raw.type_name != type_name);
+                    }
+                }
 
                 // Create RawNetworkEvent
                 let raw_event = crate::network::backend::RawNetworkEvent {
                     metadata,
                     scope,
-                    type_name: std::any::type_name::<E>().to_string(),
+                    type_name: type_name.to_string(),
                     payload: bincode::serialize(&event).unwrap_or_default(),
                 };
-
-                // Queue for async send
-                if let Ok(serialized) = bincode::serialize(&raw_event) {
-                    let _ = net.tx.try_send(NetworkTask::Send(serialized));
-                }
+                net.outgoing[priority.lane()].push(crate::network::shaping::Outgoing {
+                    raw: raw_event,
+                    delivery: E::delivery(),
+                    latest_only: E::is_latest_only(),
+                });
             }
         }
     }
@@ -253,24 +511,263 @@ impl EventBus {
         }
     }
 
+    /// Returns a reader over events of type `E` without mutating the bus.
+    ///
+    /// Unlike [`EventBus::reader`] this only needs `&self`, so it works
+    /// through a shared [`ResourceContext::get`](crate::context::ResourceContext::get)
+    /// guard that stays held while handlers run. A type that was never
+    /// published reads as empty.
+    pub fn read<E>(&self) -> EventReader<'_, E>
+    where
+        E: Event,
+    {
+        let events = self
+            .channels
+            .get(&TypeId::of::<E>())
+            .and_then(|channel| channel.as_any().downcast_ref::<EventChannel<E>>())
+            .map_or(&[][..], EventChannel::read);
+        EventReader { events, cursor: 0 }
+    }
+
+    /// Returns a reader over the events of type `E` that `reader` has not seen.
+    ///
+    /// Each reader name has its own cursor per event type: a reader called
+    /// twice in a frame sees the events once, and one that skips frames
+    /// catches up on them. Events stay buffered until every named reader of
+    /// their type has read them. A new reader starts with the events of the
+    /// current frame.
+    ///
+    /// Only needs `&self`, like [`EventBus::read`].
+    pub fn reader_for<E>(&self, reader: &str) -> EventReader<'_, E>
+    where
+        E: Event,
+    {
+        let channel = self
+            .channels
+            .get(&TypeId::of::<E>())
+            .and_then(|channel| channel.as_any().downcast_ref::<EventChannel<E>>());
+        let (start, end) = channel.map_or((0, 0), |channel| {
+            (channel.frame_start(), channel.dispatched)
+        });
+
+        let mut readers = self.readers.lock().unwrap_or_else(PoisonError::into_inner);
+        let cursors = readers.entry(TypeId::of::<E>()).or_default();
+        let cursor = match cursors.get_mut(reader) {
+            Some(cursor) => cursor,
+            None => cursors.entry(reader.to_string()).or_insert(start),
+        };
+        let events = channel.map_or(&[][..], |channel| channel.read_from(*cursor));
+        *cursor = end;
+        EventReader { events, cursor: 0 }
+    }
+
+    /// [`EventBus::snapshot`] of the events `reader` has not seen, see
+    /// [`EventBus::reader_for`]
+    pub fn snapshot_for<E>(&mut self, reader: &str) -> Vec<E>
+    where
+        E: Event,
+    {
+        let mut events = std::mem::take(&mut self.channel_mut::<E>().spare);
+        let unread = self.reader_for::<E>(reader);
+        #[cfg(debug_assertions)]
+        let grows = events.capacity() < unread.len();
+        events.extend(unread.iter().cloned());
+        #[cfg(debug_assertions)]
+        if grows {
+            self.allocations.snapshots += 1;
+        }
+        events
+    }
+
+    /// Unregisters a reader of [`EventBus::reader_for`]
+    ///
+    /// Events it has not read are no longer kept for it. Returns whether the
+    /// reader was registered.
+    pub fn remove_reader<E>(&mut self, reader: &str) -> bool
+    where
+        E: Event,
+    {
+        self.readers
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(&TypeId::of::<E>())
+            .is_some_and(|cursors| cursors.remove(reader).is_some())
+    }
+
+    /// Copies the readable events of type `E` into a reused buffer.
+    ///
+    /// For consumers that must release the bus before handling the events.
+    /// Hand the buffer back with [`EventBus::recycle`] so the next snapshot
+    /// does not allocate.
+    pub fn snapshot<E>(&mut self) -> Vec<E>
+    where
+        E: Event,
+    {
+        let channel = self.channel_mut::<E>();
+        let mut events = std::mem::take(&mut channel.spare);
+        #[cfg(debug_assertions)]
+        let grows = events.capacity() < channel.b.len();
+        events.extend_from_slice(&channel.b);
+        #[cfg(debug_assertions)]
+        if grows {
+            self.allocations.snapshots += 1;
+        }
+        events
+    }
+
+    /// Returns a buffer from [`EventBus::snapshot`] for reuse.
+    pub fn recycle<E>(&mut self, mut events: Vec<E>)
+    where
+        E: Event,
+    {
+        events.clear();
+        let channel = self.channel_mut::<E>();
+        if events.capacity() > channel.spare.capacity() {
+            channel.spare = events;
+        }
+    }
+
     /// Advances all event channels by swapping their buffers.
     ///
     /// This should be invoked once per frame (typically by the runner). After
-    /// dispatching, events published this frame become visible in the next one.
+    /// dispatching, events published this frame become visible in the next one,
+    /// lane by lane. Networked events published this frame are sent now, in
+    /// the same lane order, subject to the send rates and budget.
     pub fn dispatch(&mut self) {
-        for channel in self.channels.values_mut() {
-            channel.swap_buffers();
+        self.dispatch_at(std::time::Instant::now());
+    }
+
+    /// [`EventBus::dispatch`] at `now` instead of the current time
+    ///
+    /// Send rates and the send budget are measured against `now`; for
+    /// fixed-step simulations and tests.
+    pub fn dispatch_at(&mut self, now: std::time::Instant) {
+        let readers = self
+            .readers
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        for (type_id, channel) in &mut self.channels {
+            let oldest_unread = readers
+                .get(type_id)
+                .and_then(|cursors| cursors.values().min().copied());
+            channel.swap_buffers(oldest_unread);
         }
+
+        #[cfg(feature = "network")]
+        self.send_outgoing(now);
+        #[cfg(not(feature = "network"))]
+        let _ = now;
     }
 
-    fn channel_mut<E>(&mut self) -> &mut EventChannel<E>
+    /// Hand queued networked events to the send worker, lane by lane
+    #[cfg(feature = "network")]
+    fn send_outgoing(&mut self, now: std::time::Instant) {
+        let Some(ref mut net) = self.network else {
+            return;
+        };
+        for mut raw_event in self.shaper.shape(&mut net.outgoing, now) {
+            // Sequence numbers follow the send order
+            raw_event.metadata.sequence = net
+                .sequence
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if let Ok(serialized) = bincode::serialize(&raw_event) {
+                let _ = net.tx.try_send(NetworkTask::Send(serialized));
+            }
+        }
+    }
+
+    /// Send at most `hz` events of type `E` per second (network feature only)
+    ///
+    /// Latest-only events over the rate wait for the next free slot, a newer
+    /// value replacing the waiting one; other events over the rate are
+    /// dropped. Both count as `rate_limited` in [`EventBus::stats`]. Can be
+    /// changed at any time; a rate that is not positive removes the limit.
+    #[cfg(feature = "network")]
+    pub fn set_send_rate<E: Event>(&mut self, hz: f64) {
+        self.shaper.set_rate(std::any::type_name::<E>(), Some(hz));
+    }
+
+    /// Remove the send rate of `E`
+    #[cfg(feature = "network")]
+    pub fn clear_send_rate<E: Event>(&mut self) {
+        self.shaper.set_rate(std::any::type_name::<E>(), None);
+    }
+
+    /// Send rate of `E` set with [`EventBus::set_send_rate`]
+    #[cfg(feature = "network")]
+    pub fn send_rate<E: Event>(&self) -> Option<f64> {
+        self.shaper.rate(std::any::type_name::<E>())
+    }
+
+    /// Limit outgoing networked events to `bytes_per_second` (network feature only)
+    ///
+    /// Up to one second of budget can be saved up. [`Delivery::Reliable`]
+    /// events are always sent, ahead of the others; once they spent the
+    /// budget, [`Delivery::Deferrable`] events wait for a later dispatch and
+    /// [`Delivery::Unreliable`] ones are dropped. `None` removes the budget.
+    #[cfg(feature = "network")]
+    pub fn set_send_budget(&mut self, bytes_per_second: Option<u64>) {
+        self.shaper.set_budget(bytes_per_second);
+    }
+
+    /// Budget set with [`EventBus::set_send_budget`]
+    #[cfg(feature = "network")]
+    pub fn send_budget(&self) -> Option<u64> {
+        self.shaper.budget()
+    }
+
+    /// Removes and returns every buffered event of type `E`.
+    ///
+    /// Unlike [`EventBus::reader`], this also takes events that are still
+    /// waiting for the next [`EventBus::dispatch`]. Intended for consumers that
+    /// own a channel outright and must not lose events when the runner skips a
+    /// frame (e.g. the [lockstep driver](crate::engine::lockstep::LockstepDriver)).
+    pub fn drain<E>(&mut self) -> Vec<E>
     where
         E: Event,
     {
-        let entry = self
+        self.channel_mut::<E>().drain()
+    }
+
+    /// Per-type buffer sizes and the total number of published events
+    pub fn stats(&self) -> EventBusStats {
+        let mut channels: Vec<EventChannelStats> = self
             .channels
-            .entry(TypeId::of::<E>())
-            .or_insert_with(|| Box::new(EventChannel::<E>::new()));
+            .values()
+            .map(|channel| EventChannelStats {
+                event_type: channel.type_name().to_string(),
+                pending: channel.pending_len(),
+                readable: channel.readable_len(),
+            })
+            .collect();
+        channels.sort_by(|a, b| a.event_type.cmp(&b.event_type));
+
+        EventBusStats {
+            frame: self.current_frame,
+            published_total: self.published,
+            channels,
+            #[cfg(debug_assertions)]
+            allocations: self.allocations,
+            #[cfg(feature = "network")]
+            network: self.frames,
+            #[cfg(feature = "network")]
+            network_send: self.shaper.stats(),
+        }
+    }
+
+    fn channel_mut<E>(&mut self) -> &mut EventChannel<E>
+    where
+        E: Event,
+    {
+        #[cfg(debug_assertions)]
+        let allocations = &mut self.allocations;
+        let entry = self.channels.entry(TypeId::of::<E>()).or_insert_with(|| {
+            #[cfg(debug_assertions)]
+            {
+                allocations.channels += 1;
+            }
+            Box::new(EventChannel::<E>::new())
+        });
 
         entry
             .as_any_mut()
@@ -304,8 +801,79 @@ impl EventBus {
             sequence: std::sync::atomic::AtomicU64::new(0),
             current_metadata: None,
             deserializers: HashMap::new(),
+            ordering: None,
+            outgoing: Default::default(),
         });
+        self.announce_namespace();
+
+        self
+    }
+
+    /// Keep this bus's network traffic apart from other buses'
+    ///
+    /// For processes running several matches: outgoing networked events are
+    /// tagged with `namespace`, and [`EventBus::poll_network`] ignores
+    /// frames of other namespaces, counting them in [`EventBus::stats`].
+    /// Together with a [`SharedNetworkBackend`](crate::network::SharedNetworkBackend)
+    /// handle, the buses of many matches share one connection; the relay
+    /// routes each namespace to the connections that joined it. Local
+    /// events are unaffected.
+    #[cfg(feature = "network")]
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self.announce_namespace();
+        self
+    }
+
+    /// What a namespaced bus does with incoming frames without a namespace
+    /// (default: [`LegacyFramePolicy::Deliver`](crate::network::LegacyFramePolicy::Deliver))
+    #[cfg(feature = "network")]
+    pub fn with_legacy_frames(mut self, policy: crate::network::LegacyFramePolicy) -> Self {
+        self.legacy_frames = policy;
+        self
+    }
+
+    /// Namespace set with [`EventBus::with_namespace`]
+    #[cfg(feature = "network")]
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Tell the relay this node takes part in the namespace
+    #[cfg(feature = "network")]
+    fn announce_namespace(&self) {
+        let (Some(net), Some(namespace)) = (&self.network, &self.namespace) else {
+            return;
+        };
+        let mut metadata = NetworkMetadata::new(net.backend.node_id(), 0);
+        metadata.namespace = Some(namespace.clone());
+        let join = crate::network::backend::RawNetworkEvent {
+            metadata,
+            scope: NetworkScope::ToServer,
+            type_name: crate::network::NAMESPACE_JOIN.to_string(),
+            payload: Vec::new(),
+        };
+        if let Ok(serialized) = bincode::serialize(&join) {
+            let _ = net.tx.try_send(NetworkTask::Send(serialized));
+        }
+    }
 
+    /// Deliver relay-stamped events in the room's sequence order
+    ///
+    /// For clients in a room created with `ordered: true` on the relay. Call
+    /// after [`EventBus::with_network`]. [`EventBus::poll_network`] holds
+    /// events back until the previous sequence number arrived; a missing
+    /// event is skipped after `config.gap_window`, publishing
+    /// [`NetworkGapDetected`](crate::network::NetworkGapDetected) locally.
+    ///
+    /// Networked broadcasts published on this bus are not dispatched locally
+    /// right away: the relay echoes them back, so they take their place in
+    /// the room's order like everyone else's.
+    #[cfg(feature = "network")]
+    pub fn with_ordered_delivery(mut self, config: crate::network::OrderingConfig) -> Self {
+        if let Some(ref mut net) = self.network {
+            net.ordering = Some(crate::network::ordering::SequenceBuffer::new(config));
+        }
         self
     }
 
@@ -337,6 +905,13 @@ impl EventBus {
     }
 
     /// Poll and process incoming network events
+    ///
+    /// A bus with a [namespace](EventBus::with_namespace) only delivers
+    /// frames of that namespace.
+    ///
+    /// With [`EventBus::with_ordered_delivery`], relay-stamped events are
+    /// dispatched in sequence order and gaps older than the window are
+    /// skipped; call this every frame so expired gaps are noticed.
     #[cfg(feature = "network")]
     pub fn poll_network(&mut self) {
         use crate::network::backend::RawNetworkEvent;
@@ -358,6 +933,50 @@ impl EventBus {
             Vec::new()
         };
 
+        // Drop frames of other namespaces (and legacy frames, if so configured)
+        self.frames.received += events.len() as u64;
+        let mut events = events;
+        if let Some(namespace) = &self.namespace {
+            let frames = &mut self.frames;
+            let legacy_frames = self.legacy_frames;
+            events.retain(|raw_event| match &raw_event.metadata.namespace {
+                Some(other) if other == namespace => true,
+                Some(_) => {
+                    frames.foreign_namespace += 1;
+                    false
+                }
+                None if legacy_frames == crate::network::LegacyFramePolicy::Ignore => {
+                    frames.legacy_ignored += 1;
+                    false
+                }
+                None => true,
+            });
+        }
+
+        // Put relay-stamped events in room order when ordered delivery is on
+        let mut gaps = Vec::new();
+        let events = match self.network.as_mut().and_then(|net| net.ordering.as_mut()) {
+            Some(buffer) => {
+                let mut ready = Vec::new();
+                for raw_event in events {
+                    match raw_event.metadata.relay {
+                        Some(stamp) => buffer.push(stamp.sequence, raw_event),
+                        None => ready.push(raw_event),
+                    }
+                }
+                for ordered in buffer.drain_ready(std::time::Instant::now()) {
+                    match ordered {
+                        crate::network::ordering::Ordered::Event(raw_event) => {
+                            ready.push(raw_event)
+                        }
+                        crate::network::ordering::Ordered::Gap(gap) => gaps.push(gap),
+                    }
+                }
+                ready
+            }
+            None => events,
+        };
+
         // Process collected events
         for raw_event in events {
             if let Some(ref mut net) = self.network {
@@ -370,12 +989,22 @@ impl EventBus {
                 // Deserialize and inject into appropriate channel
                 if let Some(deserializer) = net.deserializers.get(type_name) {
                     deserializer.deserialize_and_push(&raw_event.payload, &mut self.channels);
+
+                    // Record delivered remote events for replay
+                    if let Some(ref recorder) = self.recorder {
+                        let mut r = recorder.lock().unwrap_or_else(PoisonError::into_inner);
+                        r.record_raw(type_name.clone(), raw_event.payload.clone());
+                    }
                 }
 
                 // Clear metadata after processing
                 net.current_metadata = None;
             }
         }
+
+        for gap in gaps {
+            self.publish(gap);
+        }
     }
 }
 
@@ -402,6 +1031,72 @@ async fn network_send_worker(
     }
 }
 
+/// What an `#[event_handler]` handler lets happen after it saw an event.
+///
+/// Handlers may return this instead of `()`; returning nothing is
+/// [`Continue`](Self::Continue).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HandlerFlow {
+    /// Lower-priority handlers see the event too
+    #[default]
+    Continue,
+    /// No further handler sees this event; the next event is handled
+    StopEvent,
+    /// Stop processing: remaining handlers and events are skipped
+    StopAll,
+}
+
+impl From<()> for HandlerFlow {
+    fn from(_: ()) -> Self {
+        HandlerFlow::Continue
+    }
+}
+
+/// Follow-up events collected by `#[event_handler]` handlers.
+///
+/// A handler cannot publish while `process_events` holds the bus. It takes
+/// `#[events] out: &mut EventWriter` instead and sends there; once every
+/// handler has run, the collected events are published in the order they
+/// were sent. Like any publish, they become readable after the next
+/// [`EventBus::dispatch`].
+#[derive(Default)]
+pub struct EventWriter {
+    pending: Vec<PendingPublish>,
+}
+
+/// A sent event, publishing itself to the bus
+type PendingPublish = Box<dyn FnOnce(&mut EventBus) + Send>;
+
+impl EventWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `event` for publishing after the handlers
+    pub fn send<E>(&mut self, event: E)
+    where
+        E: Event + serde::Serialize,
+    {
+        self.pending.push(Box::new(move |bus| bus.publish(event)));
+    }
+
+    /// Number of queued events
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Publish the queued events to `bus`, in send order
+    pub fn flush(&mut self, bus: &mut EventBus) {
+        for publish in self.pending.drain(..) {
+            publish(bus);
+        }
+    }
+}
+
 /// Event reader that iterates over events published in the previous frame.
 pub struct EventReader<'a, E>
 where
@@ -433,13 +1128,22 @@ where
 
 /// Internal event channel for a specific event type `E`.
 ///
-/// `a` is the write buffer; `b` is the read buffer.
+/// `pending` holds the write buffers, one per [`Priority`] lane; `b` is the
+/// read buffer; `spare` is kept for [`EventBus::snapshot`]. While the type
+/// has named readers, `log` keeps the readable events they have not all
+/// read yet, `b` included.
 struct EventChannel<E>
 where
     E: Event,
 {
-    a: Vec<E>,
+    pending: [Vec<E>; 3],
     b: Vec<E>,
+    spare: Vec<E>,
+    log: Vec<E>,
+    // Index of `log[0]`
+    log_start: u64,
+    // Events that became readable since the channel was created
+    dispatched: u64,
 }
 
 impl<E> EventChannel<E>
@@ -448,41 +1152,126 @@ where
 {
     fn new() -> Self {
         Self {
-            a: Vec::new(),
+            pending: Default::default(),
             b: Vec::new(),
+            spare: Vec::new(),
+            log: Vec::new(),
+            log_start: 0,
+            dispatched: 0,
         }
     }
 
-    fn push(&mut self, event: E) {
-        self.a.push(event);
+    fn push(&mut self, priority: Priority, event: E) {
+        if E::is_latest_only() {
+            for lane in &mut self.pending {
+                lane.clear();
+            }
+        }
+        self.pending[priority.lane()].push(event);
     }
 
     fn read(&self) -> &[E] {
         &self.b
     }
 
-    fn swap_buffers(&mut self) {
-        std::mem::swap(&mut self.a, &mut self.b);
-        self.a.clear();
+    /// Index of the first event of `b`
+    fn frame_start(&self) -> u64 {
+        self.dispatched - self.b.len() as u64
+    }
+
+    /// Readable events from index `cursor` on
+    fn read_from(&self, cursor: u64) -> &[E] {
+        let (events, start) = if self.log.is_empty() {
+            (&self.b, self.frame_start())
+        } else {
+            (&self.log, self.log_start)
+        };
+        let skip = cursor.saturating_sub(start).min(events.len() as u64);
+        &events[skip as usize..]
+    }
+
+    /// `oldest_unread` is the lowest cursor of the named readers, if any
+    fn swap_buffers(&mut self, oldest_unread: Option<u64>) {
+        let [high, normal, low] = &mut self.pending;
+        if high.is_empty() && low.is_empty() {
+            std::mem::swap(normal, &mut self.b);
+            normal.clear();
+        } else {
+            self.b.clear();
+            self.b.append(high);
+            self.b.append(normal);
+            self.b.append(low);
+        }
+        self.dispatched += self.b.len() as u64;
+
+        let Some(oldest_unread) = oldest_unread else {
+            self.log.clear();
+            return;
+        };
+        if E::is_latest_only() && !self.b.is_empty() {
+            // Older values are superseded for every reader
+            self.log.clear();
+        }
+        if self.log.is_empty() {
+            self.log_start = self.frame_start();
+        }
+        self.log.extend_from_slice(&self.b);
+
+        // Events every reader has passed; the current frame stays for new readers
+        let keep_from = oldest_unread.min(self.frame_start());
+        let passed = keep_from
+            .saturating_sub(self.log_start)
+            .min(self.log.len() as u64);
+        self.log.drain(..passed as usize);
+        self.log_start += passed;
+    }
+
+    fn drain(&mut self) -> Vec<E> {
+        self.log.clear();
+        let mut events = std::mem::take(&mut self.b);
+        for lane in &mut self.pending {
+            events.append(lane);
+        }
+        events
     }
 }
 
 trait EventChannelStorage: Any + Send + Sync {
-    fn swap_buffers(&mut self);
+    fn swap_buffers(&mut self, oldest_unread: Option<u64>);
+    fn as_any(&self) -> &dyn Any;
     fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn type_name(&self) -> &'static str;
+    fn pending_len(&self) -> usize;
+    fn readable_len(&self) -> usize;
 }
 
 impl<E> EventChannelStorage for EventChannel<E>
 where
     E: Event,
 {
-    fn swap_buffers(&mut self) {
-        EventChannel::swap_buffers(self);
+    fn swap_buffers(&mut self, oldest_unread: Option<u64>) {
+        EventChannel::swap_buffers(self, oldest_unread);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<E>()
+    }
+
+    fn pending_len(&self) -> usize {
+        self.pending.iter().map(Vec::len).sum()
+    }
+
+    fn readable_len(&self) -> usize {
+        self.b.len()
+    }
 }
 
 /// Convenience macro for collecting events from an EventBus into a Vec.
@@ -552,6 +1341,72 @@ mod tests {
         assert!(reader.is_empty());
     }
 
+    #[test]
+    fn drain_takes_visible_and_pending_events() {
+        let mut bus = EventBus::new();
+        bus.publish(Damage(1));
+        bus.dispatch();
+        bus.publish(Damage(2));
+
+        assert_eq!(bus.drain::<Damage>(), vec![Damage(1), Damage(2)]);
+
+        bus.dispatch();
+        assert!(bus.reader::<Damage>().is_empty());
+    }
+
+    #[test]
+    fn read_borrows_without_creating_channels() {
+        let mut bus = EventBus::new();
+        assert!(bus.read::<Damage>().is_empty());
+        assert!(bus.stats().channels.is_empty());
+
+        bus.publish(Damage(4));
+        bus.dispatch();
+        let reader = bus.read::<Damage>();
+        assert_eq!(reader.iter().collect::<Vec<_>>(), vec![&Damage(4)]);
+    }
+
+    #[test]
+    fn recycled_snapshot_buffer_is_reused() {
+        let mut bus = EventBus::new();
+        bus.publish(Damage(1));
+        bus.publish(Damage(2));
+        bus.dispatch();
+
+        let events = bus.snapshot::<Damage>();
+        assert_eq!(events, vec![Damage(1), Damage(2)]);
+        let buffer = events.as_ptr();
+        bus.recycle(events);
+
+        let events = bus.snapshot::<Damage>();
+        assert_eq!(events.as_ptr(), buffer);
+        assert_eq!(events, vec![Damage(1), Damage(2)]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn stats_count_allocations_until_steady_state() {
+        let mut bus = EventBus::new();
+        for _ in 0..3 {
+            bus.publish(Damage(1));
+            bus.publish(Damage(2));
+            bus.dispatch();
+            let events = bus.snapshot::<Damage>();
+            bus.recycle(events);
+        }
+        let warm = bus.stats().allocations;
+        assert_eq!(warm.channels, 1);
+
+        for _ in 0..10 {
+            bus.publish(Damage(1));
+            bus.publish(Damage(2));
+            bus.dispatch();
+            let events = bus.snapshot::<Damage>();
+            bus.recycle(events);
+        }
+        assert_eq!(bus.stats().allocations, warm);
+    }
+
     #[cfg(feature = "network")]
     #[tokio::test]
     async fn network_event_registration_and_polling() {
@@ -572,4 +1427,202 @@ mod tests {
         let reader = bus.reader::<Damage>();
         assert!(reader.is_empty());
     }
+
+    #[test]
+    fn stats_report_buffered_events_per_type() {
+        let mut bus = EventBus::new();
+        bus.publish(Damage(1));
+        bus.publish(Damage(2));
+        bus.dispatch();
+        bus.publish(Damage(3));
+
+        let stats = bus.stats();
+        assert_eq!(stats.published_total, 3);
+        assert_eq!(
+            stats.channels,
+            vec![EventChannelStats {
+                event_type: std::any::type_name::<Damage>().to_string(),
+                pending: 1,
+                readable: 2,
+            }]
+        );
+    }
+
+    #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Command(u32);
+
+    impl Event for Command {
+        fn priority() -> Priority {
+            Priority::High
+        }
+    }
+
+    #[test]
+    fn interleaved_priorities_read_lane_by_lane() {
+        let mut bus = EventBus::new();
+        bus.publish_with_priority(Damage(1), Priority::Low);
+        bus.publish(Damage(2));
+        bus.publish_with_priority(Damage(3), Priority::High);
+        bus.publish_with_priority(Damage(4), Priority::Low);
+        bus.publish(Damage(5));
+        bus.publish_with_priority(Damage(6), Priority::High);
+        bus.dispatch();
+
+        let damage: Vec<_> = bus.reader::<Damage>().iter().map(|d| d.0).collect();
+        assert_eq!(damage, vec![3, 6, 2, 5, 1, 4]);
+
+        // Lanes are empty again once read
+        bus.publish(Damage(7));
+        bus.dispatch();
+        let damage: Vec<_> = bus.reader::<Damage>().iter().map(|d| d.0).collect();
+        assert_eq!(damage, vec![7]);
+    }
+
+    #[test]
+    fn type_priority_is_the_publish_default() {
+        let mut bus = EventBus::new();
+        bus.publish_with_priority(Command(1), Priority::Normal);
+        bus.publish(Command(2));
+        bus.publish_with_priority(Command(3), Priority::Low);
+        bus.publish(Command(4));
+        bus.dispatch();
+
+        let commands: Vec<_> = bus.reader::<Command>().iter().map(|c| c.0).collect();
+        assert_eq!(commands, vec![2, 4, 1, 3]);
+        assert_eq!(Damage::priority(), Priority::Normal);
+    }
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize)]
+    struct Position(u32);
+
+    impl Event for Position {
+        fn is_latest_only() -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn latest_only_events_keep_the_last_publish_per_frame() {
+        let mut bus = EventBus::new();
+        bus.publish(Position(1));
+        bus.publish_with_priority(Position(2), Priority::High);
+        bus.publish(Position(3));
+        assert_eq!(bus.stats().channels[0].pending, 1);
+        bus.dispatch();
+        assert_eq!(
+            bus.reader::<Position>().iter().collect::<Vec<_>>(),
+            [&Position(3)]
+        );
+        assert_eq!(bus.stats().published_total, 3);
+
+        // Nothing published: the old value is not replayed
+        bus.dispatch();
+        assert!(bus.reader::<Position>().is_empty());
+    }
+
+    #[test]
+    fn drain_keeps_lane_order_for_pending_events() {
+        let mut bus = EventBus::new();
+        bus.publish(Damage(1));
+        bus.dispatch();
+        bus.publish_with_priority(Damage(2), Priority::Low);
+        bus.publish_with_priority(Damage(3), Priority::High);
+
+        assert_eq!(bus.drain::<Damage>(), vec![Damage(1), Damage(3), Damage(2)]);
+        assert_eq!(bus.stats().channels[0].pending, 0);
+    }
+
+    #[test]
+    fn writer_publishes_in_send_order() {
+        let mut writer = EventWriter::new();
+        writer.send(Damage(1));
+        writer.send(Damage(2));
+        assert_eq!(writer.len(), 2);
+
+        let mut bus = EventBus::new();
+        writer.flush(&mut bus);
+        assert!(writer.is_empty());
+        bus.dispatch();
+        let damage: Vec<_> = bus.reader::<Damage>().iter().map(|d| d.0).collect();
+        assert_eq!(damage, vec![1, 2]);
+    }
+
+    fn read_for(bus: &EventBus, reader: &str) -> Vec<u32> {
+        bus.reader_for::<Damage>(reader)
+            .iter()
+            .map(|d| d.0)
+            .collect()
+    }
+
+    #[test]
+    fn named_readers_at_different_paces_see_every_event_once() {
+        let mut bus = EventBus::new();
+        let (mut fast, mut slow) = (Vec::new(), Vec::new());
+        // Register both before anything is published
+        assert!(read_for(&bus, "fast").is_empty());
+        assert!(read_for(&bus, "slow").is_empty());
+
+        for frame in 0..10 {
+            bus.publish(Damage(frame * 2));
+            bus.publish(Damage(frame * 2 + 1));
+            bus.dispatch();
+
+            // Twice a frame, and every third frame
+            fast.extend(read_for(&bus, "fast"));
+            fast.extend(read_for(&bus, "fast"));
+            if frame % 3 == 2 {
+                slow.extend(read_for(&bus, "slow"));
+            }
+        }
+        slow.extend(read_for(&bus, "slow"));
+
+        let all: Vec<u32> = (0..20).collect();
+        assert_eq!(fast, all);
+        assert_eq!(slow, all);
+        // The unnamed reader still sees the last frame only
+        assert_eq!(bus.reader::<Damage>().len(), 2);
+    }
+
+    #[test]
+    fn events_are_released_once_every_named_reader_passed_them() {
+        let mut bus = EventBus::new();
+        read_for(&bus, "fast");
+        read_for(&bus, "slow");
+        for frame in 0..5 {
+            bus.publish(Damage(frame));
+            bus.dispatch();
+            read_for(&bus, "fast");
+        }
+        let log_len = |bus: &mut EventBus| bus.channel_mut::<Damage>().log.len();
+        assert_eq!(log_len(&mut bus), 5);
+
+        assert_eq!(read_for(&bus, "slow"), vec![0, 1, 2, 3, 4]);
+        bus.dispatch();
+        assert_eq!(log_len(&mut bus), 0);
+
+        // A removed reader no longer holds events back
+        bus.publish(Damage(5));
+        bus.dispatch();
+        assert!(bus.remove_reader::<Damage>("slow"));
+        read_for(&bus, "fast");
+        bus.publish(Damage(6));
+        bus.dispatch();
+        assert_eq!(log_len(&mut bus), 1);
+        assert!(!bus.remove_reader::<Damage>("slow"));
+    }
+
+    #[test]
+    fn new_named_reader_starts_with_the_current_frame() {
+        let mut bus = EventBus::new();
+        bus.publish(Damage(1));
+        bus.dispatch();
+        bus.publish(Damage(2));
+        bus.dispatch();
+
+        assert_eq!(read_for(&bus, "late"), vec![2]);
+        let snapshot = bus.snapshot_for::<Damage>("later");
+        assert_eq!(snapshot, vec![Damage(2)]);
+        bus.recycle(snapshot);
+        assert!(bus.snapshot_for::<Damage>("later").is_empty());
+    }
 }
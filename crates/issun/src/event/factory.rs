@@ -0,0 +1,425 @@
+//! Named factories that build typed events from JSON
+//!
+//! Everything that publishes events by name (the debug HTTP endpoint,
+//! scenario `Publish` steps, item `PublishEvent` effects, events emitted by
+//! MODs) looks the name up in an [`EventFactoryRegistry`].
+//!
+//! With the `event-factories` feature, events declared with
+//! [`event!`](crate::event!) register themselves under their type name and
+//! [`EventFactoryRegistry::registered`] collects them:
+//!
+//! ```ignore
+//! issun::event! {
+//!     pub SpawnWave { size: u32 }
+//! }
+//!
+//! let registry = EventFactoryRegistry::registered()?;
+//! registry
+//!     .construct("SpawnWave", serde_json::json!({ "size": 12 }))?
+//!     .publish(&mut bus);
+//! ```
+
+use super::{Event, EventBus};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::any::{Any, TypeId};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::panic::Location;
+
+/// A constructed event that can publish itself
+///
+/// Implemented for every [`Event`] that [`EventBus::publish`] accepts.
+pub trait AnyEventPublish: Send + Sync {
+    /// Full type name of the event
+    fn type_name(&self) -> &'static str;
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn clone_box(&self) -> Box<dyn AnyEventPublish>;
+
+    /// Publish the event onto `bus`
+    fn publish(self: Box<Self>, bus: &mut EventBus);
+}
+
+impl<E: Event + Serialize> AnyEventPublish for E {
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<E>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn AnyEventPublish> {
+        Box::new(self.clone())
+    }
+
+    fn publish(self: Box<Self>, bus: &mut EventBus) {
+        bus.publish(*self);
+    }
+}
+
+impl dyn AnyEventPublish {
+    /// The event, if it is an `E`
+    pub fn downcast_ref<E: Event>(&self) -> Option<&E> {
+        self.as_any().downcast_ref()
+    }
+}
+
+impl Clone for Box<dyn AnyEventPublish> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl fmt::Debug for dyn AnyEventPublish {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AnyEventPublish({})", self.type_name())
+    }
+}
+
+/// Errors of [`EventFactoryRegistry`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FactoryError {
+    #[error("No event factory named '{name}'")]
+    UnknownEvent { name: String },
+
+    #[error("Invalid payload for event '{name}': {message}")]
+    InvalidPayload { name: String, message: String },
+
+    #[error(
+        "Event factory '{name}' registered twice: {first_type} at {first_location}, \
+         {second_type} at {second_location}"
+    )]
+    Collision {
+        name: String,
+        first_type: String,
+        first_location: String,
+        second_type: String,
+        second_location: String,
+    },
+}
+
+type BuildFn = fn(serde_json::Value) -> Result<Box<dyn AnyEventPublish>, serde_json::Error>;
+
+fn build<E: Event + Serialize + DeserializeOwned>(
+    json: serde_json::Value,
+) -> Result<Box<dyn AnyEventPublish>, serde_json::Error> {
+    let event: E = serde_json::from_value(json)?;
+    Ok(Box::new(event))
+}
+
+#[derive(Clone)]
+struct Factory {
+    type_id: TypeId,
+    type_name: &'static str,
+    /// Source location of the registration
+    location: String,
+    build: BuildFn,
+}
+
+/// Event factories keyed by name
+///
+/// Registering the same type under the same name again is a no-op;
+/// registering another type under a taken name is a
+/// [`FactoryError::Collision`].
+#[derive(Clone, Default)]
+pub struct EventFactoryRegistry {
+    factories: BTreeMap<String, Factory>,
+}
+
+impl EventFactoryRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every event declared with [`event!`](crate::event!) in the binary
+    ///
+    /// Fails if two crates or modules declared events with the same name.
+    #[cfg(feature = "event-factories")]
+    pub fn registered() -> Result<Self, FactoryError> {
+        let mut registry = Self::new();
+        for registration in inventory::iter::<EventFactoryRegistration> {
+            registry.insert(
+                registration.name.to_string(),
+                Factory {
+                    type_id: (registration.type_id)(),
+                    type_name: (registration.type_name)(),
+                    location: registration.location.to_string(),
+                    build: registration.build,
+                },
+            )?;
+        }
+        Ok(registry)
+    }
+
+    /// Let `name` construct a `T` from JSON
+    #[track_caller]
+    pub fn register_event_factory<T: Event + Serialize + DeserializeOwned>(
+        &mut self,
+        name: impl Into<String>,
+    ) -> Result<(), FactoryError> {
+        self.insert(
+            name.into(),
+            Factory {
+                type_id: TypeId::of::<T>(),
+                type_name: std::any::type_name::<T>(),
+                location: Location::caller().to_string(),
+                build: build::<T>,
+            },
+        )
+    }
+
+    /// Add every factory of `other`
+    pub fn merge(&mut self, other: &EventFactoryRegistry) -> Result<(), FactoryError> {
+        for (name, factory) in &other.factories {
+            self.insert(name.clone(), factory.clone())?;
+        }
+        Ok(())
+    }
+
+    fn insert(&mut self, name: String, factory: Factory) -> Result<(), FactoryError> {
+        match self.factories.get(&name) {
+            Some(existing) if existing.type_id == factory.type_id => Ok(()),
+            Some(existing) => Err(FactoryError::Collision {
+                first_type: existing.type_name.to_string(),
+                first_location: existing.location.clone(),
+                second_type: factory.type_name.to_string(),
+                second_location: factory.location,
+                name,
+            }),
+            None => {
+                self.factories.insert(name, factory);
+                Ok(())
+            }
+        }
+    }
+
+    /// Build the event registered as `name` from `json`
+    pub fn construct(
+        &self,
+        name: &str,
+        json: serde_json::Value,
+    ) -> Result<Box<dyn AnyEventPublish>, FactoryError> {
+        let factory = self
+            .factories
+            .get(name)
+            .ok_or_else(|| FactoryError::UnknownEvent {
+                name: name.to_string(),
+            })?;
+        (factory.build)(json).map_err(|error| FactoryError::InvalidPayload {
+            name: name.to_string(),
+            message: error.to_string(),
+        })
+    }
+
+    /// Registered names, sorted
+    pub fn list(&self) -> Vec<&str> {
+        self.factories.keys().map(String::as_str).collect()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.factories.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.factories.len()
+    }
+}
+
+impl fmt::Debug for EventFactoryRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.factories.keys()).finish()
+    }
+}
+
+/// Factory submitted by [`event!`](crate::event!) (feature `event-factories`)
+#[cfg(feature = "event-factories")]
+#[doc(hidden)]
+pub struct EventFactoryRegistration {
+    name: &'static str,
+    location: &'static str,
+    type_id: fn() -> TypeId,
+    type_name: fn() -> &'static str,
+    build: BuildFn,
+}
+
+#[cfg(feature = "event-factories")]
+impl EventFactoryRegistration {
+    pub const fn new<E: Event + Serialize + DeserializeOwned>(
+        name: &'static str,
+        location: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            location,
+            type_id: TypeId::of::<E>,
+            type_name: std::any::type_name::<E>,
+            build: build::<E>,
+        }
+    }
+}
+
+#[cfg(feature = "event-factories")]
+inventory::collect!(EventFactoryRegistration);
+
+/// Register an `event!` type with [`EventFactoryRegistry::registered`]
+#[cfg(feature = "event-factories")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register_event_factory {
+    ($event:ty, $name:expr) => {
+        $crate::__inventory::submit! {
+            $crate::event::EventFactoryRegistration::new::<$event>(
+                $name,
+                ::core::concat!(::core::file!(), ":", ::core::line!()),
+            )
+        }
+    };
+}
+
+/// Without the `event-factories` feature, `event!` types are not registered
+#[cfg(not(feature = "event-factories"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register_event_factory {
+    ($event:ty, $name:expr) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct SpawnWave {
+        size: u32,
+    }
+
+    impl Event for SpawnWave {}
+
+    mod other {
+        #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+        pub struct SpawnWave;
+
+        impl crate::event::Event for SpawnWave {}
+    }
+
+    #[test]
+    fn test_constructed_event_publishes_itself() {
+        let mut registry = EventFactoryRegistry::new();
+        registry
+            .register_event_factory::<SpawnWave>("SpawnWave")
+            .unwrap();
+
+        let event = registry
+            .construct("SpawnWave", serde_json::json!({ "size": 12 }))
+            .unwrap();
+        assert_eq!(
+            event.downcast_ref::<SpawnWave>(),
+            Some(&SpawnWave { size: 12 })
+        );
+        assert!(event.type_name().ends_with("SpawnWave"));
+
+        let mut bus = EventBus::new();
+        event.clone().publish(&mut bus);
+        event.publish(&mut bus);
+        bus.dispatch();
+        let sizes: Vec<u32> = bus.reader::<SpawnWave>().iter().map(|e| e.size).collect();
+        assert_eq!(sizes, [12, 12]);
+    }
+
+    #[test]
+    fn test_unknown_name_and_bad_payload() {
+        let mut registry = EventFactoryRegistry::new();
+        registry
+            .register_event_factory::<SpawnWave>("SpawnWave")
+            .unwrap();
+
+        assert_eq!(
+            registry
+                .construct("SpawnBoss", serde_json::json!({}))
+                .unwrap_err(),
+            FactoryError::UnknownEvent {
+                name: "SpawnBoss".to_string()
+            }
+        );
+        let error = registry
+            .construct("SpawnWave", serde_json::json!({ "size": "many" }))
+            .unwrap_err();
+        assert!(
+            matches!(&error, FactoryError::InvalidPayload { name, .. } if name == "SpawnWave"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_collision_reports_both_locations() {
+        let mut registry = EventFactoryRegistry::new();
+        registry
+            .register_event_factory::<SpawnWave>("SpawnWave")
+            .unwrap();
+        // The same type again is fine
+        registry
+            .register_event_factory::<SpawnWave>("SpawnWave")
+            .unwrap();
+
+        let error = registry
+            .register_event_factory::<other::SpawnWave>("SpawnWave")
+            .unwrap_err();
+        let FactoryError::Collision {
+            name,
+            first_type,
+            first_location,
+            second_type,
+            second_location,
+        } = &error
+        else {
+            panic!("expected a collision, got {}", error);
+        };
+        assert_eq!(name, "SpawnWave");
+        assert_eq!(first_type, std::any::type_name::<SpawnWave>());
+        assert_eq!(second_type, std::any::type_name::<other::SpawnWave>());
+        assert!(first_location.contains("event/factory.rs:"));
+        assert!(second_location.contains("event/factory.rs:"));
+        assert_ne!(first_location, second_location);
+        assert!(error.to_string().contains(first_location.as_str()));
+        assert!(error.to_string().contains(second_location.as_str()));
+
+        // The first registration is kept
+        assert_eq!(registry.list(), ["SpawnWave"]);
+        assert!(registry
+            .construct("SpawnWave", serde_json::json!({ "size": 1 }))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_merge_and_list() {
+        let mut combat = EventFactoryRegistry::new();
+        combat
+            .register_event_factory::<SpawnWave>("SpawnWave")
+            .unwrap();
+        let mut registry = EventFactoryRegistry::new();
+        registry
+            .register_event_factory::<other::SpawnWave>("Reset")
+            .unwrap();
+
+        registry.merge(&combat).unwrap();
+        assert_eq!(registry.list(), ["Reset", "SpawnWave"]);
+        assert_eq!(registry.len(), 2);
+
+        let mut clashing = EventFactoryRegistry::new();
+        clashing
+            .register_event_factory::<other::SpawnWave>("SpawnWave")
+            .unwrap();
+        assert!(matches!(
+            registry.merge(&clashing),
+            Err(FactoryError::Collision { .. })
+        ));
+    }
+}
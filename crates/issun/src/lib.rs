@@ -27,12 +27,18 @@
 
 // Re-export macros
 pub use issun_macros::{
-    auto_pump, event, event_handler, Asset, Entity, Plugin, Resource, Scene, Service, System,
+    auto_pump, event, event_handler, hook_impl, Asset, Entity, Plugin, Resource, Scene, Service,
+    System,
 };
 
 // Re-export async-trait for macros
 pub use async_trait;
 
+// Used by event! to register event factories
+#[cfg(feature = "event-factories")]
+#[doc(hidden)]
+pub use inventory as __inventory;
+
 // Core modules
 pub mod asset;
 pub mod builder;
@@ -41,6 +47,7 @@ pub mod engine;
 pub mod entity;
 pub mod error;
 pub mod event;
+pub mod localization;
 pub mod plugin;
 pub mod replay;
 pub mod resources;
@@ -50,6 +57,7 @@ pub mod state;
 pub mod storage;
 pub mod store;
 pub mod system;
+pub mod testing;
 pub mod trace;
 pub mod ui;
 
@@ -69,9 +77,11 @@ pub mod prelude {
     pub use crate::context::{
         Context, GameContext, ResourceContext, ServiceContext, SystemContext,
     };
+    pub use crate::engine::flags::FeatureFlags;
     pub use crate::entity::Entity;
     pub use crate::error::{IssunError, Result};
-    pub use crate::event::{Event, EventBus, EventReader};
+    pub use crate::event::{Event, EventBus, EventReader, EventWriter, HandlerFlow};
+    pub use crate::localization::Localization;
     pub use crate::plugin::{
         // Room Buff
         ActiveBuff,
@@ -124,12 +134,12 @@ pub mod prelude {
         // Save/Load
         SaveLoadPlugin,
     };
-    pub use crate::resources::{Resource, Resources};
+    pub use crate::resources::{Resource, ResourceInit, Resources};
     pub use crate::scene::{Scene, SceneDirector, SceneTransition};
     pub use crate::service::Service;
     pub use crate::state::{State, States};
     pub use crate::store::{EntityStore, Store};
-    pub use crate::system::System;
+    pub use crate::system::{System, SystemExt};
     // MOD system
     pub use crate::modding::{
         ModBackend, ModHandle, ModLoader, ModMetadata, ModSystemConfig, ModSystemPlugin,
@@ -0,0 +1,281 @@
+//! Localized string tables
+//!
+//! `Localization` holds the base game's strings per language plus the
+//! strings contributed by loaded MODs. MOD keys are namespaced by MOD id
+//! (`"better_loot.item.flame_sword.name"`), so they can never shadow base
+//! keys; contributions that would are rejected.
+//!
+//! ```ignore
+//! let mut l10n = Localization::new("ja")
+//!     .with_strings("en", [("menu.start", "Start")])
+//!     .with_strings("ja", [("menu.start", "スタート")]);
+//!
+//! assert_eq!(l10n.text("menu.start"), "スタート");
+//! l10n.set_language("fr");
+//! assert_eq!(l10n.text("menu.start"), "Start"); // English fallback
+//! ```
+
+use std::collections::HashMap;
+
+/// Language used when the active language lacks a key
+pub const FALLBACK_LANGUAGE: &str = "en";
+
+/// Key → text table of one language
+pub type StringTable = HashMap<String, String>;
+
+/// Strings contributed by one MOD, per language
+#[derive(Debug, Clone, Default)]
+struct ModContribution {
+    mod_id: String,
+    tables: HashMap<String, StringTable>,
+}
+
+/// Localized string tables of the base game and loaded MODs
+#[derive(Debug, Clone)]
+pub struct Localization {
+    language: String,
+    base: HashMap<String, StringTable>,
+    /// In load order; later contributions win on identical keys
+    mods: Vec<ModContribution>,
+    /// Base and MOD strings of the active language, merged
+    active: StringTable,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self::new(FALLBACK_LANGUAGE)
+    }
+}
+
+impl Localization {
+    /// Create empty tables with `language` active
+    pub fn new(language: impl Into<String>) -> Self {
+        Self {
+            language: language.into(),
+            base: HashMap::new(),
+            mods: Vec::new(),
+            active: StringTable::new(),
+        }
+    }
+
+    /// Add base-game strings for `language`
+    pub fn with_strings<K, V>(
+        mut self,
+        language: &str,
+        strings: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        for (key, value) in strings {
+            self.insert(language, key, value);
+        }
+        self
+    }
+
+    /// Add or replace one base-game string
+    pub fn insert(&mut self, language: &str, key: impl Into<String>, value: impl Into<String>) {
+        self.base
+            .entry(language.to_string())
+            .or_default()
+            .insert(key.into(), value.into());
+        if language == self.language {
+            self.remerge();
+        }
+    }
+
+    /// Active language code
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Switch the active language, re-merging all MOD contributions
+    pub fn set_language(&mut self, language: impl Into<String>) {
+        self.language = language.into();
+        self.remerge();
+    }
+
+    /// Text for `key` in the active language, falling back to English
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.active
+            .get(key)
+            .or_else(|| self.lookup(FALLBACK_LANGUAGE, key))
+            .map(String::as_str)
+    }
+
+    /// Like `get`, but returns the key itself when no language has it
+    pub fn text(&self, key: &str) -> String {
+        self.get(key).unwrap_or(key).to_string()
+    }
+
+    /// Whether the base game defines `key` in any language
+    pub fn is_base_key(&self, key: &str) -> bool {
+        self.base.values().any(|table| table.contains_key(key))
+    }
+
+    /// Merge strings contributed by `mod_id` for `language`
+    ///
+    /// Keys are prefixed with `"<mod_id>."` unless they already are.
+    /// Entries whose namespaced key is a base-game key are skipped; their
+    /// keys are returned so the caller can report the conflict.
+    pub fn register_mod_strings(
+        &mut self,
+        mod_id: &str,
+        language: &str,
+        strings: StringTable,
+    ) -> Vec<String> {
+        let mut rejected = Vec::new();
+        let mut accepted = StringTable::new();
+        for (key, value) in strings {
+            let key = mod_key(mod_id, &key);
+            if self.is_base_key(&key) {
+                rejected.push(key);
+            } else {
+                accepted.insert(key, value);
+            }
+        }
+        rejected.sort();
+
+        let position = match self.mods.iter().position(|c| c.mod_id == mod_id) {
+            Some(position) => position,
+            None => {
+                self.mods.push(ModContribution {
+                    mod_id: mod_id.to_string(),
+                    tables: HashMap::new(),
+                });
+                self.mods.len() - 1
+            }
+        };
+        self.mods[position]
+            .tables
+            .entry(language.to_string())
+            .or_default()
+            .extend(accepted);
+
+        if language == self.language {
+            self.remerge();
+        }
+        rejected
+    }
+
+    /// Drop every string contributed by `mod_id`
+    ///
+    /// Returns whether the MOD had contributed anything.
+    pub fn remove_mod_strings(&mut self, mod_id: &str) -> bool {
+        let before = self.mods.len();
+        self.mods.retain(|c| c.mod_id != mod_id);
+        let removed = self.mods.len() != before;
+        if removed {
+            self.remerge();
+        }
+        removed
+    }
+
+    /// Ids of MODs with contributed strings, in load order
+    pub fn contributing_mods(&self) -> impl Iterator<Item = &str> {
+        self.mods.iter().map(|c| c.mod_id.as_str())
+    }
+
+    /// Rebuild the merged table of the active language
+    fn remerge(&mut self) {
+        let mut active = self.base.get(&self.language).cloned().unwrap_or_default();
+        for contribution in &self.mods {
+            if let Some(table) = contribution.tables.get(&self.language) {
+                active.extend(table.iter().map(|(k, v)| (k.clone(), v.clone())));
+            }
+        }
+        self.active = active;
+    }
+
+    /// Look `key` up in `language` without using the merged table
+    fn lookup(&self, language: &str, key: &str) -> Option<&String> {
+        self.mods
+            .iter()
+            .rev()
+            .find_map(|c| c.tables.get(language).and_then(|t| t.get(key)))
+            .or_else(|| self.base.get(language).and_then(|t| t.get(key)))
+    }
+}
+
+impl crate::resources::Resource for Localization {}
+
+/// Namespaced key of a MOD string (`"better_loot"`, `"item.x"` → `"better_loot.item.x"`)
+pub fn mod_key(mod_id: &str, key: &str) -> String {
+    match key.strip_prefix(mod_id) {
+        Some(rest) if rest.starts_with('.') => key.to_string(),
+        _ => format!("{}.{}", mod_id, key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(entries: &[(&str, &str)]) -> StringTable {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_fallback_to_english() {
+        let mut l10n = Localization::new("ja")
+            .with_strings("en", [("menu.start", "Start"), ("menu.quit", "Quit")])
+            .with_strings("ja", [("menu.start", "スタート")]);
+
+        assert_eq!(l10n.text("menu.start"), "スタート");
+        assert_eq!(l10n.text("menu.quit"), "Quit");
+        assert_eq!(l10n.get("menu.missing"), None);
+        assert_eq!(l10n.text("menu.missing"), "menu.missing");
+
+        l10n.set_language("en");
+        assert_eq!(l10n.text("menu.start"), "Start");
+    }
+
+    #[test]
+    fn test_mod_strings_follow_language_and_unload() {
+        let mut l10n = Localization::new("en");
+        l10n.register_mod_strings(
+            "better_loot",
+            "en",
+            table(&[("item.flame_sword.name", "Flame Sword")]),
+        );
+        l10n.register_mod_strings(
+            "better_loot",
+            "ja",
+            table(&[("better_loot.item.flame_sword.name", "炎の剣")]),
+        );
+
+        let key = "better_loot.item.flame_sword.name";
+        assert_eq!(l10n.text(key), "Flame Sword");
+        l10n.set_language("ja");
+        assert_eq!(l10n.text(key), "炎の剣");
+
+        assert!(l10n.remove_mod_strings("better_loot"));
+        assert_eq!(l10n.get(key), None);
+        assert!(!l10n.remove_mod_strings("better_loot"));
+    }
+
+    #[test]
+    fn test_base_key_collision_is_rejected() {
+        let mut l10n = Localization::new("en").with_strings("ja", [("ui.title", "タイトル")]);
+
+        let rejected = l10n.register_mod_strings(
+            "ui",
+            "en",
+            table(&[("title", "Hijacked"), ("subtitle", "Fine")]),
+        );
+        assert_eq!(rejected, vec!["ui.title".to_string()]);
+        assert_eq!(l10n.text("ui.title"), "ui.title");
+        assert_eq!(l10n.text("ui.subtitle"), "Fine");
+    }
+
+    #[test]
+    fn test_mod_key() {
+        assert_eq!(mod_key("m", "item.a"), "m.item.a");
+        assert_eq!(mod_key("m", "m.item.a"), "m.item.a");
+        assert_eq!(mod_key("m", "mm.item"), "m.mm.item");
+    }
+}
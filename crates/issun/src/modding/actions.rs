@@ -0,0 +1,141 @@
+//! Player actions contributed by MODs, for the game's menus
+
+use crate::localization::mod_key;
+use crate::modding::ModActionDefinition;
+
+/// One MOD action as the game's action menu shows it
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ModActionEntry {
+    /// Id in the `ActionRegistry` (`"<mod_id>:<name>"`)
+    pub action_id: String,
+    pub mod_id: String,
+    pub name: String,
+    /// Localization key of the label (`"<mod_id>.action.<name>"`)
+    ///
+    /// MODs provide the text with `register_strings`; `Localization::text`
+    /// falls back to the key when they don't.
+    pub label_key: String,
+    pub ap_cost: u32,
+    pub cooldown_days: u32,
+    /// Script function `ModBridgeSystem` calls when the action is performed
+    pub callback: String,
+}
+
+impl ModActionEntry {
+    /// Menu entry of a MOD's action registration
+    pub fn from_definition(definition: ModActionDefinition) -> Self {
+        Self {
+            action_id: format!("{}:{}", definition.mod_id, definition.name),
+            label_key: mod_key(&definition.mod_id, &format!("action.{}", definition.name)),
+            mod_id: definition.mod_id,
+            name: definition.name,
+            ap_cost: definition.ap_cost,
+            cooldown_days: definition.cooldown_days,
+            callback: definition.callback,
+        }
+    }
+}
+
+/// Actions registered by loaded MODs, in registration order
+///
+/// Registered by `ModSystemPlugin`. `ModBridgeSystem` adds entries as MODs
+/// call `register_action()` and drops a MOD's entries when it is unloaded.
+///
+/// # Example
+///
+/// ```ignore
+/// let actions = resources.get::<ModActions>().await.unwrap();
+/// let localization = resources.get::<Localization>().await.unwrap();
+/// for entry in actions.iter() {
+///     menu.push(format!("{} ({} AP)", localization.text(&entry.label_key), entry.ap_cost));
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ModActions {
+    entries: Vec<ModActionEntry>,
+}
+
+impl ModActions {
+    /// Create an empty menu
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an entry, replacing the one with the same action id in place
+    pub fn insert(&mut self, entry: ModActionEntry) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|existing| existing.action_id == entry.action_id)
+        {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// Drop every entry of `mod_id`; returns how many were dropped
+    pub fn remove_mod(&mut self, mod_id: &str) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|entry| entry.mod_id != mod_id);
+        before - self.entries.len()
+    }
+
+    /// Entry with the given action id
+    pub fn get(&self, action_id: &str) -> Option<&ModActionEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.action_id == action_id)
+    }
+
+    /// All entries in registration order
+    pub fn iter(&self) -> impl Iterator<Item = &ModActionEntry> {
+        self.entries.iter()
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no MOD registered an action
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(mod_id: &str, name: &str, ap_cost: u32) -> ModActionDefinition {
+        ModActionDefinition {
+            mod_id: mod_id.to_string(),
+            name: name.to_string(),
+            ap_cost,
+            cooldown_days: 0,
+            callback: "on_action".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_entry_is_namespaced() {
+        let entry = ModActionEntry::from_definition(definition("propaganda", "campaign", 2));
+        assert_eq!(entry.action_id, "propaganda:campaign");
+        assert_eq!(entry.label_key, "propaganda.action.campaign");
+    }
+
+    #[test]
+    fn test_insert_replaces_and_remove_mod() {
+        let mut actions = ModActions::new();
+        actions.insert(ModActionEntry::from_definition(definition("a", "x", 1)));
+        actions.insert(ModActionEntry::from_definition(definition("b", "y", 1)));
+        actions.insert(ModActionEntry::from_definition(definition("a", "x", 3)));
+
+        assert_eq!(actions.len(), 2);
+        assert_eq!(actions.get("a:x").unwrap().ap_cost, 3);
+
+        assert_eq!(actions.remove_mod("a"), 1);
+        assert!(actions.get("a:x").is_none());
+        assert_eq!(actions.len(), 1);
+    }
+}
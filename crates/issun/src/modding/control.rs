@@ -32,6 +32,9 @@ pub enum PluginAction {
 pub struct PluginControl {
     pub plugin_name: String,
     pub action: PluginAction,
+    /// MOD that issued the command; `None` for commands from the host
+    #[serde(default)]
+    pub issuer: Option<String>,
 }
 
 impl PluginControl {
@@ -40,6 +43,7 @@ impl PluginControl {
         Self {
             plugin_name: plugin_name.into(),
             action: PluginAction::Enable,
+            issuer: None,
         }
     }
 
@@ -48,6 +52,7 @@ impl PluginControl {
         Self {
             plugin_name: plugin_name.into(),
             action: PluginAction::Disable,
+            issuer: None,
         }
     }
 
@@ -63,6 +68,7 @@ impl PluginControl {
                 key: key.into(),
                 value: value.into(),
             },
+            issuer: None,
         }
     }
 
@@ -78,6 +84,13 @@ impl PluginControl {
                 hook_name: hook_name.into(),
                 data,
             },
+            issuer: None,
         }
     }
+
+    /// Attribute the command to the MOD `mod_id`
+    pub fn with_issuer(mut self, mod_id: impl Into<String>) -> Self {
+        self.issuer = Some(mod_id.into());
+        self
+    }
 }
@@ -23,6 +23,25 @@ pub enum ModError {
     #[error("Function not found: {0}")]
     FunctionNotFound(String),
 
+    #[error("MOD '{mod_name}' requires {dependency}, which is not loaded")]
+    MissingDependency {
+        mod_name: String,
+        dependency: String,
+    },
+
+    #[error("MOD '{mod_name}' requires {dependency}, but version {found} is loaded")]
+    IncompatibleDependency {
+        mod_name: String,
+        dependency: String,
+        found: String,
+    },
+
+    #[error("Dependency cycle between MODs: {}", .0.join(" -> "))]
+    DependencyCycle(Vec<String>),
+
+    #[error("Dispatch order cycle between MODs: {}", .0.join(" -> "))]
+    OrderingCycle(Vec<String>),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
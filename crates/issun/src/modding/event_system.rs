@@ -2,8 +2,9 @@
 //!
 //! Processes event subscriptions from MODs and dispatches events to them.
 
-use crate::event::EventBus;
-use crate::modding::{DynamicEvent, ModLoaderState};
+use crate::event::{EventBus, EventFactoryRegistry};
+use crate::localization::Localization;
+use crate::modding::{DynamicEvent, ModLoaderState, ModLogEvent, ModStringConflict};
 use crate::system::System;
 use async_trait::async_trait;
 use std::any::Any;
@@ -11,10 +12,16 @@ use std::any::Any;
 /// System for processing MOD event subscriptions
 ///
 /// This system:
-/// 1. Publishes MOD-generated events to EventBus as DynamicEvent
+/// 1. Publishes MOD-generated events to EventBus as DynamicEvent, and also as
+///    the typed event when the `EventFactoryRegistry` resource knows the name
 /// 2. Collects DynamicEvents from EventBus
 /// 3. Matches them against MOD subscriptions
 /// 4. Calls MOD callbacks with event data
+/// 5. Merges MOD strings into `Localization`, publishing `ModStringConflict`
+///    for keys that collide with base-game keys
+/// 6. Publishes MOD log lines as `ModLogEvent`
+/// 7. Publishes calls refused by MOD permissions as `ModPermissionDenied`
+/// 8. Publishes failed MOD callbacks as `ModRuntimeErrorEvent`
 pub struct ModEventSystem;
 
 impl Default for ModEventSystem {
@@ -43,8 +50,18 @@ impl ModEventSystem {
 
         // Publish drained events as DynamicEvent to EventBus
         if !events_to_publish.is_empty() {
+            let factories = resources.get::<EventFactoryRegistry>().await;
             if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
                 for (event_type, data) in events_to_publish {
+                    // Game systems read the typed event; MOD subscribers still get the DynamicEvent
+                    if let Some(factories) = factories.as_ref() {
+                        if factories.contains(&event_type) {
+                            match factories.construct(&event_type, data.clone()) {
+                                Ok(event) => event.publish(&mut event_bus),
+                                Err(error) => eprintln!("[ModEventSystem] {}", error),
+                            }
+                        }
+                    }
                     event_bus.publish(DynamicEvent {
                         event_type: event_type.clone(),
                         data,
@@ -80,6 +97,120 @@ impl ModEventSystem {
                 }
             }
         }
+
+        // Step 4: Merge localization contributions (including those queued by callbacks)
+        self.merge_strings(resources).await;
+
+        // Step 5: Publish log lines (including those written by callbacks)
+        self.publish_logs(resources).await;
+
+        // Step 6: Publish refused calls (including those made by callbacks)
+        self.publish_denials(resources).await;
+
+        // Step 7: Publish callback failures (event callbacks, hot reloads)
+        self.publish_errors(resources).await;
+    }
+
+    async fn publish_errors(&mut self, resources: &mut crate::context::ResourceContext) {
+        let errors = {
+            if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
+                loader_state.loader.drain_errors()
+            } else {
+                Vec::new()
+            }
+        };
+        if errors.is_empty() {
+            return;
+        }
+
+        if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+            for error in errors {
+                event_bus.publish(error);
+            }
+        }
+    }
+
+    async fn publish_denials(&mut self, resources: &mut crate::context::ResourceContext) {
+        let denials = {
+            if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
+                loader_state.loader.drain_denials()
+            } else {
+                Vec::new()
+            }
+        };
+        if denials.is_empty() {
+            return;
+        }
+
+        if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+            for denied in denials {
+                event_bus.publish(denied);
+            }
+        }
+    }
+
+    async fn publish_logs(&mut self, resources: &mut crate::context::ResourceContext) {
+        let entries = {
+            if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
+                loader_state.loader.drain_logs()
+            } else {
+                Vec::new()
+            }
+        };
+        if entries.is_empty() {
+            return;
+        }
+
+        if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+            for entry in entries {
+                event_bus.publish(ModLogEvent { entry });
+            }
+        }
+    }
+
+    async fn merge_strings(&mut self, resources: &mut crate::context::ResourceContext) {
+        let contributions = {
+            if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
+                loader_state.loader.drain_strings()
+            } else {
+                Vec::new()
+            }
+        };
+        if contributions.is_empty() {
+            return;
+        }
+
+        let mut conflicts = Vec::new();
+        {
+            let Some(mut localization) = resources.get_mut::<Localization>().await else {
+                eprintln!("[ModEventSystem] No Localization resource; dropping MOD strings");
+                return;
+            };
+            for contribution in contributions {
+                let keys = localization.register_mod_strings(
+                    &contribution.mod_id,
+                    &contribution.language,
+                    contribution.strings,
+                );
+                if !keys.is_empty() {
+                    eprintln!(
+                        "[ModEventSystem] MOD '{}' tried to override base strings: {:?}",
+                        contribution.mod_id, keys
+                    );
+                    conflicts.push(ModStringConflict {
+                        mod_id: contribution.mod_id,
+                        language: contribution.language,
+                        keys,
+                    });
+                }
+            }
+        }
+
+        if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+            for conflict in conflicts {
+                event_bus.publish(conflict);
+            }
+        }
     }
 }
 
@@ -4,7 +4,7 @@
 //! the MOD system, and ISSUN plugins.
 
 use crate::event::Event;
-use crate::modding::{ModHandle, PluginControl};
+use crate::modding::{ModHandle, ModLogEntry, ModMetadata, PluginControl};
 use std::path::PathBuf;
 
 /// Dynamic event from MOD scripts
@@ -34,6 +34,22 @@ pub struct DynamicEvent {
 
 impl Event for DynamicEvent {}
 
+/// A MOD was found in the MOD directory
+///
+/// Published by `ModLoadSystem` on its first update, once per MOD of
+/// `ModSystemPlugin::with_mod_dir` and before any load result, so games can
+/// list the installed MODs (e.g. to let players enable or disable them).
+/// `metadata` is read with `ModLoader::peek_metadata`; `error` says why it
+/// could not be.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ModDiscovered {
+    pub path: PathBuf,
+    pub metadata: Option<ModMetadata>,
+    pub error: Option<String>,
+}
+
+impl Event for ModDiscovered {}
+
 /// Request to load a MOD from a file path
 ///
 /// Published by user code to trigger MOD loading.
@@ -88,6 +104,113 @@ pub struct ModUnloadedEvent {
 
 impl Event for ModUnloadedEvent {}
 
+/// Request to reload a MOD from its source file
+///
+/// Published by user code (or tooling) after a MOD script changed.
+/// Consumed by `ModLoadSystem`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ModReloadRequested {
+    pub mod_id: String,
+}
+
+impl Event for ModReloadRequested {}
+
+/// MOD successfully reloaded
+///
+/// Published by `ModLoadSystem` after successful reload.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ModReloadedEvent {
+    pub handle: ModHandle,
+}
+
+impl Event for ModReloadedEvent {}
+
+/// MOD failed to reload
+///
+/// Published by `ModLoadSystem` when reload fails. The previous
+/// version of the MOD stays loaded.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ModReloadFailedEvent {
+    pub mod_id: String,
+    pub error: String,
+}
+
+impl Event for ModReloadFailedEvent {}
+
+/// MOD strings rejected because they collide with base-game keys
+///
+/// Published by `ModEventSystem` when merging `register_strings()`
+/// contributions into `Localization`. The other strings of the same call
+/// are still merged.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ModStringConflict {
+    pub mod_id: String,
+    pub language: String,
+    /// Namespaced keys that were rejected
+    pub keys: Vec<String>,
+}
+
+impl Event for ModStringConflict {}
+
+/// A MOD wrote a log line
+///
+/// Published by `ModEventSystem` for every entry drained from the loader,
+/// so games can show MOD output in an event log widget.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ModLogEvent {
+    pub entry: ModLogEntry,
+}
+
+impl Event for ModLogEvent {}
+
+/// A MOD attempted something its permissions don't allow
+///
+/// Queued by the loader's host functions, which drop the attempted command
+/// or event, and published by `ModEventSystem`. See
+/// [`ModPermissions`](crate::modding::ModPermissions).
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModPermissionDenied {
+    pub mod_id: String,
+    /// What was attempted, e.g. `disable plugin 'save_load'`
+    pub action: String,
+}
+
+impl Event for ModPermissionDenied {}
+
+/// A MOD callback failed while the game was running
+///
+/// Queued by the loader (event callbacks, scheduled callbacks, hot reloads)
+/// and published by `ModEventSystem`; `ModBridgeSystem` publishes failed
+/// `on_update` calls and action callbacks. Games can use it to tell the player
+/// that a MOD is broken.
+///
+/// The MOD is named by `mod_id` rather than `id`, like the other MOD events
+/// ([`ModUnloadedEvent`], [`ModPermissionDenied`]).
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModRuntimeErrorEvent {
+    /// [`ModHandle::id`] of the failing MOD
+    pub mod_id: String,
+    /// What was running, e.g. `on_update` or `event 'PlayerDamaged'`
+    pub context: String,
+    pub error: String,
+}
+
+impl ModRuntimeErrorEvent {
+    pub fn new(
+        mod_id: impl Into<String>,
+        context: impl Into<String>,
+        error: impl ToString,
+    ) -> Self {
+        Self {
+            mod_id: mod_id.into(),
+            context: context.into(),
+            error: error.to_string(),
+        }
+    }
+}
+
+impl Event for ModRuntimeErrorEvent {}
+
 /// Request to control a plugin from MOD
 ///
 /// Published by `PluginControlSystem` after draining commands from MODs.
@@ -108,6 +231,9 @@ impl Event for PluginControlRequested {}
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct PluginEnabledEvent {
     pub plugin_name: String,
+    /// MOD that issued the command; `None` for commands from the host
+    #[serde(default)]
+    pub issuer: Option<String>,
 }
 
 impl Event for PluginEnabledEvent {}
@@ -119,6 +245,9 @@ impl Event for PluginEnabledEvent {}
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct PluginDisabledEvent {
     pub plugin_name: String,
+    /// MOD that issued the command; `None` for commands from the host
+    #[serde(default)]
+    pub issuer: Option<String>,
 }
 
 impl Event for PluginDisabledEvent {}
@@ -132,6 +261,9 @@ pub struct PluginParameterChangedEvent {
     pub plugin_name: String,
     pub key: String,
     pub value: serde_json::Value,
+    /// MOD that issued the command; `None` for commands from the host
+    #[serde(default)]
+    pub issuer: Option<String>,
 }
 
 impl Event for PluginParameterChangedEvent {}
@@ -145,6 +277,9 @@ pub struct PluginHookTriggeredEvent {
     pub plugin_name: String,
     pub hook_name: String,
     pub data: serde_json::Value,
+    /// MOD that issued the command; `None` for commands from the host
+    #[serde(default)]
+    pub issuer: Option<String>,
 }
 
 impl Event for PluginHookTriggeredEvent {}
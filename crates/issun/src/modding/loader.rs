@@ -3,8 +3,14 @@
 //! This module defines the `ModLoader` trait that all backend implementations
 //! must implement (RhaiLoader, WasmLoader, etc.)
 
+use crate::engine::rng::MasterSeed;
 use crate::modding::control::PluginControl;
-use crate::modding::error::ModResult;
+use crate::modding::error::{ModError, ModResult};
+use crate::modding::events::{ModPermissionDenied, ModRuntimeErrorEvent};
+use crate::modding::manifest::{ModDependency, ModManifest};
+use crate::modding::permissions::ModPermissionPolicy;
+use async_trait::async_trait;
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Metadata about a loaded MOD
@@ -14,6 +20,87 @@ pub struct ModMetadata {
     pub version: String,
     pub author: Option<String>,
     pub description: Option<String>,
+    /// Declared dependencies (from `mod.toml` for directory MODs)
+    #[serde(default)]
+    pub dependencies: Vec<ModDependency>,
+    /// Dispatch priority; callbacks of higher-priority MODs run first
+    #[serde(default)]
+    pub priority: i32,
+    /// MODs whose callbacks must run before this MOD's
+    #[serde(default)]
+    pub after: Vec<String>,
+}
+
+/// Current values of MOD-controllable plugin parameters, keyed by (plugin, key)
+///
+/// Plugin names are normalized (`"combat"`, not `"issun:combat"`).
+pub type PluginParams = HashMap<(String, String), serde_json::Value>;
+
+/// Localized strings a MOD registered via `register_strings(lang, map)`
+///
+/// Keys are as written by the script; `Localization::register_mod_strings`
+/// namespaces them with the MOD id.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModStrings {
+    pub mod_id: String,
+    pub language: String,
+    pub strings: HashMap<String, String>,
+}
+
+/// Player action a MOD registered via `register_action(name, ap_cost, callback)`
+///
+/// `ModBridgeSystem` adds it to the `ActionRegistry` as `"<mod_id>:<name>"`
+/// and calls `callback` with a context object when the action is performed.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ModActionDefinition {
+    pub mod_id: String,
+    pub name: String,
+    pub ap_cost: u32,
+    /// Days before the action can be performed again (0: no cooldown)
+    #[serde(default)]
+    pub cooldown_days: u32,
+    /// Script function invoked when the action is performed
+    pub callback: String,
+}
+
+/// Severity of a MOD log line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ModLogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl std::fmt::Display for ModLogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModLogLevel::Info => write!(f, "info"),
+            ModLogLevel::Warn => write!(f, "warn"),
+            ModLogLevel::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A line a MOD logged via `log()`, `log_warn()` or `log_error()`
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModLogEntry {
+    /// MOD whose script was executing; `None` outside of a MOD context
+    pub mod_id: Option<String>,
+    pub level: ModLogLevel,
+    pub message: String,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+impl ModLogEntry {
+    /// Entry stamped with the current time
+    pub fn new(mod_id: Option<String>, level: ModLogLevel, message: impl Into<String>) -> Self {
+        Self {
+            mod_id,
+            level,
+            message: message.into(),
+            timestamp: chrono::Utc::now(),
+        }
+    }
 }
 
 /// Handle to a loaded MOD
@@ -54,13 +141,87 @@ impl std::fmt::Display for ModBackend {
 /// let mut loader = RhaiLoader::new();
 /// let handle = loader.load(Path::new("mods/my_mod.rhai"))?;
 /// ```
+#[async_trait]
 pub trait ModLoader: Send + Sync {
-    /// Load a MOD from a file
+    /// Load a MOD from a file, or from a directory containing a `mod.toml`
     fn load(&mut self, path: &Path) -> ModResult<ModHandle>;
 
+    /// Load a MOD without blocking the executor while its init code runs
+    ///
+    /// Backends whose MOD code can run for long (`WasmLoader::new_async`)
+    /// override this. Default: calls `load`.
+    async fn load_async(&mut self, path: &Path) -> ModResult<ModHandle> {
+        self.load(path)
+    }
+
+    /// Read a MOD's metadata without loading it or running any of its code
+    ///
+    /// Used to list the MODs of a directory before any of them loads.
+    /// Default: reads the `mod.toml` of a directory MOD and fails for files.
+    fn peek_metadata(&mut self, path: &Path) -> ModResult<ModMetadata> {
+        if path.is_dir() {
+            return ModManifest::from_dir(path)?.metadata();
+        }
+        Err(ModError::ExecutionFailed(format!(
+            "Reading metadata of {} without loading it is not supported",
+            path.display()
+        )))
+    }
+
+    /// Extensions of the MOD files this loader accepts, e.g. `["rhai"]`
+    ///
+    /// Used when scanning a MOD directory; subdirectories with a `mod.toml`
+    /// are picked up regardless.
+    fn file_extensions(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// Whether this loader handles the MOD at `path`
+    ///
+    /// Used to route a MOD to one of several loaders. Default: a file with
+    /// one of the `file_extensions()`, or a directory whose `mod.toml` entry
+    /// has one. Loaders without extensions accept every path.
+    fn can_load(&self, path: &Path) -> bool {
+        let extensions = self.file_extensions();
+        if extensions.is_empty() {
+            return true;
+        }
+        let file = if path.is_dir() {
+            match ModManifest::from_dir(path) {
+                Ok(manifest) => manifest.entry_path(path),
+                Err(_) => return false,
+            }
+        } else {
+            path.to_path_buf()
+        };
+        file.extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| extensions.contains(&extension))
+    }
+
     /// Unload a MOD
     fn unload(&mut self, handle: &ModHandle) -> ModResult<()>;
 
+    /// Reload a MOD from its original source, keeping its id
+    ///
+    /// Backends that cannot reload return `ModError::ExecutionFailed`.
+    fn reload(&mut self, handle: &ModHandle) -> ModResult<ModHandle> {
+        Err(ModError::ExecutionFailed(format!(
+            "Reload not supported for MOD '{}' ({} backend)",
+            handle.id, handle.backend
+        )))
+    }
+
+    /// Advance a MOD by one engine tick
+    ///
+    /// Called by `ModBridgeSystem` for every loaded MOD once per update.
+    /// Commands and events queued here are returned by the next
+    /// `drain_commands()` / `drain_events()`.
+    fn update(&mut self, handle: &ModHandle, tick: u64) -> ModResult<()> {
+        let _ = (handle, tick);
+        Ok(()) // Default: no per-tick callback
+    }
+
     /// Execute plugin control action
     fn control_plugin(&mut self, handle: &ModHandle, control: &PluginControl) -> ModResult<()>;
 
@@ -76,6 +237,19 @@ pub trait ModLoader: Send + Sync {
         Ok(serde_json::Value::Null)
     }
 
+    /// Call a MOD function without blocking the executor while it runs
+    ///
+    /// `ModBridgeSystem` awaits this for action callbacks. Default: calls
+    /// `call_function`.
+    async fn call_function_async(
+        &mut self,
+        handle: &ModHandle,
+        fn_name: &str,
+        args: Vec<serde_json::Value>,
+    ) -> ModResult<serde_json::Value> {
+        self.call_function(handle, fn_name, args)
+    }
+
     /// Drain queued plugin control commands
     ///
     /// This is called by `PluginControlSystem` to retrieve commands
@@ -94,6 +268,61 @@ pub trait ModLoader: Send + Sync {
         Vec::new() // Default: no events
     }
 
+    /// Drain queued localization contributions
+    ///
+    /// This is called by `ModEventSystem`, which merges them into the
+    /// `Localization` resource.
+    fn drain_strings(&mut self) -> Vec<ModStrings> {
+        Vec::new() // Default: MODs can't register strings
+    }
+
+    /// Drain queued player action registrations
+    ///
+    /// This is called by `ModBridgeSystem`, which adds them to the
+    /// `ActionRegistry` and the `ModActions` menu.
+    fn drain_actions(&mut self) -> Vec<ModActionDefinition> {
+        Vec::new() // Default: MODs can't define actions
+    }
+
+    /// Order in which the callbacks of loaded MODs run
+    ///
+    /// Set by `ModLoadSystem` whenever the loaded MODs change, see
+    /// [`dispatch_order`](crate::modding::dispatch_order). Default: ignored.
+    fn set_dispatch_order(&mut self, _order: &[String]) {}
+
+    /// Drain queued MOD log lines
+    ///
+    /// This is called by `ModEventSystem`, which publishes them as
+    /// `ModLogEvent`.
+    fn drain_logs(&mut self) -> Vec<ModLogEntry> {
+        Vec::new() // Default: MOD logs are not captured
+    }
+
+    /// Decide the permissions of MODs loaded afterwards
+    ///
+    /// Set by `ModSystemPlugin` before its first load. Loaders supporting
+    /// permissions check each MOD's [`ModPermissions`] in their host
+    /// functions; see [`permissions`](crate::modding::permissions).
+    /// Default: ignored, MODs get the full API.
+    fn set_permission_policy(&mut self, _policy: ModPermissionPolicy) {}
+
+    /// Drain calls refused by MOD permissions
+    ///
+    /// This is called by `ModEventSystem`, which publishes them as
+    /// `ModPermissionDenied`.
+    fn drain_denials(&mut self) -> Vec<ModPermissionDenied> {
+        Vec::new() // Default: nothing is refused
+    }
+
+    /// Drain errors of MOD callbacks run by the loader itself
+    ///
+    /// Loaders queue failures of event callbacks, scheduled callbacks and
+    /// hot reloads here instead of printing them. This is called by
+    /// `ModEventSystem`, which publishes them as `ModRuntimeErrorEvent`.
+    fn drain_errors(&mut self) -> Vec<ModRuntimeErrorEvent> {
+        Vec::new() // Default: errors are not reported
+    }
+
     /// Dispatch an event to subscribers
     ///
     /// This is called by `ModEventSystem` to deliver DynamicEvents
@@ -109,6 +338,67 @@ pub trait ModLoader: Send + Sync {
         0 // Default: no subscribers
     }
 
+    /// Advance callbacks scheduled by MODs by one turn
+    ///
+    /// Called by `ModBridgeSystem` for every `DayChanged` event with the new
+    /// day as `turn`. Callbacks whose delay runs out are invoked with `turn`.
+    ///
+    /// Returns the number of callbacks that ran.
+    fn tick_schedules(&mut self, turn: u64) -> usize {
+        let _ = turn;
+        0 // Default: MODs can't schedule callbacks
+    }
+
+    /// Export persistent MOD data for saving
+    ///
+    /// Returns one JSON value per MOD id. This is called by `SaveLoadSystem`
+    /// to embed MOD data in save snapshots.
+    fn export_state(&self) -> HashMap<String, serde_json::Value> {
+        HashMap::new() // Default: nothing to persist
+    }
+
+    /// Import persistent MOD data previously returned by `export_state()`
+    ///
+    /// Replaces the current data. MODs loaded or reloaded afterwards see the
+    /// imported data from `on_init()` onwards.
+    fn import_state(&mut self, state: HashMap<String, serde_json::Value>) {
+        let _ = state; // Default: no-op
+    }
+
+    /// Draw MOD random numbers from per-MOD streams of `seed`
+    ///
+    /// Called by `ModLoadSystem` whenever the `MasterSeed` resource changes.
+    /// Each MOD then draws from [`ModRng::for_mod`](crate::modding::ModRng::for_mod);
+    /// setting the same seed again keeps the stream positions, a different
+    /// one restarts every stream. `None` falls back to the loader's own
+    /// generator. Default: ignored.
+    fn set_master_seed(&mut self, seed: Option<MasterSeed>) {
+        let _ = seed;
+    }
+
+    /// Positions of the per-MOD random streams, by MOD id
+    ///
+    /// Saved alongside `export_state()` and written to replay headers.
+    fn export_rng(&self) -> HashMap<String, u64> {
+        HashMap::new() // Default: no per-MOD streams
+    }
+
+    /// Continue the per-MOD random streams from `export_rng()` positions
+    ///
+    /// Replaces the current positions; MODs without one start their stream
+    /// from the beginning.
+    fn import_rng(&mut self, positions: HashMap<String, u64>) {
+        let _ = positions; // Default: no-op
+    }
+
+    /// Refresh the plugin parameters MODs can read
+    ///
+    /// Called by `ModBridgeSystem` every update with the current values of the
+    /// MOD-controllable plugin configs, so scripts can read before they write.
+    fn sync_plugin_params(&mut self, params: &PluginParams) {
+        let _ = params; // Default: MODs can't read plugin state
+    }
+
     /// Clone this loader (for dynamic dispatch)
     fn clone_box(&self) -> Box<dyn ModLoader>;
 }
@@ -0,0 +1,403 @@
+//! MOD manifests and dependency declarations
+//!
+//! A directory MOD ships a `mod.toml` next to its scripts:
+//!
+//! ```toml
+//! name = "better_loot"
+//! version = "1.0.0"
+//! author = "someone"
+//! description = "More loot, better loot"
+//! entry = "main.rhai"            # default: main.rhai
+//! depends = ["core_tweaks >= 1.2"]
+//! assets = ["tables/loot.json"]
+//! priority = 0                   # dispatch order, see `order`
+//! after = ["core_tweaks"]
+//!
+//! [permissions]                  # optional, see `permissions`
+//! plugins = ["loot"]
+//! signature = "9f2c…"
+//! ```
+
+use crate::modding::error::{ModError, ModResult};
+use crate::modding::loader::{ModHandle, ModMetadata};
+use crate::modding::permissions::SignedPermissions;
+use std::cmp::Ordering;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// File name of the manifest inside a MOD directory
+pub const MANIFEST_FILE: &str = "mod.toml";
+
+/// Contents of a `mod.toml`
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModManifest {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Entry script, relative to the MOD directory
+    #[serde(default = "default_entry")]
+    pub entry: PathBuf,
+    /// Dependency declarations such as `"core_tweaks >= 1.2"`
+    #[serde(default)]
+    pub depends: Vec<String>,
+    /// Asset paths, relative to the MOD directory
+    #[serde(default)]
+    pub assets: Vec<PathBuf>,
+    /// Dispatch priority, see [`dispatch_order`](crate::modding::dispatch_order)
+    #[serde(default)]
+    pub priority: i32,
+    /// MODs whose callbacks must run before this MOD's
+    #[serde(default)]
+    pub after: Vec<String>,
+    /// Signed permission grant, see [`permissions`](crate::modding::permissions)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<SignedPermissions>,
+}
+
+fn default_entry() -> PathBuf {
+    PathBuf::from("main.rhai")
+}
+
+impl ModManifest {
+    /// Parse a manifest from TOML text
+    pub fn parse(content: &str) -> ModResult<Self> {
+        toml::from_str(content)
+            .map_err(|e| ModError::InvalidFormat(format!("Invalid {}: {}", MANIFEST_FILE, e)))
+    }
+
+    /// Read `mod.toml` from a MOD directory
+    pub fn from_dir(dir: &Path) -> ModResult<Self> {
+        let path = dir.join(MANIFEST_FILE);
+        let content = std::fs::read_to_string(&path).map_err(|e| {
+            ModError::LoadFailed(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        Self::parse(&content)
+    }
+
+    /// Parsed dependency declarations
+    pub fn dependencies(&self) -> ModResult<Vec<ModDependency>> {
+        self.depends
+            .iter()
+            .map(|d| ModDependency::parse(d))
+            .collect()
+    }
+
+    /// Metadata the manifest declares, as a loaded MOD would report it
+    pub fn metadata(&self) -> ModResult<ModMetadata> {
+        Ok(ModMetadata {
+            name: self.name.clone(),
+            version: self.version.clone(),
+            author: self.author.clone(),
+            description: self.description.clone(),
+            dependencies: self.dependencies()?,
+            priority: self.priority,
+            after: self.after.clone(),
+        })
+    }
+
+    /// Absolute path of the entry script for a MOD in `dir`
+    pub fn entry_path(&self, dir: &Path) -> PathBuf {
+        dir.join(&self.entry)
+    }
+
+    /// Absolute paths of the declared assets for a MOD in `dir`
+    pub fn asset_paths(&self, dir: &Path) -> Vec<PathBuf> {
+        self.assets.iter().map(|asset| dir.join(asset)).collect()
+    }
+}
+
+/// Comparison operator of a version requirement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum VersionOp {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+}
+
+impl VersionOp {
+    fn as_str(&self) -> &'static str {
+        match self {
+            VersionOp::Exact => "=",
+            VersionOp::Greater => ">",
+            VersionOp::GreaterEq => ">=",
+            VersionOp::Less => "<",
+            VersionOp::LessEq => "<=",
+        }
+    }
+}
+
+/// Version requirement such as `>= 1.2`
+///
+/// Versions are compared numerically per dot-separated component; missing
+/// components count as 0 (`1.2` == `1.2.0`) and pre-release/build suffixes
+/// are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct VersionReq {
+    pub op: VersionOp,
+    pub version: String,
+}
+
+impl VersionReq {
+    /// Parse `">= 1.2"`, `"=1.0.0"`, ... A bare version means `>=`.
+    pub fn parse(req: &str) -> ModResult<Self> {
+        let req = req.trim();
+        let (op, version) = [
+            (">=", VersionOp::GreaterEq),
+            ("<=", VersionOp::LessEq),
+            ("==", VersionOp::Exact),
+            (">", VersionOp::Greater),
+            ("<", VersionOp::Less),
+            ("=", VersionOp::Exact),
+        ]
+        .iter()
+        .find_map(|(prefix, op)| req.strip_prefix(prefix).map(|rest| (*op, rest)))
+        .unwrap_or((VersionOp::GreaterEq, req));
+
+        let version = version.trim();
+        if parse_version(version).is_none() {
+            return Err(ModError::InvalidFormat(format!(
+                "Invalid version requirement '{}'",
+                req
+            )));
+        }
+
+        Ok(Self {
+            op,
+            version: version.to_string(),
+        })
+    }
+
+    /// Whether `version` satisfies this requirement
+    ///
+    /// Unparseable versions never match.
+    pub fn matches(&self, version: &str) -> bool {
+        let Some(ordering) = compare_versions(version, &self.version) else {
+            return false;
+        };
+        match self.op {
+            VersionOp::Exact => ordering == Ordering::Equal,
+            VersionOp::Greater => ordering == Ordering::Greater,
+            VersionOp::GreaterEq => ordering != Ordering::Less,
+            VersionOp::Less => ordering == Ordering::Less,
+            VersionOp::LessEq => ordering != Ordering::Greater,
+        }
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.op.as_str(), self.version)
+    }
+}
+
+/// A declared dependency on another MOD
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ModDependency {
+    /// Name (or id) of the required MOD
+    pub name: String,
+    /// Required version, if any
+    pub requirement: Option<VersionReq>,
+}
+
+impl ModDependency {
+    /// Parse `"core_tweaks"` or `"core_tweaks >= 1.2"`
+    pub fn parse(declaration: &str) -> ModResult<Self> {
+        let declaration = declaration.trim();
+        let split = declaration
+            .find(['<', '>', '='])
+            .unwrap_or(declaration.len());
+        let name = declaration[..split].trim();
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(ModError::InvalidFormat(format!(
+                "Invalid dependency '{}'",
+                declaration
+            )));
+        }
+
+        let requirement = match declaration[split..].trim() {
+            "" => None,
+            req => Some(VersionReq::parse(req)?),
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            requirement,
+        })
+    }
+
+    /// Whether `handle` is the MOD this dependency refers to
+    pub fn refers_to(&self, handle: &ModHandle) -> bool {
+        handle.metadata.name == self.name || handle.id == self.name
+    }
+
+    /// Check this dependency against the currently loaded MODs
+    pub fn check(&self, dependent: &str, loaded: &[ModHandle]) -> ModResult<()> {
+        let Some(handle) = loaded.iter().find(|handle| self.refers_to(handle)) else {
+            return Err(ModError::MissingDependency {
+                mod_name: dependent.to_string(),
+                dependency: self.to_string(),
+            });
+        };
+
+        match &self.requirement {
+            Some(req) if !req.matches(&handle.metadata.version) => {
+                Err(ModError::IncompatibleDependency {
+                    mod_name: dependent.to_string(),
+                    dependency: self.to_string(),
+                    found: handle.metadata.version.clone(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for ModDependency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.requirement {
+            Some(req) => write!(f, "{} {}", self.name, req),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// Numeric components of a version (`"1.2.3-beta"` → `[1, 2, 3]`)
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    let core = version.split(['-', '+']).next()?;
+    core.split('.')
+        .map(|part| part.trim().parse::<u64>().ok())
+        .collect()
+}
+
+fn compare_versions(a: &str, b: &str) -> Option<Ordering> {
+    let a = parse_version(a)?;
+    let b = parse_version(b)?;
+    let len = a.len().max(b.len());
+    let component = |v: &[u64], i: usize| v.get(i).copied().unwrap_or(0);
+    Some(
+        (0..len)
+            .map(|i| component(&a, i).cmp(&component(&b, i)))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modding::ModBackend;
+
+    fn handle(name: &str, version: &str) -> ModHandle {
+        ModHandle {
+            id: name.to_string(),
+            metadata: ModMetadata {
+                name: name.to_string(),
+                version: version.to_string(),
+                author: None,
+                description: None,
+                dependencies: Vec::new(),
+                priority: 0,
+                after: Vec::new(),
+            },
+            backend: ModBackend::Rhai,
+        }
+    }
+
+    #[test]
+    fn test_parse_manifest_with_defaults() {
+        let manifest = ModManifest::parse(
+            r#"
+name = "better_loot"
+version = "1.0.0"
+depends = ["core_tweaks >= 1.2", "ui_kit"]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.entry, PathBuf::from("main.rhai"));
+        assert!(manifest.assets.is_empty());
+
+        let deps = manifest.dependencies().unwrap();
+        assert_eq!(deps[0].name, "core_tweaks");
+        assert_eq!(deps[0].to_string(), "core_tweaks >= 1.2");
+        assert_eq!(deps[1].requirement, None);
+    }
+
+    #[test]
+    fn test_manifest_metadata() {
+        let manifest = ModManifest::parse(
+            r#"
+name = "better_loot"
+version = "1.0.0"
+author = "someone"
+depends = ["core_tweaks >= 1.2"]
+priority = 3
+after = ["core_tweaks"]
+"#,
+        )
+        .unwrap();
+
+        let metadata = manifest.metadata().unwrap();
+        assert_eq!(metadata.name, "better_loot");
+        assert_eq!(metadata.author.as_deref(), Some("someone"));
+        assert_eq!(metadata.description, None);
+        assert_eq!(metadata.dependencies[0].name, "core_tweaks");
+        assert_eq!(metadata.priority, 3);
+        assert_eq!(metadata.after, vec!["core_tweaks".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_manifest_rejects_missing_fields() {
+        let err = ModManifest::parse("name = \"x\"").unwrap_err();
+        assert!(matches!(err, ModError::InvalidFormat(_)));
+    }
+
+    #[test]
+    fn test_version_requirements() {
+        let req = VersionReq::parse(">= 1.2").unwrap();
+        assert!(req.matches("1.2"));
+        assert!(req.matches("1.2.0"));
+        assert!(req.matches("2.0.0"));
+        assert!(!req.matches("1.1.9"));
+
+        assert!(VersionReq::parse("=1.0").unwrap().matches("1.0.0"));
+        assert!(VersionReq::parse("< 2").unwrap().matches("1.99.0"));
+        assert!(VersionReq::parse("1.5").unwrap().matches("1.6.0-beta"));
+        assert!(!VersionReq::parse("> 1.0").unwrap().matches("banana"));
+        assert!(VersionReq::parse(">= x").is_err());
+    }
+
+    #[test]
+    fn test_parse_dependency() {
+        let dep = ModDependency::parse("core_tweaks>=1.2").unwrap();
+        assert_eq!(dep.name, "core_tweaks");
+        assert_eq!(dep.requirement.unwrap().op, VersionOp::GreaterEq);
+
+        assert!(ModDependency::parse(">= 1.2").is_err());
+        assert!(ModDependency::parse("core tweaks").is_err());
+    }
+
+    #[test]
+    fn test_check_dependency_against_loaded_mods() {
+        let dep = ModDependency::parse("core_tweaks >= 1.2").unwrap();
+
+        let err = dep.check("better_loot", &[]).unwrap_err();
+        assert!(matches!(err, ModError::MissingDependency { .. }));
+
+        let err = dep
+            .check("better_loot", &[handle("core_tweaks", "1.1.0")])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "MOD 'better_loot' requires core_tweaks >= 1.2, but version 1.1.0 is loaded"
+        );
+
+        dep.check("better_loot", &[handle("core_tweaks", "1.2.3")])
+            .unwrap();
+    }
+}
@@ -27,31 +27,59 @@
 //! use issun::prelude::*;
 //!
 //! let game = GameBuilder::new()
-//!     .with_plugin(ModSystemPlugin::new().with_loader(RhaiLoader::new()))?
+//!     .with_plugin(
+//!         ModSystemPlugin::new()
+//!             .with_loader(RhaiLoader::new())
+//!             .with_loader(WasmLoader::new()?),
+//!     )?
 //!     .build()
 //!     .await?;
 //! ```
 
+pub mod actions;
 pub mod control;
 pub mod error;
 pub mod event_system;
 pub mod events;
 pub mod loader;
+pub mod manifest;
+pub mod multi_loader;
+pub mod order;
+pub mod permissions;
 pub mod plugin;
+pub mod rng;
+pub mod schema;
+pub mod source;
 
 #[cfg(test)]
 mod tests;
 
+pub use actions::{ModActionEntry, ModActions};
 pub use control::{PluginAction, PluginControl};
 pub use error::{ModError, ModResult};
 pub use event_system::ModEventSystem;
 pub use events::{
-    DynamicEvent, ModLoadFailedEvent, ModLoadRequested, ModLoadedEvent, ModUnloadRequested,
-    ModUnloadedEvent, PluginControlRequested, PluginDisabledEvent, PluginEnabledEvent,
-    PluginHookTriggeredEvent, PluginParameterChangedEvent,
+    DynamicEvent, ModDiscovered, ModLoadFailedEvent, ModLoadRequested, ModLoadedEvent, ModLogEvent,
+    ModPermissionDenied, ModReloadFailedEvent, ModReloadRequested, ModReloadedEvent,
+    ModRuntimeErrorEvent, ModStringConflict, ModUnloadRequested, ModUnloadedEvent,
+    PluginControlRequested, PluginDisabledEvent, PluginEnabledEvent, PluginHookTriggeredEvent,
+    PluginParameterChangedEvent,
 };
-pub use loader::{ModBackend, ModHandle, ModLoader, ModMetadata};
-pub use plugin::{ModLoaderState, ModSystemConfig, ModSystemPlugin};
+pub use loader::{
+    ModActionDefinition, ModBackend, ModHandle, ModLoader, ModLogEntry, ModLogLevel, ModMetadata,
+    ModStrings, PluginParams,
+};
+pub use manifest::{ModDependency, ModManifest, VersionOp, VersionReq, MANIFEST_FILE};
+pub use multi_loader::MultiLoader;
+pub use order::dispatch_order;
+pub use permissions::{ModPermissionPolicy, ModPermissions, SignedPermissions};
+pub use plugin::{
+    find_mod, load_mods, update_mod_systems, ModLoaderState, ModRegistry, ModSystemConfig,
+    ModSystemPlugin, ParamConflictPolicy,
+};
+pub use rng::{ModRng, ModRngSnapshot};
+pub use schema::{EventSchema, FieldType};
+pub use source::{content_hash, ModSource};
 
 // Backend loaders are NOT re-exported from issun core to avoid circular dependencies.
 // Users should import them directly from their respective crates:
@@ -0,0 +1,314 @@
+//! Several backend loaders behind one `ModLoader`
+//!
+//! `ModSystemPlugin` wraps its loaders in a [`MultiLoader`] when
+//! `with_loader` is called more than once, so Rhai and Wasm MODs can share
+//! one MOD directory.
+
+use crate::engine::rng::MasterSeed;
+use crate::modding::control::PluginControl;
+use crate::modding::error::{ModError, ModResult};
+use crate::modding::events::{ModPermissionDenied, ModRuntimeErrorEvent};
+use crate::modding::loader::{
+    ModActionDefinition, ModHandle, ModLoader, ModLogEntry, ModMetadata, ModStrings, PluginParams,
+};
+use crate::modding::permissions::ModPermissionPolicy;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Routes every MOD to the first loader that can load it
+///
+/// Loads and metadata reads go to the first loader whose
+/// [`can_load`](ModLoader::can_load) accepts the path; calls on a loaded MOD
+/// go to the loader that loaded it. Queues (`drain_*`) and event dispatch
+/// are combined over all loaders, in the order they were added.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut loader = MultiLoader::new()
+///     .with_loader(RhaiLoader::new())
+///     .with_loader(WasmLoader::new()?);
+/// let script = loader.load(Path::new("mods/easy_mode.rhai"))?;
+/// let component = loader.load(Path::new("mods/herald.wasm"))?;
+/// ```
+#[derive(Default)]
+pub struct MultiLoader {
+    loaders: Vec<Box<dyn ModLoader>>,
+    /// Index into `loaders` of the loader that loaded each MOD id
+    owners: HashMap<String, usize>,
+}
+
+impl MultiLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a loader (builder); earlier loaders are asked first
+    pub fn with_loader(mut self, loader: impl ModLoader + 'static) -> Self {
+        self.push(Box::new(loader));
+        self
+    }
+
+    /// Add a boxed loader; earlier loaders are asked first
+    pub fn push(&mut self, loader: Box<dyn ModLoader>) {
+        self.loaders.push(loader);
+    }
+
+    /// Number of loaders
+    pub fn len(&self) -> usize {
+        self.loaders.len()
+    }
+
+    /// Whether no loader was added
+    pub fn is_empty(&self) -> bool {
+        self.loaders.is_empty()
+    }
+
+    /// Index of the first loader accepting `path`
+    fn route(&self, path: &Path) -> ModResult<usize> {
+        self.loaders
+            .iter()
+            .position(|loader| loader.can_load(path))
+            .ok_or_else(|| ModError::InvalidFormat(format!("No loader accepts {}", path.display())))
+    }
+
+    /// Entries of MOD ids per loader; ids no loader loaded go to all of them
+    fn split_by_owner<T: Clone>(&self, entries: HashMap<String, T>) -> Vec<HashMap<String, T>> {
+        let mut per_loader = vec![HashMap::new(); self.loaders.len()];
+        for (mod_id, value) in entries {
+            match self.owners.get(&mod_id) {
+                Some(&index) => {
+                    per_loader[index].insert(mod_id, value);
+                }
+                None => {
+                    for own in &mut per_loader {
+                        own.insert(mod_id.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        per_loader
+    }
+
+    /// Loader that loaded `handle`
+    fn owner(&mut self, handle: &ModHandle) -> ModResult<&mut Box<dyn ModLoader>> {
+        let index = *self
+            .owners
+            .get(&handle.id)
+            .ok_or_else(|| ModError::NotFound(handle.id.clone()))?;
+        Ok(&mut self.loaders[index])
+    }
+}
+
+#[async_trait]
+impl ModLoader for MultiLoader {
+    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
+        let index = self.route(path)?;
+        let handle = self.loaders[index].load(path)?;
+        self.owners.insert(handle.id.clone(), index);
+        Ok(handle)
+    }
+
+    async fn load_async(&mut self, path: &Path) -> ModResult<ModHandle> {
+        let index = self.route(path)?;
+        let handle = self.loaders[index].load_async(path).await?;
+        self.owners.insert(handle.id.clone(), index);
+        Ok(handle)
+    }
+
+    fn peek_metadata(&mut self, path: &Path) -> ModResult<ModMetadata> {
+        let index = self.route(path)?;
+        self.loaders[index].peek_metadata(path)
+    }
+
+    fn file_extensions(&self) -> Vec<&'static str> {
+        let mut extensions = Vec::new();
+        for extension in self
+            .loaders
+            .iter()
+            .flat_map(|loader| loader.file_extensions())
+        {
+            if !extensions.contains(&extension) {
+                extensions.push(extension);
+            }
+        }
+        extensions
+    }
+
+    fn can_load(&self, path: &Path) -> bool {
+        self.loaders.iter().any(|loader| loader.can_load(path))
+    }
+
+    fn unload(&mut self, handle: &ModHandle) -> ModResult<()> {
+        self.owner(handle)?.unload(handle)?;
+        self.owners.remove(&handle.id);
+        Ok(())
+    }
+
+    fn reload(&mut self, handle: &ModHandle) -> ModResult<ModHandle> {
+        self.owner(handle)?.reload(handle)
+    }
+
+    fn update(&mut self, handle: &ModHandle, tick: u64) -> ModResult<()> {
+        self.owner(handle)?.update(handle, tick)
+    }
+
+    fn control_plugin(&mut self, handle: &ModHandle, control: &PluginControl) -> ModResult<()> {
+        self.owner(handle)?.control_plugin(handle, control)
+    }
+
+    fn call_function(
+        &mut self,
+        handle: &ModHandle,
+        fn_name: &str,
+        args: Vec<serde_json::Value>,
+    ) -> ModResult<serde_json::Value> {
+        self.owner(handle)?.call_function(handle, fn_name, args)
+    }
+
+    async fn call_function_async(
+        &mut self,
+        handle: &ModHandle,
+        fn_name: &str,
+        args: Vec<serde_json::Value>,
+    ) -> ModResult<serde_json::Value> {
+        self.owner(handle)?
+            .call_function_async(handle, fn_name, args)
+            .await
+    }
+
+    fn drain_commands(&mut self) -> Vec<PluginControl> {
+        self.loaders
+            .iter_mut()
+            .flat_map(|loader| loader.drain_commands())
+            .collect()
+    }
+
+    fn drain_events(&mut self) -> Vec<(String, serde_json::Value)> {
+        self.loaders
+            .iter_mut()
+            .flat_map(|loader| loader.drain_events())
+            .collect()
+    }
+
+    fn drain_strings(&mut self) -> Vec<ModStrings> {
+        self.loaders
+            .iter_mut()
+            .flat_map(|loader| loader.drain_strings())
+            .collect()
+    }
+
+    fn drain_actions(&mut self) -> Vec<ModActionDefinition> {
+        self.loaders
+            .iter_mut()
+            .flat_map(|loader| loader.drain_actions())
+            .collect()
+    }
+
+    fn set_dispatch_order(&mut self, order: &[String]) {
+        for (index, loader) in self.loaders.iter_mut().enumerate() {
+            let own: Vec<String> = order
+                .iter()
+                .filter(|id| self.owners.get(*id) == Some(&index))
+                .cloned()
+                .collect();
+            loader.set_dispatch_order(&own);
+        }
+    }
+
+    fn drain_logs(&mut self) -> Vec<ModLogEntry> {
+        self.loaders
+            .iter_mut()
+            .flat_map(|loader| loader.drain_logs())
+            .collect()
+    }
+
+    fn set_permission_policy(&mut self, policy: ModPermissionPolicy) {
+        for loader in &mut self.loaders {
+            loader.set_permission_policy(policy.clone());
+        }
+    }
+
+    fn drain_denials(&mut self) -> Vec<ModPermissionDenied> {
+        self.loaders
+            .iter_mut()
+            .flat_map(|loader| loader.drain_denials())
+            .collect()
+    }
+
+    fn drain_errors(&mut self) -> Vec<ModRuntimeErrorEvent> {
+        self.loaders
+            .iter_mut()
+            .flat_map(|loader| loader.drain_errors())
+            .collect()
+    }
+
+    fn dispatch_event(&mut self, event_type: &str, event_data: &serde_json::Value) -> usize {
+        self.loaders
+            .iter_mut()
+            .map(|loader| loader.dispatch_event(event_type, event_data))
+            .sum()
+    }
+
+    fn tick_schedules(&mut self, turn: u64) -> usize {
+        self.loaders
+            .iter_mut()
+            .map(|loader| loader.tick_schedules(turn))
+            .sum()
+    }
+
+    fn export_state(&self) -> HashMap<String, serde_json::Value> {
+        self.loaders
+            .iter()
+            .flat_map(|loader| loader.export_state())
+            .collect()
+    }
+
+    /// Hands every loader the data of its MODs, and the data of MODs not
+    /// loaded yet to all of them
+    fn import_state(&mut self, state: HashMap<String, serde_json::Value>) {
+        let per_loader = self.split_by_owner(state);
+        for (loader, own) in self.loaders.iter_mut().zip(per_loader) {
+            loader.import_state(own);
+        }
+    }
+
+    fn set_master_seed(&mut self, seed: Option<MasterSeed>) {
+        for loader in &mut self.loaders {
+            loader.set_master_seed(seed);
+        }
+    }
+
+    fn export_rng(&self) -> HashMap<String, u64> {
+        self.loaders
+            .iter()
+            .flat_map(|loader| loader.export_rng())
+            .collect()
+    }
+
+    /// Split like `import_state`
+    fn import_rng(&mut self, positions: HashMap<String, u64>) {
+        let per_loader = self.split_by_owner(positions);
+        for (loader, own) in self.loaders.iter_mut().zip(per_loader) {
+            loader.import_rng(own);
+        }
+    }
+
+    fn sync_plugin_params(&mut self, params: &PluginParams) {
+        for loader in &mut self.loaders {
+            loader.sync_plugin_params(params);
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn ModLoader> {
+        Box::new(Self {
+            loaders: self
+                .loaders
+                .iter()
+                .map(|loader| loader.clone_box())
+                .collect(),
+            owners: self.owners.clone(),
+        })
+    }
+}
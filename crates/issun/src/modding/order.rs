@@ -0,0 +1,136 @@
+//! Dispatch order of MOD callbacks
+//!
+//! When several MODs react to the same event, their callbacks run in the
+//! dispatch order. A MOD can influence it from `mod.toml` or `get_metadata()`:
+//!
+//! ```toml
+//! priority = 10              # higher runs earlier, default 0
+//! after = ["core_balance"]   # always run after these MODs
+//! ```
+//!
+//! `after` constraints win over priority; MODs with the same priority keep
+//! their load order. Constraints naming a MOD that isn't loaded are ignored.
+
+use crate::modding::error::{ModError, ModResult};
+use crate::modding::loader::ModHandle;
+use std::cmp::Reverse;
+
+/// Ids of `mods` (given in load order) in dispatch order
+///
+/// Fails with `ModError::OrderingCycle` if the `after` constraints form a cycle.
+pub fn dispatch_order(mods: &[ModHandle]) -> ModResult<Vec<String>> {
+    // deps[i] = MODs that must run before i
+    let deps: Vec<Vec<usize>> = mods
+        .iter()
+        .map(|handle| {
+            handle
+                .metadata
+                .after
+                .iter()
+                .filter_map(|id| mods.iter().position(|other| &other.id == id))
+                .collect()
+        })
+        .collect();
+
+    let mut order = Vec::with_capacity(mods.len());
+    let mut done = vec![false; mods.len()];
+    while let Some(next) = (0..mods.len())
+        .filter(|&i| !done[i] && deps[i].iter().all(|&d| done[d]))
+        .min_by_key(|&i| Reverse(mods[i].metadata.priority))
+    {
+        done[next] = true;
+        order.push(mods[next].id.clone());
+    }
+
+    match find_cycle(&deps, &done) {
+        Some(cycle) => Err(ModError::OrderingCycle(
+            cycle.into_iter().map(|i| mods[i].id.clone()).collect(),
+        )),
+        None => Ok(order),
+    }
+}
+
+/// A cycle through the unfinished nodes, closed by repeating its first node
+///
+/// `deps[i]` lists the nodes `i` waits for; every unfinished node must wait
+/// for at least one other unfinished node. Returns `None` once all are done.
+pub(crate) fn find_cycle(deps: &[Vec<usize>], done: &[bool]) -> Option<Vec<usize>> {
+    let start = done.iter().position(|d| !d)?;
+    let mut path = vec![start];
+    loop {
+        let current = *path.last().unwrap();
+        let next = deps[current]
+            .iter()
+            .copied()
+            .find(|&d| !done[d])
+            .expect("unfinished node has an unfinished dependency");
+        if let Some(pos) = path.iter().position(|&p| p == next) {
+            let mut cycle = path.split_off(pos);
+            cycle.push(next);
+            return Some(cycle);
+        }
+        path.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modding::{ModBackend, ModMetadata};
+
+    fn handle(id: &str, priority: i32, after: &[&str]) -> ModHandle {
+        ModHandle {
+            id: id.to_string(),
+            metadata: ModMetadata {
+                name: id.to_string(),
+                version: "1.0.0".to_string(),
+                author: None,
+                description: None,
+                dependencies: Vec::new(),
+                priority,
+                after: after.iter().map(|id| id.to_string()).collect(),
+            },
+            backend: ModBackend::Rhai,
+        }
+    }
+
+    #[test]
+    fn test_after_overrides_load_order_and_priority() {
+        let mods = [
+            handle("balance_plus", 5, &["core_balance"]),
+            handle("core_balance", 0, &[]),
+            handle("ui", 1, &["unloaded"]),
+        ];
+        assert_eq!(
+            dispatch_order(&mods).unwrap(),
+            vec!["ui", "core_balance", "balance_plus"]
+        );
+    }
+
+    #[test]
+    fn test_priority_ties_keep_load_order() {
+        let mods = [
+            handle("zeta", 0, &[]),
+            handle("alpha", 0, &[]),
+            handle("urgent", 3, &[]),
+        ];
+        assert_eq!(
+            dispatch_order(&mods).unwrap(),
+            vec!["urgent", "zeta", "alpha"]
+        );
+    }
+
+    #[test]
+    fn test_cycle_names_every_mod() {
+        let mods = [
+            handle("a", 0, &["b"]),
+            handle("b", 0, &["a"]),
+            handle("c", 0, &[]),
+        ];
+        let err = dispatch_order(&mods).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Dispatch order cycle between MODs: a -> b -> a"
+        );
+    }
+}
@@ -0,0 +1,431 @@
+//! Per-MOD permissions
+//!
+//! Every loaded MOD gets a [`ModPermissions`]: allow lists of the plugins it
+//! may control, the event types it may publish and, for Wasm MODs, the
+//! directories it may access. Loaders check them inside the host functions;
+//! a denied call queues nothing and is reported as a
+//! [`ModPermissionDenied`] event.
+//!
+//! MODs get the host's defaults, set with
+//! `ModSystemPlugin::with_default_permissions`. A MOD can ship its own in a
+//! `[permissions]` section of its manifest (`mod.toml`, or the sidecar
+//! `<name>.toml` of a single-file MOD), which only counts when signed by a
+//! key the host trusts (`ModSystemPlugin::with_trusted_key`):
+//!
+//! ```toml
+//! name = "better_loot"
+//! version = "1.0.0"
+//!
+//! [permissions]
+//! plugins = ["loot", "inventory"]
+//! events = ["loot:*"]
+//! filesystem = ["tables"]
+//! signature = "9f2c…"   # hex Ed25519 signature of `signing_payload`
+//! ```
+
+use crate::modding::control::{PluginAction, PluginControl};
+use crate::modding::error::{ModError, ModResult};
+use crate::modding::events::ModPermissionDenied;
+use crate::modding::manifest::ModManifest;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// What a MOD may do through the host API
+///
+/// Allow list entries are exact names, `"*"` for everything, or a prefix
+/// ending in `*` (`"loot:*"`). Plugin names match with or without the
+/// `issun:` prefix. The default allows nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModPermissions {
+    /// Plugins the MOD may enable, disable or configure
+    #[serde(default)]
+    pub plugins: Vec<String>,
+    /// Event types the MOD may publish
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// Directories a Wasm MOD may access, preopened under the path as
+    /// written; relative paths are resolved against the MOD's directory
+    #[serde(default)]
+    pub filesystem: Vec<PathBuf>,
+}
+
+impl ModPermissions {
+    /// Every plugin and event type, no filesystem access
+    pub fn all() -> Self {
+        Self {
+            plugins: vec!["*".to_string()],
+            events: vec!["*".to_string()],
+            filesystem: Vec::new(),
+        }
+    }
+
+    /// Nothing at all
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Allow controlling plugins matching `pattern` (builder)
+    pub fn with_plugin(mut self, pattern: impl Into<String>) -> Self {
+        self.plugins.push(pattern.into());
+        self
+    }
+
+    /// Allow publishing event types matching `pattern` (builder)
+    pub fn with_event(mut self, pattern: impl Into<String>) -> Self {
+        self.events.push(pattern.into());
+        self
+    }
+
+    /// Grant access to the directory `dir` (builder)
+    pub fn with_filesystem(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.filesystem.push(dir.into());
+        self
+    }
+
+    /// Whether the MOD may control `plugin`
+    pub fn allows_plugin(&self, plugin: &str) -> bool {
+        let plugin = plugin.strip_prefix("issun:").unwrap_or(plugin);
+        self.plugins
+            .iter()
+            .any(|pattern| matches(pattern.strip_prefix("issun:").unwrap_or(pattern), plugin))
+    }
+
+    /// Whether the MOD may publish events of `event_type`
+    pub fn allows_event(&self, event_type: &str) -> bool {
+        self.events
+            .iter()
+            .any(|pattern| matches(pattern, event_type))
+    }
+
+    /// `Err` naming the attempt unless `mod_id` may issue `control`
+    pub fn check_control(
+        &self,
+        mod_id: &str,
+        control: &PluginControl,
+    ) -> Result<(), ModPermissionDenied> {
+        if self.allows_plugin(&control.plugin_name) {
+            return Ok(());
+        }
+        let plugin = &control.plugin_name;
+        let action = match &control.action {
+            PluginAction::Enable => format!("enable plugin '{}'", plugin),
+            PluginAction::Disable => format!("disable plugin '{}'", plugin),
+            PluginAction::SetParameter { key, .. } => {
+                format!("set parameter '{}' of plugin '{}'", key, plugin)
+            }
+            PluginAction::TriggerHook { hook_name, .. } => {
+                format!("trigger hook '{}' of plugin '{}'", hook_name, plugin)
+            }
+        };
+        Err(ModPermissionDenied {
+            mod_id: mod_id.to_string(),
+            action,
+        })
+    }
+
+    /// `Err` naming the attempt unless `mod_id` may publish `event_type`
+    pub fn check_publish(&self, mod_id: &str, event_type: &str) -> Result<(), ModPermissionDenied> {
+        if self.allows_event(event_type) {
+            return Ok(());
+        }
+        Err(ModPermissionDenied {
+            mod_id: mod_id.to_string(),
+            action: format!("publish event '{}'", event_type),
+        })
+    }
+
+    /// Granted directories as (host path, guest path) for a MOD in `mod_dir`
+    pub fn filesystem_dirs(&self, mod_dir: &Path) -> Vec<(PathBuf, String)> {
+        self.filesystem
+            .iter()
+            .map(|dir| (mod_dir.join(dir), dir.to_string_lossy().into_owned()))
+            .collect()
+    }
+
+    /// Bytes a publisher signs to grant these permissions to a MOD
+    ///
+    /// Binds the grant to the MOD's name and version, so a signed section
+    /// can't be copied into another MOD.
+    pub fn signing_payload(&self, name: &str, version: &str) -> Vec<u8> {
+        let grants = serde_json::to_string(self).unwrap_or_default();
+        format!("issun-mod-permissions\n{}\n{}\n{}", name, version, grants).into_bytes()
+    }
+}
+
+/// Whether `name` matches an allow list entry
+fn matches(pattern: &str, name: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => name.starts_with(prefix),
+        None => pattern == name,
+    }
+}
+
+/// `[permissions]` section of a manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedPermissions {
+    #[serde(flatten)]
+    pub grants: ModPermissions,
+    /// Hex Ed25519 signature of
+    /// [`signing_payload`](ModPermissions::signing_payload)
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+/// How loaders decide each MOD's permissions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModPermissionPolicy {
+    /// Permissions of MODs without a trusted manifest section
+    pub defaults: ModPermissions,
+    /// Ed25519 public keys whose manifest signatures are trusted
+    pub trusted_keys: Vec<[u8; 32]>,
+}
+
+impl Default for ModPermissionPolicy {
+    /// Everything but filesystem access, as before permissions existed
+    fn default() -> Self {
+        Self {
+            defaults: ModPermissions::all(),
+            trusted_keys: Vec::new(),
+        }
+    }
+}
+
+impl ModPermissionPolicy {
+    /// Permissions of a MOD declared by `manifest`
+    ///
+    /// A `[permissions]` section replaces the defaults when its signature
+    /// verifies with a trusted key. Unsigned sections and signatures by
+    /// other keys are ignored; a malformed signature is an error.
+    pub fn resolve(&self, manifest: Option<&ModManifest>) -> ModResult<ModPermissions> {
+        let Some((manifest, section)) =
+            manifest.and_then(|manifest| Some((manifest, manifest.permissions.as_ref()?)))
+        else {
+            return Ok(self.defaults.clone());
+        };
+        let Some(signature) = &section.signature else {
+            return Ok(self.defaults.clone());
+        };
+        let signature = decode_hex(signature).ok_or_else(|| {
+            ModError::InvalidFormat(format!(
+                "Permission signature of MOD '{}' is not hex",
+                manifest.name
+            ))
+        })?;
+
+        let payload = section
+            .grants
+            .signing_payload(&manifest.name, &manifest.version);
+        let trusted = self.trusted_keys.iter().any(|key| {
+            ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, key)
+                .verify(&payload, &signature)
+                .is_ok()
+        });
+        Ok(if trusted {
+            section.grants.clone()
+        } else {
+            self.defaults.clone()
+        })
+    }
+
+    /// Permissions of the MOD at `path`: a MOD directory (its `mod.toml`) or
+    /// a MOD file (its sidecar `<name>.toml`, if any)
+    pub fn permissions_for(&self, path: &Path) -> ModResult<ModPermissions> {
+        if path.is_dir() {
+            return self.resolve(Some(&ModManifest::from_dir(path)?));
+        }
+        let sidecar = path.with_extension("toml");
+        if sidecar == path || !sidecar.is_file() {
+            return self.resolve(None);
+        }
+        let content = std::fs::read_to_string(&sidecar).map_err(|e| {
+            ModError::LoadFailed(format!("Failed to read {}: {}", sidecar.display(), e))
+        })?;
+        self.resolve(Some(&ModManifest::parse(&content)?))
+    }
+}
+
+/// Directory holding a MOD's files, which relative filesystem grants start from
+pub fn mod_dir(path: &Path) -> PathBuf {
+    if path.is_dir() {
+        return path.to_path_buf();
+    }
+    path.parent().map(Path::to_path_buf).unwrap_or_default()
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim();
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    fn key_pair(seed: u8) -> Ed25519KeyPair {
+        Ed25519KeyPair::from_seed_unchecked(&[seed; 32]).unwrap()
+    }
+
+    fn public_key(pair: &Ed25519KeyPair) -> [u8; 32] {
+        pair.public_key().as_ref().try_into().unwrap()
+    }
+
+    /// Manifest granting `grants`, signed by `pair`
+    fn signed_manifest(grants: &ModPermissions, pair: &Ed25519KeyPair) -> ModManifest {
+        let signature = pair.sign(&grants.signing_payload("better_loot", "1.0.0"));
+        let hex: String = signature
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        let mut manifest =
+            ModManifest::parse("name = \"better_loot\"\nversion = \"1.0.0\"").unwrap();
+        manifest.permissions = Some(SignedPermissions {
+            grants: grants.clone(),
+            signature: Some(hex),
+        });
+        manifest
+    }
+
+    #[test]
+    fn test_allow_lists_and_wildcards() {
+        let permissions = ModPermissions::none()
+            .with_plugin("combat")
+            .with_plugin("issun:loot*")
+            .with_event("loot:*");
+        assert!(permissions.allows_plugin("combat"));
+        assert!(permissions.allows_plugin("issun:combat"));
+        assert!(permissions.allows_plugin("loot_tables"));
+        assert!(!permissions.allows_plugin("save_load"));
+        assert!(permissions.allows_event("loot:dropped"));
+        assert!(!permissions.allows_event("LootDropped"));
+
+        assert!(ModPermissions::all().allows_plugin("save_load"));
+        assert!(ModPermissions::all().allows_event("Anything"));
+        assert!(!ModPermissions::none().allows_plugin("combat"));
+        assert!(!ModPermissions::none().allows_event("Anything"));
+    }
+
+    #[test]
+    fn test_denials_name_the_attempt() {
+        let permissions = ModPermissions::none().with_plugin("combat");
+        assert!(permissions
+            .check_control("cheats", &PluginControl::set_param("combat", "damage", 9))
+            .is_ok());
+
+        let denied = permissions
+            .check_control("cheats", &PluginControl::disable("save_load"))
+            .unwrap_err();
+        assert_eq!(denied.mod_id, "cheats");
+        assert_eq!(denied.action, "disable plugin 'save_load'");
+
+        let denied = permissions
+            .check_control("cheats", &PluginControl::set_param("save_load", "slots", 0))
+            .unwrap_err();
+        assert_eq!(denied.action, "set parameter 'slots' of plugin 'save_load'");
+
+        let denied = permissions
+            .check_publish("cheats", "GoldAdded")
+            .unwrap_err();
+        assert_eq!(denied.action, "publish event 'GoldAdded'");
+    }
+
+    #[test]
+    fn test_signed_manifest_replaces_defaults() {
+        let publisher = key_pair(1);
+        let grants = ModPermissions::none()
+            .with_plugin("loot")
+            .with_filesystem("tables");
+        let manifest = signed_manifest(&grants, &publisher);
+
+        let policy = ModPermissionPolicy {
+            defaults: ModPermissions::none(),
+            trusted_keys: vec![public_key(&publisher)],
+        };
+        assert_eq!(policy.resolve(Some(&manifest)).unwrap(), grants);
+        assert_eq!(policy.resolve(None).unwrap(), ModPermissions::none());
+
+        // Other keys, tampered grants and unsigned sections get the defaults
+        let untrusted = ModPermissionPolicy {
+            trusted_keys: vec![public_key(&key_pair(2))],
+            ..policy.clone()
+        };
+        assert_eq!(
+            untrusted.resolve(Some(&manifest)).unwrap(),
+            ModPermissions::none()
+        );
+
+        let mut tampered = manifest.clone();
+        if let Some(section) = &mut tampered.permissions {
+            section.grants.plugins.push("save_load".to_string());
+        }
+        assert_eq!(
+            policy.resolve(Some(&tampered)).unwrap(),
+            ModPermissions::none()
+        );
+
+        let mut unsigned = manifest.clone();
+        if let Some(section) = &mut unsigned.permissions {
+            section.signature = None;
+        }
+        assert_eq!(
+            policy.resolve(Some(&unsigned)).unwrap(),
+            ModPermissions::none()
+        );
+
+        let mut malformed = manifest;
+        if let Some(section) = &mut malformed.permissions {
+            section.signature = Some("not hex".to_string());
+        }
+        assert!(matches!(
+            policy.resolve(Some(&malformed)),
+            Err(ModError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_permissions_section_parses() {
+        let manifest = ModManifest::parse(
+            r#"
+name = "better_loot"
+version = "1.0.0"
+
+[permissions]
+plugins = ["loot"]
+events = ["loot:*"]
+signature = "00ff"
+"#,
+        )
+        .unwrap();
+        let section = manifest.permissions.unwrap();
+        assert_eq!(section.grants.plugins, vec!["loot"]);
+        assert_eq!(section.grants.events, vec!["loot:*"]);
+        assert!(section.grants.filesystem.is_empty());
+        assert_eq!(section.signature.as_deref(), Some("00ff"));
+
+        let plain = ModManifest::parse("name = \"x\"\nversion = \"1\"").unwrap();
+        assert_eq!(plain.permissions, None);
+    }
+
+    #[test]
+    fn test_filesystem_dirs_resolve_against_the_mod_dir() {
+        let permissions = ModPermissions::none().with_filesystem("tables");
+        assert_eq!(
+            permissions.filesystem_dirs(Path::new("mods/better_loot")),
+            vec![(
+                PathBuf::from("mods/better_loot/tables"),
+                "tables".to_string()
+            )]
+        );
+        assert_eq!(
+            mod_dir(Path::new("mods/pathfinder.wasm")),
+            PathBuf::from("mods")
+        );
+    }
+}
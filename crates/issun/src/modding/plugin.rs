@@ -1,14 +1,23 @@
 //! MOD System Plugin for ISSUN integration
 
-use crate::context::ResourceContext;
+use crate::context::{ResourceContext, SystemContext};
+use crate::engine::rng::MasterSeed;
 use crate::engine::ModBridgeSystem;
 use crate::event::EventBus;
+use crate::localization::Localization;
 use crate::modding::events::*;
-use crate::modding::{ModEventSystem, ModHandle, ModLoader, PluginAction};
+use crate::modding::order::find_cycle;
+use crate::modding::{
+    dispatch_order, ModActions, ModDependency, ModError, ModEventSystem, ModHandle, ModLoader,
+    ModLogEntry, ModLogLevel, ModManifest, ModPermissionPolicy, ModPermissions, ModSource,
+    MultiLoader, PluginAction, MANIFEST_FILE,
+};
 use crate::plugin::{Plugin, PluginBuilder, PluginBuilderExt};
 use crate::system::System;
 use async_trait::async_trait;
 use std::any::Any;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// MOD System Plugin
 ///
@@ -26,9 +35,76 @@ use std::any::Any;
 ///     .build()
 ///     .await?;
 /// ```
-#[derive(Default)]
+///
+/// # Startup MODs
+///
+/// With [`with_mod_dir`](ModSystemPlugin::with_mod_dir), every MOD in the
+/// directory is loaded while the plugin is built: files with an extension
+/// a loader accepts (`*.rhai` for `RhaiLoader`, `*.wasm` for `WasmLoader`)
+/// and subdirectories with a `mod.toml`, sorted by file name and then by
+/// `mod.toml` dependencies. A MOD that fails to load doesn't stop the others.
+/// `ModLoadSystem` publishes a `ModLoadedEvent` or `ModLoadFailedEvent` per
+/// MOD on its first update, and [`ModRegistry`] lists the loaded handles.
+///
+/// Before those, it publishes a `ModDiscovered` per MOD of the directory
+/// with the metadata the loader could read without running the MOD. With
+/// [`with_auto_load(false)`](ModSystemPlugin::with_auto_load) nothing is
+/// loaded at startup: the game shows the discovered MODs and sends
+/// `ModLoadRequested` for the ones the player enabled.
+///
+/// Games drive the MOD systems with [`update_mod_systems`] once per frame.
+///
+/// # Several backends
+///
+/// Call `with_loader` once per backend to mix Rhai and Wasm MODs:
+///
+/// ```ignore
+/// ModSystemPlugin::new()
+///     .with_loader(RhaiLoader::new())
+///     .with_loader(WasmLoader::new()?)
+///     .with_mod_dir("mods")
+/// ```
+///
+/// Startup MODs and `ModLoadRequested` paths go to the loader that can load
+/// them; a path no loader accepts fails with `ModLoadFailedEvent`.
+///
+/// # Permissions
+///
+/// MODs can control every plugin and publish every event type unless
+/// [`with_default_permissions`](ModSystemPlugin::with_default_permissions)
+/// narrows that. MODs whose manifest carries a `[permissions]` section
+/// signed with a key from
+/// [`with_trusted_key`](ModSystemPlugin::with_trusted_key) get that section
+/// instead. Refused calls are published as `ModPermissionDenied`:
+///
+/// ```ignore
+/// ModSystemPlugin::new()
+///     .with_loader(RhaiLoader::new())
+///     .with_default_permissions(ModPermissions::none().with_plugin("loot"))
+///     .with_trusted_key(PUBLISHER_KEY)
+/// ```
 pub struct ModSystemPlugin {
-    loader: Option<Box<dyn ModLoader>>,
+    loaders: Vec<Box<dyn ModLoader>>,
+    mod_dir: Option<PathBuf>,
+    param_conflicts: ParamConflictPolicy,
+    auto_load: bool,
+    master_seed: Option<MasterSeed>,
+    reset_rng_on_reload: bool,
+    permissions: ModPermissionPolicy,
+}
+
+impl Default for ModSystemPlugin {
+    fn default() -> Self {
+        Self {
+            loaders: Vec::new(),
+            mod_dir: None,
+            param_conflicts: ParamConflictPolicy::default(),
+            auto_load: true,
+            master_seed: None,
+            reset_rng_on_reload: false,
+            permissions: ModPermissionPolicy::default(),
+        }
+    }
 }
 
 impl ModSystemPlugin {
@@ -36,9 +112,82 @@ impl ModSystemPlugin {
         Self::default()
     }
 
-    /// Set the backend loader (Rhai or Wasm)
+    /// Add a backend loader (Rhai or Wasm)
+    ///
+    /// Call once per backend; with several loaders each MOD goes to the
+    /// first one whose `can_load` accepts it (see [`MultiLoader`]).
     pub fn with_loader(mut self, loader: impl ModLoader + 'static) -> Self {
-        self.loader = Some(Box::new(loader));
+        self.loaders.push(Box::new(loader));
+        self
+    }
+
+    /// The loader registered in `ModLoaderState`, if any
+    fn build_loader(&self) -> Option<Box<dyn ModLoader>> {
+        match self.loaders.as_slice() {
+            [] => None,
+            [loader] => Some(loader.clone_box()),
+            loaders => {
+                let mut multi = MultiLoader::new();
+                for loader in loaders {
+                    multi.push(loader.clone_box());
+                }
+                Some(Box::new(multi))
+            }
+        }
+    }
+
+    /// Load every MOD in `dir` at startup
+    pub fn with_mod_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.mod_dir = Some(dir.into());
+        self
+    }
+
+    /// Whether the MODs of the MOD directory load at startup (default: true)
+    ///
+    /// When false they are only discovered.
+    pub fn with_auto_load(mut self, auto_load: bool) -> Self {
+        self.auto_load = auto_load;
+        self
+    }
+
+    /// Choose which MOD wins when several set the same plugin parameter
+    pub fn with_param_conflicts(mut self, policy: ParamConflictPolicy) -> Self {
+        self.param_conflicts = policy;
+        self
+    }
+
+    /// Draw MOD random numbers from per-MOD streams of `seed`
+    ///
+    /// Registers `seed` as the `MasterSeed` resource and seeds the loader
+    /// before the startup MODs run their `on_init()`. Games that insert the
+    /// `MasterSeed` resource themselves get the same streams from the first
+    /// update on.
+    pub fn with_master_seed(mut self, seed: MasterSeed) -> Self {
+        self.master_seed = Some(seed);
+        self
+    }
+
+    /// Restart a MOD's random stream when it is reloaded (default: false)
+    ///
+    /// By default a reloaded MOD continues its stream where it was.
+    pub fn with_rng_reset_on_reload(mut self, reset: bool) -> Self {
+        self.reset_rng_on_reload = reset;
+        self
+    }
+
+    /// Permissions of every MOD without a trusted signed manifest
+    ///
+    /// Default: [`ModPermissions::all`], every plugin and event type but no
+    /// filesystem access.
+    pub fn with_default_permissions(mut self, permissions: ModPermissions) -> Self {
+        self.permissions.defaults = permissions;
+        self
+    }
+
+    /// Trust `[permissions]` manifest sections signed with this Ed25519
+    /// public key
+    pub fn with_trusted_key(mut self, public_key: [u8; 32]) -> Self {
+        self.permissions.trusted_keys.push(public_key);
         self
     }
 }
@@ -50,17 +199,57 @@ impl Plugin for ModSystemPlugin {
     }
 
     fn build(&self, builder: &mut dyn PluginBuilder) {
-        builder.register_resource(ModSystemConfig::default());
+        let mut config = ModSystemConfig {
+            param_conflicts: self.param_conflicts,
+            auto_load: self.auto_load,
+            reset_rng_on_reload: self.reset_rng_on_reload,
+            ..ModSystemConfig::default()
+        };
+        let mut discovered = Vec::new();
+        let mut startup = Vec::new();
 
-        if let Some(loader) = &self.loader {
+        if let Some(mut loader) = self.build_loader() {
+            if self.master_seed.is_some() {
+                loader.set_master_seed(self.master_seed);
+            }
+            loader.set_permission_policy(self.permissions.clone());
+            let mut loaded_mods = Vec::new();
+            if let Some(dir) = &self.mod_dir {
+                config.mod_dir = dir.display().to_string();
+                let requests = scan_mod_dir(dir, &loader.file_extensions());
+                discovered = requests
+                    .iter()
+                    .map(|request| discover(loader.as_mut(), &request.path))
+                    .collect();
+                if self.auto_load {
+                    startup = load_batch(loader.as_mut(), &mut loaded_mods, requests);
+                }
+            }
+
+            let mut registry = ModRegistry::new(loaded_mods.clone());
+            for (handle, source) in startup.iter().flatten() {
+                registry = registry.with_source(&handle.id, source.clone());
+            }
+            loader.set_dispatch_order(registry.dispatch_order());
+            builder.register_runtime_state(registry);
             builder.register_runtime_state(ModLoaderState {
-                loader: loader.clone_box(),
-                loaded_mods: Vec::new(),
+                loader,
+                loaded_mods,
             });
         }
+        builder.register_resource(config);
+        if let Some(seed) = self.master_seed {
+            builder.register_resource(seed);
+        }
+        builder.register_runtime_state(ModActions::new());
 
         // Register all four systems
-        builder.register_system(Box::new(ModLoadSystem));
+        builder.register_system(Box::new(ModLoadSystem {
+            discovered,
+            startup,
+            master_seed: self.master_seed,
+            warned_unseeded: false,
+        }));
         builder.register_system(Box::new(PluginControlSystem));
         builder.register_system(Box::new(ModEventSystem::new()));
         builder.register_system(Box::new(ModBridgeSystem::new()));
@@ -73,6 +262,12 @@ pub struct ModSystemConfig {
     pub mod_dir: String,
     pub hot_reload: bool,
     pub auto_load: bool,
+    /// Which MOD wins when several set the same plugin parameter
+    #[serde(default)]
+    pub param_conflicts: ParamConflictPolicy,
+    /// Restart a MOD's random stream when it is reloaded
+    #[serde(default)]
+    pub reset_rng_on_reload: bool,
 }
 
 impl Default for ModSystemConfig {
@@ -81,10 +276,27 @@ impl Default for ModSystemConfig {
             mod_dir: "mods".to_string(),
             hot_reload: false,
             auto_load: true,
+            param_conflicts: ParamConflictPolicy::default(),
+            reset_rng_on_reload: false,
         }
     }
 }
 
+/// Resolution of MODs setting the same plugin parameter
+///
+/// Applied by `ModBridgeSystem` per `(plugin, key)`. Either way a conflict
+/// is reported as a warning `ModLogEvent` for the MOD that lost or overrode.
+/// Commands from the host are always applied and never claim a key.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParamConflictPolicy {
+    /// Every change is applied; the latest MOD to set a key wins
+    #[default]
+    LastWins,
+    /// The first MOD to set a key owns it until that MOD is unloaded
+    FirstWins,
+}
+
 impl crate::resources::Resource for ModSystemConfig {}
 
 /// Runtime state for MOD system
@@ -93,18 +305,137 @@ pub struct ModLoaderState {
     pub loaded_mods: Vec<ModHandle>,
 }
 
+/// MODs currently loaded, for display
+///
+/// Registered by `ModSystemPlugin` when it has a loader. `ModLoadSystem`
+/// keeps it in sync with loads, reloads and unloads, including where each
+/// MOD was loaded from and the hash of its files.
+#[derive(Debug, Clone, Default)]
+pub struct ModRegistry {
+    handles: Vec<ModHandle>,
+    dispatch_order: Vec<String>,
+    sources: HashMap<String, ModSource>,
+}
+
+impl ModRegistry {
+    /// Registry of `handles` (in load order) with their dispatch order
+    ///
+    /// Falls back to load order if the `after` constraints form a cycle,
+    /// which `ModLoadSystem` rejects at load time.
+    pub fn new(handles: Vec<ModHandle>) -> Self {
+        let dispatch_order = dispatch_order(&handles).unwrap_or_else(|e| {
+            eprintln!("[MOD System] {}; dispatching in load order", e);
+            handles.iter().map(|handle| handle.id.clone()).collect()
+        });
+        Self {
+            handles,
+            dispatch_order,
+            sources: HashMap::new(),
+        }
+    }
+
+    /// Record where the MOD `mod_id` was loaded from
+    pub fn with_source(mut self, mod_id: impl Into<String>, source: ModSource) -> Self {
+        self.sources.insert(mod_id.into(), source);
+        self
+    }
+
+    /// Path and content hash of a loaded MOD, if it was loaded from disk
+    pub fn source(&self, mod_id: &str) -> Option<&ModSource> {
+        self.sources.get(mod_id)
+    }
+
+    /// Ids of the loaded MODs in the order their callbacks run
+    pub fn dispatch_order(&self) -> &[String] {
+        &self.dispatch_order
+    }
+
+    /// Loaded MODs in load order
+    pub fn handles(&self) -> &[ModHandle] {
+        &self.handles
+    }
+
+    /// Loaded MOD with the given id
+    pub fn get(&self, mod_id: &str) -> Option<&ModHandle> {
+        self.handles.iter().find(|handle| handle.id == mod_id)
+    }
+
+    /// Ids of the loaded MODs in load order
+    pub fn ids(&self) -> Vec<&str> {
+        self.handles
+            .iter()
+            .map(|handle| handle.id.as_str())
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
+
+/// Run the four systems registered by [`ModSystemPlugin`] once, in order:
+/// loading, plugin control, MOD events, then the [`ModBridgeSystem`]
+///
+/// The runners don't drive plugin systems, so games call this from their
+/// per-frame pump. Without it, startup MODs are loaded but their `on_init()`
+/// commands (e.g. `set_plugin_param`) never reach the plugins.
+pub async fn update_mod_systems(systems: &mut SystemContext, resources: &mut ResourceContext) {
+    if let Some(system) = systems.get_mut::<ModLoadSystem>() {
+        system.update_resources(resources).await;
+    }
+    if let Some(system) = systems.get_mut::<PluginControlSystem>() {
+        system.update_resources(resources).await;
+    }
+    if let Some(system) = systems.get_mut::<ModEventSystem>() {
+        system.update_resources(resources).await;
+    }
+    if let Some(system) = systems.get_mut::<ModBridgeSystem>() {
+        system.update_resources(resources).await;
+    }
+}
+
+/// Outcome of one load request: the handle and where it came from, or the
+/// path and error message
+type LoadResult = Result<(ModHandle, ModSource), (PathBuf, String)>;
+
 /// System for loading and managing MODs
 ///
-/// Processes `ModLoadRequested` and `ModUnloadRequested` events,
+/// Processes `ModLoadRequested`, `ModReloadRequested` and `ModUnloadRequested` events,
 /// delegates to the configured `ModLoader`, and publishes result events.
-struct ModLoadSystem;
+///
+/// Load requests of one frame are ordered so that directory MODs load after
+/// the MODs their `mod.toml` depends on. Missing or incompatible
+/// dependencies and dependency cycles fail the request with a `ModError`.
+#[derive(Default)]
+pub(crate) struct ModLoadSystem {
+    /// MODs found in the MOD directory, published on the first update
+    discovered: Vec<ModDiscovered>,
+    /// Results of the startup MODs, published on the first update
+    startup: Vec<LoadResult>,
+    /// `MasterSeed` last handed to the loader
+    master_seed: Option<MasterSeed>,
+    /// Whether the missing `MasterSeed` was reported
+    warned_unseeded: bool,
+}
 
 impl ModLoadSystem {
     /// Update method using ResourceContext (Modern API)
     ///
     /// This method is the recommended way to update the system.
-    #[allow(dead_code)]
     pub async fn update_resources(&mut self, resources: &mut ResourceContext) {
+        // Step 0: MODs draw random numbers from streams of the MasterSeed
+        let master_seed = resources.get::<MasterSeed>().await.map(|seed| *seed);
+        if master_seed != self.master_seed {
+            if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
+                loader_state.loader.set_master_seed(master_seed);
+            }
+            self.master_seed = master_seed;
+        }
+
         // Step 1: Collect load requests
         let load_requests: Vec<ModLoadRequested> = {
             if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
@@ -131,38 +462,106 @@ impl ModLoadSystem {
             }
         };
 
-        // Step 3: Process load requests
-        let mut load_results = Vec::new();
+        // Step 2b: Collect reload requests
+        let reload_requests: Vec<ModReloadRequested> = {
+            if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+                event_bus
+                    .reader::<ModReloadRequested>()
+                    .iter()
+                    .cloned()
+                    .collect()
+            } else {
+                Vec::new()
+            }
+        };
+
+        // Step 3: Process load requests (dependencies first)
+        let mut load_results = std::mem::take(&mut self.startup);
         if !load_requests.is_empty() {
             if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
-                for request in load_requests {
-                    match loader_state.loader.load(&request.path) {
-                        Ok(handle) => {
+                let ModLoaderState {
+                    loader,
+                    loaded_mods,
+                } = &mut *loader_state;
+                load_results.extend(load_batch(loader.as_mut(), loaded_mods, load_requests));
+            }
+        }
+
+        // Publish discovered MODs, then load results
+        let discovered = std::mem::take(&mut self.discovered);
+        let any_loads = !load_results.is_empty();
+        if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+            for event in discovered {
+                event_bus.publish(event);
+            }
+        }
+        let new_sources = publish_load_results(resources, load_results).await;
+
+        // Step 3b: Process reload requests
+        let mut reload_results = Vec::new();
+        if !reload_requests.is_empty() {
+            let reset_rng = match resources.get::<ModSystemConfig>().await {
+                Some(config) => config.reset_rng_on_reload,
+                None => false,
+            };
+            if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
+                for request in reload_requests {
+                    let Some(pos) = loader_state
+                        .loaded_mods
+                        .iter()
+                        .position(|h| h.id == request.mod_id)
+                    else {
+                        eprintln!("[MOD System] MOD '{}' not found", request.mod_id);
+                        reload_results.push(Err((
+                            request.mod_id.clone(),
+                            format!("MOD '{}' not loaded", request.mod_id),
+                        )));
+                        continue;
+                    };
+
+                    let handle = loader_state.loaded_mods[pos].clone();
+                    if reset_rng {
+                        let mut positions = loader_state.loader.export_rng();
+                        if positions.remove(&handle.id).is_some() {
+                            loader_state.loader.import_rng(positions);
+                        }
+                    }
+                    match loader_state.loader.reload(&handle) {
+                        Ok(new_handle) => {
                             println!(
-                                "[MOD System] Loaded MOD: {} v{}",
-                                handle.metadata.name, handle.metadata.version
+                                "[MOD System] Reloaded MOD: {} v{}",
+                                new_handle.metadata.name, new_handle.metadata.version
                             );
-                            loader_state.loaded_mods.push(handle.clone());
-                            load_results.push(Ok(handle));
+                            loader_state.loaded_mods[pos] = new_handle.clone();
+                            reload_results.push(Ok(new_handle));
                         }
                         Err(e) => {
-                            eprintln!("[MOD System] Failed to load MOD {:?}: {}", request.path, e);
-                            load_results.push(Err((request.path, e.to_string())));
+                            eprintln!(
+                                "[MOD System] Failed to reload MOD {}: {}",
+                                request.mod_id, e
+                            );
+                            reload_results.push(Err((request.mod_id, e.to_string())));
                         }
                     }
                 }
             }
         }
 
-        // Publish load results
+        // Reloaded MODs register their strings again from on_init()
+        let mut stale_strings: Vec<String> = reload_results
+            .iter()
+            .filter_map(|result| result.as_ref().ok().map(|handle| handle.id.clone()))
+            .collect();
+
+        // Publish reload results
         if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
-            for result in load_results {
+            for result in reload_results {
                 match result {
                     Ok(handle) => {
-                        event_bus.publish(ModLoadedEvent { handle });
+                        event_bus.publish(ModReloadedEvent { handle });
                     }
-                    Err((path, error)) => {
-                        event_bus.publish(ModLoadFailedEvent { path, error });
+                    Err((mod_id, error)) => {
+                        event_bus.publish(ModReloadFailedEvent { mod_id, error });
                     }
                 }
             }
@@ -202,15 +601,358 @@ impl ModLoadSystem {
             }
         }
 
+        // Step 5: Keep the registry in sync
+        if any_loads || !stale_strings.is_empty() || !unload_results.is_empty() {
+            sync_registry(resources, new_sources, &stale_strings).await;
+        }
+
+        stale_strings.extend(unload_results.iter().flatten().cloned());
+        if !stale_strings.is_empty() {
+            if let Some(mut localization) = resources.get_mut::<Localization>().await {
+                for mod_id in &stale_strings {
+                    localization.remove_mod_strings(mod_id);
+                }
+            }
+        }
+
         // Publish unload results
         if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
             for mod_id in unload_results.into_iter().flatten() {
                 event_bus.publish(ModUnloadedEvent { mod_id });
             }
         }
+
+        // Step 6: Without a MasterSeed MOD randomness isn't reproducible; say so once
+        if self.master_seed.is_none() && !self.warned_unseeded {
+            let any_loaded = match resources.get::<ModLoaderState>().await {
+                Some(loader_state) => !loader_state.loaded_mods.is_empty(),
+                None => false,
+            };
+            if any_loaded {
+                if let Some(mut event_bus) = resources.get_mut::<EventBus>().await {
+                    event_bus.publish(ModLogEvent {
+                        entry: ModLogEntry::new(
+                            None,
+                            ModLogLevel::Warn,
+                            "No MasterSeed resource: MOD random numbers are not reproducible",
+                        ),
+                    });
+                    self.warned_unseeded = true;
+                }
+            }
+        }
     }
 }
 
+/// Load the MODs at `paths` right away, dependencies first
+///
+/// For code that can't wait for `ModLoadSystem`'s next update, e.g. loading
+/// the MODs a save was made with before the save is applied. Like a
+/// `ModLoadRequested`, this publishes a `ModLoadedEvent` or
+/// `ModLoadFailedEvent` per path and updates the [`ModRegistry`]. Returns
+/// the loaded handles; nothing loads without the MOD system.
+pub async fn load_mods(resources: &mut ResourceContext, paths: Vec<PathBuf>) -> Vec<ModHandle> {
+    let results = match resources.get_mut::<ModLoaderState>().await {
+        Some(mut loader_state) => {
+            let ModLoaderState {
+                loader,
+                loaded_mods,
+            } = &mut *loader_state;
+            let requests = paths
+                .into_iter()
+                .map(|path| ModLoadRequested { path })
+                .collect();
+            load_batch(loader.as_mut(), loaded_mods, requests)
+        }
+        None => return Vec::new(),
+    };
+
+    let new_sources = publish_load_results(resources, results).await;
+    let loaded = match resources.get::<ModLoaderState>().await {
+        Some(loader_state) => loader_state
+            .loaded_mods
+            .iter()
+            .filter(|handle| new_sources.iter().any(|(id, _)| *id == handle.id))
+            .cloned()
+            .collect(),
+        None => Vec::new(),
+    };
+    sync_registry(resources, new_sources, &[]).await;
+    loaded
+}
+
+/// Path of the MOD `mod_id` in the MOD directory `dir`, if it is there
+///
+/// A MOD's id is its file stem, or its directory name for directory MODs.
+pub fn find_mod(dir: &Path, mod_id: &str, extensions: &[&str]) -> Option<PathBuf> {
+    scan_mod_dir(dir, extensions)
+        .into_iter()
+        .map(|request| request.path)
+        .find(|path| {
+            let id = if path.is_dir() {
+                path.file_name()
+            } else {
+                path.file_stem()
+            };
+            id.and_then(|id| id.to_str()) == Some(mod_id)
+        })
+}
+
+/// Publish a `ModLoadedEvent` or `ModLoadFailedEvent` per result
+///
+/// Returns the sources of the loaded MODs by id.
+async fn publish_load_results(
+    resources: &ResourceContext,
+    results: Vec<LoadResult>,
+) -> Vec<(String, ModSource)> {
+    let mut sources = Vec::new();
+    let Some(mut event_bus) = resources.get_mut::<EventBus>().await else {
+        return sources;
+    };
+    for result in results {
+        match result {
+            Ok((handle, source)) => {
+                sources.push((handle.id.clone(), source));
+                event_bus.publish(ModLoadedEvent { handle });
+            }
+            Err((path, error)) => {
+                event_bus.publish(ModLoadFailedEvent { path, error });
+            }
+        }
+    }
+    sources
+}
+
+/// Rebuild the [`ModRegistry`] from the loaded MODs
+///
+/// Sources of MODs still loaded carry over; `new_sources` are added and the
+/// content hashes of the `reloaded` MODs are read again.
+async fn sync_registry(
+    resources: &ResourceContext,
+    new_sources: Vec<(String, ModSource)>,
+    reloaded: &[String],
+) {
+    let Some(mut updated) = (match resources.get_mut::<ModLoaderState>().await {
+        Some(mut loader_state) => {
+            let updated = ModRegistry::new(loader_state.loaded_mods.clone());
+            loader_state
+                .loader
+                .set_dispatch_order(updated.dispatch_order());
+            Some(updated)
+        }
+        None => None,
+    }) else {
+        return;
+    };
+    let Some(mut registry) = resources.get_mut::<ModRegistry>().await else {
+        return;
+    };
+
+    for (mod_id, source) in registry.sources.drain() {
+        if updated.get(&mod_id).is_some() {
+            let source = if reloaded.contains(&mod_id) {
+                ModSource::read(source.path)
+            } else {
+                source
+            };
+            updated.sources.insert(mod_id, source);
+        }
+    }
+    updated.sources.extend(new_sources);
+    *registry = updated;
+}
+
+/// Metadata of the MOD at `path`, read without loading it
+fn discover(loader: &mut dyn ModLoader, path: &Path) -> ModDiscovered {
+    let (metadata, error) = match loader.peek_metadata(path) {
+        Ok(metadata) => (Some(metadata), None),
+        Err(e) => (None, Some(e.to_string())),
+    };
+    ModDiscovered {
+        path: path.to_path_buf(),
+        metadata,
+        error,
+    }
+}
+
+/// Load requests for every MOD in `dir`, sorted by file name
+///
+/// Picks files whose extension is in `extensions` and subdirectories with a
+/// `mod.toml`. A missing or unreadable directory yields no requests.
+fn scan_mod_dir(dir: &Path, extensions: &[&str]) -> Vec<ModLoadRequested> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("[MOD System] Cannot read MOD directory {:?}: {}", dir, e);
+            return Vec::new();
+        }
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            if path.is_dir() {
+                path.join(MANIFEST_FILE).is_file()
+            } else {
+                path.extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| extensions.contains(&ext))
+            }
+        })
+        .collect();
+    paths.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+
+    paths
+        .into_iter()
+        .map(|path| ModLoadRequested { path })
+        .collect()
+}
+
+/// Load a batch of requests, dependencies first
+///
+/// Loaded handles are appended to `loaded_mods`; a failing request doesn't
+/// stop the rest of the batch.
+fn load_batch(
+    loader: &mut dyn ModLoader,
+    loaded_mods: &mut Vec<ModHandle>,
+    requests: Vec<ModLoadRequested>,
+) -> Vec<LoadResult> {
+    let mut results = Vec::new();
+    let (pending, failed) = order_load_requests(requests);
+    for (path, e) in failed {
+        eprintln!("[MOD System] Failed to load MOD {:?}: {}", path, e);
+        results.push(Err((path, e.to_string())));
+    }
+
+    for request in pending {
+        let checked = request
+            .dependencies
+            .iter()
+            .try_for_each(|dep| dep.check(&request.name, loaded_mods));
+        let result = checked
+            .and_then(|_| loader.load(&request.path))
+            .and_then(|handle| check_dispatch_order(loader, loaded_mods, handle));
+
+        match result {
+            Ok(handle) => {
+                println!(
+                    "[MOD System] Loaded MOD: {} v{}",
+                    handle.metadata.name, handle.metadata.version
+                );
+                loaded_mods.push(handle.clone());
+                results.push(Ok((handle, ModSource::read(&request.path))));
+            }
+            Err(e) => {
+                eprintln!("[MOD System] Failed to load MOD {:?}: {}", request.path, e);
+                results.push(Err((request.path, e.to_string())));
+            }
+        }
+    }
+    results
+}
+
+/// Reject a freshly loaded MOD whose `after` constraints close a cycle
+///
+/// The MOD is unloaded again so the loader doesn't keep its callbacks.
+fn check_dispatch_order(
+    loader: &mut dyn ModLoader,
+    loaded_mods: &[ModHandle],
+    handle: ModHandle,
+) -> Result<ModHandle, ModError> {
+    let mut mods = loaded_mods.to_vec();
+    mods.push(handle.clone());
+    match dispatch_order(&mods) {
+        Ok(_) => Ok(handle),
+        Err(e) => {
+            let _ = loader.unload(&handle);
+            Err(e)
+        }
+    }
+}
+
+/// A load request together with what its manifest declares
+struct PendingLoad {
+    path: PathBuf,
+    name: String,
+    dependencies: Vec<ModDependency>,
+}
+
+impl PendingLoad {
+    fn provides(&self, dep: &ModDependency) -> bool {
+        self.name == dep.name
+            || self.path.file_name().and_then(|n| n.to_str()) == Some(dep.name.as_str())
+    }
+}
+
+/// Order a batch of load requests so that dependencies load before dependents
+///
+/// Requests without a `mod.toml` can't declare dependencies; they keep their
+/// relative order. Requests with an unreadable manifest or caught in a
+/// dependency cycle are returned as failures.
+fn order_load_requests(
+    requests: Vec<ModLoadRequested>,
+) -> (Vec<PendingLoad>, Vec<(PathBuf, ModError)>) {
+    let mut pending = Vec::new();
+    let mut failed = Vec::new();
+
+    for request in requests {
+        if !request.path.join(MANIFEST_FILE).is_file() {
+            pending.push(PendingLoad {
+                name: request.path.display().to_string(),
+                path: request.path,
+                dependencies: Vec::new(),
+            });
+            continue;
+        }
+
+        let manifest = ModManifest::from_dir(&request.path);
+        match manifest.and_then(|m| Ok((m.dependencies()?, m.name))) {
+            Ok((dependencies, name)) => pending.push(PendingLoad {
+                path: request.path,
+                name,
+                dependencies,
+            }),
+            Err(e) => failed.push((request.path, e)),
+        }
+    }
+
+    // Edges within the batch: deps[i] = requests that i depends on
+    let deps: Vec<Vec<usize>> = pending
+        .iter()
+        .map(|load| {
+            load.dependencies
+                .iter()
+                .filter_map(|dep| pending.iter().position(|other| other.provides(dep)))
+                .collect()
+        })
+        .collect();
+
+    // Kahn's algorithm, always taking the earliest ready request
+    let mut order = Vec::with_capacity(pending.len());
+    let mut done = vec![false; pending.len()];
+    while let Some(next) =
+        (0..pending.len()).find(|&i| !done[i] && deps[i].iter().all(|&d| done[d]))
+    {
+        done[next] = true;
+        order.push(next);
+    }
+
+    // Whatever is left depends on a cycle
+    if let Some(cycle) = find_cycle(&deps, &done) {
+        let cycle: Vec<String> = cycle.iter().map(|&i| pending[i].name.clone()).collect();
+        for i in (0..pending.len()).filter(|&i| !done[i]) {
+            failed.push((
+                pending[i].path.clone(),
+                ModError::DependencyCycle(cycle.clone()),
+            ));
+        }
+    }
+
+    let mut slots: Vec<Option<PendingLoad>> = pending.into_iter().map(Some).collect();
+    let ordered = order.into_iter().filter_map(|i| slots[i].take()).collect();
+    (ordered, failed)
+}
+
 #[async_trait]
 impl System for ModLoadSystem {
     fn name(&self) -> &'static str {
@@ -236,7 +978,6 @@ impl PluginControlSystem {
     /// Update method using ResourceContext (Modern API)
     ///
     /// This method is the recommended way to update the system.
-    #[allow(dead_code)]
     pub async fn update_resources(&mut self, resources: &mut ResourceContext) {
         // Step 1: Drain commands from loader
         let commands = {
@@ -257,7 +998,7 @@ impl PluginControlSystem {
             for command in &commands {
                 event_bus.publish(PluginControlRequested {
                     control: command.clone(),
-                    source_mod: None, // TODO: Track source MOD
+                    source_mod: command.issuer.clone(),
                 });
             }
 
@@ -268,12 +1009,14 @@ impl PluginControlSystem {
                         println!("[MOD System] Enabling plugin: {}", command.plugin_name);
                         event_bus.publish(PluginEnabledEvent {
                             plugin_name: command.plugin_name.clone(),
+                            issuer: command.issuer.clone(),
                         });
                     }
                     PluginAction::Disable => {
                         println!("[MOD System] Disabling plugin: {}", command.plugin_name);
                         event_bus.publish(PluginDisabledEvent {
                             plugin_name: command.plugin_name.clone(),
+                            issuer: command.issuer.clone(),
                         });
                     }
                     PluginAction::SetParameter { key, value } => {
@@ -285,6 +1028,7 @@ impl PluginControlSystem {
                             plugin_name: command.plugin_name.clone(),
                             key: key.clone(),
                             value: value.clone(),
+                            issuer: command.issuer.clone(),
                         });
                     }
                     PluginAction::TriggerHook { hook_name, data } => {
@@ -296,6 +1040,7 @@ impl PluginControlSystem {
                             plugin_name: command.plugin_name.clone(),
                             hook_name: hook_name.clone(),
                             data: data.clone(),
+                            issuer: command.issuer.clone(),
                         });
                     }
                 }
@@ -0,0 +1,147 @@
+//! Per-MOD random streams
+//!
+//! With a [`MasterSeed`] resource, every MOD draws `random()`,
+//! `random_range()` and `random_int()` from its own stream, seeded from
+//! (master seed, `"mod"`, MOD id). A MOD rolling more often doesn't shift
+//! another MOD's numbers, and two sessions with the same seed and the same
+//! MOD calls see the same values.
+//!
+//! Stream positions travel with save snapshots (`"mod_rng"`) and replay
+//! headers as a [`ModRngSnapshot`], so resumed sessions continue each stream
+//! where it was.
+
+use crate::context::ResourceContext;
+use crate::engine::rng::MasterSeed;
+use crate::modding::plugin::ModLoaderState;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Random stream of one MOD (xorshift64*)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModRng {
+    state: u64,
+}
+
+impl ModRng {
+    /// Stream of `mod_id` under `master`
+    pub fn for_mod(master: MasterSeed, mod_id: &str) -> Self {
+        Self::from_seed(master.mod_stream(mod_id))
+    }
+
+    /// Stream seeded through splitmix64, so any seed (even 0) works
+    pub fn from_seed(seed: u64) -> Self {
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        Self::from_position(z)
+    }
+
+    /// Continue a stream from a [`position`](ModRng::position)
+    pub fn from_position(position: u64) -> Self {
+        Self {
+            state: if position == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                position
+            },
+        }
+    }
+
+    /// Current position, for saves and replay headers
+    pub fn position(&self) -> u64 {
+        self.state
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Uniform float in [0, 1) from the top 53 bits
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform float in [min, max)
+    pub fn range(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.next_f64()
+    }
+
+    /// Uniform integer in [min, max]; the bounds may come in either order
+    pub fn int(&mut self, min: i64, max: i64) -> i64 {
+        let (low, high) = if min <= max { (min, max) } else { (max, min) };
+        let span = (high as i128 - low as i128 + 1) as u128;
+        let offset = self.next_u64() as u128 % span;
+        (low as i128 + offset as i128) as i64
+    }
+}
+
+/// Master seed and stream positions of the MOD random streams
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModRngSnapshot {
+    pub master_seed: Option<MasterSeed>,
+    /// Stream position per MOD id, see [`ModRng::position`]
+    #[serde(default)]
+    pub positions: HashMap<String, u64>,
+}
+
+impl ModRngSnapshot {
+    /// The `MasterSeed` resource and the positions of the MOD loader's streams
+    pub async fn capture(resources: &ResourceContext) -> Self {
+        let master_seed = resources.get::<MasterSeed>().await.map(|seed| *seed);
+        let positions = match resources.get::<ModLoaderState>().await {
+            Some(loader_state) => loader_state.loader.export_rng(),
+            None => HashMap::new(),
+        };
+        Self {
+            master_seed,
+            positions,
+        }
+    }
+
+    /// Put the master seed back and continue the MOD streams from here
+    pub async fn restore(&self, resources: &mut ResourceContext) {
+        if let Some(seed) = self.master_seed {
+            resources.insert(seed);
+        }
+        if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
+            loader_state.loader.set_master_seed(self.master_seed);
+            loader_state.loader.import_rng(self.positions.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streams_are_per_mod_and_reproducible() {
+        let master = MasterSeed(42);
+        let roll = |mod_id: &str| {
+            let mut rng = ModRng::for_mod(master, mod_id);
+            (0..4).map(|_| rng.int(1, 6)).collect::<Vec<_>>()
+        };
+        assert_eq!(roll("easy_mode"), roll("easy_mode"));
+        assert_ne!(
+            ModRng::for_mod(master, "easy_mode"),
+            ModRng::for_mod(master, "hard_mode")
+        );
+        assert!(roll("easy_mode").iter().all(|n| (1..=6).contains(n)));
+    }
+
+    #[test]
+    fn test_position_continues_the_stream() {
+        let mut rng = ModRng::from_seed(7);
+        rng.next_u64();
+        let mut resumed = ModRng::from_position(rng.position());
+        assert_eq!(rng.next_u64(), resumed.next_u64());
+        assert_eq!(rng.int(6, 1), resumed.int(1, 6));
+
+        let value = rng.range(2.0, 3.0);
+        assert!((2.0..3.0).contains(&value));
+    }
+}
@@ -0,0 +1,200 @@
+//! Payload schemas for MOD-published events
+//!
+//! A MOD may declare the shape of an event before publishing it:
+//!
+//! ```text
+//! declare_event("CustomEvent1", #{ message: "string", value: "int" });
+//! publish_event("CustomEvent1", #{ message: "Hello", value: 42 });  // ok
+//! publish_event("CustomEvent1", #{ message: "Hello" });             // error
+//! ```
+//!
+//! Declared fields are required and must have the declared type; extra
+//! fields are allowed. Events without a declaration are not checked.
+
+use crate::modding::error::{ModError, ModResult};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Type of one declared event field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    String,
+    Int,
+    /// Any number; integers are accepted
+    Float,
+    Bool,
+    Map,
+    Array,
+    /// Must be present, any value
+    Any,
+}
+
+impl FieldType {
+    /// Parse a type name as written in a declaration
+    pub fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "string" => FieldType::String,
+            "int" => FieldType::Int,
+            "float" => FieldType::Float,
+            "bool" => FieldType::Bool,
+            "map" => FieldType::Map,
+            "array" => FieldType::Array,
+            "any" => FieldType::Any,
+            _ => return None,
+        })
+    }
+
+    /// Type name as written in a declaration
+    pub fn name(self) -> &'static str {
+        match self {
+            FieldType::String => "string",
+            FieldType::Int => "int",
+            FieldType::Float => "float",
+            FieldType::Bool => "bool",
+            FieldType::Map => "map",
+            FieldType::Array => "array",
+            FieldType::Any => "any",
+        }
+    }
+
+    /// Whether `value` has this type
+    pub fn matches(self, value: &Value) -> bool {
+        match self {
+            FieldType::String => value.is_string(),
+            FieldType::Int => value.is_i64() || value.is_u64(),
+            FieldType::Float => value.is_number(),
+            FieldType::Bool => value.is_boolean(),
+            FieldType::Map => value.is_object(),
+            FieldType::Array => value.is_array(),
+            FieldType::Any => true,
+        }
+    }
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Declared fields of a MOD event
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventSchema {
+    fields: BTreeMap<String, FieldType>,
+}
+
+impl EventSchema {
+    /// Create a schema without fields
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a required field
+    pub fn field(mut self, name: impl Into<String>, field_type: FieldType) -> Self {
+        self.fields.insert(name.into(), field_type);
+        self
+    }
+
+    /// Build a schema from `(field, type name)` pairs
+    ///
+    /// Fails with `ModError::InvalidFormat` on an unknown type name.
+    pub fn parse<K, V>(fields: impl IntoIterator<Item = (K, V)>) -> ModResult<Self>
+    where
+        K: Into<String>,
+        V: AsRef<str>,
+    {
+        let mut schema = Self::new();
+        for (name, type_name) in fields {
+            let name = name.into();
+            let field_type = FieldType::parse(type_name.as_ref()).ok_or_else(|| {
+                ModError::InvalidFormat(format!(
+                    "Field '{}' has unknown type '{}' (expected string, int, float, bool, map, array or any)",
+                    name,
+                    type_name.as_ref()
+                ))
+            })?;
+            schema.fields.insert(name, field_type);
+        }
+        Ok(schema)
+    }
+
+    /// Declared fields, sorted by name
+    pub fn fields(&self) -> impl Iterator<Item = (&str, FieldType)> {
+        self.fields.iter().map(|(name, ty)| (name.as_str(), *ty))
+    }
+
+    /// Every way `payload` fails the schema; empty if it conforms
+    pub fn violations(&self, payload: &Value) -> Vec<String> {
+        let Some(object) = payload.as_object() else {
+            return vec![format!("payload must be a map, got {}", json_type(payload))];
+        };
+
+        self.fields
+            .iter()
+            .filter_map(|(name, field_type)| match object.get(name) {
+                None => Some(format!("missing field '{}'", name)),
+                Some(value) if !field_type.matches(value) => Some(format!(
+                    "field '{}' must be {}, got {}",
+                    name,
+                    field_type,
+                    json_type(value)
+                )),
+                Some(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Schema type name of a JSON value, for error messages
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "()",
+        Value::Bool(_) => "bool",
+        Value::Number(n) if n.is_f64() => "float",
+        Value::Number(_) => "int",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "map",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_violations() {
+        let schema = EventSchema::parse([("message", "string"), ("value", "int")]).unwrap();
+
+        assert!(schema
+            .violations(&json!({ "message": "hi", "value": 1, "extra": true }))
+            .is_empty());
+        assert_eq!(
+            schema.violations(&json!({ "value": "1" })),
+            vec![
+                "missing field 'message'".to_string(),
+                "field 'value' must be int, got string".to_string(),
+            ]
+        );
+        assert_eq!(
+            schema.violations(&json!([1])),
+            vec!["payload must be a map, got array".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_float_accepts_integers() {
+        let schema = EventSchema::new().field("speed", FieldType::Float);
+        assert!(schema.violations(&json!({ "speed": 2 })).is_empty());
+        assert!(schema.violations(&json!({ "speed": 2.5 })).is_empty());
+        assert!(!schema.violations(&json!({ "speed": "fast" })).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_type_name_is_rejected() {
+        let err = EventSchema::parse([("value", "integer")]).unwrap_err();
+        assert!(err.to_string().contains("unknown type 'integer'"));
+    }
+}
@@ -0,0 +1,119 @@
+//! Where loaded MODs come from, and what their files contained
+
+use ring::digest::{Context, SHA256};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Path and content hash of a loaded MOD
+///
+/// Recorded by the MOD system when a MOD loads or reloads; see
+/// [`ModRegistry::source`](super::ModRegistry::source).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModSource {
+    pub path: PathBuf,
+    /// [`content_hash`] of the MOD when it was (re)loaded; `None` if its
+    /// files could not be read
+    pub content_hash: Option<String>,
+}
+
+impl ModSource {
+    /// Source of the MOD at `path`, hashing its files now
+    pub fn read(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let content_hash = content_hash(&path).ok();
+        Self { path, content_hash }
+    }
+}
+
+/// `sha256:<hex>` of a MOD file, or of every file of a MOD directory
+///
+/// Directory files are hashed with their relative paths in sorted order, so
+/// the hash only changes when a file's name or content does.
+pub fn content_hash(path: &Path) -> io::Result<String> {
+    let mut context = Context::new(&SHA256);
+    if path.is_dir() {
+        let mut files = Vec::new();
+        collect_files(path, &mut files)?;
+        files.sort();
+        for file in files {
+            let relative = file.strip_prefix(path).unwrap_or(&file);
+            context.update(relative.to_string_lossy().replace('\\', "/").as_bytes());
+            context.update(&[0]);
+            context.update(&std::fs::read(&file)?);
+            context.update(&[0]);
+        }
+    } else {
+        context.update(&std::fs::read(path)?);
+    }
+
+    let hex: String = context
+        .finish()
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    Ok(format!("sha256:{}", hex))
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_hash_follows_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("easy_mode.rhai");
+        std::fs::write(&path, "fn on_init() {}").unwrap();
+
+        let hash = content_hash(&path).unwrap();
+        assert!(hash.starts_with("sha256:"));
+        assert_eq!(hash.len(), "sha256:".len() + 64);
+        assert_eq!(content_hash(&path).unwrap(), hash);
+
+        std::fs::write(
+            &path,
+            "fn on_init() { set_plugin_param(\"combat\", \"hp\", 999); }",
+        )
+        .unwrap();
+        assert_ne!(content_hash(&path).unwrap(), hash);
+    }
+
+    #[test]
+    fn test_directory_hash_covers_names_and_contents() {
+        let dir = tempfile::tempdir().unwrap();
+        let mod_dir = dir.path().join("better_loot");
+        std::fs::create_dir_all(mod_dir.join("data")).unwrap();
+        std::fs::write(mod_dir.join("mod.toml"), "name = \"better_loot\"").unwrap();
+        std::fs::write(mod_dir.join("data/drops.json"), "[]").unwrap();
+        let hash = content_hash(&mod_dir).unwrap();
+
+        std::fs::rename(
+            mod_dir.join("data/drops.json"),
+            mod_dir.join("data/loot.json"),
+        )
+        .unwrap();
+        let renamed = content_hash(&mod_dir).unwrap();
+        assert_ne!(renamed, hash);
+
+        std::fs::write(mod_dir.join("data/loot.json"), "[1]").unwrap();
+        assert_ne!(content_hash(&mod_dir).unwrap(), renamed);
+    }
+
+    #[test]
+    fn test_unreadable_source_has_no_hash() {
+        let source = ModSource::read("/nonexistent/mod.rhai");
+        assert_eq!(source.content_hash, None);
+    }
+}
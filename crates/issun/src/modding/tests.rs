@@ -1,6 +1,7 @@
 //! Unit tests for MOD system
 
 use super::*;
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Mock loader for testing
@@ -29,6 +30,9 @@ impl ModLoader for MockLoader {
                 version: "1.0.0".to_string(),
                 author: Some("Test Author".to_string()),
                 description: Some("Test Description".to_string()),
+                dependencies: Vec::new(),
+                priority: 0,
+                after: Vec::new(),
             },
             backend: ModBackend::Rhai,
         };
@@ -59,6 +63,9 @@ fn test_mod_metadata_serialization() {
         version: "1.0.0".to_string(),
         author: Some("Author".to_string()),
         description: Some("Description".to_string()),
+        dependencies: Vec::new(),
+        priority: 0,
+        after: Vec::new(),
     };
 
     let json = serde_json::to_string(&metadata).unwrap();
@@ -146,3 +153,1126 @@ fn test_mod_error_display() {
     let err = ModError::PluginNotFound("combat".to_string());
     assert_eq!(err.to_string(), "Plugin not found: combat");
 }
+
+#[test]
+fn test_default_reload_is_unsupported() {
+    let mut loader = MockLoader::new();
+    let handle = loader.load(Path::new("test_mod.rhai")).unwrap();
+
+    let err = loader.reload(&handle).unwrap_err();
+    assert!(matches!(err, ModError::ExecutionFailed(_)));
+    assert!(err.to_string().contains("test_mod"));
+}
+
+/// Loader that takes metadata from `mod.toml` and records the load order
+struct ManifestLoader {
+    order: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+impl ModLoader for ManifestLoader {
+    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
+        let manifest = ModManifest::from_dir(path)?;
+        self.order.lock().unwrap().push(manifest.name.clone());
+        Ok(ModHandle {
+            id: manifest.name.clone(),
+            metadata: ModMetadata {
+                dependencies: manifest.dependencies()?,
+                priority: manifest.priority,
+                after: manifest.after,
+                name: manifest.name,
+                version: manifest.version,
+                author: manifest.author,
+                description: manifest.description,
+            },
+            backend: ModBackend::Rhai,
+        })
+    }
+
+    fn unload(&mut self, _handle: &ModHandle) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn control_plugin(&mut self, _handle: &ModHandle, _control: &PluginControl) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn clone_box(&self) -> Box<dyn ModLoader> {
+        Box::new(Self {
+            order: self.order.clone(),
+        })
+    }
+}
+
+fn write_mod_dir(root: &Path, name: &str, version: &str, depends: &[&str]) -> std::path::PathBuf {
+    let dir = root.join(name);
+    std::fs::create_dir_all(&dir).unwrap();
+    let depends: Vec<String> = depends.iter().map(|d| format!("\"{}\"", d)).collect();
+    std::fs::write(
+        dir.join(MANIFEST_FILE),
+        format!(
+            "name = \"{}\"\nversion = \"{}\"\ndepends = [{}]\n",
+            name,
+            version,
+            depends.join(", ")
+        ),
+    )
+    .unwrap();
+    dir
+}
+
+/// Run one batch of load requests; returns (load order, failures by path)
+async fn load_batch(
+    resources: &mut crate::context::ResourceContext,
+    paths: Vec<std::path::PathBuf>,
+) -> (Vec<String>, Vec<ModLoadFailedEvent>) {
+    use crate::event::EventBus;
+
+    let order = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let loaded_mods = match resources.get::<ModLoaderState>().await {
+        Some(state) => state.loaded_mods.clone(),
+        None => Vec::new(),
+    };
+    resources.insert(ModLoaderState {
+        loader: Box::new(ManifestLoader {
+            order: order.clone(),
+        }),
+        loaded_mods,
+    });
+
+    {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        for path in paths {
+            bus.publish(ModLoadRequested { path });
+        }
+        bus.dispatch();
+    }
+
+    plugin::ModLoadSystem::default()
+        .update_resources(resources)
+        .await;
+
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    let failed = bus.reader::<ModLoadFailedEvent>().iter().cloned().collect();
+    let order = order.lock().unwrap().clone();
+    (order, failed)
+}
+
+fn resources() -> crate::context::ResourceContext {
+    let mut resources = crate::context::ResourceContext::new();
+    resources.insert(crate::event::EventBus::new());
+    resources
+}
+
+#[tokio::test]
+async fn test_load_batch_initializes_dependencies_first() {
+    let root = tempfile::tempdir().unwrap();
+    let ui = write_mod_dir(root.path(), "ui_kit", "1.0.0", &["core_tweaks"]);
+    let loot = write_mod_dir(
+        root.path(),
+        "better_loot",
+        "1.0.0",
+        &["ui_kit", "core_tweaks >= 1.0"],
+    );
+    let core = write_mod_dir(root.path(), "core_tweaks", "1.2.0", &[]);
+
+    let mut resources = resources();
+    let (order, failed) = load_batch(&mut resources, vec![loot, ui, core]).await;
+
+    assert!(failed.is_empty());
+    assert_eq!(order, vec!["core_tweaks", "ui_kit", "better_loot"]);
+}
+
+#[tokio::test]
+async fn test_load_batch_rejects_cycles_and_missing_dependencies() {
+    let root = tempfile::tempdir().unwrap();
+    let a = write_mod_dir(root.path(), "a", "1.0.0", &["b"]);
+    let b = write_mod_dir(root.path(), "b", "1.0.0", &["a"]);
+    let lonely = write_mod_dir(root.path(), "lonely", "1.0.0", &["nowhere"]);
+    let fine = write_mod_dir(root.path(), "fine", "1.0.0", &[]);
+
+    let mut resources = resources();
+    let (order, failed) =
+        load_batch(&mut resources, vec![a.clone(), b, lonely.clone(), fine]).await;
+
+    assert_eq!(order, vec!["fine"]);
+    assert_eq!(failed.len(), 3);
+
+    let error_for = |path: &Path| {
+        failed
+            .iter()
+            .find(|f| f.path == path)
+            .map(|f| f.error.clone())
+            .unwrap()
+    };
+    assert_eq!(error_for(&a), "Dependency cycle between MODs: a -> b -> a");
+    assert_eq!(
+        error_for(&lonely),
+        "MOD 'lonely' requires nowhere, which is not loaded"
+    );
+}
+
+#[tokio::test]
+async fn test_load_rejects_incompatible_dependency_version() {
+    let root = tempfile::tempdir().unwrap();
+    let core = write_mod_dir(root.path(), "core_tweaks", "1.1.0", &[]);
+    let loot = write_mod_dir(root.path(), "better_loot", "1.0.0", &["core_tweaks >= 1.2"]);
+
+    let mut resources = resources();
+    let (order, failed) = load_batch(&mut resources, vec![core]).await;
+    assert_eq!(order, vec!["core_tweaks"]);
+    assert!(failed.is_empty());
+
+    // A later batch checks against the MODs that are already loaded
+    let (order, failed) = load_batch(&mut resources, vec![loot]).await;
+    assert!(order.is_empty());
+    assert_eq!(
+        failed[0].error,
+        "MOD 'better_loot' requires core_tweaks >= 1.2, but version 1.1.0 is loaded"
+    );
+}
+
+#[tokio::test]
+async fn test_load_rejects_dispatch_order_cycle() {
+    let root = tempfile::tempdir().unwrap();
+    let a = write_mod_dir(root.path(), "a", "1.0.0", &[]);
+    let b = write_mod_dir(root.path(), "b", "1.0.0", &[]);
+    std::fs::write(
+        a.join(MANIFEST_FILE),
+        "name = \"a\"\nversion = \"1.0.0\"\nafter = [\"b\"]\n",
+    )
+    .unwrap();
+    std::fs::write(
+        b.join(MANIFEST_FILE),
+        "name = \"b\"\nversion = \"1.0.0\"\nafter = [\"a\"]\npriority = 9\n",
+    )
+    .unwrap();
+
+    let mut resources = resources();
+    let (order, failed) = load_batch(&mut resources, vec![a, b.clone()]).await;
+
+    // `a` loads, `b` closes the cycle and is turned away
+    assert_eq!(order, vec!["a", "b"]);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].path, b);
+    assert_eq!(
+        failed[0].error,
+        "Dispatch order cycle between MODs: a -> b -> a"
+    );
+    let state = resources.get::<ModLoaderState>().await.unwrap();
+    let loaded: Vec<&str> = state.loaded_mods.iter().map(|h| h.id.as_str()).collect();
+    assert_eq!(loaded, vec!["a"]);
+}
+
+/// Loader whose MODs register English and Japanese strings on load
+struct StringsLoader {
+    queued: Vec<ModStrings>,
+}
+
+impl ModLoader for StringsLoader {
+    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
+        let id = path.file_stem().unwrap().to_str().unwrap().to_string();
+        let strings = |language: &str, entries: &[(&str, &str)]| ModStrings {
+            mod_id: id.clone(),
+            language: language.to_string(),
+            strings: entries
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        };
+        self.queued.push(strings(
+            "en",
+            &[
+                ("item.flame_sword.name", "Flame Sword"),
+                ("title", "Hijacked title"),
+            ],
+        ));
+        self.queued
+            .push(strings("ja", &[("item.flame_sword.name", "炎の剣")]));
+
+        Ok(ModHandle {
+            id: id.clone(),
+            metadata: ModMetadata {
+                name: id,
+                version: "1.0.0".to_string(),
+                author: None,
+                description: None,
+                dependencies: Vec::new(),
+                priority: 0,
+                after: Vec::new(),
+            },
+            backend: ModBackend::Rhai,
+        })
+    }
+
+    fn unload(&mut self, _handle: &ModHandle) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn control_plugin(&mut self, _handle: &ModHandle, _control: &PluginControl) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn drain_strings(&mut self) -> Vec<ModStrings> {
+        std::mem::take(&mut self.queued)
+    }
+
+    fn clone_box(&self) -> Box<dyn ModLoader> {
+        Box::new(Self { queued: Vec::new() })
+    }
+}
+
+#[tokio::test]
+async fn test_mod_strings_merge_into_localization_and_unload() {
+    use crate::event::EventBus;
+    use crate::localization::Localization;
+
+    let mut resources = resources();
+    resources.insert(
+        Localization::new("en")
+            .with_strings("en", [("menu.start", "Start")])
+            .with_strings("ja", [("menu.start", "スタート")])
+            // The base game owns "ui.*", including what MOD "ui" would produce
+            .with_strings("en", [("ui.title", "Dungeon")]),
+    );
+    resources.insert(ModLoaderState {
+        loader: Box::new(StringsLoader { queued: Vec::new() }),
+        loaded_mods: Vec::new(),
+    });
+
+    async fn frame(resources: &mut crate::context::ResourceContext) {
+        resources.get_mut::<EventBus>().await.unwrap().dispatch();
+        plugin::ModLoadSystem::default()
+            .update_resources(resources)
+            .await;
+        ModEventSystem::new().update_resources(resources).await;
+    }
+
+    {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        for path in ["mods/better_loot.rhai", "mods/ui.rhai"] {
+            bus.publish(ModLoadRequested { path: path.into() });
+        }
+    }
+    frame(&mut resources).await;
+
+    {
+        let mut l10n = resources.get_mut::<Localization>().await.unwrap();
+        assert_eq!(
+            l10n.text("better_loot.item.flame_sword.name"),
+            "Flame Sword"
+        );
+        l10n.set_language("ja");
+        assert_eq!(l10n.text("better_loot.item.flame_sword.name"), "炎の剣");
+        assert_eq!(l10n.text("menu.start"), "スタート");
+        // Base wins; the colliding MOD string was never merged
+        assert_eq!(l10n.text("ui.title"), "Dungeon");
+        assert_eq!(l10n.text("ui.item.flame_sword.name"), "炎の剣");
+    }
+
+    {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        let conflicts: Vec<ModStringConflict> =
+            bus.reader::<ModStringConflict>().iter().cloned().collect();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].mod_id, "ui");
+        assert_eq!(conflicts[0].language, "en");
+        assert_eq!(conflicts[0].keys, vec!["ui.title".to_string()]);
+
+        bus.publish(ModUnloadRequested {
+            mod_id: "better_loot".to_string(),
+        });
+    }
+    frame(&mut resources).await;
+
+    let l10n = resources.get::<Localization>().await.unwrap();
+    assert_eq!(l10n.get("better_loot.item.flame_sword.name"), None);
+    assert_eq!(l10n.contributing_mods().collect::<Vec<_>>(), vec!["ui"]);
+}
+
+/// Loader whose only output is log lines and refused calls
+#[derive(Default)]
+struct LogLoader {
+    logs: Vec<ModLogEntry>,
+    denials: Vec<ModPermissionDenied>,
+    errors: Vec<ModRuntimeErrorEvent>,
+    events: Vec<(String, serde_json::Value)>,
+}
+
+impl ModLoader for LogLoader {
+    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
+        MockLoader::new().load(path)
+    }
+
+    fn unload(&mut self, _handle: &ModHandle) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn control_plugin(&mut self, _handle: &ModHandle, _control: &PluginControl) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn drain_logs(&mut self) -> Vec<ModLogEntry> {
+        std::mem::take(&mut self.logs)
+    }
+
+    fn drain_denials(&mut self) -> Vec<ModPermissionDenied> {
+        std::mem::take(&mut self.denials)
+    }
+
+    fn drain_errors(&mut self) -> Vec<ModRuntimeErrorEvent> {
+        std::mem::take(&mut self.errors)
+    }
+
+    fn drain_events(&mut self) -> Vec<(String, serde_json::Value)> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn clone_box(&self) -> Box<dyn ModLoader> {
+        Box::new(Self::default())
+    }
+}
+
+#[tokio::test]
+async fn test_mod_events_with_a_factory_are_published_typed() {
+    use crate::event::{EventBus, EventFactoryRegistry};
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct BossSpawned {
+        boss: String,
+    }
+    impl crate::event::Event for BossSpawned {}
+
+    let mut factories = EventFactoryRegistry::new();
+    factories
+        .register_event_factory::<BossSpawned>("BossSpawned")
+        .unwrap();
+    let mut resources = resources();
+    resources.insert(factories);
+    resources.insert(ModLoaderState {
+        loader: Box::new(LogLoader {
+            events: vec![
+                (
+                    "BossSpawned".to_string(),
+                    serde_json::json!({"boss": "lich"}),
+                ),
+                ("BossSpawned".to_string(), serde_json::json!({"boss": 3})),
+                ("Unregistered".to_string(), serde_json::json!({})),
+            ],
+            ..LogLoader::default()
+        }),
+        loaded_mods: Vec::new(),
+    });
+
+    ModEventSystem::new().update_resources(&mut resources).await;
+
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    let typed: Vec<BossSpawned> = bus.reader::<BossSpawned>().iter().cloned().collect();
+    assert_eq!(
+        typed,
+        vec![BossSpawned {
+            boss: "lich".to_string()
+        }]
+    );
+    let dynamic: Vec<String> = bus
+        .reader::<DynamicEvent>()
+        .iter()
+        .map(|event| event.event_type.clone())
+        .collect();
+    assert_eq!(dynamic, ["BossSpawned", "BossSpawned", "Unregistered"]);
+}
+
+#[tokio::test]
+async fn test_mod_logs_are_published_as_events() {
+    use crate::event::EventBus;
+
+    let mut resources = resources();
+    resources.insert(ModLoaderState {
+        loader: Box::new(LogLoader {
+            logs: vec![
+                ModLogEntry::new(Some("arena".to_string()), ModLogLevel::Info, "ready"),
+                ModLogEntry::new(Some("arena".to_string()), ModLogLevel::Error, "no boss"),
+            ],
+            ..LogLoader::default()
+        }),
+        loaded_mods: Vec::new(),
+    });
+
+    ModEventSystem::new().update_resources(&mut resources).await;
+    ModEventSystem::new().update_resources(&mut resources).await;
+
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    let logged: Vec<(ModLogLevel, String)> = bus
+        .reader::<ModLogEvent>()
+        .iter()
+        .map(|event| (event.entry.level, event.entry.message.clone()))
+        .collect();
+    assert_eq!(
+        logged,
+        vec![
+            (ModLogLevel::Info, "ready".to_string()),
+            (ModLogLevel::Error, "no boss".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_permission_denials_are_published_as_events() {
+    use crate::event::EventBus;
+
+    let denied = PluginControl::disable("save_load");
+    let denied = ModPermissions::none()
+        .check_control("cheats", &denied)
+        .unwrap_err();
+    let mut resources = resources();
+    resources.insert(ModLoaderState {
+        loader: Box::new(LogLoader {
+            denials: vec![denied.clone()],
+            ..LogLoader::default()
+        }),
+        loaded_mods: Vec::new(),
+    });
+
+    ModEventSystem::new().update_resources(&mut resources).await;
+    ModEventSystem::new().update_resources(&mut resources).await;
+
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    let published: Vec<_> = bus
+        .reader::<ModPermissionDenied>()
+        .iter()
+        .cloned()
+        .collect();
+    assert_eq!(published, vec![denied]);
+}
+
+#[tokio::test]
+async fn test_runtime_errors_are_published_as_events() {
+    use crate::event::EventBus;
+
+    let error = ModRuntimeErrorEvent::new("arena", "event 'Boom'", "kaboom");
+    let mut resources = resources();
+    resources.insert(ModLoaderState {
+        loader: Box::new(
+            MultiLoader::new()
+                .with_loader(LogLoader::default())
+                .with_loader(LogLoader {
+                    errors: vec![error.clone()],
+                    ..LogLoader::default()
+                }),
+        ),
+        loaded_mods: Vec::new(),
+    });
+
+    ModEventSystem::new().update_resources(&mut resources).await;
+    ModEventSystem::new().update_resources(&mut resources).await;
+
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    let published: Vec<_> = bus
+        .reader::<ModRuntimeErrorEvent>()
+        .iter()
+        .cloned()
+        .collect();
+    assert_eq!(published, vec![error]);
+}
+
+#[test]
+fn test_multi_loader_forwards_permission_policy() {
+    let mut loader = MultiLoader::new()
+        .with_loader(LogLoader {
+            denials: vec![ModPermissions::none()
+                .check_publish("cheats", "GoldAdded")
+                .unwrap_err()],
+            ..LogLoader::default()
+        })
+        .with_loader(LogLoader::default());
+    loader.set_permission_policy(ModPermissionPolicy::default());
+    assert_eq!(loader.drain_denials().len(), 1);
+    assert!(loader.drain_denials().is_empty());
+}
+
+#[tokio::test]
+async fn test_mod_dir_loads_every_mod_at_startup() {
+    use crate::event::EventBus;
+    use crate::prelude::GameBuilder;
+
+    let root = tempfile::tempdir().unwrap();
+    write_mod_dir(root.path(), "addon", "1.0.0", &["base"]);
+    write_mod_dir(root.path(), "base", "1.0.0", &[]);
+    write_mod_dir(root.path(), "broken", "1.0.0", &["missing"]);
+    std::fs::write(root.path().join("notes.txt"), "not a MOD").unwrap();
+
+    let order = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut game = GameBuilder::new()
+        .with_plugin(
+            ModSystemPlugin::new()
+                .with_loader(ManifestLoader {
+                    order: order.clone(),
+                })
+                .with_mod_dir(root.path()),
+        )
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    // The failing MOD doesn't stop the others
+    assert_eq!(*order.lock().unwrap(), vec!["base", "addon"]);
+    assert_eq!(
+        game.resources.get::<ModRegistry>().await.unwrap().ids(),
+        vec!["base", "addon"]
+    );
+
+    // Results are published on the first update only
+    async fn published(
+        resources: &mut crate::context::ResourceContext,
+    ) -> (Vec<String>, Vec<ModLoadFailedEvent>) {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        let loaded = bus
+            .reader::<ModLoadedEvent>()
+            .iter()
+            .map(|event| event.handle.id.clone())
+            .collect();
+        let failed = bus.reader::<ModLoadFailedEvent>().iter().cloned().collect();
+        (loaded, failed)
+    }
+
+    let system = game.systems.get_mut::<plugin::ModLoadSystem>().unwrap();
+    system.update_resources(&mut game.resources).await;
+    let (loaded, failed) = published(&mut game.resources).await;
+    assert_eq!(loaded, vec!["base", "addon"]);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].path, root.path().join("broken"));
+
+    system.update_resources(&mut game.resources).await;
+    let (loaded, failed) = published(&mut game.resources).await;
+    assert!(loaded.is_empty() && failed.is_empty());
+
+    // The registry follows later unloads
+    {
+        let mut bus = game.resources.get_mut::<EventBus>().await.unwrap();
+        bus.publish(ModUnloadRequested {
+            mod_id: "addon".to_string(),
+        });
+        bus.dispatch();
+    }
+    system.update_resources(&mut game.resources).await;
+    assert_eq!(
+        game.resources.get::<ModRegistry>().await.unwrap().ids(),
+        vec!["base"]
+    );
+}
+
+#[tokio::test]
+async fn test_mod_dir_discovery_without_auto_load() {
+    use crate::event::EventBus;
+    use crate::prelude::GameBuilder;
+
+    let root = tempfile::tempdir().unwrap();
+    write_mod_dir(root.path(), "addon", "1.1.0", &["base"]);
+    write_mod_dir(root.path(), "base", "1.0.0", &[]);
+    let garbled = root.path().join("garbled");
+    std::fs::create_dir_all(&garbled).unwrap();
+    std::fs::write(garbled.join(MANIFEST_FILE), "name = 1").unwrap();
+
+    let order = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut game = GameBuilder::new()
+        .with_plugin(
+            ModSystemPlugin::new()
+                .with_loader(ManifestLoader {
+                    order: order.clone(),
+                })
+                .with_mod_dir(root.path())
+                .with_auto_load(false),
+        )
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    // Nothing loads at startup
+    assert!(order.lock().unwrap().is_empty());
+    assert!(game
+        .resources
+        .get::<ModRegistry>()
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(
+        !game
+            .resources
+            .get::<ModSystemConfig>()
+            .await
+            .unwrap()
+            .auto_load
+    );
+
+    let system = game.systems.get_mut::<plugin::ModLoadSystem>().unwrap();
+    system.update_resources(&mut game.resources).await;
+    let discovered: Vec<ModDiscovered> = {
+        let mut bus = game.resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        assert!(bus.reader::<ModLoadedEvent>().iter().next().is_none());
+        bus.reader::<ModDiscovered>().iter().cloned().collect()
+    };
+
+    // Every MOD of the directory, in file name order
+    let paths: Vec<_> = discovered.iter().map(|event| event.path.clone()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            root.path().join("addon"),
+            root.path().join("base"),
+            garbled.clone()
+        ]
+    );
+    let addon = discovered[0].metadata.as_ref().unwrap();
+    assert_eq!(addon.name, "addon");
+    assert_eq!(addon.version, "1.1.0");
+    assert_eq!(addon.dependencies[0].name, "base");
+    assert!(discovered[0].error.is_none());
+    assert!(discovered[2].metadata.is_none());
+    assert!(discovered[2]
+        .error
+        .as_ref()
+        .unwrap()
+        .contains(MANIFEST_FILE));
+
+    // The game loads the MODs the player enabled
+    {
+        let mut bus = game.resources.get_mut::<EventBus>().await.unwrap();
+        bus.publish(ModLoadRequested {
+            path: root.path().join("base"),
+        });
+        bus.dispatch();
+    }
+    system.update_resources(&mut game.resources).await;
+    assert_eq!(*order.lock().unwrap(), vec!["base"]);
+    assert_eq!(
+        game.resources.get::<ModRegistry>().await.unwrap().ids(),
+        vec!["base"]
+    );
+}
+
+#[test]
+fn test_default_peek_metadata() {
+    let root = tempfile::tempdir().unwrap();
+    let dir = write_mod_dir(root.path(), "base", "1.0.0", &[]);
+    let mut loader = MockLoader::new();
+
+    assert_eq!(loader.peek_metadata(&dir).unwrap().name, "base");
+    let err = loader
+        .peek_metadata(&root.path().join("loose.rhai"))
+        .unwrap_err();
+    assert!(matches!(err, ModError::ExecutionFailed(_)));
+    // Peeking loads nothing
+    assert!(loader.mods.is_empty());
+}
+
+/// Loader for files with one extension; records the MODs it loaded
+#[derive(Clone)]
+struct BackendLoader {
+    extension: &'static str,
+    backend: ModBackend,
+    loaded: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    events: Vec<(String, serde_json::Value)>,
+}
+
+impl BackendLoader {
+    fn new(extension: &'static str, backend: ModBackend) -> Self {
+        Self {
+            extension,
+            backend,
+            loaded: Default::default(),
+            events: Vec::new(),
+        }
+    }
+}
+
+impl ModLoader for BackendLoader {
+    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
+        let mut handle = MockLoader::new().load(path)?;
+        handle.backend = self.backend;
+        self.loaded.lock().unwrap().push(handle.id.clone());
+        self.events
+            .push(("Loaded".to_string(), serde_json::json!(handle.id.clone())));
+        Ok(handle)
+    }
+
+    fn file_extensions(&self) -> Vec<&'static str> {
+        vec![self.extension]
+    }
+
+    fn unload(&mut self, handle: &ModHandle) -> ModResult<()> {
+        self.loaded.lock().unwrap().retain(|id| *id != handle.id);
+        Ok(())
+    }
+
+    fn control_plugin(&mut self, _handle: &ModHandle, _control: &PluginControl) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn drain_events(&mut self) -> Vec<(String, serde_json::Value)> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn dispatch_event(&mut self, _event_type: &str, _event_data: &serde_json::Value) -> usize {
+        self.loaded.lock().unwrap().len()
+    }
+
+    fn clone_box(&self) -> Box<dyn ModLoader> {
+        Box::new(self.clone())
+    }
+}
+
+#[test]
+fn test_default_can_load() {
+    let root = tempfile::tempdir().unwrap();
+    let dir = root.path().join("herald");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join(MANIFEST_FILE),
+        "name = \"herald\"\nversion = \"1.0.0\"\nentry = \"herald.wasm\"\n",
+    )
+    .unwrap();
+
+    let rhai = BackendLoader::new("rhai", ModBackend::Rhai);
+    let wasm = BackendLoader::new("wasm", ModBackend::Wasm);
+    assert!(rhai.can_load(Path::new("mods/easy_mode.rhai")));
+    assert!(!rhai.can_load(Path::new("mods/herald.wasm")));
+    assert!(wasm.can_load(&dir));
+    assert!(!rhai.can_load(&dir));
+    assert!(!wasm.can_load(&root.path().join("no_manifest")));
+    // Loaders without extensions accept everything
+    assert!(MockLoader::new().can_load(Path::new("mods/anything.txt")));
+}
+
+#[tokio::test]
+async fn test_multi_loader_routes_and_aggregates() {
+    let rhai = BackendLoader::new("rhai", ModBackend::Rhai);
+    let wasm = BackendLoader::new("wasm", ModBackend::Wasm);
+    let (rhai_loaded, wasm_loaded) = (rhai.loaded.clone(), wasm.loaded.clone());
+    let mut loader = MultiLoader::new().with_loader(rhai).with_loader(wasm);
+
+    assert_eq!(loader.file_extensions(), vec!["rhai", "wasm"]);
+    let easy = loader.load(Path::new("mods/easy_mode.rhai")).unwrap();
+    let herald = loader
+        .load_async(Path::new("mods/herald.wasm"))
+        .await
+        .unwrap();
+    assert_eq!(easy.backend, ModBackend::Rhai);
+    assert_eq!(herald.backend, ModBackend::Wasm);
+    assert_eq!(*rhai_loaded.lock().unwrap(), vec!["easy_mode"]);
+    assert_eq!(*wasm_loaded.lock().unwrap(), vec!["herald"]);
+
+    let err = loader.load(Path::new("mods/notes.txt")).unwrap_err();
+    assert!(matches!(err, ModError::InvalidFormat(_)));
+
+    // Queues and dispatch cover both backends
+    let events: Vec<_> = loader
+        .drain_events()
+        .into_iter()
+        .map(|(_, data)| data)
+        .collect();
+    assert_eq!(
+        events,
+        vec![serde_json::json!("easy_mode"), serde_json::json!("herald")]
+    );
+    assert_eq!(
+        loader.dispatch_event("TurnAdvanced", &serde_json::json!({})),
+        2
+    );
+
+    // Calls on a loaded MOD go to the loader that loaded it
+    loader.unload(&herald).unwrap();
+    assert!(wasm_loaded.lock().unwrap().is_empty());
+    assert_eq!(*rhai_loaded.lock().unwrap(), vec!["easy_mode"]);
+    assert!(matches!(
+        loader.unload(&herald).unwrap_err(),
+        ModError::NotFound(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_mod_dir_with_several_loaders() {
+    use crate::prelude::GameBuilder;
+
+    let root = tempfile::tempdir().unwrap();
+    std::fs::write(root.path().join("easy_mode.rhai"), "").unwrap();
+    std::fs::write(root.path().join("herald.wasm"), "").unwrap();
+    std::fs::write(root.path().join("notes.txt"), "not a MOD").unwrap();
+
+    let rhai = BackendLoader::new("rhai", ModBackend::Rhai);
+    let wasm = BackendLoader::new("wasm", ModBackend::Wasm);
+    let (rhai_loaded, wasm_loaded) = (rhai.loaded.clone(), wasm.loaded.clone());
+    let game = GameBuilder::new()
+        .with_plugin(
+            ModSystemPlugin::new()
+                .with_loader(rhai)
+                .with_loader(wasm)
+                .with_mod_dir(root.path()),
+        )
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(*rhai_loaded.lock().unwrap(), vec!["easy_mode"]);
+    assert_eq!(*wasm_loaded.lock().unwrap(), vec!["herald"]);
+    let registry = game.resources.get::<ModRegistry>().await.unwrap();
+    assert_eq!(registry.ids(), vec!["easy_mode", "herald"]);
+    assert_eq!(registry.get("herald").unwrap().backend, ModBackend::Wasm);
+}
+
+/// Master seed and stream positions seen by a `SeedLoader`
+type SeedState = (Option<crate::engine::MasterSeed>, HashMap<String, u64>);
+
+/// Loader with per-MOD random stream positions; shares its seed and positions
+#[derive(Clone, Default)]
+struct SeedLoader {
+    rng: std::sync::Arc<std::sync::Mutex<SeedState>>,
+}
+
+impl ModLoader for SeedLoader {
+    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
+        let handle = MockLoader::new().load(path)?;
+        self.rng.lock().unwrap().1.insert(handle.id.clone(), 1);
+        Ok(handle)
+    }
+
+    fn reload(&mut self, handle: &ModHandle) -> ModResult<ModHandle> {
+        Ok(handle.clone())
+    }
+
+    fn unload(&mut self, _handle: &ModHandle) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn control_plugin(&mut self, _handle: &ModHandle, _control: &PluginControl) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn set_master_seed(&mut self, seed: Option<crate::engine::MasterSeed>) {
+        self.rng.lock().unwrap().0 = seed;
+    }
+
+    fn export_rng(&self) -> HashMap<String, u64> {
+        self.rng.lock().unwrap().1.clone()
+    }
+
+    fn import_rng(&mut self, positions: HashMap<String, u64>) {
+        self.rng.lock().unwrap().1 = positions;
+    }
+
+    fn clone_box(&self) -> Box<dyn ModLoader> {
+        Box::new(self.clone())
+    }
+}
+
+/// Publish `event`, run `system` once and return the warnings it logged
+async fn run_with<E: crate::event::Event + serde::Serialize>(
+    system: &mut plugin::ModLoadSystem,
+    resources: &mut crate::context::ResourceContext,
+    event: Option<E>,
+) -> Vec<String> {
+    use crate::event::EventBus;
+
+    {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        if let Some(event) = event {
+            bus.publish(event);
+        }
+        bus.dispatch();
+    }
+    system.update_resources(resources).await;
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    bus.reader::<ModLogEvent>()
+        .iter()
+        .filter(|event| event.entry.level == ModLogLevel::Warn)
+        .map(|event| event.entry.message.clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_missing_master_seed_is_reported_once() {
+    let loader = SeedLoader::default();
+    let rng = loader.rng.clone();
+    let mut resources = resources();
+    resources.insert(ModLoaderState {
+        loader: Box::new(loader),
+        loaded_mods: Vec::new(),
+    });
+    let mut system = plugin::ModLoadSystem::default();
+
+    // Nothing to warn about before a MOD loads
+    let warnings = run_with::<ModLoadRequested>(&mut system, &mut resources, None).await;
+    assert!(warnings.is_empty());
+
+    let warnings = run_with(
+        &mut system,
+        &mut resources,
+        Some(ModLoadRequested {
+            path: "mods/easy_mode.rhai".into(),
+        }),
+    )
+    .await;
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("MasterSeed"));
+
+    let warnings = run_with(
+        &mut system,
+        &mut resources,
+        Some(ModLoadRequested {
+            path: "mods/hard_mode.rhai".into(),
+        }),
+    )
+    .await;
+    assert!(warnings.is_empty());
+
+    // The loader follows the MasterSeed resource
+    resources.insert(crate::engine::MasterSeed(7));
+    run_with::<ModLoadRequested>(&mut system, &mut resources, None).await;
+    assert_eq!(rng.lock().unwrap().0, Some(crate::engine::MasterSeed(7)));
+}
+
+#[tokio::test]
+async fn test_reload_keeps_or_resets_the_random_stream() {
+    for reset in [false, true] {
+        let loader = SeedLoader::default();
+        let rng = loader.rng.clone();
+        let mut resources = resources();
+        resources.insert(crate::engine::MasterSeed(7));
+        resources.insert(ModSystemConfig {
+            reset_rng_on_reload: reset,
+            ..ModSystemConfig::default()
+        });
+        resources.insert(ModLoaderState {
+            loader: Box::new(loader),
+            loaded_mods: Vec::new(),
+        });
+        let mut system = plugin::ModLoadSystem::default();
+
+        run_with(
+            &mut system,
+            &mut resources,
+            Some(ModLoadRequested {
+                path: "mods/easy_mode.rhai".into(),
+            }),
+        )
+        .await;
+        rng.lock().unwrap().1.insert("easy_mode".into(), 42);
+
+        run_with(
+            &mut system,
+            &mut resources,
+            Some(ModReloadRequested {
+                mod_id: "easy_mode".into(),
+            }),
+        )
+        .await;
+        let position = rng.lock().unwrap().1.get("easy_mode").copied();
+        assert_eq!(position, if reset { None } else { Some(42) });
+    }
+}
+
+#[test]
+fn test_multi_loader_splits_rng_positions() {
+    let rhai = SeedLoader::default();
+    let rhai_rng = rhai.rng.clone();
+    let mut loader = MultiLoader::new()
+        .with_loader(BackendLoader::new("wasm", ModBackend::Wasm))
+        .with_loader(rhai);
+    loader.set_master_seed(Some(crate::engine::MasterSeed(7)));
+    assert_eq!(
+        rhai_rng.lock().unwrap().0,
+        Some(crate::engine::MasterSeed(7))
+    );
+}
+
+/// Loader whose MODs set the combat difficulty from `on_init()`
+struct InitLoader {
+    commands: Vec<PluginControl>,
+}
+
+impl ModLoader for InitLoader {
+    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
+        let manifest = ModManifest::from_dir(path)?;
+        self.commands.push(
+            PluginControl::set_param("combat", "difficulty", 0.5).with_issuer(&manifest.name),
+        );
+        Ok(ModHandle {
+            id: manifest.name.clone(),
+            metadata: ModMetadata {
+                name: manifest.name,
+                version: manifest.version,
+                author: None,
+                description: None,
+                dependencies: Vec::new(),
+                priority: 0,
+                after: Vec::new(),
+            },
+            backend: ModBackend::Rhai,
+        })
+    }
+
+    fn unload(&mut self, _handle: &ModHandle) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn control_plugin(&mut self, _handle: &ModHandle, _control: &PluginControl) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn drain_commands(&mut self) -> Vec<PluginControl> {
+        std::mem::take(&mut self.commands)
+    }
+
+    fn clone_box(&self) -> Box<dyn ModLoader> {
+        Box::new(Self {
+            commands: Vec::new(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_update_mod_systems_applies_startup_params() {
+    use crate::event::EventBus;
+    use crate::plugin::{CombatConfig, CombatPlugin};
+    use crate::prelude::GameBuilder;
+
+    let root = tempfile::tempdir().unwrap();
+    write_mod_dir(root.path(), "balance", "1.0.0", &[]);
+    let mut game = GameBuilder::new()
+        .with_plugin(CombatPlugin::default())
+        .unwrap()
+        .with_plugin(
+            ModSystemPlugin::new()
+                .with_loader(InitLoader {
+                    commands: Vec::new(),
+                })
+                .with_mod_dir(root.path()),
+        )
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    // The command becomes an event on the first frame and is applied on the next
+    for _ in 0..2 {
+        update_mod_systems(&mut game.systems, &mut game.resources).await;
+        game.resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .dispatch();
+    }
+    update_mod_systems(&mut game.systems, &mut game.resources).await;
+
+    let config = game.resources.get::<CombatConfig>().await.unwrap();
+    assert_eq!(config.difficulty_multiplier, 0.5);
+}
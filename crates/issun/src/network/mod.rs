@@ -10,7 +10,28 @@ pub mod types;
 pub mod backend;
 
 #[cfg(feature = "network")]
-pub use types::{NetworkMetadata, NetworkScope, NetworkedEvent, NodeId};
+pub mod ordering;
+
+#[cfg(feature = "network")]
+pub mod shaping;
+
+#[cfg(feature = "network")]
+pub mod shared;
+
+#[cfg(feature = "network")]
+pub use types::{
+    Delivery, LegacyFramePolicy, NetworkMetadata, NetworkScope, NetworkedEvent, NodeId, RelayStamp,
+    NAMESPACE_JOIN,
+};
 
 #[cfg(feature = "network")]
 pub use backend::{NetworkBackend, QuicClientBackend};
+
+#[cfg(feature = "network")]
+pub use ordering::{NetworkGapDetected, OrderingConfig, RelaySequencer};
+
+#[cfg(feature = "network")]
+pub use shaping::NetworkSendStats;
+
+#[cfg(feature = "network")]
+pub use shared::{NamespacedBackend, SharedNetworkBackend};
@@ -0,0 +1,314 @@
+//! Server-sequenced delivery for ordered rooms
+//!
+//! In an ordered room the relay stamps every broadcast with a per-room
+//! sequence number ([`RelaySequencer`]) and echoes it to the sender as well.
+//! Clients that enable [`EventBus::with_ordered_delivery`](crate::event::EventBus::with_ordered_delivery)
+//! hold events back until the previous sequence number arrived, so every
+//! player and spectator in the room sees the same event order.
+
+use super::backend::RawNetworkEvent;
+use super::types::{now_millis, RelayStamp};
+use crate::event::Event;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Sequence numbers at or past this distance behind `next` count as stale
+const HALF_RANGE: u32 = 1 << 31;
+
+/// Assigns the relay stamps of one ordered room
+#[derive(Debug, Clone, Default)]
+pub struct RelaySequencer {
+    next: u32,
+}
+
+impl RelaySequencer {
+    /// Sequencer starting at 0
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sequencer starting at `sequence`
+    pub fn starting_at(sequence: u32) -> Self {
+        Self { next: sequence }
+    }
+
+    /// Stamp the next event, wrapping around after `u32::MAX`
+    pub fn stamp(&mut self) -> RelayStamp {
+        let sequence = self.next;
+        self.next = self.next.wrapping_add(1);
+        RelayStamp {
+            sequence,
+            timestamp: now_millis(),
+        }
+    }
+}
+
+/// How long a client waits for a missing sequence number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderingConfig {
+    /// Time to wait for a missing event before skipping it
+    pub gap_window: Duration,
+    /// Events held back at most; more skip the gap immediately
+    pub max_buffered: usize,
+}
+
+impl Default for OrderingConfig {
+    fn default() -> Self {
+        Self {
+            gap_window: Duration::from_millis(200),
+            max_buffered: 256,
+        }
+    }
+}
+
+/// Local event published when ordered delivery skips missing events
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkGapDetected {
+    /// First sequence number that never arrived
+    pub expected: u32,
+    /// Sequence number delivery resumed at
+    pub resumed_at: u32,
+    /// Number of skipped sequence numbers
+    pub missing: u32,
+}
+
+impl Event for NetworkGapDetected {}
+
+/// Output of [`SequenceBuffer::drain_ready`], in delivery order
+#[derive(Debug)]
+pub(crate) enum Ordered {
+    Event(RawNetworkEvent),
+    Gap(NetworkGapDetected),
+}
+
+/// Reorders stamped events into relay sequence order
+///
+/// Delivery starts at sequence 0, the room's first event, as soon as it
+/// arrives. A client that joined later waits one gap window and starts at
+/// the earliest sequence number it received. Sequence numbers are compared
+/// modulo 2^32, so the buffer follows the relay through wraparound; events
+/// more than 2^31 behind are duplicates or stale and are dropped.
+#[derive(Debug)]
+pub(crate) struct SequenceBuffer {
+    config: OrderingConfig,
+    // Next sequence to deliver, once the start is known
+    next: Option<u32>,
+    // First sequence received, reference for picking the start
+    first_seen: Option<u32>,
+    pending: HashMap<u32, RawNetworkEvent>,
+    // When delivery started waiting for `next`
+    waiting_since: Option<Instant>,
+}
+
+impl SequenceBuffer {
+    pub(crate) fn new(config: OrderingConfig) -> Self {
+        Self {
+            config,
+            next: None,
+            first_seen: None,
+            pending: HashMap::new(),
+            waiting_since: None,
+        }
+    }
+
+    /// Buffer an event stamped with `sequence`
+    pub(crate) fn push(&mut self, sequence: u32, event: RawNetworkEvent) {
+        match self.next {
+            Some(next) if sequence.wrapping_sub(next) >= HALF_RANGE => return,
+            Some(_) => {}
+            None => {
+                self.first_seen.get_or_insert(sequence);
+            }
+        }
+        self.pending.entry(sequence).or_insert(event);
+    }
+
+    /// Sequence to start delivering at, once it can be decided
+    fn start(&mut self, now: Instant) -> Option<u32> {
+        let first_seen = self.first_seen?;
+        // Earliest pending sequence, as a signed offset from the first seen
+        let earliest = self
+            .pending
+            .keys()
+            .copied()
+            .min_by_key(|sequence| sequence.wrapping_sub(first_seen) as i32)?;
+
+        let since = *self.waiting_since.get_or_insert(now);
+        let settled = earliest == 0
+            || now.duration_since(since) >= self.config.gap_window
+            || self.pending.len() > self.config.max_buffered;
+        settled.then_some(earliest)
+    }
+
+    /// Events that can be delivered at `now`, with the gaps given up on
+    pub(crate) fn drain_ready(&mut self, now: Instant) -> Vec<Ordered> {
+        let mut ready = Vec::new();
+        let mut next = match self.next {
+            Some(next) => next,
+            None => match self.start(now) {
+                Some(start) => {
+                    self.waiting_since = None;
+                    start
+                }
+                None => return ready,
+            },
+        };
+
+        loop {
+            while let Some(event) = self.pending.remove(&next) {
+                ready.push(Ordered::Event(event));
+                next = next.wrapping_add(1);
+                self.waiting_since = None;
+            }
+            if self.pending.is_empty() {
+                self.waiting_since = None;
+                break;
+            }
+
+            let since = *self.waiting_since.get_or_insert(now);
+            let overflowing = self.pending.len() > self.config.max_buffered;
+            if now.duration_since(since) < self.config.gap_window && !overflowing {
+                break;
+            }
+
+            let resumed_at = self
+                .pending
+                .keys()
+                .copied()
+                .min_by_key(|sequence| sequence.wrapping_sub(next))
+                .expect("pending is not empty");
+            ready.push(Ordered::Gap(NetworkGapDetected {
+                expected: next,
+                resumed_at,
+                missing: resumed_at.wrapping_sub(next),
+            }));
+            next = resumed_at;
+            self.waiting_since = None;
+        }
+
+        self.next = Some(next);
+        ready
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::{NetworkMetadata, NetworkScope, NodeId};
+
+    fn event(sequence: u32) -> RawNetworkEvent {
+        RawNetworkEvent {
+            metadata: NetworkMetadata::new(NodeId::from_u64(1), sequence as u64),
+            scope: NetworkScope::Broadcast,
+            type_name: "Move".to_string(),
+            payload: Vec::new(),
+        }
+    }
+
+    fn delivered(ready: &[Ordered]) -> Vec<Result<u64, u32>> {
+        ready
+            .iter()
+            .map(|ordered| match ordered {
+                Ordered::Event(event) => Ok(event.metadata.sequence),
+                Ordered::Gap(gap) => Err(gap.missing),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sequencer_wraps_around() {
+        let mut sequencer = RelaySequencer::starting_at(u32::MAX);
+        assert_eq!(sequencer.stamp().sequence, u32::MAX);
+        assert_eq!(sequencer.stamp().sequence, 0);
+    }
+
+    #[test]
+    fn test_reorders_within_window() {
+        let now = Instant::now();
+        let mut buffer = SequenceBuffer::new(OrderingConfig::default());
+
+        buffer.push(0, event(0));
+        buffer.push(2, event(2));
+        assert_eq!(delivered(&buffer.drain_ready(now)), [Ok(0)]);
+
+        buffer.push(1, event(1));
+        buffer.push(0, event(0)); // duplicate
+        assert_eq!(delivered(&buffer.drain_ready(now)), [Ok(1), Ok(2)]);
+    }
+
+    #[test]
+    fn test_gap_skipped_after_window() {
+        let now = Instant::now();
+        let mut buffer = SequenceBuffer::new(OrderingConfig {
+            gap_window: Duration::from_millis(50),
+            max_buffered: 16,
+        });
+
+        buffer.push(0, event(0));
+        buffer.push(3, event(3));
+        assert_eq!(delivered(&buffer.drain_ready(now)), [Ok(0)]);
+        assert!(buffer
+            .drain_ready(now + Duration::from_millis(30))
+            .is_empty());
+
+        let ready = buffer.drain_ready(now + Duration::from_millis(60));
+        assert_eq!(delivered(&ready), [Err(2), Ok(3)]);
+        match &ready[0] {
+            Ordered::Gap(gap) => assert_eq!((gap.expected, gap.resumed_at), (1, 3)),
+            other => panic!("expected a gap, got {:?}", other),
+        }
+
+        // Late arrivals of skipped events are stale
+        buffer.push(1, event(1));
+        assert!(buffer.drain_ready(now + Duration::from_secs(1)).is_empty());
+    }
+
+    #[test]
+    fn test_overflow_skips_gap_immediately() {
+        let now = Instant::now();
+        let mut buffer = SequenceBuffer::new(OrderingConfig {
+            gap_window: Duration::from_secs(60),
+            max_buffered: 2,
+        });
+
+        buffer.push(0, event(0));
+        for sequence in 2..5 {
+            buffer.push(sequence, event(sequence));
+        }
+        assert_eq!(
+            delivered(&buffer.drain_ready(now)),
+            [Ok(0), Err(1), Ok(2), Ok(3), Ok(4)]
+        );
+    }
+
+    #[test]
+    fn test_follows_wraparound() {
+        let now = Instant::now();
+        let mut buffer = SequenceBuffer::new(OrderingConfig::default());
+
+        buffer.push(u32::MAX - 1, event(1));
+        buffer.push(0, event(3));
+        buffer.push(u32::MAX, event(2));
+        assert!(buffer.drain_ready(now).is_empty());
+        let later = now + OrderingConfig::default().gap_window;
+        assert_eq!(delivered(&buffer.drain_ready(later)), [Ok(1), Ok(2), Ok(3)]);
+    }
+
+    #[test]
+    fn test_late_joiner_starts_after_window() {
+        let now = Instant::now();
+        let mut buffer = SequenceBuffer::new(OrderingConfig {
+            gap_window: Duration::from_millis(50),
+            max_buffered: 16,
+        });
+
+        buffer.push(41, event(41));
+        buffer.push(40, event(40));
+        assert!(buffer.drain_ready(now).is_empty());
+        assert_eq!(
+            delivered(&buffer.drain_ready(now + Duration::from_millis(50))),
+            [Ok(40), Ok(41)]
+        );
+    }
+}
@@ -0,0 +1,331 @@
+//! Client-side send shaping for networked events
+//!
+//! Two limits keep a client from saturating a slow link:
+//! - a send rate per event type ([`EventBus::set_send_rate`](crate::event::EventBus::set_send_rate)):
+//!   latest-only events over the rate wait for the next free slot, newer
+//!   values replacing older ones; other events over the rate are dropped
+//! - an outgoing byte budget ([`EventBus::set_send_budget`](crate::event::EventBus::set_send_budget)):
+//!   [`Delivery::Reliable`] events are always sent, first; the others are
+//!   deferred or shed per their [`Delivery`] class while the budget is spent
+//!
+//! Frames the bus sends for itself (namespace joins) are not shaped.
+
+use super::backend::RawNetworkEvent;
+use super::types::Delivery;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Outgoing traffic counted by the send shaper, see [`EventBus::stats`](crate::event::EventBus::stats)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkSendStats {
+    /// Events handed to the backend
+    pub sent: u64,
+    /// Serialized size of the sent events
+    pub sent_bytes: u64,
+    /// Sends suppressed by a send rate: dropped, or replaced by a newer
+    /// latest-only value before their slot came
+    pub rate_limited: u64,
+    /// [`Delivery::Deferrable`] events pushed to a later dispatch by the budget
+    pub deferred: u64,
+    /// [`Delivery::Unreliable`] events dropped by the budget
+    pub shed: u64,
+}
+
+/// A networked event waiting for [`SendShaper::shape`]
+pub(crate) struct Outgoing {
+    pub(crate) raw: RawNetworkEvent,
+    pub(crate) delivery: Delivery,
+    pub(crate) latest_only: bool,
+}
+
+/// Token bucket holding up to one second of budget
+struct Budget {
+    bytes_per_second: u64,
+    /// Negative after reliable events overspent it
+    tokens: f64,
+    refilled: Option<Instant>,
+}
+
+impl Budget {
+    fn new(bytes_per_second: u64) -> Self {
+        Self {
+            bytes_per_second,
+            tokens: bytes_per_second as f64,
+            refilled: None,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        if let Some(last) = self.refilled {
+            let elapsed = now.saturating_duration_since(last).as_secs_f64();
+            self.tokens = (self.tokens + elapsed * self.bytes_per_second as f64)
+                .min(self.bytes_per_second as f64);
+        }
+        self.refilled = Some(now);
+    }
+}
+
+/// Applies send rates and the byte budget to outgoing events
+#[derive(Default)]
+pub(crate) struct SendShaper {
+    /// Sends per second, by type name
+    rates: HashMap<String, f64>,
+    last_sent: HashMap<String, Instant>,
+    /// Latest-only values waiting for their type's next slot, with their lane
+    held: HashMap<String, (usize, Outgoing)>,
+    /// Events the budget pushed back, with their lane
+    deferred: Vec<(usize, Outgoing)>,
+    budget: Option<Budget>,
+    stats: NetworkSendStats,
+}
+
+impl SendShaper {
+    /// Send `type_name` at most `hz` times per second; `None` removes the limit
+    pub(crate) fn set_rate(&mut self, type_name: &str, hz: Option<f64>) {
+        match hz.filter(|hz| hz.is_finite() && *hz > 0.0) {
+            Some(hz) => {
+                self.rates.insert(type_name.to_string(), hz);
+            }
+            None => {
+                self.rates.remove(type_name);
+                if let Some((lane, held)) = self.held.remove(type_name) {
+                    self.deferred.push((lane, held));
+                }
+            }
+        }
+    }
+
+    pub(crate) fn rate(&self, type_name: &str) -> Option<f64> {
+        self.rates.get(type_name).copied()
+    }
+
+    /// Limit outgoing events to `bytes_per_second`; `None` removes the budget
+    pub(crate) fn set_budget(&mut self, bytes_per_second: Option<u64>) {
+        match (bytes_per_second, &mut self.budget) {
+            (Some(rate), Some(budget)) => {
+                budget.bytes_per_second = rate;
+                budget.tokens = budget.tokens.min(rate as f64);
+            }
+            (Some(rate), None) => self.budget = Some(Budget::new(rate)),
+            (None, _) => self.budget = None,
+        }
+    }
+
+    pub(crate) fn budget(&self) -> Option<u64> {
+        self.budget.as_ref().map(|budget| budget.bytes_per_second)
+    }
+
+    pub(crate) fn stats(&self) -> NetworkSendStats {
+        self.stats
+    }
+
+    /// Events of `outgoing` to send at `now`, lane by lane
+    ///
+    /// Takes every queued event; what is not returned is held for a later
+    /// call or dropped.
+    pub(crate) fn shape(
+        &mut self,
+        outgoing: &mut [Vec<Outgoing>; 3],
+        now: Instant,
+    ) -> Vec<RawNetworkEvent> {
+        let mut lanes: [Vec<Outgoing>; 3] = Default::default();
+        for (lane, event) in self.deferred.drain(..) {
+            lanes[lane].push(event);
+        }
+
+        // Send rates
+        for (lane, queued) in outgoing.iter_mut().enumerate() {
+            for event in queued.drain(..) {
+                let type_name = &event.raw.type_name;
+                if event.latest_only {
+                    // A newer value replaces one the budget pushed back
+                    lanes
+                        .iter_mut()
+                        .for_each(|lane| lane.retain(|old| &old.raw.type_name != type_name));
+                }
+                let Some(interval) = self.interval(type_name) else {
+                    lanes[lane].push(event);
+                    continue;
+                };
+                if event.latest_only {
+                    if self.held.insert(type_name.clone(), (lane, event)).is_some() {
+                        self.stats.rate_limited += 1;
+                    }
+                } else if self.slot_free(type_name, interval, now) {
+                    self.last_sent.insert(type_name.clone(), now);
+                    lanes[lane].push(event);
+                } else {
+                    self.stats.rate_limited += 1;
+                }
+            }
+        }
+        let ready: Vec<String> = self
+            .held
+            .keys()
+            .filter(|type_name| {
+                self.interval(type_name)
+                    .is_some_and(|interval| self.slot_free(type_name, interval, now))
+            })
+            .cloned()
+            .collect();
+        for type_name in ready {
+            let (lane, event) = self.held.remove(&type_name).expect("held");
+            self.last_sent.insert(type_name, now);
+            lanes[lane].push(event);
+        }
+
+        // Byte budget
+        let Some(budget) = self.budget.as_mut() else {
+            let sent: Vec<_> = lanes.into_iter().flatten().map(|e| e.raw).collect();
+            for raw in &sent {
+                self.stats.sent += 1;
+                self.stats.sent_bytes += size_of(raw);
+            }
+            return sent;
+        };
+        budget.refill(now);
+
+        let mut sent = Vec::new();
+        let (reliable, rest): (Vec<_>, Vec<_>) = lanes
+            .into_iter()
+            .enumerate()
+            .flat_map(|(lane, events)| events.into_iter().map(move |event| (lane, event)))
+            .partition(|(_, event)| event.delivery == Delivery::Reliable);
+        for (_, event) in reliable {
+            let size = size_of(&event.raw);
+            budget.tokens -= size as f64;
+            self.stats.sent += 1;
+            self.stats.sent_bytes += size;
+            sent.push(event.raw);
+        }
+        for (lane, event) in rest {
+            let size = size_of(&event.raw);
+            if budget.tokens >= size as f64 {
+                budget.tokens -= size as f64;
+                self.stats.sent += 1;
+                self.stats.sent_bytes += size;
+                sent.push(event.raw);
+            } else if event.delivery == Delivery::Deferrable {
+                self.stats.deferred += 1;
+                self.deferred.push((lane, event));
+            } else {
+                self.stats.shed += 1;
+            }
+        }
+        sent
+    }
+
+    /// Minimum time between two sends of `type_name`
+    fn interval(&self, type_name: &str) -> Option<Duration> {
+        self.rates
+            .get(type_name)
+            .map(|hz| Duration::from_secs_f64(1.0 / hz))
+    }
+
+    fn slot_free(&self, type_name: &str, interval: Duration, now: Instant) -> bool {
+        self.last_sent
+            .get(type_name)
+            .is_none_or(|last| now.saturating_duration_since(*last) >= interval)
+    }
+}
+
+fn size_of(raw: &RawNetworkEvent) -> u64 {
+    bincode::serialized_size(raw).unwrap_or(raw.payload.len() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::{NetworkMetadata, NetworkScope, NodeId};
+
+    fn event(type_name: &str, value: u8, delivery: Delivery, latest_only: bool) -> Outgoing {
+        Outgoing {
+            raw: RawNetworkEvent {
+                metadata: NetworkMetadata::new(NodeId::from_u64(1), 0),
+                scope: NetworkScope::Broadcast,
+                type_name: type_name.to_string(),
+                payload: vec![value; 16],
+            },
+            delivery,
+            latest_only,
+        }
+    }
+
+    fn values(sent: &[RawNetworkEvent]) -> Vec<(&str, u8)> {
+        sent.iter()
+            .map(|raw| (raw.type_name.as_str(), raw.payload[0]))
+            .collect()
+    }
+
+    #[test]
+    fn test_rate_limited_events_without_latest_only_are_dropped() {
+        let mut shaper = SendShaper::default();
+        shaper.set_rate("click", Some(10.0));
+        let start = Instant::now();
+
+        let mut outgoing: [Vec<Outgoing>; 3] = Default::default();
+        outgoing[1].push(event("click", 1, Delivery::Reliable, false));
+        outgoing[1].push(event("click", 2, Delivery::Reliable, false));
+        assert_eq!(values(&shaper.shape(&mut outgoing, start)), [("click", 1)]);
+
+        outgoing[1].push(event("click", 3, Delivery::Reliable, false));
+        let later = start + Duration::from_millis(50);
+        assert!(shaper.shape(&mut outgoing, later).is_empty());
+        assert_eq!(shaper.stats().rate_limited, 2);
+
+        // The limit can be lifted at runtime
+        shaper.set_rate("click", None);
+        outgoing[1].push(event("click", 4, Delivery::Reliable, false));
+        assert_eq!(values(&shaper.shape(&mut outgoing, later)), [("click", 4)]);
+        assert_eq!(shaper.rate("click"), None);
+    }
+
+    #[test]
+    fn test_held_latest_value_goes_out_in_the_next_slot() {
+        let mut shaper = SendShaper::default();
+        shaper.set_rate("cursor", Some(10.0));
+        let start = Instant::now();
+
+        let mut outgoing: [Vec<Outgoing>; 3] = Default::default();
+        outgoing[1].push(event("cursor", 1, Delivery::Unreliable, true));
+        assert_eq!(values(&shaper.shape(&mut outgoing, start)), [("cursor", 1)]);
+
+        outgoing[1].push(event("cursor", 2, Delivery::Unreliable, true));
+        assert!(shaper
+            .shape(&mut outgoing, start + Duration::from_millis(40))
+            .is_empty());
+        outgoing[1].push(event("cursor", 3, Delivery::Unreliable, true));
+        assert!(shaper
+            .shape(&mut outgoing, start + Duration::from_millis(80))
+            .is_empty());
+
+        // Nothing new published, the held value goes out
+        let sent = shaper.shape(&mut outgoing, start + Duration::from_millis(100));
+        assert_eq!(values(&sent), [("cursor", 3)]);
+        assert_eq!(shaper.stats().rate_limited, 1);
+    }
+
+    #[test]
+    fn test_budget_sends_reliable_first_and_defers_or_sheds_the_rest() {
+        let mut shaper = SendShaper::default();
+        let size = size_of(&event("chat", 0, Delivery::Reliable, false).raw);
+        shaper.set_budget(Some(size * 2));
+        let start = Instant::now();
+
+        let mut outgoing: [Vec<Outgoing>; 3] = Default::default();
+        outgoing[0].push(event("move", 1, Delivery::Unreliable, false));
+        outgoing[1].push(event("wave", 2, Delivery::Deferrable, false));
+        outgoing[1].push(event("move", 3, Delivery::Unreliable, false));
+        outgoing[2].push(event("chat", 4, Delivery::Reliable, false));
+        let sent = shaper.shape(&mut outgoing, start);
+        assert_eq!(values(&sent), [("chat", 4), ("move", 1)]);
+        assert_eq!((shaper.stats().deferred, shaper.stats().shed), (1, 1));
+
+        // Half a second refills one event's worth
+        let sent = shaper.shape(&mut outgoing, start + Duration::from_millis(500));
+        assert_eq!(values(&sent), [("wave", 2)]);
+        assert_eq!(shaper.stats().sent, 3);
+        assert_eq!(shaper.stats().sent_bytes, size * 3);
+    }
+}
@@ -0,0 +1,187 @@
+//! One network connection shared by the buses of several matches
+//!
+//! A headless server running many matches in one process opens a single
+//! backend and hands each match's [`EventBus`](crate::event::EventBus) a
+//! [`NamespacedBackend`]. Incoming frames are demultiplexed by the
+//! namespace in their metadata; the relay routes each namespace to the
+//! connections that joined it, so one connection carries every match.
+//!
+//! ```ignore
+//! let shared = SharedNetworkBackend::new(QuicClientBackend::connect_to_server(addr).await?);
+//! let bus = EventBus::new()
+//!     .with_namespace("match-42")
+//!     .with_network(shared.handle("match-42"));
+//! ```
+
+use super::backend::{NetworkBackend, RawNetworkEvent};
+use super::types::NodeId;
+use crate::error::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::mpsc;
+
+/// Frames buffered per namespace before the demultiplexer waits
+const HANDLE_CAPACITY: usize = 1000;
+
+type Routes = Arc<Mutex<HashMap<String, mpsc::Sender<RawNetworkEvent>>>>;
+
+/// A backend shared by several namespaced buses
+///
+/// Frames of a namespace go to its handle only. Frames without a namespace
+/// go to every handle, whose bus applies its
+/// [`LegacyFramePolicy`](super::LegacyFramePolicy); frames of a namespace
+/// without a handle are dropped and counted in [`unrouted`](Self::unrouted).
+///
+/// Must be created inside a tokio runtime.
+pub struct SharedNetworkBackend {
+    backend: Arc<dyn NetworkBackend>,
+    routes: Routes,
+    unrouted: Arc<AtomicU64>,
+}
+
+impl SharedNetworkBackend {
+    /// Take over `backend`'s receive stream and start demultiplexing it
+    pub fn new(backend: impl NetworkBackend) -> Self {
+        let backend: Arc<dyn NetworkBackend> = Arc::new(backend);
+        let routes: Routes = Arc::new(Mutex::new(HashMap::new()));
+        let unrouted = Arc::new(AtomicU64::new(0));
+
+        let incoming = backend.receive_stream();
+        tokio::spawn(demultiplex(incoming, routes.clone(), unrouted.clone()));
+
+        Self {
+            backend,
+            routes,
+            unrouted,
+        }
+    }
+
+    /// Backend for the bus of `namespace`
+    ///
+    /// A second handle for the same namespace takes its frames over from
+    /// the first.
+    pub fn handle(&self, namespace: impl Into<String>) -> NamespacedBackend {
+        let namespace = namespace.into();
+        let (tx, rx) = mpsc::channel(HANDLE_CAPACITY);
+        self.routes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(namespace.clone(), tx);
+
+        NamespacedBackend {
+            namespace,
+            backend: self.backend.clone(),
+            rx: Mutex::new(Some(rx)),
+        }
+    }
+
+    /// This node's ID, the same for every handle
+    pub fn node_id(&self) -> NodeId {
+        self.backend.node_id()
+    }
+
+    /// Frames dropped because no handle was open for their namespace
+    pub fn unrouted(&self) -> u64 {
+        self.unrouted.load(Ordering::Relaxed)
+    }
+}
+
+async fn demultiplex(
+    mut incoming: mpsc::Receiver<RawNetworkEvent>,
+    routes: Routes,
+    unrouted: Arc<AtomicU64>,
+) {
+    while let Some(event) = incoming.recv().await {
+        // Clone the senders out so no lock is held while a handle is full
+        let targets: Vec<(String, mpsc::Sender<RawNetworkEvent>)> = {
+            let routes = routes.lock().unwrap_or_else(PoisonError::into_inner);
+            match &event.metadata.namespace {
+                Some(namespace) => routes
+                    .get(namespace)
+                    .map(|tx| (namespace.clone(), tx.clone()))
+                    .into_iter()
+                    .collect(),
+                None => routes
+                    .iter()
+                    .map(|(namespace, tx)| (namespace.clone(), tx.clone()))
+                    .collect(),
+            }
+        };
+        if targets.is_empty() {
+            unrouted.fetch_add(1, Ordering::Relaxed);
+            continue;
+        }
+
+        for (namespace, tx) in targets {
+            if tx.send(event.clone()).await.is_err() {
+                // The bus was dropped; forget its route unless it was replaced
+                let mut routes = routes.lock().unwrap_or_else(PoisonError::into_inner);
+                if routes
+                    .get(&namespace)
+                    .is_some_and(|route| route.is_closed())
+                {
+                    routes.remove(&namespace);
+                }
+            }
+        }
+    }
+}
+
+/// One namespace's view of a [`SharedNetworkBackend`]
+///
+/// Sends go out on the shared connection, stamped with the namespace if
+/// the bus didn't. `connect` and `disconnect` are no-ops: the connection
+/// belongs to the shared backend.
+pub struct NamespacedBackend {
+    namespace: String,
+    backend: Arc<dyn NetworkBackend>,
+    rx: Mutex<Option<mpsc::Receiver<RawNetworkEvent>>>,
+}
+
+impl NamespacedBackend {
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+}
+
+#[async_trait]
+impl NetworkBackend for NamespacedBackend {
+    fn node_id(&self) -> NodeId {
+        self.backend.node_id()
+    }
+
+    async fn send(&self, mut event: RawNetworkEvent) -> Result<()> {
+        event
+            .metadata
+            .namespace
+            .get_or_insert_with(|| self.namespace.clone());
+        self.backend.send(event).await
+    }
+
+    fn receive_stream(&self) -> mpsc::Receiver<RawNetworkEvent> {
+        // Can only be taken once, like the other backends
+        match self
+            .rx
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+        {
+            Some(rx) => rx,
+            None => mpsc::channel(1).1,
+        }
+    }
+
+    async fn connect(&mut self, _addr: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.backend.is_connected()
+    }
+}
@@ -39,6 +39,11 @@ pub struct NetworkMetadata {
     pub timestamp: u64,
     /// Sequence number for ordering guarantees
     pub sequence: u64,
+    /// Stamp assigned by the relay in ordered rooms
+    pub relay: Option<RelayStamp>,
+    /// Namespace of the sending bus (e.g. a match id); `None` for buses
+    /// without one and for frames of older clients
+    pub namespace: Option<String>,
 }
 
 impl NetworkMetadata {
@@ -48,10 +53,35 @@ impl NetworkMetadata {
             sender,
             timestamp: now_millis(),
             sequence,
+            relay: None,
+            namespace: None,
         }
     }
 }
 
+/// Type name of the frame a namespaced bus sends when it joins the network,
+/// so the relay routes the namespace to it before it publishes anything
+pub const NAMESPACE_JOIN: &str = "issun::network::NamespaceJoin";
+
+/// What a namespaced bus does with incoming frames that carry no namespace
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LegacyFramePolicy {
+    /// Deliver them, as sent by a client that predates namespaces (default)
+    #[default]
+    Deliver,
+    /// Ignore them like frames of another namespace
+    Ignore,
+}
+
+/// Position of an event in an ordered room's timeline, assigned by the relay
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayStamp {
+    /// Per-room sequence number; wraps around after `u32::MAX`
+    pub sequence: u32,
+    /// Relay clock, Unix timestamp in milliseconds
+    pub timestamp: u64,
+}
+
 /// Event propagation scope
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum NetworkScope {
@@ -64,6 +94,19 @@ pub enum NetworkScope {
     Targeted(NodeId),
 }
 
+/// What happens to a networked event when the outgoing byte budget is spent
+/// (see [`EventBus::set_send_budget`](crate::event::EventBus::set_send_budget))
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Delivery {
+    /// Always sent, ahead of the other classes (default)
+    #[default]
+    Reliable,
+    /// Waits for a later dispatch
+    Deferrable,
+    /// Dropped; for gameplay updates that the next one supersedes
+    Unreliable,
+}
+
 /// Wrapper for networked events with metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkedEvent<T> {
@@ -113,6 +156,8 @@ mod tests {
         assert_eq!(metadata.sender, sender);
         assert_eq!(metadata.sequence, 10);
         assert!(metadata.timestamp > 0);
+        assert!(metadata.relay.is_none());
+        assert!(metadata.namespace.is_none());
     }
 
     #[test]
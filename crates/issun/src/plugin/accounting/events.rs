@@ -28,6 +28,19 @@ pub struct BudgetTransferRequested {
 
 impl Event for BudgetTransferRequested {}
 
+/// Request to post income earned outside settlement (e.g. trade routes)
+///
+/// `AccountingSystem` adds `amount` to the cash channel and publishes
+/// `IncomePostedEvent`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncomePostRequested {
+    /// What earned the income, e.g. `"trade:route-1"`
+    pub source: String,
+    pub amount: Currency,
+}
+
+impl Event for IncomePostRequested {}
+
 // =============================================================================
 // State Events (Notification)
 // =============================================================================
@@ -53,6 +66,15 @@ pub struct BudgetTransferredEvent {
 
 impl Event for BudgetTransferredEvent {}
 
+/// Published when posted income reached the budget ledger
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncomePostedEvent {
+    pub source: String,
+    pub amount: Currency,
+}
+
+impl Event for IncomePostedEvent {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -21,8 +21,9 @@ use super::state::AccountingState;
 /// 1. Listens for DayChanged events and runs settlements
 /// 2. Processes manual settlement requests
 /// 3. Processes budget transfer requests
-/// 4. Calls hooks for custom income/expense calculations
-/// 5. Publishes state change events for network replication
+/// 4. Posts income requested by other systems
+/// 5. Calls hooks for custom income/expense calculations
+/// 6. Publishes state change events for network replication
 ///
 /// # Feedback Loop
 ///
@@ -48,6 +49,7 @@ impl AccountingSystem {
         self.process_day_changed_events(services, resources).await;
         self.process_settlement_requests(services, resources).await;
         self.process_transfer_requests(resources).await;
+        self.process_income_posts(resources).await;
     }
 
     /// Process day changed events for auto-settlement
@@ -223,6 +225,34 @@ impl AccountingSystem {
             }
         }
     }
+
+    /// Process income post requests
+    async fn process_income_posts(&mut self, resources: &mut ResourceContext) {
+        let requests = {
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                let reader = bus.reader::<IncomePostRequested>();
+                reader.iter().cloned().collect::<Vec<_>>()
+            } else {
+                Vec::new()
+            }
+        };
+
+        for request in requests {
+            {
+                let Some(mut ledger) = resources.get_mut::<BudgetLedger>().await else {
+                    return;
+                };
+                ledger.cash = ledger.cash.saturating_add(request.amount);
+            }
+
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.publish(IncomePostedEvent {
+                    source: request.source,
+                    amount: request.amount,
+                });
+            }
+        }
+    }
 }
 
 #[async_trait]
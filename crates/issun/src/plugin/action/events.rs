@@ -57,6 +57,63 @@ pub struct ActionsResetEvent {
     pub new_count: u32,
 }
 
-// Implement Event trait for both events
+/// Published by the game when the player chooses a registered action
+///
+/// Actions contributed by MODs are validated and performed by
+/// `ModBridgeSystem`, which answers with `ActionPerformed` or `ActionFailed`.
+///
+/// # Example
+///
+/// ```ignore
+/// use issun::plugin::action::ActionRequested;
+///
+/// bus.publish(ActionRequested {
+///     action_id: "propaganda:campaign".to_string(),
+///     actor: Some("player".to_string()),
+///     target: Some(serde_json::json!({ "district": "north" })),
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionRequested {
+    /// Id of the registered action
+    pub action_id: String,
+    /// Who performs the action, if the game tracks it
+    pub actor: Option<String>,
+    /// Target selected in the UI, if any
+    pub target: Option<serde_json::Value>,
+}
+
+/// Published once a requested action has been carried out
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionPerformed {
+    /// Id of the registered action
+    pub action_id: String,
+    /// Actor from the request
+    pub actor: Option<String>,
+    /// Action points consumed
+    pub ap_cost: u32,
+    /// Action points remaining afterwards
+    pub remaining: u32,
+}
+
+/// Published when a requested action was rejected or its effect failed
+///
+/// Points taken for an action that failed are given back; `refunded` is
+/// 0 when the request was rejected before anything was consumed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionFailed {
+    /// Id of the requested action
+    pub action_id: String,
+    /// Actor from the request
+    pub actor: Option<String>,
+    /// Why the action did not happen
+    pub reason: String,
+    /// Action points given back
+    pub refunded: u32,
+}
+
 impl Event for ActionConsumedEvent {}
 impl Event for ActionsResetEvent {}
+impl Event for ActionRequested {}
+impl Event for ActionPerformed {}
+impl Event for ActionFailed {}
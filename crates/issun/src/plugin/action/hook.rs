@@ -13,15 +13,15 @@ use async_trait::async_trait;
 /// # Example
 ///
 /// ```ignore
+/// use issun::hook_impl;
 /// use issun::plugin::action::{ActionHook, ActionConsumed};
 /// use issun::context::ResourceContext;
-/// use async_trait::async_trait;
 ///
 /// struct GameLogHook;
 ///
-/// #[async_trait]
+/// #[hook_impl]
 /// impl ActionHook for GameLogHook {
-///     async fn on_action_consumed(
+///     fn on_action_consumed(
 ///         &self,
 ///         consumed: &ActionConsumed,
 ///         resources: &mut ResourceContext,
@@ -47,7 +47,17 @@ pub trait ActionHook: Send + Sync {
     ///
     /// * `consumed` - Details about the consumed action
     /// * `resources` - Access to game resources for modification
-    async fn on_action_consumed(&self, consumed: &ActionConsumed, resources: &mut ResourceContext);
+    ///
+    /// # Default
+    ///
+    /// Does nothing
+    async fn on_action_consumed(
+        &self,
+        _consumed: &ActionConsumed,
+        _resources: &mut ResourceContext,
+    ) {
+        // Default: do nothing
+    }
 
     /// Called when actions are depleted
     ///
@@ -95,12 +105,8 @@ pub trait ActionHook: Send + Sync {
 #[derive(Default, Debug, Clone, Copy)]
 pub struct DefaultActionHook;
 
-#[async_trait]
-impl ActionHook for DefaultActionHook {
-    async fn on_action_consumed(&self, _: &ActionConsumed, _: &mut ResourceContext) {
-        // No-op
-    }
-}
+#[crate::hook_impl]
+impl ActionHook for DefaultActionHook {}
 
 #[cfg(test)]
 mod tests {
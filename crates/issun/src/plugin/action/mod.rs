@@ -3,11 +3,15 @@
 mod events;
 mod hook;
 mod plugin;
+mod registry;
 mod resources;
 mod systems;
 
-pub use events::{ActionConsumedEvent, ActionsResetEvent};
+pub use events::{
+    ActionConsumedEvent, ActionFailed, ActionPerformed, ActionRequested, ActionsResetEvent,
+};
 pub use hook::{ActionHook, DefaultActionHook};
 pub use plugin::{ActionConfig, ActionPlugin};
+pub use registry::{ActionDefinition, ActionRegistry};
 pub use resources::{ActionConsumed, ActionError, ActionPoints};
 pub use systems::{ActionResetSystem, ActionSystem};
@@ -2,7 +2,7 @@
 
 use super::hook::{ActionHook, DefaultActionHook};
 use super::systems::{ActionResetSystem, ActionSystem};
-use super::ActionPoints;
+use super::{ActionPoints, ActionRegistry};
 use crate::plugin::{Plugin, PluginBuilder, PluginBuilderExt};
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -25,7 +25,7 @@ impl crate::resources::Resource for ActionConfig {}
 /// Built-in action points plugin with hook support
 ///
 /// This plugin provides action point management for turn-based games.
-/// It registers ActionPoints resource and systems that handle:
+/// It registers the ActionPoints and ActionRegistry resources and systems that handle:
 /// - Processing ActionConsumedEvent with custom hooks (ActionSystem)
 /// - Resetting action points when day changes (ActionResetSystem)
 ///
@@ -155,6 +155,10 @@ impl Plugin for ActionPlugin {
         // Register ActionPoints as runtime resource
         let points = ActionPoints::new(self.config.max_per_period);
         builder.register_runtime_state(points);
+        builder.register_reset_to_initial::<ActionPoints>();
+
+        // Actions the game or MODs make available to the player
+        builder.register_runtime_state(ActionRegistry::new());
 
         // Register systems with hook
         builder.register_system(Box::new(ActionSystem::new(Arc::clone(&self.hook))));
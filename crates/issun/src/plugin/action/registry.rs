@@ -0,0 +1,254 @@
+//! Registry of the actions a player can choose
+
+use super::resources::{ActionError, ActionPoints};
+use std::collections::HashMap;
+
+/// A player action with its cost
+///
+/// Actions contributed by MODs carry the MOD id as `source` and are
+/// performed by `ModBridgeSystem`; the game performs its own actions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionDefinition {
+    /// Unique id (`"<mod_id>:<name>"` for MOD actions)
+    pub id: String,
+    /// Localization key of the menu label
+    pub label_key: String,
+    /// Action points consumed when performed
+    pub ap_cost: u32,
+    /// Days before the action can be performed again (0: no cooldown)
+    pub cooldown_days: u32,
+    /// MOD that contributed the action, if any
+    pub source: Option<String>,
+}
+
+impl ActionDefinition {
+    /// Action without cooldown, labelled by its id
+    pub fn new(id: impl Into<String>, ap_cost: u32) -> Self {
+        let id = id.into();
+        Self {
+            label_key: id.clone(),
+            id,
+            ap_cost,
+            cooldown_days: 0,
+            source: None,
+        }
+    }
+
+    /// Use `label_key` for the menu label
+    pub fn with_label(mut self, label_key: impl Into<String>) -> Self {
+        self.label_key = label_key.into();
+        self
+    }
+
+    /// Require `days` days between two uses
+    pub fn with_cooldown(mut self, days: u32) -> Self {
+        self.cooldown_days = days;
+        self
+    }
+
+    /// Mark the action as contributed by `mod_id`
+    pub fn with_source(mut self, mod_id: impl Into<String>) -> Self {
+        self.source = Some(mod_id.into());
+        self
+    }
+}
+
+/// Actions available to the player, in registration order
+///
+/// Registered by `ActionPlugin` as runtime state. Also remembers the day
+/// each action was last performed, for cooldowns.
+///
+/// # Example
+///
+/// ```
+/// use issun::plugin::action::{ActionDefinition, ActionError, ActionPoints, ActionRegistry};
+///
+/// let mut registry = ActionRegistry::new();
+/// registry.register(ActionDefinition::new("scout", 1).with_cooldown(2));
+///
+/// let points = ActionPoints::new(3);
+/// assert!(registry.check("scout", &points, 1).is_ok());
+///
+/// registry.mark_used("scout", 1);
+/// assert_eq!(
+///     registry.check("scout", &points, 2),
+///     Err(ActionError::CoolingDown { ready_on: 3 })
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ActionRegistry {
+    definitions: Vec<ActionDefinition>,
+    last_used: HashMap<String, u32>,
+}
+
+impl ActionRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an action, replacing the one with the same id
+    ///
+    /// Returns the replaced definition. A replaced action keeps its place
+    /// in the menu order.
+    pub fn register(&mut self, definition: ActionDefinition) -> Option<ActionDefinition> {
+        match self.definitions.iter_mut().find(|d| d.id == definition.id) {
+            Some(existing) => Some(std::mem::replace(existing, definition)),
+            None => {
+                self.definitions.push(definition);
+                None
+            }
+        }
+    }
+
+    /// Remove the action with the given id
+    pub fn remove(&mut self, id: &str) -> Option<ActionDefinition> {
+        let index = self.definitions.iter().position(|d| d.id == id)?;
+        self.last_used.remove(id);
+        Some(self.definitions.remove(index))
+    }
+
+    /// Remove every action contributed by `mod_id`
+    ///
+    /// Returns the removed definitions.
+    pub fn remove_source(&mut self, mod_id: &str) -> Vec<ActionDefinition> {
+        let (removed, kept) = std::mem::take(&mut self.definitions)
+            .into_iter()
+            .partition(|d| d.source.as_deref() == Some(mod_id));
+        self.definitions = kept;
+        for definition in &removed {
+            self.last_used.remove(&definition.id);
+        }
+        removed
+    }
+
+    /// Action with the given id
+    pub fn get(&self, id: &str) -> Option<&ActionDefinition> {
+        self.definitions.iter().find(|d| d.id == id)
+    }
+
+    /// Registered actions in registration order
+    pub fn iter(&self) -> impl Iterator<Item = &ActionDefinition> {
+        self.definitions.iter()
+    }
+
+    /// Number of registered actions
+    pub fn len(&self) -> usize {
+        self.definitions.len()
+    }
+
+    /// Whether no action is registered
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+
+    /// Whether `id` can be performed on `day` with `points`
+    ///
+    /// Checks the cooldown first, then the action point cost.
+    pub fn check(
+        &self,
+        id: &str,
+        points: &ActionPoints,
+        day: u32,
+    ) -> Result<&ActionDefinition, ActionError> {
+        let definition = self.get(id).ok_or(ActionError::UnknownAction)?;
+
+        if let Some(ready_on) = self.ready_on(id) {
+            if day < ready_on {
+                return Err(ActionError::CoolingDown { ready_on });
+            }
+        }
+        if !points.can_consume(definition.ap_cost) {
+            return Err(ActionError::Insufficient {
+                needed: definition.ap_cost,
+                available: points.available,
+            });
+        }
+        Ok(definition)
+    }
+
+    /// Record that `id` was performed on `day`
+    pub fn mark_used(&mut self, id: &str, day: u32) {
+        if self.get(id).is_some() {
+            self.last_used.insert(id.to_string(), day);
+        }
+    }
+
+    /// First day `id` can be performed again, if it is on cooldown
+    pub fn ready_on(&self, id: &str) -> Option<u32> {
+        let definition = self.get(id)?;
+        if definition.cooldown_days == 0 {
+            return None;
+        }
+        let last = self.last_used.get(id)?;
+        Some(last.saturating_add(definition.cooldown_days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_replaces_in_place() {
+        let mut registry = ActionRegistry::new();
+        registry.register(ActionDefinition::new("a", 1));
+        registry.register(ActionDefinition::new("b", 1));
+
+        let replaced = registry.register(ActionDefinition::new("a", 2));
+        assert_eq!(replaced.map(|d| d.ap_cost), Some(1));
+
+        let ids: Vec<_> = registry.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, ["a", "b"]);
+        assert_eq!(registry.get("a").unwrap().ap_cost, 2);
+    }
+
+    #[test]
+    fn test_check_cost_and_unknown() {
+        let mut registry = ActionRegistry::new();
+        registry.register(ActionDefinition::new("campaign", 2));
+
+        let mut points = ActionPoints::new(3);
+        assert!(registry.check("campaign", &points, 1).is_ok());
+
+        points.consume_n(2);
+        assert_eq!(
+            registry.check("campaign", &points, 1),
+            Err(ActionError::Insufficient {
+                needed: 2,
+                available: 1
+            })
+        );
+        assert_eq!(
+            registry.check("missing", &points, 1),
+            Err(ActionError::UnknownAction)
+        );
+    }
+
+    #[test]
+    fn test_cooldown_expires() {
+        let mut registry = ActionRegistry::new();
+        registry.register(ActionDefinition::new("scout", 1).with_cooldown(2));
+        let points = ActionPoints::new(3);
+
+        registry.mark_used("scout", 4);
+        assert_eq!(registry.ready_on("scout"), Some(6));
+        assert!(registry.check("scout", &points, 5).is_err());
+        assert!(registry.check("scout", &points, 6).is_ok());
+    }
+
+    #[test]
+    fn test_remove_source() {
+        let mut registry = ActionRegistry::new();
+        registry.register(ActionDefinition::new("base", 1));
+        registry.register(ActionDefinition::new("m:one", 1).with_source("m"));
+        registry.register(ActionDefinition::new("m:two", 1).with_source("m"));
+        registry.mark_used("m:one", 1);
+
+        let removed = registry.remove_source("m");
+        assert_eq!(removed.len(), 2);
+        assert_eq!(registry.len(), 1);
+        assert!(registry.get("base").is_some());
+        assert_eq!(registry.ready_on("m:one"), None);
+    }
+}
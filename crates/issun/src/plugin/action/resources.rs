@@ -19,12 +19,25 @@ pub struct ActionConsumed {
 pub enum ActionError {
     /// No actions remaining
     Depleted,
+    /// Fewer action points than the action costs
+    Insufficient { needed: u32, available: u32 },
+    /// The action was performed too recently
+    CoolingDown { ready_on: u32 },
+    /// No registered action has the requested id
+    UnknownAction,
 }
 
 impl fmt::Display for ActionError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ActionError::Depleted => write!(f, "No action points remaining"),
+            ActionError::Insufficient { needed, available } => {
+                write!(f, "Needs {} action points, {} remaining", needed, available)
+            }
+            ActionError::CoolingDown { ready_on } => {
+                write!(f, "Action is on cooldown until day {}", ready_on)
+            }
+            ActionError::UnknownAction => write!(f, "Unknown action"),
         }
     }
 }
@@ -181,6 +194,30 @@ impl ActionPoints {
         }
     }
 
+    /// Give back `n` points taken by an action that did not happen
+    ///
+    /// Never exceeds `max_per_period`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use issun::plugin::ActionPoints;
+    ///
+    /// let mut points = ActionPoints::new(3);
+    /// points.consume_n(2);
+    /// points.refund(2);
+    /// assert_eq!(points.available, 3);
+    ///
+    /// points.refund(1);
+    /// assert_eq!(points.available, 3);
+    /// ```
+    pub fn refund(&mut self, n: u32) {
+        self.available = self
+            .available
+            .saturating_add(n)
+            .min(self.max_per_period.max(self.available));
+    }
+
     /// Reset to maximum points (called on period boundary)
     ///
     /// # Example
@@ -383,5 +420,11 @@ mod tests {
     fn test_action_error_display() {
         let error = ActionError::Depleted;
         assert_eq!(error.to_string(), "No action points remaining");
+
+        let error = ActionError::Insufficient {
+            needed: 2,
+            available: 1,
+        };
+        assert_eq!(error.to_string(), "Needs 2 action points, 1 remaining");
     }
 }
@@ -0,0 +1,105 @@
+//! Boss encounter events for command and state notification
+
+use crate::event::Event;
+use crate::plugin::combat::{BattleId, CombatantId};
+use serde::{Deserialize, Serialize};
+
+use super::types::BossId;
+
+// =============================================================================
+// Command Events (Request)
+// =============================================================================
+
+/// Request to start the encounter `encounter` of the `BossEncounterCatalog`
+///
+/// Starts `battle_id` through `CombatStartRequested`; the encounter begins
+/// once the battle has started.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BossEncounterStartRequested {
+    pub encounter: BossId,
+    pub battle_id: BattleId,
+}
+
+impl Event for BossEncounterStartRequested {}
+
+// =============================================================================
+// State Events (Notification)
+// =============================================================================
+
+/// Published when the battle of a boss encounter has started
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BossEncounterStartedEvent {
+    pub encounter: BossId,
+    pub battle_id: BattleId,
+    pub boss: CombatantId,
+}
+
+impl Event for BossEncounterStartedEvent {}
+
+/// Published when a start request is refused
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BossEncounterRejectedEvent {
+    pub encounter: BossId,
+    pub battle_id: BattleId,
+    pub reason: String,
+}
+
+impl Event for BossEncounterRejectedEvent {}
+
+/// Published when the boss's HP reaches the threshold of a later phase
+///
+/// Published once per phase entered; a hit skipping a phase enters (and
+/// reports) every phase in between.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseChangedEvent {
+    pub encounter: BossId,
+    pub battle_id: BattleId,
+    pub previous: usize,
+    pub phase: usize,
+    pub name: String,
+}
+
+impl Event for PhaseChangedEvent {}
+
+/// Published when a phase's add has joined the fight
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BossAddJoinedEvent {
+    pub encounter: BossId,
+    pub battle_id: BattleId,
+    pub add: CombatantId,
+    pub template: String,
+}
+
+impl Event for BossAddJoinedEvent {}
+
+/// Published when the enrage timer adds a stack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BossEnragedEvent {
+    pub encounter: BossId,
+    pub battle_id: BattleId,
+    pub stacks: u32,
+    pub damage_multiplier: f32,
+}
+
+impl Event for BossEnragedEvent {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_serialization() {
+        let event = PhaseChangedEvent {
+            encounter: "lich_king".to_string(),
+            battle_id: "crypt".to_string(),
+            previous: 0,
+            phase: 1,
+            name: "Army".to_string(),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        let deserialized: PhaseChangedEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.encounter, "lich_king");
+        assert_eq!(deserialized.phase, 1);
+        assert_eq!(deserialized.name, "Army");
+    }
+}
@@ -0,0 +1,76 @@
+//! Hook trait for custom boss encounter behavior
+
+use crate::context::ResourceContext;
+use async_trait::async_trait;
+
+use super::state::ActiveEncounter;
+use super::types::BossAdd;
+
+/// Trait for custom boss encounter behavior
+///
+/// **Hook vs Event**:
+/// - **Hook**: Synchronous, direct call, can modify resources, NO network replication
+/// - **Event**: Asynchronous, Pub-Sub, network-friendly, for loose coupling
+///
+/// **Use Hook for**:
+/// - Creating the game's combatants for adds
+/// - Direct resource modification (e.g., restoring arena state between phases)
+/// - Local machine only
+///
+/// **Use Event for**:
+/// - Notifying other systems (e.g., UI phase banners, music changes)
+/// - Network replication (multiplayer)
+/// - Audit log / replay
+#[async_trait]
+pub trait BossHook: Send + Sync {
+    /// Create the combatant for a phase's add
+    ///
+    /// **This is the combatant factory for adds.** Put the combatant where
+    /// the game keeps its fighters, built from `add.template` and named
+    /// `add.id`. Returning `Err` keeps the add out of the fight.
+    ///
+    /// # Default
+    ///
+    /// Accepts the add without creating anything
+    async fn spawn_add(
+        &self,
+        _encounter: &ActiveEncounter,
+        _add: &BossAdd,
+        _resources: &mut ResourceContext,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Called after a phase started, its adds joined and its events were published
+    ///
+    /// # Default
+    ///
+    /// Does nothing
+    async fn on_phase_changed(
+        &self,
+        _encounter: &ActiveEncounter,
+        _previous: usize,
+        _resources: &mut ResourceContext,
+    ) {
+    }
+
+    /// Called when the encounter's battle ended, whatever the result
+    ///
+    /// # Default
+    ///
+    /// Does nothing
+    async fn on_encounter_ended(
+        &self,
+        _encounter: &ActiveEncounter,
+        _victory: bool,
+        _resources: &mut ResourceContext,
+    ) {
+    }
+}
+
+/// Default hook that does nothing
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultBossHook;
+
+#[async_trait]
+impl BossHook for DefaultBossHook {}
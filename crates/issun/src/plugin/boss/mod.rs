@@ -0,0 +1,82 @@
+//! Boss encounter plugin
+//!
+//! Multi-phase boss fights on top of the combat plugin.
+//!
+//! # Overview
+//!
+//! The boss plugin turns a battle into a scripted encounter:
+//! - Phases entered at HP thresholds (`hp_percent` of the boss's max HP)
+//! - Per-phase skills and target policy for the game's boss AI
+//! - Adds joining the fight when a phase starts
+//! - Scripted events published on phase entry, built by name through an
+//!   [`EventFactoryRegistry`](crate::event::EventFactoryRegistry)
+//! - Enrage timer stacking a damage multiplier once the fight runs long
+//! - Boss loot table rolled on victory
+//!
+//! # Combat Integration
+//!
+//! An encounter starts its battle with `CombatStartRequested` and follows
+//! the boss through `DamageDealt` and `HealingDone`; the game keeps resolving
+//! attacks through its `CombatHook`. While the encounter runs, the battle
+//! carries [`BossMetadata`](crate::plugin::combat::BossMetadata), which ends
+//! up on `CombatEndedEvent::boss` (and counts as a boss kill in the run
+//! summary). Combatants for adds come from [`BossHook::spawn_add`].
+//!
+//! The combat system scales the boss's `CombatDamageRequested` by the
+//! metadata's enrage multiplier. When the boss acts, the game's hook picks
+//! its target with [`ActiveEncounter::select_target`], which follows the
+//! current phase's target policy.
+//!
+//! The combat plugin owns victory conditions; the boss system requests a
+//! victorious end when the boss's HP reaches 0. Dungeon rooms start
+//! encounters by publishing `BossEncounterStartRequested` from their hooks.
+//!
+//! # Usage Example
+//!
+//! ```ignore
+//! use issun::prelude::*;
+//! use issun::plugin::boss::*;
+//!
+//! let lich = BossEncounter::new("lich", 300)
+//!     .with_loot_table("lich_hoard")
+//!     .with_enrage(EnrageTimer { after_turns: 20, damage_bonus: 0.25 })
+//!     .with_phase(BossPhase::new("Frost", 100).with_skills(["frostbolt"]))
+//!     .with_phase(
+//!         BossPhase::new("Undead Army", 60)
+//!             .with_add("ghoul_1", "ghoul")
+//!             .with_event("ScreenShake", serde_json::json!({ "strength": 3 })),
+//!     );
+//!
+//! let game = GameBuilder::new()
+//!     .with_plugin(TurnBasedCombatPlugin::new().with_hook(MyCombatHook))
+//!     .with_plugin(LootPlugin::new().with_hook(MyLootHook))
+//!     .with_plugin(
+//!         BossPlugin::new()
+//!             .with_hook(MyBossHook)
+//!             .with_encounters(BossEncounterCatalog::new().with_encounter("lich_king", lich)),
+//!     )
+//!     .build()
+//!     .await?;
+//!
+//! bus.publish(BossEncounterStartRequested {
+//!     encounter: "lich_king".to_string(),
+//!     battle_id: "crypt".to_string(),
+//! });
+//! ```
+
+mod events;
+mod hook;
+mod plugin;
+mod state;
+mod system;
+mod types;
+
+pub use events::*;
+pub use hook::{BossHook, DefaultBossHook};
+pub use plugin::BossPlugin;
+pub use state::{ActiveEncounter, BossEncounterState};
+pub use system::BossSystem;
+pub use types::{
+    BossAdd, BossAttackPolicy, BossEncounter, BossEncounterCatalog, BossId, BossPhase, EnrageTimer,
+    ScriptedEvent,
+};
@@ -0,0 +1,150 @@
+//! Boss plugin implementation
+
+use super::hook::{BossHook, DefaultBossHook};
+use super::state::BossEncounterState;
+use super::system::BossSystem;
+use super::types::BossEncounterCatalog;
+use crate::event::EventFactoryRegistry;
+use crate::Plugin;
+use std::sync::Arc;
+
+/// Boss encounter plugin
+///
+/// This plugin runs multi-phase boss fights with:
+/// - HP-threshold phases with their own skills and target policy
+/// - Adds and scripted events on phase entry
+/// - Enrage timers
+/// - Boss loot tables rolled on victory
+///
+/// Requires the combat plugin; the loot plugin rolls the boss's loot.
+///
+/// # Example
+///
+/// ```ignore
+/// use issun::builder::GameBuilder;
+/// use issun::plugin::boss::{BossPlugin, BossEncounterCatalog};
+///
+/// let encounters: BossEncounterCatalog =
+///     ron::from_str(&std::fs::read_to_string("assets/bosses.ron")?)?;
+///
+/// let game = GameBuilder::new()
+///     .with_plugin(TurnBasedCombatPlugin::new().with_hook(MyCombatHook))
+///     .with_plugin(BossPlugin::new().with_hook(MyBossHook).with_encounters(encounters))
+///     .build()
+///     .await?;
+/// ```
+#[derive(Plugin)]
+#[plugin(name = "issun:boss")]
+pub struct BossPlugin {
+    #[plugin(skip)]
+    hook: Arc<dyn BossHook>,
+
+    #[plugin(skip)]
+    events: Option<EventFactoryRegistry>,
+
+    #[resource]
+    encounters: BossEncounterCatalog,
+
+    #[state]
+    #[plugin(reset)]
+    state: BossEncounterState,
+
+    #[system]
+    system: BossSystem,
+}
+
+impl BossPlugin {
+    /// Create a new boss plugin
+    ///
+    /// Uses the default hook (adds join without creating combatants) and no
+    /// encounters by default.
+    pub fn new() -> Self {
+        let hook = Arc::new(DefaultBossHook);
+        Self {
+            hook: hook.clone(),
+            events: None,
+            encounters: BossEncounterCatalog::new(),
+            state: BossEncounterState::new(),
+            system: BossSystem::new(hook),
+        }
+    }
+
+    /// Add a custom hook for boss behavior
+    ///
+    /// The hook will be called when:
+    /// - A phase's add joins the fight (`spawn_add`) - **creates the combatant**
+    /// - A later phase started (`on_phase_changed`)
+    /// - The encounter's battle ended (`on_encounter_ended`)
+    pub fn with_hook<H: BossHook + 'static>(mut self, hook: H) -> Self {
+        self.hook = Arc::new(hook);
+        self.rebuild_system();
+        self
+    }
+
+    /// Set the encounters that `BossEncounterStartRequested` can start
+    pub fn with_encounters(mut self, encounters: BossEncounterCatalog) -> Self {
+        self.encounters = encounters;
+        self
+    }
+
+    /// Build scripted events with the event factories of `registry`
+    ///
+    /// Names without a factory are published as `DynamicEvent`s for MODs.
+    pub fn with_event_factories(mut self, registry: impl Into<EventFactoryRegistry>) -> Self {
+        self.events = Some(registry.into());
+        self.rebuild_system();
+        self
+    }
+
+    fn rebuild_system(&mut self) {
+        let system = BossSystem::new(self.hook.clone());
+        self.system = match &self.events {
+            Some(registry) => system.with_event_factories(registry.clone()),
+            None => system,
+        };
+    }
+}
+
+impl Default for BossPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::boss::{BossEncounter, BossPhase};
+    use crate::plugin::Plugin;
+
+    #[test]
+    fn test_plugin_creation() {
+        let plugin = BossPlugin::new();
+        assert_eq!(plugin.name(), "issun:boss");
+    }
+
+    #[test]
+    fn test_plugin_with_custom_hook() {
+        struct CustomHook;
+
+        #[async_trait::async_trait]
+        impl BossHook for CustomHook {}
+
+        let plugin = BossPlugin::new()
+            .with_event_factories(EventFactoryRegistry::new())
+            .with_hook(CustomHook);
+        assert_eq!(plugin.name(), "issun:boss");
+        assert!(plugin.events.is_some());
+    }
+
+    #[test]
+    fn test_plugin_with_encounters() {
+        let encounters = BossEncounterCatalog::new().with_encounter(
+            "lich_king",
+            BossEncounter::new("lich", 300).with_phase(BossPhase::new("Frost", 100)),
+        );
+
+        let plugin = BossPlugin::new().with_encounters(encounters);
+        assert!(plugin.encounters.get("lich_king").is_some());
+    }
+}
@@ -0,0 +1,180 @@
+//! Boss encounter runtime state (Mutable)
+
+use super::types::{BossAttackPolicy, BossEncounter, BossId};
+use crate::plugin::combat::{BattleId, BattleView, BossMetadata, CombatantId};
+use crate::state::State;
+use serde::{Deserialize, Serialize};
+
+/// The boss fight in progress
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveEncounter {
+    pub encounter: BossId,
+    pub battle_id: BattleId,
+    /// Copy of the catalog entry, so catalog reloads do not change a running fight
+    pub definition: BossEncounter,
+    /// False until the battle has started
+    pub started: bool,
+    /// Boss HP, following `DamageDealt` and `HealingDone`
+    pub hp: i32,
+    /// Index of the current phase
+    pub phase: usize,
+    pub enrage_stacks: u32,
+    /// Adds that joined the fight
+    pub adds: Vec<CombatantId>,
+}
+
+impl ActiveEncounter {
+    pub fn new(encounter: BossId, battle_id: BattleId, definition: BossEncounter) -> Self {
+        Self {
+            encounter,
+            battle_id,
+            hp: definition.max_hp,
+            definition,
+            started: false,
+            phase: 0,
+            enrage_stacks: 0,
+            adds: Vec::new(),
+        }
+    }
+
+    /// Skills of the current phase
+    pub fn skills(&self) -> &[String] {
+        self.definition
+            .phases
+            .get(self.phase)
+            .map_or(&[], |phase| &phase.skills)
+    }
+
+    /// Target policy of the current phase
+    pub fn attack_policy(&self) -> BossAttackPolicy {
+        self.definition.attack_policy_in(self.phase)
+    }
+
+    /// Whom the boss attacks, picked by the current phase's policy
+    ///
+    /// Index into `view.candidates()`, or `None` if nobody can be attacked.
+    pub fn select_target(&self, view: &BattleView<'_>) -> Option<usize> {
+        self.attack_policy()
+            .policy()
+            .select_target(&self.definition.boss, view)
+    }
+
+    /// Multiplier for the boss's damage (1.0 until enraged)
+    ///
+    /// Carried on the battle's `BossMetadata`, where the combat system
+    /// applies it to the boss's `CombatDamageRequested`.
+    pub fn damage_multiplier(&self) -> f32 {
+        let bonus = self
+            .definition
+            .enrage
+            .map_or(0.0, |enrage| enrage.damage_bonus);
+        1.0 + bonus * self.enrage_stacks as f32
+    }
+
+    /// Details carried by the battle's `CombatEndedEvent`
+    pub fn metadata(&self) -> BossMetadata {
+        BossMetadata {
+            encounter: self.encounter.clone(),
+            boss: self.definition.boss.clone(),
+            loot_table: self
+                .definition
+                .loot_table
+                .clone()
+                .unwrap_or_else(|| self.encounter.clone()),
+            phase: self.phase,
+            enrage_stacks: self.enrage_stacks,
+            damage_multiplier: self.damage_multiplier(),
+        }
+    }
+}
+
+/// Boss encounter runtime state (Mutable)
+///
+/// Games read the current phase's skills and pick the boss's targets
+/// ([`ActiveEncounter::select_target`]) from here when the boss acts; the
+/// enrage multiplier reaches combat through the battle's `BossMetadata`.
+/// This is a save/load target.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BossEncounterState {
+    active: Option<ActiveEncounter>,
+}
+
+impl State for BossEncounterState {}
+
+impl BossEncounterState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn active(&self) -> Option<&ActiveEncounter> {
+        self.active.as_ref()
+    }
+
+    pub fn active_mut(&mut self) -> Option<&mut ActiveEncounter> {
+        self.active.as_mut()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Begin tracking an encounter
+    pub fn begin(&mut self, encounter: ActiveEncounter) -> Result<(), String> {
+        if self.active.is_some() {
+            return Err("A boss encounter is already in progress".to_string());
+        }
+        self.active = Some(encounter);
+        Ok(())
+    }
+
+    /// Stop tracking the encounter, returning it
+    pub fn finish(&mut self) -> Option<ActiveEncounter> {
+        self.active.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::boss::{BossPhase, EnrageTimer};
+
+    fn encounter() -> ActiveEncounter {
+        let definition = BossEncounter::new("lich", 100)
+            .with_phase(BossPhase::new("Frost", 100).with_skills(["frostbolt"]))
+            .with_phase(BossPhase::new("Army", 50).with_skills(["raise_dead"]))
+            .with_enrage(EnrageTimer {
+                after_turns: 10,
+                damage_bonus: 0.5,
+            });
+        ActiveEncounter::new("lich_king".to_string(), "crypt".to_string(), definition)
+    }
+
+    #[test]
+    fn test_one_encounter_at_a_time() {
+        let mut state = BossEncounterState::new();
+        state.begin(encounter()).unwrap();
+        assert!(state.begin(encounter()).is_err());
+
+        let finished = state.finish().unwrap();
+        assert_eq!(finished.hp, 100);
+        assert!(!state.is_active());
+    }
+
+    #[test]
+    fn test_phase_skills_and_enrage_multiplier() {
+        let mut active = encounter();
+        assert_eq!(active.skills(), ["frostbolt"]);
+        assert_eq!(active.damage_multiplier(), 1.0);
+
+        active.phase = 1;
+        active.enrage_stacks = 3;
+        assert_eq!(active.skills(), ["raise_dead"]);
+        assert_eq!(active.damage_multiplier(), 2.5);
+
+        let metadata = active.metadata();
+        assert_eq!(metadata.loot_table, "lich_king");
+        assert_eq!(metadata.phase, 1);
+        assert_eq!(metadata.enrage_stacks, 3);
+        assert_eq!(metadata.damage_multiplier, 2.5);
+    }
+}
@@ -0,0 +1,442 @@
+//! Boss encounter system implementation
+
+use crate::context::{ResourceContext, ServiceContext};
+use crate::event::{EventBus, EventFactoryRegistry, FactoryError};
+use crate::modding::DynamicEvent;
+use crate::plugin::combat::{
+    CombatEndRequested, CombatEndedEvent, CombatResult, CombatStartRequested, CombatStartedEvent,
+    CombatState, CombatTurnCompletedEvent, DamageDealt, HealingDone,
+};
+use crate::plugin::loot::LootGenerateRequested;
+use crate::system::System;
+use async_trait::async_trait;
+use std::any::Any;
+use std::sync::Arc;
+
+use super::events::*;
+use super::hook::BossHook;
+use super::state::{ActiveEncounter, BossEncounterState};
+use super::types::{BossEncounter, BossEncounterCatalog};
+
+/// System that runs boss encounters on top of combat
+///
+/// This system:
+/// 1. Starts encounters through `CombatStartRequested` and tags the battle
+///    with `BossMetadata` once it has started
+/// 2. Tracks the boss's HP from `DamageDealt` and `HealingDone`
+/// 3. Enters phases at their HP thresholds: adds join through
+///    [`BossHook::spawn_add`], scripted events are published, then
+///    `PhaseChangedEvent`
+/// 4. Requests a victorious `CombatEndRequested` when the boss's HP reaches 0
+/// 5. Adds an enrage stack per `CombatTurnCompletedEvent` once the timer ran out
+/// 6. Requests the boss loot table on victory (`LootGenerateRequested`)
+///
+/// The first phase is entered (adds, scripted events) when the battle
+/// starts, without a `PhaseChangedEvent`. Each phase is entered at most once;
+/// healing the boss does not go back.
+#[derive(Clone)]
+pub struct BossSystem {
+    hook: Arc<dyn BossHook>,
+    events: Option<Arc<EventFactoryRegistry>>,
+}
+
+impl BossSystem {
+    /// Create a new BossSystem with a custom hook
+    pub fn new(hook: Arc<dyn BossHook>) -> Self {
+        Self { hook, events: None }
+    }
+
+    /// Build scripted events with the factories of `registry`
+    pub fn with_event_factories(mut self, registry: impl Into<EventFactoryRegistry>) -> Self {
+        self.events = Some(Arc::new(registry.into()));
+        self
+    }
+
+    /// Process all boss encounter events
+    pub async fn process_events(
+        &mut self,
+        _services: &ServiceContext,
+        resources: &mut ResourceContext,
+    ) {
+        self.process_start_requests(resources).await;
+        self.process_battle_starts(resources).await;
+        self.process_health_reports(resources).await;
+        self.process_turns(resources).await;
+        self.process_battle_ends(resources).await;
+    }
+
+    /// Validate start requests and start their battles
+    async fn process_start_requests(&mut self, resources: &mut ResourceContext) {
+        let requests = collect::<BossEncounterStartRequested>(resources).await;
+
+        for request in requests {
+            let active = match self.prepare(&request, resources).await {
+                Ok(active) => active,
+                Err(reason) => {
+                    publish(
+                        resources,
+                        BossEncounterRejectedEvent {
+                            encounter: request.encounter,
+                            battle_id: request.battle_id,
+                            reason,
+                        },
+                    )
+                    .await;
+                    continue;
+                }
+            };
+
+            if let Some(mut state) = resources.get_mut::<BossEncounterState>().await {
+                if state.begin(active).is_err() {
+                    continue;
+                }
+            } else {
+                continue;
+            }
+            publish(
+                resources,
+                CombatStartRequested {
+                    battle_id: request.battle_id,
+                },
+            )
+            .await;
+        }
+    }
+
+    /// The encounter to begin for `request`, or why it cannot start
+    async fn prepare(
+        &self,
+        request: &BossEncounterStartRequested,
+        resources: &ResourceContext,
+    ) -> Result<ActiveEncounter, String> {
+        if resources
+            .get::<BossEncounterState>()
+            .await
+            .is_some_and(|state| state.is_active())
+        {
+            return Err("A boss encounter is already in progress".to_string());
+        }
+        if resources
+            .get::<CombatState>()
+            .await
+            .is_some_and(|state| state.is_battle_active())
+        {
+            return Err("A battle is already in progress".to_string());
+        }
+
+        let definition = resources
+            .get::<BossEncounterCatalog>()
+            .await
+            .and_then(|catalog| catalog.get(&request.encounter).cloned())
+            .ok_or_else(|| format!("Unknown boss encounter '{}'", request.encounter))?;
+        if definition.phases.is_empty() {
+            return Err(format!("{} has no phases", request.encounter));
+        }
+        self.check_scripts(&definition)?;
+
+        Ok(ActiveEncounter::new(
+            request.encounter.clone(),
+            request.battle_id.clone(),
+            definition,
+        ))
+    }
+
+    /// Fail on scripted events whose payload their factory rejects
+    fn check_scripts(&self, definition: &BossEncounter) -> Result<(), String> {
+        let Some(registry) = &self.events else {
+            return Ok(());
+        };
+        for event in definition.phases.iter().flat_map(|phase| &phase.on_enter) {
+            match registry.construct(&event.name, event.payload.clone()) {
+                Ok(_) | Err(FactoryError::UnknownEvent { .. }) => {}
+                Err(error) => return Err(error.to_string()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Tag the started battle and enter the first phase
+    async fn process_battle_starts(&mut self, resources: &mut ResourceContext) {
+        let started = collect::<CombatStartedEvent>(resources).await;
+
+        for event in started {
+            let active = {
+                let Some(mut state) = resources.get_mut::<BossEncounterState>().await else {
+                    return;
+                };
+                let Some(active) = state.active_mut() else {
+                    return;
+                };
+                if active.started || active.battle_id != event.battle_id {
+                    continue;
+                }
+                active.started = true;
+                active.clone()
+            };
+
+            publish(
+                resources,
+                BossEncounterStartedEvent {
+                    encounter: active.encounter.clone(),
+                    battle_id: active.battle_id.clone(),
+                    boss: active.definition.boss.clone(),
+                },
+            )
+            .await;
+            self.enter_phase(resources, 0).await;
+            sync_metadata(resources).await;
+        }
+    }
+
+    /// Follow the boss's HP and enter the phases it reaches
+    async fn process_health_reports(&mut self, resources: &mut ResourceContext) {
+        let damage = collect::<DamageDealt>(resources).await;
+        let healing = collect::<HealingDone>(resources).await;
+        if damage.is_empty() && healing.is_empty() {
+            return;
+        }
+
+        let (previous, reached, defeated, battle_id) = {
+            let Some(mut state) = resources.get_mut::<BossEncounterState>().await else {
+                return;
+            };
+            let Some(active) = state.active_mut().filter(|active| active.started) else {
+                return;
+            };
+            let was_alive = active.hp > 0;
+            let boss = &active.definition.boss;
+            for report in &damage {
+                if report.battle_id == active.battle_id && &report.target == boss {
+                    active.hp -= report.amount;
+                }
+            }
+            for report in &healing {
+                if report.battle_id == active.battle_id && &report.target == boss {
+                    active.hp = (active.hp + report.amount).min(active.definition.max_hp);
+                }
+            }
+            (
+                active.phase,
+                active.definition.phase_for_hp(active.hp),
+                was_alive && active.hp <= 0,
+                active.battle_id.clone(),
+            )
+        };
+
+        for phase in previous + 1..=reached {
+            self.enter_phase(resources, phase).await;
+        }
+        if reached > previous {
+            sync_metadata(resources).await;
+        }
+
+        if defeated {
+            publish(
+                resources,
+                CombatEndRequested {
+                    battle_id,
+                    result: CombatResult::Victory,
+                },
+            )
+            .await;
+        }
+    }
+
+    /// Make `phase` current: adds join, scripted events are published and,
+    /// after the first phase, `PhaseChangedEvent`
+    async fn enter_phase(&mut self, resources: &mut ResourceContext, phase: usize) {
+        let Some(mut active) = resources
+            .get::<BossEncounterState>()
+            .await
+            .and_then(|state| state.active().cloned())
+        else {
+            return;
+        };
+        let Some(definition) = active.definition.phases.get(phase).cloned() else {
+            return;
+        };
+        let previous = active.phase;
+        active.phase = phase;
+
+        let mut joined = Vec::new();
+        for add in &definition.adds {
+            if self.hook.spawn_add(&active, add, resources).await.is_ok() {
+                active.adds.push(add.id.clone());
+                joined.push(BossAddJoinedEvent {
+                    encounter: active.encounter.clone(),
+                    battle_id: active.battle_id.clone(),
+                    add: add.id.clone(),
+                    template: add.template.clone(),
+                });
+            }
+        }
+
+        if let Some(mut state) = resources.get_mut::<BossEncounterState>().await {
+            if let Some(current) = state.active_mut() {
+                *current = active.clone();
+            }
+        }
+
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            for event in joined {
+                bus.publish(event);
+            }
+            for scripted in &definition.on_enter {
+                let built = self
+                    .events
+                    .as_ref()
+                    .map(|registry| registry.construct(&scripted.name, scripted.payload.clone()));
+                match built {
+                    Some(Ok(event)) => event.publish(&mut bus),
+                    // Checked when the encounter started
+                    Some(Err(FactoryError::InvalidPayload { .. })) => {}
+                    _ => bus.publish(DynamicEvent {
+                        event_type: scripted.name.clone(),
+                        data: scripted.payload.clone(),
+                    }),
+                }
+            }
+            if phase > 0 {
+                bus.publish(PhaseChangedEvent {
+                    encounter: active.encounter.clone(),
+                    battle_id: active.battle_id.clone(),
+                    previous,
+                    phase,
+                    name: definition.name.clone(),
+                });
+            }
+        }
+
+        if phase > 0 {
+            self.hook
+                .on_phase_changed(&active, previous, resources)
+                .await;
+        }
+    }
+
+    /// Add enrage stacks for turns completed after the timer ran out
+    async fn process_turns(&mut self, resources: &mut ResourceContext) {
+        let turns = collect::<CombatTurnCompletedEvent>(resources).await;
+        if turns.is_empty() {
+            return;
+        }
+
+        let enraged = {
+            let Some(mut state) = resources.get_mut::<BossEncounterState>().await else {
+                return;
+            };
+            let Some(active) = state.active_mut().filter(|active| active.started) else {
+                return;
+            };
+            let Some(enrage) = active.definition.enrage else {
+                return;
+            };
+
+            let mut enraged = Vec::new();
+            for turn in turns {
+                if turn.battle_id != active.battle_id || turn.turn < enrage.after_turns {
+                    continue;
+                }
+                active.enrage_stacks += 1;
+                enraged.push(BossEnragedEvent {
+                    encounter: active.encounter.clone(),
+                    battle_id: active.battle_id.clone(),
+                    stacks: active.enrage_stacks,
+                    damage_multiplier: active.damage_multiplier(),
+                });
+            }
+            enraged
+        };
+
+        if enraged.is_empty() {
+            return;
+        }
+        sync_metadata(resources).await;
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            for event in enraged {
+                bus.publish(event);
+            }
+        }
+    }
+
+    /// Finish the encounter when its battle ends; roll the boss loot on victory
+    async fn process_battle_ends(&mut self, resources: &mut ResourceContext) {
+        let ended = collect::<CombatEndedEvent>(resources).await;
+
+        for event in ended {
+            let active = {
+                let Some(mut state) = resources.get_mut::<BossEncounterState>().await else {
+                    return;
+                };
+                if state
+                    .active()
+                    .is_none_or(|active| active.battle_id != event.battle_id)
+                {
+                    continue;
+                }
+                state.finish()
+            };
+            let Some(active) = active else {
+                continue;
+            };
+
+            let victory = event.result == CombatResult::Victory;
+            if victory {
+                publish(
+                    resources,
+                    LootGenerateRequested {
+                        source_id: active.metadata().loot_table,
+                        drop_rate: 1.0,
+                    },
+                )
+                .await;
+            }
+            self.hook
+                .on_encounter_ended(&active, victory, resources)
+                .await;
+        }
+    }
+}
+
+/// Copy the encounter's phase and enrage stacks onto the battle
+async fn sync_metadata(resources: &mut ResourceContext) {
+    let Some(metadata) = resources
+        .get::<BossEncounterState>()
+        .await
+        .and_then(|state| state.active().map(ActiveEncounter::metadata))
+    else {
+        return;
+    };
+    if let Some(mut state) = resources.get_mut::<CombatState>().await {
+        let _ = state.set_boss(metadata);
+    }
+}
+
+async fn collect<E: crate::event::Event>(resources: &ResourceContext) -> Vec<E> {
+    if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+        bus.reader::<E>().iter().cloned().collect()
+    } else {
+        Vec::new()
+    }
+}
+
+async fn publish<E: crate::event::Event + serde::Serialize>(resources: &ResourceContext, event: E) {
+    if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+        bus.publish(event);
+    }
+}
+
+#[async_trait]
+impl System for BossSystem {
+    fn name(&self) -> &'static str {
+        "boss_system"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,289 @@
+//! Boss encounter definitions
+//!
+//! Encounters are data: load a [`BossEncounterCatalog`] from RON or JSON, or
+//! build one in code.
+//!
+//! ```ron
+//! (encounters: {
+//!     "lich_king": (
+//!         boss: "lich",
+//!         max_hp: 300,
+//!         loot_table: Some("lich_hoard"),
+//!         enrage: Some((after_turns: 20, damage_bonus: 0.25)),
+//!         phases: [
+//!             (name: "Frost", hp_percent: 100, skills: ["frostbolt"]),
+//!             (
+//!                 name: "Undead Army",
+//!                 hp_percent: 60,
+//!                 attack_policy: Some(LowestHp),
+//!                 adds: [(id: "ghoul_1", template: "ghoul"), (id: "ghoul_2", template: "ghoul")],
+//!                 on_enter: [(name: "ScreenShake", payload: {"strength": 3})],
+//!             ),
+//!         ],
+//!     ),
+//! })
+//! ```
+
+use crate::plugin::combat::{AttackPolicy, CombatantId, FirstAlive, HighestThreat, LowestHp};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Identifier of a [`BossEncounter`] in the catalog
+pub type BossId = String;
+
+/// A multi-phase boss fight
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BossEncounter {
+    /// The boss combatant, as named in `DamageDealt` and `HealingDone`
+    pub boss: CombatantId,
+    pub max_hp: i32,
+    /// Ordered by descending `hp_percent`; the first one is active from the start
+    pub phases: Vec<BossPhase>,
+    /// Target policy of phases without an override
+    #[serde(default)]
+    pub attack_policy: BossAttackPolicy,
+    /// Loot source rolled on victory; the encounter id without one
+    #[serde(default)]
+    pub loot_table: Option<String>,
+    #[serde(default)]
+    pub enrage: Option<EnrageTimer>,
+}
+
+impl crate::asset::Asset for BossEncounter {}
+
+impl BossEncounter {
+    pub fn new(boss: impl Into<CombatantId>, max_hp: i32) -> Self {
+        Self {
+            boss: boss.into(),
+            max_hp,
+            phases: Vec::new(),
+            attack_policy: BossAttackPolicy::default(),
+            loot_table: None,
+            enrage: None,
+        }
+    }
+
+    pub fn with_phase(mut self, phase: BossPhase) -> Self {
+        self.phases.push(phase);
+        self
+    }
+
+    pub fn with_loot_table(mut self, loot_table: impl Into<String>) -> Self {
+        self.loot_table = Some(loot_table.into());
+        self
+    }
+
+    pub fn with_enrage(mut self, enrage: EnrageTimer) -> Self {
+        self.enrage = Some(enrage);
+        self
+    }
+
+    /// Index of the last phase whose threshold `hp` has reached
+    ///
+    /// A phase is reached when `hp` is at or below `hp_percent` of
+    /// `max_hp`; the first phase always is.
+    pub fn phase_for_hp(&self, hp: i32) -> usize {
+        let hp = i64::from(hp) * 100;
+        let max_hp = i64::from(self.max_hp);
+        self.phases
+            .iter()
+            .rposition(|phase| hp <= max_hp * i64::from(phase.hp_percent))
+            .unwrap_or(0)
+    }
+
+    /// Target policy during `phase`
+    pub fn attack_policy_in(&self, phase: usize) -> BossAttackPolicy {
+        self.phases
+            .get(phase)
+            .and_then(|phase| phase.attack_policy)
+            .unwrap_or(self.attack_policy)
+    }
+}
+
+/// One stage of a boss fight
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BossPhase {
+    pub name: String,
+    /// Entered when the boss's HP is at or below this percentage of its max
+    pub hp_percent: u32,
+    /// Game-defined skills the boss uses in this phase
+    #[serde(default)]
+    pub skills: Vec<String>,
+    /// Overrides the encounter's `attack_policy`
+    #[serde(default)]
+    pub attack_policy: Option<BossAttackPolicy>,
+    /// Combatants joining the fight when the phase starts
+    #[serde(default)]
+    pub adds: Vec<BossAdd>,
+    /// Published once when the phase starts
+    #[serde(default)]
+    pub on_enter: Vec<ScriptedEvent>,
+}
+
+impl BossPhase {
+    pub fn new(name: impl Into<String>, hp_percent: u32) -> Self {
+        Self {
+            name: name.into(),
+            hp_percent,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_skills(mut self, skills: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.skills = skills.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_attack_policy(mut self, policy: BossAttackPolicy) -> Self {
+        self.attack_policy = Some(policy);
+        self
+    }
+
+    pub fn with_add(mut self, id: impl Into<CombatantId>, template: impl Into<String>) -> Self {
+        self.adds.push(BossAdd {
+            id: id.into(),
+            template: template.into(),
+        });
+        self
+    }
+
+    pub fn with_event(mut self, name: impl Into<String>, payload: serde_json::Value) -> Self {
+        self.on_enter.push(ScriptedEvent {
+            name: name.into(),
+            payload,
+        });
+        self
+    }
+}
+
+/// Combatant spawned by [`BossHook::spawn_add`](super::BossHook::spawn_add)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BossAdd {
+    pub id: CombatantId,
+    /// Game-defined kind of combatant to create
+    pub template: String,
+}
+
+/// Event published by name when a phase starts
+///
+/// Names with a factory in the boss system's `EventFactoryRegistry` become
+/// that event; any other name is published as a
+/// [`DynamicEvent`](crate::modding::DynamicEvent).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptedEvent {
+    pub name: String,
+    #[serde(default)]
+    pub payload: serde_json::Value,
+}
+
+/// Stacking damage buff on the boss once the fight runs long
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EnrageTimer {
+    /// A stack is added at the end of this turn and every turn after it
+    pub after_turns: u32,
+    /// Damage multiplier added per stack (0.25: ×1.25, ×1.5, ...)
+    pub damage_bonus: f32,
+}
+
+/// Built-in target policy a phase can switch the boss to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BossAttackPolicy {
+    FirstAlive,
+    LowestHp,
+    #[default]
+    HighestThreat,
+}
+
+impl BossAttackPolicy {
+    pub fn policy(&self) -> &'static dyn AttackPolicy {
+        match self {
+            BossAttackPolicy::FirstAlive => &FirstAlive,
+            BossAttackPolicy::LowestHp => &LowestHp,
+            BossAttackPolicy::HighestThreat => &HighestThreat,
+        }
+    }
+}
+
+/// Boss encounters by id
+///
+/// Register this as a Resource (or with `BossPlugin::with_encounters`).
+#[derive(crate::Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BossEncounterCatalog {
+    pub encounters: HashMap<BossId, BossEncounter>,
+}
+
+impl BossEncounterCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_encounter(mut self, id: impl Into<BossId>, encounter: BossEncounter) -> Self {
+        self.encounters.insert(id.into(), encounter);
+        self
+    }
+
+    pub fn get(&self, id: &str) -> Option<&BossEncounter> {
+        self.encounters.get(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lich() -> BossEncounter {
+        BossEncounter::new("lich", 200)
+            .with_phase(BossPhase::new("Frost", 100))
+            .with_phase(BossPhase::new("Army", 60).with_attack_policy(BossAttackPolicy::LowestHp))
+            .with_phase(BossPhase::new("Last Stand", 25))
+    }
+
+    #[test]
+    fn test_phase_thresholds_are_inclusive() {
+        let lich = lich();
+        assert_eq!(lich.phase_for_hp(200), 0);
+        assert_eq!(lich.phase_for_hp(121), 0);
+        assert_eq!(lich.phase_for_hp(120), 1);
+        assert_eq!(lich.phase_for_hp(51), 1);
+        assert_eq!(lich.phase_for_hp(50), 2);
+        assert_eq!(lich.phase_for_hp(-10), 2);
+    }
+
+    #[test]
+    fn test_attack_policy_overrides() {
+        let lich = lich();
+        assert_eq!(lich.attack_policy_in(0), BossAttackPolicy::HighestThreat);
+        assert_eq!(lich.attack_policy_in(1), BossAttackPolicy::LowestHp);
+        assert_eq!(lich.attack_policy_in(2), BossAttackPolicy::HighestThreat);
+    }
+
+    #[test]
+    fn test_catalog_from_ron() {
+        let catalog: BossEncounterCatalog = ron::from_str(
+            r#"(encounters: {
+                "lich_king": (
+                    boss: "lich",
+                    max_hp: 300,
+                    loot_table: Some("lich_hoard"),
+                    enrage: Some((after_turns: 20, damage_bonus: 0.25)),
+                    phases: [
+                        (name: "Frost", hp_percent: 100, skills: ["frostbolt"]),
+                        (
+                            name: "Army",
+                            hp_percent: 60,
+                            adds: [(id: "ghoul_1", template: "ghoul")],
+                            on_enter: [(name: "ScreenShake")],
+                        ),
+                    ],
+                ),
+            })"#,
+        )
+        .unwrap();
+
+        let lich = catalog.get("lich_king").unwrap();
+        assert_eq!(lich.phases[0].skills, ["frostbolt"]);
+        assert_eq!(lich.phases[1].adds[0].template, "ghoul");
+        assert_eq!(lich.phases[1].on_enter[0].payload, serde_json::Value::Null);
+        assert_eq!(lich.enrage.unwrap().after_turns, 20);
+    }
+}
@@ -0,0 +1,188 @@
+//! Target selection for AI-controlled combatants
+//!
+//! An [`AttackPolicy`] picks whom an attacker hits from a [`BattleView`]:
+//! the candidate combatants plus the battle's threat table.
+//!
+//! ```ignore
+//! let candidates: Vec<&dyn Combatant> = party.iter().map(|m| m as &dyn Combatant).collect();
+//! let view = BattleView::new(turn, &candidates).with_threat(state.threat().unwrap());
+//! if let Some(index) = HighestThreat.select_target("ogre", &view) {
+//!     // attack candidates[index]
+//! }
+//! ```
+
+use super::threat::ThreatTable;
+use super::types::Combatant;
+
+/// What an attack policy can see of the battle
+pub struct BattleView<'a> {
+    turn: u32,
+    candidates: &'a [&'a dyn Combatant],
+    threat: Option<&'a ThreatTable>,
+}
+
+impl<'a> BattleView<'a> {
+    /// View over `candidates`, identified by [`Combatant::name`]
+    pub fn new(turn: u32, candidates: &'a [&'a dyn Combatant]) -> Self {
+        Self {
+            turn,
+            candidates,
+            threat: None,
+        }
+    }
+
+    /// Let policies consult the battle's threat table
+    pub fn with_threat(mut self, threat: &'a ThreatTable) -> Self {
+        self.threat = Some(threat);
+        self
+    }
+
+    pub fn turn(&self) -> u32 {
+        self.turn
+    }
+
+    pub fn candidates(&self) -> &[&'a dyn Combatant] {
+        self.candidates
+    }
+
+    pub fn threat(&self) -> Option<&ThreatTable> {
+        self.threat
+    }
+
+    /// Living candidates with their index
+    pub fn alive(&self) -> impl Iterator<Item = (usize, &'a dyn Combatant)> + '_ {
+        self.candidates
+            .iter()
+            .enumerate()
+            .filter(|(_, candidate)| candidate.is_alive())
+            .map(|(index, candidate)| (index, *candidate))
+    }
+
+    /// Index of the living candidate named `name`
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.alive()
+            .find(|(_, candidate)| candidate.name() == name)
+            .map(|(index, _)| index)
+    }
+}
+
+/// Chooses the target of an attack
+pub trait AttackPolicy: Send + Sync {
+    /// Index into `view.candidates()`, or `None` if nobody can be attacked
+    fn select_target(&self, attacker: &str, view: &BattleView<'_>) -> Option<usize>;
+}
+
+/// First living candidate
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FirstAlive;
+
+impl AttackPolicy for FirstAlive {
+    fn select_target(&self, _attacker: &str, view: &BattleView<'_>) -> Option<usize> {
+        view.alive().next().map(|(index, _)| index)
+    }
+}
+
+/// Living candidate with the least HP
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LowestHp;
+
+impl AttackPolicy for LowestHp {
+    fn select_target(&self, _attacker: &str, view: &BattleView<'_>) -> Option<usize> {
+        view.alive()
+            .min_by_key(|(_, candidate)| candidate.hp())
+            .map(|(index, _)| index)
+    }
+}
+
+/// Taunter, else the living candidate the attacker has most threat toward
+///
+/// Falls back to [`FirstAlive`] when the attacker has no threat yet.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HighestThreat;
+
+impl AttackPolicy for HighestThreat {
+    fn select_target(&self, attacker: &str, view: &BattleView<'_>) -> Option<usize> {
+        if let Some(threat) = view.threat() {
+            if let Some(index) = threat
+                .taunt_of(attacker)
+                .and_then(|taunt| view.index_of(&taunt.target))
+            {
+                return Some(index);
+            }
+            if let Some(index) = threat
+                .ranking(attacker)
+                .into_iter()
+                .filter(|(_, value)| *value > 0.0)
+                .find_map(|(target, _)| view.index_of(target))
+            {
+                return Some(index);
+            }
+        }
+        FirstAlive.select_target(attacker, view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::combat::ThreatConfig;
+
+    struct Member {
+        name: &'static str,
+        hp: i32,
+    }
+
+    impl Combatant for Member {
+        fn name(&self) -> &str {
+            self.name
+        }
+        fn hp(&self) -> i32 {
+            self.hp
+        }
+        fn max_hp(&self) -> i32 {
+            100
+        }
+        fn attack(&self) -> i32 {
+            10
+        }
+        fn take_damage(&mut self, damage: i32) {
+            self.hp -= damage;
+        }
+    }
+
+    #[test]
+    fn test_policies() {
+        let party = [
+            Member {
+                name: "warrior",
+                hp: 90,
+            },
+            Member {
+                name: "mage",
+                hp: 40,
+            },
+            Member {
+                name: "cleric",
+                hp: 0,
+            },
+        ];
+        let candidates: Vec<&dyn Combatant> = party.iter().map(|m| m as &dyn Combatant).collect();
+
+        let config = ThreatConfig::default();
+        let mut threat = ThreatTable::new();
+        let view = BattleView::new(1, &candidates);
+        assert_eq!(FirstAlive.select_target("ogre", &view), Some(0));
+        assert_eq!(LowestHp.select_target("ogre", &view), Some(1));
+        assert_eq!(HighestThreat.select_target("ogre", &view), Some(0));
+
+        // Dead candidates are skipped even with the most threat
+        threat.record_damage("cleric", "ogre", 80, &config);
+        threat.record_damage("mage", "ogre", 20, &config);
+        let view = BattleView::new(1, &candidates).with_threat(&threat);
+        assert_eq!(HighestThreat.select_target("ogre", &view), Some(1));
+
+        threat.taunt("ogre", "warrior", 1);
+        let view = BattleView::new(1, &candidates).with_threat(&threat);
+        assert_eq!(HighestThreat.select_target("ogre", &view), Some(0));
+    }
+}
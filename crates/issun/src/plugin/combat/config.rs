@@ -1,5 +1,6 @@
 //! Combat system configuration (ReadOnly)
 
+use crate::engine::config_file::{ConfigParams, ParamSchema};
 use crate::resources::Resource;
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +30,13 @@ pub struct CombatConfig {
 
 impl Resource for CombatConfig {}
 
+impl ConfigParams for CombatConfig {
+    /// Combatants take their max HP when they are created
+    fn param_schema() -> ParamSchema {
+        ParamSchema::new().immutable_after_start("default_max_hp")
+    }
+}
+
 impl Default for CombatConfig {
     fn default() -> Self {
         Self {
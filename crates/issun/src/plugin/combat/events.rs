@@ -3,11 +3,14 @@
 use crate::event::Event;
 use serde::{Deserialize, Serialize};
 
-use super::types::CombatResult;
+use super::types::{BossMetadata, CombatResult};
 
 /// Unique identifier for a combat battle
 pub type BattleId = String;
 
+/// Identifier of a combatant ([`Combatant::name`](super::types::Combatant::name))
+pub type CombatantId = String;
+
 // =============================================================================
 // Command Events (Request)
 // =============================================================================
@@ -28,14 +31,100 @@ pub struct CombatTurnAdvanceRequested {
 
 impl Event for CombatTurnAdvanceRequested {}
 
-/// Request to end combat (surrender, retreat, etc.)
+/// Request to end combat
+///
+/// `result` is the outcome the game decided on; it defaults to
+/// [`CombatResult::Ongoing`] for surrender, retreat, etc.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CombatEndRequested {
     pub battle_id: BattleId,
+    #[serde(default)]
+    pub result: CombatResult,
 }
 
 impl Event for CombatEndRequested {}
 
+/// Request to force `enemy` to target `taunter` for `ThreatConfig::taunt_turns` turns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TauntRequested {
+    pub battle_id: BattleId,
+    pub enemy: CombatantId,
+    pub taunter: CombatantId,
+}
+
+impl Event for TauntRequested {}
+
+/// Request to damage `target`, applied by [`CombatHook::apply_damage`](super::CombatHook::apply_damage)
+///
+/// Only honoured during `battle_id`; the dealt damage is reported as
+/// [`DamageDealt`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombatDamageRequested {
+    pub battle_id: BattleId,
+    pub source: CombatantId,
+    pub target: CombatantId,
+    pub amount: i32,
+    /// Game-defined damage type (e.g. "fire"); empty for untyped damage
+    #[serde(default)]
+    pub damage_type: String,
+}
+
+impl Event for CombatDamageRequested {}
+
+/// Request to heal `target`, applied by [`CombatHook::apply_healing`](super::CombatHook::apply_healing)
+///
+/// Honoured in and out of battle (`battle_id: None`); healing during the
+/// current battle is reported as [`HealingDone`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombatHealRequested {
+    #[serde(default)]
+    pub battle_id: Option<BattleId>,
+    pub source: CombatantId,
+    pub target: CombatantId,
+    pub amount: i32,
+}
+
+impl Event for CombatHealRequested {}
+
+// =============================================================================
+// Report Events (game → threat tracking)
+// =============================================================================
+
+/// Reported by the game when damage is dealt; raises `target`'s threat toward `attacker`
+///
+/// Also published by `CombatSystem` for applied [`CombatDamageRequested`]s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DamageDealt {
+    pub battle_id: BattleId,
+    pub attacker: CombatantId,
+    pub target: CombatantId,
+    pub amount: i32,
+}
+
+impl Event for DamageDealt {}
+
+/// Reported by the game when HP is restored; enemies engaged with `target` gain threat toward `healer`
+///
+/// Also published by `CombatSystem` for applied [`CombatHealRequested`]s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealingDone {
+    pub battle_id: BattleId,
+    pub healer: CombatantId,
+    pub target: CombatantId,
+    pub amount: i32,
+}
+
+impl Event for HealingDone {}
+
+/// Reported by the game when a combatant dies; removes it from the threat table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombatantDefeated {
+    pub battle_id: BattleId,
+    pub combatant: CombatantId,
+}
+
+impl Event for CombatantDefeated {}
+
 // =============================================================================
 // State Events (Notification)
 // =============================================================================
@@ -65,10 +154,25 @@ pub struct CombatEndedEvent {
     pub result: CombatResult,
     pub total_turns: u32,
     pub score: u32,
+    /// Set when the battle was a boss fight
+    #[serde(default)]
+    pub boss: Option<BossMetadata>,
 }
 
 impl Event for CombatEndedEvent {}
 
+/// Published when an enemy's top-threat target changes (aggro marker)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThreatChangedEvent {
+    pub battle_id: BattleId,
+    pub enemy: CombatantId,
+    pub previous: Option<CombatantId>,
+    /// `None` when the enemy has nobody to target (or died)
+    pub current: Option<CombatantId>,
+}
+
+impl Event for ThreatChangedEvent {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,6 +196,7 @@ mod tests {
             result: CombatResult::Victory,
             total_turns: 5,
             score: 100,
+            boss: None,
         };
         let json = serde_json::to_string(&event).unwrap();
         let deserialized: CombatEndedEvent = serde_json::from_str(&json).unwrap();
@@ -100,4 +205,15 @@ mod tests {
         assert_eq!(deserialized.total_turns, 5);
         assert_eq!(deserialized.score, 100);
     }
+
+    #[test]
+    fn test_end_request_result_defaults_to_ongoing() {
+        let request: CombatEndRequested =
+            serde_json::from_str(r#"{"battle_id": "battle_1"}"#).unwrap();
+        assert_eq!(request.result, CombatResult::Ongoing);
+
+        let request: CombatEndRequested =
+            serde_json::from_str(r#"{"battle_id": "battle_1", "result": "Victory"}"#).unwrap();
+        assert_eq!(request.result, CombatResult::Victory);
+    }
 }
@@ -3,7 +3,7 @@
 use crate::context::ResourceContext;
 use async_trait::async_trait;
 
-use super::events::BattleId;
+use super::events::{BattleId, CombatantId};
 use super::types::{CombatResult, Combatant};
 
 /// Trait for custom combat behavior
@@ -55,6 +55,10 @@ pub trait CombatHook: Send + Sync {
     /// Allows game-specific bonuses/penalties based on context.
     /// Examples: critical hits, elemental weaknesses, buff/debuff effects
     ///
+    /// A boss's enrage multiplier is already applied to its
+    /// `CombatDamageRequested`; include it here only for damage the game
+    /// deals itself.
+    ///
     /// # Arguments
     ///
     /// * `battle_id` - Unique identifier for this battle
@@ -81,6 +85,52 @@ pub trait CombatHook: Send + Sync {
         base_multiplier
     }
 
+    /// Apply a [`CombatDamageRequested`](super::CombatDamageRequested)
+    ///
+    /// Combatants are game data, so the hook applies the damage.
+    ///
+    /// # Returns
+    ///
+    /// Damage actually dealt, or `Err(reason)` to refuse (e.g. unknown or
+    /// dead target)
+    ///
+    /// # Default
+    ///
+    /// Refuses every request
+    async fn apply_damage(
+        &self,
+        _battle_id: &BattleId,
+        _source: &CombatantId,
+        _target: &CombatantId,
+        _amount: i32,
+        _damage_type: &str,
+        _resources: &mut ResourceContext,
+    ) -> Result<i32, String> {
+        Err("damage requests are not supported by this game".to_string())
+    }
+
+    /// Apply a [`CombatHealRequested`](super::CombatHealRequested)
+    ///
+    /// `battle_id` is `None` outside battle.
+    ///
+    /// # Returns
+    ///
+    /// HP actually restored, or `Err(reason)` to refuse
+    ///
+    /// # Default
+    ///
+    /// Refuses every request
+    async fn apply_healing(
+        &self,
+        _battle_id: Option<&BattleId>,
+        _source: &CombatantId,
+        _target: &CombatantId,
+        _amount: i32,
+        _resources: &mut ResourceContext,
+    ) -> Result<i32, String> {
+        Err("heal requests are not supported by this game".to_string())
+    }
+
     /// Process a single combat turn
     ///
     /// **This is the main hook for game-specific combat logic.**
@@ -6,8 +6,10 @@
 //! - Customizable combat logic via hooks
 //! - Combat log and scoring
 //! - Win/lose conditions
+//! - Threat (aggro) tracking and AI target policies
 
 // Module declarations
+pub mod ai;
 mod config;
 mod events;
 mod hook;
@@ -15,9 +17,11 @@ pub mod plugin;
 pub mod service;
 mod state;
 mod system;
+pub mod threat;
 pub mod types;
 
 // Re-export main types from modules
+pub use ai::{AttackPolicy, BattleView, FirstAlive, HighestThreat, LowestHp};
 pub use config::CombatConfig;
 pub use events::*;
 pub use hook::{CombatHook, DefaultCombatHook};
@@ -25,4 +29,5 @@ pub use plugin::CombatPlugin;
 pub use service::{CombatService, DamageResult};
 pub use state::{BattleState, CombatState};
 pub use system::CombatSystem;
-pub use types::{CombatLogEntry, CombatResult, Combatant};
+pub use threat::{Taunt, ThreatConfig, ThreatTable};
+pub use types::{BossMetadata, CombatLogEntry, CombatResult, Combatant};
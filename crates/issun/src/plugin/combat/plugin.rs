@@ -5,6 +5,7 @@ use super::hook::{CombatHook, DefaultCombatHook};
 use super::service::CombatService;
 use super::state::CombatState;
 use super::system::CombatSystem;
+use super::threat::ThreatConfig;
 use crate::Plugin;
 use std::sync::Arc;
 
@@ -61,9 +62,14 @@ pub struct CombatPlugin {
     hook: Arc<dyn CombatHook>,
 
     #[resource]
+    #[plugin(config = "combat")]
     config: CombatConfig,
 
+    #[resource]
+    threat_config: ThreatConfig,
+
     #[state]
+    #[plugin(reset)]
     state: CombatState,
 
     #[service]
@@ -83,6 +89,7 @@ impl CombatPlugin {
         Self {
             hook: hook.clone(),
             config: CombatConfig::default(),
+            threat_config: ThreatConfig::default(),
             state: CombatState::new(),
             service: CombatService::new(),
             system: CombatSystem::new(hook),
@@ -155,6 +162,12 @@ impl CombatPlugin {
         self.config = config;
         self
     }
+
+    /// Set threat decay, healing factor, taunt duration and cap
+    pub fn with_threat_config(mut self, config: ThreatConfig) -> Self {
+        self.threat_config = config;
+        self
+    }
 }
 
 impl Default for CombatPlugin {
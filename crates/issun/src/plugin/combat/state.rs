@@ -1,7 +1,8 @@
 //! Combat runtime state (Mutable)
 
 use super::events::BattleId;
-use super::types::CombatLogEntry;
+use super::threat::ThreatTable;
+use super::types::{BossMetadata, CombatLogEntry};
 use crate::state::State;
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +17,14 @@ pub struct BattleState {
 
     /// Accumulated score
     pub score: u32,
+
+    /// Threat per (enemy, target); starts empty every battle
+    #[serde(default)]
+    pub threat: ThreatTable,
+
+    /// Set when the battle is a boss fight
+    #[serde(default)]
+    pub boss: Option<BossMetadata>,
 }
 
 impl BattleState {
@@ -24,6 +33,8 @@ impl BattleState {
             turn_count: 0,
             log: Vec::new(),
             score: 0,
+            threat: ThreatTable::new(),
+            boss: None,
         }
     }
 }
@@ -174,6 +185,42 @@ impl CombatState {
         }
     }
 
+    // ========================================
+    // Threat Management
+    // ========================================
+
+    /// Threat table of the current battle
+    pub fn threat(&self) -> Option<&ThreatTable> {
+        self.battle_state.as_ref().map(|s| &s.threat)
+    }
+
+    /// Mutable threat table of the current battle
+    ///
+    /// Changes made here do not publish `ThreatChangedEvent`; prefer the
+    /// `DamageDealt`, `HealingDone` and `TauntRequested` events.
+    pub fn threat_mut(&mut self) -> Option<&mut ThreatTable> {
+        self.battle_state.as_mut().map(|s| &mut s.threat)
+    }
+
+    // ========================================
+    // Boss Fights
+    // ========================================
+
+    /// Boss details of the current battle
+    pub fn boss(&self) -> Option<&BossMetadata> {
+        self.battle_state.as_ref().and_then(|s| s.boss.as_ref())
+    }
+
+    /// Mark the current battle as a boss fight (or update its details)
+    pub fn set_boss(&mut self, boss: BossMetadata) -> Result<(), String> {
+        let state = self
+            .battle_state
+            .as_mut()
+            .ok_or_else(|| "No battle in progress".to_string())?;
+        state.boss = Some(boss);
+        Ok(())
+    }
+
     /// Clear all state
     pub fn clear(&mut self) {
         self.current_battle = None;
@@ -284,6 +331,47 @@ mod tests {
         assert_eq!(state.score(), 30);
     }
 
+    #[test]
+    fn test_threat_resets_per_battle() {
+        let mut state = CombatState::new();
+        assert!(state.threat().is_none());
+
+        state.start_battle("battle_1".to_string()).unwrap();
+        let config = crate::plugin::combat::ThreatConfig::default();
+        state
+            .threat_mut()
+            .unwrap()
+            .record_damage("mage", "ogre", 10, &config);
+        assert_eq!(state.threat().unwrap().top_target("ogre"), Some("mage"));
+
+        state.end_battle().unwrap();
+        state.start_battle("battle_2".to_string()).unwrap();
+        assert!(state.threat().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_boss_metadata_is_per_battle() {
+        let boss = BossMetadata {
+            encounter: "lich_king".to_string(),
+            boss: "lich".to_string(),
+            loot_table: "lich_hoard".to_string(),
+            phase: 0,
+            enrage_stacks: 0,
+            damage_multiplier: 1.0,
+        };
+
+        let mut state = CombatState::new();
+        assert!(state.set_boss(boss.clone()).is_err());
+
+        state.start_battle("battle_1".to_string()).unwrap();
+        state.set_boss(boss).unwrap();
+        assert_eq!(state.boss().unwrap().encounter, "lich_king");
+
+        state.end_battle().unwrap();
+        state.start_battle("battle_2".to_string()).unwrap();
+        assert!(state.boss().is_none());
+    }
+
     #[test]
     fn test_clear() {
         let mut state = CombatState::new();
@@ -11,7 +11,7 @@ use super::config::CombatConfig;
 use super::events::*;
 use super::hook::CombatHook;
 use super::state::CombatState;
-use super::types::CombatResult;
+use super::threat::{ThreatConfig, ThreatTable};
 
 /// System that processes combat events with hooks
 ///
@@ -19,8 +19,16 @@ use super::types::CombatResult;
 /// 1. Processes combat start requests
 /// 2. Processes combat turn advance requests
 /// 3. Processes combat end requests
-/// 4. Calls hooks for custom behavior
-/// 5. Publishes state change events for network replication
+/// 4. Applies damage and heal requests through the hook, scaling the damage
+///    of a boss by its `BossMetadata::damage_multiplier`
+/// 5. Feeds damage, healing, taunt and defeat reports into the threat table
+/// 6. Calls hooks for custom behavior
+/// 7. Publishes state change events for network replication
+///
+/// Threat reports are applied before turn advances in the same frame; threat
+/// decays at the end of every turn. Applied damage and heal requests are
+/// reported as `DamageDealt` / `HealingDone`, which feed the threat table on
+/// the next update.
 ///
 /// # Feedback Loop
 ///
@@ -45,6 +53,8 @@ impl CombatSystem {
         resources: &mut ResourceContext,
     ) {
         self.process_start_requests(resources).await;
+        self.process_effect_requests(resources).await;
+        self.process_threat_reports(resources).await;
         self.process_turn_advance_requests(resources).await;
         self.process_end_requests(resources).await;
     }
@@ -158,6 +168,12 @@ impl CombatSystem {
                 .after_turn(&request.battle_id, turn, &log_entries, resources)
                 .await;
 
+            // Threat decays and taunts count down at the end of the turn
+            update_threat(resources, &request.battle_id, |table, config| {
+                table.end_turn(config)
+            })
+            .await;
+
             // Publish event
             if let Some(mut bus) = resources.get_mut::<EventBus>().await {
                 bus.publish(CombatTurnCompletedEvent {
@@ -169,6 +185,160 @@ impl CombatSystem {
         }
     }
 
+    /// Apply damage and heal requests through the hook
+    async fn process_effect_requests(&mut self, resources: &mut ResourceContext) {
+        let (damage_requests, heal_requests) = {
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                (
+                    bus.reader::<CombatDamageRequested>()
+                        .iter()
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                    bus.reader::<CombatHealRequested>()
+                        .iter()
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                )
+            } else {
+                return;
+            }
+        };
+
+        if damage_requests.is_empty() && heal_requests.is_empty() {
+            return;
+        }
+
+        let (current_battle, boss) = resources
+            .get::<CombatState>()
+            .await
+            .map(|state| (state.current_battle().cloned(), state.boss().cloned()))
+            .unwrap_or_default();
+
+        let mut dealt = Vec::new();
+        for request in damage_requests {
+            if current_battle.as_ref() != Some(&request.battle_id) {
+                continue;
+            }
+            // An enraged boss hits harder
+            let amount = match &boss {
+                Some(boss) if boss.boss == request.source => {
+                    (request.amount as f32 * boss.damage_multiplier).round() as i32
+                }
+                _ => request.amount,
+            };
+            if let Ok(amount) = self
+                .hook
+                .apply_damage(
+                    &request.battle_id,
+                    &request.source,
+                    &request.target,
+                    amount,
+                    &request.damage_type,
+                    resources,
+                )
+                .await
+            {
+                dealt.push(DamageDealt {
+                    battle_id: request.battle_id,
+                    attacker: request.source,
+                    target: request.target,
+                    amount,
+                });
+            }
+        }
+
+        let mut healed = Vec::new();
+        for request in heal_requests {
+            if request.battle_id.is_some() && request.battle_id != current_battle {
+                continue;
+            }
+            let Ok(amount) = self
+                .hook
+                .apply_healing(
+                    request.battle_id.as_ref(),
+                    &request.source,
+                    &request.target,
+                    request.amount,
+                    resources,
+                )
+                .await
+            else {
+                continue;
+            };
+            if let Some(battle_id) = request.battle_id {
+                healed.push(HealingDone {
+                    battle_id,
+                    healer: request.source,
+                    target: request.target,
+                    amount,
+                });
+            }
+        }
+
+        // Publish reports
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            for report in dealt {
+                bus.publish(report);
+            }
+            for report in healed {
+                bus.publish(report);
+            }
+        }
+    }
+
+    /// Apply threat reports and taunts to the current battle
+    async fn process_threat_reports(&mut self, resources: &mut ResourceContext) {
+        let (damage, healing, taunts, defeats) = {
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                (
+                    bus.reader::<DamageDealt>()
+                        .iter()
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                    bus.reader::<HealingDone>()
+                        .iter()
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                    bus.reader::<TauntRequested>()
+                        .iter()
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                    bus.reader::<CombatantDefeated>()
+                        .iter()
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                )
+            } else {
+                return;
+            }
+        };
+
+        for report in damage {
+            update_threat(resources, &report.battle_id, |table, config| {
+                table.record_damage(&report.attacker, &report.target, report.amount, config)
+            })
+            .await;
+        }
+        for report in healing {
+            update_threat(resources, &report.battle_id, |table, config| {
+                table.record_healing(&report.healer, &report.target, report.amount, config)
+            })
+            .await;
+        }
+        for request in taunts {
+            update_threat(resources, &request.battle_id, |table, config| {
+                table.taunt(&request.enemy, &request.taunter, config.taunt_turns)
+            })
+            .await;
+        }
+        for report in defeats {
+            update_threat(resources, &report.battle_id, |table, _| {
+                table.remove(&report.combatant)
+            })
+            .await;
+        }
+    }
+
     /// Process combat end requests
     async fn process_end_requests(&mut self, resources: &mut ResourceContext) {
         // Collect end requests
@@ -183,19 +353,18 @@ impl CombatSystem {
 
         for request in requests {
             // Get final state before ending
-            let (total_turns, score) = {
+            let (total_turns, score, boss) = {
                 if let Some(state) = resources.get::<CombatState>().await {
                     if state.current_battle() != Some(&request.battle_id) {
                         continue;
                     }
-                    (state.turn_count(), state.score())
+                    (state.turn_count(), state.score(), state.boss().cloned())
                 } else {
                     continue;
                 }
             };
 
-            // Default result is Ongoing (user requested end)
-            let result = CombatResult::Ongoing;
+            let result = request.result.clone();
 
             // End battle (update state)
             {
@@ -220,12 +389,65 @@ impl CombatSystem {
                     result,
                     total_turns,
                     score,
+                    boss,
                 });
             }
         }
     }
 }
 
+/// Change the threat table of `battle_id` (if it is the current battle) and
+/// publish a `ThreatChangedEvent` for every enemy whose top target changed
+async fn update_threat(
+    resources: &mut ResourceContext,
+    battle_id: &BattleId,
+    change: impl FnOnce(&mut ThreatTable, &ThreatConfig),
+) {
+    let config = resources
+        .get::<ThreatConfig>()
+        .await
+        .map(|config| config.clone())
+        .unwrap_or_default();
+
+    let (before, after) = {
+        let Some(mut state) = resources.get_mut::<CombatState>().await else {
+            return;
+        };
+        if state.current_battle() != Some(battle_id) {
+            return;
+        }
+        let Some(table) = state.threat_mut() else {
+            return;
+        };
+        let before = table.top_targets();
+        change(table, &config);
+        (before, table.top_targets())
+    };
+
+    let mut enemies: Vec<&CombatantId> = before.keys().chain(after.keys()).collect();
+    enemies.sort();
+    enemies.dedup();
+    let changes: Vec<ThreatChangedEvent> = enemies
+        .into_iter()
+        .filter(|enemy| before.get(*enemy) != after.get(*enemy))
+        .map(|enemy| ThreatChangedEvent {
+            battle_id: battle_id.clone(),
+            enemy: enemy.clone(),
+            previous: before.get(enemy).cloned(),
+            current: after.get(enemy).cloned(),
+        })
+        .collect();
+
+    if changes.is_empty() {
+        return;
+    }
+    if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+        for event in changes {
+            bus.publish(event);
+        }
+    }
+}
+
 #[async_trait]
 impl System for CombatSystem {
     fn name(&self) -> &'static str {
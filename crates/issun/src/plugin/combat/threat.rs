@@ -0,0 +1,291 @@
+//! Threat (aggro) tracking for AI target selection
+//!
+//! Every enemy keeps a threat value per opponent. Damage taken raises threat
+//! toward the attacker, healing an engaged combatant raises threat toward the
+//! healer, and a taunt forces the enemy's target for a few turns. Threat
+//! decays at the end of every turn, so recent damage weighs more than old.
+
+use super::events::CombatantId;
+use crate::resources::Resource;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Threat tuning (ReadOnly)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreatConfig {
+    /// Fraction of threat lost at the end of every turn (0.0 - 1.0)
+    pub decay_per_turn: f32,
+
+    /// Threat per point of healing (damage generates 1.0 per point)
+    pub healing_factor: f32,
+
+    /// Turns a taunt forces the enemy's target
+    pub taunt_turns: u32,
+
+    /// Upper bound of a single threat value
+    pub max_threat: f32,
+}
+
+impl Resource for ThreatConfig {}
+
+impl Default for ThreatConfig {
+    fn default() -> Self {
+        Self {
+            decay_per_turn: 0.1,
+            healing_factor: 0.5,
+            taunt_turns: 2,
+            max_threat: 1_000_000.0,
+        }
+    }
+}
+
+/// Active taunt on an enemy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Taunt {
+    pub target: CombatantId,
+    pub remaining_turns: u32,
+}
+
+/// Threat values of one battle, per (enemy, target)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThreatTable {
+    threat: BTreeMap<CombatantId, BTreeMap<CombatantId, f32>>,
+    taunts: BTreeMap<CombatantId, Taunt>,
+}
+
+impl ThreatTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `amount` threat of `enemy` toward `target`, clamped to `0..=max_threat`
+    pub fn add_threat(&mut self, enemy: &str, target: &str, amount: f32, max_threat: f32) {
+        let value = self
+            .threat
+            .entry(enemy.to_string())
+            .or_default()
+            .entry(target.to_string())
+            .or_insert(0.0);
+        *value = (*value + amount).clamp(0.0, max_threat);
+    }
+
+    /// `attacker` dealt `amount` damage to `target`
+    pub fn record_damage(
+        &mut self,
+        attacker: &str,
+        target: &str,
+        amount: i32,
+        config: &ThreatConfig,
+    ) {
+        if amount > 0 && attacker != target {
+            self.add_threat(target, attacker, amount as f32, config.max_threat);
+        }
+    }
+
+    /// `healer` restored `amount` HP of `target`
+    ///
+    /// Every enemy already tracking `target` gains threat toward the healer.
+    pub fn record_healing(
+        &mut self,
+        healer: &str,
+        target: &str,
+        amount: i32,
+        config: &ThreatConfig,
+    ) {
+        if amount <= 0 {
+            return;
+        }
+        let engaged: Vec<CombatantId> = self
+            .threat
+            .iter()
+            .filter(|(enemy, targets)| enemy.as_str() != healer && targets.contains_key(target))
+            .map(|(enemy, _)| enemy.clone())
+            .collect();
+        for enemy in engaged {
+            self.add_threat(
+                &enemy,
+                healer,
+                amount as f32 * config.healing_factor,
+                config.max_threat,
+            );
+        }
+    }
+
+    /// Force `enemy` to target `taunter` for `turns` turns
+    pub fn taunt(&mut self, enemy: &str, taunter: &str, turns: u32) {
+        if turns == 0 {
+            return;
+        }
+        self.taunts.insert(
+            enemy.to_string(),
+            Taunt {
+                target: taunter.to_string(),
+                remaining_turns: turns,
+            },
+        );
+    }
+
+    /// Decay all threat and count down taunts; called once per turn
+    pub fn end_turn(&mut self, config: &ThreatConfig) {
+        let keep = (1.0 - config.decay_per_turn).clamp(0.0, 1.0);
+        for targets in self.threat.values_mut() {
+            for value in targets.values_mut() {
+                *value *= keep;
+            }
+        }
+        self.taunts.retain(|_, taunt| {
+            taunt.remaining_turns -= 1;
+            taunt.remaining_turns > 0
+        });
+    }
+
+    /// Drop a dead combatant as an enemy and as a target
+    pub fn remove(&mut self, combatant: &str) {
+        self.threat.remove(combatant);
+        for targets in self.threat.values_mut() {
+            targets.remove(combatant);
+        }
+        self.threat.retain(|_, targets| !targets.is_empty());
+        self.taunts
+            .retain(|enemy, taunt| enemy != combatant && taunt.target != combatant);
+    }
+
+    /// Threat of `enemy` toward `target`
+    pub fn threat(&self, enemy: &str, target: &str) -> f32 {
+        self.threat
+            .get(enemy)
+            .and_then(|targets| targets.get(target))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Active taunt on `enemy`
+    pub fn taunt_of(&self, enemy: &str) -> Option<&Taunt> {
+        self.taunts.get(enemy)
+    }
+
+    /// Targets of `enemy` by descending threat (ties by id)
+    pub fn ranking(&self, enemy: &str) -> Vec<(&str, f32)> {
+        let mut ranking: Vec<(&str, f32)> = self
+            .threat
+            .get(enemy)
+            .map(|targets| {
+                targets
+                    .iter()
+                    .map(|(target, value)| (target.as_str(), *value))
+                    .collect()
+            })
+            .unwrap_or_default();
+        ranking.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        ranking
+    }
+
+    /// Current target of `enemy`: the taunter, else the highest threat
+    pub fn top_target(&self, enemy: &str) -> Option<&str> {
+        if let Some(taunt) = self.taunts.get(enemy) {
+            return Some(&taunt.target);
+        }
+        self.ranking(enemy)
+            .first()
+            .filter(|(_, value)| *value > 0.0)
+            .map(|(target, _)| *target)
+    }
+
+    /// Top target of every enemy that has one
+    pub fn top_targets(&self) -> BTreeMap<CombatantId, CombatantId> {
+        self.threat
+            .keys()
+            .chain(self.taunts.keys())
+            .filter_map(|enemy| {
+                self.top_target(enemy)
+                    .map(|target| (enemy.clone(), target.to_string()))
+            })
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.threat.is_empty() && self.taunts.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ThreatConfig {
+        ThreatConfig {
+            decay_per_turn: 0.5,
+            ..ThreatConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_damage_orders_threat() {
+        let config = config();
+        let mut table = ThreatTable::new();
+        table.record_damage("warrior", "ogre", 30, &config);
+        table.record_damage("mage", "ogre", 50, &config);
+        table.record_damage("warrior", "ogre", 10, &config);
+
+        assert_eq!(
+            table.ranking("ogre"),
+            vec![("mage", 50.0), ("warrior", 40.0)]
+        );
+        assert_eq!(table.top_target("ogre"), Some("mage"));
+
+        // Healing an engaged target draws fractional threat
+        table.record_healing("cleric", "warrior", 40, &config);
+        assert_eq!(table.threat("ogre", "cleric"), 20.0);
+    }
+
+    #[test]
+    fn test_taunt_overrides_for_its_duration() {
+        let config = config();
+        let mut table = ThreatTable::new();
+        table.record_damage("mage", "ogre", 100, &config);
+        table.taunt("ogre", "warrior", config.taunt_turns);
+
+        assert_eq!(table.top_target("ogre"), Some("warrior"));
+        table.end_turn(&config);
+        assert_eq!(table.top_target("ogre"), Some("warrior"));
+        table.end_turn(&config);
+        assert_eq!(table.top_target("ogre"), Some("mage"));
+    }
+
+    #[test]
+    fn test_decay_favours_recent_damage() {
+        let config = config();
+        let mut table = ThreatTable::new();
+
+        // A single burst from the rogue, steady damage from the warrior
+        table.record_damage("rogue", "ogre", 100, &config);
+        let mut tops = Vec::new();
+        for _ in 0..3 {
+            table.record_damage("warrior", "ogre", 30, &config);
+            tops.push(table.top_target("ogre").unwrap().to_string());
+            table.end_turn(&config);
+        }
+        assert_eq!(tops, vec!["rogue", "rogue", "warrior"]);
+    }
+
+    #[test]
+    fn test_threat_is_capped_and_dead_are_removed() {
+        let config = ThreatConfig {
+            max_threat: 100.0,
+            ..config()
+        };
+        let mut table = ThreatTable::new();
+        table.record_damage("mage", "ogre", i32::MAX, &config);
+        table.record_damage("mage", "ogre", i32::MAX, &config);
+        assert_eq!(table.threat("ogre", "mage"), 100.0);
+
+        table.record_damage("warrior", "ogre", 10, &config);
+        table.taunt("ogre", "mage", 3);
+        table.remove("mage");
+        assert_eq!(table.top_target("ogre"), Some("warrior"));
+        assert!(table.taunt_of("ogre").is_none());
+
+        table.remove("ogre");
+        assert!(table.is_empty());
+    }
+}
@@ -40,10 +40,35 @@ pub struct CombatLogEntry {
     pub message: String,
 }
 
+/// Boss fight details of a battle
+///
+/// Set by the boss plugin's `BossSystem` and carried by
+/// [`CombatEndedEvent`](super::CombatEndedEvent), so loot and the run summary
+/// can tell boss kills apart.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct BossMetadata {
+    /// Encounter id in the `BossEncounterCatalog`
+    pub encounter: String,
+    pub boss: super::events::CombatantId,
+    /// Loot source rolled on victory
+    pub loot_table: String,
+    /// Index of the last phase reached
+    pub phase: usize,
+    pub enrage_stacks: u32,
+    /// Scales the boss's `CombatDamageRequested` (1.0 until enraged)
+    #[serde(default = "default_damage_multiplier")]
+    pub damage_multiplier: f32,
+}
+
+fn default_damage_multiplier() -> f32 {
+    1.0
+}
+
 /// Combat result
-#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum CombatResult {
     Victory,
     Defeat,
+    #[default]
     Ongoing,
 }
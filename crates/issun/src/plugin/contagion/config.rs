@@ -1,5 +1,6 @@
 //! Configuration for ContagionPlugin
 
+use crate::engine::config_file::ConfigParams;
 use crate::resources::Resource;
 use serde::{Deserialize, Serialize};
 
@@ -42,6 +43,8 @@ impl Default for ContagionConfig {
 
 impl Resource for ContagionConfig {}
 
+impl ConfigParams for ContagionConfig {}
+
 impl ContagionConfig {
     /// Create a new configuration with default values
     pub fn new() -> Self {
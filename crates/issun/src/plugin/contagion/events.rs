@@ -0,0 +1,51 @@
+//! Events for contagion scenarios
+
+use super::scenario::ScenarioAction;
+use crate::event::Event;
+use serde::{Deserialize, Serialize};
+
+/// Request to replace the graph and contagions with a scenario file
+///
+/// `ContagionSystem::process_events` resets `GraphTopology`,
+/// `ContagionState` and `ScenarioSchedule` from the file and publishes
+/// `ScenarioLoaded`, or `ScenarioLoadFailed` if the file is invalid (the
+/// current state is kept then).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoadScenarioRequested {
+    /// RON file, or JSON with a `.json` extension
+    pub path: String,
+}
+
+impl Event for LoadScenarioRequested {}
+
+/// A scenario replaced the contagion state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioLoaded {
+    pub path: String,
+    pub name: String,
+    pub nodes: usize,
+    pub edges: usize,
+    pub strains: usize,
+}
+
+impl Event for ScenarioLoaded {}
+
+/// A scenario file could not be read or failed validation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioLoadFailed {
+    pub path: String,
+    /// One line per problem
+    pub errors: Vec<String>,
+}
+
+impl Event for ScenarioLoadFailed {}
+
+/// A scripted scenario action was applied
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioActionFired {
+    /// Turns since the scenario was loaded
+    pub turn: u64,
+    pub action: ScenarioAction,
+}
+
+impl Event for ScenarioActionFired {}
@@ -10,6 +10,8 @@
 //! - **Transmission**: Edge-based spreading with probability
 //! - **Mutation**: Content changes during transmission
 //! - **Credibility Decay**: Information degrades over time
+//! - **Scenarios**: Graph, starting strains and scripted route closures or
+//!   outbreaks loaded from RON/JSON files (see [`scenario`])
 //!
 //! # Example
 //!
@@ -27,8 +29,10 @@
 
 // Module declarations
 pub mod config;
+pub mod events;
 pub mod hook;
 pub mod plugin;
+pub mod scenario;
 pub mod service;
 pub mod state;
 pub mod system;
@@ -37,8 +41,13 @@ pub mod types;
 
 // Public re-exports
 pub use config::ContagionConfig;
+pub use events::{LoadScenarioRequested, ScenarioActionFired, ScenarioLoadFailed, ScenarioLoaded};
 pub use hook::{ContagionHook, DefaultContagionHook};
 pub use plugin::ContagionPlugin;
+pub use scenario::{
+    ContagionScenario, ScenarioAction, ScenarioEdge, ScenarioError, ScenarioNode, ScenarioSchedule,
+    ScenarioStrain, ScheduledAction,
+};
 pub use service::ContagionService;
 pub use state::{Contagion, ContagionState};
 pub use system::{ContagionSystem, PropagationReport, SpreadDetail};
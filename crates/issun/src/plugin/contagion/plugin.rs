@@ -2,6 +2,7 @@
 
 use super::config::ContagionConfig;
 use super::hook::{ContagionHook, DefaultContagionHook};
+use super::scenario::{ContagionScenario, ScenarioError, ScenarioSchedule};
 use super::state::ContagionState;
 use super::system::ContagionSystem;
 use super::topology::GraphTopology;
@@ -86,18 +87,23 @@ pub struct ContagionPlugin {
     hook: Arc<dyn ContagionHook>,
 
     /// Configuration (propagation rate, mutation rate, lifetime)
-    #[plugin(resource)]
+    #[plugin(resource, config = "contagion")]
     config: ContagionConfig,
 
     /// Graph topology (nodes and edges)
     #[plugin(resource)]
     topology: GraphTopology,
 
-    /// Runtime state (active contagions)
-    #[plugin(runtime_state)]
+    /// Runtime state (active contagions), reset on restart
+    #[plugin(runtime_state, reset)]
     #[allow(dead_code)]
     state: ContagionState,
 
+    /// Scripted scenario actions, reset on restart
+    #[plugin(runtime_state, reset)]
+    #[allow(dead_code)]
+    schedule: ScenarioSchedule,
+
     /// System (orchestration)
     #[plugin(system)]
     system: ContagionSystem,
@@ -112,6 +118,7 @@ impl ContagionPlugin {
             config: ContagionConfig::default(),
             topology: GraphTopology::new(),
             state: ContagionState::new(),
+            schedule: ScenarioSchedule::default(),
             system: ContagionSystem::new(hook),
         }
     }
@@ -151,6 +158,29 @@ impl ContagionPlugin {
         self
     }
 
+    /// Start from a scenario file (RON, or JSON with a `.json` extension)
+    ///
+    /// Replaces the topology and initial contagions; a restart returns to
+    /// the scenario's turn 0. Scripted actions fire as
+    /// `ContagionSystem::process_events` sees `DayChanged`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let plugin = ContagionPlugin::new().with_scenario("scenarios/harbor.ron")?;
+    /// ```
+    pub fn with_scenario(self, path: impl AsRef<std::path::Path>) -> Result<Self, ScenarioError> {
+        Ok(self.with_loaded_scenario(&ContagionScenario::load(path)?))
+    }
+
+    /// Start from an already loaded (and validated) scenario
+    pub fn with_loaded_scenario(mut self, scenario: &ContagionScenario) -> Self {
+        self.topology = scenario.topology();
+        self.state = scenario.initial_state();
+        self.schedule = scenario.schedule();
+        self
+    }
+
     /// Add a custom hook for contagion behavior
     ///
     /// The hook will be called for:
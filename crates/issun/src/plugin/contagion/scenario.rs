@@ -0,0 +1,477 @@
+//! Scenario files for contagion
+//!
+//! A scenario describes a starting situation as data: the nodes and routes
+//! of the graph, the strains in play and where they start, and scripted
+//! actions that fire on later turns. RON or JSON (by `.json` extension):
+//!
+//! ```ron
+//! (
+//!     name: "Harbor outbreak",
+//!     nodes: [
+//!         (id: "harbor", population: 5000, infections: ["red_fever"]),
+//!         (id: "downtown", population: 20000, resistance: 0.8),
+//!         (id: "island", node_type: Village, population: 800),
+//!     ],
+//!     edges: [
+//!         (id: "harbor_downtown", from: "harbor", to: "downtown", weight: 0.6),
+//!         (id: "ferry", from: "harbor", to: "island", weight: 0.9, open: false),
+//!     ],
+//!     strains: [
+//!         (id: "red_fever", content: Disease(severity: Moderate, location: "harbor")),
+//!         (id: "grey_cough", content: Disease(severity: Mild, location: "island"), mutation_rate: Some(0.3)),
+//!     ],
+//!     events: [
+//!         (turn: 10, action: CloseRoute(edge: "harbor_downtown")),
+//!         (turn: 12, action: SpawnStrain(strain: "grey_cough", node: "island")),
+//!     ],
+//! )
+//! ```
+//!
+//! Each strain becomes one [`Contagion`] whose id is the strain id. Event
+//! turns count `DayChanged` events since the scenario was loaded.
+
+use super::state::{Contagion, ContagionState};
+use super::topology::{ContagionNode, GraphTopology, NodeType, PropagationEdge};
+use super::types::{ContagionContent, ContagionId, EdgeId, NodeId, Timestamp};
+use crate::resources::Resource;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use thiserror::Error;
+
+/// A starting situation for the contagion graph
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ContagionScenario {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub nodes: Vec<ScenarioNode>,
+    #[serde(default)]
+    pub edges: Vec<ScenarioEdge>,
+    #[serde(default)]
+    pub strains: Vec<ScenarioStrain>,
+    #[serde(default)]
+    pub events: Vec<ScheduledAction>,
+}
+
+/// A node and the strains it starts infected with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioNode {
+    pub id: NodeId,
+    #[serde(default = "default_node_type")]
+    pub node_type: NodeType,
+    pub population: usize,
+    /// Resistance to contagion (0.0-1.0)
+    #[serde(default)]
+    pub resistance: f32,
+    /// Strains present at this node on turn 0
+    #[serde(default)]
+    pub infections: Vec<ContagionId>,
+}
+
+/// A route between two nodes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioEdge {
+    pub id: EdgeId,
+    pub from: NodeId,
+    pub to: NodeId,
+    /// Transmission rate (0.0-1.0)
+    pub weight: f32,
+    /// Noise level (0.0-1.0)
+    #[serde(default)]
+    pub noise: f32,
+    /// Whether the route starts open
+    #[serde(default = "default_open")]
+    pub open: bool,
+}
+
+/// A strain that can be placed on nodes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioStrain {
+    pub id: ContagionId,
+    pub content: ContagionContent,
+    /// Mutation rate (0.0-1.0); `Contagion`'s default if missing
+    #[serde(default)]
+    pub mutation_rate: Option<f32>,
+    /// Initial credibility (0.0-1.0)
+    #[serde(default = "default_credibility")]
+    pub credibility: f32,
+}
+
+/// A scripted action and the turn it fires on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledAction {
+    /// Turns after loading, from 1
+    pub turn: u64,
+    pub action: ScenarioAction,
+}
+
+/// Scripted change to the graph or the active strains
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ScenarioAction {
+    /// Stop transmission along a route
+    CloseRoute { edge: EdgeId },
+    /// Resume transmission along a route
+    OpenRoute { edge: EdgeId },
+    /// Place a strain on a node
+    SpawnStrain { strain: ContagionId, node: NodeId },
+}
+
+fn default_node_type() -> NodeType {
+    NodeType::City
+}
+
+fn default_open() -> bool {
+    true
+}
+
+fn default_credibility() -> f32 {
+    1.0
+}
+
+/// Why a scenario could not be loaded
+#[derive(Debug, Error)]
+pub enum ScenarioError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid scenario file: {0}")]
+    Parse(String),
+
+    #[error("Invalid scenario:\n  {}", .0.join("\n  "))]
+    Invalid(Vec<String>),
+}
+
+impl ScenarioError {
+    /// Problems found by [`ContagionScenario::validate`], or the parse/IO
+    /// error as a single line
+    pub fn messages(&self) -> Vec<String> {
+        match self {
+            ScenarioError::Invalid(errors) => errors.clone(),
+            other => vec![other.to_string()],
+        }
+    }
+}
+
+impl ContagionScenario {
+    /// Parse a scenario from RON
+    pub fn from_ron(source: &str) -> Result<Self, ScenarioError> {
+        ron::from_str(source).map_err(|e| ScenarioError::Parse(e.to_string()))
+    }
+
+    /// Parse a scenario from JSON
+    pub fn from_json(source: &str) -> Result<Self, ScenarioError> {
+        serde_json::from_str(source).map_err(|e| ScenarioError::Parse(e.to_string()))
+    }
+
+    /// Read, parse and validate a scenario file (`.json` is JSON,
+    /// anything else RON)
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ScenarioError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)?;
+        let scenario = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&source)?,
+            _ => Self::from_ron(&source)?,
+        };
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    /// Check references and ranges; lists every problem found
+    pub fn validate(&self) -> Result<(), ScenarioError> {
+        let mut errors = Vec::new();
+
+        let mut nodes = HashSet::new();
+        for node in &self.nodes {
+            if !nodes.insert(node.id.as_str()) {
+                errors.push(format!("duplicate node '{}'", node.id));
+            }
+            if !(0.0..=1.0).contains(&node.resistance) {
+                errors.push(format!(
+                    "node '{}': resistance {} is outside 0.0-1.0",
+                    node.id, node.resistance
+                ));
+            }
+        }
+
+        let mut strains = HashSet::new();
+        for strain in &self.strains {
+            if !strains.insert(strain.id.as_str()) {
+                errors.push(format!("duplicate strain '{}'", strain.id));
+            }
+            if let Some(rate) = strain.mutation_rate {
+                if !(0.0..=1.0).contains(&rate) {
+                    errors.push(format!(
+                        "strain '{}': mutation_rate {} is outside 0.0-1.0",
+                        strain.id, rate
+                    ));
+                }
+            }
+        }
+
+        for node in &self.nodes {
+            for strain in &node.infections {
+                if !strains.contains(strain.as_str()) {
+                    errors.push(format!("node '{}': unknown strain '{}'", node.id, strain));
+                }
+            }
+        }
+
+        let mut edges = HashSet::new();
+        for edge in &self.edges {
+            if !edges.insert(edge.id.as_str()) {
+                errors.push(format!("duplicate edge '{}'", edge.id));
+            }
+            for end in [&edge.from, &edge.to] {
+                if !nodes.contains(end.as_str()) {
+                    errors.push(format!("edge '{}': unknown node '{}'", edge.id, end));
+                }
+            }
+            if !(0.0..=1.0).contains(&edge.weight) {
+                errors.push(format!(
+                    "edge '{}': weight {} is outside 0.0-1.0",
+                    edge.id, edge.weight
+                ));
+            }
+            if !(0.0..=1.0).contains(&edge.noise) {
+                errors.push(format!(
+                    "edge '{}': noise {} is outside 0.0-1.0",
+                    edge.id, edge.noise
+                ));
+            }
+        }
+
+        for event in &self.events {
+            if event.turn == 0 {
+                errors.push("scheduled event on turn 0; turns start at 1".to_string());
+            }
+            match &event.action {
+                ScenarioAction::CloseRoute { edge } | ScenarioAction::OpenRoute { edge } => {
+                    if !edges.contains(edge.as_str()) {
+                        errors.push(format!(
+                            "turn {} event: unknown edge '{}'",
+                            event.turn, edge
+                        ));
+                    }
+                }
+                ScenarioAction::SpawnStrain { strain, node } => {
+                    if !strains.contains(strain.as_str()) {
+                        errors.push(format!(
+                            "turn {} event: unknown strain '{}'",
+                            event.turn, strain
+                        ));
+                    }
+                    if !nodes.contains(node.as_str()) {
+                        errors.push(format!(
+                            "turn {} event: unknown node '{}'",
+                            event.turn, node
+                        ));
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ScenarioError::Invalid(errors))
+        }
+    }
+
+    /// Graph described by the scenario
+    pub fn topology(&self) -> GraphTopology {
+        let mut topology = GraphTopology::new();
+        for node in &self.nodes {
+            topology.add_node(
+                ContagionNode::new(node.id.clone(), node.node_type.clone(), node.population)
+                    .with_resistance(node.resistance),
+            );
+        }
+        for edge in &self.edges {
+            topology.add_edge(
+                PropagationEdge::new(
+                    edge.id.clone(),
+                    edge.from.clone(),
+                    edge.to.clone(),
+                    edge.weight,
+                )
+                .with_noise(edge.noise)
+                .with_open(edge.open),
+            );
+        }
+        topology
+    }
+
+    /// Contagions on turn 0: one per strain that starts on any node
+    pub fn initial_state(&self) -> ContagionState {
+        let mut state = ContagionState::new();
+        for node in &self.nodes {
+            for strain_id in &node.infections {
+                if let Some(strain) = self.strain(strain_id) {
+                    strain.infect(&mut state, &node.id, 0);
+                }
+            }
+        }
+        state
+    }
+
+    /// Scripted actions, waiting for their turns
+    pub fn schedule(&self) -> ScenarioSchedule {
+        let mut schedule = ScenarioSchedule::default();
+        for event in &self.events {
+            schedule
+                .pending
+                .entry(event.turn)
+                .or_default()
+                .push(event.action.clone());
+        }
+        schedule.strains = self.strains.clone();
+        schedule
+    }
+
+    /// Strain definition by id
+    pub fn strain(&self, id: &str) -> Option<&ScenarioStrain> {
+        self.strains.iter().find(|strain| strain.id == id)
+    }
+}
+
+impl ScenarioStrain {
+    /// Put this strain on `node`: spreads an active contagion of the strain
+    /// there, or starts one
+    pub fn infect(&self, state: &mut ContagionState, node: &NodeId, turn: Timestamp) {
+        if let Some(contagion) = state.get_contagion_mut(&self.id) {
+            contagion.add_spread(node.clone());
+            return;
+        }
+        let mut contagion = Contagion::new(self.id.clone(), self.content.clone(), node, turn)
+            .with_credibility(self.credibility);
+        if let Some(rate) = self.mutation_rate {
+            contagion = contagion.with_mutation_rate(rate);
+        }
+        state.spawn_contagion(contagion);
+    }
+}
+
+/// Scripted scenario actions by turn (RuntimeState, reset on restart)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScenarioSchedule {
+    /// Turns elapsed since the scenario was loaded
+    pub turn: u64,
+    /// Actions waiting for their turn
+    pub pending: BTreeMap<u64, Vec<ScenarioAction>>,
+    /// Strains `SpawnStrain` actions can place
+    pub strains: Vec<ScenarioStrain>,
+}
+
+impl Resource for ScenarioSchedule {}
+
+impl ScenarioSchedule {
+    /// Advance one turn and take the actions due on it
+    pub fn advance(&mut self) -> Vec<ScenarioAction> {
+        self.turn += 1;
+        self.pending.remove(&self.turn).unwrap_or_default()
+    }
+
+    /// Whether any action is still waiting
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::contagion::types::DiseaseLevel;
+
+    fn scenario() -> ContagionScenario {
+        ContagionScenario::from_ron(
+            r#"(
+                nodes: [
+                    (id: "harbor", population: 5000, infections: ["red"]),
+                    (id: "downtown", population: 20000, resistance: 0.8, infections: ["red"]),
+                ],
+                edges: [(id: "road", from: "harbor", to: "downtown", weight: 0.5, open: false)],
+                strains: [(id: "red", content: Disease(severity: Severe, location: "harbor"))],
+                events: [(turn: 2, action: OpenRoute(edge: "road"))],
+            )"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_scenario_builds_graph_state_and_schedule() {
+        let scenario = scenario();
+        assert!(scenario.validate().is_ok());
+
+        let topology = scenario.topology();
+        assert_eq!(topology.node_count(), 2);
+        assert!(!topology.get_edge(&"road".to_string()).unwrap().open);
+        assert_eq!(
+            topology
+                .get_node(&"downtown".to_string())
+                .unwrap()
+                .resistance,
+            0.8
+        );
+
+        let state = scenario.initial_state();
+        let red = state.get_contagion(&"red".to_string()).unwrap();
+        assert_eq!(red.spread_count(), 2);
+        assert_eq!(
+            red.content,
+            ContagionContent::Disease {
+                severity: DiseaseLevel::Severe,
+                location: "harbor".to_string(),
+            }
+        );
+
+        let mut schedule = scenario.schedule();
+        assert!(schedule.advance().is_empty());
+        assert_eq!(
+            schedule.advance(),
+            vec![ScenarioAction::OpenRoute {
+                edge: "road".to_string()
+            }]
+        );
+        assert!(schedule.is_empty());
+    }
+
+    #[test]
+    fn test_validation_lists_every_problem() {
+        let mut scenario = scenario();
+        scenario.edges[0].weight = -0.2;
+        scenario.nodes[0].infections.push("blue".to_string());
+        scenario.events.push(ScheduledAction {
+            turn: 0,
+            action: ScenarioAction::SpawnStrain {
+                strain: "red".to_string(),
+                node: "moon".to_string(),
+            },
+        });
+
+        let errors = scenario.validate().unwrap_err().messages();
+        assert_eq!(
+            errors,
+            vec![
+                "node 'harbor': unknown strain 'blue'",
+                "edge 'road': weight -0.2 is outside 0.0-1.0",
+                "scheduled event on turn 0; turns start at 1",
+                "turn 0 event: unknown node 'moon'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_scenario() {
+        let scenario = ContagionScenario::from_json(
+            r#"{
+                "nodes": [{"id": "a", "population": 10}],
+                "strains": [{"id": "rumor", "content": {"Political": {"faction": "x", "claim": "y"}}}]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(scenario.nodes[0].node_type, NodeType::City);
+        assert_eq!(scenario.strains[0].credibility, 1.0);
+        assert!(scenario.validate().is_ok());
+    }
+}
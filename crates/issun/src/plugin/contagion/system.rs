@@ -1,12 +1,18 @@
 //! System orchestration for contagion propagation
 
 use super::config::ContagionConfig;
+use super::events::{
+    LoadScenarioRequested, ScenarioActionFired, ScenarioLoadFailed, ScenarioLoaded,
+};
 use super::hook::ContagionHook;
+use super::scenario::{ContagionScenario, ScenarioAction, ScenarioSchedule};
 use super::service::ContagionService;
 use super::state::{Contagion, ContagionState};
 use super::topology::GraphTopology;
 use super::types::{ContagionId, NodeId};
 use crate::context::ResourceContext;
+use crate::event::EventBus;
+use crate::plugin::time::DayChanged;
 use crate::system::System;
 use async_trait::async_trait;
 use rand::Rng;
@@ -56,8 +62,8 @@ impl ContagionSystem {
                 let outgoing_edges = topology.get_outgoing_edges(&node_id);
 
                 for edge in outgoing_edges {
-                    // Skip if already spread to target
-                    if contagion.has_reached(&edge.to) {
+                    // Skip closed routes and targets already reached
+                    if !edge.open || contagion.has_reached(&edge.to) {
                         continue;
                     }
 
@@ -238,6 +244,115 @@ impl ContagionSystem {
         Ok(removed_count)
     }
 
+    /// Fire scripted scenario actions once per `DayChanged` event, then
+    /// handle `LoadScenarioRequested`
+    pub async fn process_events(&self, resources: &mut ResourceContext) {
+        let (days, loads) = match resources.get_mut::<EventBus>().await {
+            Some(mut bus) => (
+                bus.reader::<DayChanged>().iter().count(),
+                bus.reader::<LoadScenarioRequested>()
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            ),
+            None => return,
+        };
+
+        let mut published = Vec::new();
+        for _ in 0..days {
+            published.extend(Self::advance_schedule(resources).await);
+        }
+
+        let mut outcomes = Vec::new();
+        for request in loads {
+            outcomes.push(match ContagionScenario::load(&request.path) {
+                Ok(scenario) => {
+                    Self::apply_scenario(resources, &scenario).await;
+                    Ok(ScenarioLoaded {
+                        path: request.path,
+                        name: scenario.name.clone(),
+                        nodes: scenario.nodes.len(),
+                        edges: scenario.edges.len(),
+                        strains: scenario.strains.len(),
+                    })
+                }
+                Err(err) => Err(ScenarioLoadFailed {
+                    path: request.path,
+                    errors: err.messages(),
+                }),
+            });
+        }
+
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            for event in published {
+                bus.publish(event);
+            }
+            for outcome in outcomes {
+                match outcome {
+                    Ok(loaded) => bus.publish(loaded),
+                    Err(failed) => bus.publish(failed),
+                }
+            }
+        }
+    }
+
+    /// Replace the graph, contagions and schedule with `scenario`
+    pub async fn apply_scenario(resources: &mut ResourceContext, scenario: &ContagionScenario) {
+        match resources.get_mut::<GraphTopology>().await {
+            Some(mut topology) => *topology = scenario.topology(),
+            None => resources.insert(scenario.topology()),
+        }
+        match resources.get_mut::<ContagionState>().await {
+            Some(mut state) => *state = scenario.initial_state(),
+            None => resources.insert(scenario.initial_state()),
+        }
+        match resources.get_mut::<ScenarioSchedule>().await {
+            Some(mut schedule) => *schedule = scenario.schedule(),
+            None => resources.insert(scenario.schedule()),
+        }
+    }
+
+    /// Advance the scenario one turn and apply the actions due
+    async fn advance_schedule(resources: &mut ResourceContext) -> Vec<ScenarioActionFired> {
+        let Some(mut schedule) = resources.get_mut::<ScenarioSchedule>().await else {
+            return Vec::new();
+        };
+        let actions = schedule.advance();
+        if actions.is_empty() {
+            return Vec::new();
+        }
+        let turn = schedule.turn;
+        let strains = schedule.strains.clone();
+        drop(schedule);
+
+        let mut fired = Vec::new();
+        for action in actions {
+            let applied = match &action {
+                ScenarioAction::CloseRoute { edge } | ScenarioAction::OpenRoute { edge } => {
+                    let open = matches!(action, ScenarioAction::OpenRoute { .. });
+                    match resources.get_mut::<GraphTopology>().await {
+                        Some(mut topology) => topology.set_edge_open(edge, open),
+                        None => false,
+                    }
+                }
+                ScenarioAction::SpawnStrain { strain, node } => {
+                    let strain = strains.iter().find(|s| &s.id == strain);
+                    match (strain, resources.get_mut::<ContagionState>().await) {
+                        (Some(strain), Some(mut state)) => {
+                            strain.infect(&mut state, node, turn);
+                            true
+                        }
+                        _ => false,
+                    }
+                }
+            };
+            if applied {
+                fired.push(ScenarioActionFired { turn, action });
+            }
+        }
+        fired
+    }
+
     /// Get a reference to a specific node's contagions
     pub async fn get_node_contagions(
         &self,
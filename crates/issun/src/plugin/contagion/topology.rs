@@ -49,6 +49,13 @@ pub struct PropagationEdge {
     ///
     /// Higher noise = more likely to mutate during propagation
     pub noise_level: f32,
+    /// Closed edges transmit nothing (e.g. a suspended ferry route)
+    #[serde(default = "default_open")]
+    pub open: bool,
+}
+
+fn default_open() -> bool {
+    true
 }
 
 impl Resource for GraphTopology {}
@@ -84,6 +91,17 @@ impl GraphTopology {
         self.edges.get(id)
     }
 
+    /// Open or close an edge; returns false if there is no such edge
+    pub fn set_edge_open(&mut self, id: &EdgeId, open: bool) -> bool {
+        match self.edges.get_mut(id) {
+            Some(edge) => {
+                edge.open = open;
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Get all outgoing edges from a node
     pub fn get_outgoing_edges(&self, node_id: &NodeId) -> Vec<&PropagationEdge> {
         self.edges
@@ -185,6 +203,7 @@ impl PropagationEdge {
             to: to.into(),
             transmission_rate: transmission_rate.clamp(0.0, 1.0),
             noise_level: 0.0,
+            open: true,
         }
     }
 
@@ -193,6 +212,12 @@ impl PropagationEdge {
         self.noise_level = noise.clamp(0.0, 1.0);
         self
     }
+
+    /// Set whether the edge starts open
+    pub fn with_open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,50 @@
+//! Configuration for the debug HTTP plugin
+
+use std::net::SocketAddr;
+
+/// Debug HTTP server configuration
+///
+/// Disabled by default. Enabling requires a non-empty bearer token.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DebugHttpConfig {
+    pub enabled: bool,
+    /// Address to listen on; port 0 picks a free port
+    pub bind: SocketAddr,
+    /// Bearer token every request must present
+    pub token: String,
+}
+
+impl Default for DebugHttpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind: SocketAddr::from(([127, 0, 0, 1], 7878)),
+            token: String::new(),
+        }
+    }
+}
+
+impl DebugHttpConfig {
+    /// Enabled on the default address, guarded by `token`
+    pub fn enabled(token: impl Into<String>) -> Self {
+        Self {
+            enabled: true,
+            token: token.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Listen on `bind` instead of the default address
+    pub fn with_bind(mut self, bind: SocketAddr) -> Self {
+        self.bind = bind;
+        self
+    }
+}
+
+impl crate::resources::Resource for DebugHttpConfig {}
+
+/// Address the debug server actually listens on
+///
+/// Inserted by `DebugHttpPlugin::on_start` once the listener is bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebugHttpAddress(pub SocketAddr);
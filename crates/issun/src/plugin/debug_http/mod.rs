@@ -0,0 +1,66 @@
+//! Debug HTTP plugin (feature `debug-http`)
+//!
+//! [`DebugRegistry`] is available without the feature; scenario tests
+//! ([`crate::testing::scenario`]) use it to read resources and build events.
+//!
+//! Read-mostly introspection endpoints for long-running headless
+//! simulations:
+//!
+//! | Route | Response |
+//! |-------|----------|
+//! | `GET /debug/resources` | Every resource's type name, write count and whether it is observable |
+//! | `GET /debug/resources/{TypeName}` | JSON of an observable resource |
+//! | `GET /debug/events` | [`EventBusStats`](crate::event::EventBusStats) |
+//! | `POST /debug/events/{TypeName}` | Publish an event built from the JSON body |
+//! | `POST /debug/config/reload` | Re-read the config file ([`ReloadConfigRequested`](crate::engine::ReloadConfigRequested)) |
+//!
+//! Every request needs `Authorization: Bearer <token>`. Requests are answered
+//! through the runner's [query channel](crate::engine::query) between ticks,
+//! so no resource lock is held while a client is connected.
+//!
+//! Events are built through an
+//! [`EventFactoryRegistry`](crate::event::EventFactoryRegistry): `.event::<E>()`
+//! adds one under its short type name, and `.event_factories(&registry)` adds
+//! a whole registry, such as `EventFactoryRegistry::registered()` with the
+//! `event-factories` feature.
+//!
+//! # Usage Example
+//!
+//! ```ignore
+//! use issun::engine::query_channel;
+//! use issun::plugin::debug_http::{DebugHttpConfig, DebugHttpPlugin};
+//!
+//! let (queries, receiver) = query_channel();
+//! let game = GameBuilder::new()
+//!     .with_plugin(
+//!         DebugHttpPlugin::new(queries)
+//!             .with_config(DebugHttpConfig::enabled("s3cret"))
+//!             .observe::<Wallet>()
+//!             .event::<SpawnWave>()?,
+//!     )?
+//!     .build()
+//!     .await?;
+//!
+//! // ... build the director ...
+//! HeadlessRunner::new(director)
+//!     .with_query_channel(receiver)
+//!     .run()
+//!     .await?;
+//! ```
+
+#[cfg(feature = "debug-http")]
+mod config;
+#[cfg(feature = "debug-http")]
+mod plugin;
+mod registry;
+#[cfg(feature = "debug-http")]
+mod server;
+
+#[cfg(feature = "debug-http")]
+pub use config::{DebugHttpAddress, DebugHttpConfig};
+#[cfg(feature = "debug-http")]
+pub use plugin::DebugHttpPlugin;
+pub(crate) use registry::short_name;
+pub use registry::{short_type_name, DebugRegistry};
+#[cfg(feature = "debug-http")]
+pub use server::{router, ResourceEntry};
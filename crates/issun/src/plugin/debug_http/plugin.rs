@@ -0,0 +1,141 @@
+//! Debug HTTP plugin implementation
+
+use super::config::{DebugHttpAddress, DebugHttpConfig};
+use super::registry::DebugRegistry;
+use super::server::router;
+use crate::context::{ResourceContext, ServiceContext, SystemContext};
+use crate::engine::flags::{FeatureFlags, SetFeatureFlagRequested};
+use crate::engine::query::QueryHandle;
+use crate::event::{Event, EventFactoryRegistry, FactoryError};
+use crate::plugin::{Plugin, PluginBuilder, PluginBuilderExt};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+/// Serves the `/debug` introspection routes while the game runs
+///
+/// The server starts in `on_start` (only if the config is enabled and has
+/// a token) and stops in `on_exit`. The runner must serve the query
+/// channel whose handle is passed to [`DebugHttpPlugin::new`].
+pub struct DebugHttpPlugin {
+    config: DebugHttpConfig,
+    queries: QueryHandle,
+    registry: DebugRegistry,
+    server: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl DebugHttpPlugin {
+    /// Create a disabled plugin answering through `queries`
+    ///
+    /// [`FeatureFlags`] are observable and [`SetFeatureFlagRequested`] can be
+    /// posted out of the box.
+    pub fn new(queries: QueryHandle) -> Self {
+        let mut registry = DebugRegistry::new();
+        registry.observe::<FeatureFlags>();
+        registry
+            .event::<SetFeatureFlagRequested>()
+            .expect("empty registry has no collisions");
+        Self {
+            config: DebugHttpConfig::default(),
+            queries,
+            registry,
+            server: Mutex::new(None),
+        }
+    }
+
+    pub fn with_config(mut self, config: DebugHttpConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Expose resource `T` as JSON
+    pub fn observe<T: Serialize + Send + Sync + 'static>(mut self) -> Self {
+        self.registry.observe::<T>();
+        self
+    }
+
+    /// Accept `POST /debug/events/{TypeName}` for event `E`
+    ///
+    /// Fails if another event has the same short name.
+    #[track_caller]
+    pub fn event<E: Event + Serialize + DeserializeOwned>(mut self) -> Result<Self, FactoryError> {
+        self.registry.event::<E>()?;
+        Ok(self)
+    }
+
+    /// Accept `POST /debug/events/{name}` for every event of `registry`
+    ///
+    /// Fails if a name is taken by another event type.
+    pub fn event_factories(
+        mut self,
+        registry: &EventFactoryRegistry,
+    ) -> Result<Self, FactoryError> {
+        self.registry.add_event_factories(registry)?;
+        Ok(self)
+    }
+}
+
+#[async_trait]
+impl Plugin for DebugHttpPlugin {
+    fn name(&self) -> &'static str {
+        "issun:debug_http"
+    }
+
+    fn build(&self, builder: &mut dyn PluginBuilder) {
+        builder.register_resource(self.config.clone());
+    }
+
+    async fn on_start(
+        &self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) {
+        if !self.config.enabled {
+            return;
+        }
+        if self.config.token.is_empty() {
+            eprintln!("[DebugHttp] Refusing to start without a bearer token");
+            return;
+        }
+
+        let listener = match tokio::net::TcpListener::bind(self.config.bind).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                eprintln!("[DebugHttp] Failed to bind {}: {}", self.config.bind, e);
+                return;
+            }
+        };
+        let address = listener.local_addr().unwrap_or(self.config.bind);
+        resources.insert(DebugHttpAddress(address));
+
+        let app = router(
+            self.queries.clone(),
+            Arc::new(self.registry.clone()),
+            &self.config.token,
+        );
+        let handle = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                eprintln!("[DebugHttp] Server error: {}", e);
+            }
+        });
+        println!("[DebugHttp] Listening on http://{}/debug", address);
+
+        if let Ok(mut server) = self.server.lock() {
+            *server = Some(handle);
+        }
+    }
+
+    async fn on_exit(
+        &self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+    ) {
+        if let Some(handle) = self.server.lock().ok().and_then(|mut s| s.take()) {
+            handle.abort();
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Observable resources and event factories exposed over HTTP
+
+use crate::context::ResourceContext;
+use crate::engine::query::QueryFuture;
+use crate::event::{Event, EventBus, EventFactoryRegistry, FactoryError};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+type ResourceSerializer = Arc<
+    dyn for<'a> Fn(&'a ResourceContext) -> QueryFuture<'a, Option<serde_json::Value>> + Send + Sync,
+>;
+
+/// Types the debug endpoints may read or construct, keyed by short type name
+///
+/// Events are built by an [`EventFactoryRegistry`].
+#[derive(Clone, Default)]
+pub struct DebugRegistry {
+    resources: BTreeMap<String, (String, ResourceSerializer)>,
+    events: EventFactoryRegistry,
+}
+
+impl DebugRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow `GET /debug/resources/{TypeName}` for `T`
+    pub fn observe<T: Serialize + Send + Sync + 'static>(&mut self) {
+        let serializer: ResourceSerializer = Arc::new(|resources| {
+            Box::pin(async move {
+                let resource = resources.get::<T>().await?;
+                serde_json::to_value(&*resource).ok()
+            })
+        });
+        self.resources.insert(
+            short_type_name::<T>().to_string(),
+            (std::any::type_name::<T>().to_string(), serializer),
+        );
+    }
+
+    /// Allow `POST /debug/events/{TypeName}` for `E`
+    ///
+    /// Fails with [`FactoryError::Collision`], naming both registrations, if
+    /// another event type is registered under the same short name.
+    #[track_caller]
+    pub fn event<E: Event + Serialize + DeserializeOwned>(&mut self) -> Result<(), FactoryError> {
+        self.events
+            .register_event_factory::<E>(short_type_name::<E>())
+    }
+
+    /// Allow every event of `registry`, under its registered names
+    pub fn add_event_factories(
+        &mut self,
+        registry: &EventFactoryRegistry,
+    ) -> Result<(), FactoryError> {
+        self.events.merge(registry)
+    }
+
+    /// The event factories
+    pub fn event_factories(&self) -> &EventFactoryRegistry {
+        &self.events
+    }
+
+    /// Whether the resource with this full type name is observable
+    pub fn is_observable(&self, type_name: &str) -> bool {
+        self.resources.values().any(|(full, _)| full == type_name)
+    }
+
+    /// Serialize an observable resource; `None` if unknown or not present
+    pub async fn read(&self, name: &str, resources: &ResourceContext) -> Option<serde_json::Value> {
+        let (_, serializer) = self.resources.get(name)?;
+        serializer(resources).await
+    }
+
+    /// Whether `name` is an observable resource
+    pub fn has_resource(&self, name: &str) -> bool {
+        self.resources.contains_key(name)
+    }
+
+    /// Build and publish an event; `None` if no factory is registered
+    pub fn publish(
+        &self,
+        name: &str,
+        bus: &mut EventBus,
+        body: serde_json::Value,
+    ) -> Option<Result<(), String>> {
+        match self.events.construct(name, body) {
+            Ok(event) => {
+                event.publish(bus);
+                Some(Ok(()))
+            }
+            Err(FactoryError::UnknownEvent { .. }) => None,
+            Err(error) => Some(Err(error.to_string())),
+        }
+    }
+
+    /// Whether `name` has an event factory
+    pub fn has_event(&self, name: &str) -> bool {
+        self.events.contains(name)
+    }
+}
+
+impl From<DebugRegistry> for EventFactoryRegistry {
+    fn from(registry: DebugRegistry) -> Self {
+        registry.events
+    }
+}
+
+/// Last path segment of a type name, without generic arguments
+/// (`my_game::state::Wallet` → `Wallet`)
+pub fn short_type_name<T: ?Sized>() -> &'static str {
+    short_name(std::any::type_name::<T>())
+}
+
+pub(crate) fn short_name(type_name: &str) -> &str {
+    let base = type_name.split('<').next().unwrap_or(type_name);
+    base.rsplit("::").next().unwrap_or(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, serde::Serialize, serde::Deserialize)]
+    struct Wallet {
+        gold: u32,
+    }
+
+    impl Event for Wallet {}
+
+    #[test]
+    fn test_short_name() {
+        assert_eq!(short_name("a::b::Wallet"), "Wallet");
+        assert_eq!(short_name("a::Wrapper<b::Inner>"), "Wrapper");
+        assert_eq!(short_name("u32"), "u32");
+    }
+
+    #[tokio::test]
+    async fn test_read_and_publish() {
+        let mut registry = DebugRegistry::new();
+        registry.observe::<Wallet>();
+        registry.event::<Wallet>().unwrap();
+
+        let mut resources = ResourceContext::new();
+        assert_eq!(registry.read("Wallet", &resources).await, None);
+        resources.insert(Wallet { gold: 5 });
+        assert_eq!(
+            registry.read("Wallet", &resources).await,
+            Some(serde_json::json!({ "gold": 5 }))
+        );
+        assert!(registry.is_observable(std::any::type_name::<Wallet>()));
+
+        let mut bus = EventBus::new();
+        assert!(registry
+            .publish("Wallet", &mut bus, serde_json::json!({ "nope": 1 }))
+            .unwrap()
+            .is_err());
+        registry
+            .publish("Wallet", &mut bus, serde_json::json!({ "gold": 9 }))
+            .unwrap()
+            .unwrap();
+        assert!(registry
+            .publish("Unknown", &mut bus, serde_json::json!({}))
+            .is_none());
+        bus.dispatch();
+        assert_eq!(bus.reader::<Wallet>().iter().next().unwrap().gold, 9);
+    }
+
+    #[test]
+    fn test_factories_from_another_registry() {
+        mod other {
+            #[derive(Clone, serde::Serialize, serde::Deserialize)]
+            pub struct Wallet {
+                pub silver: u32,
+            }
+
+            impl crate::event::Event for Wallet {}
+        }
+
+        let mut factories = EventFactoryRegistry::new();
+        factories.register_event_factory::<Wallet>("Purse").unwrap();
+        factories
+            .register_event_factory::<other::Wallet>("Wallet")
+            .unwrap();
+
+        let mut registry = DebugRegistry::new();
+        registry.event::<Wallet>().unwrap();
+        let error = registry.add_event_factories(&factories).unwrap_err();
+        assert!(matches!(error, FactoryError::Collision { ref name, .. } if name == "Wallet"));
+        assert!(registry.event_factories().contains("Purse"));
+    }
+
+    #[test]
+    fn test_event_name_collision_is_an_error() {
+        mod other {
+            #[derive(Clone, serde::Serialize, serde::Deserialize)]
+            pub struct Wallet;
+
+            impl crate::event::Event for Wallet {}
+        }
+
+        let mut registry = DebugRegistry::new();
+        registry.event::<Wallet>().unwrap();
+        let error = registry.event::<other::Wallet>().unwrap_err();
+        assert!(matches!(
+            error,
+            FactoryError::Collision { ref name, ref first_type, ref second_type, .. }
+                if name == "Wallet"
+                    && first_type == std::any::type_name::<Wallet>()
+                    && second_type == std::any::type_name::<other::Wallet>()
+        ));
+        // The first registration is kept
+        assert!(registry.has_event("Wallet"));
+    }
+}
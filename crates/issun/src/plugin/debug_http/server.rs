@@ -0,0 +1,192 @@
+//! HTTP routes of the debug server
+
+use super::registry::{short_name, DebugRegistry};
+use crate::engine::config_file::ReloadConfigRequested;
+use crate::engine::query::QueryHandle;
+use crate::event::EventBus;
+use axum::extract::{Path, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use std::sync::Arc;
+
+/// One entry of `GET /debug/resources`
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ResourceEntry {
+    pub name: String,
+    pub type_name: String,
+    /// Mutable borrows since insertion
+    pub writes: u64,
+    /// Readable through `GET /debug/resources/{name}`
+    pub observable: bool,
+}
+
+#[derive(Clone)]
+struct DebugState {
+    queries: QueryHandle,
+    registry: Arc<DebugRegistry>,
+    token: Arc<str>,
+}
+
+/// Build the `/debug` routes
+pub fn router(queries: QueryHandle, registry: Arc<DebugRegistry>, token: &str) -> Router {
+    Router::new()
+        .route("/debug/resources", get(list_resources))
+        .route("/debug/resources/:name", get(read_resource))
+        .route("/debug/events", get(event_stats))
+        .route("/debug/events/:name", axum::routing::post(publish_event))
+        .route("/debug/config/reload", axum::routing::post(reload_config))
+        .with_state(DebugState {
+            queries,
+            registry,
+            token: Arc::from(token),
+        })
+}
+
+fn authorized(state: &DebugState, headers: &HeaderMap) -> bool {
+    let presented = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    presented == Some(&*state.token)
+}
+
+fn unauthorized() -> Response {
+    (StatusCode::UNAUTHORIZED, "missing or invalid bearer token").into_response()
+}
+
+fn runner_stopped() -> Response {
+    (StatusCode::SERVICE_UNAVAILABLE, "simulation is not running").into_response()
+}
+
+async fn list_resources(State(state): State<DebugState>, headers: HeaderMap) -> Response {
+    if !authorized(&state, &headers) {
+        return unauthorized();
+    }
+    let registry = state.registry.clone();
+    let entries = state
+        .queries
+        .query(move |resources| {
+            Box::pin(async move {
+                resources
+                    .resource_infos()
+                    .into_iter()
+                    .map(|info| ResourceEntry {
+                        name: short_name(&info.type_name).to_string(),
+                        observable: registry.is_observable(&info.type_name),
+                        type_name: info.type_name,
+                        writes: info.writes,
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+        .await;
+
+    match entries {
+        Some(entries) => Json(entries).into_response(),
+        None => runner_stopped(),
+    }
+}
+
+async fn read_resource(
+    State(state): State<DebugState>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    if !authorized(&state, &headers) {
+        return unauthorized();
+    }
+    if !state.registry.has_resource(&name) {
+        return (StatusCode::NOT_FOUND, "resource is not observable").into_response();
+    }
+
+    let registry = state.registry.clone();
+    let value = state
+        .queries
+        .query(move |resources| Box::pin(async move { registry.read(&name, resources).await }))
+        .await;
+
+    match value {
+        Some(Some(value)) => Json(value).into_response(),
+        Some(None) => (StatusCode::NOT_FOUND, "resource is not present").into_response(),
+        None => runner_stopped(),
+    }
+}
+
+async fn event_stats(State(state): State<DebugState>, headers: HeaderMap) -> Response {
+    if !authorized(&state, &headers) {
+        return unauthorized();
+    }
+    let stats = state
+        .queries
+        .query(|resources| {
+            Box::pin(async move {
+                let bus = resources.get::<EventBus>().await?;
+                Some(bus.stats())
+            })
+        })
+        .await;
+
+    match stats {
+        Some(Some(stats)) => Json(stats).into_response(),
+        Some(None) => (StatusCode::NOT_FOUND, "no EventBus").into_response(),
+        None => runner_stopped(),
+    }
+}
+
+async fn publish_event(
+    State(state): State<DebugState>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+    Json(body): Json<serde_json::Value>,
+) -> Response {
+    if !authorized(&state, &headers) {
+        return unauthorized();
+    }
+    if !state.registry.has_event(&name) {
+        return (StatusCode::NOT_FOUND, "no factory for this event").into_response();
+    }
+
+    let registry = state.registry.clone();
+    let published = state
+        .queries
+        .query(move |resources| {
+            Box::pin(async move {
+                let mut bus = resources.get_mut::<EventBus>().await?;
+                registry.publish(&name, &mut bus, body)
+            })
+        })
+        .await;
+
+    match published {
+        Some(Some(Ok(()))) => StatusCode::ACCEPTED.into_response(),
+        Some(Some(Err(error))) => (StatusCode::BAD_REQUEST, error).into_response(),
+        Some(None) => (StatusCode::NOT_FOUND, "no EventBus").into_response(),
+        None => runner_stopped(),
+    }
+}
+
+/// `config reload`: re-read the config file in the next tick
+async fn reload_config(State(state): State<DebugState>, headers: HeaderMap) -> Response {
+    if !authorized(&state, &headers) {
+        return unauthorized();
+    }
+
+    let published = state
+        .queries
+        .query(|resources| {
+            Box::pin(async move {
+                let mut bus = resources.get_mut::<EventBus>().await?;
+                bus.publish(ReloadConfigRequested);
+                Some(())
+            })
+        })
+        .await;
+
+    match published {
+        Some(Some(())) => StatusCode::ACCEPTED.into_response(),
+        Some(None) => (StatusCode::NOT_FOUND, "no EventBus").into_response(),
+        None => runner_stopped(),
+    }
+}
@@ -60,6 +60,24 @@ pub struct ConnectionUnlockedEvent {
 
 impl Event for ConnectionUnlockedEvent {}
 
+/// Published when a room move was rejected by a gate or the hook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveBlockedEvent {
+    pub target_room: RoomId,
+    pub reason: String,
+}
+
+impl Event for MoveBlockedEvent {}
+
+/// Published when a gated connection could not be unlocked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionUnlockFailedEvent {
+    pub connection: Connection,
+    pub reason: String,
+}
+
+impl Event for ConnectionUnlockFailedEvent {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,6 +55,23 @@ pub trait DungeonHook: Send + Sync {
         Ok(())
     }
 
+    /// Evaluate a `ConnectionGate::Custom(tag)` gate
+    ///
+    /// Return `Ok(())` to let the player through (the connection then stays
+    /// open), `Err(reason)` to block.
+    ///
+    /// # Default
+    ///
+    /// Always blocks
+    async fn check_custom_gate(
+        &self,
+        tag: &str,
+        _connection: &Connection,
+        _resources: &ResourceContext,
+    ) -> Result<(), String> {
+        Err(format!("'{}' is not met", tag))
+    }
+
     /// Called when player enters a room
     ///
     /// **This is the key feedback loop method.**
@@ -138,6 +155,10 @@ mod tests {
         // Should not panic
         let result = hook.validate_room_move(&room1, &room2, &resources).await;
         assert!(result.is_ok());
+        let gate = hook
+            .check_custom_gate("lever_pulled", &connection, &resources)
+            .await;
+        assert!(gate.is_err());
 
         let mut resources = ResourceContext::new();
         hook.on_room_entered(&room2, true, &mut resources).await;
@@ -5,6 +5,7 @@
 //! - Floor progression (configurable number of floors)
 //! - Room navigation (linear, branching, or graph patterns)
 //! - Event-driven architecture
+//! - Locked doors and flag-gated passages (`ConnectionGate`)
 //! - Customizable room events via hooks
 //! - Progress tracking and visited rooms history
 //!
@@ -20,6 +21,7 @@
 //!             total_floors: 5,
 //!             rooms_per_floor: 3,
 //!             connection_pattern: ConnectionPattern::Linear,
+//!             ..Default::default()
 //!         })
 //!         .with_hook(MyDungeonHook)
 //!     )
@@ -44,5 +46,9 @@ pub use events::*;
 pub use hook::{DefaultDungeonHook, DungeonHook};
 pub use plugin::DungeonPlugin;
 pub use service::DungeonService;
+pub use service::Exit;
 pub use system::DungeonSystem;
-pub use types::{Connection, ConnectionPattern, DungeonConfig, DungeonState, RoomId};
+pub use types::{
+    Connection, ConnectionGate, ConnectionPattern, DungeonConfig, DungeonState, GameFlags,
+    GatedConnection, RoomId,
+};
@@ -3,7 +3,8 @@
 use super::hook::{DefaultDungeonHook, DungeonHook};
 use super::service::DungeonService;
 use super::system::DungeonSystem;
-use super::types::{DungeonConfig, DungeonState};
+use super::types::{Connection, ConnectionGate, DungeonConfig, DungeonState, GatedConnection};
+use crate::plugin::inventory::EntityId;
 use crate::Plugin;
 use std::sync::Arc;
 
@@ -11,7 +12,7 @@ use std::sync::Arc;
 ///
 /// Provides dungeon progression functionality with:
 /// - Floor and room navigation
-/// - Connection unlocking
+/// - Connection unlocking, with locked doors and flag-gated passages
 /// - Customizable room events via hooks
 /// - Event-driven architecture for loose coupling
 ///
@@ -64,6 +65,7 @@ pub struct DungeonPlugin {
     config: DungeonConfig,
 
     #[state]
+    #[plugin(reset)]
     state: DungeonState,
 
     #[service]
@@ -145,6 +147,7 @@ impl DungeonPlugin {
     ///     total_floors: 10,
     ///     rooms_per_floor: 5,
     ///     connection_pattern: ConnectionPattern::Branching,
+    ///     ..Default::default()
     /// };
     ///
     /// let plugin = DungeonPlugin::new().with_config(config);
@@ -153,6 +156,34 @@ impl DungeonPlugin {
         self.config = config;
         self
     }
+
+    /// Gate the connection between two rooms
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let plugin = DungeonPlugin::new()
+    ///     .with_gate(
+    ///         Connection::new(RoomId::new(1, 2), RoomId::new(1, 3)),
+    ///         ConnectionGate::Locked { key_item_id: "iron_key".into(), consume_key: true },
+    ///     )
+    ///     .with_gate(
+    ///         Connection::new(RoomId::new(2, 1), RoomId::new(2, 2)),
+    ///         ConnectionGate::FlagRequired { flag_key: "bridge_lowered".into() },
+    ///     );
+    /// ```
+    pub fn with_gate(mut self, connection: Connection, gate: ConnectionGate) -> Self {
+        self.config
+            .gates
+            .push(GatedConnection::new(connection, gate));
+        self
+    }
+
+    /// Set the entity whose inventory holds the keys (default `"player"`)
+    pub fn with_key_holder(mut self, entity_id: impl Into<EntityId>) -> Self {
+        self.config.key_holder = entity_id.into();
+        self
+    }
 }
 
 impl Default for DungeonPlugin {
@@ -191,6 +222,7 @@ mod tests {
             total_floors: 10,
             rooms_per_floor: 5,
             connection_pattern: ConnectionPattern::Branching,
+            ..Default::default()
         };
 
         let plugin = DungeonPlugin::new().with_config(config);
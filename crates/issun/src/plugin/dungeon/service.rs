@@ -1,6 +1,28 @@
 //! Dungeon navigation service (pure logic)
 
-use super::types::{ConnectionPattern, DungeonConfig, DungeonState, RoomId};
+use super::types::{
+    Connection, ConnectionGate, ConnectionPattern, DungeonConfig, DungeonState, RoomId,
+};
+
+/// Way out of the current room, for drawing its doors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exit {
+    pub room: RoomId,
+    /// Gate on the connection, unlocked or not
+    pub gate: Option<ConnectionGate>,
+    /// Passable without meeting a gate condition
+    pub open: bool,
+}
+
+impl Exit {
+    /// Text for a closed door (e.g. "Locked (needs key 'iron_key')")
+    pub fn blocked_label(&self) -> Option<String> {
+        match (&self.gate, self.open) {
+            (Some(gate), false) => Some(gate.to_string()),
+            _ => None,
+        }
+    }
+}
 
 /// Dungeon navigation service
 ///
@@ -52,6 +74,39 @@ impl DungeonService {
         }
     }
 
+    /// Exits of the current room: the available rooms plus the far ends of
+    /// gated connections, closed ones marked so scenes can draw them apart
+    pub fn exits(&self, config: &DungeonConfig, state: &DungeonState) -> Vec<Exit> {
+        let here = RoomId::new(state.current_floor, state.current_room);
+        let mut rooms: Vec<RoomId> = self
+            .available_rooms(config, state)
+            .into_iter()
+            .map(|room| RoomId::new(state.current_floor, room))
+            .collect();
+        for gated in &config.gates {
+            let other = if gated.connection.from == here {
+                &gated.connection.to
+            } else if gated.connection.to == here {
+                &gated.connection.from
+            } else {
+                continue;
+            };
+            if !rooms.contains(other) {
+                rooms.push(other.clone());
+            }
+        }
+
+        rooms
+            .into_iter()
+            .map(|room| {
+                let gate = config.gate_between(&here, &room).map(|g| g.gate.clone());
+                let open = gate.is_none()
+                    || state.is_unlocked(&Connection::new(here.clone(), room.clone()));
+                Exit { room, gate, open }
+            })
+            .collect()
+    }
+
     /// Check if can advance to next floor
     pub fn can_advance_floor(&self, config: &DungeonConfig, state: &DungeonState) -> bool {
         // Boss room cleared (last room on floor)
@@ -94,6 +149,7 @@ mod tests {
             total_floors: 3,
             rooms_per_floor: 3,
             connection_pattern: ConnectionPattern::Linear,
+            ..Default::default()
         };
         let state = DungeonState {
             current_floor: 1,
@@ -125,6 +181,7 @@ mod tests {
             total_floors: 5,
             rooms_per_floor: 3,
             connection_pattern: ConnectionPattern::Linear,
+            ..Default::default()
         };
         let state = DungeonState {
             current_floor: 3,
@@ -136,4 +193,48 @@ mod tests {
         // (2 * 3 + 2) / 15 * 100 = 8 / 15 * 100 = 53.33...
         assert!((progress - 53.33).abs() < 0.1);
     }
+
+    #[test]
+    fn test_exits_mark_gated_connections() {
+        use super::super::types::GatedConnection;
+
+        let service = DungeonService::new();
+        let door = Connection::new(RoomId::new(1, 1), RoomId::new(1, 2));
+        let config = DungeonConfig {
+            gates: vec![
+                GatedConnection::new(
+                    door.clone(),
+                    ConnectionGate::Locked {
+                        key_item_id: "iron_key".to_string(),
+                        consume_key: true,
+                    },
+                ),
+                GatedConnection::new(
+                    Connection::new(RoomId::new(1, 3), RoomId::new(1, 1)),
+                    ConnectionGate::FlagRequired {
+                        flag_key: "bridge_lowered".to_string(),
+                    },
+                ),
+            ],
+            ..Default::default()
+        };
+        let mut state = DungeonState::default();
+
+        let exits = service.exits(&config, &state);
+        assert_eq!(exits.len(), 2);
+        assert_eq!(exits[0].room, RoomId::new(1, 2));
+        assert!(!exits[0].open);
+        assert_eq!(
+            exits[0].blocked_label().unwrap(),
+            "Locked (needs key 'iron_key')"
+        );
+        assert_eq!(exits[1].room, RoomId::new(1, 3));
+        assert!(!exits[1].open);
+
+        state.unlocked_connections.push(door);
+        let exits = service.exits(&config, &state);
+        assert!(exits[0].open);
+        assert!(exits[0].gate.is_some());
+        assert_eq!(exits[0].blocked_label(), None);
+    }
 }
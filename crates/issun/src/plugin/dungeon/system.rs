@@ -9,7 +9,10 @@ use std::sync::Arc;
 
 use super::events::*;
 use super::hook::DungeonHook;
-use super::types::DungeonState;
+use super::types::{
+    Connection, ConnectionGate, DungeonConfig, DungeonState, GameFlags, GatedConnection,
+};
+use crate::plugin::inventory::{EntityId, InventoryState};
 
 /// System that processes dungeon events with hooks
 ///
@@ -17,8 +20,10 @@ use super::types::DungeonState;
 /// 1. Processes room move requests
 /// 2. Processes floor advance requests
 /// 3. Processes connection unlock requests
-/// 4. Calls hooks for custom behavior
-/// 5. Publishes state change events for network replication
+/// 4. Checks gated connections (keys in `InventoryState`, flags in
+///    `GameFlags`, custom gates via the hook)
+/// 5. Calls hooks for custom behavior
+/// 6. Publishes state change events for network replication
 ///
 /// # Feedback Loop
 ///
@@ -70,16 +75,24 @@ impl DungeonSystem {
             };
 
             // Validate via hook
-            {
-                let resources_ref = resources as &ResourceContext;
-                if self
-                    .hook
-                    .validate_room_move(&current_room, &request.target_room, resources_ref)
-                    .await
-                    .is_err()
-                {
+            let validation = self
+                .hook
+                .validate_room_move(&current_room, &request.target_room, resources)
+                .await;
+            if let Err(reason) = validation {
+                publish_blocked(resources, &request, reason).await;
+                continue;
+            }
+
+            // Pass a gate: its condition must hold, then it stays open
+            let connection = Connection::new(current_room.clone(), request.target_room.clone());
+            if let Some(gated) = self.closed_gate(&connection, resources).await {
+                if let Err(reason) = self.check_gate(&gated, resources).await {
+                    publish_blocked(resources, &request, reason).await;
                     continue;
                 }
+                self.unlock(gated.connection, Some(&gated.gate), resources)
+                    .await;
             }
 
             // Check if first visit
@@ -176,7 +189,7 @@ impl DungeonSystem {
             // Check if already unlocked
             let already_unlocked = {
                 if let Some(state) = resources.get::<DungeonState>().await {
-                    state.unlocked_connections.contains(&request.connection)
+                    state.is_unlocked(&request.connection)
                 } else {
                     true
                 }
@@ -186,27 +199,130 @@ impl DungeonSystem {
                 continue;
             }
 
-            // Unlock connection (update state)
-            {
-                if let Some(mut state) = resources.get_mut::<DungeonState>().await {
-                    state.unlocked_connections.push(request.connection.clone());
-                } else {
+            // Gated connections open only when their condition holds
+            let gate = self
+                .closed_gate(&request.connection, resources)
+                .await
+                .map(|gated| gated.gate);
+            if let Some(gate) = &gate {
+                let gated = GatedConnection::new(request.connection.clone(), gate.clone());
+                if let Err(reason) = self.check_gate(&gated, resources).await {
+                    if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                        bus.publish(ConnectionUnlockFailedEvent {
+                            connection: request.connection.clone(),
+                            reason,
+                        });
+                    }
                     continue;
                 }
             }
 
-            // Call hook
-            self.hook
-                .on_connection_unlocked(&request.connection, resources)
+            self.unlock(request.connection, gate.as_ref(), resources)
                 .await;
+        }
+    }
 
-            // Publish event
-            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
-                bus.publish(ConnectionUnlockedEvent {
-                    connection: request.connection.clone(),
-                });
+    /// Gate of `connection` if it has one and is not unlocked yet
+    async fn closed_gate(
+        &self,
+        connection: &Connection,
+        resources: &ResourceContext,
+    ) -> Option<GatedConnection> {
+        let gated = resources
+            .get::<DungeonConfig>()
+            .await?
+            .gate_between(&connection.from, &connection.to)
+            .cloned()?;
+        match resources.get::<DungeonState>().await {
+            Some(state) if state.is_unlocked(connection) => None,
+            _ => Some(gated),
+        }
+    }
+
+    /// `Err(reason)` unless the gate's condition holds
+    async fn check_gate(
+        &self,
+        gated: &GatedConnection,
+        resources: &ResourceContext,
+    ) -> Result<(), String> {
+        let held = match &gated.gate {
+            ConnectionGate::Locked { key_item_id, .. } => {
+                let key_holder = key_holder(resources).await;
+                match resources.get::<InventoryState>().await {
+                    Some(inventory) => inventory.has_item(&key_holder, key_item_id, 1),
+                    None => false,
+                }
+            }
+            ConnectionGate::FlagRequired { flag_key } => match resources.get::<GameFlags>().await {
+                Some(flags) => flags.is_set(flag_key),
+                None => false,
+            },
+            ConnectionGate::Custom(tag) => {
+                return self
+                    .hook
+                    .check_custom_gate(tag, &gated.connection, resources)
+                    .await
+            }
+        };
+        if held {
+            Ok(())
+        } else {
+            Err(gated.gate.to_string())
+        }
+    }
+
+    /// Open `connection` for good, using up the key of a consuming lock
+    async fn unlock(
+        &mut self,
+        connection: Connection,
+        gate: Option<&ConnectionGate>,
+        resources: &mut ResourceContext,
+    ) {
+        if let Some(ConnectionGate::Locked {
+            key_item_id,
+            consume_key: true,
+        }) = gate
+        {
+            let key_holder = key_holder(resources).await;
+            if let Some(mut inventory) = resources.get_mut::<InventoryState>().await {
+                let _ = inventory.remove_item(&key_holder, key_item_id, 1);
+            }
+        }
+
+        // Unlock connection (update state)
+        {
+            if let Some(mut state) = resources.get_mut::<DungeonState>().await {
+                state.unlocked_connections.push(connection.clone());
+            } else {
+                return;
             }
         }
+
+        // Call hook
+        self.hook
+            .on_connection_unlocked(&connection, resources)
+            .await;
+
+        // Publish event
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.publish(ConnectionUnlockedEvent { connection });
+        }
+    }
+}
+
+async fn key_holder(resources: &ResourceContext) -> EntityId {
+    match resources.get::<DungeonConfig>().await {
+        Some(config) => config.key_holder.clone(),
+        None => DungeonConfig::default().key_holder,
+    }
+}
+
+async fn publish_blocked(resources: &ResourceContext, request: &RoomMoveRequested, reason: String) {
+    if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+        bus.publish(MoveBlockedEvent {
+            target_room: request.target_room.clone(),
+            reason,
+        });
     }
 }
 
@@ -224,3 +340,194 @@ impl System for DungeonSystem {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Event;
+    use crate::plugin::dungeon::hook::DefaultDungeonHook;
+    use crate::plugin::dungeon::types::RoomId;
+    use serde::Serialize;
+
+    fn door() -> Connection {
+        Connection::new(RoomId::new(1, 1), RoomId::new(1, 2))
+    }
+
+    fn resources_with(gate: ConnectionGate, keys: u32) -> ResourceContext {
+        let mut inventory = InventoryState::new();
+        inventory
+            .add_item(&"player".to_string(), &"iron_key".to_string(), keys)
+            .unwrap();
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(DungeonConfig {
+            gates: vec![GatedConnection::new(door(), gate)],
+            ..Default::default()
+        });
+        resources.insert(DungeonState::default());
+        resources.insert(inventory);
+        resources.insert(GameFlags::new());
+        resources
+    }
+
+    fn iron_door() -> ConnectionGate {
+        ConnectionGate::Locked {
+            key_item_id: "iron_key".to_string(),
+            consume_key: true,
+        }
+    }
+
+    /// Publish `event`, run the system once and return the blocked moves
+    async fn run<E: Event + Serialize>(
+        system: &mut DungeonSystem,
+        resources: &mut ResourceContext,
+        event: E,
+    ) -> Vec<MoveBlockedEvent> {
+        {
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+            bus.publish(event);
+            bus.dispatch();
+        }
+        system
+            .process_events(&ServiceContext::new(), resources)
+            .await;
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        bus.reader::<MoveBlockedEvent>().iter().cloned().collect()
+    }
+
+    fn move_to(room: u32) -> RoomMoveRequested {
+        RoomMoveRequested {
+            target_room: RoomId::new(1, room),
+        }
+    }
+
+    async fn current_room(resources: &ResourceContext) -> u32 {
+        resources.get::<DungeonState>().await.unwrap().current_room
+    }
+
+    async fn keys(resources: &ResourceContext) -> u32 {
+        resources
+            .get::<InventoryState>()
+            .await
+            .unwrap()
+            .get_item_quantity(&"player".to_string(), &"iron_key".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_locked_door_blocks_without_key() {
+        let mut system = DungeonSystem::new(Arc::new(DefaultDungeonHook));
+        let mut resources = resources_with(iron_door(), 0);
+
+        let blocked = run(&mut system, &mut resources, move_to(2)).await;
+        assert_eq!(current_room(&resources).await, 1);
+        assert_eq!(blocked.len(), 1);
+        assert_eq!(blocked[0].reason, "Locked (needs key 'iron_key')");
+
+        // Other connections are not affected
+        run(&mut system, &mut resources, move_to(3)).await;
+        assert_eq!(current_room(&resources).await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_unlock_consumes_key_once_and_persists() {
+        let mut system = DungeonSystem::new(Arc::new(DefaultDungeonHook));
+        let mut resources = resources_with(iron_door(), 2);
+
+        let unlock = ConnectionUnlockRequested { connection: door() };
+        run(&mut system, &mut resources, unlock.clone()).await;
+        run(&mut system, &mut resources, unlock).await;
+        assert_eq!(keys(&resources).await, 1);
+        assert!(resources
+            .get::<DungeonState>()
+            .await
+            .unwrap()
+            .is_unlocked(&door()));
+
+        // Revisit through the open door, both ways
+        for room in [2, 1, 2] {
+            assert!(run(&mut system, &mut resources, move_to(room))
+                .await
+                .is_empty());
+        }
+        assert_eq!(current_room(&resources).await, 2);
+        assert_eq!(keys(&resources).await, 1);
+
+        // Save and load the dungeon state into a game without keys
+        let saved =
+            serde_json::to_string(&*resources.get::<DungeonState>().await.unwrap()).unwrap();
+        let mut loaded = resources_with(iron_door(), 0);
+        loaded.insert(serde_json::from_str::<DungeonState>(&saved).unwrap());
+        assert!(run(&mut system, &mut loaded, move_to(1)).await.is_empty());
+        assert_eq!(current_room(&loaded).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_moving_with_key_opens_door() {
+        let mut system = DungeonSystem::new(Arc::new(DefaultDungeonHook));
+        let mut resources = resources_with(iron_door(), 1);
+
+        assert!(run(&mut system, &mut resources, move_to(2))
+            .await
+            .is_empty());
+        assert_eq!(current_room(&resources).await, 2);
+        assert_eq!(keys(&resources).await, 0);
+
+        run(&mut system, &mut resources, move_to(1)).await;
+        assert_eq!(current_room(&resources).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_flag_gate_opens_when_flag_is_set() {
+        let mut system = DungeonSystem::new(Arc::new(DefaultDungeonHook));
+        let gate = ConnectionGate::FlagRequired {
+            flag_key: "bridge_lowered".to_string(),
+        };
+        let mut resources = resources_with(gate, 0);
+
+        let blocked = run(&mut system, &mut resources, move_to(2)).await;
+        assert_eq!(blocked[0].reason, "Sealed (needs 'bridge_lowered')");
+        assert_eq!(current_room(&resources).await, 1);
+
+        resources
+            .get_mut::<GameFlags>()
+            .await
+            .unwrap()
+            .set("bridge_lowered");
+        assert!(run(&mut system, &mut resources, move_to(2))
+            .await
+            .is_empty());
+        assert_eq!(current_room(&resources).await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_custom_gate_is_checked_by_hook() {
+        struct LeverHook;
+
+        #[async_trait]
+        impl DungeonHook for LeverHook {
+            async fn check_custom_gate(
+                &self,
+                tag: &str,
+                _connection: &Connection,
+                resources: &ResourceContext,
+            ) -> Result<(), String> {
+                match resources.get::<GameFlags>().await {
+                    Some(flags) if flags.is_set(tag) => Ok(()),
+                    _ => Err("The lever is up".to_string()),
+                }
+            }
+        }
+
+        let mut system = DungeonSystem::new(Arc::new(LeverHook));
+        let mut resources = resources_with(ConnectionGate::Custom("lever".to_string()), 0);
+
+        let blocked = run(&mut system, &mut resources, move_to(2)).await;
+        assert_eq!(blocked[0].reason, "The lever is up");
+
+        resources.get_mut::<GameFlags>().await.unwrap().set("lever");
+        run(&mut system, &mut resources, move_to(2)).await;
+        assert_eq!(current_room(&resources).await, 2);
+    }
+}
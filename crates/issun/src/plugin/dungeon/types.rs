@@ -1,7 +1,10 @@
 //! Dungeon plugin types
 
+use crate::plugin::inventory::{EntityId, ItemId};
 use crate::state::State;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
 
 /// Dungeon structure configuration
 ///
@@ -12,6 +15,16 @@ pub struct DungeonConfig {
     pub total_floors: u32,
     pub rooms_per_floor: u32,
     pub connection_pattern: ConnectionPattern,
+    /// Locked doors and flag-gated passages; a gate blocks both directions
+    #[serde(default)]
+    pub gates: Vec<GatedConnection>,
+    /// Entity whose inventory (`InventoryState`) holds the keys
+    #[serde(default = "default_key_holder")]
+    pub key_holder: EntityId,
+}
+
+fn default_key_holder() -> EntityId {
+    "player".to_string()
 }
 
 impl Default for DungeonConfig {
@@ -20,10 +33,19 @@ impl Default for DungeonConfig {
             total_floors: 5,
             rooms_per_floor: 3,
             connection_pattern: ConnectionPattern::Linear,
+            gates: Vec::new(),
+            key_holder: default_key_holder(),
         }
     }
 }
 
+impl DungeonConfig {
+    /// Gate on the connection between `a` and `b`, in either direction
+    pub fn gate_between(&self, a: &RoomId, b: &RoomId) -> Option<&GatedConnection> {
+        self.gates.iter().find(|gate| gate.connection.links(a, b))
+    }
+}
+
 /// Room connection pattern
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ConnectionPattern {
@@ -46,6 +68,15 @@ pub struct DungeonState {
 
 impl State for DungeonState {}
 
+impl DungeonState {
+    /// Whether `connection` was unlocked, in either direction
+    pub fn is_unlocked(&self, connection: &Connection) -> bool {
+        self.unlocked_connections
+            .iter()
+            .any(|unlocked| unlocked.links(&connection.from, &connection.to))
+    }
+}
+
 impl Default for DungeonState {
     fn default() -> Self {
         Self {
@@ -81,4 +112,77 @@ impl Connection {
     pub fn new(from: RoomId, to: RoomId) -> Self {
         Self { from, to }
     }
+
+    /// Whether this connection joins `a` and `b`, in either direction
+    pub fn links(&self, a: &RoomId, b: &RoomId) -> bool {
+        (&self.from == a && &self.to == b) || (&self.from == b && &self.to == a)
+    }
+}
+
+/// Condition for passing a gated connection
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConnectionGate {
+    /// Needs `key_item_id` in the key holder's inventory; with `consume_key`
+    /// one key is used up when the door opens
+    Locked {
+        key_item_id: ItemId,
+        consume_key: bool,
+    },
+    /// Needs `flag_key` set in the [`GameFlags`] resource
+    FlagRequired { flag_key: String },
+    /// Evaluated by [`DungeonHook::check_custom_gate`](super::DungeonHook::check_custom_gate)
+    Custom(String),
+}
+
+impl fmt::Display for ConnectionGate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectionGate::Locked { key_item_id, .. } => {
+                write!(f, "Locked (needs key '{}')", key_item_id)
+            }
+            ConnectionGate::FlagRequired { flag_key } => write!(f, "Sealed (needs '{}')", flag_key),
+            ConnectionGate::Custom(tag) => write!(f, "Blocked ({})", tag),
+        }
+    }
+}
+
+/// Connection closed until its gate's condition holds
+///
+/// Once passed or unlocked through `ConnectionUnlockRequested`, the connection
+/// is recorded in `DungeonState::unlocked_connections` and stays open.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GatedConnection {
+    pub connection: Connection,
+    pub gate: ConnectionGate,
+}
+
+impl GatedConnection {
+    pub fn new(connection: Connection, gate: ConnectionGate) -> Self {
+        Self { connection, gate }
+    }
+}
+
+/// Game-wide flags (quest progress, switches) checked by flag gates
+#[derive(crate::Resource, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameFlags {
+    flags: HashSet<String>,
+}
+
+impl GameFlags {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, flag: impl Into<String>) {
+        self.flags.insert(flag.into());
+    }
+
+    /// Returns `false` if the flag was not set
+    pub fn clear(&mut self, flag: &str) -> bool {
+        self.flags.remove(flag)
+    }
+
+    pub fn is_set(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
 }
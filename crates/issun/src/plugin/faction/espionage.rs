@@ -0,0 +1,346 @@
+//! Espionage operations
+//!
+//! An espionage operation is a regular [`Operation`] whose metadata carries
+//! an [`EspionageOrder`] (`"kind": "espionage"`). It stays in progress for
+//! [`EspionageConfig::duration_turns`] `DayChanged` events and then resolves
+//! against the target's [`Faction::security`]:
+//! - success: `IntelGatheredEvent` with noisy facts about the target
+//! - failure: the operation fails; if the target detects it, a
+//!   `DiplomaticIncidentEvent` and reputation penalties follow
+
+use super::state::FactionState;
+use super::types::{Faction, FactionId, Operation, OperationStatus};
+use crate::resources::Resource;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Metadata `kind` that marks an espionage operation
+pub const ESPIONAGE_KIND: &str = "espionage";
+
+/// Rules for espionage operations (ReadOnly)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EspionageConfig {
+    /// Turns (`DayChanged` events) before an operation resolves
+    pub duration_turns: u32,
+
+    /// Success chance against a target without security
+    pub base_success_chance: f32,
+
+    /// Success chance lost per point of target security (0.0-1.0)
+    pub security_success_penalty: f32,
+
+    /// Success chance never drops below this
+    pub min_success_chance: f32,
+
+    /// Intel accuracy against a target without security
+    pub base_accuracy: f32,
+
+    /// Accuracy lost per point of target security
+    pub security_accuracy_penalty: f32,
+
+    /// Largest relative error of numeric intel, reached at accuracy 0.0
+    ///
+    /// At accuracy `a` values are off by at most `max_noise * (1 - a)`.
+    pub max_noise: f32,
+
+    /// Chance that a failed operation is detected by an unguarded target;
+    /// security raises it towards 1.0
+    pub detection_chance: f32,
+
+    /// Chance that a detected operation becomes public
+    pub exposure_chance: f32,
+
+    /// Target's opinion of the spying faction lost on detection
+    pub relation_penalty: f32,
+
+    /// Every other faction's opinion lost when the operation is exposed
+    pub reputation_penalty: f32,
+}
+
+impl Resource for EspionageConfig {}
+
+impl Default for EspionageConfig {
+    fn default() -> Self {
+        Self {
+            duration_turns: 2,
+            base_success_chance: 0.7,
+            security_success_penalty: 0.6,
+            min_success_chance: 0.05,
+            base_accuracy: 0.9,
+            security_accuracy_penalty: 0.4,
+            max_noise: 0.5,
+            detection_chance: 0.5,
+            exposure_chance: 0.3,
+            relation_penalty: 20.0,
+            reputation_penalty: 5.0,
+        }
+    }
+}
+
+impl EspionageConfig {
+    /// Chance that an operation against a target with `security` succeeds
+    pub fn success_chance(&self, security: f32) -> f32 {
+        let chance =
+            self.base_success_chance - security.clamp(0.0, 1.0) * self.security_success_penalty;
+        chance.clamp(self.min_success_chance.clamp(0.0, 1.0), 1.0)
+    }
+
+    /// Accuracy of intel gathered from a target with `security`
+    pub fn accuracy(&self, security: f32) -> f32 {
+        (self.base_accuracy - security.clamp(0.0, 1.0) * self.security_accuracy_penalty)
+            .clamp(0.0, 1.0)
+    }
+
+    /// Chance that a failed operation is detected by a target with `security`
+    pub fn detection_chance(&self, security: f32) -> f32 {
+        let security = security.clamp(0.0, 1.0);
+        let base = self.detection_chance.clamp(0.0, 1.0);
+        base + (1.0 - base) * security
+    }
+
+    /// Largest relative error of numeric intel at `accuracy`
+    pub fn noise_bound(&self, accuracy: f32) -> f32 {
+        self.max_noise.max(0.0) * (1.0 - accuracy.clamp(0.0, 1.0))
+    }
+}
+
+/// Target of an espionage operation, stored in the operation metadata
+///
+/// # Example
+///
+/// ```
+/// use issun::plugin::faction::{EspionageOrder, FactionId};
+///
+/// let request = EspionageOrder::new("azure")
+///     .with_territory("nova-harbor")
+///     .into_request(FactionId::new("crimson"), "Watch the harbor");
+/// assert_eq!(request.metadata["kind"], "espionage");
+/// assert_eq!(
+///     EspionageOrder::from_metadata(&request.metadata).unwrap().target,
+///     FactionId::new("azure")
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EspionageOrder {
+    /// Faction being spied on
+    pub target: FactionId,
+
+    /// Territory the operation focuses on
+    #[serde(default)]
+    pub territory: Option<String>,
+}
+
+impl EspionageOrder {
+    /// Spy on `target`
+    pub fn new(target: impl Into<FactionId>) -> Self {
+        Self {
+            target: target.into(),
+            territory: None,
+        }
+    }
+
+    /// Focus on one of the target's territories
+    pub fn with_territory(mut self, territory: impl Into<String>) -> Self {
+        self.territory = Some(territory.into());
+        self
+    }
+
+    /// Operation metadata for this order
+    pub fn to_metadata(&self) -> serde_json::Value {
+        serde_json::json!({
+            "kind": ESPIONAGE_KIND,
+            "target": self.target.as_str(),
+            "territory": self.territory,
+        })
+    }
+
+    /// Order stored in operation metadata, if it is an espionage operation
+    pub fn from_metadata(metadata: &serde_json::Value) -> Option<Self> {
+        if metadata.get("kind").and_then(|kind| kind.as_str()) != Some(ESPIONAGE_KIND) {
+            return None;
+        }
+        Some(Self {
+            target: FactionId::new(metadata.get("target")?.as_str()?),
+            territory: metadata
+                .get("territory")
+                .and_then(|territory| territory.as_str())
+                .map(str::to_string),
+        })
+    }
+
+    /// Launch request for this order
+    pub fn into_request(
+        self,
+        faction_id: FactionId,
+        operation_name: impl Into<String>,
+    ) -> super::events::OperationLaunchRequested {
+        super::events::OperationLaunchRequested {
+            faction_id,
+            operation_name: operation_name.into(),
+            metadata: self.to_metadata(),
+        }
+    }
+}
+
+/// An espionage operation waiting to resolve (saved with [`FactionState`])
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EspionageProgress {
+    pub order: EspionageOrder,
+    pub turns_remaining: u32,
+}
+
+/// One piece of gathered intel
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntelFact {
+    /// Fact key (`"garrison"`, `"treasury"`, `"upcoming_operations"` or a
+    /// game-specific key from `FactionHook::gather_intel`)
+    pub key: String,
+
+    /// Observed value, numeric values include noise
+    pub value: serde_json::Value,
+}
+
+impl IntelFact {
+    /// Create a fact
+    pub fn new(key: impl Into<String>, value: serde_json::Value) -> Self {
+        Self {
+            key: key.into(),
+            value,
+        }
+    }
+}
+
+/// Built-in intel about `target`
+///
+/// - `garrison`: `metadata.garrisons[territory]` for a territory order,
+///   otherwise `metadata.garrison`
+/// - `treasury`: `metadata.treasury`
+/// - `upcoming_operations`: names of the target's pending and in-progress
+///   operations, exact
+///
+/// Numbers are multiplied by a factor within `1.0 ± noise_bound`.
+pub fn gather_intel(
+    target: &Faction,
+    order: &EspionageOrder,
+    state: &FactionState,
+    noise_bound: f32,
+    rng: &mut impl Rng,
+) -> Vec<IntelFact> {
+    let mut facts = Vec::new();
+
+    let garrison = match &order.territory {
+        Some(territory) => target
+            .metadata
+            .get("garrisons")
+            .and_then(|g| g.get(territory)),
+        None => target.metadata.get("garrison"),
+    };
+    if let Some(garrison) = garrison.and_then(|value| value.as_f64()) {
+        let estimate = apply_noise(garrison as f32, noise_bound, rng).round();
+        facts.push(IntelFact::new("garrison", serde_json::json!(estimate)));
+    }
+
+    if let Some(treasury) = target.metadata.get("treasury").and_then(|v| v.as_f64()) {
+        let estimate = apply_noise(treasury as f32, noise_bound, rng);
+        facts.push(IntelFact::new("treasury", serde_json::json!(estimate)));
+    }
+
+    let mut upcoming: Vec<&Operation> = state
+        .operations_for_faction(&target.id)
+        .filter(|op| {
+            matches!(
+                op.status,
+                OperationStatus::Pending | OperationStatus::InProgress
+            )
+        })
+        .collect();
+    upcoming.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
+    if !upcoming.is_empty() {
+        let names: Vec<&str> = upcoming.iter().map(|op| op.name.as_str()).collect();
+        facts.push(IntelFact::new(
+            "upcoming_operations",
+            serde_json::json!(names),
+        ));
+    }
+
+    facts
+}
+
+/// `value` off by a random relative error of at most `noise_bound`
+pub fn apply_noise(value: f32, noise_bound: f32, rng: &mut impl Rng) -> f32 {
+    if noise_bound <= 0.0 {
+        return value;
+    }
+    value * (1.0 + rng.gen_range(-noise_bound..=noise_bound))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_security_lowers_chance_and_accuracy() {
+        let config = EspionageConfig::default();
+        assert!(config.success_chance(0.8) < config.success_chance(0.0));
+        assert!(config.accuracy(0.8) < config.accuracy(0.0));
+        assert!(config.detection_chance(0.8) > config.detection_chance(0.0));
+        assert_eq!(config.success_chance(5.0), config.success_chance(1.0));
+        assert!(config.success_chance(1.0) >= config.min_success_chance);
+    }
+
+    #[test]
+    fn test_order_round_trips_through_metadata() {
+        let order = EspionageOrder::new("azure").with_territory("nova-harbor");
+        assert_eq!(
+            EspionageOrder::from_metadata(&order.to_metadata()),
+            Some(order)
+        );
+        assert_eq!(
+            EspionageOrder::from_metadata(&serde_json::json!({ "target": "azure" })),
+            None
+        );
+    }
+
+    #[test]
+    fn test_gather_intel_noise_is_bounded() {
+        let target = Faction::new("azure", "Azure Order").with_metadata(serde_json::json!({
+            "garrison": 100,
+            "garrisons": { "nova-harbor": 40 },
+            "treasury": 5000.0,
+        }));
+        let mut state = FactionState::new();
+        state
+            .launch_operation(Operation::new("op-2", target.id.clone(), "Raid"))
+            .unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..50 {
+            let facts = gather_intel(
+                &target,
+                &EspionageOrder::new("azure"),
+                &state,
+                0.1,
+                &mut rng,
+            );
+            let garrison = facts[0].value.as_f64().unwrap();
+            let treasury = facts[1].value.as_f64().unwrap();
+            assert!((90.0..=110.0).contains(&garrison), "{garrison}");
+            assert!((4500.0..=5500.0).contains(&treasury), "{treasury}");
+            assert_eq!(facts[2].value, serde_json::json!(["Raid"]));
+        }
+
+        let focused = gather_intel(
+            &target,
+            &EspionageOrder::new("azure").with_territory("nova-harbor"),
+            &state,
+            0.0,
+            &mut rng,
+        );
+        assert_eq!(
+            focused[0],
+            IntelFact::new("garrison", serde_json::json!(40.0))
+        );
+    }
+}
@@ -4,6 +4,7 @@ use crate::event::Event;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::espionage::IntelFact;
 use super::types::{FactionId, OperationId, Outcome};
 
 // ========================================
@@ -196,3 +197,51 @@ pub struct OperationFailedEvent {
 }
 
 impl Event for OperationFailedEvent {}
+
+/// Published when an espionage operation succeeds (State Change Event)
+///
+/// The subjective_reality plugin turns the facts into beliefs of the spying
+/// faction (`PerceptionSystem::ingest_intel`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntelGatheredEvent {
+    /// Espionage operation
+    pub operation_id: OperationId,
+    /// Faction that gathered the intel
+    pub faction_id: FactionId,
+    /// Faction the intel is about
+    pub target: FactionId,
+    /// Territory the operation focused on
+    pub territory: Option<String>,
+    /// Accuracy of the intel (0.0-1.0), lowered by the target's security
+    pub accuracy: f32,
+    /// Day the operation resolved
+    pub day: u32,
+    /// Gathered facts
+    pub facts: Vec<IntelFact>,
+}
+
+impl Event for IntelGatheredEvent {}
+
+/// Published when a target detects a failed espionage operation (State
+/// Change Event)
+///
+/// `FactionSystem` also requests the reputation penalties: the target's
+/// opinion of the spy drops by `relation_penalty`, and, if `exposed`, every
+/// other faction's by `reputation_penalty`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiplomaticIncidentEvent {
+    /// Espionage operation
+    pub operation_id: OperationId,
+    /// Faction caught spying
+    pub faction_id: FactionId,
+    /// Faction that detected the operation
+    pub target: FactionId,
+    /// Whether the operation became public
+    pub exposed: bool,
+    /// Target's opinion lost
+    pub relation_penalty: f32,
+    /// Other factions' opinion lost (0.0 unless exposed)
+    pub reputation_penalty: f32,
+}
+
+impl Event for DiplomaticIncidentEvent {}
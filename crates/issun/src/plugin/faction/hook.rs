@@ -3,6 +3,8 @@
 use crate::context::ResourceContext;
 use async_trait::async_trait;
 
+use super::espionage::{EspionageOrder, IntelFact};
+use super::events::DiplomaticIncidentEvent;
 use super::types::*;
 
 /// Trait for custom faction behavior
@@ -150,6 +152,38 @@ pub trait FactionHook: Send + Sync {
     ) {
         // Default: do nothing
     }
+
+    /// Game-specific intel from a successful espionage operation
+    ///
+    /// Returned facts are added to the built-in ones (garrison, treasury,
+    /// upcoming operations) in the `IntelGatheredEvent`. `accuracy` is the
+    /// accuracy of this operation's intel.
+    ///
+    /// # Default
+    ///
+    /// No extra facts
+    async fn gather_intel(
+        &self,
+        _spy: &Faction,
+        _target: &Faction,
+        _order: &EspionageOrder,
+        _accuracy: f32,
+        _resources: &ResourceContext,
+    ) -> Vec<IntelFact> {
+        Vec::new()
+    }
+
+    /// Called when a target detects a failed espionage operation
+    ///
+    /// Reputation penalties are already requested; use this for game-specific
+    /// consequences (e.g., breaking treaties).
+    async fn on_diplomatic_incident(
+        &self,
+        _incident: &DiplomaticIncidentEvent,
+        _resources: &mut ResourceContext,
+    ) {
+        // Default: do nothing
+    }
 }
 
 /// No-op default hook
@@ -1,5 +1,6 @@
 //! Faction management plugin for strategy, RPG, and simulation games
 
+mod espionage;
 mod events;
 mod factions;
 mod hook;
@@ -9,9 +10,13 @@ mod state;
 mod system;
 mod types;
 
+pub use espionage::{
+    apply_noise, gather_intel, EspionageConfig, EspionageOrder, EspionageProgress, IntelFact,
+    ESPIONAGE_KIND,
+};
 pub use events::{
-    OperationCompletedEvent, OperationFailedEvent, OperationLaunchRequested,
-    OperationLaunchedEvent, OperationResolveRequested,
+    DiplomaticIncidentEvent, IntelGatheredEvent, OperationCompletedEvent, OperationFailedEvent,
+    OperationLaunchRequested, OperationLaunchedEvent, OperationResolveRequested,
 };
 pub use factions::Factions;
 pub use hook::{DefaultFactionHook, FactionHook};
@@ -1,5 +1,6 @@
 //! Faction plugin implementation
 
+use super::espionage::EspionageConfig;
 use super::factions::Factions;
 use super::hook::{DefaultFactionHook, FactionHook};
 use super::state::FactionState;
@@ -12,6 +13,7 @@ use std::sync::Arc;
 /// This plugin provides faction/organization/group management for games.
 /// It registers Factions, FactionState resources and FactionSystem that handles:
 /// - Processing operation launch requests
+/// - Resolving espionage operations once per `DayChanged`
 /// - Processing operation resolution requests
 /// - Custom hooks for game-specific behavior
 ///
@@ -66,6 +68,8 @@ pub struct FactionPlugin {
     hook: Arc<dyn FactionHook>,
     #[plugin(resource)]
     factions: Factions,
+    #[plugin(resource)]
+    espionage: EspionageConfig,
     #[plugin(runtime_state)]
     #[allow(dead_code)]
     state: FactionState,
@@ -83,6 +87,7 @@ impl FactionPlugin {
         Self {
             hook: hook.clone(),
             factions: Factions::new(),
+            espionage: EspionageConfig::default(),
             state: FactionState::new(),
             system: FactionSystem::new(hook),
         }
@@ -150,6 +155,23 @@ impl FactionPlugin {
         self.factions = factions;
         self
     }
+
+    /// Set the rules for espionage operations
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use issun::plugin::faction::{EspionageConfig, FactionPlugin};
+    ///
+    /// let plugin = FactionPlugin::new().with_espionage_config(EspionageConfig {
+    ///     duration_turns: 3,
+    ///     ..EspionageConfig::default()
+    /// });
+    /// ```
+    pub fn with_espionage_config(mut self, config: EspionageConfig) -> Self {
+        self.espionage = config;
+        self
+    }
 }
 
 impl Default for FactionPlugin {
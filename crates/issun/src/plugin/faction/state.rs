@@ -1,5 +1,6 @@
 //! Faction runtime state (Mutable)
 
+use super::espionage::{EspionageOrder, EspionageProgress};
 use super::types::*;
 use crate::state::State;
 use serde::{Deserialize, Serialize};
@@ -13,6 +14,10 @@ use std::collections::HashMap;
 pub struct FactionState {
     /// Active operations
     operations: HashMap<OperationId, Operation>,
+
+    /// Espionage operations waiting to resolve
+    #[serde(default)]
+    espionage: HashMap<OperationId, EspionageProgress>,
 }
 
 impl State for FactionState {}
@@ -22,6 +27,7 @@ impl FactionState {
     pub fn new() -> Self {
         Self {
             operations: HashMap::new(),
+            espionage: HashMap::new(),
         }
     }
 
@@ -148,6 +154,46 @@ impl FactionState {
     /// Clear all operations
     pub fn clear(&mut self) {
         self.operations.clear();
+        self.espionage.clear();
+    }
+
+    // ========================================
+    // Espionage
+    // ========================================
+
+    /// Track an espionage operation that resolves after `turns` turns
+    pub fn start_espionage(&mut self, id: OperationId, order: EspionageOrder, turns: u32) {
+        self.espionage.insert(
+            id,
+            EspionageProgress {
+                order,
+                turns_remaining: turns,
+            },
+        );
+    }
+
+    /// Espionage operation still waiting to resolve
+    pub fn espionage(&self, id: &OperationId) -> Option<&EspionageProgress> {
+        self.espionage.get(id)
+    }
+
+    /// Count one turn down for every espionage operation and return those
+    /// that are due, sorted by operation id
+    pub fn advance_espionage(&mut self) -> Vec<(OperationId, EspionageOrder)> {
+        let mut due: Vec<OperationId> = Vec::new();
+        for (id, progress) in self.espionage.iter_mut() {
+            progress.turns_remaining = progress.turns_remaining.saturating_sub(1);
+            if progress.turns_remaining == 0 {
+                due.push(id.clone());
+            }
+        }
+        due.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        due.into_iter()
+            .filter_map(|id| {
+                let progress = self.espionage.remove(&id)?;
+                Some((id, progress.order))
+            })
+            .collect()
     }
 }
 
@@ -1,12 +1,17 @@
 //! Faction management system
 
 use crate::context::{ResourceContext, ServiceContext};
+use crate::engine::rng::{derive_seed, GameRng, MasterSeed};
 use crate::event::EventBus;
+use crate::plugin::reputation::{ReputationChangeRequested, SubjectId};
+use crate::plugin::time::DayChanged;
+use crate::scene::ResetRegistry;
 use crate::system::System;
 use async_trait::async_trait;
 use std::any::Any;
 use std::sync::Arc;
 
+use super::espionage::{gather_intel, EspionageConfig, EspionageOrder};
 use super::events::*;
 use super::factions::Factions;
 use super::hook::FactionHook;
@@ -17,9 +22,10 @@ use super::types::*;
 ///
 /// This system:
 /// 1. Processes operation launch requests
-/// 2. Processes operation resolution requests
-/// 3. Calls hooks for custom behavior
-/// 4. Publishes state change events for network replication
+/// 2. Advances and resolves espionage operations once per `DayChanged`
+/// 3. Processes operation resolution requests
+/// 4. Calls hooks for custom behavior
+/// 5. Publishes state change events for network replication
 ///
 /// # Feedback Loop
 ///
@@ -84,6 +90,18 @@ impl FactionSystem {
                 }
             };
 
+            // Espionage needs a known target
+            let espionage = EspionageOrder::from_metadata(&request.metadata);
+            if let Some(order) = &espionage {
+                let target_known = match resources.get::<Factions>().await {
+                    Some(factions) => factions.contains(&order.target),
+                    None => false,
+                };
+                if !target_known || order.target == request.faction_id {
+                    continue;
+                }
+            }
+
             // Generate operation ID
             let operation_id = self.generate_operation_id();
 
@@ -112,12 +130,27 @@ impl FactionSystem {
             // by the hook or a separate system that listens to OperationLaunchedEvent
             let _ = cost; // Suppress unused warning
 
+            let espionage_turns = match &espionage {
+                Some(_) => resources
+                    .get::<EspionageConfig>()
+                    .await
+                    .map(|config| config.duration_turns)
+                    .unwrap_or_else(|| EspionageConfig::default().duration_turns),
+                None => 0,
+            };
+
             // Launch operation (add to state)
             {
                 if let Some(mut state) = resources.get_mut::<FactionState>().await {
                     if state.launch_operation(operation.clone()).is_err() {
                         continue; // Failed to launch
                     }
+                    if let Some(order) = espionage {
+                        // Resolves on its own after `espionage_turns` turns
+                        let _ = state
+                            .update_operation_status(&operation.id, OperationStatus::InProgress);
+                        state.start_espionage(operation.id.clone(), order, espionage_turns);
+                    }
                 } else {
                     continue;
                 }
@@ -161,84 +194,94 @@ impl FactionSystem {
         };
 
         for request in requests {
-            // Get operation
-            let operation = {
-                if let Some(state) = resources.get::<FactionState>().await {
-                    match state.get_operation(&request.operation_id) {
-                        Some(op) => op.clone(),
-                        None => continue, // Operation not found
-                    }
-                } else {
-                    continue;
-                }
-            };
+            self.resolve_operation(request, resources).await;
+        }
+    }
 
-            // Get faction
-            let faction = {
-                if let Some(factions) = resources.get::<Factions>().await {
-                    match factions.get(&operation.faction_id) {
-                        Some(f) => f.clone(),
-                        None => continue, // Faction not found
-                    }
-                } else {
-                    continue;
+    /// Resolve one operation: update its status, call the hook and publish
+    /// `OperationCompletedEvent` or `OperationFailedEvent`
+    async fn resolve_operation(
+        &mut self,
+        request: OperationResolveRequested,
+        resources: &mut ResourceContext,
+    ) {
+        // Get operation
+        let operation = {
+            if let Some(state) = resources.get::<FactionState>().await {
+                match state.get_operation(&request.operation_id) {
+                    Some(op) => op.clone(),
+                    None => return, // Operation not found
                 }
-            };
-
-            // Update operation status based on success
-            let status = if request.outcome.success {
-                OperationStatus::Completed
             } else {
-                OperationStatus::Failed
-            };
+                return;
+            }
+        };
 
-            {
-                if let Some(mut state) = resources.get_mut::<FactionState>().await {
-                    if state
-                        .update_operation_status(&request.operation_id, status)
-                        .is_err()
-                    {
-                        continue; // Failed to update status
-                    }
-                } else {
-                    continue;
+        // Get faction
+        let faction = {
+            if let Some(factions) = resources.get::<Factions>().await {
+                match factions.get(&operation.faction_id) {
+                    Some(f) => f.clone(),
+                    None => return, // Faction not found
                 }
+            } else {
+                return;
             }
+        };
 
-            // **Key feedback loop**: Call hook to interpret outcome and update resources
-            if request.outcome.success {
-                self.hook
-                    .on_operation_completed(&faction, &operation, &request.outcome, resources)
-                    .await;
+        // Update operation status based on success
+        let status = if request.outcome.success {
+            OperationStatus::Completed
+        } else {
+            OperationStatus::Failed
+        };
 
-                // Publish completion event
-                if let Some(mut bus) = resources.get_mut::<EventBus>().await {
-                    bus.publish(OperationCompletedEvent {
-                        operation_id: request.operation_id.clone(),
-                        faction_id: operation.faction_id.clone(),
-                        success: true,
-                        metrics: request.outcome.metrics.clone(),
-                    });
+        {
+            if let Some(mut state) = resources.get_mut::<FactionState>().await {
+                if state
+                    .update_operation_status(&request.operation_id, status)
+                    .is_err()
+                {
+                    return; // Failed to update status
                 }
             } else {
-                self.hook
-                    .on_operation_failed(&faction, &operation, resources)
-                    .await;
+                return;
+            }
+        }
 
-                // Publish failure event
-                if let Some(mut bus) = resources.get_mut::<EventBus>().await {
-                    bus.publish(OperationFailedEvent {
-                        operation_id: request.operation_id.clone(),
-                        faction_id: operation.faction_id.clone(),
-                        reason: request
-                            .outcome
-                            .metadata
-                            .get("reason")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("Unknown failure")
-                            .to_string(),
-                    });
-                }
+        // **Key feedback loop**: Call hook to interpret outcome and update resources
+        if request.outcome.success {
+            self.hook
+                .on_operation_completed(&faction, &operation, &request.outcome, resources)
+                .await;
+
+            // Publish completion event
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.publish(OperationCompletedEvent {
+                    operation_id: request.operation_id.clone(),
+                    faction_id: operation.faction_id.clone(),
+                    success: true,
+                    metrics: request.outcome.metrics.clone(),
+                });
+            }
+        } else {
+            self.hook
+                .on_operation_failed(&faction, &operation, resources)
+                .await;
+
+            // Publish failure event
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.publish(OperationFailedEvent {
+                    operation_id: request.operation_id.clone(),
+                    faction_id: operation.faction_id.clone(),
+                    reason: request
+                        .outcome
+                        .metadata
+                        .get("reason")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("Unknown failure")
+                        .to_string(),
+                });
             }
         }
     }
@@ -250,9 +293,208 @@ impl FactionSystem {
         resources: &mut ResourceContext,
     ) {
         self.process_operation_launches(services, resources).await;
+        self.process_espionage(services, resources).await;
         self.process_operation_resolutions(services, resources)
             .await;
     }
+
+    /// Advance espionage operations once per `DayChanged` event and resolve
+    /// those that are due
+    ///
+    /// Success (`EspionageConfig::success_chance` of the target's security)
+    /// completes the operation and publishes `IntelGatheredEvent`. Failure
+    /// fails it; if the target detects it, `DiplomaticIncidentEvent` and
+    /// `ReputationChangeRequested` penalties are published.
+    pub async fn process_espionage(
+        &mut self,
+        _services: &ServiceContext,
+        resources: &mut ResourceContext,
+    ) {
+        let days: Vec<u32> = {
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.reader::<DayChanged>()
+                    .iter()
+                    .map(|event| event.day)
+                    .collect()
+            } else {
+                Vec::new()
+            }
+        };
+        if days.is_empty() {
+            return;
+        }
+
+        let config = resources
+            .get::<EspionageConfig>()
+            .await
+            .map(|config| config.clone())
+            .unwrap_or_default();
+
+        for day in days {
+            let due = match resources.get_mut::<FactionState>().await {
+                Some(mut state) => state.advance_espionage(),
+                None => return,
+            };
+            for (operation_id, order) in due {
+                self.resolve_espionage(operation_id, order, day, &config, resources)
+                    .await;
+            }
+        }
+    }
+
+    async fn resolve_espionage(
+        &mut self,
+        operation_id: OperationId,
+        order: EspionageOrder,
+        day: u32,
+        config: &EspionageConfig,
+        resources: &mut ResourceContext,
+    ) {
+        let (spy, target, bystanders) = {
+            let Some(state) = resources.get::<FactionState>().await else {
+                return;
+            };
+            let Some(operation) = state.get_operation(&operation_id) else {
+                return;
+            };
+            let Some(factions) = resources.get::<Factions>().await else {
+                return;
+            };
+            let (Some(spy), Some(target)) = (
+                factions.get(&operation.faction_id),
+                factions.get(&order.target),
+            ) else {
+                return;
+            };
+            let mut bystanders: Vec<FactionId> = factions
+                .iter()
+                .map(|faction| faction.id.clone())
+                .filter(|id| id != &spy.id && id != &target.id)
+                .collect();
+            bystanders.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+            (spy.clone(), target.clone(), bystanders)
+        };
+
+        let mut rng = Self::espionage_rng(resources, &operation_id).await;
+
+        if rng.chance(config.success_chance(target.security)) {
+            let accuracy = config.accuracy(target.security);
+            let mut facts = match resources.get::<FactionState>().await {
+                Some(state) => gather_intel(
+                    &target,
+                    &order,
+                    &state,
+                    config.noise_bound(accuracy),
+                    &mut rng,
+                ),
+                None => Vec::new(),
+            };
+            facts.extend(
+                self.hook
+                    .gather_intel(&spy, &target, &order, accuracy, resources)
+                    .await,
+            );
+
+            let outcome = Outcome::new(operation_id.as_str(), true)
+                .with_metric("intel_facts", facts.len() as f32)
+                .with_metric("accuracy", accuracy);
+            self.resolve_operation(
+                OperationResolveRequested {
+                    operation_id: operation_id.clone(),
+                    outcome,
+                },
+                resources,
+            )
+            .await;
+
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.publish(IntelGatheredEvent {
+                    operation_id,
+                    faction_id: spy.id.clone(),
+                    target: target.id.clone(),
+                    territory: order.territory.clone(),
+                    accuracy,
+                    day,
+                    facts,
+                });
+            }
+            return;
+        }
+
+        let detected = rng.chance(config.detection_chance(target.security));
+        let exposed = detected && rng.chance(config.exposure_chance);
+        let reason = if detected {
+            "Detected by target"
+        } else {
+            "No intel gathered"
+        };
+        let outcome = Outcome::new(operation_id.as_str(), false)
+            .with_metadata(serde_json::json!({ "reason": reason }));
+        self.resolve_operation(
+            OperationResolveRequested {
+                operation_id: operation_id.clone(),
+                outcome,
+            },
+            resources,
+        )
+        .await;
+
+        if !detected {
+            return;
+        }
+        let incident = DiplomaticIncidentEvent {
+            operation_id,
+            faction_id: spy.id.clone(),
+            target: target.id.clone(),
+            exposed,
+            relation_penalty: config.relation_penalty,
+            reputation_penalty: if exposed {
+                config.reputation_penalty
+            } else {
+                0.0
+            },
+        };
+        self.hook.on_diplomatic_incident(&incident, resources).await;
+
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.publish(ReputationChangeRequested {
+                subject_id: SubjectId::relation(target.id.as_str(), spy.id.as_str()),
+                delta: -incident.relation_penalty,
+                category: None,
+                reason: Some("Espionage detected".to_string()),
+            });
+            if exposed {
+                for bystander in &bystanders {
+                    bus.publish(ReputationChangeRequested {
+                        subject_id: SubjectId::relation(bystander.as_str(), spy.id.as_str()),
+                        delta: -incident.reputation_penalty,
+                        category: None,
+                        reason: Some("Espionage exposed".to_string()),
+                    });
+                }
+            }
+            bus.publish(incident);
+        }
+    }
+
+    /// RNG for resolving `operation_id`
+    ///
+    /// Seeded from (master seed, "espionage", run counter, operation id);
+    /// falls back to entropy when the game has no `MasterSeed`.
+    async fn espionage_rng(resources: &ResourceContext, operation_id: &OperationId) -> GameRng {
+        let Some(master) = resources.get::<MasterSeed>().await.map(|seed| *seed) else {
+            return GameRng::from_entropy();
+        };
+        let run = resources
+            .get::<ResetRegistry>()
+            .await
+            .map(|registry| registry.restarts())
+            .unwrap_or(0);
+        GameRng::new(derive_seed(
+            master.stream("espionage", run),
+            operation_id.as_str(),
+        ))
+    }
 }
 
 #[async_trait]
@@ -57,6 +57,13 @@ pub struct Faction {
     /// - Relationships: `{ "relationships": { "other_faction": "ally" } }`
     #[serde(default)]
     pub metadata: serde_json::Value,
+
+    /// Counter-intelligence investment (0.0-1.0)
+    ///
+    /// Lowers the success chance and intel accuracy of espionage against
+    /// this faction and raises the chance that failed attempts are detected.
+    #[serde(default)]
+    pub security: f32,
 }
 
 impl Faction {
@@ -81,6 +88,7 @@ impl Faction {
             id: FactionId::new(id),
             name: name.into(),
             metadata: serde_json::Value::Null,
+            security: 0.0,
         }
     }
 
@@ -89,6 +97,12 @@ impl Faction {
         self.metadata = metadata;
         self
     }
+
+    /// Set counter-intelligence investment (clamped to 0.0-1.0)
+    pub fn with_security(mut self, security: f32) -> Self {
+        self.security = security.clamp(0.0, 1.0);
+        self
+    }
 }
 
 /// Unique identifier for an operation
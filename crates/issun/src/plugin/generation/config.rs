@@ -1,6 +1,6 @@
 //! Configuration for GenerationPlugin
 
-use super::types::GenerationType;
+use super::types::{GenerationStatus, GenerationType};
 use crate::resources::Resource;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -19,6 +19,12 @@ pub struct GenerationConfig {
 
     /// Maximum number of generation events to keep in history
     pub max_generation_events: usize,
+
+    /// Resources drawn while growing, per generation type
+    ///
+    /// Only applied by `GenerationSystemECS::update_generation_with_provider`.
+    #[serde(default)]
+    pub consumption: HashMap<GenerationType, ResourceConsumption>,
 }
 
 impl Default for GenerationConfig {
@@ -74,10 +80,96 @@ impl Default for GenerationConfig {
             environment_modifiers,
             auto_remove_on_complete: false,
             max_generation_events: 1000,
+            consumption: HashMap::new(),
+        }
+    }
+}
+
+/// Resources a generation type draws from a `ResourceProvider`
+///
+/// Each tick a growing entity draws its per-tick requirement, plus the
+/// requirement of every stage it is about to enter, in one all-or-nothing
+/// draw. When the draw fails nothing is taken: a per-tick shortage slows
+/// growth by `starved_rate_multiplier`, and a stage whose requirement was
+/// not drawn is not entered.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResourceConsumption {
+    /// Drawn every tick the entity grows (resource key, amount)
+    #[serde(default)]
+    pub per_tick: Vec<(String, u32)>,
+    /// Drawn once when the entity enters a stage
+    #[serde(default)]
+    pub per_stage: HashMap<GenerationStatus, Vec<(String, u32)>>,
+    /// Growth rate multiplier while per-tick supply is short (0.0 stops growth)
+    #[serde(default = "default_starved_rate_multiplier")]
+    pub starved_rate_multiplier: f32,
+}
+
+fn default_starved_rate_multiplier() -> f32 {
+    0.25
+}
+
+impl Default for ResourceConsumption {
+    fn default() -> Self {
+        Self {
+            per_tick: Vec::new(),
+            per_stage: HashMap::new(),
+            starved_rate_multiplier: default_starved_rate_multiplier(),
         }
     }
 }
 
+impl ResourceConsumption {
+    /// Create consumption without requirements
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require `amount` of `resource` every tick
+    pub fn per_tick(mut self, resource: impl Into<String>, amount: u32) -> Self {
+        self.per_tick.push((resource.into(), amount));
+        self
+    }
+
+    /// Require `amount` of `resource` to enter `stage`
+    pub fn on_stage(
+        mut self,
+        stage: GenerationStatus,
+        resource: impl Into<String>,
+        amount: u32,
+    ) -> Self {
+        self.per_stage
+            .entry(stage)
+            .or_default()
+            .push((resource.into(), amount));
+        self
+    }
+
+    /// Set the growth rate multiplier while starved
+    pub fn with_starved_rate(mut self, multiplier: f32) -> Self {
+        self.starved_rate_multiplier = multiplier.max(0.0);
+        self
+    }
+
+    /// Requirement for entering the stages after `from` up to and including `to`
+    pub fn stage_requirements(
+        &self,
+        from: &GenerationStatus,
+        to: &GenerationStatus,
+    ) -> Vec<(String, u32)> {
+        let mut stages: Vec<_> = self
+            .per_stage
+            .iter()
+            .filter(|(stage, _)| *stage > from && *stage <= to)
+            .collect();
+        stages.sort_by(|a, b| a.0.cmp(b.0));
+        stages
+            .into_iter()
+            .flat_map(|(_, requirement)| requirement.iter().cloned())
+            .collect()
+    }
+}
+
 /// Environmental modifiers for generation calculation
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EnvironmentModifiers {
@@ -151,6 +243,31 @@ mod tests {
             .contains_key(&GenerationType::Organic));
     }
 
+    #[test]
+    fn test_stage_requirements_between_stages() {
+        let consumption = ResourceConsumption::new()
+            .per_tick("water", 1)
+            .on_stage(GenerationStatus::Generating, "fertilizer", 1)
+            .on_stage(GenerationStatus::Mature, "stakes", 2);
+
+        assert!(consumption
+            .stage_requirements(&GenerationStatus::Seed, &GenerationStatus::Seed)
+            .is_empty());
+        assert_eq!(
+            consumption.stage_requirements(&GenerationStatus::Seed, &GenerationStatus::Maturing),
+            vec![("fertilizer".to_string(), 1)]
+        );
+        assert_eq!(
+            consumption.stage_requirements(&GenerationStatus::Seed, &GenerationStatus::Completed),
+            vec![("fertilizer".to_string(), 1), ("stakes".to_string(), 2)]
+        );
+        assert_eq!(
+            consumption
+                .stage_requirements(&GenerationStatus::Generating, &GenerationStatus::Mature),
+            vec![("stakes".to_string(), 2)]
+        );
+    }
+
     #[test]
     fn test_environment_modifiers_organic() {
         let modifiers = EnvironmentModifiers {
@@ -1,6 +1,6 @@
 //! Hook trait for game-specific generation behavior (ECS version)
 
-use super::state_ecs::GenerationStateECS;
+use super::state_ecs::{GenerationStateECS, GrowthStarved};
 use async_trait::async_trait;
 
 /// Hook for customizing generation behavior (ECS version)
@@ -71,6 +71,16 @@ pub trait GenerationHookECS: Send + Sync {
         base_rate
     }
 
+    /// Called when an entity could not draw the resources it needs
+    ///
+    /// # Arguments
+    /// * `entity` - Entity that starved
+    /// * `event` - Shortfall and its effect on growth
+    async fn on_growth_starved(&self, entity: hecs::Entity, event: &GrowthStarved) {
+        let _ = (entity, event);
+        // Default: no-op
+    }
+
     /// Called when generation is paused
     ///
     /// # Arguments
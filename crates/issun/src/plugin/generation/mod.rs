@@ -7,17 +7,19 @@
 pub mod config;
 pub mod hook_ecs;
 pub mod plugin_ecs;
+pub mod provider;
 pub mod service;
 pub mod state_ecs;
 pub mod system_ecs;
 pub mod types;
 
 // Re-export commonly used types
-pub use config::{EnvironmentModifiers, GenerationConfig};
+pub use config::{EnvironmentModifiers, GenerationConfig, ResourceConsumption};
 pub use hook_ecs::{DefaultGenerationHookECS, GenerationHookECS};
 pub use plugin_ecs::GenerationPluginECS;
+pub use provider::{InventoryProvider, ResourceProvider, ResourceStock};
 pub use service::GenerationService;
-pub use state_ecs::{GenerationEventECS, GenerationStateECS};
+pub use state_ecs::{GenerationEventECS, GenerationStateECS, GrowthStarved};
 pub use system_ecs::GenerationSystemECS;
 pub use types::{
     EntityTimestamp, Generation, GenerationConditions, GenerationEnvironment, GenerationHistory,
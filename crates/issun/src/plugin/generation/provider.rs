@@ -0,0 +1,204 @@
+//! Resource supply for generation consumption
+
+use crate::plugin::inventory::{EntityId, InventoryState};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Source of the resources growing entities consume
+///
+/// Implement `available` and `take`; `draw` combines them into an
+/// all-or-nothing withdrawal.
+pub trait ResourceProvider: Send {
+    /// Units of `resource` the entity can draw
+    fn available(&self, entity: hecs::Entity, resource: &str) -> u32;
+
+    /// Remove `amount` of `resource`; only called after `available` covered it
+    fn take(&mut self, entity: hecs::Entity, resource: &str, amount: u32);
+
+    /// Take every requested resource, or nothing
+    ///
+    /// Amounts of a resource listed twice are added up. On failure returns
+    /// the shortfall per resource (resource key, missing amount).
+    fn draw(
+        &mut self,
+        entity: hecs::Entity,
+        request: &[(String, u32)],
+    ) -> Result<(), Vec<(String, u32)>> {
+        let totals = merge_request(request);
+        let missing: Vec<(String, u32)> = totals
+            .iter()
+            .filter_map(|(resource, &amount)| {
+                let available = self.available(entity, resource);
+                (available < amount).then(|| (resource.clone(), amount - available))
+            })
+            .collect();
+        if !missing.is_empty() {
+            return Err(missing);
+        }
+
+        for (resource, amount) in totals {
+            if amount > 0 {
+                self.take(entity, &resource, amount);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Total amount per resource, in key order
+pub(crate) fn merge_request(request: &[(String, u32)]) -> BTreeMap<String, u32> {
+    let mut totals = BTreeMap::new();
+    for (resource, amount) in request {
+        let total: &mut u32 = totals.entry(resource.clone()).or_default();
+        *total = total.saturating_add(*amount);
+    }
+    totals
+}
+
+/// In-memory stockpile shared by all entities
+///
+/// # Example
+///
+/// ```ignore
+/// let mut water_tank = ResourceStock::new().with("water", 100);
+/// system
+///     .update_generation_with_provider(&mut state, &config, 1.0, &mut water_tank)
+///     .await;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceStock {
+    stock: HashMap<String, u32>,
+}
+
+impl ResourceStock {
+    /// Create an empty stockpile
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stockpile holding `amount` of `resource` (builder)
+    pub fn with(mut self, resource: impl Into<String>, amount: u32) -> Self {
+        self.add(resource, amount);
+        self
+    }
+
+    /// Add `amount` of `resource`
+    pub fn add(&mut self, resource: impl Into<String>, amount: u32) {
+        let total = self.stock.entry(resource.into()).or_default();
+        *total = total.saturating_add(amount);
+    }
+
+    /// Units of `resource` in stock
+    pub fn get(&self, resource: &str) -> u32 {
+        self.stock.get(resource).copied().unwrap_or(0)
+    }
+}
+
+impl ResourceProvider for ResourceStock {
+    fn available(&self, _entity: hecs::Entity, resource: &str) -> u32 {
+        self.get(resource)
+    }
+
+    fn take(&mut self, _entity: hecs::Entity, resource: &str, amount: u32) {
+        if let Some(total) = self.stock.get_mut(resource) {
+            *total = total.saturating_sub(amount);
+        }
+    }
+}
+
+/// Draws resources from the items of one inventory owner
+///
+/// Resource keys are item ids, e.g. a `"storehouse"` inventory holding
+/// `"water"` and `"fertilizer"`.
+pub struct InventoryProvider<'a> {
+    inventory: &'a mut InventoryState,
+    owner: EntityId,
+}
+
+impl<'a> InventoryProvider<'a> {
+    /// Provider drawing from `owner`'s inventory
+    pub fn new(inventory: &'a mut InventoryState, owner: impl Into<EntityId>) -> Self {
+        Self {
+            inventory,
+            owner: owner.into(),
+        }
+    }
+}
+
+impl ResourceProvider for InventoryProvider<'_> {
+    fn available(&self, _entity: hecs::Entity, resource: &str) -> u32 {
+        self.inventory
+            .get_item_quantity(&self.owner, &resource.to_string())
+    }
+
+    fn take(&mut self, _entity: hecs::Entity, resource: &str, amount: u32) {
+        let _ = self
+            .inventory
+            .remove_item(&self.owner, &resource.to_string(), amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(items: &[(&str, u32)]) -> Vec<(String, u32)> {
+        items
+            .iter()
+            .map(|(resource, amount)| (resource.to_string(), *amount))
+            .collect()
+    }
+
+    #[test]
+    fn test_stock_draw_is_all_or_nothing() {
+        let mut stock = ResourceStock::new().with("water", 5).with("fertilizer", 1);
+        let entity = hecs::Entity::DANGLING;
+
+        let missing = stock
+            .draw(entity, &request(&[("water", 3), ("fertilizer", 2)]))
+            .unwrap_err();
+        assert_eq!(missing, request(&[("fertilizer", 1)]));
+        assert_eq!(stock.get("water"), 5);
+        assert_eq!(stock.get("fertilizer"), 1);
+
+        stock
+            .draw(
+                entity,
+                &request(&[("water", 2), ("fertilizer", 1), ("water", 1)]),
+            )
+            .unwrap();
+        assert_eq!(stock.get("water"), 2);
+        assert_eq!(stock.get("fertilizer"), 0);
+
+        // Listed twice: 2 + 1 exceeds the 2 left
+        assert!(stock
+            .draw(entity, &request(&[("water", 2), ("water", 1)]))
+            .is_err());
+        assert_eq!(stock.get("water"), 2);
+    }
+
+    #[test]
+    fn test_inventory_provider_decrements_items() {
+        let mut inventory = InventoryState::new();
+        let owner = "storehouse".to_string();
+        inventory.add_item(&owner, &"water".to_string(), 4).unwrap();
+        inventory
+            .add_item(&owner, &"fertilizer".to_string(), 1)
+            .unwrap();
+        let entity = hecs::Entity::DANGLING;
+
+        {
+            let mut provider = InventoryProvider::new(&mut inventory, "storehouse");
+            provider
+                .draw(entity, &request(&[("water", 3), ("fertilizer", 1)]))
+                .unwrap();
+            let missing = provider
+                .draw(entity, &request(&[("water", 2)]))
+                .unwrap_err();
+            assert_eq!(missing, request(&[("water", 1)]));
+        }
+
+        assert_eq!(inventory.get_item_quantity(&owner, &"water".to_string()), 1);
+        assert!(!inventory.has_item(&owner, &"fertilizer".to_string(), 1));
+    }
+}
@@ -12,6 +12,8 @@ pub struct GenerationStateECS {
     pub world: hecs::World,
     /// History of generation events
     pub generation_events: Vec<GenerationEventECS>,
+    /// History of failed resource draws
+    pub starvation_events: Vec<GrowthStarved>,
     /// Queue of completed entities to be removed
     pub completed_queue: Vec<hecs::Entity>,
     /// Performance metrics
@@ -36,6 +38,25 @@ pub struct GenerationEventECS {
     pub status_changed: bool,
 }
 
+/// An entity could not draw the resources it needs to grow
+///
+/// Recorded by `GenerationSystemECS::update_generation_with_provider`.
+/// Nothing was drawn for the entity this tick.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GrowthStarved {
+    /// Entity that starved
+    #[serde(skip)] // hecs::Entity is not serializable
+    pub entity: Option<hecs::Entity>,
+    /// Shortfall per resource (resource key, missing amount)
+    pub missing: Vec<(String, u32)>,
+    /// Whether growth was slowed by a per-tick shortage
+    pub slowed: bool,
+    /// Stage the entity could not enter, if any
+    pub blocked_stage: Option<GenerationStatus>,
+    /// Timestamp of event
+    pub timestamp: SystemTime,
+}
+
 impl Clone for GenerationStateECS {
     fn clone(&self) -> Self {
         // Note: hecs::World doesn't implement Clone, so we create a new empty world
@@ -108,6 +129,10 @@ impl GenerationStateECS {
             let remove_count = self.generation_events.len() - max_events;
             self.generation_events.drain(0..remove_count);
         }
+        if self.starvation_events.len() > max_events {
+            let remove_count = self.starvation_events.len() - max_events;
+            self.starvation_events.drain(0..remove_count);
+        }
     }
 
     /// Get metrics
@@ -4,8 +4,9 @@
 
 use super::config::GenerationConfig;
 use super::hook_ecs::GenerationHookECS;
+use super::provider::{merge_request, ResourceProvider};
 use super::service::GenerationService;
-use super::state_ecs::{GenerationEventECS, GenerationStateECS};
+use super::state_ecs::{GenerationEventECS, GenerationStateECS, GrowthStarved};
 use super::types::*;
 use crate::system::System;
 use async_trait::async_trait;
@@ -42,91 +43,197 @@ impl GenerationSystemECS {
     }
 
     /// Update all entities with parallel generation processing
+    ///
+    /// `config.consumption` is ignored; see
+    /// [`update_generation_with_provider`](Self::update_generation_with_provider).
     pub async fn update_generation(
         &mut self,
         state: &mut GenerationStateECS,
         config: &GenerationConfig,
         delta_time: f32,
+    ) {
+        self.update(state, config, delta_time, None).await;
+    }
+
+    /// Update all entities, drawing the resources of `config.consumption`
+    /// from `provider`
+    ///
+    /// Growth is calculated in parallel, then entities draw one by one in
+    /// entity order. Failed draws are recorded as [`GrowthStarved`] events
+    /// and passed to `GenerationHookECS::on_growth_starved`.
+    pub async fn update_generation_with_provider(
+        &mut self,
+        state: &mut GenerationStateECS,
+        config: &GenerationConfig,
+        delta_time: f32,
+        provider: &mut dyn ResourceProvider,
+    ) {
+        self.update(state, config, delta_time, Some(provider)).await;
+    }
+
+    async fn update(
+        &mut self,
+        state: &mut GenerationStateECS,
+        config: &GenerationConfig,
+        delta_time: f32,
+        mut provider: Option<&mut dyn ResourceProvider>,
     ) {
         use rayon::prelude::*;
 
         let start = Instant::now();
         let mut processed = 0;
+        let mut starved = 0;
         let mut total_progress = 0.0;
 
-        // Collect changes in parallel
-        let changes: Vec<_> = state
+        // Calculate progress in parallel
+        let mut planned: Vec<_> = state
             .world
-            .query_mut::<(
-                &mut Generation,
-                &GenerationEnvironment,
-                &GenerationConditions,
-                &mut EntityTimestamp,
-            )>()
+            .query_mut::<(&Generation, &GenerationEnvironment, &GenerationConditions)>()
             .into_iter()
             .par_bridge() // ← Parallel iteration
-            .filter_map(
-                |(entity, (generation, environment, conditions, timestamp))| {
-                    // Level 1: Skip paused entities
-                    if generation.paused {
-                        return None;
-                    }
+            .filter_map(|(entity, (generation, environment, conditions))| {
+                // Level 1: Skip paused entities
+                if generation.paused {
+                    return None;
+                }
 
-                    // Level 2: Check conditions
-                    if !GenerationService::check_conditions(
-                        conditions,
-                        environment.temperature,
-                        &[], // Resource check would be done via hook in real game
-                    ) {
-                        return None;
-                    }
+                // Level 2: Check conditions
+                if !GenerationService::check_conditions(
+                    conditions,
+                    environment.temperature,
+                    &[], // Resource check would be done via hook in real game
+                ) {
+                    return None;
+                }
 
-                    // Get environment modifiers for this type
-                    let modifiers = config
-                        .environment_modifiers
-                        .get(&generation.generation_type)
-                        .cloned()
-                        .unwrap_or(super::config::EnvironmentModifiers {
-                            temperature_factor: 0.0,
-                            fertility_factor: 0.0,
-                            resource_factor: 0.0,
-                            light_factor: 0.0,
-                        });
-
-                    // Calculate generation
-                    let progress_amount = GenerationService::calculate_generation(
-                        generation.generation_rate,
-                        &generation.generation_type,
-                        environment,
-                        &modifiers,
-                        config.global_generation_multiplier,
-                        delta_time,
-                    );
-
-                    // Apply generation
-                    let old_value = generation.current;
-                    let old_status = generation.status.clone();
-
-                    generation.current = (generation.current + progress_amount).min(generation.max);
-                    generation.update_status();
-
-                    // Update timestamp
-                    timestamp.last_updated = SystemTime::now();
-
-                    let status_changed = old_status != generation.status;
-                    let completed = generation.is_completed();
-
-                    Some((
-                        entity,
-                        old_value,
-                        generation.current,
-                        progress_amount,
-                        status_changed,
-                        completed,
-                    ))
-                },
-            )
+                // Get environment modifiers for this type
+                let modifiers = config
+                    .environment_modifiers
+                    .get(&generation.generation_type)
+                    .cloned()
+                    .unwrap_or(super::config::EnvironmentModifiers {
+                        temperature_factor: 0.0,
+                        fertility_factor: 0.0,
+                        resource_factor: 0.0,
+                        light_factor: 0.0,
+                    });
+
+                // Calculate generation
+                let progress_amount = GenerationService::calculate_generation(
+                    generation.generation_rate,
+                    &generation.generation_type,
+                    environment,
+                    &modifiers,
+                    config.global_generation_multiplier,
+                    delta_time,
+                );
+
+                Some((entity, progress_amount))
+            })
             .collect();
+        // Entities draw from the provider in a stable order
+        planned.sort_by_key(|(entity, _)| entity.id());
+
+        // Draw resources and apply progress sequentially
+        let mut changes = Vec::with_capacity(planned.len());
+        for (entity, mut progress_amount) in planned {
+            let (generation_type, old_value, old_status, target_status) = {
+                let Ok(generation) = state.world.get::<&Generation>(entity) else {
+                    continue;
+                };
+                (
+                    generation.generation_type.clone(),
+                    generation.current,
+                    generation.status.clone(),
+                    generation.status_at(generation.current + progress_amount),
+                )
+            };
+
+            let consumption = config.consumption.get(&generation_type);
+            if let (Some(provider), Some(consumption)) = (provider.as_deref_mut(), consumption) {
+                let mut request = consumption.per_tick.clone();
+                request.extend(consumption.stage_requirements(&old_status, &target_status));
+
+                match provider.draw(entity, &request) {
+                    Ok(()) => {
+                        let mut drawn = 0.0;
+                        for (resource, amount) in merge_request(&request) {
+                            *state
+                                .metrics
+                                .resources_consumed
+                                .entry(resource)
+                                .or_default() += u64::from(amount);
+                            drawn += amount as f32;
+                        }
+                        if let Ok(mut history) = state.world.get::<&mut GenerationHistory>(entity) {
+                            history.total_resources_consumed += drawn;
+                        }
+                    }
+                    Err(missing) => {
+                        // A per-tick shortage slows growth
+                        let slowed = merge_request(&consumption.per_tick).iter().any(
+                            |(resource, &amount)| provider.available(entity, resource) < amount,
+                        );
+                        if slowed {
+                            progress_amount *= consumption.starved_rate_multiplier;
+                        }
+
+                        // Stages whose requirement was not drawn are not entered
+                        let reached = state
+                            .world
+                            .get::<&Generation>(entity)
+                            .map(|generation| generation.status_at(old_value + progress_amount))
+                            .unwrap_or(old_status.clone());
+                        let blocked_stage = consumption
+                            .per_stage
+                            .iter()
+                            .filter(|(stage, requirement)| {
+                                **stage > old_status
+                                    && **stage <= reached
+                                    && !requirement.is_empty()
+                            })
+                            .map(|(stage, _)| stage.clone())
+                            .min();
+                        if blocked_stage.is_some() {
+                            progress_amount = 0.0;
+                        }
+
+                        let event = GrowthStarved {
+                            entity: Some(entity),
+                            missing,
+                            slowed,
+                            blocked_stage,
+                            timestamp: SystemTime::now(),
+                        };
+                        self.hook.on_growth_starved(entity, &event).await;
+                        state.starvation_events.push(event);
+                        starved += 1;
+                    }
+                }
+            }
+
+            // Apply generation
+            let Ok((generation, timestamp)) = state
+                .world
+                .query_one_mut::<(&mut Generation, &mut EntityTimestamp)>(entity)
+            else {
+                continue;
+            };
+            generation.current = (generation.current + progress_amount).min(generation.max);
+            generation.update_status();
+
+            // Update timestamp
+            timestamp.last_updated = SystemTime::now();
+
+            changes.push((
+                entity,
+                old_value,
+                generation.current,
+                progress_amount,
+                old_status != generation.status,
+                generation.is_completed(),
+            ));
+        }
 
         // Process results sequentially (event recording, hook calls)
         for (entity, old_value, new_value, progress_amount, status_changed, completed) in changes {
@@ -165,6 +272,7 @@ impl GenerationSystemECS {
 
         // Update metrics
         state.metrics.entities_processed = processed;
+        state.metrics.entities_starved = starved;
         state.metrics.entities_completed += state.completed_queue.len();
         state.metrics.total_progress_applied = total_progress;
         state.metrics.last_update_duration_us = start.elapsed().as_micros() as u64;
@@ -248,8 +356,33 @@ impl GenerationSystemECS {
 
 #[cfg(test)]
 mod tests {
+    use super::super::config::ResourceConsumption;
     use super::super::hook_ecs::DefaultGenerationHookECS;
+    use super::super::provider::ResourceStock;
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Default)]
+    struct StarvationCounter(AtomicUsize);
+
+    #[async_trait]
+    impl GenerationHookECS for StarvationCounter {
+        async fn on_growth_starved(&self, _entity: hecs::Entity, _event: &GrowthStarved) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn watered_config() -> GenerationConfig {
+        let mut config = GenerationConfig::default();
+        config.consumption.insert(
+            GenerationType::Organic,
+            ResourceConsumption::new()
+                .per_tick("water", 2)
+                .on_stage(GenerationStatus::Generating, "fertilizer", 1)
+                .with_starved_rate(0.5),
+        );
+        config
+    }
 
     #[tokio::test]
     async fn test_update_generation_basic() {
@@ -435,4 +568,146 @@ mod tests {
         // Should be fast due to parallel processing
         assert!(elapsed.as_millis() < 1000); // Less than 1 second
     }
+
+    #[tokio::test]
+    async fn test_supplied_entities_draw_per_tick() {
+        let mut system = GenerationSystemECS::new(Arc::new(DefaultGenerationHookECS));
+        let mut state = GenerationStateECS::new();
+        let config = watered_config();
+        let mut stock = ResourceStock::new().with("water", 10);
+
+        let plant = state.spawn_entity(
+            Generation::new(100.0, 1.0, GenerationType::Organic),
+            GenerationEnvironment::default(),
+        );
+        // No consumption rule for this type
+        state.spawn_entity(
+            Generation::new(100.0, 1.0, GenerationType::Production),
+            GenerationEnvironment::default(),
+        );
+
+        system
+            .update_generation_with_provider(&mut state, &config, 1.0, &mut stock)
+            .await;
+
+        let expected = {
+            let mut unsupplied = GenerationStateECS::new();
+            let entity = unsupplied.spawn_entity(
+                Generation::new(100.0, 1.0, GenerationType::Organic),
+                GenerationEnvironment::default(),
+            );
+            system
+                .update_generation(&mut unsupplied, &config, 1.0)
+                .await;
+            let current = unsupplied.world.get::<&Generation>(entity).unwrap().current;
+            current
+        };
+
+        assert_eq!(
+            state.world.get::<&Generation>(plant).unwrap().current,
+            expected
+        );
+        assert_eq!(stock.get("water"), 8);
+        assert_eq!(state.metrics.resources_consumed.get("water"), Some(&2));
+        assert_eq!(state.metrics.entities_starved, 0);
+        assert!(state.starvation_events.is_empty());
+        assert_eq!(
+            state
+                .world
+                .get::<&GenerationHistory>(plant)
+                .unwrap()
+                .total_resources_consumed,
+            2.0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_starved_entities_grow_slower() {
+        let hook = Arc::new(StarvationCounter::default());
+        let mut system = GenerationSystemECS::new(hook.clone());
+        let mut state = GenerationStateECS::new();
+        let config = watered_config();
+        // Enough water for one of the two plants
+        let mut stock = ResourceStock::new().with("water", 3);
+
+        let first = state.spawn_entity(
+            Generation::new(100.0, 1.0, GenerationType::Organic),
+            GenerationEnvironment::default(),
+        );
+        let second = state.spawn_entity(
+            Generation::new(100.0, 1.0, GenerationType::Organic),
+            GenerationEnvironment::default(),
+        );
+
+        system
+            .update_generation_with_provider(&mut state, &config, 1.0, &mut stock)
+            .await;
+
+        let fed = state.world.get::<&Generation>(first).unwrap().current;
+        let starved = state.world.get::<&Generation>(second).unwrap().current;
+        assert!(fed > 0.0);
+        assert!((starved - fed * 0.5).abs() < 1e-6);
+
+        assert_eq!(stock.get("water"), 1);
+        assert_eq!(state.metrics.resources_consumed.get("water"), Some(&2));
+        assert_eq!(state.metrics.entities_starved, 1);
+        assert_eq!(state.metrics.entities_processed, 2);
+        assert_eq!(hook.0.load(Ordering::SeqCst), 1);
+
+        let event = &state.starvation_events[0];
+        assert_eq!(event.entity, Some(second));
+        assert_eq!(event.missing, vec![("water".to_string(), 1)]);
+        assert!(event.slowed);
+        assert_eq!(event.blocked_stage, None);
+    }
+
+    #[tokio::test]
+    async fn test_stage_requirement_blocks_transition() {
+        let hook = Arc::new(StarvationCounter::default());
+        let mut system = GenerationSystemECS::new(hook.clone());
+        let mut state = GenerationStateECS::new();
+        let config = watered_config();
+        let mut stock = ResourceStock::new().with("water", 10);
+
+        // Just below the Generating threshold
+        let plant = state.spawn_entity(
+            Generation::with_current(19.99, 100.0, 1.0, GenerationType::Organic),
+            GenerationEnvironment::default(),
+        );
+
+        system
+            .update_generation_with_provider(&mut state, &config, 1.0, &mut stock)
+            .await;
+
+        {
+            let generation = state.world.get::<&Generation>(plant).unwrap();
+            assert_eq!(generation.current, 19.99);
+            assert_eq!(generation.status, GenerationStatus::Seed);
+        }
+        // All or nothing: the water was not drawn either
+        assert_eq!(stock.get("water"), 10);
+        assert!(state.metrics.resources_consumed.is_empty());
+        assert!(state.generation_events.is_empty());
+        assert_eq!(hook.0.load(Ordering::SeqCst), 1);
+
+        let event = &state.starvation_events[0];
+        assert_eq!(event.missing, vec![("fertilizer".to_string(), 1)]);
+        assert!(!event.slowed);
+        assert_eq!(event.blocked_stage, Some(GenerationStatus::Generating));
+
+        // With fertilizer the stage is entered and both resources are drawn
+        stock.add("fertilizer", 1);
+        system
+            .update_generation_with_provider(&mut state, &config, 1.0, &mut stock)
+            .await;
+
+        assert_eq!(
+            state.world.get::<&Generation>(plant).unwrap().status,
+            GenerationStatus::Generating
+        );
+        assert_eq!(stock.get("water"), 8);
+        assert_eq!(stock.get("fertilizer"), 0);
+        assert_eq!(state.metrics.resources_consumed.get("fertilizer"), Some(&1));
+        assert_eq!(state.metrics.entities_starved, 0);
+    }
 }
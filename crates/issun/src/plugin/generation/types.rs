@@ -3,6 +3,7 @@
 //! Handles growth, construction, production, and recovery systems.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::time::SystemTime;
 
 /// Generation type determines growth behavior and modifiers
@@ -21,7 +22,9 @@ pub enum GenerationType {
 }
 
 /// Generation status based on current progress ratio
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Ordered from `Seed` to `Completed`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum GenerationStatus {
     /// 0-20% - Initial stage (seed, foundation)
     Seed,
@@ -89,8 +92,17 @@ impl Generation {
 
     /// Update status based on current ratio
     pub fn update_status(&mut self) {
-        let ratio = self.progress_ratio();
-        self.status = if ratio >= 1.0 {
+        self.status = self.status_at(self.current);
+    }
+
+    /// Status the entity would have with `current` progress
+    pub fn status_at(&self, current: f32) -> GenerationStatus {
+        let ratio = if self.max <= 0.0 {
+            0.0
+        } else {
+            (current / self.max).clamp(0.0, 1.0)
+        };
+        if ratio >= 1.0 {
             GenerationStatus::Completed
         } else if ratio >= 0.9 {
             GenerationStatus::Mature
@@ -100,7 +112,7 @@ impl Generation {
             GenerationStatus::Generating
         } else {
             GenerationStatus::Seed
-        };
+        }
     }
 
     /// Check if generation is complete
@@ -269,6 +281,12 @@ pub struct GenerationMetrics {
     pub last_update_duration_us: u64,
     /// Total progress applied
     pub total_progress_applied: f32,
+    /// Entities whose resource draw failed in the last update
+    #[serde(default)]
+    pub entities_starved: usize,
+    /// Resources drawn from the `ResourceProvider` since the start (resource -> amount)
+    #[serde(default)]
+    pub resources_consumed: BTreeMap<String, u64>,
 }
 
 #[cfg(test)]
@@ -19,6 +19,14 @@ pub struct InventoryConfig {
 
     /// Maximum stack size for stackable items (0 = unlimited)
     pub max_stack_size: u32,
+
+    /// Maximum number of saved loadouts per entity (0 = unlimited) (MOD-controllable)
+    #[serde(default = "default_max_loadouts")]
+    pub max_loadouts_per_entity: usize,
+}
+
+fn default_max_loadouts() -> usize {
+    10
 }
 
 impl Resource for InventoryConfig {}
@@ -30,6 +38,7 @@ impl Default for InventoryConfig {
             default_capacity: 0, // Unlimited by default
             allow_stacking: true,
             max_stack_size: 99,
+            max_loadouts_per_entity: default_max_loadouts(),
         }
     }
 }
@@ -44,6 +53,7 @@ mod tests {
         assert_eq!(config.default_capacity, 0);
         assert!(config.allow_stacking);
         assert_eq!(config.max_stack_size, 99);
+        assert_eq!(config.max_loadouts_per_entity, 10);
     }
 
     #[test]
@@ -53,6 +63,7 @@ mod tests {
             default_capacity: 20,
             allow_stacking: false,
             max_stack_size: 1,
+            max_loadouts_per_entity: 0,
         };
         assert!(!config.enabled);
         assert_eq!(config.default_capacity, 20);
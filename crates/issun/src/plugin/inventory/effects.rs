@@ -0,0 +1,442 @@
+//! Data-defined item effects
+//!
+//! Items are defined in an [`ItemCatalog`] resource, e.g. loaded from RON:
+//!
+//! ```ron
+//! (items: {
+//!     "potion": (consumable: true, effects: [Heal(amount: 20)]),
+//!     "bomb": (consumable: true, effects: [DamageTarget(amount: 15, type: "fire")]),
+//!     "fireworks": (effects: [PublishEvent(name: "Fireworks", payload: {"color": "red"})]),
+//! })
+//! ```
+//!
+//! `InventorySystem` checks a used item's effects with the
+//! [`ItemEffectExecutor`] and publishes the resulting requests to the plugins
+//! that own the state.
+
+use super::events::{ItemUseRequested, ResourcePoolRestoreRequested};
+use super::hook::InventoryHook;
+use super::types::ItemId;
+use crate::context::ResourceContext;
+use crate::event::{AnyEventPublish, EventBus, EventFactoryRegistry};
+use crate::modding::DynamicEvent;
+use crate::plugin::combat::{CombatDamageRequested, CombatHealRequested, CombatState};
+use crate::plugin::dungeon::{DungeonConfig, DungeonState, RoomId, RoomMoveRequested};
+use crate::plugin::room_buff::{BuffApplyRequested, BuffDuration, BuffId};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// One step of what using an item does
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ItemEffect {
+    /// Heal the request's target, or the user without one
+    Heal { amount: i32 },
+    /// Damage the request's target (required); battle only
+    DamageTarget {
+        amount: i32,
+        /// Game-defined damage type, passed on to `CombatHook::apply_damage`
+        #[serde(rename = "type", default)]
+        damage_type: String,
+    },
+    /// Apply a `RoomBuffDatabase` buff, optionally with another duration
+    ApplyBuff {
+        id: BuffId,
+        #[serde(default)]
+        duration: Option<BuffDuration>,
+    },
+    /// Refill a game-defined pool (mana, stamina, ...) of the target or the user
+    RestoreResourcePool { pool: String, amount: i32 },
+    /// Move to another room of the dungeon
+    TeleportToRoom { selector: RoomSelector },
+    /// Publish an event by name
+    ///
+    /// Names with a factory in the executor's registry become that event;
+    /// any other name is published as a [`DynamicEvent`], so MOD
+    /// subscriptions receive it.
+    PublishEvent {
+        name: String,
+        #[serde(default)]
+        payload: serde_json::Value,
+    },
+}
+
+impl ItemEffect {
+    /// Whether the effect is rejected outside battle
+    pub fn requires_battle(&self) -> bool {
+        matches!(self, ItemEffect::DamageTarget { .. })
+    }
+
+    /// Whether the use request must name a target
+    pub fn requires_target(&self) -> bool {
+        matches!(self, ItemEffect::DamageTarget { .. })
+    }
+}
+
+/// Room picked by [`ItemEffect::TeleportToRoom`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RoomSelector {
+    /// A specific room
+    Room(RoomId),
+    /// First room of the current floor
+    FloorStart,
+    /// Rooms ahead (negative: back) on the current floor
+    Offset(i32),
+}
+
+impl RoomSelector {
+    /// Room to move to from `dungeon`; offsets stay within `rooms_per_floor`
+    pub fn resolve(
+        &self,
+        dungeon: &DungeonState,
+        config: Option<&DungeonConfig>,
+    ) -> Option<RoomId> {
+        match self {
+            RoomSelector::Room(room) => Some(room.clone()),
+            RoomSelector::FloorStart => Some(RoomId::new(dungeon.current_floor, 1)),
+            RoomSelector::Offset(offset) => {
+                let room = dungeon.current_room.checked_add_signed(*offset)?;
+                let last = config.map_or(u32::MAX, |config| config.rooms_per_floor);
+                (1..=last)
+                    .contains(&room)
+                    .then(|| RoomId::new(dungeon.current_floor, room))
+            }
+        }
+    }
+}
+
+/// What an item type does when used
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ItemDefinition {
+    /// Run in order on every use
+    #[serde(default)]
+    pub effects: Vec<ItemEffect>,
+    /// One is removed from the inventory per use
+    #[serde(default)]
+    pub consumable: bool,
+}
+
+impl ItemDefinition {
+    /// Definition of an item used up by its effects
+    pub fn consumable(effects: Vec<ItemEffect>) -> Self {
+        Self {
+            effects,
+            consumable: true,
+        }
+    }
+}
+
+/// Item definitions by item id
+///
+/// Register this as a Resource (or with `InventoryPlugin::with_items`).
+/// Items without a definition have no effects.
+#[derive(crate::Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ItemCatalog {
+    pub items: HashMap<ItemId, ItemDefinition>,
+}
+
+impl ItemCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_item(mut self, id: impl Into<ItemId>, definition: ItemDefinition) -> Self {
+        self.items.insert(id.into(), definition);
+        self
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ItemDefinition> {
+        self.items.get(id)
+    }
+}
+
+/// Interprets [`ItemEffect`]s as requests to the plugins owning the state
+///
+/// Nothing is changed directly: healing and damage go to `CombatSystem`,
+/// buffs to `BuffSystem` and teleports to `DungeonSystem`, so their
+/// validation and hooks still apply. `InventorySystem` runs it for
+/// `ItemUseRequested`; games can run it for other sources (loot pickups,
+/// skills) as well.
+#[derive(Clone, Default)]
+pub struct ItemEffectExecutor {
+    events: Option<Arc<EventFactoryRegistry>>,
+}
+
+impl ItemEffectExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build `PublishEvent` effects with the factories of `registry`
+    pub fn with_event_factories(mut self, registry: impl Into<EventFactoryRegistry>) -> Self {
+        self.events = Some(Arc::new(registry.into()));
+        self
+    }
+
+    /// Check the effects of a use and resolve the requests they publish
+    ///
+    /// Each effect goes through [`InventoryHook::intercept_item_effect`]
+    /// first. Fails with the reason to report in `ItemUseRejectedEvent`.
+    pub async fn plan(
+        &self,
+        hook: &dyn InventoryHook,
+        request: &ItemUseRequested,
+        effects: &[ItemEffect],
+        resources: &ResourceContext,
+    ) -> Result<ItemEffectPlan, String> {
+        let battle_id = resources
+            .get::<CombatState>()
+            .await
+            .and_then(|state| state.current_battle().cloned());
+        let user = &request.entity_id;
+        let target = request.target.as_ref();
+
+        let mut requests = Vec::with_capacity(effects.len());
+        for effect in effects {
+            let Some(effect) = hook
+                .intercept_item_effect(user, &request.item_id, target, effect.clone(), resources)
+                .await?
+            else {
+                continue;
+            };
+
+            if effect.requires_target() && target.is_none() {
+                return Err(format!("{} needs a target", request.item_id));
+            }
+            if effect.requires_battle() && battle_id.is_none() {
+                return Err(format!("{} can only be used in battle", request.item_id));
+            }
+
+            let planned = match effect {
+                ItemEffect::Heal { amount } => PlannedRequest::Heal(CombatHealRequested {
+                    battle_id: battle_id.clone(),
+                    source: user.clone(),
+                    target: target.unwrap_or(user).clone(),
+                    amount,
+                }),
+                ItemEffect::DamageTarget {
+                    amount,
+                    damage_type,
+                } => PlannedRequest::Damage(CombatDamageRequested {
+                    battle_id: battle_id.clone().unwrap_or_default(),
+                    source: user.clone(),
+                    target: target.unwrap_or(user).clone(),
+                    amount,
+                    damage_type,
+                }),
+                ItemEffect::ApplyBuff { id, duration } => {
+                    PlannedRequest::Buff(BuffApplyRequested {
+                        buff_id: id,
+                        duration,
+                    })
+                }
+                ItemEffect::RestoreResourcePool { pool, amount } => {
+                    PlannedRequest::Pool(ResourcePoolRestoreRequested {
+                        entity_id: target.unwrap_or(user).clone(),
+                        pool,
+                        amount,
+                    })
+                }
+                ItemEffect::TeleportToRoom { selector } => {
+                    let room = match resources.get::<DungeonState>().await {
+                        Some(dungeon) => {
+                            let config = resources.get::<DungeonConfig>().await;
+                            selector.resolve(&dungeon, config.as_deref())
+                        }
+                        None => None,
+                    };
+                    let Some(target_room) = room else {
+                        return Err(format!("{} has no room to teleport to", request.item_id));
+                    };
+                    PlannedRequest::Move(RoomMoveRequested { target_room })
+                }
+                ItemEffect::PublishEvent { name, payload } => match self.factory_for(&name) {
+                    // A payload the factory can't read rejects the use
+                    Some(registry) => PlannedRequest::Typed(
+                        registry
+                            .construct(&name, payload)
+                            .map_err(|error| format!("{}: {}", name, error))?,
+                    ),
+                    None => PlannedRequest::Event { name, payload },
+                },
+            };
+            requests.push(planned);
+        }
+
+        Ok(ItemEffectPlan { requests })
+    }
+
+    /// Publish the requests of a checked use, in effect order
+    pub fn publish(&self, plan: ItemEffectPlan, bus: &mut EventBus) {
+        for request in plan.requests {
+            match request {
+                PlannedRequest::Heal(request) => bus.publish(request),
+                PlannedRequest::Damage(request) => bus.publish(request),
+                PlannedRequest::Buff(request) => bus.publish(request),
+                PlannedRequest::Pool(request) => bus.publish(request),
+                PlannedRequest::Move(request) => bus.publish(request),
+                PlannedRequest::Typed(event) => event.publish(bus),
+                PlannedRequest::Event { name, payload } => bus.publish(DynamicEvent {
+                    event_type: name,
+                    data: payload,
+                }),
+            }
+        }
+    }
+
+    fn factory_for(&self, name: &str) -> Option<&EventFactoryRegistry> {
+        self.events
+            .as_deref()
+            .filter(|registry| registry.contains(name))
+    }
+}
+
+/// Requests of a checked item use, from [`ItemEffectExecutor::plan`]
+#[derive(Debug, Clone, Default)]
+pub struct ItemEffectPlan {
+    requests: Vec<PlannedRequest>,
+}
+
+impl ItemEffectPlan {
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum PlannedRequest {
+    Heal(CombatHealRequested),
+    Damage(CombatDamageRequested),
+    Buff(BuffApplyRequested),
+    Pool(ResourcePoolRestoreRequested),
+    Move(RoomMoveRequested),
+    /// Built by an event factory
+    Typed(Box<dyn AnyEventPublish>),
+    /// No factory: a `DynamicEvent`
+    Event {
+        name: String,
+        payload: serde_json::Value,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_loads_from_ron() {
+        let catalog: ItemCatalog = ron::from_str(
+            r#"(items: {
+                "potion": (consumable: true, effects: [Heal(amount: 20)]),
+                "bomb": (consumable: true, effects: [DamageTarget(amount: 15, type: "fire")]),
+                "scroll": (effects: [
+                    ApplyBuff(id: "haste", duration: Some(Turns(2))),
+                    TeleportToRoom(selector: Offset(-1)),
+                ]),
+            })"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            catalog.get("bomb").unwrap().effects,
+            vec![ItemEffect::DamageTarget {
+                amount: 15,
+                damage_type: "fire".to_string(),
+            }]
+        );
+        let scroll = catalog.get("scroll").unwrap();
+        assert!(!scroll.consumable);
+        assert_eq!(scroll.effects.len(), 2);
+    }
+
+    #[test]
+    fn test_room_selector_stays_on_the_floor() {
+        let dungeon = DungeonState {
+            current_floor: 2,
+            current_room: 2,
+            ..Default::default()
+        };
+        let config = DungeonConfig::default(); // 3 rooms per floor
+
+        let resolve = |selector: RoomSelector| selector.resolve(&dungeon, Some(&config));
+        assert_eq!(resolve(RoomSelector::FloorStart), Some(RoomId::new(2, 1)));
+        assert_eq!(resolve(RoomSelector::Offset(-1)), Some(RoomId::new(2, 1)));
+        assert_eq!(resolve(RoomSelector::Offset(1)), Some(RoomId::new(2, 3)));
+        assert_eq!(resolve(RoomSelector::Offset(2)), None);
+        assert_eq!(resolve(RoomSelector::Offset(-2)), None);
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Fireworks {
+        color: String,
+    }
+
+    impl crate::event::Event for Fireworks {}
+
+    #[tokio::test]
+    async fn test_publish_event_builds_registered_events() {
+        let mut registry = EventFactoryRegistry::new();
+        registry
+            .register_event_factory::<Fireworks>("Fireworks")
+            .unwrap();
+        let executor = ItemEffectExecutor::new().with_event_factories(registry);
+        let hook = crate::plugin::inventory::DefaultInventoryHook;
+        let request = ItemUseRequested {
+            entity_id: "hero".to_string(),
+            item_id: "rocket".to_string(),
+            target: None,
+        };
+        let resources = ResourceContext::new();
+
+        let effects = [
+            ItemEffect::PublishEvent {
+                name: "Fireworks".to_string(),
+                payload: serde_json::json!({ "color": "red" }),
+            },
+            ItemEffect::PublishEvent {
+                name: "Confetti".to_string(),
+                payload: serde_json::json!({ "amount": 3 }),
+            },
+        ];
+        let plan = executor
+            .plan(&hook, &request, &effects, &resources)
+            .await
+            .unwrap();
+        let mut bus = EventBus::new();
+        executor.publish(plan, &mut bus);
+        bus.dispatch();
+        assert_eq!(
+            bus.reader::<Fireworks>()
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            [Fireworks {
+                color: "red".to_string()
+            }]
+        );
+        let dynamic: Vec<String> = bus
+            .reader::<DynamicEvent>()
+            .iter()
+            .map(|event| event.event_type.clone())
+            .collect();
+        assert_eq!(dynamic, ["Confetti"]);
+
+        let bad_payload = [ItemEffect::PublishEvent {
+            name: "Fireworks".to_string(),
+            payload: serde_json::json!({ "colour": 1 }),
+        }];
+        let error = executor
+            .plan(&hook, &request, &bad_payload, &resources)
+            .await
+            .unwrap_err();
+        assert!(
+            error.starts_with("Fireworks: Invalid payload for event 'Fireworks'"),
+            "{}",
+            error
+        );
+    }
+}
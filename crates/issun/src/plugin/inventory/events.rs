@@ -3,7 +3,7 @@
 use crate::event::Event;
 use serde::{Deserialize, Serialize};
 
-use super::types::{EntityId, ItemId};
+use super::types::{EntityId, ItemId, SlotId};
 
 // =============================================================================
 // Command Events (Request)
@@ -34,6 +34,9 @@ impl Event for ItemRemoveRequested {}
 pub struct ItemUseRequested {
     pub entity_id: EntityId,
     pub item_id: ItemId,
+    /// Who the item is used on, for effects that need one
+    #[serde(default)]
+    pub target: Option<EntityId>,
 }
 
 impl Event for ItemUseRequested {}
@@ -49,6 +52,65 @@ pub struct ItemTransferRequested {
 
 impl Event for ItemTransferRequested {}
 
+/// Request to equip a held item into a slot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemEquipRequested {
+    pub entity_id: EntityId,
+    pub slot: SlotId,
+    pub item_id: ItemId,
+}
+
+impl Event for ItemEquipRequested {}
+
+/// Request to clear an equipment slot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemUnequipRequested {
+    pub entity_id: EntityId,
+    pub slot: SlotId,
+}
+
+impl Event for ItemUnequipRequested {}
+
+/// Request to save an entity's current equipment as a named loadout
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveLoadoutRequested {
+    pub entity_id: EntityId,
+    pub name: String,
+}
+
+impl Event for SaveLoadoutRequested {}
+
+/// Request to re-equip a saved loadout
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplyLoadoutRequested {
+    pub entity_id: EntityId,
+    pub name: String,
+}
+
+impl Event for ApplyLoadoutRequested {}
+
+/// Request to delete a saved loadout
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteLoadoutRequested {
+    pub entity_id: EntityId,
+    pub name: String,
+}
+
+impl Event for DeleteLoadoutRequested {}
+
+/// Request to refill a game-defined resource pool (mana, stamina, ...)
+///
+/// Published for `ItemEffect::RestoreResourcePool`; no built-in plugin owns
+/// pools, so the game handles it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourcePoolRestoreRequested {
+    pub entity_id: EntityId,
+    pub pool: String,
+    pub amount: i32,
+}
+
+impl Event for ResourcePoolRestoreRequested {}
+
 // =============================================================================
 // State Events (Notification)
 // =============================================================================
@@ -74,14 +136,28 @@ pub struct ItemRemovedEvent {
 impl Event for ItemRemovedEvent {}
 
 /// Published when an item is used
+///
+/// The requests of the item's effects are published right after it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ItemUsedEvent {
     pub entity_id: EntityId,
     pub item_id: ItemId,
+    #[serde(default)]
+    pub target: Option<EntityId>,
 }
 
 impl Event for ItemUsedEvent {}
 
+/// Published when using an item is refused; the item is kept
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemUseRejectedEvent {
+    pub entity_id: EntityId,
+    pub item_id: ItemId,
+    pub reason: String,
+}
+
+impl Event for ItemUseRejectedEvent {}
+
 /// Published when an item is transferred between entities
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ItemTransferredEvent {
@@ -93,6 +169,78 @@ pub struct ItemTransferredEvent {
 
 impl Event for ItemTransferredEvent {}
 
+/// Published when an item is equipped
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemEquippedEvent {
+    pub entity_id: EntityId,
+    pub slot: SlotId,
+    pub item_id: ItemId,
+    /// Item the slot held before
+    pub previous: Option<ItemId>,
+}
+
+impl Event for ItemEquippedEvent {}
+
+/// Published when an equipment slot is cleared
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemUnequippedEvent {
+    pub entity_id: EntityId,
+    pub slot: SlotId,
+    pub item_id: ItemId,
+}
+
+impl Event for ItemUnequippedEvent {}
+
+/// Published when a loadout is saved (created or overwritten)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadoutSavedEvent {
+    pub entity_id: EntityId,
+    pub name: String,
+}
+
+impl Event for LoadoutSavedEvent {}
+
+/// Published when saving a loadout is rejected (e.g., loadout cap reached)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadoutSaveRejectedEvent {
+    pub entity_id: EntityId,
+    pub name: String,
+    pub reason: String,
+}
+
+impl Event for LoadoutSaveRejectedEvent {}
+
+/// Published when every slot of a loadout was equipped
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadoutAppliedEvent {
+    pub entity_id: EntityId,
+    pub name: String,
+}
+
+impl Event for LoadoutAppliedEvent {}
+
+/// Published when some loadout items are no longer in the inventory
+///
+/// Slots listed in `missing` kept their previous item.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadoutPartiallyAppliedEvent {
+    pub entity_id: EntityId,
+    pub name: String,
+    /// (slot, item) pairs that could not be equipped
+    pub missing: Vec<(SlotId, ItemId)>,
+}
+
+impl Event for LoadoutPartiallyAppliedEvent {}
+
+/// Published when a loadout is deleted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadoutDeletedEvent {
+    pub entity_id: EntityId,
+    pub name: String,
+}
+
+impl Event for LoadoutDeletedEvent {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
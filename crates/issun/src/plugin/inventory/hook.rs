@@ -3,6 +3,7 @@
 use crate::context::ResourceContext;
 use async_trait::async_trait;
 
+use super::effects::ItemEffect;
 use super::types::{EntityId, ItemId};
 
 /// Trait for custom inventory behavior
@@ -107,7 +108,9 @@ pub trait InventoryHook: Send + Sync {
     ///
     /// **This is the key feedback loop method for item effects.**
     ///
-    /// Hook interprets the item usage and updates other resources.
+    /// Effects defined in the `ItemCatalog` are checked before and published
+    /// after this call; for anything they can't express, the hook interprets
+    /// the item usage and updates other resources.
     /// For example:
     /// - Consumables: HP/MP recovery, buff application
     /// - Equipment: Stat bonuses, special abilities
@@ -135,6 +138,30 @@ pub trait InventoryHook: Send + Sync {
         Ok(())
     }
 
+    /// Called for each [`ItemEffect`] of a used item before it is checked
+    ///
+    /// Return the effect to run (possibly changed), `Ok(None)` to skip it,
+    /// or `Err(reason)` to reject the whole use.
+    ///
+    /// # Default
+    ///
+    /// Runs every effect unchanged
+    ///
+    /// # Example Use Cases
+    ///
+    /// - Validate targets (only enemies can be bombed)
+    /// - Scale effects (alchemist heals more)
+    async fn intercept_item_effect(
+        &self,
+        _entity_id: &EntityId,
+        _item_id: &ItemId,
+        _target: Option<&EntityId>,
+        effect: ItemEffect,
+        _resources: &ResourceContext,
+    ) -> Result<Option<ItemEffect>, String> {
+        Ok(Some(effect))
+    }
+
     /// Validate whether an item can be transferred
     ///
     /// Return `Ok(())` to allow, `Err(reason)` to prevent.
@@ -3,12 +3,14 @@
 //! Provides reusable inventory system with:
 //! - Item storage per entity
 //! - Add, remove, use, and transfer operations
+//! - Equipment slots and named loadout presets
 //! - Event-driven architecture
-//! - Customizable item effects via hooks
+//! - Data-defined item effects ([`ItemCatalog`]) and customizable ones via hooks
 //! - Generic item support
 
 // Module declarations
 mod config;
+pub mod effects;
 mod events;
 mod hook;
 pub mod plugin;
@@ -19,10 +21,13 @@ pub mod types;
 
 // Re-export main types from modules
 pub use config::InventoryConfig;
+pub use effects::{
+    ItemCatalog, ItemDefinition, ItemEffect, ItemEffectExecutor, ItemEffectPlan, RoomSelector,
+};
 pub use events::*;
 pub use hook::{DefaultInventoryHook, InventoryHook};
 pub use plugin::InventoryPlugin;
 pub use service::InventoryService;
 pub use state::InventoryState;
 pub use system::InventorySystem;
-pub use types::{EntityId, InventoryError, Item, ItemId};
+pub use types::{EntityId, InventoryError, Item, ItemId, Loadout, SlotId};
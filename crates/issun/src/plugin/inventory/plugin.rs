@@ -1,10 +1,12 @@
 //! Inventory plugin implementation
 
 use super::config::InventoryConfig;
+use super::effects::{ItemCatalog, ItemEffectExecutor};
 use super::hook::{DefaultInventoryHook, InventoryHook};
 use super::service::InventoryService;
 use super::state::InventoryState;
 use super::system::InventorySystem;
+use crate::event::EventFactoryRegistry;
 use crate::Plugin;
 use std::sync::Arc;
 
@@ -13,7 +15,7 @@ use std::sync::Arc;
 /// This plugin provides inventory management functionality with:
 /// - Item storage per entity (player, NPC, container, etc.)
 /// - Add, remove, use, and transfer operations
-/// - Customizable item effects via hooks
+/// - Data-defined item effects (`with_items`) and customizable ones via hooks
 /// - Event-driven architecture for loose coupling
 ///
 /// # Hook Customization
@@ -65,9 +67,15 @@ pub struct InventoryPlugin {
     #[plugin(skip)]
     hook: Arc<dyn InventoryHook>,
 
+    #[plugin(skip)]
+    executor: ItemEffectExecutor,
+
     #[resource]
     config: InventoryConfig,
 
+    #[resource]
+    items: ItemCatalog,
+
     #[state]
     state: InventoryState,
 
@@ -87,7 +95,9 @@ impl InventoryPlugin {
         let hook = Arc::new(DefaultInventoryHook);
         Self {
             hook: hook.clone(),
+            executor: ItemEffectExecutor::new(),
             config: InventoryConfig::default(),
+            items: ItemCatalog::new(),
             state: InventoryState::new(),
             service: InventoryService::new(),
             system: InventorySystem::new(hook),
@@ -133,7 +143,31 @@ impl InventoryPlugin {
     pub fn with_hook<H: InventoryHook + 'static>(mut self, hook: H) -> Self {
         let hook = Arc::new(hook);
         self.hook = hook.clone();
-        self.system = InventorySystem::new(hook);
+        self.system = InventorySystem::new(hook).with_executor(self.executor.clone());
+        self
+    }
+
+    /// Set the item definitions whose effects run when items are used
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use issun::plugin::inventory::{InventoryPlugin, ItemCatalog};
+    ///
+    /// let items: ItemCatalog = ron::from_str(&std::fs::read_to_string("assets/items.ron")?)?;
+    /// let plugin = InventoryPlugin::new().with_items(items);
+    /// ```
+    pub fn with_items(mut self, items: ItemCatalog) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Build `PublishEvent` effects with the event factories of `registry`
+    ///
+    /// Names without a factory are published as `DynamicEvent`s for MODs.
+    pub fn with_event_factories(mut self, registry: impl Into<EventFactoryRegistry>) -> Self {
+        self.executor = ItemEffectExecutor::new().with_event_factories(registry);
+        self.system = InventorySystem::new(self.hook.clone()).with_executor(self.executor.clone());
         self
     }
 
@@ -197,6 +231,7 @@ mod tests {
             default_capacity: 20,
             allow_stacking: false,
             max_stack_size: 1,
+            max_loadouts_per_entity: 3,
         };
 
         let plugin = InventoryPlugin::new().with_config(config);
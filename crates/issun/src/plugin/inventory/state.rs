@@ -1,6 +1,6 @@
 //! Inventory runtime state (Mutable)
 
-use super::types::{EntityId, InventoryError, ItemId};
+use super::types::{EntityId, InventoryError, ItemId, Loadout, SlotId};
 use crate::state::State;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -14,6 +14,15 @@ pub struct InventoryState {
     /// Inventories mapped by entity ID
     /// Inner HashMap maps ItemId -> quantity
     inventories: HashMap<EntityId, HashMap<ItemId, u32>>,
+
+    /// Equipped items mapped by entity ID
+    /// Inner HashMap maps SlotId -> ItemId (item must be held in the inventory)
+    #[serde(default)]
+    equipment: HashMap<EntityId, HashMap<SlotId, ItemId>>,
+
+    /// Saved loadouts mapped by entity ID, then by loadout name
+    #[serde(default)]
+    loadouts: HashMap<EntityId, HashMap<String, Loadout>>,
 }
 
 impl State for InventoryState {}
@@ -23,6 +32,8 @@ impl InventoryState {
     pub fn new() -> Self {
         Self {
             inventories: HashMap::new(),
+            equipment: HashMap::new(),
+            loadouts: HashMap::new(),
         }
     }
 
@@ -101,12 +112,15 @@ impl InventoryState {
             return Err(InventoryError::ItemNotFound);
         }
 
-        if *current == quantity {
+        let remaining = *current - quantity;
+        if remaining == 0 {
             inventory.remove(item_id);
         } else {
-            *inventory.get_mut(item_id).unwrap() -= quantity;
+            *inventory.get_mut(item_id).unwrap() = remaining;
         }
 
+        self.release_equipped(entity_id, item_id, remaining);
+
         Ok(())
     }
 
@@ -148,11 +162,232 @@ impl InventoryState {
     /// Clear an entity's inventory
     pub fn clear_inventory(&mut self, entity_id: &EntityId) {
         self.inventories.remove(entity_id);
+        self.equipment.remove(entity_id);
     }
 
     /// Clear all inventories
     pub fn clear_all(&mut self) {
         self.inventories.clear();
+        self.equipment.clear();
+    }
+
+    // ========================================
+    // Equipment
+    // ========================================
+
+    /// Equip an item held in the entity's inventory into a slot
+    ///
+    /// Returns the previously equipped item of that slot, if any.
+    pub fn equip_item(
+        &mut self,
+        entity_id: &EntityId,
+        slot: &SlotId,
+        item_id: &ItemId,
+    ) -> Result<Option<ItemId>, InventoryError> {
+        if !self.has_inventory(entity_id) {
+            return Err(InventoryError::EntityNotFound);
+        }
+
+        // Every equipped copy must be backed by one held item
+        let in_use = self
+            .equipment
+            .get(entity_id)
+            .map(|slots| {
+                slots
+                    .iter()
+                    .filter(|(s, i)| *s != slot && *i == item_id)
+                    .count() as u32
+            })
+            .unwrap_or(0);
+        if self.get_item_quantity(entity_id, item_id) <= in_use {
+            return Err(InventoryError::ItemNotFound);
+        }
+
+        Ok(self
+            .equipment
+            .entry(entity_id.clone())
+            .or_default()
+            .insert(slot.clone(), item_id.clone()))
+    }
+
+    /// Clear an equipment slot, returning the item that was in it
+    pub fn unequip_item(&mut self, entity_id: &EntityId, slot: &SlotId) -> Option<ItemId> {
+        self.equipment
+            .get_mut(entity_id)
+            .and_then(|slots| slots.remove(slot))
+    }
+
+    /// Get the item equipped in a slot
+    pub fn get_equipped(&self, entity_id: &EntityId, slot: &SlotId) -> Option<&ItemId> {
+        self.equipment
+            .get(entity_id)
+            .and_then(|slots| slots.get(slot))
+    }
+
+    /// Get all equipped items of an entity (read-only)
+    pub fn get_equipment(&self, entity_id: &EntityId) -> Option<&HashMap<SlotId, ItemId>> {
+        self.equipment.get(entity_id)
+    }
+
+    /// Unequip copies of an item that are no longer backed by the inventory
+    fn release_equipped(&mut self, entity_id: &EntityId, item_id: &ItemId, remaining: u32) {
+        let Some(slots) = self.equipment.get_mut(entity_id) else {
+            return;
+        };
+
+        let mut equipped: Vec<SlotId> = slots
+            .iter()
+            .filter(|(_, i)| *i == item_id)
+            .map(|(s, _)| s.clone())
+            .collect();
+        equipped.sort();
+
+        for slot in equipped.into_iter().skip(remaining as usize) {
+            slots.remove(&slot);
+        }
+    }
+
+    // ========================================
+    // Loadouts
+    // ========================================
+
+    /// Save the entity's current equipment as a named loadout
+    ///
+    /// Overwriting an existing loadout does not count against `max_loadouts`
+    /// (0 = unlimited).
+    pub fn save_loadout(
+        &mut self,
+        entity_id: &EntityId,
+        name: &str,
+        max_loadouts: usize,
+    ) -> Result<&Loadout, InventoryError> {
+        let slots = self
+            .equipment
+            .get(entity_id)
+            .map(|slots| slots.iter().map(|(s, i)| (s.clone(), i.clone())).collect())
+            .unwrap_or_default();
+
+        let loadouts = self.loadouts.entry(entity_id.clone()).or_default();
+        if max_loadouts > 0 && !loadouts.contains_key(name) && loadouts.len() >= max_loadouts {
+            return Err(InventoryError::LoadoutLimitReached(max_loadouts));
+        }
+
+        let loadout = Loadout {
+            name: name.to_string(),
+            slots,
+        };
+        loadouts.insert(name.to_string(), loadout);
+        Ok(&loadouts[name])
+    }
+
+    /// Apply a saved loadout to the entity's equipment
+    ///
+    /// Each slot either receives its target item or keeps what it had.
+    /// Slots that are empty in the loadout are cleared. Returns the
+    /// `(slot, item)` pairs that could not be equipped because the item is
+    /// no longer held (an empty list means the loadout was fully applied).
+    pub fn apply_loadout(
+        &mut self,
+        entity_id: &EntityId,
+        name: &str,
+    ) -> Result<Vec<(SlotId, ItemId)>, InventoryError> {
+        let loadout = self
+            .get_loadout(entity_id, name)
+            .cloned()
+            .ok_or(InventoryError::LoadoutNotFound)?;
+        let current = self.equipment.get(entity_id).cloned().unwrap_or_default();
+
+        // Remaining copies of each item that can still be assigned to a slot
+        let mut available: HashMap<ItemId, u32> =
+            self.get_inventory(entity_id).cloned().unwrap_or_default();
+        let mut next: HashMap<SlotId, ItemId> = HashMap::new();
+        let mut missing = Vec::new();
+
+        // Slots whose target is not held at all keep their current item,
+        // so reserve those first
+        let (satisfiable, unsatisfiable): (Vec<_>, Vec<_>) = loadout
+            .slots
+            .iter()
+            .partition(|(_, item)| available.get(*item).copied().unwrap_or(0) > 0);
+
+        for (slot, item) in unsatisfiable {
+            missing.push((slot.clone(), item.clone()));
+            if let Some(kept) = current.get(slot) {
+                if take_one(&mut available, kept) {
+                    next.insert(slot.clone(), kept.clone());
+                }
+            }
+        }
+
+        for (slot, item) in satisfiable {
+            if take_one(&mut available, item) {
+                next.insert(slot.clone(), item.clone());
+                continue;
+            }
+
+            // All copies are already assigned to other slots
+            missing.push((slot.clone(), item.clone()));
+            if let Some(kept) = current.get(slot) {
+                if take_one(&mut available, kept) {
+                    next.insert(slot.clone(), kept.clone());
+                }
+            }
+        }
+
+        if next.is_empty() {
+            self.equipment.remove(entity_id);
+        } else {
+            self.equipment.insert(entity_id.clone(), next);
+        }
+
+        missing.sort();
+        Ok(missing)
+    }
+
+    /// Delete a saved loadout, returning it
+    pub fn delete_loadout(
+        &mut self,
+        entity_id: &EntityId,
+        name: &str,
+    ) -> Result<Loadout, InventoryError> {
+        self.loadouts
+            .get_mut(entity_id)
+            .and_then(|loadouts| loadouts.remove(name))
+            .ok_or(InventoryError::LoadoutNotFound)
+    }
+
+    /// Get a saved loadout by name
+    pub fn get_loadout(&self, entity_id: &EntityId, name: &str) -> Option<&Loadout> {
+        self.loadouts
+            .get(entity_id)
+            .and_then(|loadouts| loadouts.get(name))
+    }
+
+    /// Get the names of an entity's saved loadouts (sorted)
+    pub fn loadout_names(&self, entity_id: &EntityId) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .loadouts
+            .get(entity_id)
+            .map(|loadouts| loadouts.keys().cloned().collect())
+            .unwrap_or_default();
+        names.sort();
+        names
+    }
+
+    /// Get the number of saved loadouts for an entity
+    pub fn loadout_count(&self, entity_id: &EntityId) -> usize {
+        self.loadouts.get(entity_id).map(|l| l.len()).unwrap_or(0)
+    }
+}
+
+/// Take one copy of an item from the availability pool
+fn take_one(available: &mut HashMap<ItemId, u32>, item_id: &ItemId) -> bool {
+    match available.get_mut(item_id) {
+        Some(count) if *count > 0 => {
+            *count -= 1;
+            true
+        }
+        _ => false,
     }
 }
 
@@ -319,4 +554,173 @@ mod tests {
         assert_eq!(state.get_total_items(&entity_id), 0);
         assert_eq!(state.get_slot_count(&entity_id), 0);
     }
+
+    fn arena_player() -> (InventoryState, EntityId) {
+        let mut state = InventoryState::new();
+        let player = "player_1".to_string();
+        for item in ["shield", "mace", "dagger", "potion"] {
+            state.add_item(&player, &item.to_string(), 1).unwrap();
+        }
+        (state, player)
+    }
+
+    #[test]
+    fn test_equip_requires_held_item() {
+        let (mut state, player) = arena_player();
+        let weapon = "weapon".to_string();
+
+        assert!(matches!(
+            state.equip_item(&player, &weapon, &"bow".to_string()),
+            Err(InventoryError::ItemNotFound)
+        ));
+
+        state
+            .equip_item(&player, &weapon, &"mace".to_string())
+            .unwrap();
+        // Only one mace is held, so it cannot fill a second slot
+        assert!(state
+            .equip_item(&player, &"offhand".to_string(), &"mace".to_string())
+            .is_err());
+        assert_eq!(
+            state.get_equipped(&player, &weapon),
+            Some(&"mace".to_string())
+        );
+    }
+
+    #[test]
+    fn test_loadout_save_apply_round_trip() {
+        let (mut state, player) = arena_player();
+        let weapon = "weapon".to_string();
+        let offhand = "offhand".to_string();
+        let quick = "quick_1".to_string();
+
+        state
+            .equip_item(&player, &weapon, &"mace".to_string())
+            .unwrap();
+        state
+            .equip_item(&player, &offhand, &"shield".to_string())
+            .unwrap();
+        state
+            .equip_item(&player, &quick, &"potion".to_string())
+            .unwrap();
+        state.save_loadout(&player, "tank", 0).unwrap();
+
+        state.unequip_item(&player, &offhand);
+        state
+            .equip_item(&player, &weapon, &"dagger".to_string())
+            .unwrap();
+        state.save_loadout(&player, "dps", 0).unwrap();
+
+        let missing = state.apply_loadout(&player, "tank").unwrap();
+        assert!(missing.is_empty());
+        assert_eq!(
+            state.get_equipped(&player, &weapon),
+            Some(&"mace".to_string())
+        );
+        assert_eq!(
+            state.get_equipped(&player, &offhand),
+            Some(&"shield".to_string())
+        );
+        assert_eq!(
+            state.get_equipped(&player, &quick),
+            Some(&"potion".to_string())
+        );
+
+        // Slots that were empty when "dps" was saved are cleared
+        assert!(state.apply_loadout(&player, "dps").unwrap().is_empty());
+        assert_eq!(
+            state.get_equipped(&player, &weapon),
+            Some(&"dagger".to_string())
+        );
+        assert_eq!(state.get_equipped(&player, &offhand), None);
+
+        // Survives save/load
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: InventoryState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.loadout_names(&player), vec!["dps", "tank"]);
+        assert_eq!(
+            restored.get_loadout(&player, "tank"),
+            state.get_loadout(&player, "tank")
+        );
+        assert_eq!(
+            restored.get_equipped(&player, &weapon),
+            Some(&"dagger".to_string())
+        );
+    }
+
+    #[test]
+    fn test_loadout_partial_apply_after_item_consumed() {
+        let (mut state, player) = arena_player();
+        let weapon = "weapon".to_string();
+        let quick = "quick_1".to_string();
+
+        state
+            .equip_item(&player, &weapon, &"mace".to_string())
+            .unwrap();
+        state
+            .equip_item(&player, &quick, &"potion".to_string())
+            .unwrap();
+        state.save_loadout(&player, "tank", 0).unwrap();
+
+        state
+            .equip_item(&player, &weapon, &"dagger".to_string())
+            .unwrap();
+        state.add_item(&player, &"elixir".to_string(), 1).unwrap();
+        state
+            .equip_item(&player, &quick, &"elixir".to_string())
+            .unwrap();
+
+        // Potion is drunk and the mace is still held
+        state
+            .remove_item(&player, &"potion".to_string(), 1)
+            .unwrap();
+
+        let missing = state.apply_loadout(&player, "tank").unwrap();
+        assert_eq!(missing, vec![(quick.clone(), "potion".to_string())]);
+        assert_eq!(
+            state.get_equipped(&player, &weapon),
+            Some(&"mace".to_string())
+        );
+        // Quick slot keeps what it had instead of being emptied
+        assert_eq!(
+            state.get_equipped(&player, &quick),
+            Some(&"elixir".to_string())
+        );
+    }
+
+    #[test]
+    fn test_removing_item_unequips_it() {
+        let (mut state, player) = arena_player();
+        let weapon = "weapon".to_string();
+
+        state
+            .equip_item(&player, &weapon, &"mace".to_string())
+            .unwrap();
+        state.remove_item(&player, &"mace".to_string(), 1).unwrap();
+
+        assert_eq!(state.get_equipped(&player, &weapon), None);
+    }
+
+    #[test]
+    fn test_loadout_cap_rejection() {
+        let (mut state, player) = arena_player();
+
+        state.save_loadout(&player, "tank", 2).unwrap();
+        state.save_loadout(&player, "dps", 2).unwrap();
+        assert_eq!(
+            state.save_loadout(&player, "healer", 2).unwrap_err(),
+            InventoryError::LoadoutLimitReached(2)
+        );
+
+        // Overwriting an existing loadout is still allowed
+        assert!(state.save_loadout(&player, "tank", 2).is_ok());
+        assert_eq!(state.loadout_count(&player), 2);
+
+        state.delete_loadout(&player, "dps").unwrap();
+        assert!(state.save_loadout(&player, "healer", 2).is_ok());
+        assert!(matches!(
+            state.delete_loadout(&player, "dps"),
+            Err(InventoryError::LoadoutNotFound)
+        ));
+    }
 }
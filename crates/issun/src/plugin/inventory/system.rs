@@ -7,9 +7,12 @@ use async_trait::async_trait;
 use std::any::Any;
 use std::sync::Arc;
 
+use super::config::InventoryConfig;
+use super::effects::{ItemCatalog, ItemEffectExecutor};
 use super::events::*;
 use super::hook::InventoryHook;
 use super::state::InventoryState;
+use super::types::InventoryError;
 
 /// System that processes inventory events with hooks
 ///
@@ -18,8 +21,14 @@ use super::state::InventoryState;
 /// 2. Processes item remove requests
 /// 3. Processes item use requests
 /// 4. Processes item transfer requests
-/// 5. Calls hooks for custom behavior
-/// 6. Publishes state change events for network replication
+/// 5. Processes equip/unequip requests
+/// 6. Processes loadout save/apply/delete requests
+/// 7. Calls hooks for custom behavior
+/// 8. Publishes state change events for network replication
+///
+/// Used items run the effects of their `ItemCatalog` definition through an
+/// [`ItemEffectExecutor`]; a use whose effects can't run is rejected with
+/// `ItemUseRejectedEvent` and the item is kept.
 ///
 /// # Feedback Loop
 ///
@@ -29,12 +38,22 @@ use super::state::InventoryState;
 #[derive(Clone)]
 pub struct InventorySystem {
     hook: Arc<dyn InventoryHook>,
+    executor: ItemEffectExecutor,
 }
 
 impl InventorySystem {
     /// Create a new InventorySystem with a custom hook
     pub fn new(hook: Arc<dyn InventoryHook>) -> Self {
-        Self { hook }
+        Self {
+            hook,
+            executor: ItemEffectExecutor::new(),
+        }
+    }
+
+    /// Run item effects with `executor` (e.g. one with event factories)
+    pub fn with_executor(mut self, executor: ItemEffectExecutor) -> Self {
+        self.executor = executor;
+        self
     }
 
     /// Process all inventory events
@@ -47,6 +66,8 @@ impl InventorySystem {
         self.process_remove_requests(resources).await;
         self.process_use_requests(resources).await;
         self.process_transfer_requests(resources).await;
+        self.process_equip_requests(resources).await;
+        self.process_loadout_requests(resources).await;
     }
 
     /// Process item add requests
@@ -186,25 +207,73 @@ impl InventorySystem {
             };
 
             if !has_item {
+                reject_use(
+                    resources,
+                    &request,
+                    InventoryError::ItemNotFound.to_string(),
+                )
+                .await;
                 continue;
             }
 
+            // Check the item's effects
+            let definition = resources
+                .get::<ItemCatalog>()
+                .await
+                .and_then(|catalog| catalog.get(&request.item_id).cloned())
+                .unwrap_or_default();
+            let plan = match self
+                .executor
+                .plan(&*self.hook, &request, &definition.effects, resources)
+                .await
+            {
+                Ok(plan) => plan,
+                Err(reason) => {
+                    reject_use(resources, &request, reason).await;
+                    continue;
+                }
+            };
+
             // Call hook (item effect)
-            if self
+            if let Err(reason) = self
                 .hook
                 .on_item_used(&request.entity_id, &request.item_id, resources)
                 .await
-                .is_err()
             {
+                reject_use(resources, &request, reason).await;
                 continue;
             }
 
-            // Publish event
+            // Use up consumables
+            let consumed = definition.consumable && {
+                match resources.get_mut::<InventoryState>().await {
+                    Some(mut state) => state
+                        .remove_item(&request.entity_id, &request.item_id, 1)
+                        .is_ok(),
+                    None => false,
+                }
+            };
+            if consumed {
+                self.hook
+                    .on_item_removed(&request.entity_id, &request.item_id, 1, resources)
+                    .await;
+            }
+
+            // Publish events, then the effects' requests
             if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                if consumed {
+                    bus.publish(ItemRemovedEvent {
+                        entity_id: request.entity_id.clone(),
+                        item_id: request.item_id.clone(),
+                        quantity: 1,
+                    });
+                }
                 bus.publish(ItemUsedEvent {
                     entity_id: request.entity_id.clone(),
                     item_id: request.item_id.clone(),
+                    target: request.target.clone(),
                 });
+                self.executor.publish(plan, &mut bus);
             }
         }
     }
@@ -284,6 +353,197 @@ impl InventorySystem {
     }
 }
 
+impl InventorySystem {
+    /// Process equip and unequip requests
+    ///
+    /// Requests for items the entity does not hold are ignored.
+    async fn process_equip_requests(&mut self, resources: &mut ResourceContext) {
+        // Collect equip requests
+        let (equip_requests, unequip_requests) = {
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                let equip = bus
+                    .reader::<ItemEquipRequested>()
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let unequip = bus
+                    .reader::<ItemUnequipRequested>()
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (equip, unequip)
+            } else {
+                return;
+            }
+        };
+
+        if equip_requests.is_empty() && unequip_requests.is_empty() {
+            return;
+        }
+
+        // Update state
+        let mut equipped = Vec::new();
+        let mut unequipped = Vec::new();
+        {
+            let Some(mut state) = resources.get_mut::<InventoryState>().await else {
+                return;
+            };
+
+            for request in equip_requests {
+                if let Ok(previous) =
+                    state.equip_item(&request.entity_id, &request.slot, &request.item_id)
+                {
+                    equipped.push((request, previous));
+                }
+            }
+
+            for request in unequip_requests {
+                if let Some(item_id) = state.unequip_item(&request.entity_id, &request.slot) {
+                    unequipped.push((request, item_id));
+                }
+            }
+        }
+
+        // Publish events
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            for (request, previous) in equipped {
+                bus.publish(ItemEquippedEvent {
+                    entity_id: request.entity_id,
+                    slot: request.slot,
+                    item_id: request.item_id,
+                    previous,
+                });
+            }
+            for (request, item_id) in unequipped {
+                bus.publish(ItemUnequippedEvent {
+                    entity_id: request.entity_id,
+                    slot: request.slot,
+                    item_id,
+                });
+            }
+        }
+    }
+
+    /// Process loadout save, apply, and delete requests
+    async fn process_loadout_requests(&mut self, resources: &mut ResourceContext) {
+        // Collect loadout requests
+        let (save_requests, apply_requests, delete_requests) = {
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                let save = bus
+                    .reader::<SaveLoadoutRequested>()
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let apply = bus
+                    .reader::<ApplyLoadoutRequested>()
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let delete = bus
+                    .reader::<DeleteLoadoutRequested>()
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (save, apply, delete)
+            } else {
+                return;
+            }
+        };
+
+        if save_requests.is_empty() && apply_requests.is_empty() && delete_requests.is_empty() {
+            return;
+        }
+
+        let max_loadouts = resources
+            .get::<InventoryConfig>()
+            .await
+            .map(|config| config.max_loadouts_per_entity)
+            .unwrap_or_else(|| InventoryConfig::default().max_loadouts_per_entity);
+
+        // Update state
+        let mut saved = Vec::new();
+        let mut rejected = Vec::new();
+        let mut applied = Vec::new();
+        let mut partial = Vec::new();
+        let mut deleted = Vec::new();
+        {
+            let Some(mut state) = resources.get_mut::<InventoryState>().await else {
+                return;
+            };
+
+            for request in save_requests {
+                match state.save_loadout(&request.entity_id, &request.name, max_loadouts) {
+                    Ok(_) => saved.push(request),
+                    Err(e) => rejected.push((request, e.to_string())),
+                }
+            }
+
+            for request in apply_requests {
+                match state.apply_loadout(&request.entity_id, &request.name) {
+                    Ok(missing) if missing.is_empty() => applied.push(request),
+                    Ok(missing) => partial.push((request, missing)),
+                    Err(_) => continue,
+                }
+            }
+
+            for request in delete_requests {
+                if state
+                    .delete_loadout(&request.entity_id, &request.name)
+                    .is_ok()
+                {
+                    deleted.push(request);
+                }
+            }
+        }
+
+        // Publish events
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            for request in saved {
+                bus.publish(LoadoutSavedEvent {
+                    entity_id: request.entity_id,
+                    name: request.name,
+                });
+            }
+            for (request, reason) in rejected {
+                bus.publish(LoadoutSaveRejectedEvent {
+                    entity_id: request.entity_id,
+                    name: request.name,
+                    reason,
+                });
+            }
+            for request in applied {
+                bus.publish(LoadoutAppliedEvent {
+                    entity_id: request.entity_id,
+                    name: request.name,
+                });
+            }
+            for (request, missing) in partial {
+                bus.publish(LoadoutPartiallyAppliedEvent {
+                    entity_id: request.entity_id,
+                    name: request.name,
+                    missing,
+                });
+            }
+            for request in deleted {
+                bus.publish(LoadoutDeletedEvent {
+                    entity_id: request.entity_id,
+                    name: request.name,
+                });
+            }
+        }
+    }
+}
+
+async fn reject_use(resources: &ResourceContext, request: &ItemUseRequested, reason: String) {
+    if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+        bus.publish(ItemUseRejectedEvent {
+            entity_id: request.entity_id.clone(),
+            item_id: request.item_id.clone(),
+            reason,
+        });
+    }
+}
+
 #[async_trait]
 impl System for InventorySystem {
     fn name(&self) -> &'static str {
@@ -298,3 +558,87 @@ impl System for InventorySystem {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Event;
+    use crate::plugin::inventory::DefaultInventoryHook;
+    use serde::Serialize;
+
+    async fn run<E: Event + Serialize>(resources: &mut ResourceContext, event: E) {
+        {
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+            bus.publish(event);
+            bus.dispatch();
+        }
+        InventorySystem::new(Arc::new(DefaultInventoryHook))
+            .process_events(&ServiceContext::new(), resources)
+            .await;
+        resources.get_mut::<EventBus>().await.unwrap().dispatch();
+    }
+
+    fn equip(item: &str) -> ItemEquipRequested {
+        ItemEquipRequested {
+            entity_id: "player".to_string(),
+            slot: "weapon".to_string(),
+            item_id: item.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_equip_and_unequip_requests() {
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        let mut state = InventoryState::new();
+        for item in ["dagger", "axe"] {
+            state
+                .add_item(&"player".to_string(), &item.to_string(), 1)
+                .unwrap();
+        }
+        resources.insert(state);
+
+        run(&mut resources, equip("dagger")).await;
+        run(&mut resources, equip("axe")).await;
+        {
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+            let equipped: Vec<_> = bus.reader::<ItemEquippedEvent>().iter().cloned().collect();
+            assert_eq!(equipped.len(), 1);
+            assert_eq!(equipped[0].item_id, "axe");
+            assert_eq!(equipped[0].previous.as_deref(), Some("dagger"));
+        }
+
+        // Items that are not held are not equipped
+        run(&mut resources, equip("sword")).await;
+        assert_eq!(
+            resources
+                .get_mut::<EventBus>()
+                .await
+                .unwrap()
+                .reader::<ItemEquippedEvent>()
+                .len(),
+            0
+        );
+
+        run(
+            &mut resources,
+            ItemUnequipRequested {
+                entity_id: "player".to_string(),
+                slot: "weapon".to_string(),
+            },
+        )
+        .await;
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        let unequipped: Vec<_> = bus
+            .reader::<ItemUnequippedEvent>()
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(unequipped[0].item_id, "axe");
+        drop(bus);
+        let state = resources.get::<InventoryState>().await.unwrap();
+        assert!(state
+            .get_equipped(&"player".to_string(), &"weapon".to_string())
+            .is_none());
+    }
+}
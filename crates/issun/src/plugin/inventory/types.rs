@@ -1,6 +1,7 @@
 //! Inventory types and traits
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Item trait for inventory management
 ///
@@ -28,6 +29,20 @@ pub type ItemId = String;
 /// Unique identifier for an entity (player, NPC, container, etc.)
 pub type EntityId = String;
 
+/// Identifier for an equipment slot (e.g., "weapon", "armor", "quick_1")
+///
+/// Quick-slot assignments are ordinary slots with game-defined ids.
+pub type SlotId = String;
+
+/// Named equipment configuration that can be re-applied later
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Loadout {
+    /// Loadout name (e.g., "tank", "dps")
+    pub name: String,
+    /// Slot → item assignments captured when the loadout was saved
+    pub slots: BTreeMap<SlotId, ItemId>,
+}
+
 /// Error types for inventory operations
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum InventoryError {
@@ -39,6 +54,10 @@ pub enum InventoryError {
     InventoryFull,
     /// Cannot perform operation (e.g., equip non-equippable item)
     InvalidOperation(String),
+    /// Loadout with the given name does not exist
+    LoadoutNotFound,
+    /// Entity already has the maximum number of loadouts
+    LoadoutLimitReached(usize),
 }
 
 impl std::fmt::Display for InventoryError {
@@ -48,6 +67,10 @@ impl std::fmt::Display for InventoryError {
             InventoryError::ItemNotFound => write!(f, "Item not found in inventory"),
             InventoryError::InventoryFull => write!(f, "Inventory is full"),
             InventoryError::InvalidOperation(msg) => write!(f, "Invalid operation: {}", msg),
+            InventoryError::LoadoutNotFound => write!(f, "Loadout not found"),
+            InventoryError::LoadoutLimitReached(max) => {
+                write!(f, "Loadout limit reached (max {})", max)
+            }
         }
     }
 }
@@ -1,11 +1,13 @@
 //! Loot system configuration (ReadOnly)
 
+use crate::engine::config_file::ConfigParams;
 use crate::resources::Resource;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for loot system (ReadOnly)
 ///
-/// This is a config loaded at startup and does not change during gameplay.
+/// Systems only read it; a watched config file may replace it between
+/// ticks (`[loot]`, see [`ConfigReloadSystem`](crate::engine::ConfigReloadSystem)).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LootConfig {
     /// Global drop rate multiplier (applies to all drops)
@@ -14,6 +16,8 @@ pub struct LootConfig {
 
 impl Resource for LootConfig {}
 
+impl ConfigParams for LootConfig {}
+
 impl Default for LootConfig {
     fn default() -> Self {
         Self {
@@ -10,6 +10,15 @@
 //! - Drop rate calculations with multipliers
 //! - Event-driven loot generation
 //! - Customizable loot tables via hooks
+//! - Reproducible drops from the game's [`MasterSeed`](crate::engine::MasterSeed)
+//!
+//! # Seeded Drops
+//!
+//! With a `MasterSeed` resource, every roll comes from a stream derived from
+//! (master seed, `"loot"`, run counter, source id, per-source sequence number).
+//! Each chest or enemy therefore drops the same items for a given seed no
+//! matter in which order sources are looted, which is what a daily challenge
+//! needs. Without a `MasterSeed`, rolls use entropy.
 //!
 //! # Usage Example
 //!
@@ -50,6 +59,7 @@ mod events;
 mod hook;
 mod plugin;
 mod service;
+mod state;
 mod system;
 mod types;
 
@@ -58,5 +68,6 @@ pub use events::*;
 pub use hook::{DefaultLootHook, LootHook};
 pub use plugin::LootPlugin;
 pub use service::LootService;
+pub use state::LootState;
 pub use system::LootSystem;
 pub use types::{DropConfig, Rarity};
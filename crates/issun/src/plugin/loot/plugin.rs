@@ -3,6 +3,7 @@
 use super::config::LootConfig;
 use super::hook::{DefaultLootHook, LootHook};
 use super::service::LootService;
+use super::state::LootState;
 use super::system::LootSystem;
 use crate::Plugin;
 use std::sync::Arc;
@@ -67,8 +68,13 @@ pub struct LootPlugin {
     hook: Arc<dyn LootHook>,
 
     #[resource]
+    #[plugin(config = "loot")]
     config: LootConfig,
 
+    #[state]
+    #[plugin(reset)]
+    state: LootState,
+
     #[service]
     service: LootService,
 
@@ -86,6 +92,7 @@ impl LootPlugin {
         Self {
             hook: hook.clone(),
             config: LootConfig::default(),
+            state: LootState::new(),
             service: LootService::new(),
             system: LootSystem::new(hook),
         }
@@ -0,0 +1,57 @@
+//! Loot runtime state (Mutable)
+
+use super::events::LootSourceId;
+use crate::state::State;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Loot runtime state (Mutable)
+///
+/// Counts the loot rolls made for each source. A roll's random stream is
+/// keyed by (source, sequence number), so drops do not depend on the order
+/// in which sources are opened. This is a save/load target.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LootState {
+    sequences: BTreeMap<LootSourceId, u64>,
+}
+
+impl State for LootState {}
+
+impl LootState {
+    /// Create a new empty state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rolls made so far for `source_id`
+    pub fn sequence(&self, source_id: &str) -> u64 {
+        self.sequences.get(source_id).copied().unwrap_or(0)
+    }
+
+    /// Sequence number for the next roll of `source_id`, advancing the count
+    pub fn next_sequence(&mut self, source_id: &str) -> u64 {
+        let sequence = self.sequences.entry(source_id.to_string()).or_insert(0);
+        let current = *sequence;
+        *sequence += 1;
+        current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequences_are_per_source() {
+        let mut state = LootState::new();
+        assert_eq!(state.next_sequence("chest_a"), 0);
+        assert_eq!(state.next_sequence("chest_a"), 1);
+        assert_eq!(state.next_sequence("chest_b"), 0);
+        assert_eq!(state.sequence("chest_a"), 2);
+        assert_eq!(state.sequence("goblin"), 0);
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: LootState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, state);
+    }
+}
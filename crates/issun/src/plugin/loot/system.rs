@@ -1,9 +1,12 @@
 //! Loot system implementation
 
 use crate::context::{ResourceContext, ServiceContext};
+use crate::engine::rng::{derive_seed, mix_seed, GameRng, MasterSeed};
 use crate::event::EventBus;
+use crate::scene::ResetRegistry;
 use crate::system::System;
 use async_trait::async_trait;
+use rand::Rng;
 use std::any::Any;
 use std::sync::Arc;
 
@@ -11,6 +14,7 @@ use super::config::LootConfig;
 use super::events::*;
 use super::hook::LootHook;
 use super::service::LootService;
+use super::state::LootState;
 
 /// System that processes loot events with hooks
 ///
@@ -20,6 +24,9 @@ use super::service::LootService;
 /// 3. Calls hooks for custom behavior
 /// 4. Publishes state change events for network replication
 ///
+/// Each request rolls on its own stream keyed by source and the source's
+/// sequence number in [`LootState`], derived from the game's [`MasterSeed`].
+///
 /// # Feedback Loop
 ///
 /// ```text
@@ -64,6 +71,8 @@ impl LootSystem {
         };
 
         for request in requests {
+            let mut rng = Self::source_rng(resources, &request.source_id).await;
+
             // Get global multiplier
             let global_multiplier = {
                 if let Some(config) = resources.get::<LootConfig>().await {
@@ -88,10 +97,9 @@ impl LootSystem {
             let should_drop = {
                 if let Some(_service) = services.get_as::<LootService>("loot_service") {
                     let drop_config = super::types::DropConfig::new(effective_rate, 1.0);
-                    let mut rng = rand::thread_rng();
                     LootService::should_drop(&drop_config, &mut rng)
                 } else {
-                    rand::random::<f32>() < effective_rate
+                    rng.gen::<f32>() < effective_rate
                 }
             };
 
@@ -108,7 +116,6 @@ impl LootSystem {
             // Select rarity using service
             let rarity = {
                 if let Some(_service) = services.get_as::<LootService>("loot_service") {
-                    let mut rng = rand::thread_rng();
                     LootService::select_rarity(&mut rng)
                 } else {
                     super::types::Rarity::Common
@@ -139,6 +146,30 @@ impl LootSystem {
         }
     }
 
+    /// RNG for the next roll of `source_id`
+    ///
+    /// Seeded from (master seed, "loot", run counter, source, sequence number),
+    /// so a source's drops do not depend on rolls made for other sources.
+    /// Falls back to entropy when the game has no `MasterSeed`.
+    async fn source_rng(resources: &mut ResourceContext, source_id: &str) -> GameRng {
+        let sequence = match resources.get_mut::<LootState>().await {
+            Some(mut state) => state.next_sequence(source_id),
+            None => 0,
+        };
+
+        let Some(master) = resources.get::<MasterSeed>().await.map(|seed| *seed) else {
+            return GameRng::from_entropy();
+        };
+        let run = resources
+            .get::<ResetRegistry>()
+            .await
+            .map(|registry| registry.restarts())
+            .unwrap_or(0);
+
+        let source = derive_seed(master.stream("loot", run), source_id);
+        GameRng::new(mix_seed(source, sequence))
+    }
+
     /// Process rarity roll requests
     async fn process_rarity_roll_requests(
         &mut self,
@@ -156,10 +187,11 @@ impl LootSystem {
         };
 
         for request in requests {
+            let mut rng = Self::source_rng(resources, &request.source_id).await;
+
             // Select rarity using service
             let rarity = {
                 if let Some(_service) = services.get_as::<LootService>("loot_service") {
-                    let mut rng = rand::thread_rng();
                     LootService::select_rarity(&mut rng)
                 } else {
                     super::types::Rarity::Common
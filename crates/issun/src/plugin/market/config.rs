@@ -1,5 +1,6 @@
 //! Configuration for MarketPlugin
 
+use super::history::{PriceHistory, TrendThresholds};
 use serde::{Deserialize, Serialize};
 
 /// Market configuration (Resource, ReadOnly)
@@ -44,6 +45,18 @@ pub struct MarketConfig {
     /// Lower = more sensitive to short-term changes
     /// - 0.05 = 5% threshold for trend detection
     pub trend_sensitivity: f32,
+
+    /// Settlement days kept in each item's [`PriceHistory`]
+    #[serde(default = "default_history_days")]
+    pub history_days: usize,
+
+    /// Thresholds for the settlement trend (Rising/Falling/Stable)
+    #[serde(default)]
+    pub trend_thresholds: TrendThresholds,
+}
+
+fn default_history_days() -> usize {
+    PriceHistory::DEFAULT_CAPACITY
 }
 
 impl crate::resources::Resource for MarketConfig {}
@@ -59,6 +72,8 @@ impl Default for MarketConfig {
             event_impact_coefficient: 0.3,
             price_history_length: 20,
             trend_sensitivity: 0.05,
+            history_days: default_history_days(),
+            trend_thresholds: TrendThresholds::default(),
         }
     }
 }
@@ -81,6 +96,8 @@ impl MarketConfig {
             event_impact_coefficient: 0.3,
             price_history_length: 20,
             trend_sensitivity: 0.05,
+            history_days: default_history_days(),
+            trend_thresholds: TrendThresholds::default(),
         }
     }
 
@@ -127,6 +144,18 @@ impl MarketConfig {
         self
     }
 
+    /// Builder: Set settlement days kept in price histories
+    pub fn with_history_days(mut self, days: usize) -> Self {
+        self.history_days = days.max(1);
+        self
+    }
+
+    /// Builder: Set settlement trend thresholds
+    pub fn with_trend_thresholds(mut self, thresholds: TrendThresholds) -> Self {
+        self.trend_thresholds = thresholds;
+        self
+    }
+
     /// Validate configuration
     ///
     /// Returns true if all values are within valid ranges
@@ -142,6 +171,8 @@ impl MarketConfig {
             && self.price_history_length > 0
             && self.trend_sensitivity >= 0.0
             && self.trend_sensitivity <= 1.0
+            && self.history_days > 0
+            && self.trend_thresholds.exit_percent <= self.trend_thresholds.enter_percent
     }
 }
 
@@ -273,6 +304,28 @@ mod tests {
         assert_eq!(config.trend_sensitivity, 0.1);
     }
 
+    #[test]
+    fn test_with_history_days_and_trend_thresholds() {
+        let config = MarketConfig::default()
+            .with_history_days(0)
+            .with_trend_thresholds(TrendThresholds::new(3, 4.0, 6.0));
+        assert_eq!(config.history_days, 1);
+        assert_eq!(config.trend_thresholds.window_days, 3);
+        assert_eq!(config.trend_thresholds.exit_percent, 4.0);
+        assert!(config.is_valid());
+    }
+
+    #[test]
+    fn test_deserialize_without_history_fields() {
+        let mut json = serde_json::to_value(MarketConfig::default()).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("history_days");
+        fields.remove("trend_thresholds");
+
+        let config: MarketConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(config, MarketConfig::default());
+    }
+
     #[test]
     fn test_serialization() {
         let config = MarketConfig::default();
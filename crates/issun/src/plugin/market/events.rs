@@ -67,10 +67,13 @@ pub struct PriceChangedEvent {
 
 impl Event for PriceChangedEvent {}
 
-/// Market trend changed
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Settlement trend of an item changed (see [`PriceHistory::classify`])
+///
+/// [`PriceHistory::classify`]: super::PriceHistory::classify
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MarketTrendChangedEvent {
     pub item_id: ItemId,
+    pub day: u32,
     pub old_trend: MarketTrend,
     pub new_trend: MarketTrend,
 }
@@ -194,6 +197,7 @@ mod tests {
     fn test_market_trend_changed_event_serialization() {
         let event = MarketTrendChangedEvent {
             item_id: "medicine".to_string(),
+            day: 12,
             old_trend: MarketTrend::Stable,
             new_trend: MarketTrend::Rising,
         };
@@ -0,0 +1,414 @@
+//! Per-day price history for MarketPlugin
+//!
+//! [`PriceHistory`] keeps one `(day, price)` sample per settlement in a
+//! bounded ring buffer and answers the questions traders ask: moving
+//! average, percent change, volatility and a Rising/Falling/Stable trend
+//! that only flips on genuine moves (see [`TrendThresholds`]).
+
+use super::types::MarketTrend;
+use crate::ui::ratatui::SparklineProvider;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// One settlement price
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PriceSample {
+    pub day: u32,
+    pub price: f32,
+}
+
+/// Thresholds for trend classification with hysteresis
+///
+/// A trend is entered when the price moved more than `enter_percent` over
+/// `window_days`, and only left once the move shrinks to `exit_percent` or
+/// less. Noise between the two thresholds keeps the current trend.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TrendThresholds {
+    /// Days over which the price change is measured
+    pub window_days: u32,
+    /// Percent change needed to start a Rising/Falling trend
+    pub enter_percent: f32,
+    /// Percent change at or below which a trend ends
+    pub exit_percent: f32,
+}
+
+impl Default for TrendThresholds {
+    fn default() -> Self {
+        Self {
+            window_days: 5,
+            enter_percent: 5.0,
+            exit_percent: 2.0,
+        }
+    }
+}
+
+impl TrendThresholds {
+    /// Create thresholds; `exit_percent` is capped at `enter_percent`
+    pub fn new(window_days: u32, enter_percent: f32, exit_percent: f32) -> Self {
+        let enter_percent = enter_percent.max(0.0);
+        Self {
+            window_days: window_days.max(1),
+            enter_percent,
+            exit_percent: exit_percent.clamp(0.0, enter_percent),
+        }
+    }
+}
+
+/// Bounded history of settlement prices for one item
+///
+/// Serializes with the market state; loading trims it to its capacity.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "SerializedPriceHistory")]
+pub struct PriceHistory {
+    capacity: usize,
+    samples: VecDeque<PriceSample>,
+    trend: MarketTrend,
+}
+
+/// Wire form of [`PriceHistory`], trimmed on conversion
+#[derive(Deserialize)]
+struct SerializedPriceHistory {
+    capacity: usize,
+    samples: VecDeque<PriceSample>,
+    trend: MarketTrend,
+}
+
+impl From<SerializedPriceHistory> for PriceHistory {
+    fn from(data: SerializedPriceHistory) -> Self {
+        let mut history = Self {
+            capacity: data.capacity.max(1),
+            samples: data.samples,
+            trend: data.trend,
+        };
+        history.trim();
+        history
+    }
+}
+
+impl Default for PriceHistory {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl PriceHistory {
+    /// Samples kept when no capacity is configured
+    pub const DEFAULT_CAPACITY: usize = 30;
+
+    /// Create an empty history keeping at most `capacity` samples
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            samples: VecDeque::new(),
+            trend: MarketTrend::Stable,
+        }
+    }
+
+    /// Maximum number of samples kept
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Change the capacity, dropping the oldest samples if needed
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.trim();
+    }
+
+    /// Record the settlement price of `day`
+    ///
+    /// A second sample for the same day replaces the first.
+    pub fn record(&mut self, day: u32, price: f32) {
+        match self.samples.back_mut() {
+            Some(last) if last.day == day => last.price = price,
+            _ => self.samples.push_back(PriceSample { day, price }),
+        }
+        self.trim();
+    }
+
+    /// Samples from oldest to newest
+    pub fn samples(&self) -> impl Iterator<Item = &PriceSample> {
+        self.samples.iter()
+    }
+
+    /// Most recent sample
+    pub fn latest(&self) -> Option<&PriceSample> {
+        self.samples.back()
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Current trend classification
+    pub fn trend(&self) -> MarketTrend {
+        self.trend
+    }
+
+    /// Average price over the last `days` days (including the latest day)
+    pub fn moving_average(&self, days: u32) -> Option<f32> {
+        let window = self.window(days);
+        if window.is_empty() {
+            return None;
+        }
+        Some(window.iter().sum::<f32>() / window.len() as f32)
+    }
+
+    /// Percent change of the latest price against the price `days` days
+    /// earlier (the last sample at or before that day)
+    pub fn percent_change(&self, days: u32) -> Option<f32> {
+        let latest = self.latest()?;
+        let reference_day = latest.day.checked_sub(days)?;
+        let reference = self
+            .samples
+            .iter()
+            .rev()
+            .find(|sample| sample.day <= reference_day)?;
+        if reference.price <= 0.0 {
+            return None;
+        }
+        Some((latest.price - reference.price) / reference.price * 100.0)
+    }
+
+    /// Standard deviation of the prices over the last `days` days
+    pub fn volatility(&self, days: u32) -> Option<f32> {
+        let window = self.window(days);
+        if window.is_empty() {
+            return None;
+        }
+        let mean = window.iter().sum::<f32>() / window.len() as f32;
+        let variance = window
+            .iter()
+            .map(|price| (price - mean).powi(2))
+            .sum::<f32>()
+            / window.len() as f32;
+        Some(variance.sqrt())
+    }
+
+    /// Classify the trend from the price change over the threshold window
+    ///
+    /// Starting from the current trend: a Rising (Falling) trend holds
+    /// while the change stays above `exit_percent` (below -`exit_percent`);
+    /// otherwise a change of at least `enter_percent` either way starts a
+    /// new trend, and anything else is Stable. Without enough history the
+    /// current trend is kept.
+    pub fn classify(&self, thresholds: &TrendThresholds) -> MarketTrend {
+        let Some(change) = self.percent_change(thresholds.window_days) else {
+            return self.trend;
+        };
+        match self.trend {
+            MarketTrend::Rising if change > thresholds.exit_percent => MarketTrend::Rising,
+            MarketTrend::Falling if change < -thresholds.exit_percent => MarketTrend::Falling,
+            _ if change >= thresholds.enter_percent => MarketTrend::Rising,
+            _ if change <= -thresholds.enter_percent => MarketTrend::Falling,
+            _ => MarketTrend::Stable,
+        }
+    }
+
+    /// Re-classify the trend; returns the previous trend if it changed
+    pub fn update_trend(&mut self, thresholds: &TrendThresholds) -> Option<MarketTrend> {
+        let new_trend = self.classify(thresholds);
+        if new_trend == self.trend {
+            return None;
+        }
+        Some(std::mem::replace(&mut self.trend, new_trend))
+    }
+
+    fn window(&self, days: u32) -> Vec<f32> {
+        let Some(latest) = self.latest() else {
+            return Vec::new();
+        };
+        if days == 0 {
+            return Vec::new();
+        }
+        let first_day = latest.day.saturating_sub(days - 1);
+        self.samples
+            .iter()
+            .filter(|sample| sample.day >= first_day)
+            .map(|sample| sample.price)
+            .collect()
+    }
+
+    fn trim(&mut self) {
+        while self.samples.len() > self.capacity {
+            self.samples.pop_front();
+        }
+    }
+}
+
+impl SparklineProvider for PriceHistory {
+    fn sparkline_values(&self) -> Vec<f64> {
+        self.samples
+            .iter()
+            .map(|sample| sample.price as f64)
+            .collect()
+    }
+
+    fn sparkline_label(&self) -> Option<String> {
+        let latest = self.latest()?;
+        Some(format!("{:.2} ({:?})", latest.price, self.trend))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(prices: &[f32]) -> PriceHistory {
+        let mut history = PriceHistory::new(prices.len().max(1));
+        for (day, price) in prices.iter().enumerate() {
+            history.record(day as u32 + 1, *price);
+        }
+        history
+    }
+
+    #[test]
+    fn test_moving_average_matches_fixture() {
+        let history = history(&[10.0, 12.0, 11.0, 13.0, 14.0, 16.0, 15.0]);
+
+        assert_eq!(history.moving_average(1), Some(15.0));
+        assert_eq!(history.moving_average(3), Some(15.0));
+        assert_eq!(history.moving_average(4), Some(14.5));
+        assert_eq!(history.moving_average(7), Some(13.0));
+        // Longer windows use what is there
+        assert_eq!(history.moving_average(30), Some(13.0));
+        assert_eq!(history.moving_average(0), None);
+        assert_eq!(PriceHistory::default().moving_average(3), None);
+    }
+
+    #[test]
+    fn test_percent_change_and_volatility() {
+        let series = history(&[10.0, 12.0, 11.0, 13.0, 14.0, 16.0, 15.0]);
+
+        assert_eq!(series.percent_change(6), Some(50.0));
+        assert_eq!(series.percent_change(2), Some((15.0 - 14.0) / 14.0 * 100.0));
+        assert_eq!(series.percent_change(7), None);
+
+        let flat = history(&[8.0, 8.0, 8.0]);
+        assert_eq!(flat.volatility(3), Some(0.0));
+        let swing = history(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!(swing.volatility(8), Some(2.0));
+    }
+
+    #[test]
+    fn test_percent_change_skips_missing_days() {
+        let mut history = PriceHistory::new(10);
+        history.record(1, 10.0);
+        history.record(4, 11.0);
+        history.record(6, 12.0);
+
+        // Day 3 has no sample; day 1 is the last settlement at or before it
+        assert_eq!(history.percent_change(3), Some(20.0));
+        assert_eq!(history.moving_average(3), Some(11.5));
+    }
+
+    #[test]
+    fn test_buffer_trims_to_capacity() {
+        let mut history = PriceHistory::new(3);
+        for day in 1..=5 {
+            history.record(day, day as f32);
+        }
+        let days: Vec<u32> = history.samples().map(|sample| sample.day).collect();
+        assert_eq!(days, vec![3, 4, 5]);
+
+        // Same-day settlement replaces the sample
+        history.record(5, 50.0);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.latest().unwrap().price, 50.0);
+
+        history.set_capacity(1);
+        let days: Vec<u32> = history.samples().map(|sample| sample.day).collect();
+        assert_eq!(days, vec![5]);
+    }
+
+    #[test]
+    fn test_deserialize_trims_to_capacity() {
+        let json = r#"{
+            "capacity": 2,
+            "samples": [
+                {"day": 1, "price": 10.0},
+                {"day": 2, "price": 11.0},
+                {"day": 3, "price": 12.0}
+            ],
+            "trend": "Rising"
+        }"#;
+        let history: PriceHistory = serde_json::from_str(json).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.samples().next().unwrap().day, 2);
+        assert_eq!(history.trend(), MarketTrend::Rising);
+
+        let round_trip: PriceHistory =
+            serde_json::from_str(&serde_json::to_string(&history).unwrap()).unwrap();
+        assert_eq!(round_trip, history);
+    }
+
+    #[test]
+    fn test_hysteresis_ignores_noise_around_threshold() {
+        let thresholds = TrendThresholds::new(1, 5.0, 2.0);
+        let mut history = PriceHistory::new(50);
+        let mut flips = Vec::new();
+
+        // Climb past the enter threshold, then jitter around +5% per day
+        let prices = [
+            100.0, 106.0, 110.5, 116.0, 121.0, 127.0, 131.5, 138.0, 143.0, 150.0,
+        ];
+        for (day, price) in prices.iter().enumerate() {
+            history.record(day as u32, *price);
+            if let Some(old) = history.update_trend(&thresholds) {
+                flips.push((day, old, history.trend()));
+            }
+        }
+        assert_eq!(
+            flips,
+            vec![(1, MarketTrend::Stable, MarketTrend::Rising)],
+            "daily moves of 3.5%..6% must not leave the Rising trend"
+        );
+
+        // Noise of ±4% around a flat price never enters a trend
+        let mut flat = PriceHistory::new(50);
+        for day in 0..20 {
+            let price = if day % 2 == 0 { 100.0 } else { 104.0 };
+            flat.record(day, price);
+            assert_eq!(flat.update_trend(&thresholds), None);
+        }
+        assert_eq!(flat.trend(), MarketTrend::Stable);
+    }
+
+    #[test]
+    fn test_trend_flips_on_genuine_changes() {
+        let thresholds = TrendThresholds::new(1, 5.0, 2.0);
+        let mut history = PriceHistory::new(50);
+        let mut flips = Vec::new();
+
+        let prices = [100.0, 110.0, 120.0, 121.0, 110.0, 100.0, 101.0, 101.5];
+        for (day, price) in prices.iter().enumerate() {
+            history.record(day as u32, *price);
+            if let Some(old) = history.update_trend(&thresholds) {
+                flips.push((day, old, history.trend()));
+            }
+        }
+        assert_eq!(
+            flips,
+            vec![
+                (1, MarketTrend::Stable, MarketTrend::Rising),
+                (3, MarketTrend::Rising, MarketTrend::Stable),
+                (4, MarketTrend::Stable, MarketTrend::Falling),
+                (6, MarketTrend::Falling, MarketTrend::Stable),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sparkline_values() {
+        let history = history(&[10.0, 12.5, 11.0]);
+        assert_eq!(history.sparkline_values(), vec![10.0, 12.5, 11.0]);
+        assert_eq!(
+            history.sparkline_label(),
+            Some("11.00 (Stable)".to_string())
+        );
+    }
+}
@@ -10,6 +10,7 @@
 //! - **Trend Detection**: Automatic detection of Rising, Falling, Stable, or Volatile markets
 //! - **Hook System**: Extensible with game-specific customization
 //! - **Price History**: Track price changes over time for analysis
+//! - **Settlement History**: Per-day prices with moving average, volatility and hysteresis trends
 //!
 //! # Example
 //!
@@ -36,10 +37,15 @@
 //!
 //! // Update prices
 //! let changes = system.update_prices(&mut state, &config).await;
+//!
+//! // Or settle the day: update prices, record history, classify trends
+//! let settlement = system.settle(&mut state, &config, timer.day).await;
+//! settlement.publish(&mut bus);
 //! ```
 
 pub mod config;
 pub mod events;
+pub mod history;
 pub mod hook;
 pub mod plugin;
 pub mod service;
@@ -49,6 +55,7 @@ pub mod types;
 
 pub use config::*;
 pub use events::*;
+pub use history::*;
 pub use hook::*;
 pub use plugin::*;
 pub use service::*;
@@ -226,6 +226,7 @@ impl MarketService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::plugin::market::PriceHistory;
     use std::collections::VecDeque;
 
     fn create_test_data(base_price: f32, demand: f32, supply: f32) -> MarketData {
@@ -238,6 +239,7 @@ mod tests {
             price_history: VecDeque::new(),
             trend: MarketTrend::Stable,
             volatility: 0.1,
+            history: PriceHistory::default(),
         }
     }
 
@@ -1,10 +1,35 @@
 //! System for market updates and orchestration
 
 use super::config::MarketConfig;
+use super::events::{MarketTrendChangedEvent, PricesUpdatedEvent};
 use super::hook::MarketHook;
 use super::service::MarketService;
 use super::state::MarketState;
 use super::types::{ItemId, MarketEvent, MarketTrend, PriceChange};
+use crate::event::EventBus;
+
+/// Outcome of a daily market settlement
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarketSettlement {
+    /// Prices that changed
+    pub price_changes: Vec<PriceChange>,
+    /// Items whose settlement trend flipped
+    pub trend_changes: Vec<MarketTrendChangedEvent>,
+}
+
+impl MarketSettlement {
+    /// Publish the price update (if any) and every trend change
+    pub fn publish(&self, bus: &mut EventBus) {
+        if !self.price_changes.is_empty() {
+            bus.publish(PricesUpdatedEvent {
+                changes: self.price_changes.clone(),
+            });
+        }
+        for change in &self.trend_changes {
+            bus.publish(change.clone());
+        }
+    }
+}
 
 /// Market system (orchestrates market updates)
 ///
@@ -45,7 +70,6 @@ impl<H: MarketHook> MarketSystem<H> {
 
         for (item_id, data) in state.all_items_mut() {
             let old_price = data.current_price;
-            let old_trend = data.trend;
 
             // Service: Calculate new price from supply/demand
             let new_price = MarketService::calculate_price(data, config);
@@ -60,8 +84,7 @@ impl<H: MarketHook> MarketSystem<H> {
             data.update_price(new_price, config.price_history_length);
 
             // Service: Detect trend
-            let new_trend = MarketService::detect_trend(data, config);
-            data.trend = new_trend;
+            data.trend = MarketService::detect_trend(data, config);
 
             // Hook: Allow custom price adjustment
             let adjusted_price = self
@@ -76,12 +99,6 @@ impl<H: MarketHook> MarketSystem<H> {
             if (old_price - adjusted_price).abs() > 0.01 {
                 price_changes.push(PriceChange::new(item_id.clone(), old_price, adjusted_price));
             }
-
-            // Note: Trend changes are tracked but not returned
-            // Games can listen to events for trend changes
-            if old_trend != new_trend {
-                // Could emit MarketTrendChangedEvent here
-            }
         }
 
         // Hook: Notify of price updates
@@ -92,6 +109,56 @@ impl<H: MarketHook> MarketSystem<H> {
         price_changes
     }
 
+    /// Settle the market for `day`
+    ///
+    /// Updates prices like [`update_prices`](Self::update_prices), then
+    /// records each item's price in its [`PriceHistory`](super::PriceHistory)
+    /// and re-classifies the settlement trend. Publish the result with
+    /// [`MarketSettlement::publish`] so MODs and AI can react to trend changes.
+    pub async fn settle(
+        &self,
+        state: &mut MarketState,
+        config: &MarketConfig,
+        day: u32,
+    ) -> MarketSettlement {
+        let price_changes = self.update_prices(state, config).await;
+        let trend_changes = Self::record_settlement(state, config, day);
+        MarketSettlement {
+            price_changes,
+            trend_changes,
+        }
+    }
+
+    /// Record current prices as the settlement of `day`
+    ///
+    /// # Returns
+    ///
+    /// Trend changes, sorted by item id
+    pub fn record_settlement(
+        state: &mut MarketState,
+        config: &MarketConfig,
+        day: u32,
+    ) -> Vec<MarketTrendChangedEvent> {
+        let mut trend_changes = Vec::new();
+
+        for (item_id, data) in state.all_items_mut() {
+            data.history.set_capacity(config.history_days);
+            data.history.record(day, data.current_price);
+
+            if let Some(old_trend) = data.history.update_trend(&config.trend_thresholds) {
+                trend_changes.push(MarketTrendChangedEvent {
+                    item_id: item_id.clone(),
+                    day,
+                    old_trend,
+                    new_trend: data.history.trend(),
+                });
+            }
+        }
+
+        trend_changes.sort_by(|a, b| a.item_id.cmp(&b.item_id));
+        trend_changes
+    }
+
     /// Apply a market event
     ///
     /// # Arguments
@@ -209,7 +276,7 @@ impl<H: MarketHook> MarketSystem<H> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::plugin::market::{DefaultMarketHook, MarketData};
+    use crate::plugin::market::{DefaultMarketHook, MarketData, TrendThresholds};
 
     fn create_test_state() -> MarketState {
         let mut state = MarketState::new();
@@ -423,4 +490,62 @@ mod tests {
         let ammo_demand = state.get_item(&"ammo".to_string()).unwrap().demand;
         assert!((ammo_demand - 0.62).abs() < 0.05);
     }
+
+    #[test]
+    fn test_trend_changed_fires_only_on_genuine_changes() {
+        let mut state = MarketState::new();
+        state.register_item("water", 100.0);
+        state.register_item("ammo", 50.0);
+        let config = create_config().with_trend_thresholds(TrendThresholds::new(1, 5.0, 2.0));
+        let water = "water".to_string();
+
+        let prices = [100.0, 110.0, 114.0, 117.0, 118.0, 112.0, 106.0, 103.0];
+        let mut events = Vec::new();
+        for (day, price) in prices.iter().enumerate() {
+            state.get_item_mut(&water).unwrap().current_price = *price;
+            events.extend(MarketSystem::<DefaultMarketHook>::record_settlement(
+                &mut state,
+                &config,
+                day as u32 + 1,
+            ));
+        }
+
+        let flips: Vec<(u32, MarketTrend, MarketTrend)> = events
+            .iter()
+            .map(|event| (event.day, event.old_trend, event.new_trend))
+            .collect();
+        assert_eq!(
+            flips,
+            vec![
+                (2, MarketTrend::Stable, MarketTrend::Rising),
+                (5, MarketTrend::Rising, MarketTrend::Stable),
+                (6, MarketTrend::Stable, MarketTrend::Falling),
+            ]
+        );
+        assert!(events.iter().all(|event| event.item_id == "water"));
+
+        let water_history = &state.get_item(&water).unwrap().history;
+        assert_eq!(water_history.len(), prices.len());
+        assert_eq!(water_history.trend(), MarketTrend::Falling);
+    }
+
+    #[tokio::test]
+    async fn test_settle_records_history_and_publishes() {
+        let system = MarketSystem::new(DefaultMarketHook);
+        let mut state = create_test_state();
+        let config = create_config().with_history_days(3);
+
+        let mut bus = EventBus::new();
+        for day in 1..=5 {
+            let settlement = system.settle(&mut state, &config, day).await;
+            settlement.publish(&mut bus);
+        }
+        bus.dispatch();
+
+        let water = state.get_item(&"water".to_string()).unwrap();
+        let days: Vec<u32> = water.history.samples().map(|sample| sample.day).collect();
+        assert_eq!(days, vec![3, 4, 5]);
+        assert_eq!(water.history.latest().unwrap().price, water.current_price);
+        assert_eq!(bus.reader::<MarketTrendChangedEvent>().iter().count(), 0);
+    }
 }
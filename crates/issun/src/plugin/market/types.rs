@@ -1,5 +1,6 @@
 //! Core data types for MarketPlugin
 
+use super::history::PriceHistory;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
@@ -35,6 +36,11 @@ pub struct MarketData {
 
     /// Volatility (how much price fluctuates)
     pub volatility: f32,
+
+    /// Settlement prices by day, with the hysteresis trend
+    /// (see [`MarketSystem::settle`](super::MarketSystem::settle))
+    #[serde(default)]
+    pub history: PriceHistory,
 }
 
 impl MarketData {
@@ -49,6 +55,7 @@ impl MarketData {
             price_history: VecDeque::with_capacity(10),
             trend: MarketTrend::Stable,
             volatility: 0.1,
+            history: PriceHistory::default(),
         }
     }
 
@@ -1,9 +1,14 @@
 //! Metrics plugin implementation
 
+use super::events::MetricDefined;
 use super::hook::{MetricsHook, NoOpMetricsHook};
 use super::registry::{MetricsConfig, MetricsRegistry};
 use super::system::MetricsSystem;
-use crate::Plugin;
+use super::types::MetricDefinition;
+use crate::context::{ResourceContext, ServiceContext, SystemContext};
+use crate::event::EventBus;
+use crate::plugin::{Plugin, PluginBuilder, PluginBuilderExt};
+use async_trait::async_trait;
 use std::sync::Arc;
 
 /// Built-in metrics collection, aggregation, and reporting plugin
@@ -15,6 +20,9 @@ use std::sync::Arc;
 /// - Processing snapshot and report generation requests
 /// - Custom hooks for game-specific behavior
 ///
+/// Metrics passed to `with_definition()` are defined when the runner starts,
+/// before the first tick, so systems can record them right away.
+///
 /// # Hook Customization
 ///
 /// You can provide a custom hook to add game-specific behavior:
@@ -53,17 +61,12 @@ use std::sync::Arc;
 ///     .build()
 ///     .await?;
 /// ```
-#[derive(Plugin)]
-#[plugin(name = "issun:metrics")]
 pub struct MetricsPlugin {
-    #[plugin(skip)]
     hook: Arc<dyn MetricsHook>,
-    #[plugin(resource)]
     config: MetricsConfig,
-    #[plugin(runtime_state)]
     registry: MetricsRegistry,
-    #[plugin(system)]
     system: MetricsSystem,
+    definitions: Vec<MetricDefinition>,
 }
 
 impl MetricsPlugin {
@@ -79,6 +82,7 @@ impl MetricsPlugin {
             config: config.clone(),
             registry: MetricsRegistry::with_config(config),
             system: MetricsSystem::new(hook),
+            definitions: Vec::new(),
         }
     }
 
@@ -149,6 +153,28 @@ impl MetricsPlugin {
         self.registry = MetricsRegistry::with_config(config);
         self
     }
+
+    /// Define a metric at startup
+    ///
+    /// The definition goes through the same path as a `DefineMetricRequested`
+    /// event (registry, `on_metric_defined` hook, `MetricDefined` event), but
+    /// runs in `on_start` instead of waiting for the first tick.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let plugin = MetricsPlugin::new().with_definition(MetricDefinition::new(
+    ///     "fps",
+    ///     "Frames per second",
+    ///     "Rendering frame rate",
+    ///     MetricType::Gauge,
+    ///     "fps",
+    /// ));
+    /// ```
+    pub fn with_definition(mut self, definition: MetricDefinition) -> Self {
+        self.definitions.push(definition);
+        self
+    }
 }
 
 impl Default for MetricsPlugin {
@@ -157,14 +183,48 @@ impl Default for MetricsPlugin {
     }
 }
 
+#[async_trait]
+impl Plugin for MetricsPlugin {
+    fn name(&self) -> &'static str {
+        "issun:metrics"
+    }
+
+    fn build(&self, builder: &mut dyn PluginBuilder) {
+        builder.register_resource(self.config.clone());
+        builder.register_runtime_state(self.registry.clone());
+        builder.register_system(Box::new(self.system.clone()));
+    }
+
+    async fn on_start(
+        &self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) {
+        for definition in &self.definitions {
+            if let Some(mut registry) = resources.get_mut::<MetricsRegistry>().await {
+                registry.define(definition.clone());
+            }
+
+            self.hook.on_metric_defined(definition, resources).await;
+
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.publish(MetricDefined {
+                    definition: definition.clone(),
+                });
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_plugin_creation() {
-        let _plugin = MetricsPlugin::new();
-        // Plugin derive macro automatically implements name()
+        let plugin = MetricsPlugin::new();
+        assert_eq!(plugin.name(), "issun:metrics");
     }
 
     #[test]
@@ -175,7 +235,6 @@ mod tests {
         impl MetricsHook for CustomHook {}
 
         let _plugin = MetricsPlugin::new().with_hook(CustomHook);
-        // Plugin derive macro automatically implements name()
     }
 
     #[test]
@@ -187,6 +246,32 @@ mod tests {
         };
 
         let _plugin = MetricsPlugin::new().with_config(config);
-        // Plugin derive macro automatically implements name()
+    }
+
+    #[tokio::test]
+    async fn test_definitions_are_registered_on_start() {
+        use crate::plugin::metrics::{MetricId, MetricType};
+
+        let plugin = MetricsPlugin::new().with_definition(MetricDefinition::new(
+            "fps",
+            "Frames per second",
+            "Rendering frame rate",
+            MetricType::Gauge,
+            "fps",
+        ));
+        let mut game = crate::builder::GameBuilder::new().build().await.unwrap();
+        game.resources.insert(MetricsRegistry::new());
+
+        plugin
+            .on_start(&game.services, &mut game.systems, &mut game.resources)
+            .await;
+
+        let registry = game.resources.get::<MetricsRegistry>().await.unwrap();
+        assert!(registry.get_definition(&MetricId::new("fps")).is_some());
+        drop(registry);
+
+        let mut bus = game.resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        assert_eq!(bus.reader::<MetricDefined>().iter().count(), 1);
     }
 }
@@ -20,16 +20,68 @@
 //!     .add_plugin(TurnBasedCombatPlugin::default())
 //!     .build();
 //! ```
+//!
+//! # Derived components
+//!
+//! `#[derive(Plugin)]` builds struct-level components with `Default`, or with
+//! the expression in a string, and registers `#[plugin(hook = ...)]` through
+//! [`PluginBuilderExt::register_hook`]:
+//!
+//! ```
+//! use issun::resources::Resource;
+//! use issun::Plugin;
+//! use issun::plugin::Hook;
+//! use std::sync::Arc;
+//!
+//! #[derive(Clone)]
+//! struct Difficulty(u32);
+//! impl Resource for Difficulty {}
+//!
+//! trait SpawnHook: Send + Sync {}
+//! #[derive(Default)]
+//! struct NoSpawns;
+//! impl SpawnHook for NoSpawns {}
+//!
+//! #[derive(Plugin)]
+//! #[plugin(resource = "Difficulty(3)")]
+//! #[plugin(hook = NoSpawns)]
+//! #[plugin(hook = "Arc::new(NoSpawns) as Arc<dyn SpawnHook>")]
+//! struct SpawnPlugin;
+//! ```
+//!
+//! A type without `Default` needs the expression form:
+//!
+//! ```compile_fail,E0599
+//! use issun::resources::Resource;
+//! use issun::Plugin;
+//!
+//! struct Difficulty(u32);
+//! impl Resource for Difficulty {}
+//!
+//! #[derive(Plugin)]
+//! #[plugin(resource = Difficulty)]
+//! struct SpawnPlugin;
+//! ```
+//!
+//! ```compile_fail
+//! use issun::Plugin;
+//!
+//! #[derive(Plugin)]
+//! #[plugin(resource = "Difficulty(")]
+//! struct SpawnPlugin;
+//! ```
 
 use async_trait::async_trait;
 
 // Built-in plugins
 pub mod accounting;
 pub mod action;
+pub mod boss;
 pub mod chain_of_command;
 pub mod combat;
 pub mod contagion;
 pub mod culture;
+pub mod debug_http;
 pub mod dungeon;
 pub mod economy;
 pub mod entropy;
@@ -48,6 +100,7 @@ pub mod policy;
 pub mod reputation;
 pub mod research;
 pub mod room_buff;
+pub mod run_summary;
 pub mod save_load;
 pub mod social;
 pub mod subjective_reality;
@@ -71,8 +124,10 @@ pub use combat::{
     BattleState,
     // Resources
     CombatConfig,
+    CombatDamageRequested,
     CombatEndRequested,
     CombatEndedEvent,
+    CombatHealRequested,
     // Hook
     CombatHook,
     CombatLogEntry,
@@ -95,7 +150,9 @@ pub use combat::{
 };
 
 pub use inventory::{
+    ApplyLoadoutRequested,
     DefaultInventoryHook,
+    DeleteLoadoutRequested,
     EntityId,
     // Resources
     InventoryConfig,
@@ -114,13 +171,32 @@ pub use inventory::{
     // Events
     ItemAddRequested,
     ItemAddedEvent,
+    ItemCatalog,
+    ItemDefinition,
+    ItemEffect,
+    ItemEffectExecutor,
+    ItemEquipRequested,
+    ItemEquippedEvent,
     ItemId,
     ItemRemoveRequested,
     ItemRemovedEvent,
     ItemTransferRequested,
     ItemTransferredEvent,
+    ItemUnequipRequested,
+    ItemUnequippedEvent,
+    ItemUseRejectedEvent,
     ItemUseRequested,
     ItemUsedEvent,
+    Loadout,
+    LoadoutAppliedEvent,
+    LoadoutDeletedEvent,
+    LoadoutPartiallyAppliedEvent,
+    LoadoutSaveRejectedEvent,
+    LoadoutSavedEvent,
+    ResourcePoolRestoreRequested,
+    RoomSelector,
+    SaveLoadoutRequested,
+    SlotId,
 };
 
 pub use loot::{
@@ -149,7 +225,9 @@ pub use loot::{
 pub use dungeon::{
     // Types
     Connection,
+    ConnectionGate,
     ConnectionPattern,
+    ConnectionUnlockFailedEvent,
     ConnectionUnlockRequested,
     ConnectionUnlockedEvent,
     DefaultDungeonHook,
@@ -166,6 +244,9 @@ pub use dungeon::{
     DungeonSystem,
     FloorAdvanceRequested,
     FloorAdvancedEvent,
+    GameFlags,
+    GatedConnection,
+    MoveBlockedEvent,
     RoomEnteredEvent,
     RoomId,
     // Events
@@ -198,6 +279,7 @@ pub use room_buff::{
     RoomBuffHook,
     // Plugin
     RoomBuffPlugin,
+    TickContext,
 };
 
 pub use territory::{
@@ -258,6 +340,8 @@ pub use faction::{
 pub use policy::{
     AggregationStrategy,
     DefaultPolicyHook,
+    FactionStanceInfluence,
+    PendingPolicyVote,
     // Resources
     Policies,
     // Types
@@ -275,6 +359,14 @@ pub use policy::{
     // Plugin
     PolicyPlugin,
     PolicyState,
+    PolicyVoteCancelRequested,
+    PolicyVoteCancelled,
+    PolicyVoteFailed,
+    PolicyVotePassed,
+    PolicyVoteStarted,
+    ReputationTierInfluence,
+    VoteConfig,
+    VoteInfluence,
 };
 
 pub use reputation::{
@@ -395,6 +487,9 @@ pub use save_load::{
     // System
     SaveLoadSystem,
     SaveMetadataRetrieved,
+    // MOD manifest
+    SaveModManifest,
+    SaveModMismatch,
     SavesListed,
 };
 
@@ -640,6 +735,7 @@ pub use market::{
     MarketPlugin,
     // Service (Phase 4) ✅
     MarketService,
+    MarketSettlement,
     // State (Phase 3) ✅
     MarketState,
     // System (Phase 7) ✅
@@ -648,9 +744,12 @@ pub use market::{
     MarketTrendChangedEvent,
     PriceChange,
     PriceChangedEvent,
+    PriceHistory,
+    PriceSample,
     // Events (Phase 6) ✅
     PriceUpdateRequested,
     PricesUpdatedEvent,
+    TrendThresholds,
 };
 
 // OrganizationSuitePlugin exports (Phase 0 complete ✅)
@@ -715,7 +814,14 @@ pub use modular_synthesis::{
 };
 
 use crate::builder::RuntimeResourceEntry;
+use crate::context::{ResourceContext, ServiceContext, SystemContext};
+use crate::scene::{ResetHandler, ResetToInitial};
 use std::any::TypeId;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Default upper bound for a single plugin's `on_start` / `on_exit`
+pub const DEFAULT_LIFECYCLE_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Plugin trait for system composition
 #[async_trait]
@@ -733,6 +839,36 @@ pub trait Plugin: Send + Sync {
 
     /// Initialize plugin (called before build)
     async fn initialize(&mut self) {}
+
+    /// Called once by the runner after the director is constructed and
+    /// before the first tick, in plugin build order.
+    ///
+    /// Use this for async setup that needs the final contexts, e.g. opening a
+    /// storage backend or seeding resources registered by other plugins.
+    async fn on_start(
+        &self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+    ) {
+    }
+
+    /// Called once by the runner on clean shutdown, in reverse build order.
+    ///
+    /// Use this to flush state (mod storage, metrics export, trace dumps).
+    async fn on_exit(
+        &self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+    ) {
+    }
+
+    /// Maximum time `on_start` / `on_exit` may take before the runner gives
+    /// up on this plugin and moves on
+    fn lifecycle_timeout(&self) -> Duration {
+        DEFAULT_LIFECYCLE_TIMEOUT
+    }
 }
 
 /// Builder interface for plugins to register components
@@ -761,6 +897,23 @@ pub trait PluginBuilder {
 
     /// Get mutable access to the resources registry (internal use)
     fn resources_mut(&mut self) -> &mut crate::resources::Resources;
+
+    /// Register a handler that resets plugin state on `SceneTransition::Restart`
+    ///
+    /// Default: ignored, for builders that don't support restarts
+    fn register_reset_handler(&mut self, _handler: Box<dyn ResetHandler>) {}
+
+    /// Declare feature flag `name` with its default value
+    ///
+    /// See [`FeatureFlags`](crate::engine::FeatureFlags). Default: ignored,
+    /// for builders without feature flags
+    fn declare_flag(&mut self, _name: &str, _default: bool) {}
+
+    /// Register a config resource as a section of the config file
+    ///
+    /// See [`ConfigReloadSystem`](crate::engine::ConfigReloadSystem).
+    /// Default: ignored, for builders without a config file
+    fn register_config_section(&mut self, _section: Box<dyn crate::engine::ConfigSection>) {}
 }
 
 /// Extension trait for PluginBuilder with generic methods
@@ -790,7 +943,65 @@ pub trait PluginBuilderExt: PluginBuilder {
     fn register_runtime_state<T: 'static + Send + Sync>(&mut self, resource: T) {
         self.register_runtime_resource_boxed(TypeId::of::<T>(), Box::new(resource));
     }
+
+    /// Let the config file override resource `T` under `[section]`
+    ///
+    /// `#[plugin(config = "section")]` on a field does the same.
+    fn register_config<T: crate::engine::ConfigParams>(&mut self, section: &str) {
+        self.register_config_section(Box::new(crate::engine::TypedConfigSection::<T>::new(
+            section,
+        )));
+    }
+
+    /// Reset runtime state `T` to its value after `GameBuilder::build`
+    /// whenever the run restarts
+    fn register_reset_to_initial<T: Clone + Send + Sync + 'static>(&mut self) {
+        self.register_reset_handler(Box::new(ResetToInitial::<T>::new()));
+    }
+
+    /// Register a hook as the resource [`Hook<H>`]
+    ///
+    /// `H` may be a trait object, so systems can look up the game's hook
+    /// without the plugin passing it along.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// builder.register_hook::<dyn ContagionHook>(Arc::new(AlertHook));
+    ///
+    /// // In a system
+    /// let hook = resources.get::<Hook<dyn ContagionHook>>().await;
+    /// ```
+    fn register_hook<H: ?Sized + Send + Sync + 'static>(&mut self, hook: Arc<H>) {
+        self.register_resource(Hook(hook));
+    }
+}
+
+/// Hook registered with [`PluginBuilderExt::register_hook`] (ReadOnly)
+pub struct Hook<H: ?Sized>(pub Arc<H>);
+
+impl<H: ?Sized> Hook<H> {
+    /// Shared handle to the hook
+    pub fn get(&self) -> Arc<H> {
+        self.0.clone()
+    }
+}
+
+impl<H: ?Sized> Clone for Hook<H> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
 }
 
+impl<H: ?Sized> std::ops::Deref for Hook<H> {
+    type Target = H;
+
+    fn deref(&self) -> &H {
+        &self.0
+    }
+}
+
+impl<H: ?Sized + Send + Sync + 'static> crate::resources::Resource for Hook<H> {}
+
 // Blanket implementation
 impl<T: ?Sized + PluginBuilder> PluginBuilderExt for T {}
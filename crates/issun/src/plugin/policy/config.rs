@@ -35,6 +35,39 @@ pub struct PolicyConfig {
 
     /// Default aggregation strategy (when effect not in aggregation_strategies map)
     pub default_aggregation: AggregationStrategy,
+
+    /// Approval workflow: activation requests start a vote instead
+    ///
+    /// `None` activates requested policies immediately.
+    #[serde(default)]
+    pub approval: Option<VoteConfig>,
+}
+
+/// Rules for policy approval votes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoteConfig {
+    /// Turns (`DayChanged` events) a vote stays open
+    pub duration_turns: u32,
+
+    /// Support a vote starts with
+    pub initial_support: f32,
+
+    /// Support needed to pass when the vote closes (inclusive)
+    pub pass_threshold: f32,
+
+    /// Turns before a policy whose vote failed can be proposed again
+    pub reproposal_cooldown: u32,
+}
+
+impl Default for VoteConfig {
+    fn default() -> Self {
+        Self {
+            duration_turns: 3,
+            initial_support: 0.0,
+            pass_threshold: 0.5,
+            reproposal_cooldown: 5,
+        }
+    }
 }
 
 impl Default for PolicyConfig {
@@ -45,6 +78,7 @@ impl Default for PolicyConfig {
             enable_cycling: true,
             aggregation_strategies: HashMap::new(),
             default_aggregation: AggregationStrategy::Multiply,
+            approval: None,
         }
     }
 }
@@ -63,6 +97,7 @@ mod tests {
         assert!(config.enable_cycling);
         assert!(config.aggregation_strategies.is_empty());
         assert_eq!(config.default_aggregation, AggregationStrategy::Multiply);
+        assert!(config.approval.is_none());
     }
 
     #[test]
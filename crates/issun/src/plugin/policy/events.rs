@@ -1,6 +1,7 @@
 //! Policy events for command and state changes
 
 use super::types::*;
+use super::vote::PendingPolicyVote;
 use crate::event::Event;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -9,6 +10,9 @@ use std::collections::HashMap;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyActivateRequested {
     pub policy_id: PolicyId,
+    /// Who asks for the change (the proposer of the vote in approval mode)
+    #[serde(default)]
+    pub proposer: Option<String>,
 }
 
 impl Event for PolicyActivateRequested {}
@@ -47,3 +51,55 @@ pub struct PolicyDeactivatedEvent {
 }
 
 impl Event for PolicyDeactivatedEvent {}
+
+/// Request to withdraw a pending approval vote
+///
+/// Ignored unless `proposer` matches the vote's proposer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyVoteCancelRequested {
+    pub policy_id: PolicyId,
+    pub proposer: Option<String>,
+}
+
+impl Event for PolicyVoteCancelRequested {}
+
+/// Published when an activation request opened an approval vote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyVoteStarted {
+    pub vote: PendingPolicyVote,
+}
+
+impl Event for PolicyVoteStarted {}
+
+/// Published when a vote closes with enough support
+///
+/// The policy then goes through the normal activation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyVotePassed {
+    pub policy_id: PolicyId,
+    pub proposer: Option<String>,
+    pub support: f32,
+}
+
+impl Event for PolicyVotePassed {}
+
+/// Published when a vote closes below the threshold
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyVoteFailed {
+    pub policy_id: PolicyId,
+    pub proposer: Option<String>,
+    pub support: f32,
+    /// Turns before the policy can be proposed again
+    pub cooldown_turns: u32,
+}
+
+impl Event for PolicyVoteFailed {}
+
+/// Published when the proposer withdrew a vote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyVoteCancelled {
+    pub policy_id: PolicyId,
+    pub proposer: Option<String>,
+}
+
+impl Event for PolicyVoteCancelled {}
@@ -4,6 +4,7 @@ use crate::context::ResourceContext;
 use async_trait::async_trait;
 
 use super::types::*;
+use super::vote::PendingPolicyVote;
 
 /// Trait for custom policy behavior
 ///
@@ -107,6 +108,29 @@ pub trait PolicyHook: Send + Sync {
         // Default: always allow
         Ok(())
     }
+
+    /// Game-specific support for a pending approval vote
+    ///
+    /// Called once per turn for every open vote, after the registered
+    /// `VoteInfluence`s. The returned value is added to the vote's support.
+    ///
+    /// # Arguments
+    ///
+    /// * `vote` - The vote, with the support accumulated so far
+    /// * `policy` - The policy being voted on
+    /// * `resources` - Access to game resources (read-only)
+    ///
+    /// # Default
+    ///
+    /// Returns 0.0
+    async fn vote_influence(
+        &self,
+        _vote: &PendingPolicyVote,
+        _policy: &Policy,
+        _resources: &ResourceContext,
+    ) -> f32 {
+        0.0
+    }
 }
 
 /// Default hook that does nothing
@@ -6,6 +6,7 @@
 //! - Hook-based customization for game-specific logic
 //! - Event-driven architecture for network replication
 //! - Single-active OR multi-active policy modes
+//! - Optional approval votes resolved over several turns
 //!
 //! # Example
 //!
@@ -54,9 +55,10 @@ mod service;
 mod state;
 mod system;
 mod types;
+mod vote;
 
 // Public exports
-pub use config::PolicyConfig;
+pub use config::{PolicyConfig, VoteConfig};
 pub use events::*;
 pub use hook::{DefaultPolicyHook, PolicyHook};
 pub use plugin::PolicyPlugin;
@@ -64,3 +66,4 @@ pub use policies::Policies;
 pub use service::PolicyService;
 pub use state::PolicyState;
 pub use types::{AggregationStrategy, Policy, PolicyId};
+pub use vote::{FactionStanceInfluence, PendingPolicyVote, ReputationTierInfluence, VoteInfluence};
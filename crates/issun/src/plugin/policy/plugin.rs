@@ -5,6 +5,7 @@ use super::hook::{DefaultPolicyHook, PolicyHook};
 use super::policies::Policies;
 use super::state::PolicyState;
 use super::system::PolicySystem;
+use super::vote::VoteInfluence;
 use crate::Plugin;
 use std::sync::Arc;
 
@@ -15,6 +16,7 @@ use std::sync::Arc;
 /// - Processing policy activation requests
 /// - Processing policy deactivation requests
 /// - Processing policy cycling requests
+/// - Running approval votes (`PolicyConfig::approval`)
 /// - Custom hooks for game-specific behavior
 ///
 /// # Hook Customization
@@ -124,7 +126,38 @@ impl PolicyPlugin {
     pub fn with_hook<H: PolicyHook + 'static>(mut self, hook: H) -> Self {
         let hook = Arc::new(hook);
         self.hook = hook.clone();
+        let influences = self.system.influences().to_vec();
         self.system = PolicySystem::new(hook);
+        for influence in influences {
+            self.system.add_influence(influence);
+        }
+        self
+    }
+
+    /// Add a source of support for approval votes
+    ///
+    /// Influences are asked once per turn for every pending vote, in the
+    /// order they were added, before `PolicyHook::vote_influence`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use issun::plugin::policy::{
+    ///     FactionStanceInfluence, PolicyConfig, PolicyPlugin, ReputationTierInfluence, VoteConfig,
+    /// };
+    ///
+    /// let plugin = PolicyPlugin::new()
+    ///     .with_config(PolicyConfig {
+    ///         approval: Some(VoteConfig::default()),
+    ///         ..Default::default()
+    ///     })
+    ///     .with_vote_influence(
+    ///         ReputationTierInfluence::new(["citizens"]).with_tier("Trusted", 0.2),
+    ///     )
+    ///     .with_vote_influence(FactionStanceInfluence::new(0.5));
+    /// ```
+    pub fn with_vote_influence<I: VoteInfluence + 'static>(mut self, influence: I) -> Self {
+        self.system.add_influence(Arc::new(influence));
         self
     }
 
@@ -204,6 +237,19 @@ mod tests {
         // Plugin derive macro automatically implements name()
     }
 
+    #[test]
+    fn test_with_hook_keeps_vote_influences() {
+        struct CustomHook;
+
+        #[async_trait::async_trait]
+        impl PolicyHook for CustomHook {}
+
+        let plugin = PolicyPlugin::new()
+            .with_vote_influence(super::super::FactionStanceInfluence::default())
+            .with_hook(CustomHook);
+        assert_eq!(plugin.system.influences().len(), 1);
+    }
+
     #[test]
     fn test_plugin_with_custom_config() {
         let config = PolicyConfig {
@@ -1,8 +1,10 @@
 //! Policy runtime state (Mutable)
 
-use super::types::PolicyId;
+use super::types::{Policy, PolicyId};
+use super::vote::PendingPolicyVote;
 use crate::state::State;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Policy runtime state (Mutable)
 ///
@@ -15,6 +17,14 @@ pub struct PolicyState {
 
     /// Currently active policies (multi-active mode)
     active_policy_ids: Vec<PolicyId>,
+
+    /// Open approval votes
+    #[serde(default)]
+    pending_votes: Vec<PendingPolicyVote>,
+
+    /// Turns until a policy whose vote failed can be proposed again
+    #[serde(default)]
+    reproposal_cooldowns: HashMap<PolicyId, u32>,
 }
 
 impl State for PolicyState {}
@@ -25,6 +35,8 @@ impl PolicyState {
         Self {
             active_policy_id: None,
             active_policy_ids: Vec::new(),
+            pending_votes: Vec::new(),
+            reproposal_cooldowns: HashMap::new(),
         }
     }
 
@@ -97,6 +109,77 @@ impl PolicyState {
         self.active_policy_id = None;
         self.active_policy_ids.clear();
     }
+
+    // ========================================
+    // Approval votes
+    // ========================================
+
+    /// Open approval votes
+    pub fn pending_votes(&self) -> &[PendingPolicyVote] {
+        &self.pending_votes
+    }
+
+    /// Open vote on a policy
+    pub fn pending_vote(&self, id: &PolicyId) -> Option<&PendingPolicyVote> {
+        self.pending_votes.iter().find(|vote| &vote.policy_id == id)
+    }
+
+    /// Open vote that keeps `policy` from being put to a vote
+    pub fn conflicting_vote(&self, policy: &Policy) -> Option<&PendingPolicyVote> {
+        self.pending_votes
+            .iter()
+            .find(|vote| vote.conflicts_with(policy))
+    }
+
+    /// Open a vote
+    ///
+    /// Returns `false` if a vote on the same policy or group is pending.
+    pub fn start_vote(&mut self, vote: PendingPolicyVote) -> bool {
+        let conflict = self.pending_votes.iter().any(|pending| {
+            pending.policy_id == vote.policy_id
+                || (pending.group.is_some() && pending.group == vote.group)
+        });
+        if conflict {
+            return false;
+        }
+        self.pending_votes.push(vote);
+        true
+    }
+
+    /// Close the vote on a policy
+    pub fn remove_vote(&mut self, id: &PolicyId) -> Option<PendingPolicyVote> {
+        let index = self
+            .pending_votes
+            .iter()
+            .position(|vote| &vote.policy_id == id)?;
+        Some(self.pending_votes.remove(index))
+    }
+
+    pub(crate) fn pending_votes_mut(&mut self) -> &mut Vec<PendingPolicyVote> {
+        &mut self.pending_votes
+    }
+
+    /// Turns before a policy can be proposed again (0: no cooldown)
+    pub fn reproposal_cooldown(&self, id: &PolicyId) -> u32 {
+        self.reproposal_cooldowns.get(id).copied().unwrap_or(0)
+    }
+
+    /// Block new votes on a policy for `turns` turns
+    pub fn set_reproposal_cooldown(&mut self, id: PolicyId, turns: u32) {
+        if turns == 0 {
+            self.reproposal_cooldowns.remove(&id);
+        } else {
+            self.reproposal_cooldowns.insert(id, turns);
+        }
+    }
+
+    /// Count re-proposal cooldowns down by one turn
+    pub fn tick_cooldowns(&mut self) {
+        self.reproposal_cooldowns.retain(|_, turns| {
+            *turns = turns.saturating_sub(1);
+            *turns > 0
+        });
+    }
 }
 
 impl Default for PolicyState {
@@ -177,4 +260,60 @@ mod tests {
         assert!(state.active_policy_id().is_none());
         assert!(state.active_policy_ids().is_empty());
     }
+
+    fn vote(id: &str, group: Option<&str>) -> PendingPolicyVote {
+        PendingPolicyVote {
+            policy_id: PolicyId::new(id),
+            group: group.map(String::from),
+            proposer: Some("chancellor".into()),
+            turns_remaining: 2,
+            support: 0.25,
+        }
+    }
+
+    #[test]
+    fn test_one_pending_vote_per_group() {
+        let mut state = PolicyState::new();
+
+        assert!(state.start_vote(vote("free_trade", Some("economy"))));
+        assert!(!state.start_vote(vote("protectionism", Some("economy"))));
+        assert!(!state.start_vote(vote("free_trade", None)));
+        assert!(state.start_vote(vote("conscription", None)));
+        assert!(state.start_vote(vote("volunteers", None)));
+        assert_eq!(state.pending_votes().len(), 3);
+
+        assert!(state.remove_vote(&PolicyId::new("free_trade")).is_some());
+        assert!(state.start_vote(vote("protectionism", Some("economy"))));
+    }
+
+    #[test]
+    fn test_cooldowns_tick_down() {
+        let mut state = PolicyState::new();
+        state.set_reproposal_cooldown(PolicyId::new("p1"), 2);
+
+        state.tick_cooldowns();
+        assert_eq!(state.reproposal_cooldown(&PolicyId::new("p1")), 1);
+        state.tick_cooldowns();
+        assert_eq!(state.reproposal_cooldown(&PolicyId::new("p1")), 0);
+    }
+
+    #[test]
+    fn test_pending_votes_serde() {
+        let mut state = PolicyState::new();
+        state.start_vote(vote("free_trade", Some("economy")));
+        state.set_reproposal_cooldown(PolicyId::new("conscription"), 3);
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: PolicyState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.pending_votes(), state.pending_votes());
+        assert_eq!(
+            restored.reproposal_cooldown(&PolicyId::new("conscription")),
+            3
+        );
+
+        // States saved before approval votes existed still load
+        let old: PolicyState =
+            serde_json::from_str(r#"{"active_policy_id":null,"active_policy_ids":[]}"#).unwrap();
+        assert!(old.pending_votes().is_empty());
+    }
 }
@@ -7,11 +7,14 @@ use async_trait::async_trait;
 use std::any::Any;
 use std::sync::Arc;
 
-use super::config::PolicyConfig;
+use super::config::{PolicyConfig, VoteConfig};
 use super::events::*;
 use super::hook::PolicyHook;
 use super::policies::Policies;
 use super::state::PolicyState;
+use super::types::PolicyId;
+use super::vote::{PendingPolicyVote, VoteInfluence};
+use crate::plugin::time::DayChanged;
 
 /// System that processes policy events with hooks
 ///
@@ -19,8 +22,9 @@ use super::state::PolicyState;
 /// 1. Processes policy activation requests
 /// 2. Processes policy deactivation requests
 /// 3. Processes policy cycling requests
-/// 4. Calls hooks for custom behavior
-/// 5. Publishes state change events for network replication
+/// 4. Runs approval votes (when `PolicyConfig::approval` is set)
+/// 5. Calls hooks for custom behavior
+/// 6. Publishes state change events for network replication
 ///
 /// # Feedback Loop
 ///
@@ -31,13 +35,27 @@ use super::state::PolicyState;
 pub struct PolicySystem {
     #[allow(dead_code)]
     hook: Arc<dyn PolicyHook>,
+    influences: Vec<Arc<dyn VoteInfluence>>,
 }
 
 #[allow(dead_code)]
 impl PolicySystem {
     /// Create a new PolicySystem with a custom hook
     pub fn new(hook: Arc<dyn PolicyHook>) -> Self {
-        Self { hook }
+        Self {
+            hook,
+            influences: Vec::new(),
+        }
+    }
+
+    /// Register a source of support for approval votes
+    pub fn add_influence(&mut self, influence: Arc<dyn VoteInfluence>) {
+        self.influences.push(influence);
+    }
+
+    /// Registered vote influences
+    pub fn influences(&self) -> &[Arc<dyn VoteInfluence>] {
+        &self.influences
     }
 
     /// Process all policy events
@@ -46,6 +64,8 @@ impl PolicySystem {
         services: &ServiceContext,
         resources: &mut ResourceContext,
     ) {
+        self.process_vote_cancellations(services, resources).await;
+        self.process_votes(services, resources).await;
         self.process_activations(services, resources).await;
         self.process_deactivations(services, resources).await;
         self.process_cycles(services, resources).await;
@@ -53,11 +73,8 @@ impl PolicySystem {
 
     /// Process policy activation requests
     ///
-    /// Listens for `PolicyActivateRequested` events and:
-    /// 1. Validates activation (via hook)
-    /// 2. Activates policy and updates state
-    /// 3. Calls hook
-    /// 4. Publishes `PolicyActivatedEvent`
+    /// Listens for `PolicyActivateRequested` events and activates the
+    /// policy, or opens an approval vote on it in approval mode.
     pub async fn process_activations(
         &mut self,
         _services: &ServiceContext,
@@ -73,82 +90,317 @@ impl PolicySystem {
             }
         };
 
+        let approval = match resources.get::<PolicyConfig>().await {
+            Some(config) => config.approval.clone(),
+            None => return,
+        };
+
         for request in requests {
-            // Get policy for validation
-            let policy = {
+            match &approval {
+                Some(vote_config) => self.propose(request, vote_config, resources).await,
+                None => self.activate_policy(&request.policy_id, resources).await,
+            }
+        }
+    }
+
+    /// Activate a policy
+    ///
+    /// 1. Validates activation (via hook)
+    /// 2. Activates policy and updates state
+    /// 3. Calls hook
+    /// 4. Publishes `PolicyActivatedEvent`
+    async fn activate_policy(&mut self, policy_id: &PolicyId, resources: &mut ResourceContext) {
+        // Get policy for validation
+        let policy = {
+            if let Some(policies) = resources.get::<Policies>().await {
+                match policies.get(policy_id) {
+                    Some(p) => p.clone(),
+                    None => return, // Policy not found, skip
+                }
+            } else {
+                return;
+            }
+        };
+
+        // Validate activation via hook (read-only resources access)
+        {
+            let resources_ref = resources as &ResourceContext;
+            match self.hook.validate_activation(&policy, resources_ref).await {
+                Ok(()) => {}
+                Err(_) => return, // Hook rejected activation
+            }
+        }
+
+        // Get previous policy (before activation)
+        let previous_policy_id = {
+            let state = match resources.get::<PolicyState>().await {
+                Some(s) => s,
+                None => return,
+            };
+            state.active_policy_id().cloned()
+        };
+
+        let previous_policy = {
+            if let Some(id) = &previous_policy_id {
                 if let Some(policies) = resources.get::<Policies>().await {
-                    match policies.get(&request.policy_id) {
-                        Some(p) => p.clone(),
-                        None => continue, // Policy not found, skip
-                    }
+                    policies.get(id).cloned()
                 } else {
-                    continue;
+                    None
                 }
+            } else {
+                None
+            }
+        };
+
+        // Activate policy (update state)
+        {
+            let config = match resources.get::<PolicyConfig>().await {
+                Some(c) => c,
+                None => return,
+            };
+            let mut state = match resources.get_mut::<PolicyState>().await {
+                Some(s) => s,
+                None => return,
             };
 
-            // Validate activation via hook (read-only resources access)
+            if config.allow_multiple_active {
+                if !state.activate_multi(policy_id.clone()) {
+                    return; // Already active
+                }
+            } else {
+                state.activate(policy_id.clone());
+            }
+        }
+
+        // Call hook (synchronous, immediate, local only)
+        self.hook
+            .on_policy_activated(&policy, previous_policy.as_ref(), resources)
+            .await;
+
+        // Publish event (asynchronous, for other systems and network)
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.publish(PolicyActivatedEvent {
+                policy_id: policy.id.clone(),
+                policy_name: policy.name.clone(),
+                effects: policy.effects.clone(),
+                previous_policy_id,
+            });
+        }
+    }
+
+    /// Open an approval vote for an activation request
+    ///
+    /// Requests are dropped if the hook rejects the policy, it is already
+    /// active, its re-proposal cooldown runs, or a vote on it or its group
+    /// is pending.
+    async fn propose(
+        &mut self,
+        request: PolicyActivateRequested,
+        vote_config: &VoteConfig,
+        resources: &mut ResourceContext,
+    ) {
+        let policy = {
+            match resources.get::<Policies>().await {
+                Some(policies) => match policies.get(&request.policy_id) {
+                    Some(p) => p.clone(),
+                    None => return,
+                },
+                None => return,
+            }
+        };
+
+        if self
+            .hook
+            .validate_activation(&policy, resources as &ResourceContext)
+            .await
+            .is_err()
+        {
+            return;
+        }
+
+        let vote = PendingPolicyVote {
+            policy_id: policy.id.clone(),
+            group: policy.group.clone(),
+            proposer: request.proposer,
+            turns_remaining: vote_config.duration_turns,
+            support: vote_config.initial_support,
+        };
+
+        {
+            let mut state = match resources.get_mut::<PolicyState>().await {
+                Some(s) => s,
+                None => return,
+            };
+            let active =
+                state.active_policy_id() == Some(&policy.id) || state.is_active(&policy.id);
+            if active
+                || state.reproposal_cooldown(&policy.id) > 0
+                || !state.start_vote(vote.clone())
             {
+                return;
+            }
+        }
+
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.publish(PolicyVoteStarted { vote });
+        }
+    }
+
+    /// Advance approval votes once per `DayChanged` event
+    ///
+    /// Each turn counts re-proposal cooldowns down, adds the support of the
+    /// registered influences and the hook to every open vote, and resolves
+    /// the votes that close:
+    /// - `PolicyVotePassed`, then the normal activation
+    /// - `PolicyVoteFailed`, and the policy's re-proposal cooldown starts
+    pub async fn process_votes(
+        &mut self,
+        _services: &ServiceContext,
+        resources: &mut ResourceContext,
+    ) {
+        let turns = {
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.reader::<DayChanged>().iter().count()
+            } else {
+                0
+            }
+        };
+        if turns == 0 {
+            return;
+        }
+
+        let vote_config = match resources.get::<PolicyConfig>().await {
+            Some(config) => match &config.approval {
+                Some(vote_config) => vote_config.clone(),
+                None => return,
+            },
+            None => return,
+        };
+
+        for _ in 0..turns {
+            self.advance_votes(&vote_config, resources).await;
+        }
+    }
+
+    async fn advance_votes(&mut self, vote_config: &VoteConfig, resources: &mut ResourceContext) {
+        let votes = {
+            let mut state = match resources.get_mut::<PolicyState>().await {
+                Some(s) => s,
+                None => return,
+            };
+            state.tick_cooldowns();
+            state.pending_votes().to_vec()
+        };
+
+        let mut advanced = Vec::with_capacity(votes.len());
+        for mut vote in votes {
+            let policy = match resources.get::<Policies>().await {
+                Some(policies) => policies.get(&vote.policy_id).cloned(),
+                None => None,
+            };
+            if let Some(policy) = policy {
                 let resources_ref = resources as &ResourceContext;
-                match self.hook.validate_activation(&policy, resources_ref).await {
-                    Ok(()) => {}
-                    Err(_) => continue, // Hook rejected activation
+                let mut support = 0.0;
+                for influence in &self.influences {
+                    support += influence.influence(&vote, &policy, resources_ref).await;
                 }
+                support += self
+                    .hook
+                    .vote_influence(&vote, &policy, resources_ref)
+                    .await;
+                vote.support += support;
             }
+            vote.turns_remaining = vote.turns_remaining.saturating_sub(1);
+            advanced.push(vote);
+        }
 
-            // Get previous policy (before activation)
-            let previous_policy_id = {
-                let state = match resources.get::<PolicyState>().await {
-                    Some(s) => s,
-                    None => continue,
-                };
-                state.active_policy_id().cloned()
+        let mut closed = Vec::new();
+        {
+            let mut state = match resources.get_mut::<PolicyState>().await {
+                Some(s) => s,
+                None => return,
             };
-
-            let previous_policy = {
-                if let Some(id) = &previous_policy_id {
-                    if let Some(policies) = resources.get::<Policies>().await {
-                        policies.get(id).cloned()
-                    } else {
-                        None
+            for vote in advanced {
+                if vote.turns_remaining == 0 {
+                    state.remove_vote(&vote.policy_id);
+                    if vote.support < vote_config.pass_threshold {
+                        state.set_reproposal_cooldown(
+                            vote.policy_id.clone(),
+                            vote_config.reproposal_cooldown,
+                        );
                     }
-                } else {
-                    None
+                    closed.push(vote);
+                } else if let Some(pending) = state
+                    .pending_votes_mut()
+                    .iter_mut()
+                    .find(|pending| pending.policy_id == vote.policy_id)
+                {
+                    *pending = vote;
                 }
-            };
+            }
+        }
 
-            // Activate policy (update state)
-            {
-                let config = match resources.get::<PolicyConfig>().await {
-                    Some(c) => c,
-                    None => continue,
-                };
+        for vote in closed {
+            if vote.support >= vote_config.pass_threshold {
+                if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                    bus.publish(PolicyVotePassed {
+                        policy_id: vote.policy_id.clone(),
+                        proposer: vote.proposer.clone(),
+                        support: vote.support,
+                    });
+                }
+                self.activate_policy(&vote.policy_id, resources).await;
+            } else if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.publish(PolicyVoteFailed {
+                    policy_id: vote.policy_id,
+                    proposer: vote.proposer,
+                    support: vote.support,
+                    cooldown_turns: vote_config.reproposal_cooldown,
+                });
+            }
+        }
+    }
+
+    /// Process vote cancellations
+    ///
+    /// Listens for `PolicyVoteCancelRequested` events and closes the vote
+    /// if the request comes from its proposer, publishing
+    /// `PolicyVoteCancelled`. Cancelled votes start no cooldown.
+    pub async fn process_vote_cancellations(
+        &mut self,
+        _services: &ServiceContext,
+        resources: &mut ResourceContext,
+    ) {
+        let requests = {
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                let reader = bus.reader::<PolicyVoteCancelRequested>();
+                reader.iter().cloned().collect::<Vec<_>>()
+            } else {
+                Vec::new()
+            }
+        };
+
+        for request in requests {
+            let cancelled = {
                 let mut state = match resources.get_mut::<PolicyState>().await {
                     Some(s) => s,
                     None => continue,
                 };
-
-                if config.allow_multiple_active {
-                    if !state.activate_multi(request.policy_id.clone()) {
-                        continue; // Already active
+                match state.pending_vote(&request.policy_id) {
+                    Some(vote) if vote.proposer == request.proposer => {
+                        state.remove_vote(&request.policy_id)
                     }
-                } else {
-                    state.activate(request.policy_id.clone());
+                    _ => None,
                 }
-            }
-
-            // Call hook (synchronous, immediate, local only)
-            self.hook
-                .on_policy_activated(&policy, previous_policy.as_ref(), resources)
-                .await;
+            };
 
-            // Publish event (asynchronous, for other systems and network)
-            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
-                bus.publish(PolicyActivatedEvent {
-                    policy_id: policy.id.clone(),
-                    policy_name: policy.name.clone(),
-                    effects: policy.effects.clone(),
-                    previous_policy_id,
-                });
+            if let Some(vote) = cancelled {
+                if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                    bus.publish(PolicyVoteCancelled {
+                        policy_id: vote.policy_id,
+                        proposer: vote.proposer,
+                    });
+                }
             }
         }
     }
@@ -384,6 +636,7 @@ mod tests {
     use crate::context::ResourceContext;
     use crate::event::EventBus;
     use crate::plugin::policy::{DefaultPolicyHook, Policy, PolicyId};
+    use crate::plugin::time::DayChanged;
 
     #[tokio::test]
     async fn test_system_creation() {
@@ -412,6 +665,7 @@ mod tests {
             let mut bus = resources.get_mut::<EventBus>().await.unwrap();
             bus.publish(PolicyActivateRequested {
                 policy_id: PolicyId::new("test"),
+                proposer: None,
             });
             bus.dispatch();
         }
@@ -542,4 +796,227 @@ mod tests {
         assert_eq!(events.len(), 1);
         assert_eq!(events[0].policy_id.as_str(), "policy2");
     }
+
+    struct FixedInfluence(f32);
+
+    #[async_trait]
+    impl VoteInfluence for FixedInfluence {
+        async fn influence(
+            &self,
+            _vote: &PendingPolicyVote,
+            _policy: &Policy,
+            _resources: &ResourceContext,
+        ) -> f32 {
+            self.0
+        }
+    }
+
+    fn vote_resources() -> ResourceContext {
+        let mut resources = ResourceContext::new();
+        let mut policies = Policies::new();
+        policies.add(Policy::new("free_trade", "Free Trade", "Test").with_group("economy"));
+        policies.add(Policy::new("protectionism", "Protectionism", "Test").with_group("economy"));
+        resources.insert(policies);
+        resources.insert(PolicyConfig {
+            approval: Some(VoteConfig {
+                duration_turns: 3,
+                initial_support: 0.1,
+                pass_threshold: 0.5,
+                reproposal_cooldown: 2,
+            }),
+            ..Default::default()
+        });
+        resources.insert(PolicyState::new());
+        resources.insert(EventBus::new());
+        resources
+    }
+
+    fn vote_system(support_per_turn: f32) -> PolicySystem {
+        let mut system = PolicySystem::new(Arc::new(DefaultPolicyHook));
+        system.add_influence(Arc::new(FixedInfluence(support_per_turn)));
+        system
+    }
+
+    /// Publish `event`, make it visible and run the system for one frame
+    async fn run_frame<E: crate::event::Event + serde::Serialize>(
+        system: &mut PolicySystem,
+        resources: &mut ResourceContext,
+        event: E,
+    ) {
+        {
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+            bus.publish(event);
+            bus.dispatch();
+        }
+        let services = ServiceContext::new();
+        system.process_events(&services, resources).await;
+    }
+
+    async fn propose(system: &mut PolicySystem, resources: &mut ResourceContext, id: &str) {
+        let request = PolicyActivateRequested {
+            policy_id: PolicyId::new(id),
+            proposer: Some("chancellor".into()),
+        };
+        run_frame(system, resources, request).await;
+    }
+
+    async fn drain<E: crate::event::Event>(resources: &mut ResourceContext) -> Vec<E> {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.drain::<E>()
+    }
+
+    #[tokio::test]
+    async fn test_vote_passes_after_accumulating_support() {
+        let mut system = vote_system(0.15);
+        let mut resources = vote_resources();
+
+        propose(&mut system, &mut resources, "free_trade").await;
+        {
+            let state = resources.get::<PolicyState>().await.unwrap();
+            assert!(state.active_policy_id().is_none());
+            let vote = state.pending_vote(&PolicyId::new("free_trade")).unwrap();
+            assert_eq!(vote.turns_remaining, 3);
+            assert_eq!(vote.proposer.as_deref(), Some("chancellor"));
+        }
+        assert_eq!(drain::<PolicyVoteStarted>(&mut resources).await.len(), 1);
+
+        // Another economy policy can't be put to a vote meanwhile
+        propose(&mut system, &mut resources, "protectionism").await;
+        assert!(drain::<PolicyVoteStarted>(&mut resources).await.is_empty());
+
+        for day in 1..=2 {
+            run_frame(&mut system, &mut resources, DayChanged { day }).await;
+        }
+        {
+            let state = resources.get::<PolicyState>().await.unwrap();
+            assert!(state.active_policy_id().is_none());
+            let vote = state.pending_vote(&PolicyId::new("free_trade")).unwrap();
+            assert_eq!(vote.turns_remaining, 1);
+            assert!((vote.support - 0.4).abs() < 1e-6);
+        }
+
+        run_frame(&mut system, &mut resources, DayChanged { day: 3 }).await;
+        {
+            let state = resources.get::<PolicyState>().await.unwrap();
+            assert_eq!(state.active_policy_id().unwrap().as_str(), "free_trade");
+            assert!(state.pending_votes().is_empty());
+        }
+        let passed = drain::<PolicyVotePassed>(&mut resources).await;
+        assert_eq!(passed.len(), 1);
+        assert!((passed[0].support - 0.55).abs() < 1e-6);
+        let activated = drain::<PolicyActivatedEvent>(&mut resources).await;
+        assert_eq!(activated.len(), 1);
+        assert_eq!(activated[0].policy_id.as_str(), "free_trade");
+    }
+
+    #[tokio::test]
+    async fn test_vote_fails_below_threshold_with_cooldown() {
+        let mut system = vote_system(0.05);
+        let mut resources = vote_resources();
+
+        propose(&mut system, &mut resources, "free_trade").await;
+        for day in 1..=3 {
+            run_frame(&mut system, &mut resources, DayChanged { day }).await;
+        }
+
+        let failed = drain::<PolicyVoteFailed>(&mut resources).await;
+        assert_eq!(failed.len(), 1);
+        assert!((failed[0].support - 0.25).abs() < 1e-6);
+        assert_eq!(failed[0].cooldown_turns, 2);
+        assert!(drain::<PolicyActivatedEvent>(&mut resources)
+            .await
+            .is_empty());
+        {
+            let state = resources.get::<PolicyState>().await.unwrap();
+            assert!(state.active_policy_id().is_none());
+            assert!(state.pending_votes().is_empty());
+            assert_eq!(state.reproposal_cooldown(&PolicyId::new("free_trade")), 2);
+        }
+
+        // Re-proposal is ignored while the cooldown runs
+        propose(&mut system, &mut resources, "free_trade").await;
+        assert!(resources
+            .get::<PolicyState>()
+            .await
+            .unwrap()
+            .pending_votes()
+            .is_empty());
+
+        // Other policies of the group are not affected
+        propose(&mut system, &mut resources, "protectionism").await;
+        assert!(resources
+            .get::<PolicyState>()
+            .await
+            .unwrap()
+            .pending_vote(&PolicyId::new("protectionism"))
+            .is_some());
+
+        for day in 4..=5 {
+            run_frame(&mut system, &mut resources, DayChanged { day }).await;
+        }
+        {
+            let mut state = resources.get_mut::<PolicyState>().await.unwrap();
+            assert_eq!(state.reproposal_cooldown(&PolicyId::new("free_trade")), 0);
+            state.remove_vote(&PolicyId::new("protectionism"));
+        }
+        propose(&mut system, &mut resources, "free_trade").await;
+        assert!(resources
+            .get::<PolicyState>()
+            .await
+            .unwrap()
+            .pending_vote(&PolicyId::new("free_trade"))
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_vote_cancellation() {
+        let mut system = vote_system(1.0);
+        let mut resources = vote_resources();
+
+        propose(&mut system, &mut resources, "free_trade").await;
+
+        // Only the proposer can cancel
+        let request = PolicyVoteCancelRequested {
+            policy_id: PolicyId::new("free_trade"),
+            proposer: Some("opposition".into()),
+        };
+        run_frame(&mut system, &mut resources, request).await;
+        assert_eq!(
+            resources
+                .get::<PolicyState>()
+                .await
+                .unwrap()
+                .pending_votes()
+                .len(),
+            1
+        );
+
+        let request = PolicyVoteCancelRequested {
+            policy_id: PolicyId::new("free_trade"),
+            proposer: Some("chancellor".into()),
+        };
+        run_frame(&mut system, &mut resources, request).await;
+        {
+            let state = resources.get::<PolicyState>().await.unwrap();
+            assert!(state.pending_votes().is_empty());
+            assert_eq!(state.reproposal_cooldown(&PolicyId::new("free_trade")), 0);
+        }
+        let cancelled = drain::<PolicyVoteCancelled>(&mut resources).await;
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(cancelled[0].proposer.as_deref(), Some("chancellor"));
+
+        // Nothing resolves once the vote is gone, and the group is free again
+        for day in 1..=3 {
+            run_frame(&mut system, &mut resources, DayChanged { day }).await;
+        }
+        assert!(drain::<PolicyVotePassed>(&mut resources).await.is_empty());
+        assert!(resources
+            .get::<PolicyState>()
+            .await
+            .unwrap()
+            .active_policy_id()
+            .is_none());
+        propose(&mut system, &mut resources, "protectionism").await;
+        assert_eq!(drain::<PolicyVoteStarted>(&mut resources).await.len(), 1);
+    }
 }
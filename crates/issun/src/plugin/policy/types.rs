@@ -148,6 +148,12 @@ pub struct Policy {
     /// - Duration: `{ "duration_turns": 10, "cooldown": 5 }`
     #[serde(default)]
     pub metadata: serde_json::Value,
+
+    /// Policy group (e.g. "economy")
+    ///
+    /// In approval mode only one vote per group can be pending.
+    #[serde(default)]
+    pub group: Option<String>,
 }
 
 impl Policy {
@@ -183,6 +189,7 @@ impl Policy {
             description: description.into(),
             effects: HashMap::new(),
             metadata: serde_json::Value::Null,
+            group: None,
         }
     }
 
@@ -198,6 +205,12 @@ impl Policy {
         self
     }
 
+    /// Put the policy in a group
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
     /// Add a single effect
     pub fn add_effect(mut self, name: impl Into<String>, value: f32) -> Self {
         self.effects.insert(name.into(), value);
@@ -0,0 +1,256 @@
+//! Approval votes for policy changes
+//!
+//! With `PolicyConfig::approval` set, `PolicyActivateRequested` opens a
+//! [`PendingPolicyVote`]. Every `DayChanged` the registered [`VoteInfluence`]s
+//! and `PolicyHook::vote_influence` add to its support; when the vote closes
+//! it passes if the support reaches `VoteConfig::pass_threshold`.
+
+use super::types::{Policy, PolicyId};
+use crate::context::ResourceContext;
+use crate::plugin::faction::Factions;
+use crate::plugin::reputation::{ReputationConfig, ReputationState, SubjectId};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A policy change waiting for approval
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingPolicyVote {
+    pub policy_id: PolicyId,
+
+    /// Group of the policy (only one pending vote per group)
+    pub group: Option<String>,
+
+    /// Who proposed the change; only they can cancel the vote
+    pub proposer: Option<String>,
+
+    /// Turns until the vote closes
+    pub turns_remaining: u32,
+
+    /// Running support score
+    pub support: f32,
+}
+
+impl PendingPolicyVote {
+    /// Whether this vote blocks a vote on `policy`
+    ///
+    /// Policies without a group only conflict with themselves.
+    pub fn conflicts_with(&self, policy: &Policy) -> bool {
+        self.policy_id == policy.id || (self.group.is_some() && self.group == policy.group)
+    }
+}
+
+/// Source of support for pending votes
+///
+/// Called once per turn for every open vote; return the support to add
+/// (negative values are opposition).
+///
+/// # Example
+///
+/// ```ignore
+/// struct TreasuryInfluence;
+///
+/// #[async_trait]
+/// impl VoteInfluence for TreasuryInfluence {
+///     async fn influence(
+///         &self,
+///         _vote: &PendingPolicyVote,
+///         policy: &Policy,
+///         _resources: &ResourceContext,
+///     ) -> f32 {
+///         if policy.group.as_deref() == Some("tax") { -0.1 } else { 0.0 }
+///     }
+/// }
+///
+/// let plugin = PolicyPlugin::new().with_vote_influence(TreasuryInfluence);
+/// ```
+#[async_trait]
+pub trait VoteInfluence: Send + Sync {
+    async fn influence(
+        &self,
+        vote: &PendingPolicyVote,
+        policy: &Policy,
+        resources: &ResourceContext,
+    ) -> f32;
+}
+
+/// Support from how observers regard the proposer
+///
+/// Looks up `SubjectId::relation(observer, proposer)` in `ReputationState`
+/// for every observer and adds the weight of the tier
+/// (`ReputationConfig::thresholds`) the score falls in. Votes without a
+/// proposer get no support.
+#[derive(Debug, Clone, Default)]
+pub struct ReputationTierInfluence {
+    observers: Vec<String>,
+    tier_weights: HashMap<String, f32>,
+}
+
+impl ReputationTierInfluence {
+    /// Influence of the given observers (e.g. `["citizens", "nobility"]`)
+    pub fn new<I, S>(observers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            observers: observers.into_iter().map(Into::into).collect(),
+            tier_weights: HashMap::new(),
+        }
+    }
+
+    /// Support added per turn while an observer's score is in `tier`
+    pub fn with_tier(mut self, tier: impl Into<String>, weight: f32) -> Self {
+        self.tier_weights.insert(tier.into(), weight);
+        self
+    }
+}
+
+#[async_trait]
+impl VoteInfluence for ReputationTierInfluence {
+    async fn influence(
+        &self,
+        vote: &PendingPolicyVote,
+        _policy: &Policy,
+        resources: &ResourceContext,
+    ) -> f32 {
+        let Some(proposer) = &vote.proposer else {
+            return 0.0;
+        };
+        let (Some(state), Some(config)) = (
+            resources.get::<ReputationState>().await,
+            resources.get::<ReputationConfig>().await,
+        ) else {
+            return 0.0;
+        };
+
+        self.observers
+            .iter()
+            .filter_map(|observer| state.get(&SubjectId::relation(observer, proposer)))
+            .filter_map(|score| config.get_threshold(score))
+            .filter_map(|tier| self.tier_weights.get(&tier.name))
+            .sum()
+    }
+}
+
+/// Support from the factions' stances on the policy
+///
+/// Reads `{ "policy_stances": { "<policy_id>": <stance> } }` from each
+/// faction's metadata and adds the stances, scaled by `weight`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FactionStanceInfluence {
+    pub weight: f32,
+}
+
+impl FactionStanceInfluence {
+    pub fn new(weight: f32) -> Self {
+        Self { weight }
+    }
+}
+
+impl Default for FactionStanceInfluence {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+#[async_trait]
+impl VoteInfluence for FactionStanceInfluence {
+    async fn influence(
+        &self,
+        vote: &PendingPolicyVote,
+        _policy: &Policy,
+        resources: &ResourceContext,
+    ) -> f32 {
+        let Some(factions) = resources.get::<Factions>().await else {
+            return 0.0;
+        };
+
+        let stances: f64 = factions
+            .iter()
+            .filter_map(|faction| {
+                faction.metadata["policy_stances"][vote.policy_id.as_str()].as_f64()
+            })
+            .sum();
+        stances as f32 * self.weight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::faction::Faction;
+    use crate::plugin::reputation::ReputationThreshold;
+
+    fn vote(policy_id: &str, proposer: Option<&str>) -> PendingPolicyVote {
+        PendingPolicyVote {
+            policy_id: PolicyId::new(policy_id),
+            group: None,
+            proposer: proposer.map(String::from),
+            turns_remaining: 3,
+            support: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_conflicts_with_group() {
+        let mut pending = vote("free_trade", None);
+        pending.group = Some("economy".into());
+
+        assert!(pending.conflicts_with(&Policy::new("free_trade", "Free Trade", "")));
+        assert!(pending.conflicts_with(
+            &Policy::new("protectionism", "Protectionism", "").with_group("economy")
+        ));
+        assert!(!pending.conflicts_with(&Policy::new("conscription", "Conscription", "")));
+    }
+
+    #[tokio::test]
+    async fn test_reputation_tier_influence() {
+        let mut resources = ResourceContext::new();
+        let mut config = ReputationConfig::default();
+        config.add_threshold(ReputationThreshold::new("Hostile", -100.0, 0.0));
+        config.add_threshold(ReputationThreshold::new("Trusted", 50.0, 101.0));
+        resources.insert(config);
+        let mut state = ReputationState::new();
+        state.set(&SubjectId::relation("citizens", "chancellor"), 80.0);
+        state.set(&SubjectId::relation("nobility", "chancellor"), -20.0);
+        resources.insert(state);
+
+        let influence = ReputationTierInfluence::new(["citizens", "nobility", "clergy"])
+            .with_tier("Trusted", 0.2)
+            .with_tier("Hostile", -0.05);
+        let policy = Policy::new("free_trade", "Free Trade", "");
+
+        let support = influence
+            .influence(&vote("free_trade", Some("chancellor")), &policy, &resources)
+            .await;
+        assert!((support - 0.15).abs() < 1e-6);
+
+        let support = influence
+            .influence(&vote("free_trade", None), &policy, &resources)
+            .await;
+        assert_eq!(support, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_faction_stance_influence() {
+        let mut resources = ResourceContext::new();
+        let mut factions = Factions::new();
+        factions.add(
+            Faction::new("merchants", "Merchants")
+                .with_metadata(serde_json::json!({ "policy_stances": { "free_trade": 0.3 } })),
+        );
+        factions.add(
+            Faction::new("guilds", "Guilds")
+                .with_metadata(serde_json::json!({ "policy_stances": { "free_trade": -0.1 } })),
+        );
+        factions.add(Faction::new("clergy", "Clergy"));
+        resources.insert(factions);
+
+        let policy = Policy::new("free_trade", "Free Trade", "");
+        let support = FactionStanceInfluence::new(0.5)
+            .influence(&vote("free_trade", None), &policy, &resources)
+            .await;
+        assert!((support - 0.1).abs() < 1e-6);
+    }
+}
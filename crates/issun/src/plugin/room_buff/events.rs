@@ -1,5 +1,6 @@
 //! Room buff events for command and state notification
 
+use super::types::{BuffDuration, TickContext};
 use crate::event::Event;
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +15,9 @@ pub type BuffId = String;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BuffApplyRequested {
     pub buff_id: BuffId,
+    /// Overrides the duration of the buff's database entry
+    #[serde(default)]
+    pub duration: Option<BuffDuration>,
 }
 
 impl Event for BuffApplyRequested {}
@@ -26,9 +30,19 @@ pub struct BuffRemoveRequested {
 
 impl Event for BuffRemoveRequested {}
 
-/// Request to tick all buffs (advance turn, expire timed buffs)
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BuffTickRequested;
+/// Request to tick the buffs of one context (advance turn, expire timed buffs)
+///
+/// Each request is one tick; only buffs ticked by `context` count down.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BuffTickRequested {
+    pub context: TickContext,
+}
+
+impl BuffTickRequested {
+    pub fn new(context: TickContext) -> Self {
+        Self { context }
+    }
+}
 
 impl Event for BuffTickRequested {}
 
@@ -68,6 +82,7 @@ mod tests {
     fn test_event_serialization() {
         let event = BuffApplyRequested {
             buff_id: "haste".to_string(),
+            duration: None,
         };
         let json = serde_json::to_string(&event).unwrap();
         assert!(json.contains("haste"));
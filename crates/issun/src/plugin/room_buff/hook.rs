@@ -108,6 +108,7 @@ mod tests {
             name: "Test Buff".to_string(),
             duration: super::super::types::BuffDuration::Permanent,
             effect: super::super::types::BuffEffect::AttackBonus(10),
+            tick_on: vec![],
         });
         let mut resources = ResourceContext::new();
 
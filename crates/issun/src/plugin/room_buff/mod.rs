@@ -3,7 +3,9 @@
 //! # Features
 //!
 //! - Configurable buff database
-//! - Multiple buff durations (permanent, room-scoped, turn-based)
+//! - Multiple buff durations (permanent, room-scoped, turn-based, real-time)
+//! - Tick contexts (combat turn, world turn, real time) so buffs only count
+//!   down when their context advances
 //! - Various buff effects (attack, defense, HP regen, drop rate)
 //! - Event-driven architecture
 //! - Customizable buff effects via hooks
@@ -35,6 +37,7 @@
 //!         name: "Attack Boost".to_string(),
 //!         duration: BuffDuration::UntilRoomExit,
 //!         effect: BuffEffect::AttackBonus(5),
+//!         tick_on: vec![],
 //!     });
 //!
 //! // Register plugin
@@ -47,7 +50,7 @@
 //!     .await?;
 //!
 //! // Apply buff via events
-//! bus.publish(BuffApplyRequested { buff_id: "attack_boost".to_string() });
+//! bus.publish(BuffApplyRequested { buff_id: "attack_boost".to_string(), duration: None });
 //! ```
 
 mod events;
@@ -63,4 +66,6 @@ pub use hook::{DefaultRoomBuffHook, RoomBuffHook};
 pub use plugin::RoomBuffPlugin;
 pub use service::BuffService;
 pub use system::BuffSystem;
-pub use types::{ActiveBuff, ActiveBuffs, BuffConfig, BuffDuration, BuffEffect, RoomBuffDatabase};
+pub use types::{
+    ActiveBuff, ActiveBuffs, BuffConfig, BuffDuration, BuffEffect, RoomBuffDatabase, TickContext,
+};
@@ -12,7 +12,7 @@ use std::sync::Arc;
 /// Provides temporary buff management functionality with:
 /// - Buff database for buff definitions
 /// - Active buff tracking
-/// - Turn-based duration management
+/// - Turn-based and real-time duration management
 /// - Customizable buff effects via hooks
 /// - Event-driven architecture for loose coupling
 ///
@@ -152,6 +152,7 @@ impl RoomBuffPlugin {
     ///         name: "Haste".to_string(),
     ///         duration: BuffDuration::Turns(5),
     ///         effect: BuffEffect::AttackBonus(10),
+    ///         tick_on: vec![],
     ///     });
     ///
     /// let plugin = RoomBuffPlugin::new().with_database(database);
@@ -93,6 +93,7 @@ mod tests {
             name: "Attack Up".to_string(),
             duration: BuffDuration::Permanent,
             effect: BuffEffect::AttackBonus(5),
+            tick_on: vec![],
         }));
 
         buffs.add(ActiveBuff::new(BuffConfig {
@@ -100,6 +101,7 @@ mod tests {
             name: "Attack Up 2".to_string(),
             duration: BuffDuration::Turns(3),
             effect: BuffEffect::AttackBonus(3),
+            tick_on: vec![],
         }));
 
         assert_eq!(service.calculate_attack_bonus(&buffs), 8);
@@ -115,6 +117,7 @@ mod tests {
             name: "Lucky".to_string(),
             duration: BuffDuration::UntilRoomExit,
             effect: BuffEffect::DropRateMultiplier(2.0),
+            tick_on: vec![],
         }));
 
         assert_eq!(service.calculate_drop_rate_multiplier(&buffs), 2.0);
@@ -2,6 +2,7 @@
 
 use crate::context::{ResourceContext, ServiceContext};
 use crate::event::EventBus;
+use crate::plugin::time::GameTimer;
 use crate::system::System;
 use async_trait::async_trait;
 use std::any::Any;
@@ -14,11 +15,16 @@ use super::types::{ActiveBuff, ActiveBuffs, RoomBuffDatabase};
 /// System that processes room buff events with hooks
 ///
 /// This system:
-/// 1. Processes buff apply requests
-/// 2. Processes buff remove requests
-/// 3. Processes buff tick requests (turn advancement)
-/// 4. Calls hooks for custom behavior
-/// 5. Publishes state change events for network replication
+/// 1. Counts down real-time buffs by the game time `GameTimer` accumulated
+///    since the last update (nothing passes while the timer is paused)
+/// 2. Processes buff apply requests
+/// 3. Processes buff remove requests
+/// 4. Processes buff tick requests (one tick per request, per context)
+/// 5. Calls hooks for custom behavior
+/// 6. Publishes state change events for network replication
+///
+/// An expired buff is removed in the same pass, so its `BuffExpiredEvent`
+/// fires exactly once whichever context expired it.
 ///
 /// # Feedback Loop
 ///
@@ -42,11 +48,30 @@ impl BuffSystem {
         _services: &ServiceContext,
         resources: &mut ResourceContext,
     ) {
+        self.process_real_time(resources).await;
         self.process_buff_apply_requests(resources).await;
         self.process_buff_remove_requests(resources).await;
         self.process_buff_tick_requests(resources).await;
     }
 
+    /// Count down real-time buffs
+    ///
+    /// Runs before new buffs are applied, so a buff applied this frame does
+    /// not lose the time that passed before it existed.
+    async fn process_real_time(&mut self, resources: &mut ResourceContext) {
+        let elapsed = match resources.get::<GameTimer>().await {
+            Some(timer) => timer.elapsed,
+            None => return,
+        };
+
+        let expired_buffs = match resources.get_mut::<ActiveBuffs>().await {
+            Some(mut buffs) => buffs.advance_clock(elapsed),
+            None => return,
+        };
+
+        self.expire_buffs(expired_buffs, resources).await;
+    }
+
     /// Process buff apply requests
     async fn process_buff_apply_requests(&mut self, resources: &mut ResourceContext) {
         // Collect buff apply requests
@@ -69,10 +94,13 @@ impl BuffSystem {
                 }
             };
 
-            let buff_config = match buff_config {
+            let mut buff_config = match buff_config {
                 Some(config) => config,
                 None => continue, // Buff not found in database
             };
+            if let Some(duration) = request.duration.clone() {
+                buff_config.duration = duration;
+            }
 
             // Create active buff
             let active_buff = ActiveBuff::new(buff_config);
@@ -148,47 +176,48 @@ impl BuffSystem {
         let requests = {
             if let Some(mut bus) = resources.get_mut::<EventBus>().await {
                 let reader = bus.reader::<BuffTickRequested>();
-                reader.iter().count() // Just count, we don't need the actual events
-            } else {
-                0
-            }
-        };
-
-        if requests == 0 {
-            return;
-        }
-
-        // Get current buffs for ticking
-        let current_buffs = {
-            if let Some(buffs) = resources.get::<ActiveBuffs>().await {
-                buffs.buffs.clone()
+                reader.iter().cloned().collect::<Vec<_>>()
             } else {
                 Vec::new()
             }
         };
 
-        // Call hook for each buff
-        for buff in &current_buffs {
-            self.hook.on_buff_tick(buff, resources).await;
-        }
-
-        // Tick and remove expired buffs
-        let mut expired_buffs = Vec::new();
-
-        {
-            if let Some(mut buffs) = resources.get_mut::<ActiveBuffs>().await {
-                for buff in &mut buffs.buffs {
-                    buff.tick();
-                    if buff.is_expired() {
-                        expired_buffs.push(buff.clone());
-                    }
+        for request in requests {
+            // Get the buffs this context ticks
+            let ticked_buffs = {
+                if let Some(buffs) = resources.get::<ActiveBuffs>().await {
+                    buffs
+                        .buffs
+                        .iter()
+                        .filter(|buff| buff.config.ticks_on(request.context))
+                        .cloned()
+                        .collect::<Vec<_>>()
+                } else {
+                    Vec::new()
                 }
+            };
 
-                buffs.buffs.retain(|buff| !buff.is_expired());
+            // Call hook for each buff
+            for buff in &ticked_buffs {
+                self.hook.on_buff_tick(buff, resources).await;
             }
+
+            // Tick and remove expired buffs
+            let expired_buffs = match resources.get_mut::<ActiveBuffs>().await {
+                Some(mut buffs) => buffs.tick(request.context),
+                None => Vec::new(),
+            };
+
+            self.expire_buffs(expired_buffs, resources).await;
         }
+    }
 
-        // Call hook and publish events for expired buffs
+    /// Call hook and publish events for expired buffs
+    async fn expire_buffs(
+        &mut self,
+        expired_buffs: Vec<ActiveBuff>,
+        resources: &mut ResourceContext,
+    ) {
         for buff in expired_buffs {
             self.hook.on_buff_expired(&buff, resources).await;
 
@@ -221,3 +250,239 @@ impl System for BuffSystem {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::room_buff::types::{BuffConfig, BuffDuration, BuffEffect, TickContext};
+    use std::time::Duration;
+
+    fn buff(id: &str, duration: BuffDuration, tick_on: Vec<TickContext>) -> BuffConfig {
+        BuffConfig {
+            id: id.to_string(),
+            name: id.to_string(),
+            duration,
+            effect: BuffEffect::AttackBonus(1),
+            tick_on,
+        }
+    }
+
+    fn setup(buffs: Vec<BuffConfig>) -> ResourceContext {
+        let mut database = RoomBuffDatabase::new();
+        for config in buffs {
+            database = database.with_buff(config.id.clone(), config);
+        }
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(GameTimer::new());
+        resources.insert(database);
+        resources.insert(ActiveBuffs::new());
+        resources
+    }
+
+    async fn publish<E: crate::event::Event + serde::Serialize>(
+        resources: &ResourceContext,
+        event: E,
+    ) {
+        resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .publish(event);
+    }
+
+    async fn apply(resources: &ResourceContext, buff_id: &str) {
+        publish(
+            resources,
+            BuffApplyRequested {
+                buff_id: buff_id.to_string(),
+                duration: None,
+            },
+        )
+        .await;
+    }
+
+    async fn tick(resources: &ResourceContext, context: TickContext) {
+        publish(resources, BuffTickRequested::new(context)).await;
+    }
+
+    async fn advance(resources: &ResourceContext, real_secs: u64) {
+        resources
+            .get_mut::<GameTimer>()
+            .await
+            .unwrap()
+            .advance(Duration::from_secs(real_secs));
+    }
+
+    /// Run one update; returns the ids of buffs that expired in it
+    async fn frame(system: &mut BuffSystem, resources: &mut ResourceContext) -> Vec<String> {
+        resources.get_mut::<EventBus>().await.unwrap().dispatch();
+        system
+            .process_events(&ServiceContext::new(), resources)
+            .await;
+
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        bus.reader::<BuffExpiredEvent>()
+            .iter()
+            .map(|event| event.buff_id.clone())
+            .collect()
+    }
+
+    async fn remaining_turns(resources: &ResourceContext, buff_id: &str) -> Option<u32> {
+        let buffs = resources.get::<ActiveBuffs>().await.unwrap();
+        buffs.find(buff_id).and_then(|buff| buff.remaining_turns)
+    }
+
+    async fn remaining_time(resources: &ResourceContext, buff_id: &str) -> Option<Duration> {
+        let buffs = resources.get::<ActiveBuffs>().await.unwrap();
+        buffs.find(buff_id).and_then(|buff| buff.remaining_time)
+    }
+
+    #[tokio::test]
+    async fn test_real_time_expiry_honors_pause_and_scale() {
+        let mut resources = setup(vec![buff(
+            "potion",
+            BuffDuration::RealTime(Duration::from_secs(60)),
+            vec![],
+        )]);
+        let mut system = BuffSystem::default();
+
+        apply(&resources, "potion").await;
+        assert!(frame(&mut system, &mut resources).await.is_empty());
+
+        advance(&resources, 20).await;
+        frame(&mut system, &mut resources).await;
+        assert_eq!(
+            remaining_time(&resources, "potion").await,
+            Some(Duration::from_secs(40))
+        );
+
+        // Paused: real time passes, the buff does not
+        resources.get_mut::<GameTimer>().await.unwrap().pause();
+        advance(&resources, 120).await;
+        assert!(frame(&mut system, &mut resources).await.is_empty());
+        assert_eq!(
+            remaining_time(&resources, "potion").await,
+            Some(Duration::from_secs(40))
+        );
+
+        // Double speed: 15 real seconds are 30 game seconds
+        {
+            let mut timer = resources.get_mut::<GameTimer>().await.unwrap();
+            timer.resume();
+            timer.set_time_scale(2.0);
+        }
+        advance(&resources, 15).await;
+        frame(&mut system, &mut resources).await;
+        assert_eq!(
+            remaining_time(&resources, "potion").await,
+            Some(Duration::from_secs(10))
+        );
+        let gauge = resources
+            .get::<ActiveBuffs>()
+            .await
+            .unwrap()
+            .find("potion")
+            .unwrap()
+            .remaining_fraction()
+            .unwrap();
+        assert!((gauge - 1.0 / 6.0).abs() < 1e-6);
+
+        advance(&resources, 5).await;
+        assert_eq!(frame(&mut system, &mut resources).await, vec!["potion"]);
+
+        advance(&resources, 5).await;
+        assert!(frame(&mut system, &mut resources).await.is_empty());
+        assert!(resources.get::<ActiveBuffs>().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_combat_buff_survives_suspend_and_resume() {
+        let mut resources = setup(vec![buff(
+            "battle_cry",
+            BuffDuration::Turns(3),
+            vec![TickContext::CombatTurn],
+        )]);
+        let mut system = BuffSystem::default();
+
+        apply(&resources, "battle_cry").await;
+        tick(&resources, TickContext::CombatTurn).await;
+        frame(&mut system, &mut resources).await;
+        assert_eq!(remaining_turns(&resources, "battle_cry").await, Some(2));
+
+        // Combat scene suspended: only world turns pass
+        for _ in 0..4 {
+            tick(&resources, TickContext::WorldTurn).await;
+            assert!(frame(&mut system, &mut resources).await.is_empty());
+        }
+        assert_eq!(remaining_turns(&resources, "battle_cry").await, Some(2));
+
+        // Resumed: two requests in one update are two turns
+        tick(&resources, TickContext::CombatTurn).await;
+        tick(&resources, TickContext::CombatTurn).await;
+        assert_eq!(frame(&mut system, &mut resources).await, vec!["battle_cry"]);
+
+        tick(&resources, TickContext::CombatTurn).await;
+        assert!(frame(&mut system, &mut resources).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mixed_context_buffs_coexist() {
+        let mut resources = setup(vec![
+            buff(
+                "combat",
+                BuffDuration::Turns(2),
+                vec![TickContext::CombatTurn],
+            ),
+            buff(
+                "world",
+                BuffDuration::Turns(2),
+                vec![TickContext::WorldTurn],
+            ),
+            buff("any_turn", BuffDuration::Turns(4), vec![]),
+            buff(
+                "potion",
+                BuffDuration::RealTime(Duration::from_secs(10)),
+                vec![],
+            ),
+            buff("blessing", BuffDuration::Permanent, vec![]),
+        ]);
+        let mut system = BuffSystem::default();
+
+        for id in ["combat", "world", "any_turn", "potion", "blessing"] {
+            apply(&resources, id).await;
+        }
+        frame(&mut system, &mut resources).await;
+
+        tick(&resources, TickContext::WorldTurn).await;
+        advance(&resources, 4).await;
+        assert!(frame(&mut system, &mut resources).await.is_empty());
+        assert_eq!(remaining_turns(&resources, "combat").await, Some(2));
+        assert_eq!(remaining_turns(&resources, "world").await, Some(1));
+        assert_eq!(remaining_turns(&resources, "any_turn").await, Some(3));
+        assert_eq!(
+            remaining_time(&resources, "potion").await,
+            Some(Duration::from_secs(6))
+        );
+
+        // A real-time tick counts down no turn buff
+        tick(&resources, TickContext::RealTime).await;
+        tick(&resources, TickContext::CombatTurn).await;
+        tick(&resources, TickContext::WorldTurn).await;
+        let mut expired = frame(&mut system, &mut resources).await;
+        expired.sort();
+        assert_eq!(expired, vec!["world"]);
+        assert_eq!(remaining_turns(&resources, "combat").await, Some(1));
+        assert_eq!(remaining_turns(&resources, "any_turn").await, Some(1));
+
+        advance(&resources, 6).await;
+        assert_eq!(frame(&mut system, &mut resources).await, vec!["potion"]);
+
+        let buffs = resources.get::<ActiveBuffs>().await.unwrap();
+        let mut ids: Vec<&str> = buffs.buffs.iter().map(|b| b.config.id.as_str()).collect();
+        ids.sort();
+        assert_eq!(ids, vec!["any_turn", "blessing", "combat"]);
+    }
+}
@@ -3,6 +3,7 @@
 use crate::state::State;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// Buff configuration database
 ///
@@ -42,6 +43,25 @@ pub struct BuffConfig {
     pub name: String,
     pub duration: BuffDuration,
     pub effect: BuffEffect,
+    /// Tick contexts that tick this buff (empty: see [`BuffConfig::ticks_on`])
+    #[serde(default)]
+    pub tick_on: Vec<TickContext>,
+}
+
+impl BuffConfig {
+    /// Whether a tick in `context` ticks this buff
+    ///
+    /// Without explicit `tick_on` contexts, real-time buffs are ticked by
+    /// real-time ticks and every other buff by any turn (combat or world).
+    pub fn ticks_on(&self, context: TickContext) -> bool {
+        if !self.tick_on.is_empty() {
+            return self.tick_on.contains(&context);
+        }
+        match self.duration {
+            BuffDuration::RealTime(_) => context == TickContext::RealTime,
+            _ => context != TickContext::RealTime,
+        }
+    }
 }
 
 /// Buff duration
@@ -53,6 +73,23 @@ pub enum BuffDuration {
     UntilRoomExit,
     /// N turns
     Turns(u32),
+    /// Game time as measured by `GameTimer` (stops while paused, follows time scale)
+    RealTime(Duration),
+}
+
+/// What a buff tick stands for
+///
+/// Buffs only count down on the contexts they are ticked by, so a combat
+/// buff stays frozen while world turns pass with the combat scene suspended.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TickContext {
+    /// A combat turn
+    CombatTurn,
+    /// A world/exploration turn
+    #[default]
+    WorldTurn,
+    /// A real-time tick (per-tick effects of real-time buffs)
+    RealTime,
 }
 
 /// Buff effect types
@@ -72,6 +109,9 @@ pub enum BuffEffect {
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ActiveBuffs {
     pub buffs: Vec<ActiveBuff>,
+    /// `GameTimer::elapsed` when real-time buffs were last advanced
+    #[serde(default)]
+    pub clock: Option<Duration>,
 }
 
 impl State for ActiveBuffs {}
@@ -85,6 +125,47 @@ impl ActiveBuffs {
         self.buffs.push(buff);
     }
 
+    /// First active buff with the given id
+    pub fn find(&self, buff_id: &str) -> Option<&ActiveBuff> {
+        self.buffs.iter().find(|buff| buff.config.id == buff_id)
+    }
+
+    /// Tick every buff ticked by `context`, returning the buffs that expired
+    pub fn tick(&mut self, context: TickContext) -> Vec<ActiveBuff> {
+        for buff in &mut self.buffs {
+            if buff.config.ticks_on(context) {
+                buff.tick();
+            }
+        }
+        self.take_expired()
+    }
+
+    /// Advance real-time buffs to the given `GameTimer::elapsed`
+    ///
+    /// Only the game time since the previous call counts; the first call just
+    /// starts the clock. Returns the buffs that expired.
+    pub fn advance_clock(&mut self, elapsed: Duration) -> Vec<ActiveBuff> {
+        let delta = elapsed.saturating_sub(self.clock.unwrap_or(elapsed));
+        self.clock = Some(elapsed);
+        if delta.is_zero() {
+            return Vec::new();
+        }
+
+        for buff in &mut self.buffs {
+            buff.tick_time(delta);
+        }
+        self.take_expired()
+    }
+
+    /// Remove and return expired buffs
+    fn take_expired(&mut self) -> Vec<ActiveBuff> {
+        let (expired, active) = std::mem::take(&mut self.buffs)
+            .into_iter()
+            .partition(|buff| buff.is_expired());
+        self.buffs = active;
+        expired
+    }
+
     pub fn clear_room_buffs(&mut self) {
         self.buffs
             .retain(|buff| !matches!(buff.config.duration, BuffDuration::UntilRoomExit));
@@ -104,6 +185,9 @@ impl ActiveBuffs {
 pub struct ActiveBuff {
     pub config: BuffConfig,
     pub remaining_turns: Option<u32>,
+    /// Game time left for `BuffDuration::RealTime` buffs
+    #[serde(default)]
+    pub remaining_time: Option<Duration>,
 }
 
 impl ActiveBuff {
@@ -112,15 +196,20 @@ impl ActiveBuff {
             BuffDuration::Turns(n) => Some(n),
             _ => None,
         };
+        let remaining_time = match config.duration {
+            BuffDuration::RealTime(duration) => Some(duration),
+            _ => None,
+        };
 
         Self {
             config,
             remaining_turns,
+            remaining_time,
         }
     }
 
     pub fn is_expired(&self) -> bool {
-        self.remaining_turns == Some(0)
+        self.remaining_turns == Some(0) || self.remaining_time == Some(Duration::ZERO)
     }
 
     pub fn tick(&mut self) {
@@ -128,4 +217,104 @@ impl ActiveBuff {
             *turns = turns.saturating_sub(1);
         }
     }
+
+    /// Count down a real-time buff by `delta` of game time
+    pub fn tick_time(&mut self, delta: Duration) {
+        if let Some(remaining) = self.remaining_time.as_mut() {
+            *remaining = remaining.saturating_sub(delta);
+        }
+    }
+
+    /// Remaining share of the duration (1.0 = full, 0.0 = expired), for UI gauges
+    ///
+    /// `None` for buffs without a finite duration.
+    pub fn remaining_fraction(&self) -> Option<f32> {
+        match self.config.duration {
+            BuffDuration::Turns(total) => {
+                let remaining = self.remaining_turns?;
+                Some(if total == 0 {
+                    0.0
+                } else {
+                    remaining as f32 / total as f32
+                })
+            }
+            BuffDuration::RealTime(total) => {
+                let remaining = self.remaining_time?;
+                Some(if total.is_zero() {
+                    0.0
+                } else {
+                    remaining.as_secs_f32() / total.as_secs_f32()
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(id: &str, duration: BuffDuration, tick_on: Vec<TickContext>) -> BuffConfig {
+        BuffConfig {
+            id: id.to_string(),
+            name: id.to_string(),
+            duration,
+            effect: BuffEffect::AttackBonus(1),
+            tick_on,
+        }
+    }
+
+    #[test]
+    fn test_default_tick_contexts() {
+        let turns = config("t", BuffDuration::Turns(2), vec![]);
+        assert!(turns.ticks_on(TickContext::CombatTurn));
+        assert!(turns.ticks_on(TickContext::WorldTurn));
+        assert!(!turns.ticks_on(TickContext::RealTime));
+
+        let timed = config("r", BuffDuration::RealTime(Duration::from_secs(1)), vec![]);
+        assert!(timed.ticks_on(TickContext::RealTime));
+        assert!(!timed.ticks_on(TickContext::WorldTurn));
+
+        let combat = config("c", BuffDuration::Turns(2), vec![TickContext::CombatTurn]);
+        assert!(!combat.ticks_on(TickContext::WorldTurn));
+    }
+
+    #[test]
+    fn test_remaining_fraction() {
+        let mut buff = ActiveBuff::new(config(
+            "potion",
+            BuffDuration::RealTime(Duration::from_secs(60)),
+            vec![],
+        ));
+        assert_eq!(buff.remaining_fraction(), Some(1.0));
+
+        buff.tick_time(Duration::from_secs(15));
+        assert_eq!(buff.remaining_fraction(), Some(0.75));
+        assert_eq!(buff.remaining_time, Some(Duration::from_secs(45)));
+
+        let permanent = ActiveBuff::new(config("p", BuffDuration::Permanent, vec![]));
+        assert_eq!(permanent.remaining_fraction(), None);
+    }
+
+    #[test]
+    fn test_advance_clock_starts_on_first_call() {
+        let mut buffs = ActiveBuffs::new();
+        buffs.add(ActiveBuff::new(config(
+            "potion",
+            BuffDuration::RealTime(Duration::from_secs(10)),
+            vec![],
+        )));
+
+        assert!(buffs.advance_clock(Duration::from_secs(100)).is_empty());
+        assert_eq!(
+            buffs.find("potion").unwrap().remaining_time,
+            Some(Duration::from_secs(10))
+        );
+
+        assert!(buffs.advance_clock(Duration::from_secs(104)).is_empty());
+        let expired = buffs.advance_clock(Duration::from_secs(120));
+        assert_eq!(expired.len(), 1);
+        assert!(buffs.is_empty());
+    }
 }
@@ -0,0 +1,72 @@
+//! Run summary configuration (ReadOnly, moddable weights)
+
+use crate::resources::Resource;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Score formula for the run summary
+///
+/// The score is `Σ weight(key) × value(key)` over every summary entry.
+/// Entries without a weight do not count. MODs can change a weight with
+/// `set_plugin_param("run_summary", "<key>", <weight>)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSummaryConfig {
+    /// Contribution key (e.g. `"combat.battles_won"`) → weight
+    pub weights: HashMap<String, f64>,
+}
+
+impl Resource for RunSummaryConfig {}
+
+impl Default for RunSummaryConfig {
+    fn default() -> Self {
+        let weights = [
+            ("combat.battles_won", 100.0),
+            ("combat.score", 1.0),
+            ("loot.items", 5.0),
+            ("dungeon.deepest_floor", 250.0),
+        ];
+        Self {
+            weights: weights
+                .into_iter()
+                .map(|(key, weight)| (key.to_string(), weight))
+                .collect(),
+        }
+    }
+}
+
+impl RunSummaryConfig {
+    /// Start from an empty formula (nothing scores)
+    pub fn empty() -> Self {
+        Self {
+            weights: HashMap::new(),
+        }
+    }
+
+    /// Set the weight of a contribution
+    pub fn with_weight(mut self, key: impl Into<String>, weight: f64) -> Self {
+        self.weights.insert(key.into(), weight);
+        self
+    }
+
+    /// Weight of a contribution (0 if unset)
+    pub fn weight(&self, key: &str) -> f64 {
+        self.weights.get(key).copied().unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weights() {
+        let config = RunSummaryConfig::empty().with_weight("dungeon.deepest_floor", 10.0);
+
+        assert_eq!(config.weight("dungeon.deepest_floor"), 10.0);
+        assert_eq!(config.weight("combat.battles_won"), 0.0);
+        assert_eq!(
+            RunSummaryConfig::default().weight("combat.battles_won"),
+            100.0
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Summary contributors: per-plugin sections of the end-of-run recap
+//!
+//! Each contributor watches the run while it is in progress (`observe`) and
+//! turns what it saw into a [`SummarySection`] when the run ends
+//! (`contribute`). Contributors for plugins that are not part of the build
+//! return `None` and their section is simply left out.
+
+use super::types::SummarySection;
+use crate::context::ResourceContext;
+use crate::event::EventBus;
+use crate::plugin::combat::{CombatConfig, CombatEndedEvent, CombatResult};
+use crate::plugin::dungeon::DungeonState;
+use crate::plugin::economy::Wallet;
+use crate::plugin::loot::{LootConfig, LootGeneratedEvent, Rarity};
+use crate::plugin::time::GameTimer;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// Source of one section of the run summary
+#[async_trait]
+pub trait SummaryContributor: Send + Sync {
+    /// Section id, also the prefix of every contribution key
+    fn section_id(&self) -> &'static str;
+
+    /// Called every update while the run is in progress
+    ///
+    /// Use this to accumulate statistics from events that would otherwise
+    /// be gone by the time the run ends.
+    async fn observe(&mut self, _resources: &ResourceContext) {}
+
+    /// Build this contributor's section, or `None` to omit it
+    async fn contribute(&self, resources: &ResourceContext) -> Option<SummarySection>;
+
+    /// Forget everything observed so far (called after each summary)
+    fn reset(&mut self) {}
+
+    /// Clone this contributor (for dynamic dispatch)
+    fn clone_box(&self) -> Box<dyn SummaryContributor>;
+}
+
+impl Clone for Box<dyn SummaryContributor> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+async fn collect<E: crate::event::Event + Clone>(resources: &ResourceContext) -> Vec<E> {
+    if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+        bus.reader::<E>().iter().cloned().collect()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Battles fought/won, bosses defeated, turns and combat score (from `CombatEndedEvent`)
+#[derive(Debug, Clone, Default)]
+pub struct CombatContributor {
+    battles: u32,
+    won: u32,
+    bosses: u32,
+    turns: u64,
+    score: u64,
+}
+
+#[async_trait]
+impl SummaryContributor for CombatContributor {
+    fn section_id(&self) -> &'static str {
+        "combat"
+    }
+
+    async fn observe(&mut self, resources: &ResourceContext) {
+        for event in collect::<CombatEndedEvent>(resources).await {
+            self.battles += 1;
+            if event.result == CombatResult::Victory {
+                self.won += 1;
+                if event.boss.is_some() {
+                    self.bosses += 1;
+                }
+            }
+            self.turns += event.total_turns as u64;
+            self.score += event.score as u64;
+        }
+    }
+
+    async fn contribute(&self, resources: &ResourceContext) -> Option<SummarySection> {
+        if !resources.contains::<CombatConfig>() {
+            return None;
+        }
+        Some(
+            SummarySection::new(self.section_id(), "Combat")
+                .with_entry("battles_fought", "Battles fought", self.battles as f64)
+                .with_entry("battles_won", "Battles won", self.won as f64)
+                .with_entry("bosses_defeated", "Bosses defeated", self.bosses as f64)
+                .with_entry("turns", "Turns in combat", self.turns as f64)
+                .with_entry("score", "Combat score", self.score as f64),
+        )
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn clone_box(&self) -> Box<dyn SummaryContributor> {
+        Box::new(self.clone())
+    }
+}
+
+/// Items looted, in total and per rarity (from `LootGeneratedEvent`)
+#[derive(Debug, Clone, Default)]
+pub struct LootContributor {
+    by_rarity: HashMap<Rarity, u32>,
+}
+
+#[async_trait]
+impl SummaryContributor for LootContributor {
+    fn section_id(&self) -> &'static str {
+        "loot"
+    }
+
+    async fn observe(&mut self, resources: &ResourceContext) {
+        for event in collect::<LootGeneratedEvent>(resources).await {
+            *self.by_rarity.entry(event.rarity).or_default() += event.items.len() as u32;
+        }
+    }
+
+    async fn contribute(&self, resources: &ResourceContext) -> Option<SummarySection> {
+        if !resources.contains::<LootConfig>() {
+            return None;
+        }
+        let total: u32 = self.by_rarity.values().sum();
+        let mut section = SummarySection::new(self.section_id(), "Loot").with_entry(
+            "items",
+            "Items",
+            total as f64,
+        );
+        for rarity in Rarity::all() {
+            let name = format!("{:?}", rarity).to_lowercase();
+            let count = self.by_rarity.get(&rarity).copied().unwrap_or(0);
+            section = section.with_entry(&name, format!("{:?}", rarity), count as f64);
+        }
+        Some(section)
+    }
+
+    fn reset(&mut self) {
+        self.by_rarity.clear();
+    }
+
+    fn clone_box(&self) -> Box<dyn SummaryContributor> {
+        Box::new(self.clone())
+    }
+}
+
+/// Deepest floor reached and rooms visited (from `DungeonState`)
+#[derive(Debug, Clone, Default)]
+pub struct DungeonContributor {
+    deepest: u32,
+}
+
+impl DungeonContributor {
+    async fn current_floor(resources: &ResourceContext) -> Option<u32> {
+        resources
+            .get::<DungeonState>()
+            .await
+            .map(|state| state.current_floor)
+    }
+}
+
+#[async_trait]
+impl SummaryContributor for DungeonContributor {
+    fn section_id(&self) -> &'static str {
+        "dungeon"
+    }
+
+    async fn observe(&mut self, resources: &ResourceContext) {
+        if let Some(floor) = Self::current_floor(resources).await {
+            self.deepest = self.deepest.max(floor);
+        }
+    }
+
+    async fn contribute(&self, resources: &ResourceContext) -> Option<SummarySection> {
+        let state = resources.get::<DungeonState>().await?;
+        let deepest = self.deepest.max(state.current_floor);
+        Some(
+            SummarySection::new(self.section_id(), "Dungeon")
+                .with_entry("deepest_floor", "Deepest floor", deepest as f64)
+                .with_entry(
+                    "rooms_visited",
+                    "Rooms visited",
+                    state.visited_rooms.len() as f64,
+                ),
+        )
+    }
+
+    fn reset(&mut self) {
+        self.deepest = 0;
+    }
+
+    fn clone_box(&self) -> Box<dyn SummaryContributor> {
+        Box::new(self.clone())
+    }
+}
+
+/// Net worth across all currencies (from the economy `Wallet`)
+#[derive(Debug, Clone, Default)]
+pub struct EconomyContributor;
+
+#[async_trait]
+impl SummaryContributor for EconomyContributor {
+    fn section_id(&self) -> &'static str {
+        "economy"
+    }
+
+    async fn contribute(&self, resources: &ResourceContext) -> Option<SummarySection> {
+        let wallet = resources.get::<Wallet>().await?;
+        let net_worth: i64 = wallet.values().map(|currency| currency.amount()).sum();
+        Some(
+            SummarySection::new(self.section_id(), "Economy").with_entry(
+                "net_worth",
+                "Net worth",
+                net_worth as f64,
+            ),
+        )
+    }
+
+    fn clone_box(&self) -> Box<dyn SummaryContributor> {
+        Box::new(self.clone())
+    }
+}
+
+/// Days and ticks elapsed (from `GameTimer`)
+#[derive(Debug, Clone, Default)]
+pub struct TimeContributor;
+
+#[async_trait]
+impl SummaryContributor for TimeContributor {
+    fn section_id(&self) -> &'static str {
+        "time"
+    }
+
+    async fn contribute(&self, resources: &ResourceContext) -> Option<SummarySection> {
+        let timer = resources.get::<GameTimer>().await?;
+        Some(
+            SummarySection::new(self.section_id(), "Time")
+                .with_entry("days", "Days elapsed", timer.day as f64)
+                .with_entry("ticks", "Ticks elapsed", timer.tick as f64),
+        )
+    }
+
+    fn clone_box(&self) -> Box<dyn SummaryContributor> {
+        Box::new(self.clone())
+    }
+}
+
+/// The built-in contributors, in recap order
+pub fn default_contributors() -> Vec<Box<dyn SummaryContributor>> {
+    vec![
+        Box::new(CombatContributor::default()),
+        Box::new(DungeonContributor::default()),
+        Box::new(LootContributor::default()),
+        Box::new(EconomyContributor),
+        Box::new(TimeContributor),
+    ]
+}
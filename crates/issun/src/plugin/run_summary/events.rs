@@ -0,0 +1,47 @@
+//! Run summary events
+
+use super::types::{RunSummary, ScoreComponent, SummarySection};
+use crate::event::Event;
+use serde::{Deserialize, Serialize};
+
+// =============================================================================
+// Command Events (Request)
+// =============================================================================
+
+/// The run is over (victory or defeat)
+///
+/// Published by the game (or a victory-condition plugin) when the run ends;
+/// the summary system answers with [`RunSummaryReady`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameEndedEvent {
+    pub victory: bool,
+    pub reason: String,
+}
+
+impl Event for GameEndedEvent {}
+
+// =============================================================================
+// State Events (Notification)
+// =============================================================================
+
+/// The end-of-run recap was assembled
+///
+/// The same data is stored as the [`RunSummary`] resource.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSummaryReady {
+    pub sections: Vec<SummarySection>,
+    pub score: f64,
+    pub breakdown: Vec<ScoreComponent>,
+}
+
+impl Event for RunSummaryReady {}
+
+impl From<&RunSummary> for RunSummaryReady {
+    fn from(summary: &RunSummary) -> Self {
+        Self {
+            sections: summary.sections.clone(),
+            score: summary.score,
+            breakdown: summary.breakdown.clone(),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Hook trait for run summary customization
+
+use super::types::RunSummary;
+use crate::context::ResourceContext;
+use async_trait::async_trait;
+
+/// Hook for reacting to the end-of-run recap
+///
+/// Use this to feed the summary into lifetime statistics, unlock
+/// achievements, or upload a leaderboard entry.
+#[async_trait]
+pub trait RunSummaryHook: Send + Sync {
+    /// Called after the summary is stored and `RunSummaryReady` is published
+    async fn on_summary_ready(&self, _summary: &RunSummary, _resources: &mut ResourceContext) {
+        // Default: no-op
+    }
+}
+
+/// Default hook that does nothing
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultRunSummaryHook;
+
+#[async_trait]
+impl RunSummaryHook for DefaultRunSummaryHook {}
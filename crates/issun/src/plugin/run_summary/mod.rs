@@ -0,0 +1,49 @@
+//! Run summary plugin
+//!
+//! Assembles an end-of-run recap (score breakdown and stats per plugin) when
+//! the game publishes [`GameEndedEvent`].
+//!
+//! # Overview
+//!
+//! - [`SummaryContributor`]s observe the run and each produce one section
+//! - A weighted score formula over named contributions ([`RunSummaryConfig`]);
+//!   MODs can change weights with `set_plugin_param("run_summary", key, weight)`
+//! - The result is published as [`RunSummaryReady`] and stored as the
+//!   [`RunSummary`] resource for the result scene
+//! - [`RunSummaryHook`] lets games feed the summary into lifetime statistics
+//!
+//! # Usage Example
+//!
+//! ```ignore
+//! use issun::plugin::run_summary::{GameEndedEvent, RunSummaryPlugin};
+//!
+//! let game = GameBuilder::new()
+//!     .with_plugin(RunSummaryPlugin::new())?
+//!     .build()
+//!     .await?;
+//!
+//! // When the run is over
+//! bus.publish(GameEndedEvent {
+//!     victory: true,
+//!     reason: "boss defeated".to_string(),
+//! });
+//! ```
+
+mod config;
+mod contributor;
+mod events;
+mod hook;
+mod plugin;
+mod system;
+mod types;
+
+pub use config::RunSummaryConfig;
+pub use contributor::{
+    default_contributors, CombatContributor, DungeonContributor, EconomyContributor,
+    LootContributor, SummaryContributor, TimeContributor,
+};
+pub use events::*;
+pub use hook::{DefaultRunSummaryHook, RunSummaryHook};
+pub use plugin::RunSummaryPlugin;
+pub use system::{score, RunSummarySystem};
+pub use types::{RunSummary, ScoreComponent, SummaryEntry, SummarySection};
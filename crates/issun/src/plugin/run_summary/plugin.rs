@@ -0,0 +1,122 @@
+//! Run summary plugin implementation
+
+use super::config::RunSummaryConfig;
+use super::contributor::{default_contributors, SummaryContributor};
+use super::hook::{DefaultRunSummaryHook, RunSummaryHook};
+use super::system::RunSummarySystem;
+use crate::plugin::{Plugin, PluginBuilder, PluginBuilderExt};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// End-of-run recap plugin
+///
+/// Registers `RunSummaryConfig` and `RunSummarySystem`. When the game
+/// publishes `GameEndedEvent`, the system asks every contributor for its
+/// section, scores the result, stores it as the `RunSummary` resource and
+/// publishes `RunSummaryReady`.
+///
+/// The built-in contributors cover combat, dungeon, loot, economy and time.
+/// Sections of plugins that are not part of the build are left out.
+///
+/// # Example
+///
+/// ```ignore
+/// use issun::plugin::run_summary::{RunSummaryPlugin, RunSummaryConfig};
+///
+/// let game = GameBuilder::new()
+///     .with_plugin(
+///         RunSummaryPlugin::new()
+///             .with_config(RunSummaryConfig::default().with_weight("economy.net_worth", 0.1))
+///             .with_contributor(MyQuestContributor::default()),
+///     )?
+///     .build()
+///     .await?;
+///
+/// // Later, in the result scene
+/// if let Some(summary) = resources.get::<RunSummary>().await {
+///     RecapWidget::new(&summary).with_theme(&theme).render(frame, area);
+/// }
+/// ```
+pub struct RunSummaryPlugin {
+    hook: Arc<dyn RunSummaryHook>,
+    config: RunSummaryConfig,
+    contributors: Vec<Box<dyn SummaryContributor>>,
+}
+
+impl RunSummaryPlugin {
+    /// Create a plugin with the built-in contributors and default weights
+    pub fn new() -> Self {
+        Self {
+            hook: Arc::new(DefaultRunSummaryHook),
+            config: RunSummaryConfig::default(),
+            contributors: default_contributors(),
+        }
+    }
+
+    /// Add a custom hook (e.g. to feed lifetime statistics)
+    pub fn with_hook<H: RunSummaryHook + 'static>(mut self, hook: H) -> Self {
+        self.hook = Arc::new(hook);
+        self
+    }
+
+    /// Set the score formula
+    pub fn with_config(mut self, config: RunSummaryConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Add a contributor after the built-in ones
+    pub fn with_contributor(mut self, contributor: impl SummaryContributor + 'static) -> Self {
+        self.contributors.push(Box::new(contributor));
+        self
+    }
+
+    /// Drop the built-in contributors
+    pub fn without_default_contributors(mut self) -> Self {
+        self.contributors.clear();
+        self
+    }
+}
+
+impl Default for RunSummaryPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Plugin for RunSummaryPlugin {
+    fn name(&self) -> &'static str {
+        "issun:run_summary"
+    }
+
+    fn build(&self, builder: &mut dyn PluginBuilder) {
+        builder.register_resource(self.config.clone());
+        builder.register_system(Box::new(RunSummarySystem::new(
+            self.hook.clone(),
+            self.contributors.clone(),
+        )));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::run_summary::contributor::TimeContributor;
+
+    #[test]
+    fn test_plugin_creation() {
+        let plugin = RunSummaryPlugin::new();
+        assert_eq!(plugin.name(), "issun:run_summary");
+        assert_eq!(plugin.contributors.len(), 5);
+    }
+
+    #[test]
+    fn test_custom_contributors() {
+        let plugin = RunSummaryPlugin::new()
+            .without_default_contributors()
+            .with_contributor(TimeContributor);
+        assert_eq!(plugin.contributors.len(), 1);
+        assert_eq!(plugin.contributors[0].section_id(), "time");
+    }
+}
@@ -0,0 +1,304 @@
+//! Run summary system implementation
+
+use super::config::RunSummaryConfig;
+use super::contributor::SummaryContributor;
+use super::events::{GameEndedEvent, RunSummaryReady};
+use super::hook::RunSummaryHook;
+use super::types::{RunSummary, ScoreComponent, SummarySection};
+use crate::context::{ResourceContext, ServiceContext};
+use crate::event::EventBus;
+use crate::system::System;
+use async_trait::async_trait;
+use std::any::Any;
+use std::sync::Arc;
+
+/// System that assembles the end-of-run recap
+///
+/// This system:
+/// 1. Lets every contributor observe the current frame
+/// 2. On `GameEndedEvent`, collects the contributors' sections
+/// 3. Scores them with the weights from `RunSummaryConfig`
+/// 4. Stores the `RunSummary` resource, publishes `RunSummaryReady` and calls the hook
+#[derive(Clone)]
+pub struct RunSummarySystem {
+    hook: Arc<dyn RunSummaryHook>,
+    contributors: Vec<Box<dyn SummaryContributor>>,
+}
+
+impl RunSummarySystem {
+    /// Create a new RunSummarySystem with a hook and contributors
+    pub fn new(
+        hook: Arc<dyn RunSummaryHook>,
+        contributors: Vec<Box<dyn SummaryContributor>>,
+    ) -> Self {
+        Self { hook, contributors }
+    }
+
+    /// Process all run summary events
+    pub async fn process_events(
+        &mut self,
+        _services: &ServiceContext,
+        resources: &mut ResourceContext,
+    ) {
+        for contributor in &mut self.contributors {
+            contributor.observe(resources).await;
+        }
+
+        let ended = {
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                let reader = bus.reader::<GameEndedEvent>();
+                reader.iter().last().cloned()
+            } else {
+                None
+            }
+        };
+
+        if let Some(event) = ended {
+            let summary = self.summarize(&event, resources).await;
+
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.publish(RunSummaryReady::from(&summary));
+            }
+            resources.insert(summary.clone());
+            self.hook.on_summary_ready(&summary, resources).await;
+
+            for contributor in &mut self.contributors {
+                contributor.reset();
+            }
+        }
+    }
+
+    /// Collect every section and apply the score formula
+    pub async fn summarize(
+        &self,
+        event: &GameEndedEvent,
+        resources: &ResourceContext,
+    ) -> RunSummary {
+        let mut sections = Vec::new();
+        for contributor in &self.contributors {
+            if let Some(section) = contributor.contribute(resources).await {
+                sections.push(section);
+            }
+        }
+
+        let config = match resources.get::<RunSummaryConfig>().await {
+            Some(config) => config.clone(),
+            None => RunSummaryConfig::default(),
+        };
+        let (score, breakdown) = score(&sections, &config);
+
+        RunSummary {
+            victory: event.victory,
+            reason: event.reason.clone(),
+            sections,
+            score,
+            breakdown,
+        }
+    }
+}
+
+/// Weighted sum over all entries; entries without a weight are skipped
+pub fn score(sections: &[SummarySection], config: &RunSummaryConfig) -> (f64, Vec<ScoreComponent>) {
+    let breakdown: Vec<ScoreComponent> = sections
+        .iter()
+        .flat_map(|section| section.entries.iter())
+        .filter_map(|entry| {
+            let weight = config.weight(&entry.key);
+            (weight != 0.0).then(|| ScoreComponent {
+                key: entry.key.clone(),
+                value: entry.value,
+                weight,
+                points: entry.value * weight,
+            })
+        })
+        .collect();
+    let total = breakdown.iter().map(|c| c.points).sum();
+    (total, breakdown)
+}
+
+#[async_trait]
+impl System for RunSummarySystem {
+    fn name(&self) -> &'static str {
+        "run_summary_system"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::combat::{BossMetadata, CombatConfig, CombatEndedEvent, CombatResult};
+    use crate::plugin::dungeon::DungeonState;
+    use crate::plugin::loot::{LootConfig, LootGeneratedEvent, Rarity};
+    use crate::plugin::run_summary::contributor::default_contributors;
+    use crate::plugin::run_summary::hook::DefaultRunSummaryHook;
+    use crate::plugin::time::GameTimer;
+
+    fn system() -> RunSummarySystem {
+        RunSummarySystem::new(Arc::new(DefaultRunSummaryHook), default_contributors())
+    }
+
+    async fn publish<E: crate::event::Event + serde::Serialize>(
+        resources: &ResourceContext,
+        event: E,
+    ) {
+        resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .publish(event);
+    }
+
+    async fn frame(system: &mut RunSummarySystem, resources: &mut ResourceContext) {
+        resources.get_mut::<EventBus>().await.unwrap().dispatch();
+        system
+            .process_events(&ServiceContext::new(), resources)
+            .await;
+    }
+
+    fn ended(result: CombatResult, score: u32) -> CombatEndedEvent {
+        CombatEndedEvent {
+            battle_id: "b".to_string(),
+            result,
+            total_turns: 4,
+            score,
+            boss: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scripted_run_aggregates_contributions() {
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(CombatConfig::default());
+        resources.insert(LootConfig::default());
+        resources.insert(DungeonState::default());
+        resources.insert(GameTimer::new());
+        resources.insert(
+            RunSummaryConfig::empty()
+                .with_weight("combat.battles_won", 100.0)
+                .with_weight("loot.items", 5.0)
+                .with_weight("dungeon.deepest_floor", 50.0),
+        );
+        let mut system = system();
+
+        // Frame 1: two battles, one loot drop
+        publish(&resources, ended(CombatResult::Victory, 30)).await;
+        publish(&resources, ended(CombatResult::Defeat, 5)).await;
+        publish(
+            &resources,
+            LootGeneratedEvent {
+                source_id: "chest".to_string(),
+                items: vec!["sword".to_string(), "shield".to_string()],
+                rarity: Rarity::Rare,
+            },
+        )
+        .await;
+        resources
+            .get_mut::<DungeonState>()
+            .await
+            .unwrap()
+            .current_floor = 3;
+        frame(&mut system, &mut resources).await;
+
+        // Frame 2: a boss kill, a floor reset that must not lower the record
+        let mut boss_kill = ended(CombatResult::Victory, 10);
+        boss_kill.boss = Some(BossMetadata {
+            encounter: "lich_king".to_string(),
+            boss: "lich".to_string(),
+            loot_table: "lich_hoard".to_string(),
+            phase: 2,
+            enrage_stacks: 0,
+            damage_multiplier: 1.0,
+        });
+        publish(&resources, boss_kill).await;
+        resources
+            .get_mut::<DungeonState>()
+            .await
+            .unwrap()
+            .current_floor = 1;
+        frame(&mut system, &mut resources).await;
+
+        // Frame 3: the run ends
+        publish(
+            &resources,
+            GameEndedEvent {
+                victory: true,
+                reason: "boss defeated".to_string(),
+            },
+        )
+        .await;
+        frame(&mut system, &mut resources).await;
+
+        let summary = resources.get::<RunSummary>().await.unwrap().clone();
+        assert!(summary.victory);
+        assert_eq!(summary.value("combat.battles_fought"), Some(3.0));
+        assert_eq!(summary.value("combat.battles_won"), Some(2.0));
+        assert_eq!(summary.value("combat.score"), Some(45.0));
+        assert_eq!(summary.value("combat.bosses_defeated"), Some(1.0));
+        assert_eq!(summary.value("loot.items"), Some(2.0));
+        assert_eq!(summary.value("loot.rare"), Some(2.0));
+        assert_eq!(summary.value("dungeon.deepest_floor"), Some(3.0));
+        assert_eq!(summary.value("time.days"), Some(1.0));
+
+        // 2 × 100 + 2 × 5 + 3 × 50
+        assert_eq!(summary.score, 360.0);
+        assert_eq!(summary.breakdown.len(), 3);
+
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        let ready: Vec<RunSummaryReady> = bus.reader::<RunSummaryReady>().iter().cloned().collect();
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].score, 360.0);
+    }
+
+    #[tokio::test]
+    async fn test_score_applies_weights() {
+        let sections = vec![SummarySection::new("combat", "Combat")
+            .with_entry("battles_won", "Battles won", 4.0)
+            .with_entry("turns", "Turns", 20.0)];
+
+        let config = RunSummaryConfig::empty()
+            .with_weight("combat.battles_won", 10.0)
+            .with_weight("combat.turns", -0.5);
+        let (total, breakdown) = score(&sections, &config);
+
+        assert_eq!(total, 30.0);
+        assert_eq!(breakdown[1].points, -10.0);
+
+        let (total, breakdown) = score(&sections, &RunSummaryConfig::empty());
+        assert_eq!(total, 0.0);
+        assert!(breakdown.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_absent_plugins_omit_sections() {
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(GameTimer::new());
+        let mut system = system();
+
+        publish(
+            &resources,
+            GameEndedEvent {
+                victory: false,
+                reason: "party wiped".to_string(),
+            },
+        )
+        .await;
+        frame(&mut system, &mut resources).await;
+
+        let summary = resources.get::<RunSummary>().await.unwrap();
+        let ids: Vec<&str> = summary.sections.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["time"]);
+        assert!(!summary.victory);
+        assert_eq!(summary.score, 0.0);
+    }
+}
@@ -0,0 +1,104 @@
+//! Run summary data types
+
+use crate::resources::Resource;
+use serde::{Deserialize, Serialize};
+
+/// A single named value in a summary section
+///
+/// `key` is the contribution name used by the score formula
+/// (e.g. `"combat.battles_won"`), `label` is shown to the player.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SummaryEntry {
+    pub key: String,
+    pub label: String,
+    pub value: f64,
+}
+
+impl SummaryEntry {
+    pub fn new(key: impl Into<String>, label: impl Into<String>, value: f64) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            value,
+        }
+    }
+}
+
+/// One plugin's part of the recap (e.g. "Combat")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SummarySection {
+    pub id: String,
+    pub title: String,
+    pub entries: Vec<SummaryEntry>,
+}
+
+impl SummarySection {
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add an entry whose key is `<section id>.<name>`
+    pub fn with_entry(mut self, name: &str, label: impl Into<String>, value: f64) -> Self {
+        let key = format!("{}.{}", self.id, name);
+        self.entries.push(SummaryEntry::new(key, label, value));
+        self
+    }
+
+    /// Look up an entry value by its full key
+    pub fn value(&self, key: &str) -> Option<f64> {
+        self.entries.iter().find(|e| e.key == key).map(|e| e.value)
+    }
+}
+
+/// Points a single weighted contribution added to the score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreComponent {
+    pub key: String,
+    pub value: f64,
+    pub weight: f64,
+    pub points: f64,
+}
+
+/// End-of-run recap (stored in `ResourceContext` once the run ends)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// Whether the run ended in victory
+    pub victory: bool,
+    /// Why the run ended (e.g. "boss defeated", "party wiped")
+    pub reason: String,
+    pub sections: Vec<SummarySection>,
+    pub score: f64,
+    pub breakdown: Vec<ScoreComponent>,
+}
+
+impl Resource for RunSummary {}
+
+impl RunSummary {
+    /// Find a section by id
+    pub fn section(&self, id: &str) -> Option<&SummarySection> {
+        self.sections.iter().find(|s| s.id == id)
+    }
+
+    /// Look up a contribution value across all sections
+    pub fn value(&self, key: &str) -> Option<f64> {
+        self.sections.iter().find_map(|s| s.value(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_section_entries_are_prefixed() {
+        let section = SummarySection::new("combat", "Combat").with_entry("won", "Battles won", 3.0);
+
+        assert_eq!(section.entries[0].key, "combat.won");
+        assert_eq!(section.value("combat.won"), Some(3.0));
+        assert_eq!(section.value("won"), None);
+    }
+}
@@ -1,8 +1,10 @@
 //! Save/Load plugin events
 
+use super::mods::ModVersionChange;
 use crate::event::Event;
 use crate::storage::save_data::SaveMetadata;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 // ============================================================================
 // Command Events (request actions)
@@ -144,6 +146,57 @@ pub struct SaveLoadFailed {
 
 impl Event for SaveLoadFailed {}
 
+/// A loaded save was made with other MODs than the ones loaded now
+///
+/// Published before the save is applied, after
+/// [`MissingModPolicy::AutoLoad`](super::MissingModPolicy::AutoLoad) loaded
+/// what it could. When [`SaveLoadConfig`](super::SaveLoadConfig)'s policies
+/// refuse the load, `refused` is set and a `SaveLoadFailed` follows. Extra
+/// MODs never refuse a load.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveModMismatch {
+    /// The save slot loaded from
+    pub slot: String,
+    /// Ids of MODs the save was made with that are not loaded
+    pub missing: Vec<String>,
+    /// Ids of loaded MODs the save was made without
+    pub extra: Vec<String>,
+    /// MODs loaded in another version than the save was made with
+    pub version_changed: Vec<ModVersionChange>,
+    /// Ids of MODs the save was made with that were loaded for it
+    #[serde(default)]
+    pub auto_loaded: Vec<String>,
+    /// Whether the load was refused because of this mismatch
+    #[serde(default)]
+    pub refused: bool,
+}
+
+impl Event for SaveModMismatch {}
+
+impl fmt::Display for SaveModMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if !self.missing.is_empty() {
+            parts.push(format!("missing {}", self.missing.join(", ")));
+        }
+        if !self.auto_loaded.is_empty() {
+            parts.push(format!("loaded {}", self.auto_loaded.join(", ")));
+        }
+        if !self.extra.is_empty() {
+            parts.push(format!("extra {}", self.extra.join(", ")));
+        }
+        if !self.version_changed.is_empty() {
+            let changes: Vec<String> = self
+                .version_changed
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            parts.push(format!("changed {}", changes.join(", ")));
+        }
+        f.write_str(&parts.join("; "))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,6 +234,9 @@ mod tests {
             slot: "test_slot".to_string(),
             timestamp: std::time::SystemTime::now(),
             size_bytes: 1024,
+            label: None,
+            play_time_secs: 0,
+            corrupted: false,
         };
         let event = GameSaved {
             slot: "test_slot".to_string(),
@@ -1,8 +1,10 @@
 //! Save/Load plugin hooks for customization
 
+use super::mods::SavedMod;
 use crate::context::ResourceContext;
 use crate::storage::save_data::{SaveData, SaveMetadata};
 use async_trait::async_trait;
+use std::path::PathBuf;
 
 /// Hook trait for customizing save/load behavior
 ///
@@ -97,6 +99,26 @@ pub trait SaveLoadHook: Send + Sync {
         // Default: no action
     }
 
+    /// Called for a MOD a save was made with that is neither loaded nor in
+    /// the MOD directory, under
+    /// [`MissingModPolicy::AutoLoad`](super::MissingModPolicy::AutoLoad)
+    ///
+    /// Use this to:
+    /// - Download the MOD from a remote index
+    /// - Look it up in a shared MOD cache
+    ///
+    /// Return the path of the MOD file or directory to load, or `None` if it
+    /// can't be provided (the default).
+    ///
+    /// # Arguments
+    ///
+    /// * `saved` - Id, version and content hash the save was made with
+    /// * `resources` - Access to game resources
+    async fn locate_mod(&self, _saved: &SavedMod, _resources: &ResourceContext) -> Option<PathBuf> {
+        // Default: only the MOD directory is searched
+        None
+    }
+
     /// Called when a save operation fails
     ///
     /// Use this to:
@@ -189,6 +211,9 @@ mod tests {
             slot: "test".to_string(),
             timestamp: SystemTime::now(),
             size_bytes: 100,
+            label: None,
+            play_time_secs: 0,
+            corrupted: false,
         };
         hook.after_save(&save_data, &metadata, &mut resources).await;
 
@@ -0,0 +1,433 @@
+//! Save/load menu
+//!
+//! [`SaveLoadMenu`] is the state and input handler of a save/load screen.
+//! Embed it in a scene's data, forward input to
+//! [`SaveLoadMenu::handle_input`] and draw it with
+//! [`SaveMenuWidget`](crate::ui::ratatui::SaveMenuWidget):
+//!
+//! ```ignore
+//! #[derive(Debug, Clone, Serialize, Deserialize)]
+//! pub struct SaveMenuSceneData {
+//!     pub menu: SaveLoadMenu,
+//! }
+//!
+//! impl SaveMenuSceneData {
+//!     pub async fn handle_input(
+//!         &mut self,
+//!         services: &ServiceContext,
+//!         systems: &mut SystemContext,
+//!         resources: &mut ResourceContext,
+//!         input: InputEvent,
+//!     ) -> SceneTransition<GameScene> {
+//!         self.menu
+//!             .handle_input(services, systems, resources, input, |_loaded, resources| {
+//!                 // e.g. rebuild the scene from the restored GameContext
+//!                 SceneTransition::Replace(GameScene::resume(resources))
+//!             })
+//!             .await
+//!     }
+//! }
+//!
+//! // Opening it from a pause menu
+//! let mut menu = SaveLoadMenu::new(["slot1", "slot2", "slot3"]);
+//! menu.refresh(services, systems, resources).await;
+//! SceneTransition::Push(GameScene::SaveMenu(SaveMenuSceneData { menu }))
+//! ```
+//!
+//! Keys: ↑/↓ select, Enter load, `s` save, `d` delete, Esc close.
+//!
+//! Loading a save made with other MODs opens [`SaveMenuMode::ModMismatch`]
+//! with the [`SaveModMismatch`]; Enter or Esc closes it, and a load that
+//! went ahead continues with `on_loaded` only then.
+//!
+//! Requests are carried out immediately: the menu publishes them, runs the
+//! registered [`SaveLoadSystem`] and applies the result events. This
+//! dispatches the `EventBus`, so the menu should be the only scene taking
+//! input while it is open.
+
+use super::events::*;
+use super::system::SaveLoadSystem;
+use crate::context::{ResourceContext, ServiceContext, SystemContext};
+use crate::event::EventBus;
+use crate::scene::SceneTransition;
+use crate::storage::save_data::SaveMetadata;
+use crate::ui::core::text_input::{TextEntryActive, TextInputOutcome, TextInputState};
+use crate::ui::core::widget::InputEvent;
+use serde::{Deserialize, Serialize};
+
+/// Longest label accepted by the label prompt
+const MAX_LABEL_LEN: usize = 32;
+
+/// One row of the menu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveSlotEntry {
+    pub slot: String,
+    /// `None` for an empty slot
+    pub metadata: Option<SaveMetadata>,
+}
+
+impl SaveSlotEntry {
+    pub fn is_empty(&self) -> bool {
+        self.metadata.is_none()
+    }
+
+    pub fn is_corrupted(&self) -> bool {
+        self.metadata.as_ref().is_some_and(|m| m.corrupted)
+    }
+}
+
+/// What the menu is waiting for
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SaveMenuMode {
+    /// Choosing a slot
+    Browse,
+    /// Saving over an existing slot needs a yes/no
+    ConfirmOverwrite { slot: String },
+    /// Typing the label of a new save
+    EnterLabel { slot: String },
+    /// Deleting a slot needs a yes/no
+    ConfirmDelete { slot: String },
+    /// The loaded save was made with other MODs; shown until acknowledged
+    ModMismatch { mismatch: SaveModMismatch },
+}
+
+/// Short notice shown until the next key press
+///
+/// Kept as data rather than text so the widget can localize it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SaveMenuToast {
+    Saved {
+        slot: String,
+    },
+    Deleted {
+        slot: String,
+    },
+    /// Load or delete was chosen on an empty slot
+    EmptySlot {
+        slot: String,
+    },
+    /// A request failed (`SaveLoadFailed`)
+    Failed {
+        operation: String,
+        slot: Option<String>,
+        error: String,
+    },
+}
+
+impl SaveMenuToast {
+    pub fn is_error(&self) -> bool {
+        matches!(self, SaveMenuToast::Failed { .. })
+    }
+}
+
+/// State of a save/load menu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveLoadMenu {
+    /// Slots always listed, in order, even when empty
+    slots: Vec<String>,
+    entries: Vec<SaveSlotEntry>,
+    selected: usize,
+    mode: SaveMenuMode,
+    label: TextInputState,
+    toast: Option<SaveMenuToast>,
+    /// Load that went ahead despite a MOD mismatch, finished once the
+    /// mismatch is acknowledged
+    #[serde(default)]
+    pending_load: Option<GameLoaded>,
+}
+
+impl SaveLoadMenu {
+    /// Menu over the given fixed slots
+    ///
+    /// Saves found in other slots are listed after them.
+    pub fn new<I, T>(slots: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let slots: Vec<String> = slots.into_iter().map(Into::into).collect();
+        let mut menu = Self {
+            slots,
+            entries: Vec::new(),
+            selected: 0,
+            mode: SaveMenuMode::Browse,
+            label: TextInputState::new().with_max_len(MAX_LABEL_LEN),
+            toast: None,
+            pending_load: None,
+        };
+        menu.apply_listing(Vec::new());
+        menu
+    }
+
+    pub fn entries(&self) -> &[SaveSlotEntry] {
+        &self.entries
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_entry(&self) -> Option<&SaveSlotEntry> {
+        self.entries.get(self.selected)
+    }
+
+    pub fn mode(&self) -> &SaveMenuMode {
+        &self.mode
+    }
+
+    /// Label being typed in [`SaveMenuMode::EnterLabel`]
+    pub fn label_input(&self) -> &TextInputState {
+        &self.label
+    }
+
+    pub fn toast(&self) -> Option<&SaveMenuToast> {
+        self.toast.as_ref()
+    }
+
+    /// Re-read the slot list (`ListSavesRequested` → `SavesListed`)
+    pub async fn refresh(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) {
+        self.request(services, systems, resources, ListSavesRequested)
+            .await;
+    }
+
+    /// Handle one key press
+    ///
+    /// `on_loaded` picks the transition once `GameLoaded` arrives, with the
+    /// restored resources readable through `ResourceContext::try_get`; every
+    /// other outcome stays on the menu, and Esc pops it.
+    pub async fn handle_input<S>(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+        input: InputEvent,
+        on_loaded: impl FnOnce(&GameLoaded, &ResourceContext) -> SceneTransition<S>,
+    ) -> SceneTransition<S> {
+        self.toast = None;
+
+        match self.mode.clone() {
+            SaveMenuMode::Browse => {
+                return self
+                    .handle_browse(services, systems, resources, input, on_loaded)
+                    .await;
+            }
+            SaveMenuMode::ConfirmOverwrite { slot } => match confirmation(input) {
+                Some(true) => {
+                    let label = self
+                        .entry(&slot)
+                        .and_then(|entry| entry.metadata.as_ref())
+                        .and_then(|metadata| metadata.label.clone())
+                        .unwrap_or_default();
+                    self.start_label(slot, label, resources);
+                }
+                Some(false) => self.mode = SaveMenuMode::Browse,
+                None => {}
+            },
+            SaveMenuMode::EnterLabel { slot } => match self.label.handle_input(input) {
+                TextInputOutcome::Editing => {}
+                TextInputOutcome::Submitted(label) => {
+                    self.end_label(resources);
+                    let label = (!label.trim().is_empty()).then(|| label.trim().to_string());
+                    self.request(
+                        services,
+                        systems,
+                        resources,
+                        SaveGameRequested { slot, label },
+                    )
+                    .await;
+                    self.refresh(services, systems, resources).await;
+                }
+                TextInputOutcome::Cancelled => self.end_label(resources),
+            },
+            SaveMenuMode::ConfirmDelete { slot } => match confirmation(input) {
+                Some(true) => {
+                    self.mode = SaveMenuMode::Browse;
+                    self.request(services, systems, resources, DeleteSaveRequested { slot })
+                        .await;
+                    self.refresh(services, systems, resources).await;
+                }
+                Some(false) => self.mode = SaveMenuMode::Browse,
+                None => {}
+            },
+            SaveMenuMode::ModMismatch { .. } => {
+                if matches!(input, InputEvent::Select | InputEvent::Cancel) {
+                    self.mode = SaveMenuMode::Browse;
+                    if let Some(loaded) = self.pending_load.take() {
+                        return on_loaded(&loaded, resources);
+                    }
+                }
+            }
+        }
+
+        SceneTransition::Stay
+    }
+
+    async fn handle_browse<S>(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+        input: InputEvent,
+        on_loaded: impl FnOnce(&GameLoaded, &ResourceContext) -> SceneTransition<S>,
+    ) -> SceneTransition<S> {
+        let Some(entry) = self.selected_entry().cloned() else {
+            return match input {
+                InputEvent::Cancel => SceneTransition::Pop,
+                _ => SceneTransition::Stay,
+            };
+        };
+
+        match input {
+            InputEvent::Up => self.selected = self.selected.saturating_sub(1),
+            InputEvent::Down => {
+                self.selected = (self.selected + 1).min(self.entries.len().saturating_sub(1))
+            }
+            InputEvent::Cancel => return SceneTransition::Pop,
+            InputEvent::Select if entry.is_empty() => {
+                self.toast = Some(SaveMenuToast::EmptySlot { slot: entry.slot });
+            }
+            InputEvent::Select => {
+                let loaded = self
+                    .request(
+                        services,
+                        systems,
+                        resources,
+                        LoadGameRequested { slot: entry.slot },
+                    )
+                    .await;
+                if let Some(loaded) = loaded {
+                    if matches!(self.mode, SaveMenuMode::ModMismatch { .. }) {
+                        self.pending_load = Some(loaded);
+                    } else {
+                        return on_loaded(&loaded, resources);
+                    }
+                }
+            }
+            InputEvent::Char('s') | InputEvent::Char('S') => {
+                if entry.is_empty() {
+                    self.start_label(entry.slot, String::new(), resources);
+                } else {
+                    self.mode = SaveMenuMode::ConfirmOverwrite { slot: entry.slot };
+                }
+            }
+            InputEvent::Char('d') | InputEvent::Char('D') => {
+                if entry.is_empty() {
+                    self.toast = Some(SaveMenuToast::EmptySlot { slot: entry.slot });
+                } else {
+                    self.mode = SaveMenuMode::ConfirmDelete { slot: entry.slot };
+                }
+            }
+            _ => {}
+        }
+
+        SceneTransition::Stay
+    }
+
+    fn entry(&self, slot: &str) -> Option<&SaveSlotEntry> {
+        self.entries.iter().find(|entry| entry.slot == slot)
+    }
+
+    fn start_label(&mut self, slot: String, label: String, resources: &mut ResourceContext) {
+        self.label.set_value(label);
+        self.mode = SaveMenuMode::EnterLabel { slot };
+        resources.insert(TextEntryActive);
+    }
+
+    fn end_label(&mut self, resources: &mut ResourceContext) {
+        self.mode = SaveMenuMode::Browse;
+        resources.remove::<TextEntryActive>();
+    }
+
+    /// Publish `request`, run the save/load system and apply its results
+    ///
+    /// Returns the `GameLoaded` event of a successful load.
+    async fn request<E: crate::event::Event + Serialize>(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+        request: E,
+    ) -> Option<GameLoaded> {
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.publish(request);
+            bus.dispatch();
+        }
+        if let Some(system) = systems.get_mut::<SaveLoadSystem>() {
+            system.process_events(services, resources).await;
+        }
+
+        let mut bus = resources.get_mut::<EventBus>().await?;
+        bus.dispatch();
+
+        let listed = bus.reader::<SavesListed>().iter().last().cloned();
+        let saved = bus.reader::<GameSaved>().iter().last().cloned();
+        let deleted = bus.reader::<SaveDeleted>().iter().last().cloned();
+        let failed = bus.reader::<SaveLoadFailed>().iter().last().cloned();
+        let loaded = bus.reader::<GameLoaded>().iter().last().cloned();
+        let mismatch = bus.reader::<SaveModMismatch>().iter().last().cloned();
+        drop(bus);
+
+        if let Some(mismatch) = mismatch {
+            self.mode = SaveMenuMode::ModMismatch { mismatch };
+        }
+        if let Some(listed) = listed {
+            self.apply_listing(listed.saves);
+        }
+        if let Some(saved) = saved {
+            self.toast = Some(SaveMenuToast::Saved { slot: saved.slot });
+        }
+        if let Some(deleted) = deleted {
+            self.toast = Some(SaveMenuToast::Deleted { slot: deleted.slot });
+        }
+        if let Some(failed) = failed {
+            self.toast = Some(SaveMenuToast::Failed {
+                operation: failed.operation,
+                slot: failed.slot,
+                error: failed.error,
+            });
+        }
+        loaded
+    }
+
+    /// Rebuild the rows: fixed slots first, then any other listed saves
+    fn apply_listing(&mut self, saves: Vec<SaveMetadata>) {
+        let selected_slot = self.selected_entry().map(|entry| entry.slot.clone());
+
+        let mut entries: Vec<SaveSlotEntry> = self
+            .slots
+            .iter()
+            .map(|slot| SaveSlotEntry {
+                slot: slot.clone(),
+                metadata: saves.iter().find(|save| &save.slot == slot).cloned(),
+            })
+            .collect();
+        entries.extend(
+            saves
+                .into_iter()
+                .filter(|save| !self.slots.contains(&save.slot))
+                .map(|save| SaveSlotEntry {
+                    slot: save.slot.clone(),
+                    metadata: Some(save),
+                }),
+        );
+        self.entries = entries;
+
+        self.selected = selected_slot
+            .and_then(|slot| self.entries.iter().position(|entry| entry.slot == slot))
+            .unwrap_or(0);
+    }
+}
+
+/// `Some(true)` for yes, `Some(false)` for no, `None` for any other key
+fn confirmation(input: InputEvent) -> Option<bool> {
+    match input {
+        InputEvent::Char('y') | InputEvent::Char('Y') | InputEvent::Select => Some(true),
+        InputEvent::Char('n') | InputEvent::Char('N') | InputEvent::Cancel => Some(false),
+        _ => None,
+    }
+}
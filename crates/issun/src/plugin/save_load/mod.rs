@@ -39,6 +39,27 @@
 //! - Continue from last save
 //! - Save file validation and error recovery
 //!
+//! # MODs
+//!
+//! With the MOD system installed, saves record the loaded MODs (ids,
+//! versions and content hashes) as a [`SaveModManifest`]. Loading a save
+//! made with other MODs (or other MOD versions) publishes a
+//! [`SaveModMismatch`]. [`SaveLoadConfig::missing_mods`] decides whether
+//! MODs the save needs only warn, refuse the load or are loaded first
+//! ([`MissingModPolicy`]); [`SaveLoadConfig::mod_versions`] does the same
+//! for version changes ([`ModVersionPolicy`]). Extra MODs are only listed.
+//!
+//! # Game State
+//!
+//! [`SaveLoadPlugin::persist`] stores a resource in every save and restores
+//! it on load. Implement [`SaveLoadHook`] for anything beyond that.
+//!
+//! # Save Menu
+//!
+//! [`SaveLoadMenu`] is a ready-made slot menu (list, save with overwrite
+//! confirmation and a label prompt, load, delete) to embed in a scene; draw it
+//! with [`SaveMenuWidget`](crate::ui::ratatui::SaveMenuWidget).
+//!
 //! # Custom Formats
 //!
 //! You can extend the plugin to support additional save formats by implementing
@@ -57,7 +78,7 @@
 //!
 //! ```ignore
 //! use issun::plugin::save_load::{
-//!     SaveLoadPlugin, SaveLoadConfig, SaveFormat,
+//!     SaveLoadPlugin, SaveLoadConfig, SaveFormat, MissingModPolicy, ModVersionPolicy,
 //!     SaveGameRequested, LoadGameRequested, AutoSaveRequested
 //! };
 //! use issun::event::EventBus;
@@ -69,6 +90,8 @@
 //!     format: SaveFormat::Ron,
 //!     enable_auto_save: true,
 //!     auto_save_interval: 300, // 5 minutes
+//!     missing_mods: MissingModPolicy::Warn,
+//!     mod_versions: ModVersionPolicy::Warn,
 //! };
 //!
 //! let game = GameBuilder::new()
@@ -104,11 +127,16 @@
 
 mod events;
 mod hook;
+mod menu;
+mod mods;
+mod persist;
 mod plugin;
 mod system;
 
 // Re-export public API
 pub use events::*;
 pub use hook::{DefaultSaveLoadHook, SaveLoadHook};
+pub use menu::{SaveLoadMenu, SaveMenuMode, SaveMenuToast, SaveSlotEntry};
+pub use mods::{MissingModPolicy, ModVersionChange, ModVersionPolicy, SaveModManifest, SavedMod};
 pub use plugin::{SaveFormat, SaveLoadConfig, SaveLoadPlugin};
 pub use system::SaveLoadSystem;
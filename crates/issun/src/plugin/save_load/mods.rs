@@ -0,0 +1,301 @@
+//! MODs recorded in save files
+//!
+//! With the MOD system installed, every save carries a [`SaveModManifest`]
+//! (section `mod_manifest` of the snapshot). Loading compares it with the
+//! MODs loaded now and publishes a [`SaveModMismatch`] when they differ;
+//! [`MissingModPolicy`] and [`ModVersionPolicy`] decide whether the load
+//! goes ahead.
+
+use super::events::SaveModMismatch;
+use crate::context::ResourceContext;
+use crate::modding::{ModBackend, ModRegistry};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A MOD that was active when the game was saved
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedMod {
+    pub id: String,
+    pub version: String,
+    pub backend: ModBackend,
+    /// `sha256:<hex>` of the MOD's files (see
+    /// [`content_hash`](crate::modding::content_hash)); `None` in older saves
+    /// and for MODs not loaded from disk
+    #[serde(default)]
+    pub content_hash: Option<String>,
+}
+
+/// What loading a save does when MODs it was made with are not loaded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingModPolicy {
+    /// Publish the `SaveModMismatch` and load anyway
+    #[default]
+    Warn,
+    /// Refuse the load with a `SaveLoadFailed` listing the MODs
+    Block,
+    /// Load the missing MODs first, from the MOD directory or through
+    /// [`SaveLoadHook::locate_mod`](super::SaveLoadHook::locate_mod); MODs
+    /// that still can't be loaded are handled like `Warn`
+    AutoLoad,
+}
+
+/// What loading a save does when MODs are loaded in another version (or
+/// with other files) than the save was made with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModVersionPolicy {
+    /// Publish the `SaveModMismatch` and load anyway
+    #[default]
+    Warn,
+    /// Refuse the load with a `SaveLoadFailed` listing the MODs
+    Block,
+}
+
+/// MODs active when a game was saved, sorted by id
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveModManifest {
+    pub mods: Vec<SavedMod>,
+}
+
+/// A MOD loaded in another version than the save was made with
+///
+/// `saved` and `current` are equal when the version is the same but the
+/// MOD's files changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModVersionChange {
+    pub id: String,
+    pub saved: String,
+    pub current: String,
+}
+
+impl ModVersionChange {
+    /// Same version, other files
+    pub fn is_content_change(&self) -> bool {
+        self.saved == self.current
+    }
+}
+
+impl fmt::Display for ModVersionChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_content_change() {
+            write!(f, "{} {} (modified)", self.id, self.saved)
+        } else {
+            write!(f, "{} {} → {}", self.id, self.saved, self.current)
+        }
+    }
+}
+
+impl SaveModManifest {
+    /// Manifest of the MODs in `registry`
+    pub fn from_registry(registry: &ModRegistry) -> Self {
+        let mut mods: Vec<SavedMod> = registry
+            .handles()
+            .iter()
+            .map(|handle| SavedMod {
+                id: handle.id.clone(),
+                version: handle.metadata.version.clone(),
+                backend: handle.backend,
+                content_hash: registry
+                    .source(&handle.id)
+                    .and_then(|source| source.content_hash.clone()),
+            })
+            .collect();
+        mods.sort_by(|a, b| a.id.cmp(&b.id));
+        Self { mods }
+    }
+
+    /// Manifest of the loaded MODs, if the MOD system is installed
+    pub async fn capture(resources: &ResourceContext) -> Option<Self> {
+        let registry = resources.get::<ModRegistry>().await?;
+        Some(Self::from_registry(&registry))
+    }
+
+    /// Saved MOD with the given id
+    pub fn get(&self, mod_id: &str) -> Option<&SavedMod> {
+        self.mods.iter().find(|saved| saved.id == mod_id)
+    }
+
+    /// Differences between this (saved) manifest and the `current` one,
+    /// regardless of order; `None` if the same MODs are loaded in the same
+    /// versions
+    ///
+    /// A MOD whose content hash differs in both manifests counts as a
+    /// version change even if its version doesn't.
+    pub fn mismatch(&self, slot: &str, current: &SaveModManifest) -> Option<SaveModMismatch> {
+        let find = |manifest: &SaveModManifest, id: &str| -> Option<SavedMod> {
+            manifest.mods.iter().find(|saved| saved.id == id).cloned()
+        };
+
+        let mut missing = Vec::new();
+        let mut version_changed = Vec::new();
+        for saved in &self.mods {
+            match find(current, &saved.id) {
+                None => missing.push(saved.id.clone()),
+                Some(loaded)
+                    if loaded.version != saved.version || content_changed(saved, &loaded) =>
+                {
+                    version_changed.push(ModVersionChange {
+                        id: saved.id.clone(),
+                        saved: saved.version.clone(),
+                        current: loaded.version,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        let mut extra: Vec<String> = current
+            .mods
+            .iter()
+            .filter(|loaded| find(self, &loaded.id).is_none())
+            .map(|loaded| loaded.id.clone())
+            .collect();
+
+        if missing.is_empty() && extra.is_empty() && version_changed.is_empty() {
+            return None;
+        }
+        missing.sort();
+        extra.sort();
+        version_changed.sort_by(|a, b| a.id.cmp(&b.id));
+        Some(SaveModMismatch {
+            slot: slot.to_string(),
+            missing,
+            extra,
+            version_changed,
+            auto_loaded: Vec::new(),
+            refused: false,
+        })
+    }
+}
+
+/// Both MODs carry a content hash and the hashes differ
+fn content_changed(saved: &SavedMod, loaded: &SavedMod) -> bool {
+    match (&saved.content_hash, &loaded.content_hash) {
+        (Some(saved), Some(loaded)) => saved != loaded,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modding::{ModHandle, ModMetadata};
+
+    fn handle(id: &str, version: &str) -> ModHandle {
+        ModHandle {
+            id: id.to_string(),
+            metadata: ModMetadata {
+                name: id.to_string(),
+                version: version.to_string(),
+                author: None,
+                description: None,
+                dependencies: Vec::new(),
+                priority: 0,
+                after: Vec::new(),
+            },
+            backend: ModBackend::Rhai,
+        }
+    }
+
+    fn manifest(mods: &[(&str, &str)]) -> SaveModManifest {
+        SaveModManifest::from_registry(&ModRegistry::new(
+            mods.iter()
+                .map(|(id, version)| handle(id, version))
+                .collect(),
+        ))
+    }
+
+    #[test]
+    fn test_mismatch_ignores_order() {
+        let saved = manifest(&[("easy_mode", "1.0.0"), ("better_loot", "2.0.0")]);
+        let current = manifest(&[("better_loot", "2.0.0"), ("easy_mode", "1.0.0")]);
+        assert_eq!(saved.mismatch("slot1", &current), None);
+    }
+
+    #[test]
+    fn test_mismatch_lists_missing_extra_and_changed() {
+        let saved = manifest(&[("easy_mode", "1.0.0"), ("better_loot", "2.0.0")]);
+        let current = manifest(&[("hard_mode", "1.0.0"), ("better_loot", "2.1.0")]);
+
+        let mismatch = saved.mismatch("slot1", &current).unwrap();
+        assert_eq!(mismatch.slot, "slot1");
+        assert_eq!(mismatch.missing, vec!["easy_mode"]);
+        assert_eq!(mismatch.extra, vec!["hard_mode"]);
+        assert_eq!(
+            mismatch.version_changed,
+            vec![ModVersionChange {
+                id: "better_loot".to_string(),
+                saved: "2.0.0".to_string(),
+                current: "2.1.0".to_string(),
+            }]
+        );
+        assert_eq!(
+            mismatch.to_string(),
+            "missing easy_mode; extra hard_mode; changed better_loot 2.0.0 → 2.1.0"
+        );
+    }
+
+    #[test]
+    fn test_content_hash_change_counts_as_version_change() {
+        let registry = |hash: &str| {
+            ModRegistry::new(vec![handle("easy_mode", "1.0.0")]).with_source(
+                "easy_mode",
+                crate::modding::ModSource {
+                    path: "mods/easy_mode.rhai".into(),
+                    content_hash: Some(hash.to_string()),
+                },
+            )
+        };
+        let saved = SaveModManifest::from_registry(&registry("sha256:aa"));
+        assert_eq!(saved.mods[0].content_hash.as_deref(), Some("sha256:aa"));
+        assert_eq!(
+            saved.mismatch(
+                "slot1",
+                &SaveModManifest::from_registry(&registry("sha256:aa"))
+            ),
+            None
+        );
+
+        let mismatch = saved
+            .mismatch(
+                "slot1",
+                &SaveModManifest::from_registry(&registry("sha256:bb")),
+            )
+            .unwrap();
+        assert!(mismatch.version_changed[0].is_content_change());
+        assert_eq!(mismatch.to_string(), "changed easy_mode 1.0.0 (modified)");
+
+        // Saves without hashes only compare versions
+        assert_eq!(
+            saved.mismatch("slot1", &manifest(&[("easy_mode", "1.0.0")])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_manifest_without_hashes_still_reads() {
+        let json = r#"{"mods":[{"id":"easy_mode","version":"1.0.0","backend":"Rhai"}]}"#;
+        let manifest: SaveModManifest = serde_json::from_str(json).unwrap();
+        assert_eq!(manifest.get("easy_mode").unwrap().content_hash, None);
+    }
+
+    #[test]
+    fn test_manifest_round_trips_through_save_formats() {
+        let saved = manifest(&[("easy_mode", "1.0.0"), ("better_loot", "2.0.0")]);
+
+        let json = serde_json::to_string(&saved).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SaveModManifest>(&json).unwrap(),
+            saved
+        );
+
+        let ron = ron::to_string(&saved).unwrap();
+        assert_eq!(ron::from_str::<SaveModManifest>(&ron).unwrap(), saved);
+
+        let binary = bincode::serialize(&saved).unwrap();
+        assert_eq!(
+            bincode::deserialize::<SaveModManifest>(&binary).unwrap(),
+            saved
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Resources stored in every save, see [`SaveLoadPlugin::persist`]
+//!
+//! [`SaveLoadPlugin::persist`]: super::SaveLoadPlugin::persist
+
+use crate::context::ResourceContext;
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::marker::PhantomData;
+
+/// A resource written under `resources.<key>` of a save snapshot
+#[async_trait]
+pub(crate) trait PersistedResource: Send + Sync {
+    fn key(&self) -> &str;
+
+    /// The resource as JSON, `None` if it is missing
+    async fn export(&self, resources: &ResourceContext) -> Option<Value>;
+
+    /// Replace (or insert) the resource with the saved value
+    async fn import(&self, value: &Value, resources: &mut ResourceContext);
+}
+
+pub(crate) struct Persisted<T> {
+    key: String,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Persisted<T> {
+    pub(crate) fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<T> PersistedResource for Persisted<T>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    async fn export(&self, resources: &ResourceContext) -> Option<Value> {
+        let resource = resources.get::<T>().await?;
+        serde_json::to_value(&*resource).ok()
+    }
+
+    async fn import(&self, value: &Value, resources: &mut ResourceContext) {
+        let Ok(loaded) = serde_json::from_value::<T>(value.clone()) else {
+            eprintln!("Ignoring malformed '{}' in save file", self.key);
+            return;
+        };
+        match resources.get_mut::<T>().await {
+            Some(mut resource) => *resource = loaded,
+            None => resources.insert(loaded),
+        }
+    }
+}
+
+/// The `resources` section of a save snapshot, `None` if nothing is persisted
+pub(crate) async fn export_resources(
+    persisted: &[std::sync::Arc<dyn PersistedResource>],
+    resources: &ResourceContext,
+) -> Option<Value> {
+    let mut section = serde_json::Map::new();
+    for entry in persisted {
+        if let Some(value) = entry.export(resources).await {
+            section.insert(entry.key().to_string(), value);
+        }
+    }
+    (!section.is_empty()).then_some(Value::Object(section))
+}
+
+/// Restore every persisted resource found in the save snapshot
+pub(crate) async fn import_resources(
+    persisted: &[std::sync::Arc<dyn PersistedResource>],
+    data: &Value,
+    resources: &mut ResourceContext,
+) {
+    let Some(section) = data.get("resources") else {
+        return;
+    };
+    for entry in persisted {
+        if let Some(value) = section.get(entry.key()) {
+            entry.import(value, resources).await;
+        }
+    }
+}
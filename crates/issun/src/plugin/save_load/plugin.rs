@@ -1,10 +1,15 @@
 //! Save/Load plugin implementation
 
 use super::hook::{DefaultSaveLoadHook, SaveLoadHook};
+use super::mods::{MissingModPolicy, ModVersionPolicy};
+use super::persist::{Persisted, PersistedResource};
 use super::system::SaveLoadSystem;
+use crate::context::{ResourceContext, ServiceContext, SystemContext};
 use crate::plugin::{Plugin, PluginBuilder, PluginBuilderExt};
 use crate::resources::Resource;
 use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -19,6 +24,10 @@ pub struct SaveLoadConfig {
     pub enable_auto_save: bool,
     /// Auto-save interval in seconds (if auto-save is enabled)
     pub auto_save_interval: u64,
+    /// What loading a save made with MODs that aren't loaded does
+    pub missing_mods: MissingModPolicy,
+    /// What loading a save made with other versions of the loaded MODs does
+    pub mod_versions: ModVersionPolicy,
 }
 
 impl Resource for SaveLoadConfig {}
@@ -30,6 +39,8 @@ impl Default for SaveLoadConfig {
             format: SaveFormat::Json,
             enable_auto_save: true,
             auto_save_interval: 300, // 5 minutes
+            missing_mods: MissingModPolicy::default(),
+            mod_versions: ModVersionPolicy::default(),
         }
     }
 }
@@ -73,6 +84,8 @@ pub enum SaveFormat {
 ///     format: SaveFormat::Ron,
 ///     enable_auto_save: true,
 ///     auto_save_interval: 180, // 3 minutes
+///     missing_mods: MissingModPolicy::Warn,
+///     mod_versions: ModVersionPolicy::Warn,
 /// };
 ///
 /// let game = GameBuilder::new()
@@ -116,6 +129,7 @@ pub enum SaveFormat {
 pub struct SaveLoadPlugin {
     hook: Arc<dyn SaveLoadHook>,
     config: SaveLoadConfig,
+    persisted: Vec<Arc<dyn PersistedResource>>,
 }
 
 impl SaveLoadPlugin {
@@ -129,6 +143,7 @@ impl SaveLoadPlugin {
         Self {
             hook: Arc::new(DefaultSaveLoadHook),
             config: SaveLoadConfig::default(),
+            persisted: Vec::new(),
         }
     }
 
@@ -187,6 +202,8 @@ impl SaveLoadPlugin {
     ///     format: SaveFormat::Ron,
     ///     enable_auto_save: false,
     ///     auto_save_interval: 0,
+    ///     missing_mods: MissingModPolicy::Warn,
+    ///     mod_versions: ModVersionPolicy::Warn,
     /// };
     ///
     /// let plugin = SaveLoadPlugin::new().with_config(config);
@@ -252,6 +269,64 @@ impl SaveLoadPlugin {
         self.config.auto_save_interval = interval_seconds;
         self
     }
+
+    /// Store the resource `T` in every save and restore it on load
+    ///
+    /// The resource is written under `resources.<key>` of the snapshot;
+    /// loading replaces it, or inserts it if the game has none. Covers game
+    /// state that needs no custom [`SaveLoadHook`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let plugin = SaveLoadPlugin::new()
+    ///     .persist::<Player>("player")
+    ///     .persist::<InventoryState>("inventory");
+    /// ```
+    pub fn persist<T>(mut self, key: impl Into<String>) -> Self
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+    {
+        self.persisted.push(Arc::new(Persisted::<T>::new(key)));
+        self
+    }
+
+    /// Convenience method to refuse loading saves made with missing MODs or
+    /// other MOD versions (`true`), or only warn about them (`false`)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let plugin = SaveLoadPlugin::new().with_strict_mods(true);
+    /// ```
+    pub fn with_strict_mods(mut self, strict: bool) -> Self {
+        if strict {
+            self.config.missing_mods = MissingModPolicy::Block;
+            self.config.mod_versions = ModVersionPolicy::Block;
+        } else {
+            self.config.missing_mods = MissingModPolicy::Warn;
+            self.config.mod_versions = ModVersionPolicy::Warn;
+        }
+        self
+    }
+
+    /// Choose what loading a save made with MODs that aren't loaded does
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let plugin = SaveLoadPlugin::new().with_missing_mods(MissingModPolicy::AutoLoad);
+    /// ```
+    pub fn with_missing_mods(mut self, policy: MissingModPolicy) -> Self {
+        self.config.missing_mods = policy;
+        self
+    }
+
+    /// Choose what loading a save made with other MOD versions does
+    pub fn with_mod_versions(mut self, policy: ModVersionPolicy) -> Self {
+        self.config.mod_versions = policy;
+        self
+    }
 }
 
 impl Default for SaveLoadPlugin {
@@ -267,25 +342,36 @@ impl Plugin for SaveLoadPlugin {
     }
 
     fn build(&self, builder: &mut dyn PluginBuilder) {
-        // Note: Repository creation is moved to initialize() since constructors are async
-
         // Register the SaveLoadConfig as a resource for other systems to access
         builder.register_resource(self.config.clone());
 
         // Register save/load system with hook
-        builder.register_system(Box::new(SaveLoadSystem::new(
-            self.hook.clone(),
-            self.config.clone(),
-        )));
+        builder.register_system(Box::new(
+            SaveLoadSystem::new(self.hook.clone(), self.config.clone())
+                .with_persisted(self.persisted.clone()),
+        ));
     }
 
-    async fn initialize(&mut self) {
+    async fn on_start(
+        &self,
+        _services: &ServiceContext,
+        systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+    ) {
         // Create save directory if it doesn't exist
         if let Err(e) = tokio::fs::create_dir_all(&self.config.save_directory).await {
             eprintln!(
                 "Warning: Failed to create save directory {:?}: {}",
                 self.config.save_directory, e
             );
+            return;
+        }
+
+        // Open the repository up front instead of on the first save request
+        if let Some(system) = systems.get_mut::<SaveLoadSystem>() {
+            if let Err(e) = system.ensure_repository().await {
+                eprintln!("Failed to initialize save repository: {}", e);
+            }
         }
     }
 }
@@ -310,6 +396,8 @@ mod tests {
             format: SaveFormat::Ron,
             enable_auto_save: false,
             auto_save_interval: 60,
+            missing_mods: MissingModPolicy::AutoLoad,
+            mod_versions: ModVersionPolicy::Block,
         };
 
         let plugin = SaveLoadPlugin::new().with_config(config.clone());
@@ -317,6 +405,8 @@ mod tests {
         assert_eq!(plugin.config.format, SaveFormat::Ron);
         assert!(!plugin.config.enable_auto_save);
         assert_eq!(plugin.config.auto_save_interval, 60);
+        assert_eq!(plugin.config.missing_mods, MissingModPolicy::AutoLoad);
+        assert_eq!(plugin.config.mod_versions, ModVersionPolicy::Block);
     }
 
     #[test]
@@ -335,12 +425,21 @@ mod tests {
         let plugin = SaveLoadPlugin::new()
             .with_save_directory(PathBuf::from("custom"))
             .with_format(SaveFormat::Ron)
-            .with_auto_save(false, 42);
+            .with_auto_save(false, 42)
+            .with_strict_mods(true);
 
         assert_eq!(plugin.config.save_directory, PathBuf::from("custom"));
         assert_eq!(plugin.config.format, SaveFormat::Ron);
         assert!(!plugin.config.enable_auto_save);
         assert_eq!(plugin.config.auto_save_interval, 42);
+        assert_eq!(plugin.config.missing_mods, MissingModPolicy::Block);
+        assert_eq!(plugin.config.mod_versions, ModVersionPolicy::Block);
+
+        let plugin = plugin
+            .with_missing_mods(MissingModPolicy::AutoLoad)
+            .with_mod_versions(ModVersionPolicy::Warn);
+        assert_eq!(plugin.config.missing_mods, MissingModPolicy::AutoLoad);
+        assert_eq!(plugin.config.mod_versions, ModVersionPolicy::Warn);
     }
 
     #[test]
@@ -350,5 +449,28 @@ mod tests {
         assert_eq!(config.format, SaveFormat::Json);
         assert!(config.enable_auto_save);
         assert_eq!(config.auto_save_interval, 300);
+        assert_eq!(config.missing_mods, MissingModPolicy::Warn);
+        assert_eq!(config.mod_versions, ModVersionPolicy::Warn);
+    }
+
+    #[tokio::test]
+    async fn test_on_start_creates_save_directory() {
+        let temp = tempfile::tempdir().unwrap();
+        let save_dir = temp.path().join("nested").join("saves");
+        let plugin = SaveLoadPlugin::new().with_save_directory(save_dir.clone());
+
+        let mut game = crate::builder::GameBuilder::new()
+            .with_plugin(SaveLoadPlugin::new().with_save_directory(save_dir.clone()))
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        assert!(!save_dir.exists());
+
+        plugin
+            .on_start(&game.services, &mut game.systems, &mut game.resources)
+            .await;
+
+        assert!(save_dir.is_dir());
     }
 }
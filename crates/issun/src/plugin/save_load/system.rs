@@ -2,10 +2,14 @@
 
 use super::events::*;
 use super::hook::SaveLoadHook;
+use super::mods::{MissingModPolicy, ModVersionPolicy, SaveModManifest};
+use super::persist::{export_resources, import_resources, PersistedResource};
 use super::plugin::{SaveFormat, SaveLoadConfig};
 use crate::context::{Context, ResourceContext, ServiceContext};
+use crate::engine::playtime::{roll_up_playtime, PlaytimeStats};
 use crate::error::{IssunError, Result};
 use crate::event::EventBus;
+use crate::modding::{find_mod, load_mods, ModLoaderState, ModRngSnapshot, ModSystemConfig};
 use crate::storage::json_repository::JsonSaveRepository;
 use crate::storage::repository::SaveRepository;
 use crate::storage::ron_repository::RonSaveRepository;
@@ -13,6 +17,7 @@ use crate::storage::save_data::SaveData;
 use crate::system::System;
 use async_trait::async_trait;
 use std::any::Any;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 /// System that handles save/load operations
@@ -26,6 +31,7 @@ pub struct SaveLoadSystem {
     hook: Arc<dyn SaveLoadHook>,
     config: SaveLoadConfig,
     repository: Option<Arc<dyn SaveRepository>>,
+    persisted: Vec<Arc<dyn PersistedResource>>,
 }
 
 impl SaveLoadSystem {
@@ -35,21 +41,27 @@ impl SaveLoadSystem {
             hook,
             config,
             repository: None,
+            persisted: Vec::new(),
         }
     }
 
+    /// Store these resources in every save and restore them on load
+    pub(crate) fn with_persisted(mut self, persisted: Vec<Arc<dyn PersistedResource>>) -> Self {
+        self.persisted = persisted;
+        self
+    }
+
     /// Process all save/load events
     pub async fn process_events(
         &mut self,
         _services: &ServiceContext,
         resources: &mut ResourceContext,
     ) {
-        // Ensure repository is initialized
-        if self.repository.is_none() {
-            if let Err(e) = self.initialize_repository().await {
-                eprintln!("Failed to initialize save repository: {}", e);
-                return;
-            }
+        // SaveLoadPlugin::on_start opens the repository; this only covers
+        // systems that are driven without a runner
+        if let Err(e) = self.ensure_repository().await {
+            eprintln!("Failed to initialize save repository: {}", e);
+            return;
         }
 
         self.process_save_requests(resources).await;
@@ -60,6 +72,14 @@ impl SaveLoadSystem {
         self.process_auto_save_requests(resources).await;
     }
 
+    /// Open the save repository unless it is already open
+    pub async fn ensure_repository(&mut self) -> Result<()> {
+        if self.repository.is_none() {
+            self.initialize_repository().await?;
+        }
+        Ok(())
+    }
+
     /// Initialize the save repository based on config
     async fn initialize_repository(&mut self) -> Result<()> {
         let repository: Arc<dyn SaveRepository> = match self.config.format {
@@ -253,6 +273,26 @@ impl SaveLoadSystem {
                 .as_secs()
         });
 
+        let mut game_state_json = game_state_json;
+        if let Some(persisted) = export_resources(&self.persisted, resources).await {
+            game_state_json["resources"] = persisted;
+        }
+        if let Some(mods) = export_mod_state(resources).await {
+            game_state_json["mods"] = mods;
+        }
+        if let Some(mod_rng) = export_mod_rng(resources).await {
+            game_state_json["mod_rng"] = mod_rng;
+        }
+        if let Some(manifest) = export_mod_manifest(resources).await {
+            game_state_json["mod_manifest"] = manifest;
+        }
+        if let Some(stats) = resources.get::<PlaytimeStats>().await {
+            game_state_json["play_time_secs"] = stats.total().as_secs().into();
+            if let Ok(playtime) = serde_json::to_value(&*stats) {
+                game_state_json["playtime"] = playtime;
+            }
+        }
+
         let mut save_data = SaveData::new(&event.slot, game_state_json);
 
         // Call before_save hook
@@ -268,6 +308,9 @@ impl SaveLoadSystem {
         // Get metadata for the saved file
         let metadata = repository.get_metadata(&event.slot).await?;
 
+        // Time played since the previous save counts towards the profile
+        roll_up_playtime(resources).await;
+
         // Call after_save hook
         self.hook.after_save(&save_data, &metadata, resources).await;
 
@@ -308,8 +351,19 @@ impl SaveLoadSystem {
         // Load the save data
         let save_data = repository.load(&event.slot).await?;
 
+        if let Some(refused) = self
+            .check_mod_manifest(&event.slot, &save_data.data, resources)
+            .await
+        {
+            return Err(IssunError::Plugin(refused));
+        }
+
         // Apply loaded data to game state (simplified)
         // In a real implementation, you'd deserialize and apply the actual game state
+        import_resources(&self.persisted, &save_data.data, resources).await;
+        import_mod_state(&save_data.data, resources).await;
+        import_mod_rng(&save_data.data, resources).await;
+        import_playtime(&save_data.data, resources).await;
 
         // Call after_load hook
         self.hook.after_load(&save_data, resources).await;
@@ -326,6 +380,100 @@ impl SaveLoadSystem {
         Ok(())
     }
 
+    /// Compare the `mod_manifest` section of a save snapshot with the loaded
+    /// MODs and apply the MOD policies
+    ///
+    /// Publishes a `SaveModMismatch` if the MODs differ (or some had to be
+    /// loaded) and returns the error message if the load is refused. Saves
+    /// without a manifest (older saves, or saved without the MOD system) are
+    /// not checked; without the MOD system every saved MOD counts as missing.
+    async fn check_mod_manifest(
+        &self,
+        slot: &str,
+        data: &serde_json::Value,
+        resources: &mut ResourceContext,
+    ) -> Option<String> {
+        let saved = data.get("mod_manifest")?;
+        let Ok(saved) = serde_json::from_value::<SaveModManifest>(saved.clone()) else {
+            eprintln!("Ignoring malformed MOD manifest in save file");
+            return None;
+        };
+
+        let mut mismatch = saved.mismatch(slot, &current_mod_manifest(resources).await);
+        let mut auto_loaded = Vec::new();
+        if let Some(missing) = mismatch.as_ref().map(|mismatch| mismatch.missing.clone()) {
+            if self.config.missing_mods == MissingModPolicy::AutoLoad && !missing.is_empty() {
+                auto_loaded = self.auto_load_mods(&saved, &missing, resources).await;
+                if !auto_loaded.is_empty() {
+                    mismatch = saved.mismatch(slot, &current_mod_manifest(resources).await);
+                }
+            }
+        }
+        if mismatch.is_none() && auto_loaded.is_empty() {
+            return None;
+        }
+
+        let mut mismatch = mismatch.unwrap_or_else(|| SaveModMismatch {
+            slot: slot.to_string(),
+            ..SaveModMismatch::default()
+        });
+        mismatch.auto_loaded = auto_loaded;
+        mismatch.refused = (self.config.missing_mods == MissingModPolicy::Block
+            && !mismatch.missing.is_empty())
+            || (self.config.mod_versions == ModVersionPolicy::Block
+                && !mismatch.version_changed.is_empty());
+
+        let refused = mismatch
+            .refused
+            .then(|| format!("Save '{}' was made with other MODs: {}", slot, mismatch));
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.publish(mismatch);
+        }
+        refused
+    }
+
+    /// Load the `missing` MODs of a save from the MOD directory, or from
+    /// where [`SaveLoadHook::locate_mod`] finds them
+    ///
+    /// Returns the ids of the MODs that loaded.
+    async fn auto_load_mods(
+        &self,
+        saved: &SaveModManifest,
+        missing: &[String],
+        resources: &mut ResourceContext,
+    ) -> Vec<String> {
+        let extensions = match resources.get::<ModLoaderState>().await {
+            Some(loader_state) => loader_state.loader.file_extensions(),
+            None => return Vec::new(),
+        };
+        let mod_dir = resources
+            .get::<ModSystemConfig>()
+            .await
+            .map(|config| PathBuf::from(&config.mod_dir));
+
+        let mut paths = Vec::new();
+        for mod_id in missing {
+            let in_mod_dir = mod_dir
+                .as_deref()
+                .and_then(|dir| find_mod(dir, mod_id, &extensions));
+            let path = match (in_mod_dir, saved.get(mod_id)) {
+                (Some(path), _) => Some(path),
+                (None, Some(saved_mod)) => self.hook.locate_mod(saved_mod, resources).await,
+                (None, None) => None,
+            };
+            paths.extend(path);
+        }
+        if paths.is_empty() {
+            return Vec::new();
+        }
+
+        load_mods(resources, paths)
+            .await
+            .into_iter()
+            .map(|handle| handle.id)
+            .collect()
+    }
+
     async fn handle_delete_request(
         &self,
         event: &DeleteSaveRequested,
@@ -424,6 +572,80 @@ impl SaveLoadSystem {
     }
 }
 
+/// Restore [`PlaytimeStats`] from the `playtime` section of a save snapshot
+async fn import_playtime(data: &serde_json::Value, resources: &mut ResourceContext) {
+    let Some(playtime) = data.get("playtime") else {
+        return;
+    };
+    let Ok(stats) = serde_json::from_value::<PlaytimeStats>(playtime.clone()) else {
+        eprintln!("Ignoring malformed 'playtime' in save file");
+        return;
+    };
+    match resources.get_mut::<PlaytimeStats>().await {
+        Some(mut current) => *current = stats,
+        None => resources.insert(stats),
+    }
+}
+
+/// Collect persistent MOD data from the MOD loader, if the MOD system is installed
+async fn export_mod_state(resources: &ResourceContext) -> Option<serde_json::Value> {
+    let loader_state = resources.get::<ModLoaderState>().await?;
+    let state = loader_state.loader.export_state();
+    if state.is_empty() {
+        return None;
+    }
+    serde_json::to_value(state).ok()
+}
+
+/// Hand the `mods` section of a save snapshot back to the MOD loader
+async fn import_mod_state(data: &serde_json::Value, resources: &ResourceContext) {
+    let Some(mods) = data.get("mods") else {
+        return;
+    };
+    let Ok(state) = serde_json::from_value(mods.clone()) else {
+        eprintln!("Ignoring malformed MOD data in save file");
+        return;
+    };
+    if let Some(mut loader_state) = resources.get_mut::<ModLoaderState>().await {
+        loader_state.loader.import_state(state);
+    }
+}
+
+/// The loaded MODs' ids, versions and backends, if the MOD system is installed
+async fn export_mod_manifest(resources: &ResourceContext) -> Option<serde_json::Value> {
+    let manifest = SaveModManifest::capture(resources).await?;
+    serde_json::to_value(manifest).ok()
+}
+
+/// Manifest of the loaded MODs; empty without the MOD system
+async fn current_mod_manifest(resources: &ResourceContext) -> SaveModManifest {
+    SaveModManifest::capture(resources)
+        .await
+        .unwrap_or_default()
+}
+
+/// Master seed and MOD random stream positions, if the MOD system is installed
+async fn export_mod_rng(resources: &ResourceContext) -> Option<serde_json::Value> {
+    resources.get::<ModLoaderState>().await?;
+    let snapshot = ModRngSnapshot::capture(resources).await;
+    if snapshot == ModRngSnapshot::default() {
+        return None;
+    }
+    serde_json::to_value(snapshot).ok()
+}
+
+/// Continue the MOD random streams from the `mod_rng` section of a save snapshot
+async fn import_mod_rng(data: &serde_json::Value, resources: &mut ResourceContext) {
+    let Some(mod_rng) = data.get("mod_rng") else {
+        return;
+    };
+    let Ok(snapshot) = serde_json::from_value::<ModRngSnapshot>(mod_rng.clone()) else {
+        eprintln!("Ignoring malformed MOD random state in save file");
+        return;
+    };
+    snapshot.restore(resources).await;
+}
+
 #[async_trait]
 impl System for SaveLoadSystem {
     fn name(&self) -> &'static str {
@@ -448,7 +670,14 @@ impl System for SaveLoadSystem {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::rng::MasterSeed;
+    use crate::modding::{
+        ModBackend, ModError, ModHandle, ModLoader, ModMetadata, ModRegistry, ModResult,
+        PluginControl,
+    };
     use crate::plugin::save_load::hook::DefaultSaveLoadHook;
+    use std::collections::HashMap;
+    use std::path::Path;
 
     #[test]
     fn test_system_creation() {
@@ -457,4 +686,347 @@ mod tests {
         let system = SaveLoadSystem::new(hook, config);
         assert_eq!(system.name(), "save_load_system");
     }
+
+    #[derive(Default)]
+    struct StoreLoader {
+        state: HashMap<String, serde_json::Value>,
+        seed: Option<MasterSeed>,
+        rng: HashMap<String, u64>,
+    }
+
+    impl ModLoader for StoreLoader {
+        fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
+            let id = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .ok_or_else(|| ModError::InvalidFormat("Invalid path".to_string()))?;
+            Ok(registry(&[(id, "1.0.0")]).handles()[0].clone())
+        }
+
+        fn unload(&mut self, _handle: &ModHandle) -> ModResult<()> {
+            Ok(())
+        }
+
+        fn control_plugin(
+            &mut self,
+            _handle: &ModHandle,
+            _control: &PluginControl,
+        ) -> ModResult<()> {
+            Ok(())
+        }
+
+        fn export_state(&self) -> HashMap<String, serde_json::Value> {
+            self.state.clone()
+        }
+
+        fn import_state(&mut self, state: HashMap<String, serde_json::Value>) {
+            self.state = state;
+        }
+
+        fn set_master_seed(&mut self, seed: Option<MasterSeed>) {
+            self.seed = seed;
+        }
+
+        fn export_rng(&self) -> HashMap<String, u64> {
+            self.rng.clone()
+        }
+
+        fn import_rng(&mut self, positions: HashMap<String, u64>) {
+            self.rng = positions;
+        }
+
+        fn clone_box(&self) -> Box<dyn ModLoader> {
+            Box::new(Self::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mod_state_round_trips_through_snapshot() {
+        let mut source = ResourceContext::new();
+        let mut loader = StoreLoader::default();
+        loader
+            .state
+            .insert("my_mod".to_string(), serde_json::json!({ "kills": 3 }));
+        source.insert(ModLoaderState {
+            loader: Box::new(loader),
+            loaded_mods: Vec::new(),
+        });
+
+        let mut snapshot = serde_json::json!({ "slot": "slot1" });
+        snapshot["mods"] = export_mod_state(&source).await.unwrap();
+
+        let mut target = ResourceContext::new();
+        target.insert(ModLoaderState {
+            loader: Box::new(StoreLoader::default()),
+            loaded_mods: Vec::new(),
+        });
+        import_mod_state(&snapshot, &target).await;
+
+        let loader_state = target.get::<ModLoaderState>().await.unwrap();
+        assert_eq!(
+            loader_state.loader.export_state()["my_mod"],
+            serde_json::json!({ "kills": 3 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mod_state_absent_without_mod_system() {
+        let resources = ResourceContext::new();
+        assert!(export_mod_state(&resources).await.is_none());
+        assert!(export_mod_rng(&resources).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_mod_rng_round_trips_through_snapshot() {
+        let mut source = ResourceContext::new();
+        source.insert(MasterSeed(42));
+        let mut loader = StoreLoader::default();
+        loader.rng.insert("my_mod".to_string(), 0xfeed);
+        source.insert(ModLoaderState {
+            loader: Box::new(loader),
+            loaded_mods: Vec::new(),
+        });
+
+        let mut snapshot = serde_json::json!({ "slot": "slot1" });
+        snapshot["mod_rng"] = export_mod_rng(&source).await.unwrap();
+
+        let mut target = ResourceContext::new();
+        target.insert(ModLoaderState {
+            loader: Box::new(StoreLoader::default()),
+            loaded_mods: Vec::new(),
+        });
+        import_mod_rng(&snapshot, &mut target).await;
+
+        assert_eq!(*target.get::<MasterSeed>().await.unwrap(), MasterSeed(42));
+        let loader_state = target.get::<ModLoaderState>().await.unwrap();
+        assert_eq!(loader_state.loader.export_rng()["my_mod"], 0xfeed);
+    }
+
+    fn registry(mods: &[(&str, &str)]) -> ModRegistry {
+        ModRegistry::new(
+            mods.iter()
+                .map(|(id, version)| ModHandle {
+                    id: id.to_string(),
+                    metadata: ModMetadata {
+                        name: id.to_string(),
+                        version: version.to_string(),
+                        author: None,
+                        description: None,
+                        dependencies: Vec::new(),
+                        priority: 0,
+                        after: Vec::new(),
+                    },
+                    backend: ModBackend::Rhai,
+                })
+                .collect(),
+        )
+    }
+
+    /// Publish `event`, let the system handle it and return the bus
+    async fn process<E: crate::event::Event + serde::Serialize>(
+        system: &mut SaveLoadSystem,
+        resources: &mut ResourceContext,
+        event: E,
+    ) {
+        {
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+            bus.publish(event);
+            bus.dispatch();
+        }
+        system
+            .process_events(&ServiceContext::new(), resources)
+            .await;
+        resources.get_mut::<EventBus>().await.unwrap().dispatch();
+    }
+
+    /// Save with `easy_mode` 1.0.0 and `better_loot` 2.0.0, then load with
+    /// `better_loot` 2.1.0 and `hard_mode`
+    async fn load_with_other_mods(format: SaveFormat, strict_mods: bool) -> ResourceContext {
+        let (missing_mods, mod_versions) = if strict_mods {
+            (MissingModPolicy::Block, ModVersionPolicy::Block)
+        } else {
+            (MissingModPolicy::Warn, ModVersionPolicy::Warn)
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let config = SaveLoadConfig {
+            save_directory: dir.path().to_path_buf(),
+            format,
+            enable_auto_save: false,
+            auto_save_interval: 0,
+            missing_mods,
+            mod_versions,
+        };
+        let mut system = SaveLoadSystem::new(Arc::new(DefaultSaveLoadHook), config);
+        system.ensure_repository().await.unwrap();
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(registry(&[
+            ("easy_mode", "1.0.0"),
+            ("better_loot", "2.0.0"),
+        ]));
+        process(
+            &mut system,
+            &mut resources,
+            SaveGameRequested {
+                slot: "slot1".to_string(),
+                label: None,
+            },
+        )
+        .await;
+
+        resources.insert(registry(&[
+            ("hard_mode", "1.0.0"),
+            ("better_loot", "2.1.0"),
+        ]));
+        process(
+            &mut system,
+            &mut resources,
+            LoadGameRequested {
+                slot: "slot1".to_string(),
+            },
+        )
+        .await;
+        resources
+    }
+
+    #[tokio::test]
+    async fn test_load_with_other_mods_publishes_mismatch() {
+        for format in [SaveFormat::Json, SaveFormat::Ron] {
+            let resources = load_with_other_mods(format, false).await;
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+
+            let mismatches: Vec<SaveModMismatch> =
+                bus.reader::<SaveModMismatch>().iter().cloned().collect();
+            assert_eq!(mismatches.len(), 1, "{:?}", format);
+            assert_eq!(mismatches[0].missing, vec!["easy_mode"]);
+            assert_eq!(mismatches[0].extra, vec!["hard_mode"]);
+            assert_eq!(mismatches[0].version_changed[0].id, "better_loot");
+            assert_eq!(bus.reader::<GameLoaded>().len(), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_strict_mods_refuses_load() {
+        let resources = load_with_other_mods(SaveFormat::Json, true).await;
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+
+        assert_eq!(bus.reader::<SaveModMismatch>().len(), 1);
+        assert_eq!(bus.reader::<GameLoaded>().len(), 0);
+        let failure = bus
+            .reader::<SaveLoadFailed>()
+            .iter()
+            .next()
+            .cloned()
+            .unwrap();
+        assert_eq!(failure.operation, "load");
+        assert!(failure.error.contains("missing easy_mode"));
+    }
+
+    #[tokio::test]
+    async fn test_same_mods_in_other_order_load_cleanly() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = SaveLoadConfig {
+            save_directory: dir.path().to_path_buf(),
+            missing_mods: MissingModPolicy::Block,
+            mod_versions: ModVersionPolicy::Block,
+            ..SaveLoadConfig::default()
+        };
+        let mut system = SaveLoadSystem::new(Arc::new(DefaultSaveLoadHook), config);
+        system.ensure_repository().await.unwrap();
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(registry(&[
+            ("easy_mode", "1.0.0"),
+            ("better_loot", "2.0.0"),
+        ]));
+        process(
+            &mut system,
+            &mut resources,
+            SaveGameRequested {
+                slot: "slot1".to_string(),
+                label: None,
+            },
+        )
+        .await;
+
+        resources.insert(registry(&[
+            ("better_loot", "2.0.0"),
+            ("easy_mode", "1.0.0"),
+        ]));
+        process(
+            &mut system,
+            &mut resources,
+            LoadGameRequested {
+                slot: "slot1".to_string(),
+            },
+        )
+        .await;
+
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        assert_eq!(bus.reader::<SaveModMismatch>().len(), 0);
+        assert_eq!(bus.reader::<GameLoaded>().len(), 1);
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Gold(u32);
+
+    #[tokio::test]
+    async fn test_persisted_resource_round_trips() {
+        for format in [SaveFormat::Json, SaveFormat::Ron] {
+            let dir = tempfile::tempdir().unwrap();
+            let config = SaveLoadConfig {
+                save_directory: dir.path().to_path_buf(),
+                format,
+                ..SaveLoadConfig::default()
+            };
+            let mut system = SaveLoadSystem::new(Arc::new(DefaultSaveLoadHook), config)
+                .with_persisted(vec![Arc::new(
+                    super::super::persist::Persisted::<Gold>::new("gold"),
+                )]);
+            system.ensure_repository().await.unwrap();
+
+            let mut resources = ResourceContext::new();
+            resources.insert(EventBus::new());
+            resources.insert(Gold(120));
+            process(
+                &mut system,
+                &mut resources,
+                SaveGameRequested {
+                    slot: "slot1".to_string(),
+                    label: None,
+                },
+            )
+            .await;
+
+            *resources.get_mut::<Gold>().await.unwrap() = Gold(5);
+            process(
+                &mut system,
+                &mut resources,
+                LoadGameRequested {
+                    slot: "slot1".to_string(),
+                },
+            )
+            .await;
+            assert_eq!(
+                *resources.get::<Gold>().await.unwrap(),
+                Gold(120),
+                "{:?}",
+                format
+            );
+
+            // A game without the resource gets it from the save
+            resources.remove::<Gold>();
+            process(
+                &mut system,
+                &mut resources,
+                LoadGameRequested {
+                    slot: "slot1".to_string(),
+                },
+            )
+            .await;
+            assert_eq!(*resources.get::<Gold>().await.unwrap(), Gold(120));
+        }
+    }
 }
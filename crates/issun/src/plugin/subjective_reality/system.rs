@@ -6,8 +6,10 @@ use super::config::PerceptionConfig;
 use super::hook::PerceptionHook;
 use super::service::PerceptionService;
 use super::state::KnowledgeBoardRegistry;
-use super::types::{FactionId, GroundTruthFact};
+use super::types::{FactType, FactionId, GroundTruthFact, PerceivedFact};
 use crate::context::ResourceContext;
+use crate::event::EventBus;
+use crate::plugin::faction::{IntelFact, IntelGatheredEvent};
 use crate::system::System;
 use async_trait::async_trait;
 use std::any::Any;
@@ -185,6 +187,66 @@ impl PerceptionSystem {
         Ok(())
     }
 
+    /// Record intel from espionage operations as beliefs of the spying faction
+    ///
+    /// Reads `IntelGatheredEvent`s (faction plugin) from the event bus. Each
+    /// fact is stored as `intel:{target}:{key}` (`intel:{target}:{territory}:{key}`
+    /// for territory orders) with the intel accuracy as confidence:
+    /// - `garrison` → `FactType::MilitaryStrength`
+    /// - `treasury` → `FactType::FinancialStatus`
+    /// - anything else → `FactType::Custom`
+    ///
+    /// Factions without a knowledge board are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Number of facts recorded
+    ///
+    /// # Errors
+    ///
+    /// Returns error if required resources are not found
+    pub async fn ingest_intel(&mut self, resources: &mut ResourceContext) -> Result<usize, String> {
+        let reports: Vec<IntelGatheredEvent> = {
+            let mut bus = resources
+                .get_mut::<EventBus>()
+                .await
+                .ok_or("EventBus not found")?;
+            bus.reader::<IntelGatheredEvent>().iter().cloned().collect()
+        };
+        if reports.is_empty() {
+            return Ok(0);
+        }
+
+        let mut boards = resources
+            .get_mut::<KnowledgeBoardRegistry>()
+            .await
+            .ok_or("KnowledgeBoardRegistry not found")?;
+
+        let mut recorded = 0;
+        for report in reports {
+            let Some(board) = boards.get_board_mut(&report.faction_id.as_str().to_string()) else {
+                continue;
+            };
+            let target = report.target.as_str();
+            for fact in &report.facts {
+                let fact_id = match &report.territory {
+                    Some(territory) => format!("intel:{}:{}:{}", target, territory, fact.key),
+                    None => format!("intel:{}:{}", target, fact.key),
+                };
+                let perceived = PerceivedFact::new(
+                    intel_fact_type(target, fact),
+                    report.accuracy,
+                    Duration::ZERO,
+                    None,
+                );
+                board.update_fact(fact_id, perceived, report.accuracy, report.day as u64);
+                recorded += 1;
+            }
+        }
+
+        Ok(recorded)
+    }
+
     /// Get a faction's knowledge board (read-only)
     ///
     /// # Arguments
@@ -205,6 +267,23 @@ impl PerceptionSystem {
     }
 }
 
+fn intel_fact_type(target: &str, fact: &IntelFact) -> FactType {
+    match (fact.key.as_str(), fact.value.as_f64()) {
+        ("garrison", Some(strength)) => FactType::MilitaryStrength {
+            faction: target.to_string(),
+            strength: strength.round() as i32,
+        },
+        ("treasury", Some(budget)) => FactType::FinancialStatus {
+            faction: target.to_string(),
+            budget: budget as f32,
+        },
+        _ => FactType::Custom {
+            fact_type: fact.key.clone(),
+            data: fact.value.clone(),
+        },
+    }
+}
+
 #[async_trait]
 impl System for PerceptionSystem {
     fn name(&self) -> &'static str {
@@ -3,7 +3,9 @@
 use crate::event::Event;
 use serde::{Deserialize, Serialize};
 
+use super::trade::{DisruptionCause, RouteId, RouteStats, TradeRouteError};
 use super::types::{TerritoryEffects, TerritoryId};
+use crate::plugin::economy::Currency;
 
 /// Request to change territory control (Command Event)
 ///
@@ -163,3 +165,111 @@ pub struct TerritoryEffectsUpdatedEvent {
 }
 
 impl Event for TerritoryEffectsUpdatedEvent {}
+
+/// Request to establish a trade route (Command Event)
+///
+/// `TradeRouteSystem` validates the endpoints against adjacency (or a path
+/// through friendly territory) and publishes `RouteEstablishedEvent` or
+/// `RouteRejectedEvent`. Requesting a disrupted route again re-establishes it.
+///
+/// # Example
+///
+/// ```ignore
+/// use issun::event::EventBus;
+/// use issun::plugin::territory::EstablishRouteRequested;
+///
+/// let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+/// bus.publish(EstablishRouteRequested {
+///     from: "nova-harbor".into(),
+///     to: "rust-city".into(),
+///     good: "grain".into(),
+///     capacity: 20,
+/// });
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstablishRouteRequested {
+    pub from: TerritoryId,
+    pub to: TerritoryId,
+    pub good: String,
+    /// Units carried per settlement
+    pub capacity: u32,
+}
+
+impl Event for EstablishRouteRequested {}
+
+/// Request to repair a disrupted trade route (Command Event)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepairRouteRequested {
+    pub route_id: RouteId,
+}
+
+impl Event for RepairRouteRequested {}
+
+/// Published when a trade route is established (State Change Event)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteEstablishedEvent {
+    pub route_id: RouteId,
+    pub from: TerritoryId,
+    pub to: TerritoryId,
+    pub good: String,
+    pub capacity: u32,
+    /// Territories the route passes through, endpoints included
+    pub path: Vec<TerritoryId>,
+}
+
+impl Event for RouteEstablishedEvent {}
+
+/// Published when a route request is rejected
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteRejectedEvent {
+    pub from: TerritoryId,
+    pub to: TerritoryId,
+    pub good: String,
+    pub error: TradeRouteError,
+}
+
+impl Event for RouteRejectedEvent {}
+
+/// Published when a trade route stops carrying goods (State Change Event)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteDisruptedEvent {
+    pub route_id: RouteId,
+    pub cause: DisruptionCause,
+    /// Income lost per settlement until the route is repaired
+    pub income_loss: Currency,
+}
+
+impl Event for RouteDisruptedEvent {}
+
+/// Published when a disrupted route carries goods again (State Change Event)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteRepairedEvent {
+    pub route_id: RouteId,
+}
+
+impl Event for RouteRepairedEvent {}
+
+/// One route's line in a trade report
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteReport {
+    pub route_id: RouteId,
+    pub from: TerritoryId,
+    pub to: TerritoryId,
+    pub good: String,
+    pub active: bool,
+    /// Statistics since the previous report
+    pub stats: RouteStats,
+}
+
+/// Periodic trade report, every `TradeConfig::report_interval_days`
+/// (State Change Event)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeReportPublished {
+    /// Day the report covers up to
+    pub day: u32,
+    pub routes: Vec<RouteReport>,
+    /// Income of all routes since the previous report
+    pub total_income: Currency,
+}
+
+impl Event for TradeReportPublished {}
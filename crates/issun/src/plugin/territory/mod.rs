@@ -7,11 +7,15 @@ mod service;
 mod state;
 mod system;
 mod territories;
+mod trade;
+mod trade_system;
 mod types;
 
 pub use events::{
-    TerritoryControlChangeRequested, TerritoryControlChangedEvent, TerritoryDevelopedEvent,
-    TerritoryDevelopmentRequested, TerritoryEffectsUpdatedEvent,
+    EstablishRouteRequested, RepairRouteRequested, RouteDisruptedEvent, RouteEstablishedEvent,
+    RouteRejectedEvent, RouteRepairedEvent, RouteReport, TerritoryControlChangeRequested,
+    TerritoryControlChangedEvent, TerritoryDevelopedEvent, TerritoryDevelopmentRequested,
+    TerritoryEffectsUpdatedEvent, TradeReportPublished,
 };
 pub use hook::{DefaultTerritoryHook, TerritoryHook};
 pub use plugin::TerritoryPlugin;
@@ -19,6 +23,11 @@ pub use service::TerritoryService;
 pub use state::TerritoryState;
 pub use system::TerritorySystem;
 pub use territories::Territories;
+pub use trade::{
+    DisruptionCause, RouteId, RouteStats, RouteStatus, TradeConfig, TradeRoute, TradeRouteError,
+    TradeRoutes,
+};
+pub use trade_system::{TradeRouteSystem, TARGET_ROUTE_KEY, TRADE_ROUTE_INCOME_METRIC};
 pub use types::{
     ControlChanged, Developed, Territory, TerritoryEffects, TerritoryError, TerritoryId,
 };
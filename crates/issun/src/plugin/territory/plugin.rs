@@ -4,6 +4,8 @@ use super::hook::{DefaultTerritoryHook, TerritoryHook};
 use super::state::TerritoryState;
 use super::system::TerritorySystem;
 use super::territories::Territories;
+use super::trade::{TradeConfig, TradeRoutes};
+use super::trade_system::TradeRouteSystem;
 use crate::Plugin;
 use std::sync::Arc;
 
@@ -16,6 +18,9 @@ use std::sync::Arc;
 /// - Processing territory development
 /// - Custom hooks for game-specific behavior
 ///
+/// It also registers `TradeRoutes` (saved with the game), `TradeConfig` and
+/// `TradeRouteSystem` for trade routes between territories.
+///
 /// # Hook Customization
 ///
 /// You can provide a custom hook to add game-specific behavior:
@@ -71,6 +76,13 @@ pub struct TerritoryPlugin {
     state: TerritoryState,
     #[plugin(system)]
     system: TerritorySystem,
+    #[plugin(runtime_state)]
+    #[allow(dead_code)]
+    routes: TradeRoutes,
+    #[plugin(resource)]
+    trade_config: TradeConfig,
+    #[plugin(system)]
+    trade_system: TradeRouteSystem,
 }
 
 impl TerritoryPlugin {
@@ -85,6 +97,9 @@ impl TerritoryPlugin {
             territories: Territories::new(),
             state: TerritoryState::new(),
             system: TerritorySystem::new(hook),
+            routes: TradeRoutes::new(),
+            trade_config: TradeConfig::default(),
+            trade_system: TradeRouteSystem::new(),
         }
     }
 
@@ -123,6 +138,12 @@ impl TerritoryPlugin {
         self.system = TerritorySystem::new(hook);
         self
     }
+
+    /// Set trade route configuration (good values, income factors, report interval)
+    pub fn with_trade_config(mut self, config: TradeConfig) -> Self {
+        self.trade_config = config;
+        self
+    }
 }
 
 impl Default for TerritoryPlugin {
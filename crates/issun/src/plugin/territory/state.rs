@@ -3,7 +3,7 @@
 use super::types::*;
 use crate::state::State;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Territory runtime state (mutable)
 ///
@@ -44,6 +44,10 @@ pub struct TerritoryState {
 
     /// Territory effects
     effects: HashMap<TerritoryId, TerritoryEffects>,
+
+    /// Territories currently contested or under siege
+    #[serde(default)]
+    contested: HashSet<TerritoryId>,
 }
 
 impl State for TerritoryState {}
@@ -55,6 +59,7 @@ impl TerritoryState {
             control: HashMap::new(),
             development: HashMap::new(),
             effects: HashMap::new(),
+            contested: HashSet::new(),
         }
     }
 
@@ -167,6 +172,30 @@ impl TerritoryState {
         true
     }
 
+    // ========================================
+    // Contested Territories
+    // ========================================
+
+    /// Mark a territory as contested (e.g. under siege) or secure again
+    ///
+    /// Returns false if the territory is not initialized.
+    pub fn set_contested(&mut self, id: &TerritoryId, contested: bool) -> bool {
+        if !self.contains(id) {
+            return false;
+        }
+        if contested {
+            self.contested.insert(id.clone());
+        } else {
+            self.contested.remove(id);
+        }
+        true
+    }
+
+    /// Check if a territory is contested
+    pub fn is_contested(&self, id: &TerritoryId) -> bool {
+        self.contested.contains(id)
+    }
+
     // ========================================
     // Queries
     // ========================================
@@ -333,4 +362,16 @@ mod tests {
         assert_eq!(developed.len(), 1);
         assert_eq!(developed[0].as_str(), "nova");
     }
+
+    #[test]
+    fn test_contested() {
+        let mut state = TerritoryState::new();
+        state.initialize(&TerritoryId::new("nova"));
+
+        assert!(state.set_contested(&TerritoryId::new("nova"), true));
+        assert!(state.is_contested(&TerritoryId::new("nova")));
+        assert!(state.set_contested(&TerritoryId::new("nova"), false));
+        assert!(!state.is_contested(&TerritoryId::new("nova")));
+        assert!(!state.set_contested(&TerritoryId::new("rust"), true));
+    }
 }
@@ -3,7 +3,7 @@
 use super::types::*;
 use issun_macros::Resource as DeriveResource;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Collection of all territory definitions (read-only)
 ///
@@ -27,6 +27,10 @@ use std::collections::HashMap;
 #[derive(Debug, Clone, Serialize, Deserialize, DeriveResource)]
 pub struct Territories {
     territories: HashMap<TerritoryId, Territory>,
+
+    /// Borders between territories (both directions)
+    #[serde(default)]
+    adjacency: HashMap<TerritoryId, HashSet<TerritoryId>>,
 }
 
 impl Territories {
@@ -34,6 +38,7 @@ impl Territories {
     pub fn new() -> Self {
         Self {
             territories: HashMap::new(),
+            adjacency: HashMap::new(),
         }
     }
 
@@ -67,6 +72,31 @@ impl Territories {
         self.territories.values()
     }
 
+    /// Mark two territories as sharing a border
+    pub fn connect(&mut self, a: &TerritoryId, b: &TerritoryId) {
+        if a == b {
+            return;
+        }
+        self.adjacency
+            .entry(a.clone())
+            .or_default()
+            .insert(b.clone());
+        self.adjacency
+            .entry(b.clone())
+            .or_default()
+            .insert(a.clone());
+    }
+
+    /// Check if two territories share a border
+    pub fn are_adjacent(&self, a: &TerritoryId, b: &TerritoryId) -> bool {
+        self.adjacency.get(a).is_some_and(|n| n.contains(b))
+    }
+
+    /// Territories bordering `id`
+    pub fn neighbors(&self, id: &TerritoryId) -> impl Iterator<Item = &TerritoryId> {
+        self.adjacency.get(id).into_iter().flatten()
+    }
+
     /// Query territories by predicate
     pub fn query<F>(&self, predicate: F) -> Vec<&Territory>
     where
@@ -130,6 +160,17 @@ mod tests {
         assert!(names.contains(&"Rust City"));
     }
 
+    #[test]
+    fn test_connect() {
+        let mut territories = Territories::new();
+        territories.connect(&"nova".into(), &"rust".into());
+
+        assert!(territories.are_adjacent(&"nova".into(), &"rust".into()));
+        assert!(territories.are_adjacent(&"rust".into(), &"nova".into()));
+        assert!(!territories.are_adjacent(&"nova".into(), &"delta".into()));
+        assert_eq!(territories.neighbors(&"rust".into()).count(), 1);
+    }
+
     #[test]
     fn test_query() {
         let mut territories = Territories::new();
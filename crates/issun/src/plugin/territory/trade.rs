@@ -0,0 +1,453 @@
+//! Trade routes between territories (runtime state, save/load target)
+
+use super::state::TerritoryState;
+use super::territories::Territories;
+use super::types::TerritoryId;
+use crate::plugin::economy::Currency;
+use crate::plugin::faction::{FactionId, OperationId};
+use crate::state::State;
+use issun_macros::Resource as DeriveResource;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt;
+
+/// Unique identifier for a trade route
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct RouteId(String);
+
+impl RouteId {
+    /// Create a new route ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    /// Get as string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for RouteId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for RouteId {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+/// Why a route stopped carrying goods
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisruptionCause {
+    /// An endpoint is contested or under siege
+    Contested(TerritoryId),
+    /// An enemy operation targets the route
+    Raided {
+        operation_id: OperationId,
+        faction_id: FactionId,
+    },
+}
+
+/// Whether a route is carrying goods
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RouteStatus {
+    Active,
+    Disrupted(DisruptionCause),
+}
+
+/// Accumulated statistics of a route
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteStats {
+    /// Settlements the route was active for
+    pub settlements: u32,
+    /// Units of goods carried
+    pub goods_moved: u64,
+    /// Income posted to accounting
+    pub income: Currency,
+    /// Times the route was disrupted
+    pub disruptions: u32,
+    /// Income the route would have earned while disrupted
+    pub income_lost: Currency,
+}
+
+/// A trade route carrying one good between two territories
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeRoute {
+    pub id: RouteId,
+    pub from: TerritoryId,
+    pub to: TerritoryId,
+    pub good: String,
+    /// Units carried per settlement
+    pub capacity: u32,
+    /// Territories the route passes through, endpoints included
+    pub path: Vec<TerritoryId>,
+    pub status: RouteStatus,
+    /// Statistics since the route was established
+    pub stats: RouteStats,
+    /// Statistics since the last trade report
+    #[serde(default)]
+    pub period_stats: RouteStats,
+}
+
+impl TradeRoute {
+    /// Check if the route is carrying goods
+    pub fn is_active(&self) -> bool {
+        self.status == RouteStatus::Active
+    }
+
+    /// Check if the route starts or ends in `territory`
+    pub fn touches(&self, territory: &TerritoryId) -> bool {
+        &self.from == territory || &self.to == territory
+    }
+}
+
+/// Reasons an established route is rejected
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TradeRouteError {
+    /// Endpoint does not exist
+    UnknownTerritory(TerritoryId),
+    /// Both endpoints are the same territory
+    SameTerritory,
+    /// Capacity must be positive
+    ZeroCapacity,
+    /// Endpoint is contested or under siege
+    Contested(TerritoryId),
+    /// Endpoints are neither adjacent nor connected through friendly territory
+    NoPath,
+    /// An active route already carries this good between the endpoints
+    Duplicate(RouteId),
+    /// Route not found
+    NotFound(RouteId),
+}
+
+impl fmt::Display for TradeRouteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TradeRouteError::UnknownTerritory(id) => write!(f, "Unknown territory: {}", id),
+            TradeRouteError::SameTerritory => write!(f, "Route must connect two territories"),
+            TradeRouteError::ZeroCapacity => write!(f, "Route capacity must be positive"),
+            TradeRouteError::Contested(id) => write!(f, "Territory {} is contested", id),
+            TradeRouteError::NoPath => write!(f, "No path through friendly territory"),
+            TradeRouteError::Duplicate(id) => write!(f, "Route {} already exists", id),
+            TradeRouteError::NotFound(id) => write!(f, "Route not found: {}", id),
+        }
+    }
+}
+
+impl std::error::Error for TradeRouteError {}
+
+/// Trade route configuration (resource)
+///
+/// Income of an active route per settlement:
+///
+/// ```text
+/// capacity × value(good) × factor(from) × factor(to)
+/// factor(t) = (1 + development × development_bonus) × (1 + population / population_scale)
+/// ```
+///
+/// Population is read from the territory's `metadata["population"]`
+/// (missing means 0).
+#[derive(Debug, Clone, Serialize, Deserialize, DeriveResource)]
+pub struct TradeConfig {
+    /// Value per unit of goods without an entry in `good_values`
+    pub default_good_value: i64,
+    /// Value per unit, by good
+    pub good_values: HashMap<String, i64>,
+    /// Income bonus per development level of an endpoint
+    pub development_bonus: f32,
+    /// Population that doubles an endpoint's income factor
+    pub population_scale: f32,
+    /// Control from which a territory lets routes pass through
+    pub friendly_control: f32,
+    /// Days between trade reports
+    pub report_interval_days: u32,
+}
+
+impl Default for TradeConfig {
+    fn default() -> Self {
+        Self {
+            default_good_value: 1,
+            good_values: HashMap::new(),
+            development_bonus: 0.1,
+            population_scale: 1000.0,
+            friendly_control: 0.5,
+            report_interval_days: 7,
+        }
+    }
+}
+
+impl TradeConfig {
+    /// Set the value per unit of a good
+    pub fn with_good_value(mut self, good: impl Into<String>, value: i64) -> Self {
+        self.good_values.insert(good.into(), value);
+        self
+    }
+
+    /// Value per unit of `good`
+    pub fn good_value(&self, good: &str) -> i64 {
+        self.good_values
+            .get(good)
+            .copied()
+            .unwrap_or(self.default_good_value)
+    }
+
+    /// Income multiplier an endpoint contributes
+    pub fn endpoint_factor(
+        &self,
+        id: &TerritoryId,
+        territories: &Territories,
+        state: &TerritoryState,
+    ) -> f32 {
+        let development = state.get_development(id).unwrap_or(0) as f32;
+        let population = territories
+            .get(id)
+            .and_then(|t| t.metadata.get("population"))
+            .and_then(|p| p.as_f64())
+            .unwrap_or(0.0) as f32;
+        let population_factor = if self.population_scale > 0.0 {
+            1.0 + population / self.population_scale
+        } else {
+            1.0
+        };
+        (1.0 + development * self.development_bonus) * population_factor
+    }
+
+    /// Income of `route` for one settlement
+    pub fn route_income(
+        &self,
+        route: &TradeRoute,
+        territories: &Territories,
+        state: &TerritoryState,
+    ) -> Currency {
+        let base = route.capacity as f32 * self.good_value(&route.good) as f32;
+        let income = base
+            * self.endpoint_factor(&route.from, territories, state)
+            * self.endpoint_factor(&route.to, territories, state);
+        Currency::new(income.round() as i64)
+    }
+
+    /// Find a path for a route from `from` to `to`
+    ///
+    /// Adjacent territories connect directly; otherwise the route may pass
+    /// through territories with at least `friendly_control` that are not
+    /// contested.
+    pub fn find_path(
+        &self,
+        from: &TerritoryId,
+        to: &TerritoryId,
+        territories: &Territories,
+        state: &TerritoryState,
+    ) -> Option<Vec<TerritoryId>> {
+        let mut previous: HashMap<&TerritoryId, &TerritoryId> = HashMap::new();
+        let mut visited: HashSet<&TerritoryId> = HashSet::from([from]);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(current) = queue.pop_front() {
+            // Sorted for a deterministic path
+            let mut neighbors: Vec<_> = territories.neighbors(current).collect();
+            neighbors.sort();
+            for next in neighbors {
+                if !visited.insert(next) {
+                    continue;
+                }
+                previous.insert(next, current);
+                if next == to {
+                    let mut path = vec![to.clone()];
+                    let mut step = to;
+                    while let Some(&prev) = previous.get(step) {
+                        path.push(prev.clone());
+                        step = prev;
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                let friendly = state
+                    .get_control(next)
+                    .is_some_and(|c| c >= self.friendly_control)
+                    && !state.is_contested(next);
+                if friendly {
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// All trade routes (runtime state, save/load target)
+///
+/// # Example
+///
+/// ```ignore
+/// use issun::plugin::territory::{EstablishRouteRequested, TradeRoutes};
+///
+/// bus.publish(EstablishRouteRequested {
+///     from: "nova".into(),
+///     to: "rust-city".into(),
+///     good: "grain".into(),
+///     capacity: 20,
+/// });
+///
+/// // Later
+/// let routes = resources.get::<TradeRoutes>().await.unwrap();
+/// for route in routes.iter().filter(|r| r.is_active()) {
+///     println!("{}: {} income", route.id, route.stats.income);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TradeRoutes {
+    routes: BTreeMap<RouteId, TradeRoute>,
+    next_id: u64,
+}
+
+impl State for TradeRoutes {}
+
+impl TradeRoutes {
+    /// Create an empty route table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a route, returning its ID
+    pub fn add(
+        &mut self,
+        from: TerritoryId,
+        to: TerritoryId,
+        good: impl Into<String>,
+        capacity: u32,
+        path: Vec<TerritoryId>,
+    ) -> RouteId {
+        self.next_id += 1;
+        let id = RouteId::new(format!("route-{}", self.next_id));
+        self.routes.insert(
+            id.clone(),
+            TradeRoute {
+                id: id.clone(),
+                from,
+                to,
+                good: good.into(),
+                capacity,
+                path,
+                status: RouteStatus::Active,
+                stats: RouteStats::default(),
+                period_stats: RouteStats::default(),
+            },
+        );
+        id
+    }
+
+    /// Get route by id
+    pub fn get(&self, id: &RouteId) -> Option<&TradeRoute> {
+        self.routes.get(id)
+    }
+
+    /// Get mutable route by id
+    pub fn get_mut(&mut self, id: &RouteId) -> Option<&mut TradeRoute> {
+        self.routes.get_mut(id)
+    }
+
+    /// Find the route carrying `good` from `from` to `to`
+    pub fn find(&self, from: &TerritoryId, to: &TerritoryId, good: &str) -> Option<&TradeRoute> {
+        self.routes
+            .values()
+            .find(|r| &r.from == from && &r.to == to && r.good == good)
+    }
+
+    /// Remove a route
+    pub fn remove(&mut self, id: &RouteId) -> Option<TradeRoute> {
+        self.routes.remove(id)
+    }
+
+    /// Iterate over all routes in ID order
+    pub fn iter(&self) -> impl Iterator<Item = &TradeRoute> {
+        self.routes.values()
+    }
+
+    /// Iterate mutably over all routes in ID order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut TradeRoute> {
+        self.routes.values_mut()
+    }
+
+    /// Get the number of routes
+    pub fn len(&self) -> usize {
+        self.routes.len()
+    }
+
+    /// Check if empty
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::territory::Territory;
+
+    fn world() -> (Territories, TerritoryState) {
+        let mut territories = Territories::new();
+        let mut state = TerritoryState::new();
+        for id in ["a", "b", "c", "d"] {
+            territories.add(Territory::new(id, id.to_uppercase()));
+            state.initialize(&id.into());
+        }
+        territories.connect(&"a".into(), &"b".into());
+        territories.connect(&"b".into(), &"c".into());
+        territories.connect(&"c".into(), &"d".into());
+        (territories, state)
+    }
+
+    #[test]
+    fn test_adjacent_path() {
+        let (territories, state) = world();
+        let path = TradeConfig::default().find_path(&"a".into(), &"b".into(), &territories, &state);
+        assert_eq!(path, Some(vec!["a".into(), "b".into()]));
+    }
+
+    #[test]
+    fn test_path_needs_friendly_territory() {
+        let (territories, mut state) = world();
+        let config = TradeConfig::default();
+        assert_eq!(
+            config.find_path(&"a".into(), &"d".into(), &territories, &state),
+            None
+        );
+
+        state.set_control(&"b".into(), 0.8);
+        state.set_control(&"c".into(), 0.6);
+        assert_eq!(
+            config.find_path(&"a".into(), &"d".into(), &territories, &state),
+            Some(vec!["a".into(), "b".into(), "c".into(), "d".into()])
+        );
+
+        state.set_contested(&"c".into(), true);
+        assert_eq!(
+            config.find_path(&"a".into(), &"d".into(), &territories, &state),
+            None
+        );
+    }
+
+    #[test]
+    fn test_route_ids_are_sequential() {
+        let mut routes = TradeRoutes::new();
+        let first = routes.add("a".into(), "b".into(), "grain", 10, Vec::new());
+        let second = routes.add("b".into(), "c".into(), "ore", 5, Vec::new());
+        assert_eq!(first.as_str(), "route-1");
+        assert_eq!(second.as_str(), "route-2");
+        assert_eq!(
+            routes.find(&"b".into(), &"c".into(), "ore").unwrap().id,
+            second
+        );
+    }
+
+    #[test]
+    fn test_trade_route_error_display() {
+        assert_eq!(
+            TradeRouteError::Contested("a".into()).to_string(),
+            "Territory a is contested"
+        );
+    }
+}
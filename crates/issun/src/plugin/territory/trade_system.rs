@@ -0,0 +1,468 @@
+//! Trade route system
+
+use crate::context::{ResourceContext, ServiceContext};
+use crate::event::{Event, EventBus};
+use crate::plugin::accounting::{IncomePostRequested, SettlementCompletedEvent};
+use crate::plugin::economy::Currency;
+use crate::plugin::faction::{FactionState, OperationLaunchedEvent};
+use crate::plugin::metrics::{
+    DefineMetricRequested, MetricDefinition, MetricId, MetricType, MetricValue, MetricsRegistry,
+    RecordMetricRequested,
+};
+use crate::plugin::time::DayChanged;
+use crate::system::System;
+use async_trait::async_trait;
+use std::any::Any;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::events::*;
+use super::state::TerritoryState;
+use super::territories::Territories;
+use super::trade::{
+    DisruptionCause, RouteId, RouteStats, RouteStatus, TradeConfig, TradeRouteError, TradeRoutes,
+};
+use super::types::TerritoryId;
+
+/// Metric recorded per route and settlement, with `{"route": id}` metadata
+pub const TRADE_ROUTE_INCOME_METRIC: &str = "trade.route_income";
+
+/// Metadata key of an operation targeting a trade route
+///
+/// Launching an operation whose metadata has `"target_route": "route-1"`
+/// disrupts that route.
+pub const TARGET_ROUTE_KEY: &str = "target_route";
+
+/// System that moves goods and income along trade routes
+///
+/// This system:
+/// 1. Establishes and repairs routes on request
+/// 2. Disrupts routes whose endpoint is contested or that an enemy
+///    operation targets
+/// 3. On every accounting settlement, posts each active route's income via
+///    `IncomePostRequested` and records it as a metric
+/// 4. Publishes `TradeReportPublished` every `report_interval_days`
+#[derive(Clone, Default)]
+pub struct TradeRouteSystem {
+    /// `DefineMetricRequested` was published
+    metric_defined: bool,
+}
+
+impl TradeRouteSystem {
+    /// Create a new TradeRouteSystem
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Process all trade route events
+    pub async fn process_events(
+        &mut self,
+        _services: &ServiceContext,
+        resources: &mut ResourceContext,
+    ) {
+        self.process_establish_requests(resources).await;
+        self.process_repair_requests(resources).await;
+        self.process_raids(resources).await;
+        self.process_contested(resources).await;
+        self.process_settlements(resources).await;
+        self.process_reports(resources).await;
+    }
+
+    /// Process `EstablishRouteRequested` events
+    async fn process_establish_requests(&mut self, resources: &mut ResourceContext) {
+        for request in read_events::<EstablishRouteRequested>(resources).await {
+            let existing = match resources.get::<TradeRoutes>().await {
+                Some(routes) => routes
+                    .find(&request.from, &request.to, &request.good)
+                    .map(|route| (route.id.clone(), route.is_active())),
+                None => continue,
+            };
+
+            let result = match existing {
+                Some((id, true)) => Err(TradeRouteError::Duplicate(id)),
+                // Re-establishing a disrupted route repairs it
+                Some((id, false)) => self
+                    .repair(&id, Some(request.capacity), resources)
+                    .await
+                    .map(|_| None),
+                None => validate(&request, resources).await.map(Some),
+            };
+
+            let event = match result {
+                Ok(Some(path)) => {
+                    let Some(mut routes) = resources.get_mut::<TradeRoutes>().await else {
+                        continue;
+                    };
+                    let route_id = routes.add(
+                        request.from.clone(),
+                        request.to.clone(),
+                        request.good.clone(),
+                        request.capacity,
+                        path.clone(),
+                    );
+                    Some(Published::Established(RouteEstablishedEvent {
+                        route_id,
+                        from: request.from,
+                        to: request.to,
+                        good: request.good,
+                        capacity: request.capacity,
+                        path,
+                    }))
+                }
+                Ok(None) => None,
+                Err(error) => Some(Published::Rejected(RouteRejectedEvent {
+                    from: request.from,
+                    to: request.to,
+                    good: request.good,
+                    error,
+                })),
+            };
+
+            if let (Some(event), Some(mut bus)) = (event, resources.get_mut::<EventBus>().await) {
+                match event {
+                    Published::Established(event) => bus.publish(event),
+                    Published::Rejected(event) => bus.publish(event),
+                }
+            }
+        }
+    }
+
+    /// Process `RepairRouteRequested` events
+    async fn process_repair_requests(&mut self, resources: &mut ResourceContext) {
+        for request in read_events::<RepairRouteRequested>(resources).await {
+            let Err(error) = self.repair(&request.route_id, None, resources).await else {
+                continue;
+            };
+            let route = resources
+                .get::<TradeRoutes>()
+                .await
+                .and_then(|routes| routes.get(&request.route_id).cloned());
+            if let (Some(route), Some(mut bus)) = (route, resources.get_mut::<EventBus>().await) {
+                bus.publish(RouteRejectedEvent {
+                    from: route.from,
+                    to: route.to,
+                    good: route.good,
+                    error,
+                });
+            }
+        }
+    }
+
+    /// Reactivate a disrupted route, publishing `RouteRepairedEvent`
+    async fn repair(
+        &mut self,
+        id: &RouteId,
+        capacity: Option<u32>,
+        resources: &mut ResourceContext,
+    ) -> Result<(), TradeRouteError> {
+        let request = {
+            let routes = resources
+                .get::<TradeRoutes>()
+                .await
+                .ok_or_else(|| TradeRouteError::NotFound(id.clone()))?;
+            let route = routes
+                .get(id)
+                .ok_or_else(|| TradeRouteError::NotFound(id.clone()))?;
+            if route.is_active() {
+                return Ok(());
+            }
+            EstablishRouteRequested {
+                from: route.from.clone(),
+                to: route.to.clone(),
+                good: route.good.clone(),
+                capacity: capacity.unwrap_or(route.capacity),
+            }
+        };
+        let path = validate(&request, resources).await?;
+
+        if let Some(mut routes) = resources.get_mut::<TradeRoutes>().await {
+            if let Some(route) = routes.get_mut(id) {
+                route.status = RouteStatus::Active;
+                route.capacity = request.capacity;
+                route.path = path;
+            }
+        }
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.publish(RouteRepairedEvent {
+                route_id: id.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Disrupt routes targeted by newly launched operations
+    async fn process_raids(&mut self, resources: &mut ResourceContext) {
+        for event in read_events::<OperationLaunchedEvent>(resources).await {
+            let target = match resources.get::<FactionState>().await {
+                Some(state) => state
+                    .get_operation(&event.operation_id)
+                    .and_then(|op| op.metadata.get(TARGET_ROUTE_KEY))
+                    .and_then(|target| target.as_str())
+                    .map(RouteId::from),
+                None => None,
+            };
+            if let Some(route_id) = target {
+                let cause = DisruptionCause::Raided {
+                    operation_id: event.operation_id,
+                    faction_id: event.faction_id,
+                };
+                disrupt(&route_id, cause, resources).await;
+            }
+        }
+    }
+
+    /// Disrupt active routes with a contested endpoint
+    async fn process_contested(&mut self, resources: &mut ResourceContext) {
+        let affected: Vec<(RouteId, TerritoryId)> = {
+            let (Some(routes), Some(state)) = (
+                resources.get::<TradeRoutes>().await,
+                resources.get::<TerritoryState>().await,
+            ) else {
+                return;
+            };
+            routes
+                .iter()
+                .filter(|route| route.is_active())
+                .filter_map(|route| {
+                    [&route.from, &route.to]
+                        .into_iter()
+                        .find(|id| state.is_contested(id))
+                        .map(|id| (route.id.clone(), id.clone()))
+                })
+                .collect()
+        };
+
+        for (route_id, territory) in affected {
+            disrupt(&route_id, DisruptionCause::Contested(territory), resources).await;
+        }
+    }
+
+    /// Move goods and income along every route once per settlement
+    async fn process_settlements(&mut self, resources: &mut ResourceContext) {
+        for _ in read_events::<SettlementCompletedEvent>(resources).await {
+            let config = trade_config(resources).await;
+            let (posts, samples) = {
+                let (Some(mut routes), Some(territories), Some(state)) = (
+                    resources.get_mut::<TradeRoutes>().await,
+                    resources.get::<Territories>().await,
+                    resources.get::<TerritoryState>().await,
+                ) else {
+                    return;
+                };
+
+                let mut posts = Vec::new();
+                let mut samples = Vec::new();
+                for route in routes.iter_mut() {
+                    let income = config.route_income(route, &territories, &state);
+                    let active = route.is_active();
+                    for stats in [&mut route.stats, &mut route.period_stats] {
+                        record_settlement(stats, active, route.capacity, income);
+                    }
+                    let earned = if active { income } else { Currency::ZERO };
+                    if active {
+                        posts.push(IncomePostRequested {
+                            source: format!("trade:{}", route.id),
+                            amount: income,
+                        });
+                    }
+                    samples.push((route.id.clone(), earned));
+                }
+                (posts, samples)
+            };
+
+            let metrics = self.metric_events(samples, resources).await;
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                for post in posts {
+                    bus.publish(post);
+                }
+                if let Some((define, values)) = metrics {
+                    if let Some(definition) = define {
+                        bus.publish(DefineMetricRequested { definition });
+                    }
+                    for value in values {
+                        bus.publish(RecordMetricRequested { value });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Per-route income samples, with the metric definition the first time
+    async fn metric_events(
+        &mut self,
+        samples: Vec<(RouteId, Currency)>,
+        resources: &ResourceContext,
+    ) -> Option<(Option<MetricDefinition>, Vec<MetricValue>)> {
+        let id = MetricId::new(TRADE_ROUTE_INCOME_METRIC);
+        let defined = resources
+            .get::<MetricsRegistry>()
+            .await?
+            .get_definition(&id)
+            .is_some();
+        let define = (!defined && !std::mem::replace(&mut self.metric_defined, true)).then(|| {
+            MetricDefinition::new(
+                TRADE_ROUTE_INCOME_METRIC,
+                "Trade route income",
+                "Income of one trade route per settlement",
+                MetricType::Gauge,
+                "currency",
+            )
+        });
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        let values = samples
+            .into_iter()
+            .map(|(route_id, income)| {
+                MetricValue::new(id.clone(), income.amount() as f64, timestamp)
+                    .with_metadata(serde_json::json!({ "route": route_id.as_str() }))
+            })
+            .collect();
+        Some((define, values))
+    }
+
+    /// Publish the trade report on report days
+    async fn process_reports(&mut self, resources: &mut ResourceContext) {
+        for event in read_events::<DayChanged>(resources).await {
+            let interval = trade_config(resources).await.report_interval_days;
+            if interval == 0 || event.day % interval != 0 {
+                continue;
+            }
+
+            let report = {
+                let Some(mut routes) = resources.get_mut::<TradeRoutes>().await else {
+                    return;
+                };
+                let lines: Vec<RouteReport> = routes
+                    .iter_mut()
+                    .map(|route| RouteReport {
+                        route_id: route.id.clone(),
+                        from: route.from.clone(),
+                        to: route.to.clone(),
+                        good: route.good.clone(),
+                        active: route.is_active(),
+                        stats: std::mem::take(&mut route.period_stats),
+                    })
+                    .collect();
+                let total_income = lines
+                    .iter()
+                    .fold(Currency::ZERO, |total, line| total + line.stats.income);
+                TradeReportPublished {
+                    day: event.day,
+                    routes: lines,
+                    total_income,
+                }
+            };
+
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.publish(report);
+            }
+        }
+    }
+}
+
+enum Published {
+    Established(RouteEstablishedEvent),
+    Rejected(RouteRejectedEvent),
+}
+
+fn record_settlement(stats: &mut RouteStats, active: bool, capacity: u32, income: Currency) {
+    if active {
+        stats.settlements += 1;
+        stats.goods_moved += u64::from(capacity);
+        stats.income += income;
+    } else {
+        stats.income_lost += income;
+    }
+}
+
+async fn read_events<E: Event + Clone>(resources: &ResourceContext) -> Vec<E> {
+    match resources.get_mut::<EventBus>().await {
+        Some(mut bus) => bus.reader::<E>().iter().cloned().collect(),
+        None => Vec::new(),
+    }
+}
+
+async fn trade_config(resources: &ResourceContext) -> TradeConfig {
+    resources
+        .get::<TradeConfig>()
+        .await
+        .map(|config| config.clone())
+        .unwrap_or_default()
+}
+
+/// Check a route request, returning the route's path
+async fn validate(
+    request: &EstablishRouteRequested,
+    resources: &ResourceContext,
+) -> Result<Vec<TerritoryId>, TradeRouteError> {
+    if request.from == request.to {
+        return Err(TradeRouteError::SameTerritory);
+    }
+    if request.capacity == 0 {
+        return Err(TradeRouteError::ZeroCapacity);
+    }
+    let config = trade_config(resources).await;
+    let territories = resources.get::<Territories>().await;
+    let state = resources.get::<TerritoryState>().await;
+    let (Some(territories), Some(state)) = (territories, state) else {
+        return Err(TradeRouteError::UnknownTerritory(request.from.clone()));
+    };
+    for id in [&request.from, &request.to] {
+        if !territories.contains(id) {
+            return Err(TradeRouteError::UnknownTerritory(id.clone()));
+        }
+        if state.is_contested(id) {
+            return Err(TradeRouteError::Contested(id.clone()));
+        }
+    }
+    config
+        .find_path(&request.from, &request.to, &territories, &state)
+        .ok_or(TradeRouteError::NoPath)
+}
+
+/// Stop an active route, publishing `RouteDisruptedEvent`
+async fn disrupt(route_id: &RouteId, cause: DisruptionCause, resources: &ResourceContext) {
+    let config = trade_config(resources).await;
+    let income_loss = {
+        let (Some(mut routes), Some(territories), Some(state)) = (
+            resources.get_mut::<TradeRoutes>().await,
+            resources.get::<Territories>().await,
+            resources.get::<TerritoryState>().await,
+        ) else {
+            return;
+        };
+        let Some(route) = routes.get_mut(route_id) else {
+            return;
+        };
+        if !route.is_active() {
+            return;
+        }
+        route.status = RouteStatus::Disrupted(cause.clone());
+        route.stats.disruptions += 1;
+        route.period_stats.disruptions += 1;
+        config.route_income(route, &territories, &state)
+    };
+
+    if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+        bus.publish(RouteDisruptedEvent {
+            route_id: route_id.clone(),
+            cause,
+            income_loss,
+        });
+    }
+}
+
+#[async_trait]
+impl System for TradeRouteSystem {
+    fn name(&self) -> &'static str {
+        "trade_route_system"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 use std::fmt;
 
 /// Unique identifier for a territory
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct TerritoryId(String);
 
 impl TerritoryId {
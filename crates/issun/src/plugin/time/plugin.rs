@@ -72,6 +72,7 @@ impl Plugin for BuiltInTimePlugin {
         let mut timer = GameTimer::new();
         timer.day = self.config.initial_day;
         builder.register_runtime_state(timer);
+        builder.register_reset_to_initial::<GameTimer>();
 
         // Store config as read-only resource for other systems to reference
         builder.register_resource(self.config.clone());
@@ -1,12 +1,17 @@
 //! Time-related resources for game timer management
 
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Game timer resource for tracking in-game time progression
 ///
 /// This resource provides pure time management without action point coupling.
 /// It tracks days and ticks independently of game mechanics.
 ///
+/// Real-time games also feed it the frame delta with [`GameTimer::advance`],
+/// which accumulates `elapsed` game time (stopped while paused, multiplied by
+/// `time_scale`).
+///
 /// # Example
 ///
 /// ```
@@ -25,6 +30,19 @@ pub struct GameTimer {
     pub day: u32,
     /// Frame/tick counter for sub-day timing
     pub tick: u64,
+    /// Game time accumulated by `advance`
+    #[serde(default)]
+    pub elapsed: Duration,
+    /// While paused, `advance` does not accumulate game time
+    #[serde(default)]
+    pub paused: bool,
+    /// Game seconds per real second
+    #[serde(default = "default_time_scale")]
+    pub time_scale: f32,
+}
+
+fn default_time_scale() -> f32 {
+    1.0
 }
 
 impl GameTimer {
@@ -40,7 +58,13 @@ impl GameTimer {
     /// assert_eq!(timer.tick, 0);
     /// ```
     pub fn new() -> Self {
-        Self { day: 1, tick: 0 }
+        Self {
+            day: 1,
+            tick: 0,
+            elapsed: Duration::ZERO,
+            paused: false,
+            time_scale: default_time_scale(),
+        }
     }
 
     /// Increment day counter
@@ -92,6 +116,55 @@ impl GameTimer {
     pub fn current_day(&self) -> u32 {
         self.day
     }
+
+    /// Advance game time by a real-time frame delta
+    ///
+    /// # Returns
+    ///
+    /// The game time that passed (zero while paused)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use issun::plugin::GameTimer;
+    /// use std::time::Duration;
+    ///
+    /// let mut timer = GameTimer::new();
+    /// timer.set_time_scale(2.0);
+    /// assert_eq!(timer.advance(Duration::from_secs(1)), Duration::from_secs(2));
+    ///
+    /// timer.pause();
+    /// assert_eq!(timer.advance(Duration::from_secs(1)), Duration::ZERO);
+    /// assert_eq!(timer.elapsed, Duration::from_secs(2));
+    /// ```
+    pub fn advance(&mut self, real_delta: Duration) -> Duration {
+        if self.paused {
+            return Duration::ZERO;
+        }
+        let delta = real_delta.mul_f32(self.time_scale);
+        self.elapsed += delta;
+        delta
+    }
+
+    /// Stop game time
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Let game time run again
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Whether game time is stopped
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Set game seconds per real second (negative values are clamped to 0)
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.max(0.0);
+    }
 }
 
 impl Default for GameTimer {
@@ -152,6 +225,27 @@ mod tests {
         assert_eq!(timer.tick, 0);
     }
 
+    #[test]
+    fn test_advance_respects_pause_and_scale() {
+        let mut timer = GameTimer::new();
+        timer.advance(Duration::from_millis(500));
+        assert_eq!(timer.elapsed, Duration::from_millis(500));
+
+        timer.pause();
+        assert_eq!(timer.advance(Duration::from_secs(10)), Duration::ZERO);
+        assert!(timer.is_paused());
+
+        timer.resume();
+        timer.set_time_scale(0.5);
+        timer.advance(Duration::from_secs(1));
+        assert_eq!(timer.elapsed, Duration::from_secs(1));
+
+        // Old saves without the real-time fields still load
+        let timer: GameTimer = serde_json::from_str(r#"{"day":3,"tick":7}"#).unwrap();
+        assert_eq!(timer.time_scale, 1.0);
+        assert_eq!(timer.elapsed, Duration::ZERO);
+    }
+
     #[test]
     fn test_independent_day_and_tick() {
         let mut timer = GameTimer::new();
@@ -1,6 +1,7 @@
 //! Event recorder implementation
 
 use super::types::{RecordedEvent, RecordingFile, RecordingMetadata, RecordingStats};
+use crate::modding::ModRngSnapshot;
 use serde::Serialize;
 use std::time::Instant;
 
@@ -10,6 +11,7 @@ pub struct EventRecorder {
     start_time: Instant,
     enabled: bool,
     current_frame: u64,
+    mod_rng: Option<ModRngSnapshot>,
 }
 
 impl EventRecorder {
@@ -20,6 +22,7 @@ impl EventRecorder {
             start_time: Instant::now(),
             enabled: false,
             current_frame: 0,
+            mod_rng: None,
         }
     }
 
@@ -34,6 +37,19 @@ impl EventRecorder {
         self.enabled = false;
     }
 
+    /// MOD 乱数ストリームをヘッダーに記録
+    ///
+    /// 記録開始時に `ModRngSnapshot::capture` の結果を渡すと、リプレイ側は
+    /// `ModRngSnapshot::restore` で同じ乱数列から再開できる。
+    pub fn set_mod_rng(&mut self, snapshot: ModRngSnapshot) {
+        self.mod_rng = Some(snapshot);
+    }
+
+    /// ヘッダーの MOD 乱数ストリーム
+    pub fn mod_rng(&self) -> Option<&ModRngSnapshot> {
+        self.mod_rng.as_ref()
+    }
+
     /// 記録中かどうか
     pub fn is_recording(&self) -> bool {
         self.enabled
@@ -76,6 +92,21 @@ impl EventRecorder {
         ));
     }
 
+    /// シリアライズ済みのイベントを記録（ネットワークから届いたイベント用）
+    pub fn record_raw(&mut self, event_type: impl Into<String>, payload: Vec<u8>) {
+        if !self.enabled {
+            return;
+        }
+
+        let timestamp_ms = self.elapsed_ms();
+        self.recordings.push(RecordedEvent::new(
+            self.current_frame,
+            timestamp_ms,
+            event_type.into(),
+            payload,
+        ));
+    }
+
     /// 記録をクリア
     pub fn clear(&mut self) {
         self.recordings.clear();
@@ -105,7 +136,8 @@ impl EventRecorder {
 
     /// ファイルに保存
     pub fn save(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let metadata = RecordingMetadata::new(self.stats());
+        let mut metadata = RecordingMetadata::new(self.stats());
+        metadata.mod_rng = self.mod_rng.clone();
         let file = RecordingFile {
             metadata,
             recordings: self.recordings.clone(),
@@ -126,6 +158,7 @@ impl EventRecorder {
             start_time: Instant::now(),
             enabled: false,
             current_frame: 0,
+            mod_rng: file.metadata.mod_rng,
         })
     }
 }
@@ -247,6 +280,7 @@ mod tests {
         let loaded = EventRecorder::load(temp_file).unwrap();
         assert_eq!(loaded.recordings().len(), 1);
         assert!(loaded.recordings()[0].event_type.contains("TestEvent"));
+        assert!(loaded.mod_rng().is_none());
 
         std::fs::remove_file(temp_file).ok();
     }
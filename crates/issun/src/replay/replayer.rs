@@ -3,6 +3,7 @@
 use super::recorder::EventRecorder;
 use super::types::{RecordedEvent, RecordingFile, RecordingStats};
 use crate::event::{Event, EventBus};
+use crate::modding::ModRngSnapshot;
 use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 
@@ -49,6 +50,7 @@ pub struct EventReplayer {
     current_frame: u64,
     current_index: usize,
     deserializers: HashMap<String, Box<dyn EventDeserializer>>,
+    mod_rng: Option<ModRngSnapshot>,
 }
 
 impl EventReplayer {
@@ -59,6 +61,7 @@ impl EventReplayer {
             current_frame: u64::MAX, // 初期値を最大値に（最初のフレームを確実に再生するため）
             current_index: 0,
             deserializers: HashMap::new(),
+            mod_rng: recorder.mod_rng().cloned(),
         }
     }
 
@@ -72,9 +75,18 @@ impl EventReplayer {
             current_frame: u64::MAX, // 初期値を最大値に（最初のフレームを確実に再生するため）
             current_index: 0,
             deserializers: HashMap::new(),
+            mod_rng: file.metadata.mod_rng,
         })
     }
 
+    /// 記録開始時の MOD 乱数ストリーム
+    ///
+    /// 再生前に `ModRngSnapshot::restore` で復元すると、MOD の `random()` が
+    /// 記録時と同じ値を返す。
+    pub fn mod_rng(&self) -> Option<&ModRngSnapshot> {
+        self.mod_rng.as_ref()
+    }
+
     /// デシリアライザーを登録
     pub fn register_deserializer<E>(&mut self)
     where
@@ -281,11 +293,19 @@ mod tests {
         recorder.start();
         recorder.record(&TestEvent { value: 42 });
 
+        recorder.set_mod_rng(ModRngSnapshot {
+            master_seed: Some(crate::engine::MasterSeed(42)),
+            positions: HashMap::from([("easy_mode".to_string(), 0xfeed)]),
+        });
+
         let temp_file = "/tmp/test_replayer.bin";
         recorder.save(temp_file).unwrap();
 
         let replayer = EventReplayer::load(temp_file).unwrap();
         assert_eq!(replayer.event_count(), 1);
+        let mod_rng = replayer.mod_rng().unwrap();
+        assert_eq!(mod_rng.master_seed, Some(crate::engine::MasterSeed(42)));
+        assert_eq!(mod_rng.positions["easy_mode"], 0xfeed);
 
         std::fs::remove_file(temp_file).ok();
     }
@@ -1,5 +1,6 @@
 //! Types for event recording and replay
 
+use crate::modding::ModRngSnapshot;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -84,6 +85,9 @@ pub struct RecordingMetadata {
 
     /// 統計情報
     pub stats: RecordingStats,
+
+    /// 記録開始時の MOD 乱数ストリーム（マスターシードと各 MOD の位置）
+    pub mod_rng: Option<ModRngSnapshot>,
 }
 
 impl RecordingMetadata {
@@ -91,8 +95,9 @@ impl RecordingMetadata {
     pub fn new(stats: RecordingStats) -> Self {
         Self {
             created_at: chrono::Utc::now().to_rfc3339(),
-            version: 1,
+            version: 2,
             stats,
+            mod_rng: None,
         }
     }
 }
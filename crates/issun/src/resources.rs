@@ -73,6 +73,31 @@ pub trait Resource: Send + Sync + 'static {
     }
 }
 
+/// How a resource is built when only its type is named
+///
+/// `#[plugin(resource = T)]`, `#[plugin(state = T)]` and
+/// `GameBuilder::with_default_resource` build `T` with this. Every `Default`
+/// type has it; types that should not start out as their `Default` (ring
+/// buffers that need a capacity, ...) declare an init expression instead,
+/// and then cannot implement `Default` as well:
+///
+/// ```ignore
+/// #[derive(Resource)]
+/// #[resource(init = "EventLog::with_capacity(64)")]
+/// pub struct EventLog {
+///     entries: VecDeque<String>,
+/// }
+/// ```
+pub trait ResourceInit: Sized {
+    fn init_resource() -> Self;
+}
+
+impl<T: Default> ResourceInit for T {
+    fn init_resource() -> Self {
+        T::default()
+    }
+}
+
 /// Resource registry for global read-only data
 ///
 /// Uses type-based lookup to store and retrieve resources.
@@ -87,6 +112,7 @@ pub trait Resource: Send + Sync + 'static {
 /// resources remain immutable after game initialization.
 pub struct Resources {
     data: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    names: HashMap<TypeId, &'static str>,
 }
 
 impl Resources {
@@ -96,6 +122,7 @@ impl Resources {
     pub(crate) fn new() -> Self {
         Self {
             data: HashMap::new(),
+            names: HashMap::new(),
         }
     }
 
@@ -120,6 +147,8 @@ impl Resources {
     /// and remain read-only during runtime.
     pub fn register<T: Resource>(&mut self, resource: T) {
         self.data.insert(TypeId::of::<T>(), Box::new(resource));
+        self.names
+            .insert(TypeId::of::<T>(), std::any::type_name::<T>());
     }
 
     /// Get an immutable reference to a resource
@@ -163,11 +192,17 @@ impl Resources {
         self.data.is_empty()
     }
 
-    /// Consume the Resources and return the internal HashMap (internal use only)
+    /// Consume the Resources and return (type id, type name, resource) entries (internal use only)
     ///
     /// This is used by GameBuilder to transfer resources to ResourceContext.
-    pub(crate) fn into_inner(self) -> HashMap<TypeId, Box<dyn Any + Send + Sync>> {
+    pub(crate) fn into_inner(mut self) -> Vec<(TypeId, &'static str, Box<dyn Any + Send + Sync>)> {
         self.data
+            .drain()
+            .map(|(type_id, boxed)| {
+                let name = self.names.get(&type_id).copied().unwrap_or("<unknown>");
+                (type_id, name, boxed)
+            })
+            .collect()
     }
 
     /// Remove a resource from the registry
@@ -177,6 +212,7 @@ impl Resources {
     /// This is only available within the crate for internal use.
     #[allow(dead_code)]
     pub(crate) fn remove<T: Resource>(&mut self) -> Option<T> {
+        self.names.remove(&TypeId::of::<T>());
         self.data
             .remove(&TypeId::of::<T>())
             .and_then(|boxed| boxed.downcast::<T>().ok())
@@ -189,6 +225,7 @@ impl Resources {
     #[allow(dead_code)]
     pub(crate) fn clear(&mut self) {
         self.data.clear();
+        self.names.clear();
     }
 }
 
@@ -6,6 +6,7 @@
 //! - Scene stack management
 //! - Quit state management
 //! - Ownership and distribution of Service/System/Resource contexts
+//! - Removal of scene-scoped resources ([`ResourceContext::insert_scoped`])
 //!
 //! # Example
 //!
@@ -49,17 +50,44 @@
 
 use super::{Scene, SceneTransition};
 use crate::context::{ResourceContext, ServiceContext, SystemContext};
-use crate::error::Result;
+use crate::error::{IssunError, Result};
+use crate::event::{Event, EventBus};
+use serde::{Deserialize, Serialize};
 use std::{future::Future, pin::Pin};
 
+/// Builds the scene a restarted run begins in
+type RestartScene<S> = Box<dyn Fn() -> S + Send + Sync>;
+
+/// Published when an exiting scene's scoped resources were removed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScopedResourcesDropped {
+    /// Instance id of the exited scene (1 for the initial scene)
+    pub scene: u64,
+    /// Type names of the removed resources, sorted
+    pub types: Vec<String>,
+}
+
+impl Event for ScopedResourcesDropped {}
+
 /// Scene Director manages scene lifecycle and transitions
 ///
 /// Phase 2+3: Stack-based scene management with full lifecycle hooks
+///
+/// Every scene on the stack gets an instance id. While one of its hooks runs,
+/// [`ResourceContext::insert_scoped`] ties resources to it; they are removed
+/// when the scene exits (switch, pop, replace, quit or restart) and
+/// [`ScopedResourcesDropped`] is published. Suspended scenes keep theirs.
 pub struct SceneDirector<S> {
     /// Scene stack (top is the active scene)
     stack: Vec<S>,
+    /// Instance id of each scene in `stack`
+    scene_ids: Vec<u64>,
+    /// Last instance id handed out
+    last_scene_id: u64,
     /// Whether the application should quit
     should_quit: bool,
+    /// Scene entered by `SceneTransition::Restart(None)`
+    restart_scene: Option<RestartScene<S>>,
     services: ServiceContext,
     systems: SystemContext,
     resources: ResourceContext,
@@ -81,23 +109,39 @@ impl<S: Scene> SceneDirector<S> {
     /// ).await;
     /// ```
     pub async fn new(
-        mut initial_scene: S,
+        initial_scene: S,
         services: ServiceContext,
-        mut systems: SystemContext,
-        mut resources: ResourceContext,
+        systems: SystemContext,
+        resources: ResourceContext,
     ) -> Self {
-        // Call on_enter for the initial scene
-        initial_scene
-            .on_enter(&services, &mut systems, &mut resources)
-            .await;
-
-        Self {
-            stack: vec![initial_scene],
+        let mut director = Self {
+            stack: Vec::new(),
+            scene_ids: Vec::new(),
+            last_scene_id: 0,
             should_quit: false,
+            restart_scene: None,
             services,
             systems,
             resources,
-        }
+        };
+
+        // Call on_enter for the initial scene
+        director.enter(initial_scene).await;
+        director
+    }
+
+    /// Set the scene a restarted run begins in (usually the title scene)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let director = SceneDirector::new(GameScene::Title(TitleData::new()), services, systems, resources)
+    ///     .await
+    ///     .with_restart_scene(|| GameScene::Title(TitleData::new()));
+    /// ```
+    pub fn with_restart_scene(mut self, scene: impl Fn() -> S + Send + Sync + 'static) -> Self {
+        self.restart_scene = Some(Box::new(scene));
+        self
     }
 
     /// Update the current scene (top of stack)
@@ -108,6 +152,7 @@ impl<S: Scene> SceneDirector<S> {
     ///
     /// The SceneTransition<S> indicating what should happen next.
     pub async fn update(&mut self) -> SceneTransition<S> {
+        self.resources.set_scope(self.scene_ids.last().copied());
         if let Some(current) = self.stack.last_mut() {
             current
                 .on_update(&self.services, &mut self.systems, &mut self.resources)
@@ -129,20 +174,12 @@ impl<S: Scene> SceneDirector<S> {
     /// ```ignore
     /// director.switch_to(GameScene::Combat(CombatData::new())).await;
     /// ```
-    pub async fn switch_to(&mut self, mut next: S) {
-        if let Some(mut current) = self.stack.pop() {
-            // Exit current scene
-            current
-                .on_exit(&self.services, &mut self.systems, &mut self.resources)
-                .await;
-        }
-
-        // Enter new scene
-        next.on_enter(&self.services, &mut self.systems, &mut self.resources)
-            .await;
+    pub async fn switch_to(&mut self, next: S) {
+        // Exit current scene
+        self.exit_top().await;
 
-        // Push new scene onto stack
-        self.stack.push(next);
+        // Enter new scene and push it onto the stack
+        self.enter(next).await;
     }
 
     /// Push a new scene on top of the stack
@@ -159,20 +196,17 @@ impl<S: Scene> SceneDirector<S> {
     /// ```ignore
     /// director.push(GameScene::PauseMenu(PauseData::new())).await;
     /// ```
-    pub async fn push(&mut self, mut next: S) {
-        // Suspend current scene (if any)
+    pub async fn push(&mut self, next: S) {
+        // Suspend current scene (if any); its scoped resources stay
+        self.resources.set_scope(self.scene_ids.last().copied());
         if let Some(current) = self.stack.last_mut() {
             current
                 .on_suspend(&self.services, &mut self.systems, &mut self.resources)
                 .await;
         }
 
-        // Enter new scene
-        next.on_enter(&self.services, &mut self.systems, &mut self.resources)
-            .await;
-
-        // Push onto stack
-        self.stack.push(next);
+        // Enter new scene and push it onto the stack
+        self.enter(next).await;
     }
 
     /// Pop the current scene from the stack
@@ -192,12 +226,8 @@ impl<S: Scene> SceneDirector<S> {
     /// }
     /// ```
     pub async fn pop(&mut self) -> bool {
-        if let Some(mut popped) = self.stack.pop() {
-            // Exit popped scene
-            popped
-                .on_exit(&self.services, &mut self.systems, &mut self.resources)
-                .await;
-
+        // Exit popped scene
+        if self.exit_top().await {
             // Resume scene below (if any)
             if let Some(current) = self.stack.last_mut() {
                 current
@@ -211,6 +241,19 @@ impl<S: Scene> SceneDirector<S> {
         }
     }
 
+    /// Replace the whole stack with a new scene
+    ///
+    /// This will:
+    /// 1. Call `on_exit()` on all scenes in the stack (from top to bottom)
+    /// 2. Call `on_enter()` on the new scene
+    ///
+    /// Unlike [`SceneDirector::restart`], no reset handlers run.
+    pub async fn replace_all(&mut self, next: S) {
+        while self.exit_top().await {}
+
+        self.enter(next).await;
+    }
+
     /// Transition to a new scene (deprecated in favor of switch_to)
     ///
     /// This is kept for backward compatibility with Phase 1 code.
@@ -231,15 +274,73 @@ impl<S: Scene> SceneDirector<S> {
     /// 2. Set the quit flag to true
     pub async fn quit(&mut self) {
         // Exit all scenes in reverse order (top to bottom)
-        while let Some(mut scene) = self.stack.pop() {
-            scene
-                .on_exit(&self.services, &mut self.systems, &mut self.resources)
-                .await;
-        }
+        while self.exit_top().await {}
 
         self.should_quit = true;
     }
 
+    /// Start a new run
+    ///
+    /// This will:
+    /// 1. Call `on_exit()` on all scenes in the stack (from top to bottom)
+    /// 2. Run the reset handlers of the `ResetRegistry` resource
+    /// 3. Publish `RunRestarted`
+    /// 4. Call `on_enter()` on `next`, or on a new restart scene if `None`
+    ///
+    /// Fails without touching anything if `next` is `None` and no restart
+    /// scene was set with `with_restart_scene`.
+    pub async fn restart(&mut self, next: Option<S>) -> Result<()> {
+        let next = match next.or_else(|| self.restart_scene.as_ref().map(|scene| scene())) {
+            Some(next) => next,
+            None => {
+                return Err(IssunError::GameLoop(
+                    "Restart without a scene; set one with SceneDirector::with_restart_scene"
+                        .to_string(),
+                ))
+            }
+        };
+
+        while self.exit_top().await {}
+
+        super::restart::reset_run(&mut self.resources).await;
+
+        self.enter(next).await;
+        Ok(())
+    }
+
+    /// Give `next` an instance id, call its `on_enter()` and push it
+    async fn enter(&mut self, mut next: S) {
+        self.last_scene_id += 1;
+        let id = self.last_scene_id;
+        self.resources.set_scope(Some(id));
+        next.on_enter(&self.services, &mut self.systems, &mut self.resources)
+            .await;
+        self.stack.push(next);
+        self.scene_ids.push(id);
+    }
+
+    /// Pop the top scene, call its `on_exit()` and drop its scoped resources
+    ///
+    /// Returns `false` if the stack was empty.
+    async fn exit_top(&mut self) -> bool {
+        let (Some(mut scene), Some(id)) = (self.stack.pop(), self.scene_ids.pop()) else {
+            return false;
+        };
+        self.resources.set_scope(Some(id));
+        scene
+            .on_exit(&self.services, &mut self.systems, &mut self.resources)
+            .await;
+
+        let types = self.resources.drop_scope(id);
+        self.resources.set_scope(self.scene_ids.last().copied());
+        if !types.is_empty() {
+            if let Some(mut bus) = self.resources.get_mut::<EventBus>().await {
+                bus.publish(ScopedResourcesDropped { scene: id, types });
+            }
+        }
+        true
+    }
+
     /// Check if the application should quit
     ///
     /// # Returns
@@ -387,6 +488,11 @@ impl<S: Scene> SceneDirector<S> {
         &mut self.resources
     }
 
+    /// Borrow all three contexts at once, independent of the scene stack
+    pub fn contexts_mut(&mut self) -> (&ServiceContext, &mut SystemContext, &mut ResourceContext) {
+        (&self.services, &mut self.systems, &mut self.resources)
+    }
+
     /// Handle a scene transition returned from update()
     ///
     /// This is the primary method for processing scene transitions.
@@ -404,6 +510,8 @@ impl<S: Scene> SceneDirector<S> {
     /// director.handle(transition).await?;
     /// ```
     pub async fn handle(&mut self, transition: SceneTransition<S>) -> Result<()> {
+        // Effects are drawn by GameRunner; the director only changes scenes
+        let (_, transition) = transition.into_parts();
         match transition {
             SceneTransition::Stay => {
                 // Do nothing
@@ -420,6 +528,13 @@ impl<S: Scene> SceneDirector<S> {
             SceneTransition::Quit => {
                 self.quit().await;
             }
+            SceneTransition::Replace(next) => {
+                self.replace_all(next).await;
+            }
+            SceneTransition::Restart(next) => {
+                self.restart(next).await?;
+            }
+            SceneTransition::Styled(..) => unreachable!("into_parts removes the style"),
         }
         Ok(())
     }
@@ -666,6 +781,49 @@ mod tests {
         assert_eq!(director.current().unwrap().name, "scene1");
     }
 
+    #[tokio::test]
+    async fn test_handle_replace_clears_stack() {
+        let mut director = director_with_scene(TestScene::new("game")).await;
+        director
+            .handle(SceneTransition::Push(TestScene::new("pause")))
+            .await
+            .unwrap();
+        director
+            .handle(SceneTransition::Push(TestScene::new("save_menu")))
+            .await
+            .unwrap();
+
+        director
+            .handle(SceneTransition::Replace(TestScene::new("loaded")))
+            .await
+            .unwrap();
+
+        assert_eq!(director.depth(), 1);
+        assert_eq!(director.current().unwrap().name, "loaded");
+        assert!(!director.should_quit());
+    }
+
+    #[tokio::test]
+    async fn test_handle_styled_transition() {
+        use crate::scene::TransitionStyle;
+        use std::time::Duration;
+
+        let mut director = director_with_scene(TestScene::new("scene1")).await;
+
+        // The director ignores the effect and performs the wrapped transition
+        let transition = SceneTransition::Push(TestScene::new("scene2"))
+            .with_style(TransitionStyle::dissolve(Duration::from_millis(150)));
+        assert!(transition.changes_scene());
+        director.handle(transition).await.unwrap();
+        assert_eq!(director.depth(), 2);
+
+        let transition =
+            SceneTransition::Pop.with_style(TransitionStyle::wipe_vertical(Duration::ZERO));
+        director.handle(transition).await.unwrap();
+        assert_eq!(director.depth(), 1);
+        assert_eq!(director.current().unwrap().name, "scene1");
+    }
+
     #[tokio::test]
     async fn test_handle_quit() {
         let scene1 = TestScene::new("scene1");
@@ -724,6 +882,37 @@ mod tests {
         assert_eq!(director.current().unwrap().name, "scene2");
     }
 
+    #[tokio::test]
+    async fn test_handle_restart() {
+        let mut director = director_with_scene(TestScene::new("title"))
+            .await
+            .with_restart_scene(|| TestScene::new("title"));
+        director.push(TestScene::new("result")).await;
+
+        director
+            .handle(SceneTransition::Restart(None))
+            .await
+            .unwrap();
+        assert_eq!(director.depth(), 1);
+        assert_eq!(director.current().unwrap().name, "title");
+        assert_eq!(director.current().unwrap().enter_count, 1);
+
+        director
+            .handle(SceneTransition::Restart(Some(TestScene::new("tutorial"))))
+            .await
+            .unwrap();
+        assert_eq!(director.current().unwrap().name, "tutorial");
+    }
+
+    #[tokio::test]
+    async fn test_restart_without_scene_fails() {
+        let mut director = director_with_scene(TestScene::new("title")).await;
+
+        assert!(director.restart(None).await.is_err());
+        assert_eq!(director.current().unwrap().name, "title");
+        assert_eq!(director.current().unwrap().exit_count, 0);
+    }
+
     #[tokio::test]
     async fn test_iter_scenes() {
         let scene1 = TestScene::new("scene1");
@@ -762,4 +951,154 @@ mod tests {
 
         assert_eq!(items, vec![(0, "scene1"), (1, "scene2")]);
     }
+
+    // Scenes that keep per-scene state in scoped resources
+    #[derive(Debug)]
+    enum ScopedScene {
+        Combat { keep_result: bool },
+        Pause,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct CombatPreview(u32);
+
+    #[derive(Debug, PartialEq)]
+    struct CombatResult(u32);
+
+    #[derive(Debug, PartialEq)]
+    struct PauseSelection(usize);
+
+    #[async_trait]
+    impl Scene for ScopedScene {
+        async fn on_enter(
+            &mut self,
+            _services: &ServiceContext,
+            _systems: &mut SystemContext,
+            resources: &mut ResourceContext,
+        ) {
+            match self {
+                ScopedScene::Combat { .. } => {
+                    resources.insert_scoped(CombatPreview(0));
+                    resources.insert_scoped(CombatResult(0));
+                }
+                ScopedScene::Pause => resources.insert_scoped(PauseSelection(0)),
+            }
+        }
+
+        async fn on_update(
+            &mut self,
+            _services: &ServiceContext,
+            _systems: &mut SystemContext,
+            resources: &mut ResourceContext,
+        ) -> SceneTransition<Self> {
+            if let Some(mut preview) = resources.get_mut::<CombatPreview>().await {
+                preview.0 += 1;
+            }
+            SceneTransition::Stay
+        }
+
+        async fn on_exit(
+            &mut self,
+            _services: &ServiceContext,
+            _systems: &mut SystemContext,
+            resources: &mut ResourceContext,
+        ) {
+            if let ScopedScene::Combat { keep_result: true } = self {
+                resources.promote::<CombatResult>();
+            }
+        }
+    }
+
+    async fn scoped_director(keep_result: bool) -> SceneDirector<ScopedScene> {
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        SceneDirector::new(
+            ScopedScene::Combat { keep_result },
+            ServiceContext::new(),
+            SystemContext::new(),
+            resources,
+        )
+        .await
+    }
+
+    fn dropped(director: &mut SceneDirector<ScopedScene>) -> Vec<ScopedResourcesDropped> {
+        let mut bus = director.resources_mut().try_get_mut::<EventBus>().unwrap();
+        bus.dispatch();
+        bus.reader::<ScopedResourcesDropped>()
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_scoped_resources_live_until_scene_exits() {
+        let mut director = scoped_director(false).await;
+        director.update().await;
+        assert_eq!(
+            *director.resources().get::<CombatPreview>().await.unwrap(),
+            CombatPreview(1)
+        );
+        assert!(director.resources().is_scoped::<CombatPreview>());
+
+        director.switch_to(ScopedScene::Pause).await;
+        assert!(!director.resources().contains::<CombatPreview>());
+        assert!(!director.resources().contains::<CombatResult>());
+        assert!(director.resources().contains::<PauseSelection>());
+        assert_eq!(
+            dropped(&mut director),
+            vec![ScopedResourcesDropped {
+                scene: 1,
+                types: vec![
+                    std::any::type_name::<CombatPreview>().to_string(),
+                    std::any::type_name::<CombatResult>().to_string(),
+                ],
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scoped_resources_survive_suspend_and_resume() {
+        let mut director = scoped_director(false).await;
+        director.push(ScopedScene::Pause).await;
+        assert!(director.resources().contains::<CombatPreview>());
+        assert!(director.resources().contains::<PauseSelection>());
+
+        director.pop().await;
+        assert!(director.resources().contains::<CombatPreview>());
+        assert!(!director.resources().contains::<PauseSelection>());
+
+        director.update().await;
+        assert_eq!(
+            *director.resources().get::<CombatPreview>().await.unwrap(),
+            CombatPreview(1)
+        );
+        assert_eq!(dropped(&mut director)[0].scene, 2);
+    }
+
+    #[tokio::test]
+    async fn test_promoted_resource_outlives_its_scene() {
+        let mut director = scoped_director(true).await;
+        director.quit().await;
+
+        assert!(!director.resources().contains::<CombatPreview>());
+        assert!(director.resources().contains::<CombatResult>());
+        assert!(!director.resources().is_scoped::<CombatResult>());
+        assert_eq!(
+            dropped(&mut director)[0].types,
+            vec![std::any::type_name::<CombatPreview>().to_string()]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "outside a scene lifecycle hook")
+    )]
+    fn test_insert_scoped_without_scene_is_global_in_release() {
+        let mut resources = ResourceContext::new();
+        resources.insert_scoped(CombatPreview(0));
+        assert!(resources.contains::<CombatPreview>());
+        assert!(!resources.is_scoped::<CombatPreview>());
+        assert!(!resources.promote::<CombatPreview>());
+    }
 }
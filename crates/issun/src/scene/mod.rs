@@ -1,15 +1,47 @@
 //! Scene system for ISSUN
 //!
 //! Scenes represent distinct game states with their own data and lifecycle.
+//!
+//! `#[derive(Scene)]` works on structs and enums, but variant dispatch
+//! (`handler_params`) and the generated GameState (`initial`) need an enum:
+//!
+//! ```compile_fail
+//! use issun::Scene;
+//!
+//! #[derive(Scene)]
+//! #[scene(handler_params = "input: issun::ui::InputEvent")]
+//! struct TitleScene {
+//!     selected: usize,
+//! }
+//! ```
+//!
+//! Delegated hooks (`delegate = "on_enter, on_update"`) must exist as
+//! inherent methods on every variant's data:
+//!
+//! ```compile_fail
+//! use issun::Scene;
+//!
+//! struct CombatData;
+//!
+//! #[derive(Scene)]
+//! #[scene(delegate = "on_update")]
+//! enum GameScene {
+//!     Combat(CombatData),
+//! }
+//! ```
 
 use crate::context::{ResourceContext, ServiceContext, SystemContext};
 use async_trait::async_trait;
 
 // Sub-modules
 pub mod director;
+pub mod restart;
+pub mod style;
 
 // Re-exports
-pub use director::SceneDirector;
+pub use director::{SceneDirector, ScopedResourcesDropped};
+pub use restart::{ResetHandler, ResetRegistry, ResetToInitial, ResetWith, RunRestarted};
+pub use style::{TransitionEffect, TransitionStyle};
 
 /// Scene transition result
 ///
@@ -41,6 +73,57 @@ pub enum SceneTransition<S> {
     Pop,
     /// Quit the game
     Quit,
+    /// Exit every scene and enter the given one, keeping the run's state
+    /// (e.g. after loading a save from a menu pushed over the game)
+    Replace(S),
+    /// Start a new run: exit every scene, run the registered reset handlers
+    /// and enter the given scene, or the director's restart scene if `None`
+    Restart(Option<S>),
+    /// Perform the wrapped transition with a visual effect (see
+    /// [`SceneTransition::with_style`])
+    Styled(TransitionStyle, Box<SceneTransition<S>>),
+}
+
+impl<S> SceneTransition<S> {
+    /// Play `style` when `GameRunner` performs this transition
+    ///
+    /// ```
+    /// use issun::scene::{SceneTransition, TransitionStyle};
+    /// use std::time::Duration;
+    ///
+    /// let transition = SceneTransition::Switch("combat")
+    ///     .with_style(TransitionStyle::wipe_horizontal(Duration::from_millis(150)));
+    /// assert!(transition.style().is_some());
+    /// ```
+    pub fn with_style(self, style: TransitionStyle) -> Self {
+        Self::Styled(style, Box::new(self.into_parts().1))
+    }
+
+    /// Style requested for this transition, if any
+    pub fn style(&self) -> Option<TransitionStyle> {
+        match self {
+            Self::Styled(style, _) => Some(*style),
+            _ => None,
+        }
+    }
+
+    /// Split off the requested style
+    pub fn into_parts(self) -> (Option<TransitionStyle>, Self) {
+        match self {
+            Self::Styled(style, inner) => (Some(style), inner.into_parts().1),
+            other => (None, other),
+        }
+    }
+
+    /// Whether this transition changes the scene stack (anything but
+    /// `Stay` and `Quit`)
+    pub fn changes_scene(&self) -> bool {
+        match self {
+            Self::Stay | Self::Quit => false,
+            Self::Styled(_, inner) => inner.changes_scene(),
+            _ => true,
+        }
+    }
 }
 
 /// Scene trait with lifecycle methods
@@ -68,6 +151,7 @@ pub trait Scene: Send + Sized {
     /// - Scene is first created (via `SceneDirector::new(..)` with contexts)
     /// - Scene is pushed onto the stack (via `Push`)
     /// - Scene is switched to (via `Switch`)
+    /// - A new run starts (via `Restart`)
     async fn on_enter(
         &mut self,
         _services: &ServiceContext,
@@ -82,6 +166,7 @@ pub trait Scene: Send + Sized {
     /// - Scene is popped from the stack (via `Pop`)
     /// - Scene is replaced (via `Switch`)
     /// - Application quits (via `Quit`)
+    /// - The run restarts (via `Restart`)
     async fn on_exit(
         &mut self,
         _services: &ServiceContext,
@@ -150,6 +235,15 @@ pub trait Scene: Send + Sized {
     ) -> SceneTransition<Self> {
         SceneTransition::Stay
     }
+
+    /// Name shown in diagnostics such as crash reports
+    ///
+    /// Default: the type name. `#[derive(Scene)]` enums report the variant
+    /// (`GameScene::Combat`).
+    fn scene_name(&self) -> String {
+        let name = std::any::type_name::<Self>();
+        name.rsplit("::").next().unwrap_or(name).to_string()
+    }
 }
 
 #[cfg(test)]
@@ -204,6 +298,14 @@ mod tests {
     enum GameScene {
         Title,
         Combat,
+        Shop(u32),
+    }
+
+    #[test]
+    fn test_scene_name() {
+        assert_eq!(TestScene { entered: false }.scene_name(), "TestScene");
+        assert_eq!(GameScene::Combat.scene_name(), "GameScene::Combat");
+        assert_eq!(GameScene::Shop(3).scene_name(), "GameScene::Shop");
     }
 
     #[tokio::test]
@@ -0,0 +1,275 @@
+//! Run restarts ("play again")
+//!
+//! [`SceneTransition::Restart`](super::SceneTransition::Restart) exits every
+//! scene, runs the [`ResetRegistry`] chain and enters the restart scene again.
+//! Plugins and games opt in per resource:
+//!
+//! ```ignore
+//! // In Plugin::build: restore the value the game was built with
+//! builder.register_reset_to_initial::<DungeonState>();
+//!
+//! // In a game: custom reset logic
+//! let game = GameBuilder::new()
+//!     .with_resource(Score::default())
+//!     .with_resource(Profile::load())          // no handler: survives restarts
+//!     .with_reset_handler("my_game", ResetWith::new(|score: &mut Score| score.points = 0))
+//!     .build()
+//!     .await?;
+//! ```
+//!
+//! Resources without a handler are left untouched; plugins that registered
+//! none are listed in [`RunRestarted`].
+
+use crate::context::ResourceContext;
+use crate::engine::lifecycle::PluginLifecycle;
+use crate::event::{Event, EventBus};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Restores resources to their start-of-run state
+#[async_trait]
+pub trait ResetHandler: Send + Sync {
+    /// Remember the post-build state; called once when the game is built
+    async fn capture(&self, _resources: &ResourceContext) {}
+
+    /// Restore the resources this handler owns
+    async fn reset(&self, resources: &mut ResourceContext);
+}
+
+/// Resets `T` to a clone of its value right after `GameBuilder::build`
+pub struct ResetToInitial<T> {
+    initial: Mutex<Option<T>>,
+}
+
+impl<T> ResetToInitial<T> {
+    pub fn new() -> Self {
+        Self {
+            initial: Mutex::new(None),
+        }
+    }
+}
+
+impl<T> Default for ResetToInitial<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync + 'static> ResetHandler for ResetToInitial<T> {
+    async fn capture(&self, resources: &ResourceContext) {
+        let value = resources.get::<T>().await.map(|value| T::clone(&value));
+        *self.initial.lock().unwrap_or_else(PoisonError::into_inner) = value;
+    }
+
+    async fn reset(&self, resources: &mut ResourceContext) {
+        // A panic elsewhere while the lock was held must not cancel restarts
+        let initial = self
+            .initial
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        if let (Some(initial), Some(mut value)) = (initial, resources.get_mut::<T>().await) {
+            *value = initial;
+        }
+    }
+}
+
+/// Resets `T` in place with a closure
+pub struct ResetWith<T, F> {
+    reset: F,
+    _marker: PhantomData<fn(&mut T)>,
+}
+
+impl<T, F> ResetWith<T, F>
+where
+    F: Fn(&mut T) + Send + Sync,
+{
+    pub fn new(reset: F) -> Self {
+        Self {
+            reset,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<T, F> ResetHandler for ResetWith<T, F>
+where
+    T: Send + Sync + 'static,
+    F: Fn(&mut T) + Send + Sync,
+{
+    async fn reset(&self, resources: &mut ResourceContext) {
+        if let Some(mut value) = resources.get_mut::<T>().await {
+            (self.reset)(&mut value);
+        }
+    }
+}
+
+/// Reset handlers in registration order, with the plugin or game owning each
+#[derive(Default, Clone)]
+pub struct ResetRegistry {
+    handlers: Vec<(String, Arc<dyn ResetHandler>)>,
+    restarts: u32,
+}
+
+impl ResetRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a handler owned by `owner` (a plugin name or game label)
+    pub fn register(&mut self, owner: impl Into<String>, handler: impl ResetHandler + 'static) {
+        self.handlers.push((owner.into(), Arc::new(handler)));
+    }
+
+    /// Add a boxed handler owned by `owner`
+    pub fn register_boxed(&mut self, owner: impl Into<String>, handler: Box<dyn ResetHandler>) {
+        self.handlers.push((owner.into(), Arc::from(handler)));
+    }
+
+    /// Append every handler of `other`, keeping their owners
+    pub fn extend(&mut self, other: ResetRegistry) {
+        self.handlers.extend(other.handlers);
+    }
+
+    /// Owners with at least one handler, in registration order
+    pub fn owners(&self) -> Vec<String> {
+        let mut owners: Vec<String> = Vec::new();
+        for (owner, _) in &self.handlers {
+            if !owners.contains(owner) {
+                owners.push(owner.clone());
+            }
+        }
+        owners
+    }
+
+    /// Number of handlers
+    pub fn len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    /// Restarts performed so far
+    pub fn restarts(&self) -> u32 {
+        self.restarts
+    }
+
+    /// Let every handler remember the current state
+    pub async fn capture(&self, resources: &ResourceContext) {
+        for (_, handler) in &self.handlers {
+            handler.capture(resources).await;
+        }
+    }
+}
+
+impl crate::resources::Resource for ResetRegistry {}
+
+/// Published after a restart reset the registered resources
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunRestarted {
+    /// 1 for the first restart
+    pub restart: u32,
+    /// Plugins and games whose reset handlers ran
+    pub reset: Vec<String>,
+    /// Plugins without a reset handler; their state was left as is
+    pub untouched: Vec<String>,
+}
+
+impl Event for RunRestarted {}
+
+/// Run the reset chain in `resources` and publish [`RunRestarted`]
+pub(crate) async fn reset_run(resources: &mut ResourceContext) -> RunRestarted {
+    let (handlers, reset, restart) = match resources.get_mut::<ResetRegistry>().await {
+        Some(mut registry) => {
+            registry.restarts += 1;
+            (
+                registry.handlers.clone(),
+                registry.owners(),
+                registry.restarts,
+            )
+        }
+        None => (Vec::new(), Vec::new(), 1),
+    };
+
+    for (_, handler) in &handlers {
+        handler.reset(resources).await;
+    }
+
+    let untouched = match resources.get::<PluginLifecycle>().await {
+        Some(lifecycle) => lifecycle
+            .plugin_names()
+            .into_iter()
+            .filter(|name| !reset.iter().any(|owner| owner == name))
+            .map(str::to_string)
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let event = RunRestarted {
+        restart,
+        reset,
+        untouched,
+    };
+    if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+        bus.publish(event.clone());
+    }
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Score(u32);
+
+    #[derive(Debug, PartialEq)]
+    struct Streak(u32);
+
+    #[tokio::test]
+    async fn test_handlers_restore_resources() {
+        let mut resources = ResourceContext::new();
+        resources.insert(Score(10));
+        resources.insert(Streak(4));
+
+        let mut registry = ResetRegistry::new();
+        registry.register("game", ResetToInitial::<Score>::new());
+        registry.register("game", ResetWith::new(|streak: &mut Streak| streak.0 = 0));
+        registry.capture(&resources).await;
+        assert_eq!(registry.owners(), vec!["game".to_string()]);
+        resources.insert(registry);
+
+        resources.get_mut::<Score>().await.unwrap().0 = 99;
+        let event = reset_run(&mut resources).await;
+
+        assert_eq!(*resources.get::<Score>().await.unwrap(), Score(10));
+        assert_eq!(*resources.get::<Streak>().await.unwrap(), Streak(0));
+        assert_eq!(event.restart, 1);
+        assert_eq!(event.reset, vec!["game".to_string()]);
+        assert_eq!(reset_run(&mut resources).await.restart, 2);
+    }
+
+    #[tokio::test]
+    async fn test_reset_survives_a_poisoned_lock() {
+        let mut resources = ResourceContext::new();
+        resources.insert(Score(10));
+        let handler = ResetToInitial::<Score>::new();
+        handler.capture(&resources).await;
+
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _held = handler.initial.lock().unwrap();
+            panic!("poison");
+        }));
+        assert!(handler.initial.is_poisoned());
+
+        resources.get_mut::<Score>().await.unwrap().0 = 99;
+        handler.reset(&mut resources).await;
+        assert_eq!(*resources.get::<Score>().await.unwrap(), Score(10));
+    }
+}
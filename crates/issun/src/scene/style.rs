@@ -0,0 +1,68 @@
+//! Visual effects played when the scene changes
+//!
+//! A [`TransitionStyle`] travels with a transition
+//! (`SceneTransition::Switch(next).with_style(..)`) or is set as the default
+//! of a [`GameRunner`](crate::engine::GameRunner). Only the runner draws the
+//! effect; the director and the headless runner ignore it.
+
+use std::time::Duration;
+
+/// How the outgoing frame gives way to the incoming scene
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TransitionEffect {
+    /// Replace the frame at once
+    #[default]
+    Instant,
+    /// The new scene sweeps in from the left
+    WipeHorizontal,
+    /// The new scene sweeps in from the top
+    WipeVertical,
+    /// Cells switch over in a fixed pseudo-random order
+    Dissolve,
+}
+
+/// A transition effect and how long it plays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TransitionStyle {
+    pub effect: TransitionEffect,
+    pub duration: Duration,
+}
+
+impl TransitionStyle {
+    /// No effect (the default)
+    pub const fn instant() -> Self {
+        Self {
+            effect: TransitionEffect::Instant,
+            duration: Duration::ZERO,
+        }
+    }
+
+    /// Left-to-right wipe
+    pub const fn wipe_horizontal(duration: Duration) -> Self {
+        Self {
+            effect: TransitionEffect::WipeHorizontal,
+            duration,
+        }
+    }
+
+    /// Top-to-bottom wipe
+    pub const fn wipe_vertical(duration: Duration) -> Self {
+        Self {
+            effect: TransitionEffect::WipeVertical,
+            duration,
+        }
+    }
+
+    /// Dissolve via a deterministic cell shuffle
+    pub const fn dissolve(duration: Duration) -> Self {
+        Self {
+            effect: TransitionEffect::Dissolve,
+            duration,
+        }
+    }
+
+    /// Whether there is anything to animate
+    pub fn is_instant(&self) -> bool {
+        self.effect == TransitionEffect::Instant || self.duration.is_zero()
+    }
+}
@@ -1,6 +1,27 @@
 //! Service system for ISSUN
 //!
 //! Services provide reusable game systems and utilities
+//!
+//! `#[derive(Service)]` names a service after its type (`CombatService` is
+//! `"combat_service"`) unless `#[service(name = "...")]` says otherwise.
+//! Empty or conflicting names are rejected:
+//!
+//! ```compile_fail
+//! use issun::Service;
+//!
+//! #[derive(Clone, Service)]
+//! #[service(name = "combat")]
+//! #[service(name = "fight")]
+//! struct CombatService;
+//! ```
+//!
+//! ```compile_fail
+//! use issun::Service;
+//!
+//! #[derive(Clone, Service)]
+//! #[service(name = "")]
+//! struct CombatService;
+//! ```
 
 use crate::context::Context;
 use async_trait::async_trait;
@@ -5,6 +5,7 @@ use crate::storage::repository::SaveRepository;
 use crate::storage::save_data::{SaveData, SaveMetadata};
 use async_trait::async_trait;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 use tokio::fs;
 
 /// JSON-based save repository
@@ -68,15 +69,21 @@ impl SaveRepository for JsonSaveRepository {
 
             if path.extension().and_then(|s| s.to_str()) == Some("json") {
                 if let Some(slot) = path.file_stem().and_then(|s| s.to_str()) {
-                    if let Ok(metadata) = self.get_metadata(slot).await {
-                        saves.push(metadata);
+                    match self.get_metadata(slot).await {
+                        Ok(metadata) => saves.push(metadata),
+                        // Listed so menus can show the slot as unreadable
+                        Err(_) => {
+                            let file = entry.metadata().await?;
+                            let modified = file.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                            saves.push(SaveMetadata::corrupted(slot, modified, file.len()));
+                        }
                     }
                 }
             }
         }
 
         // Sort by timestamp (newest first)
-        saves.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        saves.sort_by_key(|s| std::cmp::Reverse(s.timestamp));
 
         Ok(saves)
     }
@@ -146,6 +153,23 @@ mod tests {
         assert_eq!(saves.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_list_saves_marks_corrupted_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = JsonSaveRepository::new(temp_dir.path()).await.unwrap();
+
+        repo.save(&SaveData::new("slot1", serde_json::json!({"score": 100})))
+            .await
+            .unwrap();
+        std::fs::write(temp_dir.path().join("slot2.json"), "{ not json").unwrap();
+
+        let saves = repo.list_saves().await.unwrap();
+        assert_eq!(saves.len(), 2);
+        let broken = saves.iter().find(|s| s.slot == "slot2").unwrap();
+        assert!(broken.corrupted);
+        assert!(repo.load("slot2").await.is_err());
+    }
+
     #[tokio::test]
     async fn test_delete() {
         let temp_dir = TempDir::new().unwrap();
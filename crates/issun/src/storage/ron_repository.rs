@@ -5,6 +5,7 @@ use crate::storage::repository::SaveRepository;
 use crate::storage::save_data::{SaveData, SaveMetadata};
 use async_trait::async_trait;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 use tokio::fs;
 
 /// RON-based save repository
@@ -68,15 +69,21 @@ impl SaveRepository for RonSaveRepository {
 
             if path.extension().and_then(|s| s.to_str()) == Some("ron") {
                 if let Some(slot) = path.file_stem().and_then(|s| s.to_str()) {
-                    if let Ok(metadata) = self.get_metadata(slot).await {
-                        saves.push(metadata);
+                    match self.get_metadata(slot).await {
+                        Ok(metadata) => saves.push(metadata),
+                        // Listed so menus can show the slot as unreadable
+                        Err(_) => {
+                            let file = entry.metadata().await?;
+                            let modified = file.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                            saves.push(SaveMetadata::corrupted(slot, modified, file.len()));
+                        }
                     }
                 }
             }
         }
 
         // Sort by timestamp (newest first)
-        saves.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        saves.sort_by_key(|s| std::cmp::Reverse(s.timestamp));
 
         Ok(saves)
     }
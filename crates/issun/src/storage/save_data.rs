@@ -56,6 +56,15 @@ pub struct SaveMetadata {
     #[serde(with = "system_time_serde")]
     pub timestamp: SystemTime,
     pub size_bytes: u64,
+    /// Label given when saving (`"label"` in the game data)
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Play time in seconds (`"play_time_secs"` in the game data)
+    #[serde(default)]
+    pub play_time_secs: u64,
+    /// The slot file exists but could not be parsed
+    #[serde(default)]
+    pub corrupted: bool,
 }
 
 impl SaveMetadata {
@@ -65,6 +74,32 @@ impl SaveMetadata {
             slot: data.slot.clone(),
             timestamp: data.timestamp,
             size_bytes,
+            label: data
+                .data
+                .get("label")
+                .and_then(|label| label.as_str())
+                .map(str::to_string),
+            play_time_secs: data
+                .data
+                .get("play_time_secs")
+                .and_then(|secs| secs.as_u64())
+                .unwrap_or(0),
+            corrupted: false,
+        }
+    }
+
+    /// Metadata of a slot file that could not be parsed
+    ///
+    /// `timestamp` is the file's modification time.
+    pub fn corrupted(slot: impl Into<String>, timestamp: SystemTime, size_bytes: u64) -> Self {
+        Self {
+            version: 0,
+            slot: slot.into(),
+            timestamp,
+            size_bytes,
+            label: None,
+            play_time_secs: 0,
+            corrupted: true,
         }
     }
 }
@@ -126,4 +161,29 @@ mod tests {
 
         assert_eq!(ctx, loaded);
     }
+
+    #[test]
+    fn test_metadata_reads_label_and_play_time() {
+        let save = SaveData::new(
+            "slot1",
+            serde_json::json!({ "label": "Floor 3", "play_time_secs": 754 }),
+        );
+        let metadata = SaveMetadata::from_save_data(&save, 64);
+
+        assert_eq!(metadata.label.as_deref(), Some("Floor 3"));
+        assert_eq!(metadata.play_time_secs, 754);
+        assert!(!metadata.corrupted);
+    }
+
+    #[test]
+    fn test_metadata_defaults_for_older_files() {
+        let metadata: SaveMetadata = serde_json::from_str(
+            r#"{ "version": 1, "slot": "slot1", "timestamp": 0, "size_bytes": 10 }"#,
+        )
+        .unwrap();
+
+        assert_eq!(metadata.label, None);
+        assert_eq!(metadata.play_time_secs, 0);
+        assert!(!metadata.corrupted);
+    }
 }
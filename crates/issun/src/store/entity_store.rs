@@ -0,0 +1,647 @@
+//! Entities with string IDs and optional attached components
+//!
+//! Components keep rarely-present data (status effects, a vendor inventory,
+//! quest giver state) out of the entity struct. Each component type lives in
+//! its own side map keyed by entity id, so this is deliberately not an ECS:
+//! there are no archetypes or systems, and `query_with` walks one map. It is
+//! meant for hundreds to low thousands of entities.
+
+use crate::error::{IssunError, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::any::{type_name, Any, TypeId};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Store for entities with string IDs
+///
+/// Besides the entity itself, any number of components of different types
+/// can be attached to an entity. Removing the entity removes its components.
+///
+/// # Example
+///
+/// ```
+/// use issun::store::EntityStore;
+///
+/// #[derive(Debug, Clone)]
+/// struct Player {
+///     name: String,
+///     hp: i32,
+/// }
+///
+/// struct Vendor {
+///     stock: Vec<String>,
+/// }
+///
+/// let mut players = EntityStore::new();
+/// players.insert("alice".to_string(), Player { name: "Alice".into(), hp: 100 });
+/// players.insert("bob".to_string(), Player { name: "Bob".into(), hp: 90 });
+///
+/// // Get all alive players
+/// let alive: Vec<_> = players.values()
+///     .filter(|p| p.hp > 0)
+///     .collect();
+/// assert_eq!(alive.len(), 2);
+///
+/// // Only Bob sells things
+/// players.attach("bob", Vendor { stock: vec!["potion".into()] }).ok();
+/// let vendors: Vec<_> = players.query_with::<Vendor>().map(|(id, _)| id).collect();
+/// assert_eq!(vendors, ["bob"]);
+/// ```
+pub struct EntityStore<V> {
+    data: HashMap<String, V>,
+    components: HashMap<TypeId, Column>,
+}
+
+/// All components of one type, keyed by entity id
+struct Column {
+    type_name: &'static str,
+    data: Box<dyn ComponentMap>,
+    serde: Option<ComponentSerde>,
+}
+
+impl Column {
+    fn new<C: Send + Sync + 'static>() -> Self {
+        Self {
+            type_name: type_name::<C>(),
+            data: Box::new(HashMap::<String, C>::new()),
+            serde: None,
+        }
+    }
+}
+
+/// Type-erased `HashMap<String, C>`
+trait ComponentMap: Send + Sync {
+    fn remove_entity(&mut self, id: &str);
+    fn retain_entities(&mut self, keep: &dyn Fn(&str) -> bool);
+    fn clear_all(&mut self);
+    fn count(&self) -> usize;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<C: Send + Sync + 'static> ComponentMap for HashMap<String, C> {
+    fn remove_entity(&mut self, id: &str) {
+        self.remove(id);
+    }
+
+    fn retain_entities(&mut self, keep: &dyn Fn(&str) -> bool) {
+        self.retain(|id, _| keep(id));
+    }
+
+    fn clear_all(&mut self) {
+        self.clear();
+    }
+
+    fn count(&self) -> usize {
+        self.len()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+type SavedComponents = BTreeMap<String, serde_json::Value>;
+
+/// Serde functions of a registered component type
+struct ComponentSerde {
+    name: String,
+    save: fn(&dyn Any) -> serde_json::Result<SavedComponents>,
+    load: fn(SavedComponents) -> serde_json::Result<Box<dyn ComponentMap>>,
+}
+
+fn save_components<C: Serialize + 'static>(data: &dyn Any) -> serde_json::Result<SavedComponents> {
+    let components = data
+        .downcast_ref::<HashMap<String, C>>()
+        .expect("column holds its registered type");
+    components
+        .iter()
+        .map(|(id, component)| Ok((id.clone(), serde_json::to_value(component)?)))
+        .collect()
+}
+
+fn load_components<C: DeserializeOwned + Send + Sync + 'static>(
+    saved: SavedComponents,
+) -> serde_json::Result<Box<dyn ComponentMap>> {
+    let components = saved
+        .into_iter()
+        .map(|(id, value)| Ok((id, serde_json::from_value::<C>(value)?)))
+        .collect::<serde_json::Result<HashMap<_, _>>>()?;
+    Ok(Box::new(components))
+}
+
+/// Serialized [`EntityStore`], from [`EntityStore::snapshot`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EntityStoreSnapshot {
+    /// Entities by id
+    pub entities: BTreeMap<String, serde_json::Value>,
+    /// Registered components by registration name, then entity id
+    #[serde(default)]
+    pub components: BTreeMap<String, BTreeMap<String, serde_json::Value>>,
+}
+
+/// What [`EntityStore::snapshot`] saved and what it had to leave out
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SaveReport {
+    /// Registration names of the saved component types
+    pub saved: Vec<String>,
+    /// One warning per unregistered component type that was skipped
+    pub warnings: Vec<String>,
+}
+
+impl SaveReport {
+    /// Whether every attached component was saved
+    pub fn is_complete(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+impl<V> EntityStore<V> {
+    /// Create a new empty store
+    pub fn new() -> Self {
+        Self {
+            data: HashMap::new(),
+            components: HashMap::new(),
+        }
+    }
+
+    /// Create a store with a specified entity capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            data: HashMap::with_capacity(capacity),
+            components: HashMap::new(),
+        }
+    }
+
+    /// Insert an entity
+    ///
+    /// Returns the previous entity if the id already existed. Components
+    /// attached to the id are kept.
+    pub fn insert(&mut self, id: impl Into<String>, entity: V) -> Option<V> {
+        self.data.insert(id.into(), entity)
+    }
+
+    /// Get a reference to an entity by id
+    pub fn get(&self, id: &str) -> Option<&V> {
+        self.data.get(id)
+    }
+
+    /// Get a mutable reference to an entity by id
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut V> {
+        self.data.get_mut(id)
+    }
+
+    /// Remove an entity and all its components
+    ///
+    /// Returns the removed entity if it existed.
+    pub fn remove(&mut self, id: &str) -> Option<V> {
+        let entity = self.data.remove(id)?;
+        for column in self.components.values_mut() {
+            column.data.remove_entity(id);
+        }
+        Some(entity)
+    }
+
+    /// Check if an entity exists in the store
+    pub fn contains_key(&self, id: &str) -> bool {
+        self.data.contains_key(id)
+    }
+
+    /// Get the number of entities in the store
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if the store is empty
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Remove all entities and components
+    ///
+    /// Component registrations are kept.
+    pub fn clear(&mut self) {
+        self.data.clear();
+        for column in self.components.values_mut() {
+            column.data.clear_all();
+        }
+    }
+
+    /// Get an iterator over the store's ids and entities
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &V)> {
+        self.data.iter()
+    }
+
+    /// Get a mutable iterator over the store's ids and entities
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&String, &mut V)> {
+        self.data.iter_mut()
+    }
+
+    /// Get an iterator over the store's ids
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.data.keys()
+    }
+
+    /// Get an iterator over the store's entities
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.data.values()
+    }
+
+    /// Get a mutable iterator over the store's entities
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.data.values_mut()
+    }
+
+    /// Retain only the entities that satisfy the predicate
+    ///
+    /// Components of the dropped entities are removed too.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&String, &mut V) -> bool,
+    {
+        self.data.retain(f);
+        let data = &self.data;
+        for column in self.components.values_mut() {
+            column.data.retain_entities(&|id| data.contains_key(id));
+        }
+    }
+
+    /// Attach a component to an entity, replacing one of the same type
+    ///
+    /// Returns the replaced component, or the given one back as `Err` when
+    /// `id` is not in the store.
+    pub fn attach<C: Send + Sync + 'static>(
+        &mut self,
+        id: &str,
+        component: C,
+    ) -> std::result::Result<Option<C>, C> {
+        if !self.data.contains_key(id) {
+            return Err(component);
+        }
+        Ok(self.column_entry::<C>().insert(id.to_string(), component))
+    }
+
+    /// Get an entity's component of type `C`
+    pub fn get_component<C: Send + Sync + 'static>(&self, id: &str) -> Option<&C> {
+        self.column::<C>()?.get(id)
+    }
+
+    /// Get a mutable reference to an entity's component of type `C`
+    pub fn get_component_mut<C: Send + Sync + 'static>(&mut self, id: &str) -> Option<&mut C> {
+        self.column_mut::<C>()?.get_mut(id)
+    }
+
+    /// Detach an entity's component of type `C`
+    pub fn remove_component<C: Send + Sync + 'static>(&mut self, id: &str) -> Option<C> {
+        self.column_mut::<C>()?.remove(id)
+    }
+
+    /// Iterate over the entities that have a component of type `C`
+    ///
+    /// Yields `(id, component)` pairs in no particular order.
+    pub fn query_with<C: Send + Sync + 'static>(&self) -> impl Iterator<Item = (&str, &C)> {
+        self.column::<C>()
+            .into_iter()
+            .flat_map(|components| components.iter())
+            .map(|(id, component)| (id.as_str(), component))
+    }
+
+    /// Save components of type `C` in snapshots, under `name`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is already registered for another component type.
+    pub fn register_component<C>(&mut self, name: impl Into<String>)
+    where
+        C: Serialize + DeserializeOwned + Send + Sync + 'static,
+    {
+        let name = name.into();
+        if let Some(other) = self
+            .components
+            .iter()
+            .find(|(type_id, column)| {
+                **type_id != TypeId::of::<C>()
+                    && column.serde.as_ref().map(|serde| &serde.name) == Some(&name)
+            })
+            .map(|(_, column)| column.type_name)
+        {
+            panic!(
+                "component name '{}' is already registered for {}",
+                name, other
+            );
+        }
+
+        self.components
+            .entry(TypeId::of::<C>())
+            .or_insert_with(Column::new::<C>)
+            .serde = Some(ComponentSerde {
+            name,
+            save: save_components::<C>,
+            load: load_components::<C>,
+        });
+    }
+
+    /// Serialize the entities and their registered components
+    ///
+    /// Components of unregistered types are skipped; the report lists a
+    /// warning for each such type.
+    pub fn snapshot(&self) -> Result<(EntityStoreSnapshot, SaveReport)>
+    where
+        V: Serialize,
+    {
+        let mut snapshot = EntityStoreSnapshot::default();
+        let mut report = SaveReport::default();
+
+        for (id, entity) in &self.data {
+            snapshot
+                .entities
+                .insert(id.clone(), serde_json::to_value(entity).map_err(to_issun)?);
+        }
+
+        for column in self.components.values() {
+            match &column.serde {
+                Some(serde) => {
+                    let saved = (serde.save)(column.data.as_any()).map_err(to_issun)?;
+                    snapshot.components.insert(serde.name.clone(), saved);
+                    report.saved.push(serde.name.clone());
+                }
+                None if column.data.count() > 0 => report.warnings.push(format!(
+                    "component {} is not registered for saving; skipped {} attachment(s)",
+                    column.type_name,
+                    column.data.count()
+                )),
+                None => {}
+            }
+        }
+
+        report.saved.sort();
+        report.warnings.sort();
+        Ok((snapshot, report))
+    }
+
+    /// Replace the store's contents with a snapshot
+    ///
+    /// Unregistered components end up empty. Components saved under names
+    /// not registered here, or attached to ids without an entity, are
+    /// ignored. On error the store is left unchanged.
+    pub fn restore(&mut self, snapshot: EntityStoreSnapshot) -> Result<()>
+    where
+        V: DeserializeOwned,
+    {
+        let data = snapshot
+            .entities
+            .into_iter()
+            .map(|(id, value)| Ok((id, serde_json::from_value(value)?)))
+            .collect::<serde_json::Result<HashMap<_, _>>>()
+            .map_err(to_issun)?;
+
+        let mut saved_components = snapshot.components;
+        let mut loaded = Vec::new();
+        for (type_id, column) in &self.components {
+            let Some(serde) = &column.serde else {
+                continue;
+            };
+            let mut saved = saved_components.remove(&serde.name).unwrap_or_default();
+            saved.retain(|id, _| data.contains_key(id));
+            loaded.push((*type_id, (serde.load)(saved).map_err(to_issun)?));
+        }
+
+        self.data = data;
+        for column in self.components.values_mut() {
+            column.data.clear_all();
+        }
+        for (type_id, components) in loaded {
+            if let Some(column) = self.components.get_mut(&type_id) {
+                column.data = components;
+            }
+        }
+        Ok(())
+    }
+
+    fn column<C: Send + Sync + 'static>(&self) -> Option<&HashMap<String, C>> {
+        self.components
+            .get(&TypeId::of::<C>())?
+            .data
+            .as_any()
+            .downcast_ref()
+    }
+
+    fn column_mut<C: Send + Sync + 'static>(&mut self) -> Option<&mut HashMap<String, C>> {
+        self.components
+            .get_mut(&TypeId::of::<C>())?
+            .data
+            .as_any_mut()
+            .downcast_mut()
+    }
+
+    fn column_entry<C: Send + Sync + 'static>(&mut self) -> &mut HashMap<String, C> {
+        self.components
+            .entry(TypeId::of::<C>())
+            .or_insert_with(Column::new::<C>)
+            .data
+            .as_any_mut()
+            .downcast_mut()
+            .expect("column holds the type it is keyed by")
+    }
+}
+
+fn to_issun(error: serde_json::Error) -> IssunError {
+    IssunError::Serialization(error.to_string())
+}
+
+impl<V> Default for EntityStore<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> FromIterator<(String, V)> for EntityStore<V> {
+    fn from_iter<T: IntoIterator<Item = (String, V)>>(iter: T) -> Self {
+        Self {
+            data: HashMap::from_iter(iter),
+            components: HashMap::new(),
+        }
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for EntityStore<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let components: BTreeMap<_, _> = self
+            .components
+            .values()
+            .map(|column| (column.type_name, column.data.count()))
+            .collect();
+        f.debug_struct("EntityStore")
+            .field("data", &self.data)
+            .field("components", &components)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Npc {
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Vendor {
+        stock: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct QuestGiver {
+        quest: &'static str,
+    }
+
+    fn town() -> EntityStore<Npc> {
+        ["smith", "elder", "guard"]
+            .into_iter()
+            .map(|id| (id.to_string(), Npc { name: id.into() }))
+            .collect()
+    }
+
+    #[test]
+    fn test_attach_query_remove() {
+        let mut npcs = town();
+        let vendor = Vendor {
+            stock: vec!["sword".into()],
+        };
+
+        assert_eq!(npcs.attach("smith", vendor.clone()), Ok(None));
+        assert_eq!(npcs.attach("elder", QuestGiver { quest: "rats" }), Ok(None));
+        assert_eq!(
+            npcs.attach("ghost", QuestGiver { quest: "boo" }),
+            Err(QuestGiver { quest: "boo" })
+        );
+
+        assert_eq!(npcs.get_component::<Vendor>("smith"), Some(&vendor));
+        assert!(npcs.get_component::<Vendor>("elder").is_none());
+        npcs.get_component_mut::<Vendor>("smith")
+            .unwrap()
+            .stock
+            .push("shield".into());
+
+        let vendors: Vec<_> = npcs.query_with::<Vendor>().collect();
+        assert_eq!(vendors.len(), 1);
+        assert_eq!(vendors[0].0, "smith");
+        assert_eq!(vendors[0].1.stock, ["sword", "shield"]);
+
+        // Attaching again replaces
+        let replaced = npcs.attach("smith", vendor.clone()).unwrap().unwrap();
+        assert_eq!(replaced.stock.len(), 2);
+
+        assert_eq!(npcs.remove_component::<Vendor>("smith"), Some(vendor));
+        assert!(npcs.remove_component::<Vendor>("smith").is_none());
+        assert_eq!(npcs.query_with::<Vendor>().count(), 0);
+        assert_eq!(npcs.query_with::<QuestGiver>().count(), 1);
+        assert_eq!(npcs.len(), 3);
+    }
+
+    #[test]
+    fn test_components_removed_with_entity() {
+        let mut npcs = town();
+        for id in ["smith", "elder", "guard"] {
+            npcs.attach(id, QuestGiver { quest: "rats" }).unwrap();
+        }
+        npcs.attach("smith", Vendor { stock: Vec::new() }).unwrap();
+
+        npcs.remove("smith");
+        assert!(npcs.get_component::<QuestGiver>("smith").is_none());
+        assert_eq!(npcs.query_with::<Vendor>().count(), 0);
+
+        // An entity reusing the id starts without components
+        npcs.insert(
+            "smith",
+            Npc {
+                name: "new smith".into(),
+            },
+        );
+        assert!(npcs.get_component::<QuestGiver>("smith").is_none());
+
+        npcs.retain(|id, _| id != "guard");
+        let givers: Vec<_> = npcs.query_with::<QuestGiver>().map(|(id, _)| id).collect();
+        assert_eq!(givers, ["elder"]);
+
+        npcs.clear();
+        assert_eq!(npcs.query_with::<QuestGiver>().count(), 0);
+    }
+
+    #[test]
+    fn test_snapshot_round_trip_skips_unregistered() {
+        let mut npcs = town();
+        npcs.register_component::<Vendor>("vendor");
+        npcs.attach(
+            "smith",
+            Vendor {
+                stock: vec!["sword".into()],
+            },
+        )
+        .unwrap();
+        npcs.attach("elder", QuestGiver { quest: "rats" }).unwrap();
+
+        let (snapshot, report) = npcs.snapshot().unwrap();
+        assert_eq!(report.saved, ["vendor"]);
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("QuestGiver"), "{:?}", report);
+        assert!(!report.is_complete());
+
+        // Through the serialized form, into a fresh store
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let mut loaded: EntityStore<Npc> = EntityStore::new();
+        loaded.register_component::<Vendor>("vendor");
+        loaded
+            .restore(serde_json::from_str(&json).unwrap())
+            .unwrap();
+
+        assert_eq!(loaded.len(), 3);
+        assert_eq!(loaded.get("elder"), npcs.get("elder"));
+        assert_eq!(
+            loaded.get_component::<Vendor>("smith"),
+            npcs.get_component::<Vendor>("smith")
+        );
+        assert!(loaded.get_component::<QuestGiver>("elder").is_none());
+
+        // Only registered components: nothing to warn about
+        npcs.remove_component::<QuestGiver>("elder");
+        assert!(npcs.snapshot().unwrap().1.is_complete());
+    }
+
+    #[test]
+    fn test_restore_failure_leaves_store_unchanged() {
+        let mut npcs = town();
+        npcs.register_component::<Vendor>("vendor");
+        npcs.attach("smith", Vendor { stock: Vec::new() }).unwrap();
+
+        let mut snapshot = npcs.snapshot().unwrap().0;
+        snapshot
+            .components
+            .get_mut("vendor")
+            .unwrap()
+            .insert("smith".into(), serde_json::json!("not a vendor"));
+
+        assert!(npcs.restore(snapshot).is_err());
+        assert!(npcs.get_component::<Vendor>("smith").is_some());
+        assert_eq!(npcs.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "already registered")]
+    fn test_name_registered_once() {
+        #[derive(Serialize, Deserialize)]
+        struct OtherVendor;
+
+        let mut npcs = town();
+        npcs.register_component::<Vendor>("vendor");
+        npcs.register_component::<Vendor>("vendor");
+        npcs.register_component::<OtherVendor>("vendor");
+    }
+}
@@ -2,6 +2,12 @@
 //!
 //! Provides HashMap-based storage for game entities, assets, and other objects.
 //! Useful for managing collections of entities that need to be accessed by ID.
+//! [`EntityStore`] additionally lets optional data be attached to entities as
+//! components.
+
+mod entity_store;
+
+pub use entity_store::{EntityStore, EntityStoreSnapshot, SaveReport};
 
 use std::collections::HashMap;
 use std::hash::Hash;
@@ -158,34 +164,6 @@ where
     }
 }
 
-/// Specialized store for entities with string IDs
-///
-/// This is a convenience type for the common case of storing entities
-/// with String keys.
-///
-/// # Example
-///
-/// ```
-/// use issun::store::EntityStore;
-///
-/// #[derive(Debug, Clone)]
-/// struct Player {
-///     name: String,
-///     hp: i32,
-/// }
-///
-/// let mut players = EntityStore::new();
-/// players.insert("alice".to_string(), Player { name: "Alice".into(), hp: 100 });
-/// players.insert("bob".to_string(), Player { name: "Bob".into(), hp: 90 });
-///
-/// // Get all alive players
-/// let alive: Vec<_> = players.values()
-///     .filter(|p| p.hp > 0)
-///     .collect();
-/// assert_eq!(alive.len(), 2);
-/// ```
-pub type EntityStore<V> = Store<String, V>;
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -312,7 +290,7 @@ mod tests {
         );
 
         assert_eq!(players.len(), 2);
-        assert_eq!(players.get(&"alice".to_string()).unwrap().hp, 100);
+        assert_eq!(players.get("alice").unwrap().hp, 100);
     }
 
     #[test]
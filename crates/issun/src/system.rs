@@ -61,8 +61,82 @@ pub trait System: Send + Sync + 'static {
 
     /// Downcast to Any for type-safe mutable access
     fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Feature flag that must be on for the runners to update this system
+    ///
+    /// Set with [`SystemExt::gated_on`].
+    fn gate(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// A system that only runs while a feature flag is on
+///
+/// Registers under the wrapped system's type, so `systems.get::<S>()`
+/// still finds it.
+pub struct Gated<S> {
+    system: S,
+    flag: String,
 }
 
+impl<S> Gated<S> {
+    /// The wrapped system
+    pub fn inner(&self) -> &S {
+        &self.system
+    }
+
+    /// Flag that gates the system
+    pub fn flag(&self) -> &str {
+        &self.flag
+    }
+}
+
+#[async_trait]
+impl<S: System> System for Gated<S> {
+    fn name(&self) -> &'static str {
+        self.system.name()
+    }
+
+    async fn initialize(&mut self, ctx: &mut Context) {
+        self.system.initialize(ctx).await;
+    }
+
+    async fn shutdown(&mut self, ctx: &mut Context) {
+        self.system.shutdown(ctx).await;
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self.system.as_any()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self.system.as_any_mut()
+    }
+
+    fn gate(&self) -> Option<&str> {
+        Some(&self.flag)
+    }
+}
+
+/// Builder-style helpers for systems
+pub trait SystemExt: System + Sized {
+    /// Skip this system while feature flag `flag` is off
+    ///
+    /// ```ignore
+    /// GameBuilder::new()
+    ///     .declare_flag("new_damage_pipeline", false)
+    ///     .with_system(DamagePipelineSystem::new().gated_on("new_damage_pipeline"))
+    /// ```
+    fn gated_on(self, flag: impl Into<String>) -> Gated<Self> {
+        Gated {
+            system: self,
+            flag: flag.into(),
+        }
+    }
+}
+
+impl<S: System> SystemExt for S {}
+
 /// Example: Turn management system
 #[cfg(test)]
 mod tests {
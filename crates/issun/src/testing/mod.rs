@@ -0,0 +1,8 @@
+//! Test helpers for games built on ISSUN
+
+pub mod scenario;
+
+pub use scenario::{
+    CompareOp, Scenario, ScenarioError, ScenarioFactories, ScenarioFailure, ScenarioGame,
+    ScenarioReport, ScenarioStep,
+};
@@ -0,0 +1,810 @@
+//! Scripted end-to-end scenarios
+//!
+//! A scenario is a RON file that names a game and lists steps to run against
+//! it, so regression checks can be written without touching Rust:
+//!
+//! ```ron
+//! (
+//!     name: "outbreak is contained",
+//!     game: "outbreak",
+//!     steps: [
+//!         Publish(event: "SpreadRequested", data: {"cases": 3}),
+//!         Tick(5),
+//!         WaitFor(event: "OutbreakReported", timeout_ticks: 50),
+//!         AssertResource(type: "GameStats", path: "total_infected", op: "<=", value: 10),
+//!         CallMod(mod_id: "easy_mode", function: "difficulty", expect: Some(0.5)),
+//!     ],
+//! )
+//! ```
+//!
+//! Games are built by named factories registered in code. Events are built
+//! and resources read through the same [`DebugRegistry`] the debug HTTP
+//! endpoints use, so `Publish` needs [`ScenarioFactories::event`] (or an
+//! [`EventFactoryRegistry`] via [`ScenarioFactories::event_factories`]) and
+//! `AssertResource` needs [`ScenarioFactories::observe`] for the type.
+//! `WaitFor` matches any published event by short type name.
+//!
+//! ```ignore
+//! let factories = ScenarioFactories::new()
+//!     .game("outbreak", || async {
+//!         let game = GameBuilder::new().with_plugin(ContagionPlugin::default())?.build().await?;
+//!         Ok(SceneDirector::new(OutbreakScene::new(), game.services, game.systems, game.resources).await)
+//!     })
+//!     .event::<SpreadRequested>()?
+//!     .observe::<GameStats>();
+//!
+//! let report = issun::testing::scenario::run("scenarios/outbreak.ron", &factories).await?;
+//! ```
+//!
+//! A failing step stops the run with a [`ScenarioFailure`] naming the step,
+//! the expected and actual values and the events published last.
+
+use crate::context::ResourceContext;
+use crate::engine::headless_runner::run_tick;
+use crate::engine::lifecycle::{exit_plugins, start_plugins};
+use crate::error::Result;
+use crate::event::{Event, EventBus, EventFactoryRegistry, FactoryError};
+use crate::modding::ModLoaderState;
+use crate::plugin::debug_http::{short_name, DebugRegistry};
+use crate::replay::EventRecorder;
+use crate::scene::{Scene, SceneDirector};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use thiserror::Error;
+
+/// Events listed in a failure report
+const RECENT_EVENTS: usize = 10;
+
+/// A scenario file
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Scenario {
+    pub name: String,
+    /// Name of the [game factory](ScenarioFactories::game) to run against
+    pub game: String,
+    pub steps: Vec<ScenarioStep>,
+}
+
+impl Scenario {
+    /// Parse a scenario from RON
+    pub fn from_ron(source: &str) -> std::result::Result<Self, ScenarioError> {
+        ron::from_str(source).map_err(|e| ScenarioError::Parse(e.to_string()))
+    }
+
+    /// Read and parse a scenario file
+    pub fn load(path: impl AsRef<Path>) -> std::result::Result<Self, ScenarioError> {
+        Self::from_ron(&std::fs::read_to_string(path)?)
+    }
+}
+
+/// One step of a [`Scenario`]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum ScenarioStep {
+    /// Publish an event built from JSON data by its registered factory
+    Publish {
+        event: String,
+        #[serde(default)]
+        data: Value,
+    },
+    /// Run this many ticks
+    Tick(u64),
+    /// Tick until an event of this type is published; fails after
+    /// `timeout_ticks` ticks without one
+    WaitFor { event: String, timeout_ticks: u64 },
+    /// Compare the value at `path` of an observable resource
+    ///
+    /// `path` is dotted with optional indices (`regions[0].infected` or
+    /// `regions.0.infected`); an empty path compares the whole resource.
+    AssertResource {
+        #[serde(rename = "type")]
+        resource: String,
+        #[serde(default)]
+        path: String,
+        op: CompareOp,
+        value: Value,
+    },
+    /// Call a function of a loaded MOD, optionally checking its result
+    CallMod {
+        mod_id: String,
+        function: String,
+        #[serde(default)]
+        args: Vec<Value>,
+        #[serde(default)]
+        expect: Option<Value>,
+    },
+}
+
+impl fmt::Display for ScenarioStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioStep::Publish { event, .. } => write!(f, "publish {}", event),
+            ScenarioStep::Tick(ticks) => write!(f, "tick {}", ticks),
+            ScenarioStep::WaitFor {
+                event,
+                timeout_ticks,
+            } => write!(f, "wait_for {} (timeout {} ticks)", event, timeout_ticks),
+            ScenarioStep::AssertResource {
+                resource,
+                path,
+                op,
+                value,
+            } => {
+                write!(f, "assert_resource {}", resource)?;
+                if !path.is_empty() {
+                    write!(f, ".{}", path)?;
+                }
+                write!(f, " {} {}", op, value)
+            }
+            ScenarioStep::CallMod {
+                mod_id, function, ..
+            } => write!(f, "call_mod {}.{}", mod_id, function),
+        }
+    }
+}
+
+/// Comparison of an [`AssertResource`](ScenarioStep::AssertResource) step,
+/// written as `"=="`, `"!="`, `"<"`, `"<="`, `">"` or `">="`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompareOp::Eq => "==",
+            CompareOp::Ne => "!=",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+        }
+    }
+
+    /// Whether `actual op expected` holds
+    ///
+    /// Numbers compare by value (`3` equals `3.0`); strings order
+    /// lexically; other values only support `==` and `!=`.
+    pub fn holds(&self, actual: &Value, expected: &Value) -> bool {
+        let ordering = match (actual, expected) {
+            (Value::Number(a), Value::Number(b)) => a
+                .as_f64()
+                .and_then(|a| b.as_f64().and_then(|b| a.partial_cmp(&b))),
+            (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+            _ => None,
+        };
+        match (self, ordering) {
+            (CompareOp::Eq, Some(ordering)) => ordering.is_eq(),
+            (CompareOp::Ne, Some(ordering)) => ordering.is_ne(),
+            (CompareOp::Eq, None) => actual == expected,
+            (CompareOp::Ne, None) => actual != expected,
+            (CompareOp::Lt, Some(ordering)) => ordering.is_lt(),
+            (CompareOp::Le, Some(ordering)) => ordering.is_le(),
+            (CompareOp::Gt, Some(ordering)) => ordering.is_gt(),
+            (CompareOp::Ge, Some(ordering)) => ordering.is_ge(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for CompareOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<String> for CompareOp {
+    type Error = String;
+
+    fn try_from(op: String) -> std::result::Result<Self, Self::Error> {
+        Ok(match op.as_str() {
+            "==" => CompareOp::Eq,
+            "!=" => CompareOp::Ne,
+            "<" => CompareOp::Lt,
+            "<=" => CompareOp::Le,
+            ">" => CompareOp::Gt,
+            ">=" => CompareOp::Ge,
+            _ => return Err(format!("unknown comparison '{}'", op)),
+        })
+    }
+}
+
+impl From<CompareOp> for String {
+    fn from(op: CompareOp) -> Self {
+        op.as_str().to_string()
+    }
+}
+
+/// A game a scenario can drive
+///
+/// Implemented for [`SceneDirector`], which ticks like
+/// [`HeadlessRunner`](crate::engine::HeadlessRunner) and runs plugin
+/// lifecycle hooks on start and finish. Its futures need not be `Send`,
+/// so scenarios run on the current task.
+#[async_trait(?Send)]
+pub trait ScenarioGame: Send {
+    fn resources(&self) -> &ResourceContext;
+
+    fn resources_mut(&mut self) -> &mut ResourceContext;
+
+    /// Called once before the first step
+    async fn start(&mut self) {}
+
+    /// Advance the game by one tick
+    async fn tick(&mut self) -> Result<()>;
+
+    /// Called once after the last step, also when a step failed
+    async fn finish(&mut self) {}
+}
+
+#[async_trait(?Send)]
+impl<S: Scene + 'static> ScenarioGame for SceneDirector<S> {
+    fn resources(&self) -> &ResourceContext {
+        SceneDirector::resources(self)
+    }
+
+    fn resources_mut(&mut self) -> &mut ResourceContext {
+        SceneDirector::resources_mut(self)
+    }
+
+    async fn start(&mut self) {
+        start_plugins(self).await;
+    }
+
+    async fn tick(&mut self) -> Result<()> {
+        run_tick(self).await
+    }
+
+    async fn finish(&mut self) {
+        exit_plugins(self).await;
+    }
+}
+
+type GameFuture = Pin<Box<dyn Future<Output = Result<Box<dyn ScenarioGame>>> + Send>>;
+
+type GameFactory = Arc<dyn Fn() -> GameFuture + Send + Sync>;
+
+/// Named game factories plus the events and resources scenarios may use
+#[derive(Clone, Default)]
+pub struct ScenarioFactories {
+    games: BTreeMap<String, GameFactory>,
+    registry: DebugRegistry,
+}
+
+impl ScenarioFactories {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a game factory under `name`; every run builds a fresh game
+    pub fn game<F, Fut, G>(mut self, name: impl Into<String>, factory: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<G>> + Send + 'static,
+        G: ScenarioGame + 'static,
+    {
+        let factory: GameFactory = Arc::new(move || {
+            let build = factory();
+            Box::pin(async move {
+                let game: Box<dyn ScenarioGame> = Box::new(build.await?);
+                Ok(game)
+            })
+        });
+        self.games.insert(name.into(), factory);
+        self
+    }
+
+    /// Allow `AssertResource` steps on `T`
+    pub fn observe<T: Serialize + Send + Sync + 'static>(mut self) -> Self {
+        self.registry.observe::<T>();
+        self
+    }
+
+    /// Allow `Publish` steps for `E`
+    ///
+    /// Fails if another event has the same short name.
+    #[track_caller]
+    pub fn event<E: Event + Serialize + DeserializeOwned>(
+        mut self,
+    ) -> std::result::Result<Self, FactoryError> {
+        self.registry.event::<E>()?;
+        Ok(self)
+    }
+
+    /// Allow `Publish` steps for every event of `registry`, by its names
+    ///
+    /// Fails if a name is taken by another event type.
+    pub fn event_factories(
+        mut self,
+        registry: &EventFactoryRegistry,
+    ) -> std::result::Result<Self, FactoryError> {
+        self.registry.add_event_factories(registry)?;
+        Ok(self)
+    }
+
+    /// Names of the registered games, sorted
+    pub fn game_names(&self) -> Vec<&str> {
+        self.games.keys().map(String::as_str).collect()
+    }
+}
+
+/// Outcome of a scenario that passed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioReport {
+    pub scenario: String,
+    pub steps: usize,
+    pub ticks: u64,
+}
+
+impl fmt::Display for ScenarioReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Scenario '{}' passed ({} steps, {} ticks)",
+            self.scenario, self.steps, self.ticks
+        )
+    }
+}
+
+/// The step a scenario failed at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioFailure {
+    pub scenario: String,
+    /// Index into [`Scenario::steps`]
+    pub step: usize,
+    pub total_steps: usize,
+    /// The step as written, e.g. `assert_resource GameStats.total_infected <= 10`
+    pub description: String,
+    pub expected: String,
+    pub actual: String,
+    /// Ticks run before the failure
+    pub tick: u64,
+    /// The last events published, oldest first, as `tick N: TypeName`
+    pub recent_events: Vec<String>,
+}
+
+impl fmt::Display for ScenarioFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Scenario '{}' failed at step {}/{}: {}",
+            self.scenario,
+            self.step + 1,
+            self.total_steps,
+            self.description
+        )?;
+        writeln!(f, "  expected: {}", self.expected)?;
+        writeln!(f, "  actual:   {}", self.actual)?;
+        writeln!(f, "  tick:     {}", self.tick)?;
+        if self.recent_events.is_empty() {
+            write!(f, "  recent events: none")
+        } else {
+            write!(f, "  recent events:")?;
+            for event in &self.recent_events {
+                write!(f, "\n    {}", event)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Errors of [`run`] and [`run_scenario`]
+#[derive(Error, Debug)]
+pub enum ScenarioError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid scenario: {0}")]
+    Parse(String),
+
+    #[error("Unknown game '{game}' (registered: {})", .registered.join(", "))]
+    UnknownGame {
+        game: String,
+        registered: Vec<String>,
+    },
+
+    #[error("Failed to build game '{game}': {error}")]
+    Build { game: String, error: String },
+
+    #[error("{0}")]
+    Failed(Box<ScenarioFailure>),
+}
+
+impl ScenarioError {
+    /// The failed step, if the scenario ran and a step failed
+    pub fn failure(&self) -> Option<&ScenarioFailure> {
+        match self {
+            ScenarioError::Failed(failure) => Some(failure),
+            _ => None,
+        }
+    }
+}
+
+/// Load the scenario at `path` and run it against its game from `factories`
+pub async fn run(
+    path: impl AsRef<Path>,
+    factories: &ScenarioFactories,
+) -> std::result::Result<ScenarioReport, ScenarioError> {
+    let scenario = Scenario::load(path)?;
+    run_scenario(&scenario, factories).await
+}
+
+/// Run a parsed scenario against its game from `factories`
+///
+/// The game's `EventBus` gets a fresh [`EventRecorder`] for `WaitFor` and
+/// the failure report, replacing any recorder the factory set.
+pub async fn run_scenario(
+    scenario: &Scenario,
+    factories: &ScenarioFactories,
+) -> std::result::Result<ScenarioReport, ScenarioError> {
+    let factory =
+        factories
+            .games
+            .get(&scenario.game)
+            .ok_or_else(|| ScenarioError::UnknownGame {
+                game: scenario.game.clone(),
+                registered: factories.games.keys().cloned().collect(),
+            })?;
+    let game = factory().await.map_err(|e| ScenarioError::Build {
+        game: scenario.game.clone(),
+        error: e.to_string(),
+    })?;
+
+    let mut run = ScenarioRun::new(game, &factories.registry).await;
+    run.game.start().await;
+    let mut outcome = Ok(());
+    for (index, step) in scenario.steps.iter().enumerate() {
+        if let Err(mismatch) = run.step(step).await {
+            outcome = Err(ScenarioFailure {
+                scenario: scenario.name.clone(),
+                step: index,
+                total_steps: scenario.steps.len(),
+                description: step.to_string(),
+                expected: mismatch.expected,
+                actual: mismatch.actual,
+                tick: run.tick,
+                recent_events: run.recent_events(),
+            });
+            break;
+        }
+    }
+    run.game.finish().await;
+
+    match outcome {
+        Ok(()) => Ok(ScenarioReport {
+            scenario: scenario.name.clone(),
+            steps: scenario.steps.len(),
+            ticks: run.tick,
+        }),
+        Err(failure) => Err(ScenarioError::Failed(Box::new(failure))),
+    }
+}
+
+/// Expected and actual value of a failed step
+struct Mismatch {
+    expected: String,
+    actual: String,
+}
+
+impl Mismatch {
+    fn new(expected: impl Into<String>, actual: impl Into<String>) -> Self {
+        Self {
+            expected: expected.into(),
+            actual: actual.into(),
+        }
+    }
+}
+
+type StepResult = std::result::Result<(), Mismatch>;
+
+struct ScenarioRun<'a> {
+    game: Box<dyn ScenarioGame>,
+    registry: &'a DebugRegistry,
+    recorder: Arc<Mutex<EventRecorder>>,
+    tick: u64,
+}
+
+impl<'a> ScenarioRun<'a> {
+    async fn new(mut game: Box<dyn ScenarioGame>, registry: &'a DebugRegistry) -> Self {
+        let mut recorder = EventRecorder::new();
+        recorder.start();
+        let recorder = Arc::new(Mutex::new(recorder));
+
+        let resources = game.resources_mut();
+        if !resources.contains::<EventBus>() {
+            resources.insert(EventBus::new());
+        }
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.set_recorder(recorder.clone());
+            bus.set_frame(0);
+        }
+
+        Self {
+            game,
+            registry,
+            recorder,
+            tick: 0,
+        }
+    }
+
+    async fn step(&mut self, step: &ScenarioStep) -> StepResult {
+        match step {
+            ScenarioStep::Publish { event, data } => self.publish(event, data.clone()).await,
+            ScenarioStep::Tick(ticks) => {
+                for _ in 0..*ticks {
+                    self.advance().await?;
+                }
+                Ok(())
+            }
+            ScenarioStep::WaitFor {
+                event,
+                timeout_ticks,
+            } => self.wait_for(event, *timeout_ticks).await,
+            ScenarioStep::AssertResource {
+                resource,
+                path,
+                op,
+                value,
+            } => self.assert_resource(resource, path, *op, value).await,
+            ScenarioStep::CallMod {
+                mod_id,
+                function,
+                args,
+                expect,
+            } => self.call_mod(mod_id, function, args, expect.as_ref()).await,
+        }
+    }
+
+    async fn advance(&mut self) -> StepResult {
+        self.game
+            .tick()
+            .await
+            .map_err(|e| Mismatch::new("tick to succeed", e.to_string()))?;
+        self.tick += 1;
+        if let Some(mut bus) = self.game.resources().get_mut::<EventBus>().await {
+            bus.set_frame(self.tick);
+        }
+        Ok(())
+    }
+
+    async fn publish(&mut self, event: &str, data: Value) -> StepResult {
+        let Some(mut bus) = self.game.resources().get_mut::<EventBus>().await else {
+            return Err(Mismatch::new("an EventBus resource", "none"));
+        };
+        match self.registry.publish(event, &mut bus, data) {
+            Some(Ok(())) => Ok(()),
+            Some(Err(e)) => Err(Mismatch::new(format!("valid {} data", event), e)),
+            None => Err(Mismatch::new(
+                format!("a registered event factory for {}", event),
+                "none registered",
+            )),
+        }
+    }
+
+    async fn wait_for(&mut self, event: &str, timeout_ticks: u64) -> StepResult {
+        let since = self.recorded();
+        let mut waited = 0;
+        loop {
+            if self.published_since(since, event) {
+                return Ok(());
+            }
+            if waited == timeout_ticks {
+                return Err(Mismatch::new(
+                    format!("{} within {} ticks", event, timeout_ticks),
+                    format!("not published after {} ticks", waited),
+                ));
+            }
+            self.advance().await?;
+            waited += 1;
+        }
+    }
+
+    async fn assert_resource(
+        &mut self,
+        resource: &str,
+        path: &str,
+        op: CompareOp,
+        expected: &Value,
+    ) -> StepResult {
+        if !self.registry.has_resource(resource) {
+            return Err(Mismatch::new(
+                format!("{} registered as observable", resource),
+                "not registered",
+            ));
+        }
+        let Some(value) = self.registry.read(resource, self.game.resources()).await else {
+            return Err(Mismatch::new(
+                format!("{} resource present", resource),
+                "missing",
+            ));
+        };
+        let Some(actual) = json_path(&value, path) else {
+            return Err(Mismatch::new(
+                format!("a value at '{}'", path),
+                format!("none in {}", value),
+            ));
+        };
+        if op.holds(actual, expected) {
+            Ok(())
+        } else {
+            Err(Mismatch::new(
+                format!("{} {}", op, expected),
+                actual.to_string(),
+            ))
+        }
+    }
+
+    async fn call_mod(
+        &mut self,
+        mod_id: &str,
+        function: &str,
+        args: &[Value],
+        expect: Option<&Value>,
+    ) -> StepResult {
+        let Some(mut loader_state) = self.game.resources().get_mut::<ModLoaderState>().await else {
+            return Err(Mismatch::new("a MOD loader", "none"));
+        };
+        let Some(handle) = loader_state
+            .loaded_mods
+            .iter()
+            .find(|handle| handle.id == mod_id)
+            .cloned()
+        else {
+            return Err(Mismatch::new(
+                format!("MOD '{}' loaded", mod_id),
+                "not loaded",
+            ));
+        };
+        let result = loader_state
+            .loader
+            .call_function(&handle, function, args.to_vec())
+            .map_err(|e| Mismatch::new(format!("{} to succeed", function), e.to_string()))?;
+        match expect {
+            Some(expected) if !CompareOp::Eq.holds(&result, expected) => {
+                Err(Mismatch::new(expected.to_string(), result.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Number of events recorded so far
+    fn recorded(&self) -> usize {
+        self.recorder
+            .lock()
+            .map(|recorder| recorder.recordings().len())
+            .unwrap_or(0)
+    }
+
+    /// Whether `event` (short or full type name) was recorded after the first
+    /// `since` events
+    fn published_since(&self, since: usize, event: &str) -> bool {
+        let Ok(recorder) = self.recorder.lock() else {
+            return false;
+        };
+        recorder.recordings()[since.min(recorder.recordings().len())..]
+            .iter()
+            .any(|recorded| {
+                recorded.event_type == event || short_name(&recorded.event_type) == event
+            })
+    }
+
+    fn recent_events(&self) -> Vec<String> {
+        let Ok(recorder) = self.recorder.lock() else {
+            return Vec::new();
+        };
+        let recordings = recorder.recordings();
+        recordings[recordings.len().saturating_sub(RECENT_EVENTS)..]
+            .iter()
+            .map(|recorded| {
+                format!(
+                    "tick {}: {}",
+                    recorded.frame,
+                    short_name(&recorded.event_type)
+                )
+            })
+            .collect()
+    }
+}
+
+/// Value at a dotted path with optional indices (`regions[0].infected`,
+/// `regions.0.infected`); an empty path or `$` is the value itself
+pub fn json_path<'v>(value: &'v Value, path: &str) -> Option<&'v Value> {
+    let path = path.strip_prefix('$').unwrap_or(path);
+    let mut current = value;
+    for segment in path.split('.').filter(|segment| !segment.is_empty()) {
+        let (key, indices) = match segment.find('[') {
+            Some(start) => segment.split_at(start),
+            None => (segment, ""),
+        };
+        if !key.is_empty() {
+            current = match current {
+                Value::Object(map) => map.get(key)?,
+                Value::Array(items) => items.get(key.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+        for index in indices.split('[').filter(|index| !index.is_empty()) {
+            let index = index.strip_suffix(']')?.parse::<usize>().ok()?;
+            current = current.as_array()?.get(index)?;
+        }
+    }
+    Some(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_steps() {
+        let scenario = Scenario::from_ron(
+            r#"(
+                name: "smoke",
+                game: "arena",
+                steps: [
+                    Publish(event: "CombatStartRequested", data: {"enemy": "slime"}),
+                    Tick(5),
+                    WaitFor(event: "CombatEndedEvent", timeout_ticks: 50),
+                    AssertResource(type: "GameStats", path: "total_infected", op: "<=", value: 10),
+                    CallMod(mod_id: "easy_mode", function: "difficulty", expect: Some(0.5)),
+                ],
+            )"#,
+        )
+        .unwrap();
+
+        assert_eq!(scenario.steps.len(), 5);
+        assert_eq!(
+            scenario.steps[0],
+            ScenarioStep::Publish {
+                event: "CombatStartRequested".into(),
+                data: json!({ "enemy": "slime" }),
+            }
+        );
+        assert_eq!(
+            scenario.steps[3].to_string(),
+            "assert_resource GameStats.total_infected <= 10"
+        );
+        assert!(matches!(
+            Scenario::from_ron(
+                r#"(name: "x", game: "y", steps: [AssertResource(type: "A", op: "=~", value: 1)])"#
+            ),
+            Err(ScenarioError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_json_path() {
+        let value = json!({ "regions": [{ "infected": 4 }], "total": 4 });
+        assert_eq!(json_path(&value, "total"), Some(&json!(4)));
+        assert_eq!(json_path(&value, "regions[0].infected"), Some(&json!(4)));
+        assert_eq!(json_path(&value, "$.regions.0.infected"), Some(&json!(4)));
+        assert_eq!(json_path(&value, ""), Some(&value));
+        assert_eq!(json_path(&value, "regions[1]"), None);
+        assert_eq!(json_path(&value, "total.more"), None);
+    }
+
+    #[test]
+    fn test_compare_ops() {
+        assert!(CompareOp::Eq.holds(&json!(3), &json!(3.0)));
+        assert!(CompareOp::Le.holds(&json!(10), &json!(10)));
+        assert!(!CompareOp::Lt.holds(&json!(10), &json!(10)));
+        assert!(CompareOp::Gt.holds(&json!("b"), &json!("a")));
+        assert!(CompareOp::Eq.holds(&json!([1, 2]), &json!([1, 2])));
+        assert!(!CompareOp::Lt.holds(&json!([1]), &json!([2])));
+        assert!(CompareOp::Ne.holds(&json!(null), &json!(0)));
+    }
+}
@@ -0,0 +1,149 @@
+//! Per-(system, event type) processing cost attribution
+//!
+//! `#[event_handler]` systems wrap each event type's batch in a [`HandlerSpan`]
+//! when the `EventBus` has a tracer; hand-written `process_events` use
+//! [`trace_handler!`](crate::trace_handler). The tracer sums the spans into
+//! [`EventCost`]s, queried with `EventChainTracer::top_event_costs`.
+
+use super::tracer::EventChainTracer;
+use crate::plugin::metrics::{MetricDefinition, MetricId, MetricType, MetricValue};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Instant;
+
+/// Time one system spent handling one event type
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EventCost {
+    pub system: String,
+    pub event_type: String,
+    /// Total handler time in milliseconds
+    pub total_ms: f64,
+    /// Number of handler batches (one per system and event type per frame)
+    pub batches: u64,
+    /// Number of events handled across all batches
+    pub events: u64,
+}
+
+impl EventCost {
+    pub(crate) fn new(system: &str, event_type: &str) -> Self {
+        Self {
+            system: system.to_string(),
+            event_type: event_type.to_string(),
+            total_ms: 0.0,
+            batches: 0,
+            events: 0,
+        }
+    }
+
+    pub(crate) fn add(&mut self, events: usize, duration_ms: f64) {
+        self.total_ms += duration_ms;
+        self.batches += 1;
+        self.events += events as u64;
+    }
+
+    /// Average handler time per event instance
+    pub fn avg_ms_per_event(&self) -> f64 {
+        if self.events == 0 {
+            0.0
+        } else {
+            self.total_ms / self.events as f64
+        }
+    }
+
+    /// Gauge id for `MetricsPlugin` (`"trace.cost.<event>.<system>"`)
+    pub fn metric_id(&self) -> String {
+        format!("trace.cost.{}.{}", self.event_type, self.system)
+    }
+
+    /// Gauge definition for [`EventCost::metric_id`]
+    pub fn metric_definition(&self) -> MetricDefinition {
+        MetricDefinition::new(
+            self.metric_id(),
+            format!("{} handling {}", self.system, self.event_type),
+            "Handler time per frame, from the event chain tracer",
+            MetricType::Gauge,
+            "ms",
+        )
+    }
+
+    /// Gauge value of this cost, with the batch and event counts as metadata
+    pub fn metric_value(&self, timestamp: u64) -> MetricValue {
+        MetricValue::new(MetricId::new(self.metric_id()), self.total_ms, timestamp)
+            .with_metadata(serde_json::json!({ "batches": self.batches, "events": self.events }))
+    }
+}
+
+/// Timer for one handler batch, recorded into the tracer when finished
+///
+/// Created only when a tracer is set, so the untraced path costs a branch.
+pub struct HandlerSpan<'a> {
+    tracer: &'a Arc<Mutex<EventChainTracer>>,
+    system: &'static str,
+    event_type: &'static str,
+    started: Instant,
+}
+
+impl<'a> HandlerSpan<'a> {
+    /// Start timing system `S` handling events of type `E`
+    pub fn start<S: ?Sized, E: ?Sized>(
+        tracer: Option<&'a Arc<Mutex<EventChainTracer>>>,
+    ) -> Option<Self> {
+        let tracer = tracer?;
+        Some(Self {
+            tracer,
+            system: short_type_name::<S>(),
+            event_type: short_type_name::<E>(),
+            started: Instant::now(),
+        })
+    }
+
+    /// Record the batch of `events` events
+    pub fn finish(self, events: usize) {
+        let duration_ms = self.started.elapsed().as_secs_f64() * 1000.0;
+        let mut tracer = self.tracer.lock().unwrap_or_else(PoisonError::into_inner);
+        tracer.record_handler(self.system, self.event_type, events, duration_ms);
+    }
+}
+
+/// Type name without its module path (`"ContagionSpread"`)
+///
+/// Generic arguments keep their paths.
+pub fn short_type_name<T: ?Sized>() -> &'static str {
+    let full = std::any::type_name::<T>();
+    let end = full.find('<').unwrap_or(full.len());
+    let start = full[..end].rfind("::").map(|i| i + 2).unwrap_or(0);
+    &full[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ContagionSpread;
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name::<ContagionSpread>(), "ContagionSpread");
+        assert_eq!(
+            short_type_name::<Vec<ContagionSpread>>().find("Vec<"),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_span_without_tracer() {
+        assert!(HandlerSpan::start::<(), ContagionSpread>(None).is_none());
+    }
+
+    #[test]
+    fn test_averages_and_metric_id() {
+        let mut cost = EventCost::new("SpreadSystem", "ContagionSpread");
+        cost.add(3, 6.0);
+        cost.add(1, 2.0);
+
+        assert_eq!(cost.batches, 2);
+        assert_eq!(cost.avg_ms_per_event(), 2.0);
+        assert_eq!(cost.metric_id(), "trace.cost.ContagionSpread.SpreadSystem");
+        assert_eq!(cost.metric_value(7).metadata["events"], 4);
+    }
+}
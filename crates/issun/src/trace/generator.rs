@@ -87,6 +87,21 @@ impl EventChainTracer {
                     node_counter += 1;
                     Some(node_id)
                 }
+                TraceEntryType::HandlerCompleted {
+                    system,
+                    event_type,
+                    events,
+                    duration_ms,
+                } => {
+                    let node_id = format!("HB{}", node_counter);
+                    graph.push_str(&format!(
+                        "    {}[\"⏱ {} ← {} x{} ({:.2}ms)\"]\n",
+                        node_id, system, event_type, events, duration_ms
+                    ));
+                    graph.push_str(&format!("    style {} fill:#f3e5f5\n", node_id));
+                    node_counter += 1;
+                    Some(node_id)
+                }
             };
 
             // 前のノードとエッジを作成
@@ -182,6 +197,20 @@ impl EventChainTracer {
                     node_counter += 1;
                     Some(node_id)
                 }
+                TraceEntryType::HandlerCompleted {
+                    system,
+                    event_type,
+                    events,
+                    duration_ms,
+                } => {
+                    let node_id = format!("HB{}", node_counter);
+                    graph.push_str(&format!(
+                        "    {} [label=\"⏱ {} ← {}\\nx{} ({:.2}ms)\", fillcolor=\"#f3e5f5\"];\n",
+                        node_id, system, event_type, events, duration_ms
+                    ));
+                    node_counter += 1;
+                    Some(node_id)
+                }
             };
 
             // 前のノードとエッジを作成
@@ -73,6 +73,40 @@ macro_rules! call_hook_traced {
     }};
 }
 
+/// イベントハンドラのバッチを (システム, イベント型) のスパンで計測するマクロ
+///
+/// 手書きの `process_events` 用。`#[event_handler]` が生成するコードと同じく、
+/// トレーサーが無い場合は分岐1つのコストで `$body` を実行する。
+///
+/// # Example
+///
+/// ```ignore
+/// use issun::trace_handler;
+///
+/// let (tracer, spreads) = {
+///     let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+///     let spreads: Vec<ContagionSpread> = bus.reader::<ContagionSpread>().iter().cloned().collect();
+///     (bus.tracer().cloned(), spreads)
+/// };
+///
+/// trace_handler!(tracer.as_ref(), Self, ContagionSpread, spreads.len(), {
+///     for spread in &spreads {
+///         self.apply(spread, resources).await;
+///     }
+/// });
+/// ```
+#[macro_export]
+macro_rules! trace_handler {
+    ($tracer:expr, $system:ty, $event:ty, $count:expr, $body:expr $(,)?) => {{
+        let span = $crate::trace::HandlerSpan::start::<$system, $event>($tracer);
+        let result = $body;
+        if let Some(span) = span {
+            span.finish($count);
+        }
+        result
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use crate::trace::EventChainTracer;
@@ -132,4 +166,33 @@ mod tests {
             _ => panic!("Expected HookCompleted"),
         }
     }
+
+    struct TestSystem;
+    struct TestEvent;
+
+    #[test]
+    fn test_trace_handler_macro() {
+        let mut tracer = EventChainTracer::new();
+        tracer.enable();
+        let tracer = Arc::new(Mutex::new(tracer));
+
+        let events = [1, 2, 3];
+        let sum: i32 = trace_handler!(
+            Some(&tracer),
+            TestSystem,
+            TestEvent,
+            events.len(),
+            events.iter().sum()
+        );
+        assert_eq!(sum, 6);
+
+        let untraced: i32 = trace_handler!(None, TestSystem, TestEvent, 1, 7);
+        assert_eq!(untraced, 7);
+
+        let top = tracer.lock().unwrap().top_event_costs(5);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].system, "TestSystem");
+        assert_eq!(top[0].event_type, "TestEvent");
+        assert_eq!(top[0].events, 3);
+    }
 }
@@ -18,10 +18,12 @@
 //! std::fs::write("event_chain.mmd", mermaid).unwrap();
 //! ```
 
+pub mod cost;
 pub mod generator;
 pub mod macros;
 pub mod tracer;
 pub mod types;
 
+pub use cost::{short_type_name, EventCost, HandlerSpan};
 pub use tracer::{EventChainTracer, TracerStats};
 pub use types::{HookResult, TraceEntry, TraceEntryType};
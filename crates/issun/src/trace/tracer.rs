@@ -1,7 +1,8 @@
 //! Event chain tracer implementation
 
+use super::cost::EventCost;
 use super::types::{TraceEntry, TraceEntryType};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::Instant;
 
 /// Event/Hook呼び出しトレーサー
@@ -10,6 +11,8 @@ pub struct EventChainTracer {
     enabled: bool,
     start_time: Instant,
     current_frame: u64,
+    event_costs: HashMap<(String, String), EventCost>,
+    defined_cost_metrics: HashSet<String>,
 }
 
 impl EventChainTracer {
@@ -20,6 +23,8 @@ impl EventChainTracer {
             enabled: false,
             start_time: Instant::now(),
             current_frame: 0,
+            event_costs: HashMap::new(),
+            defined_cost_metrics: HashSet::new(),
         }
     }
 
@@ -77,9 +82,78 @@ impl EventChainTracer {
         });
     }
 
+    /// イベントハンドラのバッチを記録し、(システム, イベント型) ごとに集計
+    pub fn record_handler(
+        &mut self,
+        system: &str,
+        event_type: &str,
+        events: usize,
+        duration_ms: f64,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        self.event_costs
+            .entry((system.to_string(), event_type.to_string()))
+            .or_insert_with(|| EventCost::new(system, event_type))
+            .add(events, duration_ms);
+        self.record_simple(
+            TraceEntryType::HandlerCompleted {
+                system: system.to_string(),
+                event_type: event_type.to_string(),
+                events,
+                duration_ms,
+            },
+            system,
+        );
+    }
+
+    /// 処理時間の合計が大きい順に上位 `n` 件の (システム, イベント型) を取得
+    pub fn top_event_costs(&self, n: usize) -> Vec<EventCost> {
+        let mut costs: Vec<EventCost> = self.event_costs.values().cloned().collect();
+        sort_costs(&mut costs);
+        costs.truncate(n);
+        costs
+    }
+
+    /// 全イベントハンドラの処理時間の合計（ミリ秒）
+    pub fn total_event_cost_ms(&self) -> f64 {
+        self.event_costs.values().map(|cost| cost.total_ms).sum()
+    }
+
+    /// 特定フレームの (システム, イベント型) ごとの処理時間を取得
+    pub fn event_costs_for_frame(&self, frame: u64) -> Vec<EventCost> {
+        let mut costs: HashMap<(&str, &str), EventCost> = HashMap::new();
+        for entry in self.traces.iter().filter(|e| e.frame == frame) {
+            if let TraceEntryType::HandlerCompleted {
+                system,
+                event_type,
+                events,
+                duration_ms,
+            } = &entry.entry_type
+            {
+                costs
+                    .entry((system, event_type))
+                    .or_insert_with(|| EventCost::new(system, event_type))
+                    .add(*events, *duration_ms);
+            }
+        }
+
+        let mut costs: Vec<EventCost> = costs.into_values().collect();
+        sort_costs(&mut costs);
+        costs
+    }
+
+    /// メトリクスIDを初めて見た場合に true（定義の送信は一度だけ）
+    pub(crate) fn mark_cost_metric_defined(&mut self, metric_id: &str) -> bool {
+        self.defined_cost_metrics.insert(metric_id.to_string())
+    }
+
     /// トレースをクリア
     pub fn clear(&mut self) {
         self.traces.clear();
+        self.event_costs.clear();
         self.start_time = Instant::now();
         self.current_frame = 0;
     }
@@ -144,11 +218,29 @@ impl EventChainTracer {
         let json = std::fs::read_to_string(path)?;
         let traces: Vec<TraceEntry> = serde_json::from_str(&json)?;
 
+        let mut event_costs = HashMap::new();
+        for entry in &traces {
+            if let TraceEntryType::HandlerCompleted {
+                system,
+                event_type,
+                events,
+                duration_ms,
+            } = &entry.entry_type
+            {
+                event_costs
+                    .entry((system.clone(), event_type.clone()))
+                    .or_insert_with(|| EventCost::new(system, event_type))
+                    .add(*events, *duration_ms);
+            }
+        }
+
         Ok(Self {
             traces,
             enabled: false,
             start_time: Instant::now(),
             current_frame: 0,
+            event_costs,
+            defined_cost_metrics: HashSet::new(),
         })
     }
 }
@@ -159,6 +251,16 @@ impl Default for EventChainTracer {
     }
 }
 
+/// 処理時間の降順、同じ場合はシステム名・イベント型の順
+fn sort_costs(costs: &mut [EventCost]) {
+    costs.sort_by(|a, b| {
+        b.total_ms
+            .total_cmp(&a.total_ms)
+            .then_with(|| a.system.cmp(&b.system))
+            .then_with(|| a.event_type.cmp(&b.event_type))
+    });
+}
+
 /// トレーサー統計情報
 #[derive(Clone, Debug)]
 pub struct TracerStats {
@@ -308,6 +410,33 @@ mod tests {
         assert_eq!(*stats.hook_calls.get("on_test").unwrap(), 1);
     }
 
+    #[test]
+    fn test_event_costs_aggregate_per_system_and_event() {
+        let mut tracer = EventChainTracer::new();
+        tracer.record_handler("SpreadSystem", "ContagionSpread", 4, 3.0);
+        assert!(tracer.top_event_costs(10).is_empty());
+
+        tracer.enable();
+        tracer.record_handler("SpreadSystem", "ContagionSpread", 4, 3.0);
+        tracer.record_handler("UiSystem", "ContagionSpread", 4, 1.0);
+        tracer.set_frame(1);
+        tracer.record_handler("SpreadSystem", "ContagionSpread", 2, 5.0);
+
+        let top = tracer.top_event_costs(1);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].system, "SpreadSystem");
+        assert_eq!(top[0].total_ms, 8.0);
+        assert_eq!((top[0].batches, top[0].events), (2, 6));
+        assert_eq!(tracer.total_event_cost_ms(), 9.0);
+
+        let frame_0 = tracer.event_costs_for_frame(0);
+        assert_eq!(frame_0.len(), 2);
+        assert_eq!(frame_0[0].total_ms, 3.0);
+
+        tracer.clear();
+        assert!(tracer.top_event_costs(10).is_empty());
+    }
+
     #[test]
     fn test_save_and_load() {
         let mut tracer = EventChainTracer::new();
@@ -330,4 +459,19 @@ mod tests {
 
         std::fs::remove_file(temp_file).ok();
     }
+
+    #[test]
+    fn test_load_restores_event_costs() {
+        let mut tracer = EventChainTracer::new();
+        tracer.enable();
+        tracer.record_handler("TestSystem", "TestEvent", 2, 1.5);
+
+        let temp_file = "/tmp/test_tracer_costs.json";
+        tracer.save(temp_file).unwrap();
+
+        let loaded = EventChainTracer::load(temp_file).unwrap();
+        assert_eq!(loaded.top_event_costs(5), tracer.top_event_costs(5));
+
+        std::fs::remove_file(temp_file).ok();
+    }
 }
@@ -74,6 +74,14 @@ pub enum TraceEntryType {
         duration_ms: f64,
         result: HookResult,
     },
+
+    /// イベントハンドラのバッチが完了した（システム・イベント型ごとに1件）
+    HandlerCompleted {
+        system: String,
+        event_type: String,
+        events: usize,
+        duration_ms: f64,
+    },
 }
 
 /// Hook実行結果
@@ -13,6 +13,7 @@
 //! - `log`: Log viewer widget trait
 //! - `gauge`: Gauge/progress bar widget trait
 //! - `modal`: Modal/popup widget trait
+//! - `text_input`: Single-line text input state
 
 pub mod component;
 pub mod dialog;
@@ -21,6 +22,7 @@ pub mod log;
 pub mod menu;
 pub mod modal;
 pub mod stats;
+pub mod text_input;
 pub mod widget;
 
 // Re-exports for convenience
@@ -31,4 +33,5 @@ pub use log::LogViewer;
 pub use menu::Menu;
 pub use modal::Modal;
 pub use stats::StatsPanel;
+pub use text_input::{TextEntryActive, TextInputOutcome, TextInputState};
 pub use widget::{InputEvent, Widget};
@@ -0,0 +1,196 @@
+//! Single-line text input state
+//!
+//! Backend-independent editing state for text fields such as save labels or
+//! player names. While a field has focus, insert [`TextEntryActive`] into the
+//! resources so the game runner delivers `h`/`j`/`k`/`l`/`q` as characters
+//! instead of navigation.
+
+use super::widget::InputEvent;
+use serde::{Deserialize, Serialize};
+
+/// Marker resource: a text field has keyboard focus
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextEntryActive;
+
+impl crate::resources::Resource for TextEntryActive {}
+
+/// Result of feeding one input event to a [`TextInputState`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextInputOutcome {
+    /// Still editing (the value may have changed)
+    Editing,
+    /// Enter was pressed; carries the current value
+    Submitted(String),
+    /// Esc was pressed
+    Cancelled,
+}
+
+/// Editable single-line text with a cursor
+///
+/// # Example
+///
+/// ```
+/// use issun::ui::core::text_input::{TextInputOutcome, TextInputState};
+/// use issun::ui::InputEvent;
+///
+/// let mut input = TextInputState::new().with_max_len(16);
+/// input.handle_input(InputEvent::Char('h'));
+/// input.handle_input(InputEvent::Char('i'));
+/// assert_eq!(
+///     input.handle_input(InputEvent::Select),
+///     TextInputOutcome::Submitted("hi".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextInputState {
+    value: String,
+    /// Cursor position in characters
+    cursor: usize,
+    max_len: Option<usize>,
+}
+
+impl TextInputState {
+    /// Empty input without a length limit
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start with `value`, cursor at the end
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Limit the value to `max_len` characters
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Current value
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Cursor position in characters
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Replace the value, cursor at the end
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        let mut value: String = value.into();
+        if let Some(max_len) = self.max_len {
+            value = value.chars().take(max_len).collect();
+        }
+        self.cursor = value.chars().count();
+        self.value = value;
+    }
+
+    /// Clear the value
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+    }
+
+    /// Apply one input event
+    pub fn handle_input(&mut self, input: InputEvent) -> TextInputOutcome {
+        match input {
+            InputEvent::Char(c) if !c.is_control() => self.insert(c),
+            InputEvent::Backspace => self.backspace(),
+            InputEvent::Left => self.cursor = self.cursor.saturating_sub(1),
+            InputEvent::Right => self.cursor = (self.cursor + 1).min(self.len()),
+            InputEvent::Select => return TextInputOutcome::Submitted(self.value.clone()),
+            InputEvent::Cancel => return TextInputOutcome::Cancelled,
+            _ => {}
+        }
+        TextInputOutcome::Editing
+    }
+
+    fn len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    fn byte_index(&self, cursor: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(cursor)
+            .map_or(self.value.len(), |(index, _)| index)
+    }
+
+    fn insert(&mut self, c: char) {
+        if self.max_len.is_some_and(|max_len| self.len() >= max_len) {
+            return;
+        }
+        let index = self.byte_index(self.cursor);
+        self.value.insert(index, c);
+        self.cursor += 1;
+    }
+
+    fn backspace(&mut self) {
+        if self.cursor == 0 {
+            return;
+        }
+        self.cursor -= 1;
+        let index = self.byte_index(self.cursor);
+        self.value.remove(index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(input: &mut TextInputState, text: &str) {
+        for c in text.chars() {
+            input.handle_input(InputEvent::Char(c));
+        }
+    }
+
+    #[test]
+    fn test_typing_and_backspace() {
+        let mut input = TextInputState::new();
+        typed(&mut input, "salvage");
+        input.handle_input(InputEvent::Backspace);
+
+        assert_eq!(input.value(), "salvag");
+        assert_eq!(input.cursor(), 6);
+    }
+
+    #[test]
+    fn test_cursor_editing_with_multibyte_text() {
+        let mut input = TextInputState::new().with_value("ネジ");
+        input.handle_input(InputEvent::Left);
+        input.handle_input(InputEvent::Char('x'));
+        assert_eq!(input.value(), "ネxジ");
+
+        input.handle_input(InputEvent::Left);
+        input.handle_input(InputEvent::Backspace);
+        assert_eq!(input.value(), "xジ");
+        assert_eq!(input.cursor(), 0);
+    }
+
+    #[test]
+    fn test_max_len() {
+        let mut input = TextInputState::new().with_max_len(3);
+        typed(&mut input, "abcdef");
+        assert_eq!(input.value(), "abc");
+    }
+
+    #[test]
+    fn test_submit_and_cancel() {
+        let mut input = TextInputState::new().with_value("run 2");
+        assert_eq!(
+            input.handle_input(InputEvent::Select),
+            TextInputOutcome::Submitted("run 2".to_string())
+        );
+        assert_eq!(
+            input.handle_input(InputEvent::Cancel),
+            TextInputOutcome::Cancelled
+        );
+    }
+}
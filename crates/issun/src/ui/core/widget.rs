@@ -35,6 +35,8 @@ pub enum InputEvent {
     Cancel,
     /// Tab key
     Tab,
+    /// Backspace key
+    Backspace,
     /// Character input
     Char(char),
     /// Function key
@@ -54,6 +56,7 @@ impl From<crossterm::event::KeyCode> for InputEvent {
             KeyCode::Enter => InputEvent::Select,
             KeyCode::Esc | KeyCode::Char('q') => InputEvent::Cancel,
             KeyCode::Tab => InputEvent::Tab,
+            KeyCode::Backspace => InputEvent::Backspace,
             KeyCode::Char(c) => InputEvent::Char(c),
             KeyCode::F(n) => InputEvent::Function(n),
             _ => InputEvent::Other,
@@ -61,6 +64,19 @@ impl From<crossterm::event::KeyCode> for InputEvent {
     }
 }
 
+impl InputEvent {
+    /// Map a key for text entry: every character key arrives as `Char`
+    ///
+    /// Unlike `From<KeyCode>`, `h`/`j`/`k`/`l`/`q` are not turned into
+    /// navigation, so they can be typed into a text field.
+    pub fn from_text_key(key: crossterm::event::KeyCode) -> Self {
+        match key {
+            crossterm::event::KeyCode::Char(c) => InputEvent::Char(c),
+            key => InputEvent::from(key),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,5 +89,22 @@ mod tests {
         assert_eq!(InputEvent::from(KeyCode::Enter), InputEvent::Select);
         assert_eq!(InputEvent::from(KeyCode::Char('k')), InputEvent::Up);
         assert_eq!(InputEvent::from(KeyCode::Char('a')), InputEvent::Char('a'));
+        assert_eq!(InputEvent::from(KeyCode::Backspace), InputEvent::Backspace);
+    }
+
+    #[test]
+    fn test_text_key_keeps_navigation_letters() {
+        use crossterm::event::KeyCode;
+
+        assert_eq!(
+            InputEvent::from_text_key(KeyCode::Char('k')),
+            InputEvent::Char('k')
+        );
+        assert_eq!(
+            InputEvent::from_text_key(KeyCode::Char('q')),
+            InputEvent::Char('q')
+        );
+        assert_eq!(InputEvent::from_text_key(KeyCode::Esc), InputEvent::Cancel);
+        assert_eq!(InputEvent::from_text_key(KeyCode::Up), InputEvent::Up);
     }
 }
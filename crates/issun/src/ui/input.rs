@@ -43,6 +43,46 @@ pub fn poll_input(timeout: Duration) -> std::io::Result<InputEvent> {
     Ok(InputEvent::Other)
 }
 
+/// Poll for input events while a text field has focus
+///
+/// Like `poll_input()`, but character keys are delivered as
+/// `InputEvent::Char` (see [`InputEvent::from_text_key`]).
+pub fn poll_text_input(timeout: Duration) -> std::io::Result<InputEvent> {
+    Ok(poll_key(timeout)?.map_or(InputEvent::Other, InputEvent::from_text_key))
+}
+
+/// Input or a focus change, from [`poll_input_or_focus`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolledInput {
+    Input(InputEvent),
+    /// The terminal gained (`true`) or lost focus
+    Focus(bool),
+}
+
+/// Like `poll_input()` (`poll_text_input()` with `text_entry`), but also
+/// reports terminal focus changes
+///
+/// Terminals only send them while focus reporting is enabled, which
+/// [`Tui::new`](crate::ui::Tui::new) does.
+pub fn poll_input_or_focus(timeout: Duration, text_entry: bool) -> std::io::Result<PolledInput> {
+    if event::poll(timeout)? {
+        match event::read()? {
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                let input = if text_entry {
+                    InputEvent::from_text_key(key_event.code)
+                } else {
+                    InputEvent::from(key_event.code)
+                };
+                return Ok(PolledInput::Input(input));
+            }
+            Event::FocusGained => return Ok(PolledInput::Focus(true)),
+            Event::FocusLost => return Ok(PolledInput::Focus(false)),
+            _ => {}
+        }
+    }
+    Ok(PolledInput::Input(InputEvent::Other))
+}
+
 /// Poll for raw key code with timeout
 ///
 /// Similar to `poll_input()` but returns the raw KeyCode instead of InputEvent.
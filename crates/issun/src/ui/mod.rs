@@ -54,5 +54,8 @@ pub use core::{Component, InputEvent, MultiResourceComponent, Widget};
 pub use layer::{LayoutConstraint, LayoutDirection, UILayer, UILayoutPresets};
 pub use ratatui::Tui;
 pub use resource_guard::{ResourceError, ResourceGuard};
-pub use theme::{Emphasis, Theme, ThemeColor, ThemeConfig, ThemePresets};
+pub use theme::{
+    ColorDepth, Emphasis, SetThemeRequested, SlotStyle, StyleSlot, Theme, ThemeChanged, ThemeColor,
+    ThemeConfig, ThemePresets, ThemeSource,
+};
 pub use title::title_screen::{AsciiFont, TitleScreenAsset, TitleScreenService};
@@ -4,8 +4,10 @@
 
 use crate::context::ResourceContext;
 use crate::ui::core::MultiResourceComponent;
+use crate::ui::ratatui::theme::RatatuiTheme;
+use crate::ui::theme::StyleSlot;
 use ratatui::{
-    style::{Color, Modifier, Style},
+    style::Style,
     widgets::{Block, Borders, List, ListItem},
 };
 
@@ -36,6 +38,7 @@ pub trait DistrictsProvider: Send + Sync + 'static {
 /// Districts list component
 ///
 /// Renders a scrollable list of districts with selection highlighting.
+/// Styles come from the [`RatatuiTheme`] resource, if present.
 ///
 /// # Type Parameters
 ///
@@ -88,6 +91,11 @@ impl<T: DistrictsProvider> DistrictsComponent<T> {
         selected_index: usize,
     ) -> Option<List<'static>> {
         let provider = resources.try_get::<T>()?;
+        let theme = resources
+            .try_get::<RatatuiTheme>()
+            .map(|theme| theme.clone())
+            .unwrap_or_default();
+        let selected_style = theme.slot_style(StyleSlot::Selection);
 
         let districts = provider.districts();
         let items: Vec<ListItem> = districts
@@ -95,9 +103,7 @@ impl<T: DistrictsProvider> DistrictsComponent<T> {
             .enumerate()
             .map(|(i, district)| {
                 let style = if i == selected_index {
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD)
+                    selected_style
                 } else {
                     Style::default()
                 };
@@ -110,6 +116,8 @@ impl<T: DistrictsProvider> DistrictsComponent<T> {
             List::new(items).block(
                 Block::default()
                     .borders(Borders::ALL)
+                    .border_style(theme.slot_style(StyleSlot::Border))
+                    .title_style(theme.slot_style(StyleSlot::Title))
                     .title(self.title.clone()),
             ),
         )
@@ -4,6 +4,8 @@
 
 use crate::context::ResourceContext;
 use crate::ui::core::MultiResourceComponent;
+use crate::ui::ratatui::theme::RatatuiTheme;
+use crate::ui::theme::StyleSlot;
 use ratatui::widgets::{Block, Borders, List, ListItem};
 
 /// Trait for log message providers
@@ -19,6 +21,7 @@ pub trait LogProvider: Send + Sync + 'static {
 /// Log component with configurable controls
 ///
 /// Renders a list of log messages with optional control hints in the title.
+/// Border and title styles come from the [`RatatuiTheme`] resource, if present.
 ///
 /// # Example
 ///
@@ -79,17 +82,29 @@ impl<T: LogProvider> LogComponent<T> {
     {
         let provider = resources.try_get::<T>()?;
         let title = title_fn(&*provider);
-
-        let items: Vec<ListItem> = provider
-            .log_messages()
-            .iter()
-            .map(|msg| ListItem::new(msg.clone()))
-            .collect();
-
-        Some(List::new(items).block(Block::default().borders(Borders::ALL).title(title)))
+        Some(log_list(resources, provider.log_messages(), title))
     }
 }
 
+fn log_list(resources: &ResourceContext, messages: &[String], title: String) -> List<'static> {
+    let theme = resources
+        .try_get::<RatatuiTheme>()
+        .map(|theme| theme.clone())
+        .unwrap_or_default();
+    let items: Vec<ListItem> = messages
+        .iter()
+        .map(|msg| ListItem::new(msg.clone()))
+        .collect();
+
+    List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.slot_style(StyleSlot::Border))
+            .title_style(theme.slot_style(StyleSlot::Title))
+            .title(title),
+    )
+}
+
 impl<T: LogProvider> Default for LogComponent<T> {
     fn default() -> Self {
         Self::new()
@@ -101,20 +116,11 @@ impl<T: LogProvider> MultiResourceComponent for LogComponent<T> {
 
     fn render_multi(&self, resources: &ResourceContext) -> Option<Self::Output> {
         let provider = resources.try_get::<T>()?;
-
-        let items: Vec<ListItem> = provider
-            .log_messages()
-            .iter()
-            .map(|msg| ListItem::new(msg.clone()))
-            .collect();
-
-        Some(
-            List::new(items).block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(self.title.clone()),
-            ),
-        )
+        Some(log_list(
+            resources,
+            provider.log_messages(),
+            self.title.clone(),
+        ))
     }
 }
 
@@ -4,9 +4,10 @@
 
 use crate::ui::core::gauge::Gauge;
 use crate::ui::core::widget::Widget;
+use crate::ui::ratatui::theme::RatatuiTheme;
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Color, Style},
     widgets::Gauge as RatatuiGauge,
     Frame,
 };
@@ -46,7 +47,8 @@ pub fn ratio_color(ratio: f64) -> Color {
 /// let gauge = GaugeWidget::new()
 ///     .with_ratio(hp_ratio)
 ///     .with_label(format!("HP: {}/{}", player.hp, player.max_hp))
-///     .with_auto_color(true);
+///     .with_auto_color(true)
+///     .with_theme(&theme);
 /// gauge.render(frame, area);
 /// ```
 pub struct GaugeWidget {
@@ -58,6 +60,8 @@ pub struct GaugeWidget {
     style: Style,
     /// Whether to automatically color based on ratio
     auto_color: bool,
+    /// Theme used for automatic coloring
+    theme: RatatuiTheme,
 }
 
 impl GaugeWidget {
@@ -68,6 +72,7 @@ impl GaugeWidget {
             label: None,
             style: Style::default(),
             auto_color: false,
+            theme: RatatuiTheme::default(),
         }
     }
 
@@ -90,7 +95,13 @@ impl GaugeWidget {
         self
     }
 
-    /// Enable automatic color based on ratio (green/yellow/red)
+    /// Take automatic colors from a theme (success/warning/danger slots)
+    pub fn with_theme(mut self, theme: &RatatuiTheme) -> Self {
+        self.theme = theme.clone();
+        self
+    }
+
+    /// Enable automatic color based on ratio (success/warning/danger)
     pub fn with_auto_color(mut self, enabled: bool) -> Self {
         self.auto_color = enabled;
         self
@@ -99,9 +110,7 @@ impl GaugeWidget {
     /// Render the gauge widget (ratatui-specific)
     pub fn render(&self, frame: &mut Frame, area: Rect) {
         let style = if self.auto_color {
-            Style::default()
-                .fg(ratio_color(self.ratio))
-                .add_modifier(Modifier::BOLD)
+            self.theme.ratio_style(self.ratio)
         } else {
             self.style
         };
@@ -2,10 +2,12 @@
 
 use crate::ui::core::menu::Menu;
 use crate::ui::core::widget::{InputEvent, Widget};
+use crate::ui::ratatui::theme::RatatuiTheme;
+use crate::ui::theme::StyleSlot;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::Style,
     text::{Line, Span},
     Frame,
 };
@@ -17,7 +19,8 @@ use ratatui::{
 /// ```ignore
 /// use issun::ui::ratatui::MenuWidget;
 ///
-/// let mut menu = MenuWidget::new(vec!["Start Game".into(), "Quit".into()]);
+/// let mut menu = MenuWidget::new(vec!["Start Game".into(), "Quit".into()])
+///     .with_theme(&theme);
 /// menu.render(frame, area);
 /// ```
 pub struct MenuWidget {
@@ -27,6 +30,8 @@ pub struct MenuWidget {
     selected: usize,
     /// Title (optional)
     title: Option<String>,
+    /// Style for the title
+    title_style: Style,
     /// Style for selected item
     selected_style: Style,
     /// Style for normal items
@@ -40,11 +45,18 @@ impl MenuWidget {
             items,
             selected: 0,
             title: None,
-            selected_style: Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
+            title_style: Style::default(),
+            selected_style: Style::default(),
             normal_style: Style::default(),
         }
+        .with_theme(&RatatuiTheme::default())
+    }
+
+    /// Take title and selection styles from a theme
+    pub fn with_theme(mut self, theme: &RatatuiTheme) -> Self {
+        self.title_style = theme.slot_style(StyleSlot::Title);
+        self.selected_style = theme.slot_style(StyleSlot::Selection);
+        self
     }
 
     /// Set title
@@ -70,12 +82,7 @@ impl MenuWidget {
 
         // Render title if present
         if let Some(title) = &self.title {
-            buf.set_string(
-                area.x,
-                y,
-                title,
-                Style::default().add_modifier(Modifier::BOLD),
-            );
+            buf.set_string(area.x, y, title, self.title_style);
             y += 2; // Skip a line after title
         }
 
@@ -179,6 +186,39 @@ mod tests {
         assert_eq!(menu.selected(), 1);
     }
 
+    #[test]
+    fn test_menu_renders_differently_per_theme() {
+        use crate::ui::theme::ThemePresets;
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let render = |theme: &RatatuiTheme| {
+            let mut terminal = Terminal::new(TestBackend::new(12, 2)).unwrap();
+            let menu = MenuWidget::new(vec!["Start".into(), "Quit".into()]).with_theme(theme);
+            terminal
+                .draw(|frame| menu.render(frame, frame.area()))
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+
+        let dark = render(&RatatuiTheme::dark());
+        let mono = render(&RatatuiTheme::preset("mono").unwrap());
+
+        // Same text, different styling of the selected row
+        assert_eq!(dark[(2, 0)].symbol(), "S");
+        assert_eq!(mono[(2, 0)].symbol(), "S");
+        assert_eq!(dark[(2, 0)].fg, ratatui::style::Color::Yellow);
+        assert!(dark[(2, 0)]
+            .modifier
+            .contains(ratatui::style::Modifier::BOLD));
+        assert_eq!(mono[(2, 0)].fg, ratatui::style::Color::Reset);
+        assert!(mono[(2, 0)]
+            .modifier
+            .contains(ratatui::style::Modifier::REVERSED));
+
+        // Unselected rows are unstyled in both
+        assert_eq!(dark[(2, 1)], mono[(2, 1)]);
+    }
+
     #[test]
     fn test_handle_input() {
         let mut menu = MenuWidget::new(vec!["Item 1".into(), "Item 2".into()]);
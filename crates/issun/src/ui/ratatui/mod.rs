@@ -7,7 +7,12 @@ pub mod gauge;
 pub mod layer;
 pub mod menu;
 pub mod modal;
+pub mod recap;
+pub mod save_menu;
+pub mod sparkline;
+pub mod table;
 pub mod theme;
+pub mod transition;
 pub mod tui;
 // pub mod dialog;  // TODO: Migrate from old structure
 // pub mod stats_panel;  // TODO: Add
@@ -21,5 +26,10 @@ pub use gauge::{ratio_color, GaugeWidget};
 pub use layer::RatatuiLayer;
 pub use menu::MenuWidget;
 pub use modal::{centered_rect, ModalWidget};
-pub use theme::RatatuiTheme;
+pub use recap::RecapWidget;
+pub use save_menu::SaveMenuWidget;
+pub use sparkline::{MetricSparkline, SparklineProvider};
+pub use table::{DataTable, SortDirection, TableColumn, TableState};
+pub use theme::{apply_theme_requests, degrade_color, RatatuiTheme};
+pub use transition::TransitionLayer;
 pub use tui::Tui;
@@ -4,9 +4,11 @@
 
 use crate::ui::core::modal::Modal;
 use crate::ui::core::widget::{InputEvent, Widget};
+use crate::ui::ratatui::theme::RatatuiTheme;
+use crate::ui::theme::StyleSlot;
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     widgets::{Block, Borders, Clear},
     Frame,
 };
@@ -48,6 +50,7 @@ pub fn centered_rect(parent: Rect, width_percent: f32, height_percent: f32) -> R
 /// use ratatui::widgets::Paragraph;
 ///
 /// let mut modal = ModalWidget::new()
+///     .with_theme(&theme)
 ///     .with_title("Inventory")
 ///     .with_size(0.7, 0.6);
 ///
@@ -67,8 +70,10 @@ pub struct ModalWidget {
     width_percent: f32,
     /// Height as percentage of parent (0.0 to 1.0)
     height_percent: f32,
-    /// Background style
+    /// Border style
     style: Style,
+    /// Title style
+    title_style: Style,
 }
 
 impl ModalWidget {
@@ -79,8 +84,17 @@ impl ModalWidget {
             title: None,
             width_percent: 0.6,
             height_percent: 0.6,
-            style: Style::default().bg(Color::Black).fg(Color::Cyan),
+            style: Style::default(),
+            title_style: Style::default(),
         }
+        .with_theme(&RatatuiTheme::default())
+    }
+
+    /// Take border and title styles from a theme
+    pub fn with_theme(mut self, theme: &RatatuiTheme) -> Self {
+        self.style = theme.slot_style(StyleSlot::Border);
+        self.title_style = theme.slot_style(StyleSlot::Title);
+        self
     }
 
     /// Set the modal title
@@ -120,7 +134,10 @@ impl ModalWidget {
         frame.render_widget(Clear, popup_area);
 
         // Render modal background
-        let mut block = Block::default().borders(Borders::ALL).style(self.style);
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .style(self.style)
+            .title_style(self.title_style);
         if let Some(title) = &self.title {
             block = block.title(title.as_str());
         }
@@ -0,0 +1,174 @@
+//! End-of-run recap widget for ratatui backend
+//!
+//! Renders a [`RunSummary`] (sections, score and score breakdown).
+
+use crate::plugin::run_summary::RunSummary;
+use crate::ui::ratatui::theme::RatatuiTheme;
+use crate::ui::theme::StyleSlot;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Recap widget for the result scene
+///
+/// # Example
+///
+/// ```ignore
+/// use issun::plugin::run_summary::RunSummary;
+/// use issun::ui::ratatui::RecapWidget;
+///
+/// if let Some(summary) = resources.get::<RunSummary>().await {
+///     RecapWidget::new(&summary)
+///         .with_theme(&theme)
+///         .render(frame, area);
+/// }
+/// ```
+pub struct RecapWidget<'a> {
+    summary: &'a RunSummary,
+    theme: RatatuiTheme,
+}
+
+impl<'a> RecapWidget<'a> {
+    /// Create a recap for the given summary, styled with the default theme
+    pub fn new(summary: &'a RunSummary) -> Self {
+        Self {
+            summary,
+            theme: RatatuiTheme::default(),
+        }
+    }
+
+    /// Take styles from a theme
+    pub fn with_theme(mut self, theme: &RatatuiTheme) -> Self {
+        self.theme = theme.clone();
+        self
+    }
+
+    /// Text lines of the recap (sections, then score and breakdown)
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        let heading = self.theme.slot_style(StyleSlot::Accent);
+        let muted = self.theme.slot_style(StyleSlot::Muted);
+        let mut lines = Vec::new();
+
+        for section in &self.summary.sections {
+            lines.push(Line::styled(section.title.clone(), heading));
+            for entry in &section.entries {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {}: ", entry.label), muted),
+                    Span::raw(format_value(entry.value)),
+                ]));
+            }
+            lines.push(Line::default());
+        }
+
+        lines.push(Line::styled(
+            format!("Final Score: {}", format_value(self.summary.score)),
+            self.theme.slot_style(StyleSlot::Title),
+        ));
+        for component in &self.summary.breakdown {
+            lines.push(Line::styled(
+                format!(
+                    "  {} × {} = {}",
+                    format_value(component.value),
+                    format_value(component.weight),
+                    format_value(component.points)
+                ),
+                muted,
+            ));
+        }
+
+        lines
+    }
+
+    /// Render the recap into `area`
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let (title, title_slot) = if self.summary.victory {
+            ("Victory", StyleSlot::Success)
+        } else {
+            ("Defeat", StyleSlot::Danger)
+        };
+        let title = if self.summary.reason.is_empty() {
+            title.to_string()
+        } else {
+            format!("{} — {}", title, self.summary.reason)
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.theme.slot_style(StyleSlot::Border))
+            .title(title)
+            .title_style(self.theme.slot_style(title_slot));
+        let paragraph = Paragraph::new(self.lines())
+            .block(block)
+            .style(Style::default())
+            .wrap(Wrap { trim: false });
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// Whole numbers without decimals, everything else with one
+fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::run_summary::{ScoreComponent, SummarySection};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn summary() -> RunSummary {
+        RunSummary {
+            victory: true,
+            reason: "boss defeated".to_string(),
+            sections: vec![SummarySection::new("combat", "Combat").with_entry(
+                "battles_won",
+                "Battles won",
+                2.0,
+            )],
+            score: 200.0,
+            breakdown: vec![ScoreComponent {
+                key: "combat.battles_won".to_string(),
+                value: 2.0,
+                weight: 100.0,
+                points: 200.0,
+            }],
+        }
+    }
+
+    fn row(buffer: &ratatui::buffer::Buffer, y: u16) -> String {
+        (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect::<String>()
+    }
+
+    #[test]
+    fn test_recap_renders_sections_and_score() {
+        let summary = summary();
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        terminal
+            .draw(|frame| RecapWidget::new(&summary).render(frame, frame.area()))
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+
+        assert!(row(&buffer, 0).contains("Victory — boss defeated"));
+        assert!(row(&buffer, 1).contains("Combat"));
+        assert!(row(&buffer, 2).contains("Battles won: 2"));
+        assert!(row(&buffer, 4).contains("Final Score: 200"));
+        assert!(row(&buffer, 5).contains("2 × 100 = 200"));
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(3.0), "3");
+        assert_eq!(format_value(-10.0), "-10");
+        assert_eq!(format_value(2.5), "2.5");
+    }
+}
@@ -0,0 +1,377 @@
+//! Save/load menu widget for ratatui backend
+//!
+//! Renders a [`SaveLoadMenu`]: the slot list, the prompt of the current mode
+//! in a modal, and the toast line.
+//!
+//! Texts come from the [`Localization`] resource when given, under these
+//! keys (English defaults in parentheses; `{slot}`, `{operation}`,
+//! `{error}` and `{mods}` are substituted):
+//!
+//! - `save_menu.title` (Save / Load)
+//! - `save_menu.empty` (— empty —)
+//! - `save_menu.corrupted` (corrupted)
+//! - `save_menu.help` (↑/↓ select  Enter load  s save  d delete  Esc back)
+//! - `save_menu.confirm_overwrite` (Overwrite {slot}? (y/n))
+//! - `save_menu.confirm_delete` (Delete {slot}? (y/n))
+//! - `save_menu.label_prompt` (Label for {slot}:)
+//! - `save_menu.saved` (Saved to {slot})
+//! - `save_menu.deleted` (Deleted {slot})
+//! - `save_menu.empty_slot` ({slot} is empty)
+//! - `save_menu.failed` (Could not {operation} {slot}: {error})
+//! - `save_menu.mods_title` ({slot} was saved with other MODs)
+//! - `save_menu.mods_missing` (Missing: {mods})
+//! - `save_menu.mods_loaded` (Loaded for this save: {mods})
+//! - `save_menu.mods_changed` (Other version: {mods})
+//! - `save_menu.mods_extra` (Not in this save: {mods})
+//! - `save_menu.mods_continue` (Enter continue)
+//! - `save_menu.mods_refused` (Load refused  Enter back)
+
+use crate::localization::Localization;
+use crate::plugin::save_load::{
+    SaveLoadMenu, SaveMenuMode, SaveMenuToast, SaveModMismatch, SaveSlotEntry,
+};
+use crate::ui::core::modal::Modal;
+use crate::ui::ratatui::modal::ModalWidget;
+use crate::ui::ratatui::theme::RatatuiTheme;
+use crate::ui::theme::StyleSlot;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Save/load menu widget
+///
+/// # Example
+///
+/// ```ignore
+/// use issun::ui::ratatui::SaveMenuWidget;
+///
+/// let l10n = resources.try_get::<Localization>();
+/// let mut widget = SaveMenuWidget::new(&data.menu).with_theme(&theme);
+/// if let Some(l10n) = l10n.as_deref() {
+///     widget = widget.with_localization(l10n);
+/// }
+/// widget.render(frame, frame.area());
+/// ```
+pub struct SaveMenuWidget<'a> {
+    menu: &'a SaveLoadMenu,
+    theme: RatatuiTheme,
+    localization: Option<&'a Localization>,
+}
+
+impl<'a> SaveMenuWidget<'a> {
+    /// Widget for `menu`, styled with the default theme and English texts
+    pub fn new(menu: &'a SaveLoadMenu) -> Self {
+        Self {
+            menu,
+            theme: RatatuiTheme::default(),
+            localization: None,
+        }
+    }
+
+    /// Take styles from a theme
+    pub fn with_theme(mut self, theme: &RatatuiTheme) -> Self {
+        self.theme = theme.clone();
+        self
+    }
+
+    /// Take texts from a localization table
+    pub fn with_localization(mut self, localization: &'a Localization) -> Self {
+        self.localization = Some(localization);
+        self
+    }
+
+    /// Text for `key`, or `default` when there is no table or no entry
+    fn text(&self, key: &str, default: &str) -> String {
+        self.localization
+            .and_then(|l10n| l10n.get(key))
+            .unwrap_or(default)
+            .to_string()
+    }
+
+    /// Row lines of the slot list
+    pub fn slot_lines(&self) -> Vec<Line<'static>> {
+        self.menu
+            .entries()
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| self.slot_line(entry, index == self.menu.selected()))
+            .collect()
+    }
+
+    fn slot_line(&self, entry: &SaveSlotEntry, selected: bool) -> Line<'static> {
+        let marker = if selected { "> " } else { "  " };
+        let slot_style = if selected {
+            self.theme.slot_style(StyleSlot::Selection)
+        } else {
+            self.theme.slot_style(StyleSlot::Accent)
+        };
+        let muted = self.theme.slot_style(StyleSlot::Muted);
+
+        let mut spans = vec![Span::styled(
+            format!("{}{:<10}", marker, entry.slot),
+            slot_style,
+        )];
+        match &entry.metadata {
+            None => spans.push(Span::styled(
+                self.text("save_menu.empty", "— empty —"),
+                muted,
+            )),
+            Some(metadata) if metadata.corrupted => spans.push(Span::styled(
+                self.text("save_menu.corrupted", "corrupted"),
+                self.theme.slot_style(StyleSlot::Danger),
+            )),
+            Some(metadata) => {
+                let timestamp = chrono::DateTime::<chrono::Local>::from(metadata.timestamp);
+                spans.push(Span::raw(format!(
+                    "{:<20}",
+                    metadata.label.clone().unwrap_or_default()
+                )));
+                spans.push(Span::styled(
+                    format!(
+                        "{}  {}",
+                        timestamp.format("%Y-%m-%d %H:%M"),
+                        format_play_time(metadata.play_time_secs)
+                    ),
+                    muted,
+                ));
+            }
+        }
+        Line::from(spans)
+    }
+
+    /// Text of the current toast, if any
+    pub fn toast_text(&self) -> Option<String> {
+        let text = match self.menu.toast()? {
+            SaveMenuToast::Saved { slot } => self
+                .text("save_menu.saved", "Saved to {slot}")
+                .replace("{slot}", slot),
+            SaveMenuToast::Deleted { slot } => self
+                .text("save_menu.deleted", "Deleted {slot}")
+                .replace("{slot}", slot),
+            SaveMenuToast::EmptySlot { slot } => self
+                .text("save_menu.empty_slot", "{slot} is empty")
+                .replace("{slot}", slot),
+            SaveMenuToast::Failed {
+                operation,
+                slot,
+                error,
+            } => self
+                .text("save_menu.failed", "Could not {operation} {slot}: {error}")
+                .replace("{operation}", operation)
+                .replace("{slot}", slot.as_deref().unwrap_or_default())
+                .replace("{error}", error),
+        };
+        Some(text)
+    }
+
+    /// Render the menu into `area`
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.theme.slot_style(StyleSlot::Border))
+            .title(self.text("save_menu.title", "Save / Load"))
+            .title_style(self.theme.slot_style(StyleSlot::Title));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        frame.render_widget(Paragraph::new(self.slot_lines()), chunks[0]);
+
+        if let Some(toast) = self.menu.toast() {
+            let slot = if toast.is_error() {
+                StyleSlot::Danger
+            } else {
+                StyleSlot::Success
+            };
+            frame.render_widget(
+                Paragraph::new(Line::styled(
+                    self.toast_text().unwrap_or_default(),
+                    self.theme.slot_style(slot),
+                )),
+                chunks[1],
+            );
+        }
+
+        frame.render_widget(
+            Paragraph::new(Line::styled(
+                self.text(
+                    "save_menu.help",
+                    "↑/↓ select  Enter load  s save  d delete  Esc back",
+                ),
+                self.theme.slot_style(StyleSlot::Muted),
+            )),
+            chunks[2],
+        );
+
+        self.render_prompt(frame, area);
+    }
+
+    /// Lines of the MOD mismatch modal
+    pub fn mismatch_lines(&self, mismatch: &SaveModMismatch) -> Vec<Line<'static>> {
+        let mut lines = vec![Line::styled(
+            self.text("save_menu.mods_title", "{slot} was saved with other MODs")
+                .replace("{slot}", &mismatch.slot),
+            self.theme.slot_style(StyleSlot::Title),
+        )];
+        let changed: Vec<String> = mismatch
+            .version_changed
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let sections = [
+            (
+                "save_menu.mods_missing",
+                "Missing: {mods}",
+                &mismatch.missing,
+                StyleSlot::Danger,
+            ),
+            (
+                "save_menu.mods_loaded",
+                "Loaded for this save: {mods}",
+                &mismatch.auto_loaded,
+                StyleSlot::Success,
+            ),
+            (
+                "save_menu.mods_changed",
+                "Other version: {mods}",
+                &changed,
+                StyleSlot::Warning,
+            ),
+            (
+                "save_menu.mods_extra",
+                "Not in this save: {mods}",
+                &mismatch.extra,
+                StyleSlot::Muted,
+            ),
+        ];
+        for (key, default, mods, slot) in sections {
+            if !mods.is_empty() {
+                lines.push(Line::styled(
+                    self.text(key, default).replace("{mods}", &mods.join(", ")),
+                    self.theme.slot_style(slot),
+                ));
+            }
+        }
+        lines.push(Line::from(""));
+        lines.push(if mismatch.refused {
+            Line::styled(
+                self.text("save_menu.mods_refused", "Load refused  Enter back"),
+                self.theme.slot_style(StyleSlot::Danger),
+            )
+        } else {
+            Line::styled(
+                self.text("save_menu.mods_continue", "Enter continue"),
+                self.theme.slot_style(StyleSlot::Muted),
+            )
+        });
+        lines
+    }
+
+    /// Modal of the confirmation, label and MOD mismatch modes
+    fn render_prompt(&self, frame: &mut Frame, area: Rect) {
+        let mut size = (0.6, 0.3);
+        let lines = match self.menu.mode() {
+            SaveMenuMode::Browse => return,
+            SaveMenuMode::ConfirmOverwrite { slot } => vec![Line::from(
+                self.text("save_menu.confirm_overwrite", "Overwrite {slot}? (y/n)")
+                    .replace("{slot}", slot),
+            )],
+            SaveMenuMode::ConfirmDelete { slot } => vec![Line::from(
+                self.text("save_menu.confirm_delete", "Delete {slot}? (y/n)")
+                    .replace("{slot}", slot),
+            )],
+            SaveMenuMode::EnterLabel { slot } => {
+                let input = self.menu.label_input();
+                let (before, after): (String, String) = {
+                    let cursor = input.cursor();
+                    (
+                        input.value().chars().take(cursor).collect(),
+                        input.value().chars().skip(cursor).collect(),
+                    )
+                };
+                vec![
+                    Line::from(
+                        self.text("save_menu.label_prompt", "Label for {slot}:")
+                            .replace("{slot}", slot),
+                    ),
+                    Line::from(vec![
+                        Span::raw(before),
+                        Span::styled("_", self.theme.slot_style(StyleSlot::Selection)),
+                        Span::raw(after),
+                    ]),
+                ]
+            }
+            SaveMenuMode::ModMismatch { mismatch } => {
+                size = (0.8, 0.7);
+                self.mismatch_lines(mismatch)
+            }
+        };
+
+        let mut modal = ModalWidget::new()
+            .with_theme(&self.theme)
+            .with_size(size.0, size.1);
+        modal.show();
+        modal.render(frame, area, |frame, inner| {
+            frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+        });
+    }
+}
+
+/// `h:mm:ss` of a play time in seconds
+fn format_play_time(secs: u64) -> String {
+    format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn test_format_play_time() {
+        assert_eq!(format_play_time(0), "0:00:00");
+        assert_eq!(format_play_time(3754), "1:02:34");
+    }
+
+    #[test]
+    fn test_localized_texts() {
+        let menu = SaveLoadMenu::new(["slot1"]);
+        let l10n = Localization::new("ja").with_strings("ja", [("save_menu.empty", "（空き）")]);
+        let widget = SaveMenuWidget::new(&menu).with_localization(&l10n);
+
+        let line = widget.slot_lines()[0].to_string();
+        assert!(line.contains("（空き）"), "{}", line);
+    }
+
+    #[test]
+    fn test_render_marks_selected_slot() {
+        let menu = SaveLoadMenu::new(["slot1", "slot2"]);
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 8)).unwrap();
+        terminal
+            .draw(|frame| SaveMenuWidget::new(&menu).render(frame, frame.area()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect()
+        };
+
+        assert!(row(0).contains("Save / Load"));
+        assert!(row(1).contains("> slot1") && row(1).contains("— empty —"));
+        assert!(row(2).contains("  slot2"));
+        assert!(row(6).contains("Esc back"));
+    }
+}
@@ -0,0 +1,176 @@
+//! Sparkline widget for ratatui backend
+//!
+//! Small trend charts for metric series such as market price histories.
+
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Sparkline},
+    Frame,
+};
+
+/// Bar height of the highest value; the lowest value is drawn at 1
+const RESOLUTION: f64 = 100.0;
+
+/// Trait for series shown in a [`MetricSparkline`]
+pub trait SparklineProvider {
+    /// Values from oldest to newest
+    fn sparkline_values(&self) -> Vec<f64>;
+
+    /// Short summary shown after the title (e.g. the latest value)
+    fn sparkline_label(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Sparkline of a metric series (ratatui implementation)
+///
+/// Values are scaled between the series minimum and maximum, so small
+/// moves of a large value stay visible.
+///
+/// # Example
+///
+/// ```ignore
+/// use issun::ui::ratatui::MetricSparkline;
+///
+/// let water = market.get_item(&"water".to_string()).unwrap();
+/// MetricSparkline::new()
+///     .with_title("Water")
+///     .with_provider(&water.history)
+///     .render(frame, area);
+/// ```
+pub struct MetricSparkline {
+    title: String,
+    label: Option<String>,
+    bars: Vec<u64>,
+    style: Style,
+}
+
+impl MetricSparkline {
+    /// Create an empty sparkline
+    pub fn new() -> Self {
+        Self {
+            title: String::new(),
+            label: None,
+            bars: Vec::new(),
+            style: Style::default(),
+        }
+    }
+
+    /// Set the title
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Take values and label from a provider
+    pub fn with_provider<P: SparklineProvider + ?Sized>(mut self, provider: &P) -> Self {
+        self.label = provider.sparkline_label();
+        self.with_values(&provider.sparkline_values())
+    }
+
+    /// Set the values directly
+    pub fn with_values(mut self, values: &[f64]) -> Self {
+        self.bars = scale(values);
+        self
+    }
+
+    /// Set custom style
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Scaled bar heights, oldest first
+    pub fn bars(&self) -> &[u64] {
+        &self.bars
+    }
+
+    /// Title including the provider label
+    pub fn title(&self) -> String {
+        match &self.label {
+            Some(label) if self.title.is_empty() => label.clone(),
+            Some(label) => format!("{} {}", self.title, label),
+            None => self.title.clone(),
+        }
+    }
+
+    /// Render the sparkline widget (ratatui-specific)
+    ///
+    /// Shows the newest values that fit into the area.
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default().borders(Borders::ALL).title(self.title());
+        let width = block.inner(area).width as usize;
+        let start = self.bars.len().saturating_sub(width);
+
+        let sparkline = Sparkline::default()
+            .block(block)
+            .data(&self.bars[start..])
+            .max(RESOLUTION as u64)
+            .style(self.style);
+
+        frame.render_widget(sparkline, area);
+    }
+}
+
+impl Default for MetricSparkline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn scale(values: &[f64]) -> Vec<u64> {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    values
+        .iter()
+        .map(|value| {
+            if range > 0.0 {
+                1 + ((value - min) / range * (RESOLUTION - 1.0)).round() as u64
+            } else {
+                (RESOLUTION / 2.0) as u64
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Series(Vec<f64>);
+
+    impl SparklineProvider for Series {
+        fn sparkline_values(&self) -> Vec<f64> {
+            self.0.clone()
+        }
+
+        fn sparkline_label(&self) -> Option<String> {
+            self.0.last().map(|value| format!("{:.1}", value))
+        }
+    }
+
+    #[test]
+    fn test_scales_between_min_and_max() {
+        let sparkline = MetricSparkline::new().with_values(&[100.0, 150.0, 200.0]);
+        assert_eq!(sparkline.bars(), &[1, 51, 100]);
+    }
+
+    #[test]
+    fn test_flat_series() {
+        let sparkline = MetricSparkline::new().with_values(&[7.0, 7.0]);
+        assert_eq!(sparkline.bars(), &[50, 50]);
+        assert!(MetricSparkline::new().with_values(&[]).bars().is_empty());
+    }
+
+    #[test]
+    fn test_provider_label_in_title() {
+        let series = Series(vec![1.0, 2.5]);
+        let sparkline = MetricSparkline::new()
+            .with_title("Water")
+            .with_provider(&series);
+        assert_eq!(sparkline.title(), "Water 2.5");
+        assert_eq!(sparkline.bars(), &[1, 100]);
+    }
+}
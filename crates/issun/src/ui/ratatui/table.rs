@@ -0,0 +1,726 @@
+//! Data table widget for ratatui backend
+//!
+//! Management screens list many entities with several attributes each.
+//! [`DataTable`] renders them as typed columns, lets the player sort by a
+//! column and keeps the selection on the same row while the order changes.
+
+use crate::ui::core::widget::InputEvent;
+use crate::ui::ratatui::theme::RatatuiTheme;
+use crate::ui::theme::StyleSlot;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::Style,
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::ops::Range;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Sort direction of a table column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// Arrow shown next to the sorted column's header
+    pub fn indicator(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "▲",
+            SortDirection::Descending => "▼",
+        }
+    }
+}
+
+/// Selection, sort and scroll state of a [`DataTable`]
+///
+/// Lives in the scene data between frames. The selection is stored as a row
+/// key, so it stays on the same row when the rows are re-sorted or refreshed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableState {
+    selected: Option<String>,
+    sort: Option<(usize, SortDirection)>,
+    focused_column: usize,
+    column_offset: usize,
+    row_offset: usize,
+}
+
+impl TableState {
+    /// Create an empty state (first row selected on first use, unsorted)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Key of the selected row
+    pub fn selected(&self) -> Option<&str> {
+        self.selected.as_deref()
+    }
+
+    /// Select the row with the given key
+    pub fn select(&mut self, key: impl Into<String>) {
+        self.selected = Some(key.into());
+    }
+
+    /// Sorted column and direction, `None` when rows keep their input order
+    pub fn sort(&self) -> Option<(usize, SortDirection)> {
+        self.sort
+    }
+
+    /// Sort by a column, or restore the input order with `None`
+    pub fn set_sort(&mut self, sort: Option<(usize, SortDirection)>) {
+        self.sort = sort;
+    }
+
+    /// Column the sort key applies to
+    pub fn focused_column(&self) -> usize {
+        self.focused_column
+    }
+
+    /// First column shown when the columns don't fit the width
+    pub fn column_offset(&self) -> usize {
+        self.column_offset
+    }
+}
+
+/// Comparator of a sortable column
+type Compare<'a, R> = Box<dyn Fn(&R, &R) -> Ordering + 'a>;
+
+/// A column of a [`DataTable`]
+pub struct TableColumn<'a, R> {
+    header: String,
+    width: Constraint,
+    cell: Box<dyn Fn(&R) -> String + 'a>,
+    compare: Option<Compare<'a, R>>,
+}
+
+impl<'a, R> TableColumn<'a, R> {
+    /// Create a column from a header, a width and a cell extractor
+    ///
+    /// Supported widths: `Length`, `Max`, `Percentage` and `Ratio` are fixed;
+    /// `Min` and `Fill` take the space left over (`Fill` by weight, `Min` with
+    /// weight 1) and start at their minimum or the header width respectively.
+    pub fn new(
+        header: impl Into<String>,
+        width: Constraint,
+        cell: impl Fn(&R) -> String + 'a,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            width,
+            cell: Box::new(cell),
+            compare: None,
+        }
+    }
+
+    /// Make the column sortable with a comparator
+    pub fn sort_by(mut self, compare: impl Fn(&R, &R) -> Ordering + 'a) -> Self {
+        self.compare = Some(Box::new(compare));
+        self
+    }
+
+    /// Make the column sortable by a key
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&R) -> K + 'a) -> Self {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Whether the sort key has an effect on this column
+    pub fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    /// Header text
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+}
+
+/// Table of rows with typed columns (ratatui implementation)
+///
+/// The table borrows nothing from the rows; callers pass the row slice and a
+/// [`TableState`] to [`DataTable::handle_key`] and [`DataTable::render`].
+///
+/// - Up/Down move the selection in display order
+/// - Left/Right move the column focus, scrolling horizontally when needed
+/// - The sort key (`s` by default) cycles the focused column through
+///   ascending, descending and unsorted
+///
+/// # Example
+///
+/// ```ignore
+/// use issun::ui::ratatui::{DataTable, TableColumn};
+/// use ratatui::layout::Constraint;
+///
+/// let table = DataTable::new(|f: &Faction| f.id.clone())
+///     .column(TableColumn::new("Name", Constraint::Min(8), |f: &Faction| f.name.clone()))
+///     .column(
+///         TableColumn::new("Morale", Constraint::Length(6), |f: &Faction| f.morale.to_string())
+///             .sort_by_key(|f: &Faction| f.morale),
+///     )
+///     .with_theme(&theme);
+///
+/// table.handle_key(input, &factions, &mut state);
+/// table.render(frame, area, &factions, &mut state);
+/// ```
+pub struct DataTable<'a, R> {
+    columns: Vec<TableColumn<'a, R>>,
+    row_key: Box<dyn Fn(&R) -> String + 'a>,
+    sort_key: char,
+    /// Style for column headers
+    header_style: Style,
+    /// Style for the focused column's header
+    focused_header_style: Style,
+    /// Style for the selected row
+    selected_style: Style,
+    /// Style for every other row
+    stripe_style: Style,
+}
+
+impl<'a, R> DataTable<'a, R> {
+    /// Create a table whose rows are identified by `row_key`
+    pub fn new(row_key: impl Fn(&R) -> String + 'a) -> Self {
+        Self {
+            columns: Vec::new(),
+            row_key: Box::new(row_key),
+            sort_key: 's',
+            header_style: Style::default(),
+            focused_header_style: Style::default(),
+            selected_style: Style::default(),
+            stripe_style: Style::default(),
+        }
+        .with_theme(&RatatuiTheme::default())
+    }
+
+    /// Builder: Append a column
+    pub fn column(mut self, column: TableColumn<'a, R>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Take header, selection and stripe styles from a theme
+    pub fn with_theme(mut self, theme: &RatatuiTheme) -> Self {
+        self.header_style = theme.slot_style(StyleSlot::Title);
+        self.focused_header_style = theme.slot_style(StyleSlot::Accent);
+        self.selected_style = theme.slot_style(StyleSlot::Selection);
+        self.stripe_style = theme.slot_style(StyleSlot::Stripe);
+        self
+    }
+
+    /// Builder: Change the key that cycles the sort order
+    pub fn with_sort_key(mut self, key: char) -> Self {
+        self.sort_key = key;
+        self
+    }
+
+    /// Columns of the table
+    pub fn columns(&self) -> &[TableColumn<'a, R>] {
+        &self.columns
+    }
+
+    /// Rows in display order
+    ///
+    /// Sorting is stable: rows that compare equal keep their input order in
+    /// both directions.
+    pub fn sorted<'r>(&self, rows: &'r [R], state: &TableState) -> Vec<&'r R> {
+        let mut sorted: Vec<&R> = rows.iter().collect();
+        let compare = state.sort.and_then(|(column, direction)| {
+            self.columns
+                .get(column)
+                .and_then(|c| c.compare.as_ref())
+                .map(|compare| (compare, direction))
+        });
+        if let Some((compare, direction)) = compare {
+            match direction {
+                SortDirection::Ascending => sorted.sort_by(|a, b| compare(a, b)),
+                SortDirection::Descending => sorted.sort_by(|a, b| compare(b, a)),
+            }
+        }
+        sorted
+    }
+
+    /// The selected row, if it is still present
+    pub fn selected_row<'r>(&self, rows: &'r [R], state: &TableState) -> Option<&'r R> {
+        let selected = state.selected.as_deref()?;
+        rows.iter().find(|row| (self.row_key)(row) == selected)
+    }
+
+    /// Handle navigation and sort input
+    ///
+    /// Returns `true` when the event was consumed.
+    pub fn handle_key(&self, event: InputEvent, rows: &[R], state: &mut TableState) -> bool {
+        match event {
+            InputEvent::Up | InputEvent::Down => {
+                let sorted = self.sorted(rows, state);
+                let Some(current) = self.selected_index(&sorted, state) else {
+                    return false;
+                };
+                let next = if event == InputEvent::Up {
+                    current.saturating_sub(1)
+                } else {
+                    (current + 1).min(sorted.len() - 1)
+                };
+                state.selected = Some((self.row_key)(sorted[next]));
+                true
+            }
+            InputEvent::Left if !self.columns.is_empty() => {
+                state.focused_column = state.focused_column.saturating_sub(1);
+                true
+            }
+            InputEvent::Right if !self.columns.is_empty() => {
+                state.focused_column = (state.focused_column + 1).min(self.columns.len() - 1);
+                true
+            }
+            InputEvent::Char(c) if c == self.sort_key => {
+                let focused = state.focused_column;
+                if !self.columns.get(focused).is_some_and(|c| c.is_sortable()) {
+                    return false;
+                }
+                state.sort = match state.sort {
+                    Some((column, SortDirection::Ascending)) if column == focused => {
+                        Some((column, SortDirection::Descending))
+                    }
+                    Some((column, SortDirection::Descending)) if column == focused => None,
+                    _ => Some((focused, SortDirection::Ascending)),
+                };
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Render the table (ratatui-specific)
+    pub fn render(&self, frame: &mut Frame, area: Rect, rows: &[R], state: &mut TableState) {
+        self.render_to_buffer(area, frame.buffer_mut(), rows, state);
+    }
+
+    /// Render to buffer (for lower-level rendering)
+    ///
+    /// Scrolls `state` so the selected row and the focused column are visible.
+    pub fn render_to_buffer(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        rows: &[R],
+        state: &mut TableState,
+    ) {
+        if area.width == 0 || area.height == 0 || self.columns.is_empty() {
+            return;
+        }
+
+        let widths = self.column_widths(area.width);
+        let visible = self.scroll_columns(&widths, area.width, state);
+        let sorted = self.sorted(rows, state);
+        let selected = self.selected_index(&sorted, state);
+
+        // Header
+        let mut x = area.x;
+        for column in visible.clone() {
+            let width = widths[column].min(area.right() - x);
+            let mut style = self.header_style;
+            if column == state.focused_column {
+                style = style.patch(self.focused_header_style);
+            }
+            let header = match state.sort {
+                Some((sorted_column, direction)) if sorted_column == column && width >= 2 => {
+                    let header = ellipsize(&self.columns[column].header, width as usize - 2);
+                    format!(
+                        "{:<pad$} {}",
+                        header,
+                        direction.indicator(),
+                        pad = width as usize - 2
+                    )
+                }
+                _ => ellipsize(&self.columns[column].header, width as usize),
+            };
+            buf.set_stringn(x, area.y, header, width as usize, style);
+            x = (x + width + 1).min(area.right());
+        }
+
+        // Rows
+        let body_height = (area.height - 1) as usize;
+        if let Some(selected) = selected {
+            if selected < state.row_offset {
+                state.row_offset = selected;
+            } else if selected >= state.row_offset + body_height {
+                state.row_offset = selected + 1 - body_height;
+            }
+        }
+        state.row_offset = state
+            .row_offset
+            .min(sorted.len().saturating_sub(body_height));
+
+        for (line, position) in (state.row_offset..sorted.len())
+            .take(body_height)
+            .enumerate()
+        {
+            let y = area.y + 1 + line as u16;
+            let style = if Some(position) == selected {
+                self.selected_style
+            } else if position % 2 == 1 {
+                self.stripe_style
+            } else {
+                Style::default()
+            };
+            buf.set_style(Rect::new(area.x, y, area.width, 1), style);
+
+            let mut x = area.x;
+            for column in visible.clone() {
+                let width = widths[column].min(area.right() - x);
+                let cell = ellipsize(
+                    &(self.columns[column].cell)(sorted[position]),
+                    width as usize,
+                );
+                buf.set_stringn(x, y, cell, width as usize, style);
+                x = (x + width + 1).min(area.right());
+            }
+        }
+    }
+
+    /// Position of the selected row in `sorted`
+    ///
+    /// Selects the first row when nothing is selected or the selected row is
+    /// gone.
+    fn selected_index(&self, sorted: &[&R], state: &mut TableState) -> Option<usize> {
+        let found = state
+            .selected
+            .as_deref()
+            .and_then(|key| sorted.iter().position(|row| (self.row_key)(row) == key));
+        match found {
+            Some(index) => Some(index),
+            None => {
+                let first = sorted.first()?;
+                state.selected = Some((self.row_key)(first));
+                Some(0)
+            }
+        }
+    }
+
+    /// Resolve column constraints against the available width
+    fn column_widths(&self, total: u16) -> Vec<u16> {
+        let mut widths: Vec<u16> = self
+            .columns
+            .iter()
+            .map(|column| match column.width {
+                Constraint::Length(n) | Constraint::Min(n) => n,
+                Constraint::Max(n) => n.min(total),
+                Constraint::Percentage(p) => (total as u32 * p as u32 / 100) as u16,
+                Constraint::Ratio(_, 0) => 0,
+                Constraint::Ratio(a, b) => {
+                    (total as u64 * a as u64 / b as u64).min(total as u64) as u16
+                }
+                Constraint::Fill(_) => column.header.width() as u16,
+            })
+            .collect();
+
+        let weights: Vec<u32> = self
+            .columns
+            .iter()
+            .map(|column| match column.width {
+                Constraint::Min(_) => 1,
+                Constraint::Fill(weight) => weight as u32,
+                _ => 0,
+            })
+            .collect();
+        let total_weight: u32 = weights.iter().sum();
+        let used = widths.iter().map(|w| *w as u32).sum::<u32>() + widths.len() as u32 - 1;
+        if total_weight > 0 && used < total as u32 {
+            let leftover = total as u32 - used;
+            for (width, weight) in widths.iter_mut().zip(&weights) {
+                *width += (leftover * weight / total_weight) as u16;
+            }
+        }
+        widths
+    }
+
+    /// Columns that fit the width, scrolled so the focused column is shown
+    fn scroll_columns(&self, widths: &[u16], total: u16, state: &mut TableState) -> Range<usize> {
+        let last = self.columns.len() - 1;
+        state.focused_column = state.focused_column.min(last);
+        state.column_offset = state.column_offset.min(state.focused_column);
+
+        let end_from = |offset: usize| {
+            let mut used = 0u32;
+            let mut end = offset;
+            while end <= last {
+                let needed = widths[end] as u32 + if end > offset { 1 } else { 0 };
+                if end > offset && used + needed > total as u32 {
+                    break;
+                }
+                used += needed;
+                end += 1;
+            }
+            end
+        };
+
+        while end_from(state.column_offset) <= state.focused_column {
+            state.column_offset += 1;
+        }
+        state.column_offset..end_from(state.column_offset)
+    }
+}
+
+/// Shorten `text` to `width` terminal cells, ending in `…` when cut
+fn ellipsize(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut shortened = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > width - 1 {
+            break;
+        }
+        shortened.push(c);
+        used += w;
+    }
+    shortened.push('…');
+    shortened
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, style::Color, Terminal};
+
+    #[derive(Debug, Clone)]
+    struct Unit {
+        id: &'static str,
+        name: &'static str,
+        rank: u32,
+    }
+
+    fn units() -> Vec<Unit> {
+        vec![
+            Unit {
+                id: "a",
+                name: "Alpha",
+                rank: 2,
+            },
+            Unit {
+                id: "b",
+                name: "Bravo",
+                rank: 1,
+            },
+            Unit {
+                id: "c",
+                name: "Charlie",
+                rank: 2,
+            },
+            Unit {
+                id: "d",
+                name: "Delta",
+                rank: 1,
+            },
+        ]
+    }
+
+    fn table<'a>() -> DataTable<'a, Unit> {
+        DataTable::new(|u: &Unit| u.id.to_string())
+            .column(
+                TableColumn::new("Name", Constraint::Length(8), |u: &Unit| u.name.to_string())
+                    .sort_by_key(|u: &Unit| u.name),
+            )
+            .column(
+                TableColumn::new("Rank", Constraint::Length(6), |u: &Unit| u.rank.to_string())
+                    .sort_by_key(|u: &Unit| u.rank),
+            )
+            .column(TableColumn::new("Id", Constraint::Length(4), |u: &Unit| {
+                u.id.to_string()
+            }))
+    }
+
+    fn ids(table: &DataTable<'_, Unit>, rows: &[Unit], state: &TableState) -> Vec<&'static str> {
+        table.sorted(rows, state).iter().map(|u| u.id).collect()
+    }
+
+    fn render(
+        table: &DataTable<'_, Unit>,
+        rows: &[Unit],
+        state: &mut TableState,
+        width: u16,
+    ) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(width, rows.len() as u16 + 1)).unwrap();
+        terminal
+            .draw(|frame| table.render(frame, frame.area(), rows, state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sort_key_cycles_direction() {
+        let table = table();
+        let rows = units();
+        let mut state = TableState::new();
+
+        assert!(table.handle_key(InputEvent::Right, &rows, &mut state));
+        assert!(table.handle_key(InputEvent::Char('s'), &rows, &mut state));
+        assert_eq!(state.sort(), Some((1, SortDirection::Ascending)));
+        // Ties keep their input order
+        assert_eq!(ids(&table, &rows, &state), vec!["b", "d", "a", "c"]);
+
+        table.handle_key(InputEvent::Char('s'), &rows, &mut state);
+        assert_eq!(state.sort(), Some((1, SortDirection::Descending)));
+        assert_eq!(ids(&table, &rows, &state), vec!["a", "c", "b", "d"]);
+
+        table.handle_key(InputEvent::Char('s'), &rows, &mut state);
+        assert_eq!(state.sort(), None);
+        assert_eq!(ids(&table, &rows, &state), vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_sort_key_ignores_unsortable_column() {
+        let table = table();
+        let rows = units();
+        let mut state = TableState::new();
+
+        table.handle_key(InputEvent::Right, &rows, &mut state);
+        table.handle_key(InputEvent::Right, &rows, &mut state);
+        table.handle_key(InputEvent::Right, &rows, &mut state);
+        assert_eq!(state.focused_column(), 2);
+        assert!(!table.handle_key(InputEvent::Char('s'), &rows, &mut state));
+        assert_eq!(state.sort(), None);
+    }
+
+    #[test]
+    fn test_selection_follows_row_across_resort() {
+        let table = table();
+        let rows = units();
+        let mut state = TableState::new();
+
+        table.handle_key(InputEvent::Down, &rows, &mut state);
+        assert_eq!(state.selected(), Some("b"));
+
+        state.set_sort(Some((1, SortDirection::Descending)));
+        assert_eq!(table.selected_row(&rows, &state).unwrap().id, "b");
+
+        // Navigation continues from the row's new position
+        table.handle_key(InputEvent::Down, &rows, &mut state);
+        assert_eq!(state.selected(), Some("d"));
+        table.handle_key(InputEvent::Down, &rows, &mut state);
+        assert_eq!(state.selected(), Some("d"));
+        table.handle_key(InputEvent::Up, &rows, &mut state);
+        table.handle_key(InputEvent::Up, &rows, &mut state);
+        assert_eq!(state.selected(), Some("c"));
+    }
+
+    #[test]
+    fn test_selection_falls_back_when_row_disappears() {
+        let table = table();
+        let mut rows = units();
+        let mut state = TableState::new();
+        state.select("c");
+
+        rows.retain(|u| u.id != "c");
+        table.handle_key(InputEvent::Down, &rows, &mut state);
+        assert_eq!(state.selected(), Some("b"));
+    }
+
+    #[test]
+    fn test_render_ellipsizes_narrow_columns() {
+        let table = DataTable::new(|u: &Unit| u.id.to_string())
+            .column(TableColumn::new(
+                "Name",
+                Constraint::Length(4),
+                |u: &Unit| u.name.to_string(),
+            ))
+            .column(
+                TableColumn::new("Rank", Constraint::Length(4), |u: &Unit| u.rank.to_string())
+                    .sort_by_key(|u: &Unit| u.rank),
+            );
+        let rows = units();
+        let mut state = TableState::new();
+        state.set_sort(Some((1, SortDirection::Ascending)));
+
+        assert_eq!(
+            render(&table, &rows, &mut state, 9),
+            vec![
+                "Name R… ▲",
+                "Bra… 1   ",
+                "Del… 1   ",
+                "Alp… 2   ",
+                "Cha… 2   ",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_scrolls_to_focused_column() {
+        let table = table();
+        let rows = units();
+        let mut state = TableState::new();
+
+        assert_eq!(
+            render(&table, &rows, &mut state, 12),
+            vec![
+                "Name        ",
+                "Alpha       ",
+                "Bravo       ",
+                "Charlie     ",
+                "Delta       ",
+            ]
+        );
+
+        table.handle_key(InputEvent::Right, &rows, &mut state);
+        table.handle_key(InputEvent::Right, &rows, &mut state);
+        assert_eq!(
+            render(&table, &rows, &mut state, 12),
+            vec![
+                "Rank   Id   ",
+                "2      a    ",
+                "1      b    ",
+                "2      c    ",
+                "1      d    ",
+            ]
+        );
+        assert_eq!(state.column_offset(), 1);
+
+        table.handle_key(InputEvent::Left, &rows, &mut state);
+        table.handle_key(InputEvent::Left, &rows, &mut state);
+        render(&table, &rows, &mut state, 12);
+        assert_eq!(state.column_offset(), 0);
+    }
+
+    #[test]
+    fn test_render_stripes_and_selection_use_theme() {
+        let theme = RatatuiTheme::default();
+        let table = table().with_theme(&theme);
+        let rows = units();
+        let mut state = TableState::new();
+        state.select("c");
+
+        let mut terminal = Terminal::new(TestBackend::new(20, 5)).unwrap();
+        terminal
+            .draw(|frame| table.render(frame, frame.area(), &rows, &mut state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+
+        let stripe = theme.slot_style(StyleSlot::Stripe);
+        let selection = theme.slot_style(StyleSlot::Selection);
+        assert_eq!(buffer[(0, 1)].bg, Color::Reset);
+        assert_eq!(Some(buffer[(0, 2)].bg), stripe.bg);
+        assert_eq!(Some(buffer[(0, 3)].fg), selection.fg);
+        assert_eq!(Some(buffer[(19, 4)].bg), stripe.bg);
+    }
+
+    #[test]
+    fn test_ellipsize_wide_characters() {
+        assert_eq!(ellipsize("支配率", 6), "支配率");
+        assert_eq!(ellipsize("支配率", 5), "支配…");
+        assert_eq!(ellipsize("支配率", 4), "支…");
+        assert_eq!(ellipsize("abc", 0), "");
+    }
+}
@@ -1,18 +1,90 @@
 //! Ratatui implementation of Theme trait
-
-use crate::ui::theme::{Emphasis, Theme, ThemeColor, ThemeConfig, ThemePresets};
+//!
+//! [`RatatuiTheme`] is also the theme *resource*: insert it into the
+//! [`ResourceContext`] and all `issun::ui` widgets and components pick their
+//! styles from it. Colors are degraded to the terminal's [`ColorDepth`]
+//! (RGB → 256 → 16 → mono) when styles are resolved.
+
+use crate::context::ResourceContext;
+use crate::error::{IssunError, Result};
+use crate::event::EventBus;
+use crate::plugin::loot::Rarity;
+use crate::ui::theme::{
+    ColorDepth, Emphasis, SetThemeRequested, StyleSlot, Theme, ThemeChanged, ThemeColor,
+    ThemeConfig, ThemePresets, ThemeSource,
+};
 use ratatui::style::{Color, Modifier, Style};
+use std::path::Path;
 
 /// Ratatui-specific theme implementation
 #[derive(Debug, Clone)]
 pub struct RatatuiTheme {
     config: ThemeConfig,
+    depth: ColorDepth,
 }
 
+impl Default for RatatuiTheme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl crate::resources::Resource for RatatuiTheme {}
+
 impl RatatuiTheme {
     /// Create a new RatatuiTheme from configuration
+    ///
+    /// Colors are rendered at full depth; see [`RatatuiTheme::with_color_depth`].
     pub fn new(config: ThemeConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            depth: ColorDepth::TrueColor,
+        }
+    }
+
+    /// Look up a preset by name (`dark`, `light`, `high_contrast`, `mono`, `plague`, `savior`)
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "plague" => Some(Self::plague()),
+            "savior" => Some(Self::savior()),
+            _ => ThemeConfig::preset(name).map(Self::new),
+        }
+    }
+
+    /// Load a theme from a TOML file (see [`ThemeConfig::from_toml_str`])
+    ///
+    /// Ignored entries are reported on stderr.
+    pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|e| {
+            IssunError::AssetLoad(format!("Failed to read theme {:?}: {}", path, e))
+        })?;
+        let (config, warnings) = ThemeConfig::from_toml_str(&source)?;
+        for warning in warnings {
+            eprintln!("[Theme] {:?}: {}", path, warning);
+        }
+        Ok(Self::new(config))
+    }
+
+    /// Render colors at the given depth
+    pub fn with_color_depth(mut self, depth: ColorDepth) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Render colors at the depth reported by the terminal
+    pub fn with_detected_color_depth(self) -> Self {
+        self.with_color_depth(ColorDepth::detect())
+    }
+
+    /// Color depth used when resolving styles
+    pub fn color_depth(&self) -> ColorDepth {
+        self.depth
+    }
+
+    /// Underlying configuration
+    pub fn config(&self) -> &ThemeConfig {
+        &self.config
     }
 
     /// Create a "Plague" theme (red-based, ominous)
@@ -35,17 +107,21 @@ impl RatatuiTheme {
         )
     }
 
-    /// Convert ThemeColor to ratatui Color
+    /// Convert ThemeColor to ratatui Color (degraded to the theme's color depth)
     pub fn to_ratatui_color(&self, color: ThemeColor) -> Color {
+        degrade_color(self.full_color(color), self.depth)
+    }
+
+    fn full_color(&self, color: ThemeColor) -> Color {
         match color {
-            ThemeColor::Primary => self.to_ratatui_color(self.config.primary),
-            ThemeColor::Secondary => self.to_ratatui_color(self.config.secondary),
-            ThemeColor::Error => self.to_ratatui_color(self.config.error),
-            ThemeColor::Success => self.to_ratatui_color(self.config.success),
-            ThemeColor::Warning => self.to_ratatui_color(self.config.warning),
-            ThemeColor::Info => self.to_ratatui_color(self.config.info),
-            ThemeColor::Foreground => self.to_ratatui_color(self.config.foreground),
-            ThemeColor::Background => self.to_ratatui_color(self.config.background),
+            ThemeColor::Primary => self.full_color(self.config.primary),
+            ThemeColor::Secondary => self.full_color(self.config.secondary),
+            ThemeColor::Error => self.full_color(self.config.error),
+            ThemeColor::Success => self.full_color(self.config.success),
+            ThemeColor::Warning => self.full_color(self.config.warning),
+            ThemeColor::Info => self.full_color(self.config.info),
+            ThemeColor::Foreground => self.full_color(self.config.foreground),
+            ThemeColor::Background => self.full_color(self.config.background),
             ThemeColor::Muted => Color::DarkGray,
             ThemeColor::Highlight => Color::Yellow,
             ThemeColor::Rgb(r, g, b) => Color::Rgb(r, g, b),
@@ -59,17 +135,37 @@ impl RatatuiTheme {
 
     /// Create a Style with the given color and emphasis
     pub fn style_with_emphasis(&self, color: ThemeColor, emphasis: Emphasis) -> Style {
-        let mut style = self.style(color);
-
-        style = match emphasis {
-            Emphasis::Bold => style.add_modifier(Modifier::BOLD),
-            Emphasis::Italic => style.add_modifier(Modifier::ITALIC),
-            Emphasis::Underline => style.add_modifier(Modifier::UNDERLINED),
-            Emphasis::Dim => style.add_modifier(Modifier::DIM),
-            Emphasis::Normal => style,
-        };
+        apply_emphasis(self.style(color), emphasis)
+    }
 
-        style
+    /// Resolve a named style slot
+    pub fn slot_style(&self, slot: StyleSlot) -> Style {
+        let slot = self.config.slot(slot);
+        let mut style = Style::default();
+        if let Some(fg) = slot.fg {
+            style = style.fg(self.to_ratatui_color(fg));
+        }
+        if let Some(bg) = slot.bg {
+            style = style.bg(self.to_ratatui_color(bg));
+        }
+        apply_emphasis(style, slot.emphasis)
+    }
+
+    /// Style for a loot rarity tier
+    pub fn rarity_style(&self, rarity: Rarity) -> Style {
+        self.slot_style(StyleSlot::Rarity(rarity))
+    }
+
+    /// Style for a resource ratio (success > 60% > warning > 30% > danger)
+    pub fn ratio_style(&self, ratio: f64) -> Style {
+        let slot = if ratio > 0.6 {
+            StyleSlot::Success
+        } else if ratio > 0.3 {
+            StyleSlot::Warning
+        } else {
+            StyleSlot::Danger
+        };
+        self.slot_style(slot).add_modifier(Modifier::BOLD)
     }
 
     /// Get primary style (with bold)
@@ -161,12 +257,155 @@ impl ThemePresets for RatatuiTheme {
     }
 
     fn high_contrast() -> Self {
-        Self::new(
-            ThemeConfig::dark()
-                .with_name("high_contrast")
-                .with_primary(ThemeColor::Rgb(255, 255, 255))
-                .with_secondary(ThemeColor::Rgb(255, 255, 0)),
-        )
+        Self::new(ThemeConfig::high_contrast())
+    }
+}
+
+fn apply_emphasis(style: Style, emphasis: Emphasis) -> Style {
+    match emphasis {
+        Emphasis::Bold => style.add_modifier(Modifier::BOLD),
+        Emphasis::Italic => style.add_modifier(Modifier::ITALIC),
+        Emphasis::Underline => style.add_modifier(Modifier::UNDERLINED),
+        Emphasis::Dim => style.add_modifier(Modifier::DIM),
+        Emphasis::Reversed => style.add_modifier(Modifier::REVERSED),
+        Emphasis::Normal => style,
+    }
+}
+
+/// xterm default RGB values of the 16 ANSI colors
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Channel levels of the 6x6x6 color cube in the 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Degrade a color so the terminal can display it
+///
+/// RGB colors map to the nearest 256-palette entry, then to the nearest of the
+/// 16 ANSI colors; in mono every color becomes the terminal default.
+pub fn degrade_color(color: Color, depth: ColorDepth) -> Color {
+    match (depth, color) {
+        (_, Color::Reset) | (ColorDepth::TrueColor, _) => color,
+        (ColorDepth::Mono, _) => Color::Reset,
+        (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+        (ColorDepth::Ansi256, _) => color,
+        (ColorDepth::Ansi16, Color::Rgb(r, g, b)) => nearest_ansi16((r, g, b)),
+        (ColorDepth::Ansi16, Color::Indexed(index)) => nearest_ansi16(ansi256_to_rgb(index)),
+        (ColorDepth::Ansi16, _) => color,
+    }
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (i32::from(x) - i32::from(y)).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+fn nearest_ansi16(rgb: (u8, u8, u8)) -> Color {
+    ANSI16
+        .iter()
+        .min_by_key(|(_, candidate)| distance(rgb, *candidate))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, l)| (i32::from(**l) - i32::from(c)).abs())
+            .map(|(i, _)| i as u8)
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (
+        CUBE_LEVELS[ri as usize],
+        CUBE_LEVELS[gi as usize],
+        CUBE_LEVELS[bi as usize],
+    );
+
+    // Grayscale ramp 232..=255 covers 8..=238 in steps of 10
+    let avg = ((u16::from(r) + u16::from(g) + u16::from(b)) / 3) as u8;
+    let gray_index = (avg.saturating_sub(3) / 10).min(23);
+    let gray = 8 + gray_index * 10;
+
+    if distance((r, g, b), (gray, gray, gray)) < distance((r, g, b), cube) {
+        232 + gray_index
+    } else {
+        16 + 36 * ri + 6 * gi + bi
+    }
+}
+
+fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16[index as usize].1,
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[((i / 6) % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Process [`SetThemeRequested`] events, replacing the [`RatatuiTheme`] resource
+///
+/// The color depth of the current theme is kept (or detected if there is no
+/// theme yet). Publishes [`ThemeChanged`] on success; failures are logged and
+/// leave the current theme in place.
+pub async fn apply_theme_requests(resources: &mut ResourceContext) {
+    let requests: Vec<SetThemeRequested> = match resources.get_mut::<EventBus>().await {
+        Some(mut bus) => bus.reader::<SetThemeRequested>().iter().cloned().collect(),
+        None => return,
+    };
+
+    for request in requests {
+        let theme = match &request.source {
+            ThemeSource::Preset(name) => RatatuiTheme::preset(name)
+                .ok_or_else(|| IssunError::AssetLoad(format!("Unknown theme preset '{}'", name))),
+            ThemeSource::File(path) => RatatuiTheme::from_toml_file(path),
+        };
+        let theme = match theme {
+            Ok(theme) => theme,
+            Err(e) => {
+                eprintln!("[Theme] Failed to apply theme: {}", e);
+                continue;
+            }
+        };
+
+        let depth = match resources.get::<RatatuiTheme>().await {
+            Some(current) => current.color_depth(),
+            None => ColorDepth::detect(),
+        };
+        let theme = theme.with_color_depth(depth);
+        let name = theme.name().to_string();
+        resources.insert(theme);
+
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.publish(ThemeChanged { name });
+        }
     }
 }
 
@@ -220,6 +459,110 @@ mod tests {
         assert!(style.fg.is_some());
     }
 
+    #[test]
+    fn test_degradation_sampled_colors() {
+        // 256 colors: cube and grayscale ramp
+        assert_eq!(
+            degrade_color(Color::Rgb(255, 0, 0), ColorDepth::Ansi256),
+            Color::Indexed(196)
+        );
+        assert_eq!(
+            degrade_color(Color::Rgb(95, 135, 175), ColorDepth::Ansi256),
+            Color::Indexed(67)
+        );
+        assert_eq!(
+            degrade_color(Color::Rgb(128, 128, 128), ColorDepth::Ansi256),
+            Color::Indexed(244)
+        );
+        assert_eq!(
+            degrade_color(Color::Rgb(0, 0, 0), ColorDepth::Ansi256),
+            Color::Indexed(16)
+        );
+
+        // 16 colors: nearest ANSI color
+        assert_eq!(
+            degrade_color(Color::Rgb(250, 10, 10), ColorDepth::Ansi16),
+            Color::LightRed
+        );
+        assert_eq!(
+            degrade_color(Color::Rgb(0, 190, 200), ColorDepth::Ansi16),
+            Color::Cyan
+        );
+        assert_eq!(
+            degrade_color(Color::Rgb(23, 23, 23), ColorDepth::Ansi16),
+            Color::Black
+        );
+        assert_eq!(
+            degrade_color(Color::Indexed(196), ColorDepth::Ansi16),
+            Color::LightRed
+        );
+        assert_eq!(
+            degrade_color(Color::Yellow, ColorDepth::Ansi16),
+            Color::Yellow
+        );
+
+        // Mono drops every color; true color keeps everything
+        assert_eq!(
+            degrade_color(Color::Rgb(1, 2, 3), ColorDepth::Mono),
+            Color::Reset
+        );
+        assert_eq!(
+            degrade_color(Color::Rgb(1, 2, 3), ColorDepth::TrueColor),
+            Color::Rgb(1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn test_slot_styles_follow_color_depth() {
+        let theme = RatatuiTheme::dark().with_color_depth(ColorDepth::Ansi16);
+        let danger = theme.slot_style(StyleSlot::Danger);
+        assert_eq!(danger.fg, Some(Color::LightRed));
+        assert!(danger.add_modifier.contains(Modifier::BOLD));
+
+        let mono = RatatuiTheme::dark().with_color_depth(ColorDepth::Mono);
+        assert_eq!(mono.slot_style(StyleSlot::Title).fg, Some(Color::Reset));
+        assert_eq!(
+            RatatuiTheme::preset("mono")
+                .unwrap()
+                .slot_style(StyleSlot::Selection)
+                .add_modifier,
+            Modifier::REVERSED
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_theme_requested_swaps_theme() {
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(RatatuiTheme::dark().with_color_depth(ColorDepth::Ansi256));
+
+        {
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+            bus.publish(SetThemeRequested {
+                source: ThemeSource::Preset("light".into()),
+            });
+            bus.publish(SetThemeRequested {
+                source: ThemeSource::Preset("unknown".into()),
+            });
+            bus.dispatch();
+        }
+        apply_theme_requests(&mut resources).await;
+
+        let theme = resources.get::<RatatuiTheme>().await.unwrap();
+        assert_eq!(theme.name(), "light");
+        assert_eq!(theme.color_depth(), ColorDepth::Ansi256);
+        drop(theme);
+
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        let changed: Vec<String> = bus
+            .reader::<ThemeChanged>()
+            .iter()
+            .map(|e| e.name.clone())
+            .collect();
+        assert_eq!(changed, vec!["light".to_string()]);
+    }
+
     #[test]
     fn test_emphasis() {
         let theme = RatatuiTheme::dark();
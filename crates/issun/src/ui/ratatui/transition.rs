@@ -0,0 +1,323 @@
+//! Scene transition effects for ratatui backend
+//!
+//! [`TransitionLayer`] keeps the last drawn frame and, while an effect plays,
+//! mixes it into the frames of the incoming scene. It draws into the frame
+//! buffer like any widget, so ratatui still only flushes changed cells.
+
+use crate::scene::{TransitionEffect, TransitionStyle};
+use crate::ui::InputEvent;
+use ratatui::buffer::Buffer;
+use std::collections::VecDeque;
+use std::time::Instant;
+
+/// Effect that is currently playing
+struct ActiveEffect {
+    from: Buffer,
+    style: TransitionStyle,
+    started: Instant,
+}
+
+impl ActiveEffect {
+    fn progress(&self, now: Instant) -> f32 {
+        let elapsed = now.saturating_duration_since(self.started);
+        (elapsed.as_secs_f32() / self.style.duration.as_secs_f32()).min(1.0)
+    }
+}
+
+/// Outgoing-frame capture, effect compositing and input hold-back
+///
+/// Used by [`GameRunner`](crate::engine::GameRunner); a hand-written loop
+/// calls [`compose`](Self::compose) at the end of each draw,
+/// [`capture`](Self::capture) with the completed frame, [`start`](Self::start)
+/// on a scene change and passes input through [`hold`](Self::hold) and
+/// [`release`](Self::release).
+///
+/// # Example
+///
+/// ```ignore
+/// let completed = terminal.draw(|frame| {
+///     render(frame, &state);
+///     transitions.compose(frame.buffer_mut(), Instant::now());
+/// })?;
+/// transitions.capture(completed.buffer);
+/// ```
+#[derive(Default)]
+pub struct TransitionLayer {
+    last_frame: Option<Buffer>,
+    active: Option<ActiveEffect>,
+    held: VecDeque<InputEvent>,
+}
+
+impl TransitionLayer {
+    /// Create a layer without a captured frame
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember a completed frame as the outgoing frame of the next effect
+    pub fn capture(&mut self, buffer: &Buffer) {
+        match &mut self.last_frame {
+            Some(last) if last.area == buffer.area => last.clone_from(buffer),
+            last => *last = Some(buffer.clone()),
+        }
+    }
+
+    /// Start `style` from the last captured frame
+    ///
+    /// Instant styles, or no captured frame yet, change nothing: the next
+    /// frame shows the new scene as before.
+    pub fn start(&mut self, style: TransitionStyle, now: Instant) {
+        if style.is_instant() {
+            return;
+        }
+        if let Some(from) = self.last_frame.clone() {
+            self.active = Some(ActiveEffect {
+                from,
+                style,
+                started: now,
+            });
+        }
+    }
+
+    /// Whether an effect is still playing at `now`
+    pub fn is_active(&self, now: Instant) -> bool {
+        self.active
+            .as_ref()
+            .is_some_and(|effect| effect.progress(now) < 1.0)
+    }
+
+    /// Mix the outgoing frame into `buffer`, which holds the incoming scene
+    ///
+    /// Ends the effect when it is complete or the terminal was resized.
+    pub fn compose(&mut self, buffer: &mut Buffer, now: Instant) {
+        let Some(effect) = &self.active else {
+            return;
+        };
+        let progress = effect.progress(now);
+        if progress >= 1.0 || effect.from.area != buffer.area {
+            self.active = None;
+            return;
+        }
+        compose_at(&effect.from, buffer, effect.style.effect, progress);
+    }
+
+    /// Pass `input` through, or hold it back while an effect plays
+    pub fn hold(&mut self, input: InputEvent, now: Instant) -> Option<InputEvent> {
+        if self.is_active(now) || !self.held.is_empty() {
+            self.held.push_back(input);
+            None
+        } else {
+            Some(input)
+        }
+    }
+
+    /// Input held back during an effect that has finished, oldest first
+    ///
+    /// Returns one event at a time so that a transition it triggers can
+    /// hold back the rest.
+    pub fn release(&mut self, now: Instant) -> Option<InputEvent> {
+        if self.is_active(now) {
+            return None;
+        }
+        self.active = None;
+        self.held.pop_front()
+    }
+}
+
+/// Show the outgoing frame `from` in the cells of `to` that the effect has
+/// not reached at `progress` (0.0 = all old, 1.0 = all new)
+pub fn compose_at(from: &Buffer, to: &mut Buffer, effect: TransitionEffect, progress: f32) {
+    let area = to.area;
+    let cells = area.width as u32 * area.height as u32;
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let shows_new = match effect {
+                TransitionEffect::Instant => true,
+                TransitionEffect::WipeHorizontal => {
+                    ((x - area.left()) as f32) < progress * area.width as f32
+                }
+                TransitionEffect::WipeVertical => {
+                    ((y - area.top()) as f32) < progress * area.height as f32
+                }
+                TransitionEffect::Dissolve => {
+                    let index =
+                        (y - area.top()) as u32 * area.width as u32 + (x - area.left()) as u32;
+                    (dissolve_rank(index, cells) as f32) < progress * cells as f32
+                }
+            };
+            if !shows_new {
+                to[(x, y)] = from[(x, y)].clone();
+            }
+        }
+    }
+}
+
+/// Position of cell `index` in a fixed shuffle of `cells` cells
+///
+/// An affine map `i * step + offset (mod cells)` with `step` coprime to
+/// `cells` is a permutation, so every cell switches exactly once.
+fn dissolve_rank(index: u32, cells: u32) -> u32 {
+    let mut step = (cells as u64 * 5 / 8).max(1) | 1;
+    while gcd(step, cells as u64) != 1 {
+        step += 2;
+    }
+    ((index as u64 * step + 7) % cells as u64) as u32
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, widgets::Paragraph, Terminal};
+    use std::time::Duration;
+
+    const WIDTH: u16 = 10;
+    const HEIGHT: u16 = 2;
+
+    fn filled(symbol: char) -> String {
+        std::iter::repeat_n(symbol, (WIDTH * HEIGHT) as usize).collect()
+    }
+
+    fn row(buffer: &Buffer, y: u16) -> String {
+        (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect()
+    }
+
+    /// Draw `symbol` everywhere through the layer, like GameRunner does
+    fn draw(
+        terminal: &mut Terminal<TestBackend>,
+        layer: &mut TransitionLayer,
+        symbol: char,
+        now: Instant,
+    ) -> Buffer {
+        let text = filled(symbol);
+        let completed = terminal
+            .draw(|frame| {
+                frame.render_widget(
+                    Paragraph::new(text).wrap(ratatui::widgets::Wrap { trim: false }),
+                    frame.area(),
+                );
+                layer.compose(frame.buffer_mut(), now);
+            })
+            .unwrap();
+        let buffer = completed.buffer.clone();
+        layer.capture(&buffer);
+        buffer
+    }
+
+    #[test]
+    fn test_horizontal_wipe_at_half_progress() {
+        let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+        let mut layer = TransitionLayer::new();
+        let start = Instant::now();
+        let duration = Duration::from_millis(150);
+
+        draw(&mut terminal, &mut layer, 'o', start);
+        layer.start(TransitionStyle::wipe_horizontal(duration), start);
+
+        let frame = draw(&mut terminal, &mut layer, 'n', start + duration / 2);
+        assert_eq!(row(&frame, 0), "nnnnnooooo");
+        assert_eq!(row(&frame, 1), "nnnnnooooo");
+        // The terminal received the mixed frame
+        assert_eq!(row(terminal.backend().buffer(), 0), "nnnnnooooo");
+
+        let frame = draw(&mut terminal, &mut layer, 'n', start + duration);
+        assert_eq!(row(&frame, 0), "nnnnnnnnnn");
+        assert!(!layer.is_active(start + duration));
+    }
+
+    #[test]
+    fn test_vertical_wipe_and_dissolve() {
+        let from = Buffer::with_lines(["oooo", "oooo", "oooo", "oooo"]);
+
+        let mut to = Buffer::with_lines(["nnnn", "nnnn", "nnnn", "nnnn"]);
+        compose_at(&from, &mut to, TransitionEffect::WipeVertical, 0.5);
+        assert_eq!(to, Buffer::with_lines(["nnnn", "nnnn", "oooo", "oooo"]));
+
+        let mut to = Buffer::with_lines(["nnnn", "nnnn", "nnnn", "nnnn"]);
+        compose_at(&from, &mut to, TransitionEffect::Dissolve, 0.5);
+        let new_cells = to
+            .content
+            .iter()
+            .filter(|cell| cell.symbol() == "n")
+            .count();
+        assert_eq!(new_cells, 8);
+
+        // The shuffle is deterministic and only ever adds new cells
+        let mut again = Buffer::with_lines(["nnnn", "nnnn", "nnnn", "nnnn"]);
+        compose_at(&from, &mut again, TransitionEffect::Dissolve, 0.5);
+        assert_eq!(again, to);
+        let mut later = Buffer::with_lines(["nnnn", "nnnn", "nnnn", "nnnn"]);
+        compose_at(&from, &mut later, TransitionEffect::Dissolve, 0.75);
+        assert!(to
+            .content
+            .iter()
+            .zip(&later.content)
+            .all(|(half, later)| half.symbol() == "o" || later.symbol() == "n"));
+    }
+
+    #[test]
+    fn test_input_is_held_until_effect_completes() {
+        let mut layer = TransitionLayer::new();
+        let start = Instant::now();
+        let duration = Duration::from_millis(150);
+        layer.capture(&Buffer::with_lines(["old"]));
+        layer.start(TransitionStyle::dissolve(duration), start);
+
+        let during = start + duration / 3;
+        assert_eq!(layer.hold(InputEvent::Up, during), None);
+        assert_eq!(layer.hold(InputEvent::Select, during), None);
+        assert_eq!(layer.release(during), None);
+
+        let after = start + duration;
+        assert_eq!(layer.release(after), Some(InputEvent::Up));
+        // Input arriving while older input is still held queues behind it
+        assert_eq!(layer.hold(InputEvent::Down, after), None);
+        assert_eq!(layer.release(after), Some(InputEvent::Select));
+        assert_eq!(layer.release(after), Some(InputEvent::Down));
+        assert_eq!(layer.release(after), None);
+        assert_eq!(
+            layer.hold(InputEvent::Cancel, after),
+            Some(InputEvent::Cancel)
+        );
+    }
+
+    #[test]
+    fn test_instant_style_changes_nothing() {
+        let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+        let mut layer = TransitionLayer::new();
+        let start = Instant::now();
+
+        draw(&mut terminal, &mut layer, 'o', start);
+        layer.start(TransitionStyle::instant(), start);
+        assert!(!layer.is_active(start));
+        assert_eq!(layer.hold(InputEvent::Up, start), Some(InputEvent::Up));
+
+        let frame = draw(&mut terminal, &mut layer, 'n', start);
+        assert_eq!(row(&frame, 0), "nnnnnnnnnn");
+    }
+
+    #[test]
+    fn test_resize_ends_effect() {
+        let mut layer = TransitionLayer::new();
+        let start = Instant::now();
+        layer.capture(&Buffer::with_lines(["oooo"]));
+        layer.start(
+            TransitionStyle::wipe_horizontal(Duration::from_millis(150)),
+            start,
+        );
+
+        let mut resized = Buffer::with_lines(["nnnnnn", "nnnnnn"]);
+        layer.compose(&mut resized, start);
+        assert_eq!(resized, Buffer::with_lines(["nnnnnn", "nnnnnn"]));
+        assert!(!layer.is_active(start));
+    }
+}
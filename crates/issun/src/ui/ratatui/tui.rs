@@ -95,11 +95,14 @@
 //! ```
 
 use crossterm::{
+    cursor::Show,
+    event::{DisableFocusChange, EnableFocusChange},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
+use std::sync::Once;
 use std::time::{Duration, Instant};
 
 /// Terminal User Interface wrapper
@@ -137,11 +140,15 @@ impl Tui {
     /// This will:
     /// - Enable raw mode (disable line buffering)
     /// - Enter alternate screen (preserve shell history)
+    /// - Ask the terminal to report focus changes (ignored where unsupported)
     /// - Create a ratatui Terminal instance
+    /// - Install a panic hook that restores the terminal before the panic
+    ///   message is printed (once per process)
     pub fn new() -> io::Result<Self> {
+        install_panic_hook();
         enable_raw_mode()?;
         let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen)?;
+        execute!(stdout, EnterAlternateScreen, EnableFocusChange)?;
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend)?;
         Ok(Self { terminal })
@@ -156,11 +163,15 @@ impl Tui {
     ///
     /// This will:
     /// - Disable raw mode
-    /// - Leave alternate screen
+    /// - Stop focus reporting and leave alternate screen
     /// - Show cursor
     pub fn restore(&mut self) -> io::Result<()> {
         disable_raw_mode()?;
-        execute!(self.terminal.backend_mut(), LeaveAlternateScreen)?;
+        execute!(
+            self.terminal.backend_mut(),
+            DisableFocusChange,
+            LeaveAlternateScreen
+        )?;
         self.terminal.show_cursor()?;
         Ok(())
     }
@@ -244,6 +255,19 @@ impl Tui {
     }
 }
 
+/// Restore the terminal on panic, then run the previous hook
+fn install_panic_hook() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = disable_raw_mode();
+            let _ = execute!(io::stdout(), DisableFocusChange, LeaveAlternateScreen, Show);
+            previous(info);
+        }));
+    });
+}
+
 impl Drop for Tui {
     /// Automatically restore terminal on drop
     fn drop(&mut self) {
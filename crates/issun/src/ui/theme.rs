@@ -14,7 +14,40 @@
 //!
 //! // Use theme colors
 //! let style = theme.style_primary();
+//!
+//! // Or named style slots shared by all widgets
+//! let title = theme.slot_style(StyleSlot::Title);
 //! ```
+//!
+//! # Style Slots
+//!
+//! Widgets never hard-code colors; they ask the theme for a [`StyleSlot`]
+//! (title, accent, selection, border, per-rarity loot colors, ...). Each slot
+//! defaults to a color of the palette and can be overridden per theme.
+//!
+//! # TOML Themes
+//!
+//! ```toml
+//! name = "forest"
+//! base = "dark"          # preset to start from (optional)
+//!
+//! [colors]
+//! primary = "#22c55e"
+//!
+//! [slots.title]
+//! fg = "primary"
+//! emphasis = "bold"
+//!
+//! [slots."rarity.legendary"]
+//! fg = "#ffaa00"
+//! ```
+
+use crate::error::{IssunError, Result};
+use crate::event::Event;
+use crate::plugin::loot::Rarity;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Abstract color definition
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -56,6 +89,8 @@ pub enum Emphasis {
     Underline,
     /// Dimmed text
     Dim,
+    /// Swapped foreground/background (readable without colors)
+    Reversed,
 }
 
 /// Theme trait for UI styling
@@ -115,6 +150,8 @@ pub struct ThemeConfig {
     pub info: ThemeColor,
     pub foreground: ThemeColor,
     pub background: ThemeColor,
+    /// Slot overrides; slots not listed here use [`ThemeConfig::default_slot`]
+    pub slots: HashMap<StyleSlot, SlotStyle>,
 }
 
 impl Default for ThemeConfig {
@@ -136,6 +173,7 @@ impl ThemeConfig {
             info: ThemeColor::Rgb(14, 165, 233),        // Cyan
             foreground: ThemeColor::Rgb(229, 229, 229), // Light gray
             background: ThemeColor::Rgb(23, 23, 23),    // Dark gray
+            slots: HashMap::new(),
         }
     }
 
@@ -151,7 +189,181 @@ impl ThemeConfig {
             info: ThemeColor::Rgb(6, 182, 212),         // Cyan
             foreground: ThemeColor::Rgb(23, 23, 23),    // Dark gray
             background: ThemeColor::Rgb(255, 255, 255), // White
+            slots: HashMap::new(),
+        }
+        // Yellow highlight is unreadable on white
+        .with_slot(
+            StyleSlot::Selection,
+            SlotStyle::fg(ThemeColor::Primary).with_emphasis(Emphasis::Bold),
+        )
+        .with_slot(
+            StyleSlot::Muted,
+            SlotStyle::fg(ThemeColor::Rgb(115, 115, 115)),
+        )
+        .with_slot(
+            StyleSlot::Stripe,
+            SlotStyle::plain(Emphasis::Normal).with_bg(ThemeColor::Rgb(243, 244, 246)),
+        )
+    }
+
+    /// Create a high-contrast theme configuration
+    pub fn high_contrast() -> Self {
+        Self::dark()
+            .with_name("high_contrast")
+            .with_primary(ThemeColor::Rgb(255, 255, 255))
+            .with_secondary(ThemeColor::Rgb(255, 255, 0))
+            .with_slot(
+                StyleSlot::Selection,
+                SlotStyle::fg(ThemeColor::Rgb(0, 0, 0))
+                    .with_bg(ThemeColor::Rgb(255, 255, 0))
+                    .with_emphasis(Emphasis::Bold),
+            )
+            .with_slot(StyleSlot::Muted, SlotStyle::fg(ThemeColor::Foreground))
+    }
+
+    /// Create a monochrome theme configuration
+    ///
+    /// Every slot relies on emphasis only, so it stays readable on terminals
+    /// without color support.
+    pub fn mono() -> Self {
+        let mut config = Self::dark().with_name("mono");
+        for slot in StyleSlot::all() {
+            let emphasis = match slot {
+                StyleSlot::Title | StyleSlot::Danger => Emphasis::Bold,
+                StyleSlot::Selection => Emphasis::Reversed,
+                StyleSlot::Muted => Emphasis::Dim,
+                StyleSlot::Warning | StyleSlot::Accent => Emphasis::Underline,
+                StyleSlot::Rarity(Rarity::Epic | Rarity::Legendary) => Emphasis::Bold,
+                _ => Emphasis::Normal,
+            };
+            config.slots.insert(slot, SlotStyle::plain(emphasis));
+        }
+        config
+    }
+
+    /// Look up a built-in preset by name (`dark`, `light`, `high_contrast`, `mono`)
+    pub fn preset(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().replace('-', "_").as_str() {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high_contrast" => Some(Self::high_contrast()),
+            "mono" | "monochrome" => Some(Self::mono()),
+            _ => None,
+        }
+    }
+
+    /// Style of a slot, falling back to the palette default
+    pub fn slot(&self, slot: StyleSlot) -> SlotStyle {
+        self.slots
+            .get(&slot)
+            .copied()
+            .unwrap_or_else(|| Self::default_slot(slot))
+    }
+
+    /// Palette-based default for a slot
+    pub fn default_slot(slot: StyleSlot) -> SlotStyle {
+        match slot {
+            StyleSlot::Title => SlotStyle::fg(ThemeColor::Primary).with_emphasis(Emphasis::Bold),
+            StyleSlot::Accent => SlotStyle::fg(ThemeColor::Secondary),
+            StyleSlot::Warning => SlotStyle::fg(ThemeColor::Warning),
+            StyleSlot::Danger => SlotStyle::fg(ThemeColor::Error).with_emphasis(Emphasis::Bold),
+            StyleSlot::Success => SlotStyle::fg(ThemeColor::Success),
+            StyleSlot::Muted => SlotStyle::fg(ThemeColor::Muted).with_emphasis(Emphasis::Dim),
+            StyleSlot::Selection => {
+                SlotStyle::fg(ThemeColor::Highlight).with_emphasis(Emphasis::Bold)
+            }
+            StyleSlot::Border => SlotStyle::fg(ThemeColor::Info),
+            StyleSlot::Stripe => {
+                SlotStyle::plain(Emphasis::Normal).with_bg(ThemeColor::Rgb(38, 38, 38))
+            }
+            StyleSlot::Rarity(rarity) => SlotStyle::fg(match rarity {
+                Rarity::Common => ThemeColor::Foreground,
+                Rarity::Uncommon => ThemeColor::Rgb(34, 197, 94),
+                Rarity::Rare => ThemeColor::Rgb(59, 130, 246),
+                Rarity::Epic => ThemeColor::Rgb(168, 85, 247),
+                Rarity::Legendary => ThemeColor::Rgb(250, 204, 21),
+            }),
+        }
+    }
+
+    /// Builder: Override a style slot
+    pub fn with_slot(mut self, slot: StyleSlot, style: SlotStyle) -> Self {
+        self.slots.insert(slot, style);
+        self
+    }
+
+    /// Parse a theme from TOML
+    ///
+    /// Returns the configuration together with warnings for entries that were
+    /// ignored (unknown slots, colors or emphasis names).
+    pub fn from_toml_str(source: &str) -> Result<(Self, Vec<String>)> {
+        let doc: toml::Table = source
+            .parse()
+            .map_err(|e| IssunError::AssetLoad(format!("Invalid theme TOML: {}", e)))?;
+        let mut warnings = Vec::new();
+
+        let base = doc.get("base").and_then(|v| v.as_str()).unwrap_or("dark");
+        let mut config = Self::preset(base)
+            .ok_or_else(|| IssunError::AssetLoad(format!("Unknown base theme '{}'", base)))?;
+        if let Some(name) = doc.get("name").and_then(|v| v.as_str()) {
+            config.name = name.to_string();
+        }
+
+        if let Some(colors) = doc.get("colors").and_then(|v| v.as_table()) {
+            for (key, value) in colors {
+                let Some(color) = value.as_str().and_then(ThemeColor::parse) else {
+                    warnings.push(format!("Invalid color for '{}': {}", key, value));
+                    continue;
+                };
+                match key.as_str() {
+                    "primary" => config.primary = color,
+                    "secondary" => config.secondary = color,
+                    "error" => config.error = color,
+                    "success" => config.success = color,
+                    "warning" => config.warning = color,
+                    "info" => config.info = color,
+                    "foreground" => config.foreground = color,
+                    "background" => config.background = color,
+                    _ => warnings.push(format!("Unknown palette color '{}'", key)),
+                }
+            }
         }
+
+        if let Some(slots) = doc.get("slots").and_then(|v| v.as_table()) {
+            for (key, value) in slots {
+                let Some(slot) = StyleSlot::parse(key) else {
+                    warnings.push(format!("Unknown style slot '{}'", key));
+                    continue;
+                };
+                let Some(table) = value.as_table() else {
+                    warnings.push(format!("Style slot '{}' must be a table", key));
+                    continue;
+                };
+
+                let mut style = SlotStyle::plain(Emphasis::Normal);
+                for (field, value) in table {
+                    let text = value.as_str().unwrap_or_default();
+                    match field.as_str() {
+                        "fg" | "bg" => match ThemeColor::parse(text) {
+                            Some(color) if field == "fg" => style.fg = Some(color),
+                            Some(color) => style.bg = Some(color),
+                            None => warnings
+                                .push(format!("Invalid color for '{}.{}': {}", key, field, value)),
+                        },
+                        "emphasis" => match Emphasis::parse(text) {
+                            Some(emphasis) => style.emphasis = emphasis,
+                            None => {
+                                warnings.push(format!("Invalid emphasis for '{}': {}", key, value))
+                            }
+                        },
+                        _ => warnings.push(format!("Unknown field '{}.{}'", key, field)),
+                    }
+                }
+                config.slots.insert(slot, style);
+            }
+        }
+
+        Ok((config, warnings))
     }
 
     /// Builder: Set primary color
@@ -179,6 +391,213 @@ impl ThemeConfig {
     }
 }
 
+impl ThemeColor {
+    /// Parse `#rrggbb` or a palette name (`primary`, `muted`, ...)
+    pub fn parse(value: &str) -> Option<Self> {
+        if let Some(hex) = value.strip_prefix('#') {
+            if hex.len() != 6 {
+                return None;
+            }
+            let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+            return Some(ThemeColor::Rgb(channel(0)?, channel(2)?, channel(4)?));
+        }
+        match value.to_ascii_lowercase().as_str() {
+            "primary" => Some(ThemeColor::Primary),
+            "secondary" => Some(ThemeColor::Secondary),
+            "error" => Some(ThemeColor::Error),
+            "success" => Some(ThemeColor::Success),
+            "warning" => Some(ThemeColor::Warning),
+            "info" => Some(ThemeColor::Info),
+            "foreground" => Some(ThemeColor::Foreground),
+            "background" => Some(ThemeColor::Background),
+            "muted" => Some(ThemeColor::Muted),
+            "highlight" => Some(ThemeColor::Highlight),
+            _ => None,
+        }
+    }
+}
+
+impl Emphasis {
+    /// Parse an emphasis name (`bold`, `italic`, `underline`, `dim`, `reversed`, `normal`)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "normal" => Some(Emphasis::Normal),
+            "bold" => Some(Emphasis::Bold),
+            "italic" => Some(Emphasis::Italic),
+            "underline" | "underlined" => Some(Emphasis::Underline),
+            "dim" => Some(Emphasis::Dim),
+            "reversed" | "reverse" => Some(Emphasis::Reversed),
+            _ => None,
+        }
+    }
+}
+
+/// Named style slot that widgets request from the theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StyleSlot {
+    /// Panel and screen titles
+    Title,
+    /// Secondary highlights
+    Accent,
+    /// Warnings (e.g. low resources)
+    Warning,
+    /// Critical state and errors
+    Danger,
+    /// Positive feedback
+    Success,
+    /// De-emphasized text and hints
+    Muted,
+    /// Selected list/menu entry
+    Selection,
+    /// Panel borders
+    Border,
+    /// Every other table row (zebra striping)
+    Stripe,
+    /// Loot rarity color
+    Rarity(Rarity),
+}
+
+impl StyleSlot {
+    /// All slots, including one per rarity tier
+    pub fn all() -> Vec<StyleSlot> {
+        let mut slots = vec![
+            StyleSlot::Title,
+            StyleSlot::Accent,
+            StyleSlot::Warning,
+            StyleSlot::Danger,
+            StyleSlot::Success,
+            StyleSlot::Muted,
+            StyleSlot::Selection,
+            StyleSlot::Border,
+            StyleSlot::Stripe,
+        ];
+        slots.extend(Rarity::all().into_iter().map(StyleSlot::Rarity));
+        slots
+    }
+
+    /// Name used in theme files (`title`, `rarity.epic`, ...)
+    pub fn name(&self) -> String {
+        match self {
+            StyleSlot::Title => "title".into(),
+            StyleSlot::Accent => "accent".into(),
+            StyleSlot::Warning => "warning".into(),
+            StyleSlot::Danger => "danger".into(),
+            StyleSlot::Success => "success".into(),
+            StyleSlot::Muted => "muted".into(),
+            StyleSlot::Selection => "selection".into(),
+            StyleSlot::Border => "border".into(),
+            StyleSlot::Stripe => "stripe".into(),
+            StyleSlot::Rarity(rarity) => format!("rarity.{:?}", rarity).to_ascii_lowercase(),
+        }
+    }
+
+    /// Parse a slot name as used in theme files
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|slot| slot.name() == name)
+    }
+}
+
+/// Colors and emphasis of a single style slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotStyle {
+    pub fg: Option<ThemeColor>,
+    pub bg: Option<ThemeColor>,
+    pub emphasis: Emphasis,
+}
+
+impl SlotStyle {
+    /// Slot with a foreground color only
+    pub fn fg(color: ThemeColor) -> Self {
+        Self {
+            fg: Some(color),
+            bg: None,
+            emphasis: Emphasis::Normal,
+        }
+    }
+
+    /// Slot without colors (terminal defaults)
+    pub fn plain(emphasis: Emphasis) -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            emphasis,
+        }
+    }
+
+    /// Builder: Set background color
+    pub fn with_bg(mut self, color: ThemeColor) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Builder: Set emphasis
+    pub fn with_emphasis(mut self, emphasis: Emphasis) -> Self {
+        self.emphasis = emphasis;
+        self
+    }
+}
+
+/// Color support of the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// No colors, emphasis only
+    Mono,
+    /// 16 ANSI colors
+    Ansi16,
+    /// 256-color palette
+    Ansi256,
+    /// 24-bit RGB
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detect the color support reported by the terminal
+    ///
+    /// Honors `NO_COLOR` and otherwise asks crossterm (which inspects
+    /// `COLORTERM`/`TERM`).
+    pub fn detect() -> Self {
+        if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            return ColorDepth::Mono;
+        }
+        Self::from_color_count(crossterm::style::available_color_count())
+    }
+
+    /// Map a color count to a depth
+    pub fn from_color_count(count: u16) -> Self {
+        match count {
+            u16::MAX => ColorDepth::TrueColor,
+            256.. => ColorDepth::Ansi256,
+            8.. => ColorDepth::Ansi16,
+            _ => ColorDepth::Mono,
+        }
+    }
+}
+
+/// Where a theme comes from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ThemeSource {
+    /// Built-in preset name (see [`ThemeConfig::preset`])
+    Preset(String),
+    /// TOML theme file
+    File(PathBuf),
+}
+
+/// Request to swap the active theme at runtime
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetThemeRequested {
+    pub source: ThemeSource,
+}
+
+impl Event for SetThemeRequested {}
+
+/// The active theme has been replaced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThemeChanged {
+    pub name: String,
+}
+
+impl Event for ThemeChanged {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,6 +634,80 @@ mod tests {
         assert_ne!(color1, color3);
     }
 
+    #[test]
+    fn test_presets_by_name() {
+        for name in ["dark", "light", "high-contrast", "mono"] {
+            let config = ThemeConfig::preset(name).unwrap();
+            assert_eq!(config.name, name.replace('-', "_"));
+        }
+        assert!(ThemeConfig::preset("neon").is_none());
+
+        // Mono relies on emphasis only
+        let mono = ThemeConfig::mono();
+        for slot in StyleSlot::all() {
+            assert_eq!(mono.slot(slot).fg, None);
+        }
+        assert_eq!(mono.slot(StyleSlot::Selection).emphasis, Emphasis::Reversed);
+    }
+
+    #[test]
+    fn test_toml_theme_parsing_warns_unknown_entries() {
+        let (config, warnings) = ThemeConfig::from_toml_str(
+            r##"
+name = "forest"
+base = "light"
+
+[colors]
+primary = "#22c55e"
+sparkle = "#ffffff"
+
+[slots.title]
+fg = "primary"
+emphasis = "underline"
+
+[slots."rarity.legendary"]
+fg = "#ffaa00"
+bg = "background"
+
+[slots.sidebar]
+fg = "#000000"
+"##,
+        )
+        .unwrap();
+
+        assert_eq!(config.name, "forest");
+        assert_eq!(config.primary, ThemeColor::Rgb(0x22, 0xc5, 0x5e));
+        assert_eq!(config.background, ThemeConfig::light().background);
+        assert_eq!(
+            config.slot(StyleSlot::Title),
+            SlotStyle::fg(ThemeColor::Primary).with_emphasis(Emphasis::Underline)
+        );
+        assert_eq!(
+            config.slot(StyleSlot::Rarity(Rarity::Legendary)),
+            SlotStyle::fg(ThemeColor::Rgb(255, 170, 0)).with_bg(ThemeColor::Background)
+        );
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings.iter().any(|w| w.contains("sparkle")));
+        assert!(warnings.iter().any(|w| w.contains("sidebar")));
+    }
+
+    #[test]
+    fn test_toml_theme_rejects_unknown_base() {
+        assert!(ThemeConfig::from_toml_str("base = \"neon\"").is_err());
+        assert!(ThemeConfig::from_toml_str("not toml [").is_err());
+    }
+
+    #[test]
+    fn test_color_depth_from_count() {
+        assert_eq!(
+            ColorDepth::from_color_count(u16::MAX),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(ColorDepth::from_color_count(256), ColorDepth::Ansi256);
+        assert_eq!(ColorDepth::from_color_count(8), ColorDepth::Ansi16);
+        assert_eq!(ColorDepth::from_color_count(2), ColorDepth::Mono);
+    }
+
     #[test]
     fn test_emphasis() {
         let bold = Emphasis::Bold;
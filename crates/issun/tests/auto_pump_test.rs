@@ -37,3 +37,140 @@ async fn test_auto_pump_basic() {
     // but if it compiles, the macro worked
     assert_eq!(scene.value, 0);
 }
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct Hit(u32);
+
+impl issun::event::Event for Hit {}
+
+/// Hits seen by each pump, in order
+#[derive(Default)]
+struct PumpTrace(Vec<Vec<u32>>);
+
+pub async fn tracing_pump(
+    _services: &ServiceContext,
+    _systems: &mut SystemContext,
+    resources: &mut ResourceContext,
+) {
+    let seen = {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        bus.reader::<Hit>().iter().map(|hit| hit.0).collect()
+    };
+    resources.get_mut::<PumpTrace>().await.unwrap().0.push(seen);
+}
+
+struct DuelScene;
+
+impl DuelScene {
+    #[auto_pump(after, pump_fn = tracing_pump)]
+    async fn strike_twice(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) -> usize {
+        resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .publish(Hit(1));
+        auto_pump!();
+        let first_pumps = resources.get::<PumpTrace>().await.unwrap().0.len();
+        resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .publish(Hit(2));
+        first_pumps
+    }
+
+    #[auto_pump(after, around = "publish", pump_fn = tracing_pump)]
+    async fn combo(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+        hits: &[u32],
+    ) {
+        for hit in hits {
+            match hit {
+                0 => {}
+                _ => resources
+                    .get_mut::<EventBus>()
+                    .await
+                    .unwrap()
+                    .publish(Hit(*hit)),
+            }
+        }
+    }
+
+    #[auto_pump(after, around = "publish", pump_fn = tracing_pump)]
+    async fn guarded_strikes(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+        hits: &[u32],
+    ) -> usize {
+        if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+            bus.publish(Hit(hits[0]));
+        }
+        for hit in &hits[1..] {
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.publish(Hit(*hit));
+            }
+        }
+        hits.len()
+    }
+}
+
+fn contexts() -> (ServiceContext, SystemContext, ResourceContext) {
+    let mut resources = ResourceContext::new();
+    resources.insert(EventBus::new());
+    resources.insert(PumpTrace::default());
+    (ServiceContext::new(), SystemContext::new(), resources)
+}
+
+#[tokio::test]
+async fn test_auto_pump_marker_runs_between_publishes() {
+    let (services, mut systems, mut resources) = contexts();
+
+    let first_pumps = DuelScene
+        .strike_twice(&services, &mut systems, &mut resources)
+        .await;
+
+    assert_eq!(first_pumps, 1);
+    let trace = resources.get::<PumpTrace>().await.unwrap();
+    assert_eq!(trace.0, vec![vec![1], vec![2]]);
+}
+
+#[tokio::test]
+async fn test_auto_pump_around_publish_pumps_in_match_arms() {
+    let (services, mut systems, mut resources) = contexts();
+
+    DuelScene
+        .combo(&services, &mut systems, &mut resources, &[3, 0, 4])
+        .await;
+
+    // One pump once the loop is done; the `after` pump is not repeated
+    let trace = resources.get::<PumpTrace>().await.unwrap();
+    assert_eq!(trace.0, vec![vec![3, 4]]);
+}
+
+#[tokio::test]
+async fn test_auto_pump_around_publish_waits_for_the_bus_guard() {
+    let (services, mut systems, mut resources) = contexts();
+
+    // Pumping while `bus` is held would never get the bus
+    let strikes = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        DuelScene.guarded_strikes(&services, &mut systems, &mut resources, &[1, 2, 3]),
+    )
+    .await
+    .expect("pump deadlocked on the held bus guard");
+
+    assert_eq!(strikes, 3);
+    let trace = resources.get::<PumpTrace>().await.unwrap();
+    assert_eq!(trace.0, vec![vec![1], vec![2, 3]]);
+}
@@ -0,0 +1,523 @@
+//! Boss encounters driven through the combat and loot systems
+
+use async_trait::async_trait;
+use issun::context::{ResourceContext, ServiceContext};
+use issun::event::{Event, EventBus, EventFactoryRegistry};
+use issun::modding::DynamicEvent;
+use issun::plugin::boss::BossAttackPolicy;
+use issun::plugin::boss::{
+    ActiveEncounter, BossAdd, BossAddJoinedEvent, BossEncounter, BossEncounterCatalog,
+    BossEncounterRejectedEvent, BossEncounterStartRequested, BossEncounterStartedEvent,
+    BossEncounterState, BossEnragedEvent, BossHook, BossPhase, BossSystem, EnrageTimer,
+    PhaseChangedEvent,
+};
+use issun::plugin::combat::{
+    BattleId, BattleView, CombatDamageRequested, CombatEndedEvent, CombatHook, CombatResult,
+    CombatState, CombatSystem, CombatTurnAdvanceRequested, Combatant, CombatantId,
+};
+use issun::plugin::loot::{LootGeneratedEvent, LootHook, LootSourceId, LootSystem, Rarity};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// HP of everyone on the field
+#[derive(Default)]
+struct Field {
+    hp: HashMap<String, i32>,
+}
+
+/// The boss's targets, in this order
+const PARTY: [&str; 2] = ["hero", "squire"];
+
+struct Member {
+    name: String,
+    hp: i32,
+}
+
+impl Combatant for Member {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn hp(&self) -> i32 {
+        self.hp
+    }
+    fn max_hp(&self) -> i32 {
+        50
+    }
+    fn attack(&self) -> i32 {
+        10
+    }
+    fn take_damage(&mut self, damage: i32) {
+        self.hp -= damage;
+    }
+}
+
+/// The boss hits a party member for 10 every turn
+struct FieldCombat;
+
+#[async_trait]
+impl CombatHook for FieldCombat {
+    async fn process_turn(
+        &self,
+        battle_id: &BattleId,
+        turn: u32,
+        resources: &mut ResourceContext,
+    ) -> Vec<String> {
+        let Some(active) = resources
+            .get::<BossEncounterState>()
+            .await
+            .and_then(|state| state.active().cloned())
+            .filter(|active| active.started)
+        else {
+            return Vec::new();
+        };
+        let party: Vec<Member> = {
+            let field = resources.get::<Field>().await.unwrap();
+            PARTY
+                .iter()
+                .map(|name| Member {
+                    name: name.to_string(),
+                    hp: field.hp[*name],
+                })
+                .collect()
+        };
+        let target = {
+            let candidates: Vec<&dyn Combatant> =
+                party.iter().map(|m| m as &dyn Combatant).collect();
+            active.select_target(&BattleView::new(turn, &candidates))
+        };
+        let Some(target) = target.map(|index| party[index].name.clone()) else {
+            return Vec::new();
+        };
+
+        resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .publish(CombatDamageRequested {
+                battle_id: battle_id.clone(),
+                source: active.definition.boss.clone(),
+                target: target.clone(),
+                amount: 10,
+                damage_type: String::new(),
+            });
+        vec![format!("{} attacks {}", active.definition.boss, target)]
+    }
+
+    async fn apply_damage(
+        &self,
+        _battle_id: &BattleId,
+        _source: &CombatantId,
+        target: &CombatantId,
+        amount: i32,
+        _damage_type: &str,
+        resources: &mut ResourceContext,
+    ) -> Result<i32, String> {
+        let mut field = resources.get_mut::<Field>().await.unwrap();
+        let hp = field.hp.get_mut(target).ok_or("no such combatant")?;
+        *hp -= amount;
+        Ok(amount)
+    }
+}
+
+/// Ghouls join the field; anything else is not a known template
+struct FieldAdds;
+
+#[async_trait]
+impl BossHook for FieldAdds {
+    async fn spawn_add(
+        &self,
+        _encounter: &ActiveEncounter,
+        add: &BossAdd,
+        resources: &mut ResourceContext,
+    ) -> Result<(), String> {
+        if add.template != "ghoul" {
+            return Err(format!("unknown template {}", add.template));
+        }
+        let mut field = resources.get_mut::<Field>().await.unwrap();
+        field.hp.insert(add.id.clone(), 10);
+        Ok(())
+    }
+}
+
+struct BossLoot;
+
+#[async_trait]
+impl LootHook for BossLoot {
+    async fn generate_loot(
+        &self,
+        source_id: &LootSourceId,
+        _rarity: Rarity,
+        _resources: &ResourceContext,
+    ) -> Vec<String> {
+        match source_id.as_str() {
+            "lich_hoard" => vec!["phylactery".to_string()],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ScreenShake {
+    strength: u32,
+}
+
+impl Event for ScreenShake {}
+
+fn lich() -> BossEncounter {
+    BossEncounter::new("lich", 100)
+        .with_loot_table("lich_hoard")
+        .with_enrage(EnrageTimer {
+            after_turns: 2,
+            damage_bonus: 0.5,
+        })
+        .with_phase(BossPhase::new("Frost", 100).with_skills(["frostbolt"]))
+        .with_phase(
+            BossPhase::new("Undead Army", 60)
+                .with_skills(["raise_dead"])
+                .with_attack_policy(BossAttackPolicy::LowestHp)
+                .with_add("ghoul_1", "ghoul")
+                .with_add("ghoul_2", "ghoul")
+                .with_event("ScreenShake", json!({ "strength": 3 })),
+        )
+        .with_phase(BossPhase::new("Last Stand", 25).with_event("PlayMusic", json!("finale")))
+}
+
+struct Game {
+    resources: ResourceContext,
+    combat: CombatSystem,
+    boss: BossSystem,
+    loot: LootSystem,
+    /// Events seen after every frame
+    phases: Vec<PhaseChangedEvent>,
+    joined: Vec<BossAddJoinedEvent>,
+    shakes: Vec<ScreenShake>,
+    music: Vec<DynamicEvent>,
+    enraged: Vec<BossEnragedEvent>,
+    ended: Vec<CombatEndedEvent>,
+    loot_drops: Vec<LootGeneratedEvent>,
+}
+
+impl Game {
+    fn new(encounter: BossEncounter) -> Self {
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(Field {
+            hp: HashMap::from([
+                ("hero".to_string(), 50),
+                ("squire".to_string(), 20),
+                ("lich".to_string(), 100),
+            ]),
+        });
+        resources.insert(CombatState::new());
+        resources.insert(BossEncounterState::new());
+        resources.insert(BossEncounterCatalog::new().with_encounter("lich_king", encounter));
+
+        let mut events = EventFactoryRegistry::new();
+        events
+            .register_event_factory::<ScreenShake>("ScreenShake")
+            .unwrap();
+
+        Self {
+            resources,
+            combat: CombatSystem::new(Arc::new(FieldCombat)),
+            boss: BossSystem::new(Arc::new(FieldAdds)).with_event_factories(events),
+            loot: LootSystem::new(Arc::new(BossLoot)),
+            phases: Vec::new(),
+            joined: Vec::new(),
+            shakes: Vec::new(),
+            music: Vec::new(),
+            enraged: Vec::new(),
+            ended: Vec::new(),
+            loot_drops: Vec::new(),
+        }
+    }
+
+    async fn publish<E: Event + Serialize>(&mut self, event: E) {
+        self.resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .publish(event);
+        self.frames(1).await;
+    }
+
+    /// Run every system for `count` frames
+    async fn frames(&mut self, count: usize) {
+        let services = ServiceContext::new();
+        for _ in 0..count {
+            {
+                let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+                bus.dispatch();
+                self.phases
+                    .extend(bus.reader::<PhaseChangedEvent>().iter().cloned());
+                self.joined
+                    .extend(bus.reader::<BossAddJoinedEvent>().iter().cloned());
+                self.shakes
+                    .extend(bus.reader::<ScreenShake>().iter().cloned());
+                self.music
+                    .extend(bus.reader::<DynamicEvent>().iter().cloned());
+                self.enraged
+                    .extend(bus.reader::<BossEnragedEvent>().iter().cloned());
+                self.ended
+                    .extend(bus.reader::<CombatEndedEvent>().iter().cloned());
+                self.loot_drops
+                    .extend(bus.reader::<LootGeneratedEvent>().iter().cloned());
+            }
+            self.combat
+                .process_events(&services, &mut self.resources)
+                .await;
+            self.boss
+                .process_events(&services, &mut self.resources)
+                .await;
+            self.loot
+                .process_events(&services, &mut self.resources)
+                .await;
+        }
+    }
+
+    async fn start(&mut self) {
+        self.publish(BossEncounterStartRequested {
+            encounter: "lich_king".to_string(),
+            battle_id: "crypt".to_string(),
+        })
+        .await;
+        self.frames(3).await;
+    }
+
+    async fn hit(&mut self, amount: i32) {
+        self.publish(CombatDamageRequested {
+            battle_id: "crypt".to_string(),
+            source: "hero".to_string(),
+            target: "lich".to_string(),
+            amount,
+            damage_type: String::new(),
+        })
+        .await;
+        self.frames(4).await;
+    }
+
+    async fn turn(&mut self) {
+        self.publish(CombatTurnAdvanceRequested {
+            battle_id: "crypt".to_string(),
+        })
+        .await;
+        self.frames(2).await;
+    }
+
+    async fn active(&self) -> Option<ActiveEncounter> {
+        self.resources
+            .get::<BossEncounterState>()
+            .await
+            .unwrap()
+            .active()
+            .cloned()
+    }
+
+    async fn hp(&self, name: &str) -> Option<i32> {
+        self.resources
+            .get::<Field>()
+            .await
+            .unwrap()
+            .hp
+            .get(name)
+            .copied()
+    }
+}
+
+#[tokio::test]
+async fn test_encounter_tags_the_battle() {
+    let mut game = Game::new(lich());
+    game.start().await;
+
+    let state = game.resources.get::<CombatState>().await.unwrap();
+    assert_eq!(state.current_battle().map(String::as_str), Some("crypt"));
+    let boss = state.boss().unwrap();
+    assert_eq!((boss.boss.as_str(), boss.phase), ("lich", 0));
+    drop(state);
+
+    let active = game.active().await.unwrap();
+    assert!(active.started);
+    assert_eq!(active.skills(), ["frostbolt"]);
+    assert!(game.phases.is_empty());
+}
+
+#[tokio::test]
+async fn test_phases_start_at_their_threshold_once() {
+    let mut game = Game::new(lich());
+    game.start().await;
+
+    game.hit(39).await;
+    assert_eq!(game.active().await.unwrap().phase, 0);
+    assert!(game.phases.is_empty());
+
+    // 61 -> 60: exactly 60% enters the second phase
+    game.hit(1).await;
+    assert_eq!(game.phases.len(), 1);
+    assert_eq!((game.phases[0].previous, game.phases[0].phase), (0, 1));
+    assert_eq!(game.phases[0].name, "Undead Army");
+    let active = game.active().await.unwrap();
+    assert_eq!(active.skills(), ["raise_dead"]);
+    assert_eq!(active.adds, ["ghoul_1", "ghoul_2"]);
+
+    // Adds joined mid-fight, and the scripted event became its typed event
+    assert_eq!(game.hp("ghoul_1").await, Some(10));
+    assert_eq!(game.joined.len(), 2);
+    assert_eq!(game.shakes.len(), 1);
+    assert_eq!(game.shakes[0].strength, 3);
+
+    // Further hits in the same phase run nothing again
+    game.hit(5).await;
+    assert_eq!(game.phases.len(), 1);
+    assert_eq!(game.joined.len(), 2);
+    assert_eq!(game.shakes.len(), 1);
+
+    // Names without a factory are published for MODs
+    game.hit(30).await;
+    assert_eq!(game.phases.len(), 2);
+    assert_eq!(game.music.len(), 1);
+    assert_eq!(game.music[0].event_type, "PlayMusic");
+    assert_eq!(game.music[0].data, json!("finale"));
+}
+
+#[tokio::test]
+async fn test_a_big_hit_enters_every_phase_in_between() {
+    let mut game = Game::new(lich());
+    game.start().await;
+
+    game.hit(80).await;
+    let phases: Vec<_> = game.phases.iter().map(|p| (p.previous, p.phase)).collect();
+    assert_eq!(phases, [(0, 1), (1, 2)]);
+    assert_eq!(game.shakes.len(), 1);
+    assert_eq!(game.music.len(), 1);
+}
+
+#[tokio::test]
+async fn test_enrage_stacks_after_the_timer() {
+    let mut game = Game::new(lich());
+    game.start().await;
+
+    game.turn().await;
+    assert!(game.enraged.is_empty());
+
+    game.turn().await;
+    game.turn().await;
+    let stacks: Vec<_> = game
+        .enraged
+        .iter()
+        .map(|e| (e.stacks, e.damage_multiplier))
+        .collect();
+    assert_eq!(stacks, [(1, 1.5), (2, 2.0)]);
+
+    let state = game.resources.get::<CombatState>().await.unwrap();
+    assert_eq!(state.boss().unwrap().enrage_stacks, 2);
+}
+
+#[tokio::test]
+async fn test_phases_retarget_and_enrage_hits_harder() {
+    let mut game = Game::new(lich());
+    game.start().await;
+
+    // Nobody has threat yet, so the first phase's policy hits the hero
+    game.turn().await;
+    assert_eq!(game.hp("hero").await, Some(40));
+    assert_eq!(game.hp("squire").await, Some(20));
+
+    // The second phase goes for the weakest party member
+    game.hit(40).await;
+    assert_eq!(
+        game.active().await.unwrap().attack_policy(),
+        BossAttackPolicy::LowestHp
+    );
+    game.turn().await;
+    assert_eq!(game.hp("hero").await, Some(40));
+    assert_eq!(game.hp("squire").await, Some(10));
+
+    // Enraged at the end of turn 2: the same attack deals 10 * 1.5
+    assert_eq!(game.enraged.len(), 1);
+    let state = game.resources.get::<CombatState>().await.unwrap();
+    assert_eq!(state.boss().unwrap().damage_multiplier, 1.5);
+    drop(state);
+    game.turn().await;
+    assert_eq!(game.hp("squire").await, Some(-5));
+}
+
+#[tokio::test]
+async fn test_victory_rolls_the_boss_loot_table() {
+    let mut game = Game::new(lich());
+    game.start().await;
+    game.turn().await;
+    game.turn().await;
+
+    game.hit(100).await;
+    // The loot request follows the battle's end
+    game.frames(2).await;
+    assert_eq!(game.ended.len(), 1);
+    assert_eq!(game.ended[0].result, CombatResult::Victory);
+    let boss = game.ended[0].boss.as_ref().unwrap();
+    assert_eq!(boss.encounter, "lich_king");
+    assert_eq!((boss.phase, boss.enrage_stacks), (2, 1));
+
+    assert_eq!(game.loot_drops.len(), 1);
+    assert_eq!(game.loot_drops[0].source_id, "lich_hoard");
+    assert_eq!(game.loot_drops[0].items, ["phylactery"]);
+    assert!(game.active().await.is_none());
+}
+
+#[tokio::test]
+async fn test_bad_scripted_payloads_reject_the_encounter() {
+    let broken = lich().with_phase(
+        BossPhase::new("Broken", 10).with_event("ScreenShake", json!({ "strength": "big" })),
+    );
+    let mut game = Game::new(broken);
+    game.publish(BossEncounterStartRequested {
+        encounter: "lich_king".to_string(),
+        battle_id: "crypt".to_string(),
+    })
+    .await;
+    game.publish(BossEncounterStartRequested {
+        encounter: "dragon".to_string(),
+        battle_id: "lair".to_string(),
+    })
+    .await;
+    game.frames(2).await;
+
+    let mut bus = game.resources.get_mut::<EventBus>().await.unwrap();
+    assert!(bus.reader::<BossEncounterStartedEvent>().is_empty());
+    drop(bus);
+    assert!(game.active().await.is_none());
+    assert!(!game
+        .resources
+        .get::<CombatState>()
+        .await
+        .unwrap()
+        .is_battle_active());
+}
+
+#[tokio::test]
+async fn test_rejections_explain_why() {
+    let mut game = Game::new(lich());
+    game.start().await;
+    game.resources
+        .get_mut::<EventBus>()
+        .await
+        .unwrap()
+        .publish(BossEncounterStartRequested {
+            encounter: "lich_king".to_string(),
+            battle_id: "crypt_2".to_string(),
+        });
+    game.frames(2).await;
+
+    let mut bus = game.resources.get_mut::<EventBus>().await.unwrap();
+    let rejected: Vec<_> = bus
+        .reader::<BossEncounterRejectedEvent>()
+        .iter()
+        .cloned()
+        .collect();
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(
+        rejected[0].reason,
+        "A boss encounter is already in progress"
+    );
+}
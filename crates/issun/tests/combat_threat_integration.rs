@@ -0,0 +1,178 @@
+//! Threat reports flowing through CombatSystem into ThreatChangedEvent
+
+use issun::context::ResourceContext;
+use issun::event::{Event, EventBus};
+use issun::plugin::combat::{
+    CombatStartRequested, CombatState, CombatSystem, CombatTurnAdvanceRequested, CombatantDefeated,
+    DamageDealt, DefaultCombatHook, TauntRequested, ThreatChangedEvent, ThreatConfig,
+};
+use issun::plugin::CombatPlugin;
+use issun::prelude::{Game, GameBuilder};
+use std::sync::Arc;
+
+const BATTLE: &str = "battle_1";
+
+struct Battle {
+    game: Game,
+    system: CombatSystem,
+    /// (top target before, after) for every ThreatChangedEvent, per frame
+    changes: Vec<Vec<(Option<String>, Option<String>)>>,
+}
+
+impl Battle {
+    async fn new() -> Self {
+        let game = GameBuilder::new()
+            .with_plugin(CombatPlugin::new().with_threat_config(ThreatConfig {
+                decay_per_turn: 0.5,
+                taunt_turns: 2,
+                ..ThreatConfig::default()
+            }))
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let mut battle = Self {
+            game,
+            system: CombatSystem::new(Arc::new(DefaultCombatHook)),
+            changes: Vec::new(),
+        };
+        battle
+            .frame(|bus| {
+                bus.publish(CombatStartRequested {
+                    battle_id: BATTLE.to_string(),
+                })
+            })
+            .await;
+        battle
+    }
+
+    fn resources(&mut self) -> &mut ResourceContext {
+        &mut self.game.resources
+    }
+
+    /// Publish, run the combat system once, record the threat changes
+    async fn frame(&mut self, publish: impl FnOnce(&mut EventBus)) {
+        {
+            let mut bus = self.resources().get_mut::<EventBus>().await.unwrap();
+            publish(&mut bus);
+            bus.dispatch();
+        }
+        let services = &self.game.services;
+        self.system
+            .process_events(services, &mut self.game.resources)
+            .await;
+
+        let mut bus = self.resources().get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        let changes = bus
+            .reader::<ThreatChangedEvent>()
+            .iter()
+            .map(|event| {
+                assert_eq!(
+                    (event.battle_id.as_str(), event.enemy.as_str()),
+                    (BATTLE, "ogre")
+                );
+                (event.previous.clone(), event.current.clone())
+            })
+            .collect();
+        self.changes.push(changes);
+    }
+
+    /// One turn with the given reports
+    async fn turn<E: Event + Clone + serde::Serialize>(&mut self, reports: &[E]) {
+        let reports = reports.to_vec();
+        self.frame(move |bus| {
+            for report in reports {
+                bus.publish(report);
+            }
+            bus.publish(CombatTurnAdvanceRequested {
+                battle_id: BATTLE.to_string(),
+            });
+        })
+        .await;
+    }
+
+    async fn top_target(&mut self) -> Option<String> {
+        let state = self.resources().get::<CombatState>().await.unwrap();
+        state
+            .threat()
+            .unwrap()
+            .top_target("ogre")
+            .map(str::to_string)
+    }
+}
+
+fn hit(attacker: &str, amount: i32) -> DamageDealt {
+    DamageDealt {
+        battle_id: BATTLE.to_string(),
+        attacker: attacker.to_string(),
+        target: "ogre".to_string(),
+        amount,
+    }
+}
+
+fn change(previous: Option<&str>, current: Option<&str>) -> (Option<String>, Option<String>) {
+    (previous.map(str::to_string), current.map(str::to_string))
+}
+
+#[tokio::test]
+async fn test_aggro_follows_damage_taunts_decay_and_deaths() {
+    let mut battle = Battle::new().await;
+    battle.changes.clear();
+
+    // Rogue bursts, warrior keeps hitting: the burst leads at first...
+    battle.turn(&[hit("rogue", 100), hit("warrior", 30)]).await;
+    battle.turn(&[hit("warrior", 30)]).await;
+    assert_eq!(battle.top_target().await.as_deref(), Some("rogue"));
+
+    // ...until decay hands aggro to the steady damage dealer
+    battle.turn(&[hit("warrior", 30)]).await;
+    assert_eq!(battle.top_target().await.as_deref(), Some("warrior"));
+
+    // A taunt overrides threat for two turns
+    battle
+        .turn(&[TauntRequested {
+            battle_id: BATTLE.to_string(),
+            enemy: "ogre".to_string(),
+            taunter: "cleric".to_string(),
+        }])
+        .await;
+    battle.turn(&[hit("warrior", 30)]).await;
+    assert_eq!(battle.top_target().await.as_deref(), Some("warrior"));
+
+    // Dead targets leave the table at once
+    battle
+        .frame(|bus| {
+            bus.publish(CombatantDefeated {
+                battle_id: BATTLE.to_string(),
+                combatant: "warrior".to_string(),
+            })
+        })
+        .await;
+    assert_eq!(battle.top_target().await.as_deref(), Some("rogue"));
+
+    assert_eq!(
+        battle.changes,
+        vec![
+            vec![change(None, Some("rogue"))],
+            vec![],
+            vec![change(Some("rogue"), Some("warrior"))],
+            vec![change(Some("warrior"), Some("cleric"))],
+            // Taunt expires at the end of its second turn
+            vec![change(Some("cleric"), Some("warrior"))],
+            vec![change(Some("warrior"), Some("rogue"))],
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_reports_for_other_battles_are_ignored() {
+    let mut battle = Battle::new().await;
+    battle
+        .turn(&[DamageDealt {
+            battle_id: "battle_2".to_string(),
+            ..hit("rogue", 50)
+        }])
+        .await;
+    assert_eq!(battle.top_target().await, None);
+}
@@ -0,0 +1,207 @@
+//! Config file overrides applied at build and reloaded while the game runs
+
+use issun::builder::{Game, GameBuilder};
+use issun::engine::{
+    ConfigFieldsSkipped, ConfigFileOptions, ConfigReloadFailed, ConfigReloadSystem, ConfigReloaded,
+    ReloadConfigRequested,
+};
+use issun::event::EventBus;
+use issun::plugin::combat::{CombatConfig, CombatPlugin};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+struct TempConfig(PathBuf);
+
+impl TempConfig {
+    fn new(name: &str, content: &str) -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "issun_config_reload_{}_{}.toml",
+            name,
+            std::process::id()
+        ));
+        std::fs::write(&path, content).unwrap();
+        Self(path)
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+
+    fn write(&self, content: &str) {
+        std::fs::write(&self.0, content).unwrap();
+    }
+}
+
+impl Drop for TempConfig {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+async fn build(file: &TempConfig) -> Game {
+    GameBuilder::new()
+        .with_plugin(CombatPlugin::default())
+        .unwrap()
+        .with_config_file(
+            file.path(),
+            ConfigFileOptions::watch().with_poll_interval(Duration::ZERO),
+        )
+        .build()
+        .await
+        .unwrap()
+}
+
+#[derive(Debug, Default)]
+struct Tick {
+    reloaded: Vec<ConfigReloaded>,
+    failed: Vec<ConfigReloadFailed>,
+    skipped: Vec<ConfigFieldsSkipped>,
+}
+
+/// Run the reload system the way the runners do, then dispatch
+async fn tick(game: &mut Game) -> Tick {
+    game.systems
+        .get_mut::<ConfigReloadSystem>()
+        .unwrap()
+        .update(&mut game.resources)
+        .await;
+    let mut bus = game.resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    Tick {
+        reloaded: bus.reader::<ConfigReloaded>().iter().cloned().collect(),
+        failed: bus.reader::<ConfigReloadFailed>().iter().cloned().collect(),
+        skipped: bus
+            .reader::<ConfigFieldsSkipped>()
+            .iter()
+            .cloned()
+            .collect(),
+    }
+}
+
+async fn combat_config(game: &Game) -> CombatConfig {
+    game.resources.get::<CombatConfig>().await.unwrap().clone()
+}
+
+#[tokio::test]
+async fn test_edit_changes_combat_config_at_runtime() {
+    let file = TempConfig::new("edit", "[combat]\ndifficulty_multiplier = 1.5\n");
+    let mut game = build(&file).await;
+    assert_eq!(combat_config(&game).await.difficulty_multiplier, 1.5);
+
+    // Nothing changed since build
+    let first = tick(&mut game).await;
+    assert!(first.reloaded.is_empty());
+
+    file.write("[combat]\ndifficulty_multiplier = 2.0\nscore_per_enemy = 250\n");
+    let reloaded = tick(&mut game).await;
+    assert_eq!(
+        reloaded.reloaded,
+        [ConfigReloaded {
+            sections: vec!["combat".to_string()],
+            changed_fields: vec![
+                "combat.difficulty_multiplier".to_string(),
+                "combat.score_per_enemy".to_string(),
+            ],
+        }]
+    );
+    let config = combat_config(&game).await;
+    assert_eq!(config.difficulty_multiplier, 2.0);
+    assert_eq!(config.score_per_enemy, 250);
+}
+
+#[tokio::test]
+async fn test_invalid_edit_changes_nothing_and_reports_the_error() {
+    let file = TempConfig::new("invalid", "[combat]\ndifficulty_multiplier = 1.5\n");
+    let mut game = build(&file).await;
+
+    file.write("[combat]\ndifficulty_multiplier = 3.0\nscore_per_enemy = \"lots\"\n");
+    let result = tick(&mut game).await;
+    assert!(result.reloaded.is_empty());
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].section.as_deref(), Some("combat"));
+    assert!(
+        result.failed[0].error.contains("lots"),
+        "{}",
+        result.failed[0].error
+    );
+    // The valid field of the bad section is not applied either
+    assert_eq!(combat_config(&game).await.difficulty_multiplier, 1.5);
+
+    file.write("[combat]\ndifficulty_multiplier = 3.0\ncritical_chance = 0.5\n");
+    let result = tick(&mut game).await;
+    assert!(result.failed[0].error.contains("critical_chance"));
+    assert_eq!(combat_config(&game).await.difficulty_multiplier, 1.5);
+
+    file.write("[combat\n");
+    let result = tick(&mut game).await;
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].section, None);
+}
+
+#[tokio::test]
+async fn test_immutable_fields_are_skipped_with_a_warning() {
+    let file = TempConfig::new("immutable", "[combat]\ndefault_max_hp = 80\n");
+    let mut game = build(&file).await;
+    // Immutable fields still apply at build
+    assert_eq!(combat_config(&game).await.default_max_hp, 80);
+
+    file.write("[combat]\ndefault_max_hp = 200\ndifficulty_multiplier = 1.25\n");
+    let result = tick(&mut game).await;
+    assert_eq!(
+        result.skipped,
+        [ConfigFieldsSkipped {
+            section: "combat".to_string(),
+            fields: vec!["default_max_hp".to_string()],
+        }]
+    );
+    assert_eq!(
+        result.reloaded[0].changed_fields,
+        ["combat.difficulty_multiplier"]
+    );
+    let config = combat_config(&game).await;
+    assert_eq!(config.default_max_hp, 80);
+    assert_eq!(config.difficulty_multiplier, 1.25);
+}
+
+#[tokio::test]
+async fn test_reload_request_reads_an_unwatched_file() {
+    let file = TempConfig::new("request", "[combat]\nenable_log = false\n");
+    let mut game = GameBuilder::new()
+        .with_plugin(CombatPlugin::default())
+        .unwrap()
+        .with_config_file(file.path(), ConfigFileOptions::once())
+        .build()
+        .await
+        .unwrap();
+    assert!(!combat_config(&game).await.enable_log);
+
+    file.write("[combat]\nenable_log = true\n");
+    assert!(tick(&mut game).await.reloaded.is_empty());
+    assert!(!combat_config(&game).await.enable_log);
+
+    game.resources
+        .get_mut::<EventBus>()
+        .await
+        .unwrap()
+        .publish(ReloadConfigRequested);
+    game.resources
+        .get_mut::<EventBus>()
+        .await
+        .unwrap()
+        .dispatch();
+    let result = tick(&mut game).await;
+    assert_eq!(result.reloaded[0].changed_fields, ["combat.enable_log"]);
+    assert!(combat_config(&game).await.enable_log);
+}
+
+#[tokio::test]
+async fn test_invalid_file_fails_the_build() {
+    let file = TempConfig::new("build", "[combat]\ndifficulty_multiplier = \"hard\"\n");
+    let result = GameBuilder::new()
+        .with_plugin(CombatPlugin::default())
+        .unwrap()
+        .with_config_file(file.path(), ConfigFileOptions::once())
+        .build()
+        .await;
+    assert!(result.is_err());
+}
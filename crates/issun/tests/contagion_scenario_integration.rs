@@ -0,0 +1,178 @@
+//! Contagion scenarios: loading a fixture, validation, scripted route closure
+
+use issun::context::ResourceContext;
+use issun::event::EventBus;
+use issun::plugin::contagion::{
+    ContagionPlugin, ContagionScenario, ContagionState, ContagionSystem, GraphTopology,
+    LoadScenarioRequested, NodeType, ScenarioAction, ScenarioActionFired, ScenarioError,
+    ScenarioLoadFailed, ScenarioLoaded,
+};
+use issun::plugin::time::DayChanged;
+use issun::prelude::GameBuilder;
+use std::path::PathBuf;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/contagion_scenarios")
+        .join(name)
+}
+
+/// Publish `events`, run the contagion system and collect what it fired
+async fn step(
+    resources: &mut ResourceContext,
+    publish: impl FnOnce(&mut EventBus),
+) -> Vec<ScenarioActionFired> {
+    {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        publish(&mut bus);
+        bus.dispatch();
+    }
+    ContagionSystem::default().process_events(resources).await;
+
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    bus.reader::<ScenarioActionFired>()
+        .iter()
+        .cloned()
+        .collect()
+}
+
+async fn edge_open(resources: &ResourceContext, edge: &str) -> bool {
+    let topology = resources.get::<GraphTopology>().await.unwrap();
+    topology.get_edge(&edge.to_string()).unwrap().open
+}
+
+#[tokio::test]
+async fn test_fixture_scenario_loads_into_state() {
+    let plugin = ContagionPlugin::new()
+        .with_scenario(fixture("harbor_outbreak.ron"))
+        .unwrap();
+    let game = GameBuilder::new()
+        .with_plugin(plugin)
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    let topology = game.resources.get::<GraphTopology>().await.unwrap();
+    assert_eq!(topology.node_count(), 3);
+    assert_eq!(topology.edge_count(), 2);
+    let downtown = topology.get_node(&"downtown".to_string()).unwrap();
+    assert_eq!(downtown.resistance, 0.9);
+    assert_eq!(downtown.population, 20000);
+    assert_eq!(
+        topology.get_node(&"island".to_string()).unwrap().node_type,
+        NodeType::Village
+    );
+    let ferry = topology.get_edge(&"ferry".to_string()).unwrap();
+    assert!(!ferry.open);
+    assert_eq!(ferry.noise_level, 0.1);
+    assert!(
+        topology
+            .get_edge(&"harbor_downtown".to_string())
+            .unwrap()
+            .open
+    );
+
+    let state = game.resources.get::<ContagionState>().await.unwrap();
+    assert_eq!(state.contagion_count(), 1);
+    let red_fever = state.get_contagion(&"red_fever".to_string()).unwrap();
+    assert!(red_fever.has_reached(&"harbor".to_string()));
+    assert_eq!(red_fever.spread_count(), 1);
+}
+
+#[tokio::test]
+async fn test_validation_catches_dangling_edge() {
+    let Err(ScenarioError::Invalid(errors)) =
+        ContagionScenario::load(fixture("dangling_edge.json"))
+    else {
+        panic!("dangling edge was accepted");
+    };
+    assert_eq!(errors, vec!["edge 'bridge': unknown node 'old_town'"]);
+
+    // Requested at runtime, the current graph is kept
+    let mut resources = ResourceContext::new();
+    resources.insert(EventBus::new());
+    resources.insert(GraphTopology::new());
+    let path = fixture("dangling_edge.json").display().to_string();
+    step(&mut resources, |bus| {
+        bus.publish(LoadScenarioRequested { path });
+    })
+    .await;
+    assert_eq!(
+        resources.get::<GraphTopology>().await.unwrap().node_count(),
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_scripted_route_closure_fires_on_turn_ten() {
+    let mut resources = ResourceContext::new();
+    resources.insert(EventBus::new());
+    let path = fixture("harbor_outbreak.ron").display().to_string();
+    step(&mut resources, |bus| {
+        bus.publish(LoadScenarioRequested { path });
+    })
+    .await;
+    assert!(resources.contains::<ContagionState>());
+
+    for day in 1..10 {
+        assert!(step(&mut resources, |bus| bus.publish(DayChanged { day }))
+            .await
+            .is_empty());
+        assert!(edge_open(&resources, "harbor_downtown").await, "day {day}");
+    }
+
+    let fired = step(&mut resources, |bus| bus.publish(DayChanged { day: 10 })).await;
+    assert_eq!(
+        fired,
+        vec![ScenarioActionFired {
+            turn: 10,
+            action: ScenarioAction::CloseRoute {
+                edge: "harbor_downtown".to_string()
+            },
+        }]
+    );
+    assert!(!edge_open(&resources, "harbor_downtown").await);
+
+    step(&mut resources, |bus| bus.publish(DayChanged { day: 11 })).await;
+    step(&mut resources, |bus| bus.publish(DayChanged { day: 12 })).await;
+    let state = resources.get::<ContagionState>().await.unwrap();
+    assert!(state
+        .get_contagion(&"grey_cough".to_string())
+        .unwrap()
+        .has_reached(&"island".to_string()));
+}
+
+#[tokio::test]
+async fn test_load_request_publishes_outcome() {
+    let mut resources = ResourceContext::new();
+    resources.insert(EventBus::new());
+
+    let good = fixture("harbor_outbreak.ron").display().to_string();
+    let bad = fixture("missing.ron").display().to_string();
+    {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.publish(LoadScenarioRequested { path: good });
+        bus.publish(LoadScenarioRequested { path: bad.clone() });
+        bus.dispatch();
+    }
+    ContagionSystem::default()
+        .process_events(&mut resources)
+        .await;
+
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    let loaded: Vec<ScenarioLoaded> = bus.reader::<ScenarioLoaded>().iter().cloned().collect();
+    let failed: Vec<ScenarioLoadFailed> =
+        bus.reader::<ScenarioLoadFailed>().iter().cloned().collect();
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded[0].name, "Harbor outbreak");
+    assert_eq!(
+        (loaded[0].nodes, loaded[0].edges, loaded[0].strains),
+        (3, 2, 2)
+    );
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].path, bad);
+    assert!(failed[0].errors[0].starts_with("IO error"));
+}
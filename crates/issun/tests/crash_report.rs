@@ -0,0 +1,237 @@
+//! CrashReporter: panics and BugReportRequested produce a bundle
+
+use issun::context::{ResourceContext, ServiceContext, SystemContext};
+use issun::engine::{
+    BugReportRequested, BugReportSaved, CrashBundle, CrashPrivacy, CrashReportConfig,
+    HeadlessRunner,
+};
+use issun::event::EventBus;
+use issun::modding::{ModLogEntry, ModLogEvent, ModLogLevel};
+use issun::prelude::GameBuilder;
+use issun::scene::{Scene, SceneDirector, SceneTransition};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug, Serialize)]
+struct PlayerProfile {
+    name: String,
+    floor: u32,
+}
+
+/// Descends one floor per tick; panics on `crash_on` or asks for a bug
+/// report on `report_on`
+struct DungeonScene {
+    crash_on: Option<u32>,
+    report_on: Option<u32>,
+}
+
+#[async_trait::async_trait]
+impl Scene for DungeonScene {
+    async fn on_update(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) -> SceneTransition<Self> {
+        let floor = {
+            let mut profile = resources.get_mut::<PlayerProfile>().await.unwrap();
+            profile.floor += 1;
+            profile.floor
+        };
+
+        {
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+            bus.publish(ModLogEvent {
+                entry: ModLogEntry::new(
+                    Some("lanterns".into()),
+                    ModLogLevel::Info,
+                    format!("floor {} lit", floor),
+                ),
+            });
+            if self.report_on == Some(floor) {
+                bus.publish(BugReportRequested {
+                    description: Some("stairs missing".into()),
+                });
+            }
+        }
+
+        if self.crash_on == Some(floor) {
+            panic!("it crashed on floor {}", floor);
+        }
+        SceneTransition::Stay
+    }
+}
+
+async fn run(config: CrashReportConfig, scene: DungeonScene, max_ticks: u64) {
+    let game = GameBuilder::new()
+        .with_resource(PlayerProfile {
+            name: "Mina".into(),
+            floor: 0,
+        })
+        .with_crash_reporter(config)
+        .build()
+        .await
+        .unwrap();
+    let director = SceneDirector::new(scene, game.services, game.systems, game.resources).await;
+    HeadlessRunner::new(director)
+        .with_tick_rate(Duration::from_millis(1))
+        .with_max_ticks(max_ticks)
+        .run()
+        .await
+        .unwrap();
+}
+
+fn bundles(dir: &Path, prefix: &str) -> Vec<PathBuf> {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| {
+                    path.file_name()
+                        .unwrap()
+                        .to_string_lossy()
+                        .starts_with(prefix)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_panic_writes_bundle_with_sections() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = CrashReportConfig::new(dir.path())
+        .with_game("dungeon-test", "0.7.0")
+        .with_privacy(CrashPrivacy::IncludeGameState)
+        .with_snapshot_interval(1)
+        .observe::<PlayerProfile>();
+
+    let crashed = std::thread::spawn(move || {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(run(
+                config,
+                DungeonScene {
+                    crash_on: Some(7),
+                    report_on: None,
+                },
+                20,
+            ))
+    })
+    .join();
+    assert!(crashed.is_err(), "the scene should have panicked");
+
+    let found = bundles(dir.path(), "crash-");
+    assert_eq!(found.len(), 1, "{:?}", found);
+    let bundle = CrashBundle::open(&found[0]).unwrap();
+
+    let panic = bundle.get_str("panic.txt").unwrap();
+    assert!(panic.contains("it crashed on floor 7"), "{}", panic);
+    for section in [
+        "event_bus.json",
+        "frames.json",
+        "mod_log.json",
+        "resources.json",
+        "manifest.json",
+    ] {
+        assert!(bundle.get(section).is_some(), "missing {}", section);
+    }
+
+    let manifest = bundle.get_json("manifest.json").unwrap();
+    assert_eq!(manifest["kind"], "crash");
+    assert_eq!(manifest["scene"], "DungeonScene");
+    assert_eq!(manifest["game"]["name"], "dungeon-test");
+    assert_eq!(manifest["engine_version"], env!("CARGO_PKG_VERSION"));
+    // No MOD system in this game: skipped with a note
+    assert!(bundle.get("mods.json").is_none());
+    assert!(manifest["notes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|note| note == "mods.json: no ModRegistry"));
+
+    // Recorded after floor 6 was reached; the crashing tick is not recorded
+    let resources = bundle.get_json("resources.json").unwrap();
+    let profile = &resources[std::any::type_name::<PlayerProfile>()];
+    assert_eq!(profile["name"], "Mina");
+    assert_eq!(profile["floor"], 6);
+
+    let mod_log = bundle.get_json("mod_log.json").unwrap();
+    let last = mod_log.as_array().unwrap().last().unwrap();
+    assert_eq!(last["message"], "floor 5 lit");
+
+    let frames = bundle.get_json("frames.json").unwrap();
+    assert_eq!(frames["frames"], 6);
+}
+
+#[tokio::test]
+async fn test_bug_report_without_game_state() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = CrashReportConfig::new(dir.path())
+        .with_snapshot_interval(1)
+        .observe::<PlayerProfile>();
+
+    run(
+        config,
+        DungeonScene {
+            crash_on: None,
+            report_on: Some(2),
+        },
+        5,
+    )
+    .await;
+
+    let found = bundles(dir.path(), "bug-report-");
+    assert_eq!(found.len(), 1, "{:?}", found);
+    let bundle = CrashBundle::open(&found[0]).unwrap();
+
+    assert!(bundle.get("panic.txt").is_none());
+    assert!(bundle.get("resources.json").is_none());
+    assert!(bundle.get("event_bus.json").is_some());
+
+    let manifest = bundle.get_json("manifest.json").unwrap();
+    assert_eq!(manifest["kind"], "bug-report");
+    assert_eq!(manifest["privacy"], "DiagnosticsOnly");
+    assert_eq!(manifest["description"], "stairs missing");
+    assert!(!manifest["sections"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|section| section == "resources.json"));
+}
+
+#[tokio::test]
+async fn test_bug_report_saved_event() {
+    let dir = tempfile::tempdir().unwrap();
+    let game = GameBuilder::new()
+        .with_crash_reporter(CrashReportConfig::new(dir.path()))
+        .build()
+        .await
+        .unwrap();
+    let reporter = game
+        .resources
+        .get::<issun::engine::CrashReporter>()
+        .await
+        .unwrap()
+        .clone();
+
+    {
+        let mut bus = game.resources.get_mut::<EventBus>().await.unwrap();
+        bus.publish(BugReportRequested::default());
+        bus.dispatch();
+    }
+    reporter.record(None, &game.resources).await;
+
+    let mut bus = game.resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    let saved: Vec<BugReportSaved> = bus.reader::<BugReportSaved>().iter().cloned().collect();
+    assert_eq!(saved.len(), 1);
+    assert!(saved[0].path.exists());
+    assert_eq!(
+        bundles(dir.path(), "bug-report-"),
+        vec![saved[0].path.clone()]
+    );
+}
@@ -0,0 +1,221 @@
+#![cfg(feature = "debug-http")]
+
+//! Debug HTTP endpoints against a running headless simulation
+
+use issun::context::{ResourceContext, ServiceContext, SystemContext};
+use issun::engine::{query_channel, HeadlessRunner, QueryHandle};
+use issun::event::{Event, EventBus};
+use issun::plugin::debug_http::{DebugHttpAddress, DebugHttpConfig, DebugHttpPlugin};
+use issun::prelude::GameBuilder;
+use issun::scene::{Scene, SceneDirector, SceneTransition};
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+const TOKEN: &str = "let-me-in";
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+struct SimState {
+    tick: u64,
+    /// (amount, tick it was observed in)
+    pings: Vec<(u32, u64)>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct Ping {
+    amount: u32,
+}
+
+impl Event for Ping {}
+
+struct SimScene;
+
+#[async_trait::async_trait]
+impl Scene for SimScene {
+    async fn on_update(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) -> SceneTransition<Self> {
+        let pings: Vec<Ping> = {
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+            bus.reader::<Ping>().iter().cloned().collect()
+        };
+        let mut state = resources.get_mut::<SimState>().await.unwrap();
+        state.tick += 1;
+        let tick = state.tick;
+        state
+            .pings
+            .extend(pings.into_iter().map(|ping| (ping.amount, tick)));
+        SceneTransition::Stay
+    }
+}
+
+/// Minimal HTTP/1.1 client: returns (status, body)
+async fn request(
+    address: SocketAddr,
+    method: &str,
+    path: &str,
+    token: &str,
+    body: &str,
+) -> (u16, String) {
+    let mut stream = tokio::net::TcpStream::connect(address).await.unwrap();
+    let request = format!(
+        "{method} {path} HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer {token}\r\n\
+         Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(request.as_bytes()).await.unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    let status = response[9..12].parse().unwrap();
+    let body = response
+        .split_once("\r\n\r\n")
+        .map(|(_, body)| body.to_string())
+        .unwrap_or_default();
+    (status, body)
+}
+
+async fn sim_state(queries: &QueryHandle) -> (u64, Vec<(u32, u64)>) {
+    queries
+        .query(|resources| {
+            Box::pin(async move {
+                let state = resources.get::<SimState>().await.unwrap();
+                (state.tick, state.pings.clone())
+            })
+        })
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_debug_endpoints_read_and_publish() {
+    let (queries, receiver) = query_channel();
+    let game = GameBuilder::new()
+        .with_resource(SimState::default())
+        .with_plugin(
+            DebugHttpPlugin::new(queries.clone())
+                .with_config(
+                    DebugHttpConfig::enabled(TOKEN)
+                        .with_bind(SocketAddr::from(([127, 0, 0, 1], 0))),
+                )
+                .observe::<SimState>()
+                .event::<Ping>()
+                .unwrap(),
+        )
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+    let director = SceneDirector::new(SimScene, game.services, game.systems, game.resources).await;
+    let runner = HeadlessRunner::new(director)
+        .with_tick_rate(Duration::from_millis(5))
+        .with_query_channel(receiver);
+
+    let client = async {
+        let address = queries
+            .query(|resources| {
+                Box::pin(async move { resources.get::<DebugHttpAddress>().await.map(|a| a.0) })
+            })
+            .await
+            .unwrap()
+            .expect("debug server started");
+
+        // Without the token nothing is served
+        let (status, _) = request(address, "GET", "/debug/resources", "wrong", "").await;
+        assert_eq!(status, 401);
+
+        let (status, body) = request(address, "GET", "/debug/resources", TOKEN, "").await;
+        assert_eq!(status, 200);
+        let listed: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let sim = listed
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entry| entry["name"] == "SimState")
+            .unwrap();
+        assert_eq!(sim["observable"], true);
+        assert!(sim["writes"].as_u64().unwrap() > 0);
+
+        let (status, body) = request(address, "GET", "/debug/resources/SimState", TOKEN, "").await;
+        assert_eq!(status, 200);
+        let state: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert!(state["tick"].as_u64().unwrap() > 0);
+
+        let (status, _) = request(address, "GET", "/debug/resources/EventBus", TOKEN, "").await;
+        assert_eq!(status, 404);
+
+        // Publish through the factory; the scene sees it on the next tick
+        let (status, body) = request(
+            address,
+            "POST",
+            "/debug/events/Ping",
+            TOKEN,
+            r#"{"amount": 7}"#,
+        )
+        .await;
+        assert_eq!(status, 202, "{}", body);
+        let (published_at, _) = sim_state(&queries).await;
+
+        let mut observed = Vec::new();
+        while observed.is_empty() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            observed = sim_state(&queries).await.1;
+        }
+        assert_eq!(observed, vec![(7, published_at + 1)]);
+
+        let (status, _) = request(address, "POST", "/debug/events/Ping", TOKEN, r#"{}"#).await;
+        assert_eq!(status, 400);
+
+        // `config reload` is a bodyless command
+        let (status, _) = request(address, "POST", "/debug/config/reload", TOKEN, "").await;
+        assert_eq!(status, 202);
+        let (status, _) = request(address, "POST", "/debug/config/reload", "wrong", "").await;
+        assert_eq!(status, 401);
+
+        let (status, body) = request(address, "GET", "/debug/events", TOKEN, "").await;
+        assert_eq!(status, 200);
+        let stats: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert!(stats["published_total"].as_u64().unwrap() >= 1);
+        assert!(stats["channels"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|channel| channel["event_type"].as_str().unwrap().ends_with("::Ping")));
+    };
+
+    tokio::select! {
+        result = runner.run() => panic!("runner stopped early: {:?}", result.err()),
+        _ = client => {}
+    }
+}
+
+#[tokio::test]
+async fn test_debug_server_is_disabled_by_default() {
+    let (queries, receiver) = query_channel();
+    let game = GameBuilder::new()
+        .with_resource(SimState::default())
+        .with_plugin(DebugHttpPlugin::new(queries.clone()).observe::<SimState>())
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+    let director = SceneDirector::new(SimScene, game.services, game.systems, game.resources).await;
+    let runner = HeadlessRunner::new(director)
+        .with_tick_rate(Duration::from_millis(5))
+        .with_query_channel(receiver);
+
+    let client = async {
+        let started = queries
+            .query(|resources| Box::pin(async move { resources.contains::<DebugHttpAddress>() }))
+            .await;
+        assert_eq!(started, Some(false));
+    };
+
+    tokio::select! {
+        result = runner.run() => panic!("runner stopped early: {:?}", result.err()),
+        _ = client => {}
+    }
+}
@@ -0,0 +1,38 @@
+//! Names of derived Services and Systems
+
+use issun::service::Service;
+use issun::system::System;
+use issun::{Service, System};
+
+#[derive(Clone, Service)]
+struct CombatService;
+
+#[derive(Clone, Service)]
+#[service(name = "loot")]
+struct LootService;
+
+#[derive(System)]
+struct TurnManagerSystem;
+
+#[derive(System)]
+#[system(name = "combat_engine")]
+struct CombatSystem;
+
+#[test]
+fn test_service_name_defaults_to_snake_case_type_name() {
+    assert_eq!(CombatService::NAME, "combat_service");
+    assert_eq!(CombatService.name(), "combat_service");
+}
+
+#[test]
+fn test_explicit_service_name() {
+    assert_eq!(LootService::NAME, "loot");
+    assert_eq!(LootService.name(), "loot");
+}
+
+#[test]
+fn test_system_names() {
+    assert_eq!(TurnManagerSystem::NAME, "turn_manager_system");
+    assert_eq!(TurnManagerSystem.name(), "turn_manager_system");
+    assert_eq!(CombatSystem::NAME, "combat_engine");
+}
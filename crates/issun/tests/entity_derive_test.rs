@@ -0,0 +1,107 @@
+//! `#[derive(Entity)]` with string, numeric and newtype ids
+
+use issun::entity::Entity;
+use issun::Entity;
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+#[derive(Entity)]
+struct Player {
+    #[entity(id)]
+    name: String,
+    #[allow(dead_code)]
+    hp: i32,
+}
+
+#[derive(Entity)]
+struct Enemy {
+    #[entity(id)]
+    id: u32,
+}
+
+struct ItemId(u64);
+
+impl fmt::Display for ItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "item-{}", self.0)
+    }
+}
+
+#[derive(Entity)]
+struct DroppedItem {
+    #[entity(id)]
+    id: ItemId,
+}
+
+struct Tag(String);
+
+impl AsRef<str> for Tag {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Entity)]
+struct Npc {
+    #[entity(id, as_ref)]
+    tag: Tag,
+}
+
+#[derive(Entity)]
+struct Marker(#[entity(id)] Arc<str>);
+
+#[test]
+fn test_string_ids_are_borrowed() {
+    let player = Player {
+        name: "Alice".to_string(),
+        hp: 100,
+    };
+    assert!(matches!(player.id(), Cow::Borrowed("Alice")));
+    assert!(player.matches_id("Alice"));
+    assert!(!player.matches_id("Bob"));
+
+    let npc = Npc {
+        tag: Tag("merchant".to_string()),
+    };
+    assert!(matches!(npc.id(), Cow::Borrowed("merchant")));
+
+    let marker = Marker(Arc::from("spawn"));
+    assert_eq!(marker.id(), "spawn");
+}
+
+#[test]
+fn test_numeric_and_newtype_ids_are_formatted() {
+    let enemy = Enemy { id: 42 };
+    assert_eq!(enemy.id(), "42");
+    assert!(enemy.matches_id("42"));
+    assert!(!enemy.matches_id("042"));
+
+    let item = DroppedItem { id: ItemId(7) };
+    assert_eq!(item.id(), "item-7");
+    assert!(item.matches_id("item-7"));
+}
+
+#[test]
+fn test_entity_kind_and_trait_objects() {
+    assert_eq!(Player::ENTITY_KIND, "player");
+    assert_eq!(DroppedItem::ENTITY_KIND, "dropped_item");
+
+    let entities: Vec<Box<dyn Entity>> = vec![
+        Box::new(Enemy { id: 1 }),
+        Box::new(Npc {
+            tag: Tag("guard".to_string()),
+        }),
+    ];
+    let found: Vec<_> = entities
+        .iter()
+        .filter(|entity| entity.matches_id("guard"))
+        .collect();
+    assert_eq!(found.len(), 1);
+}
+
+#[test]
+fn test_malformed_entities_are_reported() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/entity_derive/*.rs");
+}
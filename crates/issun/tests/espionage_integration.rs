@@ -0,0 +1,228 @@
+//! Espionage operations: intel feeds subjective reality, detection costs
+//! reputation, and target security lowers the success rate
+
+use issun::context::{ResourceContext, ServiceContext};
+use issun::engine::rng::MasterSeed;
+use issun::event::EventBus;
+use issun::plugin::faction::{
+    DefaultFactionHook, DiplomaticIncidentEvent, EspionageConfig, EspionageOrder, Faction,
+    FactionId, FactionState, FactionSystem, Factions, IntelGatheredEvent, OperationId,
+    OperationStatus,
+};
+use issun::plugin::reputation::{ReputationChangeRequested, SubjectId};
+use issun::plugin::subjective_reality::{
+    FactType, KnowledgeBoardRegistry, PerceptionConfig, PerceptionSystem,
+};
+use issun::plugin::time::DayChanged;
+use std::sync::Arc;
+
+/// Events published while the faction system ran
+struct Published {
+    intel: Vec<IntelGatheredEvent>,
+    incidents: Vec<DiplomaticIncidentEvent>,
+    reputation: Vec<ReputationChangeRequested>,
+}
+
+struct World {
+    services: ServiceContext,
+    resources: ResourceContext,
+    system: FactionSystem,
+    day: u32,
+}
+
+impl World {
+    fn new(config: EspionageConfig, target_security: f32) -> Self {
+        let mut factions = Factions::new();
+        factions.add(Faction::new("crimson", "Crimson Syndicate"));
+        factions.add(
+            Faction::new("azure", "Azure Order")
+                .with_security(target_security)
+                .with_metadata(serde_json::json!({
+                    "garrison": 200,
+                    "treasury": 8000.0,
+                })),
+        );
+        factions.add(Faction::new("verdant", "Verdant League"));
+
+        let mut boards = KnowledgeBoardRegistry::new();
+        boards.register_faction("crimson".into());
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(factions);
+        resources.insert(FactionState::new());
+        resources.insert(config);
+        resources.insert(MasterSeed(42));
+        resources.insert(PerceptionConfig::default());
+        resources.insert(boards);
+
+        Self {
+            services: ServiceContext::new(),
+            resources,
+            system: FactionSystem::new(Arc::new(DefaultFactionHook)),
+            day: 0,
+        }
+    }
+
+    /// Publish events, run the faction system and collect what it published
+    async fn step(&mut self, publish: impl FnOnce(&mut EventBus)) -> Published {
+        {
+            let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+            publish(&mut bus);
+            bus.dispatch();
+        }
+        self.system
+            .process_events(&self.services, &mut self.resources)
+            .await;
+
+        let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        Published {
+            intel: bus.reader::<IntelGatheredEvent>().iter().cloned().collect(),
+            incidents: bus
+                .reader::<DiplomaticIncidentEvent>()
+                .iter()
+                .cloned()
+                .collect(),
+            reputation: bus
+                .reader::<ReputationChangeRequested>()
+                .iter()
+                .cloned()
+                .collect(),
+        }
+    }
+
+    async fn spy(&mut self, count: usize) {
+        self.step(|bus| {
+            for _ in 0..count {
+                bus.publish(
+                    EspionageOrder::new("azure").into_request(FactionId::new("crimson"), "Spy"),
+                );
+            }
+        })
+        .await;
+    }
+
+    async fn next_day(&mut self) -> Published {
+        self.day += 1;
+        let day = self.day;
+        self.step(|bus| bus.publish(DayChanged { day })).await
+    }
+
+    async fn status(&self, id: &str) -> OperationStatus {
+        let state = self.resources.get::<FactionState>().await.unwrap();
+        state.get_operation(&OperationId::new(id)).unwrap().status
+    }
+}
+
+#[tokio::test]
+async fn test_successful_operation_becomes_beliefs() {
+    let config = EspionageConfig {
+        base_success_chance: 1.0,
+        ..EspionageConfig::default()
+    };
+    let noise_bound = config.noise_bound(config.accuracy(0.0));
+    let mut world = World::new(config, 0.0);
+
+    world.spy(1).await;
+    assert_eq!(world.status("op-000001").await, OperationStatus::InProgress);
+
+    // Resolves after `duration_turns` days
+    assert!(world.next_day().await.intel.is_empty());
+    let published = world.next_day().await;
+    assert_eq!(published.intel.len(), 1);
+    assert_eq!(world.status("op-000001").await, OperationStatus::Completed);
+
+    // Intel from the bus becomes crimson's beliefs
+    let mut perception = PerceptionSystem::default();
+    let recorded = perception.ingest_intel(&mut world.resources).await.unwrap();
+    assert_eq!(recorded, 2);
+
+    let boards = world
+        .resources
+        .get::<KnowledgeBoardRegistry>()
+        .await
+        .unwrap();
+    let board = boards.get_board(&"crimson".into()).unwrap();
+    let garrison = board.get_fact(&"intel:azure:garrison".into()).unwrap();
+    let FactType::MilitaryStrength { faction, strength } = &garrison.fact_type else {
+        panic!("unexpected fact type: {:?}", garrison.fact_type);
+    };
+    assert_eq!(faction, "azure");
+    let error = (*strength as f32 - 200.0).abs() / 200.0;
+    assert!(error <= noise_bound + 0.01, "strength {strength}");
+    assert_eq!(
+        board.get_confidence(&"intel:azure:garrison".into()),
+        Some(published.intel[0].accuracy)
+    );
+    assert_eq!(
+        board.get_last_updated(&"intel:azure:garrison".into()),
+        Some(2)
+    );
+    assert!(matches!(
+        board
+            .get_fact(&"intel:azure:treasury".into())
+            .unwrap()
+            .fact_type,
+        FactType::FinancialStatus { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_detected_operation_applies_penalties() {
+    let config = EspionageConfig {
+        base_success_chance: 0.0,
+        min_success_chance: 0.0,
+        detection_chance: 1.0,
+        exposure_chance: 1.0,
+        duration_turns: 1,
+        ..EspionageConfig::default()
+    };
+    let mut world = World::new(config, 0.0);
+
+    world.spy(1).await;
+    let published = world.next_day().await;
+    assert_eq!(world.status("op-000001").await, OperationStatus::Failed);
+    assert!(published.intel.is_empty());
+
+    assert_eq!(published.incidents.len(), 1);
+    let incident = &published.incidents[0];
+    assert_eq!(incident.faction_id, FactionId::new("crimson"));
+    assert_eq!(incident.target, FactionId::new("azure"));
+    assert!(incident.exposed);
+
+    let penalties: Vec<(SubjectId, f32)> = published
+        .reputation
+        .iter()
+        .map(|request| (request.subject_id.clone(), request.delta))
+        .collect();
+    assert_eq!(
+        penalties,
+        vec![
+            (SubjectId::relation("azure", "crimson"), -20.0),
+            (SubjectId::relation("verdant", "crimson"), -5.0),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_security_lowers_success_rate() {
+    async fn successes(security: f32) -> usize {
+        let config = EspionageConfig {
+            duration_turns: 1,
+            ..EspionageConfig::default()
+        };
+        let mut world = World::new(config, security);
+        world.spy(200).await;
+        world.next_day().await.intel.len()
+    }
+
+    let unguarded = successes(0.0).await;
+    let guarded = successes(0.9).await;
+    assert!(
+        guarded + 40 < unguarded,
+        "guarded {guarded}, unguarded {unguarded}"
+    );
+    // Seeded: the same world gives the same results
+    assert_eq!(successes(0.9).await, guarded);
+}
@@ -0,0 +1,168 @@
+//! Per-(system, event type) handler cost attribution through the tracer
+
+use issun::context::{ResourceContext, ServiceContext};
+use issun::event::{Event, EventBus};
+use issun::plugin::metrics::{DefineMetricRequested, RecordMetricRequested};
+use issun::trace::{EventChainTracer, TraceEntryType};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct ContagionSpread;
+
+impl Event for ContagionSpread {}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct TurnEnded;
+
+impl Event for TurnEnded {}
+
+#[derive(Default)]
+struct SpreadSystem {
+    handled: usize,
+}
+
+#[issun::event_handler]
+impl SpreadSystem {
+    #[subscribe(ContagionSpread)]
+    async fn on_spread(&mut self, _event: &ContagionSpread) {
+        self.handled += 1;
+        std::thread::sleep(Duration::from_millis(8));
+    }
+
+    #[subscribe(TurnEnded)]
+    async fn on_turn_ended(&mut self, _event: &TurnEnded) {
+        self.handled += 1;
+        std::thread::sleep(Duration::from_millis(2));
+    }
+}
+
+/// Publish 3 spreads and 3 turn ends, then run the system once
+async fn run_frame(resources: &mut ResourceContext, system: &mut SpreadSystem) {
+    {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        for _ in 0..3 {
+            bus.publish(ContagionSpread);
+            bus.publish(TurnEnded);
+        }
+        bus.dispatch();
+    }
+    system
+        .process_events(&ServiceContext::new(), resources)
+        .await;
+}
+
+fn traced_bus(enabled: bool) -> (EventBus, Arc<Mutex<EventChainTracer>>) {
+    let mut tracer = EventChainTracer::new();
+    if enabled {
+        tracer.enable();
+    }
+    let tracer = Arc::new(Mutex::new(tracer));
+    let mut bus = EventBus::new();
+    bus.set_tracer(tracer.clone());
+    (bus, tracer)
+}
+
+fn handler_spans(tracer: &EventChainTracer) -> usize {
+    tracer
+        .traces()
+        .iter()
+        .filter(|entry| matches!(entry.entry_type, TraceEntryType::HandlerCompleted { .. }))
+        .count()
+}
+
+#[tokio::test]
+async fn test_costs_follow_handler_time() {
+    let (bus, tracer) = traced_bus(true);
+    let mut resources = ResourceContext::new();
+    resources.insert(bus);
+    let mut system = SpreadSystem::default();
+
+    run_frame(&mut resources, &mut system).await;
+    run_frame(&mut resources, &mut system).await;
+    assert_eq!(system.handled, 12);
+
+    let tracer = tracer.lock().unwrap();
+    // One span per handler batch, not per event
+    assert_eq!(handler_spans(&tracer), 4);
+
+    let top = tracer.top_event_costs(5);
+    assert_eq!(top.len(), 2);
+    assert_eq!(top[0].system, "SpreadSystem");
+    assert_eq!(top[0].event_type, "ContagionSpread");
+    assert_eq!((top[0].batches, top[0].events), (2, 6));
+    assert_eq!(top[1].event_type, "TurnEnded");
+
+    // 8 ms vs 2 ms of sleep per event
+    assert!(top[0].avg_ms_per_event() >= 8.0, "{:?}", top);
+    let ratio = top[0].total_ms / top[1].total_ms;
+    assert!((2.0..=6.0).contains(&ratio), "{:?}", top);
+    let share = top[0].total_ms / tracer.total_event_cost_ms();
+    assert!(share > 0.6, "{:?}", top);
+}
+
+#[tokio::test]
+async fn test_disabled_tracing_adds_no_spans() {
+    let mut system = SpreadSystem::default();
+
+    let mut resources = ResourceContext::new();
+    resources.insert(EventBus::new());
+    run_frame(&mut resources, &mut system).await;
+
+    let (bus, tracer) = traced_bus(false);
+    let mut resources = ResourceContext::new();
+    resources.insert(bus);
+    run_frame(&mut resources, &mut system).await;
+
+    assert_eq!(system.handled, 12);
+    let tracer = tracer.lock().unwrap();
+    assert!(tracer.traces().is_empty());
+    assert!(tracer.top_event_costs(5).is_empty());
+}
+
+#[tokio::test]
+async fn test_cost_metrics_published_per_frame() {
+    let (bus, _tracer) = traced_bus(true);
+    let mut resources = ResourceContext::new();
+    resources.insert(bus);
+    let mut system = SpreadSystem::default();
+
+    for frame in 0..2 {
+        run_frame(&mut resources, &mut system).await;
+
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        assert_eq!(bus.publish_cost_metrics(frame), 2);
+        bus.dispatch();
+
+        let defined: Vec<_> = bus
+            .reader::<DefineMetricRequested>()
+            .iter()
+            .map(|request| request.definition.id.as_str().to_string())
+            .collect();
+        if frame == 0 {
+            assert_eq!(
+                defined,
+                [
+                    "trace.cost.ContagionSpread.SpreadSystem",
+                    "trace.cost.TurnEnded.SpreadSystem"
+                ]
+            );
+        } else {
+            assert!(defined.is_empty());
+        }
+
+        let recorded: Vec<_> = bus
+            .reader::<RecordMetricRequested>()
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[0].value.metadata["events"], 3);
+
+        let next = bus.current_frame() + 1;
+        bus.set_frame(next);
+    }
+
+    let mut untraced = EventBus::new();
+    assert_eq!(untraced.publish_cost_metrics(0), 0);
+}
@@ -0,0 +1,72 @@
+//! Events declared with `event!` register their factories automatically
+#![cfg(feature = "event-factories")]
+
+use issun::event;
+use issun::event::{EventBus, EventFactoryRegistry, FactoryError};
+use serde_json::json;
+
+event! {
+    #[derive(PartialEq)]
+    pub CombatStartRequested {
+        pub arena: String,
+        pub enemies: u32,
+    }
+
+    pub TurnEnded;
+}
+
+#[test]
+fn test_declared_events_register_themselves() {
+    let registry = EventFactoryRegistry::registered().unwrap();
+    assert_eq!(registry.list(), ["CombatStartRequested", "TurnEnded"]);
+}
+
+#[test]
+fn test_constructed_event_is_observed_by_a_reader() {
+    let registry = EventFactoryRegistry::registered().unwrap();
+    let event = registry
+        .construct(
+            "CombatStartRequested",
+            json!({ "arena": "pit", "enemies": 3 }),
+        )
+        .unwrap();
+    assert_eq!(
+        event.type_name(),
+        std::any::type_name::<CombatStartRequested>()
+    );
+
+    let mut bus = EventBus::new();
+    event.publish(&mut bus);
+    bus.dispatch();
+    let read: Vec<_> = bus
+        .reader::<CombatStartRequested>()
+        .iter()
+        .cloned()
+        .collect();
+    assert_eq!(
+        read,
+        vec![CombatStartRequested {
+            arena: "pit".to_string(),
+            enemies: 3,
+        }]
+    );
+}
+
+#[test]
+fn test_unknown_name_and_bad_payload_are_errors() {
+    let registry = EventFactoryRegistry::registered().unwrap();
+
+    let unknown = registry.construct("CombatEnded", json!({})).unwrap_err();
+    assert!(matches!(unknown, FactoryError::UnknownEvent { ref name } if name == "CombatEnded"));
+
+    let invalid = registry
+        .construct("CombatStartRequested", json!({ "arena": "pit" }))
+        .unwrap_err();
+    match invalid {
+        FactoryError::InvalidPayload { name, message } => {
+            assert_eq!(name, "CombatStartRequested");
+            assert!(message.contains("enemies"), "{}", message);
+        }
+        other => panic!("expected InvalidPayload, got {:?}", other),
+    }
+}
@@ -0,0 +1,53 @@
+//! Two crates declaring events with the same name cannot both register
+#![cfg(feature = "event-factories")]
+
+use issun::event::{EventFactoryRegistry, FactoryError};
+
+mod combat {
+    issun::event! {
+        pub Spawned {
+            pub unit: String,
+        }
+    }
+}
+
+mod economy {
+    issun::event! {
+        pub Spawned {
+            pub caravan: u32,
+        }
+    }
+}
+
+#[test]
+fn test_colliding_names_report_both_locations() {
+    let error = EventFactoryRegistry::registered().unwrap_err();
+    let FactoryError::Collision {
+        name,
+        first_type,
+        first_location,
+        second_type,
+        second_location,
+    } = &error
+    else {
+        panic!("expected Collision, got {:?}", error);
+    };
+
+    assert_eq!(name, "Spawned");
+    let mut types = [first_type.as_str(), second_type.as_str()];
+    types.sort();
+    assert_eq!(
+        types,
+        [
+            std::any::type_name::<combat::Spawned>(),
+            std::any::type_name::<economy::Spawned>(),
+        ]
+    );
+    assert!(first_location.contains("event_factory_collision.rs:"));
+    assert!(second_location.contains("event_factory_collision.rs:"));
+    assert_ne!(first_location, second_location);
+
+    let message = error.to_string();
+    assert!(message.contains(first_location.as_str()));
+    assert!(message.contains(second_location.as_str()));
+}
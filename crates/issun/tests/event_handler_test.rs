@@ -0,0 +1,485 @@
+//! `#[event_handler]`: several event types per handler, priorities, early
+//! exit and state parameters
+
+use issun::context::{ResourceContext, ServiceContext};
+use issun::event::{Event, EventBus, EventWriter, HandlerFlow};
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct ItemAddedEvent {
+    item: String,
+}
+
+impl Event for ItemAddedEvent {}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct ItemRemovedEvent {
+    item: String,
+}
+
+impl Event for ItemRemovedEvent {}
+
+trait ItemEvent {
+    fn item(&self) -> &str;
+}
+
+impl ItemEvent for ItemAddedEvent {
+    fn item(&self) -> &str {
+        &self.item
+    }
+}
+
+impl ItemEvent for ItemRemovedEvent {
+    fn item(&self) -> &str {
+        &self.item
+    }
+}
+
+#[derive(Default)]
+struct InventoryLog {
+    changes: Vec<String>,
+    touched: Vec<String>,
+    added: usize,
+    ignored: usize,
+}
+
+#[issun::event_handler]
+impl InventoryLog {
+    #[subscribe(any(ItemAddedEvent, ItemRemovedEvent), filter = "is_not_junk")]
+    async fn on_change(&mut self, event: &ItemAddedOrRemoved) {
+        self.changes.push(match event {
+            ItemAddedOrRemoved::ItemAddedEvent(e) => format!("+{}", e.item),
+            ItemAddedOrRemoved::ItemRemovedEvent(e) => format!("-{}", e.item),
+        });
+    }
+
+    #[subscribe(ItemRemovedEvent)]
+    #[subscribe(ItemAddedEvent)]
+    async fn on_touch(&mut self, event: &dyn ItemEvent) {
+        self.touched.push(event.item().to_string());
+    }
+
+    #[subscribe(ItemAddedEvent)]
+    async fn on_added(&mut self, _event: &ItemAddedEvent) {
+        self.added += 1;
+    }
+
+    fn is_not_junk(&mut self, event: &ItemAddedOrRemoved) -> bool {
+        let junk = match event {
+            ItemAddedOrRemoved::ItemAddedEvent(e) => e.item == "junk",
+            ItemAddedOrRemoved::ItemRemovedEvent(e) => e.item == "junk",
+        };
+        if junk {
+            self.ignored += 1;
+        }
+        !junk
+    }
+}
+
+async fn run(log: &mut InventoryLog, publish: impl FnOnce(&mut EventBus)) {
+    let mut resources = ResourceContext::new();
+    let mut bus = EventBus::new();
+    publish(&mut bus);
+    bus.dispatch();
+    resources.insert(bus);
+    log.process_events(&ServiceContext::new(), &mut resources)
+        .await;
+}
+
+#[tokio::test]
+async fn test_combined_handler_sees_both_types() {
+    let mut log = InventoryLog::default();
+    run(&mut log, |bus| {
+        bus.publish(ItemRemovedEvent {
+            item: "shield".into(),
+        });
+        bus.publish(ItemAddedEvent {
+            item: "sword".into(),
+        });
+        bus.publish(ItemAddedEvent {
+            item: "junk".into(),
+        });
+    })
+    .await;
+
+    assert_eq!(log.changes, vec!["+sword", "-shield"]);
+    assert_eq!(log.ignored, 1);
+    // Types run in order of their first subscription in the impl
+    assert_eq!(log.touched, vec!["sword", "junk", "shield"]);
+    // A type shared with a single-type handler is delivered to both
+    assert_eq!(log.added, 2);
+}
+
+#[tokio::test]
+async fn test_generated_enum_converts_from_each_type() {
+    let added = ItemAddedEvent {
+        item: "potion".into(),
+    };
+    let event: ItemAddedOrRemoved = added.clone().into();
+    assert!(matches!(
+        event.clone(),
+        ItemAddedOrRemoved::ItemAddedEvent(inner) if inner == added
+    ));
+
+    let event = ItemAddedOrRemoved::from(ItemRemovedEvent {
+        item: "potion".into(),
+    });
+    assert!(matches!(event, ItemAddedOrRemoved::ItemRemovedEvent(_)));
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+enum KeyPressed {
+    Escape,
+    Char(char),
+}
+
+impl Event for KeyPressed {}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Tick;
+
+impl Event for Tick {}
+
+#[derive(Debug, Default)]
+struct TickCount(u32);
+
+/// A menu that swallows Escape before gameplay sees it
+#[derive(Default)]
+struct Input {
+    menu_open: bool,
+    seen: Vec<String>,
+}
+
+#[issun::event_handler]
+impl Input {
+    #[subscribe(KeyPressed)]
+    async fn gameplay(&mut self, event: &KeyPressed) {
+        self.seen.push(format!("gameplay {:?}", event));
+    }
+
+    #[subscribe(Tick, priority = -5)]
+    async fn tick(&mut self, _event: &Tick, #[state] ticks: &mut TickCount) -> HandlerFlow {
+        ticks.0 += 1;
+        self.seen.push("tick".to_string());
+        HandlerFlow::Continue
+    }
+
+    #[subscribe(KeyPressed, priority = 10)]
+    async fn menu(&mut self, event: &KeyPressed) -> HandlerFlow {
+        self.seen.push(format!("menu {:?}", event));
+        match event {
+            KeyPressed::Escape => {
+                self.menu_open = !self.menu_open;
+                HandlerFlow::StopEvent
+            }
+            KeyPressed::Char('q') if self.menu_open => HandlerFlow::StopAll,
+            KeyPressed::Char(_) => HandlerFlow::Continue,
+        }
+    }
+}
+
+async fn run_input(input: &mut Input, keys: &[KeyPressed]) -> u32 {
+    let mut resources = ResourceContext::new();
+    resources.insert(TickCount::default());
+    let mut bus = EventBus::new();
+    for key in keys {
+        bus.publish(key.clone());
+    }
+    bus.publish(Tick);
+    bus.dispatch();
+    resources.insert(bus);
+    input
+        .process_events(&ServiceContext::new(), &mut resources)
+        .await;
+    resources.get::<TickCount>().await.unwrap().0
+}
+
+#[tokio::test]
+async fn test_high_priority_handler_stops_event() {
+    let mut input = Input::default();
+    let ticks = run_input(
+        &mut input,
+        &[
+            KeyPressed::Char('w'),
+            KeyPressed::Escape,
+            KeyPressed::Char('a'),
+        ],
+    )
+    .await;
+
+    assert_eq!(
+        input.seen,
+        vec![
+            "menu Char('w')",
+            "gameplay Char('w')",
+            // Escape never reaches gameplay
+            "menu Escape",
+            "menu Char('a')",
+            "gameplay Char('a')",
+            // Lowest priority type runs last
+            "tick",
+        ]
+    );
+    assert!(input.menu_open);
+    assert_eq!(ticks, 1);
+}
+
+#[tokio::test]
+async fn test_stop_all_skips_remaining_events_and_types() {
+    let mut input = Input {
+        menu_open: true,
+        ..Input::default()
+    };
+    let ticks = run_input(&mut input, &[KeyPressed::Char('q'), KeyPressed::Char('w')]).await;
+
+    assert_eq!(input.seen, vec!["menu Char('q')"]);
+    assert_eq!(ticks, 0);
+}
+
+#[derive(Debug)]
+struct Difficulty(u32);
+
+#[derive(Debug, Default)]
+struct Scoreboard(u32);
+
+#[derive(Debug)]
+struct Weather(&'static str);
+
+/// Reads config, writes the score, and copes without weather
+#[derive(Default)]
+struct Scoring {
+    weather: Vec<Option<&'static str>>,
+    bonus_runs: usize,
+}
+
+#[issun::event_handler]
+impl Scoring {
+    #[subscribe(Tick)]
+    async fn score(
+        &mut self,
+        _event: &Tick,
+        #[state] difficulty: &Difficulty,
+        #[state] score: &mut Scoreboard,
+        #[state(optional)] weather: Option<&Weather>,
+    ) {
+        score.0 += difficulty.0;
+        self.weather.push(weather.map(|weather| weather.0));
+    }
+
+    #[subscribe(Tick)]
+    async fn bonus(&mut self, _event: &Tick, #[state(optional)] weather: Option<&mut Weather>) {
+        if let Some(weather) = weather {
+            weather.0 = "clear";
+        }
+        self.bonus_runs += 1;
+    }
+}
+
+async fn run_scoring(scoring: &mut Scoring, resources: &mut ResourceContext) {
+    let mut bus = EventBus::new();
+    bus.publish(Tick);
+    bus.dispatch();
+    resources.insert(bus);
+    scoring
+        .process_events(&ServiceContext::new(), resources)
+        .await;
+}
+
+#[tokio::test]
+async fn test_state_parameters_mutable_immutable_and_optional() {
+    let mut scoring = Scoring::default();
+    let mut resources = ResourceContext::new();
+    resources.insert(Difficulty(3));
+    resources.insert(Scoreboard::default());
+
+    // No weather: both handlers still run
+    run_scoring(&mut scoring, &mut resources).await;
+    assert_eq!(scoring.weather, vec![None]);
+    assert_eq!(scoring.bonus_runs, 1);
+
+    resources.insert(Weather("rain"));
+    run_scoring(&mut scoring, &mut resources).await;
+    assert_eq!(scoring.weather, vec![None, Some("rain")]);
+    assert_eq!(resources.get::<Weather>().await.unwrap().0, "clear");
+    assert_eq!(resources.get::<Scoreboard>().await.unwrap().0, 6);
+
+    // Missing required state skips only that handler
+    resources.remove::<Difficulty>();
+    run_scoring(&mut scoring, &mut resources).await;
+    assert_eq!(scoring.weather.len(), 2);
+    assert_eq!(scoring.bonus_runs, 3);
+    assert_eq!(resources.get::<Scoreboard>().await.unwrap().0, 6);
+}
+
+/// Adding an item orders a restock, which the same system handles next frame
+#[derive(Default)]
+struct Warehouse {
+    restocked: Vec<String>,
+    stop_after_first: bool,
+}
+
+#[issun::event_handler]
+impl Warehouse {
+    #[subscribe(ItemAddedEvent)]
+    async fn on_added(
+        &mut self,
+        event: &ItemAddedEvent,
+        #[events] out: &mut EventWriter,
+    ) -> HandlerFlow {
+        out.send(ItemRemovedEvent {
+            item: format!("{} crate", event.item),
+        });
+        if self.stop_after_first {
+            HandlerFlow::StopAll
+        } else {
+            HandlerFlow::Continue
+        }
+    }
+
+    #[subscribe(ItemRemovedEvent)]
+    async fn on_removed(&mut self, event: &ItemRemovedEvent) {
+        self.restocked.push(event.item.clone());
+    }
+}
+
+/// Like `Warehouse`, but a handler also locks the bus itself
+#[derive(Default)]
+struct BusWarehouse {
+    restocked: Vec<String>,
+}
+
+#[issun::event_handler]
+impl BusWarehouse {
+    #[subscribe(ItemAddedEvent)]
+    async fn on_added(
+        &mut self,
+        event: &ItemAddedEvent,
+        #[state] bus: &mut EventBus,
+        #[events] out: &mut EventWriter,
+    ) {
+        let _ = bus.current_frame();
+        out.send(ItemRemovedEvent {
+            item: format!("{} crate", event.item),
+        });
+    }
+
+    #[subscribe(ItemRemovedEvent)]
+    async fn on_removed(&mut self, event: &ItemRemovedEvent) {
+        self.restocked.push(event.item.clone());
+    }
+}
+
+fn added_items(items: &[&str]) -> ResourceContext {
+    let mut resources = ResourceContext::new();
+    let mut bus = EventBus::new();
+    for item in items {
+        bus.publish(ItemAddedEvent {
+            item: item.to_string(),
+        });
+    }
+    bus.dispatch();
+    resources.insert(bus);
+    resources
+}
+
+async fn next_frame(resources: &ResourceContext) {
+    resources.get_mut::<EventBus>().await.unwrap().dispatch();
+}
+
+#[tokio::test]
+async fn test_follow_up_events_are_handled_next_frame() {
+    let mut warehouse = Warehouse::default();
+    let mut resources = added_items(&["sword", "shield"]);
+
+    warehouse
+        .process_events(&ServiceContext::new(), &mut resources)
+        .await;
+    // Handlers ran on the snapshot; the restocks are not readable yet
+    assert!(warehouse.restocked.is_empty());
+
+    next_frame(&resources).await;
+    warehouse
+        .process_events(&ServiceContext::new(), &mut resources)
+        .await;
+    assert_eq!(warehouse.restocked, vec!["sword crate", "shield crate"]);
+
+    // Nothing cascades further
+    next_frame(&resources).await;
+    warehouse
+        .process_events(&ServiceContext::new(), &mut resources)
+        .await;
+    assert_eq!(warehouse.restocked.len(), 2);
+}
+
+#[tokio::test]
+async fn test_follow_ups_sent_before_stop_all_are_published() {
+    let mut warehouse = Warehouse {
+        stop_after_first: true,
+        ..Warehouse::default()
+    };
+    let mut resources = added_items(&["sword", "shield"]);
+
+    warehouse
+        .process_events(&ServiceContext::new(), &mut resources)
+        .await;
+    next_frame(&resources).await;
+    warehouse
+        .process_events(&ServiceContext::new(), &mut resources)
+        .await;
+    assert_eq!(warehouse.restocked, vec!["sword crate"]);
+}
+
+#[tokio::test]
+async fn test_follow_ups_with_a_handler_that_locks_the_bus() {
+    let mut warehouse = BusWarehouse::default();
+    let mut resources = added_items(&["bow"]);
+
+    warehouse
+        .process_events(&ServiceContext::new(), &mut resources)
+        .await;
+    assert!(warehouse.restocked.is_empty());
+
+    next_frame(&resources).await;
+    warehouse
+        .process_events(&ServiceContext::new(), &mut resources)
+        .await;
+    assert_eq!(warehouse.restocked, vec!["bow crate"]);
+}
+
+#[tokio::test]
+async fn test_each_system_handles_every_event_once_at_its_own_pace() {
+    let services = ServiceContext::new();
+    let mut resources = ResourceContext::new();
+    resources.insert(EventBus::new());
+    let mut every_frame = InventoryLog::default();
+    // Copies the events out of the bus, reading through the same cursors
+    let mut every_third = BusWarehouse::default();
+
+    for frame in 0..6 {
+        resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .publish(ItemAddedEvent {
+                item: format!("item{frame}"),
+            });
+        next_frame(&resources).await;
+
+        // Run twice in the same frame: the second run sees nothing new
+        every_frame.process_events(&services, &mut resources).await;
+        every_frame.process_events(&services, &mut resources).await;
+        if frame % 3 == 0 {
+            every_third.process_events(&services, &mut resources).await;
+        }
+    }
+    assert_eq!(every_frame.added, 6);
+    assert_eq!(every_third.restocked, vec!["item0 crate"]);
+
+    // Each catch-up handles what was missed and orders crates for the next
+    // frame
+    for _ in 0..2 {
+        next_frame(&resources).await;
+        every_third.process_events(&services, &mut resources).await;
+    }
+    let expected: Vec<String> = (0..6).map(|i| format!("item{i} crate")).collect();
+    assert_eq!(every_third.restocked, expected);
+}
@@ -0,0 +1,77 @@
+//! Shapes accepted by `event!`
+
+use issun::event;
+use issun::event::{Event, EventBus};
+
+event! {
+    /// Braced struct
+    pub DamageDealt {
+        pub target: String,
+        pub amount: u32,
+    }
+
+    /// Unit struct
+    pub TurnEnded;
+
+    /// Tuple struct
+    #[derive(PartialEq)]
+    pub ScoreChanged(pub i64);
+
+    pub RoomEntered(pub u32, pub u32);
+
+    /// Enum
+    #[derive(PartialEq)]
+    pub enum CombatOutcome {
+        Won { loot: Vec<String> },
+        Lost { turns: u32 },
+        Fled,
+    }
+}
+
+fn is_event<E: Event>() {}
+
+#[test]
+fn test_every_shape_is_an_event() {
+    is_event::<DamageDealt>();
+    is_event::<TurnEnded>();
+    is_event::<ScoreChanged>();
+    is_event::<RoomEntered>();
+    is_event::<CombatOutcome>();
+}
+
+#[test]
+fn test_tuple_and_enum_events_round_trip_through_the_bus() {
+    let mut bus = EventBus::new();
+    bus.publish(ScoreChanged(-3));
+    bus.publish(RoomEntered(1, 4));
+    bus.publish(CombatOutcome::Won {
+        loot: vec!["sword".into()],
+    });
+    bus.dispatch();
+
+    let scores: Vec<ScoreChanged> = bus.reader::<ScoreChanged>().iter().cloned().collect();
+    assert_eq!(scores, vec![ScoreChanged(-3)]);
+
+    let room = bus.reader::<RoomEntered>().iter().next().cloned().unwrap();
+    assert_eq!((room.0, room.1), (1, 4));
+
+    let outcomes: Vec<CombatOutcome> = bus.reader::<CombatOutcome>().iter().cloned().collect();
+    assert_eq!(
+        outcomes,
+        vec![CombatOutcome::Won {
+            loot: vec!["sword".into()]
+        }]
+    );
+}
+
+#[test]
+fn test_serde_shapes() {
+    assert_eq!(serde_json::to_string(&ScoreChanged(7)).unwrap(), "7");
+    assert_eq!(
+        serde_json::to_string(&CombatOutcome::Lost { turns: 3 }).unwrap(),
+        r#"{"Lost":{"turns":3}}"#
+    );
+    let fled: CombatOutcome = serde_json::from_str(r#""Fled""#).unwrap();
+    assert_eq!(fled, CombatOutcome::Fled);
+    let _ = format!("{:?}", TurnEnded);
+}
@@ -0,0 +1,186 @@
+//! Feature flags: build-time overrides, runtime toggles and gated systems
+
+use issun::builder::BuildReport;
+use issun::context::ResourceContext;
+use issun::engine::flags::{
+    FeatureFlagChanged, FeatureFlagRejected, FeatureFlagSystem, FeatureFlags, FlagSource,
+    SetFeatureFlagRequested,
+};
+use issun::event::EventBus;
+use issun::plugin::{Plugin, PluginBuilder};
+use issun::prelude::{GameBuilder, SystemExt};
+use std::io::Write;
+
+/// Counts how often the runner updated it
+#[derive(Debug, Default, issun::System)]
+struct CountingSystem {
+    runs: u32,
+}
+
+struct PipelinePlugin;
+
+#[async_trait::async_trait]
+impl Plugin for PipelinePlugin {
+    fn name(&self) -> &'static str {
+        "pipeline"
+    }
+
+    fn build(&self, builder: &mut dyn PluginBuilder) {
+        builder.declare_flag("pipeline_v2", false);
+    }
+}
+
+/// One runner tick: flag toggles first, then the gated system
+async fn tick(game: &mut issun::builder::Game) {
+    if let Some(mut bus) = game.resources.get_mut::<EventBus>().await {
+        bus.dispatch();
+    }
+    if let Some(system) = game.systems.get_mut::<FeatureFlagSystem>() {
+        system.update(&mut game.resources).await;
+    }
+    if let Some(system) = game.systems.active_mut::<CountingSystem>() {
+        system.runs += 1;
+    }
+}
+
+async fn publish(resources: &ResourceContext, request: SetFeatureFlagRequested) {
+    resources
+        .get_mut::<EventBus>()
+        .await
+        .unwrap()
+        .publish(request);
+}
+
+/// Flag changes published by the last tick
+async fn changes(game: &mut issun::builder::Game) -> Vec<FeatureFlagChanged> {
+    let mut bus = game.resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    bus.reader::<FeatureFlagChanged>().iter().cloned().collect()
+}
+
+fn runs(game: &issun::builder::Game) -> u32 {
+    game.systems.get::<CountingSystem>().unwrap().runs
+}
+
+#[tokio::test]
+async fn test_env_overrides_file_and_default() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(file, "pipeline_v2 = true\nfast_travel = true").unwrap();
+    std::env::set_var("ISSUN_FLAG_FAST_TRAVEL", "off");
+
+    let game = GameBuilder::new()
+        .with_plugin(PipelinePlugin)
+        .unwrap()
+        .declare_flag("fast_travel", false)
+        .declare_flag("weather", true)
+        .with_flags_file(file.path())
+        .build()
+        .await
+        .unwrap();
+
+    let flags = game.resources.get::<FeatureFlags>().await.unwrap();
+    assert!(flags.is_enabled("pipeline_v2"));
+    assert!(!flags.is_enabled("fast_travel"));
+    assert!(flags.is_enabled("weather"));
+
+    let report = game.resources.get::<BuildReport>().await.unwrap();
+    assert!(report.plugins.contains(&"pipeline".to_string()));
+    assert_eq!(report.flags["pipeline_v2"].source, FlagSource::ConfigFile);
+    assert_eq!(report.flags["fast_travel"].source, FlagSource::Env);
+    assert_eq!(report.flags["weather"].source, FlagSource::Default);
+}
+
+#[tokio::test]
+async fn test_gated_system_stops_after_runtime_toggle() {
+    let mut game = GameBuilder::new()
+        .declare_flag("counting", true)
+        .with_system(CountingSystem::default().gated_on("counting"))
+        .build()
+        .await
+        .unwrap();
+
+    tick(&mut game).await;
+    tick(&mut game).await;
+    assert_eq!(runs(&game), 2);
+
+    publish(
+        &game.resources,
+        SetFeatureFlagRequested {
+            name: "counting".into(),
+            enabled: false,
+            issuer: None,
+        },
+    )
+    .await;
+    tick(&mut game).await;
+    assert_eq!(runs(&game), 2);
+    assert_eq!(
+        changes(&mut game).await,
+        vec![FeatureFlagChanged {
+            name: "counting".into(),
+            enabled: false,
+            issuer: None,
+        }]
+    );
+    tick(&mut game).await;
+    assert_eq!(runs(&game), 2);
+    assert!(!game.systems.is_active::<CountingSystem>());
+
+    publish(
+        &game.resources,
+        SetFeatureFlagRequested {
+            name: "counting".into(),
+            enabled: true,
+            issuer: None,
+        },
+    )
+    .await;
+    tick(&mut game).await;
+    assert_eq!(runs(&game), 3);
+}
+
+#[tokio::test]
+async fn test_mod_may_only_set_its_own_flags() {
+    let mut game = GameBuilder::new()
+        .declare_flag("hard_mode", false)
+        .build()
+        .await
+        .unwrap();
+
+    for name in ["hard_mode", "weather_mod:storms"] {
+        publish(
+            &game.resources,
+            SetFeatureFlagRequested {
+                name: name.into(),
+                enabled: true,
+                issuer: Some("weather_mod".into()),
+            },
+        )
+        .await;
+    }
+    tick(&mut game).await;
+
+    let mut bus = game.resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    let rejected: Vec<FeatureFlagRejected> = bus
+        .reader::<FeatureFlagRejected>()
+        .iter()
+        .cloned()
+        .collect();
+    let changed: Vec<FeatureFlagChanged> =
+        bus.reader::<FeatureFlagChanged>().iter().cloned().collect();
+    drop(bus);
+
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected[0].name, "hard_mode");
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].name, "weather_mod:storms");
+
+    let flags = game.resources.get::<FeatureFlags>().await.unwrap();
+    assert!(!flags.is_enabled("hard_mode"));
+    assert!(flags.is_enabled("weather_mod:storms"));
+    assert_eq!(
+        flags.get("weather_mod:storms").unwrap().owner.as_deref(),
+        Some("weather_mod")
+    );
+}
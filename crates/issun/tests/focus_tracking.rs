@@ -0,0 +1,211 @@
+//! Focus policies in a simulated run: a fake clock drives the frame loop the
+//! way `GameRunner` does, one simulated day per tick
+
+use issun::context::ResourceContext;
+use issun::engine::{
+    AwaySummary, FocusConfig, FocusPolicy, FocusSignal, FocusSource, FocusTracker,
+    SimulationControl, WindowFocusChanged,
+};
+use issun::event::EventBus;
+use issun::plugin::save_load::AutoSaveRequested;
+use issun::plugin::{DayChanged, GameTimer};
+use std::time::{Duration, Instant};
+
+const FRAME: Duration = Duration::from_millis(10);
+const TICK: Duration = Duration::from_millis(50);
+
+struct Sim {
+    resources: ResourceContext,
+    focus: FocusTracker,
+    now: Instant,
+    last_tick: Instant,
+    updates: u32,
+    frames_rendered: u32,
+    focus_events: Vec<WindowFocusChanged>,
+    summaries: Vec<AwaySummary>,
+    autosaves: usize,
+}
+
+impl Sim {
+    fn new(config: FocusConfig) -> Self {
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(GameTimer::new());
+        resources.insert(config);
+        let now = Instant::now();
+        Self {
+            resources,
+            focus: FocusTracker::new()
+                .with_notable::<DayChanged>(|event| format!("Day {} began", event.day)),
+            now,
+            last_tick: now,
+            updates: 0,
+            frames_rendered: 0,
+            focus_events: Vec::new(),
+            summaries: Vec::new(),
+            autosaves: 0,
+        }
+    }
+
+    async fn signal(&mut self, signal: FocusSignal) {
+        self.focus
+            .signal(signal, self.now, &mut self.resources)
+            .await;
+    }
+
+    /// One runner frame: plan, idle check, tick if due, collect, dispatch
+    async fn frame(&mut self) {
+        let config = self.resources.get::<FocusConfig>().await.unwrap().clone();
+        let plan = self.focus.frame_plan(TICK, &config);
+        if plan.render {
+            self.frames_rendered += 1;
+        }
+        self.focus.poll_idle(self.now, &mut self.resources).await;
+
+        if self.now.duration_since(self.last_tick) >= plan.tick_interval {
+            let paused = self
+                .resources
+                .get::<SimulationControl>()
+                .await
+                .is_some_and(|control| control.is_paused());
+            if !paused {
+                self.updates += 1;
+                let day = self
+                    .resources
+                    .get_mut::<GameTimer>()
+                    .await
+                    .unwrap()
+                    .increment_day();
+                let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+                bus.publish(DayChanged { day });
+            }
+            self.last_tick = self.now;
+        }
+
+        self.focus.collect(&mut self.resources).await;
+        let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        self.focus_events
+            .extend(bus.reader::<WindowFocusChanged>().iter().cloned());
+        self.summaries
+            .extend(bus.reader::<AwaySummary>().iter().cloned());
+        self.autosaves += bus.reader::<AutoSaveRequested>().len();
+        drop(bus);
+
+        self.now += FRAME;
+    }
+
+    async fn run_for(&mut self, duration: Duration) {
+        let end = self.now + duration;
+        while self.now < end {
+            self.frame().await;
+        }
+    }
+
+    async fn day(&self) -> u32 {
+        self.resources.get::<GameTimer>().await.unwrap().day
+    }
+}
+
+#[tokio::test]
+async fn test_reduce_tick_rate_lowers_updates_while_unfocused() {
+    let config = FocusConfig::new(FocusPolicy::ReduceTickRate(5)).with_idle_timeout(None);
+    let mut sim = Sim::new(config);
+
+    sim.run_for(Duration::from_secs(1)).await;
+    let focused_updates = sim.updates;
+    let focused_frames = sim.frames_rendered;
+    assert!(focused_updates >= 19, "{}", focused_updates);
+
+    sim.signal(FocusSignal::Lost).await;
+    sim.run_for(Duration::from_secs(1)).await;
+    let unfocused_updates = sim.updates - focused_updates;
+    assert!(
+        (3..=4).contains(&unfocused_updates),
+        "{} updates while unfocused",
+        unfocused_updates
+    );
+    // The simulation keeps going, but nothing is drawn
+    assert_eq!(sim.frames_rendered, focused_frames);
+
+    sim.signal(FocusSignal::Gained).await;
+    sim.run_for(Duration::from_secs(1)).await;
+    assert!(sim.updates - focused_updates - unfocused_updates >= 19);
+    assert!(sim.frames_rendered > focused_frames);
+}
+
+#[tokio::test]
+async fn test_pause_simulation_summarizes_days_away_on_refocus() {
+    let config = FocusConfig::new(FocusPolicy::PauseSimulation)
+        .with_idle_timeout(Some(Duration::from_millis(500)))
+        .with_autosave(true);
+    let mut sim = Sim::new(config);
+
+    sim.signal(FocusSignal::Input).await;
+    let day_at_input = sim.day().await;
+    // The player walks away; days pass until the idle timeout notices
+    sim.run_for(Duration::from_secs(2)).await;
+    let day_at_pause = sim.day().await;
+    assert!(day_at_pause > day_at_input);
+    assert_eq!(
+        sim.focus_events,
+        [WindowFocusChanged {
+            focused: false,
+            source: FocusSource::Idle,
+        }]
+    );
+    assert_eq!(sim.autosaves, 1);
+    // Paused from then on
+    sim.run_for(Duration::from_secs(1)).await;
+    assert_eq!(sim.day().await, day_at_pause);
+
+    sim.signal(FocusSignal::Input).await;
+    sim.frame().await;
+    assert_eq!(sim.summaries.len(), 1);
+    let summary = &sim.summaries[0];
+    assert_eq!(summary.days_elapsed, day_at_pause - day_at_input);
+    assert_eq!(summary.away_for, Duration::from_secs(3));
+    assert_eq!(summary.notable.len() as u32, summary.days_elapsed);
+    assert_eq!(
+        summary.notable.last().unwrap(),
+        &format!("Day {} began", day_at_pause)
+    );
+
+    // Resumed automatically
+    assert!(!sim
+        .resources
+        .get::<SimulationControl>()
+        .await
+        .unwrap()
+        .is_paused());
+    sim.run_for(Duration::from_millis(200)).await;
+    assert!(sim.day().await > day_at_pause);
+}
+
+#[tokio::test]
+async fn test_focus_events_are_published_once_per_transition() {
+    let config = FocusConfig::new(FocusPolicy::PauseSimulation).with_idle_timeout(None);
+    let mut sim = Sim::new(config);
+
+    sim.signal(FocusSignal::Lost).await;
+    sim.signal(FocusSignal::Lost).await;
+    sim.frame().await;
+    sim.run_for(Duration::from_millis(300)).await;
+    sim.signal(FocusSignal::Gained).await;
+    sim.signal(FocusSignal::Input).await;
+    sim.signal(FocusSignal::Gained).await;
+    sim.frame().await;
+
+    let transitions: Vec<bool> = sim.focus_events.iter().map(|event| event.focused).collect();
+    assert_eq!(transitions, [false, true]);
+    assert!(sim
+        .focus_events
+        .iter()
+        .all(|event| event.source == FocusSource::Terminal));
+    // Terminal focus loss pauses at once: no days passed
+    assert_eq!(sim.summaries.len(), 1);
+    assert_eq!(sim.summaries[0].days_elapsed, 0);
+    assert!(sim.summaries[0].notable.is_empty());
+    // No autosave unless enabled
+    assert_eq!(sim.autosaves, 0);
+}
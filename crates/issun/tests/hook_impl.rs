@@ -0,0 +1,148 @@
+//! `#[hook_impl]` keeps the trait defaults for every method a hook leaves out
+
+use issun::context::ResourceContext;
+use issun::hook_impl;
+use issun::plugin::action::{ActionConsumed, ActionHook};
+use issun::plugin::combat::{BattleId, CombatHook};
+
+#[derive(Default)]
+struct ActionLog(Vec<String>);
+
+struct QuietHook;
+
+#[hook_impl]
+impl ActionHook for QuietHook {}
+
+struct ResetLogHook;
+
+#[hook_impl]
+impl ActionHook for ResetLogHook {
+    fn on_actions_reset(&self, new_count: u32, resources: &mut ResourceContext) {
+        if let Some(mut log) = resources.get_mut::<ActionLog>().await {
+            log.0.push(format!("reset to {}", new_count));
+        }
+    }
+}
+
+struct ManualTurnHook;
+
+#[hook_impl]
+impl ActionHook for ManualTurnHook {
+    async fn on_action_consumed(&self, consumed: &ActionConsumed, resources: &mut ResourceContext) {
+        if let Some(mut log) = resources.get_mut::<ActionLog>().await {
+            log.0.push(format!(
+                "{} ({} left)",
+                consumed.context, consumed.remaining
+            ));
+        }
+    }
+
+    fn on_actions_depleted(&self, _resources: &mut ResourceContext) -> bool {
+        false
+    }
+
+    fn on_actions_reset(&self, _new_count: u32, resources: &mut ResourceContext) {
+        if let Some(mut log) = resources.get_mut::<ActionLog>().await {
+            log.0.clear();
+        }
+    }
+}
+
+struct TurnLimit;
+
+#[hook_impl]
+impl CombatHook for TurnLimit {
+    fn before_turn(
+        &self,
+        _battle_id: &BattleId,
+        turn: u32,
+        _resources: &ResourceContext,
+    ) -> Result<(), String> {
+        if turn > 10 {
+            Err("the enemy retreats".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn consumed() -> ActionConsumed {
+    ActionConsumed {
+        context: "scout".to_string(),
+        remaining: 0,
+        depleted: true,
+    }
+}
+
+fn resources() -> ResourceContext {
+    let mut resources = ResourceContext::new();
+    resources.insert(ActionLog::default());
+    resources
+}
+
+async fn log(resources: &ResourceContext) -> Vec<String> {
+    resources.get::<ActionLog>().await.unwrap().0.clone()
+}
+
+#[tokio::test]
+async fn test_hook_without_overrides_keeps_every_default() {
+    let mut resources = resources();
+    let hook: Box<dyn ActionHook> = Box::new(QuietHook);
+
+    hook.on_action_consumed(&consumed(), &mut resources).await;
+    assert!(hook.on_actions_depleted(&mut resources).await);
+    hook.on_actions_reset(3, &mut resources).await;
+
+    assert!(log(&resources).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_hook_overriding_one_method_keeps_the_others() {
+    let mut resources = resources();
+    let hook: Box<dyn ActionHook> = Box::new(ResetLogHook);
+
+    hook.on_action_consumed(&consumed(), &mut resources).await;
+    assert!(hook.on_actions_depleted(&mut resources).await);
+    hook.on_actions_reset(3, &mut resources).await;
+
+    assert_eq!(log(&resources).await, ["reset to 3"]);
+}
+
+#[tokio::test]
+async fn test_hook_overriding_every_method() {
+    let mut resources = resources();
+    let hook: Box<dyn ActionHook> = Box::new(ManualTurnHook);
+
+    hook.on_action_consumed(&consumed(), &mut resources).await;
+    assert_eq!(log(&resources).await, ["scout (0 left)"]);
+    assert!(!hook.on_actions_depleted(&mut resources).await);
+    hook.on_actions_reset(3, &mut resources).await;
+    assert!(log(&resources).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_hook_override_on_a_wider_trait() {
+    let mut resources = resources();
+    let hook: Box<dyn CombatHook> = Box::new(TurnLimit);
+    let battle: BattleId = "arena".to_string();
+
+    assert!(hook.before_turn(&battle, 3, &resources).await.is_ok());
+    assert_eq!(
+        hook.before_turn(&battle, 11, &resources).await,
+        Err("the enemy retreats".to_string())
+    );
+
+    let log = hook.process_turn(&battle, 3, &mut resources).await;
+    assert!(log.is_empty());
+    let refused = hook
+        .apply_damage(
+            &battle,
+            &"fighter".to_string(),
+            &"goblin".to_string(),
+            4,
+            "slash",
+            &mut resources,
+        )
+        .await;
+    assert!(refused.is_err());
+}
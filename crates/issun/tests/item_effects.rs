@@ -0,0 +1,294 @@
+//! Data-defined item effects run through the plugins that own the state
+
+use async_trait::async_trait;
+use issun::context::{ResourceContext, ServiceContext};
+use issun::event::{Event, EventBus};
+use issun::plugin::combat::{
+    BattleId, CombatHook, CombatState, CombatSystem, CombatantId, DamageDealt, HealingDone,
+};
+use issun::plugin::inventory::{
+    EntityId, InventoryHook, InventoryState, InventorySystem, ItemCatalog, ItemDefinition,
+    ItemEffect, ItemId, ItemUseRejectedEvent, ItemUseRequested, ItemUsedEvent,
+};
+use issun::plugin::room_buff::{
+    ActiveBuffs, BuffConfig, BuffDuration, BuffEffect, BuffSystem, RoomBuffDatabase,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// HP of everyone on the field, and which side they are on
+#[derive(Default)]
+struct Field {
+    hp: HashMap<String, i32>,
+    enemies: Vec<String>,
+}
+
+/// The game's combatants live in `Field`
+struct FieldCombat;
+
+#[async_trait]
+impl CombatHook for FieldCombat {
+    async fn apply_damage(
+        &self,
+        _battle_id: &BattleId,
+        _source: &CombatantId,
+        target: &CombatantId,
+        amount: i32,
+        damage_type: &str,
+        resources: &mut ResourceContext,
+    ) -> Result<i32, String> {
+        let mut field = resources.get_mut::<Field>().await.unwrap();
+        let hp = field.hp.get_mut(target).ok_or("no such combatant")?;
+        let amount = if damage_type == "fire" {
+            amount * 2
+        } else {
+            amount
+        };
+        *hp -= amount;
+        Ok(amount)
+    }
+
+    async fn apply_healing(
+        &self,
+        _battle_id: Option<&BattleId>,
+        _source: &CombatantId,
+        target: &CombatantId,
+        amount: i32,
+        resources: &mut ResourceContext,
+    ) -> Result<i32, String> {
+        let mut field = resources.get_mut::<Field>().await.unwrap();
+        let hp = field.hp.get_mut(target).ok_or("no such combatant")?;
+        *hp += amount;
+        Ok(amount)
+    }
+}
+
+/// Bombs may only be thrown at the enemy team
+struct TeamRules;
+
+#[async_trait]
+impl InventoryHook for TeamRules {
+    async fn intercept_item_effect(
+        &self,
+        _entity_id: &EntityId,
+        _item_id: &ItemId,
+        target: Option<&EntityId>,
+        effect: ItemEffect,
+        resources: &ResourceContext,
+    ) -> Result<Option<ItemEffect>, String> {
+        if let (ItemEffect::DamageTarget { .. }, Some(target)) = (&effect, target) {
+            let field = resources.get::<Field>().await.unwrap();
+            if !field.enemies.contains(target) {
+                return Err(format!("{} is not an enemy", target));
+            }
+        }
+        Ok(Some(effect))
+    }
+}
+
+struct Game {
+    resources: ResourceContext,
+    /// Outcome of the last use, published by the inventory pass
+    rejected: Vec<ItemUseRejectedEvent>,
+    used: Vec<ItemUsedEvent>,
+    inventory: InventorySystem,
+    combat: CombatSystem,
+    buffs: BuffSystem,
+}
+
+impl Game {
+    async fn new() -> Self {
+        let items = ItemCatalog::new()
+            .with_item(
+                "potion",
+                ItemDefinition::consumable(vec![ItemEffect::Heal { amount: 15 }]),
+            )
+            .with_item(
+                "bomb",
+                ItemDefinition::consumable(vec![ItemEffect::DamageTarget {
+                    amount: 6,
+                    damage_type: "fire".to_string(),
+                }]),
+            )
+            .with_item(
+                "war_horn",
+                ItemDefinition {
+                    effects: vec![ItemEffect::ApplyBuff {
+                        id: "rally".to_string(),
+                        duration: Some(BuffDuration::Turns(2)),
+                    }],
+                    consumable: false,
+                },
+            );
+        let mut inventory = InventoryState::new();
+        for item in ["potion", "bomb", "war_horn"] {
+            inventory
+                .add_item(&"hero".to_string(), &item.to_string(), 1)
+                .unwrap();
+        }
+        let field = Field {
+            hp: [("hero", 10), ("squire", 20), ("goblin", 30)]
+                .into_iter()
+                .map(|(name, hp)| (name.to_string(), hp))
+                .collect(),
+            enemies: vec!["goblin".to_string()],
+        };
+
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(items);
+        resources.insert(inventory);
+        resources.insert(field);
+        resources.insert(CombatState::new());
+        resources.insert(RoomBuffDatabase::new().with_buff(
+            "rally",
+            BuffConfig {
+                id: "rally".to_string(),
+                name: "Rally".to_string(),
+                duration: BuffDuration::Permanent,
+                effect: BuffEffect::AttackBonus(2),
+                tick_on: vec![],
+            },
+        ));
+        resources.insert(ActiveBuffs::new());
+
+        Self {
+            resources,
+            rejected: Vec::new(),
+            used: Vec::new(),
+            inventory: InventorySystem::new(Arc::new(TeamRules)),
+            combat: CombatSystem::new(Arc::new(FieldCombat)),
+            buffs: BuffSystem::default(),
+        }
+    }
+
+    async fn start_battle(&mut self) {
+        let mut state = self.resources.get_mut::<CombatState>().await.unwrap();
+        state.start_battle("arena".to_string()).unwrap();
+    }
+
+    /// Use an item and let every system react
+    async fn use_item(&mut self, item: &str, target: Option<&str>) {
+        let services = ServiceContext::new();
+        {
+            let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+            bus.publish(ItemUseRequested {
+                entity_id: "hero".to_string(),
+                item_id: item.to_string(),
+                target: target.map(str::to_string),
+            });
+            bus.dispatch();
+        }
+        self.inventory
+            .process_events(&services, &mut self.resources)
+            .await;
+        self.dispatch().await;
+        self.rejected = self.events().await;
+        self.used = self.events().await;
+        self.combat
+            .process_events(&services, &mut self.resources)
+            .await;
+        self.buffs
+            .process_events(&services, &mut self.resources)
+            .await;
+        self.dispatch().await;
+    }
+
+    async fn dispatch(&self) {
+        self.resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .dispatch();
+    }
+
+    async fn events<E: Event + Clone>(&self) -> Vec<E> {
+        let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+        bus.reader::<E>().iter().cloned().collect()
+    }
+
+    async fn hp(&self, name: &str) -> i32 {
+        self.resources.get::<Field>().await.unwrap().hp[name]
+    }
+
+    async fn held(&self, item: &str) -> u32 {
+        self.resources
+            .get::<InventoryState>()
+            .await
+            .unwrap()
+            .get_item_quantity(&"hero".to_string(), &item.to_string())
+    }
+}
+
+#[tokio::test]
+async fn test_potion_heals_through_the_combat_path() {
+    let mut game = Game::new().await;
+
+    // Outside battle: healed by the combat hook, nothing to report to threat
+    game.use_item("potion", Some("squire")).await;
+    assert_eq!(game.hp("squire").await, 35);
+    assert_eq!(game.held("potion").await, 0);
+    assert_eq!(game.used[0].target.as_deref(), Some("squire"));
+    assert!(game.events::<HealingDone>().await.is_empty());
+
+    // In battle the heal is reported, so it draws threat
+    game.resources
+        .get_mut::<InventoryState>()
+        .await
+        .unwrap()
+        .add_item(&"hero".to_string(), &"potion".to_string(), 1)
+        .unwrap();
+    game.start_battle().await;
+    game.use_item("potion", None).await;
+    assert_eq!(game.hp("hero").await, 25);
+    let healed = game.events::<HealingDone>().await;
+    assert_eq!(healed.len(), 1);
+    assert_eq!((healed[0].healer.as_str(), healed[0].amount), ("hero", 15));
+}
+
+#[tokio::test]
+async fn test_bomb_damages_an_enemy_target() {
+    let mut game = Game::new().await;
+    game.start_battle().await;
+
+    // Allies are refused by the hook; the bomb is kept
+    game.use_item("bomb", Some("squire")).await;
+    assert_eq!(game.rejected[0].reason, "squire is not an enemy");
+    assert_eq!(game.held("bomb").await, 1);
+
+    game.use_item("bomb", Some("goblin")).await;
+    assert_eq!(game.hp("goblin").await, 18);
+    assert_eq!(game.hp("squire").await, 20);
+    assert_eq!(game.held("bomb").await, 0);
+    let dealt = game.events::<DamageDealt>().await;
+    assert_eq!(dealt.len(), 1);
+    assert_eq!((dealt[0].attacker.as_str(), dealt[0].amount), ("hero", 12));
+}
+
+#[tokio::test]
+async fn test_combat_only_effects_are_rejected_outside_battle() {
+    let mut game = Game::new().await;
+
+    game.use_item("bomb", Some("goblin")).await;
+    assert_eq!(game.rejected.len(), 1);
+    assert_eq!(game.rejected[0].reason, "bomb can only be used in battle");
+    assert!(game.used.is_empty());
+    assert_eq!(game.hp("goblin").await, 30);
+    assert_eq!(game.held("bomb").await, 1);
+
+    // Targets are required, in battle too
+    game.start_battle().await;
+    game.use_item("bomb", None).await;
+    assert_eq!(game.rejected[0].reason, "bomb needs a target");
+}
+
+#[tokio::test]
+async fn test_buff_effect_overrides_duration_and_keeps_the_item() {
+    let mut game = Game::new().await;
+
+    game.use_item("war_horn", None).await;
+    let buffs = game.resources.get::<ActiveBuffs>().await.unwrap();
+    assert_eq!(buffs.find("rally").unwrap().remaining_turns, Some(2));
+    drop(buffs);
+    assert_eq!(game.held("war_horn").await, 1);
+}
@@ -0,0 +1,115 @@
+//! Daily challenge: the same seed gives the same drops per source, whatever
+//! the order in which sources are looted
+
+use chrono::NaiveDate;
+use issun::context::{ResourceContext, ServiceContext};
+use issun::engine::DailyChallenge;
+use issun::event::EventBus;
+use issun::plugin::loot::{
+    LootGenerateRequested, LootGeneratedEvent, LootHook, LootNotGeneratedEvent, LootPlugin,
+    LootSourceId, LootState, LootSystem, Rarity,
+};
+use issun::prelude::GameBuilder;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+struct TableHook;
+
+#[async_trait::async_trait]
+impl LootHook for TableHook {
+    async fn generate_loot(
+        &self,
+        source_id: &LootSourceId,
+        rarity: Rarity,
+        _resources: &ResourceContext,
+    ) -> Vec<String> {
+        vec![format!("{}:{:?}", source_id, rarity)]
+    }
+}
+
+/// What each source dropped, in the order it was opened (None = nothing)
+type Drops = BTreeMap<String, Vec<Option<Rarity>>>;
+
+async fn play(date: NaiveDate, opens: &[&str]) -> (Drops, LootState) {
+    let game = GameBuilder::new()
+        .with_plugin(LootPlugin::new().with_hook(TableHook))
+        .unwrap()
+        .with_resource(DailyChallenge::new(date).master_seed())
+        .build()
+        .await
+        .unwrap();
+    let services: ServiceContext = game.services;
+    let mut resources = game.resources;
+    let mut system = LootSystem::new(Arc::new(TableHook));
+
+    let mut drops = Drops::new();
+    for source in opens {
+        {
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+            bus.publish(LootGenerateRequested {
+                source_id: source.to_string(),
+                drop_rate: 0.5,
+            });
+            bus.dispatch();
+        }
+        system.process_events(&services, &mut resources).await;
+
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        let generated: Vec<LootGeneratedEvent> =
+            bus.reader::<LootGeneratedEvent>().iter().cloned().collect();
+        let missed: Vec<LootNotGeneratedEvent> = bus
+            .reader::<LootNotGeneratedEvent>()
+            .iter()
+            .cloned()
+            .collect();
+        let entry = drops.entry(source.to_string()).or_default();
+        for event in generated {
+            assert_eq!(event.items, vec![format!("{}:{:?}", source, event.rarity)]);
+            entry.push(Some(event.rarity));
+        }
+        entry.extend(missed.iter().map(|_| None));
+    }
+
+    let state = resources.get::<LootState>().await.unwrap().clone();
+    (drops, state)
+}
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+}
+
+#[tokio::test]
+async fn test_same_seed_same_drops_in_any_order() {
+    let (first, first_state) = play(
+        date(15),
+        &[
+            "chest_a", "chest_b", "goblin", "chest_a", "chest_b", "goblin", "chest_a", "chest_b",
+            "goblin", "chest_a", "chest_b", "goblin",
+        ],
+    )
+    .await;
+    let (second, second_state) = play(
+        date(15),
+        &[
+            "goblin", "goblin", "chest_b", "goblin", "chest_a", "chest_a", "chest_b", "goblin",
+            "chest_a", "chest_b", "chest_b", "chest_a",
+        ],
+    )
+    .await;
+
+    assert_eq!(first, second);
+    assert!(first.values().all(|drops| drops.len() == 4));
+    assert_eq!(first_state, second_state);
+    assert_eq!(first_state.sequence("chest_a"), 4);
+
+    let (other_day, _) = play(
+        date(16),
+        &[
+            "chest_a", "chest_b", "goblin", "chest_a", "chest_b", "goblin", "chest_a", "chest_b",
+            "goblin", "chest_a", "chest_b", "goblin",
+        ],
+    )
+    .await;
+    assert_ne!(first, other_day);
+}
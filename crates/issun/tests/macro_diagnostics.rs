@@ -0,0 +1,39 @@
+//! Compile errors of the derive and attribute macros, checked with trybuild
+//!
+//! Regenerate the `.stderr` files with `TRYBUILD=overwrite` after changing a
+//! message on purpose.
+
+#[test]
+fn test_scene_diagnostics() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/scene/pass/*.rs");
+    cases.compile_fail("tests/ui/scene/fail/*.rs");
+}
+
+#[test]
+fn test_plugin_diagnostics() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/plugin/pass/*.rs");
+    cases.compile_fail("tests/ui/plugin/fail/*.rs");
+}
+
+#[test]
+fn test_event_handler_diagnostics() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/event_handler/pass/*.rs");
+    cases.compile_fail("tests/ui/event_handler/fail/*.rs");
+}
+
+#[test]
+fn test_auto_pump_diagnostics() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/auto_pump/pass/*.rs");
+    cases.compile_fail("tests/ui/auto_pump/fail/*.rs");
+}
+
+#[test]
+fn test_hook_impl_diagnostics() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/hook_impl/pass/*.rs");
+    cases.compile_fail("tests/ui/hook_impl/fail/*.rs");
+}
@@ -34,6 +34,7 @@ async fn test_mod_bridge_with_plugins() {
         // Enable combat
         event_bus.publish(issun::modding::events::PluginEnabledEvent {
             plugin_name: "combat".to_string(),
+            issuer: None,
         });
 
         // Set combat parameters
@@ -41,17 +42,20 @@ async fn test_mod_bridge_with_plugins() {
             plugin_name: "combat".to_string(),
             key: "max_hp".to_string(),
             value: serde_json::json!(200),
+            issuer: None,
         });
 
         event_bus.publish(issun::modding::events::PluginParameterChangedEvent {
             plugin_name: "combat".to_string(),
             key: "difficulty".to_string(),
             value: serde_json::json!(3.0),
+            issuer: None,
         });
 
         // Disable inventory
         event_bus.publish(issun::modding::events::PluginDisabledEvent {
             plugin_name: "inventory".to_string(),
+            issuer: None,
         });
 
         // Dispatch events
@@ -103,6 +107,7 @@ async fn test_namespaced_plugin_names() {
             .expect("EventBus not found");
         event_bus.publish(issun::modding::events::PluginEnabledEvent {
             plugin_name: "issun:combat".to_string(), // Namespaced
+            issuer: None,
         });
         event_bus.dispatch();
     }
@@ -135,6 +140,7 @@ async fn test_unknown_plugin_handling() {
             .expect("EventBus not found");
         event_bus.publish(issun::modding::events::PluginEnabledEvent {
             plugin_name: "unknown_plugin".to_string(),
+            issuer: None,
         });
         event_bus.dispatch();
     }
@@ -160,6 +166,7 @@ async fn test_unknown_parameter_handling() {
             plugin_name: "combat".to_string(),
             key: "unknown_param".to_string(),
             value: serde_json::json!(999),
+            issuer: None,
         });
         event_bus.dispatch();
     }
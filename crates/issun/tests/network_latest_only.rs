@@ -0,0 +1,149 @@
+#![cfg(feature = "network")]
+
+//! Latest-only events: state broadcasts coalesce before they are read or sent
+
+use async_trait::async_trait;
+use issun::event::{Event, EventBus};
+use issun::network::backend::RawNetworkEvent;
+use issun::network::{NetworkBackend, NetworkScope, NodeId};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct BallUpdate {
+    x: i32,
+    y: i32,
+}
+
+impl Event for BallUpdate {
+    fn is_networked() -> bool {
+        true
+    }
+
+    fn network_scope() -> NetworkScope {
+        NetworkScope::Broadcast
+    }
+
+    fn is_latest_only() -> bool {
+        true
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct PaddleMove {
+    y: i32,
+}
+
+impl Event for PaddleMove {
+    fn is_networked() -> bool {
+        true
+    }
+}
+
+/// Records what is sent
+struct Recorder {
+    sent: Arc<Mutex<Vec<RawNetworkEvent>>>,
+    rx: Mutex<Option<mpsc::Receiver<RawNetworkEvent>>>,
+}
+
+#[async_trait]
+impl NetworkBackend for Recorder {
+    fn node_id(&self) -> NodeId {
+        NodeId::from_u64(1)
+    }
+
+    async fn send(&self, event: RawNetworkEvent) -> issun::error::Result<()> {
+        self.sent.lock().unwrap().push(event);
+        Ok(())
+    }
+
+    fn receive_stream(&self) -> mpsc::Receiver<RawNetworkEvent> {
+        self.rx.lock().unwrap().take().unwrap()
+    }
+
+    async fn connect(&mut self, _addr: &str) -> issun::error::Result<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> issun::error::Result<()> {
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        true
+    }
+}
+
+fn host() -> (EventBus, Arc<Mutex<Vec<RawNetworkEvent>>>) {
+    let (_tx, rx) = mpsc::channel(64);
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let backend = Recorder {
+        sent: sent.clone(),
+        rx: Mutex::new(Some(rx)),
+    };
+    let mut bus = EventBus::new().with_network(backend);
+    bus.register_networked_event::<BallUpdate>();
+    bus.register_networked_event::<PaddleMove>();
+    (bus, sent)
+}
+
+fn sent_of<E>(sent: &Mutex<Vec<RawNetworkEvent>>) -> Vec<RawNetworkEvent> {
+    let type_name = std::any::type_name::<E>();
+    sent.lock()
+        .unwrap()
+        .iter()
+        .filter(|event| event.type_name == type_name)
+        .cloned()
+        .collect()
+}
+
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+}
+
+#[tokio::test]
+async fn test_hundred_ball_updates_read_and_sent_once() {
+    let (mut bus, sent) = host();
+
+    for x in 0..100 {
+        bus.publish(BallUpdate { x, y: 0 });
+        bus.publish(PaddleMove { y: x });
+    }
+    bus.dispatch();
+    settle().await;
+
+    let balls: Vec<_> = bus.reader::<BallUpdate>().iter().cloned().collect();
+    assert_eq!(balls, [BallUpdate { x: 99, y: 0 }]);
+
+    let sent_balls = sent_of::<BallUpdate>(&sent);
+    assert_eq!(sent_balls.len(), 1);
+    let ball: BallUpdate = bincode::deserialize(&sent_balls[0].payload).unwrap();
+    assert_eq!(ball.x, 99);
+
+    // Other networked events are all sent
+    assert_eq!(bus.reader::<PaddleMove>().len(), 100);
+    assert_eq!(sent_of::<PaddleMove>(&sent).len(), 100);
+}
+
+#[tokio::test]
+async fn test_latest_only_events_are_sent_once_per_dispatch() {
+    let (mut bus, sent) = host();
+
+    bus.publish(BallUpdate { x: 1, y: 1 });
+    bus.publish(BallUpdate { x: 2, y: 2 });
+    bus.dispatch();
+    bus.publish(BallUpdate { x: 3, y: 3 });
+    bus.dispatch();
+    settle().await;
+
+    let xs: Vec<i32> = sent_of::<BallUpdate>(&sent)
+        .iter()
+        .map(|event| {
+            bincode::deserialize::<BallUpdate>(&event.payload)
+                .unwrap()
+                .x
+        })
+        .collect();
+    assert_eq!(xs, [2, 3]);
+}
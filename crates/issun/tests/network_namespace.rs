@@ -0,0 +1,177 @@
+#![cfg(feature = "network")]
+
+//! Bus namespaces: several matches in one process, one connection
+
+use async_trait::async_trait;
+use issun::event::{Event, EventBus, NetworkFrameStats};
+use issun::network::backend::RawNetworkEvent;
+use issun::network::{
+    LegacyFramePolicy, NetworkBackend, NetworkMetadata, NetworkScope, NodeId, SharedNetworkBackend,
+    NAMESPACE_JOIN,
+};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct ScoreChanged {
+    score: u32,
+}
+
+impl Event for ScoreChanged {
+    fn is_networked() -> bool {
+        true
+    }
+}
+
+/// Records what is sent; the test plays the relay through the returned sender
+struct Loopback {
+    sent: Arc<Mutex<Vec<RawNetworkEvent>>>,
+    rx: Mutex<Option<mpsc::Receiver<RawNetworkEvent>>>,
+}
+
+impl Loopback {
+    fn new() -> (Self, mpsc::Sender<RawNetworkEvent>) {
+        let (tx, rx) = mpsc::channel(64);
+        let backend = Self {
+            sent: Arc::default(),
+            rx: Mutex::new(Some(rx)),
+        };
+        (backend, tx)
+    }
+}
+
+#[async_trait]
+impl NetworkBackend for Loopback {
+    fn node_id(&self) -> NodeId {
+        NodeId::from_u64(1)
+    }
+
+    async fn send(&self, event: RawNetworkEvent) -> issun::error::Result<()> {
+        self.sent.lock().unwrap().push(event);
+        Ok(())
+    }
+
+    fn receive_stream(&self) -> mpsc::Receiver<RawNetworkEvent> {
+        self.rx.lock().unwrap().take().unwrap()
+    }
+
+    async fn connect(&mut self, _addr: &str) -> issun::error::Result<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> issun::error::Result<()> {
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        true
+    }
+}
+
+/// Frame from another node, as relayed
+fn frame(score: u32, namespace: Option<&str>) -> RawNetworkEvent {
+    let mut metadata = NetworkMetadata::new(NodeId::from_u64(2), score as u64);
+    metadata.namespace = namespace.map(str::to_string);
+    RawNetworkEvent {
+        metadata,
+        scope: NetworkScope::Broadcast,
+        type_name: std::any::type_name::<ScoreChanged>().to_string(),
+        payload: bincode::serialize(&ScoreChanged { score }).unwrap(),
+    }
+}
+
+fn match_bus(namespace: &str, backend: impl NetworkBackend) -> EventBus {
+    let mut bus = EventBus::new()
+        .with_namespace(namespace)
+        .with_network(backend);
+    bus.register_networked_event::<ScoreChanged>();
+    bus
+}
+
+fn scores(bus: &mut EventBus) -> Vec<u32> {
+    bus.poll_network();
+    bus.dispatch();
+    bus.reader::<ScoreChanged>()
+        .iter()
+        .map(|event| event.score)
+        .collect()
+}
+
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+}
+
+#[tokio::test]
+async fn test_buses_sharing_a_backend_receive_their_namespace_only() {
+    let (loopback, relay) = Loopback::new();
+    let sent = loopback.sent.clone();
+    let shared = SharedNetworkBackend::new(loopback);
+    let mut match_a = match_bus("match-a", shared.handle("match-a"));
+    let mut match_b = match_bus("match-b", shared.handle("match-b"));
+
+    relay.send(frame(1, Some("match-a"))).await.unwrap();
+    relay.send(frame(2, Some("match-b"))).await.unwrap();
+    relay.send(frame(3, Some("match-z"))).await.unwrap();
+    // Legacy frames reach every match by default
+    relay.send(frame(4, None)).await.unwrap();
+    settle().await;
+
+    assert_eq!(scores(&mut match_a), [1, 4]);
+    assert_eq!(scores(&mut match_b), [2, 4]);
+    assert_eq!(shared.unrouted(), 1);
+
+    // Outgoing frames carry the namespace; each bus announced its own.
+    // Local delivery is unaffected; dispatching sends the frame
+    match_b.publish(ScoreChanged { score: 5 });
+    assert_eq!(scores(&mut match_b), [5]);
+    settle().await;
+    let sent: Vec<(String, Option<String>)> = sent
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|event| (event.type_name.clone(), event.metadata.namespace.clone()))
+        .collect();
+    let score_type = std::any::type_name::<ScoreChanged>().to_string();
+    assert!(sent.contains(&(NAMESPACE_JOIN.to_string(), Some("match-a".to_string()))));
+    assert!(sent.contains(&(NAMESPACE_JOIN.to_string(), Some("match-b".to_string()))));
+    assert!(sent.contains(&(score_type, Some("match-b".to_string()))));
+}
+
+#[tokio::test]
+async fn test_stats_count_filtered_frames() {
+    let (loopback, relay) = Loopback::new();
+    let mut bus = match_bus("match-a", loopback).with_legacy_frames(LegacyFramePolicy::Ignore);
+
+    relay.send(frame(1, Some("match-a"))).await.unwrap();
+    relay.send(frame(2, Some("match-b"))).await.unwrap();
+    relay.send(frame(3, Some("match-c"))).await.unwrap();
+    relay.send(frame(4, None)).await.unwrap();
+    settle().await;
+
+    assert_eq!(scores(&mut bus), [1]);
+    assert_eq!(
+        bus.stats().network,
+        NetworkFrameStats {
+            received: 4,
+            foreign_namespace: 2,
+            legacy_ignored: 1,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_bus_without_namespace_keeps_every_frame() {
+    let (loopback, relay) = Loopback::new();
+    let mut bus = EventBus::new().with_network(loopback);
+    bus.register_networked_event::<ScoreChanged>();
+    assert_eq!(bus.namespace(), None);
+
+    relay.send(frame(1, Some("match-a"))).await.unwrap();
+    relay.send(frame(2, None)).await.unwrap();
+    settle().await;
+
+    assert_eq!(scores(&mut bus), [1, 2]);
+    assert_eq!(bus.stats().network.received, 2);
+    assert_eq!(bus.stats().network.foreign_namespace, 0);
+}
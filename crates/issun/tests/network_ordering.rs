@@ -0,0 +1,301 @@
+#![cfg(feature = "network")]
+
+//! Ordered rooms: clients deliver relay-stamped events in the room's
+//! sequence order, whatever order the network hands them over in
+
+use async_trait::async_trait;
+use issun::event::{Event, EventBus, Priority};
+use issun::network::backend::RawNetworkEvent;
+use issun::network::{
+    NetworkBackend, NetworkGapDetected, NetworkScope, NodeId, OrderingConfig, RelaySequencer,
+};
+use issun::replay::EventRecorder;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct PaddleMove {
+    id: u32,
+}
+
+impl Event for PaddleMove {
+    fn is_networked() -> bool {
+        true
+    }
+
+    fn network_scope() -> NetworkScope {
+        NetworkScope::Broadcast
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct BallServed {
+    id: u32,
+}
+
+impl Event for BallServed {
+    fn is_networked() -> bool {
+        true
+    }
+
+    fn network_scope() -> NetworkScope {
+        NetworkScope::Broadcast
+    }
+}
+
+/// In-memory stand-in for an ordered relay room
+///
+/// Stamps what it receives like the relay does, and holds it until `flush`
+/// hands it to each member in a different order.
+#[derive(Default)]
+struct MemoryRoom {
+    sequencer: RelaySequencer,
+    members: Vec<mpsc::Sender<RawNetworkEvent>>,
+    stamped: Vec<RawNetworkEvent>,
+    dropped: HashSet<u32>,
+}
+
+impl MemoryRoom {
+    /// Deliver everything stamped so far: in order to the first member,
+    /// reversed to the second, odd sequences first to the others
+    fn flush(&mut self) {
+        let stamped: Vec<_> = std::mem::take(&mut self.stamped)
+            .into_iter()
+            .filter(|event| {
+                !self
+                    .dropped
+                    .contains(&event.metadata.relay.unwrap().sequence)
+            })
+            .collect();
+
+        for (index, member) in self.members.iter().enumerate() {
+            let mut arrival = stamped.clone();
+            match index {
+                0 => {}
+                1 => arrival.reverse(),
+                _ => arrival.sort_by_key(|event| event.metadata.relay.unwrap().sequence % 2 == 0),
+            }
+            for event in arrival {
+                member.try_send(event).unwrap();
+            }
+        }
+    }
+}
+
+struct RoomBackend {
+    node_id: NodeId,
+    room: Arc<Mutex<MemoryRoom>>,
+    rx: Mutex<Option<mpsc::Receiver<RawNetworkEvent>>>,
+}
+
+#[async_trait]
+impl NetworkBackend for RoomBackend {
+    fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+
+    async fn send(&self, mut event: RawNetworkEvent) -> issun::error::Result<()> {
+        let mut room = self.room.lock().unwrap();
+        event.metadata.relay = Some(room.sequencer.stamp());
+        room.stamped.push(event);
+        Ok(())
+    }
+
+    fn receive_stream(&self) -> mpsc::Receiver<RawNetworkEvent> {
+        self.rx.lock().unwrap().take().unwrap()
+    }
+
+    async fn connect(&mut self, _addr: &str) -> issun::error::Result<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> issun::error::Result<()> {
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        true
+    }
+}
+
+struct Member {
+    bus: EventBus,
+    recorder: Arc<Mutex<EventRecorder>>,
+}
+
+impl Member {
+    fn join(room: &Arc<Mutex<MemoryRoom>>, id: u64, gap_window: Duration) -> Self {
+        let (tx, rx) = mpsc::channel(64);
+        room.lock().unwrap().members.push(tx);
+        let backend = RoomBackend {
+            node_id: NodeId::from_u64(id),
+            room: room.clone(),
+            rx: Mutex::new(Some(rx)),
+        };
+
+        let mut bus = EventBus::new()
+            .with_network(backend)
+            .with_ordered_delivery(OrderingConfig {
+                gap_window,
+                ..OrderingConfig::default()
+            });
+        bus.register_networked_event::<PaddleMove>();
+        bus.register_networked_event::<BallServed>();
+
+        let mut recorder = EventRecorder::new();
+        recorder.start();
+        let recorder = Arc::new(Mutex::new(recorder));
+        bus.set_recorder(recorder.clone());
+
+        Self { bus, recorder }
+    }
+
+    /// (type, id) of every event that reached the bus, in delivery order
+    fn delivered(&self) -> Vec<(String, u32)> {
+        self.recorder
+            .lock()
+            .unwrap()
+            .recordings()
+            .iter()
+            .map(|recorded| {
+                let id = if recorded.event_type.ends_with("PaddleMove") {
+                    bincode::deserialize::<PaddleMove>(&recorded.payload)
+                        .unwrap()
+                        .id
+                } else {
+                    bincode::deserialize::<BallServed>(&recorded.payload)
+                        .unwrap()
+                        .id
+                };
+                let name = recorded.event_type.rsplit("::").next().unwrap();
+                (name.to_string(), id)
+            })
+            .collect()
+    }
+}
+
+/// Wait until the send workers handed `count` events to the room
+async fn wait_for_stamped(room: &Arc<Mutex<MemoryRoom>>, count: usize) {
+    for _ in 0..100 {
+        if room.lock().unwrap().stamped.len() >= count {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("relay received fewer than {} events", count);
+}
+
+#[tokio::test]
+async fn test_members_observe_identical_order() {
+    let room = Arc::new(Mutex::new(MemoryRoom::default()));
+    let window = Duration::from_millis(200);
+    let mut left = Member::join(&room, 1, window);
+    let mut right = Member::join(&room, 2, window);
+    let mut spectator = Member::join(&room, 3, window);
+
+    left.bus.publish(PaddleMove { id: 1 });
+    right.bus.publish(PaddleMove { id: 2 });
+    left.bus.publish(BallServed { id: 3 });
+    right.bus.publish(PaddleMove { id: 4 });
+    left.bus.publish(PaddleMove { id: 5 });
+    // Networked events are sent on dispatch
+    left.bus.dispatch();
+    right.bus.dispatch();
+    wait_for_stamped(&room, 5).await;
+
+    // Own broadcasts wait for the relay's echo
+    left.bus.dispatch();
+    assert!(left.bus.reader::<PaddleMove>().is_empty());
+    assert!(left.delivered().is_empty());
+
+    room.lock().unwrap().flush();
+    for member in [&mut left, &mut right, &mut spectator] {
+        member.bus.poll_network();
+    }
+
+    let order = left.delivered();
+    assert_eq!(order.len(), 5);
+    assert_eq!(right.delivered(), order);
+    assert_eq!(spectator.delivered(), order);
+
+    left.bus.dispatch();
+    assert_eq!(left.bus.reader::<PaddleMove>().len(), 4);
+    assert!(left.bus.reader::<NetworkGapDetected>().is_empty());
+}
+
+#[tokio::test]
+async fn test_dropped_frame_reported_after_window() {
+    let room = Arc::new(Mutex::new(MemoryRoom::default()));
+    let window = Duration::from_millis(50);
+    let mut player = Member::join(&room, 1, window);
+    let mut spectator = Member::join(&room, 2, window);
+    room.lock().unwrap().dropped.insert(1);
+
+    for id in 0..4 {
+        player.bus.publish(PaddleMove { id });
+    }
+    player.bus.dispatch();
+    wait_for_stamped(&room, 4).await;
+    room.lock().unwrap().flush();
+
+    // The spectator gets 3, 2, 0 (reversed): only 0 can be delivered
+    spectator.bus.poll_network();
+    assert_eq!(spectator.delivered().len(), 1);
+    spectator.bus.dispatch();
+    assert!(spectator.bus.reader::<NetworkGapDetected>().is_empty());
+
+    tokio::time::sleep(window + Duration::from_millis(20)).await;
+    spectator.bus.poll_network();
+    spectator.bus.dispatch();
+
+    assert_eq!(
+        spectator
+            .bus
+            .reader::<NetworkGapDetected>()
+            .iter()
+            .collect::<Vec<_>>(),
+        [&NetworkGapDetected {
+            expected: 1,
+            resumed_at: 2,
+            missing: 1
+        }]
+    );
+    let ids: Vec<_> = spectator
+        .bus
+        .reader::<PaddleMove>()
+        .iter()
+        .map(|event| event.id)
+        .collect();
+    assert_eq!(ids, [2, 3]);
+}
+
+#[tokio::test]
+async fn test_dispatch_sends_lane_by_lane() {
+    let room = Arc::new(Mutex::new(MemoryRoom::default()));
+    let mut player = Member::join(&room, 1, Duration::from_millis(200));
+
+    player
+        .bus
+        .publish_with_priority(PaddleMove { id: 1 }, Priority::Low);
+    player.bus.publish(PaddleMove { id: 2 });
+    player
+        .bus
+        .publish_with_priority(BallServed { id: 3 }, Priority::High);
+    assert!(room.lock().unwrap().stamped.is_empty());
+
+    player.bus.dispatch();
+    wait_for_stamped(&room, 3).await;
+
+    let sent: Vec<(String, u64)> = room
+        .lock()
+        .unwrap()
+        .stamped
+        .iter()
+        .map(|event| (event.type_name.clone(), event.metadata.sequence))
+        .collect();
+    let paddle = std::any::type_name::<PaddleMove>().to_string();
+    let ball = std::any::type_name::<BallServed>().to_string();
+    assert_eq!(sent, vec![(ball, 0), (paddle.clone(), 1), (paddle, 2)]);
+}
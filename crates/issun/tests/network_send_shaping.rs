@@ -0,0 +1,218 @@
+#![cfg(feature = "network")]
+
+//! Client-side send shaping: per-type send rates and an outgoing byte budget
+
+use async_trait::async_trait;
+use issun::event::{Event, EventBus};
+use issun::network::backend::RawNetworkEvent;
+use issun::network::{Delivery, NetworkBackend, NodeId};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct CursorMoved {
+    frame: u32,
+}
+
+impl Event for CursorMoved {
+    fn is_networked() -> bool {
+        true
+    }
+
+    fn is_latest_only() -> bool {
+        true
+    }
+
+    fn delivery() -> Delivery {
+        Delivery::Unreliable
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct PaddleMove {
+    y: u32,
+}
+
+impl Event for PaddleMove {
+    fn is_networked() -> bool {
+        true
+    }
+
+    fn delivery() -> Delivery {
+        Delivery::Unreliable
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct GoalScored {
+    frame: u32,
+}
+
+impl Event for GoalScored {
+    fn is_networked() -> bool {
+        true
+    }
+}
+
+/// Records what is sent
+struct Loopback {
+    sent: Arc<Mutex<Vec<RawNetworkEvent>>>,
+    rx: Mutex<Option<mpsc::Receiver<RawNetworkEvent>>>,
+}
+
+#[async_trait]
+impl NetworkBackend for Loopback {
+    fn node_id(&self) -> NodeId {
+        NodeId::from_u64(1)
+    }
+
+    async fn send(&self, event: RawNetworkEvent) -> issun::error::Result<()> {
+        self.sent.lock().unwrap().push(event);
+        Ok(())
+    }
+
+    fn receive_stream(&self) -> mpsc::Receiver<RawNetworkEvent> {
+        self.rx.lock().unwrap().take().unwrap()
+    }
+
+    async fn connect(&mut self, _addr: &str) -> issun::error::Result<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> issun::error::Result<()> {
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        true
+    }
+}
+
+fn client() -> (EventBus, Arc<Mutex<Vec<RawNetworkEvent>>>) {
+    let (_relay, rx) = mpsc::channel(64);
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let backend = Loopback {
+        sent: sent.clone(),
+        rx: Mutex::new(Some(rx)),
+    };
+    (EventBus::new().with_network(backend), sent)
+}
+
+/// Sent events of type `E`, decoded
+fn sent_of<E: serde::de::DeserializeOwned>(sent: &Mutex<Vec<RawNetworkEvent>>) -> Vec<E> {
+    let type_name = std::any::type_name::<E>();
+    sent.lock()
+        .unwrap()
+        .iter()
+        .filter(|event| event.type_name == type_name)
+        .map(|event| bincode::deserialize(&event.payload).unwrap())
+        .collect()
+}
+
+/// Start of the `frame`th 60 Hz tick
+fn tick(start: Instant, frame: u32) -> Instant {
+    start + Duration::from_micros(u64::from(frame) * 16_667)
+}
+
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+}
+
+#[tokio::test]
+async fn test_sixty_hz_publisher_limited_to_ten_hz() {
+    let (mut bus, sent) = client();
+    bus.set_send_rate::<CursorMoved>(10.0);
+    let start = Instant::now();
+
+    for frame in 0..60 {
+        bus.publish(CursorMoved { frame });
+        bus.dispatch_at(tick(start, frame));
+    }
+    settle().await;
+
+    let frames: Vec<u32> = sent_of::<CursorMoved>(&sent)
+        .iter()
+        .map(|e| e.frame)
+        .collect();
+    assert_eq!(frames, [0, 6, 12, 18, 24, 30, 36, 42, 48, 54]);
+    // Replaced while waiting; the last value is still held
+    assert_eq!(bus.stats().network_send.rate_limited, 49);
+
+    // The latest value wins: the last publish goes out in the next slot
+    bus.dispatch_at(tick(start, 60));
+    settle().await;
+    let last = sent_of::<CursorMoved>(&sent).pop().unwrap();
+    assert_eq!(last.frame, 59);
+
+    // Local readers are not throttled
+    bus.publish(CursorMoved { frame: 61 });
+    bus.dispatch_at(tick(start, 61));
+    assert_eq!(bus.reader::<CursorMoved>().len(), 1);
+}
+
+#[tokio::test]
+async fn test_send_rate_is_adjustable_at_runtime() {
+    let (mut bus, sent) = client();
+    bus.set_send_rate::<CursorMoved>(10.0);
+    let start = Instant::now();
+
+    for frame in 0..60 {
+        if frame == 30 {
+            // Settings slider moved up
+            bus.set_send_rate::<CursorMoved>(30.0);
+        }
+        bus.publish(CursorMoved { frame });
+        bus.dispatch_at(tick(start, frame));
+    }
+    settle().await;
+
+    let frames: Vec<u32> = sent_of::<CursorMoved>(&sent)
+        .iter()
+        .map(|e| e.frame)
+        .collect();
+    assert_eq!(
+        frames,
+        [0, 6, 12, 18, 24, 30, 32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58]
+    );
+    assert_eq!(bus.send_rate::<CursorMoved>(), Some(30.0));
+
+    bus.clear_send_rate::<CursorMoved>();
+    bus.publish(CursorMoved { frame: 60 });
+    bus.dispatch_at(tick(start, 60));
+    bus.publish(CursorMoved { frame: 61 });
+    bus.dispatch_at(tick(start, 60));
+    settle().await;
+    assert_eq!(sent_of::<CursorMoved>(&sent).len(), 22);
+}
+
+#[tokio::test]
+async fn test_tight_budget_keeps_reliable_events_and_sheds_unreliable_ones() {
+    let (mut bus, sent) = client();
+    // Room for a handful of frames per second
+    bus.set_send_budget(Some(400));
+    let start = Instant::now();
+
+    for frame in 0..60 {
+        for y in 0..20 {
+            bus.publish(PaddleMove { y });
+        }
+        if frame % 10 == 0 {
+            bus.publish(GoalScored { frame });
+        }
+        bus.dispatch_at(tick(start, frame));
+    }
+    settle().await;
+
+    let goals: Vec<u32> = sent_of::<GoalScored>(&sent)
+        .iter()
+        .map(|e| e.frame)
+        .collect();
+    assert_eq!(goals, [0, 10, 20, 30, 40, 50]);
+
+    let stats = bus.stats().network_send;
+    let paddles = sent_of::<PaddleMove>(&sent).len() as u64;
+    assert!(paddles < 1200 / 10, "sent {paddles} paddle moves");
+    assert_eq!(paddles + stats.shed, 1200);
+    assert_eq!(stats.sent, paddles + 6);
+}
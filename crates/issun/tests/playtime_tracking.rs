@@ -0,0 +1,258 @@
+//! Playtime accumulated on a fake clock the way the runners do, one
+//! `record` per frame
+
+use issun::context::{ResourceContext, ServiceContext};
+use issun::engine::{
+    IdleDetected, PlaytimeProfile, PlaytimeStats, PlaytimeTracker, SimulationControl,
+    PLAYTIME_METRIC,
+};
+use issun::event::EventBus;
+use issun::plugin::metrics::{DefineMetricRequested, MetricsRegistry, RecordMetricRequested};
+use issun::plugin::save_load::{
+    DefaultSaveLoadHook, GameSaved, LoadGameRequested, MissingModPolicy, ModVersionPolicy,
+    SaveFormat, SaveGameRequested, SaveLoadConfig, SaveLoadSystem,
+};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const FRAME: Duration = Duration::from_millis(100);
+
+struct Sim {
+    resources: ResourceContext,
+    tracker: PlaytimeTracker,
+    now: Instant,
+    idle_events: Vec<IdleDetected>,
+}
+
+impl Sim {
+    fn new(tracker: PlaytimeTracker) -> Self {
+        let mut resources = ResourceContext::new();
+        resources.insert(EventBus::new());
+        resources.insert(PlaytimeStats::default());
+        Self {
+            resources,
+            tracker,
+            now: Instant::now(),
+            idle_events: Vec::new(),
+        }
+    }
+
+    async fn run_in(&mut self, scene: &str, duration: Duration) {
+        let end = self.now + duration;
+        while self.now < end {
+            self.now += FRAME;
+            self.tracker
+                .record(Some(scene), self.now, &self.resources)
+                .await;
+            let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+            bus.dispatch();
+            self.idle_events
+                .extend(bus.reader::<IdleDetected>().iter().cloned());
+        }
+    }
+
+    async fn stats(&self) -> PlaytimeStats {
+        self.resources.get::<PlaytimeStats>().await.unwrap().clone()
+    }
+}
+
+#[tokio::test]
+async fn test_playtime_is_split_by_scene() {
+    let mut sim = Sim::new(PlaytimeTracker::new());
+    // The first frame only starts the clock
+    sim.tracker
+        .record(Some("Title"), sim.now, &sim.resources)
+        .await;
+
+    sim.run_in("Title", Duration::from_secs(2)).await;
+    sim.run_in("GameScene::Combat", Duration::from_secs(3))
+        .await;
+    sim.run_in("Title", Duration::from_millis(500)).await;
+
+    let stats = sim.stats().await;
+    assert_eq!(stats.scene("Title"), Duration::from_millis(2500));
+    assert_eq!(stats.scene("GameScene::Combat"), Duration::from_secs(3));
+    assert_eq!(stats.scene("Shop"), Duration::ZERO);
+    assert_eq!(stats.total(), Duration::from_millis(5500));
+    assert_eq!(sim.tracker.current_session(), stats.total());
+}
+
+#[tokio::test]
+async fn test_paused_and_idle_time_are_excluded() {
+    let tracker = PlaytimeTracker::new().with_idle_timeout(Some(Duration::from_secs(2)));
+    let mut sim = Sim::new(tracker);
+    sim.resources.insert(SimulationControl::new());
+    sim.tracker.note_input(sim.now);
+    sim.tracker
+        .record(Some("Map"), sim.now, &sim.resources)
+        .await;
+
+    sim.run_in("Map", Duration::from_secs(1)).await;
+    sim.resources
+        .get_mut::<SimulationControl>()
+        .await
+        .unwrap()
+        .pause();
+    sim.run_in("Map", Duration::from_secs(4)).await;
+    sim.resources
+        .get_mut::<SimulationControl>()
+        .await
+        .unwrap()
+        .resume();
+    sim.tracker.note_input(sim.now);
+
+    // No input: idle from the timeout on
+    sim.run_in("Map", Duration::from_secs(5)).await;
+    assert_eq!(
+        sim.idle_events,
+        [IdleDetected {
+            idle_for: Duration::from_secs(2)
+        }]
+    );
+    assert!(sim.tracker.is_idle());
+
+    sim.tracker.note_input(sim.now);
+    sim.run_in("Map", Duration::from_secs(1)).await;
+    assert!(!sim.tracker.is_idle());
+
+    let stats = sim.stats().await;
+    assert_eq!(stats.paused, Duration::from_secs(4));
+    assert_eq!(stats.idle, Duration::from_secs(3));
+    // 1s before the pause, 2s until idle, 1s after the input
+    assert_eq!(stats.scene("Map"), Duration::from_secs(4));
+    assert_eq!(sim.idle_events.len(), 1);
+}
+
+#[tokio::test]
+async fn test_playtime_metric_is_recorded_periodically() {
+    let tracker = PlaytimeTracker::new().with_metrics_interval(Duration::from_secs(1));
+    let mut sim = Sim::new(tracker);
+    sim.resources.insert(MetricsRegistry::default());
+
+    let mut defined = 0;
+    let mut recorded = Vec::new();
+    for _ in 0..25 {
+        sim.now += FRAME;
+        sim.tracker
+            .record(Some("Map"), sim.now, &sim.resources)
+            .await;
+        let mut bus = sim.resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        defined += bus.reader::<DefineMetricRequested>().len();
+        recorded.extend(
+            bus.reader::<RecordMetricRequested>()
+                .iter()
+                .map(|request| request.value.clone()),
+        );
+    }
+
+    assert_eq!(defined, 1);
+    assert_eq!(recorded.len(), 3);
+    assert!(recorded
+        .iter()
+        .all(|value| value.metric_id.as_str() == PLAYTIME_METRIC));
+    assert!((recorded[2].value - 2.0).abs() < 1e-9, "{:?}", recorded);
+}
+
+#[tokio::test]
+async fn test_save_round_trip_preserves_totals_and_rolls_up_the_profile() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut system = SaveLoadSystem::new(
+        Arc::new(DefaultSaveLoadHook),
+        SaveLoadConfig {
+            save_directory: dir.path().to_path_buf(),
+            format: SaveFormat::Json,
+            enable_auto_save: false,
+            auto_save_interval: 0,
+            missing_mods: MissingModPolicy::Warn,
+            mod_versions: ModVersionPolicy::Warn,
+        },
+    );
+    system.ensure_repository().await.unwrap();
+
+    let mut sim = Sim::new(PlaytimeTracker::new());
+    sim.resources.insert(PlaytimeProfile::default());
+    sim.tracker
+        .record(Some("Map"), sim.now, &sim.resources)
+        .await;
+    sim.run_in("Map", Duration::from_secs(90)).await;
+    sim.run_in("Combat", Duration::from_secs(30)).await;
+    // Runners keep the tracker as a resource, where saving finds it
+    sim.resources.insert(sim.tracker.clone());
+
+    publish(
+        &mut sim.resources,
+        SaveGameRequested {
+            slot: "slot1".to_string(),
+            label: None,
+        },
+    )
+    .await;
+    system
+        .process_events(&ServiceContext::new(), &mut sim.resources)
+        .await;
+    let saved = {
+        let mut bus = sim.resources.get_mut::<EventBus>().await.unwrap();
+        bus.dispatch();
+        let saved = bus.reader::<GameSaved>().iter().next().cloned().unwrap();
+        saved
+    };
+    assert_eq!(saved.metadata.play_time_secs, 120);
+
+    let profile = sim
+        .resources
+        .get::<PlaytimeProfile>()
+        .await
+        .unwrap()
+        .clone();
+    assert_eq!(profile.total(), Duration::from_secs(120));
+    assert_eq!(profile.scene("Combat"), Duration::from_secs(30));
+
+    // A second save only adds the time played since the first
+    let saved_stats = sim.stats().await;
+    {
+        let mut tracker = sim.resources.get_mut::<PlaytimeTracker>().await.unwrap();
+        let now = sim.now + Duration::from_secs(10);
+        tracker.record(Some("Combat"), now, &sim.resources).await;
+    }
+    publish(
+        &mut sim.resources,
+        SaveGameRequested {
+            slot: "slot2".to_string(),
+            label: None,
+        },
+    )
+    .await;
+    system
+        .process_events(&ServiceContext::new(), &mut sim.resources)
+        .await;
+    let profile = sim
+        .resources
+        .get::<PlaytimeProfile>()
+        .await
+        .unwrap()
+        .clone();
+    assert_eq!(profile.total(), Duration::from_secs(130));
+
+    // Loading slot1 brings back its stats
+    publish(
+        &mut sim.resources,
+        LoadGameRequested {
+            slot: "slot1".to_string(),
+        },
+    )
+    .await;
+    system
+        .process_events(&ServiceContext::new(), &mut sim.resources)
+        .await;
+    assert_eq!(sim.stats().await, saved_stats);
+}
+
+async fn publish<E: issun::event::Event + serde::Serialize>(
+    resources: &mut ResourceContext,
+    event: E,
+) {
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.publish(event);
+    bus.dispatch();
+}
@@ -105,3 +105,148 @@ async fn test_derived_plugin_registers_config() {
     let registered_config = game.resources.get::<TestConfig>().await.unwrap();
     assert_eq!(registered_config.value, 200);
 }
+
+/// Service that needs configuration (no `Default`)
+#[derive(Clone, issun_macros::Service)]
+struct DamageService {
+    multiplier: i32,
+}
+
+impl DamageService {
+    fn new(multiplier: i32) -> Self {
+        Self { multiplier }
+    }
+}
+
+#[derive(Default, issun_macros::System)]
+struct TickSystem;
+
+#[derive(Debug, Default, PartialEq)]
+struct TurnCounter(u32);
+
+trait AlertHook: Send + Sync {
+    fn threshold(&self) -> u32;
+}
+
+#[derive(Default)]
+struct QuietHook;
+
+impl AlertHook for QuietHook {
+    fn threshold(&self) -> u32 {
+        10
+    }
+}
+
+struct LoudHook(u32);
+
+impl AlertHook for LoudHook {
+    fn threshold(&self) -> u32 {
+        self.0
+    }
+}
+
+fn config_for_test() -> TestConfig {
+    TestConfig { value: 7 }
+}
+
+#[derive(issun_macros::Plugin)]
+#[plugin(name = "constructed_plugin")]
+#[plugin(service = "DamageService::new(3)")]
+#[plugin(system = TickSystem)]
+#[plugin(state = "TurnCounter(5)")]
+#[plugin(resource = "config_for_test()")]
+#[plugin(hook = QuietHook)]
+#[plugin(hook = "std::sync::Arc::new(LoudHook(3)) as std::sync::Arc<dyn AlertHook>")]
+struct ConstructedPlugin;
+
+#[tokio::test]
+async fn test_attribute_expressions_construct_components() {
+    let game = GameBuilder::new()
+        .with_plugin(ConstructedPlugin)
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    let service = game
+        .services
+        .get_as::<DamageService>("damage_service")
+        .unwrap();
+    assert_eq!(service.multiplier, 3);
+    assert!(game.systems.get::<TickSystem>().is_some());
+    assert_eq!(
+        *game.resources.get::<TurnCounter>().await.unwrap(),
+        TurnCounter(5)
+    );
+    assert_eq!(game.resources.get::<TestConfig>().await.unwrap().value, 7);
+}
+
+#[tokio::test]
+async fn test_hook_attribute_registers_hook() {
+    use issun::plugin::Hook;
+
+    let game = GameBuilder::new()
+        .with_plugin(ConstructedPlugin)
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    let quiet = game.resources.get::<Hook<QuietHook>>().await.unwrap();
+    assert_eq!(quiet.threshold(), 10);
+    let loud = game.resources.get::<Hook<dyn AlertHook>>().await.unwrap();
+    assert_eq!(loud.get().threshold(), 3);
+}
+
+/// Keeps the last `capacity` entries; useless at capacity zero
+#[derive(Debug, issun_macros::Resource)]
+#[resource(init = "RecentLog::with_capacity(3)")]
+struct RecentLog {
+    entries: std::collections::VecDeque<String>,
+    capacity: usize,
+}
+
+impl RecentLog {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: std::collections::VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+}
+
+#[derive(issun_macros::Plugin)]
+#[plugin(name = "init_plugin")]
+#[plugin(state = RecentLog)]
+#[plugin(resource = TestConfig)]
+struct InitPlugin;
+
+#[tokio::test]
+async fn test_bare_types_use_resource_init() {
+    let game = GameBuilder::new()
+        .with_plugin(InitPlugin)
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    let log = game.resources.get::<RecentLog>().await.unwrap();
+    assert_eq!(log.capacity, 3);
+    assert!(log.entries.is_empty());
+    // Types without `init` still start as their Default
+    assert_eq!(game.resources.get::<TestConfig>().await.unwrap().value, 42);
+}
+
+#[tokio::test]
+async fn test_builder_registers_resource_init() {
+    use issun::resources::ResourceInit;
+
+    let game = GameBuilder::new()
+        .with_default_resource::<RecentLog>()
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(game.resources.get::<RecentLog>().await.unwrap().capacity, 3);
+    assert_eq!(RecentLog::__issun_default_init().capacity, 3);
+    assert_eq!(RecentLog::init_resource().capacity, 3);
+}
@@ -0,0 +1,233 @@
+//! Heap allocations of the `#[event_handler]` pump path, counted by a
+//! global allocator
+//!
+//! Counts are per thread; every test drives its pump on a current-thread
+//! runtime so nothing else lands in its count. The first ticks are not
+//! counted: buffers grow to size there, and the runtime's own bookkeeping
+//! (e.g. yielding when a task's budget runs out) may allocate once.
+
+/// Ticks run before counting starts
+const WARMUP: usize = 20;
+
+use issun::context::{ResourceContext, ServiceContext};
+use issun::event::{Event, EventBus};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct Moved {
+    entity: u32,
+    x: i32,
+}
+
+impl Event for Moved {}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct Chat {
+    text: String,
+}
+
+impl Event for Chat {}
+
+macro_rules! pump {
+    ($($field:ident: $system:ident),* $(,)?) => {
+        $(
+            #[derive(Default)]
+            struct $system {
+                moved: i64,
+                chatted: usize,
+            }
+
+            #[issun::event_handler]
+            impl $system {
+                #[subscribe(Moved)]
+                async fn on_moved(&mut self, event: &Moved) {
+                    self.moved += i64::from(event.x) + i64::from(event.entity);
+                }
+
+                #[subscribe(Chat)]
+                async fn on_chat(&mut self, event: &Chat) {
+                    self.chatted += event.text.len();
+                }
+            }
+        )*
+
+        /// Twenty systems updated in order, like a runner's pump
+        #[derive(Default)]
+        struct Pump {
+            $($field: $system),*
+        }
+
+        impl Pump {
+            async fn tick(&mut self, services: &ServiceContext, resources: &mut ResourceContext) {
+                $(self.$field.process_events(services, resources).await;)*
+            }
+
+            fn chatted(&self) -> usize {
+                0 $(+ self.$field.chatted)*
+            }
+        }
+    };
+}
+
+pump! {
+    s0: System0, s1: System1, s2: System2, s3: System3, s4: System4,
+    s5: System5, s6: System6, s7: System7, s8: System8, s9: System9,
+    s10: System10, s11: System11, s12: System12, s13: System13, s14: System14,
+    s15: System15, s16: System16, s17: System17, s18: System18, s19: System19,
+}
+
+/// Publishes `publish` events per tick; returns allocations made while the
+/// pump handled the last `measured` ticks
+async fn measure(publish: usize, measured: usize) -> (usize, Pump) {
+    let services = ServiceContext::new();
+    let mut resources = ResourceContext::new();
+    resources.insert(EventBus::new());
+    let mut pump = Pump::default();
+
+    let mut counted = 0;
+    for tick in 0..measured + WARMUP {
+        {
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+            for i in 0..publish {
+                bus.publish(Moved {
+                    entity: i as u32,
+                    x: tick as i32,
+                });
+                bus.publish(Chat {
+                    text: format!("hello {i}"),
+                });
+            }
+            bus.dispatch();
+        }
+
+        let before = allocations();
+        pump.tick(&services, &mut resources).await;
+        if tick >= WARMUP {
+            counted += allocations() - before;
+        }
+    }
+    (counted, pump)
+}
+
+/// What the pump cost when every system cloned the events into fresh Vecs
+fn cloned_collection_allocations(bus: &mut EventBus, systems: usize) -> usize {
+    let before = allocations();
+    for _ in 0..systems {
+        let moved: Vec<Moved> = bus.reader::<Moved>().iter().cloned().collect();
+        let chat: Vec<Chat> = bus.reader::<Chat>().iter().cloned().collect();
+        std::hint::black_box((moved, chat));
+    }
+    allocations() - before
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_idle_pump_does_not_allocate() {
+    let (allocated, _) = measure(0, 60).await;
+    assert_eq!(allocated, 0, "idle pump allocated over 60 ticks");
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_busy_pump_handles_events_by_reference() {
+    let (allocated, pump) = measure(16, 60).await;
+    // "hello 0".."hello 15" over every tick, seen by every system
+    assert_eq!(pump.chatted(), 20 * (60 + WARMUP) * (10 * 7 + 6 * 8));
+
+    let mut bus = EventBus::new();
+    for i in 0..16 {
+        bus.publish(Moved { entity: i, x: 0 });
+        bus.publish(Chat {
+            text: format!("hello {i}"),
+        });
+    }
+    bus.dispatch();
+    let cloned_per_tick = cloned_collection_allocations(&mut bus, 20);
+
+    assert!(cloned_per_tick >= 20 * 18);
+    assert!(
+        allocated * 10 < cloned_per_tick * 60,
+        "{allocated} allocations over 60 ticks, cloning took {cloned_per_tick} per tick"
+    );
+}
+
+#[derive(Default)]
+struct Relay {
+    relayed: usize,
+}
+
+#[issun::event_handler]
+impl Relay {
+    #[subscribe(Moved)]
+    async fn relay(&mut self, event: &Moved, #[state] bus: &mut EventBus) {
+        self.relayed += 1;
+        bus.publish(Chat {
+            text: String::new(),
+        });
+        let _ = event.entity;
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_handler_taking_the_bus_reuses_snapshot_buffers() {
+    let services = ServiceContext::new();
+    let mut resources = ResourceContext::new();
+    resources.insert(EventBus::new());
+    let mut relay = Relay::default();
+
+    let mut counted = 0;
+    for tick in 0..30 + WARMUP {
+        {
+            let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+            for entity in 0..8 {
+                bus.publish(Moved {
+                    entity,
+                    x: tick as i32,
+                });
+            }
+            bus.dispatch();
+        }
+        let before = allocations();
+        relay.process_events(&services, &mut resources).await;
+        if tick >= WARMUP {
+            counted += allocations() - before;
+        }
+    }
+
+    assert_eq!(relay.relayed, 8 * (30 + WARMUP));
+    assert_eq!(counted, 0);
+
+    let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+    bus.dispatch();
+    assert_eq!(bus.read::<Chat>().len(), 8);
+    #[cfg(debug_assertions)]
+    assert_eq!(bus.stats().allocations.snapshots, 1);
+}
@@ -0,0 +1,71 @@
+//! ResourceContext locks are per resource: a guard held on one resource
+//! never delays acquisitions of another
+//!
+//! A zero timeout still polls the lock once, so it succeeds exactly when
+//! the resource is free and the tests need no wall-clock thresholds.
+
+use issun::context::ResourceContext;
+use issun::error::IssunError;
+use std::time::Duration;
+
+struct Ledger(u64);
+struct Market(u64);
+
+fn resources() -> ResourceContext {
+    let mut resources = ResourceContext::new();
+    resources.insert(Ledger(0));
+    resources.insert(Market(0));
+    resources
+}
+
+#[tokio::test]
+async fn test_different_keys_do_not_block_each_other() {
+    let resources = resources();
+
+    let mut ledger = resources.get_mut::<Ledger>().await.unwrap();
+    ledger.0 += 1;
+
+    // Market is free for readers and writers while the Ledger writer is alive
+    for _ in 0..100 {
+        let mut market = resources
+            .try_get_mut_timeout::<Market>(Duration::ZERO)
+            .await
+            .expect("Market must not wait on the Ledger writer");
+        market.0 += 1;
+    }
+    let market = resources
+        .try_get_timeout::<Market>(Duration::ZERO)
+        .await
+        .expect("Market must not wait on the Ledger writer");
+    assert_eq!(market.0, 100);
+    drop(market);
+
+    drop(ledger);
+    assert_eq!(resources.get::<Ledger>().await.unwrap().0, 1);
+}
+
+#[tokio::test]
+async fn test_same_key_writer_times_out_reader() {
+    let resources = resources();
+
+    let ledger = resources.get_mut::<Ledger>().await.unwrap();
+
+    // The writer is still alive: error, not a stall
+    let read = resources
+        .try_get_timeout::<Ledger>(Duration::from_millis(5))
+        .await;
+    match read {
+        Err(IssunError::ResourceTimedOut { resource, waited }) => {
+            assert!(resource.ends_with("Ledger"));
+            assert_eq!(waited, Duration::from_millis(5));
+        }
+        Err(other) => panic!("unexpected error: {}", other),
+        Ok(_) => panic!("read the Ledger while its writer held it"),
+    }
+
+    drop(ledger);
+    assert!(resources
+        .try_get_timeout::<Ledger>(Duration::ZERO)
+        .await
+        .is_ok());
+}
@@ -0,0 +1,210 @@
+//! SceneTransition::Restart: reset handlers run, everything else survives
+
+use issun::context::{ResourceContext, ServiceContext, SystemContext};
+use issun::event::EventBus;
+use issun::plugin::{
+    ActionConfig, ActionPlugin, ActionPoints, BuiltInTimePlugin, GameTimer, Plugin, PluginBuilder,
+    PluginBuilderExt, TimeConfig,
+};
+use issun::prelude::GameBuilder;
+use issun::scene::{ResetToInitial, RunRestarted, Scene, SceneDirector, SceneTransition};
+
+/// Run score; reset by the game
+#[derive(Debug, Clone, PartialEq)]
+struct Score(u32);
+
+/// Lifetime statistics; no handler, must survive restarts
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Profile {
+    turns_played: u32,
+    runs_finished: u32,
+}
+
+/// State of a plugin that registers no reset handler
+#[derive(Debug, Clone, PartialEq)]
+struct SandboxState(u32);
+
+struct SandboxPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for SandboxPlugin {
+    fn name(&self) -> &'static str {
+        "sandbox"
+    }
+
+    fn build(&self, builder: &mut dyn PluginBuilder) {
+        builder.register_runtime_state(SandboxState(0));
+    }
+}
+
+/// Plays `turns` turns, then asks for a new run
+#[derive(Debug, PartialEq)]
+struct RunScene {
+    turns: u32,
+}
+
+#[async_trait::async_trait]
+impl Scene for RunScene {
+    async fn on_exit(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) {
+        resources.get_mut::<Profile>().await.unwrap().runs_finished += 1;
+    }
+
+    async fn on_update(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) -> SceneTransition<Self> {
+        if self.turns == 0 {
+            return SceneTransition::Restart(None);
+        }
+        self.turns -= 1;
+
+        resources
+            .get_mut::<GameTimer>()
+            .await
+            .unwrap()
+            .increment_day();
+        resources
+            .get_mut::<ActionPoints>()
+            .await
+            .unwrap()
+            .consume_with("turn")
+            .unwrap();
+        resources.get_mut::<Score>().await.unwrap().0 += 10;
+        resources.get_mut::<SandboxState>().await.unwrap().0 += 1;
+        resources.get_mut::<Profile>().await.unwrap().turns_played += 1;
+        SceneTransition::Stay
+    }
+}
+
+#[tokio::test]
+async fn test_restart_resets_registered_state_only() {
+    let game = GameBuilder::new()
+        .with_plugin(BuiltInTimePlugin::new(TimeConfig {
+            initial_day: 5,
+            ..TimeConfig::default()
+        }))
+        .unwrap()
+        .with_plugin(ActionPlugin::new(ActionConfig { max_per_period: 4 }))
+        .unwrap()
+        .with_plugin(SandboxPlugin)
+        .unwrap()
+        .with_resource(Score(100))
+        .with_resource(Profile::default())
+        .with_reset_handler("game", ResetToInitial::<Score>::new())
+        .build()
+        .await
+        .unwrap();
+
+    let mut director = SceneDirector::new(
+        RunScene { turns: 3 },
+        game.services,
+        game.systems,
+        game.resources,
+    )
+    .await
+    .with_restart_scene(|| RunScene { turns: 3 });
+
+    let initial_timer = director
+        .resources()
+        .get::<GameTimer>()
+        .await
+        .unwrap()
+        .clone();
+    assert_eq!(initial_timer.day, 5);
+
+    // Three turns, then the scene requests a restart
+    for _ in 0..4 {
+        let transition = director.update().await;
+        director.handle(transition).await.unwrap();
+    }
+    assert_eq!(director.current(), Some(&RunScene { turns: 3 }));
+
+    let resources = director.resources();
+    let timer = resources.get::<GameTimer>().await.unwrap();
+    assert_eq!(timer.day, initial_timer.day);
+    assert_eq!(timer.tick, initial_timer.tick);
+    drop(timer);
+    let points = resources.get::<ActionPoints>().await.unwrap();
+    assert_eq!((points.available, points.max_per_period), (4, 4));
+    drop(points);
+    assert_eq!(*resources.get::<Score>().await.unwrap(), Score(100));
+
+    // Unregistered state and lifetime stats carry over
+    assert_eq!(
+        *resources.get::<SandboxState>().await.unwrap(),
+        SandboxState(3)
+    );
+    assert_eq!(
+        *resources.get::<Profile>().await.unwrap(),
+        Profile {
+            turns_played: 3,
+            runs_finished: 1,
+        }
+    );
+
+    let restarted: Vec<RunRestarted> = {
+        let mut bus = director
+            .resources_mut()
+            .get_mut::<EventBus>()
+            .await
+            .unwrap();
+        bus.dispatch();
+        bus.reader::<RunRestarted>().iter().cloned().collect()
+    };
+    assert_eq!(
+        restarted,
+        vec![RunRestarted {
+            restart: 1,
+            reset: vec![
+                "issun:time".to_string(),
+                "issun:action".to_string(),
+                "game".to_string(),
+            ],
+            untouched: vec!["sandbox".to_string()],
+        }]
+    );
+
+    // A second run starts from the same state
+    for _ in 0..4 {
+        let transition = director.update().await;
+        director.handle(transition).await.unwrap();
+    }
+    let resources = director.resources();
+    assert_eq!(*resources.get::<Score>().await.unwrap(), Score(100));
+    assert_eq!(resources.get::<Profile>().await.unwrap().turns_played, 6);
+    assert_eq!(
+        *resources.get::<SandboxState>().await.unwrap(),
+        SandboxState(6)
+    );
+}
+
+#[tokio::test]
+async fn test_derived_plugins_register_state_resets() {
+    use issun::plugin::contagion::ContagionPlugin;
+    use issun::plugin::{CombatPlugin, DungeonPlugin};
+    use issun::scene::ResetRegistry;
+
+    let game = GameBuilder::new()
+        .with_plugin(CombatPlugin::default())
+        .unwrap()
+        .with_plugin(DungeonPlugin::default())
+        .unwrap()
+        .with_plugin(ContagionPlugin::default())
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    let registry = game.resources.get::<ResetRegistry>().await.unwrap();
+    assert_eq!(
+        registry.owners(),
+        vec!["issun:combat", "issun:dungeon", "issun:contagion"]
+    );
+}
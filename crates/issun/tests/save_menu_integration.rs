@@ -0,0 +1,258 @@
+//! SaveLoadMenu driven against a real SaveLoadPlugin and rendered with
+//! SaveMenuWidget
+
+use issun::context::{ResourceContext, ServiceContext, SystemContext};
+use issun::plugin::save_load::{
+    MissingModPolicy, ModVersionPolicy, SaveFormat, SaveLoadConfig, SaveLoadMenu, SaveLoadPlugin,
+    SaveMenuMode, SaveMenuToast,
+};
+use issun::prelude::GameBuilder;
+use issun::scene::SceneTransition;
+use issun::storage::json_repository::JsonSaveRepository;
+use issun::storage::repository::SaveRepository;
+use issun::storage::save_data::SaveData;
+use issun::ui::core::TextEntryActive;
+use issun::ui::ratatui::SaveMenuWidget;
+use issun::ui::InputEvent;
+use ratatui::{backend::TestBackend, Terminal};
+use tempfile::TempDir;
+
+#[derive(Debug, PartialEq)]
+enum TestScene {
+    Resumed(String),
+}
+
+struct Harness {
+    _dir: TempDir,
+    repository: JsonSaveRepository,
+    services: ServiceContext,
+    systems: SystemContext,
+    resources: ResourceContext,
+    menu: SaveLoadMenu,
+}
+
+impl Harness {
+    /// Game with `slot1` saved, `slot2` empty and `slot3` corrupted
+    async fn new() -> Self {
+        let dir = TempDir::new().unwrap();
+        let repository = JsonSaveRepository::new(dir.path()).await.unwrap();
+        repository
+            .save(&SaveData::new(
+                "slot1",
+                serde_json::json!({ "label": "Floor 3", "play_time_secs": 754 }),
+            ))
+            .await
+            .unwrap();
+        std::fs::write(dir.path().join("slot3.json"), "{ truncated").unwrap();
+
+        let game = GameBuilder::new()
+            .with_plugin(SaveLoadPlugin::new().with_config(SaveLoadConfig {
+                save_directory: dir.path().to_path_buf(),
+                format: SaveFormat::Json,
+                enable_auto_save: false,
+                auto_save_interval: 300,
+                missing_mods: MissingModPolicy::Warn,
+                mod_versions: ModVersionPolicy::Warn,
+            }))
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        let mut harness = Self {
+            _dir: dir,
+            repository,
+            services: game.services,
+            systems: game.systems,
+            resources: game.resources,
+            menu: SaveLoadMenu::new(["slot1", "slot2", "slot3"]),
+        };
+        harness
+            .menu
+            .refresh(
+                &harness.services,
+                &mut harness.systems,
+                &mut harness.resources,
+            )
+            .await;
+        harness
+    }
+
+    async fn press(&mut self, input: InputEvent) -> SceneTransition<TestScene> {
+        self.menu
+            .handle_input(
+                &self.services,
+                &mut self.systems,
+                &mut self.resources,
+                input,
+                |loaded, _| SceneTransition::Replace(TestScene::Resumed(loaded.slot.clone())),
+            )
+            .await
+    }
+
+    async fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.press(InputEvent::Char(c)).await;
+        }
+    }
+
+    async fn label_of(&self, slot: &str) -> Option<String> {
+        self.repository.get_metadata(slot).await.unwrap().label
+    }
+
+    fn render(&self) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(72, 10)).unwrap();
+        terminal
+            .draw(|frame| SaveMenuWidget::new(&self.menu).render(frame, frame.area()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[tokio::test]
+async fn test_listing_renders_fixture_slots() {
+    let harness = Harness::new().await;
+
+    let rows = harness.render();
+    assert!(rows[1].contains("> slot1"), "{:?}", rows);
+    assert!(rows[1].contains("Floor 3") && rows[1].contains("0:12:34"));
+    assert!(rows[2].contains("slot2") && rows[2].contains("— empty —"));
+    assert!(rows[3].contains("slot3") && rows[3].contains("corrupted"));
+}
+
+#[tokio::test]
+async fn test_overwrite_confirmation_gates_the_save() {
+    let mut harness = Harness::new().await;
+
+    // Declining leaves the slot untouched
+    harness.press(InputEvent::Char('s')).await;
+    assert_eq!(
+        harness.menu.mode(),
+        &SaveMenuMode::ConfirmOverwrite {
+            slot: "slot1".to_string()
+        }
+    );
+    assert!(harness
+        .render()
+        .iter()
+        .any(|row| row.contains("Overwrite slot1?")));
+    harness.press(InputEvent::Char('n')).await;
+    assert_eq!(harness.menu.mode(), &SaveMenuMode::Browse);
+    assert_eq!(harness.label_of("slot1").await.as_deref(), Some("Floor 3"));
+
+    // Confirming opens the label prompt with the old label
+    harness.press(InputEvent::Char('s')).await;
+    harness.press(InputEvent::Char('y')).await;
+    assert_eq!(harness.menu.label_input().value(), "Floor 3");
+    assert!(harness.resources.contains::<TextEntryActive>());
+
+    harness.press(InputEvent::Backspace).await;
+    harness.type_text("4 (hq)").await;
+    harness.press(InputEvent::Select).await;
+
+    assert!(!harness.resources.contains::<TextEntryActive>());
+    assert_eq!(
+        harness.menu.toast(),
+        Some(&SaveMenuToast::Saved {
+            slot: "slot1".to_string()
+        })
+    );
+    assert_eq!(
+        harness.label_of("slot1").await.as_deref(),
+        Some("Floor 4 (hq)")
+    );
+    assert!(harness.render()[1].contains("Floor 4 (hq)"));
+}
+
+#[tokio::test]
+async fn test_saving_to_empty_slot_skips_confirmation() {
+    let mut harness = Harness::new().await;
+
+    harness.press(InputEvent::Down).await;
+    harness.press(InputEvent::Char('s')).await;
+    assert_eq!(
+        harness.menu.mode(),
+        &SaveMenuMode::EnterLabel {
+            slot: "slot2".to_string()
+        }
+    );
+    harness.press(InputEvent::Select).await;
+
+    assert_eq!(harness.label_of("slot2").await, None);
+    assert!(!harness.menu.entries()[1].is_empty());
+    assert_eq!(harness.menu.selected(), 1);
+}
+
+#[tokio::test]
+async fn test_corrupted_slot_load_shows_error_and_stays() {
+    let mut harness = Harness::new().await;
+
+    harness.press(InputEvent::Down).await;
+    harness.press(InputEvent::Down).await;
+    let transition = harness.press(InputEvent::Select).await;
+
+    assert_eq!(transition, SceneTransition::Stay);
+    let toast = harness.menu.toast().unwrap();
+    assert!(toast.is_error());
+    assert!(
+        harness
+            .render()
+            .iter()
+            .any(|row| row.contains("Could not load slot3")),
+        "{:?}",
+        harness.render()
+    );
+
+    // The toast goes away with the next key press
+    harness.press(InputEvent::Up).await;
+    assert_eq!(harness.menu.toast(), None);
+}
+
+#[tokio::test]
+async fn test_load_transitions_to_target_scene() {
+    let mut harness = Harness::new().await;
+
+    let transition = harness.press(InputEvent::Select).await;
+
+    assert_eq!(
+        transition,
+        SceneTransition::Replace(TestScene::Resumed("slot1".to_string()))
+    );
+}
+
+#[tokio::test]
+async fn test_empty_slot_and_delete_confirmation() {
+    let mut harness = Harness::new().await;
+
+    harness.press(InputEvent::Down).await;
+    let transition = harness.press(InputEvent::Select).await;
+    assert_eq!(transition, SceneTransition::Stay);
+    assert_eq!(
+        harness.menu.toast(),
+        Some(&SaveMenuToast::EmptySlot {
+            slot: "slot2".to_string()
+        })
+    );
+
+    harness.press(InputEvent::Up).await;
+    harness.press(InputEvent::Char('d')).await;
+    harness.press(InputEvent::Cancel).await;
+    assert!(harness.repository.exists("slot1").await);
+
+    harness.press(InputEvent::Char('d')).await;
+    harness.press(InputEvent::Char('y')).await;
+    assert!(!harness.repository.exists("slot1").await);
+    assert!(harness.menu.entries()[0].is_empty());
+
+    assert_eq!(
+        harness.press(InputEvent::Cancel).await,
+        SceneTransition::Pop
+    );
+}
@@ -0,0 +1,386 @@
+//! Saves pinned to the MODs they were made with
+//!
+//! The fixture save `saves/modded.json` was made with `easy_mode` 1.0.0 and
+//! `better_loot` 2.0.0. The game below starts with `better_loot` 2.1.0 and
+//! `hard_mode`, so `easy_mode` is missing, `better_loot` changed version and
+//! `hard_mode` is extra.
+
+use async_trait::async_trait;
+use issun::context::{ResourceContext, ServiceContext, SystemContext};
+use issun::event::{Event, EventBus};
+use issun::modding::{
+    content_hash, ModBackend, ModError, ModHandle, ModLoadedEvent, ModLoader, ModMetadata,
+    ModRegistry, ModResult, ModSystemPlugin, PluginControl,
+};
+use issun::plugin::save_load::{
+    GameLoaded, LoadGameRequested, MissingModPolicy, ModVersionPolicy, SaveGameRequested,
+    SaveLoadConfig, SaveLoadFailed, SaveLoadHook, SaveLoadMenu, SaveLoadPlugin, SaveLoadSystem,
+    SaveMenuMode, SaveModMismatch, SavedMod,
+};
+use issun::prelude::GameBuilder;
+use issun::scene::SceneTransition;
+use issun::storage::save_data::SaveData;
+use issun::ui::ratatui::SaveMenuWidget;
+use issun::ui::InputEvent;
+use ratatui::{backend::TestBackend, Terminal};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tempfile::TempDir;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/saves");
+
+/// Loads `<id>.mod` files holding `version = "x.y.z"`
+#[derive(Clone)]
+struct StubLoader;
+
+impl ModLoader for StubLoader {
+    fn load(&mut self, path: &Path) -> ModResult<ModHandle> {
+        let text = std::fs::read_to_string(path)?;
+        let version = text
+            .trim()
+            .strip_prefix("version = ")
+            .map(|version| version.trim_matches('"').to_string())
+            .ok_or_else(|| ModError::InvalidFormat(path.display().to_string()))?;
+        let id = path.file_stem().unwrap().to_string_lossy().to_string();
+        Ok(ModHandle {
+            id: id.clone(),
+            metadata: ModMetadata {
+                name: id,
+                version,
+                author: None,
+                description: None,
+                dependencies: Vec::new(),
+                priority: 0,
+                after: Vec::new(),
+            },
+            backend: ModBackend::Rhai,
+        })
+    }
+
+    fn file_extensions(&self) -> Vec<&'static str> {
+        vec!["mod"]
+    }
+
+    fn unload(&mut self, _handle: &ModHandle) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn control_plugin(&mut self, _handle: &ModHandle, _control: &PluginControl) -> ModResult<()> {
+        Ok(())
+    }
+
+    fn clone_box(&self) -> Box<dyn ModLoader> {
+        Box::new(self.clone())
+    }
+}
+
+/// Provides MODs from a "remote index" directory and records whether
+/// `easy_mode` was loaded by the time the save is applied
+#[derive(Default)]
+struct TestHook {
+    remote_index: Option<PathBuf>,
+    easy_mode_at_load: Arc<Mutex<Option<bool>>>,
+}
+
+#[async_trait]
+impl SaveLoadHook for TestHook {
+    async fn locate_mod(&self, saved: &SavedMod, _resources: &ResourceContext) -> Option<PathBuf> {
+        let path = self
+            .remote_index
+            .as_ref()?
+            .join(format!("{}.mod", saved.id));
+        path.is_file().then_some(path)
+    }
+
+    async fn after_load(&self, _save_data: &SaveData, resources: &mut ResourceContext) {
+        let registry = resources.get::<ModRegistry>().await.unwrap();
+        *self.easy_mode_at_load.lock().unwrap() = Some(registry.get("easy_mode").is_some());
+    }
+}
+
+struct Game {
+    dir: TempDir,
+    services: ServiceContext,
+    systems: SystemContext,
+    resources: ResourceContext,
+}
+
+impl Game {
+    async fn new(missing_mods: MissingModPolicy, mod_versions: ModVersionPolicy) -> Self {
+        Self::with_hook(missing_mods, mod_versions, TestHook::default()).await
+    }
+
+    async fn with_hook(
+        missing_mods: MissingModPolicy,
+        mod_versions: ModVersionPolicy,
+        hook: TestHook,
+    ) -> Self {
+        let dir = TempDir::new().unwrap();
+        let saves = dir.path().join("saves");
+        let mods = dir.path().join("mods");
+        std::fs::create_dir_all(&saves).unwrap();
+        std::fs::create_dir_all(&mods).unwrap();
+        std::fs::copy(
+            Path::new(FIXTURES).join("modded.json"),
+            saves.join("modded.json"),
+        )
+        .unwrap();
+        std::fs::write(mods.join("better_loot.mod"), "version = \"2.1.0\"").unwrap();
+        std::fs::write(mods.join("hard_mode.mod"), "version = \"1.0.0\"").unwrap();
+
+        let game = GameBuilder::new()
+            .with_plugin(
+                ModSystemPlugin::new()
+                    .with_loader(StubLoader)
+                    .with_mod_dir(&mods),
+            )
+            .unwrap()
+            .with_plugin(
+                SaveLoadPlugin::new()
+                    .with_config(SaveLoadConfig {
+                        save_directory: saves,
+                        enable_auto_save: false,
+                        missing_mods,
+                        mod_versions,
+                        ..SaveLoadConfig::default()
+                    })
+                    .with_hook(hook),
+            )
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        Self {
+            dir,
+            services: game.services,
+            systems: game.systems,
+            resources: game.resources,
+        }
+    }
+
+    fn mod_dir(&self) -> PathBuf {
+        self.dir.path().join("mods")
+    }
+
+    /// Publish `event`, run the save/load system and dispatch its results
+    async fn request<E: Event + serde::Serialize>(&mut self, event: E) {
+        {
+            let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+            bus.publish(event);
+            bus.dispatch();
+        }
+        self.systems
+            .get_mut::<SaveLoadSystem>()
+            .unwrap()
+            .process_events(&self.services, &mut self.resources)
+            .await;
+        self.resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .dispatch();
+    }
+
+    async fn load_fixture(&mut self) {
+        self.request(LoadGameRequested {
+            slot: "modded".to_string(),
+        })
+        .await;
+    }
+
+    async fn events<E: Event + Clone>(&self) -> Vec<E> {
+        let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+        bus.reader::<E>().iter().cloned().collect()
+    }
+
+    async fn mismatch(&self) -> SaveModMismatch {
+        let mut mismatches = self.events::<SaveModMismatch>().await;
+        assert_eq!(mismatches.len(), 1);
+        mismatches.remove(0)
+    }
+}
+
+#[tokio::test]
+async fn test_warn_lists_the_mismatch_and_loads() {
+    let mut game = Game::new(MissingModPolicy::Warn, ModVersionPolicy::Warn).await;
+    game.load_fixture().await;
+
+    let mismatch = game.mismatch().await;
+    assert_eq!(mismatch.missing, vec!["easy_mode"]);
+    assert_eq!(mismatch.extra, vec!["hard_mode"]);
+    assert_eq!(
+        mismatch.version_changed[0].to_string(),
+        "better_loot 2.0.0 → 2.1.0"
+    );
+    assert!(mismatch.auto_loaded.is_empty());
+    assert!(!mismatch.refused);
+    assert_eq!(game.events::<GameLoaded>().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_block_refuses_a_save_with_missing_mods() {
+    let mut game = Game::new(MissingModPolicy::Block, ModVersionPolicy::Warn).await;
+    game.load_fixture().await;
+
+    assert!(game.mismatch().await.refused);
+    assert!(game.events::<GameLoaded>().await.is_empty());
+    let failed = game.events::<SaveLoadFailed>().await.remove(0);
+    assert!(
+        failed.error.contains("missing easy_mode"),
+        "{}",
+        failed.error
+    );
+}
+
+#[tokio::test]
+async fn test_version_changes_have_their_own_policy() {
+    // Missing MODs only warn, but the version change blocks
+    let mut game = Game::new(MissingModPolicy::Warn, ModVersionPolicy::Block).await;
+    game.load_fixture().await;
+    assert!(game.mismatch().await.refused);
+    assert!(game.events::<GameLoaded>().await.is_empty());
+
+    // With easy_mode found, only the version change is left to block
+    let mut game = Game::new(MissingModPolicy::AutoLoad, ModVersionPolicy::Block).await;
+    std::fs::copy(
+        Path::new(FIXTURES).join("mods/easy_mode.mod"),
+        game.mod_dir().join("easy_mode.mod"),
+    )
+    .unwrap();
+    game.load_fixture().await;
+    let mismatch = game.mismatch().await;
+    assert!(mismatch.missing.is_empty());
+    assert!(mismatch.refused);
+    assert!(game.events::<GameLoaded>().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_auto_load_loads_missing_mods_from_the_mod_dir_first() {
+    let hook = TestHook::default();
+    let easy_mode_at_load = hook.easy_mode_at_load.clone();
+    let mut game = Game::with_hook(MissingModPolicy::AutoLoad, ModVersionPolicy::Warn, hook).await;
+    // Dropped into the MOD directory after startup
+    std::fs::copy(
+        Path::new(FIXTURES).join("mods/easy_mode.mod"),
+        game.mod_dir().join("easy_mode.mod"),
+    )
+    .unwrap();
+    game.load_fixture().await;
+
+    let mismatch = game.mismatch().await;
+    assert_eq!(mismatch.auto_loaded, vec!["easy_mode"]);
+    assert!(mismatch.missing.is_empty());
+    // Same files as in the save: only better_loot is a version change
+    assert_eq!(mismatch.version_changed.len(), 1);
+    assert!(!mismatch.refused);
+
+    let loaded: Vec<String> = game
+        .events::<ModLoadedEvent>()
+        .await
+        .into_iter()
+        .map(|event| event.handle.id)
+        .collect();
+    assert_eq!(loaded, vec!["easy_mode"]);
+    assert_eq!(*easy_mode_at_load.lock().unwrap(), Some(true));
+    assert_eq!(game.events::<GameLoaded>().await.len(), 1);
+
+    let registry = game.resources.get::<ModRegistry>().await.unwrap();
+    assert_eq!(
+        registry.source("easy_mode").unwrap().content_hash,
+        Some(content_hash(&Path::new(FIXTURES).join("mods/easy_mode.mod")).unwrap())
+    );
+}
+
+#[tokio::test]
+async fn test_auto_load_asks_the_hook_for_mods_not_in_the_mod_dir() {
+    let hook = TestHook {
+        remote_index: Some(Path::new(FIXTURES).join("mods")),
+        ..TestHook::default()
+    };
+    let easy_mode_at_load = hook.easy_mode_at_load.clone();
+    let mut game = Game::with_hook(MissingModPolicy::AutoLoad, ModVersionPolicy::Warn, hook).await;
+    game.load_fixture().await;
+
+    assert_eq!(game.mismatch().await.auto_loaded, vec!["easy_mode"]);
+    assert_eq!(*easy_mode_at_load.lock().unwrap(), Some(true));
+    assert_eq!(game.events::<GameLoaded>().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_auto_load_falls_back_to_warn() {
+    let mut game = Game::new(MissingModPolicy::AutoLoad, ModVersionPolicy::Warn).await;
+    game.load_fixture().await;
+
+    let mismatch = game.mismatch().await;
+    assert_eq!(mismatch.missing, vec!["easy_mode"]);
+    assert!(mismatch.auto_loaded.is_empty());
+    assert!(!mismatch.refused);
+    assert_eq!(game.events::<GameLoaded>().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_saves_record_content_hashes() {
+    let mut game = Game::new(MissingModPolicy::Warn, ModVersionPolicy::Warn).await;
+    game.request(SaveGameRequested {
+        slot: "fresh".to_string(),
+        label: None,
+    })
+    .await;
+
+    let json = std::fs::read_to_string(game.dir.path().join("saves/fresh.json")).unwrap();
+    let save: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let mods = &save["data"]["mod_manifest"]["mods"];
+    assert_eq!(mods[0]["id"], "better_loot");
+    assert_eq!(
+        mods[0]["content_hash"],
+        content_hash(&game.mod_dir().join("better_loot.mod")).unwrap()
+    );
+    assert_eq!(mods[1]["id"], "hard_mode");
+}
+
+async fn press(
+    menu: &mut SaveLoadMenu,
+    game: &mut Game,
+    input: InputEvent,
+) -> SceneTransition<String> {
+    menu.handle_input(
+        &game.services,
+        &mut game.systems,
+        &mut game.resources,
+        input,
+        |loaded, _| SceneTransition::Replace(loaded.slot.clone()),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_menu_shows_the_mismatch_before_resuming() {
+    let mut game = Game::new(MissingModPolicy::Warn, ModVersionPolicy::Warn).await;
+    let mut menu = SaveLoadMenu::new(["modded"]);
+    menu.refresh(&game.services, &mut game.systems, &mut game.resources)
+        .await;
+
+    let transition = press(&mut menu, &mut game, InputEvent::Select).await;
+    assert_eq!(transition, SceneTransition::Stay);
+    assert!(matches!(menu.mode(), SaveMenuMode::ModMismatch { .. }));
+
+    let mut terminal = Terminal::new(TestBackend::new(72, 14)).unwrap();
+    terminal
+        .draw(|frame| SaveMenuWidget::new(&menu).render(frame, frame.area()))
+        .unwrap();
+    let screen: String = {
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .flat_map(|y| (0..buffer.area.width).map(move |x| (x, y)))
+            .map(|(x, y)| buffer[(x, y)].symbol().to_string())
+            .collect()
+    };
+    assert!(screen.contains("modded was saved with other MODs"));
+    assert!(screen.contains("Missing: easy_mode"));
+    assert!(screen.contains("Enter continue"));
+
+    let transition = press(&mut menu, &mut game, InputEvent::Select).await;
+    assert_eq!(transition, SceneTransition::Replace("modded".to_string()));
+    assert_eq!(menu.mode(), &SaveMenuMode::Browse);
+}
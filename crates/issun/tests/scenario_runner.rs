@@ -0,0 +1,162 @@
+//! Scenario files run against a small outbreak game
+
+use async_trait::async_trait;
+use issun::context::{ResourceContext, ServiceContext, SystemContext};
+use issun::event::{Event, EventBus};
+use issun::scene::{Scene, SceneDirector, SceneTransition};
+use issun::testing::scenario::{self, ScenarioError, ScenarioFactories};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Infections above which an outbreak is reported
+const OUTBREAK_THRESHOLD: u32 = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SpreadRequested {
+    cases: u32,
+}
+
+impl Event for SpreadRequested {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct OutbreakReported {
+    total: u32,
+}
+
+impl Event for OutbreakReported {}
+
+#[derive(Debug, Serialize)]
+struct Region {
+    name: String,
+    infected: u32,
+}
+
+#[derive(Debug, Serialize)]
+struct GameStats {
+    total_infected: u32,
+    regions: Vec<Region>,
+}
+
+/// Adds requested cases to the first region, reporting when the threshold
+/// is crossed
+struct OutbreakScene;
+
+#[async_trait]
+impl Scene for OutbreakScene {
+    async fn on_update(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) -> SceneTransition<Self> {
+        let mut bus = resources.get_mut::<EventBus>().await.unwrap();
+        let cases: u32 = bus
+            .reader::<SpreadRequested>()
+            .iter()
+            .map(|spread| spread.cases)
+            .sum();
+        if cases > 0 {
+            let mut stats = resources.get_mut::<GameStats>().await.unwrap();
+            let before = stats.total_infected;
+            stats.total_infected += cases;
+            stats.regions[0].infected += cases;
+            if before <= OUTBREAK_THRESHOLD && stats.total_infected > OUTBREAK_THRESHOLD {
+                bus.publish(OutbreakReported {
+                    total: stats.total_infected,
+                });
+            }
+        }
+        SceneTransition::Stay
+    }
+}
+
+fn factories() -> ScenarioFactories {
+    ScenarioFactories::new()
+        .game("outbreak", || async {
+            let mut resources = ResourceContext::new();
+            resources.insert(EventBus::new());
+            resources.insert(GameStats {
+                total_infected: 0,
+                regions: vec![Region {
+                    name: "north".into(),
+                    infected: 0,
+                }],
+            });
+            Ok(SceneDirector::new(
+                OutbreakScene,
+                ServiceContext::new(),
+                SystemContext::new(),
+                resources,
+            )
+            .await)
+        })
+        .event::<SpreadRequested>()
+        .unwrap()
+        .observe::<GameStats>()
+}
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/scenarios")
+        .join(name)
+}
+
+#[tokio::test]
+async fn test_passing_scenario() {
+    let report = scenario::run(fixture("outbreak_reported.ron"), &factories())
+        .await
+        .unwrap();
+    assert_eq!(report.scenario, "outbreak is reported");
+    assert_eq!(report.steps, 6);
+    assert_eq!(report.ticks, 4);
+}
+
+#[tokio::test]
+async fn test_failing_assertion_reports_step() {
+    let error = scenario::run(fixture("infection_cap.ron"), &factories())
+        .await
+        .unwrap_err();
+    let failure = error.failure().unwrap();
+
+    assert_eq!(failure.step, 4);
+    assert_eq!(
+        failure.description,
+        "assert_resource GameStats.total_infected <= 10"
+    );
+    assert_eq!(failure.expected, "<= 10");
+    assert_eq!(failure.actual, "12");
+    assert_eq!(failure.tick, 4);
+    assert_eq!(
+        failure.recent_events,
+        vec![
+            "tick 0: SpreadRequested",
+            "tick 1: OutbreakReported",
+            "tick 2: SpreadRequested",
+        ]
+    );
+
+    let report = error.to_string();
+    assert!(report.starts_with("Scenario 'infections stay under the cap' failed at step 5/5"));
+    assert!(report.contains("actual:   12"));
+}
+
+#[tokio::test]
+async fn test_wait_for_times_out() {
+    let error = scenario::run(fixture("quiet_outbreak.ron"), &factories())
+        .await
+        .unwrap_err();
+    let failure = error.failure().unwrap();
+
+    assert_eq!(failure.step, 1);
+    assert_eq!(failure.expected, "OutbreakReported within 3 ticks");
+    assert_eq!(failure.actual, "not published after 3 ticks");
+    assert_eq!(failure.tick, 3);
+}
+
+#[tokio::test]
+async fn test_unknown_game() {
+    let error = scenario::run(fixture("outbreak_reported.ron"), &ScenarioFactories::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(error, ScenarioError::UnknownGame { .. }));
+}
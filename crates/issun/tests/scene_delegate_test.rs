@@ -0,0 +1,236 @@
+//! Lifecycle hook delegation of the Scene derive macro
+
+use issun::context::{ResourceContext, ServiceContext, SystemContext};
+use issun::scene::{Scene, SceneTransition};
+use issun::Scene;
+
+/// Log of hook calls, kept as a resource so associated functions can write it
+#[derive(Debug, Default)]
+struct HookLog(Vec<String>);
+
+async fn log(resources: &mut ResourceContext, entry: impl Into<String>) {
+    if resources.get::<HookLog>().await.is_none() {
+        resources.insert(HookLog::default());
+    }
+    resources
+        .get_mut::<HookLog>()
+        .await
+        .unwrap()
+        .0
+        .push(entry.into());
+}
+
+async fn entries(resources: &ResourceContext) -> Vec<String> {
+    resources
+        .get::<HookLog>()
+        .await
+        .map(|log| log.0.clone())
+        .unwrap_or_default()
+}
+
+macro_rules! scene_data {
+    ($name:ident) => {
+        #[derive(Debug, Default)]
+        struct $name {
+            ticks: u32,
+        }
+
+        impl $name {
+            async fn on_enter(
+                &mut self,
+                _services: &ServiceContext,
+                _systems: &mut SystemContext,
+                resources: &mut ResourceContext,
+            ) {
+                log(resources, concat!(stringify!($name), "::on_enter")).await;
+            }
+
+            async fn on_update(
+                &mut self,
+                _services: &ServiceContext,
+                _systems: &mut SystemContext,
+                _resources: &mut ResourceContext,
+            ) -> SceneTransition<MultiScene> {
+                self.ticks += 1;
+                if self.ticks >= 2 {
+                    SceneTransition::Switch(MultiScene::Shop(ShopData::default()))
+                } else {
+                    SceneTransition::Stay
+                }
+            }
+
+            async fn on_exit(
+                &mut self,
+                _services: &ServiceContext,
+                _systems: &mut SystemContext,
+                resources: &mut ResourceContext,
+            ) {
+                log(resources, concat!(stringify!($name), "::on_exit")).await;
+            }
+        }
+    };
+}
+
+scene_data!(CombatData);
+scene_data!(ShopData);
+scene_data!(MapData);
+
+struct TitleScreen;
+
+impl TitleScreen {
+    async fn on_enter(
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) {
+        log(resources, "TitleScreen::on_enter").await;
+    }
+
+    async fn on_update(
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+    ) -> SceneTransition<MultiScene> {
+        SceneTransition::Switch(MultiScene::Map(MapData::default()))
+    }
+
+    async fn on_exit(
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) {
+        log(resources, "TitleScreen::on_exit").await;
+    }
+}
+
+#[derive(Debug, Default)]
+struct SoloData {
+    updates: u32,
+}
+
+impl SoloData {
+    async fn on_update(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+    ) -> SceneTransition<SoloScene> {
+        self.updates += 1;
+        SceneTransition::Quit
+    }
+}
+
+#[derive(Scene)]
+#[scene(delegate = "on_update")]
+enum SoloScene {
+    Main(SoloData),
+}
+
+#[derive(Debug, Scene)]
+#[scene(delegate = "on_enter, on_update, on_exit")]
+enum MultiScene {
+    #[scene(delegate_to = "TitleScreen")]
+    Title,
+    Combat(CombatData),
+    Shop(ShopData),
+    Map(MapData),
+    Credits,
+}
+
+struct Contexts {
+    services: ServiceContext,
+    systems: SystemContext,
+    resources: ResourceContext,
+}
+
+impl Contexts {
+    fn new() -> Self {
+        Self {
+            services: ServiceContext::new(),
+            systems: SystemContext::new(),
+            resources: ResourceContext::new(),
+        }
+    }
+
+    async fn enter<S: Scene>(&mut self, scene: &mut S) {
+        scene
+            .on_enter(&self.services, &mut self.systems, &mut self.resources)
+            .await;
+    }
+
+    async fn update<S: Scene>(&mut self, scene: &mut S) -> SceneTransition<S> {
+        scene
+            .on_update(&self.services, &mut self.systems, &mut self.resources)
+            .await
+    }
+
+    async fn exit<S: Scene>(&mut self, scene: &mut S) {
+        scene
+            .on_exit(&self.services, &mut self.systems, &mut self.resources)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_single_variant_delegates_update() {
+    let mut contexts = Contexts::new();
+    let mut scene = SoloScene::Main(SoloData::default());
+
+    // Hooks not listed in `delegate` keep the default behaviour
+    contexts.enter(&mut scene).await;
+    assert!(matches!(
+        contexts.update(&mut scene).await,
+        SceneTransition::Quit
+    ));
+    let SoloScene::Main(data) = &scene;
+    assert_eq!(data.updates, 1);
+}
+
+#[tokio::test]
+async fn test_data_variants_receive_hooks() {
+    let mut contexts = Contexts::new();
+    let mut scene = MultiScene::Combat(CombatData::default());
+
+    contexts.enter(&mut scene).await;
+    assert!(matches!(
+        contexts.update(&mut scene).await,
+        SceneTransition::Stay
+    ));
+    assert!(matches!(
+        contexts.update(&mut scene).await,
+        SceneTransition::Switch(MultiScene::Shop(_))
+    ));
+    contexts.exit(&mut scene).await;
+
+    assert!(matches!(&scene, MultiScene::Combat(data) if data.ticks == 2));
+    assert_eq!(
+        entries(&contexts.resources).await,
+        vec!["CombatData::on_enter", "CombatData::on_exit"]
+    );
+}
+
+#[tokio::test]
+async fn test_unit_variants_call_target_or_skip() {
+    let mut contexts = Contexts::new();
+
+    let mut title = MultiScene::Title;
+    contexts.enter(&mut title).await;
+    assert!(matches!(
+        contexts.update(&mut title).await,
+        SceneTransition::Switch(MultiScene::Map(_))
+    ));
+    contexts.exit(&mut title).await;
+
+    let mut credits = MultiScene::Credits;
+    contexts.enter(&mut credits).await;
+    assert!(matches!(
+        contexts.update(&mut credits).await,
+        SceneTransition::Stay
+    ));
+    contexts.exit(&mut credits).await;
+
+    assert_eq!(
+        entries(&contexts.resources).await,
+        vec!["TitleScreen::on_enter", "TitleScreen::on_exit"]
+    );
+}
@@ -0,0 +1,141 @@
+//! GameState generation options of the Scene derive macro
+
+use issun::Scene;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GameConfig {
+    starting_gold: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ConfigError(String);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GameContext {
+    gold: u32,
+}
+
+impl GameContext {
+    fn new() -> Self {
+        Self { gold: 0 }
+    }
+
+    fn from_config(config: &GameConfig) -> Result<Self, ConfigError> {
+        if config.starting_gold > 1000 {
+            return Err(ConfigError("too rich".into()));
+        }
+        Ok(Self {
+            gold: config.starting_gold,
+        })
+    }
+}
+
+mod default_state {
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Scene)]
+    #[scene(context = "GameContext", initial = "Title")]
+    pub enum GameScene {
+        Title,
+        Shop(u32),
+    }
+}
+
+mod custom_state {
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Scene)]
+    #[scene(
+        context = "GameContext",
+        initial = "Title",
+        name = "ShopState",
+        ctx_params = "config: &GameConfig",
+        ctx_init = "GameContext::from_config(config)?",
+        ctx_error = "ConfigError",
+        extra_fields = "frame: u64, #[serde(skip)] paused: bool"
+    )]
+    pub enum GameScene {
+        Title,
+        Shop(u32),
+    }
+}
+
+mod infallible_init {
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Scene)]
+    #[scene(
+        context = "GameContext",
+        initial = "Shop(3)",
+        ctx_params = "gold: u32",
+        ctx_init = "GameContext { gold }"
+    )]
+    pub enum GameScene {
+        Title,
+        Shop(u32),
+    }
+}
+
+mod own_state {
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Scene)]
+    #[scene(context = "GameContext", initial = "Title", no_state)]
+    pub enum GameScene {
+        Title,
+    }
+
+    /// Written by hand; the derive must not generate a conflicting one
+    pub struct GameState {
+        pub scene: GameScene,
+        pub turn: u32,
+    }
+}
+
+#[test]
+fn test_default_state() {
+    let state = default_state::GameState::default();
+    assert!(matches!(state.scene, default_state::GameScene::Title));
+    assert_eq!(state.ctx.gold, 0);
+    assert!(!state.should_quit);
+}
+
+#[test]
+fn test_fallible_context_and_extra_fields() {
+    let state = custom_state::ShopState::new(&GameConfig { starting_gold: 50 }).unwrap();
+    assert_eq!(state.ctx.gold, 50);
+    assert_eq!(state.frame, 0);
+    assert!(!state.paused);
+
+    let error = custom_state::ShopState::new(&GameConfig {
+        starting_gold: 5000,
+    })
+    .unwrap_err();
+    assert_eq!(error, ConfigError("too rich".into()));
+
+    let mut state = state;
+    state.frame = 7;
+    state.paused = true;
+    let json = serde_json::to_string(&state).unwrap();
+    let restored: custom_state::ShopState = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.frame, 7);
+    assert!(!restored.paused);
+}
+
+#[test]
+fn test_context_init_with_parameters() {
+    let state = infallible_init::GameState::new(120);
+    assert_eq!(state.ctx.gold, 120);
+    assert!(matches!(state.scene, infallible_init::GameScene::Shop(3)));
+}
+
+#[test]
+fn test_no_state() {
+    let state = own_state::GameState {
+        scene: own_state::GameScene::Title,
+        turn: 1,
+    };
+    assert!(matches!(state.scene, own_state::GameScene::Title));
+    assert_eq!(state.turn, 1);
+}
@@ -0,0 +1,71 @@
+//! `handler_params` of the Scene derive macro
+
+use issun::context::{ResourceContext, ServiceContext, SystemContext};
+use issun::scene::SceneTransition;
+use issun::Scene;
+use std::collections::HashMap;
+
+#[derive(Scene)]
+#[scene(
+    handler_params = "prices: &HashMap<String, u32>, budget: u32,",
+    handler_return = "SceneTransition<GameScene>"
+)]
+enum GameScene {
+    Shop(ShopData),
+}
+
+struct ShopData {
+    bought: Vec<String>,
+}
+
+impl ShopData {
+    async fn handle_input(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+        prices: &HashMap<String, u32>,
+        budget: u32,
+    ) -> SceneTransition<GameScene> {
+        let mut items: Vec<(&String, &u32)> = prices.iter().collect();
+        items.sort();
+        let mut left = budget;
+        for (item, price) in items {
+            if *price <= left {
+                left -= price;
+                self.bought.push(item.clone());
+            }
+        }
+        SceneTransition::Stay
+    }
+}
+
+#[tokio::test]
+async fn test_params_with_generic_types_are_forwarded() {
+    let prices = HashMap::from([
+        ("bomb".to_string(), 30),
+        ("potion".to_string(), 10),
+        ("sword".to_string(), 50),
+    ]);
+    let mut scene = GameScene::Shop(ShopData { bought: vec![] });
+
+    let transition = handle_scene_input(
+        &mut scene,
+        &ServiceContext::new(),
+        &mut SystemContext::new(),
+        &mut ResourceContext::new(),
+        &prices,
+        45,
+    )
+    .await;
+
+    assert!(matches!(transition, SceneTransition::Stay));
+    let GameScene::Shop(shop) = scene;
+    assert_eq!(shop.bought, ["bomb", "potion"]);
+}
+
+#[test]
+fn test_malformed_params_are_reported() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/scene_handler_params/*.rs");
+}
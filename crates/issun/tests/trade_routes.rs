@@ -0,0 +1,343 @@
+//! Trade routes moving income through accounting, disrupted by sieges and
+//! raids
+
+use issun::context::{ResourceContext, ServiceContext};
+use issun::event::{Event, EventBus};
+use issun::plugin::accounting::{
+    AccountingSystem, BudgetLedger, Currency, DefaultAccountingHook, IncomePostedEvent,
+    SettlementCompletedEvent,
+};
+use issun::plugin::faction::{
+    FactionId, FactionState, Operation, OperationId, OperationLaunchedEvent,
+};
+use issun::plugin::territory::{
+    DisruptionCause, EstablishRouteRequested, RepairRouteRequested, RouteDisruptedEvent,
+    RouteEstablishedEvent, RouteId, RouteRejectedEvent, RouteStatus, Territories, Territory,
+    TerritoryState, TradeConfig, TradeReportPublished, TradeRouteError, TradeRouteSystem,
+    TradeRoutes, TARGET_ROUTE_KEY,
+};
+use issun::plugin::time::DayChanged;
+use serde::Serialize;
+use serde_json::json;
+use std::sync::Arc;
+
+struct World {
+    resources: ResourceContext,
+    trade: TradeRouteSystem,
+    accounting: AccountingSystem,
+}
+
+impl World {
+    /// `west - capital - east`, with `far` beyond `east`
+    fn new() -> Self {
+        let mut territories = Territories::new();
+        let mut state = TerritoryState::new();
+        let population = [("capital", 1000), ("west", 0), ("east", 0), ("far", 0)];
+        for (id, population) in population {
+            territories
+                .add(Territory::new(id, id).with_metadata(json!({ "population": population })));
+            state.initialize(&id.into());
+        }
+        territories.connect(&"west".into(), &"capital".into());
+        territories.connect(&"capital".into(), &"east".into());
+        territories.connect(&"east".into(), &"far".into());
+
+        let mut resources = ResourceContext::new();
+        resources.insert(territories);
+        resources.insert(state);
+        resources.insert(TradeRoutes::new());
+        resources.insert(TradeConfig::default().with_good_value("spice", 5));
+        resources.insert(BudgetLedger::new(Currency::ZERO));
+        resources.insert(FactionState::new());
+        resources.insert(EventBus::new());
+
+        Self {
+            resources,
+            trade: TradeRouteSystem::new(),
+            accounting: AccountingSystem::new(Arc::new(DefaultAccountingHook)),
+        }
+    }
+
+    async fn publish<E: Event + Serialize>(&mut self, event: E) {
+        let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+        bus.publish(event);
+        bus.dispatch();
+    }
+
+    /// Run the trade system, then dispatch its events
+    async fn step(&mut self) {
+        self.trade
+            .process_events(&ServiceContext::new(), &mut self.resources)
+            .await;
+        self.dispatch().await;
+    }
+
+    async fn dispatch(&mut self) {
+        self.resources
+            .get_mut::<EventBus>()
+            .await
+            .unwrap()
+            .dispatch();
+    }
+
+    async fn events<E: Event + Clone>(&self) -> Vec<E> {
+        let mut bus = self.resources.get_mut::<EventBus>().await.unwrap();
+        bus.reader::<E>().iter().cloned().collect()
+    }
+
+    async fn establish(&mut self, from: &str, to: &str, good: &str, capacity: u32) -> RouteId {
+        self.publish(EstablishRouteRequested {
+            from: from.into(),
+            to: to.into(),
+            good: good.into(),
+            capacity,
+        })
+        .await;
+        self.step().await;
+        self.events::<RouteEstablishedEvent>().await[0]
+            .route_id
+            .clone()
+    }
+
+    /// One settlement; returns the trade income it posted
+    async fn settle(&mut self) -> i64 {
+        let before = self.cash().await;
+        self.publish(SettlementCompletedEvent {
+            period: 1,
+            income: Currency::ZERO,
+            expenses: Currency::ZERO,
+            net: Currency::ZERO,
+        })
+        .await;
+        self.step().await;
+        // Accounting posts the income the next frame
+        self.accounting
+            .process_events(&ServiceContext::new(), &mut self.resources)
+            .await;
+        self.dispatch().await;
+        self.cash().await - before
+    }
+
+    async fn cash(&self) -> i64 {
+        self.resources
+            .get::<BudgetLedger>()
+            .await
+            .unwrap()
+            .cash
+            .amount()
+    }
+
+    async fn set_contested(&mut self, id: &str, contested: bool) {
+        self.resources
+            .get_mut::<TerritoryState>()
+            .await
+            .unwrap()
+            .set_contested(&id.into(), contested);
+    }
+
+    async fn status(&self, id: &RouteId) -> RouteStatus {
+        let routes = self.resources.get::<TradeRoutes>().await.unwrap();
+        routes.get(id).unwrap().status.clone()
+    }
+}
+
+#[tokio::test]
+async fn test_income_scales_with_endpoint_development_and_population() {
+    let mut world = World::new();
+    world.establish("west", "capital", "grain", 10).await;
+    world.establish("capital", "east", "spice", 4).await;
+
+    // grain: 10 × 1 × west 1.0 × capital (1 + 1000/1000) = 20
+    // spice: 4 × 5 × capital 2.0 × east 1.0 = 40
+    assert_eq!(world.settle().await, 60);
+    let posted = world.events::<IncomePostedEvent>().await;
+    assert_eq!(posted.len(), 2);
+    assert_eq!(posted[0].source, "trade:route-1");
+
+    world
+        .resources
+        .get_mut::<TerritoryState>()
+        .await
+        .unwrap()
+        .set_development(&"west".into(), 5);
+    // grain: 10 × west 1.5 × capital 2.0 = 30
+    assert_eq!(world.settle().await, 70);
+
+    let routes = world.resources.get::<TradeRoutes>().await.unwrap();
+    let grain = routes.get(&"route-1".into()).unwrap();
+    assert_eq!(grain.stats.settlements, 2);
+    assert_eq!(grain.stats.goods_moved, 20);
+    assert_eq!(grain.stats.income, Currency::new(50));
+}
+
+#[tokio::test]
+async fn test_routes_need_adjacency_or_friendly_territory() {
+    let mut world = World::new();
+    world
+        .publish(EstablishRouteRequested {
+            from: "capital".into(),
+            to: "far".into(),
+            good: "grain".into(),
+            capacity: 10,
+        })
+        .await;
+    world.step().await;
+    let rejected = world.events::<RouteRejectedEvent>().await;
+    assert_eq!(rejected[0].error, TradeRouteError::NoPath);
+
+    world
+        .resources
+        .get_mut::<TerritoryState>()
+        .await
+        .unwrap()
+        .set_control(&"east".into(), 0.9);
+    let route = world.establish("capital", "far", "grain", 10).await;
+    let routes = world.resources.get::<TradeRoutes>().await.unwrap();
+    assert_eq!(
+        routes.get(&route).unwrap().path,
+        ["capital".into(), "east".into(), "far".into()]
+    );
+}
+
+#[tokio::test]
+async fn test_siege_disrupts_exactly_the_affected_routes() {
+    let mut world = World::new();
+    let west = world.establish("west", "capital", "grain", 10).await;
+    let east = world.establish("capital", "east", "spice", 4).await;
+    let frontier = world.establish("east", "far", "ore", 10).await;
+
+    world.set_contested("east", true).await;
+    world.step().await;
+
+    let disrupted = world.events::<RouteDisruptedEvent>().await;
+    let ids: Vec<_> = disrupted.iter().map(|e| e.route_id.clone()).collect();
+    assert_eq!(ids, [east.clone(), frontier.clone()]);
+    assert_eq!(
+        disrupted[0].cause,
+        DisruptionCause::Contested("east".into())
+    );
+    assert_eq!(disrupted[0].income_loss, Currency::new(40));
+    assert_eq!(world.status(&west).await, RouteStatus::Active);
+
+    // Only the grain route still earns; the spice route counts its loss
+    assert_eq!(world.settle().await, 20);
+    let routes = world.resources.get::<TradeRoutes>().await.unwrap();
+    let spice = routes.get(&east).unwrap();
+    assert_eq!(spice.stats.disruptions, 1);
+    assert_eq!(spice.stats.income_lost, Currency::new(40));
+}
+
+#[tokio::test]
+async fn test_reestablishing_a_disrupted_route_resumes_income() {
+    let mut world = World::new();
+    let route = world.establish("capital", "east", "spice", 4).await;
+    world.set_contested("east", true).await;
+    world.step().await;
+    assert_eq!(world.settle().await, 0);
+
+    // Still under siege: repair is rejected
+    world
+        .publish(RepairRouteRequested {
+            route_id: route.clone(),
+        })
+        .await;
+    world.step().await;
+    let rejected = world.events::<RouteRejectedEvent>().await;
+    assert_eq!(rejected[0].error, TradeRouteError::Contested("east".into()));
+
+    world.set_contested("east", false).await;
+    world
+        .publish(EstablishRouteRequested {
+            from: "capital".into(),
+            to: "east".into(),
+            good: "spice".into(),
+            capacity: 4,
+        })
+        .await;
+    world.step().await;
+    assert_eq!(world.status(&route).await, RouteStatus::Active);
+    assert_eq!(world.resources.get::<TradeRoutes>().await.unwrap().len(), 1);
+    assert_eq!(world.settle().await, 40);
+}
+
+#[tokio::test]
+async fn test_enemy_operation_raids_the_targeted_route() {
+    let mut world = World::new();
+    let grain = world.establish("west", "capital", "grain", 10).await;
+    let spice = world.establish("capital", "east", "spice", 4).await;
+
+    let raid = Operation::new("op-1", FactionId::new("raiders"), "Raid the caravans")
+        .with_metadata(json!({ TARGET_ROUTE_KEY: spice.as_str() }));
+    world
+        .resources
+        .get_mut::<FactionState>()
+        .await
+        .unwrap()
+        .launch_operation(raid)
+        .unwrap();
+    world
+        .publish(OperationLaunchedEvent {
+            operation_id: OperationId::new("op-1"),
+            faction_id: FactionId::new("raiders"),
+            operation_name: "Raid the caravans".into(),
+        })
+        .await;
+    world.step().await;
+
+    assert!(matches!(
+        world.status(&spice).await,
+        RouteStatus::Disrupted(DisruptionCause::Raided { .. })
+    ));
+    assert_eq!(world.status(&grain).await, RouteStatus::Active);
+}
+
+#[tokio::test]
+async fn test_weekly_report_includes_the_route_section() {
+    let mut world = World::new();
+    world.establish("west", "capital", "grain", 10).await;
+    let spice = world.establish("capital", "east", "spice", 4).await;
+    world.settle().await;
+    world.set_contested("east", true).await;
+    world.settle().await;
+
+    // Not a report day
+    world.publish(DayChanged { day: 6 }).await;
+    world.step().await;
+    assert!(world.events::<TradeReportPublished>().await.is_empty());
+
+    world.publish(DayChanged { day: 7 }).await;
+    world.step().await;
+    let report = world.events::<TradeReportPublished>().await.remove(0);
+    assert_eq!(report.day, 7);
+    assert_eq!(report.routes.len(), 2);
+    assert_eq!(report.total_income, Currency::new(20 + 40 + 20));
+    let line = report.routes.iter().find(|l| l.route_id == spice).unwrap();
+    assert!(!line.active);
+    assert_eq!(line.stats.income, Currency::new(40));
+    assert_eq!(line.stats.income_lost, Currency::new(40));
+
+    // The next report starts from zero
+    world.publish(DayChanged { day: 14 }).await;
+    world.step().await;
+    let report = world.events::<TradeReportPublished>().await.remove(0);
+    assert_eq!(report.total_income, Currency::ZERO);
+}
+
+#[tokio::test]
+async fn test_routes_survive_serde() {
+    let mut world = World::new();
+    let route = world.establish("capital", "east", "spice", 4).await;
+    world.set_contested("east", true).await;
+    world.settle().await;
+
+    let routes = world.resources.get::<TradeRoutes>().await.unwrap().clone();
+    let json = serde_json::to_string(&routes).unwrap();
+    let mut restored: TradeRoutes = serde_json::from_str(&json).unwrap();
+    let restored_route = restored.get(&route).unwrap();
+    assert_eq!(restored_route.status, routes.get(&route).unwrap().status);
+    assert_eq!(restored_route.stats.income_lost, Currency::new(40));
+
+    // New IDs continue after the restored ones
+    let next = restored.add("west".into(), "capital".into(), "grain", 1, Vec::new());
+    assert_eq!(next.as_str(), "route-2");
+}
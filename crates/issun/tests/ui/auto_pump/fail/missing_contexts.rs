@@ -0,0 +1,10 @@
+use issun::auto_pump;
+
+struct Title;
+
+impl Title {
+    #[auto_pump]
+    async fn handle_input(&mut self, resources: &mut issun::prelude::ResourceContext) {}
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use issun::auto_pump;
+
+struct Title;
+
+impl Title {
+    #[auto_pump(beforehand)]
+    async fn handle_input(
+        &mut self,
+        services: &issun::prelude::ServiceContext,
+        systems: &mut issun::prelude::SystemContext,
+        resources: &mut issun::prelude::ResourceContext,
+    ) {
+    }
+}
+
+fn main() {}
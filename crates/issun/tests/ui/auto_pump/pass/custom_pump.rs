@@ -0,0 +1,28 @@
+use issun::auto_pump;
+use issun::prelude::*;
+
+async fn pump(
+    _services: &ServiceContext,
+    _systems: &mut SystemContext,
+    _resources: &mut ResourceContext,
+) {
+}
+
+struct Title;
+
+impl Title {
+    #[auto_pump(before, pump_fn = "pump", around = "publish")]
+    async fn handle_input(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) -> u32 {
+        auto_pump!();
+        1
+    }
+}
+
+fn main() {
+    let _ = Title::handle_input;
+}
@@ -0,0 +1,8 @@
+use issun::Entity;
+
+#[derive(Entity)]
+struct Player {
+    name: String,
+}
+
+fn main() {}
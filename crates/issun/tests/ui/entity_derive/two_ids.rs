@@ -0,0 +1,11 @@
+use issun::Entity;
+
+#[derive(Entity)]
+struct Player {
+    #[entity(id)]
+    name: String,
+    #[entity(id)]
+    slot: u32,
+}
+
+fn main() {}
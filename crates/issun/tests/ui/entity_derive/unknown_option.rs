@@ -0,0 +1,9 @@
+use issun::Entity;
+
+#[derive(Entity)]
+struct Player {
+    #[entity(key)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+struct Paddle;
+
+#[issun::event_handler]
+impl Paddle {
+    async fn on_ping(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use issun::event::Event;
+
+#[derive(Clone)]
+struct Ping;
+
+impl Event for Ping {}
+
+struct Paddle;
+
+#[issun::event_handler]
+impl Paddle {
+    #[subscribe(Ping)]
+    fn on_ping(&mut self, _event: &Ping) {}
+}
+
+fn main() {}
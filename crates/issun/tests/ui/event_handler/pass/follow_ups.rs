@@ -0,0 +1,25 @@
+use issun::event::{Event, EventWriter};
+
+#[derive(Clone, serde::Serialize)]
+struct Ping;
+
+impl Event for Ping {}
+
+#[derive(Clone, serde::Serialize)]
+struct Pong;
+
+impl Event for Pong {}
+
+struct Paddle;
+
+#[issun::event_handler]
+impl Paddle {
+    #[subscribe(Ping)]
+    async fn on_ping(&mut self, _event: &Ping, #[events] out: &mut EventWriter) {
+        out.send(Pong);
+    }
+}
+
+fn main() {
+    let _ = Paddle::process_events;
+}
@@ -0,0 +1,10 @@
+use issun::hook_impl;
+
+struct GameLogHook;
+
+#[hook_impl]
+impl GameLogHook {
+    fn on_action_consumed(&self) {}
+}
+
+fn main() {}
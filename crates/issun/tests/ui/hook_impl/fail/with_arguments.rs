@@ -0,0 +1,8 @@
+use issun::hook_impl;
+
+struct GameLogHook;
+
+#[hook_impl(ActionHook)]
+impl issun::plugin::action::ActionHook for GameLogHook {}
+
+fn main() {}
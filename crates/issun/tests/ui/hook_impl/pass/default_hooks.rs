@@ -0,0 +1,29 @@
+use issun::hook_impl;
+use issun::plugin::action::ActionHook;
+use issun::plugin::combat::CombatHook;
+use issun::plugin::loot::LootHook;
+
+struct Defaults;
+
+#[hook_impl]
+impl ActionHook for Defaults {}
+
+#[hook_impl]
+impl CombatHook for Defaults {}
+
+#[hook_impl]
+impl LootHook for Defaults {
+    fn on_loot_generated(
+        &self,
+        _source_id: &issun::plugin::loot::LootSourceId,
+        _items: &[String],
+        _rarity: issun::plugin::loot::Rarity,
+        _resources: &mut issun::context::ResourceContext,
+    ) {
+    }
+}
+
+fn main() {
+    let _hooks: (Box<dyn ActionHook>, Box<dyn CombatHook>, Box<dyn LootHook>) =
+        (Box::new(Defaults), Box::new(Defaults), Box::new(Defaults));
+}
@@ -0,0 +1,9 @@
+use issun::Plugin;
+
+#[derive(Clone, Default)]
+struct Score(u32);
+
+#[derive(Plugin)]
+struct ScoringPlugin(#[plugin(resource)] Score);
+
+fn main() {}
@@ -0,0 +1,12 @@
+use issun::Plugin;
+
+#[derive(Clone, Default)]
+struct Score(u32);
+
+#[derive(Plugin)]
+struct ScoringPlugin {
+    #[plugin(resorce)]
+    score: Score,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use issun::Plugin;
+
+#[derive(Plugin)]
+#[plugin(title = "scoring")]
+struct ScoringPlugin;
+
+fn main() {}
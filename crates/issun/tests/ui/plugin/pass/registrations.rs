@@ -0,0 +1,25 @@
+use issun::Plugin;
+
+#[derive(Clone, Default)]
+struct Score(u32);
+
+impl issun::resources::Resource for Score {}
+
+#[derive(Plugin)]
+#[plugin(name = "scoring")]
+struct ScoringPlugin {
+    #[plugin(resource)]
+    score: Score,
+    #[plugin(skip)]
+    #[allow(dead_code)]
+    label: String,
+}
+
+fn main() {
+    use issun::plugin::Plugin as _;
+    let plugin = ScoringPlugin {
+        score: Score(0),
+        label: String::new(),
+    };
+    assert_eq!(plugin.name(), "scoring");
+}
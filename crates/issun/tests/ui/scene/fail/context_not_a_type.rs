@@ -0,0 +1,9 @@
+use issun::Scene;
+
+#[derive(Scene)]
+#[scene(context = "Game Context", initial = "Title")]
+enum GameScene {
+    Title,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use issun::Scene;
+
+#[derive(Scene)]
+#[scene(ctx_init = "GameContext::new()")]
+enum GameScene {
+    Title,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use issun::context::{ResourceContext, ServiceContext, SystemContext};
+use issun::Scene;
+
+/// Has `on_enter` but not `on_exit`
+struct CombatData;
+
+impl CombatData {
+    async fn on_enter(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+    ) {
+    }
+}
+
+#[derive(Scene)]
+#[scene(delegate = "on_enter, on_exit")]
+enum GameScene {
+    Combat(CombatData),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use issun::Scene;
+
+struct CombatData;
+struct TitleScreen;
+
+#[derive(Scene)]
+#[scene(delegate = "on_enter")]
+enum GameScene {
+    #[scene(delegate_to = "TitleScreen")]
+    Combat(CombatData),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use issun::Scene;
+
+struct CombatData;
+
+#[derive(Scene)]
+#[scene(delegate = "on_enter, on_tick")]
+enum GameScene {
+    Combat(CombatData),
+}
+
+fn main() {}
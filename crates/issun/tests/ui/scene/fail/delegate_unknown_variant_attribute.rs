@@ -0,0 +1,12 @@
+use issun::Scene;
+
+struct TitleScreen;
+
+#[derive(Scene)]
+#[scene(delegate = "on_enter")]
+enum GameScene {
+    #[scene(forward_to = "TitleScreen")]
+    Title,
+}
+
+fn main() {}
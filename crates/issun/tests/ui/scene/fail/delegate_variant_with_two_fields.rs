@@ -0,0 +1,11 @@
+use issun::Scene;
+
+struct CombatData;
+
+#[derive(Scene)]
+#[scene(delegate = "on_enter")]
+enum GameScene {
+    Combat(CombatData, u32),
+}
+
+fn main() {}
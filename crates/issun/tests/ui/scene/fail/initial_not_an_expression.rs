@@ -0,0 +1,11 @@
+use issun::Scene;
+
+struct GameContext;
+
+#[derive(Scene)]
+#[scene(context = "GameContext", initial = "Title(")]
+enum GameScene {
+    Title,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use issun::Scene;
+
+struct GameContext;
+
+#[derive(Scene)]
+#[scene(context = "GameContext", initial = "Title")]
+struct GameScene;
+
+fn main() {}
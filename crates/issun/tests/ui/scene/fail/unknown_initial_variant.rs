@@ -0,0 +1,11 @@
+use issun::Scene;
+
+struct GameContext;
+
+#[derive(Scene)]
+#[scene(context = "GameContext", initial = "Menu")]
+enum GameScene {
+    Title,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use issun::Scene;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct GameContext;
+
+impl GameContext {
+    fn new() -> Self {
+        Self
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct TitleData;
+
+impl TitleData {
+    fn new() -> Self {
+        Self
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Scene)]
+#[scene(context = "GameContext", initial = "Title(TitleData::new())")]
+enum GameScene {
+    Title(TitleData),
+}
+
+fn main() {
+    let state = GameState::new();
+    assert!(matches!(state.scene, GameScene::Title(_)));
+}
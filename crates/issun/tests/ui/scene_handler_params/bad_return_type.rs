@@ -0,0 +1,11 @@
+use issun::Scene;
+
+struct TitleData;
+
+#[derive(Scene)]
+#[scene(handler_params = "input: u32", handler_return = "SceneTransition<")]
+enum GameScene {
+    Title(TitleData),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use issun::Scene;
+
+struct TitleData;
+
+#[derive(Scene)]
+#[scene(handler_params = "_: u32")]
+enum GameScene {
+    Title(TitleData),
+}
+
+fn main() {}
@@ -13,6 +13,20 @@ generate!({
 // Export the MOD implementation
 struct PandemicMod;
 
+/// Adjust the infection rate at the turns the pandemic changes phase
+fn advance_turn(turn: u32) {
+    if turn == 50 {
+        issun::modapi::api::log_warn("⚠️  Pandemic entering critical phase!");
+        issun::modapi::api::set_plugin_param("contagion", "infection_rate", "0.10");
+    } else if turn == 100 {
+        issun::modapi::api::log("🔴 PANDEMIC OUTBREAK!");
+        issun::modapi::api::set_plugin_param("contagion", "infection_rate", "0.15");
+    } else if turn == 200 {
+        issun::modapi::api::log("✅ Vaccine developed!");
+        issun::modapi::api::set_plugin_param("contagion", "infection_rate", "0.03");
+    }
+}
+
 impl Guest for PandemicMod {
     fn get_metadata() -> Metadata {
         Metadata {
@@ -24,30 +38,47 @@ impl Guest for PandemicMod {
     }
 
     fn on_init() {
-        issun::mod_::api::log("🦠 Wasm Pandemic MOD initialized!");
-        issun::mod_::api::enable_plugin("contagion");
-        issun::mod_::api::set_plugin_param("contagion", "infection_rate", "0.05");
-        issun::mod_::api::log("Initial infection rate: 5%");
+        issun::modapi::api::log("🦠 Wasm Pandemic MOD initialized!");
+        issun::modapi::api::enable_plugin("contagion");
+        issun::modapi::api::set_plugin_param("contagion", "infection_rate", "0.05");
+        issun::modapi::api::publish_event("PandemicStarted", r#"{"infection_rate":0.05}"#);
+        issun::modapi::api::log("Initial infection rate: 5%");
+        issun::modapi::api::subscribe_event("TurnAdvanced");
     }
 
     fn on_shutdown() {
-        issun::mod_::api::log("Wasm Pandemic MOD shutting down...");
+        issun::modapi::api::log("Wasm Pandemic MOD shutting down...");
     }
 
     fn on_control_plugin(plugin_name: String, action: String) {
         let msg = format!("Controlling plugin: {} - {}", plugin_name, action);
-        issun::mod_::api::log(&msg);
+        issun::modapi::api::log(&msg);
     }
 
-    fn call_custom(fn_name: String, args: Vec<String>) -> String {
+    fn on_event(event_type: String, payload_json: String) {
+        if event_type != "TurnAdvanced" {
+            return;
+        }
+        // Payload: {"turn": 50}
+        let turn = serde_json::from_str::<serde_json::Value>(&payload_json)
+            .ok()
+            .and_then(|payload| payload.get("turn").and_then(|turn| turn.as_u64()))
+            .unwrap_or(0);
+        advance_turn(turn as u32);
+    }
+
+    fn call_custom(fn_name: String, args_json: String) -> String {
+        // Arguments arrive as one JSON array
+        let args: Vec<serde_json::Value> = serde_json::from_str(&args_json).unwrap_or_default();
+
         match fn_name.as_str() {
             "calculate_risk" => {
                 // Parse arguments
-                let infection_count: f64 = args.get(0)
-                    .and_then(|s| s.parse().ok())
+                let infection_count = args.get(0)
+                    .and_then(|v| v.as_f64())
                     .unwrap_or(0.0);
-                let population: f64 = args.get(1)
-                    .and_then(|s| s.parse().ok())
+                let population = args.get(1)
+                    .and_then(|v| v.as_f64())
                     .unwrap_or(1.0);
 
                 let risk = infection_count / population;
@@ -68,23 +99,18 @@ impl Guest for PandemicMod {
                 }).to_string()
             }
             "tick" => {
-                let turn: u32 = args.get(0)
-                    .and_then(|s| s.parse().ok())
-                    .unwrap_or(0);
-
-                if turn == 50 {
-                    issun::mod_::api::log("⚠️  Pandemic entering critical phase!");
-                    issun::mod_::api::set_plugin_param("contagion", "infection_rate", "0.10");
-                } else if turn == 100 {
-                    issun::mod_::api::log("🔴 PANDEMIC OUTBREAK!");
-                    issun::mod_::api::set_plugin_param("contagion", "infection_rate", "0.15");
-                } else if turn == 200 {
-                    issun::mod_::api::log("✅ Vaccine developed!");
-                    issun::mod_::api::set_plugin_param("contagion", "infection_rate", "0.03");
-                }
+                let turn = args.get(0)
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(0) as u32;
+
+                advance_turn(turn);
 
                 serde_json::json!({ "turn": turn }).to_string()
             }
+            "echo" => {
+                // Return the arguments unchanged, after a parse round trip
+                serde_json::Value::Array(args).to_string()
+            }
             _ => {
                 serde_json::json!({ "error": "Unknown function" }).to_string()
             }
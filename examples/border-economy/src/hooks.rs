@@ -1,25 +1,21 @@
 //! Custom hooks for border-economy
 
-use async_trait::async_trait;
 use issun::context::ResourceContext;
-use issun::event::EventBus;
+use issun::hook_impl;
 use issun::plugin::accounting::{AccountingHook, BudgetChannel, BudgetLedger, Currency};
 use issun::plugin::action::{ActionConsumed, ActionHook};
-use issun::plugin::research::{
-    ResearchHook, ResearchProject, ResearchQueueRequested, ResearchResult,
-};
+use issun::plugin::research::{ResearchHook, ResearchProject, ResearchResult};
 use issun::plugin::territory::{ControlChanged, Developed, Territory, TerritoryHook};
 
-use crate::events::{FieldTestFeedback, ResearchQueued};
 use crate::models::GameContext;
 use crate::plugins::{EconomyState, PrototypeBacklog};
 
 /// Hook that logs actions to GameContext
 pub struct GameLogHook;
 
-#[async_trait]
+#[hook_impl]
 impl ActionHook for GameLogHook {
-    async fn on_action_consumed(
+    fn on_action_consumed(
         &self,
         consumed: &ActionConsumed,
         resources: &mut ResourceContext,
@@ -39,12 +35,7 @@ impl ActionHook for GameLogHook {
         }
     }
 
-    async fn on_actions_depleted(&self, _resources: &mut ResourceContext) -> bool {
-        // Always allow auto-advance when depleted
-        true
-    }
-
-    async fn on_actions_reset(&self, new_count: u32, resources: &mut ResourceContext) {
+    fn on_actions_reset(&self, new_count: u32, resources: &mut ResourceContext) {
         if let Some(mut ctx) = resources.get_mut::<GameContext>().await {
             ctx.record(format!("新しい日が始まりました。行動ポイント: {}", new_count));
         }
@@ -54,9 +45,9 @@ impl ActionHook for GameLogHook {
 /// Hook that logs territory changes to GameContext
 pub struct BorderEconomyTerritoryHook;
 
-#[async_trait]
+#[hook_impl]
 impl TerritoryHook for BorderEconomyTerritoryHook {
-    async fn on_control_changed(
+    fn on_control_changed(
         &self,
         territory: &Territory,
         change: &ControlChanged,
@@ -87,7 +78,7 @@ impl TerritoryHook for BorderEconomyTerritoryHook {
         }
     }
 
-    async fn calculate_development_cost(
+    fn calculate_development_cost(
         &self,
         _territory: &Territory,
         current_level: u32,
@@ -105,7 +96,7 @@ impl TerritoryHook for BorderEconomyTerritoryHook {
         Ok(final_cost)
     }
 
-    async fn on_developed(
+    fn on_developed(
         &self,
         territory: &Territory,
         developed: &Developed,
@@ -131,9 +122,9 @@ impl TerritoryHook for BorderEconomyTerritoryHook {
 /// Hook that bridges ResearchPlugin with border-economy's prototype system
 pub struct PrototypeResearchHook;
 
-#[async_trait]
+#[hook_impl]
 impl ResearchHook for PrototypeResearchHook {
-    async fn on_research_queued(
+    fn on_research_queued(
         &self,
         project: &ResearchProject,
         resources: &mut ResourceContext,
@@ -153,7 +144,7 @@ impl ResearchHook for PrototypeResearchHook {
         }
     }
 
-    async fn on_research_completed(
+    fn on_research_completed(
         &self,
         project: &ResearchProject,
         result: &ResearchResult,
@@ -177,9 +168,9 @@ impl ResearchHook for PrototypeResearchHook {
 /// Hook that implements border-economy's settlement logic
 pub struct BorderEconomyAccountingHook;
 
-#[async_trait]
+#[hook_impl]
 impl AccountingHook for BorderEconomyAccountingHook {
-    async fn calculate_income(&self, _period: u32, resources: &ResourceContext) -> Currency {
+    fn calculate_income(&self, _period: u32, resources: &ResourceContext) -> Currency {
         // Get base income from GameContext
         let base_income = if let Some(ctx) = resources.get::<GameContext>().await {
             ctx.base_income()
@@ -197,7 +188,7 @@ impl AccountingHook for BorderEconomyAccountingHook {
         Currency::new(base_income + innovation_bonus)
     }
 
-    async fn calculate_expenses(&self, _period: u32, resources: &ResourceContext) -> Currency {
+    fn calculate_expenses(&self, _period: u32, resources: &ResourceContext) -> Currency {
         // Get base upkeep from GameContext
         let base_upkeep = if let Some(ctx) = resources.get::<GameContext>().await {
             ctx.base_upkeep()
@@ -215,7 +206,7 @@ impl AccountingHook for BorderEconomyAccountingHook {
         Currency::new((base_upkeep - security_offset).max(0))
     }
 
-    async fn after_settlement(
+    fn after_settlement(
         &self,
         _period: u32,
         income: Currency,
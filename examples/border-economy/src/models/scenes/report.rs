@@ -1,54 +1,33 @@
-use crate::models::{GameContext, GameScene};
+use crate::models::context::{FactionProfile, TerritoryIntel};
+use crate::models::{GameContext, GameScene, WeaponPrototypeState};
 use issun::auto_pump;
 use issun::prelude::{ResourceContext, SceneTransition, ServiceContext, SystemContext};
+use issun::ui::ratatui::{DataTable, TableColumn, TableState};
 use issun::ui::InputEvent;
+use ratatui::layout::Constraint;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntelReportSceneData {
     pub focus: usize,
-    pub faction_lines: Vec<String>,
-    pub territory_lines: Vec<String>,
-    pub prototype_lines: Vec<String>,
+    pub factions: Vec<FactionProfile>,
+    pub territories: Vec<TerritoryIntel>,
+    pub prototypes: Vec<WeaponPrototypeState>,
+    pub faction_table: TableState,
+    pub territory_table: TableState,
+    pub prototype_table: TableState,
 }
 
 impl IntelReportSceneData {
     pub fn from_context(ctx: &GameContext) -> Self {
-        let faction_lines = ctx
-            .factions
-            .iter()
-            .map(|f| format!("{}: 士気{}", f.codename, f.readiness))
-            .collect();
-        let territory_lines = ctx
-            .territories
-            .iter()
-            .map(|t| {
-                format!(
-                    "{}: 支配率 {:>3.0}% / 不安 {:>3.0}%",
-                    t.id.as_str(),
-                    t.control * 100.0,
-                    t.unrest * 100.0
-                )
-            })
-            .collect();
-        let prototype_lines = ctx
-            .prototypes
-            .iter()
-            .map(|p| {
-                format!(
-                    "{}: 完成度 {:>3.0}% / 品質 {:>3.0}%",
-                    p.codename,
-                    p.progress * 100.0,
-                    p.quality * 100.0
-                )
-            })
-            .collect();
-
         Self {
             focus: 0,
-            faction_lines,
-            territory_lines,
-            prototype_lines,
+            factions: ctx.factions.clone(),
+            territories: ctx.territories.clone(),
+            prototypes: ctx.prototypes.clone(),
+            faction_table: TableState::new(),
+            territory_table: TableState::new(),
+            prototype_table: TableState::new(),
         }
     }
 
@@ -61,23 +40,104 @@ impl IntelReportSceneData {
         input: InputEvent,
     ) -> SceneTransition<GameScene> {
         let transition = match input {
-            InputEvent::Left => {
-                if self.focus == 0 {
-                    self.focus = 2;
-                } else {
-                    self.focus -= 1;
-                }
-                SceneTransition::Stay
-            }
-            InputEvent::Right => {
+            InputEvent::Tab => {
                 self.focus = (self.focus + 1) % 3;
                 SceneTransition::Stay
             }
             InputEvent::Cancel | InputEvent::Select => SceneTransition::Switch(
                 GameScene::Strategy(super::strategy::StrategySceneData::new()),
             ),
-            _ => SceneTransition::Stay,
+            _ => {
+                match self.focus {
+                    0 => faction_table().handle_key(input, &self.factions, &mut self.faction_table),
+                    1 => territory_table().handle_key(
+                        input,
+                        &self.territories,
+                        &mut self.territory_table,
+                    ),
+                    _ => prototype_table().handle_key(
+                        input,
+                        &self.prototypes,
+                        &mut self.prototype_table,
+                    ),
+                };
+                SceneTransition::Stay
+            }
         };
         transition
     }
 }
+
+fn percent(value: f32) -> String {
+    format!("{:>3.0}%", value * 100.0)
+}
+
+pub fn faction_table<'a>() -> DataTable<'a, FactionProfile> {
+    DataTable::new(|f: &FactionProfile| f.id.as_str().to_string())
+        .column(
+            TableColumn::new("Codename", Constraint::Min(8), |f: &FactionProfile| {
+                f.codename.clone()
+            })
+            .sort_by_key(|f: &FactionProfile| f.codename.clone()),
+        )
+        .column(
+            TableColumn::new("士気", Constraint::Length(6), |f: &FactionProfile| {
+                format!("{:>3}", f.readiness)
+            })
+            .sort_by_key(|f: &FactionProfile| f.readiness),
+        )
+}
+
+pub fn territory_table<'a>() -> DataTable<'a, TerritoryIntel> {
+    DataTable::new(|t: &TerritoryIntel| t.id.as_str().to_string())
+        .column(
+            TableColumn::new("Territory", Constraint::Min(8), |t: &TerritoryIntel| {
+                t.id.as_str().to_string()
+            })
+            .sort_by_key(|t: &TerritoryIntel| t.id.as_str().to_string()),
+        )
+        .column(
+            TableColumn::new("支配率", Constraint::Length(8), |t: &TerritoryIntel| {
+                percent(t.control)
+            })
+            .sort_by(|a: &TerritoryIntel, b: &TerritoryIntel| a.control.total_cmp(&b.control)),
+        )
+        .column(
+            TableColumn::new("不安", Constraint::Length(6), |t: &TerritoryIntel| {
+                percent(t.unrest)
+            })
+            .sort_by(|a: &TerritoryIntel, b: &TerritoryIntel| a.unrest.total_cmp(&b.unrest)),
+        )
+}
+
+pub fn prototype_table<'a>() -> DataTable<'a, WeaponPrototypeState> {
+    DataTable::new(|p: &WeaponPrototypeState| p.id.as_str().to_string())
+        .column(
+            TableColumn::new(
+                "Codename",
+                Constraint::Min(8),
+                |p: &WeaponPrototypeState| p.codename.clone(),
+            )
+            .sort_by_key(|p: &WeaponPrototypeState| p.codename.clone()),
+        )
+        .column(
+            TableColumn::new(
+                "完成度",
+                Constraint::Length(8),
+                |p: &WeaponPrototypeState| percent(p.progress),
+            )
+            .sort_by(|a: &WeaponPrototypeState, b: &WeaponPrototypeState| {
+                a.progress.total_cmp(&b.progress)
+            }),
+        )
+        .column(
+            TableColumn::new(
+                "品質",
+                Constraint::Length(6),
+                |p: &WeaponPrototypeState| percent(p.quality),
+            )
+            .sort_by(|a: &WeaponPrototypeState, b: &WeaponPrototypeState| {
+                a.quality.total_cmp(&b.quality)
+            }),
+        )
+}
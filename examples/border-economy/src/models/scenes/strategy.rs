@@ -7,7 +7,7 @@ use crate::models::{DemandProfile, GameContext, GameScene, WeaponPrototypeState}
 use crate::plugins::EconomyState;
 use issun::auto_pump;
 use issun::event::EventBus;
-use issun::plugin::action::{ActionConsumedEvent, ActionError, ActionPoints};
+use issun::plugin::action::{ActionConsumedEvent, ActionPoints};
 use issun::prelude::{ResourceContext, SceneTransition, ServiceContext, SystemContext};
 use issun::ui::InputEvent;
 use serde::{Deserialize, Serialize};
@@ -195,8 +195,9 @@ impl StrategySceneData {
                         });
                     }
                 }
-                Err(ActionError::Depleted) => {
-                    // No actions remaining, should not happen here
+                Err(_) => {
+                    // Plain actions cost one point, so this only fails when
+                    // depleted, which should not happen here
                 }
             }
         }
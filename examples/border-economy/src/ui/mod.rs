@@ -1,3 +1,4 @@
+use crate::models::scenes::report::{faction_table, prototype_table, territory_table};
 use crate::models::scenes::{
     EconomicSceneData, IntelReportSceneData, StrategySceneData, TacticalSceneData, TitleSceneData,
     VaultSceneData,
@@ -800,45 +801,38 @@ pub fn render_report(
         ])
         .split(layout[0]);
 
-    let render_column =
-        |frame: &mut Frame, area: Rect, title: &str, lines: &[String], active: bool| {
-            let style = if active {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(Color::Gray)
-            };
-            let list = List::new(
-                lines
-                    .iter()
-                    .map(|line| ListItem::new(Span::styled(line.clone(), style)))
-                    .collect::<Vec<_>>(),
-            )
-            .block(Block::default().title(title).borders(Borders::ALL));
-            frame.render_widget(list, area);
+    let panel = |frame: &mut Frame, area: Rect, title: &str, active: bool| {
+        let style = if active {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
         };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(style);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        inner
+    };
 
-    render_column(
-        frame,
-        columns[0],
-        "Factions",
-        &data.faction_lines,
-        data.focus == 0,
-    );
-    render_column(
+    let inner = panel(frame, columns[0], "Factions", data.focus == 0);
+    faction_table().render(frame, inner, &data.factions, &mut data.faction_table.clone());
+    let inner = panel(frame, columns[1], "Territories", data.focus == 1);
+    territory_table().render(
         frame,
-        columns[1],
-        "Territories",
-        &data.territory_lines,
-        data.focus == 1,
+        inner,
+        &data.territories,
+        &mut data.territory_table.clone(),
     );
-    render_column(
+    let inner = panel(frame, columns[2], "Prototypes", data.focus == 2);
+    prototype_table().render(
         frame,
-        columns[2],
-        "Prototypes",
-        &data.prototype_lines,
-        data.focus == 2,
+        inner,
+        &data.prototypes,
+        &mut data.prototype_table.clone(),
     );
 
     render_report_summary(frame, layout[1], ctx, clock, ledger, territory, prototypes, reputation);
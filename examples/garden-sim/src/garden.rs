@@ -10,10 +10,17 @@ use issun::plugin::entropy::{
 };
 use issun::plugin::generation::{
     Generation, GenerationConfig, GenerationEnvironment, GenerationStateECS, GenerationSystemECS,
-    GenerationType,
+    GenerationType, ResourceConsumption, ResourceStock,
 };
 use std::sync::Arc;
 
+/// Water each plant drinks per tick
+const WATER_PER_PLANT: u32 = 1;
+/// Water the well refills per tick
+const WELL_REFILL: u32 = 3;
+/// Water the tank starts with
+const INITIAL_WATER: u32 = 20;
+
 /// Garden simulation state
 pub struct Garden {
     pub generation_system: GenerationSystemECS,
@@ -25,6 +32,11 @@ pub struct Garden {
     pub entropy_config: EntropyConfig,
 
     pub plants: Vec<(Entity, PlantSpecies)>,
+
+    /// Shared water tank plants drink from
+    pub water: ResourceStock,
+    /// Plants that went thirsty last tick
+    pub thirsty: usize,
 }
 
 impl Garden {
@@ -32,16 +44,26 @@ impl Garden {
         let generation_hook = Arc::new(GardenGenerationHook);
         let entropy_hook = Arc::new(GardenEntropyHook);
 
+        // Thirsty plants grow at a quarter of their rate
+        let mut generation_config = GenerationConfig::default();
+        generation_config.consumption.insert(
+            GenerationType::Organic,
+            ResourceConsumption::new().per_tick("water", WATER_PER_PLANT),
+        );
+
         Self {
             generation_system: GenerationSystemECS::new(generation_hook),
             generation_state: GenerationStateECS::new(),
-            generation_config: GenerationConfig::default(),
+            generation_config,
 
             entropy_system: EntropySystemECS::new(entropy_hook),
             entropy_state: EntropyStateECS::new(),
             entropy_config: EntropyConfig::default(),
 
             plants: Vec::new(),
+
+            water: ResourceStock::new().with("water", INITIAL_WATER),
+            thirsty: 0,
         }
     }
 
@@ -106,15 +128,26 @@ impl Garden {
             prev_states.push((was_ready, was_dying));
         }
 
-        // Update generation (plants grow)
+        // Update generation (plants grow and drink)
+        self.water.add("water", WELL_REFILL);
         self.generation_system
-            .update_generation(
+            .update_generation_with_provider(
                 &mut self.generation_state,
                 &self.generation_config,
                 delta_time,
+                &mut self.water,
             )
             .await;
 
+        let thirsty = self.generation_state.metrics().entities_starved;
+        if thirsty > self.thirsty {
+            event_log.log(format!(
+                "💧 {} plant(s) are thirsty, growth slowed",
+                thirsty
+            ));
+        }
+        self.thirsty = thirsty;
+
         // Update entropy (plants decay)
         self.entropy_system
             .update_decay(&mut self.entropy_state, &self.entropy_config, delta_time)
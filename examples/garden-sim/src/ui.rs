@@ -29,7 +29,7 @@ pub fn render_simulation(
             Constraint::Length(3), // Header
             Constraint::Min(10),   // Plant list
             Constraint::Length(8), // Event log
-            Constraint::Length(5), // Metrics
+            Constraint::Length(6), // Metrics
             Constraint::Length(3), // Footer
         ])
         .split(area);
@@ -180,6 +180,19 @@ fn render_metrics(frame: &mut Frame, area: ratatui::layout::Rect, garden: &Garde
             ),
             Span::raw(" total decay"),
         ]),
+        Line::from(vec![
+            Span::raw("  Water: "),
+            Span::styled(
+                format!("{}", garden.water.get("water")),
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw(" in tank, "),
+            Span::styled(
+                format!("{}", garden.thirsty),
+                Style::default().fg(Color::LightBlue),
+            ),
+            Span::raw(" thirsty"),
+        ]),
     ];
 
     let metrics = Paragraph::new(lines).block(Block::default().borders(Borders::ALL));
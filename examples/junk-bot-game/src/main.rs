@@ -10,10 +10,14 @@ mod systems;
 mod ui;
 
 use issun::engine::GameRunner;
+use issun::localization::Localization;
+use issun::plugin::save_load::SaveLoadPlugin;
 use issun::prelude::*;
+use issun::ui::ratatui::RatatuiTheme;
 use issun::ui::Tui;
 use models::{handle_scene_input, GameContext, GameScene};
 use std::time::Duration;
+use systems::save::JunkBotSaveHook;
 
 const TICK_RATE: Duration = Duration::from_millis(33); // 30 FPS
 
@@ -30,6 +34,8 @@ async fn main() -> std::io::Result<()> {
         .map_err(|e| std::io::Error::other(e.to_string()))?
         .with_plugin(LootPlugin::default())
         .map_err(|e| std::io::Error::other(e.to_string()))?
+        .with_plugin(SaveLoadPlugin::new().with_hook(JunkBotSaveHook))
+        .map_err(|e| std::io::Error::other(e.to_string()))?
         .build()
         .await
         .map_err(|e| std::io::Error::other(e.to_string()))?;
@@ -56,7 +62,12 @@ async fn main() -> std::io::Result<()> {
             &mut tui,
             |frame, scene, resources| {
                 if let Some(state) = resources.try_get::<GameContext>() {
-                    render_scene(frame, scene, &state);
+                    let theme = resources
+                        .try_get::<RatatuiTheme>()
+                        .map(|theme| theme.clone())
+                        .unwrap_or_default();
+                    let localization = resources.try_get::<Localization>();
+                    render_scene(frame, scene, &state, &theme, localization.as_deref());
                 }
             },
             |scene, services, systems, resources, input| {
@@ -74,7 +85,13 @@ async fn main() -> std::io::Result<()> {
 }
 
 /// Render the current scene
-fn render_scene(frame: &mut ratatui::Frame, scene: &GameScene, ctx: &models::GameContext) {
+fn render_scene(
+    frame: &mut ratatui::Frame,
+    scene: &GameScene,
+    ctx: &models::GameContext,
+    theme: &RatatuiTheme,
+    localization: Option<&Localization>,
+) {
     match scene {
         GameScene::Title(data) => ui::render_title(frame, data),
         GameScene::RoomSelection(data) => ui::render_room_selection(frame, data),
@@ -83,6 +100,8 @@ fn render_scene(frame: &mut ratatui::Frame, scene: &GameScene, ctx: &models::Gam
         GameScene::CardSelection(data) => ui::render_card_selection(frame, data),
         GameScene::Floor4Choice(data) => ui::render_floor4_choice(frame, data),
         GameScene::Result(data) => render_result(frame, data),
+        GameScene::Pause(data) => ui::render_pause(frame, data, theme),
+        GameScene::SaveMenu(data) => ui::render_save_menu(frame, data, theme, localization),
     }
 }
 
@@ -23,4 +23,6 @@ pub enum GameScene {
     CardSelection(CardSelectionSceneData),
     Floor4Choice(Floor4ChoiceSceneData),
     Result(ResultSceneData),
+    Pause(PauseSceneData),
+    SaveMenu(SaveMenuSceneData),
 }
@@ -4,7 +4,7 @@ use crate::models::entities::{Enemy, RoomBuff, Weapon};
 use crate::models::{
     proceed_to_next_floor,
     scene_helpers::generate_drops,
-    scenes::{DropCollectionSceneData, ResultSceneData},
+    scenes::{DropCollectionSceneData, PauseSceneData, ResultSceneData},
     GameContext, GameScene,
 };
 use issun::prelude::{
@@ -174,6 +174,9 @@ impl CombatSceneData {
             .expect("GameContext resource not registered");
         match input {
             InputEvent::Cancel => SceneTransition::Quit,
+            InputEvent::Char('p') | InputEvent::Char('P') => {
+                SceneTransition::Push(GameScene::Pause(PauseSceneData::new()))
+            }
             InputEvent::Char('i') | InputEvent::Char('I') => {
                 // Toggle inventory
                 self.toggle_inventory();
@@ -6,14 +6,18 @@ mod card_selection;
 mod combat;
 mod drop_collection;
 mod floor4_choice;
+mod pause;
 mod result;
 mod room_selection;
+mod save_menu;
 mod title;
 
 pub use card_selection::CardSelectionSceneData;
 pub use combat::CombatSceneData;
 pub use drop_collection::DropCollectionSceneData;
 pub use floor4_choice::Floor4ChoiceSceneData;
+pub use pause::{PauseSceneData, PAUSE_ITEMS};
 pub use result::ResultSceneData;
 pub use room_selection::RoomSelectionSceneData;
+pub use save_menu::SaveMenuSceneData;
 pub use title::TitleSceneData;
@@ -0,0 +1,64 @@
+//! Pause scene data
+
+use crate::models::{
+    scenes::{SaveMenuSceneData, TitleSceneData},
+    GameScene,
+};
+use crate::systems::save::SAVE_SLOTS;
+use issun::plugin::save_load::SaveLoadMenu;
+use issun::prelude::{ResourceContext, SceneTransition, ServiceContext, SystemContext};
+use issun::ui::InputEvent;
+use serde::{Deserialize, Serialize};
+
+/// Pause menu entries
+pub const PAUSE_ITEMS: [&str; 3] = ["Resume", "Save / Load", "Quit to Title"];
+
+/// Pushed over combat; popping it resumes the fight
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PauseSceneData {
+    pub selected_index: usize,
+}
+
+impl PauseSceneData {
+    pub fn new() -> Self {
+        Self { selected_index: 0 }
+    }
+
+    pub async fn handle_input(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+        input: InputEvent,
+    ) -> SceneTransition<GameScene> {
+        match input {
+            InputEvent::Cancel | InputEvent::Char('p') | InputEvent::Char('P') => {
+                SceneTransition::Pop
+            }
+            InputEvent::Up => {
+                self.selected_index = self.selected_index.saturating_sub(1);
+                SceneTransition::Stay
+            }
+            InputEvent::Down => {
+                self.selected_index = (self.selected_index + 1).min(PAUSE_ITEMS.len() - 1);
+                SceneTransition::Stay
+            }
+            InputEvent::Select => match self.selected_index {
+                0 => SceneTransition::Pop,
+                1 => {
+                    let mut menu = SaveLoadMenu::new(SAVE_SLOTS);
+                    menu.refresh(services, systems, resources).await;
+                    SceneTransition::Push(GameScene::SaveMenu(SaveMenuSceneData { menu }))
+                }
+                _ => SceneTransition::Replace(GameScene::Title(TitleSceneData::new())),
+            },
+            _ => SceneTransition::Stay,
+        }
+    }
+}
+
+impl Default for PauseSceneData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,43 @@
+//! Save menu scene data
+
+use crate::models::{
+    scenes::{CombatSceneData, TitleSceneData},
+    GameContext, GameScene,
+};
+use issun::plugin::save_load::SaveLoadMenu;
+use issun::prelude::{ResourceContext, SceneTransition, ServiceContext, SystemContext};
+use issun::ui::InputEvent;
+use serde::{Deserialize, Serialize};
+
+/// Save/load menu, pushed from the pause menu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveMenuSceneData {
+    pub menu: SaveLoadMenu,
+}
+
+impl SaveMenuSceneData {
+    pub async fn handle_input(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+        input: InputEvent,
+    ) -> SceneTransition<GameScene> {
+        self.menu
+            .handle_input(services, systems, resources, input, |_loaded, resources| {
+                SceneTransition::Replace(resume_scene(resources))
+            })
+            .await
+    }
+}
+
+/// Restart the current room of the loaded run
+fn resume_scene(resources: &ResourceContext) -> GameScene {
+    let room = resources
+        .try_get::<GameContext>()
+        .and_then(|ctx| ctx.get_dungeon()?.get_current_room().cloned());
+    match room {
+        Some(room) => GameScene::Combat(CombatSceneData::from_room(room)),
+        None => GameScene::Title(TitleSceneData::new()),
+    }
+}
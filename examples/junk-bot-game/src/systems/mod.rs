@@ -9,3 +9,5 @@
 //! - Logic is too complex for Entity methods
 //! - External API clients are needed
 //! - Cross-entity orchestration is required
+
+pub mod save;
@@ -0,0 +1,41 @@
+//! Save/load integration
+//!
+//! Stores `GameContext` in the save snapshot and restores it on load.
+
+use crate::models::GameContext;
+use issun::plugin::save_load::SaveLoadHook;
+use issun::prelude::ResourceContext;
+use issun::storage::save_data::SaveData;
+
+/// Save slots shown in the save menu
+pub const SAVE_SLOTS: [&str; 3] = ["slot1", "slot2", "slot3"];
+
+/// Puts `GameContext` under `"game"` in every save
+pub struct JunkBotSaveHook;
+
+#[async_trait::async_trait]
+impl SaveLoadHook for JunkBotSaveHook {
+    async fn before_save(&self, save_data: &mut SaveData, resources: &mut ResourceContext) -> bool {
+        let Some(ctx) = resources.get::<GameContext>().await else {
+            return false;
+        };
+        match serde_json::to_value(&*ctx) {
+            Ok(game) => {
+                save_data.data["game"] = game;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    async fn after_load(&self, save_data: &SaveData, resources: &mut ResourceContext) {
+        let Some(game) = save_data.data.get("game") else {
+            return;
+        };
+        if let Ok(loaded) = serde_json::from_value::<GameContext>(game.clone()) {
+            if let Some(mut ctx) = resources.get_mut::<GameContext>().await {
+                *ctx = loaded;
+            }
+        }
+    }
+}
@@ -344,9 +344,10 @@ fn render_combat_log(frame: &mut Frame, area: Rect, data: &CombatSceneData) {
 }
 
 fn render_controls(frame: &mut Frame, area: Rect) {
-    let controls = Paragraph::new("Space: Attack | I: Inventory | Tab: Change Target | Q: Quit")
-        .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::DarkGray));
+    let controls =
+        Paragraph::new("Space: Attack | I: Inventory | Tab: Change Target | P: Pause | Q: Quit")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray));
 
     frame.render_widget(controls, area);
 }
@@ -6,12 +6,16 @@ mod card_selection;
 mod combat;
 mod drop_collection;
 mod floor4_choice;
+mod pause;
 mod room_selection;
+mod save_menu;
 mod title;
 
 pub use card_selection::render_card_selection;
 pub use combat::render_combat;
 pub use drop_collection::render_drop_collection;
 pub use floor4_choice::render_floor4_choice;
+pub use pause::render_pause;
 pub use room_selection::render_room_selection;
+pub use save_menu::render_save_menu;
 pub use title::render_title;
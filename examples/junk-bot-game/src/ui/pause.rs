@@ -0,0 +1,16 @@
+//! Pause menu rendering
+
+use crate::models::scenes::{PauseSceneData, PAUSE_ITEMS};
+use issun::ui::ratatui::{centered_rect, MenuWidget, RatatuiTheme};
+use ratatui::{widgets::Clear, Frame};
+
+pub fn render_pause(frame: &mut Frame, data: &PauseSceneData, theme: &RatatuiTheme) {
+    let area = centered_rect(frame.area(), 0.4, 0.4);
+    frame.render_widget(Clear, area);
+
+    MenuWidget::new(PAUSE_ITEMS.iter().map(|item| item.to_string()).collect())
+        .with_theme(theme)
+        .with_title("Paused")
+        .with_selected(data.selected_index)
+        .render(frame, area);
+}
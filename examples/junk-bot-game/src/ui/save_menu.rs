@@ -0,0 +1,19 @@
+//! Save menu rendering
+
+use crate::models::scenes::SaveMenuSceneData;
+use issun::localization::Localization;
+use issun::ui::ratatui::{RatatuiTheme, SaveMenuWidget};
+use ratatui::Frame;
+
+pub fn render_save_menu(
+    frame: &mut Frame,
+    data: &SaveMenuSceneData,
+    theme: &RatatuiTheme,
+    localization: Option<&Localization>,
+) {
+    let mut widget = SaveMenuWidget::new(&data.menu).with_theme(theme);
+    if let Some(localization) = localization {
+        widget = widget.with_localization(localization);
+    }
+    widget.render(frame, frame.area());
+}
@@ -62,6 +62,11 @@ impl Event for BallUpdate {
     fn network_scope() -> NetworkScope {
         NetworkScope::Broadcast
     }
+
+    // Only the newest ball position matters
+    fn is_latest_only() -> bool {
+        true
+    }
 }
 
 /// Local event: User input
@@ -42,8 +42,18 @@ use std::time::Duration;
 ///
 /// Note: messages attribute would use add_event(), but issun-bevy uses add_message()
 /// So event registration is done manually in Plugin::build()
+///
+/// Systems: the world is set up in order at startup; the event handlers
+/// (not turn-based logic) react after the contagion logic each frame.
 #[derive(Default, IssunBevyPlugin)]
-#[plugin(name = "pandemic_crisis")]
+#[plugin(
+    name = "pandemic_crisis",
+    systems(
+        startup(chain) = "setup_world, setup_player, spawn_initial_disease",
+        update(IssunSet::PostLogic) = "handle_contagion_spawned, handle_contagion_spread, \
+                                       handle_state_changes, handle_propagation_complete",
+    )
+)]
 pub struct PandemicCrisisPlugin {
     #[resource]
     pub game_state: GameState,
@@ -116,21 +126,6 @@ fn main() -> io::Result<()> {
             .with_event_log_size(20)
     );
 
-    // Startup systems
-    app.add_systems(Startup, (
-        setup_world,
-        setup_player,
-        spawn_initial_disease,
-    ).chain());
-
-    // Game loop systems (event handlers only, not turn-based logic)
-    app.add_systems(Update, (
-        handle_contagion_spawned,
-        handle_contagion_spread,
-        handle_state_changes,
-        handle_propagation_complete,
-    ));
-
     // Initialize
     app.update();
 
@@ -13,6 +13,11 @@ pub struct Arena {
     pub inventory: Inventory,
     pub combat: CombatManager,
     pub difficulty_multiplier: f32,
+    /// Gold granted by MODs through the host function `add_gold(amount)`
+    pub gold: i64,
+    /// MODs that failed to load or threw in a callback, shown in the MOD panel
+    #[serde(default)]
+    pub broken_mods: Vec<String>,
 }
 
 impl Arena {
@@ -27,6 +32,8 @@ impl Arena {
             inventory: Inventory::new(max_slots, allow_stacking),
             combat: CombatManager::new(),
             difficulty_multiplier: 1.0,
+            gold: 0,
+            broken_mods: Vec::new(),
         };
 
         // Add starting items
@@ -12,16 +12,19 @@ mod ui;
 use arena::Arena;
 use combat_state::CombatState;
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
-use issun::modding::{ModLoadRequested, ModSystemPlugin, ModUnloadRequested};
+use issun::modding::{
+    update_mod_systems, ModLoadFailedEvent, ModLoadRequested, ModLoadedEvent, ModLogEvent,
+    ModRegistry, ModRuntimeErrorEvent, ModSystemPlugin, ModUnloadRequested,
+};
+use issun::plugin::{CombatConfig, InventoryConfig};
 use issun::prelude::*;
-use issun::system::System;
 use issun_mod_rhai::RhaiLoader;
-use ratatui::{
-    backend::CrosstermBackend,
-    Terminal,
-};
+use issun_mod_wasm::WasmLoader;
+use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 const TICK_RATE: Duration = Duration::from_millis(100);
@@ -39,9 +42,30 @@ async fn main() -> io::Result<()> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Initialize ISSUN framework with MOD system
+    // Game-specific script API: add_gold(amount)
+    let gold = Arc::new(AtomicI64::new(0));
+    let loader = RhaiLoader::new().with_engine_setup({
+        let gold = gold.clone();
+        move |engine| {
+            let gold = gold.clone();
+            engine.register_fn("add_gold", move |amount: i64| {
+                gold.fetch_add(amount, Ordering::Relaxed);
+            });
+        }
+    });
+
+    let wasm_loader =
+        WasmLoader::new().map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+
+    // Initialize ISSUN framework with MOD system; every MOD in mods/ loads at startup,
+    // the scripts through the Rhai loader and the herald through the Wasm loader
     let game = GameBuilder::new()
-        .with_plugin(ModSystemPlugin::new().with_loader(RhaiLoader::new()))
+        .with_plugin(
+            ModSystemPlugin::new()
+                .with_loader(loader)
+                .with_loader(wasm_loader)
+                .with_mod_dir("examples/rpg-arena/mods"),
+        )
         .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?
         .build()
         .await
@@ -54,32 +78,25 @@ async fn main() -> io::Result<()> {
     } = game;
 
     // Add CombatConfig and InventoryConfig for MOD system
-    resources.insert(
-        "combat_config",
-        issun::plugin::CombatConfig {
-            enabled: true,
-            default_max_hp: 100,
-            difficulty_multiplier: 1.0,
-        },
-    );
-    resources.insert(
-        "inventory_config",
-        issun::plugin::InventoryConfig {
-            enabled: true,
-            max_slots: 10,
-            allow_stacking: true,
-        },
-    );
+    resources.insert(CombatConfig {
+        enabled: true,
+        default_max_hp: 100,
+        difficulty_multiplier: 1.0,
+        ..CombatConfig::default()
+    });
+    resources.insert(InventoryConfig {
+        enabled: true,
+        default_capacity: 10,
+        allow_stacking: true,
+        ..InventoryConfig::default()
+    });
 
     // Create arena
     let arena = Arena::new(100, 10, true);
-    resources.insert("arena", arena);
-
-    // Track loaded MODs
-    let mut loaded_mods: Vec<String> = Vec::new();
+    resources.insert(arena);
 
     // Run game loop
-    let result = run_game_loop(&mut terminal, &mut resources, &mut systems, &mut loaded_mods).await;
+    let result = run_game_loop(&mut terminal, &mut resources, &mut systems, &gold).await;
 
     // Cleanup terminal
     crossterm::terminal::disable_raw_mode()?;
@@ -97,18 +114,19 @@ async fn run_game_loop(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     resources: &mut ResourceContext,
     systems: &mut SystemContext,
-    loaded_mods: &mut Vec<String>,
+    gold: &AtomicI64,
 ) -> io::Result<()> {
     let mut last_tick = std::time::Instant::now();
 
     loop {
         // Update arena from config changes
-        update_arena_from_config(resources);
+        update_arena_from_config(resources, gold);
 
         // Render UI
+        let loaded_mods = active_mods(resources);
         terminal.draw(|f| {
-            if let Some(arena) = resources.try_get::<Arena>("arena") {
-                ui::render(f, &arena, loaded_mods);
+            if let Some(arena) = resources.try_get::<Arena>() {
+                ui::render(f, &arena, &loaded_mods);
             }
         })?;
 
@@ -121,8 +139,8 @@ async fn run_game_loop(
                         KeyCode::Char('q') => return Ok(()),
                         KeyCode::Char('n') => handle_new_combat(resources),
                         KeyCode::Char(' ') => handle_player_attack(resources),
-                        KeyCode::Char('m') => handle_load_mod(resources, loaded_mods),
-                        KeyCode::Char('u') => handle_unload_mod(resources, loaded_mods),
+                        KeyCode::Char('m') => handle_load_mod(resources),
+                        KeyCode::Char('u') => handle_unload_mod(resources),
                         KeyCode::Char(c) if c.is_ascii_digit() => {
                             let index = c.to_digit(10).unwrap() as usize;
                             handle_use_item(resources, index);
@@ -138,54 +156,117 @@ async fn run_game_loop(
             handle_enemy_turn(resources);
 
             // Run MOD systems
-            if let Some(mod_bridge) = systems.try_get_mut::<issun::engine::ModBridgeSystem>("mod_bridge_system") {
-                mod_bridge.update(resources).await;
-            }
+            update_mod_systems(systems, resources).await;
+            show_mod_logs(resources);
+            show_mod_errors(resources);
 
             last_tick = std::time::Instant::now();
         }
     }
 }
 
-fn update_arena_from_config(resources: &mut ResourceContext) {
+/// Show MOD `log()` output in the combat log instead of on stdout
+fn show_mod_logs(resources: &mut ResourceContext) {
+    let lines: Vec<String> = resources
+        .try_get_mut::<EventBus>()
+        .map(|mut bus| {
+            bus.reader::<ModLogEvent>()
+                .iter()
+                .map(|event| {
+                    let entry = &event.entry;
+                    let mod_id = entry.mod_id.as_deref().unwrap_or("?");
+                    format!("📜 [{}] {}: {}", mod_id, entry.level, entry.message)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if let Some(mut arena) = resources.try_get_mut::<Arena>() {
+        for line in lines {
+            arena.combat.add_log(line);
+        }
+    }
+}
+
+/// Mark MODs that failed to load or whose callbacks threw as broken
+fn show_mod_errors(resources: &mut ResourceContext) {
+    let (loaded, failures): (Vec<String>, Vec<(String, String)>) = resources
+        .try_get_mut::<EventBus>()
+        .map(|mut bus| {
+            let loaded = bus
+                .reader::<ModLoadedEvent>()
+                .iter()
+                .map(|event| event.handle.id.clone())
+                .collect();
+            let mut failures: Vec<(String, String)> = bus
+                .reader::<ModLoadFailedEvent>()
+                .iter()
+                .map(|event| (event.path.display().to_string(), event.error.clone()))
+                .collect();
+            failures.extend(bus.reader::<ModRuntimeErrorEvent>().iter().map(|event| {
+                (
+                    event.mod_id.clone(),
+                    format!("{}: {}", event.context, event.error),
+                )
+            }));
+            (loaded, failures)
+        })
+        .unwrap_or_default();
+
+    if let Some(mut arena) = resources.try_get_mut::<Arena>() {
+        // A MOD that loads again (after a fix) is no longer broken
+        arena.broken_mods.retain(|id| !loaded.contains(id));
+        for (mod_id, error) in failures {
+            arena
+                .combat
+                .add_log(format!("❌ MOD '{}' is broken: {}", mod_id, error));
+            if !arena.broken_mods.contains(&mod_id) {
+                arena.broken_mods.push(mod_id);
+            }
+        }
+    }
+}
+
+fn update_arena_from_config(resources: &mut ResourceContext, gold: &AtomicI64) {
     let combat_config = resources
-        .try_get::<issun::plugin::CombatConfig>("combat_config")
-        .cloned();
+        .try_get::<CombatConfig>()
+        .map(|config| config.clone());
     let inventory_config = resources
-        .try_get::<issun::plugin::InventoryConfig>("inventory_config")
-        .cloned();
+        .try_get::<InventoryConfig>()
+        .map(|config| config.clone());
 
     if let (Some(combat), Some(inventory), Some(mut arena)) = (
         combat_config,
         inventory_config,
-        resources.try_get_mut::<Arena>("arena"),
+        resources.try_get_mut::<Arena>(),
     ) {
         arena.update_difficulty(combat.difficulty_multiplier);
+        arena.gold = gold.load(Ordering::Relaxed);
 
         // Update inventory settings if changed
-        if arena.inventory.max_slots != inventory.max_slots
+        if arena.inventory.max_slots != inventory.default_capacity
             || arena.inventory.allow_stacking != inventory.allow_stacking
         {
-            arena.inventory.max_slots = inventory.max_slots;
+            arena.inventory.max_slots = inventory.default_capacity;
             arena.inventory.allow_stacking = inventory.allow_stacking;
         }
     }
 }
 
 fn handle_new_combat(resources: &mut ResourceContext) {
-    if let Some(mut arena) = resources.try_get_mut::<Arena>("arena") {
+    if let Some(mut arena) = resources.try_get_mut::<Arena>() {
         let combat_config = resources
-            .try_get::<issun::plugin::CombatConfig>("combat_config")
-            .cloned()
+            .try_get::<CombatConfig>()
+            .map(|config| config.clone())
             .unwrap_or_default();
         let inventory_config = resources
-            .try_get::<issun::plugin::InventoryConfig>("inventory_config")
-            .cloned()
+            .try_get::<InventoryConfig>()
+            .map(|config| config.clone())
             .unwrap_or_default();
 
         arena.reset(
             combat_config.default_max_hp,
-            inventory_config.max_slots,
+            inventory_config.default_capacity,
             inventory_config.allow_stacking,
         );
         arena.update_difficulty(combat_config.difficulty_multiplier);
@@ -194,7 +275,7 @@ fn handle_new_combat(resources: &mut ResourceContext) {
 }
 
 fn handle_player_attack(resources: &mut ResourceContext) {
-    if let Some(mut arena) = resources.try_get_mut::<Arena>("arena") {
+    if let Some(mut arena) = resources.try_get_mut::<Arena>() {
         if arena.combat.state == CombatState::PlayerTurn {
             arena.player_attack();
         }
@@ -202,48 +283,47 @@ fn handle_player_attack(resources: &mut ResourceContext) {
 }
 
 fn handle_use_item(resources: &mut ResourceContext, index: usize) {
-    if let Some(mut arena) = resources.try_get_mut::<Arena>("arena") {
+    if let Some(mut arena) = resources.try_get_mut::<Arena>() {
         arena.use_item(index).ok();
     }
 }
 
 fn handle_enemy_turn(resources: &mut ResourceContext) {
-    if let Some(mut arena) = resources.try_get_mut::<Arena>("arena") {
+    if let Some(mut arena) = resources.try_get_mut::<Arena>() {
         if arena.combat.state == CombatState::EnemyTurn {
             arena.enemy_attack();
         }
     }
 }
 
-fn handle_load_mod(resources: &mut ResourceContext, loaded_mods: &mut Vec<String>) {
-    // For demo, try to load a hardcoded MOD
+/// Ids of the loaded MODs, in load order
+fn active_mods(resources: &ResourceContext) -> Vec<String> {
+    resources
+        .try_get::<ModRegistry>()
+        .map(|registry| registry.ids().into_iter().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+fn handle_load_mod(resources: &mut ResourceContext) {
+    // For demo, load a hardcoded MOD again after it was unloaded
     let mod_path = PathBuf::from("examples/rpg-arena/mods/easy_mode.rhai");
+    if active_mods(resources).iter().any(|id| id == "easy_mode") {
+        return;
+    }
 
-    if let Some(mut event_bus) = resources.try_get_mut::<EventBus>("event_bus") {
-        event_bus.publish(ModLoadRequested {
-            path: mod_path.clone(),
-        });
+    if let Some(mut event_bus) = resources.try_get_mut::<EventBus>() {
+        event_bus.publish(ModLoadRequested { path: mod_path });
         event_bus.dispatch();
-
-        // Track loaded MOD
-        if let Some(file_name) = mod_path.file_stem() {
-            let mod_name = file_name.to_string_lossy().to_string();
-            if !loaded_mods.contains(&mod_name) {
-                loaded_mods.push(mod_name);
-            }
-        }
     }
 }
 
-fn handle_unload_mod(resources: &mut ResourceContext, loaded_mods: &mut Vec<String>) {
-    if loaded_mods.is_empty() {
-        return;
-    }
-
+fn handle_unload_mod(resources: &mut ResourceContext) {
     // Unload the first MOD
-    let mod_id = loaded_mods.remove(0);
+    let Some(mod_id) = active_mods(resources).into_iter().next() else {
+        return;
+    };
 
-    if let Some(mut event_bus) = resources.try_get_mut::<EventBus>("event_bus") {
+    if let Some(mut event_bus) = resources.try_get_mut::<EventBus>() {
         event_bus.publish(ModUnloadRequested { mod_id });
         event_bus.dispatch();
     }
@@ -19,7 +19,7 @@ pub fn render(frame: &mut Frame, arena: &Arena, loaded_mods: &[String]) {
             Constraint::Length(7),  // Fighters
             Constraint::Length(8),  // Inventory
             Constraint::Min(8),     // Combat log
-            Constraint::Length(6),  // Config info
+            Constraint::Length(7),  // Config info
             Constraint::Length(3),  // Controls
         ])
         .split(frame.area());
@@ -155,8 +155,15 @@ fn render_config_info(frame: &mut Frame, area: Rect, arena: &Arena, loaded_mods:
         loaded_mods.join(", ")
     };
 
-    let content = vec![
-        Line::from(format!("🔧 Active MODs: {}", mod_list)),
+    let mut content = vec![Line::from(format!("🔧 Active MODs: {}", mod_list))];
+    if !arena.broken_mods.is_empty() {
+        content.push(Line::from(format!(
+            "❌ Broken MODs: {}",
+            arena.broken_mods.join(", ")
+        )));
+    }
+    content.extend([
+        Line::from(format!("💰 Gold: {}", arena.gold)),
         Line::from(""),
         Line::from(format!(
             "⚙️  Combat Settings: Max HP={}, Difficulty={:.1}x",
@@ -171,7 +178,7 @@ fn render_config_info(frame: &mut Frame, area: Rect, arena: &Arena, loaded_mods:
             },
             arena.inventory.allow_stacking
         )),
-    ];
+    ]);
 
     let paragraph = Paragraph::new(content)
         .block(Block::default().borders(Borders::ALL).title("⚙️ Configuration"))
@@ -6,97 +6,95 @@
 //! 3. Game behavior reflects the MOD changes
 //! 4. MOD can be unloaded and settings reset
 
-use issun::context::Context;
-use issun::engine::ModBridgeSystem;
 use issun::event::EventBus;
-use issun::modding::{ModLoadRequested, ModLoadSystem, ModLoaderState};
+use issun::modding::{update_mod_systems, ModLoadRequested, ModRegistry, ModSystemPlugin};
 use issun::plugin::{CombatConfig, InventoryConfig};
-use issun::system::System;
+use issun::prelude::{Game, GameBuilder};
 use issun_mod_rhai::RhaiLoader;
 use std::path::PathBuf;
-use std::sync::Arc;
 
 /// Helper to setup test environment with MOD system
-async fn setup_mod_system() -> Context {
-    let mut ctx = Context::new();
-
-    // Add event bus
-    ctx.insert("event_bus", EventBus::new());
+async fn setup_mod_system() -> Game {
+    let mut game = GameBuilder::new()
+        .with_plugin(ModSystemPlugin::new().with_loader(RhaiLoader::new()))
+        .expect("MOD system plugin")
+        .build()
+        .await
+        .expect("game builds");
 
     // Add plugin configs (default values)
-    ctx.insert(
-        "combat_config",
-        CombatConfig {
-            enabled: true,
-            default_max_hp: 100,
-            difficulty_multiplier: 1.0,
-        },
-    );
-
-    ctx.insert(
-        "inventory_config",
-        InventoryConfig {
-            enabled: true,
-            max_slots: 10,
-            allow_stacking: true,
-        },
-    );
-
-    // Add MOD loader
-    let loader = Arc::new(RhaiLoader::new());
-    ctx.insert("mod_loader_state", ModLoaderState::new());
-    ctx.insert("mod_loader", loader);
-
-    ctx
+    game.resources.insert(CombatConfig {
+        enabled: true,
+        default_max_hp: 100,
+        difficulty_multiplier: 1.0,
+        ..CombatConfig::default()
+    });
+
+    game.resources.insert(InventoryConfig {
+        enabled: true,
+        default_capacity: 10,
+        allow_stacking: true,
+        ..InventoryConfig::default()
+    });
+
+    game
 }
 
 /// Helper to load a MOD and process events
-async fn load_mod(ctx: &mut Context, mod_path: &str) {
+async fn load_mod(game: &mut Game, mod_name: &str) {
     // Publish load request
-    if let Some(mut event_bus) = ctx.get_mut::<EventBus>("event_bus") {
-        event_bus.publish(ModLoadRequested {
-            path: PathBuf::from(mod_path),
-        });
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("mods")
+        .join(mod_name);
+    if let Some(mut event_bus) = game.resources.get_mut::<EventBus>().await {
+        event_bus.publish(ModLoadRequested { path });
         event_bus.dispatch();
     }
 
-    // Run ModLoadSystem
-    let mut mod_load_system = ModLoadSystem::new();
-    mod_load_system.update(ctx).await;
-
-    // Run ModBridgeSystem to apply changes
-    let mut mod_bridge_system = ModBridgeSystem::new();
-    mod_bridge_system.update(ctx).await;
+    // Load the MOD, turn its commands into events, then apply them to the configs
+    for _ in 0..2 {
+        update_mod_systems(&mut game.systems, &mut game.resources).await;
+        if let Some(mut event_bus) = game.resources.get_mut::<EventBus>().await {
+            event_bus.dispatch();
+        }
+    }
+    update_mod_systems(&mut game.systems, &mut game.resources).await;
 }
 
 #[tokio::test]
 async fn test_default_settings() {
-    let ctx = setup_mod_system().await;
+    let game = setup_mod_system().await;
 
     // Verify default settings
-    let combat_config = ctx
-        .get::<CombatConfig>("combat_config")
+    let combat_config = game
+        .resources
+        .get::<CombatConfig>()
+        .await
         .expect("Combat config not found");
     assert_eq!(combat_config.default_max_hp, 100);
     assert_eq!(combat_config.difficulty_multiplier, 1.0);
 
-    let inventory_config = ctx
-        .get::<InventoryConfig>("inventory_config")
+    let inventory_config = game
+        .resources
+        .get::<InventoryConfig>()
+        .await
         .expect("Inventory config not found");
-    assert_eq!(inventory_config.max_slots, 10);
+    assert_eq!(inventory_config.default_capacity, 10);
     assert!(inventory_config.allow_stacking);
 }
 
 #[tokio::test]
 async fn test_easy_mode_mod() {
-    let mut ctx = setup_mod_system().await;
+    let mut game = setup_mod_system().await;
 
     // Load easy_mode.rhai
-    load_mod(&mut ctx, "examples/rpg-arena/mods/easy_mode.rhai").await;
+    load_mod(&mut game, "easy_mode.rhai").await;
 
     // Verify Easy Mode settings
-    let combat_config = ctx
-        .get::<CombatConfig>("combat_config")
+    let combat_config = game
+        .resources
+        .get::<CombatConfig>()
+        .await
         .expect("Combat config not found");
     assert_eq!(
         combat_config.default_max_hp, 200,
@@ -107,11 +105,13 @@ async fn test_easy_mode_mod() {
         "Easy Mode should set difficulty to 0.5"
     );
 
-    let inventory_config = ctx
-        .get::<InventoryConfig>("inventory_config")
+    let inventory_config = game
+        .resources
+        .get::<InventoryConfig>()
+        .await
         .expect("Inventory config not found");
     assert_eq!(
-        inventory_config.max_slots, 30,
+        inventory_config.default_capacity, 30,
         "Easy Mode should set max_slots to 30"
     );
     assert!(
@@ -122,14 +122,16 @@ async fn test_easy_mode_mod() {
 
 #[tokio::test]
 async fn test_hard_mode_mod() {
-    let mut ctx = setup_mod_system().await;
+    let mut game = setup_mod_system().await;
 
     // Load hard_mode.rhai
-    load_mod(&mut ctx, "examples/rpg-arena/mods/hard_mode.rhai").await;
+    load_mod(&mut game, "hard_mode.rhai").await;
 
     // Verify Hard Mode settings
-    let combat_config = ctx
-        .get::<CombatConfig>("combat_config")
+    let combat_config = game
+        .resources
+        .get::<CombatConfig>()
+        .await
         .expect("Combat config not found");
     assert_eq!(
         combat_config.default_max_hp, 50,
@@ -140,11 +142,13 @@ async fn test_hard_mode_mod() {
         "Hard Mode should set difficulty to 2.0"
     );
 
-    let inventory_config = ctx
-        .get::<InventoryConfig>("inventory_config")
+    let inventory_config = game
+        .resources
+        .get::<InventoryConfig>()
+        .await
         .expect("Inventory config not found");
     assert_eq!(
-        inventory_config.max_slots, 5,
+        inventory_config.default_capacity, 5,
         "Hard Mode should set max_slots to 5"
     );
     assert!(
@@ -155,14 +159,16 @@ async fn test_hard_mode_mod() {
 
 #[tokio::test]
 async fn test_debug_mode_mod() {
-    let mut ctx = setup_mod_system().await;
+    let mut game = setup_mod_system().await;
 
     // Load debug_mode.rhai
-    load_mod(&mut ctx, "examples/rpg-arena/mods/debug_mode.rhai").await;
+    load_mod(&mut game, "debug_mode.rhai").await;
 
     // Verify Debug Mode settings
-    let combat_config = ctx
-        .get::<CombatConfig>("combat_config")
+    let combat_config = game
+        .resources
+        .get::<CombatConfig>()
+        .await
         .expect("Combat config not found");
     assert_eq!(
         combat_config.default_max_hp, 9999,
@@ -173,11 +179,13 @@ async fn test_debug_mode_mod() {
         "Debug Mode should set difficulty to 0.1"
     );
 
-    let inventory_config = ctx
-        .get::<InventoryConfig>("inventory_config")
+    let inventory_config = game
+        .resources
+        .get::<InventoryConfig>()
+        .await
         .expect("Inventory config not found");
     assert_eq!(
-        inventory_config.max_slots, 999,
+        inventory_config.default_capacity, 999,
         "Debug Mode should set max_slots to 999"
     );
     assert!(
@@ -188,18 +196,19 @@ async fn test_debug_mode_mod() {
 
 #[tokio::test]
 async fn test_mod_override_sequence() {
-    let mut ctx = setup_mod_system().await;
+    let mut game = setup_mod_system().await;
 
     // Load Easy Mode first
-    load_mod(&mut ctx, "examples/rpg-arena/mods/easy_mode.rhai").await;
+    load_mod(&mut game, "easy_mode.rhai").await;
 
-    let combat_config = ctx.get::<CombatConfig>("combat_config").unwrap();
+    let combat_config = game.resources.get::<CombatConfig>().await.unwrap();
     assert_eq!(combat_config.default_max_hp, 200);
+    drop(combat_config);
 
     // Load Hard Mode (should override)
-    load_mod(&mut ctx, "examples/rpg-arena/mods/hard_mode.rhai").await;
+    load_mod(&mut game, "hard_mode.rhai").await;
 
-    let combat_config = ctx.get::<CombatConfig>("combat_config").unwrap();
+    let combat_config = game.resources.get::<CombatConfig>().await.unwrap();
     assert_eq!(
         combat_config.default_max_hp, 50,
         "Hard Mode should override Easy Mode settings"
@@ -208,50 +217,45 @@ async fn test_mod_override_sequence() {
 }
 
 #[tokio::test]
-async fn test_mod_loader_state() {
-    let mut ctx = setup_mod_system().await;
+async fn test_mod_registry() {
+    let mut game = setup_mod_system().await;
 
     // Initially no MODs loaded
-    let loader_state = ctx
-        .get::<ModLoaderState>("mod_loader_state")
-        .expect("ModLoaderState not found");
-    assert_eq!(loader_state.loaded_mods.len(), 0);
+    let registry = game
+        .resources
+        .get::<ModRegistry>()
+        .await
+        .expect("ModRegistry not found");
+    assert_eq!(registry.len(), 0);
+    drop(registry);
 
     // Load a MOD
-    load_mod(&mut ctx, "examples/rpg-arena/mods/easy_mode.rhai").await;
+    load_mod(&mut game, "easy_mode.rhai").await;
 
     // Check MOD is tracked
-    let loader_state = ctx.get::<ModLoaderState>("mod_loader_state").unwrap();
-    assert_eq!(
-        loader_state.loaded_mods.len(),
-        1,
-        "One MOD should be loaded"
-    );
+    let registry = game.resources.get::<ModRegistry>().await.unwrap();
+    assert_eq!(registry.len(), 1, "One MOD should be loaded");
 
-    let mod_handle = &loader_state.loaded_mods[0];
+    let mod_handle = &registry.handles()[0];
     assert_eq!(mod_handle.metadata.name, "Easy Mode");
     assert_eq!(mod_handle.metadata.version, "1.0.0");
 }
 
 #[tokio::test]
 async fn test_nonexistent_mod() {
-    let mut ctx = setup_mod_system().await;
+    let mut game = setup_mod_system().await;
 
     // Try to load non-existent MOD
-    load_mod(&mut ctx, "examples/rpg-arena/mods/nonexistent.rhai").await;
+    load_mod(&mut game, "nonexistent.rhai").await;
 
     // Settings should remain at defaults
-    let combat_config = ctx.get::<CombatConfig>("combat_config").unwrap();
+    let combat_config = game.resources.get::<CombatConfig>().await.unwrap();
     assert_eq!(
         combat_config.default_max_hp, 100,
         "Settings should remain default when MOD fails to load"
     );
 
     // No MODs should be loaded
-    let loader_state = ctx.get::<ModLoaderState>("mod_loader_state").unwrap();
-    assert_eq!(
-        loader_state.loaded_mods.len(),
-        0,
-        "No MODs should be loaded"
-    );
+    let registry = game.resources.get::<ModRegistry>().await.unwrap();
+    assert_eq!(registry.len(), 0, "No MODs should be loaded");
 }
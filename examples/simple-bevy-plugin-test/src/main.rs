@@ -14,7 +14,6 @@ mod test_phase22;
 mod test_phase23;
 
 use bevy::prelude::*;
-use issun_bevy::IssunCorePlugin;
 use issun_macros::IssunBevyPlugin;
 use serde::{Deserialize, Serialize};
 
@@ -37,7 +36,7 @@ pub struct PlayerProgress {
 
 /// Test plugin with auto-generated boilerplate
 #[derive(Default, IssunBevyPlugin)]
-#[plugin(name = "simple_game")]
+#[plugin(name = "simple_game", test_harness = true)]
 pub struct SimpleGamePlugin {
     #[config]
     pub config: GameConfig,
@@ -52,50 +51,27 @@ pub struct SimpleGamePlugin {
 fn main() {
     println!("=== IssunBevyPlugin Test ===\n");
 
-    let mut app = App::new();
-
     // Test 1: Default plugin
     println!("Test 1: Default plugin");
-    app.add_plugins(MinimalPlugins)
-        .add_plugins(IssunCorePlugin)
-        .add_plugins(SimpleGamePlugin::default());
-
-    app.update();
+    let app = SimpleGamePlugin::default().test_app();
 
-    // Verify resources are registered
-    assert!(
-        app.world().get_resource::<GameConfig>().is_some(),
-        "GameConfig should be registered"
-    );
-    assert!(
-        app.world().get_resource::<GameStats>().is_some(),
-        "GameStats should be registered"
-    );
-    assert!(
-        app.world().get_resource::<PlayerProgress>().is_some(),
-        "PlayerProgress should be registered"
-    );
+    // test_app() already checked this in debug builds
+    SimpleGamePlugin::assert_resources(&app);
     println!("✅ All resources registered\n");
 
     // Test 2: Builder methods
     println!("Test 2: Builder methods");
-    let mut app2 = App::new();
-    app2.add_plugins(MinimalPlugins)
-        .add_plugins(IssunCorePlugin)
-        .add_plugins(
-        SimpleGamePlugin::default()
-            .with_config(GameConfig {
-                difficulty: 2.0,
-                max_level: 100,
-            })
-            .with_stats(GameStats {
-                score: 1000,
-                level: 5,
-            })
-            .with_progress(PlayerProgress { xp: 500 }),
-    );
-
-    app2.update();
+    let app2 = SimpleGamePlugin::default()
+        .with_config(GameConfig {
+            difficulty: 2.0,
+            max_level: 100,
+        })
+        .with_stats(GameStats {
+            score: 1000,
+            level: 5,
+        })
+        .with_progress(PlayerProgress { xp: 500 })
+        .test_app();
 
     let config = app2.world().get_resource::<GameConfig>().unwrap();
     assert_eq!(config.difficulty, 2.0);
@@ -136,7 +112,7 @@ pub struct ReflectedResource {
 }
 
 #[derive(Default, IssunBevyPlugin)]
-#[plugin(name = "auto_register_test", auto_register_types = true)]
+#[plugin(name = "auto_register_test", auto_register_types = true, test_harness = true)]
 pub struct AutoRegisterPlugin {
     #[resource]
     pub reflected: ReflectedResource,
@@ -145,18 +121,8 @@ pub struct AutoRegisterPlugin {
 fn test_auto_register() {
     println!("\nTest 4: auto_register_types");
 
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins)
-        .add_plugins(IssunCorePlugin)
-        .add_plugins(AutoRegisterPlugin::default());
-
-    app.update();
-
-    // Verify resource is registered
-    assert!(
-        app.world().get_resource::<ReflectedResource>().is_some(),
-        "ReflectedResource should be registered"
-    );
+    let app = AutoRegisterPlugin::default().test_app();
+    AutoRegisterPlugin::assert_resources(&app);
 
     // Verify type is registered for Reflection
     let registry = app.world().resource::<AppTypeRegistry>();
@@ -1,10 +1,9 @@
 //! Test for messages auto-registration
 //!
-//! Note: This test only verifies that the macro generates valid code.
-//! Full message functionality testing is done in pandemic-crisis.
+//! Registered messages can be written and read back through the plugin's
+//! systems; full message usage lives in pandemic-crisis.
 
 use bevy::prelude::*;
-use issun_bevy::IssunCorePlugin;
 use issun_macros::IssunBevyPlugin;
 
 #[derive(Message, Clone, Debug)]
@@ -31,31 +30,29 @@ pub struct GameData {
 #[derive(Default, IssunBevyPlugin)]
 #[plugin(
     name = "messages_test",
-    messages = [GameStartedMessage, TurnAdvancedMessage, VictoryMessage]
+    messages = [GameStartedMessage, TurnAdvancedMessage, VictoryMessage],
+    update_systems = [count_turns],
+    test_harness = true
 )]
 pub struct MessagesTestPlugin {
     #[resource]
     pub data: GameData,
 }
 
+fn count_turns(mut turns: MessageReader<TurnAdvancedMessage>, mut data: ResMut<GameData>) {
+    for message in turns.read() {
+        data.value = message.turn;
+    }
+}
+
 pub fn run_messages_test() {
     println!("\nTest 5: messages auto-registration");
 
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins)
-        .add_plugins(IssunCorePlugin)
-        .add_plugins(MessagesTestPlugin::default());
-
-    app.update();
-
-    // Verify resource is registered
-    assert!(
-        app.world().get_resource::<GameData>().is_some(),
-        "GameData should be registered"
-    );
+    let mut app = MessagesTestPlugin::default().test_app();
 
-    // Note: Full message testing requires issun-bevy's MessageReader/MessageWriter
-    // This test only verifies that the plugin builds and resources are registered
+    // A registered message reaches the plugin's system in the same update
+    MessagesTestPlugin::send_and_update(&mut app, TurnAdvancedMessage { turn: 3 });
+    assert_eq!(app.world().resource::<GameData>().value, 3);
 
     println!("✅ messages auto-registration works correctly");
 }
@@ -3,7 +3,6 @@
 //! Tests: components, startup_systems, update_systems
 
 use bevy::prelude::*;
-use issun_bevy::IssunCorePlugin;
 use issun_macros::IssunBevyPlugin;
 
 // Test components for registration
@@ -43,6 +42,7 @@ pub struct TestLog {
     components = [Player, Enemy, Health],
     startup_systems = [setup_test],
     update_systems = [update_test],
+    test_harness = true,
 )]
 pub struct Phase22TestPlugin {
     #[resource]
@@ -69,18 +69,8 @@ pub fn run_phase22_tests() {
 fn test_components() {
     println!("\nTest 6: Component Registration (Phase 2.2)");
 
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins)
-        .add_plugins(IssunCorePlugin)
-        .add_plugins(Phase22TestPlugin::default());
-
-    app.update();
-
-    // Verify resource is registered
-    assert!(
-        app.world().get_resource::<GameData>().is_some(),
-        "GameData should be registered"
-    );
+    // test_app() asserts GameData and TestLog are registered
+    let app = Phase22TestPlugin::default().test_app();
 
     // Verify components are registered for Reflection
     let registry = app.world().resource::<AppTypeRegistry>();
@@ -105,13 +95,8 @@ fn test_components() {
 fn test_startup_systems() {
     println!("\nTest 7: Startup Systems (Phase 2.2)");
 
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins)
-        .add_plugins(IssunCorePlugin)
-        .add_plugins(Phase22TestPlugin::default());
-
-    // update() runs Startup systems
-    app.update();
+    // test_app() runs one update, which runs Startup systems
+    let app = Phase22TestPlugin::default().test_app();
 
     // Verify startup system ran
     let log = app.world().get_resource::<TestLog>().unwrap();
@@ -126,13 +111,8 @@ fn test_startup_systems() {
 fn test_update_systems() {
     println!("\nTest 8: Update Systems (Phase 2.2)");
 
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins)
-        .add_plugins(IssunCorePlugin)
-        .add_plugins(Phase22TestPlugin::default());
-
-    // First update runs Startup, second runs Update
-    app.update();
+    // test_app() runs the first update: Startup, then Update
+    let mut app = Phase22TestPlugin::default().test_app();
 
     let log = app.world().get_resource::<TestLog>().unwrap();
     let initial_count = log.messages.iter()
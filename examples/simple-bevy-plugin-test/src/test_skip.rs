@@ -1,7 +1,6 @@
 //! Test for #[skip] attribute
 
 use bevy::prelude::*;
-use issun_bevy::IssunCorePlugin;
 use issun_macros::IssunBevyPlugin;
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +16,7 @@ pub struct InternalState {
 
 /// Test plugin with skipped field
 #[derive(Default, IssunBevyPlugin)]
+#[plugin(test_harness = true)]
 pub struct SkipTestPlugin {
     #[resource]
     pub config: PublicConfig,
@@ -32,18 +32,10 @@ mod tests {
 
     #[test]
     fn test_skip_field() {
-        let mut app = App::new();
-        app.add_plugins(MinimalPlugins)
-            .add_plugins(IssunCorePlugin)
-            .add_plugins(SkipTestPlugin::default());
-
-        app.update();
+        let app = SkipTestPlugin::default().test_app();
 
         // Verify config is registered
-        assert!(
-            app.world().get_resource::<PublicConfig>().is_some(),
-            "PublicConfig should be registered"
-        );
+        SkipTestPlugin::assert_resources(&app);
 
         // Verify internal is NOT registered (would fail to compile if it were)
         // InternalState doesn't implement Resource, so this test ensures
@@ -54,12 +46,9 @@ mod tests {
 pub fn run_skip_test() {
     println!("\nTest 3: Skip field");
 
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins)
-        .add_plugins(IssunCorePlugin)
-        .add_plugins(SkipTestPlugin::default().with_config(PublicConfig { value: 42 }));
-
-    app.update();
+    let app = SkipTestPlugin::default()
+        .with_config(PublicConfig { value: 42 })
+        .test_app();
 
     let config = app.world().get_resource::<PublicConfig>().unwrap();
     assert_eq!(config.value, 42);
@@ -0,0 +1,56 @@
+//! Spin MOD for ISSUN
+//!
+//! Custom functions that never return or exhaust memory, so the host's
+//! execution limits can be tested.
+
+use wit_bindgen::generate;
+
+// Generate guest bindings from WIT
+generate!({
+    world: "mod-guest",
+    path: "../../crates/issun-mod-wasm/wit/issun.wit",
+});
+
+struct SpinMod;
+
+impl Guest for SpinMod {
+    fn get_metadata() -> Metadata {
+        Metadata {
+            name: "Spin".to_string(),
+            version: "1.0.0".to_string(),
+            author: Some("ISSUN Team".to_string()),
+            description: Some("Runs away on purpose".to_string()),
+        }
+    }
+
+    fn on_init() {}
+
+    fn on_shutdown() {}
+
+    fn on_control_plugin(_plugin_name: String, _action: String) {}
+
+    fn on_event(_event_type: String, _payload_json: String) {}
+
+    fn call_custom(fn_name: String, args_json: String) -> String {
+        // Arguments arrive as one JSON array
+        let args: Vec<serde_json::Value> = serde_json::from_str(&args_json).unwrap_or_default();
+
+        match fn_name.as_str() {
+            "spin" => {
+                let mut turns: u64 = 0;
+                loop {
+                    turns = std::hint::black_box(turns.wrapping_add(1));
+                }
+            }
+            "allocate" => {
+                let megabytes = args.first().and_then(|arg| arg.as_u64()).unwrap_or(0) as usize;
+                let buffer = vec![1u8; megabytes * 1024 * 1024];
+                serde_json::json!({ "allocated": std::hint::black_box(buffer).len() }).to_string()
+            }
+            "ping" => serde_json::json!("pong").to_string(),
+            _ => serde_json::json!({ "error": "Unknown function" }).to_string(),
+        }
+    }
+}
+
+export!(SpinMod);
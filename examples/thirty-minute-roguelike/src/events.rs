@@ -0,0 +1,26 @@
+//! Player commands, published by the dungeon scene (or a scenario script)
+
+use crate::models::Skill;
+use issun::event::Event;
+use serde::{Deserialize, Serialize};
+
+/// Move on to the next room of the floor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExploreRequested;
+
+impl Event for ExploreRequested {}
+
+/// Fight one turn of the current encounter with `skill`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttackRequested {
+    #[serde(default)]
+    pub skill: Skill,
+}
+
+impl Event for AttackRequested {}
+
+/// Take the stairs of the current room
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DescendRequested;
+
+impl Event for DescendRequested {}
@@ -0,0 +1,148 @@
+//! Turn resolution: the player's skill, then every enemy's AI
+
+use crate::models::{EnemyKind, Player, RunState, Skill, PLAYER};
+use async_trait::async_trait;
+use issun::plugin::combat::{AttackPolicy, BattleId, BattleView, FirstAlive, LowestHp};
+use issun::plugin::{CombatConfig, CombatEndRequested, CombatHook, CombatResult, CombatService};
+use issun::prelude::*;
+
+/// Damage of Cleave relative to Strike
+const CLEAVE_MULTIPLIER: f32 = 0.75;
+
+/// Shamans heal allies below this share of their max HP
+const HEAL_BELOW: f32 = 0.5;
+
+pub struct RoguelikeCombatHook;
+
+#[async_trait]
+impl CombatHook for RoguelikeCombatHook {
+    async fn process_turn(
+        &self,
+        battle_id: &BattleId,
+        turn: u32,
+        resources: &mut ResourceContext,
+    ) -> Vec<String> {
+        // Enemy damage scale, set by the balance MOD
+        let difficulty = match resources.get::<CombatConfig>().await {
+            Some(config) => config.difficulty_multiplier,
+            None => 1.0,
+        };
+        let (Some(mut run), Some(mut player)) = (
+            resources.get_mut::<RunState>().await,
+            resources.get_mut::<Player>().await,
+        ) else {
+            return Vec::new();
+        };
+        let Some(encounter) = run.encounter.as_mut() else {
+            return Vec::new();
+        };
+
+        let service = CombatService::new();
+        let mut log = Vec::new();
+
+        // Player: Strike the weakest enemy or Cleave them all
+        let skill = if encounter.queued == Skill::Cleave && player.cleave_cooldown == 0 {
+            Skill::Cleave
+        } else {
+            Skill::Strike
+        };
+        player.cleave_cooldown = player.cleave_cooldown.saturating_sub(1);
+        let targets: Vec<usize> = match skill {
+            Skill::Strike => {
+                let candidates: Vec<&dyn Combatant> = encounter
+                    .enemies
+                    .iter()
+                    .map(|e| e as &dyn Combatant)
+                    .collect();
+                LowestHp
+                    .select_target(PLAYER, &BattleView::new(turn, &candidates))
+                    .into_iter()
+                    .collect()
+            }
+            Skill::Cleave => {
+                player.cleave_cooldown = skill.cooldown();
+                (0..encounter.enemies.len())
+                    .filter(|&index| encounter.enemies[index].is_alive())
+                    .collect()
+            }
+        };
+        let multiplier = if skill == Skill::Cleave {
+            CLEAVE_MULTIPLIER
+        } else {
+            1.0
+        };
+        for index in targets {
+            let enemy = &mut encounter.enemies[index];
+            let hit = service.apply_attack(&*player, enemy, multiplier);
+            log.push(format!(
+                "You {:?} {} for {}",
+                skill, enemy.name, hit.actual_damage
+            ));
+        }
+
+        // Enemies: shamans heal the most wounded ally, everyone else attacks
+        for index in 0..encounter.enemies.len() {
+            if !encounter.enemies[index].is_alive() || !player.is_alive() {
+                continue;
+            }
+            let wounded = {
+                let candidates: Vec<&dyn Combatant> = encounter
+                    .enemies
+                    .iter()
+                    .map(|e| e as &dyn Combatant)
+                    .collect();
+                LowestHp
+                    .select_target(
+                        &encounter.enemies[index].name,
+                        &BattleView::new(turn, &candidates),
+                    )
+                    .filter(|&i| {
+                        let ally = &encounter.enemies[i];
+                        (ally.hp as f32) < ally.max_hp as f32 * HEAL_BELOW
+                    })
+            };
+            let enemy = &encounter.enemies[index];
+            match (enemy.kind, wounded) {
+                (EnemyKind::Shaman, Some(ally)) => {
+                    let amount = enemy.attack * 2;
+                    let name = enemy.name.clone();
+                    encounter.enemies[ally].heal(amount);
+                    log.push(format!(
+                        "{} heals {} for {}",
+                        name, encounter.enemies[ally].name, amount
+                    ));
+                }
+                _ => {
+                    let party: [&dyn Combatant; 1] = [&*player];
+                    if FirstAlive
+                        .select_target(&enemy.name, &BattleView::new(turn, &party))
+                        .is_none()
+                    {
+                        continue;
+                    }
+                    let name = enemy.name.clone();
+                    let hit = service.apply_attack(enemy, &mut *player, difficulty);
+                    log.push(format!("{} hits you for {}", name, hit.actual_damage));
+                }
+            }
+        }
+
+        let result = if !player.is_alive() {
+            CombatResult::Defeat
+        } else if encounter.enemies.iter().all(|enemy| !enemy.is_alive()) {
+            CombatResult::Victory
+        } else {
+            CombatResult::Ongoing
+        };
+        drop((run, player));
+        if result != CombatResult::Ongoing {
+            if let Some(mut bus) = resources.get_mut::<EventBus>().await {
+                bus.publish(CombatEndRequested {
+                    battle_id: battle_id.clone(),
+                    result,
+                });
+            }
+        }
+        log
+    }
+}
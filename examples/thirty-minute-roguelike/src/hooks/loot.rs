@@ -0,0 +1,48 @@
+//! Loot drops read from the data-driven loot table
+
+use crate::models::LootTable;
+use async_trait::async_trait;
+use issun::engine::MasterSeed;
+use issun::plugin::loot::LootSourceId;
+use issun::plugin::{LootHook, Rarity};
+use issun::prelude::*;
+
+/// Picks one item of the rolled rarity from the [`LootTable`] resource
+///
+/// The pick is seeded by the source id, like the loot plugin's own rolls,
+/// so a chest holds the same item for a given master seed.
+pub struct TableLootHook;
+
+#[async_trait]
+impl LootHook for TableLootHook {
+    async fn generate_loot(
+        &self,
+        source_id: &LootSourceId,
+        rarity: Rarity,
+        resources: &ResourceContext,
+    ) -> Vec<String> {
+        let Some(table) = resources.get::<LootTable>().await else {
+            return Vec::new();
+        };
+        let of_rarity = |rarity: Rarity| -> Vec<&String> {
+            table
+                .items
+                .iter()
+                .filter(|(_, stats)| stats.rarity == rarity)
+                .map(|(id, _)| id)
+                .collect()
+        };
+        let mut candidates = of_rarity(rarity);
+        if candidates.is_empty() {
+            candidates = of_rarity(Rarity::Common);
+        }
+        let seed = match resources.get::<MasterSeed>().await {
+            Some(seed) => *seed,
+            None => MasterSeed(0),
+        };
+        seed.rng(&format!("drop:{}", source_id), 0)
+            .choose(&candidates)
+            .map(|id| vec![id.to_string()])
+            .unwrap_or_default()
+    }
+}
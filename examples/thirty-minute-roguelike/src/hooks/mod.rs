@@ -0,0 +1,7 @@
+//! Hook implementations for the built-in plugins
+
+mod combat;
+mod loot;
+
+pub use combat::RoguelikeCombatHook;
+pub use loot::TableLootHook;
@@ -0,0 +1,68 @@
+//! Thirty-Minute Roguelike
+//!
+//! A small dungeon crawler assembled from the built-in plugins: dungeon
+//! floors, combat, loot, inventory, save slots and MODs. The game adds a
+//! floor generator, two combat skills, a loot table and the glue system in
+//! between.
+
+pub mod events;
+pub mod hooks;
+pub mod models;
+pub mod plugins;
+pub mod services;
+pub mod systems;
+pub mod ui;
+
+use hooks::{RoguelikeCombatHook, TableLootHook};
+use issun::engine::MasterSeed;
+use issun::modding::ModSystemPlugin;
+use issun::plugin::{CombatState, InventoryState};
+use issun::prelude::*;
+use issun_mod_rhai::RhaiLoader;
+use models::{FloorMap, GameScene, Player, RunState};
+use plugins::RoguelikePlugin;
+use std::path::PathBuf;
+
+/// Rooms on every floor, stairs included
+pub const ROOMS_PER_FLOOR: u32 = 5;
+
+/// Every plugin of the game, ready for extra plugins (e.g. the debug console)
+pub fn game_builder(seed: u64, save_dir: impl Into<PathBuf>) -> Result<GameBuilder> {
+    let save_load = SaveLoadPlugin::new()
+        .with_save_directory(save_dir.into())
+        .persist::<Player>("player")
+        .persist::<RunState>("run")
+        .persist::<FloorMap>("floor")
+        .persist::<DungeonState>("dungeon")
+        .persist::<InventoryState>("inventory")
+        .persist::<CombatState>("combat");
+
+    GameBuilder::new()
+        .with_plugin(RoguelikePlugin::new(MasterSeed(seed), ROOMS_PER_FLOOR))?
+        .with_plugin(DungeonPlugin::new().with_config(DungeonConfig {
+            total_floors: 3,
+            rooms_per_floor: ROOMS_PER_FLOOR,
+            connection_pattern: ConnectionPattern::Linear,
+            ..Default::default()
+        }))?
+        .with_plugin(CombatPlugin::new().with_hook(RoguelikeCombatHook))?
+        .with_plugin(LootPlugin::new().with_hook(TableLootHook))?
+        .with_plugin(InventoryPlugin::new())?
+        .with_plugin(save_load)?
+        .with_plugin(
+            ModSystemPlugin::new()
+                .with_loader(RhaiLoader::new())
+                .with_mod_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/mods")),
+        )
+}
+
+/// Build the game and start it in the dungeon
+pub async fn new_run(seed: u64, save_dir: impl Into<PathBuf>) -> Result<SceneDirector<GameScene>> {
+    let game = game_builder(seed, save_dir)?.build().await?;
+    Ok(start(game).await)
+}
+
+pub async fn start(game: Game) -> SceneDirector<GameScene> {
+    let scene = GameScene::Dungeon(models::scenes::DungeonScene);
+    SceneDirector::new(scene, game.services, game.systems, game.resources).await
+}
@@ -0,0 +1,61 @@
+//! Thirty-Minute Roguelike - terminal front end
+//!
+//! `ROGUELIKE_SEED` picks the dungeon (default 7). With
+//! `ROGUELIKE_DEBUG_TOKEN` set, the debug console listens on
+//! `127.0.0.1:7878` (see `issun::plugin::debug_http`).
+
+use issun::engine::{query_channel, GameRunner};
+use issun::plugin::debug_http::{DebugHttpConfig, DebugHttpPlugin};
+use issun::prelude::*;
+use issun::ui::Tui;
+use std::time::Duration;
+use thirty_minute_roguelike::events::{AttackRequested, DescendRequested, ExploreRequested};
+use thirty_minute_roguelike::models::{handle_scene_input, FloorMap, Player, RunState};
+use thirty_minute_roguelike::{game_builder, start, ui};
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    let seed = std::env::var("ROGUELIKE_SEED")
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(7);
+
+    let (queries, receiver) = query_channel();
+    let mut builder = game_builder(seed, "saves").map_err(std::io::Error::other)?;
+    if let Ok(token) = std::env::var("ROGUELIKE_DEBUG_TOKEN") {
+        builder = builder
+            .with_plugin(
+                DebugHttpPlugin::new(queries)
+                    .with_config(DebugHttpConfig::enabled(token))
+                    .observe::<Player>()
+                    .observe::<RunState>()
+                    .observe::<FloorMap>()
+                    .observe::<DungeonState>()
+                    .event::<ExploreRequested>()
+                    .and_then(|plugin| plugin.event::<AttackRequested>())
+                    .and_then(|plugin| plugin.event::<DescendRequested>())
+                    .map_err(std::io::Error::other)?,
+            )
+            .map_err(std::io::Error::other)?;
+    }
+    let game = builder.build().await.map_err(std::io::Error::other)?;
+
+    let mut tui = Tui::new()?;
+    let result = GameRunner::new(start(game).await)
+        .with_tick_rate(Duration::from_millis(50))
+        .with_query_channel(receiver)
+        .run(
+            &mut tui,
+            ui::render,
+            |scene, services, systems, resources, input| {
+                Box::pin(handle_scene_input(
+                    scene, services, systems, resources, input,
+                ))
+            },
+        )
+        .await
+        .map_err(std::io::Error::other);
+
+    tui.restore()?;
+    result
+}
@@ -0,0 +1,240 @@
+//! Game entities and run state
+
+use issun::plugin::{Combatant, Rarity, RoomId};
+use issun::resources::Resource;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Inventory entity of the player
+pub const PLAYER: &str = "player";
+
+/// Log lines kept for the UI
+const LOG_LINES: usize = 8;
+
+/// The adventurer; saved with every slot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Player {
+    pub hp: i32,
+    pub max_hp: i32,
+    pub attack: i32,
+    pub defense: i32,
+    /// Bonuses of the equipped items
+    pub gear_attack: i32,
+    pub gear_defense: i32,
+    pub gold: u32,
+    /// Turns until Cleave can be used again
+    pub cleave_cooldown: u32,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self {
+            hp: 40,
+            max_hp: 40,
+            attack: 6,
+            defense: 1,
+            gear_attack: 0,
+            gear_defense: 0,
+            gold: 0,
+            cleave_cooldown: 0,
+        }
+    }
+}
+
+impl Combatant for Player {
+    fn name(&self) -> &str {
+        PLAYER
+    }
+
+    fn hp(&self) -> i32 {
+        self.hp
+    }
+
+    fn max_hp(&self) -> i32 {
+        self.max_hp
+    }
+
+    fn attack(&self) -> i32 {
+        self.attack + self.gear_attack
+    }
+
+    fn defense(&self) -> Option<i32> {
+        Some(self.defense + self.gear_defense)
+    }
+
+    fn take_damage(&mut self, damage: i32) {
+        self.hp = (self.hp - damage).max(0);
+    }
+}
+
+/// What an enemy does on its turn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EnemyKind {
+    /// Attacks the player
+    Brute,
+    /// Heals the most wounded ally, attacks when nobody is hurt
+    Shaman,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Enemy {
+    /// Unique within its room (`goblin_1`); the part before `_` is the loot source kind
+    pub name: String,
+    pub kind: EnemyKind,
+    pub hp: i32,
+    pub max_hp: i32,
+    pub attack: i32,
+    pub defense: i32,
+}
+
+impl Enemy {
+    pub fn heal(&mut self, amount: i32) {
+        self.hp = (self.hp + amount).min(self.max_hp);
+    }
+}
+
+impl Combatant for Enemy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn hp(&self) -> i32 {
+        self.hp
+    }
+
+    fn max_hp(&self) -> i32 {
+        self.max_hp
+    }
+
+    fn attack(&self) -> i32 {
+        self.attack
+    }
+
+    fn defense(&self) -> Option<i32> {
+        Some(self.defense)
+    }
+
+    fn take_damage(&mut self, damage: i32) {
+        self.hp = (self.hp - damage).max(0);
+    }
+}
+
+/// Player actions in combat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Skill {
+    /// Full damage to the weakest enemy
+    #[default]
+    Strike,
+    /// Three quarters damage to every enemy, then a cooldown
+    Cleave,
+}
+
+impl Skill {
+    /// Turns before the skill is ready again
+    pub fn cooldown(self) -> u32 {
+        match self {
+            Skill::Strike => 0,
+            Skill::Cleave => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RoomKind {
+    Start,
+    Monsters(Vec<Enemy>),
+    Treasure,
+    Stairs,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Room {
+    pub kind: RoomKind,
+    pub cleared: bool,
+}
+
+/// Rooms of the current floor, generated from the master seed; saved with every slot
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FloorMap {
+    pub floor: u32,
+    /// `rooms[0]` is room 1
+    pub rooms: Vec<Room>,
+}
+
+impl FloorMap {
+    pub fn room(&self, id: &RoomId) -> Option<&Room> {
+        self.room_index(id).and_then(|index| self.rooms.get(index))
+    }
+
+    pub fn room_mut(&mut self, id: &RoomId) -> Option<&mut Room> {
+        self.room_index(id)
+            .and_then(|index| self.rooms.get_mut(index))
+    }
+
+    fn room_index(&self, id: &RoomId) -> Option<usize> {
+        (id.floor == self.floor && id.room > 0).then(|| id.room as usize - 1)
+    }
+}
+
+/// The fight in progress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Encounter {
+    pub battle_id: String,
+    pub room: RoomId,
+    pub enemies: Vec<Enemy>,
+    /// Skill used on the next turn
+    pub queued: Skill,
+}
+
+/// Fight and log of the current run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunState {
+    pub encounter: Option<Encounter>,
+    pub dead: bool,
+    pub log: Vec<String>,
+}
+
+impl RunState {
+    pub fn log(&mut self, line: impl Into<String>) {
+        self.log.push(line.into());
+        let excess = self.log.len().saturating_sub(LOG_LINES);
+        self.log.drain(..excess);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemStats {
+    pub slot: String,
+    pub rarity: Rarity,
+    #[serde(default)]
+    pub attack: i32,
+    #[serde(default)]
+    pub defense: i32,
+}
+
+impl ItemStats {
+    pub fn power(&self) -> i32 {
+        self.attack + self.defense
+    }
+}
+
+/// `assets/loot_table.ron`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LootTable {
+    pub drop_rates: HashMap<String, f32>,
+    pub items: BTreeMap<String, ItemStats>,
+}
+
+impl Resource for LootTable {}
+
+impl LootTable {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// Drop rate of a source id (`chest_f1r3` → `chest`)
+    pub fn drop_rate(&self, source_id: &str) -> f32 {
+        let kind = source_id.split('_').next().unwrap_or(source_id);
+        self.drop_rates.get(kind).copied().unwrap_or(0.0)
+    }
+}
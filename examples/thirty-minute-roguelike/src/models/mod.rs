@@ -0,0 +1,5 @@
+pub mod entities;
+pub mod scenes;
+
+pub use entities::*;
+pub use scenes::{handle_scene_input, GameScene};
@@ -0,0 +1,184 @@
+//! Scenes: the dungeon, plus the pause and save menus stacked over it
+
+use crate::events::{AttackRequested, DescendRequested, ExploreRequested};
+use crate::models::{RunState, Skill};
+use crate::systems::pump;
+use issun::plugin::save_load::SaveLoadMenu;
+use issun::prelude::*;
+use issun::ui::InputEvent;
+use serde::{Deserialize, Serialize};
+
+/// Slots listed by the save menu
+pub const SAVE_SLOTS: [&str; 3] = ["slot1", "slot2", "slot3"];
+
+/// Pause menu entries
+pub const PAUSE_ITEMS: [&str; 3] = ["Resume", "Save / Load", "Quit"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, issun::Scene)]
+#[scene(
+    no_state,
+    delegate = "on_update",
+    handler_params = "input: ::issun::ui::InputEvent"
+)]
+pub enum GameScene {
+    Dungeon(DungeonScene),
+    Pause(PauseScene),
+    SaveMenu(SaveMenuScene),
+    GameOver(GameOverScene),
+}
+
+/// Exploring and fighting; the only scene that runs the game systems, so
+/// anything pushed over it pauses the run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DungeonScene;
+
+impl DungeonScene {
+    pub async fn on_update(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+    ) -> SceneTransition<GameScene> {
+        pump(services, systems, resources).await;
+        match resources.get::<RunState>().await {
+            Some(run) if run.dead => SceneTransition::Switch(GameScene::GameOver(GameOverScene)),
+            _ => SceneTransition::Stay,
+        }
+    }
+
+    pub async fn handle_input(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+        input: InputEvent,
+    ) -> SceneTransition<GameScene> {
+        let Some(mut bus) = resources.get_mut::<EventBus>().await else {
+            return SceneTransition::Stay;
+        };
+        match input {
+            InputEvent::Right | InputEvent::Char('n') => bus.publish(ExploreRequested),
+            InputEvent::Select | InputEvent::Char('a') => bus.publish(AttackRequested {
+                skill: Skill::Strike,
+            }),
+            InputEvent::Char('c') => bus.publish(AttackRequested {
+                skill: Skill::Cleave,
+            }),
+            InputEvent::Down | InputEvent::Char('d') => bus.publish(DescendRequested),
+            InputEvent::Cancel | InputEvent::Char('p') => {
+                return SceneTransition::Push(GameScene::Pause(PauseScene::default()))
+            }
+            _ => {}
+        }
+        SceneTransition::Stay
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PauseScene {
+    pub selected: usize,
+}
+
+impl PauseScene {
+    pub async fn on_update(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+    ) -> SceneTransition<GameScene> {
+        SceneTransition::Stay
+    }
+
+    pub async fn handle_input(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+        input: InputEvent,
+    ) -> SceneTransition<GameScene> {
+        match input {
+            InputEvent::Cancel | InputEvent::Char('p') => SceneTransition::Pop,
+            InputEvent::Up => {
+                self.selected = self.selected.saturating_sub(1);
+                SceneTransition::Stay
+            }
+            InputEvent::Down => {
+                self.selected = (self.selected + 1).min(PAUSE_ITEMS.len() - 1);
+                SceneTransition::Stay
+            }
+            InputEvent::Select => match self.selected {
+                0 => SceneTransition::Pop,
+                1 => {
+                    let mut menu = SaveLoadMenu::new(SAVE_SLOTS);
+                    menu.refresh(services, systems, resources).await;
+                    SceneTransition::Push(GameScene::SaveMenu(SaveMenuScene { menu }))
+                }
+                _ => SceneTransition::Quit,
+            },
+            _ => SceneTransition::Stay,
+        }
+    }
+}
+
+/// Save/load menu, pushed from the pause menu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveMenuScene {
+    pub menu: SaveLoadMenu,
+}
+
+impl SaveMenuScene {
+    pub async fn on_update(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+    ) -> SceneTransition<GameScene> {
+        SceneTransition::Stay
+    }
+
+    pub async fn handle_input(
+        &mut self,
+        services: &ServiceContext,
+        systems: &mut SystemContext,
+        resources: &mut ResourceContext,
+        input: InputEvent,
+    ) -> SceneTransition<GameScene> {
+        // The persisted resources are already restored; resume in the dungeon
+        self.menu
+            .handle_input(
+                services,
+                systems,
+                resources,
+                input,
+                |_loaded, _resources| SceneTransition::Replace(GameScene::Dungeon(DungeonScene)),
+            )
+            .await
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GameOverScene;
+
+impl GameOverScene {
+    pub async fn on_update(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+    ) -> SceneTransition<GameScene> {
+        SceneTransition::Stay
+    }
+
+    pub async fn handle_input(
+        &mut self,
+        _services: &ServiceContext,
+        _systems: &mut SystemContext,
+        _resources: &mut ResourceContext,
+        input: InputEvent,
+    ) -> SceneTransition<GameScene> {
+        match input {
+            InputEvent::Select | InputEvent::Cancel => SceneTransition::Quit,
+            _ => SceneTransition::Stay,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Game plugin: run state, loot table and the run system
+
+use crate::models::{LootTable, Player, RunState};
+use crate::services::FloorGenerator;
+use crate::systems::RunSystem;
+use issun::engine::MasterSeed;
+use issun::plugin::PluginBuilderExt;
+use issun::prelude::*;
+
+/// Loot table shipped with the game
+const LOOT_TABLE: &str = include_str!("../../assets/loot_table.ron");
+
+pub struct RoguelikePlugin {
+    seed: MasterSeed,
+    rooms_per_floor: u32,
+}
+
+impl RoguelikePlugin {
+    pub fn new(seed: MasterSeed, rooms_per_floor: u32) -> Self {
+        Self {
+            seed,
+            rooms_per_floor,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Plugin for RoguelikePlugin {
+    fn name(&self) -> &'static str {
+        "roguelike_plugin"
+    }
+
+    fn build(&self, builder: &mut dyn PluginBuilder) {
+        let generator = FloorGenerator;
+        let table = LootTable::from_ron(LOOT_TABLE).expect("assets/loot_table.ron is valid");
+        builder.register_resource(self.seed);
+        builder.register_resource(table);
+        builder.register_runtime_state(Player::default());
+        builder.register_runtime_state(RunState::default());
+        builder.register_runtime_state(generator.generate(self.seed, 1, self.rooms_per_floor));
+        builder.register_service(Box::new(generator));
+        builder.register_system(Box::new(RunSystem));
+    }
+}
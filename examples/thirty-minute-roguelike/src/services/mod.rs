@@ -0,0 +1,66 @@
+//! Pure game logic
+
+use crate::models::{Enemy, EnemyKind, FloorMap, Room, RoomKind};
+use issun::engine::{GameRng, MasterSeed};
+use issun::prelude::*;
+
+/// Rolls floor layouts from the master seed
+///
+/// Room 1 is the (already cleared) start and the last room holds the stairs; rooms between
+/// are fights or treasure. A floor only depends on (seed, floor), so a
+/// loaded save or a replay sees the same dungeon.
+#[derive(Clone, Default, DeriveService)]
+#[service(name = "floor_generator")]
+pub struct FloorGenerator;
+
+impl FloorGenerator {
+    pub fn generate(&self, seed: MasterSeed, floor: u32, room_count: u32) -> FloorMap {
+        let last = room_count.max(2);
+        let mut rng = seed.rng("floor", floor);
+        let rooms = (1..=last)
+            .map(|room| {
+                let kind = if room == 1 {
+                    RoomKind::Start
+                } else if room == last {
+                    RoomKind::Stairs
+                } else if rng.chance(0.25) {
+                    RoomKind::Treasure
+                } else {
+                    RoomKind::Monsters(self.pack(&mut rng, floor))
+                };
+                Room {
+                    cleared: matches!(kind, RoomKind::Start),
+                    kind,
+                }
+            })
+            .collect();
+        FloorMap { floor, rooms }
+    }
+
+    /// One to three enemies, stronger on deeper floors
+    fn pack(&self, rng: &mut GameRng, floor: u32) -> Vec<Enemy> {
+        let depth = floor as i32 - 1;
+        (1..=rng.range(1, 3))
+            .map(|index| {
+                let (kind, hp, attack, defense) = match rng.roll(6) {
+                    1..=3 => ("goblin", 12, 4, 0),
+                    4 | 5 => ("shaman", 9, 3, 0),
+                    _ => ("ogre", 22, 6, 2),
+                };
+                let hp = hp + depth * 4;
+                Enemy {
+                    name: format!("{}_{}", kind, index),
+                    kind: if kind == "shaman" {
+                        EnemyKind::Shaman
+                    } else {
+                        EnemyKind::Brute
+                    },
+                    hp,
+                    max_hp: hp,
+                    attack: attack + depth * 2,
+                    defense: defense + depth,
+                }
+            })
+            .collect()
+    }
+}
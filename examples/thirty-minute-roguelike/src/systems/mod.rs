@@ -0,0 +1,40 @@
+//! Game systems and the per-tick pump
+
+mod run;
+
+pub use run::RunSystem;
+
+use issun::modding::update_mod_systems;
+use issun::plugin::{CombatSystem, DungeonSystem, InventorySystem, LootSystem, SaveLoadSystem};
+use issun::prelude::{ResourceContext, ServiceContext, SystemContext};
+
+/// Let every system react to the events dispatched last tick
+///
+/// Runners only drive timers and feature flags; plugin systems run when the
+/// game calls them. Order follows the usual chain of a turn: move, react,
+/// fight, drop, equip, then saves and MOD parameters.
+pub async fn pump(
+    services: &ServiceContext,
+    systems: &mut SystemContext,
+    resources: &mut ResourceContext,
+) {
+    if let Some(system) = systems.get_mut::<DungeonSystem>() {
+        system.process_events(services, resources).await;
+    }
+    if let Some(system) = systems.get_mut::<RunSystem>() {
+        system.process_events(services, resources).await;
+    }
+    if let Some(system) = systems.get_mut::<CombatSystem>() {
+        system.process_events(services, resources).await;
+    }
+    if let Some(system) = systems.get_mut::<LootSystem>() {
+        system.process_events(services, resources).await;
+    }
+    if let Some(system) = systems.get_mut::<InventorySystem>() {
+        system.process_events(services, resources).await;
+    }
+    if let Some(system) = systems.get_mut::<SaveLoadSystem>() {
+        system.process_events(services, resources).await;
+    }
+    update_mod_systems(systems, resources).await;
+}
@@ -0,0 +1,244 @@
+//! Glue between the player's commands and the built-in plugins
+
+use crate::events::{AttackRequested, DescendRequested, ExploreRequested};
+use crate::models::{Encounter, FloorMap, LootTable, Player, RoomKind, RunState, PLAYER};
+use crate::services::FloorGenerator;
+use issun::engine::MasterSeed;
+use issun::plugin::{
+    CombatEndedEvent, CombatStartRequested, CombatTurnAdvanceRequested, CombatTurnCompletedEvent,
+    FloorAdvanceRequested, FloorAdvancedEvent, InventoryState, ItemAddRequested,
+    ItemEquipRequested, ItemEquippedEvent, LootGenerateRequested, LootGeneratedEvent,
+    RoomEnteredEvent, RoomMoveRequested,
+};
+use issun::prelude::*;
+use std::collections::HashMap;
+
+/// Gold per defeated enemy
+const BOUNTY: u32 = 5;
+
+/// Descending heals a third of max HP
+const REST_HEAL_DIVISOR: i32 = 3;
+
+#[derive(Default, DeriveSystem)]
+#[system(name = "run_system")]
+pub struct RunSystem;
+
+#[issun::event_handler]
+impl RunSystem {
+    #[subscribe(ExploreRequested)]
+    async fn on_explore(
+        &mut self,
+        _event: &ExploreRequested,
+        #[state] dungeon: &DungeonState,
+        #[state] map: &FloorMap,
+        #[state] run: &mut RunState,
+        #[state] bus: &mut EventBus,
+    ) {
+        let here = RoomId::new(dungeon.current_floor, dungeon.current_room);
+        let next = RoomId::new(dungeon.current_floor, dungeon.current_room + 1);
+        if run.encounter.is_some() || run.dead || map.room(&next).is_none() {
+            return;
+        }
+        if map.room(&here).is_some_and(|room| !room.cleared) {
+            run.log("Something still blocks the way");
+            return;
+        }
+        bus.publish(RoomMoveRequested { target_room: next });
+    }
+
+    #[subscribe(RoomEnteredEvent)]
+    async fn on_room_entered(
+        &mut self,
+        event: &RoomEnteredEvent,
+        #[state] map: &mut FloorMap,
+        #[state] run: &mut RunState,
+        #[state] table: &LootTable,
+        #[state] bus: &mut EventBus,
+    ) {
+        let id = &event.room_id;
+        let Some(room) = map.room_mut(id).filter(|room| !room.cleared) else {
+            return;
+        };
+        match &room.kind {
+            RoomKind::Monsters(enemies) => {
+                let battle_id = format!("f{}r{}", id.floor, id.room);
+                let names: Vec<&str> = enemies.iter().map(|e| e.name.as_str()).collect();
+                run.log(format!("Ambush! {}", names.join(", ")));
+                run.encounter = Some(Encounter {
+                    battle_id: battle_id.clone(),
+                    room: id.clone(),
+                    enemies: enemies.clone(),
+                    queued: Default::default(),
+                });
+                bus.publish(CombatStartRequested { battle_id });
+            }
+            RoomKind::Treasure => {
+                let source_id = format!("chest_f{}r{}", id.floor, id.room);
+                run.log("A chest!");
+                bus.publish(LootGenerateRequested {
+                    drop_rate: table.drop_rate(&source_id),
+                    source_id,
+                });
+                room.cleared = true;
+            }
+            RoomKind::Stairs => run.log("Stairs lead down"),
+            RoomKind::Start => {}
+        }
+    }
+
+    #[subscribe(AttackRequested)]
+    async fn on_attack(
+        &mut self,
+        event: &AttackRequested,
+        #[state] run: &mut RunState,
+        #[state] bus: &mut EventBus,
+    ) {
+        if let Some(encounter) = run.encounter.as_mut() {
+            encounter.queued = event.skill;
+            bus.publish(CombatTurnAdvanceRequested {
+                battle_id: encounter.battle_id.clone(),
+            });
+        }
+    }
+
+    #[subscribe(CombatTurnCompletedEvent)]
+    async fn on_turn(&mut self, event: &CombatTurnCompletedEvent, #[state] run: &mut RunState) {
+        for line in &event.log_entries {
+            run.log(line.clone());
+        }
+    }
+
+    #[subscribe(CombatEndedEvent)]
+    async fn on_combat_ended(
+        &mut self,
+        event: &CombatEndedEvent,
+        #[state] map: &mut FloorMap,
+        #[state] run: &mut RunState,
+        #[state] player: &mut Player,
+        #[state] table: &LootTable,
+        #[state] bus: &mut EventBus,
+    ) {
+        let Some(encounter) = run.encounter.take() else {
+            return;
+        };
+        match event.result {
+            CombatResult::Victory => {
+                run.log(format!("Victory in {} turns", event.total_turns));
+                if let Some(room) = map.room_mut(&encounter.room) {
+                    room.cleared = true;
+                }
+                for enemy in &encounter.enemies {
+                    player.gold += BOUNTY;
+                    let source_id = format!("{}_{}", enemy.name, encounter.battle_id);
+                    bus.publish(LootGenerateRequested {
+                        drop_rate: table.drop_rate(&source_id),
+                        source_id,
+                    });
+                }
+            }
+            CombatResult::Defeat => {
+                run.log("You died");
+                run.dead = true;
+            }
+            CombatResult::Ongoing => {}
+        }
+    }
+
+    #[subscribe(DescendRequested)]
+    async fn on_descend(
+        &mut self,
+        _event: &DescendRequested,
+        #[state] dungeon: &DungeonState,
+        #[state] map: &FloorMap,
+        #[state] run: &RunState,
+        #[state] bus: &mut EventBus,
+    ) {
+        let here = RoomId::new(dungeon.current_floor, dungeon.current_room);
+        let on_stairs = map
+            .room(&here)
+            .is_some_and(|room| matches!(room.kind, RoomKind::Stairs));
+        if on_stairs && run.encounter.is_none() && !run.dead {
+            bus.publish(FloorAdvanceRequested);
+        }
+    }
+
+    #[subscribe(FloorAdvancedEvent)]
+    async fn on_floor_advanced(
+        &mut self,
+        event: &FloorAdvancedEvent,
+        #[state] map: &mut FloorMap,
+        #[state] run: &mut RunState,
+        #[state] player: &mut Player,
+        #[state] seed: &MasterSeed,
+        #[service(name = "floor_generator")] generator: &FloorGenerator,
+    ) {
+        let rooms = map.rooms.len() as u32;
+        *map = generator.generate(*seed, event.new_floor, rooms);
+        player.hp = (player.hp + player.max_hp / REST_HEAL_DIVISOR).min(player.max_hp);
+        run.log(format!("You rest on the stairs. Floor {}", event.new_floor));
+    }
+
+    /// Keep the best item of every slot equipped
+    #[subscribe(LootGeneratedEvent)]
+    async fn on_loot(
+        &mut self,
+        event: &LootGeneratedEvent,
+        #[state] run: &mut RunState,
+        #[state] inventory: &InventoryState,
+        #[state] table: &LootTable,
+        #[state] bus: &mut EventBus,
+    ) {
+        let power = |item: &str| table.items.get(item).map_or(0, |stats| stats.power());
+        let mut upgrades: HashMap<&str, &String> = HashMap::new();
+        for item in &event.items {
+            run.log(format!("Found {}", item));
+            bus.publish(ItemAddRequested {
+                entity_id: PLAYER.to_string(),
+                item_id: item.clone(),
+                quantity: 1,
+            });
+            let Some(stats) = table.items.get(item) else {
+                continue;
+            };
+            let current = upgrades
+                .get(stats.slot.as_str())
+                .map(|item| item.as_str())
+                .or_else(|| {
+                    inventory
+                        .get_equipped(&PLAYER.to_string(), &stats.slot)
+                        .map(String::as_str)
+                });
+            if current.is_none_or(|current| power(current) < stats.power()) {
+                upgrades.insert(stats.slot.as_str(), item);
+            }
+        }
+        for (slot, item) in upgrades {
+            bus.publish(ItemEquipRequested {
+                entity_id: PLAYER.to_string(),
+                slot: slot.to_string(),
+                item_id: item.clone(),
+            });
+        }
+    }
+
+    #[subscribe(ItemEquippedEvent)]
+    async fn on_equipped(
+        &mut self,
+        event: &ItemEquippedEvent,
+        #[state] player: &mut Player,
+        #[state] run: &mut RunState,
+        #[state] inventory: &InventoryState,
+        #[state] table: &LootTable,
+    ) {
+        let equipped = inventory.get_equipment(&event.entity_id);
+        let gear = equipped
+            .into_iter()
+            .flat_map(|slots| slots.values())
+            .filter_map(|item| table.items.get(item));
+        (player.gear_attack, player.gear_defense) = gear
+            .fold((0, 0), |(attack, defense), stats| {
+                (attack + stats.attack, defense + stats.defense)
+            });
+        run.log(format!("Equipped {}", event.item_id));
+    }
+}
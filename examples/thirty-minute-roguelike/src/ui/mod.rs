@@ -0,0 +1,111 @@
+//! Rendering of the scenes
+
+use crate::models::scenes::PAUSE_ITEMS;
+use crate::models::{FloorMap, GameScene, Player, RoomKind, RunState};
+use issun::prelude::*;
+use issun::ui::ratatui::{RatatuiTheme, SaveMenuWidget};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+pub fn render(frame: &mut Frame, scene: &GameScene, resources: &ResourceContext) {
+    match scene {
+        GameScene::Dungeon(_) => render_dungeon(frame, resources),
+        GameScene::Pause(pause) => {
+            let lines: Vec<Line> = PAUSE_ITEMS
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    let marker = if index == pause.selected { ">" } else { " " };
+                    Line::from(format!("{} {}", marker, item))
+                })
+                .collect();
+            frame.render_widget(boxed(lines, "Paused"), frame.area());
+        }
+        GameScene::SaveMenu(save) => {
+            let theme = resources
+                .try_get::<RatatuiTheme>()
+                .map(|theme| theme.clone())
+                .unwrap_or_default();
+            SaveMenuWidget::new(&save.menu)
+                .with_theme(&theme)
+                .render(frame, frame.area());
+        }
+        GameScene::GameOver(_) => {
+            let gold = resources.try_get::<Player>().map_or(0, |p| p.gold);
+            let lines = vec![
+                Line::from("You died."),
+                Line::from(format!("Gold: {}", gold)),
+                Line::from("Enter to quit"),
+            ];
+            frame.render_widget(boxed(lines, "Game Over"), frame.area());
+        }
+    }
+}
+
+fn render_dungeon(frame: &mut Frame, resources: &ResourceContext) {
+    let (Some(player), Some(dungeon), Some(map), Some(run)) = (
+        resources.try_get::<Player>(),
+        resources.try_get::<DungeonState>(),
+        resources.try_get::<FloorMap>(),
+        resources.try_get::<RunState>(),
+    ) else {
+        return;
+    };
+    let [status, log] =
+        Layout::vertical([Constraint::Length(8), Constraint::Min(3)]).areas(frame.area());
+
+    let rooms: String = map
+        .rooms
+        .iter()
+        .enumerate()
+        .map(
+            |(index, room)| match (&room.kind, index as u32 + 1 == dungeon.current_room) {
+                (_, true) => '@',
+                (_, false) if !room.cleared && dungeon.current_room < index as u32 + 1 => '?',
+                (RoomKind::Stairs, _) => '>',
+                _ => '.',
+            },
+        )
+        .collect();
+    let mut lines = vec![
+        Line::from(format!("Floor {}  [{}]", dungeon.current_floor, rooms)),
+        Line::from(format!(
+            "HP {}/{}  ATK {}  DEF {}  Gold {}  Cleave {}",
+            player.hp,
+            player.max_hp,
+            player.attack(),
+            player.defense().unwrap_or(0),
+            player.gold,
+            match player.cleave_cooldown {
+                0 => "ready".to_string(),
+                turns => format!("in {}", turns),
+            }
+        )),
+    ];
+    match &run.encounter {
+        Some(encounter) => {
+            for enemy in encounter.enemies.iter().filter(|e| e.is_alive()) {
+                lines.push(Line::from(format!(
+                    "  {} {}/{}",
+                    enemy.name, enemy.hp, enemy.max_hp
+                )));
+            }
+            lines.push(Line::from("a: strike  c: cleave  p: pause"));
+        }
+        None => lines.push(Line::from("n: next room  d: descend  p: pause")),
+    }
+    frame.render_widget(boxed(lines, "Thirty-Minute Roguelike"), status);
+
+    let entries: Vec<Line> = run
+        .log
+        .iter()
+        .map(|line| Line::from(line.as_str()))
+        .collect();
+    frame.render_widget(boxed(entries, "Log"), log);
+}
+
+fn boxed<'a>(lines: Vec<Line<'a>>, title: &'a str) -> Paragraph<'a> {
+    Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title))
+}
@@ -0,0 +1,57 @@
+//! Scripted playthroughs on seed 7
+
+use issun::plugin::{CombatConfig, DungeonState, LoadGameRequested, SaveGameRequested};
+use issun::testing::scenario::{self, ScenarioFactories};
+use std::path::PathBuf;
+use thirty_minute_roguelike::events::{AttackRequested, DescendRequested, ExploreRequested};
+use thirty_minute_roguelike::models::{FloorMap, Player, RunState};
+
+fn factories(saves: PathBuf) -> ScenarioFactories {
+    ScenarioFactories::new()
+        .game("roguelike", move || {
+            let saves = saves.clone();
+            async move { thirty_minute_roguelike::new_run(7, saves).await }
+        })
+        .event::<ExploreRequested>()
+        .unwrap()
+        .event::<AttackRequested>()
+        .unwrap()
+        .event::<DescendRequested>()
+        .unwrap()
+        .event::<SaveGameRequested>()
+        .unwrap()
+        .event::<LoadGameRequested>()
+        .unwrap()
+        .observe::<Player>()
+        .observe::<RunState>()
+        .observe::<FloorMap>()
+        .observe::<DungeonState>()
+        .observe::<CombatConfig>()
+}
+
+async fn play(script: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(format!("{}.ron", script));
+    // Every script gets its own save directory
+    let saves = std::env::temp_dir().join(format!(
+        "thirty-minute-roguelike-{}-{}",
+        script,
+        std::process::id()
+    ));
+    let result = scenario::run(path, &factories(saves.clone())).await;
+    let _ = std::fs::remove_dir_all(&saves);
+    if let Err(error) = result {
+        panic!("{}", error);
+    }
+}
+
+#[tokio::test]
+async fn test_floor_one_playthrough() {
+    play("floor1").await;
+}
+
+#[tokio::test]
+async fn test_save_slot_restores_run() {
+    play("save_load").await;
+}
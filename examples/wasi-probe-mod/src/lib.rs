@@ -0,0 +1,71 @@
+//! WASI probe MOD for ISSUN
+//!
+//! Touches the WASI resources a guest commonly needs (stdout, the wall
+//! clock and preopened directories) so the host's WASI wiring can be tested.
+//! Build for `wasm32-wasip2`, which links the WASI preview2 imports.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+use wit_bindgen::generate;
+
+// Generate guest bindings from WIT
+generate!({
+    world: "mod-guest",
+    path: "../../crates/issun-mod-wasm/wit/issun.wit",
+});
+
+struct WasiProbe;
+
+/// Seconds since the Unix epoch, read through wasi:clocks
+fn unix_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+impl Guest for WasiProbe {
+    fn get_metadata() -> Metadata {
+        Metadata {
+            name: "WASI Probe".to_string(),
+            version: "1.0.0".to_string(),
+            author: Some("ISSUN Team".to_string()),
+            description: Some("Exercises WASI stdout, clocks and filesystem".to_string()),
+        }
+    }
+
+    fn on_init() {
+        // Goes through wasi:cli/stdout, an output-stream resource
+        println!("wasi-probe: initialized");
+        issun::modapi::api::log(&format!("wall clock: {}", unix_secs()));
+    }
+
+    fn on_shutdown() {
+        println!("wasi-probe: shutting down");
+    }
+
+    fn on_control_plugin(_plugin_name: String, _action: String) {}
+
+    fn on_event(_event_type: String, _payload_json: String) {}
+
+    fn call_custom(fn_name: String, args_json: String) -> String {
+        // Arguments arrive as one JSON array
+        let args: Vec<serde_json::Value> = serde_json::from_str(&args_json).unwrap_or_default();
+
+        match fn_name.as_str() {
+            "now" => unix_secs().to_string(),
+            "read_file" => {
+                let path = args
+                    .first()
+                    .and_then(|arg| arg.as_str())
+                    .unwrap_or_default();
+                match std::fs::read_to_string(path) {
+                    Ok(contents) => serde_json::json!({ "contents": contents }).to_string(),
+                    Err(e) => serde_json::json!({ "error": e.to_string() }).to_string(),
+                }
+            }
+            _ => serde_json::json!({ "error": "Unknown function" }).to_string(),
+        }
+    }
+}
+
+export!(WasiProbe);
@@ -13,6 +13,10 @@ pub struct District {
     pub infected: u32,
     pub dead: u32,
     pub panic_level: f32,
+    /// Resistance to infection, raised by isolation policies
+    pub resistance: u32,
+    /// Infection pressure from neighbouring districts
+    pub infection_pressure: f32,
 }
 
 impl District {
@@ -24,6 +28,8 @@ impl District {
             infected: 0,
             dead: 0,
             panic_level: 0.2,
+            resistance: 10,
+            infection_pressure: 0.0,
         }
     }
 
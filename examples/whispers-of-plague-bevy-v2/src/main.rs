@@ -11,7 +11,7 @@ use bevy::prelude::*;
 use bevy::state::app::StatesPlugin;
 use components::*;
 use crossterm::event::{self, Event, KeyCode};
-use issun_bevy::plugins::contagion_v2::{ContagionInputParams, ContagionState};
+use issun_bevy::plugins::contagion_v2::ContagionState;
 use plugins::GameSetupPlugin;
 use resources::{GameContext, GameMode, UIState, VictoryResult};
 use states::GameScene;
@@ -134,24 +134,22 @@ fn main() -> std::io::Result<()> {
                             }
                         }
                         // Isolation policy (decrease panic and increase resistance)
+                        // The params provider applies it before the next contagion step
                         KeyCode::Char('i') | KeyCode::Char('I') => {
                             let selected = world.resource::<UIState>().selected_district;
-                            let mut query =
-                                world.query::<(&mut District, &mut ContagionInputParams)>();
-                            let mut items: Vec<_> = query.iter_mut(world).collect();
+                            let mut query = world.query::<&mut District>();
+                            let mut districts: Vec<_> = query.iter_mut(world).collect();
 
-                            if let Some((ref mut district, ref mut params)) =
-                                items.get_mut(selected)
-                            {
+                            if let Some(district) = districts.get_mut(selected) {
                                 // Decrease panic level
                                 district.panic_level = (district.panic_level - 0.15).max(0.0);
                                 // Increase resistance
-                                params.resistance = (params.resistance + 5).min(50);
+                                district.resistance = (district.resistance + 5).min(50);
                                 let msg = format!(
                                     "Isolation in {}! Panic: {:.1}%, Resistance: {}",
                                     district.name,
                                     district.panic_level * 100.0,
-                                    params.resistance
+                                    district.resistance
                                 );
                                 let mut ui_state = world.resource_mut::<UIState>();
                                 ui_state.add_message(msg);
@@ -177,6 +175,8 @@ fn main() -> std::io::Result<()> {
                                     district.infected = 0;
                                     district.dead = 0;
                                     district.panic_level = 0.2;
+                                    district.resistance = 10;
+                                    district.infection_pressure = 0.0;
                                     state.state.severity = 0;
                                 }
                             }
@@ -12,8 +12,9 @@ pub struct GameSetupPlugin;
 
 impl Plugin for GameSetupPlugin {
     fn build(&self, app: &mut App) {
-        // Add contagion_v2 plugin first
-        app.add_plugins(ContagionV2Plugin::new().with_base_rate(0.15));
+        // Add contagion_v2 plugin first; its input params come from each District
+        app.add_plugins(ContagionV2Plugin::new().with_base_rate(0.15))
+            .add_contagion_input_source(district_input_params);
 
         // Game states and resources
         app.init_state::<GameScene>()
@@ -54,13 +55,11 @@ fn setup_districts(mut commands: Commands) {
     ];
 
     for (id, name, population) in districts {
-        let resistance = 10; // Base resistance
-        let density = 0.0; // Start with no infection pressure (will be updated by propagation)
-
+        // Params are computed from the District every frame by district_input_params
         commands.spawn((
             District::new(id, name, population),
             ContagionState::<PlagueVirus>::default(),
-            ContagionInputParams::new(density, resistance),
+            ContagionInputParams::new(0.0, 0),
         ));
     }
 }
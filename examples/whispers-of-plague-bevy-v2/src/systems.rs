@@ -3,6 +3,7 @@
 use crate::components::*;
 use crate::resources::{GameContext, GameMode, UIState, VictoryResult, VictoryState};
 use crate::states::GameScene;
+use bevy::ecs::world::EntityRef;
 use bevy::prelude::*;
 use issun_bevy::plugins::contagion_v2::*;
 use issun_core::mechanics::propagation::*;
@@ -13,20 +14,38 @@ pub fn setup_initial_infection(mut _commands: Commands) {
     // Initial infection will be set by infect_initial_district system
 }
 
+/// Contagion input params of a district, computed every frame
+///
+/// Infected districts spread with a base density of 0.5, exposed ones with
+/// the pressure from their neighbours; panic adds to both.
+pub fn district_input_params(
+    entity: EntityRef,
+    _current: &ContagionInputParams,
+) -> Option<ContagionInputParams> {
+    let district = entity.get::<District>()?;
+    let severity = entity
+        .get::<ContagionState<PlagueVirus>>()
+        .map_or(0, |state| state.severity());
+
+    let density = if severity > 0 {
+        (0.5 + district.panic_level).min(1.0)
+    } else if district.infection_pressure > 0.0 {
+        (district.infection_pressure + district.panic_level).min(1.0)
+    } else {
+        0.0
+    };
+    Some(ContagionInputParams::new(density, district.resistance))
+}
+
 /// Infect the initial district when game starts
 pub fn infect_initial_district(
-    mut query: Query<(
-        &District,
-        &mut ContagionState<PlagueVirus>,
-        &mut ContagionInputParams,
-    )>,
+    mut query: Query<(&District, &mut ContagionState<PlagueVirus>)>,
     mut ui_state: ResMut<UIState>,
 ) {
     // Infect Downtown district
-    for (district, mut state, mut params) in query.iter_mut() {
+    for (district, mut state) in query.iter_mut() {
         if district.id == "downtown" {
             state.state.severity = 100; // Start with severity 100
-            params.density = 0.8; // High initial infection density
             ui_state.add_message(format!("Patient Zero detected in {}!", district.name));
             break;
         }
@@ -34,12 +53,11 @@ pub fn infect_initial_district(
 }
 
 /// Propagate infection between districts using PropagationMechanic
+///
+/// Stores the resulting pressure on each District; `district_input_params`
+/// turns it into density.
 pub fn propagate_infection_between_districts_system(
-    mut query: Query<(
-        &District,
-        &mut ContagionState<PlagueVirus>,
-        &mut ContagionInputParams,
-    )>,
+    mut query: Query<(&mut District, &mut ContagionState<PlagueVirus>)>,
     contagion_graph: Res<crate::resources::ContagionGraph>,
     game_context: Res<GameContext>,
     mut ui_state: ResMut<UIState>,
@@ -61,10 +79,14 @@ pub fn propagate_infection_between_districts_system(
     // Step 2: Collect current infection states
     let district_data: std::collections::HashMap<String, (u32, String, f32)> = query
         .iter()
-        .map(|(district, state, _)| {
+        .map(|(district, state)| {
             (
                 district.id.clone(),
-                (state.severity(), district.name.clone(), district.panic_level),
+                (
+                    state.severity(),
+                    district.name.clone(),
+                    district.panic_level,
+                ),
             )
         })
         .collect();
@@ -79,22 +101,17 @@ pub fn propagate_infection_between_districts_system(
     // Step 4: Run PropagationMechanic
     let mut prop_state = PropagationState::default();
     let mut events = Vec::new();
-    let mut emitter = VecEmitter { events: &mut events };
+    let mut emitter = VecEmitter {
+        events: &mut events,
+    };
 
     LinearPropagationMechanic::step(&prop_graph, &mut prop_state, input, &mut emitter);
 
     // Step 5: Apply propagation results to districts
-    for (district, mut state, mut params) in query.iter_mut() {
-        let current_severity = district_data
-            .get(&district.id)
-            .map(|(s, _, _)| *s)
-            .unwrap_or(0);
-
+    for (mut district, mut state) in query.iter_mut() {
         let panic_level = district.panic_level;
         let infection_pressure = prop_state.get_pressure(&district.id);
-
-        // Calculate effective density = infection_pressure + panic_level bonus
-        let effective_density = (infection_pressure + panic_level).min(1.0);
+        district.infection_pressure = infection_pressure;
 
         // Apply infection events
         for event in &events {
@@ -104,7 +121,6 @@ pub fn propagate_infection_between_districts_system(
                     initial_severity,
                 } if node == &district.id => {
                     state.state.severity = *initial_severity;
-                    params.density = effective_density;
 
                     ui_state.add_message(format!(
                         "{} INFECTED! Initial severity: {} (pressure: {:.2}, panic: {:.1}%)",
@@ -117,15 +133,6 @@ pub fn propagate_infection_between_districts_system(
                 _ => {}
             }
         }
-
-        // Update density based on infection status
-        if current_severity == 0 && infection_pressure > 0.0 {
-            // Not yet infected but exposed - update density
-            params.density = effective_density;
-        } else if current_severity > 0 {
-            // Already infected - use effective density (base + panic)
-            params.density = (0.5 + panic_level).min(1.0);
-        }
     }
 }
 